@@ -0,0 +1,194 @@
+//! Benchmarks for the work done on every keystroke.
+//!
+//! Run with `cargo bench --bench keystroke`. Each group works on a large
+//! synthetic document and a long query, so a slowdown in any one step of
+//! the typing path shows up here before it shows up as lag.
+
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::sync::Arc;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use jiq::autocomplete::json_navigator::{DEFAULT_ARRAY_SAMPLE_SIZE, NavigationCache};
+use jiq::autocomplete::{BraceTracker, analyze_context, get_suggestions_with_variables};
+use jiq::headless::Headless;
+use jiq::query::worker::preprocess::preprocess_result;
+use jiq::syntax_highlight::JqHighlighter;
+use ratatui::crossterm::event::KeyCode;
+use serde_json::{Value, json};
+use tokio_util::sync::CancellationToken;
+
+const OBJECTS: usize = 20_000;
+const STATUSES: [&str; 3] = ["pending", "shipped", "delivered"];
+const CITIES: [&str; 3] = ["Paris", "Lima", "Oslo"];
+
+const QUERY: &str = r#".orders[] | select(.status == "shipped" and .total > 100) | {id, customer: .customer.name, city: .customer.address.city, items: [.items[] | .sku]} | .ci"#;
+
+/// An `orders` array of nested objects, about 6 MB pretty-printed
+fn fixture() -> Value {
+    let orders: Vec<Value> = (0..OBJECTS)
+        .map(|i| {
+            json!({
+                "id": i,
+                "status": STATUSES[i % 3],
+                "total": (i % 500) as f64 * 1.25,
+                "customer": {
+                    "name": format!("Customer {i}"),
+                    "address": {"city": CITIES[i % 3], "zip": format!("{:05}", i)},
+                },
+                "items": [{"sku": format!("SKU-{i}"), "qty": i % 7}],
+            })
+        })
+        .collect();
+    json!({ "orders": orders })
+}
+
+/// `text` colored the way jq colors its output: keys, strings, numbers and
+/// punctuation each in their own escape
+fn colorize(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() * 2);
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        out.push_str(indent);
+        match trimmed.split_once(": ") {
+            Some((key, value)) => {
+                out.push_str(&format!("\x1b[34;1m{key}\x1b[0m: \x1b[0;32m{value}\x1b[0m"));
+            }
+            None => out.push_str(&format!("\x1b[1;39m{trimmed}\x1b[0m")),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn bench_highlight(c: &mut Criterion) {
+    c.bench_function("highlight/long_query", |b| {
+        b.iter(|| JqHighlighter::highlight(black_box(QUERY)))
+    });
+}
+
+fn bench_analyze_context(c: &mut Criterion) {
+    let mut tracker = BraceTracker::new();
+    tracker.rebuild(QUERY);
+    c.bench_function("analyze_context/long_query", |b| {
+        b.iter(|| analyze_context(black_box(QUERY), &tracker))
+    });
+}
+
+fn bench_get_suggestions(c: &mut Criterion) {
+    let json = Arc::new(fixture());
+    let mut tracker = BraceTracker::new();
+    tracker.rebuild(QUERY);
+    let field_names = Arc::new(HashSet::new());
+    // Mid-query, so the path is navigated in the document
+    let cursor = QUERY.find(".address").unwrap() + ".address.".len();
+    let suggest = |navigation: &mut NavigationCache| {
+        get_suggestions_with_variables(
+            navigation,
+            QUERY,
+            cursor,
+            None,
+            None,
+            Some(Arc::clone(&json)),
+            Arc::clone(&field_names),
+            &tracker,
+            DEFAULT_ARRAY_SAMPLE_SIZE,
+            true,
+            &[],
+        )
+    };
+
+    let mut group = c.benchmark_group("get_suggestions");
+    group.bench_function("from_the_root", |b| {
+        b.iter(|| suggest(&mut NavigationCache::new()))
+    });
+    let mut kept = NavigationCache::new();
+    group.bench_function("kept_cache", |b| b.iter(|| suggest(&mut kept)));
+    group.finish();
+}
+
+/// Suggestions at every cursor position, as moving the cursor through the
+/// query asks for them: a fresh `NavigationCache` per position against
+/// one kept across positions
+fn bench_cursor_walk(c: &mut Criterion) {
+    let json = Arc::new(fixture());
+    let mut tracker = BraceTracker::new();
+    tracker.rebuild(QUERY);
+    let field_names = Arc::new(HashSet::new());
+    let suggest = |navigation: &mut NavigationCache, cursor: usize| {
+        get_suggestions_with_variables(
+            navigation,
+            QUERY,
+            cursor,
+            None,
+            None,
+            Some(Arc::clone(&json)),
+            Arc::clone(&field_names),
+            &tracker,
+            DEFAULT_ARRAY_SAMPLE_SIZE,
+            true,
+            &[],
+        )
+    };
+
+    let mut group = c.benchmark_group("cursor_walk");
+    group.bench_function("from_the_root", |b| {
+        b.iter(|| {
+            for cursor in 1..QUERY.len() {
+                black_box(suggest(&mut NavigationCache::new(), cursor));
+            }
+        })
+    });
+    let mut kept = NavigationCache::new();
+    group.bench_function("kept_cache", |b| {
+        b.iter(|| {
+            for cursor in 1..QUERY.len() {
+                black_box(suggest(&mut kept, cursor));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_colorization(c: &mut Criterion) {
+    let output = colorize(&serde_json::to_string_pretty(&fixture()).unwrap());
+    let cancel = CancellationToken::new();
+    let mut group = c.benchmark_group("query_state");
+    group.sample_size(10);
+    group.bench_function("preprocess_colored_output", |b| {
+        b.iter_batched(
+            || output.clone(),
+            |output| preprocess_result(output, ".", &cancel, DEFAULT_ARRAY_SAMPLE_SIZE),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_keystroke(c: &mut Criterion) {
+    let mut jiq = Headless::new(serde_json::to_string(&fixture()).unwrap());
+    jiq.type_str(".orders[0].customer");
+    jiq.settle();
+
+    let mut group = c.benchmark_group("keystroke");
+    group.bench_function("type_and_erase", |b| {
+        b.iter(|| {
+            jiq.type_str(".");
+            jiq.key(KeyCode::Backspace);
+        })
+    });
+    group.bench_function("render", |b| b.iter(|| black_box(jiq.screen())));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_highlight,
+    bench_analyze_context,
+    bench_get_suggestions,
+    bench_cursor_walk,
+    bench_colorization,
+    bench_keystroke
+);
+criterion_main!(benches);
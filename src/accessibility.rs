@@ -0,0 +1,33 @@
+//! Screen reader mode
+//!
+//! Turned on by `--screen-reader` or `screen_reader = true` under
+//! `[accessibility]`. Box-drawing borders are blanked out of each frame,
+//! the bottom line says in words what just changed instead of listing keys,
+//! and the linear layout stacks panes that would sit side by side so the
+//! screen reads from top to bottom.
+
+pub mod announcer;
+pub mod plain_borders;
+
+use crate::config::AccessibilityConfig;
+use announcer::Announcer;
+
+pub struct AccessibilityState {
+    pub screen_reader: bool,
+    pub linear_layout: bool,
+    pub announcer: Announcer,
+}
+
+impl AccessibilityState {
+    pub fn new(config: &AccessibilityConfig) -> Self {
+        Self {
+            screen_reader: config.screen_reader,
+            linear_layout: config.linear_layout.unwrap_or(config.screen_reader),
+            announcer: Announcer::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "accessibility_tests.rs"]
+mod accessibility_tests;
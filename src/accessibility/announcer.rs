@@ -0,0 +1,128 @@
+use crate::app::{App, Focus};
+use crate::editor::EditorMode;
+
+/// What the status line reports on
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Status {
+    /// Where keys go: the query and its editing mode, or the popup open
+    pub mode: String,
+    /// Shape of the result while the query succeeds, as in the results
+    /// title
+    pub results: Option<String>,
+    /// First line of jq's message while the query fails
+    pub error: Option<String>,
+    pub notification: Option<String>,
+}
+
+impl Status {
+    pub fn of(app: &App) -> Self {
+        let query_result = app.query.as_ref().map(|q| &q.result);
+        let error = match query_result {
+            Some(Err(message)) => message.lines().next().map(str::to_string),
+            _ => None,
+        };
+        let results = match query_result {
+            Some(Ok(_)) => app.stats.display(),
+            _ => None,
+        };
+        Self {
+            mode: mode(app),
+            results,
+            error,
+            notification: app.notification.current_message().map(str::to_string),
+        }
+    }
+}
+
+fn mode(app: &App) -> String {
+    let popup = if app.help.visible {
+        Some("Help")
+    } else if app.log_viewer.is_visible() {
+        Some("Debug log")
+    } else if app.search.is_visible() {
+        Some("Search")
+    } else if app.snippets.is_visible() {
+        Some("Snippets")
+    } else if app.save.is_visible() {
+        Some("Save")
+    } else if app.history.is_visible() {
+        Some("History")
+    } else {
+        None
+    };
+    if let Some(popup) = popup {
+        return popup.to_string();
+    }
+    if app.has_focus(Focus::ResultsPane) {
+        return "Results pane".to_string();
+    }
+    match app.input.editor_mode {
+        EditorMode::Insert => "Query, insert mode".to_string(),
+        EditorMode::Normal => "Query, normal mode".to_string(),
+        other => format!("Query, {}", other.display()),
+    }
+}
+
+/// Turns changes of [`Status`] into a sentence for the status line
+///
+/// Only what changed since the last status is said, so a screen reader
+/// that speaks new text on screen reads out news rather than the whole
+/// state again.
+#[derive(Debug, Default)]
+pub struct Announcer {
+    last: Option<Status>,
+    message: String,
+}
+
+impl Announcer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take in the current status; true when the message changed
+    pub fn update(&mut self, status: Status) -> bool {
+        let last = self.last.take();
+        if last.as_ref() == Some(&status) {
+            self.last = last;
+            return false;
+        }
+        let last = last.unwrap_or_default();
+
+        let mut parts = Vec::new();
+        if status.mode != last.mode {
+            parts.push(status.mode.clone());
+        }
+        if status.error != last.error
+            && let Some(error) = &status.error
+        {
+            parts.push(format!("Error: {}", error));
+        }
+        if status.results != last.results
+            && let Some(results) = &status.results
+        {
+            parts.push(format!("Result: {}", results));
+        }
+        if status.notification != last.notification
+            && let Some(notification) = &status.notification
+        {
+            parts.push(notification.clone());
+        }
+        self.last = Some(status);
+
+        // Something went away, like an expired notification
+        if parts.is_empty() {
+            return false;
+        }
+        self.message = parts.join(". ");
+        true
+    }
+
+    /// The latest announcement; empty until the first
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(test)]
+#[path = "announcer_tests.rs"]
+mod announcer_tests;
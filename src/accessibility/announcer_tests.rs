@@ -0,0 +1,162 @@
+use super::*;
+use crate::app::Focus;
+use crate::config::Config;
+use crate::headless::Headless;
+use crate::test_utils::test_helpers::test_app;
+
+fn status(mode: &str) -> Status {
+    Status {
+        mode: mode.to_string(),
+        ..Status::default()
+    }
+}
+
+#[test]
+fn test_first_update_announces_everything() {
+    let mut announcer = Announcer::new();
+    let status = Status {
+        results: Some("Array [3 objects]".to_string()),
+        ..status("Query, insert mode")
+    };
+
+    assert!(announcer.update(status));
+    assert_eq!(
+        announcer.message(),
+        "Query, insert mode. Result: Array [3 objects]"
+    );
+}
+
+#[test]
+fn test_same_status_is_not_news() {
+    let mut announcer = Announcer::new();
+    announcer.update(status("Results pane"));
+
+    assert!(!announcer.update(status("Results pane")));
+    assert_eq!(announcer.message(), "Results pane");
+}
+
+#[test]
+fn test_only_changes_are_announced() {
+    let mut announcer = Announcer::new();
+    announcer.update(Status {
+        results: Some("Object".to_string()),
+        ..status("Query, insert mode")
+    });
+
+    assert!(announcer.update(Status {
+        results: Some("Object".to_string()),
+        ..status("Query, normal mode")
+    }));
+    assert_eq!(announcer.message(), "Query, normal mode");
+
+    assert!(announcer.update(Status {
+        results: Some("String".to_string()),
+        ..status("Query, normal mode")
+    }));
+    assert_eq!(announcer.message(), "Result: String");
+}
+
+#[test]
+fn test_errors_are_announced() {
+    let mut announcer = Announcer::new();
+    announcer.update(status("Query, insert mode"));
+
+    announcer.update(Status {
+        error: Some("jq: error: syntax error".to_string()),
+        ..status("Query, insert mode")
+    });
+
+    assert_eq!(announcer.message(), "Error: jq: error: syntax error");
+}
+
+#[test]
+fn test_result_is_announced_again_after_an_error() {
+    let mut announcer = Announcer::new();
+    let ok = Status {
+        results: Some("Object".to_string()),
+        ..status("Query, insert mode")
+    };
+    announcer.update(ok.clone());
+    announcer.update(Status {
+        error: Some("jq: error".to_string()),
+        ..status("Query, insert mode")
+    });
+
+    announcer.update(ok);
+
+    assert_eq!(announcer.message(), "Result: Object");
+}
+
+#[test]
+fn test_something_going_away_keeps_the_message() {
+    let mut announcer = Announcer::new();
+    announcer.update(status("Help"));
+    announcer.update(Status {
+        notification: Some("Copied query".to_string()),
+        ..status("Help")
+    });
+
+    assert!(!announcer.update(status("Help")));
+    assert_eq!(announcer.message(), "Copied query");
+}
+
+#[test]
+fn test_status_of_app() {
+    let mut app = test_app(r#"{"name": "jiq"}"#);
+    assert_eq!(Status::of(&app).mode, "Query, insert mode");
+
+    app.input.editor_mode = EditorMode::Normal;
+    assert_eq!(Status::of(&app).mode, "Query, normal mode");
+
+    app.focus = Focus::ResultsPane;
+    assert_eq!(Status::of(&app).mode, "Results pane");
+
+    app.help.visible = true;
+    assert_eq!(Status::of(&app).mode, "Help");
+}
+
+#[test]
+fn test_status_of_failed_query_has_the_first_line_of_the_error() {
+    let mut app = test_app(r#"{"name": "jiq"}"#);
+    app.query.as_mut().unwrap().result = Err("jq: error: oops\nmore".to_string());
+
+    let status = Status::of(&app);
+
+    assert_eq!(status.error.as_deref(), Some("jq: error: oops"));
+    assert_eq!(status.results, None);
+}
+
+fn screen_reader() -> Headless {
+    let mut config = Config::default();
+    config.accessibility.screen_reader = true;
+    Headless::builder(r#"{"items": [1, 2, 3]}"#)
+        .config(config)
+        .size(60, 12)
+        .build()
+}
+
+fn last_line(screen: &str) -> &str {
+    screen.lines().last().unwrap().trim()
+}
+
+#[test]
+fn test_status_line_announces_results_and_errors() {
+    let mut jiq = screen_reader();
+    jiq.type_str(".items");
+    assert!(jiq.settle());
+    assert_eq!(last_line(&jiq.screen()), "Result: Array [3 numbers]");
+
+    jiq.type_str(" | nope");
+    assert!(jiq.settle());
+    assert!(last_line(&jiq.screen()).starts_with("Error: "));
+}
+
+#[test]
+fn test_screen_has_no_borders() {
+    let mut jiq = screen_reader();
+    assert!(jiq.settle());
+
+    let screen = jiq.screen();
+
+    assert!(!screen.contains(['─', '│', '╭', '╯']), "{screen}");
+}
@@ -0,0 +1,25 @@
+use ratatui::buffer::Buffer;
+
+/// Blank out the borders in `buffer`
+///
+/// Screen readers name each box-drawing character they meet, so a frame
+/// of borders reads as a long run of "box drawings light horizontal".
+/// Titles set into a border stay where they are.
+pub fn strip(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        let mut chars = cell.symbol().chars();
+        if let (Some(c), None) = (chars.next(), chars.next())
+            && is_box_drawing(c)
+        {
+            cell.set_symbol(" ");
+        }
+    }
+}
+
+fn is_box_drawing(c: char) -> bool {
+    ('\u{2500}'..='\u{257F}').contains(&c)
+}
+
+#[cfg(test)]
+#[path = "plain_borders_tests.rs"]
+mod plain_borders_tests;
@@ -0,0 +1,53 @@
+use super::*;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, BorderType, Borders, Widget};
+
+fn text(buffer: &Buffer) -> Vec<String> {
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_strip_blanks_borders_and_keeps_titles() {
+    let area = Rect::new(0, 0, 12, 3);
+    let mut buffer = Buffer::empty(area);
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Query ")
+        .render(area, &mut buffer);
+
+    strip(&mut buffer);
+
+    assert_eq!(
+        text(&buffer),
+        ["  Query     ", "            ", "            "]
+    );
+}
+
+#[test]
+fn test_strip_keeps_other_text() {
+    let area = Rect::new(0, 0, 8, 1);
+    let mut buffer = Buffer::empty(area);
+    buffer.set_string(0, 0, "a|b •█é", ratatui::style::Style::default());
+
+    strip(&mut buffer);
+
+    assert_eq!(text(&buffer), ["a|b •█é "]);
+}
+
+#[test]
+fn test_strip_blanks_heavy_and_double_lines() {
+    let area = Rect::new(0, 0, 4, 1);
+    let mut buffer = Buffer::empty(area);
+    buffer.set_string(0, 0, "━║╬x", ratatui::style::Style::default());
+
+    strip(&mut buffer);
+
+    assert_eq!(text(&buffer), ["   x"]);
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn test_off_by_default() {
+    let state = AccessibilityState::new(&AccessibilityConfig::default());
+    assert!(!state.screen_reader);
+    assert!(!state.linear_layout);
+}
+
+#[test]
+fn test_linear_layout_follows_screen_reader() {
+    let config = AccessibilityConfig {
+        screen_reader: true,
+        linear_layout: None,
+    };
+    let state = AccessibilityState::new(&config);
+    assert!(state.screen_reader);
+    assert!(state.linear_layout);
+}
+
+#[test]
+fn test_linear_layout_set_on_its_own() {
+    let on = AccessibilityConfig {
+        screen_reader: false,
+        linear_layout: Some(true),
+    };
+    assert!(AccessibilityState::new(&on).linear_layout);
+
+    let off = AccessibilityConfig {
+        screen_reader: true,
+        linear_layout: Some(false),
+    };
+    assert!(!AccessibilityState::new(&off).linear_layout);
+}
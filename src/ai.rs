@@ -7,6 +7,8 @@ pub mod ai_events;
 pub mod ai_render;
 pub mod ai_state; // Made public for integration tests
 pub mod context;
+pub mod followup;
+pub mod nl_prompt;
 pub mod prompt;
 mod provider;
 pub mod render;
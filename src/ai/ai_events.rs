@@ -12,8 +12,10 @@ use std::sync::mpsc::TryRecvError;
 use super::ai_state::{AiResponse, AiState};
 use super::context::{ContextParams, QueryContext};
 use super::prompt::build_prompt;
-use super::selection::{apply::apply_suggestion, keybindings};
+use super::selection::apply::{ApplyResult, append_suggestion, apply_suggestion};
+use super::selection::keybindings;
 use crate::autocomplete::AutocompleteState;
+use crate::history::HistoryState;
 use crate::input::InputState;
 use crate::query::QueryState;
 
@@ -27,23 +29,38 @@ use crate::query::QueryState;
 /// * `input_state` - The input state to modify
 /// * `query_state` - The query state for execution
 /// * `autocomplete_state` - The autocomplete state to hide
+/// * `history` - History to record the applied query in, marked as AI-originated
 pub fn apply_clicked_suggestion(
     suggestion: &super::suggestion::Suggestion,
     input_state: &mut InputState,
     query_state: &mut QueryState,
     autocomplete_state: &mut AutocompleteState,
+    history: &mut HistoryState,
 ) {
-    apply_suggestion(suggestion, input_state, query_state, autocomplete_state);
+    let result = apply_suggestion(suggestion, input_state, query_state, autocomplete_state);
+    record_applied(&result, query_state, history);
 }
 
-/// Handle suggestion selection events (Alt+1-5, Alt+Up/Down/j/k, Enter)
+/// Record an applied suggestion in history once it has executed cleanly.
+///
+/// Failing queries are left out, matching how typed queries only reach history
+/// when they succeed.
+fn record_applied(result: &ApplyResult, query_state: &QueryState, history: &mut HistoryState) {
+    if result.applied && query_state.result.is_ok() {
+        history.add_ai_entry(&result.query);
+    }
+}
+
+/// Handle suggestion selection events (Alt+1-5, Alt+Up/Down/j/k, Enter, Tab)
 ///
 /// This function handles all suggestion selection keybindings:
 /// 1. Direct selection (Alt+1-5): Immediately applies the selected suggestion
 /// 2. Navigation (Alt+Up/Down or Alt+j/k): Moves selection highlight through suggestions
-/// 3. Enter: Applies the currently navigated selection (if navigation is active)
+/// 3. Enter: Replaces the query with the navigated selection (if navigation is active)
+/// 4. Tab: Appends the navigated selection to the query (if navigation is active)
 ///
-/// Returns true if the key was handled, false otherwise.
+/// Applied queries that execute successfully are recorded in history marked
+/// as AI-originated. Returns true if the key was handled, false otherwise.
 ///
 /// # Arguments
 /// * `key` - The key event to handle
@@ -51,12 +68,14 @@ pub fn apply_clicked_suggestion(
 /// * `input_state` - The input state to modify when applying suggestions
 /// * `query_state` - The query state for execution
 /// * `autocomplete_state` - The autocomplete state to hide when applying
+/// * `history` - History to record applied queries in
 pub fn handle_suggestion_selection(
     key: KeyEvent,
     ai_state: &mut AiState,
     input_state: &mut InputState,
     query_state: &mut QueryState,
     autocomplete_state: &mut AutocompleteState,
+    history: &mut HistoryState,
 ) -> bool {
     if !ai_state.visible || ai_state.suggestions.is_empty() {
         return false;
@@ -67,7 +86,8 @@ pub fn handle_suggestion_selection(
     if let Some(index) = keybindings::handle_direct_selection(key, suggestion_count)
         && let Some(suggestion) = ai_state.suggestions.get(index)
     {
-        apply_suggestion(suggestion, input_state, query_state, autocomplete_state);
+        let result = apply_suggestion(suggestion, input_state, query_state, autocomplete_state);
+        record_applied(&result, query_state, history);
         ai_state.selection.clear_selection();
         return true;
     }
@@ -79,7 +99,17 @@ pub fn handle_suggestion_selection(
     if let Some(index) = keybindings::handle_apply_selection(key, &ai_state.selection)
         && let Some(suggestion) = ai_state.suggestions.get(index)
     {
-        apply_suggestion(suggestion, input_state, query_state, autocomplete_state);
+        let result = apply_suggestion(suggestion, input_state, query_state, autocomplete_state);
+        record_applied(&result, query_state, history);
+        ai_state.selection.clear_selection();
+        return true;
+    }
+
+    if let Some(index) = keybindings::handle_append_selection(key, &ai_state.selection)
+        && let Some(suggestion) = ai_state.suggestions.get(index)
+    {
+        let result = append_suggestion(suggestion, input_state, query_state, autocomplete_state);
+        record_applied(&result, query_state, history);
         ai_state.selection.clear_selection();
         return true;
     }
@@ -163,7 +193,7 @@ pub fn handle_execution_result(
     match query_result {
         Err(error) => {
             if ai_state.visible {
-                let context = QueryContext::new(
+                let mut context = QueryContext::new(
                     query.to_string(),
                     cursor_pos,
                     None,
@@ -171,13 +201,17 @@ pub fn handle_execution_result(
                     params,
                     ai_state.max_context_length,
                 );
+                context.redact(&ai_state.redactor);
                 let prompt = build_prompt(&context);
-                ai_state.send_request(prompt);
+                ai_state
+                    .conversation
+                    .begin(format!("Why does `{}` fail, and how do I fix it?", query));
+                ai_state.send_context_request(&context, prompt);
             }
         }
         Ok(output) => {
             if ai_state.visible {
-                let context = QueryContext::new(
+                let mut context = QueryContext::new(
                     query.to_string(),
                     cursor_pos,
                     Some(output.clone()),
@@ -185,8 +219,12 @@ pub fn handle_execution_result(
                     params,
                     ai_state.max_context_length,
                 );
+                context.redact(&ai_state.redactor);
                 let prompt = build_prompt(&context);
-                ai_state.send_request(prompt);
+                ai_state
+                    .conversation
+                    .begin(format!("What can I do next with `{}`?", query));
+                ai_state.send_context_request(&context, prompt);
             }
         }
     }
@@ -3,6 +3,7 @@
 use super::*;
 use crate::ai::suggestion::{Suggestion, SuggestionType};
 use crate::autocomplete::AutocompleteState;
+use crate::history::HistoryState;
 use crate::input::InputState;
 use crate::query::QueryState;
 
@@ -34,11 +35,13 @@ fn test_apply_clicked_suggestion_applies_to_query() {
     assert_eq!(input_state.query(), ".existing");
 
     let suggestion = make_suggestion(".new.query");
+    let mut history = HistoryState::empty();
     apply_clicked_suggestion(
         &suggestion,
         &mut input_state,
         &mut query_state,
         &mut autocomplete_state,
+        &mut history,
     );
 
     // The old query was cleared and the clicked suggestion's query inserted.
@@ -49,3 +52,38 @@ fn test_apply_clicked_suggestion_applies_to_query() {
         ".new.query".chars().count()
     );
 }
+
+// Test: a clicked suggestion that runs cleanly lands in history marked as AI.
+#[test]
+fn test_apply_clicked_suggestion_records_ai_history() {
+    let (mut input_state, mut query_state, mut autocomplete_state) = create_test_states();
+    let mut history = HistoryState::empty();
+
+    apply_clicked_suggestion(
+        &make_suggestion(".name"),
+        &mut input_state,
+        &mut query_state,
+        &mut autocomplete_state,
+        &mut history,
+    );
+
+    assert_eq!(history.entry_at_display_index(0), Some(".name"));
+    assert!(history.is_ai_originated(".name"));
+}
+
+// Test: suggestions that fail to execute are not recorded in history.
+#[test]
+fn test_apply_clicked_suggestion_skips_history_on_error() {
+    let (mut input_state, mut query_state, mut autocomplete_state) = create_test_states();
+    let mut history = HistoryState::empty();
+
+    apply_clicked_suggestion(
+        &make_suggestion(".name |"),
+        &mut input_state,
+        &mut query_state,
+        &mut autocomplete_state,
+        &mut history,
+    );
+
+    assert_eq!(history.total_count(), 0);
+}
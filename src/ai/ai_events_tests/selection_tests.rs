@@ -1,6 +1,96 @@
-//! Tests for selection key handling (number keys, up/down navigation)
-//!
-//! Note: The original ai_events_tests.rs file did not contain explicit selection tests.
-//! This file is created as a placeholder for future selection-related tests.
+//! Tests for selection key handling (Alt+1-5, Alt+Up/Down navigation, Enter/Tab accept)
 
-// Placeholder - no selection tests found in original file
+use super::*;
+use crate::ai::suggestion::{Suggestion, SuggestionType};
+use crate::autocomplete::AutocompleteState;
+use crate::history::HistoryState;
+use crate::input::InputState;
+use crate::query::QueryState;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+struct Fixture {
+    ai_state: AiState,
+    input_state: InputState,
+    query_state: QueryState,
+    autocomplete_state: AutocompleteState,
+    history: HistoryState,
+}
+
+impl Fixture {
+    fn new(suggestions: &[&str]) -> Self {
+        let mut ai_state = AiState::new(true);
+        ai_state.visible = true;
+        ai_state.suggestions = suggestions
+            .iter()
+            .map(|q| Suggestion {
+                query: q.to_string(),
+                description: "Test description".to_string(),
+                suggestion_type: SuggestionType::Next,
+            })
+            .collect();
+
+        Self {
+            ai_state,
+            input_state: InputState::new(),
+            query_state: QueryState::new(r#"{"name": "test", "value": 42}"#.to_string()),
+            autocomplete_state: AutocompleteState::new(),
+            history: HistoryState::empty(),
+        }
+    }
+
+    fn press(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        handle_suggestion_selection(
+            KeyEvent::new(code, modifiers),
+            &mut self.ai_state,
+            &mut self.input_state,
+            &mut self.query_state,
+            &mut self.autocomplete_state,
+            &mut self.history,
+        )
+    }
+}
+
+#[test]
+fn test_enter_after_navigation_replaces_query_and_records_history() {
+    let mut f = Fixture::new(&[".name", ".value"]);
+    f.input_state.textarea.insert_str(".old");
+
+    assert!(f.press(KeyCode::Down, KeyModifiers::ALT));
+    assert!(f.press(KeyCode::Down, KeyModifiers::ALT));
+    assert!(f.press(KeyCode::Enter, KeyModifiers::NONE));
+
+    assert_eq!(f.input_state.query(), ".value");
+    assert!(f.history.is_ai_originated(".value"));
+    assert!(!f.ai_state.selection.is_navigation_active());
+}
+
+#[test]
+fn test_tab_after_navigation_appends_to_query() {
+    let mut f = Fixture::new(&["ascii_upcase"]);
+    f.input_state.textarea.insert_str(".name");
+
+    assert!(f.press(KeyCode::Down, KeyModifiers::ALT));
+    assert!(f.press(KeyCode::Tab, KeyModifiers::NONE));
+
+    assert_eq!(f.input_state.query(), ".name | ascii_upcase");
+    assert!(f.history.is_ai_originated(".name | ascii_upcase"));
+}
+
+#[test]
+fn test_enter_and_tab_pass_through_without_navigation() {
+    let mut f = Fixture::new(&[".name"]);
+
+    assert!(!f.press(KeyCode::Enter, KeyModifiers::NONE));
+    assert!(!f.press(KeyCode::Tab, KeyModifiers::NONE));
+    assert_eq!(f.history.total_count(), 0);
+}
+
+#[test]
+fn test_direct_selection_records_history() {
+    let mut f = Fixture::new(&[".name", ".value"]);
+
+    assert!(f.press(KeyCode::Char('1'), KeyModifiers::ALT));
+
+    assert_eq!(f.input_state.query(), ".name");
+    assert!(f.history.is_ai_originated(".name"));
+}
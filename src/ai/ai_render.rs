@@ -12,7 +12,9 @@ use ratatui::{
 };
 
 use super::ai_state::AiState;
+use super::context::ContextUsage;
 use crate::scroll::Scrollable;
+use crate::str_utils::fmt_bytes;
 use crate::theme;
 use crate::widgets::{popup, scrollbar};
 
@@ -240,6 +242,18 @@ fn render_suggestions_as_widgets(
     }
 }
 
+/// Title suffix showing how much context the last request carried
+fn context_usage_label(usage: ContextUsage) -> String {
+    let mut label = format!("· {} ", fmt_bytes(usage.prompt_bytes));
+    if usage.sampled {
+        label.push_str("sampled ");
+    }
+    if usage.redacted > 0 {
+        label.push_str(&format!("· {} redacted ", usage.redacted));
+    }
+    label
+}
+
 /// Render the AI assistant popup
 ///
 /// Returns the popup area for region tracking.
@@ -285,11 +299,18 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
 
     popup::clear_area(frame, popup_area);
 
-    let title = Line::from(vec![
+    let mut title_spans = vec![
         Span::raw(" "),
         Span::styled(&ai_state.provider_name, theme::ai::title()),
         Span::raw(" "),
-    ]);
+    ];
+    if let Some(usage) = ai_state.context_usage {
+        title_spans.push(Span::styled(
+            context_usage_label(usage),
+            Style::default().fg(theme::ai::counter()),
+        ));
+    }
+    let title = Line::from(title_spans);
 
     let counter = if ai_state.suggestions.len() > 1 {
         let current = ai_state
@@ -318,9 +339,17 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
         0
     };
 
+    // Keep the model name clear of a long left title (context usage suffix).
     let max_model_width = (popup_area.width / 2)
         .saturating_sub(2)
-        .saturating_sub(counter_width / 2);
+        .saturating_sub(counter_width / 2)
+        .min(
+            popup_area
+                .width
+                .saturating_sub(title.width() as u16)
+                .saturating_sub(counter_width)
+                .saturating_sub(4),
+        );
     let model_display = if ai_state.model_name.len() > max_model_width as usize {
         format!(
             "{}...",
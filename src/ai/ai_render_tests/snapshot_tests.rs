@@ -552,3 +552,43 @@ fn snapshot_ai_popup_openai_compatible_provider() {
     let output = render_ai_popup_to_string(&mut state, 100, 30);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_ai_popup_with_context_usage() {
+    let mut state = AiState::new_with_config(
+        true,
+        true,
+        "Anthropic".to_string(),
+        "claude-3-5-sonnet-20241022".to_string(),
+        TEST_MAX_CONTEXT_LENGTH,
+    );
+    state.visible = true;
+    state.context_usage = Some(crate::ai::context::ContextUsage {
+        prompt_bytes: 24_576,
+        sampled: true,
+        redacted: 0,
+    });
+
+    let output = render_ai_popup_to_string(&mut state, 100, 30);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_ai_popup_with_redacted_values() {
+    let mut state = AiState::new_with_config(
+        true,
+        true,
+        "Anthropic".to_string(),
+        "claude-3-5-sonnet-20241022".to_string(),
+        TEST_MAX_CONTEXT_LENGTH,
+    );
+    state.visible = true;
+    state.context_usage = Some(crate::ai::context::ContextUsage {
+        prompt_bytes: 2_048,
+        sampled: false,
+        redacted: 3,
+    });
+
+    let output = render_ai_popup_to_string(&mut state, 100, 30);
+    assert_snapshot!(output);
+}
@@ -5,6 +5,9 @@
 
 use std::sync::mpsc::{Receiver, Sender};
 
+use super::context::{ContextUsage, Redactor};
+use super::followup::Conversation;
+use super::nl_prompt::NlPromptState;
 use super::selection::SelectionState;
 use tokio_util::sync::CancellationToken;
 
@@ -12,6 +15,9 @@ use tokio_util::sync::CancellationToken;
 #[allow(unused_imports)]
 pub use super::suggestion::{Suggestion, SuggestionType};
 
+/// Notification shown when the user reaches for AI in offline mode
+pub const OFFLINE_MESSAGE: &str = "Offline mode: AI assistant is disabled";
+
 // Module declarations
 #[path = "ai_state/lifecycle.rs"]
 pub(crate) mod lifecycle;
@@ -69,12 +75,16 @@ pub struct AiState {
     pub enabled: bool,
     /// Whether the AI is properly configured (has API key)
     pub configured: bool,
+    /// Whether offline mode hard-disables the assistant (`--offline` or `[network] offline`)
+    pub offline: bool,
     /// Name of the AI provider (e.g., "Anthropic", "Bedrock", "OpenAI")
     pub provider_name: String,
     /// Model name (e.g., "claude-3-5-sonnet-20241022", "gpt-4o-mini")
     pub model_name: String,
     /// Maximum character length for JSON context samples
     pub max_context_length: usize,
+    /// Masks secrets in context before it is put into a prompt
+    pub redactor: Redactor,
     /// Whether we're waiting for or receiving a response
     pub loading: bool,
     /// Current error message (if any)
@@ -120,6 +130,14 @@ pub struct AiState {
     /// Previous popup height (when suggestions were last rendered)
     /// Used to maintain consistent size during loading transitions
     pub previous_popup_height: Option<u16>,
+    /// Ctrl+G natural-language prompt line
+    pub nl_prompt: NlPromptState,
+    /// Context size of the last request sent, shown in the popup title
+    pub context_usage: Option<ContextUsage>,
+    /// Follow-up question line shown at the bottom of the popup
+    pub followup: NlPromptState,
+    /// Recent exchanges replayed in follow-up prompts
+    pub conversation: Conversation,
 }
 
 impl Default for AiState {
@@ -2,9 +2,14 @@
 //!
 //! Handles initialization, state transitions, and clearing operations.
 
+use super::super::context::Redactor;
+use super::super::followup::Conversation;
+use super::super::nl_prompt::NlPromptState;
 use super::super::selection::SelectionState;
 use super::super::suggestion::{ParseOutcome, parse_response};
 use crate::ai::ai_state::AiState;
+use crate::config::Config;
+use crate::config::ai_types::AiProviderType;
 
 /// Default max context length for tests
 #[cfg(test)]
@@ -21,9 +26,11 @@ impl AiState {
             visible: false,
             enabled,
             configured: false,
+            offline: false,
             provider_name: "AI".to_string(),
             model_name: String::new(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redactor: Redactor::default(),
             loading: false,
             error: None,
             response: String::new(),
@@ -39,6 +46,10 @@ impl AiState {
             no_suggestions: false,
             selection: SelectionState::new(),
             previous_popup_height: None,
+            nl_prompt: NlPromptState::new(),
+            context_usage: None,
+            followup: NlPromptState::new(),
+            conversation: Conversation::new(),
         }
     }
 
@@ -61,9 +72,11 @@ impl AiState {
             visible: enabled,
             enabled,
             configured,
+            offline: false,
             provider_name,
             model_name,
             max_context_length,
+            redactor: Redactor::default(),
             loading: false,
             error: None,
             response: String::new(),
@@ -79,9 +92,88 @@ impl AiState {
             no_suggestions: false,
             selection: SelectionState::new(),
             previous_popup_height: None,
+            nl_prompt: NlPromptState::new(),
+            context_usage: None,
+            followup: NlPromptState::new(),
+            conversation: Conversation::new(),
         }
     }
 
+    /// Set up from the `[ai]` config, with the provider and model it names
+    /// and whether they have credentials. Offline mode disables it.
+    pub fn from_config(config: &Config) -> Self {
+        let anthropic_configured =
+            config.ai.anthropic.api_key.is_some() && config.ai.anthropic.model.is_some();
+        let bedrock_configured =
+            config.ai.bedrock.region.is_some() && config.ai.bedrock.model.is_some();
+        let openai_configured =
+            config.ai.openai.api_key.is_some() && config.ai.openai.model.is_some();
+        let gemini_configured =
+            config.ai.gemini.api_key.is_some() && config.ai.gemini.model.is_some();
+
+        let provider_name = match config.ai.provider {
+            Some(AiProviderType::Anthropic) => "Anthropic",
+            Some(AiProviderType::Bedrock) => "Bedrock",
+            Some(AiProviderType::Openai) => {
+                // Check if using custom OpenAI-compatible endpoint
+                let is_custom = config
+                    .ai
+                    .openai
+                    .base_url
+                    .as_ref()
+                    .map(|url| !url.contains("api.openai.com"))
+                    .unwrap_or(false);
+                if is_custom {
+                    "OpenAI-compatible"
+                } else {
+                    "OpenAI"
+                }
+            }
+            Some(AiProviderType::Gemini) => "Gemini",
+            None => "Not Configured",
+        }
+        .to_string();
+
+        let ai_configured = config.ai.provider.is_some()
+            && (anthropic_configured
+                || bedrock_configured
+                || openai_configured
+                || gemini_configured);
+
+        let model_name = match config.ai.provider {
+            Some(AiProviderType::Anthropic) => {
+                config.ai.anthropic.model.clone().unwrap_or_default()
+            }
+            Some(AiProviderType::Bedrock) => config.ai.bedrock.model.clone().unwrap_or_default(),
+            Some(AiProviderType::Openai) => config.ai.openai.model.clone().unwrap_or_default(),
+            Some(AiProviderType::Gemini) => config.ai.gemini.model.clone().unwrap_or_default(),
+            None => String::new(),
+        };
+
+        let mut ai_state = Self::new_with_config(
+            config.ai.enabled,
+            ai_configured,
+            provider_name,
+            model_name,
+            config.ai.max_context_length as usize,
+        );
+        ai_state.redactor = Redactor::from_config(&config.ai.redaction);
+        if config.network.offline {
+            ai_state.set_offline();
+        }
+        ai_state
+    }
+
+    /// Hard-disable the assistant for offline mode
+    ///
+    /// Hides the popup and clears `enabled` so no request is ever built;
+    /// the worker is never spawned, so there are no channels to send on.
+    pub fn set_offline(&mut self) {
+        self.offline = true;
+        self.enabled = false;
+        self.visible = false;
+    }
+
     /// Toggle the visibility of the AI popup
     pub fn toggle(&mut self) {
         self.visible = !self.visible;
@@ -124,6 +216,8 @@ impl AiState {
     ///
     /// A genuinely-empty response (no bytes received at all) is treated as
     /// neither: it leaves all three cleared so the UI stays blank.
+    ///
+    /// Non-empty responses are also recorded in `conversation` for follow-ups.
     pub fn complete_request(&mut self) {
         self.loading = false;
         self.previous_response = None;
@@ -146,6 +240,8 @@ impl AiState {
                     );
                 }
             }
+            self.conversation
+                .complete(&self.suggestions, &self.response);
         } else {
             // No bytes received before completion: all classification flags stay
             // clear, so the popup renders empty until the next query.
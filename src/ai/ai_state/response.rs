@@ -9,6 +9,7 @@ use std::sync::mpsc::{Receiver, Sender};
 use tokio_util::sync::CancellationToken;
 
 use crate::ai::ai_state::{AiRequest, AiResponse, AiState};
+use crate::ai::context::{ContextUsage, QueryContext};
 
 impl AiState {
     /// Append a chunk to the current response
@@ -65,6 +66,19 @@ impl AiState {
         false
     }
 
+    /// Send a prompt built from `context`, recording its size for the popup
+    ///
+    /// Same as [`send_request`](Self::send_request); `context_usage` is only
+    /// updated when the request actually went out.
+    pub fn send_context_request(&mut self, context: &QueryContext, prompt: String) -> bool {
+        let usage = ContextUsage::new(context, &prompt);
+        let sent = self.send_request(prompt);
+        if sent {
+            self.context_usage = Some(usage);
+        }
+        sent
+    }
+
     /// Set the channel handles for communication with the worker thread
     pub fn set_channels(
         &mut self,
@@ -62,3 +62,50 @@ fn test_send_request_send_failure_clears_cancel_token_and_returns_false() {
         "a failed send must clear current_cancel_token so a later cancel can't act on a dead request"
     );
 }
+
+fn sampled_context() -> QueryContext {
+    QueryContext {
+        query: ".".to_string(),
+        cursor_pos: 1,
+        output_sample: Some("[1,\"... 99 more items\"]".to_string()),
+        error: None,
+        is_success: true,
+        is_empty_result: false,
+        input_schema: None,
+        base_query: None,
+        base_query_result: None,
+        output_sampled: true,
+        redacted_count: 2,
+    }
+}
+
+#[test]
+fn test_send_context_request_records_usage() {
+    let (mut state, _req_rx) = state_with_channel();
+
+    let ok = state.send_context_request(&sampled_context(), "prompt".to_string());
+
+    assert!(ok);
+    assert_eq!(
+        state.context_usage,
+        Some(ContextUsage {
+            prompt_bytes: 6,
+            sampled: true,
+            redacted: 2,
+        })
+    );
+}
+
+#[test]
+fn test_send_context_request_failure_keeps_previous_usage() {
+    let (mut state, req_rx) = state_with_channel();
+    drop(req_rx);
+
+    let ok = state.send_context_request(&sampled_context(), "prompt".to_string());
+
+    assert!(!ok);
+    assert_eq!(
+        state.context_usage, None,
+        "usage only reflects requests that actually went out"
+    );
+}
@@ -65,6 +65,16 @@ fn test_toggle_visibility() {
     assert!(!state.visible);
 }
 
+#[test]
+fn test_set_offline_hides_and_disables() {
+    let mut state = AiState::new(true);
+    state.visible = true;
+    state.set_offline();
+    assert!(state.offline);
+    assert!(!state.enabled);
+    assert!(!state.visible);
+}
+
 #[test]
 fn test_close() {
     let mut state = AiState::new(true);
@@ -3,6 +3,11 @@
 //! Builds context from app state for AI requests including query, cursor position,
 //! error messages, and JSON structure information.
 
+mod json_sampler;
+pub mod redaction;
+
+pub use redaction::Redactor;
+
 /// Maximum length for JSON sample in context (100KB characters)
 pub const MAX_JSON_SAMPLE_LENGTH: usize = 100_000;
 
@@ -45,6 +50,39 @@ pub struct QueryContext {
     pub base_query: Option<String>,
     /// Output of base_query (truncated to max 25000 chars)
     pub base_query_result: Option<String>,
+    /// Whether `output_sample` was sampled down to fit the context budget
+    pub output_sampled: bool,
+    /// Number of secret values masked by [`QueryContext::redact`]
+    pub redacted_count: usize,
+}
+
+/// How much context the last AI request carried, for the popup indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextUsage {
+    /// Size of the full prompt sent to the provider, in bytes
+    pub prompt_bytes: usize,
+    /// Whether the query output had to be sampled to fit the budget
+    pub sampled: bool,
+    /// Number of secret values masked before sending
+    pub redacted: usize,
+}
+
+impl ContextUsage {
+    pub fn new(context: &QueryContext, prompt: &str) -> Self {
+        Self {
+            prompt_bytes: prompt.len(),
+            sampled: context.output_sampled,
+            redacted: context.redacted_count,
+        }
+    }
+}
+
+/// JSON prepared for AI context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonSample {
+    pub text: String,
+    /// True when the text was sampled or truncated rather than sent whole
+    pub sampled: bool,
 }
 
 impl QueryContext {
@@ -66,8 +104,10 @@ impl QueryContext {
         } else {
             output
                 .as_ref()
-                .map(|o| prepare_json_for_context(o, max_context_length))
+                .map(|o| sample_json_for_context(o, max_context_length))
         };
+        let output_sampled = output_sample.as_ref().is_some_and(|s| s.sampled);
+        let output_sample = output_sample.map(|s| s.text);
 
         // base_query_result is now already processed
         let base_query_result = params.base_query_result.map(|s| s.to_string());
@@ -82,8 +122,48 @@ impl QueryContext {
             input_schema: params.input_schema.map(|s| s.to_string()),
             base_query: params.base_query.map(|s| s.to_string()),
             base_query_result,
+            output_sampled,
+            redacted_count: 0,
         }
     }
+
+    /// Mask secrets in every field that is copied into the prompt
+    ///
+    /// The schema is left alone: it holds key names and types, not values.
+    pub fn redact(&mut self, redactor: &Redactor) {
+        if !redactor.is_enabled() {
+            return;
+        }
+
+        let mut count = 0;
+        let mut apply = |text: &mut String, as_json: bool| {
+            let (redacted, n) = if as_json {
+                redactor.redact_json(text)
+            } else {
+                redactor.redact_text(text)
+            };
+            if n > 0 {
+                *text = redacted;
+                count += n;
+            }
+        };
+
+        apply(&mut self.query, false);
+        if let Some(output) = self.output_sample.as_mut() {
+            apply(output, true);
+        }
+        if let Some(error) = self.error.as_mut() {
+            apply(error, false);
+        }
+        if let Some(base_query) = self.base_query.as_mut() {
+            apply(base_query, false);
+        }
+        if let Some(result) = self.base_query_result.as_mut() {
+            apply(result, true);
+        }
+
+        self.redacted_count = count;
+    }
 }
 
 /// Attempt to minify JSON by parsing and re-serializing without whitespace
@@ -93,20 +173,43 @@ fn try_minify_json(json: &str) -> Option<String> {
         .and_then(|v| serde_json::to_string(&v).ok())
 }
 
-/// Prepare JSON for context with smart minification and truncation
+/// Prepare JSON for context with smart minification, sampling, and truncation
+///
+/// See [`sample_json_for_context`]; this returns only the text.
+pub fn prepare_json_for_context(json: &str, max_len: usize) -> String {
+    sample_json_for_context(json, max_len).text
+}
+
+/// Fit JSON into a `max_len` byte budget
 ///
 /// Logic:
 /// 1. For files under 5MB: always minify for consistent dense output
-/// 2. For files >= 5MB: skip minification to bound parse cost
-/// 3. Truncate result if needed to max_len
-pub fn prepare_json_for_context(json: &str, max_len: usize) -> String {
-    let content = if json.len() <= MINIFY_SIZE_LIMIT {
-        try_minify_json(json).unwrap_or_else(|| json.to_string())
-    } else {
-        json.to_string()
-    };
+/// 2. If still over budget, sample structure-preservingly (first array
+///    elements, clipped strings, all object keys)
+/// 3. For files >= 5MB or invalid JSON: skip parsing to bound cost and truncate
+pub fn sample_json_for_context(json: &str, max_len: usize) -> JsonSample {
+    if json.len() <= MINIFY_SIZE_LIMIT {
+        let minified = try_minify_json(json);
+        let content = minified.as_deref().unwrap_or(json);
+        if content.len() <= max_len {
+            return JsonSample {
+                text: content.to_string(),
+                sampled: false,
+            };
+        }
+        if let Some(text) = json_sampler::sample_json(json, max_len) {
+            return JsonSample {
+                text,
+                sampled: true,
+            };
+        }
+    }
 
-    truncate_json(&content, max_len)
+    let text = truncate_json(json, max_len);
+    JsonSample {
+        sampled: text.len() != json.len(),
+        text,
+    }
 }
 
 /// Truncate JSON to a maximum length, trying to preserve valid structure
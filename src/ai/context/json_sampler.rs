@@ -0,0 +1,87 @@
+//! Structure-preserving JSON sampling for AI context
+//!
+//! When a result is too large for the context budget, plain truncation cuts
+//! through the middle of the document and the AI loses sight of its shape.
+//! Sampling instead keeps every object key, shortens arrays to their first
+//! elements, and clips long strings, tightening step by step until the
+//! minified output fits.
+
+use serde_json::{Deserializer, Value};
+
+use super::truncate_json;
+
+/// Progressively tighter `(max_array_items, max_string_chars)` limits tried in order
+const SAMPLING_LEVELS: &[(usize, usize)] = &[(20, 500), (5, 200), (2, 100), (1, 40)];
+
+/// Sample a JSON document (or a newline-separated stream of values) to fit `max_len`
+///
+/// Returns `None` when the input is not valid JSON. If even the tightest
+/// level is over budget (e.g. an object with thousands of keys), the tightest
+/// sample is truncated as a last resort.
+pub fn sample_json(json: &str, max_len: usize) -> Option<String> {
+    let values = Deserializer::from_str(json)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut tightest = String::new();
+    for &(max_items, max_chars) in SAMPLING_LEVELS {
+        tightest = render_stream(&values, max_items, max_chars);
+        if tightest.len() <= max_len {
+            return Some(tightest);
+        }
+    }
+
+    Some(truncate_json(&tightest, max_len))
+}
+
+/// Render the first `max_items` stream values, one minified value per line
+fn render_stream(values: &[Value], max_items: usize, max_chars: usize) -> String {
+    let mut lines: Vec<String> = values
+        .iter()
+        .take(max_items)
+        .map(|v| serde_json::to_string(&sample_value(v, max_items, max_chars)).unwrap_or_default())
+        .collect();
+
+    if values.len() > max_items {
+        lines.push(format!("... {} more results", values.len() - max_items));
+    }
+
+    lines.join("\n")
+}
+
+fn sample_value(value: &Value, max_items: usize, max_chars: usize) -> Value {
+    match value {
+        Value::Array(items) => {
+            let mut sampled: Vec<Value> = items
+                .iter()
+                .take(max_items)
+                .map(|v| sample_value(v, max_items, max_chars))
+                .collect();
+            if items.len() > max_items {
+                sampled.push(Value::String(format!(
+                    "... {} more items",
+                    items.len() - max_items
+                )));
+            }
+            Value::Array(sampled)
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), sample_value(v, max_items, max_chars)))
+                .collect(),
+        ),
+        Value::String(s) if s.chars().count() > max_chars => {
+            let clipped: String = s.chars().take(max_chars).collect();
+            Value::String(format!("{}...", clipped))
+        }
+        _ => value.clone(),
+    }
+}
+
+#[cfg(test)]
+#[path = "json_sampler_tests.rs"]
+mod json_sampler_tests;
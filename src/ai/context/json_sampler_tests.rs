@@ -0,0 +1,76 @@
+//! Tests for ai/context/json_sampler
+
+use super::*;
+
+#[test]
+fn test_sample_keeps_first_array_elements_and_counts_the_rest() {
+    let json = serde_json::to_string(&(0..100).collect::<Vec<_>>()).unwrap();
+
+    let sampled = sample_json(&json, 100).unwrap();
+
+    assert!(sampled.len() <= 100);
+    assert!(sampled.starts_with("[0,1,"));
+    assert!(sampled.contains("more items"));
+}
+
+#[test]
+fn test_sample_preserves_every_object_key() {
+    let json = format!(
+        r#"{{"id": 1, "bio": "{}", "tags": [{}]}}"#,
+        "x".repeat(5_000),
+        vec!["\"t\""; 500].join(",")
+    );
+
+    let sampled = sample_json(&json, 1_000).unwrap();
+    let value: Value = serde_json::from_str(&sampled).unwrap();
+
+    assert!(sampled.len() <= 1_000);
+    assert_eq!(value["id"], 1);
+    assert!(value["bio"].as_str().unwrap().ends_with("..."));
+    assert!(value["tags"].is_array());
+}
+
+#[test]
+fn test_sample_tightens_until_it_fits() {
+    let row = format!(r#"{{"name": "{}"}}"#, "n".repeat(300));
+    let json = format!("[{}]", vec![row; 50].join(","));
+
+    let sampled = sample_json(&json, 200).unwrap();
+
+    assert!(sampled.len() <= 200);
+    assert!(serde_json::from_str::<Value>(&sampled).is_ok());
+}
+
+#[test]
+fn test_sample_handles_value_streams() {
+    let json = (0..50)
+        .map(|i| format!(r#"{{"i": {}}}"#, i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let sampled = sample_json(&json, 60).unwrap();
+
+    assert!(sampled.starts_with(r#"{"i":0}"#));
+    assert!(sampled.ends_with("more results"));
+}
+
+#[test]
+fn test_sample_truncates_when_no_level_fits() {
+    let json = format!(
+        "{{{}}}",
+        (0..2_000)
+            .map(|i| format!(r#""key{}": {}"#, i, i))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    let sampled = sample_json(&json, 500).unwrap();
+
+    assert!(sampled.ends_with("... [truncated]"));
+}
+
+#[test]
+fn test_sample_rejects_invalid_json() {
+    assert_eq!(sample_json("not json", 100), None);
+    assert_eq!(sample_json("   ", 100), None);
+}
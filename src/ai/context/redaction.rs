@@ -0,0 +1,248 @@
+//! Secret redaction for AI context
+//!
+//! Runs over the query, result samples, and error text before a prompt is
+//! built, so credentials sitting in the user's data never reach a provider.
+//! Values are masked whole when their object key looks sensitive
+//! (`password`, `token`, ...), and strings are masked in place wherever they match a
+//! secret-shaped value pattern (JWTs, common API key prefixes, and any
+//! configured regexes).
+
+use regex::Regex;
+use serde_json::{Deserializer, Value};
+
+use crate::config::ai_types::RedactionConfig;
+
+/// Replacement text for masked values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Value patterns that are always checked when redaction is enabled
+const BUILTIN_VALUE_PATTERNS: &[&str] = &[
+    // JWT: base64url header (always starts `{"` -> `eyJ`), payload, signature
+    r"eyJ[A-Za-z0-9_-]{5,}\.[A-Za-z0-9_-]{5,}\.[A-Za-z0-9_-]{5,}",
+    // OpenAI / Anthropic style secret keys
+    r"sk-[A-Za-z0-9_-]{16,}",
+    // GitHub tokens
+    r"gh[pousr]_[A-Za-z0-9]{30,}",
+    r"github_pat_[A-Za-z0-9_]{30,}",
+    // AWS access key IDs
+    r"\b(?:AKIA|ASIA)[0-9A-Z]{16}\b",
+    // Slack tokens
+    r"xox[abprs]-[A-Za-z0-9-]{10,}",
+    // Google API keys
+    r"AIza[0-9A-Za-z_-]{35}",
+    // Bearer credentials inside header strings
+    r"(?i)bearer\s+[A-Za-z0-9._~+/-]{16,}=*",
+];
+
+/// `"key": value` pairs with a string, number, boolean or null value, in
+/// text that could not be parsed as JSON
+const KEY_VALUE_PAIR: &str =
+    r#""((?:[^"\\]|\\.)*)"\s*:\s*("(?:[^"\\]|\\.)*"|-?[0-9][0-9.eE+-]*|true|false|null)"#;
+
+/// Masks secrets according to a [`RedactionConfig`]
+///
+/// The default redactor is disabled and passes everything through.
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    /// Normalized key fragments (lowercase, `_`/`-` stripped)
+    keys: Vec<String>,
+    value_patterns: Vec<Regex>,
+    key_value_pair: Option<Regex>,
+}
+
+impl Redactor {
+    /// Build a redactor; invalid configured patterns are logged and skipped
+    pub fn from_config(config: &RedactionConfig) -> Self {
+        if !config.enabled {
+            return Self::default();
+        }
+
+        let keys = config
+            .keys
+            .iter()
+            .map(|k| normalize_key(k))
+            .filter(|k| !k.is_empty())
+            .collect();
+
+        let configured = config.patterns.iter().map(String::as_str);
+        let value_patterns = BUILTIN_VALUE_PATTERNS
+            .iter()
+            .copied()
+            .chain(configured)
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    log::warn!(
+                        "AI redaction: ignoring invalid pattern {:?}: {}",
+                        pattern,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            keys,
+            value_patterns,
+            key_value_pair: Regex::new(KEY_VALUE_PAIR).ok(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty() || !self.value_patterns.is_empty()
+    }
+
+    /// Redact a JSON document or newline-separated stream
+    ///
+    /// Lines that are not valid JSON on their own (sampler markers, pretty
+    /// printed or truncated documents) fall back to [`redact_text`](Self::redact_text).
+    /// Returns the text, unchanged when nothing matched, and the number of
+    /// values masked.
+    pub fn redact_json(&self, json: &str) -> (String, usize) {
+        if !self.is_enabled() {
+            return (json.to_string(), 0);
+        }
+
+        if let Some(result) = self.redact_stream(json) {
+            return result;
+        }
+
+        let mut count = 0;
+        let lines: Vec<String> = json
+            .split('\n')
+            .map(|line| {
+                let (text, n) = self
+                    .redact_stream(line)
+                    .unwrap_or_else(|| self.redact_text(line));
+                count += n;
+                text
+            })
+            .collect();
+        (lines.join("\n"), count)
+    }
+
+    /// Redact free text such as the query or an error message
+    pub fn redact_text(&self, text: &str) -> (String, usize) {
+        if !self.is_enabled() {
+            return (text.to_string(), 0);
+        }
+
+        let mut count = 0;
+        let mut out = text.to_string();
+
+        if let Some(pair) = &self.key_value_pair
+            && !self.keys.is_empty()
+        {
+            out = pair
+                .replace_all(&out, |caps: &regex::Captures| {
+                    if self.is_sensitive_key(&caps[1]) && caps[2].trim_matches('"') != REDACTED {
+                        count += 1;
+                        format!("\"{}\": \"{}\"", &caps[1], REDACTED)
+                    } else {
+                        caps[0].to_string()
+                    }
+                })
+                .into_owned();
+        }
+
+        let (out, n) = self.mask_patterns(&out);
+        (out, count + n)
+    }
+
+    /// Mask secrets in a parsed value; returns the number of values masked
+    pub fn redact_in_place(&self, value: &mut Value) -> usize {
+        if !self.is_enabled() {
+            return 0;
+        }
+        self.redact_value(value, false)
+    }
+
+    /// Redact `text` when it parses as a stream of JSON values
+    fn redact_stream(&self, text: &str) -> Option<(String, usize)> {
+        let mut values = Deserializer::from_str(text)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        if values.is_empty() {
+            return None;
+        }
+
+        let count: usize = values.iter_mut().map(|v| self.redact_value(v, false)).sum();
+        if count == 0 {
+            return Some((text.to_string(), 0));
+        }
+
+        let lines: Vec<String> = values
+            .iter()
+            .map(|v| serde_json::to_string(v).unwrap_or_default())
+            .collect();
+        Some((lines.join("\n"), count))
+    }
+
+    /// Mask values in place; `under_sensitive_key` masks every string,
+    /// number, boolean and null below
+    fn redact_value(&self, value: &mut Value, under_sensitive_key: bool) -> usize {
+        match value {
+            Value::String(s) if s == REDACTED => 0,
+            Value::String(_) | Value::Number(_) | Value::Bool(_) | Value::Null
+                if under_sensitive_key =>
+            {
+                *value = Value::String(REDACTED.to_string());
+                1
+            }
+            Value::String(s) => {
+                let (masked, n) = self.mask_patterns(s);
+                if n > 0 {
+                    *s = masked;
+                }
+                n
+            }
+            Value::Array(items) => items
+                .iter_mut()
+                .map(|v| self.redact_value(v, under_sensitive_key))
+                .sum(),
+            Value::Object(map) => map
+                .iter_mut()
+                .map(|(k, v)| self.redact_value(v, under_sensitive_key || self.is_sensitive_key(k)))
+                .sum(),
+            _ => 0,
+        }
+    }
+
+    fn mask_patterns(&self, text: &str) -> (String, usize) {
+        let mut count = 0;
+        let mut out = text.to_string();
+        for re in &self.value_patterns {
+            if !re.is_match(&out) {
+                continue;
+            }
+            out = re
+                .replace_all(&out, |_: &regex::Captures| {
+                    count += 1;
+                    REDACTED
+                })
+                .into_owned();
+        }
+        (out, count)
+    }
+
+    fn is_sensitive_key(&self, key: &str) -> bool {
+        let key = normalize_key(key);
+        self.keys
+            .iter()
+            .any(|fragment| key.contains(fragment.as_str()))
+    }
+}
+
+/// Lowercase and drop `_`/`-` so `apiKey`, `api_key`, and `API-KEY` compare equal
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "redaction_tests.rs"]
+mod redaction_tests;
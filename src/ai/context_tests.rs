@@ -220,7 +220,7 @@ fn test_prepare_json_skips_minify_just_over_limit() {
 }
 
 #[test]
-fn test_prepare_json_truncates_after_minify_if_still_over() {
+fn test_prepare_json_samples_after_minify_if_still_over() {
     let pretty_json = format!(
         r#"{{
   "large": "{}",
@@ -233,8 +233,11 @@ fn test_prepare_json_truncates_after_minify_if_still_over() {
 
     let result = prepare_json_for_context(&pretty_json, MAX_JSON_SAMPLE_LENGTH);
 
-    assert!(result.len() <= MAX_JSON_SAMPLE_LENGTH + 15);
-    assert!(result.ends_with("... [truncated]"));
+    // Sampling clips the long string but keeps the document valid and every key.
+    assert!(result.len() <= MAX_JSON_SAMPLE_LENGTH);
+    let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+    assert!(value["large"].as_str().unwrap().ends_with("..."));
+    assert_eq!(value["more"], "data");
 }
 
 #[test]
@@ -607,3 +610,122 @@ fn test_prepare_schema_for_context_exactly_at_limit() {
     assert_eq!(result, schema);
     assert!(!result.contains("truncated"));
 }
+
+// === sample_json_for_context Tests ===
+
+#[test]
+fn test_sample_json_for_context_small_json_not_sampled() {
+    let sample = sample_json_for_context(r#"{ "a": [1, 2] }"#, 1_000);
+    assert_eq!(sample.text, r#"{"a":[1,2]}"#);
+    assert!(!sample.sampled);
+}
+
+#[test]
+fn test_sample_json_for_context_large_array_is_sampled() {
+    let json = serde_json::to_string(&(0..10_000).collect::<Vec<_>>()).unwrap();
+
+    let sample = sample_json_for_context(&json, 1_000);
+
+    assert!(sample.sampled);
+    assert!(sample.text.len() <= 1_000);
+    assert!(sample.text.contains("more items"));
+}
+
+#[test]
+fn test_sample_json_for_context_invalid_json_is_truncated() {
+    let sample = sample_json_for_context(&"x".repeat(2_000), 1_000);
+    assert!(sample.sampled);
+    assert!(sample.text.ends_with("... [truncated]"));
+}
+
+#[test]
+fn test_query_context_records_when_output_was_sampled() {
+    let big = serde_json::to_string(&(0..10_000).collect::<Vec<_>>()).unwrap();
+
+    let sampled = QueryContext::new(".".to_string(), 1, Some(big), None, empty_params(), 1_000);
+    let whole = QueryContext::new(
+        ".".to_string(),
+        1,
+        Some("[1,2,3]".to_string()),
+        None,
+        empty_params(),
+        1_000,
+    );
+
+    assert!(sampled.output_sampled);
+    assert!(!whole.output_sampled);
+}
+
+#[test]
+fn test_context_usage_from_context_and_prompt() {
+    let ctx = QueryContext::new(
+        ".".to_string(),
+        1,
+        Some("[1]".to_string()),
+        None,
+        empty_params(),
+        1_000,
+    );
+
+    let usage = ContextUsage::new(&ctx, "hello");
+
+    assert_eq!(usage.prompt_bytes, 5);
+    assert!(!usage.sampled);
+}
+
+#[test]
+fn test_redact_masks_output_query_and_base_result() {
+    let params = ContextParams {
+        input_schema: Some(r#"{"password":"string"}"#),
+        base_query: Some(".password"),
+        base_query_result: Some(r#"{"token":"abc"}"#),
+        is_empty_result: false,
+    };
+    let mut ctx = QueryContext::new(
+        r#"select(.password == "hunter2") | {"secret": "x"}"#.to_string(),
+        1,
+        Some(r#"{"user":"a","password":"hunter2"}"#.to_string()),
+        None,
+        params,
+        1_000,
+    );
+    let redactor = Redactor::from_config(&crate::config::ai_types::RedactionConfig::default());
+
+    ctx.redact(&redactor);
+
+    assert_eq!(
+        ctx.output_sample.as_deref(),
+        Some(r#"{"user":"a","password":"[REDACTED]"}"#)
+    );
+    assert_eq!(
+        ctx.base_query_result.as_deref(),
+        Some(r#"{"token":"[REDACTED]"}"#)
+    );
+    assert!(ctx.query.contains(r#""secret": "[REDACTED]""#));
+    assert_eq!(
+        ctx.input_schema.as_deref(),
+        Some(r#"{"password":"string"}"#)
+    );
+    assert_eq!(ctx.redacted_count, 3);
+    assert_eq!(ContextUsage::new(&ctx, "p").redacted, 3);
+}
+
+#[test]
+fn test_redact_with_disabled_redactor_is_noop() {
+    let mut ctx = QueryContext::new(
+        ".".to_string(),
+        1,
+        Some(r#"{"password":"hunter2"}"#.to_string()),
+        None,
+        empty_params(),
+        1_000,
+    );
+
+    ctx.redact(&Redactor::default());
+
+    assert_eq!(
+        ctx.output_sample.as_deref(),
+        Some(r#"{"password":"hunter2"}"#)
+    );
+    assert_eq!(ctx.redacted_count, 0);
+}
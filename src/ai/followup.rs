@@ -0,0 +1,12 @@
+//! Follow-up questions inside the AI popup
+//!
+//! Once the popup shows an answer, Ctrl+G opens a small input line at the
+//! popup's bottom. The question is sent together with the recent exchanges
+//! ("why did that fail?", "make it also sort by date") so the AI answers in
+//! the context of what it already suggested.
+
+pub mod conversation;
+pub mod followup_events;
+pub mod followup_render;
+
+pub use conversation::Conversation;
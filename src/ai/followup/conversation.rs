@@ -0,0 +1,76 @@
+use std::collections::VecDeque;
+
+use crate::ai::suggestion::Suggestion;
+
+/// Exchanges kept for follow-ups; older ones are dropped to bound prompt size
+pub const MAX_EXCHANGES: usize = 4;
+
+/// One question and the AI's answer to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exchange {
+    pub question: String,
+    pub answer: String,
+}
+
+/// Recent AI exchanges, replayed in follow-up prompts
+///
+/// A question is recorded when its request is sent and paired with the
+/// answer once the response completes. Requests that error or are
+/// superseded never complete, so their question is simply replaced.
+#[derive(Debug, Default)]
+pub struct Conversation {
+    exchanges: VecDeque<Exchange>,
+    pending_question: Option<String>,
+}
+
+impl Conversation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the question for the request about to be sent
+    pub fn begin(&mut self, question: impl Into<String>) {
+        self.pending_question = Some(question.into());
+    }
+
+    /// Pair the pending question with the completed answer
+    pub fn complete(&mut self, suggestions: &[Suggestion], raw_response: &str) {
+        let Some(question) = self.pending_question.take() else {
+            return;
+        };
+        let answer = summarize_answer(suggestions, raw_response);
+        if answer.is_empty() {
+            return;
+        }
+
+        if self.exchanges.len() == MAX_EXCHANGES {
+            self.exchanges.pop_front();
+        }
+        self.exchanges.push_back(Exchange { question, answer });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exchanges.is_empty()
+    }
+
+    pub fn exchanges(&self) -> impl Iterator<Item = &Exchange> {
+        self.exchanges.iter()
+    }
+}
+
+/// Render suggestions as short lines; the raw JSON is noisy to replay
+fn summarize_answer(suggestions: &[Suggestion], raw_response: &str) -> String {
+    if suggestions.is_empty() {
+        return raw_response.trim().to_string();
+    }
+    suggestions
+        .iter()
+        .enumerate()
+        .map(|(i, s)| format!("{}. `{}` - {}", i + 1, s.query, s.description))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+#[path = "conversation_tests.rs"]
+mod conversation_tests;
@@ -0,0 +1,79 @@
+use super::*;
+use crate::ai::suggestion::SuggestionType;
+
+fn suggestion(query: &str, description: &str) -> Suggestion {
+    Suggestion {
+        query: query.to_string(),
+        description: description.to_string(),
+        suggestion_type: SuggestionType::Next,
+    }
+}
+
+#[test]
+fn test_complete_pairs_question_with_suggestions() {
+    let mut conversation = Conversation::new();
+    conversation.begin("get emails");
+
+    conversation.complete(&[suggestion(".[].email", "Emails")], "{}");
+
+    let exchanges: Vec<_> = conversation.exchanges().collect();
+    assert_eq!(exchanges.len(), 1);
+    assert_eq!(exchanges[0].question, "get emails");
+    assert_eq!(exchanges[0].answer, "1. `.[].email` - Emails");
+}
+
+#[test]
+fn test_complete_falls_back_to_raw_response() {
+    let mut conversation = Conversation::new();
+    conversation.begin("q");
+
+    conversation.complete(&[], "  not json  ");
+
+    assert_eq!(conversation.exchanges().next().unwrap().answer, "not json");
+}
+
+#[test]
+fn test_complete_without_pending_question_is_ignored() {
+    let mut conversation = Conversation::new();
+
+    conversation.complete(&[suggestion(".", "Identity")], "{}");
+
+    assert!(conversation.is_empty());
+}
+
+#[test]
+fn test_empty_answer_is_not_recorded() {
+    let mut conversation = Conversation::new();
+    conversation.begin("q");
+
+    conversation.complete(&[], "   ");
+
+    assert!(conversation.is_empty());
+}
+
+#[test]
+fn test_oldest_exchange_dropped_past_cap() {
+    let mut conversation = Conversation::new();
+    for i in 0..=MAX_EXCHANGES {
+        conversation.begin(format!("q{}", i));
+        conversation.complete(&[], "answer");
+    }
+
+    let questions: Vec<_> = conversation
+        .exchanges()
+        .map(|e| e.question.as_str())
+        .collect();
+    assert_eq!(questions.len(), MAX_EXCHANGES);
+    assert_eq!(questions[0], "q1");
+}
+
+#[test]
+fn test_begin_replaces_unanswered_question() {
+    let mut conversation = Conversation::new();
+    conversation.begin("stale");
+    conversation.begin("fresh");
+
+    conversation.complete(&[], "answer");
+
+    assert_eq!(conversation.exchanges().next().unwrap().question, "fresh");
+}
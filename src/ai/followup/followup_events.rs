@@ -0,0 +1,66 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_textarea::Input;
+
+use crate::ai::prompt::build_followup_prompt;
+use crate::app::App;
+
+/// Whether Ctrl+G should open the follow-up line instead of the plain prompt
+///
+/// True once the popup is showing and at least one exchange has completed,
+/// so there is something to follow up on.
+pub fn can_follow_up(app: &App) -> bool {
+    app.ai.visible && app.ai.configured && !app.ai.loading && !app.ai.conversation.is_empty()
+}
+
+/// Open the follow-up input line at the bottom of the AI popup
+pub fn open_followup(app: &mut App) {
+    if app.query.is_none() {
+        return;
+    }
+    app.ai.followup.open();
+    app.autocomplete.hide();
+}
+
+pub fn handle_followup_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.ai.followup.close(),
+        KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.ai.followup.close()
+        }
+        KeyCode::Enter => submit_followup(app),
+        _ => {
+            let input: Input = key.into();
+            app.ai.followup.textarea_mut().input(input);
+        }
+    }
+}
+
+/// Send the question with the recent exchanges and the current query context
+///
+/// The input line is cleared after sending so the next follow-up starts
+/// fresh. Like the Ctrl+G prompt, the current query's hash is recorded so
+/// the answer is not immediately replaced by an automatic request.
+fn submit_followup(app: &mut App) {
+    let question = app.ai.followup.request_text();
+    if question.is_empty() {
+        app.notification.show("Type a follow-up question first");
+        return;
+    }
+    let Some(context) = app.current_ai_context() else {
+        return;
+    };
+    let prompt = build_followup_prompt(&question, &app.ai.conversation, &context);
+
+    app.ai.followup.clear();
+    app.ai.followup.close();
+    app.ai.conversation.begin(question);
+    if !app.ai.send_context_request(&context, prompt) {
+        app.notification.show_error("AI request could not be sent");
+        return;
+    }
+    app.ai.set_last_query_hash(&context.query);
+}
+
+#[cfg(test)]
+#[path = "followup_events_tests.rs"]
+mod followup_events_tests;
@@ -0,0 +1,135 @@
+use std::sync::mpsc::Receiver;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::*;
+use crate::ai::ai_state::{AiRequest, AiResponse};
+use crate::test_utils::test_helpers::{TEST_JSON, key, test_app};
+
+fn key_ctrl(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+/// App with the AI popup open and one completed exchange
+fn app_with_answer() -> (App, Receiver<AiRequest>) {
+    let mut app = test_app(TEST_JSON);
+    app.ai.enabled = true;
+    app.ai.configured = true;
+    app.ai.visible = true;
+    let (req_tx, req_rx) = std::sync::mpsc::channel::<AiRequest>();
+    let (_resp_tx, resp_rx) = std::sync::mpsc::channel::<AiResponse>();
+    app.ai.set_channels(req_tx, resp_rx);
+
+    app.ai.conversation.begin("get all names");
+    app.ai.start_request();
+    app.ai.append_chunk(
+        r#"{"suggestions":[{"type":"next","query":".[].name","details":"All names"}]}"#,
+    );
+    app.ai.complete_request();
+    (app, req_rx)
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        handle_followup_key(app, key(KeyCode::Char(c)));
+    }
+}
+
+fn sent_prompt(rx: &Receiver<AiRequest>) -> String {
+    match rx.try_recv().expect("a request should be sent") {
+        AiRequest::Query { prompt, .. } => prompt,
+    }
+}
+
+#[test]
+fn ctrl_g_opens_followup_once_popup_has_an_answer() {
+    let (mut app, _rx) = app_with_answer();
+
+    app.handle_key_event(key_ctrl('g'));
+
+    assert!(app.ai.followup.is_visible());
+    assert!(!app.ai.nl_prompt.is_visible());
+}
+
+#[test]
+fn ctrl_g_opens_plain_prompt_without_prior_exchange() {
+    let (mut app, _rx) = app_with_answer();
+    app.ai.conversation = crate::ai::followup::Conversation::new();
+
+    app.handle_key_event(key_ctrl('g'));
+
+    assert!(app.ai.nl_prompt.is_visible());
+    assert!(!app.ai.followup.is_visible());
+}
+
+#[test]
+fn enter_sends_question_with_prior_exchange() {
+    let (mut app, rx) = app_with_answer();
+    open_followup(&mut app);
+    type_text(&mut app, "make it also sort by date");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    let prompt = sent_prompt(&rx);
+    assert!(prompt.contains("get all names"));
+    assert!(prompt.contains("`.[].name` - All names"));
+    assert!(prompt.contains("make it also sort by date"));
+    assert!(!app.ai.followup.is_visible());
+    assert_eq!(
+        app.ai.followup.request_text(),
+        "",
+        "sent question is cleared"
+    );
+}
+
+#[test]
+fn answered_followup_joins_the_conversation() {
+    let (mut app, _rx) = app_with_answer();
+    open_followup(&mut app);
+    type_text(&mut app, "why?");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.ai.append_chunk(r#"{"suggestions":[]}"#);
+    app.ai.complete_request();
+
+    let questions: Vec<_> = app
+        .ai
+        .conversation
+        .exchanges()
+        .map(|e| e.question.clone())
+        .collect();
+    assert_eq!(questions, vec!["get all names", "why?"]);
+}
+
+#[test]
+fn empty_question_is_not_sent() {
+    let (mut app, rx) = app_with_answer();
+    open_followup(&mut app);
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(rx.try_recv().is_err());
+    assert!(app.ai.followup.is_visible());
+}
+
+#[test]
+fn esc_closes_followup_and_keeps_popup() {
+    let (mut app, _rx) = app_with_answer();
+    open_followup(&mut app);
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.ai.followup.is_visible());
+    assert!(app.ai.visible);
+}
+
+#[test]
+fn followup_closes_if_popup_was_hidden() {
+    let (mut app, _rx) = app_with_answer();
+    open_followup(&mut app);
+    app.ai.visible = false;
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(!app.ai.followup.is_visible());
+}
@@ -0,0 +1,67 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders},
+};
+
+use crate::ai::nl_prompt::NlPromptState;
+use crate::theme;
+use crate::widgets::popup;
+
+const FOLLOWUP_HEIGHT: u16 = 3;
+const PLACEHOLDER: &str = "Ask a follow-up, e.g. make it also sort by date";
+
+/// Render the follow-up line over the bottom rows of the AI popup
+///
+/// The line's bottom border takes the place of the popup's, so it reads as
+/// part of the popup. Returns `None` when hidden or the popup is too short.
+pub fn render_followup(
+    frame: &mut Frame,
+    popup_area: Rect,
+    state: &mut NlPromptState,
+) -> Option<Rect> {
+    if !state.is_visible() || popup_area.height < FOLLOWUP_HEIGHT + 2 {
+        return None;
+    }
+
+    let area = Rect {
+        x: popup_area.x,
+        y: popup_area.bottom() - FOLLOWUP_HEIGHT,
+        width: popup_area.width,
+        height: FOLLOWUP_HEIGHT,
+    };
+    popup::clear_area(frame, area);
+
+    let title = Line::from(vec![
+        Span::raw(" "),
+        Span::styled("Follow up", theme::ai::title()),
+        Span::raw(" "),
+    ]);
+    let hints = theme::border_hints::build_hints(
+        &[("Enter", "Ask"), ("Esc", "Cancel")],
+        theme::ai::border(),
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(hints.alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::ai::border()))
+        .style(Style::default().bg(theme::ai::background()));
+
+    let textarea = state.textarea_mut();
+    textarea.set_block(block);
+    textarea.set_style(Style::default().fg(theme::ai::query_text()));
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_placeholder_text(PLACEHOLDER);
+    textarea.set_placeholder_style(Style::default().fg(theme::ai::suggestion_desc_muted()));
+    frame.render_widget(&*textarea, area);
+
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "followup_render_tests.rs"]
+mod followup_render_tests;
@@ -0,0 +1,53 @@
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+use super::*;
+
+fn render_to_string(state: &mut NlPromptState, popup_area: Rect) -> String {
+    let backend = TestBackend::new(60, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            render_followup(f, popup_area, state);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn popup_area() -> Rect {
+    Rect {
+        x: 0,
+        y: 1,
+        width: 60,
+        height: 8,
+    }
+}
+
+#[test]
+fn hidden_followup_renders_nothing() {
+    let mut state = NlPromptState::new();
+    let out = render_to_string(&mut state, popup_area());
+    assert!(!out.contains("Follow up"));
+}
+
+#[test]
+fn too_short_popup_renders_nothing() {
+    let mut state = NlPromptState::new();
+    state.open();
+    let area = Rect {
+        height: 4,
+        ..popup_area()
+    };
+    let out = render_to_string(&mut state, area);
+    assert!(!out.contains("Follow up"));
+}
+
+#[test]
+fn snapshot_followup_at_popup_bottom() {
+    let mut state = NlPromptState::new();
+    state.open();
+    state.textarea_mut().insert_str("make it also sort by date");
+    let out = render_to_string(&mut state, popup_area());
+    assert_snapshot!(out);
+}
@@ -0,0 +1,12 @@
+//! Natural-language prompt mode for the AI assistant
+//!
+//! Ctrl+G opens a one-line prompt where the user describes what they want
+//! ("get all emails of active users"). The request is sent to the AI with
+//! the input schema and current query, and the answers arrive as ordinary
+//! suggestions in the AI popup, selectable with the usual keys.
+
+pub mod nl_prompt_events;
+pub mod nl_prompt_render;
+pub mod nl_prompt_state;
+
+pub use nl_prompt_state::NlPromptState;
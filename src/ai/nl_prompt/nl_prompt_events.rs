@@ -0,0 +1,82 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_textarea::Input;
+
+use crate::ai::prompt::build_natural_language_prompt;
+use crate::app::{App, Focus};
+
+/// Open the natural-language prompt line (Ctrl+G)
+///
+/// Requires a configured provider and loaded input; otherwise the prompt
+/// would have nothing to send or nowhere to send it.
+pub fn open_nl_prompt(app: &mut App) {
+    if app.ai.offline {
+        app.notification
+            .show_warning(crate::ai::ai_state::OFFLINE_MESSAGE);
+        return;
+    }
+    if !app.ai.configured {
+        app.notification
+            .show_warning("AI not configured. Add provider credentials to config.");
+        return;
+    }
+    if app.query.is_none() {
+        return;
+    }
+    app.ai.nl_prompt.open();
+    app.autocomplete.hide();
+}
+
+pub fn handle_nl_prompt_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.ai.nl_prompt.close(),
+        KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.ai.nl_prompt.close()
+        }
+        KeyCode::Enter => submit_nl_prompt(app),
+        _ => {
+            let input: Input = key.into();
+            app.ai.nl_prompt.textarea_mut().input(input);
+        }
+    }
+}
+
+/// Send the typed request to the AI and show the popup for its suggestions
+///
+/// The current query's hash is recorded so re-running the unchanged query
+/// does not immediately replace the answers with generic suggestions.
+fn submit_nl_prompt(app: &mut App) {
+    let request = app.ai.nl_prompt.request_text();
+    if request.is_empty() {
+        app.notification.show("Describe what you want first");
+        return;
+    }
+    let Some(context) = app.current_ai_context() else {
+        return;
+    };
+    let prompt = build_natural_language_prompt(&request, &context);
+
+    app.ai.nl_prompt.close();
+    show_ai_popup(app);
+    app.ai.conversation.begin(request);
+    if !app.ai.send_context_request(&context, prompt) {
+        app.notification.show_error("AI request could not be sent");
+        return;
+    }
+    app.ai.set_last_query_hash(&context.query);
+}
+
+/// Make the AI popup visible the same way Ctrl+A does, hiding the tooltip
+fn show_ai_popup(app: &mut App) {
+    if app.focus == Focus::ResultsPane {
+        app.focus_input_field();
+    }
+    if !app.ai.visible {
+        app.saved_tooltip_visibility = app.tooltip.enabled;
+        app.tooltip.enabled = false;
+        app.ai.visible = true;
+    }
+}
+
+#[cfg(test)]
+#[path = "nl_prompt_events_tests.rs"]
+mod nl_prompt_events_tests;
@@ -0,0 +1,141 @@
+use std::sync::mpsc::Receiver;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::*;
+use crate::ai::ai_state::{AiRequest, AiResponse};
+use crate::test_utils::test_helpers::{TEST_JSON, key, test_app};
+
+fn key_ctrl(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+fn configured_app() -> (App, Receiver<AiRequest>) {
+    let mut app = test_app(TEST_JSON);
+    app.ai.enabled = true;
+    app.ai.configured = true;
+    let (req_tx, req_rx) = std::sync::mpsc::channel::<AiRequest>();
+    let (_resp_tx, resp_rx) = std::sync::mpsc::channel::<AiResponse>();
+    app.ai.set_channels(req_tx, resp_rx);
+    (app, req_rx)
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        handle_nl_prompt_key(app, key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn ctrl_g_opens_prompt_when_configured() {
+    let (mut app, _rx) = configured_app();
+
+    app.handle_key_event(key_ctrl('g'));
+
+    assert!(app.ai.nl_prompt.is_visible());
+}
+
+#[test]
+fn ctrl_g_without_provider_warns_and_stays_closed() {
+    let mut app = test_app(TEST_JSON);
+
+    app.handle_key_event(key_ctrl('g'));
+
+    assert!(!app.ai.nl_prompt.is_visible());
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|m| m.contains("not configured"))
+    );
+}
+
+#[test]
+fn typing_goes_to_prompt_not_query() {
+    let (mut app, _rx) = configured_app();
+    open_nl_prompt(&mut app);
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert_eq!(app.ai.nl_prompt.request_text(), "x");
+    assert_eq!(app.query(), "");
+}
+
+#[test]
+fn esc_closes_prompt_without_sending() {
+    let (mut app, rx) = configured_app();
+    open_nl_prompt(&mut app);
+    type_text(&mut app, "names");
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.ai.nl_prompt.is_visible());
+    assert!(rx.try_recv().is_err(), "Esc must not send a request");
+}
+
+#[test]
+fn ctrl_g_again_closes_prompt() {
+    let (mut app, _rx) = configured_app();
+    open_nl_prompt(&mut app);
+
+    app.handle_key_event(key_ctrl('g'));
+
+    assert!(!app.ai.nl_prompt.is_visible());
+}
+
+#[test]
+fn enter_sends_request_and_shows_ai_popup() {
+    let (mut app, rx) = configured_app();
+    app.ai.visible = false;
+    open_nl_prompt(&mut app);
+    type_text(&mut app, "get all service names");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.ai.nl_prompt.is_visible());
+    assert!(app.ai.visible);
+    assert!(app.ai.loading);
+    assert!(!app.should_quit, "Enter in the prompt must not exit jiq");
+    let AiRequest::Query { prompt, .. } = rx.try_recv().expect("request should be sent");
+    assert!(prompt.contains("get all service names"));
+    assert!(prompt.contains("## Input JSON Schema"));
+}
+
+#[test]
+fn enter_records_query_hash_so_unchanged_query_keeps_answers() {
+    let (mut app, _rx) = configured_app();
+    open_nl_prompt(&mut app);
+    type_text(&mut app, "count items");
+
+    handle_nl_prompt_key(&mut app, key(KeyCode::Enter));
+
+    assert!(!app.ai.is_query_changed(app.query()));
+}
+
+#[test]
+fn enter_with_blank_request_keeps_prompt_open() {
+    let (mut app, rx) = configured_app();
+    open_nl_prompt(&mut app);
+    type_text(&mut app, "   ");
+
+    handle_nl_prompt_key(&mut app, key(KeyCode::Enter));
+
+    assert!(app.ai.nl_prompt.is_visible());
+    assert!(rx.try_recv().is_err());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Describe what you want first")
+    );
+}
+
+#[test]
+fn submit_from_results_pane_returns_focus_to_input() {
+    let (mut app, _rx) = configured_app();
+    app.focus_results_pane();
+    open_nl_prompt(&mut app);
+    type_text(&mut app, "names");
+
+    handle_nl_prompt_key(&mut app, key(KeyCode::Enter));
+
+    assert_eq!(app.focus, Focus::InputField);
+    assert!(app.ai.visible);
+}
@@ -0,0 +1,67 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders},
+};
+
+use super::nl_prompt_state::NlPromptState;
+use crate::theme;
+use crate::widgets::popup;
+
+const PROMPT_HEIGHT: u16 = 3;
+const PLACEHOLDER: &str = "Describe what you want, e.g. get all emails of active users";
+
+/// Render the prompt line directly above the query input
+///
+/// Returns the prompt area, or `None` when hidden or there is no room above
+/// the input bar.
+pub fn render_nl_prompt(
+    frame: &mut Frame,
+    input_area: Rect,
+    state: &mut NlPromptState,
+) -> Option<Rect> {
+    if !state.is_visible() || input_area.y < PROMPT_HEIGHT {
+        return None;
+    }
+
+    let area = Rect {
+        x: input_area.x,
+        y: input_area.y - PROMPT_HEIGHT,
+        width: input_area.width,
+        height: PROMPT_HEIGHT,
+    };
+    popup::clear_area(frame, area);
+
+    let title = Line::from(vec![
+        Span::raw(" "),
+        Span::styled("Ask AI", theme::ai::title()),
+        Span::raw(" "),
+    ]);
+    let hints = theme::border_hints::build_hints(
+        &[("Enter", "Ask"), ("Esc", "Cancel")],
+        theme::ai::border(),
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(hints.alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::ai::border()))
+        .style(Style::default().bg(theme::ai::background()));
+
+    let textarea = state.textarea_mut();
+    textarea.set_block(block);
+    textarea.set_style(Style::default().fg(theme::ai::query_text()));
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_placeholder_text(PLACEHOLDER);
+    textarea.set_placeholder_style(Style::default().fg(theme::ai::suggestion_desc_muted()));
+    frame.render_widget(&*textarea, area);
+
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "nl_prompt_render_tests.rs"]
+mod nl_prompt_render_tests;
@@ -0,0 +1,63 @@
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+use super::*;
+
+fn render_to_string(state: &mut NlPromptState, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let input_area = Rect {
+        x: 0,
+        y: height - 3,
+        width,
+        height: 3,
+    };
+    terminal
+        .draw(|f| {
+            render_nl_prompt(f, input_area, state);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn hidden_prompt_renders_nothing() {
+    let mut state = NlPromptState::new();
+    let out = render_to_string(&mut state, 60, 8);
+    assert!(!out.contains("Ask AI"));
+}
+
+#[test]
+fn snapshot_prompt_with_placeholder() {
+    let mut state = NlPromptState::new();
+    state.open();
+    let out = render_to_string(&mut state, 80, 8);
+    assert_snapshot!(out);
+}
+
+#[test]
+fn snapshot_prompt_with_request() {
+    let mut state = NlPromptState::new();
+    state.open();
+    state
+        .textarea_mut()
+        .insert_str("get all emails of active users");
+    let out = render_to_string(&mut state, 80, 8);
+    assert_snapshot!(out);
+}
+
+#[test]
+fn prompt_without_room_above_input_is_skipped() {
+    let backend = TestBackend::new(40, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = NlPromptState::new();
+    state.open();
+    let mut area = None;
+    terminal
+        .draw(|f| {
+            area = render_nl_prompt(f, Rect::new(0, 0, 40, 3), &mut state);
+        })
+        .unwrap();
+    assert!(area.is_none());
+}
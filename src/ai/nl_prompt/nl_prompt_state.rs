@@ -0,0 +1,55 @@
+use tui_textarea::TextArea;
+
+/// State of the Ctrl+G natural-language prompt line
+///
+/// The typed request survives closing the prompt so the user can reopen it
+/// and refine the wording instead of retyping it.
+pub struct NlPromptState {
+    visible: bool,
+    textarea: TextArea<'static>,
+}
+
+impl Default for NlPromptState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NlPromptState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            textarea: TextArea::default(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Drop the typed text, e.g. once a follow-up question has been sent
+    pub fn clear(&mut self) {
+        self.textarea = TextArea::default();
+    }
+
+    pub fn textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.textarea
+    }
+
+    /// The typed request with surrounding whitespace removed
+    pub fn request_text(&self) -> String {
+        self.textarea.lines().join(" ").trim().to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "nl_prompt_state_tests.rs"]
+mod nl_prompt_state_tests;
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn new_prompt_is_hidden_and_empty() {
+    let state = NlPromptState::new();
+
+    assert!(!state.is_visible());
+    assert_eq!(state.request_text(), "");
+}
+
+#[test]
+fn open_and_close_toggle_visibility() {
+    let mut state = NlPromptState::new();
+
+    state.open();
+    assert!(state.is_visible());
+
+    state.close();
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn request_text_is_trimmed() {
+    let mut state = NlPromptState::new();
+    state.textarea_mut().insert_str("  get all emails  ");
+
+    assert_eq!(state.request_text(), "get all emails");
+}
+
+#[test]
+fn request_text_survives_close_and_reopen() {
+    let mut state = NlPromptState::new();
+    state.open();
+    state.textarea_mut().insert_str("sort by date");
+    state.close();
+
+    state.open();
+
+    assert_eq!(state.request_text(), "sort by date");
+}
+
+#[test]
+fn clear_drops_typed_text() {
+    let mut state = NlPromptState::new();
+    state.textarea_mut().insert_str("names");
+
+    state.clear();
+
+    assert_eq!(state.request_text(), "");
+}
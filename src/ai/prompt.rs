@@ -4,6 +4,7 @@
 //! Generates different prompts for error troubleshooting vs success optimization.
 
 use super::context::QueryContext;
+use super::followup::Conversation;
 
 /// Shared guidance for non-ASCII field names.
 ///
@@ -212,6 +213,103 @@ pub fn build_success_prompt(context: &QueryContext) -> String {
     prompt
 }
 
+/// Build a prompt that turns a plain-English request into jq queries
+///
+/// The request comes from the Ctrl+G prompt line rather than the query
+/// input, so the current query is offered only as a starting point the
+/// model may build on or ignore.
+pub fn build_natural_language_prompt(request: &str, context: &QueryContext) -> String {
+    let mut prompt = String::new();
+
+    prompt.push_str(
+        "You are a jq query assistant translating natural-language requests into jq queries.\n",
+    );
+
+    prompt.push_str("## Request\n");
+    prompt.push_str(&format!("```\n{}\n```\n\n", request));
+
+    push_current_state(&mut prompt, context);
+
+    prompt.push_str(&build_output_format_rules("next"));
+    prompt.push_str(NON_ASCII_KEY_RULES);
+
+    prompt.push_str("## Answering the Request\n");
+    prompt.push_str(
+        "- Every `query` must be a complete jq program that runs against the input JSON as-is.\n",
+    );
+    prompt.push_str("- Use type `\"next\"` for every suggestion.\n");
+    prompt.push_str("- Put the most direct answer to the request first.\n");
+    prompt.push_str(
+        "- Later suggestions may vary the output shape (array vs stream, objects vs values).\n\n",
+    );
+
+    prompt
+}
+
+/// Build a prompt for a follow-up question typed in the AI popup
+///
+/// Replays the recent exchanges so the model can resolve references like
+/// "that" or "it also", followed by the current query state.
+pub fn build_followup_prompt(
+    question: &str,
+    conversation: &Conversation,
+    context: &QueryContext,
+) -> String {
+    let mut prompt = String::new();
+
+    prompt.push_str("You are a jq query assistant continuing a conversation with the user.\n");
+
+    prompt.push_str("## Conversation So Far\n");
+    for exchange in conversation.exchanges() {
+        prompt.push_str(&format!("### User\n```\n{}\n```\n", exchange.question));
+        prompt.push_str(&format!("### Assistant\n```\n{}\n```\n", exchange.answer));
+    }
+    prompt.push('\n');
+
+    prompt.push_str("## Follow-up Question\n");
+    prompt.push_str(&format!("```\n{}\n```\n\n", question));
+
+    push_current_state(&mut prompt, context);
+
+    prompt.push_str(&build_output_format_rules("next"));
+    prompt.push_str(NON_ASCII_KEY_RULES);
+
+    prompt.push_str("## Answering the Follow-up\n");
+    prompt.push_str(
+        "- Every `query` must be a complete jq program that runs against the input JSON as-is.\n",
+    );
+    prompt.push_str(
+        "- Resolve references like \"that\" or \"it\" against the conversation and the current query.\n",
+    );
+    prompt.push_str("- Use `\"fix\"` for corrections and `\"next\"` for extensions.\n");
+    prompt.push_str(
+        "- When the question asks why, answer in `details` on the suggestion that fixes it.\n\n",
+    );
+
+    prompt
+}
+
+/// Input schema plus the current query with its output or error
+fn push_current_state(prompt: &mut String, context: &QueryContext) {
+    if let Some(ref schema) = context.input_schema {
+        prompt.push_str("## Input JSON Schema\n");
+        prompt.push_str(&format!("```json\n{}\n```\n\n", schema));
+    }
+
+    if !context.query.trim().is_empty() {
+        prompt.push_str("## Current Query (build on it only if it helps)\n");
+        prompt.push_str(&format!("```\n{}\n```\n\n", context.query));
+
+        if let Some(ref output_sample) = context.output_sample {
+            prompt.push_str("## Current Query Output\n");
+            prompt.push_str(&format!("```json\n{}\n```\n\n", output_sample));
+        } else if let Some(ref error) = context.error {
+            prompt.push_str("## Current Query Error\n");
+            prompt.push_str(&format!("```\n{}\n```\n\n", error));
+        }
+    }
+}
+
 #[cfg(test)]
 #[path = "prompt_tests.rs"]
 mod prompt_tests;
@@ -15,6 +15,8 @@ fn test_build_error_prompt_includes_query() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_error_prompt(&ctx);
@@ -35,6 +37,8 @@ fn test_build_error_prompt_includes_schema() {
         input_schema: Some(r#"{"name":"string","age":"number"}"#.to_string()),
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_error_prompt(&ctx);
@@ -54,6 +58,8 @@ fn test_build_success_prompt_includes_query() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_success_prompt(&ctx);
@@ -73,6 +79,8 @@ fn test_build_success_prompt_includes_output_sample() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_success_prompt(&ctx);
@@ -92,6 +100,8 @@ fn test_build_success_prompt_includes_schema() {
         input_schema: Some(r#"["number"]"#.to_string()),
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_success_prompt(&ctx);
@@ -111,6 +121,8 @@ fn test_build_prompt_dispatches_to_error_prompt() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_prompt(&ctx);
@@ -130,6 +142,8 @@ fn test_build_prompt_dispatches_to_success_prompt() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_prompt(&ctx);
@@ -149,6 +163,8 @@ fn test_build_error_prompt_includes_structured_format() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_error_prompt(&ctx);
@@ -171,6 +187,8 @@ fn test_build_success_prompt_includes_structured_format() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_success_prompt(&ctx);
@@ -193,6 +211,8 @@ fn test_build_prompt_includes_natural_language_instructions() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_error_prompt(&ctx);
@@ -212,6 +232,8 @@ fn test_error_prompt_includes_base_query() {
         input_schema: None,
         base_query: Some(".name".to_string()),
         base_query_result: Some(r#""test""#.to_string()),
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_error_prompt(&ctx);
@@ -233,6 +255,8 @@ fn test_error_prompt_without_base_query() {
         input_schema: None,
         base_query: None,
         base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_error_prompt(&ctx);
@@ -251,6 +275,8 @@ fn test_success_prompt_excludes_base_query() {
         input_schema: None,
         base_query: Some(".old".to_string()),
         base_query_result: Some("old result".to_string()),
+        output_sampled: false,
+        redacted_count: 0,
     };
 
     let prompt = build_success_prompt(&ctx);
@@ -286,3 +312,100 @@ fn test_base_query_result_truncation_in_context() {
     assert!(truncated.len() <= MAX_JSON_SAMPLE_LENGTH + 15);
     assert!(truncated.ends_with("... [truncated]"));
 }
+
+#[test]
+fn test_build_natural_language_prompt_includes_request_and_schema() {
+    let ctx = QueryContext {
+        query: ".users".to_string(),
+        cursor_pos: 6,
+        output_sample: Some(r#"[{"email":"a@b.c"}]"#.to_string()),
+        error: None,
+        is_success: true,
+        is_empty_result: false,
+        input_schema: Some(r#"{"users":[{"email":"string"}]}"#.to_string()),
+        base_query: None,
+        base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
+    };
+
+    let prompt = build_natural_language_prompt("get all emails of active users", &ctx);
+    assert!(prompt.contains("## Request"));
+    assert!(prompt.contains("get all emails of active users"));
+    assert!(prompt.contains(r#"{"users":[{"email":"string"}]}"#));
+    assert!(prompt.contains("## Current Query"));
+    assert!(prompt.contains("## Current Query Output"));
+    assert!(prompt.contains("\"type\": \"next\""));
+}
+
+#[test]
+fn test_build_natural_language_prompt_omits_empty_current_query() {
+    let ctx = QueryContext {
+        query: String::new(),
+        cursor_pos: 0,
+        output_sample: None,
+        error: None,
+        is_success: true,
+        is_empty_result: false,
+        input_schema: None,
+        base_query: None,
+        base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
+    };
+
+    let prompt = build_natural_language_prompt("count the items", &ctx);
+    assert!(prompt.contains("count the items"));
+    assert!(!prompt.contains("## Current Query"));
+}
+
+#[test]
+fn test_build_natural_language_prompt_includes_error_when_query_failed() {
+    let ctx = QueryContext {
+        query: ".foo |".to_string(),
+        cursor_pos: 6,
+        output_sample: None,
+        error: Some("syntax error".to_string()),
+        is_success: false,
+        is_empty_result: false,
+        input_schema: None,
+        base_query: None,
+        base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
+    };
+
+    let prompt = build_natural_language_prompt("fix it", &ctx);
+    assert!(prompt.contains("## Current Query Error"));
+    assert!(prompt.contains("syntax error"));
+}
+
+#[test]
+fn test_build_followup_prompt_replays_conversation_then_question() {
+    let ctx = QueryContext {
+        query: ".items[]".to_string(),
+        cursor_pos: 8,
+        output_sample: Some("{\"date\":\"2024-01-01\"}".to_string()),
+        error: None,
+        is_success: true,
+        is_empty_result: false,
+        input_schema: None,
+        base_query: None,
+        base_query_result: None,
+        output_sampled: false,
+        redacted_count: 0,
+    };
+    let mut conversation = Conversation::new();
+    conversation.begin("list the items");
+    conversation.complete(&[], "1. `.items[]` - Each item");
+
+    let prompt = build_followup_prompt("make it also sort by date", &conversation, &ctx);
+
+    let history = prompt.find("## Conversation So Far").unwrap();
+    let question = prompt.find("## Follow-up Question").unwrap();
+    assert!(history < question);
+    assert!(prompt.contains("list the items"));
+    assert!(prompt.contains("1. `.items[]` - Each item"));
+    assert!(prompt.contains("make it also sort by date"));
+    assert!(prompt.contains("## Current Query Output"));
+}
@@ -24,7 +24,7 @@ pub(crate) use crate::config::ai_types::{
 #[cfg(test)]
 mod round2_coverage_tests {
     use super::*;
-    use crate::config::ai_types::TEST_MAX_CONTEXT_LENGTH;
+    use crate::config::ai_types::{RedactionConfig, TEST_MAX_CONTEXT_LENGTH};
     use std::sync::mpsc;
 
     /// Build a config with `enabled: false` for the given provider, populating that
@@ -38,6 +38,7 @@ mod round2_coverage_tests {
             openai: OpenAiConfig::default(),
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
         match provider {
             AiProviderType::Anthropic => {
@@ -138,6 +139,7 @@ mod round2_coverage_tests {
             openai: OpenAiConfig::default(),
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
 
         let result = AsyncAiProvider::from_config(&config);
@@ -171,6 +173,7 @@ mod round2_coverage_tests {
             openai: OpenAiConfig::default(),
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
 
         let result = AsyncAiProvider::from_config(&config);
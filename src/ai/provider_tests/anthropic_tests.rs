@@ -1,7 +1,7 @@
 //! Tests for Anthropic provider configuration validation
 
 use super::*;
-use crate::config::ai_types::TEST_MAX_CONTEXT_LENGTH;
+use crate::config::ai_types::{RedactionConfig, TEST_MAX_CONTEXT_LENGTH};
 
 #[test]
 fn test_async_from_config_missing_api_key() {
@@ -17,6 +17,7 @@ fn test_async_from_config_missing_api_key() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -38,6 +39,7 @@ fn test_async_from_config_empty_api_key() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -59,6 +61,7 @@ fn test_async_from_config_whitespace_api_key() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -80,6 +83,7 @@ fn test_async_from_config_valid_api_key() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -100,6 +104,7 @@ fn test_async_from_config_disabled() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -1,7 +1,7 @@
 //! Tests for Bedrock provider configuration validation and error handling
 
 use super::*;
-use crate::config::ai_types::TEST_MAX_CONTEXT_LENGTH;
+use crate::config::ai_types::{RedactionConfig, TEST_MAX_CONTEXT_LENGTH};
 
 #[test]
 fn test_bedrock_missing_model_produces_error() {
@@ -17,6 +17,7 @@ fn test_bedrock_missing_model_produces_error() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -44,6 +45,7 @@ fn test_bedrock_empty_model_produces_error() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -71,6 +73,7 @@ fn test_bedrock_whitespace_model_produces_error() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -98,6 +101,7 @@ fn test_bedrock_missing_region_produces_error() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -125,6 +129,7 @@ fn test_bedrock_empty_region_produces_error() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -152,6 +157,7 @@ fn test_bedrock_valid_config_creates_provider() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -178,6 +184,7 @@ fn test_bedrock_valid_config_with_profile_creates_provider() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -1,7 +1,7 @@
 //! Cross-cutting error handling tests for AI provider abstraction
 
 use super::*;
-use crate::config::ai_types::TEST_MAX_CONTEXT_LENGTH;
+use crate::config::ai_types::{RedactionConfig, TEST_MAX_CONTEXT_LENGTH};
 
 #[test]
 fn test_from_config_returns_error_when_provider_is_none() {
@@ -17,6 +17,7 @@ fn test_from_config_returns_error_when_provider_is_none() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -66,6 +67,7 @@ fn test_from_config_error_when_provider_none_even_with_all_credentials() {
             model: Some("gemini-pro".to_string()),
         },
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -172,6 +174,7 @@ fn test_provider_name_returns_correct_identifier() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let provider = AsyncAiProvider::from_config(&config).unwrap();
@@ -192,6 +195,7 @@ fn test_config_error_includes_correct_provider_for_missing_api_key() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -218,6 +222,7 @@ fn test_config_error_includes_correct_provider_for_disabled() {
         openai: OpenAiConfig::default(),
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
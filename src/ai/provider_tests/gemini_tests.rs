@@ -1,7 +1,7 @@
 //! Tests for Gemini provider configuration validation
 
 use super::*;
-use crate::config::ai_types::TEST_MAX_CONTEXT_LENGTH;
+use crate::config::ai_types::{RedactionConfig, TEST_MAX_CONTEXT_LENGTH};
 
 // =========================================================================
 // Unit Tests for Gemini Provider from_config Validation
@@ -20,6 +20,7 @@ fn test_gemini_from_config_missing_api_key() {
             model: Some("gemini-2.0-flash".to_string()),
         },
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -45,6 +46,7 @@ fn test_gemini_from_config_missing_model() {
             model: None,
         },
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -70,6 +72,7 @@ fn test_gemini_from_config_valid_creates_client() {
             model: Some("gemini-2.0-flash".to_string()),
         },
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let result = AsyncAiProvider::from_config(&config);
@@ -94,6 +97,7 @@ fn test_gemini_provider_name() {
             model: Some("gemini-2.0-flash".to_string()),
         },
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let provider = AsyncAiProvider::from_config(&config).unwrap();
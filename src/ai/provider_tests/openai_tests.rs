@@ -1,7 +1,7 @@
 //! Tests for OpenAI provider configuration validation
 
 use super::*;
-use crate::config::ai_types::TEST_MAX_CONTEXT_LENGTH;
+use crate::config::ai_types::{RedactionConfig, TEST_MAX_CONTEXT_LENGTH};
 
 // =========================================================================
 // Snapshot Tests for OpenAI Error Messages
@@ -28,6 +28,7 @@ mod openai_error_snapshots {
             },
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
 
         let result = AsyncAiProvider::from_config(&config);
@@ -50,6 +51,7 @@ mod openai_error_snapshots {
             },
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
 
         let result = AsyncAiProvider::from_config(&config);
@@ -72,6 +74,7 @@ mod openai_error_snapshots {
             },
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
 
         let result = AsyncAiProvider::from_config(&config);
@@ -94,6 +97,7 @@ mod openai_error_snapshots {
             },
             gemini: GeminiConfig::default(),
             max_context_length: TEST_MAX_CONTEXT_LENGTH,
+            redaction: RedactionConfig::default(),
         };
 
         let result = AsyncAiProvider::from_config(&config);
@@ -181,6 +185,7 @@ fn test_openai_provider_name_default() {
         },
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let provider = AsyncAiProvider::from_config(&config).unwrap();
@@ -201,6 +206,7 @@ fn test_openai_provider_name_explicit_openai_url() {
         },
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let provider = AsyncAiProvider::from_config(&config).unwrap();
@@ -221,6 +227,7 @@ fn test_openai_provider_name_custom_endpoint() {
         },
         gemini: GeminiConfig::default(),
         max_context_length: TEST_MAX_CONTEXT_LENGTH,
+        redaction: RedactionConfig::default(),
     };
 
     let provider = AsyncAiProvider::from_config(&config).unwrap();
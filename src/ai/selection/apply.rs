@@ -1,7 +1,8 @@
 //! Query application logic for AI suggestions
 //!
-//! Handles replacing the query input with a selected suggestion,
-//! positioning the cursor, hiding autocomplete, and triggering execution.
+//! Handles replacing (or appending to) the query input with a selected
+//! suggestion, positioning the cursor, hiding autocomplete, and triggering
+//! execution.
 
 use crate::ai::suggestion::Suggestion;
 use crate::autocomplete::AutocompleteState;
@@ -48,8 +49,45 @@ pub fn apply_suggestion(
     query_state: &mut QueryState,
     autocomplete_state: &mut AutocompleteState,
 ) -> ApplyResult {
-    let query = suggestion.query.clone();
+    set_query(
+        suggestion.query.clone(),
+        input_state,
+        query_state,
+        autocomplete_state,
+    )
+}
+
+/// Append a selected AI suggestion to the current query as a new pipe stage
+///
+/// Suggestions that already extend the current query (same prefix) or an
+/// empty input are applied as-is, so accepting a full-query suggestion never
+/// duplicates what the user typed. Otherwise the suggestion is joined with
+/// `" | "`. Execution and cursor handling match [`apply_suggestion`].
+pub fn append_suggestion(
+    suggestion: &Suggestion,
+    input_state: &mut InputState,
+    query_state: &mut QueryState,
+    autocomplete_state: &mut AutocompleteState,
+) -> ApplyResult {
+    let query = appended_query(input_state.query(), &suggestion.query);
+    set_query(query, input_state, query_state, autocomplete_state)
+}
+
+fn appended_query(current: &str, suggestion: &str) -> String {
+    let current = current.trim_end();
+    if current.is_empty() || suggestion.starts_with(current) {
+        suggestion.to_string()
+    } else {
+        format!("{} | {}", current, suggestion.trim_start())
+    }
+}
 
+fn set_query(
+    query: String,
+    input_state: &mut InputState,
+    query_state: &mut QueryState,
+    autocomplete_state: &mut AutocompleteState,
+) -> ApplyResult {
     // Step 1: Clear existing query input completely (Requirement 3.1)
     // Select all text and delete it
     input_state
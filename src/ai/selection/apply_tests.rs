@@ -28,6 +28,57 @@ fn create_test_states() -> (InputState, QueryState, AutocompleteState) {
 // Unit Tests
 // =========================================================================
 
+#[test]
+fn test_append_suggestion_adds_pipe_stage() {
+    let (mut input_state, mut query_state, mut autocomplete_state) = create_test_states();
+    input_state.textarea.insert_str(".name ");
+
+    let suggestion = make_suggestion("ascii_upcase");
+    let result = append_suggestion(
+        &suggestion,
+        &mut input_state,
+        &mut query_state,
+        &mut autocomplete_state,
+    );
+
+    assert_eq!(result.query, ".name | ascii_upcase");
+    assert_eq!(input_state.query(), ".name | ascii_upcase");
+    assert!(query_state.result.is_ok());
+}
+
+#[test]
+fn test_append_suggestion_uses_suggestion_when_it_extends_query() {
+    let (mut input_state, mut query_state, mut autocomplete_state) = create_test_states();
+    input_state.textarea.insert_str(".name");
+
+    let suggestion = make_suggestion(".name | length");
+    let result = append_suggestion(
+        &suggestion,
+        &mut input_state,
+        &mut query_state,
+        &mut autocomplete_state,
+    );
+
+    assert_eq!(result.query, ".name | length");
+}
+
+#[test]
+fn test_append_suggestion_on_empty_query_is_plain_apply() {
+    let (mut input_state, mut query_state, mut autocomplete_state) = create_test_states();
+
+    let suggestion = make_suggestion(".value");
+    let result = append_suggestion(
+        &suggestion,
+        &mut input_state,
+        &mut query_state,
+        &mut autocomplete_state,
+    );
+
+    assert_eq!(input_state.query(), ".value");
+    assert_eq!(input_state.textarea.cursor().1, ".value".chars().count());
+    assert!(result.applied);
+}
+
 #[test]
 fn test_apply_suggestion_clears_existing_query() {
     let (mut input_state, mut query_state, mut autocomplete_state) = create_test_states();
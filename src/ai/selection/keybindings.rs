@@ -1,7 +1,7 @@
 //! Keybinding handlers for AI suggestion selection
 //!
 //! Handles Alt+1-5 for direct selection, Alt+Up/Down/j/k for navigation,
-//! and Enter for applying navigated selection.
+//! Enter for applying navigated selection, and Tab for appending it.
 
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
@@ -131,6 +131,26 @@ pub fn handle_apply_selection(key: KeyEvent, selection_state: &SelectionState) -
     selection_state.get_selected()
 }
 
+/// Handle Tab key for appending navigated selection to the current query
+///
+/// Like [`handle_apply_selection`], only active once the user has navigated
+/// with Alt+Up/Down/j/k, so plain Tab keeps driving autocomplete.
+///
+/// # Returns
+/// * `Some(index)` - The 0-based index of the selected suggestion if navigation is active
+/// * `None` - If plain Tab was not pressed or no suggestion is selected via navigation
+pub fn handle_append_selection(key: KeyEvent, selection_state: &SelectionState) -> Option<usize> {
+    if key.code != KeyCode::Tab || !key.modifiers.is_empty() {
+        return None;
+    }
+
+    if !selection_state.is_navigation_active() {
+        return None;
+    }
+
+    selection_state.get_selected()
+}
+
 #[cfg(test)]
 #[path = "keybindings_tests.rs"]
 mod keybindings_tests;
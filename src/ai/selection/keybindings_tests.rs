@@ -187,6 +187,45 @@ fn test_other_key_not_handled_for_apply() {
     assert_eq!(result, None);
 }
 
+// =========================================================================
+// Unit Tests for handle_append_selection
+// =========================================================================
+
+#[test]
+fn test_tab_appends_when_navigation_active() {
+    let mut state = SelectionState::new();
+    state.navigate_next(5);
+    state.navigate_next(5);
+
+    let result = handle_append_selection(key(KeyCode::Tab), &state);
+    assert_eq!(result, Some(1));
+}
+
+#[test]
+fn test_tab_not_handled_when_no_navigation() {
+    let state = SelectionState::new();
+    let result = handle_append_selection(key(KeyCode::Tab), &state);
+    assert_eq!(result, None);
+}
+
+#[test]
+fn test_modified_tab_not_handled_for_append() {
+    let mut state = SelectionState::new();
+    state.navigate_next(5);
+
+    let result = handle_append_selection(key_with_mods(KeyCode::Tab, KeyModifiers::SHIFT), &state);
+    assert_eq!(result, None);
+}
+
+#[test]
+fn test_enter_not_handled_for_append() {
+    let mut state = SelectionState::new();
+    state.navigate_next(5);
+
+    let result = handle_append_selection(key(KeyCode::Enter), &state);
+    assert_eq!(result, None);
+}
+
 // =========================================================================
 // Property-Based Tests
 // =========================================================================
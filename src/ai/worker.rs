@@ -15,6 +15,7 @@ use tokio_util::sync::CancellationToken;
 use super::ai_state::{AiRequest, AiResponse};
 use super::provider::{AiError, AsyncAiProvider};
 use crate::config::ai_types::AiConfig;
+use crate::debug_log::LogSpan;
 
 /// Spawn the AI worker thread
 ///
@@ -155,6 +156,8 @@ async fn handle_query_async(
     cancel_token: CancellationToken,
     response_tx: &Sender<AiResponse>,
 ) {
+    let _span = LogSpan::enter("ai request", format_args!("{}", request_id));
+
     // Check if already cancelled before starting
     if cancel_token.is_cancelled() {
         log::debug!("AI request {} already cancelled", request_id);
@@ -1,16 +1,32 @@
 mod app_events;
 mod app_render;
 mod app_state;
+mod breadcrumbs_render;
 mod double_click;
+mod exit_meta;
+mod input_edit;
+mod input_files_render;
+mod large_input_render;
+mod malformed;
 mod mouse_click;
 mod mouse_events;
 mod mouse_hover;
 mod mouse_scroll;
+mod output_limit_render;
+mod paste;
 mod paste_recovery_render;
+mod reroot;
+mod scrollbar_drag;
 mod source_picker_render;
+mod split;
+mod tabs;
+mod tabs_render;
+mod too_small_render;
+mod workspace;
 
 #[cfg(test)]
 mod app_render_tests;
 
 // Re-export public types
 pub use app_state::{App, Focus, OutputMode};
+pub use input_edit::run_editor;
@@ -2,10 +2,11 @@ use ratatui::crossterm::event::{
     self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent,
 };
 use std::io;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use super::app_state::{App, Focus};
 use crate::clipboard;
+use crate::debug_log::LogSpan;
 use crate::editor;
 use crate::editor::EditorMode;
 use crate::help::HelpTab;
@@ -14,33 +15,40 @@ use crate::results;
 use crate::snippets;
 
 mod global;
+pub mod input_files;
 pub mod paste_recovery;
 pub mod source_picker;
+pub mod tabs;
 
 /// Determine the default help tab based on current app context
 ///
 /// Context-aware auto-selection:
-/// - Input box focus -> Input tab
+/// - Snippet manager open -> Snippet tab
+/// - History popup shown -> History tab
+/// - Search box visible -> Search tab
+/// - AI popup open -> AI tab
 /// - Results box focus -> Result tab
-/// - Search box focus -> Search tab
-/// - Snippet manager focus -> Snippet tab
+/// - Input box focus -> Input tab
 /// - Otherwise -> Global tab
-///
-/// Note: History and AI tabs never auto-focus - users navigate to them manually.
 fn get_default_help_tab(app: &App) -> HelpTab {
-    // Priority order: more specific contexts first
+    // Priority order: popups over the panes they cover, then focus
 
-    // Snippet manager visible
     if app.snippets.is_visible() {
         return HelpTab::Snippet;
     }
 
-    // Search mode active
+    if app.history.is_visible() {
+        return HelpTab::History;
+    }
+
     if app.search.is_visible() {
         return HelpTab::Search;
     }
 
-    // Results pane focused
+    if app.ai.visible {
+        return HelpTab::AI;
+    }
+
     if app.focus == Focus::ResultsPane {
         return HelpTab::Result;
     }
@@ -50,32 +58,73 @@ fn get_default_help_tab(app: &App) -> HelpTab {
         return HelpTab::Input;
     }
 
-    // Fallback - Global tab
-    // Note: History and AI tabs never auto-focus
     HelpTab::Global
 }
 
+/// Manual tab: `/` starts a search that takes every typed character until
+/// Enter or Esc, and ↑/↓ pick a section while j/k keep scrolling its entries
+fn handle_manual_keys(app: &mut App, key: KeyEvent) -> bool {
+    if app.help.manual.searching {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => app.help.manual.searching = false,
+            KeyCode::Backspace => app.help.manual.pop_char(),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.help.manual.push_char(c)
+            }
+            KeyCode::Up => {
+                app.help.manual.select_prev();
+            }
+            KeyCode::Down => {
+                app.help.manual.select_next();
+            }
+            _ => return false,
+        }
+        app.help.current_scroll_mut().jump_to_top();
+        return true;
+    }
+
+    let moved = match key.code {
+        KeyCode::Char('/') => {
+            app.help.manual.searching = true;
+            return true;
+        }
+        KeyCode::Up => app.help.manual.select_prev(),
+        KeyCode::Down => app.help.manual.select_next(),
+        _ => return false,
+    };
+    if moved {
+        app.help.current_scroll_mut().jump_to_top();
+    }
+    true
+}
+
 fn handle_truly_global_keys(app: &mut App, key: KeyEvent) -> bool {
     match key.code {
         KeyCode::F(1) => {
-            if app.help.visible {
-                app.help.reset();
-            } else {
-                // Auto-select tab based on current context
-                app.help.active_tab = get_default_help_tab(app);
-                app.help.visible = true;
-            }
+            app.toggle_help();
             true
         }
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.should_quit = true;
             true
         }
+        KeyCode::F(12) => {
+            crate::debug_log::toggle(app);
+            true
+        }
+        // Only claimed while a cancellable operation shows in the status bar
+        KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.cancel_visible_operation()
+        }
         _ => false,
     }
 }
 
 fn handle_help_keys(app: &mut App, key: KeyEvent) -> bool {
+    if app.help.active_tab == HelpTab::Manual && handle_manual_keys(app, key) {
+        return true;
+    }
+
     match key.code {
         // Close help
         KeyCode::Esc | KeyCode::F(1) => {
@@ -115,8 +164,8 @@ fn handle_help_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
-        // Jump to tab by number (1-7)
-        KeyCode::Char(c) if ('1'..='7').contains(&c) => {
+        // Jump to tab by number (1-8)
+        KeyCode::Char(c) if ('1'..='8').contains(&c) => {
             let index = (c as usize) - ('1' as usize);
             app.help.active_tab = HelpTab::from_index(index);
             true
@@ -180,13 +229,7 @@ fn handle_popup_passthrough_keys(app: &mut App, key: KeyEvent) -> bool {
             || app.input.editor_mode == EditorMode::Normal
             || app.focus == Focus::ResultsPane
         {
-            if app.help.visible {
-                app.help.reset();
-            } else {
-                // Auto-select tab based on current context
-                app.help.active_tab = get_default_help_tab(app);
-                app.help.visible = true;
-            }
+            app.toggle_help();
             return true;
         }
     }
@@ -216,10 +259,70 @@ fn handle_popup_passthrough_keys(app: &mut App, key: KeyEvent) -> bool {
     false
 }
 
-const EVENT_POLL_TIMEOUT: Duration = Duration::from_millis(100);
+/// Events handled before the next frame at most, so a paste-like flood of
+/// keys can't hold the screen back for long
+const MAX_EVENTS_PER_FRAME: usize = 256;
 
 impl App {
+    /// Open help at the tab for the current context, or close it
+    pub fn toggle_help(&mut self) {
+        let tab = get_default_help_tab(self);
+        self.help.toggle(tab);
+    }
+
     pub fn handle_events(&mut self) -> io::Result<()> {
+        self.tick();
+        if event::poll(self.poll_timeout())? {
+            let first = event::read()?;
+            self.handle_event_burst(first, || {
+                if event::poll(Duration::ZERO)? {
+                    event::read().map(Some)
+                } else {
+                    Ok(None)
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Handle `first` and the events `next` finds already waiting behind
+    /// it, then draw once
+    ///
+    /// Keys that arrive faster than frames, as with key repeat or a fast
+    /// typist, are all applied before the next frame instead of one per
+    /// frame. A query they would run is run once, for the state they
+    /// leave.
+    pub fn handle_event_burst(
+        &mut self,
+        first: Event,
+        mut next: impl FnMut() -> io::Result<Option<Event>>,
+    ) -> io::Result<()> {
+        self.batching_events = true;
+        self.handle_event(first);
+        let mut result = Ok(());
+        for _ in 1..MAX_EVENTS_PER_FRAME {
+            if self.should_quit() {
+                break;
+            }
+            match next() {
+                Ok(Some(event)) => self.handle_event(event),
+                Ok(None) => break,
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        self.batching_events = false;
+        if std::mem::take(&mut self.batched_query) {
+            editor::editor_events::execute_query(self);
+        }
+        result
+    }
+
+    /// Run the work due between events: the debounced query, replies
+    /// from background workers and expiring notifications
+    pub fn tick(&mut self) {
         if self.debouncer.should_execute() {
             editor::editor_events::execute_query_with_auto_show(self);
             self.debouncer.mark_executed();
@@ -230,6 +333,27 @@ impl App {
         if self.poll_query_response() {
             self.mark_dirty();
         }
+        if self.poll_split_query() {
+            self.mark_dirty();
+        }
+        if crate::autocomplete::suggestion_preview::poll_suggestion_preview(self) {
+            self.mark_dirty();
+        }
+        if crate::history::history_preview::poll_history_preview(self) {
+            self.mark_dirty();
+        }
+        if crate::results::last_stage_events::poll(self) {
+            self.mark_dirty();
+        }
+        if crate::save::save_events::poll_save_stream(self) {
+            self.mark_dirty();
+        }
+        if crate::results::frequency_events::poll_frequency(self) {
+            self.mark_dirty();
+        }
+        if self.log_viewer.refresh_if_due(Instant::now()) {
+            self.mark_dirty();
+        }
 
         if crate::ai::ai_events::poll_response_channel(&mut self.ai) {
             self.mark_dirty();
@@ -239,55 +363,83 @@ impl App {
         if self.notification.clear_if_expired() {
             self.mark_dirty();
         }
+        if self.onboarding.poll(&mut self.notification) {
+            self.mark_dirty();
+        }
+        if self.expire_paste_offer() {
+            self.mark_dirty();
+        }
+        if self.results_diff.clear_if_expired() {
+            self.mark_dirty();
+        }
+        if self.poll_input_analysis() {
+            self.mark_dirty();
+        }
+        if self
+            .which_key
+            .update(self.input.editor_mode, Instant::now())
+        {
+            self.mark_dirty();
+        }
+        self.update_progress();
 
-        if event::poll(EVENT_POLL_TIMEOUT)? {
-            match event::read()? {
-                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                    if self.source_picker.is_some() {
-                        self.handle_source_picker_key_event(key_event);
-                    } else if self.paste_recovery.is_some() {
-                        self.handle_paste_recovery_key_event(key_event);
-                    } else {
-                        self.handle_key_event(key_event);
-                    }
-                    self.mark_dirty();
-                }
-                Event::Paste(text) => {
-                    if self.source_picker.is_some() {
-                        // Pasting while the picker is open is treated
-                        // as the user explicitly wanting Paste mode.
-                        // Mouse-and-click hookup follows in a later
-                        // step; for now we just drop the bytes — the
-                        // user can press `p`.
-                    } else if self.paste_recovery.is_some() {
-                        log::debug!(
-                            "paste-recovery: bracketed paste event, {} bytes, {} lines",
-                            text.len(),
-                            text.matches('\n').count() + 1
-                        );
-                        self.handle_paste_recovery_paste(text);
-                    } else {
-                        self.handle_paste_event(text);
-                    }
-                    self.mark_dirty();
+        if self.accessibility.screen_reader {
+            let status = crate::accessibility::announcer::Status::of(self);
+            if self.accessibility.announcer.update(status) {
+                self.mark_dirty();
+            }
+        }
+    }
+
+    /// Route one terminal event to whatever is showing: the source
+    /// picker, paste recovery or the main screen
+    pub fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                let start = Instant::now();
+                let key_event = crate::platform::normalize_key(key_event, crate::platform::WINDOWS);
+                if self.source_picker.is_some() {
+                    self.handle_source_picker_key_event(key_event);
+                } else if self.paste_recovery.is_some() {
+                    self.handle_paste_recovery_key_event(key_event);
+                } else {
+                    self.handle_key_event(key_event);
                 }
-                Event::Resize(_, _) => {
-                    self.mark_dirty();
+                self.frame_timings.record_key(start.elapsed());
+                self.mark_dirty();
+            }
+            Event::Paste(text) => {
+                if self.source_picker.is_some() {
+                    // Pasting while the picker is open is treated
+                    // as the user explicitly wanting Paste mode.
+                    // Mouse-and-click hookup follows in a later
+                    // step; for now we just drop the bytes — the
+                    // user can press `p`.
+                } else if self.paste_recovery.is_some() {
+                    log::debug!(
+                        "paste-recovery: bracketed paste event, {} bytes, {} lines",
+                        text.len(),
+                        text.matches('\n').count() + 1
+                    );
+                    self.handle_paste_recovery_paste(text);
+                } else {
+                    self.handle_paste_event(text);
                 }
-                Event::Mouse(mouse_event) => {
-                    if self.source_picker.is_some() || self.paste_recovery.is_some() {
-                        // Picker / recovery occupy the screen; no
-                        // input/results split to act on. Mouse hookup
-                        // for the picker follows in a later step.
-                    } else {
-                        self.handle_mouse_event(mouse_event);
-                    }
-                    self.mark_dirty();
+                self.mark_dirty();
+            }
+            Event::Resize(width, height) => self.handle_resize(width, height),
+            Event::Mouse(mouse_event) => {
+                if self.source_picker.is_some() || self.paste_recovery.is_some() {
+                    // Picker / recovery occupy the screen; no
+                    // input/results split to act on. Mouse hookup
+                    // for the picker follows in a later step.
+                } else {
+                    self.handle_mouse_event(mouse_event);
                 }
-                _ => {}
+                self.mark_dirty();
             }
+            _ => {}
         }
-        Ok(())
     }
 
     /// Route a key while the source picker is active. Truly global keys
@@ -314,12 +466,16 @@ impl App {
         if self.help.visible && handle_help_keys(self, key) {
             return;
         }
+        // A new tab waits here for its document; tab keys leave or close it.
+        if tabs::handle_tab_keys(self, key) {
+            return;
+        }
         let _ = paste_recovery::handle_key(self, key);
     }
 
     /// Handle a paste event while in recovery: normalise CRLF endings,
     /// enforce the soft size cap, then forward to the standard
-    /// `handle_paste_event` which inserts into `app.input.textarea`.
+    /// `insert_paste` which inserts into `app.input.textarea`.
     /// `execute_query` is a no-op while `app.query` is None.
     fn handle_paste_recovery_paste(&mut self, text: String) {
         let normalised = crate::input::paste_recovery::normalise_newlines(&text);
@@ -339,11 +495,42 @@ impl App {
             self.notification.show_error(&msg);
             return;
         }
-        self.handle_paste_event(normalised);
+        self.insert_paste(&normalised);
     }
 
-    fn handle_paste_event(&mut self, text: String) {
-        self.input.textarea.insert_str(&text);
+    /// Lay everything out again for the new size straight away, instead
+    /// of leaving popups where the old layout put them until the next key
+    pub(super) fn handle_resize(&mut self, width: u16, height: u16) {
+        log::debug!("terminal resized to {}x{}", width, height);
+        // Mouse targets from the old layout would be hit at stale spots
+        self.layout_regions.clear();
+        // The AI popup keeps its height across loads; re-measure it
+        self.ai.previous_popup_height = None;
+        self.reveal_results_cursor = true;
+        self.mark_dirty();
+    }
+
+    /// Fill in the suggestions and tooltip left out while the input
+    /// analysis was running; true once it finished
+    fn poll_input_analysis(&mut self) -> bool {
+        let pending = self
+            .query
+            .as_ref()
+            .is_some_and(|q| q.executor.input_analysis_pending());
+        if !self.input_analysis_pending || pending {
+            return false;
+        }
+        self.input_analysis_pending = false;
+        if self.focus == Focus::InputField && self.input.editor_mode == EditorMode::Insert {
+            self.update_autocomplete();
+            self.update_tooltip();
+        }
+        true
+    }
+
+    /// Insert pasted text at the cursor as one edit and run the query
+    pub(super) fn insert_paste(&mut self, text: &str) {
+        self.input.textarea.insert_str(text);
 
         self.input
             .brace_tracker
@@ -363,20 +550,79 @@ impl App {
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) {
+        let _span = LogSpan::enter("key", format_args!("{:?} {:?}", key.code, key.modifiers));
+        self.macros.record(key);
+
         // STEP 1: Truly global keys - ALWAYS work regardless of any popup
         if handle_truly_global_keys(self, key) {
             return;
         }
 
+        // The debug log sits above every other popup
+        if self.log_viewer.is_visible() {
+            crate::debug_log::handle_key(self, key);
+            return;
+        }
+
+        // An offer to open pasted JSON takes Enter and Esc while it shows
+        if self.handle_paste_offer_key(key) {
+            return;
+        }
+
+        // Input that didn't parse takes the keys that load it another way
+        if self.handle_malformed_key(key) {
+            return;
+        }
+
+        // Onboarding tips sit above every popup, so Esc dismisses them first
+        if crate::notification::onboarding::handle_onboarding_key(
+            &mut self.onboarding,
+            &mut self.notification,
+            key,
+        ) {
+            return;
+        }
+
         // STEP 2: Popup stack (topmost first) - each handles its own Esc
         if self.help.visible && handle_help_keys(self, key) {
             return;
         }
 
+        if self.notification.center_visible
+            && crate::notification::notification_events::handle_center_key(
+                &mut self.notification,
+                key,
+            )
+        {
+            return;
+        }
+
         if self.search.is_visible() && crate::search::search_events::handle_search_key(self, key) {
             return;
         }
 
+        if crate::command_line::command_line_events::handle_key(self, key) {
+            return;
+        }
+
+        if crate::results::line_filter_events::handle_bar_key(self, key) {
+            return;
+        }
+
+        if self.tooltip.should_show_detail() {
+            // Enter follows the detailed view into the full manual entry
+            if key.code == KeyCode::Enter
+                && let Some(name) = self.tooltip.shown_name().map(str::to_string)
+            {
+                self.tooltip.close_detail();
+                self.help.open_manual(&name);
+                return;
+            }
+            if crate::tooltip::tooltip_events::handle_detail_key(&mut self.tooltip, key) {
+                return;
+            }
+        }
+
         // STEP 3: Keys that should pass through even when snippets/history are visible
         if (self.snippets.is_visible() || self.history.is_visible())
             && handle_popup_passthrough_keys(self, key)
@@ -399,6 +645,70 @@ impl App {
             return;
         }
 
+        if self.input_files.as_ref().is_some_and(|f| f.is_visible()) {
+            input_files::handle_key(self, key);
+            return;
+        }
+
+        if self.results_chart.is_visible() {
+            crate::results::chart_events::handle_chart_key(self, key);
+            return;
+        }
+
+        if self.results_frequency.is_visible() {
+            crate::results::frequency_events::handle_frequency_key(self, key);
+            return;
+        }
+
+        if self.results_stream.is_visible() {
+            crate::results::stream_view_events::handle_key(self, key);
+            return;
+        }
+
+        if self.results_decode.is_visible() {
+            crate::results::decode_events::handle_key(self, key);
+            return;
+        }
+
+        if self.format_menu_visible {
+            crate::results::format_menu_events::handle_format_menu_key(self, key);
+            return;
+        }
+
+        if self.flags_panel.is_visible() {
+            crate::flags_panel::handle_key(self, key);
+            return;
+        }
+
+        if self.presets.is_visible() {
+            crate::presets::handle_key(self, key);
+            return;
+        }
+
+        if self.params.is_visible() {
+            crate::params::handle_key(self, key);
+            return;
+        }
+
+        if self.path_picker.is_visible() {
+            crate::path_picker::handle_key(self, key);
+            return;
+        }
+
+        if self.ai.nl_prompt.is_visible() {
+            crate::ai::nl_prompt::nl_prompt_events::handle_nl_prompt_key(self, key);
+            return;
+        }
+
+        if self.ai.followup.is_visible() {
+            // The line lives inside the popup; drop it if the popup went away.
+            if self.ai.visible {
+                crate::ai::followup::followup_events::handle_followup_key(self, key);
+                return;
+            }
+            self.ai.followup.close();
+        }
+
         // STEP 3: Other global keys (when no popup is active)
         if global::handle_global_keys(self, key) {
             return;
@@ -425,6 +735,14 @@ impl App {
             return;
         }
 
+        // Terminals send Ctrl+Space as NUL or as a space with Control
+        if key.code == KeyCode::Null
+            || (key.code == KeyCode::Char(' ') && key.modifiers.contains(KeyModifiers::CONTROL))
+        {
+            self.open_autocomplete();
+            return;
+        }
+
         if key.code == KeyCode::Char('d') && key.modifiers.contains(KeyModifiers::CONTROL) {
             self.results_scroll.page_down();
             return;
@@ -445,6 +763,21 @@ impl App {
                     self.autocomplete.select_previous();
                     return;
                 }
+                KeyCode::PageDown => {
+                    self.autocomplete.page_down();
+                    return;
+                }
+                KeyCode::PageUp => {
+                    self.autocomplete.page_up();
+                    return;
+                }
+                KeyCode::Right | KeyCode::End
+                    if key.modifiers.is_empty()
+                        && crate::autocomplete::ghost_text::ghost_text(self).is_some() =>
+                {
+                    global::accept_autocomplete_suggestion(self);
+                    return;
+                }
                 _ => {}
             }
         }
@@ -492,6 +825,8 @@ impl App {
             EditorMode::TextObject(_, _) => {
                 editor::editor_events::handle_text_object_mode_key(self, key)
             }
+            EditorMode::Register(_) => editor::editor_events::handle_register_mode_key(self, key),
+            EditorMode::Wrap => editor::editor_events::handle_wrap_mode_key(self, key),
         }
     }
 
@@ -523,6 +858,10 @@ impl App {
     /// Uses the query returned from poll_response() to ensure AI gets correct context.
     /// Returns true if state changed (query completed).
     fn poll_query_response(&mut self) -> bool {
+        let previous = self
+            .query
+            .as_ref()
+            .and_then(|q| q.last_successful_result_unformatted.clone());
         let completed_query = if let Some(query_state) = &mut self.query {
             query_state.poll_response()
         } else {
@@ -530,6 +869,20 @@ impl App {
         };
 
         if let Some(completed_query) = completed_query {
+            // Flash what the edit changed; errors and empty results keep
+            // the previous result, so there is nothing to compare
+            if let Some(previous) = previous
+                && let Some(current) = self
+                    .query
+                    .as_ref()
+                    .and_then(|q| q.last_successful_result_unformatted.as_ref())
+                && !std::sync::Arc::ptr_eq(&previous, current)
+            {
+                self.results_diff.record(&previous, current);
+            }
+            self.relocate_pins();
+            self.update_query_fixes();
+
             // Result changed - update stats once (not on every frame)
             self.update_stats();
 
@@ -544,29 +897,17 @@ impl App {
                 let query_state = self.query.as_ref().unwrap();
                 let cursor_pos = self.input.textarea.cursor().1;
 
-                let ai_result: Result<String, String> = match &query_state.result {
-                    Ok(_) => query_state
-                        .last_successful_result_unformatted
-                        .as_ref()
-                        .map(|s| Ok(s.as_ref().clone()))
-                        .unwrap_or_else(|| Ok(String::new())),
-                    Err(e) => Err(e.clone()),
-                };
+                let ai_result = super::app_state::ai_execution_result(query_state);
 
                 crate::ai::ai_events::handle_query_result(
                     &mut self.ai,
                     &ai_result,
                     &completed_query, // Use query from response, not current input!
                     cursor_pos,
-                    crate::ai::context::ContextParams {
-                        input_schema: self.input_json_schema.as_deref(),
-                        base_query: query_state.base_query_for_suggestions.as_deref(),
-                        base_query_result: query_state
-                            .last_successful_result_for_context
-                            .as_deref()
-                            .map(|s| s.as_ref()),
-                        is_empty_result: query_state.is_empty_result,
-                    },
+                    super::app_state::ai_context_params(
+                        query_state,
+                        self.input_json_schema.as_deref(),
+                    ),
                 );
             }
             return true;
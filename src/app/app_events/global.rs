@@ -1,9 +1,8 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::super::app_state::{App, Focus, OutputMode};
-use crate::help::HelpTab;
 
-fn accept_autocomplete_suggestion(app: &mut App) -> bool {
+pub(super) fn accept_autocomplete_suggestion(app: &mut App) -> bool {
     if app.focus == Focus::InputField && app.autocomplete.is_visible() {
         if let Some(suggestion) = app.autocomplete.selected() {
             let suggestion_clone = suggestion.clone();
@@ -17,6 +16,10 @@ fn accept_autocomplete_suggestion(app: &mut App) -> bool {
 }
 
 pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
+    if super::tabs::handle_tab_keys(app, key) {
+        return true;
+    }
+
     if let Some(query) = &mut app.query
         && crate::ai::ai_events::handle_suggestion_selection(
             key,
@@ -24,6 +27,7 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             &mut app.input,
             query,
             &mut app.autocomplete,
+            &mut app.history,
         )
     {
         return true;
@@ -31,18 +35,14 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
 
     match key.code {
         KeyCode::Char('q') if !key.modifiers.contains(KeyModifiers::CONTROL) => match app.focus {
+            // `bq` and `` `q `` name mark q
+            Focus::ResultsPane if app.results_marks.has_chord() => false,
             Focus::ResultsPane => {
                 app.should_quit = true;
                 true
             }
-            Focus::InputField => {
-                if app.input.editor_mode == crate::editor::EditorMode::Normal {
-                    app.should_quit = true;
-                    true
-                } else {
-                    false
-                }
-            }
+            // In the query's Normal mode q records a macro
+            Focus::InputField => false,
         },
 
         KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -122,11 +122,7 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             if app.history.is_visible() {
                 app.history.close();
             }
-
-            match app.focus {
-                Focus::InputField => app.focus_results_pane(),
-                Focus::ResultsPane => app.focus_input_field(),
-            };
+            app.cycle_focus();
             true
         }
 
@@ -136,23 +132,7 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
                 || app.input.editor_mode == crate::editor::EditorMode::Normal
                 || app.focus == Focus::ResultsPane
             {
-                if app.help.visible {
-                    app.help.reset();
-                } else {
-                    // Context-aware tab selection
-                    // Note: History and AI tabs never auto-focus
-                    let tab = if app.snippets.is_visible() {
-                        HelpTab::Snippet
-                    } else if app.search.is_visible() {
-                        HelpTab::Search
-                    } else if app.focus == Focus::ResultsPane {
-                        HelpTab::Result
-                    } else {
-                        HelpTab::Input
-                    };
-                    app.help.active_tab = tab;
-                    app.help.visible = true;
-                }
+                app.toggle_help();
                 true
             } else {
                 false
@@ -161,7 +141,7 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
 
         KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             if let Some(query) = &app.query
-                && query.result.is_err()
+                && (query.result.is_err() || query.field_hint.is_some())
             {
                 app.error_overlay_visible = !app.error_overlay_visible;
             }
@@ -173,14 +153,33 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
+        KeyCode::F(3) => crate::tooltip::tooltip_events::handle_detail_toggle(&mut app.tooltip),
+
+        KeyCode::F(4) => {
+            crate::flags_panel::toggle(app);
+            true
+        }
+
+        KeyCode::F(5) => {
+            crate::presets::toggle(app);
+            true
+        }
+
+        KeyCode::F(6) => {
+            crate::params::toggle(app);
+            true
+        }
+
+        KeyCode::F(7) => {
+            crate::path_picker::toggle(app);
+            true
+        }
+
         KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             if app.history.is_visible() {
                 app.history.close();
             }
-            match app.focus {
-                Focus::InputField => app.focus_results_pane(),
-                Focus::ResultsPane => app.focus_input_field(),
-            };
+            app.cycle_focus();
             true
         }
 
@@ -190,6 +189,11 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
         }
 
         KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if app.ai.offline {
+                app.notification
+                    .show_warning(crate::ai::ai_state::OFFLINE_MESSAGE);
+                return true;
+            }
             let was_visible = app.ai.visible;
             app.ai.toggle();
 
@@ -208,6 +212,15 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
+        KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if crate::ai::followup::followup_events::can_follow_up(app) {
+                crate::ai::followup::followup_events::open_followup(app);
+            } else {
+                crate::ai::nl_prompt::nl_prompt_events::open_nl_prompt(app);
+            }
+            true
+        }
+
         KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.snippets.open();
             app.autocomplete.hide();
@@ -222,6 +235,29 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
+        KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.toggle_split();
+            true
+        }
+
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::ALT) => {
+            crate::editor::editor_events::apply_query_fix(app)
+        }
+
+        KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.notification.toggle_center();
+            true
+        }
+
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            let Some(files) = app.input_files.as_mut() else {
+                return false;
+            };
+            files.open();
+            app.autocomplete.hide();
+            true
+        }
+
         _ => false,
     }
 }
@@ -593,3 +593,40 @@ fn test_ctrl_a_toggles_off_no_request() {
         "Should NOT send AI request when hiding popup"
     );
 }
+
+#[test]
+fn test_ctrl_a_in_offline_mode_notifies_and_sends_nothing() {
+    let mut app = app_with_query(".initial");
+    app.input.editor_mode = EditorMode::Insert;
+    app.ai.configured = true;
+    app.ai.set_offline();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let (_response_tx, response_rx) = std::sync::mpsc::channel();
+    app.ai.set_channels(tx, response_rx);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('a'), KeyModifiers::CONTROL));
+
+    assert!(!app.ai.visible);
+    assert!(rx.try_recv().is_err());
+    assert_eq!(
+        app.notification.current_message(),
+        Some(crate::ai::ai_state::OFFLINE_MESSAGE)
+    );
+}
+
+#[test]
+fn test_ctrl_g_in_offline_mode_does_not_open_prompt() {
+    let mut app = app_with_query(".initial");
+    app.input.editor_mode = EditorMode::Insert;
+    app.ai.configured = true;
+    app.ai.set_offline();
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('g'), KeyModifiers::CONTROL));
+
+    assert!(!app.ai.nl_prompt.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some(crate::ai::ai_state::OFFLINE_MESSAGE)
+    );
+}
@@ -360,3 +360,132 @@ fn test_enter_exits_when_autocomplete_not_visible() {
         }
     }
 }
+
+// ========== Autocomplete Paging Tests ==========
+
+#[test]
+fn test_page_down_and_up_move_autocomplete_selection() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Insert;
+    app.focus = Focus::InputField;
+    let suggestions = (0..30)
+        .map(|i| {
+            crate::autocomplete::Suggestion::new(
+                format!("field{}", i),
+                crate::autocomplete::SuggestionType::Field,
+            )
+        })
+        .collect();
+    app.autocomplete.update_suggestions(suggestions);
+
+    app.handle_key_event(key(KeyCode::PageDown));
+    assert_eq!(app.autocomplete.selected_index(), 10);
+
+    app.handle_key_event(key(KeyCode::PageUp));
+    assert_eq!(app.autocomplete.selected_index(), 0);
+    assert!(app.autocomplete.is_visible());
+    assert_eq!(app.query(), ".");
+}
+
+// ========== Manual Invocation Tests ==========
+
+#[test]
+fn test_ctrl_space_opens_suggestions_mid_query() {
+    let mut app = app_with_query(".services | .");
+    app.input.editor_mode = EditorMode::Normal;
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Head);
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Forward);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char(' '), KeyModifiers::CONTROL));
+
+    assert!(app.autocomplete.is_visible());
+    assert_eq!(app.input.editor_mode, EditorMode::Insert);
+    assert_eq!(app.query(), ".services | .");
+}
+
+#[test]
+fn test_nul_key_counts_as_ctrl_space() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.handle_key_event(key(KeyCode::Null));
+
+    assert!(app.autocomplete.is_visible());
+}
+
+#[test]
+fn test_ctrl_space_with_nothing_to_suggest_says_so() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.handle_key_event(key_with_mods(KeyCode::Char(' '), KeyModifiers::CONTROL));
+
+    assert!(!app.autocomplete.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No suggestions here")
+    );
+}
+
+#[test]
+fn test_without_auto_show_typing_keeps_the_popup_closed() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.autocomplete_auto_show = false;
+
+    app.handle_key_event(key(KeyCode::Char('.')));
+    assert!(!app.autocomplete.is_visible());
+
+    // Once opened by hand, the popup follows the typing
+    app.handle_key_event(key_with_mods(KeyCode::Char(' '), KeyModifiers::CONTROL));
+    assert!(app.autocomplete.is_visible());
+    app.handle_key_event(key(KeyCode::Char('n')));
+    assert!(app.autocomplete.is_visible());
+    assert_eq!(app.query(), ".n");
+}
+
+// ========== Ghost Text Acceptance Tests ==========
+
+fn app_with_ghost(query: &str) -> App {
+    let mut app = app_with_query(query);
+    app.input.editor_mode = EditorMode::Insert;
+    app.focus = Focus::InputField;
+    app.update_autocomplete();
+    app
+}
+
+#[test]
+fn test_right_accepts_ghost_text() {
+    let mut app = app_with_ghost(".na");
+    assert!(crate::autocomplete::ghost_text::ghost_text(&app).is_some());
+
+    app.handle_key_event(key(KeyCode::Right));
+
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_end_accepts_ghost_text() {
+    let mut app = app_with_ghost(".na");
+
+    app.handle_key_event(key(KeyCode::End));
+
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_right_moves_cursor_when_not_at_end() {
+    let mut app = app_with_ghost(".na");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Head);
+
+    app.handle_key_event(key(KeyCode::Right));
+
+    assert_eq!(app.query(), ".na");
+    assert_eq!(app.input.textarea.cursor().1, 1);
+}
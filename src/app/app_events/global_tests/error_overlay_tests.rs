@@ -129,3 +129,68 @@ fn test_ctrl_e_works_when_results_pane_focused() {
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
     assert!(app.error_overlay_visible);
 }
+
+/// `.nmae` is null against TEST_JSON, which has a `name` key
+fn app_with_mistyped_field() -> App {
+    let mut app = test_app(TEST_JSON);
+    app.input.editor_mode = EditorMode::Insert;
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    for c in ".nmae".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    flush_debounced_query(&mut app);
+    app.update_query_fixes();
+    app
+}
+
+#[test]
+fn test_ctrl_e_opens_overlay_for_mistyped_field() {
+    let mut app = app_with_mistyped_field();
+    assert!(app.query.as_ref().unwrap().result.is_ok());
+    assert!(app.query.as_ref().unwrap().field_hint.is_some());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
+    assert!(app.error_overlay_visible);
+}
+
+#[test]
+fn test_alt_f_replaces_mistyped_field() {
+    let mut app = app_with_mistyped_field();
+    app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::ALT));
+
+    assert_eq!(app.input.query(), ".name");
+    assert_eq!(app.input.textarea.cursor().1, 5);
+    assert!(!app.error_overlay_visible);
+}
+
+#[test]
+fn test_known_field_gives_no_hint() {
+    let mut app = test_app(TEST_JSON);
+    app.input.editor_mode = EditorMode::Insert;
+    app.handle_key_event(key(KeyCode::Char('|')));
+    flush_debounced_query(&mut app);
+    app.update_query_fixes();
+
+    assert!(app.query.as_ref().unwrap().field_hint.is_none());
+}
+
+#[test]
+fn test_alt_f_makes_failing_accesses_optional() {
+    let mut app = test_app(r#"{"items": [{"foo": 1}, "x"]}"#);
+    app.input.editor_mode = EditorMode::Insert;
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    for c in ".items[].foo".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    flush_debounced_query(&mut app);
+    app.update_query_fixes();
+    assert!(app.query.as_ref().unwrap().result.is_err());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::ALT));
+
+    assert_eq!(app.input.query(), ".items[]?.foo?");
+}
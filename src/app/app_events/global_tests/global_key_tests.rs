@@ -17,13 +17,14 @@ fn test_ctrl_c_sets_quit_flag() {
 }
 
 #[test]
-fn test_q_sets_quit_flag_in_normal_mode() {
+fn test_q_starts_a_macro_in_normal_mode() {
     let mut app = app_with_query(".");
     app.input.editor_mode = EditorMode::Normal;
 
     app.handle_key_event(key(KeyCode::Char('q')));
 
-    assert!(app.should_quit);
+    assert!(!app.should_quit);
+    assert_eq!(app.input.editor_mode, EditorMode::Register('q'));
 }
 
 #[test]
@@ -419,15 +420,15 @@ fn test_q_does_not_quit_in_input_field_insert_mode() {
 }
 
 #[test]
-fn test_q_quits_in_input_field_normal_mode() {
+fn test_q_does_not_quit_in_input_field_normal_mode() {
     let mut app = app_with_query("");
     app.focus = Focus::InputField;
     app.input.editor_mode = EditorMode::Normal;
 
-    // 'q' should quit when in Normal mode
+    // 'q' waits for a macro register in Normal mode
     app.handle_key_event(key(KeyCode::Char('q')));
 
-    assert!(app.should_quit);
+    assert!(!app.should_quit);
 }
 
 #[test]
@@ -536,6 +537,44 @@ fn test_backtab_round_trip_preserves_popup_state() {
     assert!(app.tooltip.enabled);
 }
 
+// ========== Tooltip Detail Tests (F3) ==========
+
+#[test]
+fn test_f3_opens_and_closes_tooltip_detail() {
+    let mut app = app_with_query("range(");
+    app.update_tooltip();
+    assert!(app.tooltip.should_show());
+
+    app.handle_key_event(key(KeyCode::F(3)));
+    assert!(app.tooltip.should_show_detail());
+
+    app.handle_key_event(key(KeyCode::F(3)));
+    assert!(!app.tooltip.detail_visible);
+}
+
+#[test]
+fn test_f3_without_tooltip_does_nothing() {
+    let mut app = app_with_query(".");
+    app.update_tooltip();
+
+    app.handle_key_event(key(KeyCode::F(3)));
+
+    assert!(!app.tooltip.detail_visible);
+}
+
+#[test]
+fn test_esc_closes_tooltip_detail_before_leaving_insert_mode() {
+    let mut app = app_with_query("range(");
+    app.input.editor_mode = EditorMode::Insert;
+    app.update_tooltip();
+    app.handle_key_event(key(KeyCode::F(3)));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.tooltip.detail_visible);
+    assert_eq!(app.input.editor_mode, EditorMode::Insert);
+}
+
 // ========== Focus Toggle Tests (Ctrl+T) ==========
 
 #[test]
@@ -850,3 +889,74 @@ fn test_ctrl_f_works_in_results_pane() {
 
     assert!(app.search.is_visible());
 }
+
+// ========== Split View Tests (Alt+V) ==========
+
+#[test]
+fn test_alt_v_toggles_split_view() {
+    let mut app = app_with_query(".");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('v'), KeyModifiers::ALT));
+    assert!(app.split.is_active());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('v'), KeyModifiers::ALT));
+    assert!(!app.split.is_active());
+}
+
+#[test]
+fn test_ctrl_t_crosses_to_other_split_pane() {
+    let mut app = app_with_query(".");
+    app.handle_key_event(key_with_mods(KeyCode::Char('v'), KeyModifiers::ALT));
+    assert!(app.split.right_live());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+    assert!(!app.split.right_live());
+    assert_eq!(app.focus, Focus::InputField);
+    assert_eq!(app.query(), ".");
+}
+
+// ========== Onboarding Tips Tests (F2) ==========
+
+#[test]
+fn test_f2_shows_onboarding_tip() {
+    let mut app = app_with_query(".");
+
+    app.handle_key_event(key(KeyCode::F(2)));
+    assert!(app.notification.is_showing_hint());
+    assert!(app.onboarding.is_running());
+}
+
+#[test]
+fn test_esc_dismisses_tip_before_closing_help() {
+    let mut app = app_with_query(".");
+    app.help.visible = true;
+    app.handle_key_event(key(KeyCode::F(2)));
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(app.notification.current().is_none());
+    assert!(!app.onboarding.is_running());
+    assert!(app.help.visible);
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.help.visible);
+}
+
+// ========== Notification Center Tests (Alt+N) ==========
+
+#[test]
+fn test_alt_n_opens_notification_center_over_other_keys() {
+    let mut app = app_with_query(".");
+    app.notification.show("Copied query!");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('n'), KeyModifiers::ALT));
+    assert!(app.notification.center_visible);
+
+    // Typing goes to the center, not the query
+    app.handle_key_event(key(KeyCode::Char('x')));
+    assert_eq!(app.query(), ".");
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.notification.center_visible);
+}
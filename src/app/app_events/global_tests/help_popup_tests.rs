@@ -341,7 +341,7 @@ fn test_help_popup_shift_tab_navigates_to_previous_tab() {
 fn test_help_popup_tab_wraps_at_end() {
     let mut app = app_with_query(".");
     app.help.visible = true;
-    app.help.active_tab = HelpTab::Snippet;
+    app.help.active_tab = HelpTab::Manual;
 
     app.handle_key_event(key(KeyCode::Char('l')));
     assert_eq!(app.help.active_tab, HelpTab::Global);
@@ -354,7 +354,7 @@ fn test_help_popup_tab_wraps_at_beginning() {
     app.help.active_tab = HelpTab::Global;
 
     app.handle_key_event(key(KeyCode::Char('h')));
-    assert_eq!(app.help.active_tab, HelpTab::Snippet);
+    assert_eq!(app.help.active_tab, HelpTab::Manual);
 }
 
 #[test]
@@ -382,6 +382,9 @@ fn test_help_popup_number_keys_jump_to_tab() {
 
     app.handle_key_event(key(KeyCode::Char('7')));
     assert_eq!(app.help.active_tab, HelpTab::Snippet);
+
+    app.handle_key_event(key(KeyCode::Char('8')));
+    assert_eq!(app.help.active_tab, HelpTab::Manual);
 }
 
 // Context-aware tab selection tests
@@ -428,29 +431,36 @@ fn test_help_opens_to_snippet_tab_when_snippets_visible() {
 }
 
 #[test]
-fn test_help_does_not_auto_focus_history_tab() {
-    // History tab never auto-focuses - should use the underlying context (Input here)
+fn test_help_opens_to_history_tab_when_history_visible() {
     let mut app = app_with_query(".");
     app.focus = Focus::InputField;
     app.history.open(None);
 
     app.handle_key_event(key(KeyCode::F(1)));
     assert!(app.help.visible);
-    // Should NOT be History tab - falls back to Input since focus is InputField
-    assert_eq!(app.help.active_tab, HelpTab::Input);
+    assert_eq!(app.help.active_tab, HelpTab::History);
 }
 
 #[test]
-fn test_help_does_not_auto_focus_ai_tab() {
-    // AI tab never auto-focuses - should use the underlying context (Input here)
+fn test_help_opens_to_ai_tab_when_ai_popup_open() {
     let mut app = app_with_query(".");
     app.focus = Focus::InputField;
     app.ai.visible = true;
 
     app.handle_key_event(key(KeyCode::F(1)));
     assert!(app.help.visible);
-    // Should NOT be AI tab - falls back to Input since focus is InputField
-    assert_eq!(app.help.active_tab, HelpTab::Input);
+    assert_eq!(app.help.active_tab, HelpTab::AI);
+}
+
+#[test]
+fn test_question_mark_in_results_opens_to_ai_tab_when_ai_popup_open() {
+    let mut app = app_with_query(".");
+    app.focus = Focus::ResultsPane;
+    app.ai.visible = true;
+
+    app.handle_key_event(key(KeyCode::Char('?')));
+    assert!(app.help.visible);
+    assert_eq!(app.help.active_tab, HelpTab::AI);
 }
 
 #[test]
@@ -474,3 +484,91 @@ fn test_each_tab_has_independent_scroll() {
     app.help.active_tab = HelpTab::Global;
     assert_eq!(app.help.current_scroll().offset, 5);
 }
+
+// Manual tab tests
+
+fn app_on_manual() -> App {
+    let mut app = app_with_query(".");
+    app.help.visible = true;
+    app.help.active_tab = HelpTab::Manual;
+    app
+}
+
+#[test]
+fn test_manual_slash_starts_search_and_typing_filters() {
+    let mut app = app_on_manual();
+
+    app.handle_key_event(key(KeyCode::Char('/')));
+    assert!(app.help.manual.searching);
+
+    // Keys that close help or switch tabs elsewhere are typed while searching
+    for c in "qdate".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    assert!(app.help.visible);
+    assert_eq!(app.help.active_tab, HelpTab::Manual);
+    assert_eq!(app.help.manual.query(), "qdate");
+
+    app.handle_key_event(key(KeyCode::Backspace));
+    assert_eq!(app.help.manual.query(), "qdat");
+}
+
+#[test]
+fn test_manual_enter_and_esc_end_search_keeping_filter() {
+    let mut app = app_on_manual();
+    app.handle_key_event(key(KeyCode::Char('/')));
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+    assert!(!app.help.manual.searching);
+    assert_eq!(app.help.manual.query(), "x");
+    assert!(app.help.visible);
+
+    app.handle_key_event(key(KeyCode::Char('/')));
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.help.manual.searching);
+    assert!(app.help.visible);
+
+    // Esc outside the search box closes help as on other tabs
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.help.visible);
+}
+
+#[test]
+fn test_manual_arrows_select_sections_and_reset_scroll() {
+    let mut app = app_on_manual();
+    app.help.current_scroll_mut().update_bounds(50, 10);
+    app.help.current_scroll_mut().scroll_down(5);
+
+    app.handle_key_event(key(KeyCode::Down));
+    assert_eq!(app.help.manual.selected(), 1);
+    assert_eq!(app.help.current_scroll().offset, 0);
+
+    app.handle_key_event(key(KeyCode::Up));
+    assert_eq!(app.help.manual.selected(), 0);
+}
+
+#[test]
+fn test_manual_j_k_scroll_the_entry() {
+    let mut app = app_on_manual();
+    app.help.current_scroll_mut().update_bounds(50, 10);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    assert_eq!(app.help.current_scroll().offset, 1);
+    assert_eq!(app.help.manual.selected(), 0);
+}
+
+#[test]
+fn test_enter_in_tooltip_detail_opens_manual_entry() {
+    let mut app = app_with_query("limit(3; .[])");
+    app.tooltip.set_current_function(Some("limit".to_string()));
+    app.tooltip.toggle_detail();
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.tooltip.detail_visible);
+    assert!(app.help.visible);
+    assert_eq!(app.help.active_tab, HelpTab::Manual);
+    let section = app.help.manual.selected_section().unwrap();
+    assert!(section.entries.iter().any(|e| e.function == "limit"));
+}
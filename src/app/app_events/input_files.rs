@@ -0,0 +1,42 @@
+//! Key routing while the input file picker strip is open.
+//!
+//! Bindings:
+//! * `←` / `h` / `BackTab` — highlight the previous file.
+//! * `→` / `l` / `Tab`     — highlight the next file.
+//! * `Space`               — include/exclude the highlighted file and
+//!   re-run the query against the new input.
+//! * `Esc` / `Enter` / `Ctrl+L` — close the strip.
+//!
+//! Anything else is swallowed so typing cannot leak into the query
+//! while the strip has focus.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let Some(files) = app.input_files.as_mut() else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter => files.close(),
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => files.close(),
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => files.select_previous(),
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => files.select_next(),
+        KeyCode::Char(' ') => {
+            if files.toggle_selected() {
+                app.reload_included_files();
+            } else {
+                app.notification
+                    .show_warning("At least one input file must stay included");
+            }
+        }
+        _ => {}
+    }
+    app.mark_dirty();
+}
+
+#[cfg(test)]
+#[path = "input_files_tests.rs"]
+mod input_files_tests;
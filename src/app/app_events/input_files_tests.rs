@@ -0,0 +1,100 @@
+//! Tests for the input file picker key router.
+
+use super::*;
+
+use std::path::PathBuf;
+
+use crate::input::{InputFile, InputFilesState};
+use crate::test_utils::test_helpers::{key, key_with_mods, test_app};
+
+fn files_app() -> App {
+    let mut app = test_app("{\"a\":1}\n{\"b\":2}");
+    app.input_files = Some(InputFilesState::new(vec![
+        InputFile::new(PathBuf::from("a.json"), "{\"a\":1}".to_string()),
+        InputFile::new(PathBuf::from("b.json"), "{\"b\":2}".to_string()),
+    ]));
+    app.input_files.as_mut().unwrap().open();
+    app
+}
+
+fn files(app: &App) -> &InputFilesState {
+    app.input_files.as_ref().unwrap()
+}
+
+#[test]
+fn ctrl_l_opens_picker_when_several_files_loaded() {
+    let mut app = files_app();
+    app.input_files.as_mut().unwrap().close();
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+
+    assert!(files(&app).is_visible());
+}
+
+#[test]
+fn ctrl_l_without_input_files_does_nothing() {
+    let mut app = test_app("{}");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+
+    assert!(app.input_files.is_none());
+}
+
+#[test]
+fn esc_and_ctrl_l_close_picker() {
+    let mut app = files_app();
+    handle_key(&mut app, key(KeyCode::Esc));
+    assert!(!files(&app).is_visible());
+
+    app.input_files.as_mut().unwrap().open();
+    handle_key(
+        &mut app,
+        key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL),
+    );
+    assert!(!files(&app).is_visible());
+}
+
+#[test]
+fn arrows_move_highlight() {
+    let mut app = files_app();
+
+    handle_key(&mut app, key(KeyCode::Right));
+    assert_eq!(files(&app).selected(), 1);
+    handle_key(&mut app, key(KeyCode::Char('h')));
+    assert_eq!(files(&app).selected(), 0);
+}
+
+#[test]
+fn space_excludes_file_and_reloads_input() {
+    let mut app = files_app();
+
+    handle_key(&mut app, key(KeyCode::Char(' ')));
+
+    assert!(!files(&app).files()[0].included);
+    let input = app.query.as_ref().unwrap().executor.json_input();
+    assert_eq!(input, "{\"b\":2}");
+}
+
+#[test]
+fn space_on_last_included_file_warns() {
+    let mut app = files_app();
+    handle_key(&mut app, key(KeyCode::Char(' ')));
+    handle_key(&mut app, key(KeyCode::Right));
+
+    handle_key(&mut app, key(KeyCode::Char(' ')));
+
+    assert!(files(&app).files()[1].included);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("At least one input file must stay included")
+    );
+}
+
+#[test]
+fn typing_is_swallowed_while_open() {
+    let mut app = files_app();
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert_eq!(app.query(), "");
+}
@@ -0,0 +1,51 @@
+//! Tab keys, checked from the main view and from a tab's paste editor.
+//!
+//! Bindings:
+//! * `Alt+T`                          — open a new tab in the paste editor.
+//! * `Alt+W`                          — close the active tab.
+//! * `Ctrl+PageDown` / `Ctrl+PageUp`  — switch to the next / previous tab.
+//!
+//! `Ctrl+T` stays the focus toggle, so new tabs live on `Alt+T`.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+pub fn handle_tab_keys(app: &mut App, key: KeyEvent) -> bool {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::PageDown if app.tabs.has_multiple() => {
+                app.next_tab();
+                true
+            }
+            KeyCode::PageUp if app.tabs.has_multiple() => {
+                app.previous_tab();
+                true
+            }
+            _ => false,
+        };
+    }
+
+    if !key.modifiers.contains(KeyModifiers::ALT) {
+        return false;
+    }
+    match key.code {
+        KeyCode::Char('t') => {
+            app.new_tab();
+            app.notification
+                .show("New tab · paste JSON, Enter to load · Alt+W closes");
+            true
+        }
+        KeyCode::Char('w') => {
+            if !app.close_tab() {
+                app.notification.show_warning("Only one tab open");
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "tabs_tests.rs"]
+mod tabs_tests;
@@ -0,0 +1,82 @@
+//! Tests for the tab key bindings.
+
+use super::*;
+
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+
+fn alt(c: char) -> KeyEvent {
+    key_with_mods(KeyCode::Char(c), KeyModifiers::ALT)
+}
+
+#[test]
+fn alt_t_opens_tab_in_paste_editor() {
+    let mut app = app_with_query(".name");
+
+    app.handle_key_event(alt('t'));
+
+    assert!(app.tabs.has_multiple());
+    assert!(app.paste_recovery.is_some());
+}
+
+#[test]
+fn ctrl_t_still_toggles_focus() {
+    let mut app = app_with_query(".name");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+    assert!(!app.tabs.has_multiple());
+    assert_eq!(app.focus, crate::app::Focus::ResultsPane);
+}
+
+#[test]
+fn ctrl_page_keys_switch_tabs() {
+    let mut app = app_with_query(".name");
+    app.new_tab();
+    app.accept_paste_recovery_json("{}".to_string());
+
+    app.handle_key_event(key_with_mods(KeyCode::PageUp, KeyModifiers::CONTROL));
+    assert_eq!(app.tabs.active(), 0);
+    assert_eq!(app.query(), ".name");
+
+    app.handle_key_event(key_with_mods(KeyCode::PageDown, KeyModifiers::CONTROL));
+    assert_eq!(app.tabs.active(), 1);
+}
+
+#[test]
+fn ctrl_page_keys_with_one_tab_fall_through() {
+    let mut app = app_with_query(".name");
+    assert!(!handle_tab_keys(
+        &mut app,
+        key_with_mods(KeyCode::PageDown, KeyModifiers::CONTROL)
+    ));
+}
+
+#[test]
+fn alt_w_closes_tab_from_paste_editor() {
+    let mut app = app_with_query(".name");
+    app.new_tab();
+
+    assert!(handle_tab_keys(&mut app, alt('w')));
+
+    assert!(!app.tabs.has_multiple());
+    assert!(app.paste_recovery.is_none());
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn alt_w_on_only_tab_warns() {
+    let mut app = app_with_query(".name");
+
+    handle_tab_keys(&mut app, alt('w'));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Only one tab open")
+    );
+}
+
+#[test]
+fn plain_keys_are_not_tab_keys() {
+    let mut app = app_with_query("");
+    assert!(!handle_tab_keys(&mut app, key(KeyCode::Char('t'))));
+}
@@ -708,6 +708,8 @@ mod paste_recovery_event_tests {
         let loader = crate::input::FileLoader {
             state: LoadingState::Error(crate::error::JiqError::Io("err".to_string())),
             rx: Some(rx),
+            files_rx: None,
+            malformed_rx: None,
             source: LoaderSource::Clipboard,
         };
         let mut app = App::new_with_loader(loader, &Config::default());
@@ -1138,6 +1140,8 @@ mod paste_recovery_event_tests {
         let loader = crate::input::FileLoader {
             state: LoadingState::Error(crate::error::JiqError::Io("err".to_string())),
             rx: Some(rx),
+            files_rx: None,
+            malformed_rx: None,
             source: LoaderSource::Clipboard,
         };
         let mut app = App::new_with_loader(loader, &Config::default());
@@ -1147,3 +1151,12 @@ mod paste_recovery_event_tests {
         assert_eq!(recovery.error_message, "Clipboard is empty.");
     }
 }
+
+#[path = "app_events_tests/cancel_tests.rs"]
+mod cancel_tests;
+#[path = "app_events_tests/poll_response_tests.rs"]
+mod poll_response_tests;
+#[path = "app_events_tests/resize_tests.rs"]
+mod resize_tests;
+#[path = "app_events_tests/tick_tests.rs"]
+mod tick_tests;
@@ -0,0 +1,29 @@
+//! Tests for cancelling running work with Ctrl+X
+
+use super::*;
+
+#[test]
+fn test_ctrl_x_cancels_visible_ai_request() {
+    use crate::progress::Operation;
+    use std::time::{Duration, Instant};
+
+    let mut app = app_with_query(".");
+    app.ai.loading = true;
+    let started = Instant::now() - Duration::from_secs(2);
+    app.progress.update_at(Operation::AiRequest, true, started);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('x'), KeyModifiers::CONTROL));
+
+    assert!(!app.ai.loading);
+    assert!(app.progress.visible().is_none());
+    assert_eq!(app.notification.current_message(), Some("Cancelled"));
+}
+
+#[test]
+fn test_ctrl_x_passes_through_when_nothing_is_running() {
+    let mut app = app_with_query(".");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('x'), KeyModifiers::CONTROL));
+
+    assert!(app.notification.current().is_none());
+}
@@ -0,0 +1,82 @@
+//! Tests for the change flash and pins updated as query responses arrive
+
+use super::*;
+
+#[test]
+fn poll_query_response_flashes_lines_the_edit_changed() {
+    use crate::results::result_diff::DiffHighlight;
+
+    let mut app = app_with_query(".");
+    app.results_diff = DiffHighlight::new(true);
+    if let Some(qs) = app.query.as_mut() {
+        qs.execute_async("{name, age}");
+    }
+
+    let start = std::time::Instant::now();
+    while start.elapsed() < std::time::Duration::from_millis(2000) {
+        if app.poll_query_response() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert!(app.results_diff.is_visible());
+    // `{` and `"name": "test",` are shared, the old `"age": 30,` line
+    // became `"age": 30` at the end of the smaller object
+    assert!(!app.results_diff.is_added(0));
+    assert!(!app.results_diff.is_added(1));
+    assert!(app.results_diff.is_added(2));
+}
+
+#[test]
+fn poll_query_response_reports_pins_no_longer_present() {
+    let mut app = app_with_query(".");
+    app.focus = crate::app::Focus::ResultsPane;
+    app.results_cursor
+        .update_total_lines(app.results_line_count_u32());
+    app.results_cursor.move_to_line(1);
+    app.handle_key_event(key(KeyCode::Char('p')));
+    assert_eq!(app.notification.current_message(), Some("Pinned .name"));
+    if let Some(qs) = app.query.as_mut() {
+        qs.execute_async("{age}");
+    }
+
+    let start = std::time::Instant::now();
+    while start.elapsed() < std::time::Duration::from_millis(2000) {
+        if app.poll_query_response() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert_eq!(app.results_pins.missing(), 1);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Pinned .name no longer present")
+    );
+}
+
+#[test]
+fn poll_query_response_moves_pins_with_their_value() {
+    let mut app = app_with_query("{name, age}");
+    app.focus = crate::app::Focus::ResultsPane;
+    app.results_cursor
+        .update_total_lines(app.results_line_count_u32());
+    // `"age": 30` is line 2 of `{name, age}` and line 1 of `{age}`
+    app.results_cursor.move_to_line(2);
+    app.handle_key_event(key(KeyCode::Char('p')));
+    if let Some(qs) = app.query.as_mut() {
+        qs.execute_async("{age}");
+    }
+
+    let start = std::time::Instant::now();
+    while start.elapsed() < std::time::Duration::from_millis(2000) {
+        if app.poll_query_response() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert_eq!(app.results_pins.missing(), 0);
+    assert_eq!(app.results_pins.next_after(0), Some(1));
+}
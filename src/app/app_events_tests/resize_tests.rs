@@ -0,0 +1,69 @@
+//! Tests for keeping popups and the cursor in view on a resize
+
+use super::*;
+
+#[test]
+fn resize_keeps_the_selected_suggestion_in_view() {
+    let json = format!(
+        "{{{}}}",
+        (0..12)
+            .map(|i| format!("\"field{:02}\": {}", i, i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let mut jiq = crate::headless::Headless::builder(json)
+        .size(80, 30)
+        .build();
+    jiq.type_str(".f");
+    jiq.settle();
+    for _ in 0..8 {
+        jiq.key(KeyCode::Down);
+    }
+    assert!(jiq.screen().contains("field08"));
+
+    jiq.resize(80, 10);
+    let screen = jiq.screen();
+
+    assert!(jiq.app().autocomplete.viewport() < 10);
+    assert!(screen.contains("field08"), "{}", screen);
+    assert!(screen.contains("9/12"), "{}", screen);
+}
+
+#[test]
+fn resize_scrolls_the_results_cursor_back_into_view() {
+    let json = format!(
+        "[{}]",
+        (0..60).map(|i| i.to_string()).collect::<Vec<_>>().join(",")
+    );
+    let mut jiq = crate::headless::Headless::builder(json)
+        .size(80, 40)
+        .build();
+    jiq.settle();
+    jiq.render();
+    jiq.app_mut().focus = Focus::ResultsPane;
+    for _ in 0..30 {
+        jiq.key(KeyCode::Char('j'));
+    }
+    jiq.render();
+    let cursor = jiq.app().results_cursor.cursor_line();
+
+    jiq.resize(80, 12);
+    jiq.render();
+
+    let scroll = &jiq.app().results_scroll;
+    assert!(scroll.offset <= cursor as u16);
+    assert!(cursor < (scroll.offset + scroll.viewport_height) as u32);
+}
+
+#[test]
+fn resize_forgets_the_old_layout() {
+    let mut jiq = crate::headless::Headless::new(r#"{"a": 1}"#);
+    jiq.settle();
+    jiq.render();
+    assert!(jiq.app().layout_regions.results_pane.is_some());
+
+    jiq.event(ratatui::crossterm::event::Event::Resize(60, 20));
+
+    assert!(jiq.app().layout_regions.results_pane.is_none());
+    assert!(jiq.app().needs_render);
+}
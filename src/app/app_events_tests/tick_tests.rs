@@ -0,0 +1,124 @@
+//! Tests for work picked up on a tick and for bursts of events
+
+use super::*;
+
+#[test]
+fn test_tick_fills_in_suggestions_once_input_analysis_is_ready() {
+    let mut app = test_app(r#"{"name": "Alice", "nickname": "Al"}"#);
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+    app.input.textarea.insert_str(".n");
+    // As if suggestions were asked for while the analysis ran
+    app.input_analysis_pending = true;
+    assert!(!app.autocomplete.is_visible());
+
+    app.tick();
+
+    assert!(!app.input_analysis_pending);
+    assert!(app.autocomplete.is_visible());
+}
+
+mod event_bursts {
+    use super::*;
+    use crate::test_utils::test_helpers::wait_for_query_completion;
+    use ratatui::crossterm::event::Event;
+    use std::io;
+
+    fn insert_mode_app(json: &str) -> crate::app::App {
+        let mut app = test_app(json);
+        app.focus = Focus::InputField;
+        app.input.editor_mode = EditorMode::Insert;
+        app
+    }
+
+    fn typed(text: &str) -> Vec<Event> {
+        text.chars()
+            .map(|c| Event::Key(key(KeyCode::Char(c))))
+            .collect()
+    }
+
+    #[test]
+    fn test_burst_applies_every_waiting_key() {
+        let mut app = insert_mode_app(r#"{"name": "Alice"}"#);
+        let mut events = typed(".name").into_iter();
+        let first = events.next().unwrap();
+
+        app.handle_event_burst(first, || Ok(events.next())).unwrap();
+
+        assert_eq!(app.query(), ".name");
+        assert_eq!(events.len(), 0);
+    }
+
+    #[test]
+    fn test_burst_runs_the_query_for_the_final_state() {
+        let mut app = insert_mode_app(r#"{"name": "Alice", "age": 30}"#);
+        let mut events = std::iter::repeat_n(Event::Key(key(KeyCode::Backspace)), 5)
+            .chain([Event::Paste(".age".to_string())]);
+
+        app.handle_event_burst(Event::Paste(".name".to_string()), || Ok(events.next()))
+            .unwrap();
+
+        assert!(!app.batching_events);
+        assert!(!app.batched_query);
+        assert_eq!(app.query(), ".age");
+        assert!(wait_for_query_completion(&mut app, 2000));
+        let result = app.query.as_ref().unwrap().result.as_ref().unwrap();
+        assert!(result.contains("30"), "{result}");
+    }
+
+    #[test]
+    fn test_burst_without_a_query_runs_none() {
+        let mut app = insert_mode_app(r#"{"name": "Alice"}"#);
+
+        app.handle_event_burst(Event::Key(key(KeyCode::Left)), || Ok(None))
+            .unwrap();
+
+        assert!(!app.query.as_ref().unwrap().is_pending());
+    }
+
+    #[test]
+    fn test_burst_stops_reading_once_quitting() {
+        let mut app = insert_mode_app(r#"{"name": "Alice"}"#);
+        let mut asked = 0;
+
+        app.handle_event_burst(
+            Event::Key(key_with_mods(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            || {
+                asked += 1;
+                Ok(Some(Event::Key(key(KeyCode::Char('x')))))
+            },
+        )
+        .unwrap();
+
+        assert!(app.should_quit());
+        assert_eq!(asked, 0);
+        assert_eq!(app.query(), "");
+    }
+
+    #[test]
+    fn test_burst_is_capped_so_a_frame_still_comes() {
+        let mut app = insert_mode_app(r#"{"name": "Alice"}"#);
+        let mut asked = 0;
+
+        app.handle_event_burst(Event::Key(key(KeyCode::Char('a'))), || {
+            asked += 1;
+            Ok(Some(Event::Key(key(KeyCode::Char('a')))))
+        })
+        .unwrap();
+
+        assert_eq!(asked, super::super::super::MAX_EVENTS_PER_FRAME - 1);
+    }
+
+    #[test]
+    fn test_burst_read_error_is_returned_after_the_last_query() {
+        let mut app = insert_mode_app(r#"{"name": "Alice"}"#);
+
+        let result = app.handle_event_burst(Event::Paste(".name".to_string()), || {
+            Err(io::Error::other("terminal gone"))
+        });
+
+        assert!(result.is_err());
+        assert!(!app.batching_events);
+        assert!(wait_for_query_completion(&mut app, 2000));
+    }
+}
@@ -1,16 +1,30 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout},
+    layout::{Constraint, Layout, Rect},
     style::Style,
     widgets::Block,
 };
 
+use std::time::Instant;
+
 use super::app_state::App;
 use crate::notification::render_notification;
 use crate::theme;
 
 impl App {
     pub fn render(&mut self, frame: &mut Frame) {
+        let start = Instant::now();
+        self.render_screen(frame);
+        if self.accessibility.screen_reader {
+            crate::accessibility::plain_borders::strip(frame.buffer_mut());
+        }
+        if crate::theme::monochrome::is_enabled() {
+            crate::theme::monochrome::strip_colors(frame.buffer_mut());
+        }
+        self.frame_timings.record_frame(start.elapsed());
+    }
+
+    fn render_screen(&mut self, frame: &mut Frame) {
         self.frame_count = self.frame_count.wrapping_add(1);
         self.layout_regions.clear();
 
@@ -38,12 +52,32 @@ impl App {
             return;
         }
 
+        if !super::too_small_render::fits(frame.area()) {
+            super::too_small_render::render(frame, frame.area());
+            return;
+        }
+
+        // Once a second tab exists the tab bar takes the top row and
+        // everything below renders in what is left.
+        let area = if self.tabs.has_multiple() {
+            let layout =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(frame.area());
+            super::tabs_render::render_bar(
+                &self.tab_labels(),
+                self.tabs.active(),
+                frame,
+                layout[0],
+            );
+            layout[1]
+        } else {
+            frame.area()
+        };
+
         // Paste-recovery short-circuits the normal layout entirely.
         if self.paste_recovery.is_some() {
             // We render the live `app.input.textarea` here so all
             // existing VIM key handlers (which mutate that textarea)
             // are visible to the user without any duplicated state.
-            let area = frame.area();
             let editor_mode = self.input.editor_mode;
             super::paste_recovery_render::render(
                 self.paste_recovery.as_ref().unwrap(),
@@ -62,11 +96,50 @@ impl App {
             return;
         }
 
+        let banner = self.query.as_ref().and_then(|query| {
+            super::large_input_render::banner_line(&self.large_input, query.executor.json_input())
+        });
+        let area = match banner {
+            Some(banner) => {
+                let layout =
+                    Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+                super::large_input_render::render(banner, frame, layout[0]);
+                layout[1]
+            }
+            None => area,
+        };
+
+        let cut = self.query.as_ref().and_then(|query| {
+            super::output_limit_render::banner_line(
+                query.truncated_query.is_some(),
+                query.executor.jq_args().output_limit(),
+            )
+        });
+        let area = match cut {
+            Some(banner) => {
+                let layout =
+                    Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+                super::output_limit_render::render(banner, frame, layout[0]);
+                layout[1]
+            }
+            None => area,
+        };
+
+        let queries: Vec<&str> = self.root_stack.queries().collect();
+        let area = match super::breadcrumbs_render::bar_line(&queries, area.width) {
+            Some(bar) => {
+                let layout =
+                    Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+                super::breadcrumbs_render::render(bar, frame, layout[0]);
+                layout[1]
+            }
+            None => area,
+        };
+
         let overlay_visible = self.search.is_visible() || self.snippets.is_visible();
 
         let (results_area, input_area, help_area) = if overlay_visible {
-            let layout =
-                Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).split(frame.area());
+            let layout = Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).split(area);
             (layout[0], None, layout[1])
         } else {
             let layout = Layout::vertical([
@@ -74,12 +147,30 @@ impl App {
                 Constraint::Length(3),
                 Constraint::Length(1),
             ])
-            .split(frame.area());
+            .split(area);
             (layout[0], Some(layout[1]), layout[2])
         };
 
+        // Split view halves the results and query rows; the live pane
+        // gets its half and the parked pane is drawn into the other.
+        let (pane_results_area, parked_results_area, input_area, parked_input_area) =
+            match input_area {
+                Some(input_area) if self.split.is_active() && self.accessibility.linear_layout => {
+                    self.split_rows(results_area, input_area)
+                }
+                Some(area) => {
+                    let (live, parked) = self.split_columns(results_area);
+                    let (live_input, parked_input) = self.split_columns(area);
+                    (live, parked, Some(live_input), parked_input)
+                }
+                None => {
+                    let (live, parked) = self.split_columns(results_area);
+                    (live, parked, None, None)
+                }
+            };
+
         let (results_rect, search_rect) =
-            crate::results::results_render::render_pane(self, frame, results_area);
+            crate::results::results_render::render_pane(self, frame, pane_results_area);
         self.layout_regions.results_pane = Some(results_rect);
         if let Some(search_rect) = search_rect {
             self.layout_regions.search_bar = Some(search_rect);
@@ -90,7 +181,20 @@ impl App {
             self.layout_regions.input_field = Some(input_rect);
         }
 
-        crate::help::help_line_render::render_line(self, frame, help_area);
+        if let Some(parked_results_area) = parked_results_area {
+            self.with_parked_pane(|app| {
+                crate::results::results_render::render_pane(app, frame, parked_results_area);
+                if let Some(parked_input_area) = parked_input_area {
+                    crate::input::input_render::render_field(app, frame, parked_input_area);
+                }
+            });
+        }
+
+        if self.command_line.is_visible() {
+            crate::command_line::command_line_render::render_line(self, frame, help_area);
+        } else {
+            crate::help::help_line_render::render_line(self, frame, help_area);
+        }
 
         if let Some(input_area) = input_area {
             if self.ai.visible
@@ -99,6 +203,11 @@ impl App {
                     crate::ai::ai_render::render_popup(&mut self.ai, frame, input_area)
             {
                 self.layout_regions.ai_window = Some(ai_rect);
+            } else if self.tooltip.should_show_detail()
+                && let Some(tooltip_rect) =
+                    crate::tooltip::tooltip_render::render_detail(self, frame, input_area)
+            {
+                self.layout_regions.tooltip = Some(tooltip_rect);
             } else if self.tooltip.should_show()
                 && let Some(tooltip_rect) =
                     crate::tooltip::tooltip_render::render_popup(self, frame, input_area)
@@ -106,6 +215,9 @@ impl App {
                 self.layout_regions.tooltip = Some(tooltip_rect);
             }
 
+            // Rows above the input, less the popup's borders
+            self.autocomplete
+                .fit_to(input_area.y.saturating_sub(2) as usize);
             if self.autocomplete.is_visible()
                 && let Some(autocomplete_rect) =
                     crate::autocomplete::autocomplete_render::render_popup(self, frame, input_area)
@@ -113,12 +225,32 @@ impl App {
                 self.layout_regions.autocomplete = Some(autocomplete_rect);
             }
 
+            crate::editor::which_key_render::render_popup(self, frame, input_area);
+
             if self.history.is_visible()
                 && let Some(history_rect) =
                     crate::history::history_render::render_popup(self, frame, input_area)
             {
                 self.layout_regions.history_popup = Some(history_rect);
             }
+
+            if self.ai.nl_prompt.is_visible() {
+                crate::ai::nl_prompt::nl_prompt_render::render_nl_prompt(
+                    frame,
+                    input_area,
+                    &mut self.ai.nl_prompt,
+                );
+            }
+
+            if self.ai.followup.is_visible()
+                && let Some(ai_rect) = self.layout_regions.ai_window
+            {
+                crate::ai::followup::followup_render::render_followup(
+                    frame,
+                    ai_rect,
+                    &mut self.ai.followup,
+                );
+            }
         }
 
         if self.snippets.is_visible() {
@@ -137,7 +269,7 @@ impl App {
 
         if self.error_overlay_visible
             && let Some(query) = &self.query
-            && query.result.is_err()
+            && (query.result.is_err() || query.field_hint.is_some())
             && let Some(error_rect) = crate::results::error_overlay_render::render_error_overlay(
                 self,
                 frame,
@@ -157,6 +289,115 @@ impl App {
             crate::save::save_render::render_save_popup(frame, results_area, &mut self.save);
         }
 
+        if let Some(files) = self.input_files.as_ref().filter(|f| f.is_visible()) {
+            super::input_files_render::render_popup(files, frame, results_area);
+        }
+
+        if self.results_chart.is_visible() {
+            crate::results::chart_render::render_popup(&self.results_chart, frame, results_area);
+        }
+
+        if self.results_frequency.is_visible() {
+            crate::results::frequency_render::render_popup(
+                &self.results_frequency,
+                frame,
+                results_area,
+            );
+        }
+
+        if self.results_stream.is_visible() {
+            crate::results::stream_view_render::render_popup(
+                &mut self.results_stream,
+                frame,
+                results_area,
+            );
+        }
+
+        if self.results_decode.is_visible() {
+            crate::results::decode_render::render_popup(
+                &mut self.results_decode,
+                frame,
+                results_area,
+            );
+        }
+
+        if self.format_menu_visible
+            && let Some(query_state) = &self.query
+        {
+            crate::results::format_menu_render::render_popup(
+                query_state.output_format(),
+                frame,
+                results_area,
+            );
+        }
+
+        if self.flags_panel.is_visible() {
+            crate::flags_panel::render_popup(self, frame, results_area);
+        }
+
+        if self.presets.is_visible() {
+            crate::presets::render_popup(self, frame, results_area);
+        }
+
+        if self.params.is_visible() {
+            crate::params::render_popup(self, frame, results_area);
+        }
+
+        if self.path_picker.is_visible() {
+            crate::path_picker::render_popup(self, frame, results_area);
+        }
+
+        if self.notification.center_visible {
+            crate::notification::notification_center_render::render_center(
+                frame,
+                &mut self.notification,
+            );
+        }
+
+        if self.log_viewer.is_visible() {
+            crate::debug_log::render_popup(&self.log_viewer, frame, frame.area());
+        }
+        if self.frame_timings.is_visible() {
+            crate::debug_log::render_overlay(&self.frame_timings, frame, frame.area());
+        }
+
         render_notification(frame, &mut self.notification);
     }
+
+    /// Split `area` into (live, parked) halves while split view is open,
+    /// side by side or, in the linear layout, one above the other
+    fn split_columns(&self, area: Rect) -> (Rect, Option<Rect>) {
+        if !self.split.is_active() {
+            return (area, None);
+        }
+        let halves = if self.accessibility.linear_layout {
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).split(area)
+        } else {
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(area)
+        };
+        if self.split.right_live() {
+            (halves[1], Some(halves[0]))
+        } else {
+            (halves[0], Some(halves[1]))
+        }
+    }
+
+    /// Split view in the linear layout: each pane keeps its query under
+    /// its results, and the two stack with the right-hand pane below.
+    /// Returns the live results and query areas and the parked ones.
+    fn split_rows(
+        &self,
+        results_area: Rect,
+        input_area: Rect,
+    ) -> (Rect, Option<Rect>, Option<Rect>, Option<Rect>) {
+        let body = results_area.union(input_area);
+        let (live, parked) = self.split_columns(body);
+        let pane = |area: Rect| {
+            let rows = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(area);
+            (rows[0], rows[1])
+        };
+        let (live_results, live_input) = pane(live);
+        let (parked_results, parked_input) = parked.map(pane).unzip();
+        (live_results, parked_results, Some(live_input), parked_input)
+    }
 }
@@ -38,6 +38,71 @@ fn snapshot_history_popup_with_search() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn snapshot_history_popup_with_ai_marked_entry() {
+    let json = r#"{"test": true}"#;
+    let mut app = test_app(json);
+
+    app.history = HistoryState::empty();
+    app.history.add_entry_in_memory(".name");
+    app.history.add_ai_entry(".users[] | .email");
+    app.history.open(None);
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_history_popup_with_pinned_entry() {
+    let json = r#"{"test": true}"#;
+    let mut app = test_app(json);
+
+    app.history = HistoryState::empty();
+    app.history.add_entry_in_memory(".name");
+    app.history.add_ai_entry(".users[] | .email");
+    app.history.open(None);
+    app.history.toggle_pin_selected();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_history_popup_with_marked_entries() {
+    let json = r#"{"test": true}"#;
+    let mut app = test_app(json);
+
+    app.history = HistoryState::empty();
+    app.history.add_entry_in_memory(".name");
+    app.history.add_entry_in_memory("select(.active)");
+    app.history.add_entry_in_memory(".users[]");
+    app.history.open(None);
+    app.history.toggle_mark_selected();
+    app.history.select_next();
+    app.history.select_next();
+    app.history.toggle_mark_selected();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_history_popup_with_preview() {
+    let json = r#"{"test": true}"#;
+    let mut app = test_app(json);
+
+    app.history = HistoryState::empty();
+    app.history.add_entry_in_memory(".name");
+    app.history.add_entry_in_memory(".test");
+    app.history.open(None);
+    app.history_preview.insert(".test", "true\n");
+    app.history_preview
+        .set_query(Some(".test".to_string()), std::time::Instant::now());
+
+    let output = render_to_string(&mut app, 110, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn snapshot_history_popup_no_matches() {
     let json = r#"{"test": true}"#;
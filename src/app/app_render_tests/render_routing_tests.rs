@@ -38,6 +38,8 @@ fn app_in_recovery() -> App {
     let loader = FileLoader {
         state: LoadingState::Error(crate::error::JiqError::Io("err".to_string())),
         rx: Some(rx),
+        files_rx: None,
+        malformed_rx: None,
         source: LoaderSource::Clipboard,
     };
     let mut app = App::new_with_loader(loader, &Config::default());
@@ -1,23 +1,67 @@
+use crate::accessibility::AccessibilityState;
 use crate::ai::AiState;
 use crate::autocomplete::autocomplete_state::ValueMemo;
+use crate::autocomplete::completion_usage::CompletionUsage;
+use crate::autocomplete::json_navigator::NavigationCache;
+use crate::autocomplete::suggestion_preview::SuggestionPreview;
 use crate::autocomplete::{self, AutocompleteState};
-use crate::config::{ClipboardBackend, Config};
+use crate::command_line::CommandLineState;
+use crate::config::{ClipboardBackend, Config, HooksConfig, LargeInputConfig};
+use crate::debug_log::{FrameTimings, LogViewer};
+use crate::editor::macros::Macros;
+use crate::editor::which_key::WhichKey;
+use crate::flags_panel::FlagsPanel;
 use crate::help::HelpPopupState;
 use crate::history::HistoryState;
+use crate::history::history_preview::HistoryPreview;
 use crate::input::loader::LoaderSource;
-use crate::input::{FileLoader, InputState, PasteRecoveryState, SourcePickerState};
+use crate::input::malformed::MalformedInput;
+use crate::input::{
+    FileLoader, InputFilesState, InputState, InputText, PasteRecoveryState, SourcePickerState,
+};
+use crate::keyboard::KeyboardSupport;
 use crate::layout::LayoutRegions;
-use crate::notification::NotificationState;
+use crate::notification::{NotificationState, OnboardingTour};
+use crate::params::ParamsPanel;
 use crate::path_at_cursor::PathAtCursorCache;
+use crate::path_picker::PathPicker;
+use crate::plugins::PluginRegistry;
+use crate::presets::PresetMenu;
+use crate::progress::{Operation, ProgressTracker};
+use crate::query::output_format::OutputFormat;
 use crate::query::{Debouncer, QueryState};
 use crate::query_undo::{QueryUndoRing, ViewportState};
+use crate::results::chart::ChartPopup;
 use crate::results::cursor_state::CursorState;
+use crate::results::decode::DecodePopup;
+use crate::results::fold_state::FoldState;
+use crate::results::frequency::FrequencyPopup;
+use crate::results::last_stage::LastStage;
+use crate::results::line_filter::LineFilterState;
+use crate::results::marks::ResultMarks;
+use crate::results::match_highlights::MatchHighlights;
+use crate::results::path_view::PathView;
+use crate::results::result_diff::DiffHighlight;
+use crate::results::result_pins::ResultPins;
+use crate::results::stream_view::StreamPopup;
+use crate::results::table_view::TableView;
+use crate::root_stack::RootStack;
 use crate::save::SaveState;
+use crate::save::save_stream::SaveStream;
+use crate::scratch::Scratch;
 use crate::scroll::ScrollState;
 use crate::search::SearchState;
 use crate::snippets::SnippetState;
 use crate::stats::{self, StatsState};
 use crate::tooltip::{self, TooltipState};
+use std::time::Duration;
+
+/// Longest wait for an event while nothing animates
+const IDLE_POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Wait for an event while a spinner, the AI reply or a fading highlight
+/// animates; about 30 frames a second
+const ANIMATION_FRAME: Duration = Duration::from_millis(33);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
@@ -33,26 +77,124 @@ pub enum OutputMode {
 
 pub struct App {
     pub input: InputState,
+    /// Hints for the keys that may follow a pending Normal-mode prefix
+    pub which_key: WhichKey,
+    /// The `:` prompt shown in place of the help line
+    pub command_line: CommandLineState,
+    /// Keystroke macros recorded with `q` and played with `@`
+    pub macros: Macros,
     pub query: Option<QueryState>,
     pub file_loader: Option<FileLoader>,
     pub paste_recovery: Option<PasteRecoveryState>,
     pub source_picker: Option<SourcePickerState>,
+    /// Per-file state when several input files were given
+    pub input_files: Option<InputFilesState>,
+    /// Files named on the command line; empty for stdin, clipboard and paste
+    pub input_paths: Vec<std::path::PathBuf>,
     pub focus: Focus,
     pub results_scroll: ScrollState,
     pub results_cursor: CursorState,
+    /// Indentation folding of the results pane
+    pub results_folds: FoldState,
+    /// Lines changed by the last query edit, flashed briefly
+    pub results_diff: DiffHighlight,
+    /// Result lines pinned by jq path
+    pub results_pins: ResultPins,
+    pub results_marks: ResultMarks,
+    /// `:match` patterns, shared by every tab
+    pub results_matches: MatchHighlights,
+    /// grep/sort/uniq view of the result opened with `|`
+    pub results_filter: LineFilterState,
+    /// Column view of arrays of objects, toggled with `T`
+    pub results_table: TableView,
+    /// gron-style `path = value` lines of the result, toggled with `=`
+    pub results_paths: PathView,
+    /// Histogram or sparkline of a numeric result, opened with `#`
+    pub results_chart: ChartPopup,
+    /// Most frequent values or key sets of the result, opened with `c`
+    pub results_frequency: FrequencyPopup,
+    pub results_stream: StreamPopup,
+    /// What the encoded string at the cursor holds, opened with `E`
+    pub results_decode: DecodePopup,
+    /// Dims lines the query's last stage left unchanged, toggled with `s`
+    pub results_last_stage: LastStage,
+    /// Keeps the last line in view as output arrives, toggled with `F`
+    pub results_follow: bool,
+    /// Indent, sort-keys and ASCII settings for jq output, opened with `o`
+    pub format_menu_visible: bool,
+    /// jq output flags each new query state starts with: `[output]` in the
+    /// config, then whatever `o` or F4 last set
+    pub output_format: OutputFormat,
+    /// `-e`: exit on Enter with jq's `--exit-status` code for the result
+    pub exit_status: bool,
+    /// jq flag and jiq option checkboxes, opened with F4
+    pub flags_panel: FlagsPanel,
+    /// Curated queries for the detected document shape, opened with F5
+    pub presets: PresetMenu,
+    /// Values for the query's `$parameters`, opened with F6
+    pub params: ParamsPanel,
+    /// Walk through the input to pick a path, opened with F7
+    pub path_picker: PathPicker,
+    /// `jiq-plugin-*` executables, asked what they offer on first use
+    pub plugins: PluginRegistry,
+    /// The plugin `:post` chose to rewrite the output printed on exit
+    pub post_processor: Option<String>,
+    /// `[hooks]` commands run on load and on exit
+    pub hooks: HooksConfig,
+    /// Tail of the debug log, opened with F12
+    pub log_viewer: LogViewer,
+    pub frame_timings: FrameTimings,
+    /// Set while a burst of waiting events is handled before one frame
+    pub(crate) batching_events: bool,
+    /// A query asked for during the burst, run once it's over
+    pub(crate) batched_query: bool,
+    /// JSON pasted on the main screen, waiting for Enter to open it in a
+    /// new tab
+    pub paste_offer: Option<String>,
+    /// The terminal was resized: scroll the results cursor back into view
+    /// once the pane has its new height
+    pub reveal_results_cursor: bool,
+    /// A file or stdin that isn't valid JSON, shown in the results pane
+    /// until it is loaded another way
+    pub malformed_input: Option<MalformedInput>,
+    /// `:edit` wrote the input to this file for the main loop to open in
+    /// the editor
+    pub pending_input_edit: Option<std::path::PathBuf>,
+    /// An edited file that didn't parse, reopened by the next `:edit`
+    pub input_edit_draft: Option<std::path::PathBuf>,
+    /// Which modifier keys the terminal reports, for the help popup
+    pub keyboard: KeyboardSupport,
+    /// Screen reader mode and its status line
+    pub accessibility: AccessibilityState,
     pub output_mode: Option<OutputMode>,
     pub should_quit: bool,
     pub autocomplete: AutocompleteState,
+    /// Acceptance counts that order field and function suggestions
+    pub completion_usage: CompletionUsage,
+    /// What the selected suggestion would return
+    pub suggestion_preview: SuggestionPreview,
     pub(crate) value_memo: ValueMemo,
+    /// Paths autocomplete navigated in the input, reused while the cursor
+    /// moves around the query
+    pub(crate) navigation_cache: NavigationCache,
     pub error_overlay_visible: bool,
     pub history: HistoryState,
+    pub history_preview: HistoryPreview,
     pub help: HelpPopupState,
     pub notification: NotificationState,
+    /// Start times of running queries, file loads and AI requests
+    pub progress: ProgressTracker,
+    /// First-run tips, shown as hint notifications
+    pub onboarding: OnboardingTour,
     pub clipboard_backend: ClipboardBackend,
     pub tooltip: TooltipState,
     pub stats: StatsState,
     pub path_at_cursor: PathAtCursorCache,
     pub query_undo: QueryUndoRing,
+    /// Documents replaced by re-rooting at a result, for going back
+    pub root_stack: RootStack,
+    /// `"y` / `"p`: text kept across sessions
+    pub scratch: Scratch,
     /// Viewport state captured by `<` (drill-back) that must be applied
     /// once the worker delivers the restored query's result. The restore
     /// can't run synchronously because the freshly-rewritten input still
@@ -63,6 +205,8 @@ pub struct App {
     pub search: SearchState,
     pub snippets: SnippetState,
     pub save: SaveState,
+    /// `:w` of a result cut at the output limit, writing in the background
+    pub save_stream: SaveStream,
     pub ai: AiState,
     pub saved_tooltip_visibility: bool,
     pub saved_ai_visibility_for_search: bool,
@@ -75,10 +219,29 @@ pub struct App {
     pub needs_render: bool,
     pub layout_regions: LayoutRegions,
     pub array_sample_size: usize,
+    /// Which elements of long arrays the field index looks at
+    pub array_sampling: crate::config::ArraySampling,
+    /// A suggestion or tooltip was left out because the input analysis
+    /// wasn't ready; `tick` fills them in once it is
+    pub input_analysis_pending: bool,
+    /// When an input is large and what stays on for it
+    pub large_input: LargeInputConfig,
+    /// Offer `.a.b[].c` and `.a.b | map(.c)` while typing an array's name
+    pub tunnel_arrays: bool,
+    /// Open the autocomplete popup while typing, not only on Ctrl+Space
+    pub autocomplete_auto_show: bool,
+    /// `--rawfile` / `--slurpfile` bindings passed to every jq run
+    pub jq_args: std::sync::Arc<crate::query::JqArgs>,
     pub double_click: super::double_click::DoubleClickTracker,
+    /// Inactive tab workspaces; the active tab's state is the fields above
+    pub tabs: super::tabs::TabsState,
+    /// Second query pane over the same document, when split view is open
+    pub split: super::split::SplitState,
     /// Whether the mouse is currently hovering the clickable Back badge on
     /// the results-pane top border. Drives the badge's hover styling.
     pub back_button_hovered: bool,
+    /// The pane or popup whose scrollbar is being dragged
+    pub scrollbar_drag: Option<crate::layout::Region>,
 }
 
 impl App {
@@ -109,67 +272,7 @@ impl App {
         source_picker: Option<SourcePickerState>,
         config: &Config,
     ) -> Self {
-        let anthropic_configured =
-            config.ai.anthropic.api_key.is_some() && config.ai.anthropic.model.is_some();
-        let bedrock_configured =
-            config.ai.bedrock.region.is_some() && config.ai.bedrock.model.is_some();
-        let openai_configured =
-            config.ai.openai.api_key.is_some() && config.ai.openai.model.is_some();
-        let gemini_configured =
-            config.ai.gemini.api_key.is_some() && config.ai.gemini.model.is_some();
-
-        let provider_name = match config.ai.provider {
-            Some(crate::config::ai_types::AiProviderType::Anthropic) => "Anthropic",
-            Some(crate::config::ai_types::AiProviderType::Bedrock) => "Bedrock",
-            Some(crate::config::ai_types::AiProviderType::Openai) => {
-                // Check if using custom OpenAI-compatible endpoint
-                let is_custom = config
-                    .ai
-                    .openai
-                    .base_url
-                    .as_ref()
-                    .map(|url| !url.contains("api.openai.com"))
-                    .unwrap_or(false);
-                if is_custom {
-                    "OpenAI-compatible"
-                } else {
-                    "OpenAI"
-                }
-            }
-            Some(crate::config::ai_types::AiProviderType::Gemini) => "Gemini",
-            None => "Not Configured",
-        }
-        .to_string();
-
-        let ai_configured = config.ai.provider.is_some()
-            && (anthropic_configured
-                || bedrock_configured
-                || openai_configured
-                || gemini_configured);
-
-        let model_name = match config.ai.provider {
-            Some(crate::config::ai_types::AiProviderType::Anthropic) => {
-                config.ai.anthropic.model.clone().unwrap_or_default()
-            }
-            Some(crate::config::ai_types::AiProviderType::Bedrock) => {
-                config.ai.bedrock.model.clone().unwrap_or_default()
-            }
-            Some(crate::config::ai_types::AiProviderType::Openai) => {
-                config.ai.openai.model.clone().unwrap_or_default()
-            }
-            Some(crate::config::ai_types::AiProviderType::Gemini) => {
-                config.ai.gemini.model.clone().unwrap_or_default()
-            }
-            None => String::new(),
-        };
-
-        let ai_state = AiState::new_with_config(
-            config.ai.enabled,
-            ai_configured,
-            provider_name,
-            model_name,
-            config.ai.max_context_length as usize,
-        );
+        let ai_state = AiState::from_config(config);
 
         let tooltip_enabled = if ai_state.visible {
             false
@@ -179,31 +282,80 @@ impl App {
 
         Self {
             input: InputState::new(),
+            which_key: WhichKey::new(),
+            command_line: CommandLineState::new(),
+            macros: Macros::new(),
             query: None,
             file_loader: loader,
             source_picker,
+            input_files: None,
+            input_paths: Vec::new(),
             paste_recovery,
             focus: Focus::InputField,
             results_scroll: ScrollState::new(),
             results_cursor: CursorState::new(),
+            results_folds: FoldState::new(),
+            results_diff: DiffHighlight::new(config.results.highlight_changes),
+            results_pins: ResultPins::new(),
+            results_marks: ResultMarks::new(),
+            results_matches: MatchHighlights::new(),
+            results_follow: false,
+            results_filter: LineFilterState::new(),
+            results_table: TableView::new(),
+            results_paths: PathView::new(),
+            results_chart: ChartPopup::new(),
+            results_frequency: FrequencyPopup::new(),
+            results_stream: StreamPopup::new(),
+            results_decode: DecodePopup::new(),
+            results_last_stage: LastStage::new(),
+            format_menu_visible: false,
+            output_format: OutputFormat::from_config(&config.output),
+            exit_status: config.output.exit_status,
+            flags_panel: FlagsPanel::new(),
+            presets: PresetMenu::new(),
+            params: ParamsPanel::new(),
+            path_picker: PathPicker::new(),
+            plugins: PluginRegistry::new(),
+            post_processor: None,
+            hooks: config.hooks.clone(),
+            log_viewer: LogViewer::new(),
+            frame_timings: FrameTimings::new(),
+            batching_events: false,
+            batched_query: false,
+            paste_offer: None,
+            reveal_results_cursor: false,
+            malformed_input: None,
+            pending_input_edit: None,
+            input_edit_draft: None,
+            keyboard: KeyboardSupport::default(),
+            accessibility: AccessibilityState::new(&config.accessibility),
             output_mode: None,
             should_quit: false,
             autocomplete: AutocompleteState::new(),
+            completion_usage: CompletionUsage::new(config.autocomplete.order),
+            suggestion_preview: SuggestionPreview::new(config.autocomplete.preview),
             value_memo: ValueMemo::new(),
+            navigation_cache: NavigationCache::new(),
             error_overlay_visible: false,
-            history: HistoryState::new(),
+            history: HistoryState::new(&config.history),
+            history_preview: HistoryPreview::new(config.history.preview),
             help: HelpPopupState::new(),
             notification: NotificationState::new(),
+            progress: ProgressTracker::new(),
+            onboarding: OnboardingTour::new(config.onboarding.enabled),
             clipboard_backend: config.clipboard.backend,
             tooltip: TooltipState::new(tooltip_enabled),
             stats: StatsState::default(),
             path_at_cursor: PathAtCursorCache::new(),
             query_undo: QueryUndoRing::new(),
+            root_stack: RootStack::new(),
+            scratch: Scratch::new(),
             pending_viewport_restore: None,
             debouncer: Debouncer::new(),
             search: SearchState::new(),
             snippets: SnippetState::new(),
             save: SaveState::new(),
+            save_stream: SaveStream::new(),
             ai: ai_state,
             saved_tooltip_visibility: config.tooltip.auto_show,
             saved_ai_visibility_for_search: false,
@@ -216,8 +368,17 @@ impl App {
             needs_render: true,
             layout_regions: LayoutRegions::new(),
             array_sample_size: config.autocomplete.array_sample_size,
+            array_sampling: config.autocomplete.array_sampling,
+            input_analysis_pending: false,
+            large_input: config.large_input.clone(),
+            tunnel_arrays: config.autocomplete.tunnel_arrays,
+            autocomplete_auto_show: config.autocomplete.auto_show,
+            jq_args: std::sync::Arc::default(),
             double_click: super::double_click::DoubleClickTracker::new(),
+            tabs: super::tabs::TabsState::new(),
+            split: super::split::SplitState::new(),
             back_button_hovered: false,
+            scrollbar_drag: None,
         }
     }
 
@@ -269,7 +430,18 @@ impl App {
             self.mark_dirty();
             match result {
                 Ok(json_input) => {
+                    let files = self.file_loader.as_mut().and_then(FileLoader::take_files);
                     self.initialize_from_json(json_input);
+                    // A file opened with `:open` keeps the query typed so far
+                    if !self.query().is_empty() {
+                        crate::editor::editor_events::execute_query(self);
+                    }
+                    if let Some(files) = files {
+                        self.notification
+                            .show(&format!("Loaded {} files · Ctrl+L to choose", files.len()));
+                        self.input_files = Some(InputFilesState::new(files));
+                    }
+                    crate::hooks::on_load(self);
                 }
                 Err(e) => {
                     log::error!("File loader error: {:?}", e);
@@ -287,9 +459,18 @@ impl App {
                         self.paste_recovery = Some(PasteRecoveryState::new(original));
                         self.file_loader = None;
                     } else {
-                        // File / stdin source: existing behavior unchanged.
-                        // Keep loader for state tracking; full details in
-                        // results area.
+                        // File / stdin source: keep loader for state
+                        // tracking; full details in results area, along
+                        // with the text when it didn't parse.
+                        self.malformed_input = self
+                            .file_loader
+                            .as_mut()
+                            .and_then(FileLoader::take_malformed);
+                        if let Some(malformed) = &self.malformed_input {
+                            // Open where parsing stopped
+                            self.results_scroll.offset =
+                                malformed.line.saturating_sub(4).min(u16::MAX as usize) as u16;
+                        }
                         self.notification.show_error("Failed to load file");
                     }
                 }
@@ -300,21 +481,22 @@ impl App {
     /// Build the initial QueryState (and dependent caches) from a JSON
     /// input string. Shared by the loader-success path and the
     /// paste-recovery acceptance path.
-    fn initialize_from_json(&mut self, json_input: String) {
+    pub(super) fn initialize_from_json(&mut self, json_input: impl Into<InputText>) {
+        let json_input = json_input.into();
         log::debug!("Initialising from JSON: {} bytes", json_input.len());
-        let ai_active = self.ai.enabled && self.ai.configured;
-        self.query = Some(QueryState::new_with_sample_size(
-            json_input.clone(),
-            self.array_sample_size,
-            ai_active,
-        ));
-
-        let schema_input = crate::json::extract_first_json_value(&json_input)
-            .unwrap_or_else(|| json_input.clone());
-
-        self.input_json_schema = crate::json::extract_json_schema_dynamic(&schema_input).map(|s| {
-            crate::ai::context::prepare_schema_for_context(&s, self.ai.max_context_length)
-        });
+        self.query = Some(self.query_state_for(json_input.clone()));
+        // The last stage's base output ran on the previous document
+        self.results_last_stage.invalidate();
+
+        // The schema only feeds the AI context
+        self.input_json_schema = if self.large_input.ai_context_for(json_input.len()) {
+            let first = crate::json::extract_first_json_value(&json_input);
+            crate::json::extract_json_schema_dynamic(first.as_deref().unwrap_or(&json_input)).map(
+                |s| crate::ai::context::prepare_schema_for_context(&s, self.ai.max_context_length),
+            )
+        } else {
+            None
+        };
 
         self.update_stats();
         self.file_loader = None;
@@ -324,12 +506,74 @@ impl App {
         }
     }
 
+    /// A QueryState over `json_input`, with the input analysis started in
+    /// the background. Inputs past `[large_input] threshold_mb` go without
+    /// the input analysis and the AI context unless the config turns them
+    /// back on. With `-n` or `-R`, `.` is `null` or a line of text rather
+    /// than the input, so field suggestions don't come from the input
+    /// either.
+    pub(super) fn query_state_for(&self, json_input: InputText) -> QueryState {
+        let bytes = json_input.len();
+        let mut query = QueryState::new_with_sample_size(
+            json_input,
+            self.array_sample_size,
+            self.ai.enabled && self.ai.configured && self.large_input.ai_context_for(bytes),
+            std::sync::Arc::clone(&self.jq_args),
+        );
+        query.executor.set_input_analysis(
+            !self.jq_args.null_input()
+                && !self.jq_args.raw_input()
+                && !self.jq_args.stream()
+                && self.large_input.analysis_for(bytes),
+        );
+        query.executor.set_array_sampling(self.array_sampling);
+        query.executor.analyze_in_background();
+        query.set_output_format(self.output_format);
+        query
+    }
+
+    /// Rebuild the input from the files currently included in the picker
+    /// and re-run the query against it
+    pub fn reload_included_files(&mut self) {
+        let Some(json_input) = self
+            .input_files
+            .as_ref()
+            .map(InputFilesState::included_json)
+        else {
+            return;
+        };
+        self.initialize_from_json(json_input);
+        crate::editor::editor_events::execute_query(self);
+        self.mark_dirty();
+    }
+
+    /// Turn jq's `--raw-input` on or off and re-run the query against the
+    /// same input
+    pub fn set_raw_input(&mut self, raw_input: bool) {
+        self.update_jq_args(|jq_args| jq_args.set_raw_input(raw_input));
+    }
+
+    /// Change the flags and bindings passed to jq and re-run the query
+    /// against the same input
+    pub fn update_jq_args(&mut self, update: impl FnOnce(&mut crate::query::JqArgs)) {
+        let mut jq_args = (*self.jq_args).clone();
+        update(&mut jq_args);
+        self.jq_args = std::sync::Arc::new(jq_args);
+        let Some(json_input) = self.query.as_ref().map(|q| q.executor.json_input().clone()) else {
+            return;
+        };
+        self.initialize_from_json(json_input);
+        crate::editor::editor_events::execute_query(self);
+        self.mark_dirty();
+    }
+
     /// Accept a JSON string from the paste-recovery flow and continue as
     /// if the JSON had been loaded normally.
     pub fn accept_paste_recovery_json(&mut self, json_input: String) {
         let bytes = json_input.len();
         self.initialize_from_json(json_input);
         self.paste_recovery = None;
+        self.input_paths.clear();
         self.notification.show(&format!(
             "Loaded {} bytes — type a query, Enter outputs result",
             bytes
@@ -349,14 +593,51 @@ impl App {
         self.input.query()
     }
 
+    /// Files the current document was read from, for exporting
+    ///
+    /// With several input files only the included ones count.
+    pub fn source_paths(&self) -> Vec<std::path::PathBuf> {
+        match &self.input_files {
+            Some(files) => files
+                .files()
+                .iter()
+                .filter(|f| f.included)
+                .map(|f| f.path.clone())
+                .collect(),
+            None => self.input_paths.clone(),
+        }
+    }
+
     pub fn results_line_count_u32(&self) -> u32 {
         self.query.as_ref().map_or(0, |q| q.line_count())
     }
 
+    /// Scroll the results pane so the cursor's row stays in view, going
+    /// through the folds when the output is folded
+    pub fn ensure_results_cursor_visible(&mut self) {
+        let row = self.results_folds.row_of(self.results_cursor.cursor_line());
+        self.results_scroll.ensure_cursor_visible(row);
+    }
+
+    /// Refresh the suggestions as the query changes. With `auto_show`
+    /// off, only a popup opened with Ctrl+Space follows the typing.
     pub fn update_autocomplete(&mut self) {
+        if !self.autocomplete_auto_show && !self.autocomplete.is_visible() {
+            return;
+        }
         autocomplete::update_suggestions_from_app(self);
     }
 
+    /// Ctrl+Space: suggest for the cursor position whether or not the
+    /// popup would open by itself
+    pub fn open_autocomplete(&mut self) {
+        self.input.editor_mode = crate::editor::EditorMode::Insert;
+        autocomplete::update_suggestions_from_app(self);
+        if !self.autocomplete.is_visible() {
+            self.notification.show("No suggestions here");
+        }
+    }
+
     pub fn update_tooltip(&mut self) {
         tooltip::update_tooltip_from_app(self);
     }
@@ -391,6 +672,10 @@ impl App {
         if query_state.is_synthetic_merge {
             return None;
         }
+        // Rows of the path view are its lines, not the JSON's
+        if self.results_paths.is_active() {
+            return self.results_paths.path_at(row).cloned();
+        }
         let parsed = query_state.last_successful_result_parsed.as_ref()?;
         self.path_at_cursor.resolve(parsed, row)
     }
@@ -415,31 +700,37 @@ impl App {
 
         let query = self.input.query().to_string();
         let cursor_pos = self.input.textarea.cursor().1;
-
-        let ai_result: Result<String, String> = match &query_state.result {
-            Ok(_) => query_state
-                .last_successful_result_unformatted
-                .as_ref()
-                .map(|s| Ok(s.as_ref().clone()))
-                .unwrap_or_else(|| Ok(String::new())),
-            Err(e) => Err(e.clone()),
-        };
+        let ai_result = ai_execution_result(query_state);
 
         crate::ai::ai_events::handle_execution_result(
             &mut self.ai,
             &ai_result,
             &query,
             cursor_pos,
-            crate::ai::context::ContextParams {
-                input_schema: self.input_json_schema.as_deref(),
-                base_query: query_state.base_query_for_suggestions.as_deref(),
-                base_query_result: query_state
-                    .last_successful_result_for_context
-                    .as_deref()
-                    .map(|s| s.as_ref()),
-                is_empty_result: query_state.is_empty_result,
-            },
+            ai_context_params(query_state, self.input_json_schema.as_deref()),
+        );
+    }
+
+    /// Snapshot the current query, its result, and the input schema as AI
+    /// context. Used by requests that are not driven by a query change,
+    /// such as the Ctrl+G natural-language prompt.
+    pub fn current_ai_context(&self) -> Option<crate::ai::context::QueryContext> {
+        let query_state = self.query.as_ref()?;
+        let (output, error) = match ai_execution_result(query_state) {
+            Ok(output) => (Some(output), None),
+            Err(error) => (None, Some(error)),
+        };
+
+        let mut context = crate::ai::context::QueryContext::new(
+            self.input.query().to_string(),
+            self.input.textarea.cursor().1,
+            output,
+            error,
+            ai_context_params(query_state, self.input_json_schema.as_deref()),
+            self.ai.max_context_length,
         );
+        context.redact(&self.ai.redactor);
+        Some(context)
     }
 
     pub fn mark_dirty(&mut self) {
@@ -450,6 +741,102 @@ impl App {
         self.needs_render = false;
     }
 
+    /// Look the pinned paths up in the current result and warn about the
+    /// ones it no longer contains
+    pub fn relocate_pins(&mut self) {
+        if self.results_pins.is_empty() {
+            return;
+        }
+        let Some(query) = self.query.as_ref() else {
+            return;
+        };
+        if query.is_synthetic_merge {
+            return;
+        }
+        let Some(parsed) = query.last_successful_result_parsed.as_ref() else {
+            return;
+        };
+        let lost = self.results_pins.relocate(parsed);
+        match lost.as_slice() {
+            [] => {}
+            [path] => self
+                .notification
+                .show_warning(&format!("Pinned {} no longer present", path)),
+            paths => self
+                .notification
+                .show_warning(&format!("{} pinned paths no longer present", paths.len())),
+        }
+    }
+
+    /// Look for fixes the error overlay can offer: a mistyped field when
+    /// the result is null or an error about null, and optional access when
+    /// jq failed on some of the values the query iterates
+    pub fn update_query_fixes(&mut self) {
+        let Some(query) = self.query.as_mut() else {
+            return;
+        };
+        let nullish = match &query.result {
+            Ok(_) => query.is_empty_result,
+            Err(error) => error.contains("null"),
+        };
+        query.field_hint = if nullish && !query.executor.input_analysis_pending() {
+            let keys = query.executor.all_field_names();
+            crate::query::field_hint::unknown_field(self.input.query(), &keys)
+        } else {
+            None
+        };
+        query.optional_fix = match &query.result {
+            Err(error) => crate::query::optional_access::optional_access(self.input.query(), error),
+            Ok(_) => None,
+        };
+    }
+
+    /// Tell the progress tracker which long operations are running
+    pub fn update_progress(&mut self) {
+        let query_pending = self.query.as_ref().is_some_and(|q| q.is_pending());
+        let file_loading = self.file_loader.as_ref().is_some_and(|l| l.is_loading());
+        self.progress.update(Operation::Query, query_pending);
+        self.progress.update(Operation::FileLoad, file_loading);
+        self.progress.update(Operation::AiRequest, self.ai.loading);
+        self.progress
+            .update(Operation::SaveOutput, self.save_stream.is_running());
+        self.progress
+            .update(Operation::Frequency, self.results_frequency.is_counting());
+    }
+
+    /// Stop the operation shown in the status bar, if it can be stopped.
+    /// Returns false when nothing was cancelled.
+    pub fn cancel_visible_operation(&mut self) -> bool {
+        let cancelled = match self.progress.visible() {
+            Some((Operation::Query, _)) => match self.query.as_mut() {
+                Some(query) => {
+                    query.cancel_in_flight();
+                    true
+                }
+                None => false,
+            },
+            Some((Operation::AiRequest, _)) => {
+                self.ai.loading = false;
+                self.ai.cancel_in_flight_request();
+                true
+            }
+            Some((Operation::SaveOutput, _)) => {
+                self.save_stream.cancel();
+                true
+            }
+            Some((Operation::Frequency, _)) => {
+                self.results_frequency.cancel();
+                true
+            }
+            Some((Operation::FileLoad, _)) | None => false,
+        };
+        if cancelled {
+            self.update_progress();
+            self.notification.show("Cancelled");
+        }
+        cancelled
+    }
+
     /// Returns true if continuous rendering is needed for animations
     fn needs_animation(&self) -> bool {
         // Query execution spinner
@@ -458,21 +845,43 @@ impl App {
         {
             return true;
         }
+        // Changed-lines flash until it fades
+        if self.results_diff.is_visible() {
+            return true;
+        }
         // AI loading spinner
         if self.ai.loading {
             return true;
         }
-        // File loading spinner
-        if self.file_loader.as_ref().is_some_and(|l| l.is_loading()) {
+        // Saving output spinner
+        if self.save_stream.is_running() {
             return true;
         }
-        // Notification timer expiry check
-        if self.notification.current().is_some() {
+        // Frequency count spinner
+        if self.results_frequency.is_counting() {
+            return true;
+        }
+        // File loading spinner
+        if self.file_loader.as_ref().is_some_and(|l| l.is_loading()) {
             return true;
         }
         false
     }
 
+    /// How long the event loop may wait for an event before it has work
+    /// to do: a frame while something animates, the debounced query when
+    /// it's due, and otherwise the periodic checks in `tick`
+    pub fn poll_timeout(&self) -> Duration {
+        let timeout = if self.needs_animation() {
+            ANIMATION_FRAME
+        } else {
+            IDLE_POLL_TIMEOUT
+        };
+        self.debouncer
+            .time_until_due()
+            .map_or(timeout, |due| due.min(timeout))
+    }
+
     pub fn should_render(&self) -> bool {
         self.needs_render || self.needs_animation()
     }
@@ -501,6 +910,34 @@ impl App {
     }
 }
 
+/// The latest execution outcome in the shape the AI context expects: the
+/// unformatted output on success, the jq error message on failure.
+pub(super) fn ai_execution_result(query_state: &QueryState) -> Result<String, String> {
+    match &query_state.result {
+        Ok(_) => Ok(query_state
+            .last_successful_result_unformatted
+            .as_ref()
+            .map(|s| s.as_ref().clone())
+            .unwrap_or_default()),
+        Err(e) => Err(e.clone()),
+    }
+}
+
+pub(super) fn ai_context_params<'a>(
+    query_state: &'a QueryState,
+    input_schema: Option<&'a str>,
+) -> crate::ai::context::ContextParams<'a> {
+    crate::ai::context::ContextParams {
+        input_schema,
+        base_query: query_state.base_query_for_suggestions.as_deref(),
+        base_query_result: query_state
+            .last_successful_result_for_context
+            .as_deref()
+            .map(|s| s.as_ref()),
+        is_empty_result: query_state.is_empty_result,
+    }
+}
+
 /// Extract the first non-empty line of a multi-line error message and
 /// strip our `JiqError` Display prefix. Used to surface only the
 /// diagnosis sentence in the paste-recovery view (the loader's full
@@ -100,18 +100,42 @@ fn test_needs_animation_with_file_loading() {
 }
 
 #[test]
-fn test_needs_animation_with_notification() {
+fn test_notification_alone_does_not_redraw_every_frame() {
     let mut app = test_app(r#"{"test": true}"#);
-    app.clear_dirty();
-
+    app.query.as_mut().unwrap().cancel_in_flight();
     app.notification.show("Test notification");
+    app.clear_dirty();
 
     assert!(
-        app.should_render(),
-        "should_render should return true when notification is active (timer expiry check)"
+        !app.should_render(),
+        "a notification that hasn't changed needs no new frame; tick marks dirty when it expires"
     );
 }
 
+#[test]
+fn test_poll_timeout_is_short_while_animating() {
+    let mut app = test_app(r#"{"test": true}"#);
+    app.query.as_mut().unwrap().cancel_in_flight();
+    let idle = app.poll_timeout();
+
+    app.ai.loading = true;
+
+    assert!(app.poll_timeout() < idle);
+}
+
+#[test]
+fn test_poll_timeout_wakes_for_the_debounced_query() {
+    let mut app = test_app(r#"{"test": true}"#);
+    app.query.as_mut().unwrap().cancel_in_flight();
+    let idle = app.poll_timeout();
+
+    app.debouncer.schedule_execution();
+
+    let timeout = app.poll_timeout();
+    assert!(timeout <= idle);
+    assert!(timeout <= std::time::Duration::from_millis(150));
+}
+
 #[test]
 fn test_needs_animation_false_when_idle() {
     let mut app = test_app(r#"{"test": true}"#);
@@ -15,6 +15,8 @@ fn loader_with_error(source: LoaderSource) -> FileLoader {
     FileLoader {
         state: LoadingState::Error(crate::error::JiqError::Io("err".to_string())),
         rx: Some(rx),
+        files_rx: None,
+        malformed_rx: None,
         source,
     }
 }
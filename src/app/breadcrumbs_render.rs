@@ -0,0 +1,90 @@
+//! Breadcrumb bar: one row above the panes while the document is
+//! re-rooted.
+//!
+//! Each crumb is a level of [`crate::root_stack::RootStack`], numbered for
+//! `B` + digit: `.` for the original document, then the query whose
+//! result became each following root. The live level is highlighted.
+//! When the crumbs don't fit, the oldest ones give way to `…`.
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme;
+
+const SEPARATOR: &str = " → ";
+const HINT: &str = "  ⌫ back · B0-9 jump";
+
+/// The bar for the re-root `queries`, oldest first, or None before any
+/// re-root
+pub fn bar_line(queries: &[&str], width: u16) -> Option<Line<'static>> {
+    if queries.is_empty() {
+        return None;
+    }
+    let crumbs: Vec<String> = std::iter::once(".")
+        .chain(queries.iter().copied())
+        .enumerate()
+        .map(|(depth, query)| format!("{} {}", depth, one_line(query)))
+        .collect();
+
+    // Keep the newest crumbs that fit next to the hint
+    let budget = (width as usize).saturating_sub(1 + HINT.width());
+    let mut first = crumbs.len() - 1;
+    // The live crumb is drawn in brackets
+    let mut used = crumbs[first].width() + 2;
+    while first > 0 {
+        let next = crumbs[first - 1].width() + SEPARATOR.width();
+        let ellipsis = if first > 1 {
+            "…".width() + SEPARATOR.width()
+        } else {
+            0
+        };
+        if used + next + ellipsis > budget {
+            break;
+        }
+        used += next;
+        first -= 1;
+    }
+
+    let separator = Style::default().fg(theme::help_line::separator());
+    let mut spans = vec![Span::raw(" ")];
+    if first > 0 {
+        spans.push(Span::styled("…", theme::help::tab_inactive()));
+        spans.push(Span::styled(SEPARATOR, separator));
+    }
+    let live = crumbs.len() - 1;
+    for (depth, crumb) in crumbs.into_iter().enumerate().skip(first) {
+        if depth > first {
+            spans.push(Span::styled(SEPARATOR, separator));
+        }
+        if depth == live {
+            spans.push(Span::styled(
+                format!("[{}]", crumb),
+                theme::help::tab_active(),
+            ));
+        } else {
+            spans.push(Span::styled(crumb, theme::help::tab_inactive()));
+        }
+    }
+    spans.push(Span::styled(
+        HINT,
+        Style::default().fg(theme::palette::text_muted()),
+    ));
+    Some(Line::from(spans))
+}
+
+pub fn render(line: Line<'static>, frame: &mut Frame, area: Rect) {
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+/// A multi-line query on one line
+fn one_line(query: &str) -> String {
+    query.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+#[path = "breadcrumbs_render_tests.rs"]
+mod breadcrumbs_render_tests;
@@ -0,0 +1,49 @@
+use super::*;
+
+fn text(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+#[test]
+fn no_bar_before_any_re_root() {
+    assert!(bar_line(&[], 80).is_none());
+}
+
+#[test]
+fn crumbs_are_numbered_from_the_original_document() {
+    let line = bar_line(&[".data", ".users"], 80).unwrap();
+
+    assert_eq!(
+        text(&line),
+        " 0 . → 1 .data → [2 .users]  ⌫ back · B0-9 jump"
+    );
+}
+
+#[test]
+fn live_crumb_is_highlighted() {
+    let line = bar_line(&[".data"], 80).unwrap();
+
+    let live = line
+        .spans
+        .iter()
+        .find(|s| s.content == "[1 .data]")
+        .unwrap();
+    assert_eq!(live.style, theme::help::tab_active());
+}
+
+#[test]
+fn oldest_crumbs_are_elided_when_narrow() {
+    let line = bar_line(&[".data", ".users", ".first"], 44).unwrap();
+
+    let text = text(&line);
+    assert!(text.starts_with(" … → "), "{}", text);
+    assert!(text.contains("[3 .first]"), "{}", text);
+    assert!(!text.contains("0 ."), "{}", text);
+}
+
+#[test]
+fn multi_line_queries_show_on_one_line() {
+    let line = bar_line(&[".data\n| .users"], 80).unwrap();
+
+    assert!(text(&line).contains("[1 .data | .users]"));
+}
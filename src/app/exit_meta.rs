@@ -0,0 +1,43 @@
+//! Exit metadata for `--emit-meta`.
+//!
+//! Wrapper scripts and shell widgets only see stdout, which is either the
+//! result or the query. `--emit-meta` adds a one-line JSON object on exit
+//! with the final query, how jiq was exited, and the last run's timing
+//! and size, so they can act on more than the printed text.
+
+use serde_json::{Value, json};
+
+use crate::app::app_state::{App, OutputMode};
+
+impl App {
+    /// The metadata object written on exit
+    ///
+    /// `output` is `"results"`, `"query"`, or `null` when jiq was quit
+    /// without printing anything.
+    pub fn exit_meta(&self) -> Value {
+        let output = match self.output_mode {
+            Some(OutputMode::Results) => json!("results"),
+            Some(OutputMode::Query) => json!("query"),
+            None => Value::Null,
+        };
+        let execution_time_ms = self.query.as_ref().and_then(|q| q.cached_execution_time_ms);
+        let error = self
+            .query
+            .as_ref()
+            .and_then(|q| q.result.as_ref().err())
+            .map(|e| e.lines().next().unwrap_or_default().to_string());
+
+        json!({
+            "query": self.query(),
+            "output": output,
+            "execution_time_ms": execution_time_ms,
+            "result_lines": self.results_line_count_u32(),
+            "stats": self.stats.display(),
+            "error": error,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "exit_meta_tests.rs"]
+mod exit_meta_tests;
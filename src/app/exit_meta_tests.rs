@@ -0,0 +1,48 @@
+//! Tests for exit_meta
+
+use super::*;
+use crate::test_utils::test_helpers::app_with_query;
+
+#[test]
+fn test_exit_meta_reports_query_output_and_size() {
+    let mut app = app_with_query(".name");
+    app.output_mode = Some(OutputMode::Results);
+    app.stats.compute("\"test\"");
+
+    let meta = app.exit_meta();
+
+    assert_eq!(meta["query"], ".name");
+    assert_eq!(meta["output"], "results");
+    assert_eq!(meta["result_lines"], 1);
+    assert_eq!(meta["stats"], "String");
+    assert_eq!(meta["error"], Value::Null);
+}
+
+#[test]
+fn test_exit_meta_output_is_null_when_quit_without_printing() {
+    let app = app_with_query(".");
+
+    let meta = app.exit_meta();
+
+    assert_eq!(meta["output"], Value::Null);
+    assert!(meta.get("execution_time_ms").is_some());
+}
+
+#[test]
+fn test_exit_meta_reports_query_mode() {
+    let mut app = app_with_query(".");
+    app.output_mode = Some(OutputMode::Query);
+
+    assert_eq!(app.exit_meta()["output"], "query");
+}
+
+#[test]
+fn test_exit_meta_reports_first_error_line() {
+    let app = app_with_query(".[");
+
+    let meta = app.exit_meta();
+
+    let error = meta["error"].as_str().unwrap();
+    assert!(!error.is_empty());
+    assert!(!error.contains('\n'));
+}
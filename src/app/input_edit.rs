@@ -0,0 +1,150 @@
+//! `:edit` — fix the input in an external editor
+//!
+//! The document is written to a temporary file and the main loop, which
+//! owns the terminal, opens it in `$VISUAL` / `$EDITOR` (see
+//! [`crate::platform::editor_command`]). When the editor exits the file
+//! is read back; valid JSON replaces the input and the query runs again,
+//! while invalid JSON leaves the input alone and keeps the file, so the
+//! next `:edit` picks up where the user left off. Malformed input that
+//! failed to load can be fixed the same way.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::app_state::App;
+use crate::input::malformed::MalformedInput;
+
+impl App {
+    /// Write the input to a temporary file and ask the main loop to open
+    /// it in the editor
+    pub fn request_input_edit(&mut self) {
+        // An edit that didn't parse is still waiting in its file
+        if let Some(draft) = self.input_edit_draft.take()
+            && draft.is_file()
+        {
+            self.pending_input_edit = Some(draft);
+            return;
+        }
+        let text = match (&self.query, &self.malformed_input) {
+            (Some(query), _) => query.executor.json_input().to_string(),
+            (None, Some(malformed)) => malformed.text.clone(),
+            (None, None) => {
+                self.notification.show("Nothing to edit");
+                return;
+            }
+        };
+        let path = edit_path();
+        match write_private(&path, &text) {
+            Ok(()) => self.pending_input_edit = Some(path),
+            Err(e) => {
+                self.notification
+                    .show_error(&format!("Can't write {}: {}", path.display(), e))
+            }
+        }
+    }
+
+    /// The file the main loop should open in the editor, once
+    pub fn take_input_edit(&mut self) -> Option<PathBuf> {
+        self.pending_input_edit.take()
+    }
+
+    /// Remove an edit left unfixed, when jiq exits
+    pub fn discard_input_edit_draft(&mut self) {
+        if let Some(draft) = self.input_edit_draft.take() {
+            let _ = std::fs::remove_file(draft);
+        }
+    }
+
+    /// Load the edited file after the editor exited with `status`
+    pub fn finish_input_edit(&mut self, path: &Path, status: io::Result<ExitStatus>) {
+        self.mark_dirty();
+        let text = match status {
+            Ok(status) if status.success() => std::fs::read_to_string(path)
+                .map_err(|e| format!("Can't read {}: {}", path.display(), e)),
+            Ok(status) => Err(format!("Editor exited with {}", status)),
+            Err(e) => Err(format!("Can't start editor: {}", e)),
+        };
+        let text = match text {
+            Ok(text) => text,
+            Err(message) => {
+                let _ = std::fs::remove_file(path);
+                self.notification.show_error(&message);
+                return;
+            }
+        };
+
+        let unchanged = match &self.query {
+            Some(query) => *query.executor.json_input() == text,
+            None => false,
+        };
+        if unchanged {
+            let _ = std::fs::remove_file(path);
+            self.notification.show("Input unchanged");
+            return;
+        }
+        if let Some(malformed) = MalformedInput::find(text.clone()) {
+            self.input_edit_draft = Some(path.to_path_buf());
+            self.notification.show_error(&format!(
+                "Edited input is not valid JSON at line {}: {} — :edit to fix it",
+                malformed.line, malformed.message
+            ));
+            return;
+        }
+
+        let _ = std::fs::remove_file(path);
+        // The document no longer matches the files it came from
+        self.input_files = None;
+        self.input_paths.clear();
+        self.malformed_input = None;
+        self.results_scroll.reset();
+        self.initialize_from_json(text);
+        crate::editor::editor_events::execute_query(self);
+        self.notification.show("Reloaded edited input");
+    }
+}
+
+/// Open `path` in the user's editor and wait for it to exit. The caller
+/// hands the terminal over first.
+pub fn run_editor(path: &Path) -> io::Result<ExitStatus> {
+    let visual = std::env::var("VISUAL").ok();
+    let editor = std::env::var("EDITOR").ok();
+    let command = crate::platform::editor_command(
+        visual.as_deref(),
+        editor.as_deref(),
+        crate::platform::WINDOWS,
+    );
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no editor"))?;
+    std::process::Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+}
+
+/// A new temporary file for `:edit` to open
+fn edit_path() -> PathBuf {
+    static EDITS: AtomicUsize = AtomicUsize::new(0);
+    let n = EDITS.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!("jiq-edit-{}-{}.json", std::process::id(), n))
+}
+
+/// Write `text` readable by its owner only, since the input may hold secrets
+fn write_private(path: &Path, text: &str) -> io::Result<()> {
+    use std::io::Write;
+
+    let mut open = std::fs::OpenOptions::new();
+    open.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        open.mode(0o600);
+    }
+    open.open(path)?.write_all(text.as_bytes())
+}
+
+#[cfg(test)]
+#[path = "input_edit_tests.rs"]
+mod input_edit_tests;
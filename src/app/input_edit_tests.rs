@@ -0,0 +1,134 @@
+use std::fs;
+use std::process::ExitStatus;
+
+use crate::input::malformed::MalformedInput;
+use crate::test_utils::test_helpers::{app_with_query, test_app, wait_for_query_completion};
+
+fn exited(code: i32) -> ExitStatus {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        ExitStatus::from_raw(code << 8)
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::ExitStatusExt;
+        ExitStatus::from_raw(code as u32)
+    }
+}
+
+fn input_text(app: &crate::app::App) -> String {
+    app.query
+        .as_ref()
+        .map(|query| query.executor.json_input().to_string())
+        .unwrap_or_default()
+}
+
+#[test]
+fn edit_writes_the_input_for_the_editor() {
+    let mut app = test_app(r#"{"a":1}"#);
+
+    app.request_input_edit();
+
+    let path = app.take_input_edit().expect("an edit is pending");
+    assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"a":1}"#);
+    assert_eq!(app.take_input_edit(), None);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn edited_input_replaces_the_document_and_reruns_the_query() {
+    let mut app = app_with_query(".a");
+    app.input_paths = vec!["/data/in.json".into()];
+    app.request_input_edit();
+    let path = app.take_input_edit().unwrap();
+    fs::write(&path, r#"{"a":"fixed"}"#).unwrap();
+
+    app.finish_input_edit(&path, Ok(exited(0)));
+
+    assert_eq!(input_text(&app), r#"{"a":"fixed"}"#);
+    assert!(wait_for_query_completion(&mut app, 2000));
+    let result = app.query.as_ref().unwrap().result.clone().unwrap();
+    assert!(result.contains("fixed"), "{}", result);
+    assert!(app.input_paths.is_empty());
+    assert!(!path.exists());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Reloaded edited input")
+    );
+}
+
+#[test]
+fn unchanged_input_is_left_alone() {
+    let mut app = test_app(r#"{"a":1}"#);
+    app.input_paths = vec!["/data/in.json".into()];
+    app.request_input_edit();
+    let path = app.take_input_edit().unwrap();
+
+    app.finish_input_edit(&path, Ok(exited(0)));
+
+    assert_eq!(app.input_paths.len(), 1);
+    assert!(!path.exists());
+    assert_eq!(app.notification.current_message(), Some("Input unchanged"));
+}
+
+#[test]
+fn invalid_edit_keeps_the_input_and_reopens_the_draft() {
+    let mut app = test_app(r#"{"a":1}"#);
+    app.request_input_edit();
+    let path = app.take_input_edit().unwrap();
+    fs::write(&path, "{\"a\":\n  oops}").unwrap();
+
+    app.finish_input_edit(&path, Ok(exited(0)));
+
+    assert_eq!(input_text(&app), r#"{"a":1}"#);
+    let message = app.notification.current_message().unwrap_or("");
+    assert!(message.contains("not valid JSON at line 2"), "{}", message);
+
+    app.request_input_edit();
+    assert_eq!(app.take_input_edit().as_ref(), Some(&path));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "{\"a\":\n  oops}");
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn editor_failure_leaves_the_input_alone() {
+    let mut app = test_app(r#"{"a":1}"#);
+    app.request_input_edit();
+    let path = app.take_input_edit().unwrap();
+    fs::write(&path, r#"{"a":2}"#).unwrap();
+
+    app.finish_input_edit(&path, Ok(exited(1)));
+
+    assert_eq!(input_text(&app), r#"{"a":1}"#);
+    assert!(!path.exists());
+    let message = app.notification.current_message().unwrap_or("");
+    assert!(message.starts_with("Editor exited with"), "{}", message);
+}
+
+#[test]
+fn malformed_input_can_be_fixed_in_the_editor() {
+    let mut app = test_app("{}");
+    app.query = None;
+    app.malformed_input = MalformedInput::find("{\"a\": [1,]}".to_string());
+    app.request_input_edit();
+    let path = app.take_input_edit().unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "{\"a\": [1,]}");
+    fs::write(&path, "{\"a\": [1]}").unwrap();
+
+    app.finish_input_edit(&path, Ok(exited(0)));
+
+    assert!(app.malformed_input.is_none());
+    assert_eq!(input_text(&app), "{\"a\": [1]}");
+}
+
+#[test]
+fn nothing_to_edit_before_the_input_loads() {
+    let mut app = test_app("{}");
+    app.query = None;
+
+    app.request_input_edit();
+
+    assert_eq!(app.take_input_edit(), None);
+    assert_eq!(app.notification.current_message(), Some("Nothing to edit"));
+}
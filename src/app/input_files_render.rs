@@ -0,0 +1,142 @@
+//! Input file picker strip.
+//!
+//! Drawn over the top of the results pane while open:
+//!
+//! 1. **Strip** — one row listing every input file as `[x] name`, with
+//!    the highlighted file in bold and excluded files dimmed. The title
+//!    counts included files; the bottom border carries the key hints.
+//! 2. **Preview** — the highlighted file's schema, so each file's
+//!    structure can be checked on its own before it is included.
+
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph};
+
+use crate::input::{InputFile, InputFilesState};
+use crate::theme;
+
+const STRIP_HEIGHT: u16 = 3;
+
+/// Render the strip and preview over the top of `area`
+pub fn render_popup(state: &InputFilesState, frame: &mut Frame, area: Rect) {
+    if area.height < STRIP_HEIGHT || area.width < 20 {
+        return;
+    }
+
+    let preview_lines = preview_lines(state.selected_file());
+    let preview_height = (preview_lines.len() as u16 + 2).min(area.height - STRIP_HEIGHT);
+    let popup_area = Rect {
+        height: STRIP_HEIGHT + preview_height,
+        ..area
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::vertical([
+        Constraint::Length(STRIP_HEIGHT),
+        Constraint::Length(preview_height),
+    ])
+    .split(popup_area);
+
+    render_strip(state, frame, chunks[0]);
+    if preview_height > 2 {
+        render_preview(state, preview_lines, frame, chunks[1]);
+    }
+}
+
+fn render_strip(state: &InputFilesState, frame: &mut Frame, area: Rect) {
+    let title = format!(
+        " Input files ({}/{} included) ",
+        state.included_count(),
+        state.files().len()
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme::input::mode_insert()))
+        .title(Span::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .padding(Padding::horizontal(1))
+        .title_bottom(bottom_hints().alignment(Alignment::Center));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut spans = Vec::with_capacity(state.files().len() * 2);
+    for (i, file) in state.files().iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(file_span(file, i == state.selected()));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), inner);
+}
+
+fn file_span(file: &InputFile, selected: bool) -> Span<'static> {
+    let marker = if file.included { "[x]" } else { "[ ]" };
+    let text = format!("{} {}", marker, file.display_name());
+    let style = match (selected, file.included) {
+        (true, _) => Style::default()
+            .fg(theme::input::mode_insert())
+            .add_modifier(Modifier::BOLD),
+        (false, true) => Style::default().fg(theme::palette::text()),
+        (false, false) => Style::default().fg(theme::palette::text_dim()),
+    };
+    Span::styled(text, style)
+}
+
+fn render_preview(
+    state: &InputFilesState,
+    lines: Vec<Line<'static>>,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let title = state
+        .selected_file()
+        .map(|f| format!(" {} ", f.path.display()))
+        .unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme::input::border_unfocused()))
+        .title(title)
+        .padding(Padding::horizontal(1));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn preview_lines(file: Option<&InputFile>) -> Vec<Line<'static>> {
+    match file.and_then(|f| f.schema.as_deref()) {
+        Some(schema) => schema
+            .lines()
+            .map(|line| {
+                Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(theme::palette::text()),
+                ))
+            })
+            .collect(),
+        None => vec![Line::from(Span::styled(
+            "No schema available",
+            Style::default().fg(theme::palette::text_dim()),
+        ))],
+    }
+}
+
+fn bottom_hints() -> Line<'static> {
+    let entries: [(&'static str, &'static str); 3] = [
+        ("Space", "Include/Exclude"),
+        ("←/→", "Switch"),
+        ("Esc", "Close"),
+    ];
+    theme::border_hints::build_hints(&entries, theme::input::mode_insert())
+}
+
+#[cfg(test)]
+#[path = "input_files_render_tests.rs"]
+mod input_files_render_tests;
@@ -0,0 +1,55 @@
+use super::*;
+
+use std::path::PathBuf;
+
+use crate::app::app_render_tests::render_to_string;
+use crate::test_utils::test_helpers::test_app;
+
+fn open_files_app() -> crate::app::App {
+    let mut app = test_app("{\"id\":1}\n[{\"tag\":\"x\"}]");
+    let mut files = InputFilesState::new(vec![
+        InputFile::new(PathBuf::from("users.json"), "{\"id\":1}".to_string()),
+        InputFile::new(PathBuf::from("tags.json"), "[{\"tag\":\"x\"}]".to_string()),
+    ]);
+    files.open();
+    app.input_files = Some(files);
+    app
+}
+
+#[test]
+fn snapshot_input_files_strip() {
+    let mut app = open_files_app();
+    insta::assert_snapshot!(render_to_string(&mut app, 80, 24));
+}
+
+#[test]
+fn strip_marks_excluded_files_and_previews_selection() {
+    let mut app = open_files_app();
+    let files = app.input_files.as_mut().unwrap();
+    files.toggle_selected();
+    files.select_next();
+
+    let output = render_to_string(&mut app, 80, 24);
+
+    assert!(output.contains("[ ] users.json"), "{output}");
+    assert!(output.contains("[x] tags.json"), "{output}");
+    assert!(output.contains("(1/2 included)"), "{output}");
+    assert!(output.contains("\"tag\": \"string\""), "{output}");
+}
+
+#[test]
+fn closed_strip_is_not_drawn() {
+    let mut app = open_files_app();
+    app.input_files.as_mut().unwrap().close();
+
+    let output = render_to_string(&mut app, 80, 24);
+
+    assert!(!output.contains("Input files"));
+}
+
+#[test]
+fn preview_lines_without_schema_show_placeholder() {
+    let lines = preview_lines(None);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].spans[0].content, "No schema available");
+}
@@ -0,0 +1,79 @@
+//! One-row banner above the panes while the input is large.
+//!
+//! Past `[large_input] threshold_mb` the input goes without the analysis
+//! behind autocomplete and tooltips and without the AI context, unless the
+//! config turns them back on. The banner gives the size and says what is
+//! off, so missing suggestions don't look like a bug.
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::config::LargeInputConfig;
+use crate::input::InputText;
+use crate::theme;
+
+/// The banner for `input`, or None when it isn't large
+pub fn banner_line(config: &LargeInputConfig, input: &InputText) -> Option<Line<'static>> {
+    let bytes = input.len();
+    if !config.is_large(bytes) {
+        return None;
+    }
+    let mut size = format_size(bytes);
+    if input.is_mapped() {
+        size.push_str(", memory-mapped");
+    }
+    let off: Vec<&str> = [
+        (!config.analysis_for(bytes)).then_some("input analysis"),
+        (!config.ai_context_for(bytes)).then_some("AI context"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let warning = Style::default().fg(theme::palette::warning());
+    let mut spans = vec![Span::styled(
+        format!(" ⚠ Large input ({})", size),
+        warning.add_modifier(Modifier::BOLD),
+    )];
+    if !off.is_empty() {
+        spans.push(Span::styled(
+            format!(": {} off", off.join(" and ")),
+            warning,
+        ));
+        spans.push(Span::styled(
+            " · [large_input] in config",
+            Style::default().fg(theme::palette::text_muted()),
+        ));
+    }
+    Some(Line::from(spans))
+}
+
+pub fn render(line: Line<'static>, frame: &mut Frame, area: Rect) {
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+/// `bytes` in the largest unit that keeps it at 1 or more
+pub(super) fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = "bytes";
+    for next in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    if unit == "bytes" {
+        format!("{} bytes", bytes)
+    } else {
+        format!("{:.1} {}", size, unit)
+    }
+}
+
+#[cfg(test)]
+#[path = "large_input_render_tests.rs"]
+mod large_input_render_tests;
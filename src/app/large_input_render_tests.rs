@@ -0,0 +1,124 @@
+use super::*;
+
+use std::io::Write;
+
+use crate::config::Config;
+use crate::headless::Headless;
+
+fn large_from(threshold_mb: u64) -> LargeInputConfig {
+    LargeInputConfig {
+        threshold_mb,
+        ..LargeInputConfig::default()
+    }
+}
+
+fn text(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+#[test]
+fn no_banner_below_the_threshold() {
+    let input = InputText::from(r#"{"a": 1}"#.to_string());
+    assert!(banner_line(&LargeInputConfig::default(), &input).is_none());
+}
+
+#[test]
+fn banner_gives_the_size_and_what_is_off() {
+    let input = InputText::from("[1]".to_string());
+    let line = banner_line(&large_from(0), &input).unwrap();
+    assert_eq!(
+        text(&line),
+        " ⚠ Large input (3 bytes): input analysis and AI context off · [large_input] in config"
+    );
+}
+
+#[test]
+fn banner_leaves_out_what_the_config_turned_back_on() {
+    let input = InputText::from("[1]".to_string());
+    let config = LargeInputConfig {
+        analysis: true,
+        ..large_from(0)
+    };
+    let line = banner_line(&config, &input).unwrap();
+    assert!(text(&line).ends_with(": AI context off · [large_input] in config"));
+
+    let config = LargeInputConfig {
+        analysis: true,
+        ai_context: true,
+        ..large_from(0)
+    };
+    let line = banner_line(&config, &input).unwrap();
+    assert_eq!(text(&line), " ⚠ Large input (3 bytes)");
+}
+
+#[test]
+fn banner_says_when_the_file_is_mapped() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(b"[1, 2]").unwrap();
+    let input = InputText::map(file.as_file()).unwrap();
+
+    let line = banner_line(&large_from(0), &input).unwrap();
+    assert!(text(&line).starts_with(" ⚠ Large input (6 bytes, memory-mapped)"));
+}
+
+#[test]
+fn format_size_picks_the_unit() {
+    assert_eq!(format_size(0), "0 bytes");
+    assert_eq!(format_size(1023), "1023 bytes");
+    assert_eq!(format_size(1536), "1.5 KB");
+    assert_eq!(format_size(340 * 1024 * 1024), "340.0 MB");
+    assert_eq!(format_size(3 * 1024 * 1024 * 1024 / 2), "1.5 GB");
+}
+
+fn large_input_jiq(json: &str) -> Headless {
+    let mut config = Config::default();
+    config.large_input.threshold_mb = 0;
+    Headless::builder(json).config(config).size(100, 12).build()
+}
+
+#[test]
+fn large_input_shows_the_banner_above_the_panes() {
+    let mut jiq = large_input_jiq(r#"{"name": "Alice"}"#);
+    let screen = jiq.screen();
+    let first = screen.lines().next().unwrap();
+    assert!(first.contains("⚠ Large input (17 bytes)"), "{screen}");
+    assert!(screen.contains("\"Alice\""), "{screen}");
+}
+
+#[test]
+fn large_input_skips_the_input_analysis_and_ai_context() {
+    let jiq = large_input_jiq(r#"{"name": "Alice"}"#);
+    let query = jiq.app().query.as_ref().unwrap();
+    assert!(query.executor.json_input_parsed().is_none());
+    assert!(query.executor.all_field_names().is_empty());
+    assert!(jiq.app().input_json_schema.is_none());
+}
+
+#[test]
+fn small_input_keeps_the_input_analysis_and_has_no_banner() {
+    let mut jiq = Headless::builder(r#"{"name": "Alice"}"#)
+        .size(100, 12)
+        .build();
+    assert!(!jiq.screen().contains("Large input"));
+    let query = jiq.app().query.as_ref().unwrap();
+    assert!(query.executor.json_input_parsed().is_some());
+    assert!(jiq.app().input_json_schema.is_some());
+}
+
+#[test]
+fn configured_analysis_stays_on_for_large_input() {
+    let mut config = Config::default();
+    config.large_input.threshold_mb = 0;
+    config.large_input.analysis = true;
+    let jiq = Headless::builder(r#"{"name": "Alice"}"#)
+        .config(config)
+        .build();
+    let query = jiq.app().query.as_ref().unwrap();
+    assert!(query.executor.all_field_names().contains("name"));
+}
+
+#[test]
+fn snapshot_large_input_banner() {
+    let mut jiq = large_input_jiq(r#"{"name": "Alice"}"#);
+    insta::assert_snapshot!(jiq.screen());
+}
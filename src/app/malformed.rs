@@ -0,0 +1,73 @@
+//! Keys for input that failed to parse
+//!
+//! While the results pane shows the text of a file or stdin that isn't
+//! valid JSON, `n`, `r` and `l` load it another way and the usual motion
+//! keys scroll through it. See [`crate::input::malformed`].
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::app_state::App;
+use crate::input::malformed::Recovery;
+
+impl App {
+    /// Handle a key while malformed input is showing; returns true when
+    /// the key was used up
+    pub(super) fn handle_malformed_key(&mut self, key: KeyEvent) -> bool {
+        if self.malformed_input.is_none() || self.query.is_some() {
+            return false;
+        }
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        if let KeyCode::Char(c) = key.code
+            && let Some(recovery) = Recovery::from_key(c)
+        {
+            self.recover_malformed(recovery);
+            return true;
+        }
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => self.results_scroll.scroll_down(1),
+            KeyCode::Up | KeyCode::Char('k') => self.results_scroll.scroll_up(1),
+            KeyCode::PageDown => self.results_scroll.page_down(),
+            KeyCode::PageUp => self.results_scroll.page_up(),
+            KeyCode::Home | KeyCode::Char('g') => self.results_scroll.jump_to_top(),
+            KeyCode::End | KeyCode::Char('G') => self.results_scroll.jump_to_bottom(),
+            _ => return false,
+        }
+        self.mark_dirty();
+        true
+    }
+
+    /// Load the malformed input with `recovery`, or say why it still
+    /// doesn't parse
+    pub fn recover_malformed(&mut self, recovery: Recovery) {
+        let Some(malformed) = &self.malformed_input else {
+            return;
+        };
+        match malformed.recover(recovery) {
+            Ok(recovered) => {
+                self.malformed_input = None;
+                self.results_scroll.reset();
+                self.initialize_from_json(recovered.json);
+                let message = match recovered.skipped {
+                    0 => recovery.loaded().to_string(),
+                    1 => format!("{}, skipping 1 invalid line", recovery.loaded()),
+                    n => format!("{}, skipping {} invalid lines", recovery.loaded(), n),
+                };
+                self.notification.show(&message);
+                crate::editor::editor_events::execute_query(self);
+            }
+            Err(message) => {
+                self.notification
+                    .show_error(&format!("{}: {}", recovery.label(), message));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "malformed_tests.rs"]
+mod malformed_tests;
@@ -0,0 +1,129 @@
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::KeyCode;
+use tempfile::NamedTempFile;
+
+use crate::app::App;
+use crate::config::Config;
+use crate::headless::Headless;
+use crate::input::FileLoader;
+
+const BROKEN: &str = "{\n  // people\n  \"names\": [\"Ada\", \"Alan\",],\n}\n";
+
+/// Headless jiq over a file holding `text`, once the load has failed
+fn malformed_jiq(text: &str) -> (NamedTempFile, Headless) {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(text.as_bytes()).unwrap();
+    let loader = FileLoader::spawn_load(file.path().to_path_buf());
+    let mut jiq = Headless::from_app(App::new_with_loader(loader, &Config::default()), 80, 20);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while jiq.app().malformed_input.is_none() && Instant::now() < deadline {
+        jiq.app_mut().poll_file_loader();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    (file, jiq)
+}
+
+#[test]
+fn invalid_file_shows_its_text_and_where_it_broke() {
+    let (_file, mut jiq) = malformed_jiq(BROKEN);
+    let malformed = jiq.app().malformed_input.clone().expect("kept the text");
+    assert_eq!(malformed.line, 2);
+
+    assert_eq!(jiq.notification(), Some("Failed to load file"));
+    jiq.app_mut().notification.dismiss();
+    let screen = jiq.screen();
+    assert!(
+        screen.contains("Invalid JSON at line 2, column 3"),
+        "{screen}"
+    );
+    assert!(screen.contains("▶ 2   // people"), "{screen}");
+    assert!(
+        screen.contains("\"names\": [\"Ada\", \"Alan\",],"),
+        "{screen}"
+    );
+    assert!(screen.contains("n NDJSON"), "{screen}");
+}
+
+#[test]
+fn lenient_recovery_loads_the_document() {
+    let (_file, mut jiq) = malformed_jiq(BROKEN);
+
+    jiq.key(KeyCode::Char('l'));
+    jiq.type_str(".names[1]");
+    assert!(jiq.settle());
+
+    assert!(jiq.app().malformed_input.is_none());
+    assert_eq!(jiq.output().as_deref(), Some("\"Alan\"\n"));
+    assert!(!jiq.screen().contains("Invalid JSON"));
+}
+
+#[test]
+fn ndjson_recovery_says_how_many_lines_it_skipped() {
+    let (_file, mut jiq) = malformed_jiq("{\"a\": 1}\n{\"a\": \n{\"a\": 3}\n");
+
+    jiq.key(KeyCode::Char('n'));
+    jiq.settle();
+
+    assert_eq!(
+        jiq.notification(),
+        Some("Loaded as NDJSON, skipping 1 invalid line")
+    );
+    assert_eq!(
+        jiq.output().as_deref(),
+        Some("{\n  \"a\": 1\n}\n{\n  \"a\": 3\n}\n")
+    );
+}
+
+#[test]
+fn raw_lines_recovery_reads_strings() {
+    let (_file, mut jiq) = malformed_jiq("alpha\nbeta\n");
+
+    jiq.key(KeyCode::Char('r'));
+    jiq.type_str("length");
+    jiq.settle();
+
+    assert_eq!(jiq.output().as_deref(), Some("5\n4\n"));
+}
+
+#[test]
+fn failed_recovery_keeps_the_text_on_screen() {
+    let (_file, mut jiq) = malformed_jiq("{\"a\": 1,, }");
+
+    jiq.key(KeyCode::Char('l'));
+
+    assert!(jiq.app().malformed_input.is_some());
+    assert!(jiq.app().query.is_none());
+    assert!(
+        jiq.notification()
+            .is_some_and(|n| n.starts_with("JSON5: Still invalid")),
+        "{:?}",
+        jiq.notification()
+    );
+    assert!(jiq.screen().contains("Invalid JSON"));
+}
+
+#[test]
+fn motion_keys_scroll_the_text() {
+    let text = format!(
+        "[\n{}\n",
+        (0..50)
+            .map(|i| format!("{},", i))
+            .collect::<String>()
+            .replace(',', ",\n")
+    );
+    let (_file, mut jiq) = malformed_jiq(&text);
+    jiq.render();
+    let start = jiq.app().results_scroll.offset;
+
+    jiq.key(KeyCode::Char('g'));
+    assert_eq!(jiq.app().results_scroll.offset, 0);
+    jiq.key(KeyCode::Char('j'));
+    assert_eq!(jiq.app().results_scroll.offset, 1);
+    jiq.key(KeyCode::Char('G'));
+    jiq.app_mut().notification.dismiss();
+    let screen = jiq.screen();
+    assert!(start > 0, "opens at the error, which is at the end");
+    assert!(screen.contains("▶"), "{screen}");
+}
@@ -112,8 +112,19 @@ fn click_results_pane(app: &mut App, mouse: MouseEvent) {
         return;
     }
 
+    // The line filter's, table's and path views have no cursor to place
+    if app.results_filter.is_active()
+        || app.results_table.is_active()
+        || app.results_paths.is_active()
+    {
+        return;
+    }
+
     let relative_y = mouse.row.saturating_sub(inner_y) as u32;
-    let clicked_line = app.results_scroll.offset as u32 + relative_y;
+    let row = app.results_scroll.offset as u32 + relative_y;
+    let Some(clicked_line) = app.results_folds.line_at(row) else {
+        return;
+    };
 
     if clicked_line < app.results_cursor.total_lines() {
         app.results_cursor.click_select(clicked_line);
@@ -183,11 +194,12 @@ fn click_input_field(app: &mut App, mouse: MouseEvent) {
         return;
     }
 
-    // Calculate the character position relative to the visible area
+    // Calculate the column relative to the visible area
     let relative_x = (mouse.column - inner_x) as usize;
 
-    // Add scroll offset to get the actual character position
-    let target_col = app.input.scroll_offset + relative_x;
+    // Map the column to a character, counting wide characters as two columns
+    let target_col =
+        crate::str_utils::char_at_column(app.query(), app.input.scroll_offset, relative_x);
 
     // Set cursor to the calculated position
     app.input.set_cursor_column(target_col);
@@ -237,6 +249,7 @@ fn click_ai_window(app: &mut App, mouse: MouseEvent) {
             &mut app.input,
             query_state,
             &mut app.autocomplete,
+            &mut app.history,
         );
         app.ai.selection.clear_selection();
     }
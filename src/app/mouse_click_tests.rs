@@ -170,6 +170,24 @@ fn test_click_input_field_with_scroll_offset() {
     );
 }
 
+#[test]
+fn test_click_input_field_counts_wide_chars_as_two_columns() {
+    let mut app = setup_app();
+    app.focus = Focus::InputField;
+    app.input.textarea.insert_str(".名前.id");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Head);
+    app.input.scroll_offset = 0;
+    app.layout_regions.input_field = Some(ratatui::layout::Rect::new(0, 0, 30, 3));
+
+    // Relative column 5 is the '.' after ".名前" (1 + 2 + 2 columns)
+    let mouse = create_mouse_event(6, 1);
+    handle_click(&mut app, Some(Region::InputField), mouse);
+
+    assert_eq!(app.input.textarea.cursor().1, 3);
+}
+
 #[test]
 fn test_click_input_field_clamps_to_text_length() {
     let mut app = setup_app();
@@ -8,6 +8,7 @@ use super::app_state::App;
 use super::mouse_click;
 use super::mouse_hover;
 use super::mouse_scroll;
+use super::scrollbar_drag;
 use crate::layout::region_at;
 
 /// Handle mouse events by routing to appropriate handlers
@@ -30,9 +31,13 @@ pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
         MouseEventKind::ScrollRight => {
             mouse_scroll::handle_scroll(app, region, mouse_scroll::ScrollDirection::Right);
         }
+        MouseEventKind::Down(MouseButton::Left)
+            if scrollbar_drag::handle_press(app, region, mouse) => {}
         MouseEventKind::Down(MouseButton::Left) => {
             mouse_click::handle_click(app, region, mouse);
         }
+        MouseEventKind::Drag(MouseButton::Left) if scrollbar_drag::handle_drag(app, mouse) => {}
+        MouseEventKind::Up(MouseButton::Left) => scrollbar_drag::handle_release(app),
         MouseEventKind::Moved | MouseEventKind::Drag(MouseButton::Left) => {
             mouse_hover::handle_hover(app, region, mouse);
         }
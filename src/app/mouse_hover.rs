@@ -63,7 +63,11 @@ fn hover_results_pane(app: &mut App, mouse: MouseEvent) {
     }
 
     let relative_y = mouse.row.saturating_sub(inner_y) as u32;
-    let hovered_line = app.results_scroll.offset as u32 + relative_y;
+    let row = app.results_scroll.offset as u32 + relative_y;
+    let Some(hovered_line) = app.results_folds.line_at(row) else {
+        app.results_cursor.clear_hover();
+        return;
+    };
 
     if hovered_line < app.results_cursor.total_lines() {
         if matches!(mouse.kind, MouseEventKind::Drag(_)) && app.results_cursor.is_visual_mode() {
@@ -67,7 +67,10 @@ const LIST_SCROLL_ITEMS: usize = 1;
 
 fn scroll_results(app: &mut App, direction: ScrollDirection) {
     match direction {
-        ScrollDirection::Up => app.results_scroll.scroll_up(RESULTS_SCROLL_LINES),
+        ScrollDirection::Up => {
+            crate::results::follow_events::stop(app);
+            app.results_scroll.scroll_up(RESULTS_SCROLL_LINES)
+        }
         ScrollDirection::Down => app.results_scroll.scroll_down(RESULTS_SCROLL_LINES),
         ScrollDirection::Left | ScrollDirection::Right => {}
     }
@@ -572,3 +572,23 @@ fn test_horizontal_scroll_search_bar_does_nothing() {
         "Search bar is not scrollable horizontally"
     );
 }
+
+#[test]
+fn test_scroll_results_pane_up_stops_following() {
+    let mut app = setup_app_for_scroll_tests();
+    app.results_follow = true;
+
+    handle_scroll(&mut app, Some(Region::ResultsPane), ScrollDirection::Up);
+
+    assert!(!app.results_follow);
+}
+
+#[test]
+fn test_scroll_results_pane_down_keeps_following() {
+    let mut app = setup_app_for_scroll_tests();
+    app.results_follow = true;
+
+    handle_scroll(&mut app, Some(Region::ResultsPane), ScrollDirection::Down);
+
+    assert!(app.results_follow);
+}
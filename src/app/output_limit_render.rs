@@ -0,0 +1,40 @@
+//! One-row banner above the panes while the result was cut short.
+//!
+//! jq's output is only kept up to `[results] max_output_mb`; past that jq
+//! is stopped and the results pane shows the whole lines read so far. The
+//! banner says so, and that saving with `:w` runs the query again straight
+//! into the file, so nothing is lost.
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::large_input_render::format_size;
+use crate::theme;
+
+/// The banner for a result cut at `limit` bytes, or None when it wasn't
+pub fn banner_line(truncated: bool, limit: Option<usize>) -> Option<Line<'static>> {
+    let limit = limit.filter(|_| truncated)?;
+    let warning = Style::default().fg(theme::palette::warning());
+    Some(Line::from(vec![
+        Span::styled(
+            format!(" ✂ Output cut at {}", format_size(limit)),
+            warning.add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(": jq was stopped · :w FILE saves all of it", warning),
+        Span::styled(
+            " · [results] max_output_mb in config",
+            Style::default().fg(theme::palette::text_muted()),
+        ),
+    ]))
+}
+
+pub fn render(line: Line<'static>, frame: &mut Frame, area: Rect) {
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+#[cfg(test)]
+#[path = "output_limit_render_tests.rs"]
+mod output_limit_render_tests;
@@ -0,0 +1,43 @@
+use super::*;
+
+use crate::headless::Headless;
+use crate::query::jq_args::JqArgs;
+
+fn text(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+#[test]
+fn no_banner_for_a_whole_result() {
+    assert!(banner_line(false, Some(1024)).is_none());
+    assert!(banner_line(true, None).is_none());
+}
+
+#[test]
+fn banner_gives_the_limit_and_how_to_save_all_of_it() {
+    let line = banner_line(true, Some(256 * 1024 * 1024)).unwrap();
+    assert_eq!(
+        text(&line),
+        " ✂ Output cut at 256.0 MB: jq was stopped · :w FILE saves all of it · [results] max_output_mb in config"
+    );
+}
+
+#[test]
+fn huge_output_shows_the_banner_above_the_panes() {
+    let mut jq_args = JqArgs::new();
+    jq_args.set_output_limit(Some(2048));
+    let mut jiq = Headless::builder("null")
+        .jq_args(jq_args)
+        .size(120, 12)
+        .build();
+    jiq.type_str("range(1e9)");
+    jiq.settle();
+
+    let screen = jiq.screen();
+    let first = screen.lines().next().unwrap();
+    assert!(first.contains("✂ Output cut at 2.0 KB"), "{screen}");
+    assert_eq!(
+        jiq.app().query.as_ref().unwrap().truncated_query.as_deref(),
+        Some("range(1e9)")
+    );
+}
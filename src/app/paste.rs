@@ -0,0 +1,111 @@
+//! Pastes on the main screen
+//!
+//! A paste goes into the query as one edit: one undo step and one run of
+//! jq, however long it is. Text that looks like a JSON document rather
+//! than a query isn't inserted straight away; a hint offers to open it in
+//! a new tab instead.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::app_state::App;
+use crate::input::loader::scan_json_or_jsonl;
+use crate::notification::NotificationType;
+
+/// Longest single-line paste left to the query even when it parses as
+/// JSON, since `[1, 2]` and `{"a": 1}` are jq too
+const SHORT_JSON_LEN: usize = 40;
+
+/// Whether `text` reads as a document to query rather than a query: all
+/// objects or arrays, and spread over several lines or long
+pub fn looks_like_document(text: &str) -> bool {
+    let trimmed = text.trim();
+    if !trimmed.starts_with(['{', '[']) {
+        return false;
+    }
+    if !trimmed.contains('\n') && trimmed.len() <= SHORT_JSON_LEN {
+        return false;
+    }
+    scan_json_or_jsonl(trimmed).is_ok_and(|scan| scan.all_containers)
+}
+
+/// `text` on one line, as the query input holds a single line
+fn one_line(text: &str) -> String {
+    text.lines().collect::<Vec<_>>().join(" ")
+}
+
+impl App {
+    /// Handle a bracketed paste while the query and results are showing
+    pub(super) fn handle_paste_event(&mut self, text: String) {
+        if self.params.is_visible() {
+            self.params.insert_str(&text);
+            return;
+        }
+        if looks_like_document(&text) {
+            self.notification.show_with_type(
+                "Pasted JSON · Enter opens it in a new tab · Esc pastes it into the query",
+                NotificationType::Hint,
+            );
+            self.paste_offer = Some(text);
+            return;
+        }
+        self.paste_into_query(&text);
+    }
+
+    fn paste_into_query(&mut self, text: &str) {
+        self.insert_paste(&one_line(text));
+        // Whatever was typed before the paste ran along with it
+        self.debouncer.mark_executed();
+    }
+
+    /// Answer the offer to open pasted JSON: Enter opens it in a new tab
+    /// and Esc pastes it into the query after all. Any other key drops
+    /// the offer and is handled as usual; returns true when the key was
+    /// used up.
+    pub(super) fn handle_paste_offer_key(&mut self, key: KeyEvent) -> bool {
+        let Some(text) = self.paste_offer.take() else {
+            return false;
+        };
+        self.notification.dismiss_hints();
+        match key.code {
+            KeyCode::Enter if key.modifiers.is_empty() => {
+                self.open_document_tab(text);
+                true
+            }
+            KeyCode::Esc => {
+                self.paste_into_query(&text);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drop the offer once its hint has gone, so that a later Enter does
+    /// what it usually does. True when it was dropped.
+    pub(super) fn expire_paste_offer(&mut self) -> bool {
+        if self.paste_offer.is_some() && !self.notification.is_showing_hint() {
+            self.paste_offer = None;
+            return true;
+        }
+        false
+    }
+
+    fn open_document_tab(&mut self, text: String) {
+        self.new_tab();
+        let result = match &mut self.paste_recovery {
+            Some(recovery) => recovery.try_submit(&text),
+            None => return,
+        };
+        match result {
+            Ok(json) => self.accept_paste_recovery_json(json),
+            Err(msg) => {
+                // Leave it in the paste editor to fix up
+                self.input.textarea.insert_str(&text);
+                self.notification.show_error(&msg);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "paste_tests.rs"]
+mod paste_tests;
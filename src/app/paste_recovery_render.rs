@@ -153,6 +153,7 @@ fn mode_color(mode: EditorMode) -> Color {
         EditorMode::CharSearch(_, _) => theme::input::mode_char_search(),
         EditorMode::OperatorCharSearch(_, _, _, _) => theme::input::mode_operator(),
         EditorMode::TextObject(_, _) => theme::input::mode_operator(),
+        EditorMode::Register(_) | EditorMode::Wrap => theme::input::mode_operator(),
     }
 }
 
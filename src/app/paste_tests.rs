@@ -0,0 +1,126 @@
+//! Tests for pastes on the main screen
+
+use super::*;
+use crate::test_utils::test_helpers::{key, test_app};
+
+const DOCUMENT: &str = "{\n  \"name\": \"jiq\",\n  \"tags\": [1, 2]\n}";
+
+#[test]
+fn test_multi_line_json_looks_like_a_document() {
+    assert!(looks_like_document(DOCUMENT));
+    assert!(looks_like_document("{\"a\": 1}\n{\"a\": 2}\n"));
+}
+
+#[test]
+fn test_long_single_line_json_looks_like_a_document() {
+    assert!(looks_like_document(
+        r#"{"users": [{"name": "Alice"}, {"name": "Bob"}]}"#
+    ));
+}
+
+#[test]
+fn test_short_json_and_queries_stay_queries() {
+    assert!(!looks_like_document("[1, 2]"));
+    assert!(!looks_like_document(r#"{"a": 1}"#));
+    assert!(!looks_like_document(".users[] | .name"));
+    assert!(!looks_like_document("{name: .user}\n| keys"));
+    assert!(!looks_like_document("1\n2\n3"));
+}
+
+#[test]
+fn test_multi_line_query_is_pasted_on_one_line() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+
+    app.handle_paste_event(".name\n| length".to_string());
+
+    assert_eq!(app.query(), ".name | length");
+    assert_eq!(app.input.textarea.lines().len(), 1);
+}
+
+#[test]
+fn test_paste_is_one_undo_step() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.input.textarea.insert_str(".na");
+
+    app.handle_paste_event("me | length".to_string());
+    app.input.textarea.undo();
+
+    assert_eq!(app.query(), ".na");
+}
+
+#[test]
+fn test_paste_runs_what_was_typed_before_it_once() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.debouncer.schedule_execution();
+
+    app.handle_paste_event(".name".to_string());
+
+    assert!(!app.debouncer.has_pending());
+    assert!(app.query.as_ref().unwrap().result.is_ok());
+}
+
+#[test]
+fn test_pasted_json_is_offered_instead_of_inserted() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+
+    app.handle_paste_event(DOCUMENT.to_string());
+
+    assert_eq!(app.query(), "");
+    assert_eq!(app.paste_offer.as_deref(), Some(DOCUMENT));
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|m| m.contains("new tab"))
+    );
+}
+
+#[test]
+fn test_enter_opens_pasted_json_in_a_new_tab() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.handle_paste_event(DOCUMENT.to_string());
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.paste_offer.is_none());
+    assert!(app.tabs.has_multiple());
+    assert!(app.paste_recovery.is_none());
+    let query = app.query.as_ref().unwrap();
+    assert_eq!(query.executor.json_input(), DOCUMENT);
+    assert!(!app.should_quit());
+}
+
+#[test]
+fn test_esc_pastes_json_into_the_query() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.handle_paste_event(r#"{"name": .name, "size": (.tags | length)}"#.to_string());
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(app.paste_offer.is_none());
+    assert_eq!(app.query(), r#"{"name": .name, "size": (.tags | length)}"#);
+    assert!(!app.tabs.has_multiple());
+}
+
+#[test]
+fn test_other_keys_drop_the_offer_and_go_on() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.handle_paste_event(DOCUMENT.to_string());
+
+    app.handle_key_event(key(KeyCode::Char('.')));
+
+    assert!(app.paste_offer.is_none());
+    assert_eq!(app.query(), ".");
+    assert!(!app.notification.is_showing_hint());
+}
+
+#[test]
+fn test_offer_goes_with_its_hint() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.handle_paste_event(DOCUMENT.to_string());
+    assert!(!app.expire_paste_offer());
+
+    app.notification.dismiss_hints();
+
+    assert!(app.expire_paste_offer());
+    assert!(app.paste_offer.is_none());
+}
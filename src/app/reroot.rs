@@ -0,0 +1,92 @@
+//! `R`, `Backspace` and `B` in the results pane: re-root the document at
+//! the current result, go back one level, or jump back to any level shown
+//! in the breadcrumb bar. See [`crate::root_stack`].
+
+use super::app_state::App;
+use crate::root_stack::RootLevel;
+
+impl App {
+    /// Make the current result the input, pushing the document and query
+    /// it replaces
+    pub fn reroot(&mut self) {
+        let Some(query_state) = &self.query else {
+            return;
+        };
+        if matches!(self.query().trim(), "" | ".") {
+            self.notification
+                .show("Type a query to narrow the document first");
+            return;
+        }
+        if query_state.is_pending() {
+            self.notification.show("Wait for the query to finish");
+            return;
+        }
+        if query_state.result.is_err() || query_state.is_empty_result {
+            self.notification.show("No result to re-root at");
+            return;
+        }
+        let Some(result) = query_state.last_successful_result_unformatted.clone() else {
+            return;
+        };
+        let is_json = serde_json::Deserializer::from_str(&result)
+            .into_iter::<serde_json::Value>()
+            .all(|value| value.is_ok());
+        if !is_json {
+            self.notification
+                .show("Result is not JSON; turn off raw output to re-root");
+            return;
+        }
+
+        let query = self.query().to_string();
+        self.root_stack.push(RootLevel {
+            input: query_state.executor.json_input().clone(),
+            query: query.clone(),
+            input_paths: std::mem::take(&mut self.input_paths),
+            input_files: self.input_files.take(),
+            query_undo: std::mem::take(&mut self.query_undo),
+        });
+        self.initialize_from_json(result.as_str().to_string());
+        self.set_query_text("");
+        self.notification
+            .show(&format!("Re-rooted at {} · Backspace to go back", query));
+    }
+
+    /// Restore the document and query the last re-root replaced
+    pub fn pop_root(&mut self) {
+        match self.root_stack.depth() {
+            0 => self.notification.show("Already at the original document"),
+            depth => self.jump_to_root(depth - 1),
+        }
+    }
+
+    /// Restore the document at `depth` in the breadcrumb bar, 0 being the
+    /// original, with the query that was re-rooted there
+    pub fn jump_to_root(&mut self, depth: usize) {
+        let Some(level) = self.root_stack.pop_to(depth) else {
+            self.notification
+                .show(&format!("No level {} to go back to", depth));
+            return;
+        };
+        self.initialize_from_json(level.input);
+        self.input_paths = level.input_paths;
+        self.input_files = level.input_files;
+        self.query_undo = level.query_undo;
+        self.set_query_text(&level.query);
+        self.notification.show(&format!("Back to {}", level.query));
+    }
+
+    fn set_query_text(&mut self, text: &str) {
+        self.input.textarea.delete_line_by_head();
+        self.input.textarea.delete_line_by_end();
+        self.input.textarea.insert_str(text);
+        self.input.reset_manual_scroll();
+        self.results_scroll.reset();
+        self.results_cursor.reset();
+        self.error_overlay_visible = false;
+        crate::editor::editor_events::execute_query(self);
+    }
+}
+
+#[cfg(test)]
+#[path = "reroot_tests.rs"]
+mod reroot_tests;
@@ -0,0 +1,165 @@
+use ratatui::crossterm::event::KeyCode;
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{key, test_app, wait_for_query_completion};
+
+const DOC: &str =
+    r#"{"data":{"users":[{"name":"ada","active":true},{"name":"bob","active":false}]}}"#;
+
+fn app_after(query: &str) -> App {
+    let mut app = test_app(DOC);
+    app.input.textarea.insert_str(query);
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 2000));
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+fn input_text(app: &App) -> String {
+    app.query
+        .as_ref()
+        .unwrap()
+        .executor
+        .json_input()
+        .to_string()
+}
+
+#[test]
+fn r_makes_the_result_the_input_and_clears_the_query() {
+    let mut app = app_after(".data.users");
+
+    app.handle_key_event(key(KeyCode::Char('R')));
+
+    assert_eq!(app.query(), "");
+    let input: serde_json::Value = serde_json::from_str(&input_text(&app)).unwrap();
+    assert_eq!(input[0]["name"], "ada");
+    assert!(!app.root_stack.is_empty());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Re-rooted at .data.users · Backspace to go back")
+    );
+}
+
+#[test]
+fn queries_run_against_the_new_root() {
+    let mut app = app_after(".data.users");
+    app.reroot();
+
+    app.input.textarea.insert_str("map(.name)");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    let result = app.query.as_ref().unwrap().result.clone().unwrap();
+    assert!(
+        result.contains("ada") && result.contains("bob"),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn backspace_restores_the_document_and_query() {
+    let mut app = app_after(".data.users");
+    app.input_paths = vec!["/data/users.json".into()];
+    app.reroot();
+    assert!(app.input_paths.is_empty());
+
+    app.handle_key_event(key(KeyCode::Backspace));
+
+    assert_eq!(input_text(&app), DOC);
+    assert_eq!(app.query(), ".data.users");
+    assert_eq!(
+        app.input_paths,
+        [std::path::PathBuf::from("/data/users.json")]
+    );
+    assert!(app.root_stack.is_empty());
+}
+
+#[test]
+fn backspace_at_the_original_document_says_so() {
+    let mut app = app_after(".data");
+
+    app.pop_root();
+
+    assert_eq!(input_text(&app), DOC);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Already at the original document")
+    );
+}
+
+#[test]
+fn identity_query_is_not_re_rooted() {
+    let mut app = app_after(".");
+
+    app.reroot();
+
+    assert!(app.root_stack.is_empty());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Type a query to narrow the document first")
+    );
+}
+
+#[test]
+fn failing_query_is_not_re_rooted() {
+    let mut app = app_after(".data |");
+
+    app.reroot();
+
+    assert!(app.root_stack.is_empty());
+    assert_eq!(input_text(&app), DOC);
+}
+
+#[test]
+fn re_roots_stack_up() {
+    let mut app = app_after(".data");
+    app.reroot();
+    app.input.textarea.insert_str(".users[0]");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 2000));
+    app.reroot();
+
+    assert_eq!(
+        input_text(&app).trim(),
+        "{\n  \"name\": \"ada\",\n  \"active\": true\n}"
+    );
+
+    app.pop_root();
+    assert_eq!(app.query(), ".users[0]");
+    app.pop_root();
+    assert_eq!(app.query(), ".data");
+    assert_eq!(input_text(&app), DOC);
+}
+
+#[test]
+fn b_and_a_digit_jump_to_that_level() {
+    let mut app = app_after(".data");
+    app.reroot();
+    app.input.textarea.insert_str(".users");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 2000));
+    app.reroot();
+
+    app.handle_key_event(key(KeyCode::Char('B')));
+    app.handle_key_event(key(KeyCode::Char('0')));
+
+    assert_eq!(input_text(&app), DOC);
+    assert_eq!(app.query(), ".data");
+    assert!(app.root_stack.is_empty());
+}
+
+#[test]
+fn b_and_the_live_level_says_there_is_nothing_to_go_back_to() {
+    let mut app = app_after(".data");
+    app.reroot();
+
+    app.handle_key_event(key(KeyCode::Char('B')));
+    app.handle_key_event(key(KeyCode::Char('1')));
+
+    assert_eq!(app.root_stack.depth(), 1);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No level 1 to go back to")
+    );
+}
@@ -0,0 +1,133 @@
+//! Scrollbar dragging
+//!
+//! A left press on a scrollbar (the right border of the results pane, help
+//! popup, AI window or history list) jumps there, and dragging keeps the
+//! content following the pointer until the button is released, even if the
+//! pointer strays off the border.
+
+use ratatui::crossterm::event::MouseEvent;
+use ratatui::layout::Rect;
+
+use super::app_state::App;
+use crate::history::history_render::{self, HISTORY_SEARCH_HEIGHT};
+use crate::layout::Region;
+use crate::scroll::Scrollable;
+use crate::widgets::scrollbar;
+
+/// Start a drag when the press is on a scrollbar with somewhere to scroll.
+/// Returns whether the press was taken.
+pub fn handle_press(app: &mut App, region: Option<Region>, mouse: MouseEvent) -> bool {
+    let Some(region) = region else {
+        return false;
+    };
+    // A press outside these closes them instead
+    if (app.help.visible && region != Region::HelpPopup) || app.error_overlay_visible {
+        return false;
+    }
+    let Some(track) = track(app, region) else {
+        return false;
+    };
+    let on_track = mouse.column == track.x
+        && mouse.row >= track.y
+        && mouse.row < track.y.saturating_add(track.height);
+    if !on_track || max_scroll(app, region) == 0 {
+        return false;
+    }
+    app.double_click.reset();
+    app.scrollbar_drag = Some(region);
+    scroll_to_row(app, region, track, mouse.row);
+    true
+}
+
+/// Follow the pointer while a drag is under way. Returns whether one was.
+pub fn handle_drag(app: &mut App, mouse: MouseEvent) -> bool {
+    let Some(region) = app.scrollbar_drag else {
+        return false;
+    };
+    match track(app, region) {
+        Some(track) => scroll_to_row(app, region, track, mouse.row),
+        // The popup closed mid-drag
+        None => app.scrollbar_drag = None,
+    }
+    true
+}
+
+pub fn handle_release(app: &mut App) {
+    app.scrollbar_drag = None;
+}
+
+/// The scrollbar column of `region`, between the border's corners
+fn track(app: &App, region: Region) -> Option<Rect> {
+    let regions = &app.layout_regions;
+    let (rect, track_height) = match region {
+        Region::ResultsPane => {
+            let rect = regions.results_pane?;
+            (rect, rect.height.saturating_sub(2))
+        }
+        Region::HelpPopup => {
+            let rect = regions.help_popup?;
+            (rect, rect.height.saturating_sub(2))
+        }
+        Region::AiWindow => {
+            let rect = regions.ai_window?;
+            (rect, rect.height.saturating_sub(2))
+        }
+        // The search box sits under the list
+        Region::HistoryPopup => {
+            let rect = history_render::list_column(app, regions.history_popup?);
+            let list_height = rect.height.saturating_sub(HISTORY_SEARCH_HEIGHT);
+            (rect, list_height.saturating_sub(2))
+        }
+        _ => return None,
+    };
+    if rect.width == 0 || track_height == 0 {
+        return None;
+    }
+    Some(Rect {
+        x: rect.x + rect.width - 1,
+        y: rect.y.saturating_add(1),
+        width: 1,
+        height: track_height,
+    })
+}
+
+fn max_scroll(app: &App, region: Region) -> usize {
+    match region {
+        Region::ResultsPane => app.results_scroll.max_offset as usize,
+        Region::HelpPopup => app.help.current_scroll().max_offset as usize,
+        Region::AiWindow => app.ai.selection.max_scroll(),
+        Region::HistoryPopup => app.history.max_scroll(),
+        _ => 0,
+    }
+}
+
+fn scroll_to_row(app: &mut App, region: Region, track: Rect, row: u16) {
+    let max = max_scroll(app, region);
+    let offset = scrollbar::offset_at(row.saturating_sub(track.y), track.height, max);
+    match region {
+        Region::ResultsPane => {
+            if offset < max {
+                crate::results::follow_events::stop(app);
+            }
+            app.results_scroll.offset = offset as u16;
+        }
+        Region::HelpPopup => app.help.current_scroll_mut().offset = offset as u16,
+        Region::AiWindow => scroll_list_to(&mut app.ai.selection, offset),
+        // Newest entries are at the bottom, so the bar runs the other way
+        Region::HistoryPopup => scroll_list_to(&mut app.history, max - offset),
+        _ => {}
+    }
+}
+
+fn scroll_list_to(list: &mut dyn Scrollable, offset: usize) {
+    let current = list.scroll_offset();
+    if offset > current {
+        list.scroll_view_down(offset - current);
+    } else {
+        list.scroll_view_up(current - offset);
+    }
+}
+
+#[cfg(test)]
+#[path = "scrollbar_drag_tests.rs"]
+mod scrollbar_drag_tests;
@@ -0,0 +1,120 @@
+use ratatui::crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+
+use crate::app::App;
+use crate::app::mouse_events::handle_mouse_event;
+use crate::history::MAX_VISIBLE_HISTORY;
+use crate::scroll::Scrollable;
+use crate::test_utils::test_helpers::test_app;
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn press(app: &mut App, column: u16, row: u16) {
+    handle_mouse_event(
+        app,
+        mouse(MouseEventKind::Down(MouseButton::Left), column, row),
+    );
+}
+
+fn drag(app: &mut App, column: u16, row: u16) {
+    handle_mouse_event(
+        app,
+        mouse(MouseEventKind::Drag(MouseButton::Left), column, row),
+    );
+}
+
+fn release(app: &mut App, column: u16, row: u16) {
+    handle_mouse_event(
+        app,
+        mouse(MouseEventKind::Up(MouseButton::Left), column, row),
+    );
+}
+
+/// Results pane at (0, 0) 40x12: the scrollbar is column 39, rows 1-10
+fn app_with_long_results() -> App {
+    let mut app = test_app(r#"{"test": "data"}"#);
+    app.layout_regions.results_pane = Some(Rect::new(0, 0, 40, 12));
+    app.results_scroll.viewport_height = 10;
+    app.results_scroll.max_offset = 90;
+    app
+}
+
+#[test]
+fn press_on_the_results_scrollbar_jumps_there() {
+    let mut app = app_with_long_results();
+
+    press(&mut app, 39, 10);
+
+    assert_eq!(app.results_scroll.offset, 90);
+    assert!(app.scrollbar_drag.is_some());
+}
+
+#[test]
+fn drag_follows_the_pointer_off_the_border_until_release() {
+    let mut app = app_with_long_results();
+    press(&mut app, 39, 1);
+    assert_eq!(app.results_scroll.offset, 0);
+
+    drag(&mut app, 20, 4);
+    assert_eq!(app.results_scroll.offset, 30);
+
+    release(&mut app, 20, 4);
+    drag(&mut app, 20, 10);
+    assert_eq!(app.results_scroll.offset, 30);
+    assert!(app.scrollbar_drag.is_none());
+}
+
+#[test]
+fn press_inside_the_pane_is_a_normal_click() {
+    let mut app = app_with_long_results();
+
+    press(&mut app, 20, 5);
+
+    assert!(app.scrollbar_drag.is_none());
+    assert_eq!(app.results_scroll.offset, 0);
+}
+
+#[test]
+fn press_on_the_border_without_overflow_is_a_normal_click() {
+    let mut app = app_with_long_results();
+    app.results_scroll.max_offset = 0;
+
+    press(&mut app, 39, 5);
+
+    assert!(app.scrollbar_drag.is_none());
+}
+
+#[test]
+fn press_on_the_results_scrollbar_closes_help_instead() {
+    let mut app = app_with_long_results();
+    app.help.visible = true;
+
+    press(&mut app, 39, 10);
+
+    assert!(!app.help.visible);
+    assert_eq!(app.results_scroll.offset, 0);
+}
+
+#[test]
+fn history_scrollbar_runs_from_oldest_at_the_top() {
+    let mut app = test_app(r#"{"test": "data"}"#);
+    for i in 0..30 {
+        app.history.add_entry_in_memory(&format!(".q{}", i));
+    }
+    app.history.open(None);
+    let list_height = MAX_VISIBLE_HISTORY as u16 + 2;
+    app.layout_regions.history_popup = Some(Rect::new(0, 0, 40, list_height + 3));
+
+    press(&mut app, 39, 1);
+    assert_eq!(app.history.scroll_offset(), app.history.max_scroll());
+
+    drag(&mut app, 39, MAX_VISIBLE_HISTORY as u16);
+    assert_eq!(app.history.scroll_offset(), 0);
+}
@@ -21,6 +21,7 @@ use ratatui::widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap};
 
 use crate::input::loader::ClipboardPeek;
 use crate::input::{SourceChoice, SourcePickerState};
+use crate::str_utils::fmt_bytes;
 use crate::theme;
 
 /// Hard cap on bytes scanned when building the preview head. The
@@ -211,19 +212,6 @@ fn bottom_hints(selection: SourceChoice) -> Line<'static> {
     theme::border_hints::build_hints(&entries, theme::input::mode_insert())
 }
 
-/// Format a byte count using SI-style suffixes (B / KB / MB).
-fn fmt_bytes(n: usize) -> String {
-    const KB: usize = 1024;
-    const MB: usize = 1024 * 1024;
-    if n >= MB {
-        format!("{:.1} MB", n as f64 / MB as f64)
-    } else if n >= KB {
-        format!("{:.1} KB", n as f64 / KB as f64)
-    } else {
-        format!("{} B", n)
-    }
-}
-
 #[cfg(test)]
 #[path = "source_picker_render_tests.rs"]
 mod source_picker_render_tests;
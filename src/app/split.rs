@@ -0,0 +1,152 @@
+//! Split view: two queries side by side against the same document.
+//!
+//! The focused pane's state is live on [`App`]; the other pane is a
+//! parked [`Workspace`] over the same input. Focus cycles through all
+//! four panes — left query, left results, right query, right results —
+//! and crossing to the other side swaps the two. The renderer swaps the
+//! parked pane in just long enough to draw it.
+
+use super::workspace::Workspace;
+use crate::app::app_state::{App, Focus};
+
+pub struct SplitState {
+    other: Option<Box<Workspace>>,
+    /// Whether the live pane is the right-hand one
+    right_live: bool,
+    /// Set while the parked pane is being drawn, so it renders unfocused
+    drawing_parked: bool,
+}
+
+impl Default for SplitState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SplitState {
+    pub fn new() -> Self {
+        Self {
+            other: None,
+            right_live: false,
+            drawing_parked: false,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.other.is_some()
+    }
+
+    pub fn right_live(&self) -> bool {
+        self.right_live
+    }
+}
+
+impl App {
+    /// Whether `pane` has keyboard focus and should be drawn as focused
+    pub fn has_focus(&self, pane: Focus) -> bool {
+        self.focus == pane && !self.split.drawing_parked
+    }
+
+    /// Open the split with a fresh query on the right, or close it
+    /// keeping the focused pane
+    pub fn toggle_split(&mut self) {
+        if self.split.is_active() {
+            self.split.other = None;
+            self.split.right_live = false;
+            self.notification.show("Split view closed");
+            self.mark_dirty();
+            return;
+        }
+
+        let Some(mut other) = Workspace::new_split(self) else {
+            self.notification.show_warning("No document loaded");
+            return;
+        };
+        self.settle_pending_query();
+        let came_from_results = self.focus == Focus::ResultsPane;
+        other.swap_pane_with(self);
+        self.split.other = Some(Box::new(other));
+        self.split.right_live = true;
+        if came_from_results {
+            self.focus = Focus::ResultsPane;
+            self.focus_input_field();
+        }
+        self.update_stats();
+        self.after_pane_change();
+    }
+
+    /// Move to the next of the four panes
+    ///
+    /// Without a split this is the plain query ↔ results toggle.
+    pub fn cycle_focus(&mut self) {
+        match self.focus {
+            Focus::InputField => self.focus_results_pane(),
+            Focus::ResultsPane if self.split.is_active() => {
+                self.switch_split_side();
+                self.focus = Focus::ResultsPane;
+                self.focus_input_field();
+            }
+            Focus::ResultsPane => self.focus_input_field(),
+        }
+    }
+
+    fn switch_split_side(&mut self) {
+        let Some(mut other) = self.split.other.take() else {
+            return;
+        };
+        self.settle_pending_query();
+        other.swap_pane_with(self);
+        self.split.other = Some(other);
+        self.split.right_live = !self.split.right_live;
+        self.after_pane_change();
+    }
+
+    /// Poll the parked pane's query so results typed just before a
+    /// focus change still land while it is parked
+    pub(super) fn poll_split_query(&mut self) -> bool {
+        let Some(mut other) = self.split.other.take() else {
+            return false;
+        };
+        other.swap_pane_with(self);
+        let completed = self
+            .query
+            .as_mut()
+            .is_some_and(|q| q.poll_response().is_some());
+        if completed {
+            self.update_stats();
+        }
+        other.swap_pane_with(self);
+        self.split.other = Some(other);
+        completed
+    }
+
+    /// Run `draw` with the parked pane swapped in and drawn unfocused
+    ///
+    /// Layout regions recorded while drawing are discarded: mouse input
+    /// only targets the live pane.
+    pub(super) fn with_parked_pane(&mut self, draw: impl FnOnce(&mut App)) {
+        let Some(mut other) = self.split.other.take() else {
+            return;
+        };
+        let regions = self.layout_regions.clone();
+        other.swap_pane_with(self);
+        self.split.drawing_parked = true;
+        draw(self);
+        self.split.drawing_parked = false;
+        other.swap_pane_with(self);
+        self.split.other = Some(other);
+        self.layout_regions = regions;
+    }
+
+    fn after_pane_change(&mut self) {
+        self.autocomplete.hide();
+        if self.query.is_some() {
+            self.update_tooltip();
+        }
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+#[path = "split_tests.rs"]
+mod split_tests;
@@ -0,0 +1,168 @@
+//! Tests for split view
+
+use super::*;
+use crate::app::app_render_tests::render_to_string;
+use crate::test_utils::test_helpers::{app_with_query, test_app};
+
+fn split_app() -> App {
+    let mut app = app_with_query(".name");
+    app.toggle_split();
+    app.input.textarea.insert_str(".age");
+    if let Some(query) = &mut app.query {
+        query.execute(".age");
+    }
+    app
+}
+
+#[test]
+fn test_toggle_split_opens_right_pane_on_same_document() {
+    let mut app = app_with_query(".name");
+    let document = app
+        .query
+        .as_ref()
+        .unwrap()
+        .executor
+        .json_input()
+        .to_string();
+
+    app.toggle_split();
+
+    assert!(app.split.is_active());
+    assert!(app.split.right_live());
+    assert_eq!(app.query(), "");
+    assert_eq!(app.focus, Focus::InputField);
+    assert_eq!(app.query.as_ref().unwrap().executor.json_input(), &document);
+}
+
+#[test]
+fn test_toggle_split_without_document_warns() {
+    let mut app = test_app("{}");
+    app.query = None;
+
+    app.toggle_split();
+
+    assert!(!app.split.is_active());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No document loaded")
+    );
+}
+
+#[test]
+fn test_cycle_focus_visits_all_four_panes() {
+    let mut app = split_app();
+    let mut visited = Vec::new();
+    for _ in 0..4 {
+        visited.push((app.split.right_live(), app.focus, app.query().to_string()));
+        app.cycle_focus();
+    }
+
+    assert_eq!(
+        visited,
+        vec![
+            (true, Focus::InputField, ".age".to_string()),
+            (true, Focus::ResultsPane, ".age".to_string()),
+            (false, Focus::InputField, ".name".to_string()),
+            (false, Focus::ResultsPane, ".name".to_string()),
+        ]
+    );
+    assert!(app.split.right_live());
+    assert_eq!(app.focus, Focus::InputField);
+}
+
+#[test]
+fn test_cycle_focus_without_split_toggles_panes() {
+    let mut app = app_with_query(".name");
+    app.cycle_focus();
+    assert_eq!(app.focus, Focus::ResultsPane);
+    app.cycle_focus();
+    assert_eq!(app.focus, Focus::InputField);
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_close_split_keeps_focused_pane() {
+    let mut app = split_app();
+
+    app.toggle_split();
+
+    assert!(!app.split.is_active());
+    assert!(!app.split.right_live());
+    assert_eq!(app.query(), ".age");
+}
+
+#[test]
+fn test_has_focus_is_false_while_drawing_parked_pane() {
+    let mut app = split_app();
+    assert!(app.has_focus(Focus::InputField));
+
+    let mut parked_focus = None;
+    app.with_parked_pane(|app| {
+        parked_focus = Some((app.query().to_string(), app.has_focus(app.focus)));
+    });
+
+    assert_eq!(parked_focus, Some((".name".to_string(), false)));
+    assert_eq!(app.query(), ".age");
+}
+
+#[test]
+fn test_split_travels_with_its_tab() {
+    let mut app = split_app();
+    app.new_tab();
+    assert!(!app.split.is_active());
+
+    app.switch_tab(0);
+
+    assert!(app.split.is_active());
+    assert_eq!(app.query(), ".age");
+}
+
+#[test]
+fn snapshot_split_view() {
+    let mut app = split_app();
+    app.notification.dismiss();
+    insta::assert_snapshot!(render_to_string(&mut app, 80, 12));
+}
+
+#[test]
+fn test_linear_layout_stacks_the_panes() {
+    let mut app = split_app();
+    app.accessibility.linear_layout = true;
+
+    render_to_string(&mut app, 80, 24);
+
+    // The right-hand pane is live and goes below, with its own query row
+    let results = app.layout_regions.results_pane.unwrap();
+    let input = app.layout_regions.input_field.unwrap();
+    assert_eq!(results.width, 80);
+    assert_eq!(input.width, 80);
+    assert!(results.y >= 10);
+    assert_eq!(input.y, results.bottom());
+}
+
+#[test]
+fn snapshot_split_linear_layout() {
+    let mut app = split_app();
+    app.notification.dismiss();
+    app.accessibility.linear_layout = true;
+
+    insta::assert_snapshot!(render_to_string(&mut app, 60, 20));
+}
+
+#[test]
+fn test_result_highlights_stay_with_their_pane() {
+    let mut app = split_app();
+    app.results_last_stage.toggle();
+    app.results_matches.add("age").unwrap();
+
+    app.cycle_focus();
+    app.cycle_focus();
+    assert!(!app.split.right_live());
+    assert!(!app.results_last_stage.is_enabled());
+    assert!(app.results_matches.is_empty());
+
+    app.cycle_focus();
+    app.cycle_focus();
+    assert!(app.results_last_stage.is_enabled());
+    assert_eq!(app.results_matches.len(), 1);
+}
@@ -0,0 +1,161 @@
+//! Tab workspaces.
+//!
+//! Each tab owns its own input document, query, results scroll/cursor
+//! and history cycling position, so two API responses can be explored
+//! side by side without reloading either. The active tab's state lives
+//! directly on [`App`] — every existing handler keeps working unchanged —
+//! and inactive tabs are parked in [`TabsState`] as [`Workspace`]s.
+//! Switching swaps the two.
+//!
+//! A new tab opens in the paste editor so a second document can be
+//! pasted in. History entries, snippets, AI settings and the rest of the
+//! session stay shared across tabs.
+
+use super::workspace::Workspace;
+use crate::app::app_state::App;
+
+/// Longest query shown in a tab label before it is cut with `…`
+const MAX_LABEL_CHARS: usize = 20;
+/// Open tabs; the active tab's slot is empty because its state is on `App`
+pub struct TabsState {
+    slots: Vec<Option<Workspace>>,
+    active: usize,
+}
+
+impl Default for TabsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TabsState {
+    pub fn new() -> Self {
+        Self {
+            slots: vec![None],
+            active: 0,
+        }
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Whether the tab bar is shown: only once there is a second tab
+    pub fn has_multiple(&self) -> bool {
+        self.slots.len() > 1
+    }
+}
+
+/// Label for a tab: its query, cut to fit, or a placeholder
+pub fn tab_label(query: &str, has_document: bool) -> String {
+    if !has_document {
+        return "paste".to_string();
+    }
+    let query = query.trim();
+    if query.is_empty() {
+        return ".".to_string();
+    }
+    if query.chars().count() > MAX_LABEL_CHARS {
+        let cut: String = query.chars().take(MAX_LABEL_CHARS - 1).collect();
+        format!("{}…", cut)
+    } else {
+        query.to_string()
+    }
+}
+
+impl App {
+    /// Open a new tab in the paste editor and switch to it
+    pub fn new_tab(&mut self) {
+        self.settle_pending_query();
+        let mut workspace = Workspace::new_paste(self);
+        workspace.swap_with(self);
+        let previous = self.tabs.active;
+        self.tabs.slots[previous] = Some(workspace);
+        self.tabs.slots.push(None);
+        self.tabs.active = self.tabs.slots.len() - 1;
+        self.after_tab_change();
+    }
+
+    /// Switch to the tab at `index`; no-op for the active or a missing tab
+    pub fn switch_tab(&mut self, index: usize) {
+        if index == self.tabs.active || index >= self.tabs.slots.len() {
+            return;
+        }
+        self.settle_pending_query();
+        let Some(mut workspace) = self.tabs.slots[index].take() else {
+            return;
+        };
+        workspace.swap_with(self);
+        let previous = self.tabs.active;
+        self.tabs.slots[previous] = Some(workspace);
+        self.tabs.active = index;
+        self.after_tab_change();
+    }
+
+    /// Switch to the next tab, wrapping around
+    pub fn next_tab(&mut self) {
+        let len = self.tabs.slots.len();
+        self.switch_tab((self.tabs.active + 1) % len);
+    }
+
+    /// Switch to the previous tab, wrapping around
+    pub fn previous_tab(&mut self) {
+        let len = self.tabs.slots.len();
+        self.switch_tab((self.tabs.active + len - 1) % len);
+    }
+
+    /// Close the active tab and show its neighbour
+    ///
+    /// Returns false when this is the only tab, which stays open.
+    pub fn close_tab(&mut self) -> bool {
+        if !self.tabs.has_multiple() {
+            return false;
+        }
+        let closed = self.tabs.active;
+        self.tabs.slots.remove(closed);
+        let index = closed.min(self.tabs.slots.len() - 1);
+        if let Some(mut workspace) = self.tabs.slots[index].take() {
+            // The closed tab's state ends up in `workspace` and is dropped
+            workspace.swap_with(self);
+        }
+        self.tabs.active = index;
+        self.debouncer.mark_executed();
+        self.after_tab_change();
+        true
+    }
+
+    /// Labels of every tab, in order
+    pub fn tab_labels(&self) -> Vec<String> {
+        self.tabs
+            .slots
+            .iter()
+            .map(|slot| match slot {
+                Some(workspace) => workspace.label(),
+                None => tab_label(self.input.query(), self.query.is_some()),
+            })
+            .collect()
+    }
+
+    /// Run a debounced query now so its result lands where it was typed
+    pub(super) fn settle_pending_query(&mut self) {
+        if self.debouncer.has_pending() {
+            crate::editor::editor_events::execute_query(self);
+            self.debouncer.mark_executed();
+        }
+    }
+
+    fn after_tab_change(&mut self) {
+        self.autocomplete.hide();
+        if self.query.is_some() {
+            self.update_tooltip();
+            if self.ai.visible && self.ai.enabled && self.ai.configured {
+                self.trigger_ai_request();
+            }
+        }
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+#[path = "tabs_tests.rs"]
+mod tabs_tests;
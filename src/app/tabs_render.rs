@@ -0,0 +1,41 @@
+//! Tab bar: one row above the panes, shown once a second tab exists.
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::theme;
+
+/// Render the tab labels, highlighting `active`
+pub fn render_bar(labels: &[String], active: usize, frame: &mut Frame, area: Rect) {
+    frame.render_widget(Paragraph::new(bar_line(labels, active)), area);
+}
+
+fn bar_line(labels: &[String], active: usize) -> Line<'static> {
+    let mut spans = Vec::with_capacity(labels.len() * 2 + 1);
+    spans.push(Span::raw(" "));
+    for (i, label) in labels.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(
+                " │ ",
+                Style::default().fg(theme::help_line::separator()),
+            ));
+        }
+        let text = format!("{} {}", i + 1, label);
+        if i == active {
+            spans.push(Span::styled(
+                format!("[{}]", text),
+                theme::help::tab_active(),
+            ));
+        } else {
+            spans.push(Span::styled(text, theme::help::tab_inactive()));
+        }
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+#[path = "tabs_render_tests.rs"]
+mod tabs_render_tests;
@@ -0,0 +1,33 @@
+use super::*;
+
+use crate::app::app_render_tests::render_to_string;
+use crate::test_utils::test_helpers::app_with_query;
+
+#[test]
+fn bar_line_brackets_active_tab() {
+    let labels = vec![".name".to_string(), "paste".to_string()];
+    let text: String = bar_line(&labels, 1)
+        .spans
+        .iter()
+        .map(|s| s.content.as_ref())
+        .collect();
+    assert_eq!(text, " 1 .name │ [2 paste]");
+}
+
+#[test]
+fn snapshot_tab_bar_with_two_tabs() {
+    let mut app = app_with_query(".name");
+    app.new_tab();
+    app.accept_paste_recovery_json(r#"{"other": true}"#.to_string());
+    app.switch_tab(0);
+    app.notification.dismiss();
+
+    insta::assert_snapshot!(render_to_string(&mut app, 80, 12));
+}
+
+#[test]
+fn no_tab_bar_with_single_tab() {
+    let mut app = app_with_query(".name");
+    let output = render_to_string(&mut app, 80, 12);
+    assert!(!output.contains("[1 .name]"));
+}
@@ -0,0 +1,128 @@
+//! Tests for tab workspaces
+
+use super::*;
+use crate::test_utils::test_helpers::{app_with_query, test_app};
+
+fn app_with_two_tabs() -> App {
+    let mut app = app_with_query(".name");
+    app.new_tab();
+    app.accept_paste_recovery_json(r#"{"other": true}"#.to_string());
+    app.input.textarea.insert_str(".other");
+    app
+}
+
+#[test]
+fn test_single_tab_by_default() {
+    let app = test_app("{}");
+    assert!(!app.tabs.has_multiple());
+    assert_eq!(app.tab_labels(), vec!["."]);
+}
+
+#[test]
+fn test_new_tab_opens_paste_editor() {
+    let mut app = app_with_query(".name");
+
+    app.new_tab();
+
+    assert_eq!(app.tabs.active(), 1);
+    assert!(app.paste_recovery.is_some());
+    assert!(app.query.is_none());
+    assert_eq!(app.query(), "");
+    assert_eq!(app.tab_labels(), vec![".name", "paste"]);
+}
+
+#[test]
+fn test_switching_restores_each_tab_state() {
+    let mut app = app_with_two_tabs();
+    assert_eq!(
+        app.query.as_ref().unwrap().executor.json_input(),
+        r#"{"other": true}"#
+    );
+
+    app.switch_tab(0);
+
+    assert_eq!(app.query(), ".name");
+    assert!(
+        app.query
+            .as_ref()
+            .unwrap()
+            .executor
+            .json_input()
+            .contains("\"name\"")
+    );
+    assert_eq!(app.tab_labels(), vec![".name", ".other"]);
+
+    app.next_tab();
+    assert_eq!(app.query(), ".other");
+}
+
+#[test]
+fn test_switching_keeps_results_scroll_per_tab() {
+    let mut app = app_with_two_tabs();
+    app.results_scroll.offset = 3;
+
+    app.switch_tab(0);
+    assert_eq!(app.results_scroll.offset, 0);
+
+    app.switch_tab(1);
+    assert_eq!(app.results_scroll.offset, 3);
+}
+
+#[test]
+fn test_previous_tab_wraps() {
+    let mut app = app_with_two_tabs();
+    app.switch_tab(0);
+
+    app.previous_tab();
+
+    assert_eq!(app.tabs.active(), 1);
+}
+
+#[test]
+fn test_switch_to_missing_tab_is_ignored() {
+    let mut app = app_with_two_tabs();
+    app.switch_tab(7);
+    assert_eq!(app.tabs.active(), 1);
+}
+
+#[test]
+fn test_close_tab_shows_neighbour() {
+    let mut app = app_with_two_tabs();
+
+    assert!(app.close_tab());
+
+    assert!(!app.tabs.has_multiple());
+    assert_eq!(app.query(), ".name");
+    assert_eq!(app.tab_labels(), vec![".name"]);
+}
+
+#[test]
+fn test_close_last_tab_is_refused() {
+    let mut app = app_with_query(".name");
+    assert!(!app.close_tab());
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_tab_label_truncates_long_queries() {
+    assert_eq!(tab_label("", false), "paste");
+    assert_eq!(tab_label("  ", true), ".");
+    let label = tab_label(".items[] | select(.price > 100) | .name", true);
+    assert_eq!(label.chars().count(), MAX_LABEL_CHARS);
+    assert!(label.ends_with('…'));
+}
+
+#[test]
+fn test_result_highlights_stay_with_their_tab() {
+    let mut app = app_with_two_tabs();
+    app.results_last_stage.toggle();
+    app.results_matches.add("other").unwrap();
+
+    app.switch_tab(0);
+    assert!(!app.results_last_stage.is_enabled());
+    assert!(app.results_matches.is_empty());
+
+    app.switch_tab(1);
+    assert!(app.results_last_stage.is_enabled());
+    assert_eq!(app.results_matches.len(), 1);
+}
@@ -0,0 +1,60 @@
+//! Stand-in for the whole UI while the terminal is too small for it.
+//!
+//! The panes, popups and help line all need some room; below
+//! [`MIN_WIDTH`] × [`MIN_HEIGHT`] they would overlap or clip, so this
+//! screen says how much room is missing instead. Everything keeps its
+//! state and comes back as it was once the terminal is large enough.
+
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Wrap};
+
+use crate::theme;
+
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 9;
+
+/// Whether the UI has room to draw in `area`
+pub fn fits(area: Rect) -> bool {
+    area.width >= MIN_WIDTH && area.height >= MIN_HEIGHT
+}
+
+/// Say that the terminal is too small, centered in `area`
+pub fn render(frame: &mut Frame, area: Rect) {
+    let lines = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(theme::input::border_error())
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "need {}x{}, have {}x{}",
+                MIN_WIDTH, MIN_HEIGHT, area.width, area.height
+            ),
+            Style::default().fg(theme::palette::text()),
+        )),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Enlarge the window, or Ctrl+C to quit",
+            Style::default().fg(theme::palette::text_muted()),
+        )),
+    ];
+    let height = (lines.len() as u16).min(area.height);
+    let text_area = Rect {
+        y: area.y + (area.height - height) / 2,
+        height,
+        ..area
+    };
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, text_area);
+}
+
+#[cfg(test)]
+#[path = "too_small_render_tests.rs"]
+mod too_small_render_tests;
@@ -0,0 +1,69 @@
+use super::*;
+
+use crate::app::app_render_tests::render_to_string;
+use crate::headless::Headless;
+use crate::test_utils::test_helpers::test_app;
+
+#[test]
+fn fits_from_the_minimum_size_up() {
+    assert!(fits(Rect::new(0, 0, MIN_WIDTH, MIN_HEIGHT)));
+    assert!(fits(Rect::new(0, 0, 200, 60)));
+    assert!(!fits(Rect::new(0, 0, MIN_WIDTH - 1, MIN_HEIGHT)));
+    assert!(!fits(Rect::new(0, 0, MIN_WIDTH, MIN_HEIGHT - 1)));
+}
+
+#[test]
+fn small_terminal_says_how_much_room_is_needed() {
+    let mut app = test_app(r#"{"name": "Alice"}"#);
+    let output = render_to_string(&mut app, 38, 9);
+
+    assert!(output.contains("Terminal too small"), "{output}");
+    assert!(output.contains("need 40x9, have 38x9"), "{output}");
+    assert!(!output.contains("Alice"), "{output}");
+    assert!(!output.contains("Query"), "{output}");
+}
+
+#[test]
+fn snapshot_too_small() {
+    let mut app = test_app(r#"{"name": "Alice"}"#);
+    insta::assert_snapshot!(render_to_string(&mut app, 38, 9));
+}
+
+#[test]
+fn tiny_terminal_does_not_panic() {
+    let mut app = test_app(r#"{"name": "Alice"}"#);
+    for (width, height) in [(1, 1), (5, 2), (39, 3), (10, 40)] {
+        let _ = render_to_string(&mut app, width, height);
+    }
+}
+
+#[test]
+fn full_ui_comes_back_as_it_was_once_large_enough() {
+    let mut jiq = Headless::builder(r#"{"name": "Alice"}"#)
+        .size(80, 20)
+        .build();
+    jiq.type_str(".name");
+    jiq.settle();
+
+    jiq.resize(30, 8);
+    assert!(jiq.screen().contains("Terminal too small"));
+
+    jiq.resize(80, 20);
+    let screen = jiq.screen();
+    assert!(!screen.contains("Terminal too small"), "{screen}");
+    assert!(screen.contains(".name"), "{screen}");
+    assert!(screen.contains("\"Alice\""), "{screen}");
+}
+
+#[test]
+fn keys_still_reach_the_query_while_too_small() {
+    let mut jiq = Headless::builder(r#"{"name": "Alice"}"#)
+        .size(30, 8)
+        .build();
+    jiq.type_str(".name");
+    jiq.settle();
+    assert_eq!(jiq.query(), ".name");
+
+    jiq.resize(80, 20);
+    assert!(jiq.screen().contains("\"Alice\""));
+}
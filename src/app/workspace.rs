@@ -0,0 +1,176 @@
+//! Parked query state for tabs and split view.
+//!
+//! The live query, input and results state sits directly on [`App`] so
+//! every handler and renderer works on it unchanged. A [`Workspace`]
+//! holds the same fields for a tab or split pane that is not live, and
+//! [`Workspace::swap_with`] exchanges the two in place.
+
+use std::path::PathBuf;
+
+use super::split::SplitState;
+use super::tabs::tab_label;
+use crate::app::app_state::{App, Focus};
+use crate::autocomplete::autocomplete_state::ValueMemo;
+use crate::input::{InputFilesState, InputState, PasteRecoveryState};
+use crate::path_at_cursor::PathAtCursorCache;
+use crate::query::QueryState;
+use crate::query_undo::{QueryUndoRing, ViewportState};
+use crate::results::cursor_state::CursorState;
+use crate::results::fold_state::FoldState;
+use crate::results::last_stage::LastStage;
+use crate::results::line_filter::LineFilterState;
+use crate::results::marks::ResultMarks;
+use crate::results::match_highlights::MatchHighlights;
+use crate::results::path_view::PathView;
+use crate::results::result_diff::DiffHighlight;
+use crate::results::result_pins::ResultPins;
+use crate::results::table_view::TableView;
+use crate::root_stack::RootStack;
+use crate::scroll::ScrollState;
+use crate::search::SearchState;
+use crate::stats::StatsState;
+
+/// Query, input and results state that is parked while not live
+pub struct Workspace {
+    input: InputState,
+    query: Option<QueryState>,
+    paste_recovery: Option<PasteRecoveryState>,
+    input_files: Option<InputFilesState>,
+    input_paths: Vec<PathBuf>,
+    focus: Focus,
+    results_scroll: ScrollState,
+    results_cursor: CursorState,
+    results_folds: FoldState,
+    results_diff: DiffHighlight,
+    results_pins: ResultPins,
+    results_marks: ResultMarks,
+    results_matches: MatchHighlights,
+    results_filter: LineFilterState,
+    results_table: TableView,
+    results_paths: PathView,
+    results_last_stage: LastStage,
+    results_follow: bool,
+    value_memo: ValueMemo,
+    error_overlay_visible: bool,
+    stats: StatsState,
+    path_at_cursor: PathAtCursorCache,
+    query_undo: QueryUndoRing,
+    root_stack: RootStack,
+    pending_viewport_restore: Option<ViewportState>,
+    search: SearchState,
+    input_json_schema: Option<String>,
+    history_cycling: Option<usize>,
+    /// A tab's split pane travels with the tab
+    split: SplitState,
+}
+
+impl Workspace {
+    fn empty(app: &App) -> Self {
+        Self {
+            input: InputState::new(),
+            query: None,
+            paste_recovery: None,
+            input_files: None,
+            input_paths: Vec::new(),
+            focus: Focus::InputField,
+            results_scroll: ScrollState::new(),
+            results_cursor: CursorState::new(),
+            results_folds: FoldState::new(),
+            results_diff: DiffHighlight::new(app.results_diff.is_enabled()),
+            results_pins: ResultPins::new(),
+            results_marks: ResultMarks::new(),
+            results_matches: MatchHighlights::new(),
+            results_filter: LineFilterState::new(),
+            results_table: TableView::new(),
+            results_paths: PathView::new(),
+            results_last_stage: LastStage::new(),
+            results_follow: false,
+            value_memo: ValueMemo::new(),
+            error_overlay_visible: false,
+            stats: StatsState::default(),
+            path_at_cursor: PathAtCursorCache::new(),
+            query_undo: QueryUndoRing::new(),
+            root_stack: RootStack::new(),
+            pending_viewport_restore: None,
+            search: SearchState::new(),
+            input_json_schema: None,
+            history_cycling: None,
+            split: SplitState::new(),
+        }
+    }
+
+    /// A blank tab waiting in the paste editor for its document
+    pub(super) fn new_paste(app: &App) -> Self {
+        Self {
+            paste_recovery: Some(PasteRecoveryState::new_explicit()),
+            ..Self::empty(app)
+        }
+    }
+
+    /// A second query pane over the live document, with an empty query
+    ///
+    /// Returns `None` while no document is loaded.
+    pub(super) fn new_split(app: &App) -> Option<Self> {
+        let json_input = app.query.as_ref()?.executor.json_input().clone();
+        let query = app.query_state_for(json_input);
+        Some(Self {
+            query: Some(query),
+            input_paths: app.source_paths(),
+            input_json_schema: app.input_json_schema.clone(),
+            ..Self::empty(app)
+        })
+    }
+
+    /// Exchange this workspace, split pane included, with the live one
+    pub(super) fn swap_with(&mut self, app: &mut App) {
+        self.swap_pane_with(app);
+        std::mem::swap(&mut self.split, &mut app.split);
+    }
+
+    /// Exchange the query, input and results state with the live one
+    pub(super) fn swap_pane_with(&mut self, app: &mut App) {
+        use std::mem::swap;
+
+        swap(&mut self.input, &mut app.input);
+        swap(&mut self.query, &mut app.query);
+        swap(&mut self.paste_recovery, &mut app.paste_recovery);
+        swap(&mut self.input_files, &mut app.input_files);
+        swap(&mut self.input_paths, &mut app.input_paths);
+        swap(&mut self.focus, &mut app.focus);
+        swap(&mut self.results_scroll, &mut app.results_scroll);
+        swap(&mut self.results_cursor, &mut app.results_cursor);
+        swap(&mut self.results_folds, &mut app.results_folds);
+        swap(&mut self.results_diff, &mut app.results_diff);
+        swap(&mut self.results_pins, &mut app.results_pins);
+        swap(&mut self.results_marks, &mut app.results_marks);
+        swap(&mut self.results_matches, &mut app.results_matches);
+        swap(&mut self.results_filter, &mut app.results_filter);
+        swap(&mut self.results_table, &mut app.results_table);
+        swap(&mut self.results_paths, &mut app.results_paths);
+        swap(&mut self.results_last_stage, &mut app.results_last_stage);
+        swap(&mut self.results_follow, &mut app.results_follow);
+        swap(&mut self.value_memo, &mut app.value_memo);
+        swap(
+            &mut self.error_overlay_visible,
+            &mut app.error_overlay_visible,
+        );
+        swap(&mut self.stats, &mut app.stats);
+        swap(&mut self.path_at_cursor, &mut app.path_at_cursor);
+        swap(&mut self.query_undo, &mut app.query_undo);
+        swap(&mut self.root_stack, &mut app.root_stack);
+        swap(
+            &mut self.pending_viewport_restore,
+            &mut app.pending_viewport_restore,
+        );
+        swap(&mut self.search, &mut app.search);
+        swap(&mut self.input_json_schema, &mut app.input_json_schema);
+
+        let cycling = app.history.cycling_index();
+        app.history.set_cycling_index(self.history_cycling);
+        self.history_cycling = cycling;
+    }
+
+    pub(super) fn label(&self) -> String {
+        tab_label(self.input.query(), self.query.is_some())
+    }
+}
@@ -1,16 +1,23 @@
+mod argument_slot;
+mod array_tunnel;
 pub mod autocomplete_render;
 pub mod autocomplete_state;
 mod brace_tracker;
+pub mod completion_usage;
 mod context;
+pub mod ghost_text;
 pub mod insertion;
 pub mod jq_functions;
 pub mod json_navigator;
+mod object_shorthand;
 pub mod path_parser;
 mod result_analyzer;
 mod scan_state;
+pub mod suggestion_preview;
 pub mod value_collector;
 pub mod value_insertion;
 pub mod value_trigger;
+mod variable_context;
 mod variable_extractor;
 
 #[cfg(test)]
@@ -26,6 +33,7 @@ mod path_parser_tests;
 mod json_navigator_tests;
 
 pub use brace_tracker::BraceTracker;
+pub use variable_extractor::free_variables;
 
 #[allow(unused_imports)]
 pub use autocomplete_state::{
@@ -33,11 +41,15 @@ pub use autocomplete_state::{
     update_suggestions_from_app,
 };
 #[cfg(test)]
+pub use context::get_suggestions;
+#[cfg(test)]
 pub use context::{EntryContext, detect_entry_context};
-pub use context::{SuggestionContext, analyze_context, get_suggestions};
+pub use context::{SuggestionContext, analyze_context, get_suggestions_with_variables};
 pub use insertion::insert_suggestion_from_app;
+pub use result_analyzer::ResultAnalyzer;
 
 use crate::query::ResultType;
+use json_navigator::NavigationCache;
 use serde_json::Value;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -47,6 +59,7 @@ pub const MIN_CHARS_FOR_AUTOCOMPLETE: usize = 1;
 #[allow(clippy::too_many_arguments)]
 pub fn update_suggestions(
     autocomplete: &mut AutocompleteState,
+    navigation: &mut NavigationCache,
     query: &str,
     cursor_pos: usize,
     result_parsed: Option<Arc<Value>>,
@@ -55,13 +68,16 @@ pub fn update_suggestions(
     all_field_names: Arc<HashSet<String>>,
     brace_tracker: &BraceTracker,
     array_sample_size: usize,
+    tunnel_arrays: bool,
+    declared_variables: &[Suggestion],
 ) {
     if query.trim().len() < MIN_CHARS_FOR_AUTOCOMPLETE {
         autocomplete.hide();
         return;
     }
 
-    let suggestions = get_suggestions(
+    let suggestions = get_suggestions_with_variables(
+        navigation,
         query,
         cursor_pos,
         result_parsed,
@@ -70,6 +86,8 @@ pub fn update_suggestions(
         all_field_names,
         brace_tracker,
         array_sample_size,
+        tunnel_arrays,
+        declared_variables,
     );
     autocomplete.update_suggestions(suggestions);
 }
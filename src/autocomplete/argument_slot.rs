@@ -0,0 +1,169 @@
+//! Argument classifier for functions whose argument has a known type.
+//!
+//! Right after `has(` or inside `del(.[`, the argument is a key name, so the
+//! popup offers the keys of the object the call runs on as string literals
+//! (`"name"`), ready to accept before any quote is typed. Inside `join(`,
+//! `ltrimstr(` and the other functions that take a string, suggestions that
+//! produce strings — string fields and string-returning functions — move to
+//! the top, and an empty `join(` offers the usual separators.
+//!
+//! Once a quote is typed, [`super::value_trigger`] takes over.
+
+use super::value_trigger::{
+    clamp_to_char_boundary, extract_pre_call_path, find_innermost_unclosed_paren,
+    fold_to_absolute_path, identifier_start, is_h_ws, is_path_byte,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArgumentKind {
+    /// A key of the object the call runs on
+    KeyName,
+    String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ArgumentSlot {
+    pub kind: ArgumentKind,
+    pub function: String,
+    /// The object the keys come from, folded to a path from the root as
+    /// in [`super::value_trigger`]; None when it is the root or unknown
+    pub lhs_path: Option<String>,
+    /// The identifier typed so far, replaced on accept
+    pub partial: String,
+    pub partial_start: usize,
+}
+
+const KEY_FUNCTIONS: &[&str] = &["has"];
+
+const STRING_FUNCTIONS: &[&str] = &[
+    "join",
+    "ltrimstr",
+    "rtrimstr",
+    "startswith",
+    "endswith",
+    "split",
+    "splits",
+    "test",
+    "match",
+    "capture",
+    "scan",
+    "sub",
+    "gsub",
+    "strptime",
+    "strftime",
+];
+
+/// Functions whose output is a string, offered first in a string argument
+const STRING_RESULTS: &[&str] = &[
+    "tostring",
+    "tojson",
+    "ascii_downcase",
+    "ascii_upcase",
+    "ltrimstr",
+    "rtrimstr",
+    "trim",
+    "ltrim",
+    "rtrim",
+    "join",
+    "implode",
+    "sub",
+    "gsub",
+    "type",
+    "todate",
+    "strftime",
+    "input_filename",
+];
+
+/// What `join(` is usually given
+pub(crate) const JOIN_SEPARATORS: &[&str] = &[r#"", ""#, r#"",""#, r#"" ""#, r#""\n""#];
+
+pub(crate) fn classify(query: &str, cursor_byte: usize) -> Option<ArgumentSlot> {
+    let cursor = clamp_to_char_boundary(query, cursor_byte);
+    let prefix = &query[..cursor];
+    let partial_start = identifier_start(prefix, cursor);
+    let partial = prefix[partial_start..].to_string();
+    let before = prefix[..partial_start].trim_end_matches(is_h_ws);
+
+    // Anywhere in a string argument: `join(`, `ltrimstr(.na`
+    if let Some(paren) = find_innermost_unclosed_paren(prefix)
+        && let Some((function, _)) = call_at(prefix, paren)
+        && STRING_FUNCTIONS.contains(&function.as_str())
+    {
+        return Some(ArgumentSlot {
+            kind: ArgumentKind::String,
+            function,
+            lhs_path: None,
+            partial,
+            partial_start,
+        });
+    }
+
+    // Only right at the start of a key argument: `has(`, `has(na`
+    if before.ends_with('(') {
+        let paren = before.len() - 1;
+        let (function, name_start) = call_at(before, paren)?;
+        if !KEY_FUNCTIONS.contains(&function.as_str()) {
+            return None;
+        }
+        let (inner, start) = extract_pre_call_path(prefix, name_start);
+        return Some(ArgumentSlot {
+            kind: ArgumentKind::KeyName,
+            function,
+            lhs_path: fold_to_absolute_path(prefix, start, inner),
+            partial,
+            partial_start,
+        });
+    }
+
+    // `del(.[` or `del(.items[0][`
+    let bracket = before
+        .len()
+        .checked_sub(1)
+        .filter(|_| before.ends_with('['))?;
+    let bytes = before.as_bytes();
+    let mut chain_start = bracket;
+    while chain_start > 0 && is_path_byte(bytes[chain_start - 1]) {
+        chain_start -= 1;
+    }
+    let chain = &before[chain_start..bracket];
+    if !chain.starts_with('.') {
+        return None;
+    }
+    let before_chain = before[..chain_start].trim_end_matches(is_h_ws);
+    let paren = before_chain.len().checked_sub(1)?;
+    let (function, name_start) = call_at(before_chain, paren)?;
+    if function != "del" {
+        return None;
+    }
+    let inner = (chain != ".").then(|| chain.to_string());
+    let lhs_path = fold_to_absolute_path(prefix, name_start, inner);
+    Some(ArgumentSlot {
+        kind: ArgumentKind::KeyName,
+        function,
+        lhs_path,
+        partial,
+        partial_start,
+    })
+}
+
+/// The name of the call whose `(` is at `paren`, and where it starts, when
+/// that paren is open and outside a string
+fn call_at(text: &str, paren: usize) -> Option<(String, usize)> {
+    if !text[paren..].starts_with('(')
+        || find_innermost_unclosed_paren(&text[..=paren]) != Some(paren)
+    {
+        return None;
+    }
+    let name_end = text[..paren].trim_end_matches(is_h_ws).len();
+    let name_start = identifier_start(text, name_end);
+    (name_start < name_end).then(|| (text[name_start..name_end].to_string(), name_start))
+}
+
+/// Whether a suggestion named `name` is a function whose output is a string
+pub(crate) fn returns_string(name: &str) -> bool {
+    STRING_RESULTS.contains(&name) || name.starts_with('@')
+}
+
+#[cfg(test)]
+#[path = "argument_slot_tests.rs"]
+mod argument_slot_tests;
@@ -0,0 +1,82 @@
+use super::*;
+
+fn classify_at_end(query: &str) -> Option<ArgumentSlot> {
+    classify(query, query.len())
+}
+
+#[test]
+fn has_takes_a_key_name() {
+    let slot = classify_at_end("has(").unwrap();
+
+    assert_eq!(slot.kind, ArgumentKind::KeyName);
+    assert_eq!(slot.function, "has");
+    assert_eq!(slot.lhs_path, None);
+    assert_eq!((slot.partial.as_str(), slot.partial_start), ("", 4));
+}
+
+#[test]
+fn partial_identifier_is_replaced() {
+    let slot = classify_at_end("has( na").unwrap();
+
+    assert_eq!((slot.partial.as_str(), slot.partial_start), ("na", 5));
+}
+
+#[test]
+fn has_after_a_pipe_keys_the_piped_path() {
+    let slot = classify_at_end(".services[] | has(").unwrap();
+
+    assert_eq!(slot.lhs_path.as_deref(), Some(".services[]"));
+}
+
+#[test]
+fn del_bracket_takes_a_key_name() {
+    let slot = classify_at_end("del(.[").unwrap();
+    assert_eq!(slot.kind, ArgumentKind::KeyName);
+    assert_eq!(slot.lhs_path, None);
+
+    let slot = classify_at_end(".services[] | del(.user[n").unwrap();
+    assert_eq!(slot.lhs_path.as_deref(), Some(".services[].user"));
+    assert_eq!(slot.partial, "n");
+}
+
+#[test]
+fn string_functions_take_a_string() {
+    for query in ["join(", "ltrimstr(", "split(x", ".a | startswith("] {
+        assert_eq!(
+            classify_at_end(query).map(|slot| slot.kind),
+            Some(ArgumentKind::String),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn other_positions_are_not_slots() {
+    for query in [
+        "map(",
+        ".[",
+        "has(.a",
+        "has(1 + ",
+        r#""has(""#,
+        "has(x)",
+        ".a[",
+        "del(.a | .[",
+    ] {
+        assert_eq!(classify_at_end(query), None, "{query}");
+    }
+}
+
+#[test]
+fn string_results_include_formats() {
+    assert!(returns_string("ascii_downcase"));
+    assert!(returns_string("@base64"));
+    assert!(!returns_string("length"));
+}
+
+#[test]
+fn anywhere_in_a_string_argument_is_a_slot() {
+    let slot = classify_at_end("ltrimstr(.na").unwrap();
+
+    assert_eq!(slot.kind, ArgumentKind::String);
+    assert_eq!(slot.partial, "na");
+}
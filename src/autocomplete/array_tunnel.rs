@@ -0,0 +1,124 @@
+//! Field suggestions that reach through an array of objects
+//!
+//! Typing `.a.bo` where `books` is an array of objects offers
+//! `books[].title` and `books | map(.title)` for each field of the
+//! elements, right after `books` itself, so drilling into the array is a
+//! single Tab. Controlled by `tunnel_arrays` in the `[autocomplete]` config
+//! section.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::autocomplete_state::{JsonFieldType, Suggestion, SuggestionType};
+use super::result_analyzer::ResultAnalyzer;
+use crate::json_path::{format_bracket_access, format_field_name, is_simple_jq_identifier};
+
+/// Element fields offered per array
+const MAX_FIELDS_PER_ARRAY: usize = 10;
+
+/// `suggestions` with tunnelling suggestions after each array-of-objects
+/// field, whose elements are looked up in `parents`
+///
+/// `parents` are the values the suggested fields belong to; arrays among
+/// them stand for their elements, as when the fields were suggested.
+pub fn with_array_tunnels(
+    suggestions: Vec<Suggestion>,
+    parents: &[&Value],
+    array_sample_size: usize,
+) -> Vec<Suggestion> {
+    let mut out = Vec::with_capacity(suggestions.len());
+    for suggestion in suggestions {
+        let tunnels = if is_array_of_objects(&suggestion) {
+            tunnels_for(&suggestion.text, parents, array_sample_size)
+        } else {
+            Vec::new()
+        };
+        out.push(suggestion);
+        out.extend(tunnels);
+    }
+    out
+}
+
+fn is_array_of_objects(suggestion: &Suggestion) -> bool {
+    suggestion.suggestion_type == SuggestionType::Field
+        && matches!(
+            &suggestion.field_type,
+            Some(JsonFieldType::ArrayOf(inner)) if **inner == JsonFieldType::Object
+        )
+}
+
+fn tunnels_for(field_text: &str, parents: &[&Value], array_sample_size: usize) -> Vec<Suggestion> {
+    // The key as the analyzer wrote it, without the `.` / `[]` prefixes
+    let bare = field_text.strip_prefix("[]").unwrap_or(field_text);
+    let bare = bare.strip_prefix('.').unwrap_or(bare);
+
+    let mut seen = HashSet::new();
+    let mut tunnels = Vec::new();
+    let mut maps = Vec::new();
+    for element in array_elements(bare, parents, array_sample_size) {
+        let Value::Object(map) = element else {
+            continue;
+        };
+        for (key, value) in map {
+            if seen.len() == MAX_FIELDS_PER_ARRAY || !seen.insert(key.as_str()) {
+                continue;
+            }
+            let field_type = Some(ResultAnalyzer::detect_json_type(value));
+            let step = if is_simple_jq_identifier(key) {
+                format!(".{}", key)
+            } else {
+                format_bracket_access(key)
+            };
+            tunnels.push(Suggestion::new_with_type(
+                format!("{}[]{}", field_text, step),
+                SuggestionType::Field,
+                field_type.clone(),
+            ));
+            maps.push(Suggestion::new_with_type(
+                format!("{} | map({})", field_text, format_field_name(".", key)),
+                SuggestionType::Field,
+                field_type,
+            ));
+        }
+    }
+    tunnels.extend(maps);
+    tunnels
+}
+
+/// Elements of the arrays stored under `bare` in `parents`
+fn array_elements<'a>(
+    bare: &str,
+    parents: &[&'a Value],
+    array_sample_size: usize,
+) -> Vec<&'a Value> {
+    let mut objects: Vec<&Value> = Vec::new();
+    for &parent in parents {
+        match parent {
+            Value::Object(_) => objects.push(parent),
+            Value::Array(items) => objects.extend(items.iter().take(array_sample_size)),
+            _ => {}
+        }
+    }
+    let Some(key) = unformat_key(bare) else {
+        return Vec::new();
+    };
+    objects
+        .into_iter()
+        .filter_map(|object| object.get(&key))
+        .filter_map(|value| value.as_array())
+        .flat_map(|items| items.iter().take(array_sample_size))
+        .collect()
+}
+
+/// Undo [`format_field_name`]: `name`, or `["odd key"]`
+fn unformat_key(bare: &str) -> Option<String> {
+    match bare.strip_prefix('[').and_then(|b| b.strip_suffix(']')) {
+        Some(quoted) => serde_json::from_str(quoted).ok(),
+        None => Some(bare.to_string()),
+    }
+}
+
+#[cfg(test)]
+#[path = "array_tunnel_tests.rs"]
+mod array_tunnel_tests;
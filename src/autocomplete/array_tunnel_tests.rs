@@ -0,0 +1,114 @@
+//! Tests for array_tunnel
+
+use super::*;
+
+const SAMPLE: usize = 10;
+
+fn books_field(text: &str) -> Suggestion {
+    Suggestion::new_with_type(
+        text,
+        SuggestionType::Field,
+        Some(JsonFieldType::ArrayOf(Box::new(JsonFieldType::Object))),
+    )
+}
+
+fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions.iter().map(|s| s.text.as_str()).collect()
+}
+
+#[test]
+fn test_tunnels_follow_the_array_field() {
+    let parent = serde_json::json!({
+        "books": [{"title": "x", "year": 1}],
+        "bar": 1
+    });
+    let suggestions = vec![
+        books_field("books"),
+        Suggestion::new_with_type("bar", SuggestionType::Field, Some(JsonFieldType::Number)),
+    ];
+
+    let out = with_array_tunnels(suggestions, &[&parent], SAMPLE);
+
+    assert_eq!(
+        texts(&out),
+        vec![
+            "books",
+            "books[].title",
+            "books[].year",
+            "books | map(.title)",
+            "books | map(.year)",
+            "bar"
+        ]
+    );
+}
+
+#[test]
+fn test_tunnels_union_element_fields() {
+    let parent = serde_json::json!({"books": [{"title": "x"}, {"isbn": "1", "title": "y"}]});
+    let out = with_array_tunnels(vec![books_field(".books")], &[&parent], SAMPLE);
+
+    assert_eq!(
+        texts(&out),
+        vec![
+            ".books",
+            ".books[].title",
+            ".books[].isbn",
+            ".books | map(.title)",
+            ".books | map(.isbn)"
+        ]
+    );
+}
+
+#[test]
+fn test_tunnel_carries_element_field_type() {
+    let parent = serde_json::json!({"books": [{"year": 1}]});
+    let out = with_array_tunnels(vec![books_field("books")], &[&parent], SAMPLE);
+    assert_eq!(out[1].field_type, Some(JsonFieldType::Number));
+}
+
+#[test]
+fn test_array_parent_stands_for_its_elements() {
+    let parent = serde_json::json!([{"books": [{"title": "x"}]}]);
+    let out = with_array_tunnels(vec![books_field("[].books")], &[&parent], SAMPLE);
+    assert_eq!(out[1].text, "[].books[].title");
+    assert_eq!(out[2].text, "[].books | map(.title)");
+}
+
+#[test]
+fn test_unusual_keys_use_bracket_access() {
+    let parent = serde_json::json!({"my-books": [{"sub title": "x"}]});
+    let out = with_array_tunnels(vec![books_field(r#"["my-books"]"#)], &[&parent], SAMPLE);
+    assert_eq!(
+        texts(&out),
+        vec![
+            r#"["my-books"]"#,
+            r#"["my-books"][]["sub title"]"#,
+            r#"["my-books"] | map(.["sub title"])"#
+        ]
+    );
+}
+
+#[test]
+fn test_element_fields_are_capped() {
+    let element: serde_json::Map<String, Value> = (0..30)
+        .map(|i| (format!("f{:02}", i), Value::from(i)))
+        .collect();
+    let parent = serde_json::json!({"books": [element]});
+    let out = with_array_tunnels(vec![books_field("books")], &[&parent], SAMPLE);
+    assert_eq!(out.len(), 1 + 2 * MAX_FIELDS_PER_ARRAY);
+}
+
+#[test]
+fn test_other_fields_are_left_alone() {
+    let parent = serde_json::json!({"tags": ["a"], "name": "x"});
+    let suggestions = vec![
+        Suggestion::new_with_type(
+            "tags",
+            SuggestionType::Field,
+            Some(JsonFieldType::ArrayOf(Box::new(JsonFieldType::String))),
+        ),
+        Suggestion::new_with_type("name", SuggestionType::Field, Some(JsonFieldType::String)),
+    ];
+    let out = with_array_tunnels(suggestions, &[&parent], SAMPLE);
+    assert_eq!(texts(&out), vec!["tags", "name"]);
+}
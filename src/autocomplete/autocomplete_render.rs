@@ -1,14 +1,14 @@
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
 };
 
 use crate::app::App;
 use crate::autocomplete::SuggestionType;
-use crate::autocomplete::autocomplete_state::MAX_VISIBLE_SUGGESTIONS;
+use crate::autocomplete::suggestion_preview::PreviewLine;
 use crate::scroll::Scrollable;
 use crate::theme;
 use crate::widgets::{popup, scrollbar};
@@ -19,6 +19,8 @@ const POPUP_PADDING: u16 = 4;
 const POPUP_OFFSET_X: u16 = 2;
 const TYPE_LABEL_SPACING: usize = 1;
 const FIELD_PREFIX_LEN: usize = 2;
+const PREVIEW_HEIGHT: u16 = 3;
+const MAX_PREVIEW_WIDTH: u16 = 80;
 
 fn get_type_label(suggestion: &crate::autocomplete::Suggestion) -> String {
     match &suggestion.suggestion_type {
@@ -49,7 +51,7 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
         return None;
     }
 
-    let visible_count = suggestions.len().min(MAX_VISIBLE_SUGGESTIONS);
+    let visible_count = suggestions.len().min(app.autocomplete.viewport());
     let popup_height = (visible_count as u16) + POPUP_BORDER_HEIGHT;
 
     let max_type_label_len = suggestions
@@ -154,12 +156,23 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
 
     popup::clear_area(frame, popup_area);
 
-    let block = Block::default()
+    let total = suggestions.len();
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(" Suggestions ")
         .border_style(Style::default().fg(theme::autocomplete::border()))
         .style(Style::default().bg(theme::autocomplete::background()));
+    if total > app.autocomplete.viewport() {
+        block = block.title_bottom(
+            Line::from(format!(
+                " {}/{} ",
+                app.autocomplete.selected_index() + 1,
+                total
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
 
     let list = List::new(items).block(block);
     frame.render_widget(list, popup_area);
@@ -171,7 +184,6 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
         width: popup_area.width,
         height: popup_area.height.saturating_sub(2),
     };
-    let total = app.autocomplete.suggestions().len();
     let viewport = app.autocomplete.viewport_size();
     let max_scroll = app.autocomplete.max_scroll();
     let clamped_offset = app.autocomplete.scroll_offset().min(max_scroll);
@@ -184,9 +196,57 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
         theme::autocomplete::scrollbar(),
     );
 
+    render_preview(app, frame, popup_area);
+
     Some(popup_area)
 }
 
+/// Render what the selected suggestion would return, just above the popup
+fn render_preview(app: &App, frame: &mut Frame, popup_area: Rect) {
+    let Some(line) = app.suggestion_preview.line() else {
+        return;
+    };
+    if popup_area.y < PREVIEW_HEIGHT {
+        return;
+    }
+
+    let (text, color) = match line {
+        PreviewLine::Value(value) => (value.as_str(), theme::autocomplete::item_normal_fg()),
+        PreviewLine::Empty => ("(no output)", theme::palette::text_muted()),
+        PreviewLine::Error(message) => (message.as_str(), theme::palette::error()),
+    };
+
+    use unicode_width::UnicodeWidthStr;
+    let frame_right = frame.area().right();
+    let wanted = (UnicodeWidthStr::width(text) as u16 + POPUP_PADDING).min(MAX_PREVIEW_WIDTH);
+    let width = wanted
+        .max(popup_area.width)
+        .min(frame_right.saturating_sub(popup_area.x));
+    let area = Rect {
+        x: popup_area.x,
+        y: popup_area.y - PREVIEW_HEIGHT,
+        width,
+        height: PREVIEW_HEIGHT,
+    };
+
+    let available = (width as usize).saturating_sub(POPUP_PADDING as usize);
+    let shown = crate::str_utils::truncate_to_width(text, available);
+
+    popup::clear_area(frame, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Preview ")
+        .border_style(Style::default().fg(theme::autocomplete::border()))
+        .style(Style::default().bg(theme::autocomplete::background()));
+    let preview = Paragraph::new(Line::from(Span::styled(
+        format!(" {}", shown),
+        Style::default().fg(color),
+    )))
+    .block(block);
+    frame.render_widget(preview, area);
+}
+
 #[cfg(test)]
 #[path = "autocomplete_render_tests.rs"]
 mod autocomplete_render_tests;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::autocomplete::suggestion_preview::SuggestionPreview;
 use crate::autocomplete::{Suggestion, SuggestionType};
 use crate::test_utils::test_helpers::test_app;
 use insta::assert_snapshot;
@@ -255,3 +256,85 @@ fn snapshot_autocomplete_scrollbar_at_bottom() {
     let output = render_autocomplete_scrollbar_test(19);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_autocomplete_position_indicator_after_paging() {
+    let json = r#"{"name": "test"}"#;
+    let mut app = test_app(json);
+    let suggestions: Vec<Suggestion> = (0..87)
+        .map(|i| Suggestion::new(format!(".field{:02}", i), SuggestionType::Field))
+        .collect();
+    app.autocomplete.update_suggestions(suggestions);
+    app.autocomplete.page_down();
+    app.autocomplete.select_next();
+
+    let mut terminal = create_test_terminal(80, 20);
+    let input_area = Rect::new(0, 15, 80, 3);
+
+    terminal
+        .draw(|f| {
+            let _ = render_popup(&app, f, input_area);
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains(" 12/87 "));
+    assert_snapshot!(output);
+}
+
+fn render_with_preview(line: PreviewLine) -> String {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.autocomplete.update_suggestions(vec![
+        Suggestion::new(".name", SuggestionType::Field),
+        Suggestion::new(".nested", SuggestionType::Field),
+    ]);
+    app.suggestion_preview = SuggestionPreview::showing(line);
+
+    let mut terminal = create_test_terminal(60, 12);
+    let input_area = Rect::new(0, 9, 60, 3);
+
+    terminal
+        .draw(|f| {
+            let _ = render_popup(&app, f, input_area);
+        })
+        .unwrap();
+
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_autocomplete_preview_above_popup() {
+    let output = render_with_preview(PreviewLine::Value(r#""test""#.to_string()));
+    assert!(output.contains("Preview"));
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_autocomplete_preview_truncated_to_screen() {
+    let value = format!(r#"{{"text":"{}"}}"#, "x".repeat(100));
+    let output = render_with_preview(PreviewLine::Value(value));
+    assert!(output.contains('…'));
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_autocomplete_preview_shows_error() {
+    let output = render_with_preview(PreviewLine::Error("Cannot index string".to_string()));
+    assert!(output.contains("Cannot index string"));
+}
+
+#[test]
+fn test_autocomplete_preview_hidden_without_result() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.autocomplete
+        .update_suggestions(vec![Suggestion::new(".name", SuggestionType::Field)]);
+
+    let mut terminal = create_test_terminal(60, 12);
+    terminal
+        .draw(|f| {
+            let _ = render_popup(&app, f, Rect::new(0, 9, 60, 3));
+        })
+        .unwrap();
+
+    assert!(!terminal.backend().to_string().contains("Preview"));
+}
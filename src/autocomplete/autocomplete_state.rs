@@ -1,7 +1,11 @@
+use std::collections::HashSet;
 use std::fmt;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::app::App;
+use crate::autocomplete::argument_slot::{self, ArgumentKind, ArgumentSlot};
+use crate::autocomplete::completion_usage::CompletionUsage;
 use crate::autocomplete::json_navigator::navigate_multi;
 use crate::autocomplete::path_parser::parse_path;
 use crate::autocomplete::update_suggestions;
@@ -51,10 +55,20 @@ pub fn update_suggestions_from_app(app: &mut App) {
     let query = app.input.query().to_string();
     let cursor_char = app.input.textarea.cursor().1;
     let cursor_pos = crate::str_utils::char_pos_to_byte_pos(&query, cursor_char);
-    let original_json = query_state.executor.json_input_parsed();
+    // Suggest from the current result alone until the input analysis is
+    // ready; `App::tick` asks again then
+    let analysis_ready = !query_state.executor.input_analysis_pending();
+    app.input_analysis_pending |= !analysis_ready;
+    let original_json = analysis_ready
+        .then(|| query_state.executor.json_input_parsed())
+        .flatten();
 
     if let Some(trigger) = classify(&query, cursor_pos) {
-        let all_string_values = query_state.executor.all_string_values();
+        let all_string_values = if analysis_ready {
+            query_state.executor.all_string_values()
+        } else {
+            Arc::default()
+        };
 
         let handled = update_value_suggestions(
             &mut app.autocomplete,
@@ -68,12 +82,42 @@ pub fn update_suggestions_from_app(app: &mut App) {
         }
     }
 
+    let all_field_names = if analysis_ready {
+        query_state.executor.all_field_names()
+    } else {
+        Arc::default()
+    };
+    let slot = argument_slot::classify(&query, cursor_pos);
+    if let Some(slot) = slot.as_ref().filter(|s| s.kind == ArgumentKind::KeyName) {
+        let keys = key_suggestions(slot, original_json.as_deref(), &all_field_names);
+        if !keys.is_empty() {
+            app.autocomplete.update_suggestions(keys);
+            return;
+        }
+    }
+
     let result_parsed = query_state.last_successful_result_parsed.clone();
     let result_type = query_state.base_type_for_suggestions.clone();
-    let all_field_names = query_state.executor.all_field_names();
+    let jq_args = query_state.executor.jq_args();
+    let declared_variables: Vec<Suggestion> = jq_args
+        .file_vars()
+        .iter()
+        .map(|var| (&var.name, var.kind.flag()))
+        .chain(
+            jq_args
+                .named_args()
+                .iter()
+                .map(|arg| (&arg.name, arg.flag())),
+        )
+        .map(|(name, flag)| {
+            Suggestion::new_with_type(format!("${}", name), SuggestionType::Variable, None)
+                .with_description(flag)
+        })
+        .collect();
 
     update_suggestions(
         &mut app.autocomplete,
+        &mut app.navigation_cache,
         &query,
         cursor_pos,
         result_parsed,
@@ -82,7 +126,36 @@ pub fn update_suggestions_from_app(app: &mut App) {
         all_field_names,
         &app.input.brace_tracker,
         app.array_sample_size,
+        app.tunnel_arrays,
+        &declared_variables,
     );
+    let file = app.source_paths().into_iter().next();
+    app.autocomplete
+        .rank_with(&app.completion_usage, file.as_deref());
+    if let Some(slot) = slot.filter(|s| s.kind == ArgumentKind::String) {
+        if slot.function == "join" && slot.partial.is_empty() {
+            let separators = argument_slot::JOIN_SEPARATORS
+                .iter()
+                .map(|separator| {
+                    Suggestion::new_with_type(
+                        *separator,
+                        SuggestionType::Value,
+                        Some(JsonFieldType::String),
+                    )
+                })
+                .collect();
+            app.autocomplete.update_suggestions(separators);
+        } else {
+            app.autocomplete.put_first(produces_string);
+        }
+    }
+    // Each `.` is a line of text, so the string functions are what's wanted
+    if app.jq_args.raw_input() {
+        app.autocomplete.put_first(|s| {
+            s.suggestion_type == SuggestionType::Function
+                && super::jq_functions::is_string_function(&s.text)
+        });
+    }
 }
 
 /// Returns `true` when value autocomplete handled this keystroke. Returns
@@ -178,6 +251,18 @@ fn lhs_resolves_to_object(json: &serde_json::Value, trigger: &ValueTrigger) -> b
         Some(p) => p,
         None => return false,
     };
+    values_at(json, path, 1)
+        .into_iter()
+        .any(|v| matches!(v, serde_json::Value::Object(_)))
+}
+
+/// The values at a folded path, which is complete: its last identifier is
+/// a field, not something being typed
+fn values_at<'a>(
+    json: &'a serde_json::Value,
+    path: &str,
+    sample_size: usize,
+) -> Vec<&'a serde_json::Value> {
     let parsed = parse_path(path);
     let mut segments = parsed.segments;
     if !parsed.partial.is_empty() {
@@ -185,10 +270,54 @@ fn lhs_resolves_to_object(json: &serde_json::Value, trigger: &ValueTrigger) -> b
             parsed.partial,
         ));
     }
-    let values = navigate_multi(json, &segments, 1);
-    values
-        .into_iter()
-        .any(|v| matches!(v, serde_json::Value::Object(_)))
+    navigate_multi(json, &segments, sample_size)
+}
+
+/// The keys of the objects a `has(`/`del(.[` call runs on, as string
+/// literals; every key in the input when the values it runs on can't be
+/// found, and none when they are arrays
+fn key_suggestions(
+    slot: &ArgumentSlot,
+    original_json: Option<&serde_json::Value>,
+    all_field_names: &HashSet<String>,
+) -> Vec<Suggestion> {
+    let values = match (original_json, &slot.lhs_path) {
+        (Some(json), Some(path)) => values_at(json, path, VALUE_SAMPLE_SIZE),
+        (Some(json), None) => vec![json],
+        (None, _) => Vec::new(),
+    };
+    let mut keys: Vec<&String> = Vec::new();
+    for value in &values {
+        if let serde_json::Value::Object(map) = value {
+            for key in map.keys() {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+    }
+    if values.is_empty() {
+        keys = all_field_names.iter().collect();
+        keys.sort();
+    }
+    let lower = slot.partial.to_lowercase();
+    keys.into_iter()
+        .filter(|key| key.to_lowercase().contains(&lower))
+        .map(|key| {
+            let literal = serde_json::Value::String(key.clone()).to_string();
+            Suggestion::new_with_type(literal, SuggestionType::Value, Some(JsonFieldType::String))
+                .with_description("key")
+        })
+        .collect()
+}
+
+/// Whether a suggestion gives a string, for an argument that takes one
+fn produces_string(suggestion: &Suggestion) -> bool {
+    match suggestion.suggestion_type {
+        SuggestionType::Field => suggestion.field_type == Some(JsonFieldType::String),
+        SuggestionType::Function => argument_slot::returns_string(&suggestion.text),
+        _ => false,
+    }
 }
 
 fn build_value_suggestions(values: &[String], partial: &str) -> Vec<Suggestion> {
@@ -320,6 +449,9 @@ pub struct AutocompleteState {
     selected_index: usize,
     scroll_offset: usize,
     is_visible: bool,
+    /// Rows the popup has for suggestions; fewer than
+    /// [`MAX_VISIBLE_SUGGESTIONS`] on a short terminal
+    viewport: usize,
 }
 
 impl Default for AutocompleteState {
@@ -335,6 +467,7 @@ impl AutocompleteState {
             selected_index: 0,
             scroll_offset: 0,
             is_visible: false,
+            viewport: MAX_VISIBLE_SUGGESTIONS,
         }
     }
 
@@ -345,6 +478,25 @@ impl AutocompleteState {
         self.is_visible = !self.suggestions.is_empty();
     }
 
+    /// Reorder the suggestions by acceptance history or name, per config
+    pub fn rank_with(&mut self, usage: &CompletionUsage, file: Option<&Path>) {
+        if self.suggestions.is_empty() {
+            return;
+        }
+        let suggestions = std::mem::take(&mut self.suggestions);
+        self.update_suggestions(usage.rank(suggestions, file));
+    }
+
+    /// Move the suggestions matching `first` ahead of the rest, each group
+    /// keeping its order
+    pub fn put_first(&mut self, first: impl Fn(&Suggestion) -> bool) {
+        let (mut suggestions, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.suggestions)
+            .into_iter()
+            .partition(|s| first(s));
+        suggestions.extend(rest);
+        self.update_suggestions(suggestions);
+    }
+
     pub fn hide(&mut self) {
         self.is_visible = false;
         self.suggestions.clear();
@@ -359,6 +511,21 @@ impl AutocompleteState {
         }
     }
 
+    /// Move the selection down one popup-height, stopping at the last item
+    pub fn page_down(&mut self) {
+        if self.suggestions.is_empty() {
+            return;
+        }
+        self.selected_index = (self.selected_index + self.viewport).min(self.suggestions.len() - 1);
+        self.adjust_scroll_to_selection();
+    }
+
+    /// Move the selection up one popup-height, stopping at the first item
+    pub fn page_up(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(self.viewport);
+        self.adjust_scroll_to_selection();
+    }
+
     pub fn select_previous(&mut self) {
         if self.selected_index > 0 {
             self.selected_index -= 1;
@@ -367,8 +534,8 @@ impl AutocompleteState {
     }
 
     fn adjust_scroll_to_selection(&mut self) {
-        if self.selected_index >= self.scroll_offset + MAX_VISIBLE_SUGGESTIONS {
-            self.scroll_offset = self.selected_index - MAX_VISIBLE_SUGGESTIONS + 1;
+        if self.selected_index >= self.scroll_offset + self.viewport {
+            self.scroll_offset = self.selected_index + 1 - self.viewport;
         } else if self.selected_index < self.scroll_offset {
             self.scroll_offset = self.selected_index;
         }
@@ -406,17 +573,34 @@ impl AutocompleteState {
         self.adjust_scroll_to_selection();
     }
 
+    /// Show at most `rows` suggestions, as many as fit above the input,
+    /// keeping the selection in view
+    pub fn fit_to(&mut self, rows: usize) {
+        let viewport = rows.clamp(1, MAX_VISIBLE_SUGGESTIONS);
+        if viewport == self.viewport {
+            return;
+        }
+        self.viewport = viewport;
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+        self.adjust_scroll_to_selection();
+    }
+
+    /// Rows the popup shows suggestions in
+    pub fn viewport(&self) -> usize {
+        self.viewport
+    }
+
     #[allow(dead_code)]
     pub fn scroll_offset(&self) -> usize {
         self.scroll_offset
     }
 
     pub fn visible_suggestions(&self) -> impl Iterator<Item = (usize, &Suggestion)> {
-        self.suggestions
+        self.suggestions()
             .iter()
             .enumerate()
             .skip(self.scroll_offset)
-            .take(MAX_VISIBLE_SUGGESTIONS)
+            .take(self.viewport)
     }
 }
 
@@ -435,13 +619,11 @@ impl Scrollable for AutocompleteState {
     }
 
     fn max_scroll(&self) -> usize {
-        self.suggestions
-            .len()
-            .saturating_sub(MAX_VISIBLE_SUGGESTIONS)
+        self.suggestions.len().saturating_sub(self.viewport)
     }
 
     fn viewport_size(&self) -> usize {
-        MAX_VISIBLE_SUGGESTIONS
+        self.viewport
     }
 }
 
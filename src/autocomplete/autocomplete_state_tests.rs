@@ -26,6 +26,41 @@ fn test_update_suggestions_from_app_when_query_none() {
     assert!(!app.autocomplete.is_visible());
 }
 
+#[test]
+fn test_raw_input_offers_string_functions_first() {
+    let mut app = app_with_query(". | s");
+    let mut jq_args = crate::query::JqArgs::new();
+    jq_args.set_raw_input(true);
+    app.jq_args = Arc::new(jq_args);
+
+    update_suggestions_from_app(&mut app);
+
+    let texts = suggestion_texts(&app);
+    let select = texts.iter().position(|t| *t == "select").unwrap();
+    assert!(texts[..select].contains(&"split"), "{:?}", texts);
+    assert!(texts[..select].contains(&"sub"), "{:?}", texts);
+}
+
+#[test]
+fn test_put_first_keeps_each_group_in_order() {
+    let mut state = AutocompleteState::new();
+    state.update_suggestions(
+        ["a1", "b1", "a2", "b2"]
+            .into_iter()
+            .map(|t| Suggestion::new(t, SuggestionType::Function))
+            .collect(),
+    );
+
+    state.put_first(|s| s.text.starts_with('b'));
+
+    let texts: Vec<&str> = state
+        .suggestions()
+        .iter()
+        .map(|s| s.text.as_str())
+        .collect();
+    assert_eq!(texts, ["b1", "b2", "a1", "a2"]);
+}
+
 #[test]
 fn test_suggestion_type_display() {
     assert_eq!(SuggestionType::Function.to_string(), "function");
@@ -176,6 +211,48 @@ fn test_scroll_adjusts_when_selection_moves_above_visible() {
     assert_eq!(state.scroll_offset(), 4);
 }
 
+#[test]
+fn test_fit_to_keeps_selection_in_a_smaller_viewport() {
+    let mut state = AutocompleteState::new();
+    let suggestions: Vec<Suggestion> = (0..15)
+        .map(|i| Suggestion::new(format!("item{}", i), SuggestionType::Field))
+        .collect();
+    state.update_suggestions(suggestions);
+    state.set_selected_index(8);
+
+    state.fit_to(4);
+
+    assert_eq!(state.viewport(), 4);
+    assert_eq!(state.scroll_offset(), 5);
+    let visible: Vec<usize> = state.visible_suggestions().map(|(i, _)| i).collect();
+    assert_eq!(visible, vec![5, 6, 7, 8]);
+}
+
+#[test]
+fn test_fit_to_growing_shows_a_full_window() {
+    let mut state = AutocompleteState::new();
+    let suggestions: Vec<Suggestion> = (0..15)
+        .map(|i| Suggestion::new(format!("item{}", i), SuggestionType::Field))
+        .collect();
+    state.update_suggestions(suggestions);
+    state.fit_to(3);
+    state.set_selected_index(14);
+    assert_eq!(state.scroll_offset(), 12);
+
+    state.fit_to(40);
+
+    assert_eq!(state.viewport(), MAX_VISIBLE_SUGGESTIONS);
+    assert_eq!(state.scroll_offset(), 5);
+    assert_eq!(state.visible_suggestions().count(), MAX_VISIBLE_SUGGESTIONS);
+}
+
+#[test]
+fn test_fit_to_shows_at_least_one_row() {
+    let mut state = AutocompleteState::new();
+    state.fit_to(0);
+    assert_eq!(state.viewport(), 1);
+}
+
 #[test]
 fn test_visible_suggestions_returns_correct_window() {
     let mut state = AutocompleteState::new();
@@ -432,3 +509,294 @@ fn test_set_selected_index_no_op_when_empty() {
     // Empty list: the guard returns early, leaving the index untouched.
     assert_eq!(state.selected_index(), 0);
 }
+
+// Tests for paging and chunked loading
+
+#[test]
+fn test_page_down_moves_a_page_and_stops_at_last() {
+    let mut state = AutocompleteState::new();
+    state.update_suggestions(create_suggestions(25));
+
+    state.page_down();
+    assert_eq!(state.selected_index(), MAX_VISIBLE_SUGGESTIONS);
+    assert_eq!(state.scroll_offset(), 1);
+
+    state.page_down();
+    state.page_down();
+    assert_eq!(state.selected_index(), 24);
+    assert_eq!(state.scroll_offset(), 15);
+}
+
+#[test]
+fn test_page_up_moves_a_page_and_stops_at_first() {
+    let mut state = AutocompleteState::new();
+    state.update_suggestions(create_suggestions(25));
+    state.set_selected_index(24);
+
+    state.page_up();
+    assert_eq!(state.selected_index(), 14);
+
+    state.page_up();
+    state.page_up();
+    assert_eq!(state.selected_index(), 0);
+    assert_eq!(state.scroll_offset(), 0);
+}
+
+#[test]
+fn test_page_down_on_empty() {
+    let mut state = AutocompleteState::new();
+    state.page_down();
+    state.page_up();
+    assert_eq!(state.selected_index(), 0);
+}
+
+#[test]
+fn test_paging_reaches_the_end_of_a_long_list() {
+    let mut state = AutocompleteState::new();
+    state.update_suggestions(create_suggestions(500));
+
+    for _ in 0..100 {
+        state.page_down();
+    }
+
+    assert_eq!(state.selected_index(), 499);
+    assert_eq!(state.selected().unwrap().text, "item499");
+    assert_eq!(state.scroll_offset(), 500 - MAX_VISIBLE_SUGGESTIONS);
+}
+
+#[test]
+fn test_scroll_view_down_stops_at_the_last_page() {
+    let mut state = AutocompleteState::new();
+    state.update_suggestions(create_suggestions(500));
+
+    state.scroll_view_down(1000);
+
+    assert_eq!(state.visible_suggestions().count(), MAX_VISIBLE_SUGGESTIONS);
+    assert_eq!(state.visible_suggestions().last().unwrap().0, 499);
+}
+
+// Usage ranking through the app pipeline
+
+#[test]
+fn test_accepted_field_ranks_first_next_time() {
+    let mut app = app_with_query(".");
+    update_suggestions_from_app(&mut app);
+    assert_eq!(suggestion_texts(&app)[0], ".name");
+
+    let city = app
+        .autocomplete
+        .suggestions()
+        .iter()
+        .find(|s| s.text == ".city")
+        .cloned()
+        .unwrap();
+    app.insert_autocomplete_suggestion(&city);
+
+    app.input.textarea.select_all();
+    app.input.textarea.cut();
+    app.input.textarea.insert_str(".");
+    update_suggestions_from_app(&mut app);
+    assert_eq!(suggestion_texts(&app)[0], ".city");
+}
+
+// Suggestions through arrays of objects
+
+const LIBRARY_JSON: &str = r#"{"a": {"books": [{"title": "x", "year": 1}], "bar": 1}}"#;
+
+/// Type `query` a character at a time, running each prefix like the
+/// debouncer would, then refresh the suggestions
+fn app_after_typing(query: &str) -> App {
+    let mut app = test_app(LIBRARY_JSON);
+    for (i, c) in query.char_indices() {
+        if let Some(query_state) = &mut app.query {
+            query_state.execute(&query[..i + c.len_utf8()]);
+        }
+    }
+    app.input.textarea.insert_str(query);
+    app.input.brace_tracker.rebuild(query);
+    app
+}
+
+#[test]
+fn test_typing_array_name_offers_tunnels() {
+    let mut app = app_after_typing(".a.bo");
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(
+        suggestion_texts(&app),
+        vec![
+            "books",
+            "books[].title",
+            "books[].year",
+            "books | map(.title)",
+            "books | map(.year)"
+        ]
+    );
+}
+
+#[test]
+fn test_accepting_tunnel_inserts_full_path() {
+    for (text, expected) in [
+        ("books[].title", ".a.books[].title"),
+        ("books | map(.year)", ".a.books | map(.year)"),
+    ] {
+        let mut app = app_after_typing(".a.bo");
+        update_suggestions_from_app(&mut app);
+        let tunnel = app
+            .autocomplete
+            .suggestions()
+            .iter()
+            .find(|s| s.text == text)
+            .cloned()
+            .unwrap();
+
+        app.insert_autocomplete_suggestion(&tunnel);
+
+        assert_eq!(app.input.query(), expected);
+    }
+}
+
+#[test]
+fn test_tunnels_mid_query_use_original_json() {
+    let mut app = app_after_typing(".a.bo | length");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Jump(0, 5));
+    update_suggestions_from_app(&mut app);
+
+    assert!(suggestion_texts(&app).contains(&"books[].title"));
+}
+
+#[test]
+fn test_no_tunnels_without_partial() {
+    let mut app = app_after_typing(".a.");
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(suggestion_texts(&app), vec!["books", "bar"]);
+}
+
+#[test]
+fn test_tunnels_disabled_by_config() {
+    let mut app = app_after_typing(".a.bo");
+    app.tunnel_arrays = false;
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(suggestion_texts(&app), vec!["books"]);
+}
+
+// Arguments of a known type (argument_slot)
+
+#[test]
+fn test_has_offers_root_keys_as_literals() {
+    let mut app = app_with_query("has(");
+
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(
+        suggestion_texts(&app),
+        [
+            r#""name""#,
+            r#""age""#,
+            r#""city""#,
+            r#""services""#,
+            r#""items""#
+        ]
+    );
+    assert_eq!(
+        app.autocomplete.suggestions()[0].suggestion_type,
+        SuggestionType::Value
+    );
+}
+
+#[test]
+fn test_has_after_pipe_offers_that_objects_keys() {
+    let mut app = app_with_query(".services[] | has(s");
+
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(suggestion_texts(&app), [r#""serviceArn""#]);
+}
+
+#[test]
+fn test_del_bracket_offers_keys() {
+    let mut app = app_with_query("del(.[na");
+
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(suggestion_texts(&app), [r#""name""#]);
+}
+
+#[test]
+fn test_accepting_key_literal_replaces_partial() {
+    let mut app = app_with_query("has(na");
+    update_suggestions_from_app(&mut app);
+    let key = app.autocomplete.suggestions()[0].clone();
+
+    crate::autocomplete::insert_suggestion_from_app(&mut app, &key);
+
+    assert_eq!(app.input.query(), r#"has("name""#);
+}
+
+#[test]
+fn test_empty_join_offers_separators() {
+    let mut app = app_with_query(".services | map(.name) | join(");
+
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(suggestion_texts(&app)[..2], [r#"", ""#, r#"",""#]);
+}
+
+#[test]
+fn test_string_argument_offers_string_fields_first() {
+    let mut app = app_with_query("ltrimstr(.");
+
+    update_suggestions_from_app(&mut app);
+
+    let types: Vec<_> = app
+        .autocomplete
+        .suggestions()
+        .iter()
+        .map(|s| s.field_type.clone())
+        .collect();
+    let first_other = types
+        .iter()
+        .position(|t| *t != Some(JsonFieldType::String))
+        .unwrap();
+    assert!(first_other > 0, "{:?}", suggestion_texts(&app));
+    assert!(
+        types[first_other..]
+            .iter()
+            .all(|t| *t != Some(JsonFieldType::String)),
+        "{:?}",
+        suggestion_texts(&app)
+    );
+}
+
+// Input of several concatenated documents
+
+const STREAM_JSON: &str = "{\"user\": {\"name\": \"ann\", \"id\": 1}}\n{\"user\": {\"name\": \"bob\", \"email\": \"b@x\"}}";
+
+#[test]
+fn test_mid_query_fields_come_from_every_document() {
+    let mut app = test_app(STREAM_JSON);
+    app.input.textarea.insert_str(".user. | length");
+    app.input.brace_tracker.rebuild(".user. | length");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Jump(0, 6));
+    update_suggestions_from_app(&mut app);
+
+    let texts = suggestion_texts(&app);
+    for field in ["name", "id", "email"] {
+        assert!(texts.contains(&field), "{:?}", texts);
+    }
+}
+
+#[test]
+fn test_values_come_from_every_document() {
+    let mut app = test_app(STREAM_JSON);
+    app.input.textarea.insert_str(r#"select(.user.name == ""#);
+    update_suggestions_from_app(&mut app);
+
+    assert_eq!(suggestion_texts(&app), ["ann", "bob"]);
+}
@@ -0,0 +1,238 @@
+//! Usage-weighted ordering of field and function suggestions
+//!
+//! Each accepted field or function suggestion is counted twice: once
+//! globally and once for the input file it was accepted against. A
+//! suggestion's score is its acceptance count decayed by how long ago it
+//! was last accepted, with the current file's usage weighted above global
+//! usage. Suggestions are stably sorted by score, so anything never
+//! accepted keeps the order the context produced.
+//!
+//! Counts persist to `completion_usage.json` next to the query history.
+//! `order = "alphabetical"` in the `[autocomplete]` config section sorts by
+//! name instead.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::autocomplete_state::{Suggestion, SuggestionType};
+use crate::config::SuggestionOrder;
+
+const USAGE_DIR: &str = "jiq";
+const USAGE_FILE: &str = "completion_usage.json";
+/// Acceptances lose half their weight every two weeks
+const HALF_LIFE_SECS: f64 = 14.0 * 24.0 * 60.0 * 60.0;
+/// Usage in the current file counts this many times global usage
+const FILE_WEIGHT: f64 = 2.0;
+/// Completions kept per scope; the least recently used are dropped first
+const MAX_ENTRIES_PER_SCOPE: usize = 500;
+/// Files with their own counts; the least recently used are dropped first
+const MAX_FILES: usize = 100;
+
+pub fn usage_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join(USAGE_DIR).join(USAGE_FILE))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+struct UsageEntry {
+    count: u32,
+    /// Unix seconds of the latest acceptance
+    last_used: u64,
+}
+
+impl UsageEntry {
+    fn score(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_used) as f64;
+        f64::from(self.count) * 0.5_f64.powf(age / HALF_LIFE_SECS)
+    }
+}
+
+type Scope = HashMap<String, UsageEntry>;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct UsageFile {
+    #[serde(default)]
+    global: Scope,
+    #[serde(default)]
+    files: HashMap<String, Scope>,
+}
+
+pub struct CompletionUsage {
+    order: SuggestionOrder,
+    usage: UsageFile,
+    persist_to_disk: bool,
+}
+
+impl CompletionUsage {
+    pub fn new(order: SuggestionOrder) -> Self {
+        Self {
+            order,
+            usage: usage_path().map(|p| load_from_path(&p)).unwrap_or_default(),
+            persist_to_disk: true,
+        }
+    }
+
+    pub fn empty(order: SuggestionOrder) -> Self {
+        Self {
+            order,
+            usage: UsageFile::default(),
+            persist_to_disk: false,
+        }
+    }
+
+    pub fn order(&self) -> SuggestionOrder {
+        self.order
+    }
+
+    /// Count an accepted suggestion, against `file` when there is one
+    pub fn record(&mut self, suggestion: &Suggestion, file: Option<&Path>) {
+        self.record_at(suggestion, file, unix_now());
+        if self.persist_to_disk
+            && let Err(e) = self.save()
+        {
+            log::warn!("Failed to save completion usage: {}", e);
+        }
+    }
+
+    fn record_at(&mut self, suggestion: &Suggestion, file: Option<&Path>, now: u64) {
+        let Some(key) = usage_key(suggestion) else {
+            return;
+        };
+        bump(&mut self.usage.global, &key, now);
+        if let Some(file) = file {
+            let scope = self.usage.files.entry(file_key(file)).or_default();
+            bump(scope, &key, now);
+            prune_files(&mut self.usage.files);
+        }
+    }
+
+    /// `suggestions` in the configured order
+    pub fn rank(&self, suggestions: Vec<Suggestion>, file: Option<&Path>) -> Vec<Suggestion> {
+        self.rank_at(suggestions, file, unix_now())
+    }
+
+    fn rank_at(
+        &self,
+        mut suggestions: Vec<Suggestion>,
+        file: Option<&Path>,
+        now: u64,
+    ) -> Vec<Suggestion> {
+        match self.order {
+            SuggestionOrder::Alphabetical => {
+                suggestions.sort_by_cached_key(|s| sort_name(&s.text).to_lowercase());
+                suggestions
+            }
+            SuggestionOrder::Usage => {
+                if self.usage.global.is_empty() {
+                    return suggestions;
+                }
+                let file_scope = file.and_then(|f| self.usage.files.get(&file_key(f)));
+                let score = |s: &Suggestion| {
+                    let Some(key) = usage_key(s) else {
+                        return 0.0;
+                    };
+                    let global = self.usage.global.get(&key).map_or(0.0, |e| e.score(now));
+                    let local = file_scope
+                        .and_then(|scope| scope.get(&key))
+                        .map_or(0.0, |e| e.score(now));
+                    global + FILE_WEIGHT * local
+                };
+                let mut scored: Vec<(f64, Suggestion)> =
+                    suggestions.into_iter().map(|s| (score(&s), s)).collect();
+                scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+                scored.into_iter().map(|(_, s)| s).collect()
+            }
+        }
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = usage_path() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Could not determine completion usage path",
+            ));
+        };
+        save_to_path(&self.usage, &path)
+    }
+}
+
+/// Fields and functions are counted; other suggestion kinds are not
+fn usage_key(suggestion: &Suggestion) -> Option<String> {
+    let kind = match suggestion.suggestion_type {
+        SuggestionType::Field => "field",
+        SuggestionType::Function => "function",
+        _ => return None,
+    };
+    Some(format!("{}:{}", kind, sort_name(&suggestion.text)))
+}
+
+/// The name without the `.` / `[].` that depends on where it is inserted
+fn sort_name(text: &str) -> &str {
+    text.trim_start_matches(['.', '[', ']'])
+}
+
+fn file_key(file: &Path) -> String {
+    file.display().to_string()
+}
+
+fn bump(scope: &mut Scope, key: &str, now: u64) {
+    let entry = scope.entry(key.to_string()).or_default();
+    entry.count = entry.count.saturating_add(1);
+    entry.last_used = now;
+    if scope.len() > MAX_ENTRIES_PER_SCOPE
+        && let Some(oldest) = scope
+            .iter()
+            .min_by_key(|(_, e)| e.last_used)
+            .map(|(k, _)| k.clone())
+    {
+        scope.remove(&oldest);
+    }
+}
+
+fn prune_files(files: &mut HashMap<String, Scope>) {
+    if files.len() <= MAX_FILES {
+        return;
+    }
+    let last_used = |scope: &Scope| scope.values().map(|e| e.last_used).max().unwrap_or(0);
+    if let Some(oldest) = files
+        .iter()
+        .min_by_key(|(_, scope)| last_used(scope))
+        .map(|(k, _)| k.clone())
+    {
+        files.remove(&oldest);
+    }
+}
+
+fn load_from_path(path: &Path) -> UsageFile {
+    let Ok(contents) = fs::read_to_string(path) else {
+        log::debug!("No completion usage file at {:?}", path);
+        return UsageFile::default();
+    };
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        log::warn!("Failed to parse completion usage: {}", e);
+        UsageFile::default()
+    })
+}
+
+/// No file locking - last writer wins if multiple instances run simultaneously.
+fn save_to_path(usage: &UsageFile, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string(usage).map_err(io::Error::other)?;
+    fs::write(path, contents)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+#[path = "completion_usage_tests.rs"]
+mod completion_usage_tests;
@@ -0,0 +1,147 @@
+//! Tests for completion_usage
+
+use super::*;
+
+const DAY: u64 = 24 * 60 * 60;
+const NOW: u64 = 1_000 * DAY;
+
+fn field(text: &str) -> Suggestion {
+    Suggestion::new(text, SuggestionType::Field)
+}
+
+fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions.iter().map(|s| s.text.as_str()).collect()
+}
+
+fn fields(names: &[&str]) -> Vec<Suggestion> {
+    names.iter().map(|n| field(n)).collect()
+}
+
+#[test]
+fn test_no_usage_keeps_context_order() {
+    let usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    let ranked = usage.rank_at(fields(&["b", "a", "c"]), None, NOW);
+    assert_eq!(texts(&ranked), vec!["b", "a", "c"]);
+}
+
+#[test]
+fn test_accepted_field_moves_to_front() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    usage.record_at(&field("c"), None, NOW);
+
+    let ranked = usage.rank_at(fields(&["b", "a", "c"]), None, NOW);
+    assert_eq!(texts(&ranked), vec!["c", "b", "a"]);
+}
+
+#[test]
+fn test_frequency_orders_accepted_fields() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    usage.record_at(&field("a"), None, NOW);
+    usage.record_at(&field("c"), None, NOW);
+    usage.record_at(&field("c"), None, NOW);
+
+    let ranked = usage.rank_at(fields(&["a", "b", "c"]), None, NOW);
+    assert_eq!(texts(&ranked), vec!["c", "a", "b"]);
+}
+
+#[test]
+fn test_recent_use_outweighs_old_frequent_use() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    for _ in 0..3 {
+        usage.record_at(&field("old"), None, NOW - 60 * DAY);
+    }
+    usage.record_at(&field("new"), None, NOW);
+
+    let ranked = usage.rank_at(fields(&["old", "new"]), None, NOW);
+    assert_eq!(texts(&ranked), vec!["new", "old"]);
+}
+
+#[test]
+fn test_current_file_usage_outweighs_global_usage() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    let here = Path::new("/data/here.json");
+    let elsewhere = Path::new("/data/elsewhere.json");
+    usage.record_at(&field("global"), Some(elsewhere), NOW);
+    usage.record_at(&field("global"), Some(elsewhere), NOW);
+    usage.record_at(&field("local"), Some(here), NOW);
+    usage.record_at(&field("local"), Some(here), NOW);
+
+    let ranked = usage.rank_at(fields(&["global", "local"]), Some(here), NOW);
+    assert_eq!(texts(&ranked), vec!["local", "global"]);
+
+    let ranked = usage.rank_at(fields(&["global", "local"]), Some(elsewhere), NOW);
+    assert_eq!(texts(&ranked), vec!["global", "local"]);
+}
+
+#[test]
+fn test_field_counts_ignore_leading_dot_and_iteration() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    usage.record_at(&field(".name"), None, NOW);
+
+    let ranked = usage.rank_at(fields(&["[].id", "[].name"]), None, NOW);
+    assert_eq!(texts(&ranked), vec!["[].name", "[].id"]);
+}
+
+#[test]
+fn test_function_and_field_with_same_name_are_separate() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    usage.record_at(&field("keys"), None, NOW);
+
+    let suggestions = vec![
+        Suggestion::new("length", SuggestionType::Function),
+        Suggestion::new("keys", SuggestionType::Function),
+    ];
+    let ranked = usage.rank_at(suggestions, None, NOW);
+    assert_eq!(texts(&ranked), vec!["length", "keys"]);
+}
+
+#[test]
+fn test_other_suggestion_kinds_are_not_counted() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    usage.record_at(&Suggestion::new("$x", SuggestionType::Variable), None, NOW);
+    assert!(usage.usage.global.is_empty());
+}
+
+#[test]
+fn test_alphabetical_order_ignores_usage() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Alphabetical);
+    usage.record_at(&field("zeta"), None, NOW);
+
+    let ranked = usage.rank_at(fields(&[".zeta", ".Beta", ".alpha"]), None, NOW);
+    assert_eq!(texts(&ranked), vec![".alpha", ".Beta", ".zeta"]);
+}
+
+#[test]
+fn test_scope_drops_least_recently_used_entry() {
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    for i in 0..=MAX_ENTRIES_PER_SCOPE {
+        usage.record_at(&field(&format!("f{}", i)), None, NOW + i as u64);
+    }
+
+    assert_eq!(usage.usage.global.len(), MAX_ENTRIES_PER_SCOPE);
+    assert!(!usage.usage.global.contains_key("field:f0"));
+}
+
+#[test]
+fn test_usage_round_trips_through_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested").join(USAGE_FILE);
+    let mut usage = CompletionUsage::empty(SuggestionOrder::Usage);
+    usage.record_at(&field("name"), Some(Path::new("/data/a.json")), NOW);
+
+    save_to_path(&usage.usage, &path).unwrap();
+    let loaded = load_from_path(&path);
+
+    assert_eq!(loaded.global["field:name"].count, 1);
+    assert_eq!(loaded.files["/data/a.json"]["field:name"].last_used, NOW);
+}
+
+#[test]
+fn test_missing_or_corrupt_file_loads_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(USAGE_FILE);
+    assert!(load_from_path(&path).global.is_empty());
+
+    fs::write(&path, "not json").unwrap();
+    assert!(load_from_path(&path).global.is_empty());
+}
@@ -1,11 +1,14 @@
+use super::array_tunnel::with_array_tunnels;
 use super::autocomplete_state::{JsonFieldType, Suggestion, SuggestionType};
 use super::brace_tracker::{BraceTracker, BraceType};
 use super::jq_functions::filter_builtins;
-use super::json_navigator::navigate_multi;
+use super::json_navigator::{NavigationCache, navigate_multi};
+use super::object_shorthand::shorthand_suggestions;
 use super::path_parser::{PathSegment, parse_path};
 use super::result_analyzer::ResultAnalyzer;
 use super::scan_state::ScanState;
-use super::variable_extractor::extract_variables;
+use super::variable_context::{context_from_variable_prefix, variable_suggestions};
+use crate::json_path::format_field_name;
 use crate::query::ResultType;
 use serde_json::Value;
 use std::collections::HashSet;
@@ -24,17 +27,6 @@ fn filter_suggestions_by_partial(suggestions: Vec<Suggestion>, partial: &str) ->
         .collect()
 }
 
-/// Filters suggestions case-sensitively (for variables which are case-sensitive in jq).
-fn filter_suggestions_case_sensitive(
-    suggestions: Vec<Suggestion>,
-    partial: &str,
-) -> Vec<Suggestion> {
-    suggestions
-        .into_iter()
-        .filter(|s| s.text.contains(partial))
-        .collect()
-}
-
 /// Skips trailing whitespace backwards from a position in the character array.
 ///
 /// # Parameters
@@ -221,6 +213,7 @@ fn get_field_suggestions(
 }
 
 /// Converts all cached field names to suggestions for non-deterministic fallback.
+/// Names that don't fit `.name` syntax are offered in bracket notation.
 fn get_all_field_suggestions(
     all_field_names: &HashSet<String>,
     needs_leading_dot: bool,
@@ -229,7 +222,7 @@ fn get_all_field_suggestions(
     all_field_names
         .iter()
         .map(|name| {
-            Suggestion::new_with_type(format!("{}{}", prefix, name), SuggestionType::Field, None)
+            Suggestion::new_with_type(format_field_name(prefix, name), SuggestionType::Field, None)
         })
         .collect()
 }
@@ -253,99 +246,6 @@ fn filter_suggestions_by_partial_if_nonempty(
     }
 }
 
-/// Checks if cursor is in a variable definition context where suggestions should not be shown.
-/// This includes positions after `as `, `label `, or inside destructuring patterns.
-fn is_in_variable_definition_context(before_cursor: &str) -> bool {
-    let dollar_pos = before_cursor.rfind('$');
-    let dollar_pos = match dollar_pos {
-        Some(pos) => pos,
-        None => return false,
-    };
-
-    let text_before_dollar = &before_cursor[..dollar_pos];
-    let trimmed = text_before_dollar.trim_end();
-
-    if is_after_definition_keyword(trimmed) {
-        return true;
-    }
-
-    if is_in_destructuring_pattern(trimmed) {
-        return true;
-    }
-
-    false
-}
-
-/// Checks if text ends with a definition keyword (as, label).
-fn is_after_definition_keyword(trimmed: &str) -> bool {
-    if trimmed.ends_with("as") {
-        if trimmed.len() == 2 {
-            return true;
-        }
-        let char_before = trimmed.chars().nth(trimmed.len() - 3);
-        if let Some(ch) = char_before {
-            return !ch.is_alphanumeric() && ch != '_';
-        }
-        return true;
-    }
-
-    if trimmed.ends_with("label") {
-        if trimmed.len() == 5 {
-            return true;
-        }
-        let char_before = trimmed.chars().nth(trimmed.len() - 6);
-        if let Some(ch) = char_before {
-            return !ch.is_alphanumeric() && ch != '_';
-        }
-        return true;
-    }
-
-    false
-}
-
-/// Checks if text indicates we're inside a destructuring pattern after `as`.
-fn is_in_destructuring_pattern(trimmed: &str) -> bool {
-    if trimmed.ends_with('[')
-        || trimmed.ends_with('{')
-        || trimmed.ends_with(',')
-        || trimmed.ends_with(':')
-    {
-        return has_unclosed_as_destructure(trimmed);
-    }
-    false
-}
-
-/// Checks if there's an unclosed destructuring pattern after `as`.
-fn has_unclosed_as_destructure(text: &str) -> bool {
-    for pattern in &[" as [", " as[", " as {", " as{"] {
-        if let Some(pos) = text.rfind(pattern) {
-            let after_as = &text[pos + pattern.len()..];
-
-            let open_brackets = after_as.chars().filter(|c| *c == '[').count();
-            let closed_brackets = after_as.chars().filter(|c| *c == ']').count();
-            let open_braces = after_as.chars().filter(|c| *c == '{').count();
-            let closed_braces = after_as.chars().filter(|c| *c == '}').count();
-
-            if pattern.contains('[') && open_brackets >= closed_brackets {
-                return true;
-            }
-            if pattern.contains('{') && open_braces >= closed_braces {
-                return true;
-            }
-        }
-    }
-
-    if text.ends_with("as [")
-        || text.ends_with("as[")
-        || text.ends_with("as {")
-        || text.ends_with("as{")
-    {
-        return true;
-    }
-
-    false
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
 pub enum SuggestionContext {
@@ -612,6 +512,7 @@ fn inject_entry_field_suggestions(suggestions: &mut Vec<Suggestion>, needs_leadi
     );
 }
 
+#[cfg(test)]
 #[allow(clippy::too_many_arguments)]
 pub fn get_suggestions(
     query: &str,
@@ -622,6 +523,39 @@ pub fn get_suggestions(
     all_field_names: Arc<HashSet<String>>,
     brace_tracker: &BraceTracker,
     array_sample_size: usize,
+) -> Vec<Suggestion> {
+    get_suggestions_with_variables(
+        &mut NavigationCache::new(),
+        query,
+        cursor_pos,
+        result_parsed,
+        result_type,
+        original_json,
+        all_field_names,
+        brace_tracker,
+        array_sample_size,
+        false,
+        &[],
+    )
+}
+
+/// Like `get_suggestions`, also offering `declared_variables` (bound outside
+/// the query, e.g. by `--rawfile`) in variable context, and suggestions
+/// through arrays of objects when `tunnel_arrays` is set. Paths navigated
+/// in `original_json` are kept in `navigation` for the next call.
+#[allow(clippy::too_many_arguments)]
+pub fn get_suggestions_with_variables(
+    navigation: &mut NavigationCache,
+    query: &str,
+    cursor_pos: usize,
+    result_parsed: Option<Arc<Value>>,
+    result_type: Option<ResultType>,
+    original_json: Option<Arc<Value>>,
+    all_field_names: Arc<HashSet<String>>,
+    brace_tracker: &BraceTracker,
+    array_sample_size: usize,
+    tunnel_arrays: bool,
+    declared_variables: &[Suggestion],
 ) -> Vec<Suggestion> {
     let before_cursor = &query[..cursor_pos.min(query.len())];
     let (context, partial) = analyze_context(before_cursor, brace_tracker);
@@ -656,6 +590,7 @@ pub fn get_suggestions(
                 if let Some(ref result) = result_parsed {
                     if let Some(nested_suggestions) = get_nested_field_suggestions(
                         result,
+                        None,
                         &path_context,
                         needs_dot,
                         suppress_array_brackets,
@@ -669,6 +604,7 @@ pub fn get_suggestions(
                         // Navigation failed, fall back to original_json
                         get_nested_field_suggestions(
                             orig,
+                            Some(&mut *navigation),
                             &path_context,
                             needs_dot,
                             suppress_array_brackets,
@@ -696,6 +632,7 @@ pub fn get_suggestions(
                 if let Some(ref orig) = original_json {
                     get_nested_field_suggestions(
                         orig,
+                        Some(&mut *navigation),
                         &path_context,
                         needs_dot,
                         suppress_array_brackets,
@@ -729,7 +666,29 @@ pub fn get_suggestions(
                 inject_entry_field_suggestions(&mut suggestions, needs_dot);
             }
 
-            filter_suggestions_by_partial_if_nonempty(suggestions, &partial)
+            let suggestions = filter_suggestions_by_partial_if_nonempty(suggestions, &partial);
+            if !tunnel_arrays || partial.is_empty() {
+                return suggestions;
+            }
+            // The cache is current only when executing with the cursor at the end
+            let parents = if !is_non_executing && is_at_end {
+                result_parsed.as_deref().into_iter().collect()
+            } else {
+                let (path_context, _) =
+                    extract_path_context_with_pipe_info(before_cursor, brace_tracker);
+                let sources = [
+                    result_parsed.as_deref().filter(|_| is_at_end),
+                    original_json.as_deref(),
+                ];
+                tunnel_parents(
+                    &sources,
+                    &path_context,
+                    suppress_array_brackets,
+                    result_type.as_ref(),
+                    array_sample_size,
+                )
+            };
+            with_array_tunnels(suggestions, &parents, array_sample_size)
         }
         SuggestionContext::FunctionContext => {
             if partial.is_empty() {
@@ -743,17 +702,18 @@ pub fn get_suggestions(
                 return Vec::new();
             }
 
+            let shorthands =
+                shorthand_suggestions(result_parsed.as_deref(), &partial, array_sample_size);
             let suggestions =
                 get_field_suggestions(result_parsed, result_type, false, true, array_sample_size);
-            filter_suggestions_by_partial(suggestions, &partial)
+            // A key typed in full is only offered again with its value
+            let mut suggestions = filter_suggestions_by_partial(suggestions, &partial);
+            suggestions.retain(|s| s.text != partial);
+            suggestions.extend(shorthands);
+            suggestions
         }
         SuggestionContext::VariableContext => {
-            let all_vars = extract_variables(query);
-            let suggestions: Vec<Suggestion> = all_vars
-                .into_iter()
-                .map(|name| Suggestion::new_with_type(name, SuggestionType::Variable, None))
-                .collect();
-            filter_suggestions_case_sensitive(suggestions, &partial)
+            variable_suggestions(query, &partial, declared_variables)
         }
     }
 }
@@ -796,24 +756,6 @@ pub fn analyze_context(
     (SuggestionContext::FunctionContext, partial)
 }
 
-/// Determines context from variable prefix ($).
-/// Returns VariableContext if typing a variable usage, None if defining a variable.
-fn context_from_variable_prefix(
-    partial: &str,
-    before_cursor: &str,
-) -> Option<(SuggestionContext, String)> {
-    if !partial.starts_with('$') {
-        return None;
-    }
-
-    if is_in_variable_definition_context(before_cursor) {
-        return None;
-    }
-
-    let var_partial = partial.to_string();
-    Some((SuggestionContext::VariableContext, var_partial))
-}
-
 pub fn find_char_before_field_access(before_cursor: &str, partial: &str) -> Option<char> {
     let search_end = if partial.is_empty() {
         before_cursor.len().saturating_sub(1)
@@ -884,7 +826,7 @@ fn find_expression_boundary(
 
             match last_boundary {
                 Some(offset) => {
-                    let boundary_char = after_brace.chars().nth(offset).unwrap_or(' ');
+                    let boundary_char = after_brace[offset..].chars().next().unwrap_or(' ');
                     ExpressionBoundary {
                         position: info.pos + 1 + offset + 1, // +1 to skip the boundary char
                         is_after_pipe: boundary_char == '|',
@@ -901,7 +843,7 @@ fn find_expression_boundary(
             let boundary_pos = before_cursor.rfind(['|', ';']);
             match boundary_pos {
                 Some(pos) => {
-                    let boundary_char = before_cursor.chars().nth(pos).unwrap_or(' ');
+                    let boundary_char = before_cursor[pos..].chars().next().unwrap_or(' ');
                     ExpressionBoundary {
                         position: pos + 1,
                         is_after_pipe: boundary_char == '|',
@@ -928,10 +870,12 @@ fn extract_path_context_with_pipe_info(
 }
 
 /// Get nested field suggestions by navigating the JSON tree.
-/// This is the core Phase 3 integration point.
+/// This is the core Phase 3 integration point. With a `navigation` cache,
+/// the navigation continues from the paths already taken in `json`.
 #[allow(clippy::too_many_arguments)]
 fn get_nested_field_suggestions(
-    json: &Value,
+    json: &Arc<Value>,
+    navigation: Option<&mut NavigationCache>,
     path_context: &str,
     needs_leading_dot: bool,
     suppress_array_brackets: bool,
@@ -961,7 +905,10 @@ fn get_nested_field_suggestions(
     }
 
     // Navigate with fan-out to collect values from multiple array elements
-    let navigated_values = navigate_multi(json, &parsed_path.segments, array_sample_size);
+    let navigated_values = match navigation {
+        Some(cache) => cache.navigate(json, &parsed_path.segments, array_sample_size),
+        None => navigate_multi(json, &parsed_path.segments, array_sample_size),
+    };
     if navigated_values.is_empty() {
         return None;
     }
@@ -976,6 +923,27 @@ fn get_nested_field_suggestions(
     Some(suggestions)
 }
 
+/// The values field suggestions at `path_context` came from: the first
+/// of `sources` the path navigates in, as for [`get_nested_field_suggestions`]
+fn tunnel_parents<'a>(
+    sources: &[Option<&'a Value>],
+    path_context: &str,
+    is_in_element_context: bool,
+    result_type: Option<&ResultType>,
+    array_sample_size: usize,
+) -> Vec<&'a Value> {
+    let mut segments = parse_path(path_context).segments;
+    if is_in_element_context && !matches!(result_type, Some(ResultType::DestructuredObjects)) {
+        segments.insert(0, PathSegment::ArrayIterator);
+    }
+    sources
+        .iter()
+        .flatten()
+        .map(|json| navigate_multi(json, &segments, array_sample_size))
+        .find(|values| !values.is_empty())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 #[path = "context_tests.rs"]
 mod context_tests;
@@ -27,9 +27,6 @@ mod entry_context_tests;
 #[path = "context_tests/helper_tests.rs"]
 mod helper_tests;
 
-#[path = "context_tests/variable_context_tests.rs"]
-mod variable_context_tests;
-
 #[path = "context_tests/nested_navigation_tests.rs"]
 mod nested_navigation_tests;
 
@@ -444,3 +444,35 @@ fn test_no_duplicate_key_value_suggestions() {
         "key suggestion should have entry context description"
     );
 }
+
+#[test]
+fn test_opaque_context_quotes_fields_that_need_it() {
+    let json = r#"{"services": {"web": {"first-name": 1, "2nd": 2, "user name": 3}}}"#;
+    let parsed = Arc::new(serde_json::from_str::<Value>(json).unwrap());
+    let all_fields = field_names_from(&parsed);
+
+    let query = "to_entries | map(.value | .";
+    let tracker = tracker_for(query);
+
+    let suggestions = get_suggestions(
+        query,
+        query.len(),
+        Some(parsed.clone()),
+        Some(ResultType::Object),
+        Some(parsed),
+        all_fields,
+        &tracker,
+        DEFAULT_ARRAY_SAMPLE_SIZE,
+    );
+
+    let texts: Vec<&str> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    for expected in [
+        r#".["first-name"]"#,
+        r#".["2nd"]"#,
+        r#".["user name"]"#,
+        ".web",
+    ] {
+        assert!(texts.contains(&expected), "missing {expected}: {texts:?}");
+    }
+    assert!(!texts.contains(&".first-name"));
+}
@@ -0,0 +1,34 @@
+//! Fish-style ghost text for the selected suggestion
+//!
+//! With the popup open and the cursor at the end of the query, the part of
+//! the query the selected suggestion would add is drawn dimmed after the
+//! cursor. Right or End accepts it; the popup stays for browsing the rest.
+
+use crate::app::{App, Focus};
+use crate::autocomplete::insertion::query_with_suggestion;
+use crate::editor::EditorMode;
+
+/// Text the selected suggestion would append to the query, if accepting it
+/// only extends what is already typed
+pub fn ghost_text(app: &App) -> Option<String> {
+    if app.input.editor_mode != EditorMode::Insert
+        || !app.has_focus(Focus::InputField)
+        || !app.autocomplete.is_visible()
+    {
+        return None;
+    }
+    let query = app.input.query();
+    if app.input.textarea.cursor().1 != query.chars().count() {
+        return None;
+    }
+
+    let completed = query_with_suggestion(app, app.autocomplete.selected()?)?;
+    completed
+        .strip_prefix(query)
+        .filter(|rest| !rest.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+#[path = "ghost_text_tests.rs"]
+mod ghost_text_tests;
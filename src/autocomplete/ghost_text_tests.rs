@@ -0,0 +1,61 @@
+//! Tests for ghost_text
+
+use super::*;
+use crate::autocomplete::update_suggestions_from_app;
+use crate::test_utils::test_helpers::test_app;
+
+const JSON: &str = r#"{"name": "Alice", "tags": ["a", "b"], "age": 30}"#;
+
+fn app_typing(query: &str) -> App {
+    let mut app = test_app(JSON);
+    app.input.editor_mode = EditorMode::Insert;
+    app.input.textarea.insert_str(query);
+    update_suggestions_from_app(&mut app);
+    app
+}
+
+#[test]
+fn test_ghost_text_is_rest_of_selected_suggestion() {
+    let app = app_typing(".na");
+    assert_eq!(ghost_text(&app).as_deref(), Some("me"));
+}
+
+#[test]
+fn test_ghost_text_follows_selection() {
+    let mut app = app_typing(".");
+    let first = ghost_text(&app);
+    app.autocomplete.select_next();
+    let second = ghost_text(&app);
+    assert!(first.is_some() && second.is_some());
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_no_ghost_text_without_popup() {
+    let mut app = app_typing(".na");
+    app.autocomplete.hide();
+    assert_eq!(ghost_text(&app), None);
+}
+
+#[test]
+fn test_no_ghost_text_with_cursor_before_end() {
+    let mut app = app_typing(".na");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Back);
+    assert_eq!(ghost_text(&app), None);
+}
+
+#[test]
+fn test_no_ghost_text_in_normal_mode() {
+    let mut app = app_typing(".na");
+    app.input.editor_mode = EditorMode::Normal;
+    assert_eq!(ghost_text(&app), None);
+}
+
+#[test]
+fn test_no_ghost_text_without_focus() {
+    let mut app = app_typing(".na");
+    app.focus = Focus::ResultsPane;
+    assert_eq!(ghost_text(&app), None);
+}
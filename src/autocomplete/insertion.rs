@@ -6,10 +6,12 @@
 use tui_textarea::TextArea;
 
 use crate::app::App;
+use crate::autocomplete::argument_slot;
 use crate::autocomplete::autocomplete_state::{Suggestion, SuggestionType};
 use crate::autocomplete::value_insertion;
 use crate::autocomplete::value_trigger;
 use crate::autocomplete::{SuggestionContext, analyze_context};
+use crate::editor::editor_events::lint_query;
 use crate::query::QueryState;
 
 // Re-export sub-module functions
@@ -20,6 +22,9 @@ pub use self::cursor::move_cursor_to_column;
 mod cursor;
 
 /// Replace partial text at cursor, preserving text before and after
+///
+/// With the cursor inside a word (`.na|me`), the rest of the word is
+/// replaced too, so accepting `.name` there doesn't leave `.nameme`.
 fn replace_partial_at_cursor(
     textarea: &mut TextArea<'_>,
     query: &str,
@@ -27,11 +32,17 @@ fn replace_partial_at_cursor(
     replacement_start: usize,
     insert_text: &str,
 ) {
+    let inside_word = query[..cursor_pos].chars().last().is_some_and(is_word_char);
+    let replacement_end = if inside_word {
+        token_end(query, cursor_pos)
+    } else {
+        cursor_pos
+    };
     let new_query = format!(
         "{}{}{}",
         &query[..replacement_start],
         insert_text,
-        &query[cursor_pos..]
+        &query[replacement_end..]
     );
 
     textarea.delete_line_by_head();
@@ -43,17 +54,33 @@ fn replace_partial_at_cursor(
     move_cursor_to_column(textarea, target_char);
 }
 
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Byte offset where the word running through `cursor_pos` ends
+fn token_end(query: &str, cursor_pos: usize) -> usize {
+    query[cursor_pos..]
+        .find(|c: char| !is_word_char(c))
+        .map_or(query.len(), |offset| cursor_pos + offset)
+}
+
 /// Insert an autocomplete suggestion from App context
 ///
 /// Executes the new query immediately (no debounce) for instant feedback.
 /// Uses async execution to prevent race conditions with ongoing queries.
+/// Accepting a bare object key reopens the popup with its key-and-value
+/// follow-ups.
 pub fn insert_suggestion_from_app(app: &mut App, suggestion: &Suggestion) {
+    let file = app.source_paths().into_iter().next();
+    let offers_follow_up = accepts_bare_object_key(app, suggestion);
     let query_state = match &mut app.query {
         Some(q) => q,
         None => return,
     };
 
     insert_suggestion(&mut app.input.textarea, query_state, suggestion);
+    app.completion_usage.record(suggestion, file.as_deref());
 
     app.autocomplete.hide();
     app.results_scroll.reset();
@@ -63,6 +90,22 @@ pub fn insert_suggestion_from_app(app: &mut App, suggestion: &Suggestion) {
     let query = app.input.textarea.lines()[0].as_ref();
     app.input.brace_tracker.rebuild(query);
     query_state.execute_async(query);
+    lint_query(app);
+
+    if offers_follow_up {
+        app.update_autocomplete();
+    }
+}
+
+/// Whether `suggestion` is a key on its own, accepted inside `{…}`
+fn accepts_bare_object_key(app: &App, suggestion: &Suggestion) -> bool {
+    if suggestion.suggestion_type != SuggestionType::Field || suggestion.text.contains(": ") {
+        return false;
+    }
+    let query = app.input.query();
+    let cursor_pos = crate::str_utils::char_pos_to_byte_pos(query, app.input.textarea.cursor().1);
+    let (context, _) = analyze_context(&query[..cursor_pos], &app.input.brace_tracker);
+    context == SuggestionContext::ObjectKeyContext
 }
 
 /// Check if trailing separator should be replaced to avoid duplicates
@@ -103,13 +146,18 @@ fn insert_function_suggestion(
     suggestion: &Suggestion,
 ) {
     let replacement_start = cursor_pos.saturating_sub(partial.len());
-    let insert_text = if suggestion.needs_parens {
+    // A name retyped in front of its arguments keeps their `(`
+    let has_parens = query[token_end(query, cursor_pos)..].starts_with('(');
+    let insert_text = if suggestion.needs_parens && !has_parens {
         format!("{}(", suggestion.text)
     } else {
         suggestion.text.to_string()
     };
 
     replace_partial_at_cursor(textarea, query, cursor_pos, replacement_start, &insert_text);
+    if suggestion.needs_parens && has_parens {
+        textarea.move_cursor(tui_textarea::CursorMove::Forward);
+    }
 }
 
 /// Insert object key suggestion (e.g., keys in object literals)
@@ -191,10 +239,19 @@ fn insert_field_suggestion(
     );
 }
 
+/// The query as it would read with `suggestion` accepted at the cursor
+pub fn query_with_suggestion(app: &App, suggestion: &Suggestion) -> Option<String> {
+    let query_state = app.query.as_ref()?;
+    let mut textarea = TextArea::new(vec![app.input.query().to_string()]);
+    move_cursor_to_column(&mut textarea, app.input.textarea.cursor().1);
+    insert_suggestion(&mut textarea, query_state, suggestion);
+    textarea.lines().first().cloned()
+}
+
 /// Insert an autocomplete suggestion at the current cursor position
 pub fn insert_suggestion(
     textarea: &mut TextArea<'_>,
-    query_state: &mut QueryState,
+    query_state: &QueryState,
     suggestion: &Suggestion,
 ) {
     let query = textarea.lines()[0].clone();
@@ -215,6 +272,20 @@ pub fn insert_suggestion(
         return;
     }
 
+    // A key literal or separator accepted before any quote is typed
+    if suggestion.suggestion_type == SuggestionType::Value
+        && let Some(slot) = argument_slot::classify(&query, cursor_pos)
+    {
+        replace_partial_at_cursor(
+            textarea,
+            &query,
+            cursor_pos,
+            slot.partial_start,
+            &suggestion.text,
+        );
+        return;
+    }
+
     let mut temp_tracker = crate::autocomplete::BraceTracker::new();
     temp_tracker.rebuild(before_cursor);
     let (context, partial) = analyze_context(before_cursor, &temp_tracker);
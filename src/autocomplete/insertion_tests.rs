@@ -11,6 +11,8 @@ mod field_context_tests;
 mod function_context_tests;
 #[path = "insertion_tests/mid_query_insertion_tests.rs"]
 mod mid_query_insertion_tests;
+#[path = "insertion_tests/object_key_insertion_tests.rs"]
+mod object_key_insertion_tests;
 #[path = "insertion_tests/property_tests.rs"]
 mod property_tests;
 #[path = "insertion_tests/query_execution_tests.rs"]
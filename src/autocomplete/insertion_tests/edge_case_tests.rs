@@ -35,7 +35,7 @@ fn test_nested_array_bracket_handling() {
     // User wants to complete with "[].capacityProviderStrategy"
     let suggestion = test_suggestion("[].capacityProviderStrategy");
 
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     // Should handle nested array brackets correctly
@@ -54,7 +54,7 @@ fn test_open_paren_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::ArrayOfObjects);
 
     let suggestion = test_suggestion("id");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(result.contains("map("));
@@ -73,7 +73,7 @@ fn test_open_bracket_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::Array);
 
     let suggestion = test_suggestion("0");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(result.contains(".items["));
@@ -91,7 +91,7 @@ fn test_open_brace_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::Object);
 
     let suggestion = test_suggestion("name");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     // After insertion with OpenBrace trigger, result should contain the suggestion
@@ -110,7 +110,7 @@ fn test_question_mark_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::Object);
 
     let suggestion = test_suggestion("subfield");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(result.contains(".field?"));
@@ -129,7 +129,7 @@ fn test_dot_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::Object);
 
     let suggestion = test_suggestion("profile");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     // Should handle double dot correctly
@@ -148,7 +148,7 @@ fn test_close_paren_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::Array);
 
     let suggestion = test_suggestion("length");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(result.contains("map(.id)"));
@@ -167,7 +167,7 @@ fn test_close_brace_trigger() {
     query_state.base_type_for_suggestions = Some(ResultType::Object);
 
     let suggestion = test_suggestion("name");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(result.contains("{name:.name}"));
@@ -185,7 +185,7 @@ fn test_base_query_fallback_when_none() {
     query_state.base_query_for_suggestions = None;
 
     let suggestion = test_suggestion("field");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     // Should use textarea.lines()[0] as fallback
     let result: &str = textarea.lines()[0].as_ref();
@@ -208,7 +208,7 @@ fn test_array_iteration_when_cursor_at_partial_start() {
     // Accept [].items suggestion where cursor_pos (2) <= partial_len (1)
     // This tests the else branch: cursor_pos when cursor_pos <= partial_len
     let suggestion = test_suggestion("[].items");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     // Should insert at cursor position without going negative
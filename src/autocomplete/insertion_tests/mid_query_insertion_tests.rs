@@ -25,7 +25,7 @@ fn test_field_insertion_mid_query_basic() {
     position_cursor_at(&mut textarea, 2);
 
     let suggestion = test_suggestion(".alpha");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(
@@ -53,7 +53,7 @@ fn test_no_duplication_bug_scenario() {
     position_cursor_at(&mut textarea, 11);
 
     let suggestion = test_suggestion("foo");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(
@@ -77,7 +77,7 @@ fn test_field_insertion_mid_query_no_duplication() {
     position_cursor_at(&mut textarea, 11);
 
     let suggestion = test_suggestion("users");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(result, ".services.users | map(.name)");
@@ -97,7 +97,7 @@ fn test_field_insertion_mid_query_with_complex_pipe() {
     position_cursor_at(&mut textarea, 7);
 
     let suggestion = test_suggestion("items");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(result, ".data.items | select(.active) | sort");
@@ -113,7 +113,7 @@ fn test_array_syntax_insertion_preserves_after() {
     position_cursor_at(&mut textarea, 11);
 
     let suggestion = test_suggestion("[].id");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(result, ".services[].id | map(.name)");
@@ -129,7 +129,7 @@ fn test_function_insertion_mid_query_preserves_after() {
     position_cursor_at(&mut textarea, 12);
 
     let suggestion = Suggestion::new("select", SuggestionType::Function);
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(
@@ -148,7 +148,7 @@ fn test_object_key_insertion_mid_query_preserves_after() {
     position_cursor_at(&mut textarea, 11);
 
     let suggestion = test_suggestion("age");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert!(
@@ -167,7 +167,7 @@ fn test_cursor_position_after_mid_query_insertion() {
     position_cursor_at(&mut textarea, 11);
 
     let suggestion = test_suggestion("items");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(result, ".services.items | map(.name)");
@@ -190,7 +190,7 @@ fn test_suggestion_at_query_start() {
     position_cursor_at(&mut textarea, 0);
 
     let suggestion = test_suggestion(".services");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(result, ".services");
@@ -206,7 +206,7 @@ fn test_array_suggestion_at_partial_start() {
     position_cursor_at(&mut textarea, 1);
 
     let suggestion = test_suggestion("[]");
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result: &str = textarea.lines()[0].as_ref();
     assert_eq!(result, "[]");
@@ -243,3 +243,47 @@ fn test_suggestions_after_question_mark_with_dot() {
         crate::autocomplete::SuggestionContext::FieldContext
     );
 }
+
+#[test]
+fn test_field_accepted_inside_a_word_replaces_the_whole_word() {
+    let (mut textarea, mut query_state) = setup_insertion_test("");
+    textarea.insert_str(".services.na | length");
+    query_state.base_query_for_suggestions = Some(".services".to_string());
+
+    // Cursor between "n" and "a"
+    position_cursor_at(&mut textarea, 11);
+    insert_suggestion(&mut textarea, &query_state, &test_suggestion("name"));
+
+    let result: &str = textarea.lines()[0].as_ref();
+    assert_eq!(result, ".services.name | length");
+    assert_eq!(textarea.cursor().1, ".services.name".len());
+}
+
+#[test]
+fn test_function_accepted_inside_its_name_keeps_existing_parens() {
+    let (mut textarea, query_state) = setup_insertion_test("");
+    textarea.insert_str(".a | sel(.b) | keys");
+
+    // Cursor between "se" and "l"
+    position_cursor_at(&mut textarea, 7);
+    let suggestion = Suggestion::new("select", SuggestionType::Function).with_needs_parens(true);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
+
+    let result: &str = textarea.lines()[0].as_ref();
+    assert_eq!(result, ".a | select(.b) | keys");
+    assert_eq!(textarea.cursor().1, ".a | select(".len());
+}
+
+#[test]
+fn test_accepting_before_a_word_leaves_it_alone() {
+    let (mut textarea, mut query_state) = setup_insertion_test("");
+    textarea.insert_str(".a | .b");
+    query_state.base_query_for_suggestions = Some(".".to_string());
+
+    // Cursor right after the first ".", before "a": nothing typed yet
+    position_cursor_at(&mut textarea, 1);
+    insert_suggestion(&mut textarea, &query_state, &test_suggestion(".x"));
+
+    let result: &str = textarea.lines()[0].as_ref();
+    assert!(result.ends_with("a | .b"), "got '{}'", result);
+}
@@ -0,0 +1,57 @@
+//! Object key and key-and-value insertion tests
+
+use super::*;
+use crate::autocomplete::update_suggestions_from_app;
+
+fn app_typing(json: &str, query: &str) -> crate::app::App {
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(query);
+    app.input.brace_tracker.rebuild(query);
+    update_suggestions_from_app(&mut app);
+    app
+}
+
+fn offered(app: &crate::app::App) -> Vec<&str> {
+    app.autocomplete
+        .suggestions()
+        .iter()
+        .map(|s| s.text.as_str())
+        .collect()
+}
+
+#[test]
+fn test_partial_key_offers_key_and_shorthands() {
+    let app = app_typing(r#"{"name": "b", "user": {"name": "a"}}"#, "{na");
+    assert_eq!(
+        offered(&app),
+        vec!["name", "name: .name", "name: .user.name"]
+    );
+}
+
+#[test]
+fn test_accepting_bare_key_reopens_with_follow_ups() {
+    let mut app = app_typing(r#"{"name": "b", "user": {"name": "a"}}"#, "{na");
+
+    insert_suggestion_from_app(&mut app, &Suggestion::new("name", SuggestionType::Field));
+
+    assert_eq!(app.input.query(), "{name");
+    assert!(app.autocomplete.is_visible());
+    assert_eq!(offered(&app), vec!["name: .name", "name: .user.name"]);
+}
+
+#[test]
+fn test_accepting_shorthand_inserts_key_and_value() {
+    let mut app = app_typing(r#"{"name": "b", "user": {"name": "a"}}"#, "{id: 1, na");
+
+    let shorthand = app
+        .autocomplete
+        .suggestions()
+        .iter()
+        .find(|s| s.text == "name: .user.name")
+        .cloned()
+        .unwrap();
+    insert_suggestion_from_app(&mut app, &shorthand);
+
+    assert_eq!(app.input.query(), "{id: 1, name: .user.name");
+    assert!(!app.autocomplete.is_visible());
+}
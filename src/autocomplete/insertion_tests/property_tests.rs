@@ -35,9 +35,9 @@ fn test_all_functions_requiring_args_get_parenthesis() {
             .with_signature(func.signature);
 
         let partial = &func.name[..func.name.len().min(3)];
-        let (mut textarea, mut query_state) = setup_insertion_test(partial);
+        let (mut textarea, query_state) = setup_insertion_test(partial);
 
-        insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+        insert_suggestion(&mut textarea, &query_state, &suggestion);
 
         let result = textarea.lines()[0].clone();
         let expected_suffix = format!("{}(", func.name);
@@ -66,9 +66,9 @@ fn test_all_functions_not_requiring_args_get_no_parenthesis() {
             .with_signature(func.signature);
 
         let partial = &func.name[..func.name.len().min(3)];
-        let (mut textarea, mut query_state) = setup_insertion_test(partial);
+        let (mut textarea, query_state) = setup_insertion_test(partial);
 
-        insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+        insert_suggestion(&mut textarea, &query_state, &suggestion);
 
         let result = textarea.lines()[0].clone();
 
@@ -103,9 +103,9 @@ fn test_all_functions_cursor_positioned_correctly() {
             .with_signature(func.signature);
 
         let partial = &func.name[..func.name.len().min(3)];
-        let (mut textarea, mut query_state) = setup_insertion_test(partial);
+        let (mut textarea, query_state) = setup_insertion_test(partial);
 
-        insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+        insert_suggestion(&mut textarea, &query_state, &suggestion);
 
         let result = textarea.lines()[0].clone();
         let cursor_col = textarea.cursor().1;
@@ -143,13 +143,13 @@ proptest! {
             "{".to_string()
         };
         let initial_query = format!("{}{}", prefix, partial);
-        let (mut textarea, mut query_state) = setup_insertion_test(&initial_query);
+        let (mut textarea, query_state) = setup_insertion_test(&initial_query);
 
         // Create a field suggestion (ObjectKeyContext suggestions are field names without dots)
         let suggestion_obj = Suggestion::new(&suggestion, SuggestionType::Field);
 
         // Insert the suggestion
-        insert_suggestion(&mut textarea, &mut query_state, &suggestion_obj);
+        insert_suggestion(&mut textarea, &query_state, &suggestion_obj);
 
         // Get the result
         let result = textarea.lines()[0].clone();
@@ -192,13 +192,13 @@ fn test_object_key_context_insertion_simple() {
     // Test: `{na` + accept "name" → `{name`
     // This tests the basic ObjectKeyContext insertion after opening brace
     let initial_query = "{na";
-    let (mut textarea, mut query_state) = setup_insertion_test(initial_query);
+    let (mut textarea, query_state) = setup_insertion_test(initial_query);
 
     // Create a field suggestion (ObjectKeyContext suggestions are field names without dots)
     let suggestion = Suggestion::new("name", SuggestionType::Field);
 
     // Insert the suggestion
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     // Verify the result
     let result = textarea.lines()[0].clone();
@@ -221,13 +221,13 @@ fn test_object_key_context_insertion_after_comma() {
     // Test: `{name: .name, ag` + accept "age" → `{name: .name, age`
     // This tests ObjectKeyContext insertion after comma in object literal
     let initial_query = "{name: .name, ag";
-    let (mut textarea, mut query_state) = setup_insertion_test(initial_query);
+    let (mut textarea, query_state) = setup_insertion_test(initial_query);
 
     // Create a field suggestion
     let suggestion = Suggestion::new("age", SuggestionType::Field);
 
     // Insert the suggestion
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     // Verify the result
     let result = textarea.lines()[0].clone();
@@ -250,10 +250,10 @@ fn test_object_key_context_insertion_with_space_after_comma() {
     // Test: `{name: .name, ag` (with space after comma) + accept "age" → `{name: .name, age`
     // This tests that spaces are preserved correctly
     let initial_query = "{name: .name, ag";
-    let (mut textarea, mut query_state) = setup_insertion_test(initial_query);
+    let (mut textarea, query_state) = setup_insertion_test(initial_query);
 
     let suggestion = Suggestion::new("age", SuggestionType::Field);
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result = textarea.lines()[0].clone();
     assert_eq!(result, "{name: .name, age");
@@ -264,10 +264,10 @@ fn test_object_key_context_insertion_nested_object() {
     // Test: `{outer: {in` + accept "inner" → `{outer: {inner`
     // This tests ObjectKeyContext in nested object
     let initial_query = "{outer: {in";
-    let (mut textarea, mut query_state) = setup_insertion_test(initial_query);
+    let (mut textarea, query_state) = setup_insertion_test(initial_query);
 
     let suggestion = Suggestion::new("inner", SuggestionType::Field);
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result = textarea.lines()[0].clone();
     assert_eq!(
@@ -282,10 +282,10 @@ fn test_object_key_context_insertion_longer_partial() {
     // Test: `{servi` + accept "services" → `{services`
     // This tests with a longer partial
     let initial_query = "{servi";
-    let (mut textarea, mut query_state) = setup_insertion_test(initial_query);
+    let (mut textarea, query_state) = setup_insertion_test(initial_query);
 
     let suggestion = Suggestion::new("services", SuggestionType::Field);
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result = textarea.lines()[0].clone();
     assert_eq!(
@@ -300,10 +300,10 @@ fn test_object_key_context_insertion_single_char_partial() {
     // Test: `{n` + accept "name" → `{name`
     // This tests with a single character partial
     let initial_query = "{n";
-    let (mut textarea, mut query_state) = setup_insertion_test(initial_query);
+    let (mut textarea, query_state) = setup_insertion_test(initial_query);
 
     let suggestion = Suggestion::new("name", SuggestionType::Field);
-    insert_suggestion(&mut textarea, &mut query_state, &suggestion);
+    insert_suggestion(&mut textarea, &query_state, &suggestion);
 
     let result = textarea.lines()[0].clone();
     assert_eq!(
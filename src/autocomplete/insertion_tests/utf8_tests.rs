@@ -299,6 +299,14 @@ mod bracket_notation_emission {
         assert_eq!(app.input.query(), r#".["my-field"]"#);
     }
 
+    #[test]
+    fn key_typed_up_to_its_hyphen_is_replaced_by_bracket_notation() {
+        let mut app = test_app(r#"{"first-name": 1}"#);
+        app.input.textarea.insert_str(".a | .first-");
+        insert_suggestion_from_app(&mut app, &suggestion(r#".["first-name"]"#));
+        assert_eq!(app.input.query(), r#".a | .["first-name"]"#);
+    }
+
     #[test]
     fn ascii_identifier_still_inserts_dot_notation() {
         let mut app = test_app(r#"{"name": "Alice"}"#);
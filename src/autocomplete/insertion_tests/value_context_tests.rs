@@ -11,9 +11,9 @@ use super::*;
 /// Insert `suggestion` into a query whose buffer ends inside an unclosed string
 /// at a comparison position, with the cursor at end-of-line.
 fn insert_at_unclosed_value(query: &str, suggestion: &Suggestion) -> String {
-    let (mut textarea, mut query_state) = setup_insertion_test("");
+    let (mut textarea, query_state) = setup_insertion_test("");
     textarea.insert_str(query);
-    insert_suggestion(&mut textarea, &mut query_state, suggestion);
+    insert_suggestion(&mut textarea, &query_state, suggestion);
     textarea.lines()[0].clone()
 }
 
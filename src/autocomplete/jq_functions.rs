@@ -2,6 +2,9 @@ use super::autocomplete_state::{Suggestion, SuggestionType};
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
+use crate::query::jq_binary::JqBinary;
+use crate::query::prelude::UserFunction;
+
 /// Metadata for a jq built-in function
 #[derive(Debug, Clone)]
 pub struct JqFunction {
@@ -88,8 +91,15 @@ pub static JQ_FUNCTION_METADATA: &[JqFunction] = &[
         "Transform object entries",
         true,
     ),
+    JqFunction::new(
+        "repeat",
+        "repeat(expr)",
+        "Apply expression repeatedly",
+        true,
+    ),
     // Object functions with arguments
     JqFunction::new("has", "has(key)", "Check if key exists", true),
+    JqFunction::new("in", "in(obj)", "Check if key is in object", true),
     JqFunction::new("del", "del(path)", "Delete key/path", true),
     JqFunction::new("getpath", "getpath(path)", "Get value at path", true),
     JqFunction::new("setpath", "setpath(path; val)", "Set value at path", true),
@@ -134,6 +144,8 @@ pub static JQ_FUNCTION_METADATA: &[JqFunction] = &[
     JqFunction::new("index", "index(val)", "Find first index of value", true),
     JqFunction::new("rindex", "rindex(val)", "Find last index of value", true),
     JqFunction::new("indices", "indices(val)", "Find all indices of value", true),
+    // Math functions with arguments
+    JqFunction::new("pow", "pow(x; y)", "Raise x to the power y", true),
     // Date functions with arguments
     JqFunction::new("strftime", "strftime(fmt)", "Format timestamp", true),
     JqFunction::new("strptime", "strptime(fmt)", "Parse timestamp", true),
@@ -145,6 +157,18 @@ pub static JQ_FUNCTION_METADATA: &[JqFunction] = &[
         false,
     ),
     JqFunction::new("todate", "todate", "Format timestamp as ISO 8601", false),
+    JqFunction::new(
+        "fromdateiso8601",
+        "fromdateiso8601",
+        "Parse ISO8601 date",
+        false,
+    ),
+    JqFunction::new(
+        "todateiso8601",
+        "todateiso8601",
+        "Format as ISO8601 date",
+        false,
+    ),
     // ===== Functions not requiring arguments (needs_parens = false) =====
 
     // Array functions without arguments
@@ -167,6 +191,8 @@ pub static JQ_FUNCTION_METADATA: &[JqFunction] = &[
     JqFunction::new("min", "min", "Minimum value", false),
     JqFunction::new("max", "max", "Maximum value", false),
     JqFunction::new("transpose", "transpose", "Transpose matrix", false),
+    JqFunction::new("any", "any", "Check if any element is true", false),
+    JqFunction::new("all", "all", "Check if all elements are true", false),
     // Object functions without arguments
     JqFunction::new(
         "to_entries",
@@ -188,6 +214,7 @@ pub static JQ_FUNCTION_METADATA: &[JqFunction] = &[
     JqFunction::new("tonumber", "tonumber", "Convert to number", false),
     JqFunction::new("tojson", "tojson", "Convert value to JSON string", false),
     JqFunction::new("fromjson", "fromjson", "Parse JSON string to value", false),
+    JqFunction::new("toarray", "toarray", "Wrap non-arrays in an array", false),
     JqFunction::new("arrays", "arrays", "Select arrays", false),
     JqFunction::new("objects", "objects", "Select objects", false),
     JqFunction::new("iterables", "iterables", "Select arrays/objects", false),
@@ -220,6 +247,9 @@ pub static JQ_FUNCTION_METADATA: &[JqFunction] = &[
         false,
     ),
     JqFunction::new("env", "env", "Access environment variables", false),
+    JqFunction::new("input", "input", "Read the next input", false),
+    JqFunction::new("inputs", "inputs", "Stream remaining inputs", false),
+    JqFunction::new("debug", "debug", "Print value to stderr", false),
 ];
 
 /// Functions that provide element context for their arguments.
@@ -248,6 +278,49 @@ pub fn is_element_context_function(name: &str) -> bool {
     ELEMENT_CONTEXT_FUNCTIONS.contains(name)
 }
 
+/// Functions for taking strings apart and reshaping them, offered first
+/// while the input is read as raw lines of text
+pub static STRING_FUNCTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "split",
+        "splits",
+        "join",
+        "test",
+        "match",
+        "capture",
+        "scan",
+        "sub",
+        "gsub",
+        "ltrimstr",
+        "rtrimstr",
+        "startswith",
+        "endswith",
+        "ascii_downcase",
+        "ascii_upcase",
+        "trim",
+        "ltrim",
+        "rtrim",
+        "tonumber",
+        "tostring",
+        "fromjson",
+        "explode",
+        "implode",
+        "utf8bytelength",
+        "strptime",
+        "fromdate",
+        "@csv",
+        "@tsv",
+        "@base64d",
+        "@uri",
+    ]
+    .into_iter()
+    .collect()
+});
+
+pub fn is_string_function(name: &str) -> bool {
+    STRING_FUNCTIONS.contains(name)
+}
+
 /// Static list of all jq built-in functions, operators, and patterns
 /// Built once at first access and reused for performance
 static JQ_BUILTINS: LazyLock<Vec<Suggestion>> = LazyLock::new(|| {
@@ -295,14 +368,6 @@ static JQ_BUILTINS: LazyLock<Vec<Suggestion>> = LazyLock::new(|| {
         Suggestion::new("@base64d", SuggestionType::Function).with_description("Base64 decode"),
     ]);
 
-    // Date functions not in requirements list
-    builtins.extend(vec![
-        Suggestion::new("fromdateiso8601", SuggestionType::Function)
-            .with_description("Parse ISO8601 date"),
-        Suggestion::new("todateiso8601", SuggestionType::Function)
-            .with_description("Format as ISO8601 date"),
-    ]);
-
     // Conditional/logic keywords
     builtins.extend(vec![
         Suggestion::new("if", SuggestionType::Function).with_description("Conditional expression"),
@@ -314,11 +379,7 @@ static JQ_BUILTINS: LazyLock<Vec<Suggestion>> = LazyLock::new(|| {
 
     // Special functions
     builtins.extend(vec![
-        Suggestion::new("in", SuggestionType::Function)
-            .with_description("Check if value is in object"),
         Suggestion::new("as", SuggestionType::Function).with_description("Bind variable"),
-        Suggestion::new("repeat", SuggestionType::Function)
-            .with_description("Repeat expression infinitely"),
         Suggestion::new("$ENV", SuggestionType::Function).with_description("Environment object"),
     ]);
 
@@ -338,6 +399,20 @@ static JQ_BUILTINS: LazyLock<Vec<Suggestion>> = LazyLock::new(|| {
 
 /// Filter jq builtins by prefix (optimized for performance)
 pub fn filter_builtins(prefix: &str) -> Vec<Suggestion> {
+    filter_builtins_for(
+        prefix,
+        crate::query::jq_binary::get(),
+        crate::query::prelude::functions(),
+    )
+}
+
+/// [`filter_builtins`], noting on each builtin newer than `jq` the release
+/// it needs, followed by the prelude's `user` functions
+pub fn filter_builtins_for(
+    prefix: &str,
+    jq: Option<&JqBinary>,
+    user: &[UserFunction],
+) -> Vec<Suggestion> {
     if prefix.is_empty() {
         return Vec::new();
     }
@@ -346,7 +421,26 @@ pub fn filter_builtins(prefix: &str) -> Vec<Suggestion> {
     JQ_BUILTINS
         .iter()
         .filter(|s| s.text.to_lowercase().starts_with(&prefix_lower))
-        .cloned()
+        .map(
+            |s| match jq.and_then(|jq| Some((jq.missing_builtin(&s.text)?, jq))) {
+                Some((since, jq)) => s.clone().with_description(format!(
+                    "Needs jq {}; this is {}",
+                    since,
+                    jq.label()
+                )),
+                None => s.clone(),
+            },
+        )
+        .chain(
+            user.iter()
+                .filter(|f| f.name.to_lowercase().starts_with(&prefix_lower))
+                .map(|f| {
+                    Suggestion::new(f.name.clone(), SuggestionType::Function)
+                        .with_description(f.description())
+                        .with_signature(f.signature())
+                        .with_needs_parens(!f.params.is_empty())
+                }),
+        )
         .collect()
 }
 
@@ -455,3 +455,31 @@ fn test_jq_function_new_without_parens() {
     assert_eq!(func.description, "Get object keys");
     assert!(!func.needs_parens);
 }
+
+#[test]
+fn test_builtins_newer_than_jq_are_noted() {
+    let jq = JqBinary::new("/usr/bin/jq".into(), "jq-1.5");
+    let walk = filter_builtins_for("walk", Some(&jq), &[])
+        .into_iter()
+        .find(|s| s.text == "walk")
+        .unwrap();
+    assert_eq!(
+        walk.description.as_deref(),
+        Some("Needs jq 1.6; this is jq 1.5")
+    );
+
+    let map = filter_builtins_for("map", Some(&jq), &[])
+        .into_iter()
+        .find(|s| s.text == "map")
+        .unwrap();
+    assert!(!map.description.unwrap().contains("Needs jq"));
+}
+
+#[test]
+fn test_builtins_are_not_noted_without_a_probed_jq() {
+    let walk = filter_builtins_for("walk", None, &[])
+        .into_iter()
+        .find(|s| s.text == "walk")
+        .unwrap();
+    assert!(!walk.description.unwrap().contains("Needs jq"));
+}
@@ -2,6 +2,9 @@
 ///
 /// Navigates pre-parsed JSON structures following path segments
 /// to find nested values for field suggestions.
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use serde_json::Value;
 
 use super::path_parser::PathSegment;
@@ -84,59 +87,145 @@ pub fn navigate_multi<'a>(
     segments: &[PathSegment],
     sample_size: usize,
 ) -> Vec<&'a Value> {
-    let mut current_values: Vec<&Value> = vec![root];
+    let mut current_values = roots(root, segments, sample_size);
 
     for segment in segments {
-        let mut next_values: Vec<&Value> = Vec::new();
-
-        for value in &current_values {
-            match segment {
-                PathSegment::Field(name) | PathSegment::OptionalField(name) => {
-                    if let Value::Object(map) = value
-                        && let Some(v) = map.get(name)
-                    {
-                        next_values.push(v);
-                    }
+        current_values = step(&current_values, segment, sample_size);
+        if current_values.is_empty() {
+            break;
+        }
+    }
+
+    current_values
+}
+
+/// Where navigating `segments` starts. A field can't be looked up in an
+/// array, so an array root reached by a field is a stream of documents
+/// (see [`crate::json::parse_documents`]) and the field is looked up in
+/// each of them.
+fn roots<'a>(root: &'a Value, segments: &[PathSegment], sample_size: usize) -> Vec<&'a Value> {
+    match (root, segments.first()) {
+        (Value::Array(documents), Some(PathSegment::Field(_) | PathSegment::OptionalField(_))) => {
+            documents.iter().take(sample_size).collect()
+        }
+        _ => vec![root],
+    }
+}
+
+/// The values one `segment` past `values`, as `navigate_multi` takes them
+fn step<'a>(values: &[&'a Value], segment: &PathSegment, sample_size: usize) -> Vec<&'a Value> {
+    let mut next_values: Vec<&Value> = Vec::new();
+
+    for value in values {
+        match segment {
+            PathSegment::Field(name) | PathSegment::OptionalField(name) => {
+                if let Value::Object(map) = value
+                    && let Some(v) = map.get(name)
+                {
+                    next_values.push(v);
                 }
-                PathSegment::ArrayIterator => {
-                    if let Value::Array(arr) = value {
-                        for element in arr.iter().take(sample_size) {
-                            next_values.push(element);
-                            if next_values.len() >= MAX_NAVIGATED_VALUES {
-                                break;
-                            }
+            }
+            PathSegment::ArrayIterator => {
+                if let Value::Array(arr) = value {
+                    for element in arr.iter().take(sample_size) {
+                        next_values.push(element);
+                        if next_values.len() >= MAX_NAVIGATED_VALUES {
+                            break;
                         }
                     }
                 }
-                PathSegment::ArrayIndex(i) => {
-                    if let Value::Array(arr) = value {
-                        let index = if *i < 0 {
-                            let len = arr.len() as i64;
-                            let adjusted = len + i;
-                            if adjusted < 0 {
-                                continue;
-                            }
-                            adjusted as usize
-                        } else {
-                            *i as usize
-                        };
-                        if let Some(v) = arr.get(index) {
-                            next_values.push(v);
+            }
+            PathSegment::ArrayIndex(i) => {
+                if let Value::Array(arr) = value {
+                    let index = if *i < 0 {
+                        let len = arr.len() as i64;
+                        let adjusted = len + i;
+                        if adjusted < 0 {
+                            continue;
                         }
+                        adjusted as usize
+                    } else {
+                        *i as usize
+                    };
+                    if let Some(v) = arr.get(index) {
+                        next_values.push(v);
                     }
                 }
             }
+        }
 
-            if next_values.len() >= MAX_NAVIGATED_VALUES {
-                break;
-            }
+        if next_values.len() >= MAX_NAVIGATED_VALUES {
+            break;
         }
+    }
+
+    next_values
+}
+
+/// Paths remembered by a `NavigationCache` before it starts over
+const MAX_CACHED_PATHS: usize = 256;
 
-        if next_values.is_empty() {
+/// `navigate_multi` results in one document, per path prefix.
+///
+/// Moving the cursor around a long query asks for many paths that share
+/// their start. Each one continues from the longest prefix already
+/// navigated instead of from the root. Navigating in another document, or
+/// with another sample size, starts the cache over.
+#[derive(Debug, Default)]
+pub struct NavigationCache {
+    /// The document the paths were navigated in; held so the pointers
+    /// below stay valid
+    root: Option<Arc<Value>>,
+    sample_size: usize,
+    /// Values at each navigated path, pointing into `root`
+    paths: HashMap<Vec<PathSegment>, Vec<*const Value>>,
+}
+
+impl NavigationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `navigate_multi(root, segments, sample_size)`, reusing the paths
+    /// already navigated in `root`
+    pub fn navigate<'a>(
+        &mut self,
+        root: &'a Arc<Value>,
+        segments: &[PathSegment],
+        sample_size: usize,
+    ) -> Vec<&'a Value> {
+        let same_root = self.root.as_ref().is_some_and(|r| Arc::ptr_eq(r, root));
+        if !same_root || self.sample_size != sample_size || self.paths.len() >= MAX_CACHED_PATHS {
+            self.root = Some(Arc::clone(root));
+            self.sample_size = sample_size;
+            self.paths.clear();
+        }
+
+        let (mut depth, mut values) = (0..=segments.len())
+            .rev()
+            .find_map(|depth| {
+                let cached = self.paths.get(&segments[..depth])?;
+                // SAFETY: the pointers were taken from the document `root`
+                // points to, which `self.root` keeps alive. A document
+                // behind an `Arc` is never mutated, so they still point at
+                // the same values; `'a` can't outlive the caller's `root`,
+                // which holds the document too.
+                let values = cached.iter().map(|&v| unsafe { &*v }).collect();
+                Some((depth, values))
+            })
+            .unwrap_or_else(|| (0, roots(root, segments, sample_size)));
+
+        while depth < segments.len() && !values.is_empty() {
+            values = step(&values, &segments[depth], sample_size);
+            depth += 1;
+            self.paths.insert(
+                segments[..depth].to_vec(),
+                values.iter().map(|&v| v as *const Value).collect(),
+            );
+        }
+        if depth < segments.len() {
             return Vec::new();
         }
-        current_values = next_values;
+        values
     }
-
-    current_values
 }
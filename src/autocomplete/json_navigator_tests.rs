@@ -691,3 +691,128 @@ mod navigate_multi_tests {
         assert!(result.is_empty());
     }
 }
+
+mod navigation_cache_tests {
+    use super::*;
+    use crate::autocomplete::json_navigator::NavigationCache;
+    use crate::autocomplete::path_parser::parse_path;
+    use std::sync::Arc;
+
+    fn document() -> Arc<Value> {
+        Arc::new(json!({
+            "users": [
+                {"name": "Alice", "address": {"city": "Paris"}},
+                {"name": "Bob", "tags": ["a", "b"]}
+            ],
+            "meta": {"count": 2}
+        }))
+    }
+
+    #[test]
+    fn test_cache_matches_navigate_multi_for_every_prefix() {
+        let json = document();
+        let mut cache = NavigationCache::new();
+        // Longest first, so the shorter ones are answered from the cache
+        let paths = [
+            ".users[].address.city",
+            ".users[].address",
+            ".users[]",
+            ".users[].tags[]",
+            ".users[1].name",
+            ".users",
+            ".",
+            ".users[].missing.deeper",
+            ".users[].missing",
+            ".meta.count",
+        ];
+        for path in paths {
+            let segments = parse_path(path).segments;
+            let expected = navigate_multi(&json, &segments, DEFAULT_ARRAY_SAMPLE_SIZE);
+            for _ in 0..2 {
+                assert_eq!(
+                    cache.navigate(&json, &segments, DEFAULT_ARRAY_SAMPLE_SIZE),
+                    expected,
+                    "{path}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cache_starts_over_for_another_document() {
+        let mut cache = NavigationCache::new();
+        let segments = vec![
+            PathSegment::Field("meta".into()),
+            PathSegment::Field("count".into()),
+        ];
+        let first = document();
+        assert_eq!(
+            cache.navigate(&first, &segments, DEFAULT_ARRAY_SAMPLE_SIZE),
+            vec![&json!(2)]
+        );
+
+        // Same contents, different document
+        let second = Arc::new(json!({"meta": {"count": 5}}));
+        assert_eq!(
+            cache.navigate(&second, &segments, DEFAULT_ARRAY_SAMPLE_SIZE),
+            vec![&json!(5)]
+        );
+    }
+
+    #[test]
+    fn test_cache_starts_over_for_another_sample_size() {
+        let json = document();
+        let mut cache = NavigationCache::new();
+        let segments = vec![
+            PathSegment::Field("users".into()),
+            PathSegment::ArrayIterator,
+            PathSegment::Field("name".into()),
+        ];
+        assert_eq!(cache.navigate(&json, &segments, 10).len(), 2);
+        assert_eq!(cache.navigate(&json, &segments, 1), vec![&json!("Alice")]);
+    }
+}
+
+mod stream_tests {
+    use super::*;
+    use crate::autocomplete::json_navigator::NavigationCache;
+    use std::sync::Arc;
+
+    /// Two documents, held as the array `parse_documents` makes of them
+    fn stream() -> Value {
+        json!([{"id": 1, "tags": ["a"]}, {"id": 2}])
+    }
+
+    #[test]
+    fn test_field_at_the_root_is_looked_up_in_each_document() {
+        let json = stream();
+        let segments = vec![PathSegment::Field("id".into())];
+
+        let result = navigate_multi(&json, &segments, DEFAULT_ARRAY_SAMPLE_SIZE);
+
+        assert_eq!(result, vec![&json!(1), &json!(2)]);
+    }
+
+    #[test]
+    fn test_iterating_the_root_still_sees_the_array() {
+        let json = stream();
+        let segments = vec![
+            PathSegment::ArrayIterator,
+            PathSegment::Field("tags".into()),
+        ];
+
+        let result = navigate_multi(&json, &segments, DEFAULT_ARRAY_SAMPLE_SIZE);
+
+        assert_eq!(result, vec![&json!(["a"])]);
+    }
+
+    #[test]
+    fn test_cache_looks_in_each_document_too() {
+        let json = Arc::new(stream());
+        let segments = vec![PathSegment::Field("id".into())];
+
+        let result = NavigationCache::new().navigate(&json, &segments, DEFAULT_ARRAY_SAMPLE_SIZE);
+
+        assert_eq!(result, vec![&json!(1), &json!(2)]);
+    }
+}
@@ -0,0 +1,89 @@
+//! Key-and-value suggestions for object construction
+//!
+//! Inside `{…}`, typing part of a key offers the key on its own and also
+//! the key together with a value expression: `name: .name` for a
+//! top-level field and `name: .user.name` for a nested field with that
+//! key. Once a key has been typed in full only these follow-ups remain,
+//! so accepting a bare key reopens the popup with them.
+
+use std::collections::{HashSet, VecDeque};
+
+use serde_json::Value;
+
+use super::autocomplete_state::{Suggestion, SuggestionType};
+use super::result_analyzer::ResultAnalyzer;
+use crate::json_path::{format_bracket_access, is_simple_jq_identifier};
+
+/// Object levels searched for keys, counting the top level
+const MAX_DEPTH: usize = 3;
+/// Key-and-value suggestions offered at most
+const MAX_SHORTHANDS: usize = 20;
+
+/// `key: path` suggestions for every key matching `partial` within
+/// [`MAX_DEPTH`] levels of `value`, shallowest first
+///
+/// An array of objects is searched element by element, as in `map({…})`.
+pub fn shorthand_suggestions(
+    value: Option<&Value>,
+    partial: &str,
+    array_sample_size: usize,
+) -> Vec<Suggestion> {
+    let Some(value) = value else {
+        return Vec::new();
+    };
+    let partial = partial.to_lowercase();
+    let roots: Vec<&Value> = match value {
+        Value::Array(items) => items.iter().take(array_sample_size).collect(),
+        other => vec![other],
+    };
+
+    let mut queue: VecDeque<(&Value, String, usize)> =
+        roots.into_iter().map(|v| (v, String::new(), 1)).collect();
+    let mut seen = HashSet::new();
+    let mut suggestions = Vec::new();
+
+    while let Some((value, path, depth)) = queue.pop_front() {
+        let Value::Object(map) = value else {
+            continue;
+        };
+        for (key, child) in map {
+            let child_path = format!("{}{}", path, path_step(key));
+            if key.to_lowercase().contains(&partial) && seen.insert(child_path.clone()) {
+                suggestions.push(Suggestion::new_with_type(
+                    format!("{}: {}", object_key(key), child_path),
+                    SuggestionType::Field,
+                    Some(ResultAnalyzer::detect_json_type(child)),
+                ));
+                if suggestions.len() == MAX_SHORTHANDS {
+                    return suggestions;
+                }
+            }
+            if depth < MAX_DEPTH && child.is_object() {
+                queue.push_back((child, child_path, depth + 1));
+            }
+        }
+    }
+    suggestions
+}
+
+/// `.key`, or `.["key"]` when the key is not a plain identifier
+fn path_step(key: &str) -> String {
+    if is_simple_jq_identifier(key) {
+        format!(".{}", key)
+    } else {
+        format!(".{}", format_bracket_access(key))
+    }
+}
+
+/// The key as written in an object literal
+fn object_key(key: &str) -> String {
+    if is_simple_jq_identifier(key) {
+        key.to_string()
+    } else {
+        serde_json::to_string(key).unwrap_or_else(|_| format!("\"{}\"", key))
+    }
+}
+
+#[cfg(test)]
+#[path = "object_shorthand_tests.rs"]
+mod object_shorthand_tests;
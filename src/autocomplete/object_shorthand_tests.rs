@@ -0,0 +1,80 @@
+//! Tests for object_shorthand
+
+use super::*;
+use crate::autocomplete::JsonFieldType;
+
+const SAMPLE: usize = 10;
+
+fn texts(value: &Value, partial: &str) -> Vec<String> {
+    shorthand_suggestions(Some(value), partial, SAMPLE)
+        .into_iter()
+        .map(|s| s.text)
+        .collect()
+}
+
+#[test]
+fn test_top_level_key_gets_shorthand() {
+    let value = serde_json::json!({"name": "a", "age": 3});
+    assert_eq!(texts(&value, "na"), vec!["name: .name"]);
+}
+
+#[test]
+fn test_nested_keys_follow_top_level_ones() {
+    let value = serde_json::json!({
+        "user": {"name": "a", "team": {"name": "t"}},
+        "name": "top"
+    });
+    assert_eq!(
+        texts(&value, "name"),
+        vec!["name: .name", "name: .user.name", "name: .user.team.name"]
+    );
+}
+
+#[test]
+fn test_search_stops_at_max_depth() {
+    let value = serde_json::json!({"a": {"b": {"c": {"name": "deep"}}}});
+    assert!(texts(&value, "name").is_empty());
+}
+
+#[test]
+fn test_array_of_objects_searches_elements() {
+    let value = serde_json::json!([{"id": 1}, {"id": 2, "owner": {"id": 9}}]);
+    assert_eq!(texts(&value, "id"), vec!["id: .id", "id: .owner.id"]);
+}
+
+#[test]
+fn test_arrays_inside_objects_are_not_entered() {
+    let value = serde_json::json!({"items": [{"name": "x"}]});
+    assert!(texts(&value, "name").is_empty());
+}
+
+#[test]
+fn test_unusual_keys_are_quoted() {
+    let value = serde_json::json!({"first-name": "a", "meta": {"first-name": "b"}});
+    assert_eq!(
+        texts(&value, "first"),
+        vec![
+            r#""first-name": .["first-name"]"#,
+            r#""first-name": .meta.["first-name"]"#
+        ]
+    );
+}
+
+#[test]
+fn test_match_is_case_insensitive() {
+    let value = serde_json::json!({"userName": "a"});
+    assert_eq!(texts(&value, "name"), vec!["userName: .userName"]);
+}
+
+#[test]
+fn test_suggestion_carries_value_type() {
+    let value = serde_json::json!({"count": 3});
+    let suggestions = shorthand_suggestions(Some(&value), "co", SAMPLE);
+    assert_eq!(suggestions[0].field_type, Some(JsonFieldType::Number));
+    assert_eq!(suggestions[0].suggestion_type, SuggestionType::Field);
+}
+
+#[test]
+fn test_no_value_gives_nothing() {
+    assert!(shorthand_suggestions(None, "na", SAMPLE).is_empty());
+}
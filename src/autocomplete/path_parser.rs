@@ -4,7 +4,7 @@
 //! Used by the autocomplete system to navigate nested structures.
 
 /// A segment in a jq path expression.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PathSegment {
     /// Field access: `.name`
     Field(String),
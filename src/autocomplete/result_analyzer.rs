@@ -224,7 +224,7 @@ impl ResultAnalyzer {
         }
     }
 
-    fn detect_json_type(value: &Value) -> JsonFieldType {
+    pub fn detect_json_type(value: &Value) -> JsonFieldType {
         match value {
             Value::Null => JsonFieldType::Null,
             Value::Bool(_) => JsonFieldType::Boolean,
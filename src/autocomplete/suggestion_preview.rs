@@ -0,0 +1,158 @@
+//! One-line preview of what the selected suggestion would return
+//!
+//! While the popup is open, the query with the selected suggestion applied
+//! runs in the background once navigation pauses, and the popup shows the
+//! first value it outputs. Controlled by `preview` in the `[autocomplete]`
+//! config section.
+
+use std::time::Instant;
+
+use crate::app::App;
+use crate::autocomplete::insertion::query_with_suggestion;
+use crate::query::debounced_preview::DebouncedPreview;
+use crate::query::executor::JqExecutor;
+use crate::query::worker::types::QueryError;
+use tokio_util::sync::CancellationToken;
+
+/// Characters of the first value kept for display
+const MAX_PREVIEW_CHARS: usize = 200;
+
+/// What the previewed query returned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewLine {
+    /// The first value, compact
+    Value(String),
+    /// The query outputs nothing
+    Empty,
+    /// The query fails with this message
+    Error(String),
+}
+
+/// Background run of the previewed query and its latest result
+pub struct SuggestionPreview {
+    enabled: bool,
+    runner: DebouncedPreview<PreviewLine>,
+    line: Option<PreviewLine>,
+}
+
+impl SuggestionPreview {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            runner: DebouncedPreview::new(),
+            line: None,
+        }
+    }
+
+    /// A preview already showing `line`
+    #[cfg(test)]
+    pub fn showing(line: PreviewLine) -> Self {
+        let mut preview = Self::new(true);
+        preview.line = Some(line);
+        preview
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The result for the current query, once it has arrived
+    pub fn line(&self) -> Option<&PreviewLine> {
+        self.line.as_ref()
+    }
+
+    /// Preview `query` from now on, or nothing with `None`
+    ///
+    /// A different query drops the shown result and cancels the run in
+    /// flight.
+    pub fn set_query(&mut self, query: Option<String>, now: Instant) {
+        if self.runner.set_query(query, now) {
+            self.line = None;
+        }
+    }
+
+    /// Start the pending query once due and pick up its result
+    ///
+    /// Returns true when a result arrived.
+    pub fn poll(&mut self, executor: &JqExecutor, now: Instant) -> bool {
+        match self.runner.poll(executor, now, first_value_of) {
+            Some(line) => {
+                self.line = Some(line);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Run `query` for its first value; `None` once cancelled
+fn first_value_of(
+    executor: &JqExecutor,
+    query: &str,
+    cancel: &CancellationToken,
+) -> Option<PreviewLine> {
+    Some(match executor.execute_compact(query, cancel) {
+        Ok(output) => first_value(&output),
+        Err(QueryError::Cancelled) => return None,
+        Err(QueryError::ExecutionFailed(stderr)) => PreviewLine::Error(error_summary(&stderr)),
+        Err(e) => PreviewLine::Error(e.to_string()),
+    })
+}
+
+/// Keep the preview in step with the popup selection and collect results
+///
+/// Returns true when the preview changed and needs redrawing.
+pub fn poll_suggestion_preview(app: &mut App) -> bool {
+    let now = Instant::now();
+    let had_line = app.suggestion_preview.line().is_some();
+    let query = preview_query(app);
+    app.suggestion_preview.set_query(query, now);
+    let cleared = had_line && app.suggestion_preview.line().is_none();
+
+    let Some(query_state) = &app.query else {
+        return cleared;
+    };
+    app.suggestion_preview.poll(&query_state.executor, now) || cleared
+}
+
+/// The query as it would read with the selected suggestion accepted
+fn preview_query(app: &App) -> Option<String> {
+    if !app.suggestion_preview.is_enabled() || !app.autocomplete.is_visible() {
+        return None;
+    }
+    query_with_suggestion(app, app.autocomplete.selected()?)
+}
+
+/// The first line of compact output, cut to [`MAX_PREVIEW_CHARS`]
+fn first_value(output: &str) -> PreviewLine {
+    match output.lines().find(|line| !line.trim().is_empty()) {
+        Some(line) => PreviewLine::Value(truncate(line.trim())),
+        None => PreviewLine::Empty,
+    }
+}
+
+/// jq's first error line without the `jq: error (at <stdin>:0):` prefix
+pub fn error_summary(stderr: &str) -> String {
+    let line = stderr.lines().next().unwrap_or_default().trim();
+    let message = match line.strip_prefix("jq: error") {
+        Some(rest) if rest.starts_with(" (at ") => {
+            rest.split_once("): ").map_or(rest, |(_, message)| message)
+        }
+        Some(rest) => rest.strip_prefix(": ").unwrap_or(rest),
+        None => line,
+    };
+    truncate(message)
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_PREVIEW_CHARS {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(MAX_PREVIEW_CHARS - 1).collect();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+#[path = "suggestion_preview_tests.rs"]
+mod suggestion_preview_tests;
@@ -0,0 +1,131 @@
+//! Tests for suggestion_preview
+
+use super::*;
+use crate::autocomplete::update_suggestions_from_app;
+use crate::query::debounced_preview::PREVIEW_DELAY;
+use crate::test_utils::test_helpers::test_app;
+use std::time::Duration;
+
+const JSON: &str = r#"{"name": "Alice", "tags": ["a", "b"], "age": 30}"#;
+
+fn app_typing(query: &str) -> App {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str(query);
+    update_suggestions_from_app(&mut app);
+    app
+}
+
+/// Poll until a result arrives, as the event loop would
+fn wait_for_line(preview: &mut SuggestionPreview, executor: &JqExecutor) -> PreviewLine {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        if preview.poll(executor, Instant::now()) {
+            return preview.line().cloned().unwrap();
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("preview did not arrive");
+}
+
+#[test]
+fn test_preview_query_applies_selected_suggestion() {
+    let app = app_typing(".na");
+    assert_eq!(preview_query(&app).as_deref(), Some(".name"));
+}
+
+#[test]
+fn test_no_preview_query_without_popup() {
+    let mut app = app_typing(".na");
+    app.autocomplete.hide();
+    assert_eq!(preview_query(&app), None);
+}
+
+#[test]
+fn test_no_preview_query_when_disabled() {
+    let mut app = app_typing(".na");
+    app.suggestion_preview = SuggestionPreview::new(false);
+    assert_eq!(preview_query(&app), None);
+}
+
+#[test]
+fn test_preview_waits_for_navigation_to_pause() {
+    let executor = JqExecutor::new(JSON.to_string());
+    let mut preview = SuggestionPreview::new(true);
+    let now = Instant::now();
+    preview.set_query(Some(".name".to_string()), now);
+
+    assert!(!preview.poll(&executor, now));
+    assert!(!preview.runner.is_running());
+}
+
+#[test]
+fn test_preview_shows_first_value() {
+    let executor = JqExecutor::new(JSON.to_string());
+    let mut preview = SuggestionPreview::new(true);
+    preview.set_query(Some(".tags[]".to_string()), Instant::now() - PREVIEW_DELAY);
+
+    assert_eq!(
+        wait_for_line(&mut preview, &executor),
+        PreviewLine::Value("\"a\"".to_string())
+    );
+}
+
+#[test]
+fn test_preview_reports_error() {
+    let executor = JqExecutor::new(JSON.to_string());
+    let mut preview = SuggestionPreview::new(true);
+    preview.set_query(
+        Some(".name.first".to_string()),
+        Instant::now() - PREVIEW_DELAY,
+    );
+
+    match wait_for_line(&mut preview, &executor) {
+        PreviewLine::Error(message) => assert!(message.starts_with("Cannot index string")),
+        other => panic!("expected an error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_new_query_drops_previous_result() {
+    let executor = JqExecutor::new(JSON.to_string());
+    let mut preview = SuggestionPreview::new(true);
+    preview.set_query(Some(".age".to_string()), Instant::now() - PREVIEW_DELAY);
+    wait_for_line(&mut preview, &executor);
+
+    preview.set_query(Some(".age".to_string()), Instant::now());
+    assert!(preview.line().is_some());
+
+    preview.set_query(Some(".name".to_string()), Instant::now());
+    assert!(preview.line().is_none());
+}
+
+#[test]
+fn test_first_value_takes_first_line() {
+    assert_eq!(
+        first_value("{\"a\":1}\n{\"a\":2}\n"),
+        PreviewLine::Value("{\"a\":1}".to_string())
+    );
+    assert_eq!(first_value(""), PreviewLine::Empty);
+}
+
+#[test]
+fn test_first_value_is_truncated() {
+    let long = format!("\"{}\"", "x".repeat(MAX_PREVIEW_CHARS * 2));
+    let PreviewLine::Value(value) = first_value(&long) else {
+        panic!("expected a value");
+    };
+    assert_eq!(value.chars().count(), MAX_PREVIEW_CHARS);
+    assert!(value.ends_with('…'));
+}
+
+#[test]
+fn test_error_summary_drops_jq_prefix() {
+    assert_eq!(
+        error_summary("jq: error (at <stdin>:0): Cannot index number with \"x\"\n"),
+        "Cannot index number with \"x\""
+    );
+    assert_eq!(
+        error_summary("jq: error: syntax error, unexpected end of file\njq: 1 compile error"),
+        "syntax error, unexpected end of file"
+    );
+}
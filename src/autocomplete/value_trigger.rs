@@ -153,7 +153,11 @@ pub(crate) fn classify(query: &str, cursor_byte: usize) -> Option<ValueTrigger>
 /// by walking outward through enclosing `select`/`map`/`<path> | <here>`
 /// constructs. Returns `None` if the surrounding context can't be folded
 /// safely (reduce, foreach, user-defined functions, etc.).
-fn fold_to_absolute_path(prefix: &str, start: usize, inner: Option<String>) -> Option<String> {
+pub(crate) fn fold_to_absolute_path(
+    prefix: &str,
+    start: usize,
+    inner: Option<String>,
+) -> Option<String> {
     let mut accumulated = inner.unwrap_or_default();
     let mut cursor = start;
     let mut hops = 0;
@@ -326,7 +330,7 @@ fn skip_ws_back(prefix: &str, pos: usize) -> usize {
     pos - i
 }
 
-fn clamp_to_char_boundary(s: &str, byte: usize) -> usize {
+pub(crate) fn clamp_to_char_boundary(s: &str, byte: usize) -> usize {
     let mut clamped = byte.min(s.len());
     while clamped > 0 && !s.is_char_boundary(clamped) {
         clamped -= 1;
@@ -334,7 +338,7 @@ fn clamp_to_char_boundary(s: &str, byte: usize) -> usize {
     clamped
 }
 
-fn is_h_ws(c: char) -> bool {
+pub(crate) fn is_h_ws(c: char) -> bool {
     c == ' ' || c == '\t'
 }
 
@@ -419,7 +423,7 @@ fn enclosing_function_call(prefix: &str, quote_open_byte: usize) -> Option<Enclo
     })
 }
 
-fn find_innermost_unclosed_paren(text: &str) -> Option<usize> {
+pub(crate) fn find_innermost_unclosed_paren(text: &str) -> Option<usize> {
     let mut state = ScanState::default();
     let mut stack: Vec<usize> = Vec::new();
     for (idx, ch) in text.char_indices() {
@@ -439,7 +443,7 @@ fn find_innermost_unclosed_paren(text: &str) -> Option<usize> {
     stack.last().copied()
 }
 
-fn identifier_start(text: &str, end: usize) -> usize {
+pub(crate) fn identifier_start(text: &str, end: usize) -> usize {
     let bytes = text.as_bytes();
     let mut i = end;
     while i > 0 && is_ident_byte(bytes[i - 1]) {
@@ -456,7 +460,7 @@ fn is_ident_byte(b: u8) -> bool {
 /// When there's no `<path> |` immediately before the call, returns
 /// `(None, name_start)` so callers can still fold from the call's own
 /// position.
-fn extract_pre_call_path(prefix: &str, name_start: usize) -> (Option<String>, usize) {
+pub(crate) fn extract_pre_call_path(prefix: &str, name_start: usize) -> (Option<String>, usize) {
     let bytes = prefix.as_bytes();
     let mut i = name_start;
     while i > 0 && is_h_ws(bytes[i - 1] as char) {
@@ -517,7 +521,7 @@ fn extract_trailing_path(text: &str) -> Option<String> {
     canonicalize_path(candidate)
 }
 
-fn is_path_byte(b: u8) -> bool {
+pub(crate) fn is_path_byte(b: u8) -> bool {
     b == b'.' || b == b'?' || b == b'[' || b == b']' || b == b'-' || is_ident_byte(b)
 }
 
@@ -0,0 +1,154 @@
+//! Variable context detection
+//!
+//! `$` starts a variable, and suggestions list the ones in scope: those
+//! the query binds, jq's `$ENV` and `$__loc__`, and those declared on the
+//! command line with `--arg`, `--rawfile` and the like. Where the query is
+//! naming a new variable, after `as`, `label` or inside a destructuring
+//! pattern, nothing is suggested.
+
+use super::autocomplete_state::{Suggestion, SuggestionType};
+use super::context::SuggestionContext;
+use super::variable_extractor::extract_variables;
+
+/// Determines context from variable prefix ($).
+/// Returns VariableContext if typing a variable usage, None if defining a variable.
+pub fn context_from_variable_prefix(
+    partial: &str,
+    before_cursor: &str,
+) -> Option<(SuggestionContext, String)> {
+    if !partial.starts_with('$') {
+        return None;
+    }
+
+    if is_in_variable_definition_context(before_cursor) {
+        return None;
+    }
+
+    let var_partial = partial.to_string();
+    Some((SuggestionContext::VariableContext, var_partial))
+}
+
+/// Variables for `partial`, the `declared_variables` first, then those
+/// the query binds under other names
+pub fn variable_suggestions(
+    query: &str,
+    partial: &str,
+    declared_variables: &[Suggestion],
+) -> Vec<Suggestion> {
+    let mut suggestions: Vec<Suggestion> = declared_variables.to_vec();
+    suggestions.extend(
+        extract_variables(query)
+            .into_iter()
+            .filter(|name| !declared_variables.iter().any(|d| &d.text == name))
+            .map(|name| Suggestion::new_with_type(name, SuggestionType::Variable, None)),
+    );
+    filter_suggestions_case_sensitive(suggestions, partial)
+}
+
+/// Filters suggestions case-sensitively (for variables which are case-sensitive in jq).
+fn filter_suggestions_case_sensitive(
+    suggestions: Vec<Suggestion>,
+    partial: &str,
+) -> Vec<Suggestion> {
+    suggestions
+        .into_iter()
+        .filter(|s| s.text.contains(partial))
+        .collect()
+}
+
+/// Checks if cursor is in a variable definition context where suggestions should not be shown.
+/// This includes positions after `as `, `label `, or inside destructuring patterns.
+fn is_in_variable_definition_context(before_cursor: &str) -> bool {
+    let dollar_pos = before_cursor.rfind('$');
+    let dollar_pos = match dollar_pos {
+        Some(pos) => pos,
+        None => return false,
+    };
+
+    let text_before_dollar = &before_cursor[..dollar_pos];
+    let trimmed = text_before_dollar.trim_end();
+
+    if is_after_definition_keyword(trimmed) {
+        return true;
+    }
+
+    if is_in_destructuring_pattern(trimmed) {
+        return true;
+    }
+
+    false
+}
+
+/// Checks if text ends with a definition keyword (as, label).
+fn is_after_definition_keyword(trimmed: &str) -> bool {
+    if let Some(before) = trimmed.strip_suffix("as") {
+        if before.is_empty() {
+            return true;
+        }
+        let char_before = before.chars().next_back();
+        if let Some(ch) = char_before {
+            return !ch.is_alphanumeric() && ch != '_';
+        }
+        return true;
+    }
+
+    if let Some(before) = trimmed.strip_suffix("label") {
+        if before.is_empty() {
+            return true;
+        }
+        let char_before = before.chars().next_back();
+        if let Some(ch) = char_before {
+            return !ch.is_alphanumeric() && ch != '_';
+        }
+        return true;
+    }
+
+    false
+}
+
+/// Checks if text indicates we're inside a destructuring pattern after `as`.
+fn is_in_destructuring_pattern(trimmed: &str) -> bool {
+    if trimmed.ends_with('[')
+        || trimmed.ends_with('{')
+        || trimmed.ends_with(',')
+        || trimmed.ends_with(':')
+    {
+        return has_unclosed_as_destructure(trimmed);
+    }
+    false
+}
+
+/// Checks if there's an unclosed destructuring pattern after `as`.
+fn has_unclosed_as_destructure(text: &str) -> bool {
+    for pattern in &[" as [", " as[", " as {", " as{"] {
+        if let Some(pos) = text.rfind(pattern) {
+            let after_as = &text[pos + pattern.len()..];
+
+            let open_brackets = after_as.chars().filter(|c| *c == '[').count();
+            let closed_brackets = after_as.chars().filter(|c| *c == ']').count();
+            let open_braces = after_as.chars().filter(|c| *c == '{').count();
+            let closed_braces = after_as.chars().filter(|c| *c == '}').count();
+
+            if pattern.contains('[') && open_brackets >= closed_brackets {
+                return true;
+            }
+            if pattern.contains('{') && open_braces >= closed_braces {
+                return true;
+            }
+        }
+    }
+
+    if text.ends_with("as [")
+        || text.ends_with("as[")
+        || text.ends_with("as {")
+        || text.ends_with("as{")
+    {
+        return true;
+    }
+
+    false
+}
+
+#[cfg(test)]
+#[path = "variable_context_tests.rs"]
+mod variable_context_tests;
@@ -1,7 +1,21 @@
-use super::common::{DEFAULT_ARRAY_SAMPLE_SIZE, empty_field_names, tracker_for};
+//! Tests for variable_context
+
 use crate::autocomplete::BraceTracker;
 use crate::autocomplete::context::{SuggestionContext, analyze_context};
 use crate::autocomplete::get_suggestions;
+use crate::autocomplete::json_navigator::DEFAULT_ARRAY_SAMPLE_SIZE;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+fn tracker_for(query: &str) -> BraceTracker {
+    let mut tracker = BraceTracker::new();
+    tracker.rebuild(query);
+    tracker
+}
+
+fn empty_field_names() -> Arc<HashSet<String>> {
+    Arc::new(HashSet::new())
+}
 
 fn get_var_suggestions(query: &str, cursor_pos: usize) -> Vec<String> {
     let tracker = tracker_for(query);
@@ -113,11 +127,18 @@ mod variable_definition_no_suggestions {
     fn after_label_keyword_with_preceding_token() {
         // When "label" appears mid-query (not as the whole trimmed string), the early
         // len == 5 return is skipped and is_after_definition_keyword inspects the char
-        // before "label" (nth(len-6)). A space precedes it, so it is a real label keyword
+        // before "label" (the char before "label"). A space precedes it, so it is a real label keyword
         // and variable suggestions must be suppressed.
         assert_context_is_not_variable(". | label $");
     }
 
+    #[test]
+    fn after_keyword_following_multibyte_chars() {
+        // The char before the keyword is found by byte offset, not char count
+        assert_context_is_not_variable(".名前 as $");
+        assert_context_is_not_variable(".\"🙂\" | label $");
+    }
+
     #[test]
     fn in_array_destructuring() {
         assert_context_is_not_variable(". as [$");
@@ -349,3 +370,62 @@ mod definition_context_edge_cases {
         assert_context_is_not_variable("(as [$");
     }
 }
+
+mod declared_variables {
+    use super::*;
+    use crate::autocomplete::get_suggestions_with_variables;
+    use crate::autocomplete::json_navigator::NavigationCache;
+    use crate::autocomplete::{Suggestion, SuggestionType};
+
+    fn declared() -> Vec<Suggestion> {
+        vec![
+            Suggestion::new_with_type("$users", SuggestionType::Variable, None)
+                .with_description("--slurpfile"),
+        ]
+    }
+
+    fn suggestions(query: &str) -> Vec<Suggestion> {
+        let tracker = tracker_for(query);
+        get_suggestions_with_variables(
+            &mut NavigationCache::new(),
+            query,
+            query.len(),
+            None,
+            None,
+            None,
+            empty_field_names(),
+            &tracker,
+            DEFAULT_ARRAY_SAMPLE_SIZE,
+            false,
+            &declared(),
+        )
+    }
+
+    #[test]
+    fn declared_variable_is_suggested_with_its_flag() {
+        let results = suggestions(". as $x | $u");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].text, "$users");
+        assert_eq!(results[0].description.as_deref(), Some("--slurpfile"));
+    }
+
+    #[test]
+    fn declared_variables_come_before_query_variables() {
+        let texts: Vec<String> = suggestions(". as $x | $")
+            .into_iter()
+            .map(|s| s.text)
+            .collect();
+        assert_eq!(texts[0], "$users");
+        assert!(texts.contains(&"$x".to_string()));
+        assert!(texts.contains(&"$ENV".to_string()));
+    }
+
+    #[test]
+    fn rebinding_declared_name_in_query_is_not_duplicated() {
+        let texts: Vec<String> = suggestions(". as $users | $us")
+            .into_iter()
+            .map(|s| s.text)
+            .collect();
+        assert_eq!(texts, vec!["$users".to_string()]);
+    }
+}
@@ -21,6 +21,94 @@ pub fn extract_variables(query: &str) -> Vec<String> {
     result
 }
 
+/// Names of the `$variables` the query uses without binding them, without
+/// the `$`, in the order they first appear. Uses inside string
+/// interpolation count; `$ENV` and `$__loc__` are never free.
+pub fn free_variables(query: &str) -> Vec<String> {
+    let defined = extract_user_variables(query);
+    let (references, parameters) = variable_references(query);
+    let mut free: Vec<String> = Vec::new();
+    for name in references {
+        if !defined.contains(&name)
+            && !parameters.contains(&name)
+            && !BUILTIN_VARIABLES.contains(&name.as_str())
+            && !free.contains(&name)
+        {
+            free.push(name);
+        }
+    }
+    free.into_iter().map(|name| name[1..].to_string()).collect()
+}
+
+/// Every `$name` in the query outside string literals, split into uses and
+/// the `$params` of `def` headers
+fn variable_references(query: &str) -> (Vec<String>, Vec<String>) {
+    let chars: Vec<char> = query.chars().collect();
+    let mut references = Vec::new();
+    let mut parameters = Vec::new();
+    // Paren depth at each open `\(` in a string, innermost last
+    let mut interpolations: Vec<usize> = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut in_def_header = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        if in_string {
+            match ch {
+                '\\' if chars.get(i + 1) == Some(&'(') => {
+                    interpolations.push(depth);
+                    depth += 1;
+                    in_string = false;
+                    i += 2;
+                    continue;
+                }
+                '\\' => i += 1,
+                '"' => in_string = false,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.saturating_sub(1);
+                if interpolations.last() == Some(&depth) {
+                    interpolations.pop();
+                    in_string = true;
+                }
+            }
+            ':' => in_def_header = false,
+            '$' => {
+                if let Some((name, end)) = extract_single_variable(&chars, i) {
+                    if in_def_header {
+                        parameters.push(name);
+                    } else {
+                        references.push(name);
+                    }
+                    i = end;
+                    continue;
+                }
+            }
+            _ if is_keyword_at(&chars, i, "def") => in_def_header = true,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    (references, parameters)
+}
+
 /// Extracts user-defined variables from the query, skipping those inside strings.
 fn extract_user_variables(query: &str) -> Vec<String> {
     let mut variables = Vec::new();
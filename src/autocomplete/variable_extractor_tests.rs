@@ -355,3 +355,44 @@ mod extract_variables_after_keyword_tests {
         assert_eq!(result, None);
     }
 }
+
+mod free_variables_tests {
+    use super::*;
+
+    #[test]
+    fn lists_unbound_variables_in_order() {
+        assert_eq!(
+            free_variables(".[] | select(.env == $env and .n > $min) | $env"),
+            ["env", "min"]
+        );
+    }
+
+    #[test]
+    fn skips_bound_and_builtin_variables() {
+        assert_eq!(
+            free_variables(".a as $x | reduce .[] as [$k, $v] (0; . + $v) | $x | $ENV | $__loc__"),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn skips_def_parameters() {
+        assert_eq!(
+            free_variables("def scale($f): . * $f; scale($factor)"),
+            ["factor"]
+        );
+    }
+
+    #[test]
+    fn counts_interpolation_but_not_string_text() {
+        assert_eq!(
+            free_variables(r#""cost $price: \(.n * $rate)" | $tail"#),
+            ["rate", "tail"]
+        );
+    }
+
+    #[test]
+    fn skips_comments() {
+        assert_eq!(free_variables(".a # uses $nothing\n| $b"), ["b"]);
+    }
+}
@@ -2,3 +2,5 @@ mod backend;
 pub mod clipboard_events;
 pub mod osc52;
 mod system;
+
+pub use backend::copy_to_clipboard;
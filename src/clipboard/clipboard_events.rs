@@ -44,12 +44,28 @@ fn copy_query(app: &mut App, backend: ClipboardBackend) -> bool {
 }
 
 fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
+    let Some((result, what)) = shown_result(app) else {
+        return false;
+    };
+
+    if copy_to_clipboard(&result, backend).is_ok() {
+        app.notification.show(&format!("Copied {}!", what));
+        true
+    } else {
+        false
+    }
+}
+
+/// What `y` copies from the results pane, with words for it: the rows a
+/// line filter or table shows, the path list, the visual selection or
+/// else the whole result
+pub fn shown_result(app: &App) -> Option<(String, String)> {
     // Only copy if query state is available
     let query_state = match &app.query {
         Some(q) => q,
         None => {
             log::debug!("Clipboard: no query state, nothing to copy");
-            return false;
+            return None;
         }
     };
 
@@ -58,17 +74,38 @@ fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
         Some(text) => text.as_ref().to_string(),
         None => {
             log::debug!("Clipboard: no result available to copy");
-            return false;
+            return None;
         }
     };
 
     if full_result.is_empty() {
         log::debug!("Clipboard: result is empty, nothing to copy");
-        return false;
+        return None;
     }
 
-    let (result, notification) = if app.results_cursor.is_visual_mode() {
+    let (result, what) = if app.results_filter.is_active() {
+        // The line filter's view is copied as shown
+        let lines: Vec<&str> = full_result.lines().collect();
+        let rows = app.results_filter.rows();
+        let shown: Vec<&str> = rows
+            .iter()
+            .filter_map(|&row| lines.get(row as usize).copied())
+            .collect();
+        (shown.join("\n"), format!("{} filtered lines", shown.len()))
+    } else if let Some(table) = app.results_table.shown() {
+        (
+            table.to_tsv(app.results_table.order()),
+            "table as TSV".to_string(),
+        )
+    } else if app.results_paths.is_active() {
+        (
+            app.results_paths.text().to_string(),
+            format!("{} paths", app.results_paths.lines().len()),
+        )
+    } else if app.results_cursor.is_visual_mode() {
         let (start, end) = app.results_cursor.selection_range();
+        // A folded block at the end of the selection is copied whole
+        let end = app.results_folds.last_line_of(end);
         let lines: Vec<&str> = full_result.lines().collect();
         let start_idx = start as usize;
         let end_idx = (end as usize).min(lines.len().saturating_sub(1));
@@ -76,29 +113,20 @@ fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
         if start_idx < lines.len() {
             let selected: String = lines[start_idx..=end_idx].join("\n");
             let line_count = end_idx - start_idx + 1;
-            let notification = if line_count == 1 {
-                "Copied 1 line!".to_string()
+            let what = if line_count == 1 {
+                "1 line".to_string()
             } else {
-                format!("Copied {} lines!", line_count)
+                format!("{} lines", line_count)
             };
-            (selected, notification)
+            (selected, what)
         } else {
-            return false;
+            return None;
         }
     } else {
-        (full_result, "Copied result!".to_string())
+        (full_result, "result".to_string())
     };
 
-    if result.is_empty() {
-        return false;
-    }
-
-    if copy_to_clipboard(&result, backend).is_ok() {
-        app.notification.show(&notification);
-        true
-    } else {
-        false
-    }
+    (!result.is_empty()).then_some((result, what))
 }
 
 #[cfg(test)]
@@ -6,7 +6,10 @@ pub fn copy(text: &str) -> ClipboardResult {
     let mut clipboard = Clipboard::new().map_err(|_| ClipboardError::SystemUnavailable)?;
 
     clipboard
-        .set_text(text)
+        .set_text(crate::platform::clipboard_text(
+            text,
+            crate::platform::WINDOWS,
+        ))
         .map_err(|_| ClipboardError::WriteError)
 }
 
@@ -0,0 +1,13 @@
+//! Ex-style command line
+//!
+//! `:` in Normal mode opens a prompt in place of the help line for
+//! commands such as `:w out.json`, `:open data.json`, `:set indent=4`,
+//! `:theme light`, `:wrap map` and `:help select`. Tab completes command names and
+//! their arguments; Enter runs the command and Esc drops it.
+
+pub mod command_line_events;
+pub mod command_line_render;
+pub mod command_line_state;
+pub mod commands;
+
+pub use command_line_state::CommandLineState;
@@ -0,0 +1,186 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::commands::{self, Command};
+use crate::app::App;
+use crate::input::FileLoader;
+use crate::theme::{self, ResolvedTheme};
+
+/// `:` in Normal mode
+pub fn open(app: &mut App) {
+    app.command_line.open();
+    let plugin_commands = app.plugins.command_names();
+    app.command_line.set_plugin_commands(plugin_commands);
+    app.autocomplete.hide();
+}
+
+/// Keys while the command line is open. Every key is taken so commands
+/// and file names can hold any character.
+pub fn handle_key(app: &mut App, key: KeyEvent) -> bool {
+    if !app.command_line.is_visible() {
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Esc => app.command_line.close(),
+        KeyCode::Enter => {
+            let text = app.command_line.text();
+            app.command_line.close();
+            if !text.trim().is_empty() {
+                execute(app, &text);
+            }
+        }
+        KeyCode::Tab => app.command_line.complete(true),
+        KeyCode::BackTab => app.command_line.complete(false),
+        // Deleting past the `:` leaves, as in vim
+        KeyCode::Backspace if app.command_line.text().is_empty() => app.command_line.close(),
+        _ => app.command_line.input(key),
+    }
+    true
+}
+
+/// Run a command line as typed after `:`, reporting a bad one in a
+/// notification. A name jiq doesn't know may be a plugin's command.
+pub fn execute(app: &mut App, text: &str) {
+    match commands::parse(text) {
+        Ok(command) => run(app, command),
+        Err(err) => {
+            let (name, args) = commands::split_name(text.trim());
+            if commands::COMMANDS.contains(&name) || !crate::plugins::run_command(app, name, args) {
+                app.notification.show_error(&err);
+            }
+        }
+    }
+}
+
+fn run(app: &mut App, command: Command) {
+    match command {
+        Command::Write(path) => crate::save::save_events::save_to(app, &path),
+        Command::Open(path) => open_file(app, &path),
+        Command::Edit => app.request_input_edit(),
+        Command::Set(option) => {
+            let Some(query_state) = app.query.as_ref() else {
+                return;
+            };
+            let mut format = query_state.output_format();
+            option.apply(&mut format);
+            crate::results::format_menu_events::set_format(app, format);
+        }
+        Command::Theme(resolved) => {
+            let name = match resolved {
+                ResolvedTheme::Dark => "dark",
+                ResolvedTheme::Light => "light",
+            };
+            theme::set(resolved);
+            // The results are colored by jq, so ask it again
+            crate::editor::editor_events::execute_query(app);
+            app.notification.show(&format!("Theme: {}", name));
+        }
+        Command::Help(None) => {
+            if !app.help.visible {
+                app.toggle_help();
+            }
+        }
+        Command::Help(Some(name)) => {
+            if crate::help::section_index_for(&name).is_some() {
+                app.help.open_manual(&name);
+            } else {
+                app.notification
+                    .show_error(&format!("No manual entry for '{}'", name));
+            }
+        }
+        Command::Wrap { scaffold, whole } => {
+            crate::editor::editor_events::wrap_query(app, scaffold, whole);
+        }
+        Command::Import(path) => {
+            let path = std::path::Path::new(&path);
+            match crate::history::import::import_file(&mut app.history, path) {
+                Ok(summary) => app.notification.show(&summary.message()),
+                Err(e) => {
+                    app.notification
+                        .show_error(&format!("Can't import {}: {}", path.display(), e))
+                }
+            }
+        }
+        Command::Private(setting) => {
+            let private = setting.unwrap_or(!app.history.is_private());
+            app.history.set_private(private);
+            app.notification.show(if private {
+                "Private mode: queries are not saved to history"
+            } else {
+                "Private mode off"
+            });
+        }
+        Command::RawInput(setting) => {
+            let raw_input = setting.unwrap_or(!app.jq_args.raw_input());
+            app.set_raw_input(raw_input);
+            app.notification.show(if raw_input {
+                "Raw input: each line is a string"
+            } else {
+                "Raw input off"
+            });
+        }
+        Command::Match(pattern) => match app.results_matches.add(&pattern) {
+            Ok(_) => app.notification.show(&format!(
+                "Highlighting /{}/ · {} pattern{} · :match-clear to remove",
+                pattern,
+                app.results_matches.len(),
+                if app.results_matches.len() == 1 {
+                    ""
+                } else {
+                    "s"
+                }
+            )),
+            Err(e) => app
+                .notification
+                .show_error(&format!("Invalid regex: {}", regex_problem(&e))),
+        },
+        Command::MatchClear => {
+            if app.results_matches.clear() == 0 {
+                app.notification.show("No :match highlights");
+            } else {
+                app.notification.show("Cleared :match highlights");
+            }
+        }
+        Command::Percent(percent) => {
+            crate::results::marks_events::jump_to_percent(app, percent);
+        }
+        Command::Freq(path) => {
+            crate::results::frequency_events::open_frequency_at(app, path.as_deref());
+        }
+        Command::Share => crate::save::save_events::share_to_clipboard(app),
+        Command::Plugins => crate::plugins::list(app),
+        Command::Post(name) => crate::plugins::set_post_processor(app, name),
+        Command::Quit => app.should_quit = true,
+    }
+}
+
+/// The gist of a regex error, which the regex crate spreads over several
+/// lines with the pattern drawn above it
+fn regex_problem(error: &regex::Error) -> String {
+    let message = error.to_string();
+    let last = message.lines().last().unwrap_or_default();
+    last.trim_start_matches("error: ").to_string()
+}
+
+/// `:open path` — load `path` in place of the current input; the query
+/// runs against it once it has loaded
+fn open_file(app: &mut App, path: &str) {
+    let path = std::path::PathBuf::from(path);
+    if !path.is_file() {
+        app.notification
+            .show_error(&format!("No such file: {}", path.display()));
+        return;
+    }
+    app.input_files = None;
+    app.malformed_input = None;
+    app.input_paths = vec![path.clone()];
+    let plugin = app.plugins.input_for(&path).map(|p| p.path.clone());
+    app.file_loader = Some(match plugin {
+        Some(plugin) => FileLoader::spawn_convert(path, plugin),
+        None => FileLoader::spawn_load(path),
+    });
+}
+
+#[cfg(test)]
+#[path = "command_line_events_tests.rs"]
+mod command_line_events_tests;
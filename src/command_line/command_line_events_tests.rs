@@ -0,0 +1,211 @@
+//! Tests for command_line/command_line_events
+
+use crate::app::App;
+use crate::editor::EditorMode;
+use crate::query::output_format::Indent;
+use crate::query::worker::preprocess::strip_ansi_codes;
+use crate::test_utils::test_helpers::{app_with_query, key, test_app, wait_for_query_completion};
+use ratatui::crossterm::event::KeyCode;
+
+fn app_in_normal_mode() -> App {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Normal;
+    app
+}
+
+fn run(app: &mut App, text: &str) {
+    app.handle_key_event(key(KeyCode::Char(':')));
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    app.handle_key_event(key(KeyCode::Enter));
+}
+
+#[test]
+fn test_colon_opens_and_esc_closes() {
+    let mut app = app_in_normal_mode();
+
+    app.handle_key_event(key(KeyCode::Char(':')));
+    assert!(app.command_line.is_visible());
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.command_line.is_visible());
+    assert_eq!(app.query(), ".");
+}
+
+#[test]
+fn test_typed_keys_go_to_the_command_line() {
+    let mut app = app_in_normal_mode();
+    app.handle_key_event(key(KeyCode::Char(':')));
+
+    for c in "set x".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+
+    assert_eq!(app.command_line.text(), "set x");
+    assert_eq!(app.query(), ".");
+    assert_eq!(app.input.editor_mode, EditorMode::Normal);
+}
+
+#[test]
+fn test_backspace_on_empty_line_closes() {
+    let mut app = app_in_normal_mode();
+    app.handle_key_event(key(KeyCode::Char(':')));
+
+    app.handle_key_event(key(KeyCode::Backspace));
+
+    assert!(!app.command_line.is_visible());
+}
+
+#[test]
+fn test_set_changes_the_output_format() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "set indent=4");
+
+    assert!(!app.command_line.is_visible());
+    let format = app.query.as_ref().unwrap().output_format();
+    assert_eq!(format.indent, Indent::Spaces(4));
+}
+
+#[test]
+fn test_unknown_command_shows_an_error() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "bogus");
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Unknown command 'bogus'")
+    );
+}
+
+#[test]
+fn test_help_opens_the_manual_entry() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "help select");
+
+    assert!(app.help.visible);
+    assert_eq!(app.help.active_tab, crate::help::HelpTab::Manual);
+}
+
+#[test]
+fn test_write_saves_the_result() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("out.json");
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, &format!("w {}", path.display()));
+
+    assert!(path.exists());
+}
+
+#[test]
+fn test_open_missing_file_is_reported() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "open /no/such/file.json");
+
+    assert!(app.file_loader.is_none());
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|m| m.starts_with("No such file"))
+    );
+}
+
+#[test]
+fn test_quit_sets_should_quit() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "q");
+
+    assert!(app.should_quit());
+}
+
+#[test]
+fn test_private_toggles_history_recording() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "private");
+    assert!(app.history.is_private());
+    app.history.add_entry(".secret");
+    assert_eq!(app.history.total_count(), 0);
+
+    run(&mut app, "private off");
+    assert!(!app.history.is_private());
+}
+
+#[test]
+fn test_raw_input_reads_each_line_as_a_string() {
+    let mut app = test_app("{\"a\": 1}\n[2]");
+    app.input.editor_mode = EditorMode::Normal;
+
+    run(&mut app, "raw-input");
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    assert!(app.jq_args.raw_input());
+    let result = strip_ansi_codes(app.query.as_ref().unwrap().result.as_ref().unwrap());
+    assert_eq!(result, "\"{\\\"a\\\": 1}\"\n\"[2]\"\n");
+
+    run(&mut app, "raw-input off");
+    assert!(wait_for_query_completion(&mut app, 2000));
+    assert!(!app.jq_args.raw_input());
+}
+
+#[test]
+fn test_match_highlights_until_cleared() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, r"match id-\d+");
+    run(&mut app, "match error");
+    assert_eq!(app.results_matches.len(), 2);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Highlighting /error/ · 2 patterns · :match-clear to remove")
+    );
+
+    run(&mut app, "match-clear");
+    assert!(app.results_matches.is_empty());
+}
+
+#[test]
+fn test_match_with_a_bad_regex_explains() {
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, "match (id");
+
+    assert!(app.results_matches.is_empty());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Invalid regex: unclosed group")
+    );
+}
+
+#[test]
+fn test_percent_moves_the_results_cursor() {
+    let mut app = test_app("[1, 2, 3, 4, 5, 6, 7, 8, 9]");
+    app.input.editor_mode = EditorMode::Normal;
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    run(&mut app, "100%");
+
+    assert_eq!(app.results_cursor.cursor_line(), 10);
+}
+
+#[test]
+fn test_import_adds_queries_to_history() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("queries.txt");
+    std::fs::write(&path, ".a\n.b\n").unwrap();
+    let mut app = app_in_normal_mode();
+
+    run(&mut app, &format!("import {}", path.display()));
+
+    assert_eq!(app.history.total_count(), 2);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Imported 2 queries (0 already in history)")
+    );
+}
@@ -0,0 +1,59 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::App;
+use crate::theme;
+
+/// The `:` prompt in the help line's row. While Tab cycles through
+/// several candidates they are listed to the right, the chosen one bold.
+pub fn render_line(app: &mut App, frame: &mut Frame, area: Rect) {
+    let text_width = app.command_line.text().width() as u16;
+    let [prompt_area, text_area, candidates_area] = Layout::horizontal([
+        Constraint::Length(1),
+        Constraint::Length(text_width + 2),
+        Constraint::Min(0),
+    ])
+    .areas(area);
+
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            ":",
+            Style::default().fg(theme::help_line::key()),
+        )),
+        prompt_area,
+    );
+
+    if let Some((candidates, selected)) = app.command_line.candidates() {
+        let desc_style = Style::default().fg(theme::help_line::description());
+        let spans: Vec<Span> = candidates
+            .iter()
+            .enumerate()
+            .flat_map(|(i, candidate)| {
+                let style = if i == selected {
+                    Style::default()
+                        .fg(theme::help_line::key())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    desc_style
+                };
+                [Span::raw(" "), Span::styled(candidate.clone(), style)]
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(Line::from(spans)), candidates_area);
+    }
+
+    let textarea = app.command_line.textarea_mut();
+    textarea.set_style(Style::default().fg(theme::palette::text()));
+    textarea.set_cursor_line_style(Style::default());
+    frame.render_widget(&*textarea, text_area);
+}
+
+#[cfg(test)]
+#[path = "command_line_render_tests.rs"]
+mod command_line_render_tests;
@@ -0,0 +1,37 @@
+//! Tests for command_line/command_line_render
+
+use super::*;
+use crate::test_utils::test_helpers::{key, test_app};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+
+fn render(app: &mut App) -> String {
+    let backend = TestBackend::new(50, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| render_line(app, f, f.area())).unwrap();
+    terminal.backend().to_string()
+}
+
+fn app_with_line(text: &str) -> App {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.command_line.open();
+    for c in text.chars() {
+        app.command_line.input(key(KeyCode::Char(c)));
+    }
+    app
+}
+
+#[test]
+fn snapshot_command_line_with_text() {
+    let mut app = app_with_line("set indent=4");
+    assert_snapshot!(render(&mut app));
+}
+
+#[test]
+fn snapshot_command_line_listing_candidates() {
+    let mut app = app_with_line("set no");
+    app.command_line.complete(true);
+    assert_snapshot!(render(&mut app));
+}
@@ -0,0 +1,136 @@
+use ratatui::crossterm::event::KeyEvent;
+use tui_textarea::TextArea;
+
+use super::commands;
+
+/// Candidates Tab is cycling through, replacing the text from `start`
+#[derive(Debug)]
+struct Completion {
+    start: usize,
+    candidates: Vec<String>,
+    selected: usize,
+}
+
+pub struct CommandLineState {
+    visible: bool,
+    textarea: TextArea<'static>,
+    completion: Option<Completion>,
+    /// Plugin commands, completed alongside jiq's own
+    plugin_commands: Vec<String>,
+}
+
+impl Default for CommandLineState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandLineState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            textarea: TextArea::default(),
+            completion: None,
+            plugin_commands: Vec::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.textarea = TextArea::default();
+        self.completion = None;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.completion = None;
+    }
+
+    pub fn set_plugin_commands(&mut self, names: Vec<String>) {
+        self.plugin_commands = names;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn text(&self) -> String {
+        self.textarea.lines().join("")
+    }
+
+    pub fn textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.textarea
+    }
+
+    /// Edit the text; any edit ends a Tab cycle
+    pub fn input(&mut self, key: KeyEvent) {
+        self.completion = None;
+        self.textarea.input(key);
+    }
+
+    /// Tab / Shift+Tab: complete the last word, or step through the
+    /// candidates when there are several
+    pub fn complete(&mut self, forward: bool) {
+        let text = self.text();
+        let completion = match self.completion.take() {
+            Some(mut completion) => {
+                let len = completion.candidates.len();
+                completion.selected = if forward {
+                    (completion.selected + 1) % len
+                } else {
+                    (completion.selected + len - 1) % len
+                };
+                completion
+            }
+            None => {
+                let (start, mut candidates) = commands::complete(&text);
+                if !text.contains(' ') {
+                    let plugin_names: Vec<String> = self
+                        .plugin_commands
+                        .iter()
+                        .filter(|name| name.starts_with(&text) && !candidates.contains(name))
+                        .cloned()
+                        .collect();
+                    candidates.extend(plugin_names);
+                }
+                if candidates.is_empty() {
+                    return;
+                }
+                let selected = if forward { 0 } else { candidates.len() - 1 };
+                Completion {
+                    start,
+                    candidates,
+                    selected,
+                }
+            }
+        };
+
+        let mut completed = format!(
+            "{}{}",
+            &text[..completion.start],
+            completion.candidates[completion.selected]
+        );
+        if completion.candidates.len() == 1 {
+            // A lone command name is done; move on to its argument
+            if completion.start == 0 {
+                completed.push(' ');
+            }
+        } else {
+            self.completion = Some(completion);
+        }
+        self.textarea = TextArea::from([completed]);
+        self.textarea.move_cursor(tui_textarea::CursorMove::End);
+    }
+
+    /// The candidates of the current Tab cycle and the selected one, when
+    /// there are several to choose from
+    pub fn candidates(&self) -> Option<(&[String], usize)> {
+        self.completion
+            .as_ref()
+            .map(|completion| (completion.candidates.as_slice(), completion.selected))
+    }
+}
+
+#[cfg(test)]
+#[path = "command_line_state_tests.rs"]
+mod command_line_state_tests;
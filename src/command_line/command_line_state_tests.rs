@@ -0,0 +1,68 @@
+//! Tests for command_line/command_line_state
+
+use super::*;
+use crate::test_utils::test_helpers::key;
+use ratatui::crossterm::event::KeyCode;
+
+fn state_with(text: &str) -> CommandLineState {
+    let mut state = CommandLineState::new();
+    state.open();
+    for c in text.chars() {
+        state.input(key(KeyCode::Char(c)));
+    }
+    state
+}
+
+#[test]
+fn test_open_starts_empty_and_close_hides() {
+    let mut state = state_with("set");
+    state.close();
+    assert!(!state.is_visible());
+
+    state.open();
+    assert!(state.is_visible());
+    assert_eq!(state.text(), "");
+}
+
+#[test]
+fn test_unique_command_completes_with_a_space() {
+    let mut state = state_with("the");
+    state.complete(true);
+    assert_eq!(state.text(), "theme ");
+    assert!(state.candidates().is_none());
+
+    state.complete(true);
+    assert_eq!(state.text(), "theme dark");
+    assert_eq!(state.candidates().map(|(_, selected)| selected), Some(0));
+}
+
+#[test]
+fn test_tab_cycles_and_shift_tab_goes_back() {
+    let mut state = state_with("theme ");
+    state.complete(true);
+    state.complete(true);
+    assert_eq!(state.text(), "theme light");
+
+    state.complete(true);
+    assert_eq!(state.text(), "theme dark");
+
+    state.complete(false);
+    assert_eq!(state.text(), "theme light");
+}
+
+#[test]
+fn test_typing_ends_the_cycle() {
+    let mut state = state_with("set no");
+    state.complete(true);
+    assert!(state.candidates().is_some());
+
+    state.input(key(KeyCode::Char('x')));
+    assert!(state.candidates().is_none());
+}
+
+#[test]
+fn test_no_candidates_leaves_the_text() {
+    let mut state = state_with("zz");
+    state.complete(true);
+    assert_eq!(state.text(), "zz");
+}
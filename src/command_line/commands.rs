@@ -0,0 +1,288 @@
+use std::path::Path;
+
+use crate::editor::wrap::{self, SCAFFOLDS, Scaffold};
+use crate::help::MANUAL_SECTIONS;
+use crate::query::output_format::{Indent, MAX_INDENT, OutputFormat};
+use crate::theme::ResolvedTheme;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `:w path` — save the result, or export the query for a script extension
+    Write(String),
+    /// `:open path` — load another input file
+    Open(String),
+    /// `:edit` — fix the input in `$EDITOR`
+    Edit,
+    /// `:set option` — change how results are printed
+    Set(SetOption),
+    /// `:theme dark|light`
+    Theme(ResolvedTheme),
+    /// `:help [name]` — the help popup, or the manual at `name`
+    Help(Option<String>),
+    /// `:wrap name [all]` — wrap the last stage, or the whole query
+    Wrap {
+        scaffold: &'static Scaffold,
+        whole: bool,
+    },
+    /// `:import path` — add the queries in a file to history
+    Import(String),
+    /// `:private [on|off]` — stop or resume recording history; toggles
+    /// without an argument
+    Private(Option<bool>),
+    /// `:raw-input [on|off]` — read each input line as a string, as jq's
+    /// `-R`; toggles without an argument
+    RawInput(Option<bool>),
+    /// `:match regex` — keep the regex's matches highlighted in the results
+    Match(String),
+    /// `:match-clear` — drop every `:match` highlight
+    MatchClear,
+    /// `:50%` — move the results cursor that far through the output
+    Percent(u32),
+    /// `:freq [path]` — rank the result's most frequent values, or those
+    /// at `path`
+    Freq(Option<String>),
+    /// `:share` — copy the query, input sample and output as markdown
+    Share,
+    /// `:plugins` — list the plugins found
+    Plugins,
+    /// `:post name|off` — rewrite the output printed on exit with a
+    /// plugin, or stop
+    Post(Option<String>),
+    /// `:q`
+    Quit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOption {
+    /// `indent=N`
+    Indent(u8),
+    /// `tab`
+    Tab,
+    /// `sort-keys` / `nosort-keys`
+    SortKeys(bool),
+    /// `ascii` / `noascii`
+    Ascii(bool),
+}
+
+impl SetOption {
+    pub fn apply(self, format: &mut OutputFormat) {
+        match self {
+            SetOption::Indent(n) => format.indent = Indent::Spaces(n),
+            SetOption::Tab => format.indent = Indent::Tab,
+            SetOption::SortKeys(on) => format.sort_keys = on,
+            SetOption::Ascii(on) => format.ascii = on,
+        }
+    }
+}
+
+/// Command names as Tab completes them; `w`, `o`, `e`, `h` and `q` also work
+pub const COMMANDS: &[&str] = &[
+    "edit",
+    "freq",
+    "help",
+    "import",
+    "match",
+    "match-clear",
+    "open",
+    "plugins",
+    "post",
+    "private",
+    "quit",
+    "raw-input",
+    "set",
+    "share",
+    "theme",
+    "wrap",
+    "write",
+];
+
+const SET_OPTIONS: &[&str] = &[
+    "ascii",
+    "indent=",
+    "noascii",
+    "nosort-keys",
+    "sort-keys",
+    "tab",
+];
+
+const THEMES: &[&str] = &["dark", "light"];
+
+const SWITCHES: &[&str] = &["off", "on"];
+
+/// Parse the text typed after `:`
+pub fn parse(input: &str) -> Result<Command, String> {
+    let (name, arg) = split_name(input.trim());
+    match name {
+        "w" | "write" => required(name, arg, "a file name").map(Command::Write),
+        "o" | "open" => required(name, arg, "a file name").map(Command::Open),
+        "import" => required(name, arg, "a file name").map(Command::Import),
+        "set" => parse_set(arg).map(Command::Set),
+        "theme" => match arg {
+            "dark" => Ok(Command::Theme(ResolvedTheme::Dark)),
+            "light" => Ok(Command::Theme(ResolvedTheme::Light)),
+            "" => Err("theme needs dark or light".to_string()),
+            other => Err(format!("Unknown theme '{}': use dark or light", other)),
+        },
+        "h" | "help" => Ok(Command::Help((!arg.is_empty()).then(|| arg.to_string()))),
+        "wrap" => parse_wrap(arg),
+        "match" => required(name, arg, "a regex").map(Command::Match),
+        "match-clear" if arg.is_empty() => Ok(Command::MatchClear),
+        "match-clear" => Err("match-clear takes no arguments".to_string()),
+        "private" => parse_switch(arg).map(Command::Private),
+        "raw-input" => parse_switch(arg).map(Command::RawInput),
+        "e" | "edit" if arg.is_empty() => Ok(Command::Edit),
+        "e" | "edit" => Err(format!("{} takes no arguments", name)),
+        "freq" => Ok(Command::Freq((!arg.is_empty()).then(|| arg.to_string()))),
+        "share" if arg.is_empty() => Ok(Command::Share),
+        "share" => Err("share takes no arguments".to_string()),
+        "plugins" if arg.is_empty() => Ok(Command::Plugins),
+        "plugins" => Err("plugins takes no arguments".to_string()),
+        "post" => match arg {
+            "" => Err("post needs a plugin name or off".to_string()),
+            "off" => Ok(Command::Post(None)),
+            name => Ok(Command::Post(Some(name.to_string()))),
+        },
+        "q" | "quit" if arg.is_empty() => Ok(Command::Quit),
+        "q" | "quit" => Err(format!("{} takes no arguments", name)),
+        percent if percent.ends_with('%') && arg.is_empty() => parse_percent(percent),
+        other => Err(format!("Unknown command '{}'", other)),
+    }
+}
+
+pub fn split_name(input: &str) -> (&str, &str) {
+    match input.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (input, ""),
+    }
+}
+
+fn required(name: &str, arg: &str, what: &str) -> Result<String, String> {
+    if arg.is_empty() {
+        Err(format!("{} needs {}", name, what))
+    } else {
+        Ok(arg.to_string())
+    }
+}
+
+/// `on`, `off`, or nothing to toggle
+fn parse_switch(arg: &str) -> Result<Option<bool>, String> {
+    match arg {
+        "" => Ok(None),
+        "on" => Ok(Some(true)),
+        "off" => Ok(Some(false)),
+        other => Err(format!("Unknown setting '{}': use on or off", other)),
+    }
+}
+
+fn parse_percent(percent: &str) -> Result<Command, String> {
+    match percent.trim_end_matches('%').parse::<u32>() {
+        Ok(n) if n <= 100 => Ok(Command::Percent(n)),
+        _ => Err("Percentage must be 0-100".to_string()),
+    }
+}
+
+fn parse_wrap(arg: &str) -> Result<Command, String> {
+    let (name, scope) = split_name(arg);
+    let scaffold = match wrap::by_name(name) {
+        Some(scaffold) => scaffold,
+        None if name.is_empty() => return Err("wrap needs a scaffold".to_string()),
+        None => return Err(format!("Unknown scaffold '{}'", name)),
+    };
+    match scope {
+        "" => Ok(Command::Wrap {
+            scaffold,
+            whole: false,
+        }),
+        "all" => Ok(Command::Wrap {
+            scaffold,
+            whole: true,
+        }),
+        other => Err(format!("Unknown wrap scope '{}': use all", other)),
+    }
+}
+
+fn parse_set(arg: &str) -> Result<SetOption, String> {
+    if let Some(value) = arg.strip_prefix("indent=") {
+        return match value.parse::<u8>() {
+            Ok(n) if (1..=MAX_INDENT).contains(&n) => Ok(SetOption::Indent(n)),
+            _ => Err(format!("indent must be 1 to {}", MAX_INDENT)),
+        };
+    }
+    match arg {
+        "tab" => Ok(SetOption::Tab),
+        "sort-keys" => Ok(SetOption::SortKeys(true)),
+        "nosort-keys" => Ok(SetOption::SortKeys(false)),
+        "ascii" => Ok(SetOption::Ascii(true)),
+        "noascii" => Ok(SetOption::Ascii(false)),
+        "" => Err("set needs indent=N, tab, sort-keys or ascii".to_string()),
+        other => Err(format!("Unknown option '{}'", other)),
+    }
+}
+
+/// Completions for the last word of `input`: the byte offset where that
+/// word starts, and what may replace it
+pub fn complete(input: &str) -> (usize, Vec<String>) {
+    let Some((name, rest)) = input.split_once(' ') else {
+        return (0, matching(COMMANDS.iter().copied(), input));
+    };
+    let start = input.len() - rest.trim_start().len();
+    let arg = &input[start..];
+    let candidates = match name {
+        "set" => matching(SET_OPTIONS.iter().copied(), arg),
+        "theme" => matching(THEMES.iter().copied(), arg),
+        "private" | "raw-input" => matching(SWITCHES.iter().copied(), arg),
+        "wrap" => matching(SCAFFOLDS.iter().map(|scaffold| scaffold.name), arg),
+        "h" | "help" => {
+            let mut names = matching(
+                MANUAL_SECTIONS
+                    .iter()
+                    .flat_map(|section| section.entries.iter().map(|entry| entry.function)),
+                arg,
+            );
+            names.sort();
+            names.dedup();
+            names
+        }
+        "w" | "write" | "o" | "open" | "import" => complete_path(arg),
+        _ => Vec::new(),
+    };
+    (start, candidates)
+}
+
+fn matching<'a>(words: impl Iterator<Item = &'a str>, prefix: &str) -> Vec<String> {
+    words
+        .filter(|word| word.starts_with(prefix))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Files and directories starting with `arg`, directories ending in `/`.
+/// Hidden entries are offered once the name starts with a dot.
+fn complete_path(arg: &str) -> Vec<String> {
+    let (dir, prefix) = match arg.rfind('/') {
+        Some(i) => arg.split_at(i + 1),
+        None => ("", arg),
+    };
+    let read_from = if dir.is_empty() { "." } else { dir };
+    let Ok(entries) = std::fs::read_dir(Path::new(read_from)) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.')) {
+                return None;
+            }
+            let slash = if entry.path().is_dir() { "/" } else { "" };
+            Some(format!("{}{}{}", dir, name, slash))
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[cfg(test)]
+#[path = "commands_tests.rs"]
+mod commands_tests;
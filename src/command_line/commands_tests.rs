@@ -0,0 +1,241 @@
+//! Tests for command_line/commands
+
+use super::*;
+
+#[test]
+fn test_parse_write_and_open_take_a_path() {
+    assert_eq!(
+        parse("w out.json"),
+        Ok(Command::Write("out.json".to_string()))
+    );
+    assert_eq!(
+        parse("write  ~/out.json "),
+        Ok(Command::Write("~/out.json".to_string()))
+    );
+    assert_eq!(
+        parse("open data.json"),
+        Ok(Command::Open("data.json".to_string()))
+    );
+    assert_eq!(parse("w"), Err("w needs a file name".to_string()));
+}
+
+#[test]
+fn test_parse_set_options() {
+    assert_eq!(
+        parse("set indent=4"),
+        Ok(Command::Set(SetOption::Indent(4)))
+    );
+    assert_eq!(parse("set tab"), Ok(Command::Set(SetOption::Tab)));
+    assert_eq!(
+        parse("set nosort-keys"),
+        Ok(Command::Set(SetOption::SortKeys(false)))
+    );
+    assert_eq!(parse("set ascii"), Ok(Command::Set(SetOption::Ascii(true))));
+}
+
+#[test]
+fn test_parse_set_rejects_out_of_range_indent() {
+    assert_eq!(
+        parse("set indent=0"),
+        Err("indent must be 1 to 7".to_string())
+    );
+    assert_eq!(
+        parse("set indent=x"),
+        Err("indent must be 1 to 7".to_string())
+    );
+    assert_eq!(parse("set wrap"), Err("Unknown option 'wrap'".to_string()));
+}
+
+#[test]
+fn test_parse_theme_help_and_quit() {
+    assert_eq!(
+        parse("theme light"),
+        Ok(Command::Theme(ResolvedTheme::Light))
+    );
+    assert!(parse("theme blue").is_err());
+    assert_eq!(parse("help"), Ok(Command::Help(None)));
+    assert_eq!(
+        parse("help select"),
+        Ok(Command::Help(Some("select".to_string())))
+    );
+    assert_eq!(parse("q"), Ok(Command::Quit));
+    assert!(parse("q now").is_err());
+}
+
+#[test]
+fn test_parse_unknown_command() {
+    assert_eq!(
+        parse("frobnicate"),
+        Err("Unknown command 'frobnicate'".to_string())
+    );
+}
+
+#[test]
+fn test_apply_set_option() {
+    let mut format = OutputFormat::default();
+    SetOption::Tab.apply(&mut format);
+    SetOption::SortKeys(true).apply(&mut format);
+    assert_eq!(format.indent, Indent::Tab);
+    assert!(format.sort_keys);
+
+    SetOption::Indent(4).apply(&mut format);
+    assert_eq!(format.indent, Indent::Spaces(4));
+}
+
+#[test]
+fn test_complete_command_names() {
+    assert_eq!(complete("th"), (0, vec!["theme".to_string()]));
+    assert_eq!(
+        complete(""),
+        (0, COMMANDS.iter().map(|c| c.to_string()).collect())
+    );
+}
+
+#[test]
+fn test_complete_arguments_after_the_name() {
+    assert_eq!(complete("theme l"), (6, vec!["light".to_string()]));
+    assert_eq!(
+        complete("set  no"),
+        (5, vec!["noascii".to_string(), "nosort-keys".to_string()])
+    );
+    assert_eq!(complete("q "), (2, Vec::new()));
+}
+
+#[test]
+fn test_complete_help_offers_manual_entries() {
+    let (start, candidates) = complete("help sele");
+    assert_eq!(start, 5);
+    assert!(candidates.contains(&"select".to_string()));
+}
+
+#[test]
+fn test_complete_paths_lists_matching_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("data.json"), "{}").unwrap();
+    std::fs::write(dir.path().join("date.txt"), "").unwrap();
+    std::fs::write(dir.path().join(".hidden"), "").unwrap();
+    std::fs::create_dir(dir.path().join("dat")).unwrap();
+    let base = format!("{}/", dir.path().display());
+
+    let (_, candidates) = complete(&format!("w {}da", base));
+    assert_eq!(
+        candidates,
+        vec![
+            format!("{}dat/", base),
+            format!("{}data.json", base),
+            format!("{}date.txt", base),
+        ]
+    );
+
+    let (_, hidden) = complete(&format!("open {}.", base));
+    assert_eq!(hidden, vec![format!("{}.hidden", base)]);
+}
+
+#[test]
+fn test_parse_wrap_with_optional_scope() {
+    let map = crate::editor::wrap::by_name("map").unwrap();
+    assert_eq!(
+        parse("wrap map"),
+        Ok(Command::Wrap {
+            scaffold: map,
+            whole: false
+        })
+    );
+    assert_eq!(
+        parse("wrap map all"),
+        Ok(Command::Wrap {
+            scaffold: map,
+            whole: true
+        })
+    );
+    assert_eq!(parse("wrap"), Err("wrap needs a scaffold".to_string()));
+    assert!(parse("wrap map some").is_err());
+    assert_eq!(complete("wrap s"), (5, vec!["select".to_string()]));
+}
+
+#[test]
+fn test_parse_private() {
+    assert_eq!(parse("private"), Ok(Command::Private(None)));
+    assert_eq!(parse("private on"), Ok(Command::Private(Some(true))));
+    assert_eq!(parse("private off"), Ok(Command::Private(Some(false))));
+    assert!(parse("private maybe").is_err());
+    assert_eq!(
+        complete("private o"),
+        (8, vec!["off".to_string(), "on".to_string()])
+    );
+}
+
+#[test]
+fn test_parse_raw_input() {
+    assert_eq!(parse("raw-input"), Ok(Command::RawInput(None)));
+    assert_eq!(parse("raw-input on"), Ok(Command::RawInput(Some(true))));
+    assert_eq!(parse("raw-input off"), Ok(Command::RawInput(Some(false))));
+    assert!(parse("raw-input lines").is_err());
+    assert_eq!(complete("raw-input of"), (10, vec!["off".to_string()]));
+}
+
+#[test]
+fn test_parse_match() {
+    assert_eq!(
+        parse(r"match id-\d+"),
+        Ok(Command::Match(r"id-\d+".to_string()))
+    );
+    assert!(parse("match").is_err());
+    assert_eq!(parse("match-clear"), Ok(Command::MatchClear));
+    assert!(parse("match-clear id").is_err());
+    assert_eq!(
+        complete("mat"),
+        (0, vec!["match".to_string(), "match-clear".to_string()])
+    );
+}
+
+#[test]
+fn test_parse_percent() {
+    assert_eq!(parse("50%"), Ok(Command::Percent(50)));
+    assert_eq!(parse("100%"), Ok(Command::Percent(100)));
+    assert!(parse("150%").is_err());
+    assert!(parse("half%").is_err());
+}
+
+#[test]
+fn test_parse_import() {
+    assert_eq!(
+        parse("import ~/.bash_history"),
+        Ok(Command::Import("~/.bash_history".to_string()))
+    );
+    assert!(parse("import").is_err());
+}
+
+#[test]
+fn test_parse_share() {
+    assert_eq!(parse("share"), Ok(Command::Share));
+    assert!(parse("share now").is_err());
+}
+
+#[test]
+fn test_parse_freq() {
+    assert_eq!(parse("freq"), Ok(Command::Freq(None)));
+    assert_eq!(
+        parse("freq .[].status"),
+        Ok(Command::Freq(Some(".[].status".to_string())))
+    );
+}
+
+#[test]
+fn test_parse_plugins_and_post() {
+    assert_eq!(parse("plugins"), Ok(Command::Plugins));
+    assert!(parse("plugins all").is_err());
+    assert_eq!(
+        parse("post yaml"),
+        Ok(Command::Post(Some("yaml".to_string())))
+    );
+    assert_eq!(parse("post off"), Ok(Command::Post(None)));
+    assert!(parse("post").is_err());
+}
+
+#[test]
+fn test_parse_edit() {
+    assert_eq!(parse("edit"), Ok(Command::Edit));
+    assert_eq!(parse("e"), Ok(Command::Edit));
+    assert!(parse("edit file.json").is_err());
+}
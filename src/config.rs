@@ -7,19 +7,31 @@ mod types;
 // AI types are used internally via Config struct
 pub use types::{ClipboardBackend, Config};
 
+pub use types::AccessibilityConfig;
 #[allow(unused_imports)]
-pub use types::AutocompleteConfig;
+pub use types::{ArraySampling, AutocompleteConfig, SuggestionOrder};
 #[allow(unused_imports)]
 pub use types::{ThemeConfig, ThemeMode};
 
 // Re-export for integration tests
 #[allow(unused_imports)]
 pub use ai_types::{AiConfig, AiProviderType, AnthropicConfig};
+pub use types::HistoryConfig;
+pub use types::HooksConfig;
+#[allow(unused_imports)]
+pub use types::JqConfig;
+pub use types::LargeInputConfig;
+#[allow(unused_imports)]
+pub use types::NetworkConfig;
+#[allow(unused_imports)]
+pub use types::OnboardingConfig;
+pub use types::OutputConfig;
 #[allow(unused_imports)]
 pub use types::TooltipConfig;
 
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 
 /// Result of loading configuration
 pub struct ConfigResult {
@@ -27,7 +39,7 @@ pub struct ConfigResult {
     pub warning: Option<String>,
 }
 
-/// Loads configuration from ~/.config/jiq/config.toml
+/// Loads configuration from ~/.config/jiq/config.toml (see [`get_config_path`])
 /// Returns default configuration if file doesn't exist or on parse errors
 pub fn load_config() -> ConfigResult {
     let config_path = get_config_path();
@@ -82,14 +94,57 @@ pub fn load_config() -> ConfigResult {
     }
 }
 
+/// Set `key = value` under `[section]` in the config file for each of
+/// `settings`, keeping the rest of the file as it is, comments included.
+/// The file is created when there is none yet. Returns its path.
+pub fn save_settings(settings: &[(&str, &str, bool)]) -> Result<PathBuf, String> {
+    let config_path = get_config_path();
+    write_settings(&config_path, settings)?;
+    Ok(config_path)
+}
+
+fn write_settings(config_path: &Path, settings: &[(&str, &str, bool)]) -> Result<(), String> {
+    let contents = match fs::read_to_string(config_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read config: {}", e)),
+    };
+    let mut document = contents
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|e| format!("Invalid config: {}", e))?;
+    for (section, key, value) in settings {
+        let table = document
+            .entry(section)
+            .or_insert(toml_edit::table())
+            .as_table_like_mut()
+            .ok_or_else(|| format!("Invalid config: {} is not a table", section))?;
+        match table.get_mut(key).and_then(toml_edit::Item::as_value_mut) {
+            // Keep the comment after the old value
+            Some(old) => {
+                let decor = old.decor().clone();
+                *old = toml_edit::Value::from(*value);
+                *old.decor_mut() = decor;
+            }
+            None => {
+                table.insert(key, toml_edit::value(*value));
+            }
+        }
+    }
+
+    if let Some(dir) = config_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to write config: {}", e))?;
+    }
+    fs::write(config_path, document.to_string())
+        .map_err(|e| format!("Failed to write config: {}", e))
+}
+
 /// Returns the path to the configuration file
 ///
-/// Always uses ~/.config/jiq/config.toml on all platforms for consistency.
+/// ~/.config/jiq/config.toml, or %APPDATA%\jiq\config.toml on Windows
+/// unless ~/.config/jiq already exists there.
 fn get_config_path() -> PathBuf {
-    dirs::home_dir()
+    crate::platform::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
-        .join(".config")
-        .join("jiq")
         .join("config.toml")
 }
 
@@ -18,6 +18,29 @@ fn default_max_context_length() -> u32 {
     100_000
 }
 
+/// Object keys whose string values are masked before reaching the AI
+fn default_redaction_keys() -> Vec<String> {
+    [
+        "password",
+        "passwd",
+        "secret",
+        "token",
+        "api_key",
+        "access_key",
+        "private_key",
+        "authorization",
+        "credential",
+        "cookie",
+    ]
+    .iter()
+    .map(|k| k.to_string())
+    .collect()
+}
+
+fn default_true() -> bool {
+    true
+}
+
 /// AI provider selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -81,6 +104,30 @@ pub struct GeminiConfig {
     pub model: Option<String>,
 }
 
+/// Secret redaction applied to AI context
+#[derive(Debug, Clone, Deserialize)]
+pub struct RedactionConfig {
+    /// Whether to redact before sending (on by default)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Key substrings (case-, `_`- and `-`-insensitive) whose values are masked
+    #[serde(default = "default_redaction_keys")]
+    pub keys: Vec<String>,
+    /// Extra value regexes, on top of the built-in JWT and API key patterns
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        RedactionConfig {
+            enabled: true,
+            keys: default_redaction_keys(),
+            patterns: Vec::new(),
+        }
+    }
+}
+
 /// AI assistant configuration section
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct AiConfig {
@@ -105,6 +152,9 @@ pub struct AiConfig {
     /// Gemini-specific configuration
     #[serde(default)]
     pub gemini: GeminiConfig,
+    /// Secret redaction settings
+    #[serde(default)]
+    pub redaction: RedactionConfig,
 }
 
 #[cfg(test)]
@@ -244,3 +244,29 @@ model = "gpt-4o-mini"
     let config: Config = toml::from_str(toml).unwrap();
     assert_eq!(config.ai.openai.api_key, Some("   ".to_string()));
 }
+
+#[test]
+fn test_redaction_defaults_enabled_with_common_keys() {
+    let config = AiConfig::default();
+    assert!(config.redaction.enabled);
+    assert!(config.redaction.keys.iter().any(|k| k == "password"));
+    assert!(config.redaction.keys.iter().any(|k| k == "token"));
+    assert!(config.redaction.patterns.is_empty());
+}
+
+#[test]
+fn test_parse_redaction_section() {
+    let toml = r#"
+[ai.redaction]
+enabled = false
+keys = ["ssn"]
+patterns = ["acct-[0-9]+"]
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.ai.redaction.enabled);
+    assert_eq!(config.ai.redaction.keys, vec!["ssn".to_string()]);
+    assert_eq!(
+        config.ai.redaction.patterns,
+        vec!["acct-[0-9]+".to_string()]
+    );
+}
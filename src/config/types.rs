@@ -44,6 +44,23 @@ pub enum ThemeMode {
 pub struct ThemeConfig {
     #[serde(default)]
     pub mode: ThemeMode,
+    /// Draw with attributes instead of colors. Unset follows `NO_COLOR`
+    /// and `TERM=dumb`.
+    #[serde(default)]
+    pub monochrome: Option<bool>,
+}
+
+/// Accessibility configuration section
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct AccessibilityConfig {
+    /// Plain borders and a status line that says what changed, for
+    /// terminal screen readers
+    #[serde(default)]
+    pub screen_reader: bool,
+    /// Stack panes instead of placing them side by side. Unset follows
+    /// `screen_reader`.
+    #[serde(default)]
+    pub linear_layout: Option<bool>,
 }
 
 /// Tooltip configuration section
@@ -63,25 +80,294 @@ impl Default for TooltipConfig {
     }
 }
 
+/// How field and function suggestions are ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestionOrder {
+    /// Frequently and recently accepted completions first
+    #[default]
+    Usage,
+    Alphabetical,
+}
+
+/// Which elements of a long array the field index looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ArraySampling {
+    /// The first `array_sample_size` elements
+    #[default]
+    First,
+    /// Half from the start of the array and half from its end
+    FirstLast,
+    /// One element from each of `array_sample_size` equal stretches of
+    /// the array, the same ones every run
+    Random,
+}
+
 /// Autocomplete configuration section
 #[derive(Debug, Clone, Deserialize)]
 pub struct AutocompleteConfig {
     #[serde(default = "default_array_sample_size")]
     pub array_sample_size: usize,
+    #[serde(default)]
+    pub array_sampling: ArraySampling,
+    #[serde(default)]
+    pub order: SuggestionOrder,
+    #[serde(default = "default_tunnel_arrays")]
+    pub tunnel_arrays: bool,
+    #[serde(default = "default_preview")]
+    pub preview: bool,
+    /// Open the popup while typing; when off, only Ctrl+Space opens it
+    #[serde(default = "default_auto_show")]
+    pub auto_show: bool,
 }
 
 fn default_array_sample_size() -> usize {
     10
 }
 
+fn default_tunnel_arrays() -> bool {
+    true
+}
+
+fn default_preview() -> bool {
+    true
+}
+
 impl Default for AutocompleteConfig {
     fn default() -> Self {
         AutocompleteConfig {
             array_sample_size: 10,
+            array_sampling: ArraySampling::First,
+            order: SuggestionOrder::Usage,
+            tunnel_arrays: true,
+            preview: true,
+            auto_show: true,
+        }
+    }
+}
+
+/// Network configuration section
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct NetworkConfig {
+    /// Hard-disable every network-touching subsystem (the AI worker today)
+    #[serde(default)]
+    pub offline: bool,
+}
+
+/// jq binary configuration section
+#[derive(Debug, Clone, Deserialize)]
+pub struct JqConfig {
+    /// The jq to run instead of the one in PATH; `--jq` wins over it
+    #[serde(default)]
+    pub path: Option<std::path::PathBuf>,
+    /// Directories searched for jq modules, before any `-L` flags
+    #[serde(default)]
+    pub library_paths: Vec<std::path::PathBuf>,
+    /// `.jq` file of definitions included in every query; `prelude.jq`
+    /// next to this file is used when it exists
+    #[serde(default)]
+    pub prelude: Option<std::path::PathBuf>,
+    /// Seconds a query may run before jq is killed; 0 for no limit
+    #[serde(default = "default_jq_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_jq_timeout_secs() -> u64 {
+    5
+}
+
+impl JqConfig {
+    /// The query timeout, or None when it's turned off
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        (self.timeout_secs > 0).then(|| std::time::Duration::from_secs(self.timeout_secs))
+    }
+}
+
+impl Default for JqConfig {
+    fn default() -> Self {
+        JqConfig {
+            path: None,
+            library_paths: Vec::new(),
+            prelude: None,
+            timeout_secs: default_jq_timeout_secs(),
+        }
+    }
+}
+
+/// First-run onboarding configuration section
+#[derive(Debug, Clone, Deserialize)]
+pub struct OnboardingConfig {
+    /// Show the tips on first launch; F2 replays them either way
+    #[serde(default = "default_onboarding_enabled")]
+    pub enabled: bool,
+}
+
+fn default_onboarding_enabled() -> bool {
+    true
+}
+
+impl Default for OnboardingConfig {
+    fn default() -> Self {
+        OnboardingConfig { enabled: true }
+    }
+}
+
+/// Results pane configuration section
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResultsConfig {
+    /// Flash the lines a query edit added or removed
+    #[serde(default = "default_highlight_changes")]
+    pub highlight_changes: bool,
+    /// Megabytes of a query's output kept for the pane before jq is
+    /// stopped; 0 for no limit
+    #[serde(default = "default_max_output_mb")]
+    pub max_output_mb: u64,
+}
+
+fn default_highlight_changes() -> bool {
+    true
+}
+
+fn default_max_output_mb() -> u64 {
+    256
+}
+
+impl ResultsConfig {
+    /// The output limit in bytes, or None when it's turned off
+    pub fn output_limit(&self) -> Option<usize> {
+        (self.max_output_mb > 0).then(|| self.max_output_mb.saturating_mul(1024 * 1024) as usize)
+    }
+}
+
+impl Default for ResultsConfig {
+    fn default() -> Self {
+        ResultsConfig {
+            highlight_changes: true,
+            max_output_mb: default_max_output_mb(),
         }
     }
 }
 
+/// jq output flags section, also saved from the flags panel (F4)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct OutputConfig {
+    /// `--raw-output`: strings without quotes
+    #[serde(default)]
+    pub raw: bool,
+    /// `--compact-output`: each value on one line
+    #[serde(default)]
+    pub compact: bool,
+    /// `--sort-keys`
+    #[serde(default)]
+    pub sort_keys: bool,
+    /// `--tab`: indent with tabs
+    #[serde(default)]
+    pub tab: bool,
+    /// `--exit-status`: exit with jq's status for the printed result
+    #[serde(default)]
+    pub exit_status: bool,
+}
+
+/// Large input configuration section
+#[derive(Debug, Clone, Deserialize)]
+pub struct LargeInputConfig {
+    /// Inputs of at least this many megabytes are large: files are
+    /// memory-mapped, a banner says so and the features below are off
+    #[serde(default = "default_large_input_threshold_mb")]
+    pub threshold_mb: u64,
+    /// Parse large inputs for autocomplete and tooltips anyway
+    #[serde(default)]
+    pub analysis: bool,
+    /// Send the AI assistant a sample and schema of large inputs anyway
+    #[serde(default)]
+    pub ai_context: bool,
+}
+
+fn default_large_input_threshold_mb() -> u64 {
+    100
+}
+
+impl LargeInputConfig {
+    pub fn threshold_bytes(&self) -> u64 {
+        self.threshold_mb.saturating_mul(1024 * 1024)
+    }
+
+    /// Whether an input of `bytes` counts as large
+    pub fn is_large(&self, bytes: usize) -> bool {
+        bytes as u64 >= self.threshold_bytes()
+    }
+
+    /// Whether an input of `bytes` is parsed for autocomplete and tooltips
+    pub fn analysis_for(&self, bytes: usize) -> bool {
+        self.analysis || !self.is_large(bytes)
+    }
+
+    /// Whether the AI assistant sees a sample and schema of an input of
+    /// `bytes`
+    pub fn ai_context_for(&self, bytes: usize) -> bool {
+        self.ai_context || !self.is_large(bytes)
+    }
+}
+
+impl Default for LargeInputConfig {
+    fn default() -> Self {
+        LargeInputConfig {
+            threshold_mb: default_large_input_threshold_mb(),
+            analysis: false,
+            ai_context: false,
+        }
+    }
+}
+
+/// Query history configuration section
+#[derive(Debug, Clone, Deserialize)]
+pub struct HistoryConfig {
+    /// Queries kept before the oldest unpinned ones are pruned
+    #[serde(default = "default_history_max_entries")]
+    pub max_entries: usize,
+    /// Keep the history files readable by their owner only
+    #[serde(default)]
+    pub restrict_permissions: bool,
+    /// Encrypt the history files with the passphrase in
+    /// `JIQ_HISTORY_PASSPHRASE`
+    #[serde(default)]
+    pub encrypt: bool,
+    /// Show what the highlighted entry returns beside the popup's list
+    #[serde(default = "default_preview")]
+    pub preview: bool,
+}
+
+fn default_history_max_entries() -> usize {
+    crate::history::storage::MAX_HISTORY_ENTRIES
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        HistoryConfig {
+            max_entries: default_history_max_entries(),
+            restrict_permissions: false,
+            encrypt: false,
+            preview: default_preview(),
+        }
+    }
+}
+
+/// Hook commands section
+///
+/// Each hook is a shell command with `{query}`, `{file}` and `{format}`
+/// filled in; see `crate::hooks`.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct HooksConfig {
+    /// Run when the input has loaded
+    #[serde(default)]
+    pub on_load: Option<String>,
+    /// Run after jiq prints the result or the query on exit, with what
+    /// it printed on stdin
+    #[serde(default)]
+    pub on_exit: Option<String>,
+}
+
 /// Root configuration structure
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct Config {
@@ -95,6 +381,24 @@ pub struct Config {
     pub ai: AiConfig,
     #[serde(default)]
     pub autocomplete: AutocompleteConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub onboarding: OnboardingConfig,
+    #[serde(default)]
+    pub results: ResultsConfig,
+    #[serde(default)]
+    pub output: OutputConfig,
+    #[serde(default)]
+    pub accessibility: AccessibilityConfig,
+    #[serde(default)]
+    pub large_input: LargeInputConfig,
+    #[serde(default)]
+    pub jq: JqConfig,
+    #[serde(default)]
+    pub history: HistoryConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
 }
 
 #[cfg(test)]
@@ -135,6 +135,22 @@ mode = "dark"
     assert_eq!(config.theme.mode, ThemeMode::Dark);
 }
 
+#[test]
+fn test_monochrome_unset_by_default() {
+    let config: Config = toml::from_str("[theme]\nmode = \"dark\"\n").unwrap();
+    assert_eq!(config.theme.monochrome, None);
+}
+
+#[test]
+fn test_parse_monochrome() {
+    let toml = r#"
+[theme]
+monochrome = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.theme.monochrome, Some(false));
+}
+
 #[test]
 fn test_invalid_theme_mode_fails_parse() {
     let toml = r#"
@@ -168,6 +184,52 @@ fn test_empty_theme_section_uses_default() {
 fn test_autocomplete_config_default() {
     let config = AutocompleteConfig::default();
     assert_eq!(config.array_sample_size, 10);
+    assert_eq!(config.array_sampling, ArraySampling::First);
+    assert_eq!(config.order, SuggestionOrder::Usage);
+    assert!(config.tunnel_arrays);
+    assert!(config.preview);
+    assert!(config.auto_show);
+}
+
+#[test]
+fn test_parse_autocomplete_tunnel_arrays_off() {
+    let toml = r#"
+[autocomplete]
+tunnel_arrays = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.autocomplete.tunnel_arrays);
+}
+
+#[test]
+fn test_parse_autocomplete_auto_show_off() {
+    let toml = r#"
+[autocomplete]
+auto_show = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.autocomplete.auto_show);
+    assert!(config.tooltip.auto_show);
+}
+
+#[test]
+fn test_parse_autocomplete_preview_off() {
+    let toml = r#"
+[autocomplete]
+preview = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.autocomplete.preview);
+}
+
+#[test]
+fn test_parse_autocomplete_order_alphabetical() {
+    let toml = r#"
+[autocomplete]
+order = "alphabetical"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.autocomplete.order, SuggestionOrder::Alphabetical);
 }
 
 #[test]
@@ -180,6 +242,16 @@ array_sample_size = 50
     assert_eq!(config.autocomplete.array_sample_size, 50);
 }
 
+#[test]
+fn test_parse_autocomplete_array_sampling() {
+    let toml = r#"
+[autocomplete]
+array_sampling = "first_last"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.autocomplete.array_sampling, ArraySampling::FirstLast);
+}
+
 #[test]
 fn test_missing_autocomplete_section_uses_default() {
     let toml = r#"
@@ -204,3 +276,230 @@ fn test_empty_config_includes_autocomplete_default() {
     let config: Config = toml::from_str("").unwrap();
     assert_eq!(config.autocomplete.array_sample_size, 10);
 }
+
+#[test]
+fn test_network_config_default_is_online() {
+    let config = NetworkConfig::default();
+    assert!(!config.offline);
+}
+
+#[test]
+fn test_parse_network_offline_true() {
+    let toml = r#"
+[network]
+offline = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.network.offline);
+}
+
+#[test]
+fn test_missing_network_section_uses_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.network.offline);
+}
+
+#[test]
+fn test_large_input_defaults() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.large_input.threshold_mb, 100);
+    assert!(!config.large_input.is_large(100 * 1024 * 1024 - 1));
+    assert!(config.large_input.is_large(100 * 1024 * 1024));
+    assert!(!config.large_input.analysis_for(200 * 1024 * 1024));
+    assert!(config.large_input.ai_context_for(1024));
+}
+
+#[test]
+fn test_parse_large_input_section() {
+    let toml = r#"
+[large_input]
+threshold_mb = 8
+analysis = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.large_input.threshold_bytes(), 8 * 1024 * 1024);
+    assert!(config.large_input.analysis_for(usize::MAX));
+    assert!(!config.large_input.ai_context_for(usize::MAX));
+}
+
+#[test]
+fn test_onboarding_enabled_by_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.onboarding.enabled);
+}
+
+#[test]
+fn test_parse_onboarding_disabled() {
+    let toml = r#"
+[onboarding]
+enabled = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.onboarding.enabled);
+}
+
+#[test]
+fn test_highlight_changes_enabled_by_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.results.highlight_changes);
+}
+
+#[test]
+fn test_parse_highlight_changes_disabled() {
+    let toml = r#"
+[results]
+highlight_changes = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.results.highlight_changes);
+}
+
+#[test]
+fn test_screen_reader_off_by_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.accessibility.screen_reader);
+    assert_eq!(config.accessibility.linear_layout, None);
+}
+
+#[test]
+fn test_parse_accessibility() {
+    let toml = r#"
+[accessibility]
+screen_reader = true
+linear_layout = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.accessibility.screen_reader);
+    assert_eq!(config.accessibility.linear_layout, Some(false));
+}
+
+#[test]
+fn test_parse_jq_path() {
+    let toml = r#"
+[jq]
+path = "/opt/jq/bin/jq"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.jq.path,
+        Some(std::path::PathBuf::from("/opt/jq/bin/jq"))
+    );
+}
+
+#[test]
+fn test_missing_jq_section_uses_path() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.jq.path, None);
+    assert!(config.jq.library_paths.is_empty());
+    assert_eq!(config.jq.prelude, None);
+}
+
+#[test]
+fn test_parse_jq_modules_and_prelude() {
+    let toml = r#"
+[jq]
+library_paths = ["/opt/jq/modules"]
+prelude = "/opt/jq/defs.jq"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.jq.library_paths,
+        vec![std::path::PathBuf::from("/opt/jq/modules")]
+    );
+    assert_eq!(
+        config.jq.prelude,
+        Some(std::path::PathBuf::from("/opt/jq/defs.jq"))
+    );
+}
+
+#[test]
+fn test_jq_timeout_defaults_to_five_seconds() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.jq.timeout(), Some(std::time::Duration::from_secs(5)));
+}
+
+#[test]
+fn test_jq_timeout_of_zero_is_no_limit() {
+    let toml = r#"
+[jq]
+timeout_secs = 0
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.jq.timeout(), None);
+}
+
+#[test]
+fn test_results_output_limit_defaults_to_256_mb() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.results.max_output_mb, 256);
+    assert_eq!(config.results.output_limit(), Some(256 * 1024 * 1024));
+}
+
+#[test]
+fn test_results_output_limit_of_zero_is_no_limit() {
+    let toml = r#"
+[results]
+max_output_mb = 0
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.results.output_limit(), None);
+}
+
+#[test]
+fn test_history_preview_defaults_to_on() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.history.preview);
+
+    let config: Config = toml::from_str("[history]\npreview = false\n").unwrap();
+    assert!(!config.history.preview);
+}
+
+#[test]
+fn test_history_max_entries_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.history.max_entries, 1000);
+}
+
+#[test]
+fn test_parse_history_max_entries() {
+    let toml = r#"
+[history]
+max_entries = 200
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.history.max_entries, 200);
+}
+
+#[test]
+fn test_parse_history_protection() {
+    let toml = r#"
+[history]
+restrict_permissions = true
+encrypt = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.history.restrict_permissions);
+    assert!(config.history.encrypt);
+    assert_eq!(config.history.max_entries, 1000);
+}
+
+#[test]
+fn test_hooks_default_to_none() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.hooks.on_load.is_none());
+    assert!(config.hooks.on_exit.is_none());
+}
+
+#[test]
+fn test_parse_hooks() {
+    let toml = r#"
+[hooks]
+on_exit = "echo {query} >> queries.txt"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.hooks.on_exit.as_deref(),
+        Some("echo {query} >> queries.txt")
+    );
+    assert!(config.hooks.on_load.is_none());
+}
@@ -255,3 +255,62 @@ fn test_load_config_unreadable_file_returns_read_warning() {
         warning
     );
 }
+
+#[test]
+fn test_write_settings_keeps_the_rest_of_the_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    fs::write(
+        &path,
+        "# my settings\n[tooltip]\nauto_show = true # keep\n\n[clipboard]\nbackend = \"osc52\"\n",
+    )
+    .unwrap();
+
+    write_settings(
+        &path,
+        &[("tooltip", "auto_show", false), ("output", "raw", true)],
+    )
+    .unwrap();
+
+    let written = fs::read_to_string(&path).unwrap();
+    assert!(written.starts_with("# my settings\n"), "{}", written);
+    assert!(written.contains("auto_show = false # keep"), "{}", written);
+    assert!(written.contains("backend = \"osc52\""), "{}", written);
+    let config: Config = toml::from_str(&written).unwrap();
+    assert!(!config.tooltip.auto_show);
+    assert!(config.output.raw);
+    assert_eq!(config.clipboard.backend, ClipboardBackend::Osc52);
+}
+
+#[test]
+fn test_write_settings_creates_the_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("jiq").join("config.toml");
+
+    write_settings(&path, &[("output", "compact", true)]).unwrap();
+
+    let config: Config = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert!(config.output.compact);
+}
+
+#[test]
+fn test_write_settings_refuses_an_invalid_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "[tooltip\n").unwrap();
+
+    assert!(write_settings(&path, &[("output", "raw", true)]).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "[tooltip\n");
+}
+
+#[test]
+fn test_parse_output_section() {
+    let toml = r#"
+[output]
+raw = true
+tab = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.output.raw && config.output.tab);
+    assert!(!config.output.compact && !config.output.sort_keys);
+}
@@ -0,0 +1,24 @@
+//! Debug logging
+//!
+//! `--debug`, `JIQ_DEBUG=1` or a debug build write the `log::debug!` lines
+//! to `/tmp/jiq-debug.log`; `--log-file PATH` or `JIQ_LOG_FILE=PATH` pick
+//! another file. Spans around key handling, query runs and AI requests
+//! log when each starts and how long it took, and F12 opens a popup with
+//! the tail of the log while jiq runs; `t` there adds an overlay with how
+//! long recent frames took to draw and keys to handle.
+
+mod frame_timings;
+mod frame_timings_render;
+mod log_file;
+mod log_span;
+mod log_viewer_events;
+mod log_viewer_render;
+mod log_viewer_state;
+
+pub use frame_timings::FrameTimings;
+pub use frame_timings_render::render_overlay;
+pub use log_file::{LOG_FILE_ENV, LogSettings, init};
+pub use log_span::LogSpan;
+pub use log_viewer_events::{handle_key, toggle};
+pub use log_viewer_render::render_popup;
+pub use log_viewer_state::LogViewer;
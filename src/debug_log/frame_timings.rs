@@ -0,0 +1,75 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Frames and keys the overlay looks back over
+pub const WINDOW: usize = 60;
+
+/// How long recent frames took to draw and keys to handle, shown in a
+/// corner overlay that `t` in the debug log popup turns on
+#[derive(Debug, Default)]
+pub struct FrameTimings {
+    visible: bool,
+    frames: VecDeque<Duration>,
+    keys: VecDeque<Duration>,
+}
+
+/// The newest, mean and longest of a run of timings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingSummary {
+    pub last: Duration,
+    pub average: Duration,
+    pub max: Duration,
+}
+
+impl FrameTimings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    pub fn record_frame(&mut self, took: Duration) {
+        push(&mut self.frames, took);
+    }
+
+    pub fn record_key(&mut self, took: Duration) {
+        push(&mut self.keys, took);
+    }
+
+    /// Over the last `WINDOW` frames; None before the first
+    pub fn frames(&self) -> Option<TimingSummary> {
+        summarize(&self.frames)
+    }
+
+    /// Over the last `WINDOW` key presses; None before the first
+    pub fn keys(&self) -> Option<TimingSummary> {
+        summarize(&self.keys)
+    }
+}
+
+fn push(timings: &mut VecDeque<Duration>, took: Duration) {
+    if timings.len() == WINDOW {
+        timings.pop_front();
+    }
+    timings.push_back(took);
+}
+
+fn summarize(timings: &VecDeque<Duration>) -> Option<TimingSummary> {
+    let last = *timings.back()?;
+    let total: Duration = timings.iter().sum();
+    Some(TimingSummary {
+        last,
+        average: total / timings.len() as u32,
+        max: timings.iter().copied().max().unwrap_or(last),
+    })
+}
+
+#[cfg(test)]
+#[path = "frame_timings_tests.rs"]
+mod frame_timings_tests;
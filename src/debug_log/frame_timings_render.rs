@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::frame_timings::{FrameTimings, TimingSummary};
+use crate::theme;
+
+const WIDTH: u16 = 38;
+const HEIGHT: u16 = 4;
+
+/// Render the frame timings in the top right corner of `area`
+pub fn render_overlay(timings: &FrameTimings, frame: &mut Frame, area: Rect) {
+    if area.width < WIDTH || area.height < HEIGHT {
+        return;
+    }
+    let overlay_area = Rect {
+        x: area.x + area.width - WIDTH,
+        y: area.y,
+        width: WIDTH,
+        height: HEIGHT,
+    };
+    frame.render_widget(Clear, overlay_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme::help::border()))
+        .style(Style::default().bg(theme::help::background()))
+        .title(Span::styled(
+            " Frame timings ",
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+    let lines = vec![row("draw", timings.frames()), row("key", timings.keys())];
+    frame.render_widget(Paragraph::new(lines).block(block), overlay_area);
+}
+
+fn row(label: &str, summary: Option<TimingSummary>) -> Line<'static> {
+    let muted = Style::default().fg(theme::help::footer());
+    let mut spans = vec![Span::styled(format!(" {:<5}", label), muted)];
+    match summary {
+        Some(s) => {
+            spans.push(Span::raw(format!("{:>7}", millis(s.last))));
+            spans.push(Span::styled("  avg ", muted));
+            spans.push(Span::raw(millis(s.average)));
+            spans.push(Span::styled("  max ", muted));
+            spans.push(Span::raw(millis(s.max)));
+        }
+        None => spans.push(Span::styled("—", muted)),
+    }
+    Line::from(spans)
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+#[path = "frame_timings_render_tests.rs"]
+mod frame_timings_render_tests;
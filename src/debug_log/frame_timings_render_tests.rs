@@ -0,0 +1,49 @@
+//! Tests for debug_log/frame_timings_render
+
+use std::time::Duration;
+
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+use super::*;
+
+fn render_to_string(timings: &FrameTimings, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| render_overlay(timings, f, f.area()))
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn shows_frame_and_key_timings() {
+    let mut timings = FrameTimings::new();
+    timings.record_frame(Duration::from_micros(1_500));
+    timings.record_frame(Duration::from_micros(2_500));
+    timings.record_key(Duration::from_micros(300));
+
+    let output = render_to_string(&timings, 60, 6);
+
+    assert!(output.contains("Frame timings"), "{output}");
+    assert!(
+        output.contains("draw   2.5ms  avg 2.0ms  max 2.5ms"),
+        "{output}"
+    );
+    assert!(
+        output.contains("key    0.3ms  avg 0.3ms  max 0.3ms"),
+        "{output}"
+    );
+}
+
+#[test]
+fn dash_before_anything_was_timed() {
+    let output = render_to_string(&FrameTimings::new(), 60, 6);
+    assert!(output.contains("draw —"), "{output}");
+}
+
+#[test]
+fn skipped_when_too_small() {
+    let output = render_to_string(&FrameTimings::new(), 20, 3);
+    assert!(!output.contains("Frame"), "{output}");
+}
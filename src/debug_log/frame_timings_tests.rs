@@ -0,0 +1,58 @@
+//! Tests for debug_log/frame_timings
+
+use std::time::Duration;
+
+use super::*;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn nothing_to_summarize_at_first() {
+    let timings = FrameTimings::new();
+    assert_eq!(timings.frames(), None);
+    assert_eq!(timings.keys(), None);
+}
+
+#[test]
+fn summary_has_last_average_and_max() {
+    let mut timings = FrameTimings::new();
+    for n in [2, 9, 4] {
+        timings.record_frame(ms(n));
+    }
+    timings.record_key(ms(1));
+
+    assert_eq!(
+        timings.frames(),
+        Some(TimingSummary {
+            last: ms(4),
+            average: ms(5),
+            max: ms(9),
+        })
+    );
+    assert_eq!(timings.keys().map(|k| k.last), Some(ms(1)));
+}
+
+#[test]
+fn only_the_last_window_counts() {
+    let mut timings = FrameTimings::new();
+    timings.record_frame(ms(500));
+    for _ in 0..WINDOW {
+        timings.record_frame(ms(3));
+    }
+
+    let frames = timings.frames().unwrap();
+    assert_eq!(frames.max, ms(3));
+    assert_eq!(frames.average, ms(3));
+}
+
+#[test]
+fn toggle_shows_and_hides() {
+    let mut timings = FrameTimings::new();
+    assert!(!timings.is_visible());
+    timings.toggle();
+    assert!(timings.is_visible());
+    timings.toggle();
+    assert!(!timings.is_visible());
+}
@@ -0,0 +1,137 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Where logs go when only `--debug` or `JIQ_DEBUG=1` asks for them
+pub const DEFAULT_LOG_PATH: &str = "/tmp/jiq-debug.log";
+
+/// Environment variable naming the log file, like `--log-file`
+pub const LOG_FILE_ENV: &str = "JIQ_LOG_FILE";
+
+/// Bytes read from the end of the log for its tail
+const TAIL_BYTES: u64 = 64 * 1024;
+
+/// File the running session logs to
+static ACTIVE_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Where to log and what switched logging on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogSettings {
+    pub path: PathBuf,
+    pub method: &'static str,
+}
+
+impl LogSettings {
+    /// Settings from the command line, the environment and the build; None
+    /// when logging is off. A log file given either way turns logging on;
+    /// `--log-file` wins over `JIQ_LOG_FILE`.
+    pub fn resolve(
+        cli_debug: bool,
+        cli_path: Option<&Path>,
+        env_debug: bool,
+        env_path: Option<PathBuf>,
+        debug_build: bool,
+    ) -> Option<Self> {
+        if let Some(path) = cli_path {
+            return Some(Self {
+                path: path.to_path_buf(),
+                method: "--log-file",
+            });
+        }
+        if let Some(path) = env_path.filter(|path| !path.as_os_str().is_empty()) {
+            return Some(Self {
+                path,
+                method: "JIQ_LOG_FILE env var",
+            });
+        }
+        let method = match (cli_debug, env_debug, debug_build) {
+            (true, true, _) => "--debug flag and JIQ_DEBUG env var",
+            (true, false, _) => "--debug flag",
+            (false, true, _) => "JIQ_DEBUG env var",
+            (false, false, true) => "debug build",
+            (false, false, false) => return None,
+        };
+        Some(Self {
+            path: PathBuf::from(DEFAULT_LOG_PATH),
+            method,
+        })
+    }
+}
+
+/// Route `log` output to the settings' file, appending. Logging stays off
+/// when the file cannot be opened.
+pub fn init(settings: &LogSettings) {
+    use std::io::Write;
+
+    let log_file = match std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&settings.path)
+    {
+        Ok(f) => f,
+        Err(_) => return,
+    };
+
+    let initialized = env_logger::Builder::new()
+        .filter_level(log::LevelFilter::Debug)
+        .target(env_logger::Target::Pipe(Box::new(log_file)))
+        .format(|buf, record| {
+            use std::time::SystemTime;
+            let datetime: chrono::DateTime<chrono::Local> = SystemTime::now().into();
+            writeln!(
+                buf,
+                "[{}] [{}] {}",
+                datetime.format("%Y-%m-%dT%H:%M:%S%.3f"),
+                record.level(),
+                record.args()
+            )
+        })
+        .try_init();
+    if initialized.is_err() {
+        return;
+    }
+    let _ = ACTIVE_PATH.set(settings.path.clone());
+
+    log::debug!(
+        "=== JIQ DEBUG SESSION STARTED (v{}) ===",
+        env!("CARGO_PKG_VERSION")
+    );
+    log::debug!("Activated via: {}", settings.method);
+    log::debug!("Logging to: {}", settings.path.display());
+    log::debug!(
+        "Platform: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+}
+
+/// The file this session logs to, once [`init`] has set it up
+pub fn active_path() -> Option<&'static Path> {
+    ACTIVE_PATH.get().map(PathBuf::as_path)
+}
+
+/// The last `max_lines` lines of the file at `path`
+///
+/// Only the end of a long log is read; the line it starts inside is
+/// dropped.
+pub fn read_tail(path: &Path, max_lines: usize) -> std::io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(TAIL_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    let text = String::from_utf8_lossy(&bytes);
+    let mut lines: Vec<&str> = text.lines().collect();
+    if start > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+    let skip = lines.len().saturating_sub(max_lines);
+    Ok(lines[skip..].iter().map(|line| line.to_string()).collect())
+}
+
+#[cfg(test)]
+#[path = "log_file_tests.rs"]
+mod log_file_tests;
@@ -0,0 +1,105 @@
+//! Tests for debug_log/log_file
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::*;
+
+#[test]
+fn logging_is_off_without_a_request() {
+    assert_eq!(LogSettings::resolve(false, None, false, None, false), None);
+}
+
+#[test]
+fn debug_flag_logs_to_the_default_path() {
+    let settings = LogSettings::resolve(true, None, false, None, false).unwrap();
+
+    assert_eq!(settings.path, PathBuf::from(DEFAULT_LOG_PATH));
+    assert_eq!(settings.method, "--debug flag");
+}
+
+#[test]
+fn debug_build_logs_to_the_default_path() {
+    let settings = LogSettings::resolve(false, None, false, None, true).unwrap();
+
+    assert_eq!(settings.path, PathBuf::from(DEFAULT_LOG_PATH));
+    assert_eq!(settings.method, "debug build");
+}
+
+#[test]
+fn log_file_flag_turns_logging_on() {
+    let settings =
+        LogSettings::resolve(false, Some(Path::new("/tmp/mine.log")), false, None, false).unwrap();
+
+    assert_eq!(settings.path, PathBuf::from("/tmp/mine.log"));
+    assert_eq!(settings.method, "--log-file");
+}
+
+#[test]
+fn log_file_flag_wins_over_env() {
+    let settings = LogSettings::resolve(
+        true,
+        Some(Path::new("/tmp/flag.log")),
+        true,
+        Some(PathBuf::from("/tmp/env.log")),
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(settings.path, PathBuf::from("/tmp/flag.log"));
+}
+
+#[test]
+fn env_log_file_wins_over_default_path() {
+    let settings = LogSettings::resolve(
+        true,
+        None,
+        false,
+        Some(PathBuf::from("/tmp/env.log")),
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(settings.path, PathBuf::from("/tmp/env.log"));
+    assert_eq!(settings.method, "JIQ_LOG_FILE env var");
+}
+
+#[test]
+fn empty_env_log_file_is_ignored() {
+    assert_eq!(
+        LogSettings::resolve(false, None, false, Some(PathBuf::new()), false),
+        None
+    );
+}
+
+#[test]
+fn read_tail_keeps_the_last_lines() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "one\ntwo\nthree\nfour").unwrap();
+
+    let lines = read_tail(file.path(), 2).unwrap();
+
+    assert_eq!(lines, vec!["three", "four"]);
+}
+
+#[test]
+fn read_tail_drops_the_cut_first_line_of_a_long_log() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    let line = "x".repeat(99);
+    for _ in 0..1000 {
+        writeln!(file, "{}", line).unwrap();
+    }
+    writeln!(file, "last").unwrap();
+
+    let lines = read_tail(file.path(), usize::MAX).unwrap();
+
+    assert_eq!(lines.last().map(String::as_str), Some("last"));
+    assert!(lines[..lines.len() - 1].iter().all(|l| *l == line));
+}
+
+#[test]
+fn read_tail_errors_on_a_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert!(read_tail(&dir.path().join("missing.log"), 10).is_err());
+}
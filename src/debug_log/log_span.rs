@@ -0,0 +1,47 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A stretch of work in the debug log
+///
+/// Logs `[SPAN n] name fields` when entered and `[SPAN n] name done in
+/// Nms` when dropped. The number pairs the two lines when spans on several
+/// threads interleave. Without a debug logger nothing is formatted.
+pub struct LogSpan {
+    name: &'static str,
+    /// 0 when the logger was off on entry
+    id: u64,
+    start: Instant,
+}
+
+impl LogSpan {
+    pub fn enter(name: &'static str, fields: fmt::Arguments) -> Self {
+        let id = if log::log_enabled!(log::Level::Debug) {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            log::debug!("[SPAN {}] {} {}", id, name, fields);
+            id
+        } else {
+            0
+        };
+        Self {
+            name,
+            id,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for LogSpan {
+    fn drop(&mut self) {
+        if self.id != 0 {
+            log::debug!(
+                "[SPAN {}] {} done in {}ms",
+                self.id,
+                self.name,
+                self.start.elapsed().as_millis()
+            );
+        }
+    }
+}
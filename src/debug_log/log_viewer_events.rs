@@ -0,0 +1,38 @@
+use std::time::Instant;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::log_file::active_path;
+use crate::app::App;
+
+/// Lines a page key scrolls
+const PAGE: usize = 10;
+
+/// F12 — open the debug log popup, or close it
+pub fn toggle(app: &mut App) {
+    if app.log_viewer.is_visible() {
+        app.log_viewer.close();
+    } else {
+        app.log_viewer.open(active_path(), Instant::now());
+    }
+}
+
+/// Keys while the debug log popup is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let viewer = &mut app.log_viewer;
+    match key.code {
+        KeyCode::Char('t') => app.frame_timings.toggle(),
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::F(12) => viewer.close(),
+        KeyCode::Up | KeyCode::Char('k') => viewer.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => viewer.scroll_down(1),
+        KeyCode::PageUp => viewer.scroll_up(PAGE),
+        KeyCode::PageDown => viewer.scroll_down(PAGE),
+        KeyCode::Home | KeyCode::Char('g') => viewer.scroll_to_oldest(),
+        KeyCode::End | KeyCode::Char('G') => viewer.scroll_to_newest(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "log_viewer_events_tests.rs"]
+mod log_viewer_events_tests;
@@ -0,0 +1,67 @@
+//! Tests for debug_log/log_viewer_events
+
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::test_utils::test_helpers::{key, test_app};
+
+#[test]
+fn f12_opens_and_closes_the_viewer() {
+    let mut app = test_app(r#"{"a": 1}"#);
+
+    app.handle_key_event(key(KeyCode::F(12)));
+    assert!(app.log_viewer.is_visible());
+
+    app.handle_key_event(key(KeyCode::F(12)));
+    assert!(!app.log_viewer.is_visible());
+}
+
+#[test]
+fn esc_closes_the_viewer() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.handle_key_event(key(KeyCode::F(12)));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.log_viewer.is_visible());
+}
+
+#[test]
+fn viewer_takes_typed_keys() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let query = app.input.query().to_string();
+    app.handle_key_event(key(KeyCode::F(12)));
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.log_viewer.is_visible());
+    assert_eq!(app.input.query(), query);
+}
+
+#[test]
+fn ctrl_c_still_quits_while_open() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.handle_key_event(key(KeyCode::F(12)));
+
+    app.handle_key_event(ratatui::crossterm::event::KeyEvent::new(
+        KeyCode::Char('c'),
+        KeyModifiers::CONTROL,
+    ));
+
+    assert!(app.should_quit);
+}
+
+#[test]
+fn t_toggles_the_frame_timings_overlay() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.handle_key_event(key(KeyCode::F(12)));
+
+    app.handle_key_event(key(KeyCode::Char('t')));
+    assert!(app.frame_timings.is_visible());
+    // The overlay stays once the popup closes
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(app.frame_timings.is_visible());
+
+    app.handle_key_event(key(KeyCode::F(12)));
+    app.handle_key_event(key(KeyCode::Char('t')));
+    assert!(!app.frame_timings.is_visible());
+}
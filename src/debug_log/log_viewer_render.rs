@@ -0,0 +1,101 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use super::log_viewer_state::LogViewer;
+use crate::theme;
+
+/// Render the debug log popup over most of `area`
+pub fn render_popup(viewer: &LogViewer, frame: &mut Frame, area: Rect) {
+    let popup_area = Rect {
+        x: area.x + area.width / 20,
+        y: area.y + area.height / 10,
+        width: area.width - area.width / 10,
+        height: area.height - area.height / 5,
+    };
+    if popup_area.width < 20 || popup_area.height < 3 {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme::help::border()))
+        .style(Style::default().bg(theme::help::background()))
+        .padding(Padding::horizontal(1))
+        .title(Span::styled(
+            " Debug log ",
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(
+            theme::border_hints::build_hints(
+                &[
+                    ("↑↓", "Scroll"),
+                    ("G", "Latest"),
+                    ("t", "Frame timings"),
+                    ("Esc", "Close"),
+                ],
+                theme::results::hint_key(),
+            )
+            .alignment(Alignment::Center),
+        );
+    if let Some(path) = viewer.path() {
+        block = block.title(
+            Line::from(Span::styled(
+                format!(" {} ", path.display()),
+                Style::default().fg(theme::help::footer()),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    frame.render_widget(Paragraph::new(content(viewer, inner.height)), inner);
+}
+
+/// The lines that fit in `height`, ending `scroll` lines before the newest
+fn content(viewer: &LogViewer, height: u16) -> Vec<Line<'static>> {
+    let muted = Style::default().fg(theme::help::footer());
+    if viewer.path().is_none() {
+        return vec![
+            Line::from("Logging is off."),
+            Line::from(Span::styled(
+                "Start jiq with --debug, or --log-file PATH, to log here.",
+                muted,
+            )),
+        ];
+    }
+    if let Some(error) = viewer.error() {
+        return vec![Line::from(Span::styled(
+            format!("Cannot read the log: {}", error),
+            Style::default().fg(theme::palette::error()),
+        ))];
+    }
+
+    let lines = viewer.lines();
+    let end = lines.len().saturating_sub(viewer.scroll());
+    let start = end.saturating_sub(height as usize);
+    lines[start..end]
+        .iter()
+        .map(|line| {
+            let style = if line.contains("] [ERROR] ") {
+                Style::default().fg(theme::palette::error())
+            } else if line.contains("] [WARN] ") {
+                Style::default().fg(theme::palette::warning())
+            } else {
+                Style::default().fg(theme::help::description())
+            };
+            Line::from(Span::styled(line.clone(), style))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "log_viewer_render_tests.rs"]
+mod log_viewer_render_tests;
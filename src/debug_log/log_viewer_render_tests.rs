@@ -0,0 +1,72 @@
+//! Tests for debug_log/log_viewer_render
+
+use std::io::Write;
+use std::time::Instant;
+
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+use super::*;
+
+fn render_to_string(viewer: &LogViewer, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| render_popup(viewer, f, f.area()))
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn shows_how_to_turn_logging_on() {
+    let mut viewer = LogViewer::new();
+    viewer.open(None, Instant::now());
+
+    let output = render_to_string(&viewer, 80, 12);
+
+    assert!(output.contains("Debug log"), "{output}");
+    assert!(output.contains("Logging is off."), "{output}");
+    assert!(output.contains("--log-file PATH"), "{output}");
+}
+
+#[test]
+fn shows_the_newest_lines_that_fit() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    for i in 0..20 {
+        writeln!(file, "entry {:02}", i).unwrap();
+    }
+    let mut viewer = LogViewer::new();
+    viewer.open(Some(file.path()), Instant::now());
+
+    let output = render_to_string(&viewer, 80, 12);
+
+    assert!(output.contains("entry 19"), "{output}");
+    assert!(!output.contains("entry 00"), "{output}");
+}
+
+#[test]
+fn scrolling_shows_older_lines() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    for i in 0..20 {
+        writeln!(file, "entry {:02}", i).unwrap();
+    }
+    let mut viewer = LogViewer::new();
+    viewer.open(Some(file.path()), Instant::now());
+    viewer.scroll_to_oldest();
+
+    let output = render_to_string(&viewer, 80, 12);
+
+    assert!(output.contains("entry 00"), "{output}");
+    assert!(!output.contains("entry 19"), "{output}");
+}
+
+#[test]
+fn shows_a_read_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut viewer = LogViewer::new();
+    viewer.open(Some(&dir.path().join("missing.log")), Instant::now());
+
+    let output = render_to_string(&viewer, 80, 12);
+
+    assert!(output.contains("Cannot read the log"), "{output}");
+}
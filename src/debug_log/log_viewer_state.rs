@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::log_file::read_tail;
+
+/// Lines of the log kept for the popup
+pub const MAX_LINES: usize = 500;
+
+/// How often the open popup re-reads the log
+pub const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// F12 popup showing the tail of the debug log
+#[derive(Debug, Default)]
+pub struct LogViewer {
+    visible: bool,
+    /// File shown, None when logging is off
+    path: Option<PathBuf>,
+    lines: Vec<String>,
+    /// Why the file could not be read
+    error: Option<String>,
+    /// Lines scrolled up from the newest
+    scroll: usize,
+    last_read: Option<Instant>,
+}
+
+impl LogViewer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open on the newest lines of the log at `path`
+    pub fn open(&mut self, path: Option<&Path>, now: Instant) {
+        self.visible = true;
+        self.path = path.map(Path::to_path_buf);
+        self.scroll = 0;
+        self.reload(now);
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.lines.clear();
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.lines.len().saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn scroll_to_oldest(&mut self) {
+        self.scroll = self.lines.len().saturating_sub(1);
+    }
+
+    pub fn scroll_to_newest(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Re-read the log while open, at most every [`REFRESH_INTERVAL`].
+    /// Returns true when the lines changed.
+    pub fn refresh_if_due(&mut self, now: Instant) -> bool {
+        let due = self
+            .last_read
+            .is_none_or(|last| now.duration_since(last) >= REFRESH_INTERVAL);
+        if !self.visible || !due {
+            return false;
+        }
+        let before = self.lines.len();
+        let last_before = self.lines.last().cloned();
+        self.reload(now);
+        let added = self.lines.len().saturating_sub(before);
+        // Keep the same lines in view while scrolled up
+        if self.scroll > 0 {
+            self.scroll = (self.scroll + added).min(self.lines.len().saturating_sub(1));
+        }
+        self.lines.len() != before || self.lines.last() != last_before.as_ref()
+    }
+
+    fn reload(&mut self, now: Instant) {
+        self.last_read = Some(now);
+        let Some(path) = &self.path else {
+            return;
+        };
+        match read_tail(path, MAX_LINES) {
+            Ok(lines) => {
+                self.lines = lines;
+                self.error = None;
+            }
+            Err(e) => {
+                self.lines.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "log_viewer_state_tests.rs"]
+mod log_viewer_state_tests;
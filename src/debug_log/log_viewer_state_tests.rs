@@ -0,0 +1,125 @@
+//! Tests for debug_log/log_viewer_state
+
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use super::*;
+
+fn log_with(lines: usize) -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    for i in 0..lines {
+        writeln!(file, "line {}", i).unwrap();
+    }
+    file
+}
+
+#[test]
+fn open_reads_the_tail() {
+    let file = log_with(3);
+    let mut viewer = LogViewer::new();
+
+    viewer.open(Some(file.path()), Instant::now());
+
+    assert!(viewer.is_visible());
+    assert_eq!(viewer.lines(), ["line 0", "line 1", "line 2"]);
+    assert_eq!(viewer.scroll(), 0);
+}
+
+#[test]
+fn open_keeps_at_most_max_lines() {
+    let file = log_with(MAX_LINES + 5);
+    let mut viewer = LogViewer::new();
+
+    viewer.open(Some(file.path()), Instant::now());
+
+    assert_eq!(viewer.lines().len(), MAX_LINES);
+    assert_eq!(viewer.lines()[0], "line 5");
+}
+
+#[test]
+fn open_without_a_path_shows_nothing() {
+    let mut viewer = LogViewer::new();
+
+    viewer.open(None, Instant::now());
+
+    assert!(viewer.is_visible());
+    assert_eq!(viewer.path(), None);
+    assert!(viewer.lines().is_empty());
+}
+
+#[test]
+fn unreadable_log_records_the_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut viewer = LogViewer::new();
+
+    viewer.open(Some(&dir.path().join("missing.log")), Instant::now());
+
+    assert!(viewer.error().is_some());
+}
+
+#[test]
+fn scroll_stays_within_the_lines() {
+    let file = log_with(5);
+    let mut viewer = LogViewer::new();
+    viewer.open(Some(file.path()), Instant::now());
+
+    viewer.scroll_up(100);
+    assert_eq!(viewer.scroll(), 4);
+    viewer.scroll_down(1);
+    assert_eq!(viewer.scroll(), 3);
+    viewer.scroll_to_newest();
+    assert_eq!(viewer.scroll(), 0);
+    viewer.scroll_down(1);
+    assert_eq!(viewer.scroll(), 0);
+    viewer.scroll_to_oldest();
+    assert_eq!(viewer.scroll(), 4);
+}
+
+#[test]
+fn refresh_waits_for_the_interval() {
+    let mut file = log_with(1);
+    let mut viewer = LogViewer::new();
+    let opened = Instant::now();
+    viewer.open(Some(file.path()), opened);
+    writeln!(file, "new").unwrap();
+
+    assert!(!viewer.refresh_if_due(opened + Duration::from_millis(10)));
+    assert!(viewer.refresh_if_due(opened + REFRESH_INTERVAL));
+    assert_eq!(viewer.lines().last().map(String::as_str), Some("new"));
+}
+
+#[test]
+fn refresh_reports_no_change_for_the_same_lines() {
+    let file = log_with(2);
+    let mut viewer = LogViewer::new();
+    let opened = Instant::now();
+    viewer.open(Some(file.path()), opened);
+
+    assert!(!viewer.refresh_if_due(opened + REFRESH_INTERVAL));
+}
+
+#[test]
+fn refresh_keeps_scrolled_lines_in_view() {
+    let mut file = log_with(5);
+    let mut viewer = LogViewer::new();
+    let opened = Instant::now();
+    viewer.open(Some(file.path()), opened);
+    viewer.scroll_up(2);
+    writeln!(file, "new").unwrap();
+
+    viewer.refresh_if_due(opened + REFRESH_INTERVAL);
+
+    assert_eq!(viewer.scroll(), 3);
+}
+
+#[test]
+fn closed_viewer_does_not_refresh() {
+    let file = log_with(2);
+    let mut viewer = LogViewer::new();
+    let opened = Instant::now();
+    viewer.open(Some(file.path()), opened);
+    viewer.close();
+
+    assert!(!viewer.refresh_if_due(opened + REFRESH_INTERVAL));
+    assert!(!viewer.is_visible());
+}
@@ -1,7 +1,11 @@
 pub mod char_search;
 pub mod editor_events;
+pub mod macros;
 pub mod mode;
 pub mod text_objects;
+pub mod which_key;
+pub mod which_key_render;
+pub mod wrap;
 
 pub use char_search::CharSearchState;
 pub use mode::EditorMode;
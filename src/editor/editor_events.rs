@@ -7,13 +7,18 @@ use crate::editor::EditorMode;
 use crate::editor::char_search::{
     CharSearchState, SearchDirection, SearchType, execute_char_search,
 };
+use crate::editor::macros::is_register;
 use crate::editor::mode::TextObjectScope;
 use crate::editor::text_objects::{TextObjectTarget, execute_text_object};
-use crate::help::HelpTab;
+use crate::editor::wrap;
 
 pub fn handle_insert_mode_key(app: &mut App, key: KeyEvent) {
     app.input.reset_manual_scroll();
+    let previous_col = app.input.textarea.cursor().1;
     let content_changed = app.input.textarea.input(key);
+    if !content_changed {
+        app.input.snap_cursor_to_grapheme(previous_col);
+    }
 
     if content_changed {
         app.history.reset_cycling();
@@ -34,19 +39,18 @@ pub fn handle_normal_mode_key(app: &mut App, key: KeyEvent) {
     app.input.reset_manual_scroll();
     match key.code {
         KeyCode::Char('?') => {
-            if app.help.visible {
-                app.help.reset();
-            } else {
-                app.help.active_tab = HelpTab::Input;
-                app.help.visible = true;
-            }
+            app.toggle_help();
         }
 
         KeyCode::Char('h') | KeyCode::Left => {
+            let previous_col = app.input.textarea.cursor().1;
             app.input.textarea.move_cursor(CursorMove::Back);
+            app.input.snap_cursor_to_grapheme(previous_col);
         }
         KeyCode::Char('l') | KeyCode::Right => {
+            let previous_col = app.input.textarea.cursor().1;
             app.input.textarea.move_cursor(CursorMove::Forward);
+            app.input.snap_cursor_to_grapheme(previous_col);
         }
 
         KeyCode::Char('0') | KeyCode::Char('^') | KeyCode::Home => {
@@ -141,6 +145,27 @@ pub fn handle_normal_mode_key(app: &mut App, key: KeyEvent) {
         KeyCode::Char('/') => {
             crate::search::search_events::open_search(app);
         }
+        KeyCode::Char('q') => {
+            if let Some((register, len)) = app.macros.stop_recording() {
+                app.notification
+                    .show(&format!("Recorded @{} ({} keys)", register, len));
+            } else {
+                app.input.editor_mode = EditorMode::Register('q');
+            }
+        }
+        KeyCode::Char('@') => {
+            app.input.editor_mode = EditorMode::Register('@');
+        }
+        KeyCode::Char('"') => {
+            app.input.editor_mode = EditorMode::Register('"');
+        }
+        KeyCode::Char('W') => {
+            app.input.editor_mode = EditorMode::Wrap;
+        }
+
+        KeyCode::Char(':') => {
+            crate::command_line::command_line_events::open(app);
+        }
 
         KeyCode::Char('u') => {
             app.input.textarea.undo();
@@ -373,6 +398,123 @@ pub fn handle_text_object_mode_key(app: &mut App, key: KeyEvent) {
     app.update_tooltip();
 }
 
+/// The register after `q` (record) or `@` (play), or the scratch
+/// register's `y` / `p` / `P` after `"`
+pub fn handle_register_mode_key(app: &mut App, key: KeyEvent) {
+    let EditorMode::Register(prefix) = app.input.editor_mode else {
+        return;
+    };
+    app.input.editor_mode = EditorMode::Normal;
+    let KeyCode::Char(register) = key.code else {
+        return;
+    };
+
+    match prefix {
+        'q' if is_register(register) => app.macros.start_recording(register),
+        '@' if is_register(register) || register == '@' => play_macro(app, register),
+        '"' if register == 'y' => park_query(app),
+        '"' if register == 'p' || register == 'P' => put_scratch(app, register == 'p'),
+        _ => {}
+    }
+}
+
+/// `"y`: keep the query in the scratch register
+fn park_query(app: &mut App) {
+    if app.query().is_empty() {
+        app.notification.show("Nothing to park");
+        return;
+    }
+    let query = app.query().to_string();
+    app.scratch.set(&query);
+    app.notification.show("Parked query in scratch");
+}
+
+/// `"p` / `"P`: put the scratch register into the query after or before
+/// the cursor
+fn put_scratch(app: &mut App, after: bool) {
+    let Some(text) = app.scratch.text_for_query() else {
+        app.notification.show("Scratch is empty");
+        return;
+    };
+    if after && !app.query().is_empty() {
+        app.input.textarea.move_cursor(CursorMove::Forward);
+    }
+    app.input.textarea.insert_str(&text);
+    execute_query(app);
+}
+
+/// The scaffold key after `W`
+pub fn handle_wrap_mode_key(app: &mut App, key: KeyEvent) {
+    app.input.editor_mode = EditorMode::Normal;
+    if let KeyCode::Char(c) = key.code
+        && let Some(scaffold) = wrap::by_key(c)
+    {
+        wrap_query(app, scaffold, false);
+    }
+}
+
+/// Wrap the last stage, or the whole query, in `scaffold` and run it
+pub fn wrap_query(app: &mut App, scaffold: &wrap::Scaffold, whole: bool) {
+    let (wrapped, cursor) = wrap::wrap(app.query(), scaffold, whole);
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    app.input.textarea.insert_str(&wrapped);
+    app.input.set_cursor_column(cursor);
+    execute_query(app);
+}
+
+/// Apply the fix the error overlay offers: the key meant for a mistyped
+/// field, or else optional access. Returns false when there is none for
+/// the query as it is now.
+pub fn apply_query_fix(app: &mut App) -> bool {
+    let Some(query) = app.query.as_ref() else {
+        return false;
+    };
+    let current = app.input.query();
+    let (fixed, cursor, message) = if let Some(hint) = &query.field_hint
+        && let Some((fixed, cursor)) = hint.apply(current)
+    {
+        let message = format!("Replaced .{} with .{}", hint.field, hint.suggestion);
+        (fixed, cursor, message)
+    } else if let Some(fix) = &query.optional_fix
+        && fix.original == current
+    {
+        let message = "Made the failing accesses optional".to_string();
+        (fix.fixed.clone(), fix.cursor, message)
+    } else {
+        return false;
+    };
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    app.input.textarea.insert_str(&fixed);
+    app.input.set_cursor_column(cursor);
+    execute_query(app);
+    app.notification.show(&message);
+    true
+}
+
+fn play_macro(app: &mut App, register: char) {
+    let Some(keys) = app.macros.keys_to_play(register) else {
+        if !app.macros.is_replaying() {
+            app.notification
+                .show(&format!("Nothing recorded in @{}", register));
+        }
+        return;
+    };
+
+    app.macros.begin_replay();
+    for key in keys {
+        app.handle_key_event(key);
+    }
+    app.macros.end_replay();
+
+    // Typed text waits on the debouncer; show its result now
+    if !app.macros.is_replaying() && app.debouncer.has_pending() {
+        execute_query(app);
+        app.debouncer.mark_executed();
+    }
+}
+
 fn repeat_last_char_search(app: &mut App, reverse: bool) {
     if let Some(search) = app.input.last_char_search {
         let direction = if reverse {
@@ -395,6 +537,11 @@ pub fn execute_query(app: &mut App) {
 }
 
 pub fn execute_query_with_auto_show(app: &mut App) {
+    // Run once the burst of keys being handled is over
+    if app.batching_events {
+        app.batched_query = true;
+        return;
+    }
     let query_state = match &mut app.query {
         Some(q) => q,
         None => return,
@@ -405,6 +552,7 @@ pub fn execute_query_with_auto_show(app: &mut App) {
     app.input.brace_tracker.rebuild(query);
 
     query_state.execute_async(query);
+    lint_query(app);
 
     app.results_scroll.reset();
     app.results_cursor.reset();
@@ -413,6 +561,24 @@ pub fn execute_query_with_auto_show(app: &mut App) {
     // AI update happens in poll_query_response() when result arrives
 }
 
+/// Lint the query just run, warning about the lints it didn't have
+/// before. The input is only looked at once it's parsed, so linting never
+/// waits on it.
+pub fn lint_query(app: &mut App) {
+    let input = app
+        .query
+        .as_ref()
+        .filter(|q| !q.executor.input_analysis_pending())
+        // The lints look at the input as one value
+        .filter(|q| !q.executor.input_is_stream())
+        .and_then(|q| q.executor.json_input_parsed());
+    let query = app.input.query().to_string();
+    let lints = crate::query::lint::lint(&query, input.as_deref());
+    if let Some(lint) = app.input.set_lints(&query, lints) {
+        app.notification.show_warning(&lint.message);
+    }
+}
+
 fn operator_char_search_from_key(key: KeyCode) -> Option<(SearchDirection, SearchType)> {
     match key {
         KeyCode::Char('f') => Some((SearchDirection::Forward, SearchType::Find)),
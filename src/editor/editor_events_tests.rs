@@ -1294,3 +1294,23 @@ fn test_df_forward_on_last_char_cancels() {
     assert_eq!(app.query(), original_query);
     assert_eq!(app.input.editor_mode, EditorMode::Normal);
 }
+
+fn press(app: &mut App, keys: &str) {
+    for c in keys.chars() {
+        let code = if c == '\u{1b}' {
+            KeyCode::Esc
+        } else {
+            KeyCode::Char(c)
+        };
+        app.handle_key_event(key(code));
+    }
+}
+
+#[path = "editor_events_tests/lint_tests.rs"]
+mod lint_tests;
+#[path = "editor_events_tests/macro_tests.rs"]
+mod macro_tests;
+#[path = "editor_events_tests/scratch_tests.rs"]
+mod scratch_tests;
+#[path = "editor_events_tests/wrap_key_tests.rs"]
+mod wrap_key_tests;
@@ -0,0 +1,30 @@
+//! Tests for the lint warnings run with the query
+
+use super::*;
+
+#[test]
+fn test_execute_query_lints_the_query_and_warns_once() {
+    let mut app = app_with_query(".first-name");
+
+    execute_query_with_auto_show(&mut app);
+
+    assert_eq!(app.input.lints().len(), 1);
+    let warning = app.notification.current_message().unwrap().to_string();
+    assert!(warning.contains(r#"."first-name""#));
+
+    app.notification.dismiss();
+    execute_query_with_auto_show(&mut app);
+    assert_eq!(app.input.lints().len(), 1);
+    assert!(app.notification.current_message().is_none());
+}
+
+#[test]
+fn test_lints_are_hidden_once_the_query_is_edited() {
+    let mut app = app_with_query("select()");
+    execute_query_with_auto_show(&mut app);
+    assert_eq!(app.input.lints().len(), 1);
+
+    app.input.textarea.insert_str(" ");
+
+    assert!(app.input.lints().is_empty());
+}
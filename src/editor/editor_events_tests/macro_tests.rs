@@ -0,0 +1,70 @@
+//! Tests for recording macros with `q<reg>` and playing them with `@<reg>`
+
+use super::*;
+
+#[test]
+fn test_macro_records_and_replays_edits() {
+    let mut app = app_with_query(".a");
+    app.input.editor_mode = EditorMode::Normal;
+
+    // Wrap the query in [ ] while recording into register w
+    press(&mut app, "qwI[\u{1b}A]\u{1b}q");
+    assert_eq!(app.query(), "[.a]");
+    assert_eq!(app.macros.recording(), None);
+    assert_eq!(app.input.editor_mode, EditorMode::Normal);
+
+    press(&mut app, "@w");
+    assert_eq!(app.query(), "[[.a]]");
+
+    press(&mut app, "@@");
+    assert_eq!(app.query(), "[[[.a]]]");
+}
+
+#[test]
+fn test_q_starts_recording_instead_of_quitting() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "qa");
+
+    assert!(!app.should_quit);
+    assert_eq!(app.macros.recording(), Some('a'));
+}
+
+#[test]
+fn test_invalid_register_cancels_the_prefix() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "q!");
+
+    assert_eq!(app.macros.recording(), None);
+    assert_eq!(app.input.editor_mode, EditorMode::Normal);
+}
+
+#[test]
+fn test_playing_an_empty_register_says_so() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "@z");
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing recorded in @z")
+    );
+}
+
+#[test]
+fn test_macro_calling_itself_stops() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "qaA.\u{1b}@aq");
+    press(&mut app, "@a");
+
+    assert_eq!(
+        app.query().len(),
+        1 + crate::editor::macros::MAX_REPLAY_DEPTH
+    );
+}
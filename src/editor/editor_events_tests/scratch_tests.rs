@@ -0,0 +1,46 @@
+//! Tests for parking the query in the scratch register with `"y`, `"p` and `"P`
+
+use super::*;
+
+#[test]
+fn test_scratch_parks_the_query_and_puts_it_back() {
+    let mut app = app_with_query(".users[]");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "\"y");
+    assert_eq!(app.scratch.text(), Some(".users[]"));
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Parked query in scratch")
+    );
+
+    press(&mut app, "dd\"p");
+    assert_eq!(app.query(), ".users[]");
+}
+
+#[test]
+fn test_scratch_puts_after_or_before_the_cursor() {
+    let mut app = app_with_query(".a");
+    app.input.editor_mode = EditorMode::Normal;
+    app.scratch.set(" | .b");
+    app.input.textarea.move_cursor(CursorMove::End);
+    app.input.textarea.move_cursor(CursorMove::Back);
+
+    press(&mut app, "\"p");
+    assert_eq!(app.query(), ".a | .b");
+
+    app.scratch.set("[");
+    press(&mut app, "0\"P");
+    assert_eq!(app.query(), "[.a | .b");
+}
+
+#[test]
+fn test_putting_an_empty_scratch_says_so() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "\"p");
+
+    assert_eq!(app.query(), ".");
+    assert_eq!(app.notification.current_message(), Some("Scratch is empty"));
+}
@@ -0,0 +1,37 @@
+//! Tests for wrapping the last stage with `W` and `:wrap`
+
+use super::*;
+
+#[test]
+fn test_w_prefix_wraps_the_last_stage() {
+    let mut app = app_with_query(".items | .name");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "Wm");
+
+    assert_eq!(app.query(), ".items | map(.name)");
+    assert_eq!(app.input.textarea.cursor().1, ".items | map(.name)".len());
+    assert_eq!(app.input.editor_mode, EditorMode::Normal);
+}
+
+#[test]
+fn test_w_prefix_with_unknown_key_does_nothing() {
+    let mut app = app_with_query(".a");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, "Wz");
+
+    assert_eq!(app.query(), ".a");
+    assert_eq!(app.input.editor_mode, EditorMode::Normal);
+}
+
+#[test]
+fn test_wrap_command_wraps_the_whole_query() {
+    let mut app = app_with_query(".a | .b");
+    app.input.editor_mode = EditorMode::Normal;
+
+    press(&mut app, ":wrap try all");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.query(), "try .a | .b catch empty");
+}
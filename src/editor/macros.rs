@@ -0,0 +1,95 @@
+//! Keystroke macros for the query editor
+//!
+//! `q<reg>` in Normal mode records every key that follows into register
+//! `<reg>` until the next `q`; `@<reg>` plays the keys back and `@@`
+//! repeats the last register played. Registers are `a`-`z` and `0`-`9` and
+//! last for the session, so a transform such as wrapping each stage in
+//! `try … catch empty` can be recorded once and replayed on every stage.
+
+use std::collections::HashMap;
+
+use ratatui::crossterm::event::KeyEvent;
+
+/// How deep macros may play each other; a macro calling itself stops here
+pub const MAX_REPLAY_DEPTH: usize = 10;
+
+pub fn is_register(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit()
+}
+
+#[derive(Debug, Default)]
+pub struct Macros {
+    registers: HashMap<char, Vec<KeyEvent>>,
+    recording: Option<(char, Vec<KeyEvent>)>,
+    last_played: Option<char>,
+    replay_depth: usize,
+}
+
+impl Macros {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start_recording(&mut self, register: char) {
+        self.recording = Some((register, Vec::new()));
+    }
+
+    /// The register being recorded into
+    pub fn recording(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    /// Note a key while recording. Keys played back by a macro are left
+    /// out; the `@` that played them is already recorded.
+    pub fn record(&mut self, key: KeyEvent) {
+        if self.replay_depth > 0 {
+            return;
+        }
+        if let Some((_, keys)) = &mut self.recording {
+            keys.push(key);
+        }
+    }
+
+    /// Store the recording, without the `q` that ended it. Returns the
+    /// register and how many keys it holds.
+    pub fn stop_recording(&mut self) -> Option<(char, usize)> {
+        let (register, mut keys) = self.recording.take()?;
+        keys.pop();
+        let len = keys.len();
+        self.registers.insert(register, keys);
+        Some((register, len))
+    }
+
+    /// The keys to play for `@<register>`, `@` meaning the last register
+    /// played. None when the register is empty or macros are nested too
+    /// deep.
+    pub fn keys_to_play(&mut self, register: char) -> Option<Vec<KeyEvent>> {
+        if self.replay_depth >= MAX_REPLAY_DEPTH {
+            return None;
+        }
+        let register = if register == '@' {
+            self.last_played?
+        } else {
+            register
+        };
+        let keys = self.registers.get(&register)?.clone();
+        self.last_played = Some(register);
+        Some(keys)
+    }
+
+    pub fn begin_replay(&mut self) {
+        self.replay_depth += 1;
+    }
+
+    pub fn end_replay(&mut self) {
+        self.replay_depth = self.replay_depth.saturating_sub(1);
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay_depth > 0
+    }
+}
+
+#[cfg(test)]
+#[path = "macros_tests.rs"]
+mod macros_tests;
@@ -0,0 +1,83 @@
+//! Tests for editor/macros
+
+use super::*;
+use crate::test_utils::test_helpers::key;
+use ratatui::crossterm::event::KeyCode;
+
+fn record(macros: &mut Macros, register: char, text: &str) {
+    macros.start_recording(register);
+    for c in text.chars() {
+        macros.record(key(KeyCode::Char(c)));
+    }
+    // The q that ends the recording is recorded before it is handled
+    macros.record(key(KeyCode::Char('q')));
+    macros.stop_recording();
+}
+
+#[test]
+fn test_registers() {
+    assert!(is_register('a'));
+    assert!(is_register('7'));
+    assert!(!is_register('A'));
+    assert!(!is_register('@'));
+}
+
+#[test]
+fn test_stop_recording_drops_the_final_q() {
+    let mut macros = Macros::new();
+    macros.start_recording('a');
+    assert_eq!(macros.recording(), Some('a'));
+    macros.record(key(KeyCode::Char('x')));
+    macros.record(key(KeyCode::Char('q')));
+
+    assert_eq!(macros.stop_recording(), Some(('a', 1)));
+    assert_eq!(macros.recording(), None);
+    assert_eq!(
+        macros.keys_to_play('a'),
+        Some(vec![key(KeyCode::Char('x'))])
+    );
+}
+
+#[test]
+fn test_keys_are_ignored_when_not_recording() {
+    let mut macros = Macros::new();
+    macros.record(key(KeyCode::Char('x')));
+    assert_eq!(macros.stop_recording(), None);
+    assert_eq!(macros.keys_to_play('a'), None);
+}
+
+#[test]
+fn test_at_plays_the_last_register() {
+    let mut macros = Macros::new();
+    record(&mut macros, 'a', "x");
+    record(&mut macros, 'b', "yy");
+
+    assert_eq!(macros.keys_to_play('@'), None);
+    macros.keys_to_play('b');
+    assert_eq!(macros.keys_to_play('@').map(|keys| keys.len()), Some(2));
+}
+
+#[test]
+fn test_replayed_keys_are_not_recorded() {
+    let mut macros = Macros::new();
+    macros.start_recording('a');
+    macros.begin_replay();
+    macros.record(key(KeyCode::Char('x')));
+    macros.end_replay();
+    macros.record(key(KeyCode::Char('q')));
+
+    assert_eq!(macros.stop_recording(), Some(('a', 0)));
+}
+
+#[test]
+fn test_replay_depth_is_capped() {
+    let mut macros = Macros::new();
+    record(&mut macros, 'a', "x");
+    for _ in 0..MAX_REPLAY_DEPTH {
+        macros.begin_replay();
+    }
+    assert_eq!(macros.keys_to_play('a'), None);
+
+    macros.end_replay();
+    assert!(macros.keys_to_play('a').is_some());
+}
@@ -25,6 +25,10 @@ pub enum EditorMode {
     OperatorCharSearch(char, usize, SearchDirection, SearchType),
     /// TextObject mode - waiting for text object target after operator + i/a
     TextObject(char, TextObjectScope),
+    /// Register mode - waiting for the macro register after q or @
+    Register(char),
+    /// Wrap mode - waiting for the scaffold key after W
+    Wrap,
 }
 
 impl EditorMode {
@@ -60,6 +64,8 @@ impl EditorMode {
                 };
                 format!("{}{}…", op, scope_char)
             }
+            EditorMode::Register(prefix) => format!("{}…", prefix),
+            EditorMode::Wrap => "W…".to_string(),
         }
     }
 }
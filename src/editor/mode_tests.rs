@@ -68,3 +68,14 @@ fn test_text_object_mode_display() {
         "ca…"
     );
 }
+
+#[test]
+fn test_register_mode_display() {
+    assert_eq!(EditorMode::Register('q').display(), "q…");
+    assert_eq!(EditorMode::Register('@').display(), "@…");
+}
+
+#[test]
+fn test_wrap_mode_display() {
+    assert_eq!(EditorMode::Wrap.display(), "W…");
+}
@@ -0,0 +1,129 @@
+//! Which-key hints for pending Normal-mode prefixes
+//!
+//! After `d`, `c`, `y`, `"` or `W`, or an operator followed by `i`/`a`, the editor
+//! waits for another key. When the user pauses there for
+//! [`WHICH_KEY_DELAY`], a popup lists the keys that complete the command;
+//! it fades after [`WHICH_KEY_DURATION`] or as soon as the mode changes.
+
+use std::time::{Duration, Instant};
+
+use crate::editor::EditorMode;
+use crate::editor::mode::TextObjectScope;
+use crate::editor::wrap;
+
+/// Pause in a prefix before the popup appears, so fluent typing never sees it
+pub const WHICH_KEY_DELAY: Duration = Duration::from_millis(400);
+
+/// How long the popup stays up once shown
+pub const WHICH_KEY_DURATION: Duration = Duration::from_secs(4);
+
+const MOTIONS: &[(&str, &str)] = &[
+    ("w", "to next word"),
+    ("b", "to previous word"),
+    ("e", "to end of word"),
+    ("0 ^", "to start of query"),
+    ("$", "to end of query"),
+    ("h l", "one char left / right"),
+    ("f t", "to / till a char forward…"),
+    ("F T", "to / till a char backward…"),
+    ("i", "inside a text object…"),
+    ("a", "around a text object…"),
+];
+
+const TEXT_OBJECTS: &[(&str, &str)] = &[
+    ("w", "word"),
+    ("\" ' `", "quoted string"),
+    ("( ) b", "parentheses"),
+    ("[ ]", "brackets"),
+    ("{ } B", "braces"),
+    ("|", "pipe stage"),
+];
+
+/// The popup's title and the keys that may follow `mode`, or None when
+/// `mode` is not a prefix with hints
+pub fn follow_ups(mode: EditorMode) -> Option<(String, Vec<(&'static str, &'static str)>)> {
+    match mode {
+        EditorMode::Operator('y') => Some(("y: copy".to_string(), vec![("y", "the whole query")])),
+        EditorMode::Operator(op @ ('d' | 'c')) => {
+            let repeat = if op == 'd' { "d" } else { "c" };
+            let mut keys = vec![(repeat, "the whole query")];
+            keys.extend_from_slice(MOTIONS);
+            Some((format!("{}: {}", op, operator_name(op)), keys))
+        }
+        EditorMode::TextObject(op, scope) => {
+            let (scope_key, scope_name) = match scope {
+                TextObjectScope::Inner => ('i', "inside"),
+                TextObjectScope::Around => ('a', "around"),
+            };
+            Some((
+                format!("{}{}: {} {}", op, scope_key, operator_name(op), scope_name),
+                TEXT_OBJECTS.to_vec(),
+            ))
+        }
+        EditorMode::Register('"') => Some((
+            "\": scratch register".to_string(),
+            vec![
+                ("y", "park the query"),
+                ("p", "put after the cursor"),
+                ("P", "put before the cursor"),
+            ],
+        )),
+        EditorMode::Wrap => Some((
+            "W: wrap last stage".to_string(),
+            wrap::SCAFFOLDS
+                .iter()
+                .map(|scaffold| (scaffold.key, scaffold.template))
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+fn operator_name(op: char) -> &'static str {
+    match op {
+        'd' => "delete",
+        'c' => "change",
+        _ => "copy",
+    }
+}
+
+/// When the editor entered its current prefix, to time the popup
+#[derive(Debug, Default)]
+pub struct WhichKey {
+    pending: Option<(EditorMode, Instant)>,
+    shown: bool,
+}
+
+impl WhichKey {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Follow the editor into and out of prefixes; returns true when the
+    /// popup appears or fades and the screen needs a redraw
+    pub fn update(&mut self, mode: EditorMode, now: Instant) -> bool {
+        if follow_ups(mode).is_none() {
+            self.pending = None;
+        } else if self.pending.is_none_or(|(pending, _)| pending != mode) {
+            self.pending = Some((mode, now));
+        }
+
+        let shown = self.pending.is_some_and(|(_, since)| {
+            let waited = now.duration_since(since);
+            waited >= WHICH_KEY_DELAY && waited < WHICH_KEY_DELAY + WHICH_KEY_DURATION
+        });
+        let changed = shown != self.shown;
+        self.shown = shown;
+        changed
+    }
+
+    /// Whether to draw the popup while the editor is in `mode`; a key that
+    /// left the prefix hides it before the next update
+    pub fn is_visible(&self, mode: EditorMode) -> bool {
+        self.shown && self.pending.is_some_and(|(pending, _)| pending == mode)
+    }
+}
+
+#[cfg(test)]
+#[path = "which_key_tests.rs"]
+mod which_key_tests;
@@ -0,0 +1,80 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::App;
+use crate::editor::which_key::follow_ups;
+use crate::theme;
+use crate::widgets::popup;
+
+/// Left and right border plus a space of padding on each side
+const BORDER_WIDTH: u16 = 4;
+const BORDER_HEIGHT: u16 = 2;
+/// Gap between the key column and its description
+const COLUMN_GAP: usize = 2;
+
+/// Render the which-key popup above the right end of the input field
+///
+/// Returns the popup area, or None when no prefix is pending.
+pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Rect> {
+    let mode = app.input.editor_mode;
+    if !app.which_key.is_visible(mode) {
+        return None;
+    }
+    let (title, keys) = follow_ups(mode)?;
+
+    let key_width = keys.iter().map(|(key, _)| key.width()).max().unwrap_or(0);
+    let content_width = keys
+        .iter()
+        .map(|(_, desc)| key_width + COLUMN_GAP + desc.width())
+        .chain([title.width() + 2])
+        .max()
+        .unwrap_or(0);
+
+    let width = (content_width as u16 + BORDER_WIDTH).min(input_area.width);
+    let height = (keys.len() as u16 + BORDER_HEIGHT).min(input_area.y);
+    let area = Rect {
+        x: input_area.right().saturating_sub(width + 1),
+        y: input_area.y.saturating_sub(height),
+        width,
+        height,
+    };
+
+    let lines: Vec<Line> = keys
+        .iter()
+        .map(|(key, desc)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:width$}", key, width = key_width + COLUMN_GAP),
+                    Style::default().fg(theme::tooltip::example()),
+                ),
+                Span::styled(*desc, Style::default().fg(theme::tooltip::example_desc())),
+            ])
+        })
+        .collect();
+
+    popup::clear_area(frame, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(vec![
+            Span::raw(" "),
+            Span::styled(title, theme::tooltip::title()),
+            Span::raw(" "),
+        ]))
+        .border_style(Style::default().fg(theme::tooltip::border()))
+        .style(Style::default().bg(theme::tooltip::background()));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "which_key_render_tests.rs"]
+mod which_key_render_tests;
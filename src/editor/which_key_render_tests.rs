@@ -0,0 +1,52 @@
+//! Tests for editor/which_key_render
+
+use super::*;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use std::time::Instant;
+
+fn render(app: &App) -> (Option<Rect>, String) {
+    let backend = TestBackend::new(60, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let input_area = Rect::new(0, 17, 60, 3);
+
+    let mut area = None;
+    terminal
+        .draw(|f| {
+            area = render_popup(app, f, input_area);
+        })
+        .unwrap();
+    (area, terminal.backend().to_string())
+}
+
+fn app_in_mode(mode: EditorMode) -> App {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.input.editor_mode = mode;
+    let start = Instant::now();
+    app.which_key.update(mode, start);
+    app.which_key
+        .update(mode, start + crate::editor::which_key::WHICH_KEY_DELAY);
+    app
+}
+
+#[test]
+fn test_nothing_drawn_before_the_delay() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.input.editor_mode = EditorMode::Operator('d');
+    app.which_key.update(app.input.editor_mode, Instant::now());
+
+    assert!(render(&app).0.is_none());
+}
+
+#[test]
+fn snapshot_operator_popup() {
+    let app = app_in_mode(EditorMode::Operator('d'));
+
+    let (area, output) = render(&app);
+
+    assert!(area.is_some());
+    assert_snapshot!(output);
+}
@@ -0,0 +1,85 @@
+//! Tests for editor/which_key
+
+use super::*;
+
+fn delete_mode() -> EditorMode {
+    EditorMode::Operator('d')
+}
+
+#[test]
+fn test_follow_ups_only_for_prefixes() {
+    assert!(follow_ups(EditorMode::Normal).is_none());
+    assert!(follow_ups(EditorMode::Insert).is_none());
+    assert!(follow_ups(delete_mode()).is_some());
+    assert!(follow_ups(EditorMode::Operator('y')).is_some());
+    assert!(follow_ups(EditorMode::TextObject('c', TextObjectScope::Inner)).is_some());
+}
+
+#[test]
+fn test_operator_lists_repeat_and_motions() {
+    let (title, keys) = follow_ups(EditorMode::Operator('c')).unwrap();
+    assert_eq!(title, "c: change");
+    assert_eq!(keys[0], ("c", "the whole query"));
+    assert!(keys.iter().any(|(key, _)| *key == "w"));
+    assert!(keys.iter().any(|(key, _)| *key == "i"));
+}
+
+#[test]
+fn test_text_object_title_names_scope() {
+    let (title, keys) = follow_ups(EditorMode::TextObject('d', TextObjectScope::Around)).unwrap();
+    assert_eq!(title, "da: delete around");
+    assert!(keys.iter().any(|(_, desc)| *desc == "pipe stage"));
+}
+
+#[test]
+fn test_wrap_lists_scaffolds() {
+    let (title, keys) = follow_ups(EditorMode::Wrap).unwrap();
+    assert_eq!(title, "W: wrap last stage");
+    assert!(keys.contains(&("m", "map(_)")));
+}
+
+#[test]
+fn test_popup_waits_for_the_delay() {
+    let start = Instant::now();
+    let mut which_key = WhichKey::new();
+
+    assert!(!which_key.update(delete_mode(), start));
+    assert!(!which_key.is_visible(delete_mode()));
+
+    assert!(which_key.update(delete_mode(), start + WHICH_KEY_DELAY));
+    assert!(which_key.is_visible(delete_mode()));
+}
+
+#[test]
+fn test_popup_fades_after_duration() {
+    let start = Instant::now();
+    let mut which_key = WhichKey::new();
+    which_key.update(delete_mode(), start);
+    which_key.update(delete_mode(), start + WHICH_KEY_DELAY);
+
+    assert!(which_key.update(delete_mode(), start + WHICH_KEY_DELAY + WHICH_KEY_DURATION));
+    assert!(!which_key.is_visible(delete_mode()));
+}
+
+#[test]
+fn test_leaving_the_prefix_hides_at_once() {
+    let start = Instant::now();
+    let mut which_key = WhichKey::new();
+    which_key.update(delete_mode(), start);
+    which_key.update(delete_mode(), start + WHICH_KEY_DELAY);
+
+    assert!(!which_key.is_visible(EditorMode::Normal));
+    assert!(which_key.update(EditorMode::Normal, start + WHICH_KEY_DELAY));
+}
+
+#[test]
+fn test_next_prefix_restarts_the_delay() {
+    let start = Instant::now();
+    let mut which_key = WhichKey::new();
+    which_key.update(delete_mode(), start);
+    which_key.update(delete_mode(), start + WHICH_KEY_DELAY);
+
+    let inner = EditorMode::TextObject('d', TextObjectScope::Inner);
+    assert!(which_key.update(inner, start + WHICH_KEY_DELAY));
+    assert!(!which_key.is_visible(inner));
+}
@@ -0,0 +1,91 @@
+//! Wrap the query's last stage in a common scaffold
+//!
+//! `W` in Normal mode followed by a scaffold key, or `:wrap <name>`, puts
+//! the last pipeline stage at the scaffold's placeholder: `.a | .b` with
+//! `Wm` becomes `.a | map(.b)`. `:wrap <name> all` wraps the whole query.
+
+use crate::save::save_script::pipeline_prefixes;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Scaffold {
+    /// Key after `W`
+    pub key: &'static str,
+    /// Name for `:wrap`
+    pub name: &'static str,
+    /// The scaffold, `_` marking where the wrapped text goes
+    pub template: &'static str,
+}
+
+pub const SCAFFOLDS: &[Scaffold] = &[
+    Scaffold {
+        key: "m",
+        name: "map",
+        template: "map(_)",
+    },
+    Scaffold {
+        key: "s",
+        name: "select",
+        template: "select(_)",
+    },
+    Scaffold {
+        key: "[",
+        name: "array",
+        template: "[_]",
+    },
+    Scaffold {
+        key: "t",
+        name: "try",
+        template: "try _ catch empty",
+    },
+    Scaffold {
+        key: "/",
+        name: "alternative",
+        template: "(_ // empty)",
+    },
+];
+
+pub fn by_key(key: char) -> Option<&'static Scaffold> {
+    SCAFFOLDS
+        .iter()
+        .find(|scaffold| scaffold.key.chars().eq([key]))
+}
+
+pub fn by_name(name: &str) -> Option<&'static Scaffold> {
+    SCAFFOLDS.iter().find(|scaffold| scaffold.name == name)
+}
+
+/// `query` with its last stage, or all of it when `whole`, wrapped in
+/// `scaffold`, and the char column for the cursor: after the wrapped
+/// text, or at the placeholder when there was nothing to wrap
+pub fn wrap(query: &str, scaffold: &Scaffold, whole: bool) -> (String, usize) {
+    let start = if whole {
+        0
+    } else {
+        // Prefixes end before the whitespace leading up to their `|`
+        pipeline_prefixes(query).last().map_or(0, |prefix| {
+            let pipe = query[prefix.len()..].find('|').unwrap_or(0);
+            prefix.len() + pipe + 1
+        })
+    };
+    let (head, stage) = query.split_at(start);
+    let lead = &stage[..stage.len() - stage.trim_start().len()];
+    let stage = stage.trim();
+    let (before, after) = scaffold
+        .template
+        .split_once('_')
+        .unwrap_or((scaffold.template, ""));
+
+    let mut wrapped = format!("{}{}{}{}", head, lead, before, stage);
+    let placeholder_col = wrapped.chars().count();
+    wrapped.push_str(after);
+    let cursor = if stage.is_empty() {
+        placeholder_col
+    } else {
+        wrapped.chars().count()
+    };
+    (wrapped, cursor)
+}
+
+#[cfg(test)]
+#[path = "wrap_tests.rs"]
+mod wrap_tests;
@@ -0,0 +1,60 @@
+//! Tests for editor/wrap
+
+use super::*;
+
+fn wrap_with(query: &str, name: &str, whole: bool) -> (String, usize) {
+    wrap(query, by_name(name).unwrap(), whole)
+}
+
+#[test]
+fn test_single_stage_is_wrapped_whole() {
+    assert_eq!(
+        wrap_with(".items", "map", false),
+        ("map(.items)".to_string(), 11)
+    );
+    assert_eq!(wrap_with(".a", "array", false), ("[.a]".to_string(), 4));
+}
+
+#[test]
+fn test_only_the_last_stage_is_wrapped() {
+    assert_eq!(
+        wrap_with(".a | .b", "select", false),
+        (".a | select(.b)".to_string(), 15)
+    );
+    assert_eq!(
+        wrap_with(".a|.b ", "try", false),
+        (".a|try .b catch empty".to_string(), 21)
+    );
+}
+
+#[test]
+fn test_pipes_inside_brackets_do_not_split() {
+    assert_eq!(
+        wrap_with("map(.a | .b)", "alternative", false).0,
+        "(map(.a | .b) // empty)"
+    );
+}
+
+#[test]
+fn test_whole_wraps_every_stage() {
+    assert_eq!(wrap_with(".a | .b", "map", true).0, "map(.a | .b)");
+}
+
+#[test]
+fn test_empty_stage_leaves_the_cursor_at_the_placeholder() {
+    assert_eq!(
+        wrap_with(".a | ", "select", false),
+        (".a | select()".to_string(), 12)
+    );
+    assert_eq!(
+        wrap_with("", "try", false),
+        ("try  catch empty".to_string(), 4)
+    );
+}
+
+#[test]
+fn test_lookup_by_key_and_name() {
+    assert_eq!(by_key('m').map(|s| s.name), Some("map"));
+    assert_eq!(by_name("try").map(|s| s.key), Some("t"));
+    assert!(by_key('z').is_none());
+}
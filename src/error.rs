@@ -10,6 +10,9 @@ pub enum JiqError {
 
     #[error("IO error: {0}")]
     Io(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
 }
 
 impl From<std::io::Error> for JiqError {
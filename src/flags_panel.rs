@@ -0,0 +1,12 @@
+//! Flags panel (F4): checkboxes for common jq output flags and jiq's
+//! optional helpers, applied as they're ticked and saved to the config file
+//! on request. The same settings are scattered over keys and config
+//! sections otherwise; the panel puts them in one place.
+
+mod flags_panel_events;
+mod flags_panel_render;
+mod flags_panel_state;
+
+pub use flags_panel_events::{handle_key, toggle};
+pub use flags_panel_render::render_popup;
+pub use flags_panel_state::{Flag, FlagsPanel};
@@ -0,0 +1,134 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::Flag;
+use crate::app::App;
+use crate::query::output_format::{Indent, OutputFormat};
+
+/// F4 — open the panel, or close it
+pub fn toggle(app: &mut App) {
+    if app.flags_panel.is_visible() {
+        app.flags_panel.close();
+    } else {
+        app.flags_panel.open();
+    }
+}
+
+/// Keys while the panel is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::F(4) => app.flags_panel.close(),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => app.flags_panel.select_next(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => app.flags_panel.select_prev(),
+        KeyCode::Char(' ') | KeyCode::Enter => {
+            let flag = app.flags_panel.selected();
+            toggle_flag(app, flag);
+        }
+        KeyCode::Char('w') => save(app),
+        _ => {}
+    }
+}
+
+/// Whether `flag` is ticked
+pub fn is_on(app: &App, flag: Flag) -> bool {
+    let format = current_format(app);
+    match flag {
+        Flag::RawOutput => format.raw,
+        Flag::CompactOutput => format.compact,
+        Flag::SortKeys => format.sort_keys,
+        Flag::Tab => format.indent == Indent::Tab,
+        Flag::ExitStatus => app.exit_status,
+        Flag::Autocomplete => app.autocomplete_auto_show,
+        Flag::Tooltips => app.tooltip.enabled,
+        Flag::Ai => app.ai.enabled,
+    }
+}
+
+/// Tick or untick `flag`. The output flags re-run the query at once.
+pub fn toggle_flag(app: &mut App, flag: Flag) {
+    let mut format = current_format(app);
+    match flag {
+        Flag::RawOutput => format.toggle_raw(),
+        Flag::CompactOutput => format.toggle_compact(),
+        Flag::SortKeys => format.toggle_sort_keys(),
+        Flag::Tab => format.toggle_tab(),
+        // Only the exit on Enter uses it
+        Flag::ExitStatus => {
+            app.exit_status = !app.exit_status;
+            return;
+        }
+        Flag::Autocomplete => {
+            app.autocomplete_auto_show = !app.autocomplete_auto_show;
+            if !app.autocomplete_auto_show {
+                app.autocomplete.hide();
+            }
+            return;
+        }
+        Flag::Tooltips => {
+            app.tooltip.toggle();
+            return;
+        }
+        Flag::Ai => {
+            toggle_ai(app);
+            return;
+        }
+    }
+    crate::results::format_menu_events::set_format(app, format);
+}
+
+/// The active tab's output format
+fn current_format(app: &App) -> OutputFormat {
+    app.query
+        .as_ref()
+        .map_or(app.output_format, |q| q.output_format())
+}
+
+fn toggle_ai(app: &mut App) {
+    if app.ai.offline {
+        app.notification
+            .show_warning(crate::ai::ai_state::OFFLINE_MESSAGE);
+        return;
+    }
+    app.ai.enabled = !app.ai.enabled;
+    let active = app.ai.enabled && app.ai.configured;
+    if let Some(query_state) = app.query.as_mut() {
+        query_state.set_ai_active(active);
+        // The context comes from the result, so run it again
+        if active {
+            crate::editor::editor_events::execute_query(app);
+        }
+    }
+    if !app.ai.enabled && app.ai.visible {
+        app.ai.toggle();
+        app.tooltip.enabled = app.saved_tooltip_visibility;
+    }
+    if app.ai.enabled && !app.ai.configured {
+        app.notification
+            .show_warning("AI enabled but not configured. Add provider credentials to config.");
+    }
+}
+
+/// Every flag as the config file's `(section, key, value)`
+pub fn settings(app: &App) -> Vec<(&'static str, &'static str, bool)> {
+    Flag::ALL
+        .iter()
+        .map(|&flag| {
+            let (section, key) = flag.config_key();
+            (section, key, is_on(app, flag))
+        })
+        .collect()
+}
+
+/// `w` — write the ticked flags to the config file, so they are the
+/// defaults next time
+fn save(app: &mut App) {
+    match crate::config::save_settings(&settings(app)) {
+        Ok(path) => app
+            .notification
+            .show(&format!("Saved flags to {}", path.display())),
+        Err(e) => app.notification.show_error(&e),
+    }
+}
+
+#[cfg(test)]
+#[path = "flags_panel_events_tests.rs"]
+mod flags_panel_events_tests;
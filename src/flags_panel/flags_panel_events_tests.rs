@@ -0,0 +1,131 @@
+//! Tests for flags_panel/flags_panel_events
+
+use ratatui::crossterm::event::KeyCode;
+
+use super::*;
+use crate::query::worker::preprocess::strip_ansi_codes;
+use crate::test_utils::test_helpers::{key, test_app, wait_for_query_completion};
+
+fn app_with_panel(json: &str) -> App {
+    let mut app = test_app(json);
+    app.handle_key_event(key(KeyCode::F(4)));
+    app
+}
+
+fn select(app: &mut App, flag: Flag) {
+    while app.flags_panel.selected() != flag {
+        app.handle_key_event(key(KeyCode::Down));
+    }
+}
+
+fn result(app: &App) -> String {
+    strip_ansi_codes(app.query.as_ref().unwrap().result.as_ref().unwrap())
+}
+
+#[test]
+fn f4_opens_and_closes_the_panel() {
+    let mut app = app_with_panel("{}");
+    assert!(app.flags_panel.is_visible());
+
+    app.handle_key_event(key(KeyCode::F(4)));
+
+    assert!(!app.flags_panel.is_visible());
+}
+
+#[test]
+fn space_toggles_raw_output_live() {
+    let mut app = app_with_panel(r#"{"name": "ada"}"#);
+    app.input.textarea.insert_str(".name");
+
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    assert!(is_on(&app, Flag::RawOutput));
+    assert_eq!(result(&app), "ada\n");
+}
+
+#[test]
+fn compact_output_puts_each_value_on_one_line() {
+    let mut app = app_with_panel(r#"{"a": [1, 2]}"#);
+    select(&mut app, Flag::CompactOutput);
+
+    app.handle_key_event(key(KeyCode::Enter));
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    assert_eq!(result(&app), "{\"a\":[1,2]}\n");
+}
+
+#[test]
+fn output_flags_carry_over_to_a_reloaded_input() {
+    let mut app = app_with_panel("{}");
+    select(&mut app, Flag::SortKeys);
+    app.handle_key_event(key(KeyCode::Char(' ')));
+
+    app.accept_paste_recovery_json(r#"{"b": 1}"#.to_string());
+
+    assert!(app.query.as_ref().unwrap().output_format().sort_keys);
+}
+
+#[test]
+fn jiq_options_toggle_their_settings() {
+    let mut app = app_with_panel("{}");
+    let auto_show = app.autocomplete_auto_show;
+    let tooltips = app.tooltip.enabled;
+
+    select(&mut app, Flag::Autocomplete);
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    select(&mut app, Flag::Tooltips);
+    app.handle_key_event(key(KeyCode::Char(' ')));
+
+    assert_eq!(app.autocomplete_auto_show, !auto_show);
+    assert_eq!(app.tooltip.enabled, !tooltips);
+}
+
+#[test]
+fn exit_status_leaves_the_results_alone() {
+    let mut app = app_with_panel(r#"{"a": null}"#);
+    app.input.textarea.insert_str(".a");
+    select(&mut app, Flag::ExitStatus);
+
+    app.handle_key_event(key(KeyCode::Char(' ')));
+
+    assert!(app.exit_status);
+    assert!(!app.query.as_ref().unwrap().output_format().raw);
+    assert!(settings(&app).contains(&("output", "exit_status", true)));
+}
+
+#[test]
+fn ai_stays_off_while_offline() {
+    let mut app = app_with_panel("{}");
+    app.ai.offline = true;
+    let enabled = app.ai.enabled;
+    select(&mut app, Flag::Ai);
+
+    app.handle_key_event(key(KeyCode::Char(' ')));
+
+    assert_eq!(app.ai.enabled, enabled);
+}
+
+#[test]
+fn settings_name_each_config_key_with_its_value() {
+    let mut app = app_with_panel("{}");
+    select(&mut app, Flag::Tab);
+    app.handle_key_event(key(KeyCode::Char(' ')));
+
+    let settings = settings(&app);
+
+    assert!(settings.contains(&("output", "tab", true)));
+    assert!(settings.contains(&("output", "raw", false)));
+    assert!(settings.contains(&("tooltip", "auto_show", app.tooltip.enabled)));
+    assert_eq!(settings.len(), Flag::ALL.len());
+}
+
+#[test]
+fn other_keys_are_swallowed() {
+    let mut app = app_with_panel("{}");
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.flags_panel.is_visible());
+    assert_eq!(app.query(), "");
+}
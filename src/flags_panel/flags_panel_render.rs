@@ -0,0 +1,59 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+};
+
+use super::Flag;
+use super::flags_panel_events::is_on;
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const POPUP_WIDTH: u16 = 46;
+const SWITCH_WIDTH: usize = 7;
+
+/// Render the flags panel centered over `area`
+pub fn render_popup(app: &App, frame: &mut Frame, area: Rect) {
+    let height = Flag::ALL.len() as u16 + 2;
+    let popup_area = centered_rect(area, POPUP_WIDTH, height);
+    if popup_area.width < 36 || popup_area.height < height {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(
+        " Flags ",
+        &[("Space", "Toggle"), ("w", "Save"), ("Esc", "Close")],
+    );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let selected = app.flags_panel.selected();
+    let switch_style = Style::default().fg(theme::results::hint_key());
+    let lines: Vec<Line> = Flag::ALL
+        .iter()
+        .map(|&flag| {
+            let marker = if is_on(app, flag) { "[x] " } else { "[ ] " };
+            let label_style = if flag == selected {
+                Style::default()
+                    .fg(theme::results::border_focused())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::palette::text())
+            };
+            Line::from(vec![
+                Span::styled(marker, label_style),
+                Span::styled(format!("{:<SWITCH_WIDTH$}", flag.switch()), switch_style),
+                Span::styled(flag.label(), label_style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+#[path = "flags_panel_render_tests.rs"]
+mod flags_panel_render_tests;
@@ -0,0 +1,27 @@
+//! Tests for flags_panel/flags_panel_render
+
+use insta::assert_snapshot;
+
+use super::*;
+use crate::test_utils::test_helpers::{render_popup_to_string, test_app};
+
+#[test]
+fn snapshot_flags_panel() {
+    let mut app = test_app("{}");
+    app.autocomplete_auto_show = true;
+    app.tooltip.enabled = true;
+    app.ai.enabled = false;
+    app.output_format.sort_keys = true;
+    app.query = None;
+
+    assert_snapshot!(render_popup_to_string(&app, 50, 12, render_popup));
+}
+
+#[test]
+fn not_drawn_when_too_small() {
+    let app = test_app("{}");
+
+    let output = render_popup_to_string(&app, 30, 12, render_popup);
+
+    assert!(!output.contains("Flags"));
+}
@@ -0,0 +1,107 @@
+/// One row of the flags panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flag {
+    RawOutput,
+    CompactOutput,
+    SortKeys,
+    Tab,
+    ExitStatus,
+    Autocomplete,
+    Tooltips,
+    Ai,
+}
+
+impl Flag {
+    /// The rows, top to bottom
+    pub const ALL: [Flag; 8] = [
+        Flag::RawOutput,
+        Flag::CompactOutput,
+        Flag::SortKeys,
+        Flag::Tab,
+        Flag::ExitStatus,
+        Flag::Autocomplete,
+        Flag::Tooltips,
+        Flag::Ai,
+    ];
+
+    /// The jq switch, or empty for jiq's own options
+    pub fn switch(self) -> &'static str {
+        match self {
+            Flag::RawOutput => "-r",
+            Flag::CompactOutput => "-c",
+            Flag::SortKeys => "-S",
+            Flag::Tab => "--tab",
+            Flag::ExitStatus => "-e",
+            Flag::Autocomplete | Flag::Tooltips | Flag::Ai => "",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Flag::RawOutput => "Raw strings",
+            Flag::CompactOutput => "Compact output",
+            Flag::SortKeys => "Sort keys",
+            Flag::Tab => "Indent with tabs",
+            Flag::ExitStatus => "Exit status from result",
+            Flag::Autocomplete => "Autocomplete while typing",
+            Flag::Tooltips => "Function tooltips",
+            Flag::Ai => "AI assistant",
+        }
+    }
+
+    /// Where the setting lives in the config file: section and key
+    pub fn config_key(self) -> (&'static str, &'static str) {
+        match self {
+            Flag::RawOutput => ("output", "raw"),
+            Flag::CompactOutput => ("output", "compact"),
+            Flag::SortKeys => ("output", "sort_keys"),
+            Flag::Tab => ("output", "tab"),
+            Flag::ExitStatus => ("output", "exit_status"),
+            Flag::Autocomplete => ("autocomplete", "auto_show"),
+            Flag::Tooltips => ("tooltip", "auto_show"),
+            Flag::Ai => ("ai", "enabled"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FlagsPanel {
+    visible: bool,
+    selected: usize,
+}
+
+impl FlagsPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn selected(&self) -> Flag {
+        Flag::ALL[self.selected]
+    }
+
+    /// Highlight the next row, wrapping to the top
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % Flag::ALL.len();
+    }
+
+    /// Highlight the previous row, wrapping to the bottom
+    pub fn select_prev(&mut self) {
+        self.selected = (self.selected + Flag::ALL.len() - 1) % Flag::ALL.len();
+    }
+}
+
+#[cfg(test)]
+#[path = "flags_panel_state_tests.rs"]
+mod flags_panel_state_tests;
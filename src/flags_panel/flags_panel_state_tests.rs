@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn selection_wraps_both_ways() {
+    let mut panel = FlagsPanel::new();
+    assert_eq!(panel.selected(), Flag::RawOutput);
+
+    panel.select_prev();
+    assert_eq!(panel.selected(), Flag::Ai);
+
+    panel.select_next();
+    panel.select_next();
+    assert_eq!(panel.selected(), Flag::CompactOutput);
+}
+
+#[test]
+fn every_flag_has_its_own_config_key() {
+    let mut keys: Vec<_> = Flag::ALL.iter().map(|f| f.config_key()).collect();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), Flag::ALL.len());
+}
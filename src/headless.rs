@@ -0,0 +1,324 @@
+//! Drive jiq without a terminal
+//!
+//! [`Headless`] builds an [`App`] over JSON text, feeds it keys and pastes
+//! as if typed, runs its background work, and draws it into ratatui's
+//! [`TestBackend`] so scripts and tests can check what a user would see.
+//!
+//! ```
+//! use jiq::headless::Headless;
+//!
+//! let mut jiq = Headless::new(r#"{"name": "jiq"}"#);
+//! jiq.type_str(".name");
+//! assert!(jiq.settle());
+//! assert_eq!(jiq.output().as_deref(), Some("\"jiq\"\n"));
+//! assert!(jiq.screen().contains("\"jiq\""));
+//! ```
+//!
+//! Nothing touches the user's history, usage counts or onboarding state,
+//! and the fading flash on changed result lines is off so that screens
+//! don't depend on timing.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::App;
+use crate::autocomplete::completion_usage::CompletionUsage;
+use crate::config::Config;
+use crate::history::HistoryState;
+use crate::input::FileLoader;
+use crate::notification::OnboardingTour;
+use crate::plugins::PluginRegistry;
+use crate::query::JqArgs;
+use crate::query::worker::preprocess::strip_ansi_codes;
+use crate::results::result_diff::DiffHighlight;
+use crate::scratch::Scratch;
+
+pub const DEFAULT_WIDTH: u16 = 100;
+pub const DEFAULT_HEIGHT: u16 = 30;
+
+/// How long [`Headless::settle`] waits for background work by default
+pub const DEFAULT_SETTLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often [`Headless::settle`] checks on background work
+const SETTLE_POLL: Duration = Duration::from_millis(5);
+
+/// Options for a [`Headless`] app
+pub struct HeadlessBuilder {
+    json: String,
+    config: Config,
+    jq_args: JqArgs,
+    width: u16,
+    height: u16,
+    settle_timeout: Duration,
+}
+
+impl HeadlessBuilder {
+    /// Settings to use instead of the defaults; the user's config file is
+    /// never read
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Options every query runs with, like `--arg` or `-n` on the command
+    /// line
+    pub fn jq_args(mut self, jq_args: JqArgs) -> Self {
+        self.jq_args = jq_args;
+        self
+    }
+
+    /// Size of the screen drawn into
+    pub fn size(mut self, width: u16, height: u16) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// How long [`Headless::settle`] waits before giving up
+    pub fn settle_timeout(mut self, timeout: Duration) -> Self {
+        self.settle_timeout = timeout;
+        self
+    }
+
+    pub fn build(self) -> Headless {
+        let mut app = App::new_with_loader(FileLoader::from_json(self.json), &self.config);
+        app.jq_args = Arc::new(self.jq_args);
+        let mut headless = Headless::from_app(app, self.width, self.height);
+        headless.settle_timeout = self.settle_timeout;
+        headless
+    }
+}
+
+/// A jiq session with no terminal attached
+pub struct Headless {
+    app: App,
+    terminal: Terminal<TestBackend>,
+    settle_timeout: Duration,
+}
+
+impl Headless {
+    /// Start jiq over `json` with the default settings and screen size
+    pub fn new(json: impl Into<String>) -> Self {
+        Self::builder(json).build()
+    }
+
+    pub fn builder(json: impl Into<String>) -> HeadlessBuilder {
+        HeadlessBuilder {
+            json: json.into(),
+            config: Config::default(),
+            jq_args: JqArgs::new(),
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            settle_timeout: DEFAULT_SETTLE_TIMEOUT,
+        }
+    }
+
+    /// Drive an app built elsewhere, such as one loading a file, on a
+    /// `width` × `height` screen
+    pub fn from_app(mut app: App, width: u16, height: u16) -> Self {
+        app.history = HistoryState::empty();
+        app.completion_usage = CompletionUsage::empty(app.completion_usage.order());
+        app.onboarding = OnboardingTour::empty();
+        app.scratch = Scratch::empty();
+        app.plugins = PluginRegistry::empty();
+        app.results_diff = DiffHighlight::new(false);
+        app.poll_file_loader();
+
+        let terminal = Terminal::new(TestBackend::new(width, height))
+            .expect("a test backend never fails to start");
+        Self {
+            app,
+            terminal,
+            settle_timeout: DEFAULT_SETTLE_TIMEOUT,
+        }
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// The app itself, for state this API doesn't cover
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// Handle a terminal event the way the event loop does
+    pub fn event(&mut self, event: Event) -> &mut Self {
+        self.app.handle_event(event);
+        self
+    }
+
+    /// Press `key`
+    pub fn press(&mut self, key: KeyEvent) -> &mut Self {
+        self.event(Event::Key(key))
+    }
+
+    /// Press `code` with no modifiers
+    pub fn key(&mut self, code: KeyCode) -> &mut Self {
+        self.press(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    /// Press Ctrl and `c` together
+    pub fn ctrl(&mut self, c: char) -> &mut Self {
+        self.press(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+    }
+
+    /// Type `text` a character at a time
+    pub fn type_str(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.key(KeyCode::Char(c));
+        }
+        self
+    }
+
+    /// Paste `text` in one go
+    pub fn paste(&mut self, text: &str) -> &mut Self {
+        self.event(Event::Paste(text.to_string()))
+    }
+
+    /// Run one round of background work, as the event loop does between
+    /// events
+    pub fn tick(&mut self) -> &mut Self {
+        self.app.tick();
+        self
+    }
+
+    /// Finish loading the document, run the query as typed without
+    /// waiting out the typing delay, then tick until it has finished.
+    /// Returns false when it was still running after the settle timeout.
+    pub fn settle(&mut self) -> bool {
+        let deadline = Instant::now() + self.settle_timeout;
+        loop {
+            self.app.poll_file_loader();
+            if self.app.debouncer.has_pending() {
+                crate::editor::editor_events::execute_query_with_auto_show(&mut self.app);
+                self.app.debouncer.mark_executed();
+            }
+            self.app.tick();
+            if !self.is_busy() {
+                return true;
+            }
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(SETTLE_POLL);
+        }
+    }
+
+    fn is_busy(&self) -> bool {
+        self.app
+            .file_loader
+            .as_ref()
+            .is_some_and(FileLoader::is_loading)
+            || self.app.debouncer.has_pending()
+            || self.app.query.as_ref().is_some_and(|q| q.is_pending())
+            || self.app.input_analysis_pending
+    }
+
+    /// The query as typed
+    pub fn query(&self) -> &str {
+        self.app.input.query()
+    }
+
+    /// Output of the last query, without colors; None while there is no
+    /// document or the query failed
+    pub fn output(&self) -> Option<String> {
+        let query_state = self.app.query.as_ref()?;
+        let output = query_state.result.as_ref().ok()?;
+        Some(strip_ansi_codes(output))
+    }
+
+    /// jq's message when the last query failed
+    pub fn error(&self) -> Option<&str> {
+        let query_state = self.app.query.as_ref()?;
+        query_state.result.as_ref().err().map(String::as_str)
+    }
+
+    /// The notification showing, if any
+    pub fn notification(&self) -> Option<&str> {
+        self.app.notification.current_message()
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.app.should_quit()
+    }
+
+    /// Change the size of the screen drawn into
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.terminal.backend_mut().resize(width, height);
+        self.event(Event::Resize(width, height))
+    }
+
+    /// Draw the app and return the cells drawn
+    pub fn render(&mut self) -> &Buffer {
+        let app = &mut self.app;
+        self.terminal
+            .draw(|frame| app.render(frame))
+            .expect("drawing to a test backend never fails");
+        app.clear_dirty();
+        self.terminal.backend().buffer()
+    }
+
+    /// Draw the app and return the screen as text, as [`buffer_text`]
+    pub fn screen(&mut self) -> String {
+        buffer_text(self.render())
+    }
+
+    /// [`screen`](Self::screen) with what varies from run to run left
+    /// out: the query's run time and the spinner's frame. For snapshots.
+    pub fn snapshot(&mut self) -> String {
+        if let Some(query_state) = &mut self.app.query {
+            query_state.cached_execution_time_ms = None;
+        }
+        self.app.frame_count = 0;
+        self.screen()
+    }
+}
+
+/// Draw the whole of `app` on a `width` × `height` screen and return it as
+/// text, as [`buffer_text`]
+pub fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .expect("a test backend never fails to start");
+    terminal
+        .draw(|frame| app.render(frame))
+        .expect("drawing to a test backend never fails");
+    buffer_text(terminal.backend().buffer())
+}
+
+/// The characters of `buffer`, one line per row with trailing spaces
+/// trimmed; colors and styles are dropped
+pub fn buffer_text(buffer: &Buffer) -> String {
+    let mut text = String::new();
+    for y in buffer.area.top()..buffer.area.bottom() {
+        let mut line = String::new();
+        let mut covered = 0;
+        for x in buffer.area.left()..buffer.area.right() {
+            // A wide character covers the cells after it
+            if covered > 0 {
+                covered -= 1;
+                continue;
+            }
+            let symbol = buffer[(x, y)].symbol();
+            covered = symbol.width().saturating_sub(1);
+            line.push_str(symbol);
+        }
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+#[path = "headless_tests.rs"]
+mod headless_tests;
@@ -0,0 +1,164 @@
+//! Tests for headless
+
+use ratatui::crossterm::event::KeyCode;
+
+use super::*;
+
+#[test]
+fn starts_with_the_document_loaded() {
+    let mut jiq = Headless::new(r#"{"a": 1}"#);
+
+    assert!(jiq.settle());
+    assert_eq!(jiq.query(), "");
+    assert!(jiq.output().is_some_and(|out| out.contains("\"a\": 1")));
+}
+
+#[test]
+fn typed_query_runs_once_settled() {
+    let mut jiq = Headless::new(r#"{"a": {"b": 2}}"#);
+
+    jiq.type_str(".a.b");
+    assert!(jiq.settle());
+
+    assert_eq!(jiq.query(), ".a.b");
+    assert_eq!(jiq.output().as_deref(), Some("2\n"));
+    assert_eq!(jiq.error(), None);
+}
+
+#[test]
+fn failing_query_reports_the_error() {
+    let mut jiq = Headless::new(r#"{"a": 1}"#);
+
+    jiq.type_str(".a[");
+    assert!(jiq.settle());
+
+    assert!(jiq.error().is_some());
+    assert_eq!(jiq.output(), None);
+}
+
+#[test]
+fn jq_args_reach_the_query() {
+    let mut jq_args = JqArgs::new();
+    jq_args.set_null_input(true);
+    let mut jiq = Headless::builder(r#"{"a": 1}"#).jq_args(jq_args).build();
+
+    jiq.type_str(".");
+    assert!(jiq.settle());
+
+    assert_eq!(jiq.output().as_deref(), Some("null\n"));
+}
+
+fn null_input() -> JqArgs {
+    let mut jq_args = JqArgs::new();
+    jq_args.set_null_input(true);
+    jq_args
+}
+
+#[test]
+fn null_input_runs_without_a_document() {
+    let mut jiq = Headless::builder("").jq_args(null_input()).build();
+
+    jiq.type_str("[range(3)] | length");
+    assert!(jiq.settle());
+
+    assert_eq!(jiq.output().as_deref(), Some("3\n"));
+    assert!(jiq.screen().contains("null input"));
+}
+
+#[test]
+fn null_input_suggests_no_fields_from_the_input() {
+    let mut jiq = Headless::builder(r#"{"name": "ada"}"#)
+        .jq_args(null_input())
+        .build();
+    jiq.settle();
+
+    let query = jiq.app().query.as_ref().unwrap();
+
+    assert!(query.executor.all_field_names().is_empty());
+    assert!(query.executor.json_input_parsed().is_none());
+}
+
+#[test]
+fn screen_has_the_requested_size() {
+    let mut jiq = Headless::builder(r#"{"a": 1}"#).size(60, 12).build();
+    jiq.settle();
+
+    let screen = jiq.screen();
+
+    assert_eq!(screen.lines().count(), 12);
+    assert_eq!(jiq.render().area.width, 60);
+}
+
+#[test]
+fn resize_changes_the_screen() {
+    let mut jiq = Headless::builder(r#"{"a": 1}"#).size(60, 12).build();
+
+    jiq.resize(80, 20);
+
+    assert_eq!(jiq.render().area.height, 20);
+}
+
+#[test]
+fn ctrl_c_quits() {
+    let mut jiq = Headless::new(r#"{"a": 1}"#);
+
+    jiq.ctrl('c');
+
+    assert!(jiq.should_quit());
+}
+
+#[test]
+fn keys_chain() {
+    let mut jiq = Headless::new(r#"{"a": 1}"#);
+
+    jiq.type_str(".ab").key(KeyCode::Backspace);
+
+    assert_eq!(jiq.query(), ".a");
+}
+
+#[test]
+fn paste_inserts_the_text() {
+    let mut jiq = Headless::new(r#"{"a": 1}"#);
+
+    jiq.paste(".a");
+    assert!(jiq.settle());
+
+    assert_eq!(jiq.output().as_deref(), Some("1\n"));
+}
+
+#[test]
+fn buffer_text_trims_rows_and_skips_wide_cells() {
+    let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 6, 2));
+    buffer.set_string(0, 0, "日本", ratatui::style::Style::default());
+    buffer.set_string(0, 1, "ab", ratatui::style::Style::default());
+
+    assert_eq!(buffer_text(&buffer), "日本\nab\n");
+}
+
+#[test]
+fn render_to_string_draws_the_given_size() {
+    let mut jiq = Headless::new(r#"{"a": 1}"#);
+    jiq.settle();
+
+    let text = render_to_string(jiq.app_mut(), 40, 9);
+
+    assert_eq!(text.lines().count(), 9);
+    assert!(text.contains("\"a\": 1"), "{text}");
+}
+
+#[test]
+fn snapshot_leaves_out_the_run_time() {
+    let run = || {
+        let mut jiq = Headless::builder(r#"{"a": [1, 2, 3]}"#)
+            .size(60, 12)
+            .build();
+        jiq.type_str(".a | map(. * 2)");
+        jiq.settle();
+        jiq.snapshot()
+    };
+
+    let first = run();
+
+    assert!(!first.contains("ms ─"), "{first}");
+    assert_eq!(run(), first);
+}
@@ -2,6 +2,10 @@ mod help_content;
 pub mod help_line_render;
 pub mod help_popup_render;
 mod help_state;
+mod manual_content;
+pub mod manual_render;
+mod manual_state;
 
 pub use help_content::{HelpSection, get_tab_content};
 pub use help_state::{HelpPopupState, HelpTab};
+pub use manual_content::{MANUAL_SECTIONS, ManualSection, section_index_for};
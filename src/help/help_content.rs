@@ -1,5 +1,6 @@
 use super::help_state::HelpTab;
 
+#[derive(Clone, Copy)]
 pub struct HelpSection {
     pub title: Option<&'static str>,
     pub entries: &'static [(&'static str, &'static str)],
@@ -19,7 +20,11 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 title: None,
                 entries: &[
                     ("F1 or ?", "Toggle this help"),
+                    ("F2", "Replay onboarding tips"),
+                    ("Alt+N", "Notification history"),
+                    ("Ctrl+X", "Cancel running query / AI request"),
                     ("Ctrl+A", "Toggle AI assistant"),
+                    ("Ctrl+G", "Ask AI in plain English"),
                     ("Ctrl+S", "Open snippets manager"),
                     ("Ctrl+C", "Quit without output"),
                     ("Enter", "Output filtered JSON and exit"),
@@ -27,11 +32,25 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                     ("Shift+Tab", "Switch focus (Input / Results)"),
                     ("Ctrl+T", "Switch focus (Input / Results)"),
                     ("Ctrl+I", "Toggle function tooltip"),
+                    ("F3", "Detailed function help"),
+                    ("F4", "Flags panel (jq flags, jiq options)"),
+                    ("F5", "Presets for the detected document"),
+                    ("F6", "Values for the query's $parameters"),
+                    ("F7", "Pick a path by walking the input"),
                     ("Ctrl+Y", "Copy focused pane (query or results)"),
                     ("Ctrl+O", "Copy results from any focus"),
-                    ("Ctrl+W", "Save result to file"),
-                    ("q", "Quit (in Normal mode or Results pane)"),
+                    ("Ctrl+W", "Save result (.sh script, .md snippet)"),
+                    ("q", "Quit (in Results pane)"),
                     ("Ctrl+E", "Toggle error overlay"),
+                    ("Alt+F", "Apply the overlay's fix (field, []?)"),
+                    ("Ctrl+L", "Choose input files"),
+                    (":raw-input", "Read input lines as strings (-R)"),
+                    (":plugins", "List jiq-plugin-* commands and formats"),
+                    (":post NAME", "Rewrite printed output with a plugin"),
+                    ("Alt+T", "New tab (paste a document)"),
+                    ("Ctrl+PgDn/PgUp", "Next / previous tab"),
+                    ("Alt+W", "Close tab"),
+                    ("Alt+V", "Toggle split view"),
                 ],
             },
             HelpSection {
@@ -79,6 +98,14 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                     ("di\"/ci\"/etc", "Delete/change inside quotes/parens"),
                     ("u", "Undo"),
                     ("Ctrl+R", "Redo"),
+                    ("q{a-z}/q", "Record macro / stop recording"),
+                    ("@{a-z}/@@", "Play macro / repeat last"),
+                    ("\"y/\"p/\"P", "Park query in scratch / put after/before"),
+                    ("W+m/s/[/t//", "Wrap last stage in map/select/[]/try/(//)"),
+                    (
+                        ":",
+                        "Command line (:w :open :edit :set :theme :wrap :share :help)",
+                    ),
                     ("Ctrl+D/U", "Scroll results half page down/up"),
                 ],
             },
@@ -86,7 +113,10 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 title: Some("AUTOCOMPLETE"),
                 entries: &[
                     ("↑/↓", "Navigate suggestions"),
+                    ("PgUp/PgDn", "Page through suggestions"),
                     ("Tab", "Accept suggestion"),
+                    ("→/End", "Accept dimmed text at end"),
+                    ("Ctrl+Space", "Open suggestions at cursor"),
                     ("Esc", "Dismiss"),
                 ],
             },
@@ -106,6 +136,27 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 ("$", "Jump to right edge"),
                 ("g/Home", "Jump to top"),
                 ("G/End", "Jump to bottom"),
+                ("F", "Follow new output at the bottom"),
+                ("50%", "Jump halfway (any 0-100%)"),
+                ("m + a-z", "Mark line at cursor"),
+                ("' or ` + a-z", "Jump to mark"),
+                ("z0-z9", "Fold below depth N"),
+                ("zM/zR", "Fold all / unfold all"),
+                ("zm/zr", "Fold one level more/less"),
+                ("d", "Keep changed-line highlight"),
+                ("s", "Dim lines the last stage left unchanged"),
+                ("p", "Pin/unpin line at cursor"),
+                ("P", "Jump to next pinned line"),
+                ("Alt+P", "Clear all pins"),
+                ("|", "Filter lines (grep/sort/uniq/head/tail)"),
+                ("T", "Table view for arrays of objects"),
+                ("1-9", "Sort table by column"),
+                ("=", "Path view: path = value lines"),
+                ("#", "Chart numbers (histogram/sparkline)"),
+                ("c", "Count most frequent values or key sets"),
+                (":freq PATH", "Count the values at PATH"),
+                ("S", "Reassemble --stream events"),
+                ("o", "Output format: indent, sort keys, ASCII"),
                 ("Ctrl+D/U", "Half page down/up"),
                 ("PageDown/Up", "Half page down/up"),
                 (">", "Go to value at cursor"),
@@ -113,6 +164,11 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 ("*", "Iterate over array (replace last [N] with [])"),
                 ("^", "Step up one level in the typed query (parent)"),
                 ("}", "Wrap cursor leaf as {key: value}"),
+                ("E", "Decode JSON/base64/%-encoded string"),
+                ("R", "Re-root: result becomes the input"),
+                ("Backspace", "Back to the document before re-root"),
+                ("B 0-9", "Jump back to a breadcrumb level"),
+                ("\"y", "Park result or selection in scratch"),
             ],
         }],
     },
@@ -126,9 +182,13 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 ("↑/↓", "Navigate history entries"),
                 ("Type", "Fuzzy search filter"),
                 ("Enter/Tab", "Select entry and close"),
-                ("Ctrl+D", "Delete selected entry"),
+                ("Ctrl+Space", "Mark entry; Enter joins marks with |"),
+                ("Ctrl+P", "Pin or unpin selected entry"),
+                ("Ctrl+D/Del", "Delete selected entry"),
                 ("Click ✕", "Delete entry under mouse"),
                 ("Esc", "Close without selecting"),
+                (":private", "Stop or resume recording history"),
+                (":import FILE", "Add queries or shell history to history"),
             ],
         }],
     },
@@ -139,9 +199,12 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
             title: None,
             entries: &[
                 ("Ctrl+A", "Toggle AI assistant"),
+                ("Ctrl+G", "Ask AI in plain English"),
+                ("Ctrl+G", "Follow up (popup showing answer)"),
                 ("Alt+1-5", "Apply AI suggestion (direct)"),
                 ("Alt+↑↓/j/k", "Navigate suggestions"),
                 ("Enter", "Apply selected suggestion"),
+                ("Tab", "Append selected suggestion"),
             ],
         }],
     },
@@ -167,6 +230,13 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                     ("Esc", "Close search"),
                 ],
             },
+            HelpSection {
+                title: Some("PERSISTENT HIGHLIGHTS"),
+                entries: &[
+                    (":match REGEX", "Keep matches highlighted (a color each)"),
+                    (":match-clear", "Remove :match highlights"),
+                ],
+            },
         ],
     },
     // 7: Snippet tab
@@ -198,6 +268,21 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
             },
         ],
     },
+    // 8: Manual tab - drawn as the manual browser; these also make up its
+    // border hints
+    HelpCategory {
+        tab: HelpTab::Manual,
+        sections: &[HelpSection {
+            title: None,
+            entries: &[
+                ("/", "Search"),
+                ("↑/↓", "Select"),
+                ("j/k", "Scroll"),
+                ("Tab", "Next"),
+                ("q", "Close"),
+            ],
+        }],
+    },
 ];
 
 pub fn get_tab_content(tab: HelpTab) -> &'static HelpCategory {
@@ -6,7 +6,7 @@ use super::*;
 #[allow(clippy::const_is_empty)]
 fn test_help_categories_not_empty() {
     assert!(!HELP_CATEGORIES.is_empty());
-    assert_eq!(HELP_CATEGORIES.len(), 7);
+    assert_eq!(HELP_CATEGORIES.len(), 8);
 }
 
 #[test]
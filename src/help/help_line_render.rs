@@ -1,13 +1,16 @@
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::Style,
     text::{Line, Span},
     widgets::Paragraph,
 };
 
+use unicode_width::UnicodeWidthStr;
+
 use crate::app::{App, Focus};
 use crate::editor::EditorMode;
+use crate::progress;
 use crate::theme;
 
 macro_rules! hints {
@@ -27,9 +30,9 @@ fn get_context_hints(app: &App) -> Vec<(&'static str, &'static str)> {
         hints!["F1/?" => "Help", "Esc" => "Close"]
     } else if app.save.is_visible() {
         hints!["Esc" => "Close", "Enter" => "Confirm"]
-    } else if app.focus == Focus::InputField && app.input.editor_mode == EditorMode::Insert {
+    } else if app.has_focus(Focus::InputField) && app.input.editor_mode == EditorMode::Insert {
         hints!["F1" => "Help", "Ctrl+S" => "Snippets", "Ctrl+F" => "Search", "Enter" => "Output Result", "Ctrl+O" => "Copy Result", "Ctrl+W" => "Save", "Ctrl+Q" => "Output Query", "Ctrl+C" => "Quit"]
-    } else if app.focus == Focus::ResultsPane {
+    } else if app.has_focus(Focus::ResultsPane) {
         hints!["F1/?" => "Help", "Ctrl+T" => "Edit Query", "Ctrl+S" => "Snippets", "Ctrl+F" => "Search", "Ctrl+W" => "Save", "Ctrl+C" => "Quit"]
     } else {
         hints!["F1/?" => "Help", "Ctrl+S" => "Snippets", "Ctrl+F" => "Search", "Enter" => "Output Result", "Ctrl+O" => "Copy Result", "Ctrl+W" => "Save", "Ctrl+Q" => "Output Query", "Ctrl+C" => "Quit"]
@@ -57,10 +60,45 @@ fn build_styled_spans(hints: &[(&'static str, &'static str)]) -> Vec<Span<'stati
 }
 
 pub fn render_line(app: &App, frame: &mut Frame, area: Rect) {
-    let hints = get_context_hints(app);
-    let spans = build_styled_spans(&hints);
+    // A long operation takes over the line until it finishes
+    if let Some((operation, elapsed)) = app.progress.visible() {
+        let status = progress::render_status(operation, elapsed, app.frame_count);
+        frame.render_widget(Paragraph::new(status), area);
+        return;
+    }
+
+    // Screen reader mode says what changed in words instead of listing keys
+    let spans = if app.accessibility.screen_reader {
+        vec![
+            Span::raw(" "),
+            Span::styled(
+                app.accessibility.announcer.message(),
+                Style::default().fg(theme::help_line::description()),
+            ),
+        ]
+    } else {
+        build_styled_spans(&get_context_hints(app))
+    };
     let help = Paragraph::new(Line::from(spans));
-    frame.render_widget(help, area);
+
+    // A changed output format stays in view at the right, since it also
+    // applies to the output printed on exit
+    let Some(summary) = app.query.as_ref().and_then(|q| q.output_format().summary()) else {
+        frame.render_widget(help, area);
+        return;
+    };
+    let badge = format!(" {} ", summary);
+    let [hints_area, badge_area] =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(badge.width() as u16)])
+            .areas(area);
+    frame.render_widget(help, hints_area);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            badge,
+            Style::default().fg(theme::help_line::key()),
+        )),
+        badge_area,
+    );
 }
 
 #[cfg(test)]
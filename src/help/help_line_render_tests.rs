@@ -150,3 +150,59 @@ fn test_help_text_excludes_snippets_shortcut_when_snippet_manager_active() {
     assert!(!output.contains("Ctrl+S"));
     assert!(output.contains("Esc") && output.contains("Close"));
 }
+
+#[test]
+fn test_help_line_shows_long_running_operation() {
+    use crate::progress::Operation;
+    use std::time::{Duration, Instant};
+
+    let mut app = test_app("{}");
+    let started = Instant::now() - Duration::from_secs(2);
+    app.progress.update_at(Operation::AiRequest, true, started);
+
+    let output = render_help_line_to_string(&app, 80, 1);
+    assert!(output.contains("Waiting for AI…"), "got {output}");
+    assert!(output.contains("Ctrl+X Cancel"), "got {output}");
+}
+
+#[test]
+fn test_help_line_hides_operations_that_just_started() {
+    use crate::progress::Operation;
+
+    let mut app = test_app("{}");
+    app.progress.update(Operation::Query, true);
+
+    let output = render_help_line_to_string(&app, 80, 1);
+    assert!(!output.contains("Running query"), "got {output}");
+}
+
+#[test]
+fn test_help_line_shows_a_changed_output_format() {
+    let mut app = test_app("{}");
+    app.focus = Focus::ResultsPane;
+    let query_state = app.query.as_mut().unwrap();
+    let mut format = query_state.output_format();
+    format.indent_more();
+    format.toggle_sort_keys();
+    query_state.set_output_format(format);
+
+    let output = render_help_line_to_string(&app, 130, 1);
+
+    assert!(
+        output
+            .trim_end_matches(['"', '\n'])
+            .ends_with("indent 3 · sort-keys "),
+        "{output}"
+    );
+    assert!(output.contains("Help"), "{output}");
+}
+
+#[test]
+fn test_help_line_hides_the_default_output_format() {
+    let mut app = test_app("{}");
+    app.focus = Focus::ResultsPane;
+
+    let output = render_help_line_to_string(&app, 130, 1);
+
+    assert!(!output.contains("indent"), "{output}");
+}
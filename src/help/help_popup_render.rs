@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::help::manual_render::render_manual;
 use crate::help::{HelpSection, HelpTab, get_tab_content};
 use crate::theme;
 use crate::widgets::{popup, scrollbar};
@@ -35,24 +36,31 @@ pub fn render_popup(app: &mut App, frame: &mut Frame) -> Option<Rect> {
     let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
     popup::clear_area(frame, popup_area);
 
+    let on_manual = app.help.active_tab == HelpTab::Manual;
+    let (title, hints): (&str, &[(&str, &str)]) = if on_manual {
+        (
+            " jq Manual ",
+            get_tab_content(HelpTab::Manual).sections[0].entries,
+        )
+    } else {
+        (
+            " Keyboard Shortcuts ",
+            &[
+                ("1-8", "Jump"),
+                ("Tab", "Next"),
+                ("h/l", "Switch"),
+                ("j/k", "Scroll"),
+                ("q", "Close"),
+            ],
+        )
+    };
+
     // Outer block with title and border
     let outer_block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .title(" Keyboard Shortcuts ")
-        .title_bottom(
-            theme::border_hints::build_hints(
-                &[
-                    ("1-7", "Jump"),
-                    ("Tab", "Next"),
-                    ("h/l", "Switch"),
-                    ("j/k", "Scroll"),
-                    ("q", "Close"),
-                ],
-                theme::help::border(),
-            )
-            .centered(),
-        )
+        .title(title)
+        .title_bottom(theme::border_hints::build_hints(hints, theme::help::border()).centered())
         .border_style(Style::default().fg(theme::help::border()))
         .style(Style::default().bg(theme::help::background()));
 
@@ -90,19 +98,32 @@ pub fn render_popup(app: &mut App, frame: &mut Frame) -> Option<Rect> {
     // Apply padding to content area
     let content_area = popup::inset_rect(chunks[2], HORIZONTAL_PADDING, VERTICAL_PADDING);
 
-    // Render content for active tab
-    let content = get_tab_content(app.help.active_tab);
-    let lines = render_help_sections(content.sections, content_area.width);
-
-    // Update scroll bounds for current tab
-    let content_height = lines.len() as u32;
-    let visible_height = content_area.height;
-    app.help
-        .current_scroll_mut()
-        .update_bounds(content_height, visible_height);
-
-    let paragraph = Paragraph::new(Text::from(lines)).scroll((app.help.current_scroll().offset, 0));
-    frame.render_widget(paragraph, content_area);
+    let content_height = if on_manual {
+        render_manual(app, frame, content_area)
+    } else {
+        // Render content for active tab
+        let content = get_tab_content(app.help.active_tab);
+        let mut sections = content.sections.to_vec();
+        if app.help.active_tab == HelpTab::Global {
+            if let Some(keyboard) = app.keyboard.help_section() {
+                sections.push(*keyboard);
+            }
+            sections.extend(crate::query::jq_binary::help_section());
+        }
+        let lines = render_help_sections(&sections, content_area.width);
+
+        // Update scroll bounds for current tab
+        let content_height = lines.len() as u32;
+        let visible_height = content_area.height;
+        app.help
+            .current_scroll_mut()
+            .update_bounds(content_height, visible_height);
+
+        let paragraph =
+            Paragraph::new(Text::from(lines)).scroll((app.help.current_scroll().offset, 0));
+        frame.render_widget(paragraph, content_area);
+        content_height
+    };
 
     // Render scrollbar on outer border (excluding corners), matching border color
     let scrollbar_area = Rect {
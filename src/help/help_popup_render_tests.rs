@@ -65,3 +65,26 @@ fn test_render_tab_bar_hover_same_as_active() {
     let content = line.to_string();
     assert!(content.contains("[1:Global]"));
 }
+
+fn global_help_bottom(keyboard: crate::keyboard::KeyboardSupport) -> String {
+    let mut app = crate::test_utils::test_helpers::test_app("{}");
+    app.keyboard = keyboard;
+    app.help.visible = true;
+    // The first render sets the scroll bounds
+    crate::headless::render_to_string(&mut app, 100, 40);
+    app.help.current_scroll_mut().jump_to_bottom();
+    crate::headless::render_to_string(&mut app, 100, 40)
+}
+
+#[test]
+fn test_global_help_shows_fallbacks_on_legacy_terminals() {
+    let screen = global_help_bottom(crate::keyboard::KeyboardSupport::Legacy);
+    assert!(screen.contains("this terminal can't report every modifier"));
+    assert!(screen.contains("Arrives as Enter: use Ctrl+Q or Alt+Enter"));
+}
+
+#[test]
+fn test_global_help_has_no_keyboard_section_without_a_terminal() {
+    let screen = global_help_bottom(crate::keyboard::KeyboardSupport::Unknown);
+    assert!(!screen.contains("KEYBOARD"));
+}
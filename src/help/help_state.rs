@@ -1,3 +1,4 @@
+use super::manual_state::ManualBrowser;
 use crate::scroll::ScrollState;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -10,10 +11,11 @@ pub enum HelpTab {
     AI,
     Search,
     Snippet,
+    Manual,
 }
 
 impl HelpTab {
-    pub const COUNT: usize = 7;
+    pub const COUNT: usize = 8;
 
     pub fn all() -> &'static [HelpTab] {
         &[
@@ -24,6 +26,7 @@ impl HelpTab {
             HelpTab::AI,
             HelpTab::Search,
             HelpTab::Snippet,
+            HelpTab::Manual,
         ]
     }
 
@@ -36,6 +39,7 @@ impl HelpTab {
             HelpTab::AI => 4,
             HelpTab::Search => 5,
             HelpTab::Snippet => 6,
+            HelpTab::Manual => 7,
         }
     }
 
@@ -48,6 +52,7 @@ impl HelpTab {
             4 => HelpTab::AI,
             5 => HelpTab::Search,
             6 => HelpTab::Snippet,
+            7 => HelpTab::Manual,
             _ => HelpTab::Global,
         }
     }
@@ -61,6 +66,7 @@ impl HelpTab {
             HelpTab::AI => "AI",
             HelpTab::Search => "Search",
             HelpTab::Snippet => "Snippet",
+            HelpTab::Manual => "Manual",
         }
     }
 
@@ -78,6 +84,8 @@ pub struct HelpPopupState {
     pub active_tab: HelpTab,
     scroll_per_tab: [ScrollState; HelpTab::COUNT],
     hovered_tab: Option<HelpTab>,
+    /// Table of contents and search of the Manual tab
+    pub manual: ManualBrowser,
 }
 
 impl HelpPopupState {
@@ -93,8 +101,10 @@ impl HelpPopupState {
                 ScrollState::new(),
                 ScrollState::new(),
                 ScrollState::new(),
+                ScrollState::new(),
             ],
             hovered_tab: None,
+            manual: ManualBrowser::new(),
         }
     }
 
@@ -189,6 +199,26 @@ impl HelpPopupState {
         for scroll in &mut self.scroll_per_tab {
             scroll.reset();
         }
+        self.manual.reset();
+    }
+
+    /// Open at `tab`, or close when already open
+    pub fn toggle(&mut self, tab: HelpTab) {
+        if self.visible {
+            self.reset();
+        } else {
+            self.active_tab = tab;
+            self.visible = true;
+        }
+    }
+
+    /// Open the Manual tab at the section documenting `name`, e.g. from
+    /// the detailed tooltip view
+    pub fn open_manual(&mut self, name: &str) {
+        self.visible = true;
+        self.active_tab = HelpTab::Manual;
+        self.manual.open_entry(name);
+        self.current_scroll_mut().reset();
     }
 }
 
@@ -13,9 +13,10 @@ fn test_new_help_state() {
 #[test]
 fn test_help_tab_all() {
     let tabs = HelpTab::all();
-    assert_eq!(tabs.len(), 7);
+    assert_eq!(tabs.len(), 8);
     assert_eq!(tabs[0], HelpTab::Global);
     assert_eq!(tabs[6], HelpTab::Snippet);
+    assert_eq!(tabs[7], HelpTab::Manual);
 }
 
 #[test]
@@ -27,6 +28,7 @@ fn test_help_tab_index() {
     assert_eq!(HelpTab::AI.index(), 4);
     assert_eq!(HelpTab::Search.index(), 5);
     assert_eq!(HelpTab::Snippet.index(), 6);
+    assert_eq!(HelpTab::Manual.index(), 7);
 }
 
 #[test]
@@ -38,6 +40,7 @@ fn test_help_tab_from_index() {
     assert_eq!(HelpTab::from_index(4), HelpTab::AI);
     assert_eq!(HelpTab::from_index(5), HelpTab::Search);
     assert_eq!(HelpTab::from_index(6), HelpTab::Snippet);
+    assert_eq!(HelpTab::from_index(7), HelpTab::Manual);
     // Out of bounds returns Global
     assert_eq!(HelpTab::from_index(100), HelpTab::Global);
 }
@@ -51,20 +54,22 @@ fn test_help_tab_name() {
     assert_eq!(HelpTab::AI.name(), "AI");
     assert_eq!(HelpTab::Search.name(), "Search");
     assert_eq!(HelpTab::Snippet.name(), "Snippet");
+    assert_eq!(HelpTab::Manual.name(), "Manual");
 }
 
 #[test]
 fn test_help_tab_next() {
     assert_eq!(HelpTab::Global.next(), HelpTab::Input);
     assert_eq!(HelpTab::Input.next(), HelpTab::Result);
-    assert_eq!(HelpTab::Snippet.next(), HelpTab::Global); // Wraps around
+    assert_eq!(HelpTab::Snippet.next(), HelpTab::Manual);
+    assert_eq!(HelpTab::Manual.next(), HelpTab::Global); // Wraps around
 }
 
 #[test]
 fn test_help_tab_prev() {
     assert_eq!(HelpTab::Input.prev(), HelpTab::Global);
     assert_eq!(HelpTab::Result.prev(), HelpTab::Input);
-    assert_eq!(HelpTab::Global.prev(), HelpTab::Snippet); // Wraps around
+    assert_eq!(HelpTab::Global.prev(), HelpTab::Manual); // Wraps around
 }
 
 #[test]
@@ -90,6 +95,22 @@ fn test_help_popup_state_current_scroll() {
     assert_eq!(state.current_scroll().offset, 5);
 }
 
+#[test]
+fn test_open_manual_shows_the_entry_section() {
+    let mut state = HelpPopupState::new();
+    state.active_tab = HelpTab::Manual;
+    state.current_scroll_mut().update_bounds(50, 20);
+    state.current_scroll_mut().scroll_down(10);
+
+    state.open_manual("limit");
+
+    assert!(state.visible);
+    assert_eq!(state.active_tab, HelpTab::Manual);
+    assert_eq!(state.current_scroll().offset, 0);
+    let section = state.manual.selected_section().unwrap();
+    assert!(section.entries.iter().any(|e| e.function == "limit"));
+}
+
 #[test]
 fn test_help_popup_state_reset() {
     let mut state = HelpPopupState::new();
@@ -113,6 +134,19 @@ fn test_help_popup_state_reset() {
     assert_eq!(state.get_hovered_tab(), None);
 }
 
+#[test]
+fn test_toggle_opens_at_tab_then_closes() {
+    let mut state = HelpPopupState::new();
+
+    state.toggle(HelpTab::History);
+    assert!(state.visible);
+    assert_eq!(state.active_tab, HelpTab::History);
+
+    state.toggle(HelpTab::Input);
+    assert!(!state.visible);
+    assert_eq!(state.active_tab, HelpTab::Global);
+}
+
 #[test]
 fn test_help_popup_hovered_tab() {
     let mut state = HelpPopupState::new();
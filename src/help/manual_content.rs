@@ -0,0 +1,66 @@
+//! Sections of the jq manual browsable from the help popup
+//!
+//! Built from the tooltip content: every function and operator names the
+//! jq manual heading that documents it, and entries sharing a heading form
+//! one section, e.g. `Dates` holds `strftime`, `now`, `todate` and friends.
+
+use std::sync::LazyLock;
+
+use crate::tooltip::{OPERATOR_CONTENT, TOOLTIP_CONTENT, TooltipContent};
+
+/// One jq manual heading and the builtins it documents
+pub struct ManualSection {
+    /// Heading in the jq manual, e.g. "limit(n; exp)" or "Dates"
+    pub title: &'static str,
+    pub entries: Vec<&'static TooltipContent>,
+}
+
+impl ManualSection {
+    /// Whether `query` appears in the heading or in an entry's name or
+    /// description, ignoring case; an empty query matches every section
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.title.to_lowercase().contains(&query)
+            || self.entries.iter().any(|entry| {
+                entry.function.to_lowercase().contains(&query)
+                    || entry.description.to_lowercase().contains(&query)
+            })
+    }
+}
+
+/// Manual sections in table-of-contents order
+pub static MANUAL_SECTIONS: LazyLock<Vec<ManualSection>> = LazyLock::new(|| {
+    let mut sections: Vec<ManualSection> = Vec::new();
+    for content in TOOLTIP_CONTENT.iter().copied().chain(OPERATOR_CONTENT) {
+        let Some(title) = content.manual else {
+            continue;
+        };
+        match sections.iter_mut().find(|section| section.title == title) {
+            Some(section) => section.entries.push(content),
+            None => sections.push(ManualSection {
+                title,
+                entries: vec![content],
+            }),
+        }
+    }
+    sections.sort_by_cached_key(|section| sort_key(section.title));
+    sections
+});
+
+/// Index in [`MANUAL_SECTIONS`] of the section documenting `name`
+pub fn section_index_for(name: &str) -> Option<usize> {
+    MANUAL_SECTIONS
+        .iter()
+        .position(|section| section.entries.iter().any(|entry| entry.function == name))
+}
+
+/// Alphabetical, ignoring case and leading symbols such as `$ENV`'s `$`
+fn sort_key(title: &str) -> String {
+    title
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+#[cfg(test)]
+#[path = "manual_content_tests.rs"]
+mod manual_content_tests;
@@ -0,0 +1,66 @@
+//! Tests for help/manual_content
+
+use super::*;
+
+fn section(title: &str) -> &'static ManualSection {
+    MANUAL_SECTIONS
+        .iter()
+        .find(|section| section.title == title)
+        .unwrap_or_else(|| panic!("no section titled {title}"))
+}
+
+#[test]
+fn test_every_documented_builtin_has_a_section() {
+    for content in TOOLTIP_CONTENT.iter().copied().chain(OPERATOR_CONTENT) {
+        if content.manual.is_some() {
+            assert!(
+                section_index_for(content.function).is_some(),
+                "{} missing from the manual",
+                content.function
+            );
+        }
+    }
+}
+
+#[test]
+fn test_entries_sharing_a_heading_form_one_section() {
+    let dates = section("Dates");
+    let names: Vec<_> = dates.entries.iter().map(|entry| entry.function).collect();
+    assert!(names.contains(&"strftime"));
+    assert!(names.contains(&"todate"));
+    assert_eq!(
+        MANUAL_SECTIONS
+            .iter()
+            .filter(|s| s.title == "Dates")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_sections_are_sorted_ignoring_case_and_symbols() {
+    let keys: Vec<_> = MANUAL_SECTIONS.iter().map(|s| sort_key(s.title)).collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(keys, sorted);
+}
+
+#[test]
+fn test_operators_have_sections() {
+    let index = section_index_for("//").unwrap();
+    assert_eq!(MANUAL_SECTIONS[index].title, "Alternative operator: //");
+}
+
+#[test]
+fn test_matches_title_name_and_description_ignoring_case() {
+    let dates = section("Dates");
+    assert!(dates.matches("dates"));
+    assert!(dates.matches("STRFTIME"));
+    assert!(dates.matches(""));
+    assert!(!dates.matches("no such builtin"));
+}
+
+#[test]
+fn test_unknown_name_has_no_section() {
+    assert_eq!(section_index_for("not_a_builtin"), None);
+}
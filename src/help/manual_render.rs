@@ -0,0 +1,150 @@
+//! Manual tab of the help popup: search box, table of contents and the
+//! selected section's entries
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::Paragraph,
+};
+
+use crate::app::App;
+use crate::help::{MANUAL_SECTIONS, ManualSection};
+use crate::str_utils::truncate_to_width;
+use crate::theme;
+use crate::tooltip::tooltip_render::reference_lines;
+
+/// Widest the table of contents gets
+const TOC_MAX_WIDTH: u16 = 30;
+/// Gap between the table of contents and the entries
+const COLUMN_GAP: u16 = 2;
+
+/// Render the Manual tab into `area`
+///
+/// Returns the height of the selected section's entries, which scroll with
+/// the tab's scroll state.
+pub fn render_manual(app: &mut App, frame: &mut Frame, area: Rect) -> u32 {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Search box
+            Constraint::Length(1), // Spacing
+            Constraint::Min(1),    // Table of contents and entries
+        ])
+        .split(area);
+    frame.render_widget(Paragraph::new(search_line(app)), rows[0]);
+
+    let toc_width = (rows[2].width / 3).min(TOC_MAX_WIDTH);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(toc_width),
+            Constraint::Length(COLUMN_GAP),
+            Constraint::Min(1),
+        ])
+        .split(rows[2]);
+
+    render_toc(app, frame, columns[0]);
+
+    let entry_area = columns[2];
+    let lines = match app.help.manual.selected_section() {
+        Some(section) => section_lines(section, entry_area.width as usize),
+        None => vec![Line::from(Span::styled(
+            format!("No manual sections match \"{}\"", app.help.manual.query()),
+            Style::default().fg(theme::help::footer()),
+        ))],
+    };
+    let content_height = lines.len() as u32;
+    let scroll = app.help.current_scroll_mut();
+    scroll.update_bounds(content_height, entry_area.height);
+    let offset = scroll.offset;
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((offset, 0)),
+        entry_area,
+    );
+
+    content_height
+}
+
+/// `Search: <query>` with a cursor while typing, or a prompt when idle
+fn search_line(app: &App) -> Line<'static> {
+    let manual = &app.help.manual;
+    let mut spans = vec![Span::styled("Search: ", theme::help::key())];
+    if manual.searching || !manual.query().is_empty() {
+        spans.push(Span::styled(
+            manual.query().to_string(),
+            Style::default().fg(theme::help::description()),
+        ));
+        if manual.searching {
+            spans.push(Span::styled("▏", theme::help::key()));
+        }
+    } else {
+        spans.push(Span::styled(
+            "press / to filter sections and builtins",
+            Style::default().fg(theme::help::footer()),
+        ));
+    }
+    spans.push(Span::styled(
+        format!(
+            "  {}/{} sections",
+            manual.match_count(),
+            MANUAL_SECTIONS.len()
+        ),
+        Style::default().fg(theme::help::footer()),
+    ));
+    Line::from(spans)
+}
+
+/// Section titles, scrolled to keep the selected one in view
+fn render_toc(app: &mut App, frame: &mut Frame, area: Rect) {
+    let manual = &mut app.help.manual;
+    let height = area.height as usize;
+    let selected = manual.selected();
+    if selected < manual.toc_offset {
+        manual.toc_offset = selected;
+    } else if height > 0 && selected >= manual.toc_offset + height {
+        manual.toc_offset = selected + 1 - height;
+    }
+
+    let width = area.width.saturating_sub(2) as usize;
+    let lines: Vec<Line> = manual
+        .sections()
+        .enumerate()
+        .skip(manual.toc_offset)
+        .take(height)
+        .map(|(row, section)| {
+            let title = truncate_to_width(section.title, width);
+            if row == selected {
+                Line::from(Span::styled(
+                    format!("▸ {}", title),
+                    theme::help::tab_active(),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {}", title),
+                    Style::default().fg(theme::help::description()),
+                ))
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// The section heading, then each builtin's name and reference
+fn section_lines(section: &ManualSection, width: usize) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        format!("── {} ──", section.title),
+        theme::help::section_header(),
+    ))];
+    for entry in &section.entries {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(entry.function, theme::help::key())));
+        lines.extend(reference_lines(entry, width));
+    }
+    lines
+}
+
+#[cfg(test)]
+#[path = "manual_render_tests.rs"]
+mod manual_render_tests;
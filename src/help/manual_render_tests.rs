@@ -0,0 +1,83 @@
+//! Tests for help/manual_render
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_manual_to_string(app: &mut App, width: u16, height: u16) -> (u32, String) {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut content_height = 0;
+    terminal
+        .draw(|f| {
+            let area = f.area();
+            content_height = render_manual(app, f, area);
+        })
+        .unwrap();
+    (content_height, terminal.backend().to_string())
+}
+
+#[test]
+fn snapshot_manual_open_at_entry() {
+    let mut app = test_app("{}");
+    app.help.open_manual("limit");
+
+    let (_, output) = render_manual_to_string(&mut app, 90, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_manual_searching() {
+    let mut app = test_app("{}");
+    app.help.open_manual("limit");
+    app.help.manual.searching = true;
+    for c in "date".chars() {
+        app.help.manual.push_char(c);
+    }
+
+    let (_, output) = render_manual_to_string(&mut app, 90, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_no_matches_shows_message() {
+    let mut app = test_app("{}");
+    app.help.open_manual("limit");
+    for c in "zqx".chars() {
+        app.help.manual.push_char(c);
+    }
+
+    let (content_height, output) = render_manual_to_string(&mut app, 90, 12);
+    assert_eq!(content_height, 1);
+    assert!(output.contains("No manual sections match \"zqx\""));
+    assert!(output.contains("0/"));
+}
+
+#[test]
+fn test_toc_scrolls_to_keep_selection_visible() {
+    let mut app = test_app("{}");
+    app.help.open_manual("");
+    for _ in 0..MANUAL_SECTIONS.len() {
+        app.help.manual.select_next();
+    }
+
+    let (_, output) = render_manual_to_string(&mut app, 90, 12);
+    let last = MANUAL_SECTIONS.last().unwrap().title;
+    assert!(app.help.manual.toc_offset > 0);
+    assert!(output.contains(&format!("▸ {}", truncate_to_width(last, 28))));
+}
+
+#[test]
+fn test_entry_scroll_bounds_follow_content_height() {
+    let mut app = test_app("{}");
+    app.help.open_manual("strftime");
+
+    let (content_height, _) = render_manual_to_string(&mut app, 90, 12);
+    assert!(content_height > 10);
+    assert_eq!(
+        app.help.current_scroll().max_offset as u32,
+        content_height - 10
+    );
+}
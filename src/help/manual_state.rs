@@ -0,0 +1,111 @@
+//! Table of contents and search box of the help popup's Manual tab
+
+use super::manual_content::{MANUAL_SECTIONS, ManualSection, section_index_for};
+
+pub struct ManualBrowser {
+    query: String,
+    /// Whether typed characters go to the search box
+    pub searching: bool,
+    /// Indices into [`MANUAL_SECTIONS`] matching the query
+    matches: Vec<usize>,
+    /// Position of the selected section in `matches`
+    selected: usize,
+    /// First table-of-contents row shown, kept by the renderer so the
+    /// selection stays visible
+    pub toc_offset: usize,
+}
+
+impl ManualBrowser {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            searching: false,
+            matches: (0..MANUAL_SECTIONS.len()).collect(),
+            selected: 0,
+            toc_offset: 0,
+        }
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Sections shown in the table of contents
+    pub fn sections(&self) -> impl Iterator<Item = &'static ManualSection> + '_ {
+        self.matches.iter().map(|&i| &MANUAL_SECTIONS[i])
+    }
+
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Row of the selected section in the table of contents
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_section(&self) -> Option<&'static ManualSection> {
+        self.matches
+            .get(self.selected)
+            .map(|&i| &MANUAL_SECTIONS[i])
+    }
+
+    /// Returns true when the selection moved
+    pub fn select_next(&mut self) -> bool {
+        if self.selected + 1 >= self.matches.len() {
+            return false;
+        }
+        self.selected += 1;
+        true
+    }
+
+    /// Returns true when the selection moved
+    pub fn select_prev(&mut self) -> bool {
+        if self.selected == 0 {
+            return false;
+        }
+        self.selected -= 1;
+        true
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    /// Show the section documenting `name`, clearing any search
+    pub fn open_entry(&mut self, name: &str) {
+        self.reset();
+        if let Some(index) = section_index_for(name) {
+            self.selected = index;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn refilter(&mut self) {
+        let query = self.query.trim();
+        self.matches = (0..MANUAL_SECTIONS.len())
+            .filter(|&i| MANUAL_SECTIONS[i].matches(query))
+            .collect();
+        self.selected = 0;
+        self.toc_offset = 0;
+    }
+}
+
+impl Default for ManualBrowser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "manual_state_tests.rs"]
+mod manual_state_tests;
@@ -0,0 +1,83 @@
+//! Tests for help/manual_state
+
+use super::*;
+
+#[test]
+fn test_new_lists_every_section() {
+    let browser = ManualBrowser::new();
+    assert_eq!(browser.match_count(), MANUAL_SECTIONS.len());
+    assert_eq!(browser.selected(), 0);
+    assert!(std::ptr::eq(
+        browser.selected_section().unwrap(),
+        &MANUAL_SECTIONS[0]
+    ));
+}
+
+#[test]
+fn test_select_next_and_prev_stop_at_the_ends() {
+    let mut browser = ManualBrowser::new();
+    assert!(!browser.select_prev());
+    assert!(browser.select_next());
+    assert_eq!(browser.selected(), 1);
+    assert!(browser.select_prev());
+    assert_eq!(browser.selected(), 0);
+
+    for _ in 0..MANUAL_SECTIONS.len() {
+        browser.select_next();
+    }
+    assert_eq!(browser.selected(), MANUAL_SECTIONS.len() - 1);
+    assert!(!browser.select_next());
+}
+
+#[test]
+fn test_typing_filters_sections() {
+    let mut browser = ManualBrowser::new();
+    browser.select_next();
+    for c in "strftime".chars() {
+        browser.push_char(c);
+    }
+    assert_eq!(browser.query(), "strftime");
+    assert_eq!(browser.selected(), 0);
+    assert!(browser.match_count() < MANUAL_SECTIONS.len());
+    assert!(
+        browser
+            .sections()
+            .all(|section| section.matches("strftime"))
+    );
+}
+
+#[test]
+fn test_backspace_widens_the_filter() {
+    let mut browser = ManualBrowser::new();
+    browser.push_char('z');
+    browser.push_char('q');
+    browser.push_char('x');
+    assert_eq!(browser.match_count(), 0);
+    assert!(browser.selected_section().is_none());
+
+    browser.pop_char();
+    browser.pop_char();
+    browser.pop_char();
+    assert_eq!(browser.match_count(), MANUAL_SECTIONS.len());
+}
+
+#[test]
+fn test_open_entry_selects_its_section_and_clears_search() {
+    let mut browser = ManualBrowser::new();
+    browser.searching = true;
+    browser.push_char('x');
+
+    browser.open_entry("strftime");
+    assert!(!browser.searching);
+    assert_eq!(browser.query(), "");
+    let section = browser.selected_section().unwrap();
+    assert!(section.entries.iter().any(|e| e.function == "strftime"));
+}
+
+#[test]
+fn test_open_unknown_entry_selects_first_section() {
+    let mut browser = ManualBrowser::new();
+    browser.select_next();
+    browser.open_entry("not_a_builtin");
+    assert_eq!(browser.selected(), 0);
+}
@@ -1,6 +1,9 @@
+mod cipher;
 pub mod history_events;
+pub mod history_preview;
 pub mod history_render;
 mod history_state;
+pub mod import;
 mod matcher;
 pub mod storage;
 
@@ -0,0 +1,139 @@
+//! Passphrase encryption for the history files
+//!
+//! An encrypted file is one line: [`HEADER`] followed by base64 of the salt,
+//! the nonce and the ChaCha20-Poly1305 ciphertext. The key comes from the
+//! passphrase through PBKDF2-HMAC-SHA256 and is cached per salt, since every
+//! history write reads the file back first.
+
+use std::io;
+use std::num::NonZeroU32;
+use std::sync::Mutex;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// Marks an encrypted file; plain history never starts with it
+pub const HEADER: &str = "jiq-encrypted-v1:";
+
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+const PBKDF2_ROUNDS: u32 = 100_000;
+
+/// A derived key with the passphrase and salt it came from
+struct CachedKey {
+    passphrase: String,
+    salt: [u8; SALT_LEN],
+    key: [u8; KEY_LEN],
+}
+
+/// The last key derived
+static KEY_CACHE: Mutex<Option<CachedKey>> = Mutex::new(None);
+
+pub fn is_encrypted(content: &str) -> bool {
+    content.starts_with(HEADER)
+}
+
+/// Encrypt `plaintext`, reusing the salt of the key derived last for the same
+/// passphrase so repeated writes skip the key derivation
+pub fn encrypt(plaintext: &str, passphrase: &str) -> io::Result<String> {
+    let rng = SystemRandom::new();
+    let cached_salt = KEY_CACHE.lock().ok().and_then(|cache| {
+        cache
+            .as_ref()
+            .filter(|cached| cached.passphrase == passphrase)
+            .map(|cached| cached.salt)
+    });
+    let salt = match cached_salt {
+        Some(salt) => salt,
+        None => {
+            let mut salt = [0u8; SALT_LEN];
+            rng.fill(&mut salt).map_err(|_| random_error())?;
+            salt
+        }
+    };
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce).map_err(|_| random_error())?;
+
+    let mut sealed = plaintext.as_bytes().to_vec();
+    key_for(passphrase, &salt)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut sealed,
+        )
+        .map_err(|_| io::Error::other("Could not encrypt history"))?;
+
+    let mut blob = Vec::with_capacity(SALT_LEN + NONCE_LEN + sealed.len());
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&sealed);
+    Ok(format!("{}{}\n", HEADER, STANDARD.encode(blob)))
+}
+
+/// Decrypt a file written by [`encrypt`]; a wrong passphrase or a damaged
+/// file is `PermissionDenied`
+pub fn decrypt(content: &str, passphrase: &str) -> io::Result<String> {
+    let encoded = content
+        .strip_prefix(HEADER)
+        .ok_or_else(|| unreadable("History file is not encrypted"))?;
+    let blob = STANDARD
+        .decode(encoded.trim())
+        .map_err(|_| unreadable("History file is damaged"))?;
+    if blob.len() < SALT_LEN + NONCE_LEN {
+        return Err(unreadable("History file is damaged"));
+    }
+    let (salt, rest) = blob.split_at(SALT_LEN);
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+    let salt: [u8; SALT_LEN] = salt.try_into().expect("split at SALT_LEN");
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| unreadable("History file is damaged"))?;
+
+    let mut sealed = sealed.to_vec();
+    let plain = key_for(passphrase, &salt)?
+        .open_in_place(nonce, Aad::empty(), &mut sealed)
+        .map_err(|_| unreadable("Wrong history passphrase"))?;
+    String::from_utf8(plain.to_vec()).map_err(|_| unreadable("History file is damaged"))
+}
+
+fn key_for(passphrase: &str, salt: &[u8; SALT_LEN]) -> io::Result<LessSafeKey> {
+    let mut cache = KEY_CACHE
+        .lock()
+        .map_err(|_| io::Error::other("Key cache poisoned"))?;
+    let key = match cache.as_ref() {
+        Some(cached) if cached.passphrase == passphrase && cached.salt == *salt => cached.key,
+        _ => {
+            let mut key = [0u8; KEY_LEN];
+            pbkdf2::derive(
+                pbkdf2::PBKDF2_HMAC_SHA256,
+                NonZeroU32::new(PBKDF2_ROUNDS).expect("non-zero rounds"),
+                salt,
+                passphrase.as_bytes(),
+                &mut key,
+            );
+            *cache = Some(CachedKey {
+                passphrase: passphrase.to_string(),
+                salt: *salt,
+                key,
+            });
+            key
+        }
+    };
+    let unbound = UnboundKey::new(&CHACHA20_POLY1305, &key)
+        .map_err(|_| io::Error::other("Could not build history key"))?;
+    Ok(LessSafeKey::new(unbound))
+}
+
+fn unreadable(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, message)
+}
+
+fn random_error() -> io::Error {
+    io::Error::other("No randomness available to encrypt history")
+}
+
+#[cfg(test)]
+#[path = "cipher_tests.rs"]
+mod cipher_tests;
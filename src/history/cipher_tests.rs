@@ -0,0 +1,42 @@
+//! Tests for history/cipher
+
+use super::*;
+
+#[test]
+fn test_round_trip() {
+    let sealed = encrypt(".token == \"s3cret\"\n.name\n", "correct horse").unwrap();
+    assert!(is_encrypted(&sealed));
+    assert!(!sealed.contains("s3cret"));
+    assert_eq!(
+        decrypt(&sealed, "correct horse").unwrap(),
+        ".token == \"s3cret\"\n.name\n"
+    );
+}
+
+#[test]
+fn test_each_write_uses_a_fresh_nonce() {
+    let first = encrypt(".a\n", "pass").unwrap();
+    let second = encrypt(".a\n", "pass").unwrap();
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_wrong_passphrase_is_rejected() {
+    let sealed = encrypt(".a\n", "right").unwrap();
+    let err = decrypt(&sealed, "wrong").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn test_damaged_file_is_rejected() {
+    let sealed = encrypt(".a\n", "pass").unwrap();
+    let damaged = sealed.replacen(HEADER, &format!("{}AAAA", HEADER), 1);
+    assert!(decrypt(&damaged, "pass").is_err());
+    assert!(decrypt(&format!("{}!!", HEADER), "pass").is_err());
+}
+
+#[test]
+fn test_plain_text_is_not_encrypted() {
+    assert!(!is_encrypted(".name\n.age\n"));
+    assert!(decrypt(".name\n", "pass").is_err());
+}
@@ -13,12 +13,35 @@ pub fn handle_history_popup_key(app: &mut App, key: KeyEvent) {
         }
 
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.history.delete_selected();
-            if app.history.total_count() == 0 {
-                app.history.close();
+            delete_selected(app);
+        }
+        KeyCode::Delete => {
+            delete_selected(app);
+        }
+
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match app.history.toggle_pin_selected() {
+                Some(true) => app.notification.show("Pinned"),
+                Some(false) => app.notification.show("Unpinned"),
+                None => {}
             }
         }
 
+        // Ctrl+Space, which terminals send as NUL or as a space with
+        // Control; a plain space goes to the search
+        KeyCode::Null => {
+            app.history.toggle_mark_selected();
+        }
+        KeyCode::Char(' ') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.history.toggle_mark_selected();
+        }
+
+        KeyCode::Enter | KeyCode::Tab if app.history.marked_count() > 0 => {
+            let pipeline = app.history.take_marked().join(" | ");
+            replace_query_with(app, &pipeline);
+            app.history.close();
+        }
+
         KeyCode::Enter | KeyCode::Tab => {
             if let Some(entry) = app.history.selected_entry() {
                 let entry = entry.to_string();
@@ -40,6 +63,13 @@ pub fn handle_history_popup_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn delete_selected(app: &mut App) {
+    app.history.delete_selected();
+    if app.history.total_count() == 0 {
+        app.history.close();
+    }
+}
+
 fn replace_query_with(app: &mut App, text: &str) {
     app.input.textarea.delete_line_by_head();
     app.input.textarea.delete_line_by_end();
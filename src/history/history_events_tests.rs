@@ -373,3 +373,85 @@ fn test_backspace_on_empty_search() {
     app.handle_key_event(key(KeyCode::Backspace));
     assert_eq!(app.history.search_query(), "");
 }
+
+#[test]
+fn test_delete_key_deletes_selected_entry() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.history.add_entry_in_memory(".keep");
+    app.history.add_entry_in_memory(".delete_me");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    app.handle_key_event(key(KeyCode::Delete));
+
+    assert_eq!(app.history.total_count(), 1);
+    assert_eq!(app.history.selected_entry(), Some(".keep"));
+}
+
+#[test]
+fn test_ctrl_p_toggles_pin_on_selected_entry() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.history.add_entry_in_memory(".pin_me");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('p'), KeyModifiers::CONTROL));
+
+    assert!(app.history.is_visible());
+    assert!(app.history.is_pinned(".pin_me"));
+    assert_eq!(app.notification.current_message(), Some("Pinned"));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('p'), KeyModifiers::CONTROL));
+    assert!(!app.history.is_pinned(".pin_me"));
+}
+
+#[test]
+fn test_history_marked_entries_insert_as_one_pipeline() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.history.add_entry_in_memory("select(.active)");
+    app.history.add_entry_in_memory(".name");
+    app.history.add_entry_in_memory(".users[]");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    let mark = key_with_mods(KeyCode::Char(' '), KeyModifiers::CONTROL);
+    app.handle_key_event(mark);
+    app.handle_key_event(key(KeyCode::Up));
+    app.handle_key_event(key(KeyCode::Up));
+    app.handle_key_event(mark);
+    app.handle_key_event(key(KeyCode::Down));
+    app.handle_key_event(mark);
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.history.is_visible());
+    assert_eq!(app.query(), ".users[] | select(.active) | .name");
+}
+
+#[test]
+fn test_history_space_types_into_the_search() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.history.add_entry_in_memory(".a | length");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    for c in "| length".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+
+    assert_eq!(app.history.search_query(), "| length");
+    assert_eq!(app.history.marked_count(), 0);
+}
+
+#[test]
+fn test_history_ctrl_space_sent_as_nul_marks() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.history.add_entry_in_memory(".a");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    app.handle_key_event(key(KeyCode::Null));
+
+    assert_eq!(app.history.marked_count(), 1);
+}
@@ -0,0 +1,176 @@
+//! Preview of what the entry highlighted in the history popup returns
+//!
+//! Once navigation pauses, the highlighted entry runs in the background
+//! against the current input and the popup shows the start of its output
+//! beside the list. Results are kept until the popup closes, so going back
+//! to an entry shows it at once, and the entry that matches the query on
+//! screen reuses the result already in the results pane. Controlled by
+//! `preview` in the `[history]` config section.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::app::App;
+use crate::autocomplete::suggestion_preview::error_summary;
+use crate::query::debounced_preview::DebouncedPreview;
+use crate::query::executor::JqExecutor;
+use crate::query::output_format::OutputFormat;
+use crate::query::worker::preprocess::strip_ansi_codes;
+use crate::query::worker::types::QueryError;
+
+/// Output lines kept for display
+const MAX_PREVIEW_LINES: usize = 100;
+/// Characters of each line kept for display
+const MAX_LINE_CHARS: usize = 500;
+
+/// What a previewed entry returned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewText {
+    /// The first lines of its output
+    Lines(Vec<String>),
+    /// The entry outputs nothing
+    Empty,
+    /// The entry fails with this message
+    Error(String),
+}
+
+/// Background runs of highlighted entries and the results so far
+pub struct HistoryPreview {
+    enabled: bool,
+    runner: DebouncedPreview<PreviewText>,
+    /// Results by entry, for the popup currently open
+    results: HashMap<String, PreviewText>,
+}
+
+impl HistoryPreview {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            runner: DebouncedPreview::new(),
+            results: HashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The result for the highlighted entry, once there is one
+    pub fn text(&self) -> Option<&PreviewText> {
+        self.results.get(self.runner.query()?)
+    }
+
+    /// Preview `query` from now on, or nothing with `None`
+    ///
+    /// A different query cancels the run in flight; it only runs itself
+    /// when its result isn't already known.
+    pub fn set_query(&mut self, query: Option<String>, now: Instant) {
+        if self.runner.set_query(query, now) && self.text().is_some() {
+            self.runner.stop();
+        }
+    }
+
+    /// Use `output` as the result of `query` without running it
+    pub fn insert(&mut self, query: &str, output: &str) {
+        self.results
+            .entry(query.to_string())
+            .or_insert_with(|| preview_text(output));
+    }
+
+    /// Forget every result, when the popup closes. Returns true when
+    /// there was anything to forget.
+    pub fn clear(&mut self) -> bool {
+        self.runner.set_query(None, Instant::now());
+        let had_results = !self.results.is_empty();
+        self.results.clear();
+        had_results
+    }
+
+    /// Run the pending entry once due with `format` and pick up its
+    /// result. Returns true when a result arrived.
+    pub fn poll(&mut self, executor: &JqExecutor, format: OutputFormat, now: Instant) -> bool {
+        let run = move |executor: &JqExecutor, query: &str, cancel: &CancellationToken| {
+            run_entry(executor, query, format, cancel)
+        };
+        let Some(text) = self.runner.poll(executor, now, run) else {
+            return false;
+        };
+        if let Some(query) = self.runner.query() {
+            self.results.insert(query.to_string(), text);
+        }
+        true
+    }
+}
+
+/// Run `query` for the start of its output; `None` once cancelled
+fn run_entry(
+    executor: &JqExecutor,
+    query: &str,
+    format: OutputFormat,
+    cancel: &CancellationToken,
+) -> Option<PreviewText> {
+    Some(
+        match executor.execute_streaming(query, format, cancel, |_| {}) {
+            Ok((output, _)) => preview_text(&strip_ansi_codes(&output)),
+            Err(QueryError::Cancelled) => return None,
+            Err(QueryError::ExecutionFailed(stderr)) => PreviewText::Error(error_summary(&stderr)),
+            Err(e) => PreviewText::Error(e.to_string()),
+        },
+    )
+}
+
+/// Keep the preview in step with the popup selection and collect results
+///
+/// Returns true when the preview changed and needs redrawing.
+pub fn poll_history_preview(app: &mut App) -> bool {
+    if !app.history_preview.is_enabled() {
+        return false;
+    }
+    if !app.history.is_visible() {
+        return app.history_preview.clear();
+    }
+    let now = Instant::now();
+    let query = app.history.selected_entry().map(str::to_string);
+    let Some(query_state) = &app.query else {
+        return false;
+    };
+
+    // The query on screen already has its result
+    if let Some(query) = &query
+        && query == app.input.query()
+        && !query_state.is_pending()
+        && query_state.result.is_ok()
+        && query_state.truncated_query.is_none()
+        && let Some(output) = &query_state.last_successful_result_unformatted
+    {
+        app.history_preview.insert(query, output);
+    }
+    let had_text = app.history_preview.text().is_some();
+    app.history_preview.set_query(query, now);
+    let changed = had_text != app.history_preview.text().is_some();
+    app.history_preview
+        .poll(&query_state.executor, query_state.output_format(), now)
+        || changed
+}
+
+/// The first [`MAX_PREVIEW_LINES`] lines of `output`
+fn preview_text(output: &str) -> PreviewText {
+    if output.trim().is_empty() {
+        return PreviewText::Empty;
+    }
+    let lines = output
+        .lines()
+        .take(MAX_PREVIEW_LINES)
+        .map(|line| match line.char_indices().nth(MAX_LINE_CHARS) {
+            Some((end, _)) => format!("{}…", &line[..end]),
+            None => line.to_string(),
+        })
+        .collect();
+    PreviewText::Lines(lines)
+}
+
+#[cfg(test)]
+#[path = "history_preview_tests.rs"]
+mod history_preview_tests;
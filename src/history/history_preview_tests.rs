@@ -0,0 +1,122 @@
+//! Tests for history_preview
+
+use super::*;
+use crate::query::debounced_preview::PREVIEW_DELAY;
+use crate::test_utils::test_helpers::test_app;
+use std::time::Duration;
+
+const JSON: &str = r#"{"name": "Alice", "tags": ["a", "b"]}"#;
+
+/// Poll until a result arrives, as the event loop would
+fn wait_for_text(preview: &mut HistoryPreview, executor: &JqExecutor) -> PreviewText {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        if preview.poll(executor, OutputFormat::default(), Instant::now()) {
+            return preview.text().cloned().unwrap();
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("preview did not arrive");
+}
+
+#[test]
+fn test_preview_text_keeps_the_first_lines() {
+    let output: String = (0..150).map(|i| format!("{}\n", i)).collect();
+    let PreviewText::Lines(lines) = preview_text(&output) else {
+        panic!("expected lines");
+    };
+    assert_eq!(lines.len(), MAX_PREVIEW_LINES);
+    assert_eq!(lines[0], "0");
+}
+
+#[test]
+fn test_preview_text_of_no_output_is_empty() {
+    assert_eq!(preview_text("\n"), PreviewText::Empty);
+}
+
+#[test]
+fn test_highlighted_entry_runs_after_the_delay() {
+    let executor = JqExecutor::new(JSON.to_string());
+    let mut preview = HistoryPreview::new(true);
+    preview.set_query(Some(".tags".to_string()), Instant::now() - PREVIEW_DELAY);
+
+    assert_eq!(
+        wait_for_text(&mut preview, &executor),
+        PreviewText::Lines(vec![
+            "[".to_string(),
+            "  \"a\",".to_string(),
+            "  \"b\"".to_string(),
+            "]".to_string(),
+        ])
+    );
+}
+
+#[test]
+fn test_failing_entry_shows_jq_error() {
+    let executor = JqExecutor::new(JSON.to_string());
+    let mut preview = HistoryPreview::new(true);
+    preview.set_query(
+        Some(".name | keys".to_string()),
+        Instant::now() - PREVIEW_DELAY,
+    );
+
+    assert!(matches!(
+        wait_for_text(&mut preview, &executor),
+        PreviewText::Error(_)
+    ));
+}
+
+#[test]
+fn test_returning_to_an_entry_shows_its_result_at_once() {
+    let mut preview = HistoryPreview::new(true);
+    preview.insert(".name", "\"Alice\"\n");
+    preview.set_query(Some(".tags".to_string()), Instant::now());
+    assert!(preview.text().is_none());
+
+    preview.set_query(Some(".name".to_string()), Instant::now());
+    assert_eq!(
+        preview.text(),
+        Some(&PreviewText::Lines(vec!["\"Alice\"".to_string()]))
+    );
+    assert!(!preview.runner.is_due());
+}
+
+#[test]
+fn test_results_are_forgotten_when_the_popup_closes() {
+    let mut app = test_app(JSON);
+    app.history.add_entry_in_memory(".name");
+    app.history.open(None);
+    app.history_preview.insert(".name", "\"Alice\"\n");
+    poll_history_preview(&mut app);
+    assert!(app.history_preview.text().is_some());
+
+    app.history.close();
+    assert!(poll_history_preview(&mut app));
+    assert!(app.history_preview.text().is_none());
+}
+
+#[test]
+fn test_entry_matching_the_query_on_screen_reuses_its_result() {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str(".name");
+    app.query.as_mut().unwrap().execute(".name");
+    app.history.add_entry_in_memory(".name");
+    app.history.open(None);
+
+    assert!(poll_history_preview(&mut app));
+    assert_eq!(
+        app.history_preview.text(),
+        Some(&PreviewText::Lines(vec!["\"Alice\"".to_string()]))
+    );
+}
+
+#[test]
+fn test_disabled_preview_never_runs() {
+    let mut app = test_app(JSON);
+    app.history_preview = HistoryPreview::new(false);
+    app.history.add_entry_in_memory(".name");
+    app.history.open(None);
+
+    assert!(!poll_history_preview(&mut app));
+    assert!(app.history_preview.runner.query().is_none());
+}
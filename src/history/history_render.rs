@@ -3,11 +3,12 @@ use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
 };
 
 use crate::app::App;
 use crate::history::MAX_VISIBLE_HISTORY;
+use crate::history::history_preview::PreviewText;
 use crate::scroll::Scrollable;
 use crate::syntax_highlight::JqHighlighter;
 use crate::theme;
@@ -18,6 +19,21 @@ pub const HISTORY_SEARCH_HEIGHT: u16 = 3;
 /// Width of the trailing ` [✕] ` button column rendered when an entry is hovered or selected.
 const DELETE_BUTTON_WIDTH: u16 = 5;
 
+/// Tag shown before the delete column on entries accepted from AI suggestions.
+const AI_MARKER: &str = " AI ";
+const AI_MARKER_WIDTH: usize = 4;
+
+/// Tag shown before the delete column on pinned entries, which never age out.
+const PIN_MARKER: &str = " ● ";
+const PIN_MARKER_WIDTH: usize = 3;
+
+/// Narrowest the list gets beside the preview, which keeps its key hints whole
+const MIN_LIST_WIDTH: u16 = 78;
+/// Narrowest preview worth showing
+const MIN_PREVIEW_WIDTH: u16 = 30;
+/// Share of the popup's width the list and search box keep beside the preview
+const LIST_PERCENT: u16 = 55;
+
 /// Render the history popup
 ///
 /// Returns the popup area for region tracking.
@@ -38,20 +54,29 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
 
     popup::clear_area(frame, popup_area);
 
+    let (list_column, preview_area) = columns(app, popup_area);
     let layout = Layout::vertical([
         Constraint::Min(3),                        // History list
         Constraint::Length(HISTORY_SEARCH_HEIGHT), // Search box
     ])
-    .split(popup_area);
+    .split(list_column);
 
     let list_area = layout[0];
     let search_area = layout[1];
 
-    let title = format!(
-        " History ({}/{}) ",
-        app.history.filtered_count(),
-        app.history.total_count()
-    );
+    let title = match app.history.marked_count() {
+        0 => format!(
+            " History ({}/{}) ",
+            app.history.filtered_count(),
+            app.history.total_count()
+        ),
+        marked => format!(
+            " History ({}/{}) · {} marked ",
+            app.history.filtered_count(),
+            app.history.total_count(),
+            marked
+        ),
+    };
 
     // Reserve space for the right-edge delete button on top of the existing
     // 6-cell padding (border + indicator + trailing space).
@@ -77,8 +102,16 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
         let hovered_index = app.history.hovered_index();
 
         for (display_idx, entry) in app.history.visible_entries() {
-            let display_text = if entry.chars().count() > max_text_len {
-                let truncated: String = entry.chars().take(max_text_len).collect();
+            let is_ai = app.history.is_ai_originated(entry);
+            let is_pinned = app.history.is_pinned(entry);
+            // Marked entries are numbered in the order they join the pipeline
+            let mark = app.history.mark_number(entry).map(|n| format!(" ✓{} ", n));
+            let marker_width = if is_ai { AI_MARKER_WIDTH } else { 0 }
+                + if is_pinned { PIN_MARKER_WIDTH } else { 0 }
+                + mark.as_ref().map_or(0, |m| m.chars().count());
+            let text_limit = max_text_len.saturating_sub(marker_width);
+            let display_text = if entry.chars().count() > text_limit {
+                let truncated: String = entry.chars().take(text_limit).collect();
                 format!("{}…", truncated)
             } else {
                 entry.to_string()
@@ -129,10 +162,35 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
             let trailing_btn_width = DELETE_BUTTON_WIDTH as usize;
             let pad = inner_width
                 .saturating_sub(used)
+                .saturating_sub(marker_width)
                 .saturating_sub(trailing_btn_width);
             if pad > 0 {
                 spans.push(Span::styled(" ".repeat(pad), Style::default().bg(bg_color)));
             }
+            if let Some(mark) = mark {
+                spans.push(Span::styled(
+                    mark,
+                    Style::default()
+                        .fg(theme::history::item_selected_indicator())
+                        .bg(bg_color),
+                ));
+            }
+            if is_pinned {
+                spans.push(Span::styled(
+                    PIN_MARKER,
+                    Style::default()
+                        .fg(theme::history::pin_marker())
+                        .bg(bg_color),
+                ));
+            }
+            if is_ai {
+                spans.push(Span::styled(
+                    AI_MARKER,
+                    Style::default()
+                        .fg(theme::history::ai_marker())
+                        .bg(bg_color),
+                ));
+            }
 
             // Reserve the column even when invisible so the row layout is
             // stable as hover toggles on and off.
@@ -161,7 +219,13 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
     };
 
     let bottom_hints = theme::border_hints::build_hints(
-        &[("Enter", "Select"), ("Ctrl+D", "Delete"), ("Esc", "Close")],
+        &[
+            ("Enter", "Select"),
+            ("Ctrl+Space", "Mark"),
+            ("Ctrl+P", "Pin"),
+            ("Ctrl+D", "Delete"),
+            ("Esc", "Close"),
+        ],
         theme::history::border(),
     );
 
@@ -213,15 +277,68 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
     );
     frame.render_widget(&*search_textarea, search_area);
 
+    if let Some(preview_area) = preview_area {
+        render_preview(app, frame, preview_area);
+    }
+
     Some(popup_area)
 }
 
+/// The popup split into the list with its search box and, when it's on
+/// and there's room, the preview beside them
+fn columns(app: &App, popup: Rect) -> (Rect, Option<Rect>) {
+    if !app.history_preview.is_enabled()
+        || app.accessibility.linear_layout
+        || popup.width < MIN_LIST_WIDTH + MIN_PREVIEW_WIDTH
+    {
+        return (popup, None);
+    }
+    let list_width = (popup.width * LIST_PERCENT / 100).max(MIN_LIST_WIDTH);
+    let layout =
+        Layout::horizontal([Constraint::Length(list_width), Constraint::Min(0)]).split(popup);
+    (layout[0], Some(layout[1]))
+}
+
+/// The part of the popup `popup` with the list and search box
+pub fn list_column(app: &App, popup: Rect) -> Rect {
+    columns(app, popup).0
+}
+
+fn render_preview(app: &App, frame: &mut Frame, area: Rect) {
+    let muted = Style::default().fg(theme::history::no_matches());
+    let lines: Vec<Line> = match app.history_preview.text() {
+        _ if app.history.filtered_count() == 0 => Vec::new(),
+        None => vec![Line::from(Span::styled("Running…", muted))],
+        Some(PreviewText::Empty) => vec![Line::from(Span::styled("No output", muted))],
+        Some(PreviewText::Error(message)) => vec![Line::from(Span::styled(
+            message.clone(),
+            Style::default().fg(theme::palette::error()),
+        ))],
+        Some(PreviewText::Lines(lines)) => lines
+            .iter()
+            .map(|line| {
+                Line::from(Span::styled(
+                    line.clone(),
+                    Style::default().fg(theme::history::item_normal_fg()),
+                ))
+            })
+            .collect(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Preview ")
+        .border_style(Style::default().fg(theme::history::border()))
+        .style(Style::default().bg(theme::history::background()));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 /// Resolves the display index for an entry at screen position `(x, y)`.
 ///
 /// Returns `None` when the cursor is on padding rows, the search area, or
 /// outside the popup. Display index matches [`HistoryState::visible_entries`].
 pub fn display_index_at(app: &App, x: u16, y: u16) -> Option<usize> {
-    let popup = app.layout_regions.history_popup?;
+    let popup = list_column(app, app.layout_regions.history_popup?);
     if x < popup.x || x >= popup.x + popup.width {
         return None;
     }
@@ -245,7 +362,7 @@ pub fn display_index_at(app: &App, x: u16, y: u16) -> Option<usize> {
 
 /// Returns the display index whose ` [✕] ` delete button is at `(x, y)`.
 pub fn delete_button_at(app: &App, x: u16, y: u16) -> Option<usize> {
-    let popup = app.layout_regions.history_popup?;
+    let popup = list_column(app, app.layout_regions.history_popup?);
     let display_idx = display_index_at(app, x, y)?;
 
     let inner_right = popup.x.saturating_add(popup.width).saturating_sub(1);
@@ -116,3 +116,14 @@ fn test_delete_button_at_padding_row_returns_none() {
     // Top padding row, even on the button column.
     assert_eq!(delete_button_at(&app, 76, 1), None);
 }
+
+#[test]
+fn test_delete_button_at_sits_left_of_the_preview() {
+    let mut app = app_with_open_history(&[".only"]);
+    app.layout_regions.history_popup = Some(Rect::new(0, 0, 140, 10));
+
+    // The list keeps 55% of 140 = 77, widened to 78; its button column is [72, 77)
+    assert_eq!(delete_button_at(&app, 74, 2), Some(0));
+    assert_eq!(delete_button_at(&app, 134, 2), None);
+    assert_eq!(display_index_at(&app, 100, 2), None);
+}
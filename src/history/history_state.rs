@@ -1,12 +1,18 @@
+use std::collections::HashSet;
+
 use ratatui::style::{Modifier, Style};
 use tui_textarea::TextArea;
 
 use super::matcher::HistoryMatcher;
-use super::storage;
+use super::storage::{self, StorageOptions};
+use crate::config::HistoryConfig;
 use crate::scroll::Scrollable;
 
 pub const MAX_VISIBLE_HISTORY: usize = 15;
 
+/// Environment variable holding the passphrase for `encrypt = true`
+pub const PASSPHRASE_ENV: &str = "JIQ_HISTORY_PASSPHRASE";
+
 fn create_search_textarea() -> TextArea<'static> {
     let mut textarea = TextArea::default();
     textarea.set_cursor_line_style(Style::default());
@@ -16,6 +22,11 @@ fn create_search_textarea() -> TextArea<'static> {
 
 pub struct HistoryState {
     entries: Vec<String>,
+    /// Entries that were accepted from an AI suggestion.
+    ai_originated: HashSet<String>,
+    /// Entries kept however old they get.
+    pinned: HashSet<String>,
+    max_entries: usize,
     filtered_indices: Vec<usize>,
     search_textarea: TextArea<'static>,
     selected_index: usize,
@@ -23,39 +34,69 @@ pub struct HistoryState {
     visible: bool,
     matcher: HistoryMatcher,
     persist_to_disk: bool,
+    /// Private mode: queries run now are not recorded.
+    private: bool,
+    /// Why the history on disk could not be used this session.
+    load_warning: Option<String>,
     cycling_index: Option<usize>,
     hovered_index: Option<usize>,
+    /// Entries marked with Ctrl+Space in the popup, in the order marked
+    marked: Vec<String>,
 }
 
 impl Default for HistoryState {
     fn default() -> Self {
-        Self::new()
+        Self::new(&HistoryConfig::default())
     }
 }
 
 impl HistoryState {
-    pub fn new() -> Self {
-        let entries = storage::load_history();
-        let filtered_indices = (0..entries.len()).collect();
+    /// History loaded from disk as `config` says to store it
+    ///
+    /// When the files can't be read, encrypted without the right passphrase,
+    /// history stays in memory for the session and [`load_warning`] says why.
+    ///
+    /// [`load_warning`]: Self::load_warning
+    pub fn new(config: &HistoryConfig) -> Self {
+        let passphrase = std::env::var(PASSPHRASE_ENV)
+            .ok()
+            .filter(|p| config.encrypt && !p.is_empty());
+        storage::configure(StorageOptions {
+            restrict_permissions: config.restrict_permissions,
+            passphrase: passphrase.clone(),
+        });
+
+        let mut state = Self::empty();
+        state.max_entries = config.max_entries;
+        if config.encrypt && passphrase.is_none() {
+            state.load_warning = Some(format!(
+                "History is off: set {} to use encrypted history",
+                PASSPHRASE_ENV
+            ));
+            return state;
+        }
 
-        Self {
-            entries,
-            filtered_indices,
-            search_textarea: create_search_textarea(),
-            selected_index: 0,
-            scroll_offset: 0,
-            visible: false,
-            matcher: HistoryMatcher::new(),
-            persist_to_disk: true,
-            cycling_index: None,
-            hovered_index: None,
+        match storage::try_load_history() {
+            Ok(entries) => {
+                state.pinned = storage::load_pins();
+                state.entries = storage::prune(&entries, config.max_entries, &state.pinned);
+                state.filtered_indices = (0..state.entries.len()).collect();
+                state.ai_originated = storage::load_ai_marks();
+                state.persist_to_disk = true;
+            }
+            Err(e) => {
+                state.load_warning = Some(format!("History is off for this session: {}", e));
+            }
         }
+        state
     }
 
-    #[cfg(test)]
     pub fn empty() -> Self {
         Self {
             entries: Vec::new(),
+            ai_originated: HashSet::new(),
+            pinned: HashSet::new(),
+            max_entries: storage::MAX_HISTORY_ENTRIES,
             filtered_indices: Vec::new(),
             search_textarea: create_search_textarea(),
             selected_index: 0,
@@ -63,11 +104,28 @@ impl HistoryState {
             visible: false,
             matcher: HistoryMatcher::new(),
             persist_to_disk: false,
+            private: false,
+            load_warning: None,
             cycling_index: None,
             hovered_index: None,
+            marked: Vec::new(),
         }
     }
 
+    /// Why history on disk is unavailable this session, if it is
+    pub fn load_warning(&self) -> Option<&str> {
+        self.load_warning.as_deref()
+    }
+
+    /// Stop or resume recording queries for the rest of the session
+    pub fn set_private(&mut self, private: bool) {
+        self.private = private;
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.private
+    }
+
     #[cfg(test)]
     pub fn add_entry_in_memory(&mut self, query: &str) {
         if query.trim().is_empty() {
@@ -79,6 +137,11 @@ impl HistoryState {
         self.filtered_indices = (0..self.entries.len()).collect();
     }
 
+    #[cfg(test)]
+    pub fn set_max_entries(&mut self, max_entries: usize) {
+        self.max_entries = max_entries;
+    }
+
     pub fn open(&mut self, initial_query: Option<&str>) {
         self.visible = true;
         // Clear existing text and set initial query
@@ -99,6 +162,7 @@ impl HistoryState {
         self.selected_index = 0;
         self.scroll_offset = 0;
         self.hovered_index = None;
+        self.marked.clear();
         self.filtered_indices = (0..self.entries.len()).collect();
     }
 
@@ -199,14 +263,17 @@ impl HistoryState {
         entries.into_iter().rev()
     }
 
+    /// Record `query` as the most recent entry, moving an earlier copy of
+    /// it to the front and pruning the oldest unpinned entries past the cap.
     pub fn add_entry(&mut self, query: &str) {
-        if query.trim().is_empty() {
+        let query = query.trim();
+        if query.is_empty() || self.private {
             return;
         }
 
         // Only persist to disk if enabled (disabled for tests)
         if self.persist_to_disk
-            && let Err(e) = storage::add_entry(query)
+            && let Err(e) = storage::add_entry(query, self.max_entries)
         {
             eprintln!("Warning: Failed to save query history to disk: {}", e);
             eprintln!("History will work for this session only.");
@@ -215,10 +282,119 @@ impl HistoryState {
 
         self.entries.retain(|e| e != query);
         self.entries.insert(0, query.to_string());
+        self.entries = storage::prune(&self.entries, self.max_entries, &self.pinned);
 
         self.filtered_indices = (0..self.entries.len()).collect();
     }
 
+    /// Add `queries` (newest first) behind the existing entries, skipping
+    /// those already recorded. Returns how many were added.
+    ///
+    /// Imports are kept even in private mode, since they were asked for.
+    pub fn import(&mut self, queries: &[String]) -> usize {
+        let known: HashSet<&str> = self.entries.iter().map(String::as_str).collect();
+        let new: Vec<String> = queries
+            .iter()
+            .map(|q| q.trim())
+            .filter(|q| !q.is_empty() && !known.contains(q))
+            .map(str::to_string)
+            .collect();
+        if new.is_empty() {
+            return 0;
+        }
+
+        if self.persist_to_disk
+            && let Err(e) = storage::append_entries(&new, self.max_entries)
+        {
+            eprintln!("Warning: Failed to save imported history to disk: {}", e);
+        }
+
+        let before = self.entries.len();
+        self.entries.extend(new);
+        self.entries = storage::prune(&self.entries, self.max_entries, &self.pinned);
+        self.cycling_index = None;
+        self.update_filter();
+        self.entries.len().saturating_sub(before)
+    }
+
+    /// Record a query accepted from an AI suggestion and mark it as such.
+    ///
+    /// The mark sticks when the same query is later re-added by the exit-time
+    /// `add_entry`, so accepting a suggestion and pressing Enter keeps it marked.
+    pub fn add_ai_entry(&mut self, query: &str) {
+        let query = query.trim();
+        if query.is_empty() || self.private {
+            return;
+        }
+
+        self.add_entry(query);
+        if self.persist_to_disk
+            && let Err(e) = storage::add_ai_mark(query)
+        {
+            eprintln!("Warning: Failed to save AI history mark to disk: {}", e);
+        }
+        self.ai_originated.insert(query.to_string());
+    }
+
+    pub fn is_ai_originated(&self, query: &str) -> bool {
+        self.ai_originated.contains(query)
+    }
+
+    pub fn is_pinned(&self, query: &str) -> bool {
+        self.pinned.contains(query)
+    }
+
+    /// Pin the entry selected in the popup, or unpin it if already pinned.
+    ///
+    /// Returns whether the entry is now pinned, or `None` without a selection.
+    pub fn toggle_pin_selected(&mut self) -> Option<bool> {
+        let entry = self.selected_entry()?.to_string();
+        let pinned = !self.pinned.remove(&entry);
+        if pinned {
+            self.pinned.insert(entry.clone());
+        }
+
+        if self.persist_to_disk
+            && let Err(e) = storage::set_pin(&entry, pinned)
+        {
+            eprintln!("Warning: Failed to save history pin to disk: {}", e);
+        }
+        Some(pinned)
+    }
+
+    /// Mark the entry selected in the popup, or unmark it if already
+    /// marked. Marks survive changes to the search, so fragments found
+    /// by different searches can be combined.
+    ///
+    /// Returns whether the entry is now marked, or `None` without a selection.
+    pub fn toggle_mark_selected(&mut self) -> Option<bool> {
+        let entry = self.selected_entry()?.to_string();
+        match self.marked.iter().position(|e| *e == entry) {
+            Some(index) => {
+                self.marked.remove(index);
+                Some(false)
+            }
+            None => {
+                self.marked.push(entry);
+                Some(true)
+            }
+        }
+    }
+
+    /// Where `query` is in the marking order, from 1, if it is marked
+    pub fn mark_number(&self, query: &str) -> Option<usize> {
+        self.marked.iter().position(|e| e == query).map(|i| i + 1)
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.marked.len()
+    }
+
+    /// The marked entries in the order they were marked, clearing the marks
+    pub fn take_marked(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.marked)
+    }
+
     fn update_filter(&mut self) {
         let query = self
             .search_textarea
@@ -264,6 +440,15 @@ impl HistoryState {
         self.cycling_index = None;
     }
 
+    /// Position of Ctrl+P/Ctrl+N cycling, saved per tab
+    pub fn cycling_index(&self) -> Option<usize> {
+        self.cycling_index
+    }
+
+    pub fn set_cycling_index(&mut self, index: Option<usize>) {
+        self.cycling_index = index.filter(|&i| i < self.entries.len());
+    }
+
     pub fn set_hovered(&mut self, display_index: Option<usize>) {
         self.hovered_index = display_index;
     }
@@ -306,16 +491,29 @@ impl HistoryState {
         let removed = self.entries.remove(entry_idx);
 
         if self.persist_to_disk
-            && let Err(e) = storage::delete_entry(&removed)
+            && let Err(e) = storage::delete_entry(&removed, self.max_entries)
         {
             eprintln!(
                 "Warning: Failed to delete query history entry on disk: {}",
                 e
             );
         }
+        if self.ai_originated.remove(&removed)
+            && self.persist_to_disk
+            && let Err(e) = storage::delete_ai_mark(&removed)
+        {
+            eprintln!("Warning: Failed to delete AI history mark on disk: {}", e);
+        }
+        if self.pinned.remove(&removed)
+            && self.persist_to_disk
+            && let Err(e) = storage::set_pin(&removed, false)
+        {
+            eprintln!("Warning: Failed to delete history pin on disk: {}", e);
+        }
 
         self.cycling_index = None;
         self.hovered_index = None;
+        self.marked.retain(|e| *e != removed);
         self.update_filter();
 
         if self.selected_index >= self.filtered_indices.len() {
@@ -5,6 +5,9 @@ use super::*;
 fn create_test_state(entries: Vec<&str>) -> HistoryState {
     HistoryState {
         entries: entries.into_iter().map(String::from).collect(),
+        ai_originated: HashSet::new(),
+        pinned: HashSet::new(),
+        max_entries: storage::MAX_HISTORY_ENTRIES,
         filtered_indices: vec![0, 1, 2],
         search_textarea: create_search_textarea(),
         selected_index: 0,
@@ -12,8 +15,11 @@ fn create_test_state(entries: Vec<&str>) -> HistoryState {
         visible: false,
         matcher: HistoryMatcher::new(),
         persist_to_disk: false,
+        private: false,
+        load_warning: None,
         cycling_index: None,
         hovered_index: None,
+        marked: Vec::new(),
     }
 }
 
@@ -222,6 +228,21 @@ fn test_cycle_next_when_not_cycling() {
     assert_eq!(result, None);
 }
 
+#[test]
+fn test_set_cycling_index_restores_position() {
+    let mut state = create_test_state(vec![".first", ".second", ".third"]);
+    state.cycle_previous();
+    state.cycle_previous();
+    let saved = state.cycling_index();
+
+    state.reset_cycling();
+    state.set_cycling_index(saved);
+
+    assert_eq!(state.cycle_previous(), Some(".third".to_string()));
+    state.set_cycling_index(Some(99));
+    assert_eq!(state.cycling_index(), None);
+}
+
 #[test]
 fn test_scroll_offset_follows_selection_down() {
     let entries: Vec<&str> = (0..20)
@@ -354,6 +375,9 @@ use crate::scroll::Scrollable;
 fn create_scrollable_test_state(entry_count: usize) -> HistoryState {
     HistoryState {
         entries: (0..entry_count).map(|i| format!(".test{}", i)).collect(),
+        ai_originated: HashSet::new(),
+        pinned: HashSet::new(),
+        max_entries: storage::MAX_HISTORY_ENTRIES,
         filtered_indices: (0..entry_count).collect(),
         search_textarea: create_search_textarea(),
         selected_index: 0,
@@ -361,8 +385,11 @@ fn create_scrollable_test_state(entry_count: usize) -> HistoryState {
         visible: false,
         matcher: HistoryMatcher::new(),
         persist_to_disk: false,
+        private: false,
+        load_warning: None,
         cycling_index: None,
         hovered_index: None,
+        marked: Vec::new(),
     }
 }
 
@@ -586,3 +613,203 @@ fn test_delete_selected_returns_none_for_stale_filtered_index() {
     assert_eq!(state.total_count(), 1);
     assert_eq!(state.entry_at_display_index(0), None);
 }
+
+#[test]
+fn test_add_ai_entry_marks_query() {
+    let mut state = create_test_state(vec![".foo"]);
+
+    state.add_ai_entry(".users[] | .email");
+
+    assert_eq!(state.entry_at_display_index(0), Some(".users[] | .email"));
+    assert!(state.is_ai_originated(".users[] | .email"));
+    assert!(!state.is_ai_originated(".foo"));
+}
+
+#[test]
+fn test_ai_mark_survives_plain_re_add() {
+    let mut state = create_test_state(vec![]);
+
+    state.add_ai_entry(".a");
+    state.add_entry(".a");
+
+    assert_eq!(state.total_count(), 1);
+    assert!(state.is_ai_originated(".a"));
+}
+
+#[test]
+fn test_add_ai_entry_ignores_blank_query() {
+    let mut state = create_test_state(vec![]);
+
+    state.add_ai_entry("   ");
+
+    assert_eq!(state.total_count(), 0);
+    assert!(!state.is_ai_originated(""));
+}
+
+#[test]
+fn test_delete_clears_ai_mark() {
+    let mut state = create_test_state(vec![]);
+    state.add_ai_entry(".a");
+
+    state.delete_selected();
+
+    assert!(!state.is_ai_originated(".a"));
+}
+
+#[test]
+fn test_add_entry_moves_repeat_to_front() {
+    let mut state = HistoryState::empty();
+    state.add_entry(".a");
+    state.add_entry(".b");
+    state.add_entry("  .a ");
+
+    assert_eq!(state.total_count(), 2);
+    assert_eq!(state.entry_at_display_index(0), Some(".a"));
+    assert_eq!(state.entry_at_display_index(1), Some(".b"));
+}
+
+#[test]
+fn test_add_entry_prunes_oldest_past_max() {
+    let mut state = HistoryState::empty();
+    state.set_max_entries(2);
+    state.add_entry(".a");
+    state.add_entry(".b");
+    state.add_entry(".c");
+
+    assert_eq!(state.total_count(), 2);
+    assert_eq!(state.entry_at_display_index(1), Some(".b"));
+}
+
+#[test]
+fn test_pinned_entries_survive_pruning() {
+    let mut state = HistoryState::empty();
+    state.set_max_entries(2);
+    state.add_entry(".old");
+    state.open(None);
+    assert_eq!(state.toggle_pin_selected(), Some(true));
+    state.close();
+
+    state.add_entry(".b");
+    state.add_entry(".c");
+    state.add_entry(".d");
+
+    assert_eq!(state.total_count(), 2);
+    assert_eq!(state.entry_at_display_index(0), Some(".d"));
+    assert_eq!(state.entry_at_display_index(1), Some(".old"));
+}
+
+#[test]
+fn test_toggle_pin_selected_unpins() {
+    let mut state = create_test_state(vec![".a", ".b", ".c"]);
+    assert_eq!(state.toggle_pin_selected(), Some(true));
+    assert!(state.is_pinned(".a"));
+    assert_eq!(state.toggle_pin_selected(), Some(false));
+    assert!(!state.is_pinned(".a"));
+}
+
+#[test]
+fn test_toggle_pin_without_selection() {
+    let mut state = HistoryState::empty();
+    assert_eq!(state.toggle_pin_selected(), None);
+}
+
+#[test]
+fn test_deleting_pinned_entry_drops_pin() {
+    let mut state = create_test_state(vec![".a", ".b", ".c"]);
+    state.toggle_pin_selected();
+    state.delete_selected();
+    assert!(!state.is_pinned(".a"));
+}
+
+#[test]
+fn test_private_mode_skips_recording() {
+    let mut state = HistoryState::empty();
+    state.add_entry(".before");
+    state.set_private(true);
+    state.add_entry(".secret");
+    state.add_ai_entry(".ai_secret");
+
+    assert!(state.is_private());
+    assert_eq!(state.total_count(), 1);
+
+    state.set_private(false);
+    state.add_entry(".after");
+    assert_eq!(state.total_count(), 2);
+}
+
+#[test]
+fn test_no_load_warning_for_empty_history() {
+    assert_eq!(HistoryState::empty().load_warning(), None);
+}
+
+#[test]
+fn test_import_appends_new_queries_as_oldest() {
+    let mut state = HistoryState::empty();
+    state.add_entry(".recent");
+
+    let added = state.import(&[".recent".to_string(), ".x".to_string(), ".y".to_string()]);
+
+    assert_eq!(added, 2);
+    assert_eq!(state.entry_at_display_index(0), Some(".recent"));
+    assert_eq!(state.entry_at_display_index(1), Some(".x"));
+    assert_eq!(state.entry_at_display_index(2), Some(".y"));
+}
+
+#[test]
+fn test_import_respects_max_entries() {
+    let mut state = HistoryState::empty();
+    state.set_max_entries(2);
+    state.add_entry(".recent");
+
+    let added = state.import(&[".x".to_string(), ".y".to_string()]);
+
+    assert_eq!(added, 1);
+    assert_eq!(state.total_count(), 2);
+}
+
+#[test]
+fn test_marks_are_numbered_in_the_order_marked() {
+    let mut state = create_test_state(vec![".a", ".b", ".c"]);
+    state.select_next();
+    state.select_next();
+    assert_eq!(state.toggle_mark_selected(), Some(true));
+    state.select_previous();
+    state.select_previous();
+    assert_eq!(state.toggle_mark_selected(), Some(true));
+
+    assert_eq!(state.mark_number(".c"), Some(1));
+    assert_eq!(state.mark_number(".a"), Some(2));
+    assert_eq!(state.mark_number(".b"), None);
+    assert_eq!(state.take_marked(), vec![".c", ".a"]);
+    assert_eq!(state.marked_count(), 0);
+}
+
+#[test]
+fn test_marking_again_unmarks() {
+    let mut state = create_test_state(vec![".a", ".b", ".c"]);
+    state.toggle_mark_selected();
+    assert_eq!(state.toggle_mark_selected(), Some(false));
+    assert_eq!(state.marked_count(), 0);
+}
+
+#[test]
+fn test_marks_survive_search_and_clear_on_close() {
+    let mut state = create_test_state(vec![".a", ".b", ".c"]);
+    state.open(None);
+    state.toggle_mark_selected();
+    state.search_textarea_mut().insert_str(".b");
+    state.on_search_input_changed();
+    state.toggle_mark_selected();
+    assert_eq!(state.marked_count(), 2);
+
+    state.close();
+    assert_eq!(state.marked_count(), 0);
+}
+
+#[test]
+fn test_deleting_marked_entry_drops_mark() {
+    let mut state = create_test_state(vec![".a", ".b", ".c"]);
+    state.toggle_mark_selected();
+    state.delete_selected();
+    assert_eq!(state.marked_count(), 0);
+}
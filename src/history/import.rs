@@ -0,0 +1,219 @@
+//! Reading queries out of other tools' history files
+//!
+//! Used by `jiq --import-history FILE` and `:import FILE`. A line that runs
+//! jq (or gojq, jaq) contributes the filter it passed; shell history
+//! prefixes from zsh (`: 1700000000:0;`) and fish (`- cmd: `) are stripped
+//! first. In a file of bare queries every line is a query, but in shell
+//! history lines without a jq invocation are skipped.
+
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+
+use super::HistoryState;
+
+/// Programs whose filter argument is imported
+const JQ_PROGRAMS: &[&str] = &["jq", "gojq", "jaq"];
+
+/// Options that take this many following arguments before the filter
+const OPTIONS_WITH_ARGS: &[(&str, usize)] = &[
+    ("--arg", 2),
+    ("--argjson", 2),
+    ("--slurpfile", 2),
+    ("--rawfile", 2),
+    ("--indent", 1),
+    ("-L", 1),
+    ("--library-path", 1),
+];
+
+/// What an import found and added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Distinct queries in the file
+    pub found: usize,
+    /// Of those, the ones history didn't have yet
+    pub added: usize,
+}
+
+impl ImportSummary {
+    pub fn message(&self) -> String {
+        let plural = if self.added == 1 { "query" } else { "queries" };
+        format!(
+            "Imported {} {} ({} already in history)",
+            self.added,
+            plural,
+            self.found - self.added
+        )
+    }
+}
+
+/// Read the queries in `path` into `history`
+pub fn import_file(history: &mut HistoryState, path: &Path) -> io::Result<ImportSummary> {
+    let bytes = std::fs::read(path)?;
+    let queries = parse(&String::from_utf8_lossy(&bytes), path);
+    let added = history.import(&queries);
+    Ok(ImportSummary {
+        found: queries.len(),
+        added,
+    })
+}
+
+/// Queries found in `content`, oldest last and without duplicates
+///
+/// History files list the oldest command first, so the order is reversed to
+/// match jiq's newest-first history.
+pub fn parse(content: &str, path: &Path) -> Vec<String> {
+    let shell = is_shell_history(path, content);
+    let mut seen = HashSet::new();
+    let mut queries: Vec<String> = content
+        .lines()
+        .filter_map(|line| parse_line(line, shell))
+        .filter(|query| seen.insert(query.clone()))
+        .collect();
+    queries.reverse();
+    queries
+}
+
+fn parse_line(line: &str, shell: bool) -> Option<String> {
+    let command = strip_shell_prefix(line.trim());
+    if command.is_empty() || command.starts_with('#') {
+        return None;
+    }
+    if let Some(filter) = jq_filter(command) {
+        return Some(filter);
+    }
+    (!shell).then(|| command.to_string())
+}
+
+/// Whether `content` is a shell history rather than a list of queries
+fn is_shell_history(path: &Path, content: &str) -> bool {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    if name.ends_with("history") && name != "history" {
+        return true;
+    }
+    content
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| strip_shell_prefix(line) != line)
+}
+
+/// The command in a zsh extended-history or fish history line
+fn strip_shell_prefix(line: &str) -> &str {
+    if let Some(rest) = line.strip_prefix("- cmd: ") {
+        return rest;
+    }
+    if let Some(rest) = line.strip_prefix(": ")
+        && let Some((stamp, command)) = rest.split_once(';')
+        && stamp.chars().all(|c| c.is_ascii_digit() || c == ':')
+    {
+        return command;
+    }
+    line
+}
+
+/// The filter passed to the first jq-like program in `command`
+fn jq_filter(command: &str) -> Option<String> {
+    let words = shell_words(command);
+    let start = (0..words.len()).find(|&i| {
+        let program = words[i].rsplit('/').next().unwrap_or(&words[i]);
+        JQ_PROGRAMS.contains(&program) && (i == 0 || is_separator(&words[i - 1]))
+    })?;
+
+    let mut rest = words[start + 1..].iter();
+    while let Some(word) = rest.next() {
+        if is_separator(word) {
+            return None;
+        }
+        // The filter is read from a file, so there is nothing to import
+        if word == "-f" || word == "--from-file" {
+            return None;
+        }
+        if let Some(&(_, count)) = OPTIONS_WITH_ARGS.iter().find(|(name, _)| name == word) {
+            for _ in 0..count {
+                rest.next();
+            }
+            continue;
+        }
+        if word.starts_with('-') && word.len() > 1 {
+            continue;
+        }
+        let filter = word.trim();
+        return (!filter.is_empty()).then(|| filter.to_string());
+    }
+    None
+}
+
+fn is_separator(word: &str) -> bool {
+    matches!(word, "|" | "||" | "&&" | ";" | ">" | "<")
+}
+
+/// Split `command` into words the way a POSIX shell would, keeping `|`,
+/// `;` and `&&` as words of their own
+fn shell_words(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    word.push(c);
+                }
+            }
+            '"' => {
+                in_word = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' if matches!(chars.peek(), Some('"' | '\\' | '$' | '`')) => {
+                            word.extend(chars.next());
+                        }
+                        _ => word.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                word.extend(chars.next());
+            }
+            '|' | ';' | '&' | '>' | '<' => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+                let mut op = c.to_string();
+                if chars.peek() == Some(&c) {
+                    op.extend(chars.next());
+                }
+                words.push(op);
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            _ => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+#[path = "import_tests.rs"]
+mod import_tests;
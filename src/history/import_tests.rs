@@ -0,0 +1,73 @@
+//! Tests for history/import
+
+use super::*;
+use std::path::PathBuf;
+
+fn parse_as(name: &str, content: &str) -> Vec<String> {
+    parse(content, &PathBuf::from(name))
+}
+
+#[test]
+fn test_plain_file_imports_every_line_newest_first() {
+    let queries = parse_as("queries.txt", ".a\n\n.b | keys\n# a comment\n.a\n");
+    assert_eq!(queries, vec![".b | keys", ".a"]);
+}
+
+#[test]
+fn test_plain_file_extracts_jq_commands() {
+    let queries = parse_as("queries.txt", "jq '.users[]' file.json\n.name\n");
+    assert_eq!(queries, vec![".name", ".users[]"]);
+}
+
+#[test]
+fn test_bash_history_keeps_only_jq_filters() {
+    let content = "ls\ncat data.json | jq -c '.items | length'\ngit status\n";
+    assert_eq!(parse_as(".bash_history", content), vec![".items | length"]);
+}
+
+#[test]
+fn test_zsh_extended_history() {
+    let content = ": 1700000000:0;jq .name a.json\n: 1700000001:3;echo hi\n";
+    assert_eq!(parse_as("anything", content), vec![".name"]);
+}
+
+#[test]
+fn test_fish_history() {
+    let content = "- cmd: gojq '.a' x.json\n  when: 1700000000\n- cmd: ls\n";
+    assert_eq!(parse_as("fish_history", content), vec![".a"]);
+}
+
+#[test]
+fn test_options_before_the_filter_are_skipped() {
+    let content = "jq -r --arg name bob --indent 4 -L lib '.[] | select(.n == $name)' f\n";
+    assert_eq!(
+        parse_as(".bash_history", content),
+        vec![".[] | select(.n == $name)"]
+    );
+}
+
+#[test]
+fn test_double_quoted_filter_is_unescaped() {
+    let content = r#"jq ".a | test(\"x\")" f"#;
+    assert_eq!(
+        parse_as(".bash_history", content),
+        vec![r#".a | test("x")"#]
+    );
+}
+
+#[test]
+fn test_filter_from_file_is_skipped() {
+    assert!(parse_as(".bash_history", "jq -f prog.jq data.json\n").is_empty());
+}
+
+#[test]
+fn test_jq_word_inside_a_query_is_not_a_command() {
+    let queries = parse_as("queries.txt", r#".tool == "jq" or .x"#);
+    assert_eq!(queries, vec![r#".tool == "jq" or .x"#]);
+}
+
+#[test]
+fn test_summary_message() {
+    let summary = ImportSummary { found: 3, added: 1 };
+    assert_eq!(summary.message(), "Imported 1 query (2 already in history)");
+}
@@ -1,37 +1,77 @@
-use std::fs::{self, File};
-use std::io::{self, BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
-const MAX_HISTORY_ENTRIES: usize = 1000;
+use super::cipher;
+
+/// Default cap on stored queries, set by `max_entries` in `[history]`
+pub const MAX_HISTORY_ENTRIES: usize = 1000;
 const HISTORY_DIR: &str = "jiq";
 const HISTORY_FILE: &str = "history";
+/// Sidecar listing history entries that came from AI suggestions. Kept
+/// separate so the main history file stays one plain query per line.
+const AI_MARKS_FILE: &str = "history_ai";
+/// Sidecar listing pinned history entries, which pruning never drops
+const PINS_FILE: &str = "history_pinned";
+
+/// How the history files are written, from the `[history]` config section
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageOptions {
+    /// Keep the history directory and files readable by their owner only
+    pub restrict_permissions: bool,
+    /// Encrypt the history files with this passphrase
+    pub passphrase: Option<String>,
+}
+
+static OPTIONS: RwLock<StorageOptions> = RwLock::new(StorageOptions {
+    restrict_permissions: false,
+    passphrase: None,
+});
+
+/// Set how the history files are written for the rest of the session
+pub fn configure(options: StorageOptions) {
+    if let Ok(mut current) = OPTIONS.write() {
+        *current = options;
+    }
+}
+
+fn options() -> StorageOptions {
+    OPTIONS.read().map(|o| o.clone()).unwrap_or_default()
+}
 
 pub fn history_path() -> Option<PathBuf> {
     dirs::data_dir().map(|p| p.join(HISTORY_DIR).join(HISTORY_FILE))
 }
 
+pub fn ai_marks_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join(HISTORY_DIR).join(AI_MARKS_FILE))
+}
+
+pub fn pins_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join(HISTORY_DIR).join(PINS_FILE))
+}
+
 pub fn load_history() -> Vec<String> {
+    try_load_history().unwrap_or_default()
+}
+
+/// Loads the history, failing when the file is encrypted and the passphrase
+/// is missing or wrong, so the caller can avoid overwriting it.
+pub fn try_load_history() -> io::Result<Vec<String>> {
     log::debug!("History path: {:?}", history_path());
     let Some(path) = history_path() else {
-        return Vec::new();
-    };
-
-    let file = match File::open(&path) {
-        Ok(f) => f,
-        Err(_) => return Vec::new(),
+        return Ok(Vec::new());
     };
 
-    let reader = BufReader::new(file);
-    let entries: Vec<String> = reader
-        .lines()
-        .map_while(Result::ok)
-        .filter(|line| !line.trim().is_empty())
-        .collect();
+    let entries = try_read_lines(&path, &options())?;
     log::debug!("Loaded {} history entries", entries.len());
-    entries
+    Ok(entries)
 }
 
-pub fn save_history(entries: &[String]) -> io::Result<()> {
+/// Writes `entries`, de-duplicated and pruned to `max_entries`.
+pub fn save_history(entries: &[String], max_entries: usize) -> io::Result<()> {
     let Some(path) = history_path() else {
         return Err(io::Error::new(
             io::ErrorKind::NotFound,
@@ -39,25 +79,16 @@ pub fn save_history(entries: &[String]) -> io::Result<()> {
         ));
     };
 
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
-    }
-
-    let mut file = File::create(&path)?;
-
     let unique_entries = deduplicate(entries);
-    let trimmed = trim_to_max(&unique_entries);
-
-    for entry in &trimmed {
-        writeln!(file, "{}", entry)?;
-    }
+    let trimmed = prune(&unique_entries, max_entries, &load_pins());
+    write_lines(&path, &trimmed, &options())?;
 
     log::debug!("Saved {} history entries", trimmed.len());
     Ok(())
 }
 
 /// No file locking - last writer wins if multiple instances run simultaneously.
-pub fn add_entry(query: &str) -> io::Result<()> {
+pub fn add_entry(query: &str, max_entries: usize) -> io::Result<()> {
     let query = query.trim();
     if query.is_empty() {
         return Ok(());
@@ -68,13 +99,23 @@ pub fn add_entry(query: &str) -> io::Result<()> {
     entries.retain(|e| e != query);
     entries.insert(0, query.to_string());
 
-    save_history(&entries)
+    save_history(&entries, max_entries)
+}
+
+/// Appends `queries` not already in the history after the existing entries,
+/// so they count as older than anything run in jiq.
+///
+/// No file locking - last writer wins if multiple instances run simultaneously.
+pub fn append_entries(queries: &[String], max_entries: usize) -> io::Result<()> {
+    let mut entries = try_load_history()?;
+    entries.extend(queries.iter().cloned());
+    save_history(&entries, max_entries)
 }
 
 /// Removes all occurrences of `query` from the persisted history.
 ///
 /// No file locking - last writer wins if multiple instances run simultaneously.
-pub fn delete_entry(query: &str) -> io::Result<()> {
+pub fn delete_entry(query: &str, max_entries: usize) -> io::Result<()> {
     let mut entries = load_history();
     let original_len = entries.len();
     entries.retain(|e| e != query);
@@ -83,7 +124,163 @@ pub fn delete_entry(query: &str) -> io::Result<()> {
         return Ok(());
     }
 
-    save_history(&entries)
+    save_history(&entries, max_entries)
+}
+
+/// Loads the set of queries marked as AI-originated.
+pub fn load_ai_marks() -> HashSet<String> {
+    let Some(path) = ai_marks_path() else {
+        return HashSet::new();
+    };
+    read_lines(&path).into_iter().collect()
+}
+
+/// Marks `query` as AI-originated in the sidecar file.
+///
+/// No file locking - last writer wins if multiple instances run simultaneously.
+pub fn add_ai_mark(query: &str) -> io::Result<()> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(());
+    }
+
+    let mut marks = load_ai_marks();
+    if !marks.insert(query.to_string()) {
+        return Ok(());
+    }
+    save_ai_marks(&marks)
+}
+
+/// Removes the AI-originated mark for `query`, if present.
+pub fn delete_ai_mark(query: &str) -> io::Result<()> {
+    let mut marks = load_ai_marks();
+    if !marks.remove(query) {
+        return Ok(());
+    }
+    save_ai_marks(&marks)
+}
+
+/// Persists marks, dropping any whose query has aged out of the history.
+fn save_ai_marks(marks: &HashSet<String>) -> io::Result<()> {
+    let Some(path) = ai_marks_path() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine history file path",
+        ));
+    };
+
+    let entries = load_history();
+    let marked: Vec<String> = entries
+        .into_iter()
+        .filter(|e| marks.contains(e.as_str()))
+        .collect();
+    write_lines(&path, &marked, &options())
+}
+
+/// Loads the set of pinned queries.
+pub fn load_pins() -> HashSet<String> {
+    let Some(path) = pins_path() else {
+        return HashSet::new();
+    };
+    read_lines(&path).into_iter().collect()
+}
+
+/// Pins or unpins `query` in the sidecar file.
+///
+/// No file locking - last writer wins if multiple instances run simultaneously.
+pub fn set_pin(query: &str, pinned: bool) -> io::Result<()> {
+    let mut pins = load_pins();
+    let changed = if pinned {
+        pins.insert(query.to_string())
+    } else {
+        pins.remove(query)
+    };
+    if !changed {
+        return Ok(());
+    }
+
+    let Some(path) = pins_path() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine history file path",
+        ));
+    };
+    let pinned: Vec<String> = load_history()
+        .into_iter()
+        .filter(|e| pins.contains(e.as_str()))
+        .collect();
+    write_lines(&path, &pinned, &options())
+}
+
+fn read_lines(path: &Path) -> Vec<String> {
+    try_read_lines(path, &options()).unwrap_or_default()
+}
+
+/// Non-blank lines of `path`, decrypted when the file is encrypted; a missing
+/// file has none
+fn try_read_lines(path: &Path, options: &StorageOptions) -> io::Result<Vec<String>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let content = if cipher::is_encrypted(&content) {
+        let Some(passphrase) = &options.passphrase else {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "History is encrypted and no passphrase is set",
+            ));
+        };
+        cipher::decrypt(&content, passphrase)?
+    } else {
+        content
+    };
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Replace `path` with `lines`, encrypted when a passphrase is set and owner
+/// only when permissions are restricted
+fn write_lines(path: &Path, lines: &[String], options: &StorageOptions) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        create_dir(parent, options.restrict_permissions)?;
+    }
+
+    let mut content: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+    if let Some(passphrase) = &options.passphrase {
+        content = cipher::encrypt(&content, passphrase)?;
+    }
+
+    let mut open = OpenOptions::new();
+    open.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if options.restrict_permissions {
+        use std::os::unix::fs::OpenOptionsExt;
+        open.mode(0o600);
+    }
+    let mut file = open.open(path)?;
+    // The mode above only applies to new files
+    #[cfg(unix)]
+    if options.restrict_permissions {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(content.as_bytes())
+}
+
+fn create_dir(dir: &Path, restrict_permissions: bool) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    #[cfg(unix)]
+    if restrict_permissions {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(dir, fs::Permissions::from_mode(0o700))?;
+    }
+    #[cfg(not(unix))]
+    let _ = restrict_permissions;
+    Ok(())
 }
 
 /// Removes duplicate entries, keeping the first occurrence of each.
@@ -96,9 +293,24 @@ fn deduplicate(entries: &[String]) -> Vec<String> {
         .collect()
 }
 
-/// Trims the entries to the maximum allowed size.
-fn trim_to_max(entries: &[String]) -> Vec<String> {
-    entries.iter().take(MAX_HISTORY_ENTRIES).cloned().collect()
+/// Keeps at most `max_entries`, dropping the oldest unpinned entries first.
+///
+/// Pinned entries are always kept, even when they alone exceed the cap.
+pub fn prune(entries: &[String], max_entries: usize, pins: &HashSet<String>) -> Vec<String> {
+    let pinned_count = entries.iter().filter(|e| pins.contains(*e)).count();
+    let mut unpinned_room = max_entries.saturating_sub(pinned_count);
+    entries
+        .iter()
+        .filter(|e| {
+            if pins.contains(*e) {
+                return true;
+            }
+            let keep = unpinned_room > 0;
+            unpinned_room = unpinned_room.saturating_sub(1);
+            keep
+        })
+        .cloned()
+        .collect()
 }
 
 #[cfg(test)]
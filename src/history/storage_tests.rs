@@ -18,13 +18,27 @@ fn test_deduplicate_keeps_first_occurrence() {
 }
 
 #[test]
-fn test_trim_to_max() {
+fn test_prune_to_max() {
     let entries: Vec<String> = (0..1500).map(|i| format!("entry{}", i)).collect();
-    let trimmed = trim_to_max(&entries);
+    let trimmed = prune(&entries, MAX_HISTORY_ENTRIES, &HashSet::new());
     assert_eq!(trimmed.len(), MAX_HISTORY_ENTRIES);
     assert_eq!(trimmed[0], "entry0");
 }
 
+#[test]
+fn test_prune_keeps_pinned_and_drops_oldest_unpinned() {
+    let entries: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+    let pins: HashSet<String> = ["d".to_string()].into();
+    assert_eq!(prune(&entries, 2, &pins), vec!["a", "d"]);
+}
+
+#[test]
+fn test_prune_keeps_all_pins_past_max() {
+    let entries: Vec<String> = ["a", "b", "c"].map(String::from).to_vec();
+    let pins: HashSet<String> = entries.iter().cloned().collect();
+    assert_eq!(prune(&entries, 1, &pins), entries);
+}
+
 /// Exercises the entire filesystem-backed persistence layer (save_history,
 /// load_history, add_entry, delete_entry) end-to-end by redirecting
 /// `dirs::data_dir()` at a TempDir via `XDG_DATA_HOME`. Consolidated into a
@@ -50,11 +64,14 @@ fn test_storage_full_lifecycle_via_xdg_data_home() {
 
     // save_history writes entries; load_history reads them back in order,
     // skipping blank lines that may exist in the file.
-    save_history(&[
-        "first".to_string(),
-        "second".to_string(),
-        "third".to_string(),
-    ])
+    save_history(
+        &[
+            "first".to_string(),
+            "second".to_string(),
+            "third".to_string(),
+        ],
+        MAX_HISTORY_ENTRIES,
+    )
     .unwrap();
     assert_eq!(load_history(), vec!["first", "second", "third"]);
 
@@ -70,7 +87,7 @@ fn test_storage_full_lifecycle_via_xdg_data_home() {
     for i in 0..(MAX_HISTORY_ENTRIES + 50) {
         many.push(format!("e{}", i));
     }
-    save_history(&many).unwrap();
+    save_history(&many, MAX_HISTORY_ENTRIES).unwrap();
     let reloaded = load_history();
     assert_eq!(
         reloaded.len(),
@@ -86,14 +103,18 @@ fn test_storage_full_lifecycle_via_xdg_data_home() {
 
     // add_entry on a non-empty query moves it to the front, removing any prior
     // duplicate (retain + insert(0)).
-    save_history(&["a".to_string(), "b".to_string(), "c".to_string()]).unwrap();
-    add_entry("b").unwrap();
+    save_history(
+        &["a".to_string(), "b".to_string(), "c".to_string()],
+        MAX_HISTORY_ENTRIES,
+    )
+    .unwrap();
+    add_entry("b", MAX_HISTORY_ENTRIES).unwrap();
     assert_eq!(
         load_history(),
         vec!["b", "a", "c"],
         "add_entry moves existing query to front"
     );
-    add_entry("new").unwrap();
+    add_entry("new", MAX_HISTORY_ENTRIES).unwrap();
     assert_eq!(
         load_history(),
         vec!["new", "b", "a", "c"],
@@ -101,7 +122,7 @@ fn test_storage_full_lifecycle_via_xdg_data_home() {
     );
 
     // add_entry ignores blank/whitespace-only queries (empty guard).
-    add_entry("   ").unwrap();
+    add_entry("   ", MAX_HISTORY_ENTRIES).unwrap();
     assert_eq!(
         load_history(),
         vec!["new", "b", "a", "c"],
@@ -113,7 +134,7 @@ fn test_storage_full_lifecycle_via_xdg_data_home() {
     // save_history's dedup) so we can prove delete_entry's retain() drops
     // *every* matching occurrence, while distinct survivors stay in order.
     fs::write(&path, "k1\ndrop\nk2\ndrop\nk3\n").unwrap();
-    delete_entry("drop").unwrap();
+    delete_entry("drop", MAX_HISTORY_ENTRIES).unwrap();
     assert_eq!(
         load_history(),
         vec!["k1", "k2", "k3"],
@@ -122,14 +143,136 @@ fn test_storage_full_lifecycle_via_xdg_data_home() {
 
     // delete_entry of an absent query short-circuits without rewriting the file.
     let before = fs::read_to_string(&path).unwrap();
-    delete_entry("absent").unwrap();
+    delete_entry("absent", MAX_HISTORY_ENTRIES).unwrap();
     let after = fs::read_to_string(&path).unwrap();
     assert_eq!(
         before, after,
         "delete_entry of absent query leaves file unchanged"
     );
 
+    // AI marks live in a sidecar file and only keep queries still in history.
+    assert!(load_ai_marks().is_empty(), "no marks file yet -> empty");
+    add_ai_mark("k2").unwrap();
+    add_ai_mark("not-in-history").unwrap();
+    add_ai_mark("   ").unwrap();
+    let marks = load_ai_marks();
+    assert!(marks.contains("k2"));
+    assert!(
+        !marks.contains("not-in-history"),
+        "marks for queries absent from history are dropped on save"
+    );
+    assert_eq!(marks.len(), 1, "blank query is never marked");
+
+    delete_ai_mark("k2").unwrap();
+    assert!(
+        load_ai_marks().is_empty(),
+        "delete_ai_mark removes the mark"
+    );
+
+    // Pins live in their own sidecar and protect entries from pruning.
+    assert!(load_pins().is_empty(), "no pins file yet -> empty");
+    set_pin("k3", true).unwrap();
+    assert!(load_pins().contains("k3"));
+    save_history(&["k1".to_string(), "k2".to_string(), "k3".to_string()], 2).unwrap();
+    assert_eq!(
+        load_history(),
+        vec!["k1", "k3"],
+        "pruning drops the oldest unpinned entries and keeps pins"
+    );
+    set_pin("k3", false).unwrap();
+    assert!(load_pins().is_empty(), "set_pin(false) removes the pin");
+
     unsafe {
         env::remove_var("XDG_DATA_HOME");
     }
 }
+
+#[test]
+fn test_encrypted_lines_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    let options = StorageOptions {
+        restrict_permissions: false,
+        passphrase: Some("pass".to_string()),
+    };
+
+    write_lines(&path, &[".token == \"abc\"".to_string()], &options).unwrap();
+
+    let on_disk = fs::read_to_string(&path).unwrap();
+    assert!(cipher::is_encrypted(&on_disk));
+    assert!(!on_disk.contains("abc"));
+    assert_eq!(
+        try_read_lines(&path, &options).unwrap(),
+        vec![".token == \"abc\""]
+    );
+}
+
+#[test]
+fn test_encrypted_lines_need_the_passphrase() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    let options = StorageOptions {
+        restrict_permissions: false,
+        passphrase: Some("pass".to_string()),
+    };
+    write_lines(&path, &[".a".to_string()], &options).unwrap();
+
+    let err = try_read_lines(&path, &StorageOptions::default()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+    let wrong = StorageOptions {
+        passphrase: Some("other".to_string()),
+        ..options
+    };
+    assert!(try_read_lines(&path, &wrong).is_err());
+}
+
+#[test]
+fn test_plain_lines_still_load_with_a_passphrase() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    fs::write(&path, ".a\n.b\n").unwrap();
+    let options = StorageOptions {
+        restrict_permissions: false,
+        passphrase: Some("pass".to_string()),
+    };
+    assert_eq!(try_read_lines(&path, &options).unwrap(), vec![".a", ".b"]);
+}
+
+#[test]
+fn test_missing_file_reads_as_empty() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    assert!(
+        try_read_lines(&path, &StorageOptions::default())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_restricted_permissions_are_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("jiq").join("history");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, ".old\n").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+    let options = StorageOptions {
+        restrict_permissions: true,
+        passphrase: None,
+    };
+
+    write_lines(&path, &[".a".to_string()], &options).unwrap();
+
+    let file_mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+    let dir_mode = fs::metadata(path.parent().unwrap())
+        .unwrap()
+        .permissions()
+        .mode()
+        & 0o777;
+    assert_eq!(file_mode, 0o600);
+    assert_eq!(dir_mode, 0o700);
+}
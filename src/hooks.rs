@@ -0,0 +1,205 @@
+//! Hook commands from `[hooks]` in the config file
+//!
+//! `on_load` runs once the input has loaded and `on_exit` after jiq prints
+//! the result or the query on exit, so queries can be logged or results
+//! handed to other tools without wrapping jiq in a script. A hook is a
+//! shell command, run with `sh -c`, or `cmd /C` on Windows; `{query}`,
+//! `{file}` and `{format}` in it are replaced by values quoted for that
+//! shell before it runs.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::app::{App, OutputMode};
+use crate::query::output_format::OutputFormat;
+
+/// Values for a hook's template variables
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookVars {
+    pub query: String,
+    /// The input files; none when the input came from stdin or the clipboard
+    pub files: Vec<PathBuf>,
+    /// `json`, `compact` or `raw`, or `query` when Ctrl+Q printed the query
+    pub format: &'static str,
+}
+
+impl HookVars {
+    pub fn from_app(app: &App) -> Self {
+        let format = match app.output_mode() {
+            Some(OutputMode::Query) => "query",
+            _ => format_name(
+                app.query
+                    .as_ref()
+                    .map_or(app.output_format, |q| q.output_format()),
+            ),
+        };
+        Self {
+            query: app.query().to_string(),
+            files: app.source_paths(),
+            format,
+        }
+    }
+}
+
+/// The `{format}` name for jq's output flags
+pub fn format_name(format: OutputFormat) -> &'static str {
+    if format.raw {
+        "raw"
+    } else if format.compact {
+        "compact"
+    } else {
+        "json"
+    }
+}
+
+/// `template` with the variables filled in. Unknown `{names}` are left as
+/// they are, and values are never expanded again.
+pub fn expand(template: &str, vars: &HookVars) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = ["query", "file", "format"]
+            .into_iter()
+            .find(|name| rest[1..].starts_with(name) && rest[1 + name.len()..].starts_with('}'))
+            .map(|name| (name.len() + 2, value_of(name, vars)));
+        match value {
+            Some((len, value)) => {
+                out.push_str(&value);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn value_of(name: &str, vars: &HookVars) -> String {
+    match name {
+        "query" => shell_quote(&vars.query),
+        "file" if vars.files.is_empty() => shell_quote(""),
+        "file" => vars
+            .files
+            .iter()
+            .map(|f| shell_quote(&f.to_string_lossy()))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => vars.format.to_string(),
+    }
+}
+
+#[cfg(not(windows))]
+fn shell_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "'\\''"))
+}
+
+#[cfg(windows)]
+fn shell_quote(text: &str) -> String {
+    cmd_quote(text)
+}
+
+/// Quoting for `cmd /C`, which has no single quotes. Inside double quotes
+/// a `"` is doubled, and `%` is stepped out of them and escaped so cmd
+/// doesn't expand `%VAR%`. cmd can't take a line break in a command, so
+/// line breaks become spaces.
+#[cfg(any(windows, test))]
+fn cmd_quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\"\""),
+            '%' => quoted.push_str("\"^%\""),
+            '\r' => {}
+            '\n' => quoted.push(' '),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn shell(command: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    }
+    #[cfg(not(windows))]
+    {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
+
+/// Start the `on_load` hook without waiting for it
+///
+/// Its output is dropped so it can't draw over the screen; a failure is
+/// logged, or shown when the hook can't start at all.
+pub fn on_load(app: &mut App) {
+    let Some(template) = &app.hooks.on_load else {
+        return;
+    };
+    let command = expand(template, &HookVars::from_app(app));
+    let spawned = shell(&command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn();
+    match spawned {
+        Ok(child) => {
+            std::thread::spawn(move || match child.wait_with_output() {
+                Ok(output) if !output.status.success() => log::warn!(
+                    "on_load hook failed with {}: {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+                Err(e) => log::warn!("on_load hook failed: {}", e),
+                Ok(_) => {}
+            });
+        }
+        Err(e) => app
+            .notification
+            .show_error(&format!("Cannot run on_load hook: {}", e)),
+    }
+}
+
+/// Run the `on_exit` hook with `printed` on its stdin and wait for it
+///
+/// The hook's stdout goes to stderr, so what jiq printed stays the only
+/// thing on stdout.
+pub fn on_exit(app: &App, printed: &str) -> Result<(), String> {
+    let Some(template) = &app.hooks.on_exit else {
+        return Ok(());
+    };
+    let command = expand(template, &HookVars::from_app(app));
+    let mut child = shell(&command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::from(std::io::stderr()))
+        .spawn()
+        .map_err(|e| format!("cannot run on_exit hook: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores its input may exit before reading it all
+        let _ = stdin.write_all(printed.as_bytes());
+    }
+    let status = child
+        .wait()
+        .map_err(|e| format!("on_exit hook failed: {}", e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("on_exit hook failed with {}", status))
+    }
+}
+
+#[cfg(test)]
+#[path = "hooks_tests.rs"]
+mod hooks_tests;
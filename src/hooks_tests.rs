@@ -0,0 +1,163 @@
+//! Tests for hooks
+
+use std::path::PathBuf;
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+
+fn vars(query: &str, files: &[&str]) -> HookVars {
+    HookVars {
+        query: query.to_string(),
+        files: files.iter().map(PathBuf::from).collect(),
+        format: "json",
+    }
+}
+
+#[test]
+#[cfg(not(windows))]
+fn expand_quotes_each_variable() {
+    let command = expand(
+        "log {query} {file} {format}",
+        &vars(".a | select(.b == 'x')", &["my data.json"]),
+    );
+
+    assert_eq!(
+        command,
+        r#"log '.a | select(.b == '\''x'\'')' 'my data.json' json"#
+    );
+}
+
+#[test]
+#[cfg(not(windows))]
+fn expand_joins_several_files_and_leaves_no_file_empty() {
+    assert_eq!(
+        expand("{file}", &vars(".", &["a.json", "b.json"])),
+        "'a.json' 'b.json'"
+    );
+    assert_eq!(expand("{file}", &vars(".", &[])), "''");
+}
+
+#[test]
+fn expand_leaves_other_braces_alone() {
+    assert_eq!(
+        expand("awk '{print}' {query", &vars(".", &[])),
+        "awk '{print}' {query"
+    );
+}
+
+#[test]
+#[cfg(not(windows))]
+fn expanded_values_are_not_expanded_again() {
+    assert_eq!(expand("{query}", &vars("{format}", &[])), "'{format}'");
+}
+
+#[test]
+fn cmd_quote_doubles_quotes_and_escapes_percent() {
+    assert_eq!(
+        cmd_quote(r#".a | select(.b == "x")"#),
+        r#"".a | select(.b == ""x"")""#
+    );
+    assert_eq!(cmd_quote("100%PATH%"), r#""100"^%"PATH"^%"""#);
+    assert_eq!(cmd_quote("'&|<>'"), r#""'&|<>'""#);
+}
+
+#[test]
+fn cmd_quote_turns_line_breaks_into_spaces() {
+    assert_eq!(cmd_quote(".a\r\n| .b"), r#"".a | .b""#);
+}
+
+#[test]
+fn format_name_prefers_raw_over_compact() {
+    let mut format = OutputFormat::default();
+    assert_eq!(format_name(format), "json");
+    format.compact = true;
+    assert_eq!(format_name(format), "compact");
+    format.raw = true;
+    assert_eq!(format_name(format), "raw");
+}
+
+#[test]
+fn vars_from_app_name_the_query_output() {
+    let mut app = test_app("{}");
+    app.input.textarea.insert_str(".a");
+    app.output_mode = Some(OutputMode::Query);
+
+    let vars = HookVars::from_app(&app);
+
+    assert_eq!(vars.query, ".a");
+    assert_eq!(vars.format, "query");
+    assert!(vars.files.is_empty());
+}
+
+#[test]
+fn no_hooks_do_nothing() {
+    let mut app = test_app("{}");
+
+    on_load(&mut app);
+
+    assert_eq!(on_exit(&app, "{}"), Ok(()));
+    assert_eq!(app.notification.current_message(), None);
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::time::{Duration, Instant};
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::config::Config;
+    use crate::test_utils::test_helpers::create_test_loader;
+
+    fn wait_for_file(path: &std::path::Path) -> String {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(5) {
+            if let Ok(text) = std::fs::read_to_string(path)
+                && text.ends_with('\n')
+            {
+                return text;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("hook never wrote {}", path.display());
+    }
+
+    #[test]
+    fn on_load_runs_when_the_input_loads() {
+        let dir = TempDir::new().unwrap();
+        let log = dir.path().join("load.log");
+        let mut config = Config::default();
+        config.hooks.on_load = Some(format!("echo loaded {{format}} > '{}'", log.display()));
+        let mut app = App::new_with_loader(create_test_loader("{}".to_string()), &config);
+
+        app.poll_file_loader();
+
+        assert_eq!(wait_for_file(&log), "loaded json\n");
+    }
+
+    #[test]
+    fn on_exit_gets_what_was_printed() {
+        let dir = TempDir::new().unwrap();
+        let log = dir.path().join("exit.log");
+        let mut app = test_app("{}");
+        app.input.textarea.insert_str(".a");
+        app.hooks.on_exit = Some(format!(
+            "{{ printf '%s ' {{query}}; cat; echo; }} > '{}'",
+            log.display()
+        ));
+
+        assert_eq!(on_exit(&app, "[1,2]"), Ok(()));
+
+        assert_eq!(std::fs::read_to_string(&log).unwrap(), ".a [1,2]\n");
+    }
+
+    #[test]
+    fn failing_on_exit_reports_its_status() {
+        let mut app = test_app("{}");
+        app.hooks.on_exit = Some("exit 4".to_string());
+
+        let err = on_exit(&app, "").unwrap_err();
+
+        assert!(err.starts_with("on_exit hook failed with"), "{}", err);
+    }
+}
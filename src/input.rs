@@ -1,10 +1,16 @@
+pub mod input_files;
 pub mod input_render;
 mod input_state;
+pub mod input_text;
+pub mod json5;
 pub mod loader;
+pub mod malformed;
 pub mod paste_recovery;
 pub mod source_picker;
 
+pub use input_files::{InputFile, InputFilesState};
 pub use input_state::InputState;
+pub use input_text::InputText;
 pub use loader::FileLoader;
 pub use paste_recovery::PasteRecoveryState;
 pub use source_picker::{SourceChoice, SourcePickerState};
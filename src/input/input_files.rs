@@ -0,0 +1,137 @@
+//! Multiple input files state.
+//!
+//! `jiq a.json b.json c.json` feeds every file to jq as one stream of
+//! inputs, in argument order, so `inputs` and `--null-input` queries see
+//! each document the way they would under `jq . a.json b.json c.json`.
+//! The file picker strip (Ctrl+L) toggles which files are included and
+//! previews the structure of the highlighted one.
+
+use std::path::PathBuf;
+
+/// One file passed on the command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputFile {
+    pub path: PathBuf,
+    /// Raw file contents, already validated as JSON or JSONL
+    pub contents: String,
+    /// Pretty-printed schema of the file's first value, for the preview
+    pub schema: Option<String>,
+    /// Whether the file is part of the stream given to jq
+    pub included: bool,
+}
+
+impl InputFile {
+    pub fn new(path: PathBuf, contents: String) -> Self {
+        let schema = crate::json::extract_first_json_value(&contents)
+            .and_then(|first| crate::json::extract_json_schema_dynamic(&first))
+            .map(|schema| pretty_schema(&schema));
+        Self {
+            path,
+            contents,
+            schema,
+            included: true,
+        }
+    }
+
+    /// File name shown in the picker strip
+    pub fn display_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.path.display().to_string())
+    }
+}
+
+fn pretty_schema(schema: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(schema)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| schema.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputFilesState {
+    files: Vec<InputFile>,
+    selected: usize,
+    visible: bool,
+}
+
+impl InputFilesState {
+    pub fn new(files: Vec<InputFile>) -> Self {
+        Self {
+            files,
+            selected: 0,
+            visible: false,
+        }
+    }
+
+    pub fn files(&self) -> &[InputFile] {
+        &self.files
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_file(&self) -> Option<&InputFile> {
+        self.files.get(self.selected)
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn included_count(&self) -> usize {
+        self.files.iter().filter(|f| f.included).count()
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.files.is_empty() {
+            self.selected = (self.selected + 1) % self.files.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.files.is_empty() {
+            self.selected = (self.selected + self.files.len() - 1) % self.files.len();
+        }
+    }
+
+    /// Flip whether the highlighted file is included
+    ///
+    /// Returns false, leaving the selection unchanged, when that would
+    /// leave no file included: jq needs at least one input.
+    pub fn toggle_selected(&mut self) -> bool {
+        let only_included = self.included_count() == 1;
+        let Some(file) = self.files.get_mut(self.selected) else {
+            return false;
+        };
+        if file.included && only_included {
+            return false;
+        }
+        file.included = !file.included;
+        true
+    }
+
+    /// The included files concatenated as one JSON stream, in order
+    pub fn included_json(&self) -> String {
+        self.files
+            .iter()
+            .filter(|f| f.included)
+            .map(|f| f.contents.trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+#[path = "input_files_tests.rs"]
+mod input_files_tests;
@@ -0,0 +1,97 @@
+//! Tests for input_files
+
+use super::*;
+
+fn state() -> InputFilesState {
+    InputFilesState::new(vec![
+        InputFile::new(PathBuf::from("/tmp/a.json"), "{\"a\": 1}\n".to_string()),
+        InputFile::new(PathBuf::from("/tmp/b.json"), "[{\"b\": \"x\"}]".to_string()),
+        InputFile::new(
+            PathBuf::from("c.jsonl"),
+            "{\"c\":true}\n{\"c\":false}\n".to_string(),
+        ),
+    ])
+}
+
+#[test]
+fn test_new_file_is_included_with_pretty_schema() {
+    let file = InputFile::new(PathBuf::from("/data/a.json"), r#"{"id": 1}"#.to_string());
+
+    assert!(file.included);
+    assert_eq!(file.display_name(), "a.json");
+    assert_eq!(file.schema.as_deref(), Some("{\n  \"id\": \"number\"\n}"));
+}
+
+#[test]
+fn test_schema_uses_first_value_of_jsonl() {
+    let file = InputFile::new(
+        PathBuf::from("x.jsonl"),
+        "{\"a\":1}\n{\"b\":2}\n".to_string(),
+    );
+
+    let schema = file.schema.unwrap();
+    assert!(schema.contains("\"a\""));
+    assert!(!schema.contains("\"b\""));
+}
+
+#[test]
+fn test_included_json_joins_files_in_order() {
+    let state = state();
+    assert_eq!(
+        state.included_json(),
+        "{\"a\": 1}\n[{\"b\": \"x\"}]\n{\"c\":true}\n{\"c\":false}"
+    );
+}
+
+#[test]
+fn test_toggle_excludes_selected_file() {
+    let mut state = state();
+    state.select_next();
+
+    assert!(state.toggle_selected());
+
+    assert!(!state.files()[1].included);
+    assert_eq!(state.included_count(), 2);
+    assert!(!state.included_json().contains("\"b\""));
+}
+
+#[test]
+fn test_toggle_refuses_to_exclude_last_included_file() {
+    let mut state = state();
+    state.toggle_selected();
+    state.select_next();
+    state.toggle_selected();
+    state.select_next();
+
+    assert!(!state.toggle_selected());
+    assert!(state.files()[2].included);
+    assert_eq!(state.included_count(), 1);
+}
+
+#[test]
+fn test_toggle_reincludes_excluded_file() {
+    let mut state = state();
+    state.toggle_selected();
+    assert!(state.toggle_selected());
+    assert_eq!(state.included_count(), 3);
+}
+
+#[test]
+fn test_selection_wraps() {
+    let mut state = state();
+    state.select_previous();
+    assert_eq!(state.selected(), 2);
+    state.select_next();
+    assert_eq!(state.selected(), 0);
+    assert_eq!(state.selected_file().unwrap().display_name(), "a.json");
+}
+
+#[test]
+fn test_open_and_close() {
+    let mut state = state();
+    assert!(!state.is_visible());
+    state.open();
+    assert!(state.is_visible());
+    state.close();
+    assert!(!state.is_visible());
+}
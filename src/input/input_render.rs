@@ -1,19 +1,66 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::Style,
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
 use crate::app::{App, Focus};
+use crate::autocomplete::ghost_text::ghost_text;
 use crate::editor::EditorMode;
 use crate::syntax_highlight::JqHighlighter;
 use crate::syntax_highlight::bracket_matcher::find_matching_bracket;
 use crate::syntax_highlight::overlay::{
-    extract_visible_spans, highlight_bracket_pairs, insert_cursor_into_spans,
+    extract_visible_spans, highlight_bracket_pairs, insert_cursor_into_spans, underline_ranges,
 };
 use crate::theme;
+use crate::tooltip::{SignatureHelp, signature_at_cursor};
+
+/// Border hints ending in the Ctrl+A entry, greyed out as "AI Offline" in offline mode
+fn ai_hint_line(
+    offline: bool,
+    leading: &[(&'static str, &'static str)],
+    border_color: ratatui::style::Color,
+) -> Line<'static> {
+    if !offline {
+        let mut hints = leading.to_vec();
+        hints.push(("Ctrl+A", "AI Assistant"));
+        return theme::border_hints::build_hints(&hints, border_color);
+    }
+
+    let mut line = theme::border_hints::build_hints(leading, border_color);
+    if !leading.is_empty() {
+        line.spans
+            .push(theme::border_hints::separator(border_color));
+    }
+    line.spans.extend(theme::border_hints::hint(
+        "Ctrl+A",
+        "AI Offline",
+        theme::palette::text_dim(),
+    ));
+    line
+}
+
+/// `sub(regex; str; flags?)` with the argument being typed picked out
+fn signature_line(help: &SignatureHelp, color: Color) -> Line<'static> {
+    let dim = Style::default().fg(theme::input::unfocused_hint());
+    let mut spans = vec![Span::styled(format!(" {}(", help.function), dim)];
+    for (i, param) in help.params.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled("; ", dim));
+        }
+        let optional = if i >= help.optional_from { "?" } else { "" };
+        let style = if i == help.current {
+            Style::default().fg(color).add_modifier(Modifier::BOLD)
+        } else {
+            dim
+        };
+        spans.push(Span::styled(format!("{}{}", param, optional), style));
+    }
+    spans.push(Span::styled(") ", dim));
+    Line::from(spans)
+}
 
 /// Render the input field
 ///
@@ -29,19 +76,20 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         EditorMode::CharSearch(_, _) => theme::input::mode_char_search(),
         EditorMode::OperatorCharSearch(_, _, _, _) => theme::input::mode_operator(),
         EditorMode::TextObject(_, _) => theme::input::mode_operator(),
+        EditorMode::Register(_) | EditorMode::Wrap => theme::input::mode_operator(),
     };
 
     let has_error = app.query.as_ref().is_some_and(|q| q.result.is_err());
 
     let border_color = if has_error {
         theme::input::border_error()
-    } else if app.focus == Focus::InputField {
+    } else if app.has_focus(Focus::InputField) {
         mode_color
     } else {
         theme::input::border_unfocused()
     };
 
-    let is_focused = app.focus == Focus::InputField;
+    let is_focused = app.has_focus(Focus::InputField);
     let mode_display_color = if has_error {
         theme::input::border_error()
     } else if is_focused {
@@ -51,7 +99,7 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
     };
 
     let mode_text = app.input.editor_mode.display();
-    let title_spans = match app.input.editor_mode {
+    let mut title_spans = match app.input.editor_mode {
         EditorMode::Normal => {
             vec![
                 Span::raw(" Query ["),
@@ -68,6 +116,36 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         }
     };
 
+    if let Some(register) = app.macros.recording() {
+        title_spans.push(Span::styled(
+            format!("recording @{} ", register),
+            Style::default().fg(theme::input::mode_operator()),
+        ));
+    }
+    if app.jq_args.null_input() {
+        title_spans.push(Span::styled(
+            "null input ",
+            Style::default().fg(theme::input::unfocused_hint()),
+        ));
+    }
+    if app.jq_args.raw_input() {
+        title_spans.push(Span::styled(
+            "raw input ",
+            Style::default().fg(theme::input::unfocused_hint()),
+        ));
+    }
+    if app.jq_args.stream() {
+        title_spans.push(Span::styled(
+            "stream ",
+            Style::default().fg(theme::input::unfocused_hint()),
+        ));
+    }
+    if app.history.is_private() {
+        title_spans.push(Span::styled(
+            "private ",
+            Style::default().fg(theme::input::unfocused_hint()),
+        ));
+    }
     let title = Line::from(title_spans);
 
     let mut block = Block::default()
@@ -85,22 +163,25 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         // AI active: show nothing
     } else if tooltip_active {
         // Tooltip active: show only AI hint
-        let ai_hint = theme::border_hints::build_hints(&[("Ctrl+A", "AI Assistant")], border_color);
+        let ai_hint = ai_hint_line(app.ai.offline, &[], border_color);
         block = block.title_top(ai_hint.alignment(Alignment::Right));
     } else if has_tooltip_available {
         // Neither active, tooltip available: show both hints
-        let combined = theme::border_hints::build_hints(
-            &[("Ctrl+I", "Tooltip"), ("Ctrl+A", "AI Assistant")],
-            border_color,
-        );
+        let combined = ai_hint_line(app.ai.offline, &[("Ctrl+I", "Tooltip")], border_color);
         block = block.title_top(combined.alignment(Alignment::Right));
     } else if is_focused {
         // Neither active, no tooltip: show only AI hint
-        let ai_hint = theme::border_hints::build_hints(&[("Ctrl+A", "AI Assistant")], border_color);
+        let ai_hint = ai_hint_line(app.ai.offline, &[], border_color);
         block = block.title_top(ai_hint.alignment(Alignment::Right));
     }
 
-    if is_focused {
+    let signature = (is_focused && app.input.editor_mode == EditorMode::Insert)
+        .then(|| signature_at_cursor(app.query(), app.input.textarea.cursor().1))
+        .flatten();
+
+    if let Some(signature) = &signature {
+        block = block.title_bottom(signature_line(signature, mode_color));
+    } else if is_focused {
         if has_error {
             block = block.title_bottom(
                 theme::border_hints::build_hints(
@@ -150,7 +231,13 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         let paragraph = Paragraph::new(Line::from(final_spans)).block(block);
         frame.render_widget(paragraph, area);
     } else {
-        let highlighted_spans = JqHighlighter::highlight(query);
+        let lint_ranges: Vec<(usize, usize)> = app
+            .input
+            .lints()
+            .iter()
+            .map(|lint| (lint.start, lint.end))
+            .collect();
+        let highlighted_spans = underline_ranges(JqHighlighter::highlight(query), &lint_ranges);
 
         let spans_with_brackets =
             if let Some(bracket_positions) = find_matching_bracket(query, cursor_col) {
@@ -159,11 +246,20 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
                 highlighted_spans
             };
 
-        let visible_spans =
+        let mut visible_spans =
             extract_visible_spans(&spans_with_brackets, scroll_offset, viewport_width);
 
         let final_spans = if is_focused {
             let cursor_in_viewport = cursor_col.saturating_sub(scroll_offset);
+            // The cursor sits at the end whenever there is ghost text, so it
+            // lands on the first ghost character
+            if let Some(ghost) = ghost_text(app) {
+                let room = viewport_width.saturating_sub(cursor_in_viewport);
+                visible_spans.push(Span::styled(
+                    ghost.chars().take(room).collect::<String>(),
+                    Style::default().fg(theme::input::ghost_text()),
+                ));
+            }
             insert_cursor_into_spans(visible_spans, cursor_in_viewport)
         } else {
             visible_spans
@@ -146,3 +146,97 @@ fn snapshot_query_unfocused_normal_mode_with_syntax_error() {
     let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_query_offline_ai_hint() {
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".name");
+    app.query.as_mut().unwrap().execute(".name");
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+    app.ai.set_offline();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_query_ghost_text() {
+    let json = r#"{"name": "Alice", "age": 30}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".na");
+    app.query.as_mut().unwrap().execute(".na");
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+    crate::autocomplete::update_suggestions_from_app(&mut app);
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_ghost_text_is_dimmed() {
+    let json = r#"{"name": "Alice", "age": 30}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".na");
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+    crate::autocomplete::update_suggestions_from_app(&mut app);
+
+    let backend = TestBackend::new(TEST_WIDTH, TEST_HEIGHT);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    // The query line is the second row of the input box; "me" follows ".na"
+    let row = TEST_HEIGHT - 3;
+    assert_eq!(buffer[(5, row)].symbol(), "e");
+    assert_eq!(buffer[(5, row)].fg, crate::theme::input::ghost_text());
+}
+
+#[test]
+fn snapshot_query_signature_help() {
+    let mut app = test_app(r#"{"name": "Alice"}"#);
+    app.input.textarea.insert_str(r#".name | sub("A"; "#);
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_signature_help_highlights_the_current_argument() {
+    let mut app = test_app(r#"{"name": "Alice"}"#);
+    app.input.textarea.insert_str(r#".name | sub("A"; "#);
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+    let backend = TestBackend::new(TEST_WIDTH, TEST_HEIGHT);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let row = (0..TEST_HEIGHT)
+        .find(|y| {
+            (0..TEST_WIDTH)
+                .map(|x| buffer[(x, *y)].symbol())
+                .collect::<String>()
+                .contains("sub(regex; str; flags?)")
+        })
+        .expect("signature line");
+    let text: String = (0..TEST_WIDTH).map(|x| buffer[(x, row)].symbol()).collect();
+    let str_x = text.find("str;").unwrap() as u16;
+    // The border is drawn in box-drawing characters, so index by chars
+    let str_x = text[..str_x as usize].chars().count() as u16;
+    assert!(
+        buffer[(str_x, row)]
+            .modifier
+            .contains(ratatui::style::Modifier::BOLD)
+    );
+    assert!(
+        !buffer[(str_x - 4, row)]
+            .modifier
+            .contains(ratatui::style::Modifier::BOLD)
+    );
+}
@@ -6,6 +6,7 @@ use tui_textarea::TextArea;
 
 use crate::autocomplete::BraceTracker;
 use crate::editor::{CharSearchState, EditorMode};
+use crate::query::lint::Lint;
 use crate::theme;
 
 pub struct InputState {
@@ -15,6 +16,8 @@ pub struct InputState {
     pub brace_tracker: BraceTracker,
     pub last_char_search: Option<CharSearchState>,
     pub manual_scroll_active: bool,
+    /// The query last linted, with its lints
+    linted: (String, Vec<Lint>),
 }
 
 impl InputState {
@@ -37,6 +40,7 @@ impl InputState {
             brace_tracker: BraceTracker::new(),
             last_char_search: None,
             manual_scroll_active: false,
+            linted: (String::new(), Vec::new()),
         }
     }
 
@@ -44,26 +48,60 @@ impl InputState {
         self.textarea.lines()[0].as_ref()
     }
 
+    /// Lints of the query as it reads now; none once it's been edited
+    /// since it was linted
+    pub fn lints(&self) -> &[Lint] {
+        if self.linted.0 == self.query() {
+            &self.linted.1
+        } else {
+            &[]
+        }
+    }
+
+    /// Keep `lints` for `query`, returning the first whose message the
+    /// previous lints didn't have
+    pub fn set_lints(&mut self, query: &str, lints: Vec<Lint>) -> Option<&Lint> {
+        let previous = std::mem::replace(&mut self.linted, (query.to_string(), lints));
+        self.linted
+            .1
+            .iter()
+            .find(|lint| !previous.1.iter().any(|old| old.message == lint.message))
+    }
+
+    /// Scroll so the cursor is inside a viewport `viewport_width` columns
+    /// wide. The offset is the first visible char; wide chars such as CJK
+    /// and emoji take two columns each.
     pub fn calculate_scroll_offset(&mut self, viewport_width: usize) {
         let cursor_col = self.textarea.cursor().1;
-        let text_length = self.query().chars().count();
+        let widths = crate::str_utils::char_widths(self.query());
+        let text_length = widths.len();
+        let width = |from: usize, to: usize| -> usize { widths[from..to].iter().sum() };
+        // The cursor past the end is drawn as a space
+        let cursor_width = widths.get(cursor_col).copied().unwrap_or(1).max(1);
+        let cursor_fits =
+            |scroll: usize| width(scroll, cursor_col) + cursor_width <= viewport_width;
+        let text_fits = |scroll: usize| width(scroll, text_length) <= viewport_width;
 
-        let mut new_scroll = self.scroll_offset;
+        let mut new_scroll = self.scroll_offset.min(text_length);
 
         if !self.manual_scroll_active {
             if cursor_col < new_scroll {
                 new_scroll = cursor_col;
-            } else if cursor_col >= new_scroll + viewport_width {
-                new_scroll = cursor_col + 1 - viewport_width;
             }
-
-            if text_length < new_scroll + viewport_width {
-                let min_scroll = text_length.saturating_sub(viewport_width);
-                let max_scroll_for_cursor = cursor_col.saturating_sub(viewport_width - 1);
-                new_scroll = new_scroll.min(min_scroll.max(max_scroll_for_cursor));
+            while new_scroll < cursor_col && !cursor_fits(new_scroll) {
+                new_scroll += 1;
+            }
+            while new_scroll > 0
+                && new_scroll <= cursor_col
+                && cursor_fits(new_scroll - 1)
+                && text_fits(new_scroll - 1)
+            {
+                new_scroll -= 1;
+            }
+        } else {
+            while new_scroll > 0 && text_fits(new_scroll - 1) {
+                new_scroll -= 1;
             }
-        } else if text_length < new_scroll + viewport_width {
-            new_scroll = new_scroll.min(text_length.saturating_sub(viewport_width));
         }
 
         self.scroll_offset = new_scroll;
@@ -88,6 +126,27 @@ impl InputState {
         self.manual_scroll_active = false;
     }
 
+    /// Carry on a cursor move from `previous_col` that stopped inside a
+    /// grapheme, such as a ZWJ emoji or a letter with combining marks, so
+    /// the cursor steps over what's drawn as one glyph.
+    pub fn snap_cursor_to_grapheme(&mut self, previous_col: usize) {
+        use tui_textarea::CursorMove;
+
+        let forward = match self.textarea.cursor().1.cmp(&previous_col) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => return,
+        };
+        let query = self.query().to_string();
+        while !crate::str_utils::is_grapheme_boundary(&query, self.textarea.cursor().1) {
+            self.textarea.move_cursor(if forward {
+                CursorMove::Forward
+            } else {
+                CursorMove::Back
+            });
+        }
+    }
+
     /// Move cursor to a specific column position
     pub fn set_cursor_column(&mut self, target_col: usize) {
         use tui_textarea::CursorMove;
@@ -109,6 +168,7 @@ impl InputState {
             }
             std::cmp::Ordering::Equal => {}
         }
+        self.snap_cursor_to_grapheme(current_col);
     }
 }
 
@@ -157,7 +157,8 @@ fn test_scroll_offset_unicode_chars() {
 
     state.calculate_scroll_offset(viewport_width);
 
-    assert_eq!(state.scroll_offset, 7);
+    // "rld🌍Test" and the cursor cell fill the 10 columns: the emoji is two
+    assert_eq!(state.scroll_offset, 8);
 
     for _ in 0..5 {
         state.textarea.delete_char();
@@ -636,3 +637,70 @@ fn test_manual_scroll_reset_restores_cursor_tracking() {
     assert!(cursor_col >= state.scroll_offset);
     assert!(cursor_col < state.scroll_offset + viewport_width);
 }
+
+#[test]
+fn test_set_lints_returns_only_a_new_message() {
+    let mut state = InputState::new();
+    state.textarea.insert_str("select()");
+    let lint = |message: &str| Lint {
+        start: 0,
+        end: 6,
+        message: message.to_string(),
+    };
+
+    assert_eq!(
+        state
+            .set_lints("select()", vec![lint("a")])
+            .map(|l| l.message.clone()),
+        Some("a".to_string())
+    );
+    assert!(state.set_lints("select()", vec![lint("a")]).is_none());
+    assert_eq!(
+        state
+            .set_lints("select()", vec![lint("a"), lint("b")])
+            .map(|l| l.message.clone()),
+        Some("b".to_string())
+    );
+    assert_eq!(state.lints().len(), 2);
+    assert!(state.set_lints("other", vec![lint("a")]).is_none());
+    assert!(state.lints().is_empty());
+}
+
+#[test]
+fn test_scroll_offset_counts_wide_chars_as_two_columns() {
+    let mut state = InputState::new();
+    let viewport_width = 10;
+
+    state.textarea.insert_str(".名前.住所.都市");
+    state.calculate_scroll_offset(viewport_width);
+
+    let query = state.query().to_string();
+    let cursor = state.textarea.cursor().1;
+    let shown: usize = crate::str_utils::char_widths(&query)[state.scroll_offset..cursor]
+        .iter()
+        .sum();
+    assert!(shown < viewport_width, "cursor past the viewport: {shown}");
+    assert!(shown + 1 >= viewport_width - 1, "viewport left half empty");
+}
+
+#[test]
+fn test_scroll_offset_pulls_back_when_wide_text_fits() {
+    let mut state = InputState::new();
+    state.textarea.insert_str("名前");
+    state.scroll_offset = 2;
+
+    state.calculate_scroll_offset(10);
+
+    assert_eq!(state.scroll_offset, 0);
+}
+
+#[test]
+fn test_set_cursor_column_steps_over_emoji_sequence() {
+    let mut state = InputState::new();
+    state.textarea.insert_str("a👍🏽b");
+    state.textarea.move_cursor(tui_textarea::CursorMove::Head);
+
+    state.set_cursor_column(2);
+
+    assert_eq!(state.textarea.cursor().1, 3);
+}
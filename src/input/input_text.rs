@@ -0,0 +1,100 @@
+//! The input document, shared instead of copied.
+//!
+//! The loader, the executor, the query worker and every tab or split pane
+//! over the same document hold an [`InputText`]: a reference count on one
+//! buffer. Files past `[large_input] threshold_mb` are memory-mapped, so
+//! the text lives in the page cache rather than in jiq's own memory.
+
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Input text that is cheap to clone
+#[derive(Clone)]
+pub struct InputText(Arc<Storage>);
+
+enum Storage {
+    Owned(String),
+    /// Checked to be UTF-8 when it was mapped
+    Mapped(memmap2::Mmap),
+}
+
+impl InputText {
+    /// Map `file` into memory
+    ///
+    /// Fails like `read_to_string` when the text isn't UTF-8.
+    pub fn map(file: &File) -> io::Result<Self> {
+        // SAFETY: the map is read-only and never handed out mutably. As with
+        // any tool that maps its input, a file truncated by another process
+        // while jiq runs faults on the next read of the lost pages.
+        let map = unsafe { memmap2::Mmap::map(file)? };
+        if std::str::from_utf8(&map).is_err() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            ));
+        }
+        Ok(Self(Arc::new(Storage::Mapped(map))))
+    }
+
+    /// Whether the text is a memory-mapped file
+    pub fn is_mapped(&self) -> bool {
+        matches!(*self.0, Storage::Mapped(_))
+    }
+}
+
+impl Deref for InputText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match &*self.0 {
+            Storage::Owned(text) => text,
+            // SAFETY: checked in `map`, and the map is never written
+            Storage::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+impl From<String> for InputText {
+    fn from(text: String) -> Self {
+        Self(Arc::new(Storage::Owned(text)))
+    }
+}
+
+impl fmt::Debug for InputText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl PartialEq for InputText {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for InputText {}
+
+impl PartialEq<str> for InputText {
+    fn eq(&self, other: &str) -> bool {
+        &**self == other
+    }
+}
+
+impl PartialEq<&str> for InputText {
+    fn eq(&self, other: &&str) -> bool {
+        &**self == *other
+    }
+}
+
+impl PartialEq<String> for InputText {
+    fn eq(&self, other: &String) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(test)]
+#[path = "input_text_tests.rs"]
+mod input_text_tests;
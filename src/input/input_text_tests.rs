@@ -0,0 +1,54 @@
+use super::*;
+
+use std::io::Write;
+
+fn temp_file(contents: &[u8]) -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(contents).unwrap();
+    file
+}
+
+#[test]
+fn owned_text_reads_as_the_string() {
+    let text = InputText::from(r#"{"a": 1}"#.to_string());
+    assert_eq!(text, r#"{"a": 1}"#);
+    assert_eq!(text.len(), 8);
+    assert!(!text.is_mapped());
+}
+
+#[test]
+fn clones_share_one_buffer() {
+    let text = InputText::from("[1, 2, 3]".to_string());
+    let clone = text.clone();
+    assert_eq!(text.as_ptr(), clone.as_ptr());
+}
+
+#[test]
+fn mapped_file_reads_as_its_contents() {
+    let file = temp_file("{\"名前\": \"x\"}\n".as_bytes());
+    let text = InputText::map(file.as_file()).unwrap();
+    assert!(text.is_mapped());
+    assert_eq!(text, "{\"名前\": \"x\"}\n");
+}
+
+#[test]
+fn mapping_an_empty_file_gives_empty_text() {
+    let file = temp_file(b"");
+    let text = InputText::map(file.as_file()).unwrap();
+    assert_eq!(text, "");
+}
+
+#[test]
+fn mapping_rejects_text_that_is_not_utf8() {
+    let file = temp_file(&[b'"', 0xff, 0xfe, b'"']);
+    let err = InputText::map(file.as_file()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn compares_by_contents() {
+    let file = temp_file(b"[1]");
+    let mapped = InputText::map(file.as_file()).unwrap();
+    assert_eq!(mapped, InputText::from("[1]".to_string()));
+    assert_eq!(mapped, "[1]".to_string());
+}
@@ -0,0 +1,198 @@
+//! JSON5 and JSONC input, turned into strict JSON for jq.
+//!
+//! Config files in the wild carry comments, trailing commas, unquoted
+//! keys and single-quoted strings, all of which jq rejects. [`to_json`]
+//! rewrites those into plain JSON and leaves everything else alone, so
+//! whatever is still wrong is reported by the usual parse error. Line
+//! breaks are kept, so that error points at the same line as in the
+//! original text.
+
+use std::path::Path;
+
+/// How input text is parsed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputSyntax {
+    #[default]
+    Json,
+    /// Comments, trailing commas, unquoted keys and single quotes
+    /// allowed, as in JSON5 and JSONC
+    Json5,
+    /// Any text, each line a string to jq, as with `--raw-input`
+    Raw,
+}
+
+impl InputSyntax {
+    /// The syntax to read `path` with: JSON5 for `.json5` and `.jsonc`
+    /// files unless raw text was asked for
+    pub fn for_path(self, path: &Path) -> Self {
+        if self == InputSyntax::Raw {
+            return self;
+        }
+        let lenient_extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("json5") || e.eq_ignore_ascii_case("jsonc"));
+        if lenient_extension {
+            InputSyntax::Json5
+        } else {
+            self
+        }
+    }
+
+    /// `text` as strict JSON, or as it is for raw text
+    pub fn to_json(self, text: String) -> String {
+        match self {
+            InputSyntax::Json | InputSyntax::Raw => text,
+            InputSyntax::Json5 => to_json(&text),
+        }
+    }
+}
+
+/// `text` with comments dropped, trailing commas removed, keys quoted and
+/// single-quoted strings double-quoted
+pub fn to_json(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len() + text.len() / 8);
+    // Index in `out` of a comma that is trailing so far
+    let mut pending_comma: Option<usize> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' => {
+                pending_comma = None;
+                i = copy_string(&chars, i, &mut out);
+                continue;
+            }
+            '\'' => {
+                pending_comma = None;
+                i = requote_string(&chars, i, &mut out);
+                continue;
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    if chars[i] == '\n' {
+                        out.push('\n');
+                    }
+                    i += 1;
+                }
+                out.push(' ');
+                i += 2;
+                continue;
+            }
+            ',' => {
+                pending_comma = Some(out.len());
+                out.push(c);
+            }
+            ']' | '}' => {
+                if let Some(at) = pending_comma.take() {
+                    out.replace_range(at..at + 1, " ");
+                }
+                out.push(c);
+            }
+            c if c.is_whitespace() => out.push(c),
+            c if is_identifier_start(c) => {
+                pending_comma = None;
+                let start = i;
+                while i < chars.len() && is_identifier_part(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                if is_key(&chars, i) {
+                    out.push('"');
+                    out.push_str(&word);
+                    out.push('"');
+                } else {
+                    out.push_str(&word);
+                }
+                continue;
+            }
+            _ => {
+                pending_comma = None;
+                out.push(c);
+            }
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Copy the double-quoted string starting at `start`; returns the index
+/// just past it
+fn copy_string(chars: &[char], start: usize, out: &mut String) -> usize {
+    out.push('"');
+    let mut i = start + 1;
+    while i < chars.len() {
+        let c = chars[i];
+        out.push(c);
+        i += 1;
+        match c {
+            '\\' => {
+                if let Some(&escaped) = chars.get(i) {
+                    out.push(escaped);
+                    i += 1;
+                }
+            }
+            '"' => break,
+            _ => {}
+        }
+    }
+    i
+}
+
+/// Write the single-quoted string starting at `start` in double quotes;
+/// returns the index just past it
+fn requote_string(chars: &[char], start: usize, out: &mut String) -> usize {
+    out.push('"');
+    let mut i = start + 1;
+    while i < chars.len() {
+        let c = chars[i];
+        i += 1;
+        match c {
+            '\\' => match chars.get(i) {
+                Some('\'') => {
+                    out.push('\'');
+                    i += 1;
+                }
+                Some(&escaped) => {
+                    out.push('\\');
+                    out.push(escaped);
+                    i += 1;
+                }
+                None => out.push('\\'),
+            },
+            '"' => out.push_str("\\\""),
+            '\'' => break,
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    i
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Whether a `:` follows `from`, past whitespace
+fn is_key(chars: &[char], from: usize) -> bool {
+    chars[from..]
+        .iter()
+        .find(|c| !c.is_whitespace())
+        .is_some_and(|c| *c == ':')
+}
+
+#[cfg(test)]
+#[path = "json5_tests.rs"]
+mod json5_tests;
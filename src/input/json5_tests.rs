@@ -0,0 +1,107 @@
+use super::*;
+
+fn parse(text: &str) -> serde_json::Value {
+    serde_json::from_str(&to_json(text)).expect("converted text should be strict JSON")
+}
+
+#[test]
+fn strict_json_is_unchanged() {
+    let text = "{\"a\": [1, 2.5, true, null], \"b\": {\"c\": \"d\"}}";
+    assert_eq!(to_json(text), text);
+}
+
+#[test]
+fn drops_line_and_block_comments() {
+    let value = parse("{\n  // name\n  \"a\": 1, /* two\n lines */ \"b\": 2\n}");
+    assert_eq!(value, serde_json::json!({"a": 1, "b": 2}));
+}
+
+#[test]
+fn drops_trailing_commas() {
+    let value = parse("{\"a\": [1, 2, ], \"b\": {\"c\": 3,\n},\n}");
+    assert_eq!(value, serde_json::json!({"a": [1, 2], "b": {"c": 3}}));
+}
+
+#[test]
+fn trailing_comma_before_a_comment_is_dropped() {
+    let value = parse("[\n  1,\n  2, // last\n]");
+    assert_eq!(value, serde_json::json!([1, 2]));
+}
+
+#[test]
+fn quotes_unquoted_keys() {
+    let value = parse("{name: \"Alice\", $id: 1, _private : true}");
+    assert_eq!(
+        value,
+        serde_json::json!({"name": "Alice", "$id": 1, "_private": true})
+    );
+}
+
+#[test]
+fn keywords_as_values_stay_unquoted() {
+    let value = parse("{a: true, b: false, c: null}");
+    assert_eq!(value, serde_json::json!({"a": true, "b": false, "c": null}));
+}
+
+#[test]
+fn requotes_single_quoted_strings() {
+    let value = parse(r#"{'key': 'it\'s "quoted"'}"#);
+    assert_eq!(value, serde_json::json!({"key": "it's \"quoted\""}));
+}
+
+#[test]
+fn leaves_strings_that_look_like_syntax_alone() {
+    let value = parse(r#"{"url": "https://example.com/*x*/", "list": "a, ]", "k": "key: v"}"#);
+    assert_eq!(
+        value,
+        serde_json::json!({"url": "https://example.com/*x*/", "list": "a, ]", "k": "key: v"})
+    );
+}
+
+#[test]
+fn keeps_escaped_quotes_inside_strings() {
+    let value = parse(r#"{"a": "x\" // y"}"#);
+    assert_eq!(value, serde_json::json!({"a": "x\" // y"}));
+}
+
+#[test]
+fn keeps_line_breaks_so_errors_point_at_the_same_line() {
+    let text = "{\n  // comment\n  /* a\n  b */\n  a: 1,\n  b: ?\n}";
+    let converted = to_json(text);
+    assert_eq!(converted.lines().count(), text.lines().count());
+
+    let error = serde_json::from_str::<serde_json::Value>(&converted).unwrap_err();
+    assert_eq!(error.line(), 6);
+}
+
+#[test]
+fn unterminated_block_comment_does_not_panic() {
+    assert_eq!(to_json("[1] /* open").trim_end(), "[1]");
+}
+
+#[test]
+fn for_path_picks_json5_by_extension() {
+    assert_eq!(
+        InputSyntax::Json.for_path(Path::new("tsconfig.jsonc")),
+        InputSyntax::Json5
+    );
+    assert_eq!(
+        InputSyntax::Json.for_path(Path::new("conf/app.JSON5")),
+        InputSyntax::Json5
+    );
+    assert_eq!(
+        InputSyntax::Json.for_path(Path::new("data.json")),
+        InputSyntax::Json
+    );
+    assert_eq!(
+        InputSyntax::Json5.for_path(Path::new("data.json")),
+        InputSyntax::Json5
+    );
+}
+
+#[test]
+fn json_syntax_leaves_text_alone() {
+    let text = "{a: 1,}".to_string();
+    assert_eq!(InputSyntax::Json.to_json(text.clone()), text);
+    assert_eq!(InputSyntax::Json5.to_json(text), "{\"a\": 1 }");
+}
@@ -4,15 +4,18 @@
 //! Uses channels for thread communication following the pattern established by the AI worker.
 
 use std::path::{Path, PathBuf};
-use std::sync::mpsc::{Receiver, channel};
+use std::sync::mpsc::{Receiver, Sender, channel};
 
 use crate::error::JiqError;
+use crate::input::json5::InputSyntax;
+use crate::input::malformed::MalformedInput;
+use crate::input::{InputFile, InputText};
 
 /// Represents the current state of file loading
 #[derive(Debug, Clone, PartialEq)]
 pub enum LoadingState {
     Loading,
-    Complete(String),
+    Complete(InputText),
     Error(JiqError),
 }
 
@@ -30,10 +33,38 @@ pub enum LoaderSource {
 /// Manages asynchronous file loading in a background thread
 pub struct FileLoader {
     pub state: LoadingState,
-    pub rx: Option<Receiver<Result<String, JiqError>>>,
+    pub rx: Option<Receiver<Result<InputText, JiqError>>>,
+    /// Per-file contents when several files were given. Sent before the
+    /// combined stream on `rx`, so it is ready once `poll` succeeds.
+    pub files_rx: Option<Receiver<Vec<InputFile>>>,
+    /// The text of a file or stdin that isn't valid JSON. Sent before
+    /// the error on `rx`, so it is ready once `poll` fails.
+    pub malformed_rx: Option<Receiver<MalformedInput>>,
     pub source: LoaderSource,
 }
 
+/// How a file or stdin is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadOptions {
+    pub syntax: InputSyntax,
+    /// A single file at least this many bytes long is memory-mapped
+    /// instead of read into memory
+    pub map_threshold: u64,
+    /// Empty stdin is no input rather than an error, for `-n`, where
+    /// queries read the documents themselves if there are any
+    pub allow_empty: bool,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            syntax: InputSyntax::Json,
+            map_threshold: crate::config::LargeInputConfig::default().threshold_bytes(),
+            allow_empty: false,
+        }
+    }
+}
+
 impl FileLoader {
     /// Spawn a background thread to load a file
     ///
@@ -42,17 +73,100 @@ impl FileLoader {
     ///
     /// # Arguments
     /// * `path` - Path to the JSON file to load
+    #[allow(dead_code)]
     pub fn spawn_load(path: PathBuf) -> Self {
+        Self::spawn_load_with(path, LoadOptions::default())
+    }
+
+    /// [`spawn_load`](Self::spawn_load) with `options`
+    pub fn spawn_load_with(path: PathBuf, options: LoadOptions) -> Self {
         let (tx, rx) = channel();
+        let (malformed_tx, malformed_rx) = channel();
 
         std::thread::spawn(move || {
-            let result = load_file_sync(&path);
+            let syntax = options.syntax.for_path(&path);
+            let result = read_input_file(&path, options.map_threshold)
+                .and_then(|c| validated(c, syntax, &malformed_tx));
             let _ = tx.send(result);
         });
 
         Self {
             state: LoadingState::Loading,
             rx: Some(rx),
+            files_rx: None,
+            malformed_rx: Some(malformed_rx),
+            source: LoaderSource::File,
+        }
+    }
+
+    /// Spawn a background thread to load `path` through the input plugin
+    /// at `plugin`, which prints it as JSON
+    pub fn spawn_convert(path: PathBuf, plugin: PathBuf) -> Self {
+        let (tx, rx) = channel();
+        let (malformed_tx, malformed_rx) = channel();
+
+        std::thread::spawn(move || {
+            let result = crate::plugins::convert_input(&plugin, &path)
+                .map_err(|e| with_path(JiqError::Io(e), &path))
+                .and_then(|json| validated(json.into(), InputSyntax::Json, &malformed_tx));
+            let _ = tx.send(result);
+        });
+
+        Self {
+            state: LoadingState::Loading,
+            rx: Some(rx),
+            files_rx: None,
+            malformed_rx: Some(malformed_rx),
+            source: LoaderSource::File,
+        }
+    }
+
+    /// Spawn a background thread to load several files as one input stream
+    ///
+    /// Each file is read and validated on its own, so an error names the
+    /// file at fault. On success the files are concatenated in order, the
+    /// same stream jq sees for `jq . a.json b.json`. A single path behaves
+    /// exactly like [`spawn_load`](Self::spawn_load).
+    #[allow(dead_code)]
+    pub fn spawn_load_files(paths: Vec<PathBuf>) -> Self {
+        Self::spawn_load_files_with(paths, LoadOptions::default())
+    }
+
+    /// [`spawn_load_files`](Self::spawn_load_files) with `options`
+    pub fn spawn_load_files_with(paths: Vec<PathBuf>, options: LoadOptions) -> Self {
+        if let [path] = paths.as_slice() {
+            return Self::spawn_load_with(path.clone(), options);
+        }
+
+        let (tx, rx) = channel();
+        let (files_tx, files_rx) = channel();
+
+        std::thread::spawn(move || {
+            let files = paths
+                .into_iter()
+                .map(|path| {
+                    load_file_sync(&path, options.syntax.for_path(&path))
+                        .map(|contents| InputFile::new(path.clone(), contents))
+                        .map_err(|e| with_path(e, &path))
+                })
+                .collect::<Result<Vec<_>, _>>();
+            let result = files.map(|files| {
+                let combined = files
+                    .iter()
+                    .map(|f| f.contents.trim())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let _ = files_tx.send(files);
+                InputText::from(combined)
+            });
+            let _ = tx.send(result);
+        });
+
+        Self {
+            state: LoadingState::Loading,
+            rx: Some(rx),
+            files_rx: Some(files_rx),
+            malformed_rx: None,
             source: LoaderSource::File,
         }
     }
@@ -61,17 +175,32 @@ impl FileLoader {
     ///
     /// Creates a background thread that reads from stdin, validates JSON,
     /// and sends the result back via a channel.
+    #[allow(dead_code)]
     pub fn spawn_load_stdin() -> Self {
+        Self::spawn_load_stdin_with(LoadOptions::default())
+    }
+
+    /// [`spawn_load_stdin`](Self::spawn_load_stdin) with `options`; stdin
+    /// is never memory-mapped
+    pub fn spawn_load_stdin_with(options: LoadOptions) -> Self {
         let (tx, rx) = channel();
+        let (malformed_tx, malformed_rx) = channel();
 
         std::thread::spawn(move || {
-            let result = load_stdin_sync();
+            let result = read_stdin_sync().and_then(|c| {
+                if options.allow_empty && c.trim().is_empty() {
+                    return Ok(InputText::from(String::new()));
+                }
+                validated(c.into(), options.syntax, &malformed_tx)
+            });
             let _ = tx.send(result);
         });
 
         Self {
             state: LoadingState::Loading,
             rx: Some(rx),
+            files_rx: None,
+            malformed_rx: Some(malformed_rx),
             source: LoaderSource::Stdin,
         }
     }
@@ -89,7 +218,7 @@ impl FileLoader {
     /// generally cannot reach jiq because most terminals refuse to forward
     /// host-clipboard reads back through the SSH tunnel for security reasons.
     pub fn load_clipboard_blocking() -> Self {
-        let result = load_clipboard_sync();
+        let result = load_clipboard_sync().map(InputText::from);
         let state = match &result {
             Ok(json) => LoadingState::Complete(json.clone()),
             Err(e) => LoadingState::Error(e.clone()),
@@ -100,6 +229,8 @@ impl FileLoader {
         Self {
             state,
             rx: Some(rx),
+            files_rx: None,
+            malformed_rx: None,
             source: LoaderSource::Clipboard,
         }
     }
@@ -111,20 +242,37 @@ impl FileLoader {
     /// so a second read would be wasted work (and on remote SSH a
     /// second OSC 52 round-trip).
     pub fn from_clipboard_string(json: String) -> Self {
+        let json = InputText::from(json);
         let (tx, rx) = channel();
         let _ = tx.send(Ok(json.clone()));
         Self {
             state: LoadingState::Complete(json),
             rx: Some(rx),
+            files_rx: None,
+            malformed_rx: None,
             source: LoaderSource::Clipboard,
         }
     }
 
+    /// Wrap JSON text already in memory in a `FileLoader`, as if read
+    /// from a file. Used to drive the app headlessly.
+    pub fn from_json(json: String) -> Self {
+        let (tx, rx) = channel();
+        let _ = tx.send(Ok(json.into()));
+        Self {
+            state: LoadingState::Loading,
+            rx: Some(rx),
+            files_rx: None,
+            malformed_rx: None,
+            source: LoaderSource::File,
+        }
+    }
+
     /// Poll for loading completion (non-blocking)
     ///
     /// Checks the channel for results without blocking. Returns None if still loading,
     /// or Some with the result when complete.
-    pub fn poll(&mut self) -> Option<Result<String, JiqError>> {
+    pub fn poll(&mut self) -> Option<Result<InputText, JiqError>> {
         if let Some(rx) = &self.rx {
             match rx.try_recv() {
                 Ok(result) => {
@@ -149,6 +297,20 @@ impl FileLoader {
         }
     }
 
+    /// Take the per-file contents of a multi-file load, if any
+    pub fn take_files(&mut self) -> Option<Vec<InputFile>> {
+        let files = self.files_rx.as_ref()?.try_recv().ok()?;
+        self.files_rx = None;
+        Some(files)
+    }
+
+    /// Take the text of an input that failed to parse, if any
+    pub fn take_malformed(&mut self) -> Option<MalformedInput> {
+        let malformed = self.malformed_rx.as_ref()?.try_recv().ok()?;
+        self.malformed_rx = None;
+        Some(malformed)
+    }
+
     /// Get the current loading state
     pub fn state(&self) -> &LoadingState {
         &self.state
@@ -200,16 +362,37 @@ pub(crate) fn scan_json_or_jsonl(content: &str) -> Result<JsonScan, JiqError> {
 
 /// Validate that content is valid JSON or JSONL.
 ///
-/// Thin wrapper over [`scan_json_or_jsonl`] for callers that only care
-/// about parse validity (file/stdin loaders, paste-recovery accept).
+/// Unlike [`scan_json_or_jsonl`] it builds no values, so checking a large
+/// input never holds a second, parsed copy of it. Used by the file/stdin
+/// loaders and paste-recovery accept.
 pub(crate) fn validate_json_or_jsonl(content: &str) -> Result<(), JiqError> {
-    scan_json_or_jsonl(content).map(|_| ())
+    let deserializer =
+        serde_json::Deserializer::from_str(content).into_iter::<serde::de::IgnoredAny>();
+    let mut count = 0;
+    for result in deserializer {
+        result.map_err(|e| JiqError::InvalidJson(e.to_string()))?;
+        count += 1;
+    }
+    if count == 0 {
+        return Err(JiqError::InvalidJson("Empty input".to_string()));
+    }
+    log::debug!("JSON validation: {} top-level value(s)", count);
+    Ok(())
 }
 
 /// Synchronous file loading (runs in background thread)
 ///
-/// Reads the file from disk and validates that it contains valid JSON or JSONL.
-fn load_file_sync(path: &Path) -> Result<String, JiqError> {
+/// Reads the file from disk and validates that it contains valid JSON or JSONL,
+/// once converted to strict JSON when `syntax` is JSON5.
+fn load_file_sync(path: &Path, syntax: InputSyntax) -> Result<String, JiqError> {
+    let contents = syntax.to_json(read_file_sync(path)?);
+    if syntax != InputSyntax::Raw {
+        validate_json_or_jsonl(&contents)?;
+    }
+    Ok(contents)
+}
+
+fn read_file_sync(path: &Path) -> Result<String, JiqError> {
     use std::fs::File;
     use std::io::Read;
 
@@ -218,16 +401,71 @@ fn load_file_sync(path: &Path) -> Result<String, JiqError> {
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
     log::debug!("File read: {} bytes", contents.len());
+    Ok(contents)
+}
 
-    validate_json_or_jsonl(&contents)?;
+/// Read `path`, memory-mapping it when it is at least `map_threshold`
+/// bytes long
+fn read_input_file(path: &Path, map_threshold: u64) -> Result<InputText, JiqError> {
+    let file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    if len < map_threshold {
+        return read_file_sync(path).map(InputText::from);
+    }
+    log::debug!("Mapping file: {:?} ({} bytes)", path, len);
+    Ok(InputText::map(&file)?)
+}
 
-    Ok(contents)
+/// `contents` as strict JSON when, read as `syntax`, it is valid JSON or
+/// JSONL; otherwise the error, with the text handed to `malformed_tx` for
+/// recovery
+fn validated(
+    contents: InputText,
+    syntax: InputSyntax,
+    malformed_tx: &Sender<MalformedInput>,
+) -> Result<InputText, JiqError> {
+    if syntax == InputSyntax::Raw {
+        return Ok(contents);
+    }
+    if syntax == InputSyntax::Json {
+        return match validate_json_or_jsonl(&contents) {
+            Ok(()) => Ok(contents),
+            Err(e) => {
+                if let Some(malformed) = MalformedInput::find(contents.to_string()) {
+                    let _ = malformed_tx.send(malformed);
+                }
+                Err(e)
+            }
+        };
+    }
+    let json = crate::input::json5::to_json(&contents);
+    match validate_json_or_jsonl(&json) {
+        Ok(()) => Ok(json.into()),
+        Err(e) => {
+            // Line breaks survive the rewrite, so the position of the error
+            // in the strict JSON holds for the text as written
+            if let Some(malformed) = MalformedInput::find(json) {
+                let _ = malformed_tx.send(MalformedInput {
+                    text: contents.to_string(),
+                    ..malformed
+                });
+            }
+            Err(e)
+        }
+    }
 }
 
-/// Synchronous stdin loading (runs in background thread)
-///
-/// Reads from stdin and validates that it contains valid JSON or JSONL.
-fn load_stdin_sync() -> Result<String, JiqError> {
+/// Prefix a load error with the file it came from
+fn with_path(err: JiqError, path: &Path) -> JiqError {
+    match err {
+        JiqError::InvalidJson(msg) => JiqError::InvalidJson(format!("{}: {}", path.display(), msg)),
+        JiqError::Io(msg) => JiqError::Io(format!("{}: {}", path.display(), msg)),
+        other => other,
+    }
+}
+
+/// Synchronous stdin reading (runs in background thread)
+fn read_stdin_sync() -> Result<String, JiqError> {
     use std::io::{self, IsTerminal, Read};
 
     log::debug!("Loading from stdin");
@@ -239,9 +477,6 @@ fn load_stdin_sync() -> Result<String, JiqError> {
     let mut buffer = String::new();
     io::stdin().read_to_string(&mut buffer)?;
     log::debug!("Stdin read: {} bytes", buffer.len());
-
-    validate_json_or_jsonl(&buffer)?;
-
     Ok(buffer)
 }
 
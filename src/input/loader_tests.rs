@@ -18,7 +18,7 @@ fn create_temp_json_file(content: &str) -> (TempDir, PathBuf) {
 fn wait_for_completion(
     loader: &mut FileLoader,
     max_attempts: u32,
-) -> Option<Result<String, JiqError>> {
+) -> Option<Result<InputText, JiqError>> {
     for _ in 0..max_attempts {
         if let Some(result) = loader.poll() {
             return Some(result);
@@ -85,6 +85,31 @@ fn test_file_loader_returns_error_for_invalid_json() {
     assert!(matches!(loader.state(), LoadingState::Error(_)));
 }
 
+#[test]
+fn test_file_loader_keeps_the_text_of_invalid_json() {
+    let invalid_json = "{\n  \"name\": \"test\",\n  invalid\n}";
+    let (_temp_dir, file_path) = create_temp_json_file(invalid_json);
+
+    let mut loader = FileLoader::spawn_load(file_path);
+    let result = wait_for_completion(&mut loader, 100);
+
+    assert!(matches!(result, Some(Err(JiqError::InvalidJson(_)))));
+    let malformed = loader.take_malformed().expect("the text is kept");
+    assert_eq!(malformed.text, invalid_json);
+    assert_eq!(malformed.line, 3);
+    assert!(loader.take_malformed().is_none());
+}
+
+#[test]
+fn test_file_loader_keeps_no_text_for_valid_json() {
+    let (_temp_dir, file_path) = create_temp_json_file(r#"{"a": 1}"#);
+
+    let mut loader = FileLoader::spawn_load(file_path);
+    let _ = wait_for_completion(&mut loader, 100);
+
+    assert!(loader.take_malformed().is_none());
+}
+
 #[test]
 fn test_file_loader_returns_error_for_missing_file() {
     // Requirement 6.2: THE FileLoader SHALL have unit tests verifying error handling for missing files
@@ -102,6 +127,163 @@ fn test_file_loader_returns_error_for_missing_file() {
     assert!(matches!(loader.state(), LoadingState::Error(_)));
 }
 
+fn write_file(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_spawn_load_files_concatenates_in_order() {
+    let dir = TempDir::new().unwrap();
+    let a = write_file(&dir, "a.json", "{\"a\": 1}\n");
+    let b = write_file(&dir, "b.json", "[2]");
+
+    let mut loader = FileLoader::spawn_load_files(vec![a.clone(), b]);
+    let result = wait_for_completion(&mut loader, 100).unwrap();
+
+    assert_eq!(result.unwrap(), "{\"a\": 1}\n[2]");
+    let files = loader.take_files().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].path, a);
+    assert!(loader.take_files().is_none());
+}
+
+#[test]
+fn test_spawn_load_files_error_names_the_file() {
+    let dir = TempDir::new().unwrap();
+    let good = write_file(&dir, "good.json", "{}");
+    let bad = write_file(&dir, "bad.json", "{nope");
+
+    let mut loader = FileLoader::spawn_load_files(vec![good, bad]);
+    let err = wait_for_completion(&mut loader, 100).unwrap().unwrap_err();
+
+    assert!(matches!(err, JiqError::InvalidJson(_)));
+    assert!(err.to_string().contains("bad.json"));
+    assert!(loader.take_files().is_none());
+}
+
+#[test]
+fn test_spawn_load_files_single_path_has_no_file_list() {
+    let (_tmp, path) = create_temp_json_file("{}");
+
+    let mut loader = FileLoader::spawn_load_files(vec![path]);
+    assert!(wait_for_completion(&mut loader, 100).unwrap().is_ok());
+
+    assert!(loader.take_files().is_none());
+}
+
+fn json5() -> LoadOptions {
+    LoadOptions {
+        syntax: InputSyntax::Json5,
+        ..LoadOptions::default()
+    }
+}
+
+fn mapping_from(map_threshold: u64) -> LoadOptions {
+    LoadOptions {
+        map_threshold,
+        ..LoadOptions::default()
+    }
+}
+
+#[test]
+fn test_spawn_load_maps_files_from_the_threshold() {
+    let (_tmp, path) = create_temp_json_file(r#"{"a": 1}"#);
+
+    let mut loader = FileLoader::spawn_load_with(path.clone(), mapping_from(8));
+    let mapped = wait_for_completion(&mut loader, 100).unwrap().unwrap();
+    assert!(mapped.is_mapped());
+    assert_eq!(mapped, r#"{"a": 1}"#);
+
+    let mut loader = FileLoader::spawn_load_with(path, mapping_from(9));
+    let read = wait_for_completion(&mut loader, 100).unwrap().unwrap();
+    assert!(!read.is_mapped());
+}
+
+#[test]
+fn test_mapped_invalid_file_still_shows_as_malformed() {
+    let (_tmp, path) = create_temp_json_file("{\n  \"a\": \n}");
+
+    let mut loader = FileLoader::spawn_load_with(path, mapping_from(0));
+    assert!(wait_for_completion(&mut loader, 100).unwrap().is_err());
+
+    let malformed = loader.take_malformed().unwrap();
+    assert_eq!(malformed.line, 3);
+}
+
+#[test]
+fn test_validate_json_or_jsonl_accepts_streams_and_rejects_empty_input() {
+    assert!(validate_json_or_jsonl("{\"a\": 1}\n[2]\n3").is_ok());
+    assert!(validate_json_or_jsonl("[1, [2, {\"b\": null}]]").is_ok());
+    assert!(validate_json_or_jsonl("  \n ").is_err());
+    assert!(validate_json_or_jsonl("[1, 2").is_err());
+}
+
+#[test]
+fn test_spawn_load_reads_jsonc_files_leniently() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "tsconfig.jsonc", "{\n  // build\n  strict: true,\n}");
+
+    let mut loader = FileLoader::spawn_load(path);
+    let result = wait_for_completion(&mut loader, 100).unwrap().unwrap();
+
+    let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(value, serde_json::json!({"strict": true}));
+}
+
+#[test]
+fn test_spawn_load_as_json5_reads_any_extension() {
+    let (_tmp, path) = create_temp_json_file("[1, 2,]");
+
+    let mut strict = FileLoader::spawn_load(path.clone());
+    assert!(wait_for_completion(&mut strict, 100).unwrap().is_err());
+
+    let mut lenient = FileLoader::spawn_load_with(path, json5());
+    let result = wait_for_completion(&mut lenient, 100).unwrap().unwrap();
+    assert_eq!(serde_json::from_str::<Vec<i32>>(&result).unwrap(), [1, 2]);
+}
+
+#[test]
+fn test_spawn_load_as_json5_keeps_original_text_when_still_invalid() {
+    let text = "{\n  // fine\n  a: 1,\n  b: ?\n}";
+    let (_tmp, path) = create_temp_json_file(text);
+
+    let mut loader = FileLoader::spawn_load_with(path, json5());
+    assert!(wait_for_completion(&mut loader, 100).unwrap().is_err());
+
+    let malformed = loader.take_malformed().unwrap();
+    assert_eq!(malformed.text, text);
+    assert_eq!(malformed.line, 4);
+}
+
+#[test]
+fn test_spawn_load_files_as_json5_converts_each_file() {
+    let dir = TempDir::new().unwrap();
+    let a = write_file(&dir, "a.json", "{a: 1}");
+    let b = write_file(&dir, "b.json", "['x',]");
+
+    let mut loader = FileLoader::spawn_load_files_with(vec![a, b], json5());
+    let result = wait_for_completion(&mut loader, 100).unwrap().unwrap();
+
+    assert_eq!(result, "{\"a\": 1}\n[\"x\" ]");
+}
+
+#[test]
+fn test_spawn_load_as_raw_text_takes_any_text() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "app.json5", "GET /a 200\nPOST /b 500\n");
+    let raw = LoadOptions {
+        syntax: InputSyntax::Raw,
+        ..LoadOptions::default()
+    };
+
+    let mut loader = FileLoader::spawn_load_with(path, raw);
+    let result = wait_for_completion(&mut loader, 100).unwrap().unwrap();
+
+    assert_eq!(result, "GET /a 200\nPOST /b 500\n");
+}
+
 #[test]
 fn test_poll_returns_none_while_loading() {
     // Requirement 6.4: THE FileLoader SHALL have unit tests verifying the poll method returns None while loading
@@ -175,12 +357,12 @@ fn test_spawn_load_stdin_creates_loader() {
 }
 
 #[test]
-fn test_load_stdin_sync_detects_terminal() {
+fn test_read_stdin_sync_detects_terminal() {
     use std::io::IsTerminal;
 
-    // When stdin is a terminal (not piped), load_stdin_sync should error immediately
+    // When stdin is a terminal (not piped), read_stdin_sync should error immediately
     if std::io::stdin().is_terminal() {
-        let result = load_stdin_sync();
+        let result = read_stdin_sync();
         assert!(result.is_err(), "Should error when stdin is a terminal");
         match result.unwrap_err() {
             JiqError::Io(msg) => {
@@ -474,10 +656,12 @@ fn test_poll_returns_io_error_when_thread_disconnects_without_sending() {
     // Simulate a worker thread that drops its sender without sending a result
     // (e.g. it panicked). poll() must surface a user-visible Io error rather
     // than hanging in Loading forever.
-    let (tx, rx) = std::sync::mpsc::channel::<Result<String, JiqError>>();
+    let (tx, rx) = std::sync::mpsc::channel::<Result<InputText, JiqError>>();
     let mut loader = FileLoader {
         state: LoadingState::Loading,
         rx: Some(rx),
+        files_rx: None,
+        malformed_rx: None,
         source: LoaderSource::File,
     };
     drop(tx);
@@ -609,3 +793,31 @@ mod property_tests {
         }
     }
 }
+
+#[cfg(unix)]
+#[test]
+fn test_spawn_convert_loads_what_the_plugin_prints() {
+    let dir = TempDir::new().unwrap();
+    let plugin = crate::test_utils::test_helpers::write_plugin(
+        dir.path(),
+        "csv",
+        r#"echo "[{\"from\": \"$2\"}]""#,
+    );
+
+    let mut loader = FileLoader::spawn_convert(PathBuf::from("data.csv"), plugin);
+    let result = wait_for_completion(&mut loader, 200).unwrap().unwrap();
+
+    assert_eq!(result, "[{\"from\": \"data.csv\"}]\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_spawn_convert_fails_on_output_that_is_not_json() {
+    let dir = TempDir::new().unwrap();
+    let plugin = crate::test_utils::test_helpers::write_plugin(dir.path(), "csv", "echo 'a,b'");
+
+    let mut loader = FileLoader::spawn_convert(PathBuf::from("data.csv"), plugin);
+    let result = wait_for_completion(&mut loader, 200).unwrap();
+
+    assert!(matches!(result, Err(JiqError::InvalidJson(_))));
+}
@@ -0,0 +1,160 @@
+//! Input that isn't valid JSON, and ways to load it anyway.
+//!
+//! When a file or stdin fails to parse, the loader keeps the text as a
+//! [`MalformedInput`] along with where parsing stopped. The results pane
+//! then shows the text with that spot highlighted, and offers a
+//! [`Recovery`]: read it as NDJSON and skip the lines that don't parse,
+//! read every line as a string as `jq -R` does, or read it as JSON5 for
+//! the comments and trailing commas that hand-written JSON often has.
+
+/// Text that failed to parse, and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedInput {
+    pub text: String,
+    /// serde_json's message, without the position
+    pub message: String,
+    /// 1-based line of the first error
+    pub line: usize,
+    /// 1-based column of the first error; 0 at the very start of a line
+    pub column: usize,
+}
+
+impl MalformedInput {
+    /// Find the first parse error in `text`; None when it parses
+    pub fn find(text: String) -> Option<Self> {
+        let mut values = serde_json::Deserializer::from_str(&text).into_iter::<serde_json::Value>();
+        let error = values.find_map(Result::err)?;
+        let message = strip_position(&error.to_string());
+        Some(Self {
+            message,
+            line: error.line().max(1),
+            column: error.column(),
+            text,
+        })
+    }
+
+    /// Summary for the notification and the pane title
+    pub fn summary(&self) -> String {
+        format!(
+            "Invalid JSON at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+
+    /// The input reread with `recovery`
+    pub fn recover(&self, recovery: Recovery) -> Result<Recovered, String> {
+        match recovery {
+            Recovery::Ndjson => ndjson(&self.text),
+            Recovery::RawLines => Ok(raw_lines(&self.text)),
+            Recovery::Lenient => lenient(&self.text),
+        }
+    }
+}
+
+/// serde_json ends its messages with " at line L column C"
+fn strip_position(message: &str) -> String {
+    match message.rfind(" at line ") {
+        Some(at) => message[..at].to_string(),
+        None => message.to_string(),
+    }
+}
+
+/// A way to read input that isn't JSON
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recovery {
+    /// One value per line; lines that don't parse are skipped
+    Ndjson,
+    /// Every line is a string, as with `jq -R`
+    RawLines,
+    /// Read as JSON5: comments, trailing commas, unquoted keys and single
+    /// quotes allowed
+    Lenient,
+}
+
+impl Recovery {
+    pub const ALL: [Recovery; 3] = [Recovery::Ndjson, Recovery::RawLines, Recovery::Lenient];
+
+    /// The key that picks it in the results pane
+    pub fn key(self) -> &'static str {
+        match self {
+            Recovery::Ndjson => "n",
+            Recovery::RawLines => "r",
+            Recovery::Lenient => "l",
+        }
+    }
+
+    pub fn from_key(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.key().chars().eq([c]))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Recovery::Ndjson => "NDJSON",
+            Recovery::RawLines => "Raw lines",
+            Recovery::Lenient => "JSON5",
+        }
+    }
+
+    /// What the notification says once it worked
+    pub fn loaded(self) -> &'static str {
+        match self {
+            Recovery::Ndjson => "Loaded as NDJSON",
+            Recovery::RawLines => "Loaded as raw lines",
+            Recovery::Lenient => "Loaded as JSON5",
+        }
+    }
+}
+
+/// JSON text a [`Recovery`] made of the input, and what it had to drop
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recovered {
+    pub json: String,
+    /// Lines left out because they didn't parse
+    pub skipped: usize,
+}
+
+fn ndjson(text: &str) -> Result<Recovered, String> {
+    let mut kept = Vec::new();
+    let mut skipped = 0;
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if serde_json::from_str::<serde_json::Value>(line).is_ok() {
+            kept.push(line);
+        } else {
+            skipped += 1;
+        }
+    }
+    if kept.is_empty() {
+        return Err("No line is valid JSON on its own".to_string());
+    }
+    Ok(Recovered {
+        json: kept.join("\n"),
+        skipped,
+    })
+}
+
+fn raw_lines(text: &str) -> Recovered {
+    let json = text
+        .lines()
+        .map(|line| serde_json::Value::String(line.to_string()).to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Recovered { json, skipped: 0 }
+}
+
+fn lenient(text: &str) -> Result<Recovered, String> {
+    let json = super::json5::to_json(text);
+    let values = serde_json::Deserializer::from_str(&json).into_iter::<serde_json::Value>();
+    let mut count = 0;
+    for value in values {
+        value.map_err(|e| format!("Still invalid: {}", e))?;
+        count += 1;
+    }
+    if count == 0 {
+        return Err("Nothing left once comments are removed".to_string());
+    }
+    Ok(Recovered { json, skipped: 0 })
+}
+
+#[cfg(test)]
+#[path = "malformed_tests.rs"]
+mod malformed_tests;
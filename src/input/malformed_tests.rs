@@ -0,0 +1,81 @@
+use super::*;
+
+fn malformed(text: &str) -> MalformedInput {
+    MalformedInput::find(text.to_string()).expect("text should be invalid")
+}
+
+#[test]
+fn find_is_none_for_valid_json() {
+    assert!(MalformedInput::find(r#"{"a": 1}"#.to_string()).is_none());
+    assert!(MalformedInput::find("{\"a\": 1}\n{\"a\": 2}".to_string()).is_none());
+}
+
+#[test]
+fn find_reports_where_parsing_stopped() {
+    let m = malformed("{\n  \"a\": 1,\n  \"b\" 2\n}");
+    assert_eq!(m.line, 3);
+    assert_eq!(m.column, 7);
+    assert_eq!(m.message, "expected `:`");
+}
+
+#[test]
+fn find_reports_errors_after_valid_values() {
+    let m = malformed("{\"a\": 1}\n{\"a\": 2}\nnot json\n");
+    assert_eq!(m.line, 3);
+}
+
+#[test]
+fn summary_has_line_and_column() {
+    let m = malformed("[1, 2,, 3]");
+    assert_eq!(
+        m.summary(),
+        "Invalid JSON at line 1, column 7: expected value"
+    );
+}
+
+#[test]
+fn ndjson_skips_lines_that_do_not_parse() {
+    let m = malformed("{\"a\": 1}\n{\"a\": \ngarbage\n\n{\"a\": 3}\n");
+    let recovered = m.recover(Recovery::Ndjson).unwrap();
+    assert_eq!(recovered.json, "{\"a\": 1}\n{\"a\": 3}");
+    assert_eq!(recovered.skipped, 2);
+}
+
+#[test]
+fn ndjson_fails_when_no_line_parses() {
+    let m = malformed("hello\nworld");
+    assert!(m.recover(Recovery::Ndjson).is_err());
+}
+
+#[test]
+fn raw_lines_turns_each_line_into_a_string() {
+    let m = malformed("name,age\nAda \"Countess\",36");
+    let recovered = m.recover(Recovery::RawLines).unwrap();
+    assert_eq!(recovered.json, "\"name,age\"\n\"Ada \\\"Countess\\\",36\"");
+    assert_eq!(recovered.skipped, 0);
+}
+
+#[test]
+fn lenient_reads_json5() {
+    let m =
+        malformed("{\n  // who\n  \"name\": \"Ada\", /* born 1815 */\n  \"tags\": [1, 2,],\n}\n");
+    let recovered = m.recover(Recovery::Lenient).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&recovered.json).unwrap();
+    assert_eq!(value, serde_json::json!({"name": "Ada", "tags": [1, 2]}));
+}
+
+#[test]
+fn lenient_fails_on_other_errors() {
+    let m = malformed("{\"a\": 1,, }");
+    let error = m.recover(Recovery::Lenient).unwrap_err();
+    assert!(error.starts_with("Still invalid:"), "{error}");
+}
+
+#[test]
+fn keys_pick_recoveries() {
+    for recovery in Recovery::ALL {
+        let key = recovery.key().chars().next().unwrap();
+        assert_eq!(Recovery::from_key(key), Some(recovery));
+    }
+    assert_eq!(Recovery::from_key('x'), None);
+}
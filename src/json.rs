@@ -106,6 +106,27 @@ pub fn extract_first_json_value(input: &str) -> Option<String> {
     deserializer.next()?.ok().map(|v| v.to_string())
 }
 
+/// Parse `input` the way jq reads it: one JSON value, or several written
+/// back to back (JSONL, or concatenated documents). Several come back as an
+/// array of the documents. Also returns how many documents there were.
+///
+/// # Returns
+/// * `None` - If the input is empty or any document is invalid
+pub fn parse_documents(input: &str) -> Option<(Value, usize)> {
+    if let Ok(value) = serde_json::from_str::<Value>(input) {
+        return Some((value, 1));
+    }
+    let documents = serde_json::Deserializer::from_str(input)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    match documents.len() {
+        0 => None,
+        1 => documents.into_iter().next().map(|value| (value, 1)),
+        n => Some((Value::Array(documents), n)),
+    }
+}
+
 /// Convert a serde_json::Value to a schema Value recursively
 fn value_to_schema(value: &Value, current_depth: usize, max_depth: usize) -> Option<Value> {
     // Stop recursion at max depth
@@ -324,6 +324,11 @@ fn wrapping_neighbor(idx: usize, len: usize, dir: SiblingDir) -> usize {
     }
 }
 
+/// The value `path` addresses inside `value`, if it is there
+pub fn value_at<'a>(value: &'a Value, path: &JsonPath) -> Option<&'a Value> {
+    resolve_value(value, path.steps())
+}
+
 /// Walk `value` along `steps`, returning the addressed sub-value. Returns
 /// `None` if any step doesn't fit the current container's shape.
 fn resolve_value<'a>(value: &'a Value, steps: &[JsonPathStep]) -> Option<&'a Value> {
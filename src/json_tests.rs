@@ -235,3 +235,23 @@ fn test_extract_json_schema_dynamic_depth_scaling() {
     let depth_check = extract_json_schema(&json, 35).unwrap();
     assert_ne!(schema, depth_check);
 }
+
+#[test]
+fn test_parse_documents_single_value() {
+    let (value, count) = parse_documents(r#"{"a": 1}"#).unwrap();
+    assert_eq!(value, serde_json::json!({"a": 1}));
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_parse_documents_concatenated_values_become_an_array() {
+    let (value, count) = parse_documents("{\"a\": 1}{\"a\": 2}\n[3]").unwrap();
+    assert_eq!(value, serde_json::json!([{"a": 1}, {"a": 2}, [3]]));
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn test_parse_documents_rejects_a_broken_document() {
+    assert!(parse_documents("{\"a\": 1}\n{\"a\": ").is_none());
+    assert!(parse_documents("  ").is_none());
+}
@@ -0,0 +1,135 @@
+//! Kitty keyboard protocol
+//!
+//! Legacy terminals send the same bytes for Enter and Shift+Enter, and for
+//! Tab and Ctrl+I, so those keys can't be told apart. Terminals that speak
+//! the kitty keyboard protocol report every modifier once asked to; jiq
+//! asks at startup when the terminal says it can, and the help popup tells
+//! the user which keys to use instead when it can't. The Windows console
+//! reports modifiers with every key, so there's nothing to ask for, but
+//! Windows Terminal keeps a few of the keys jiq uses for itself.
+
+use std::io::stdout;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ratatui::crossterm::event::{
+    KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use ratatui::crossterm::execute;
+
+use crate::help::HelpSection;
+
+/// Whether enhancement flags are pushed and need popping before exit
+static PUSHED: AtomicBool = AtomicBool::new(false);
+
+/// What the terminal reports of modifier keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyboardSupport {
+    /// Not asked, as without a terminal
+    #[default]
+    Unknown,
+    /// Kitty protocol on: Shift+Enter, Ctrl+Enter and Ctrl+I arrive as
+    /// themselves
+    Enhanced,
+    /// The terminal can't report them
+    Legacy,
+    /// The Windows console, which reports every modifier
+    WindowsConsole,
+    /// Windows Terminal: every modifier reported, but Alt+Enter toggles
+    /// full screen unless unbound
+    WindowsTerminal,
+}
+
+impl KeyboardSupport {
+    /// Keys worth knowing about on this terminal, for the Global help tab
+    pub fn help_section(self) -> Option<&'static HelpSection> {
+        match self {
+            KeyboardSupport::Unknown => None,
+            KeyboardSupport::Enhanced => Some(&ENHANCED_SECTION),
+            KeyboardSupport::Legacy => Some(&LEGACY_SECTION),
+            KeyboardSupport::WindowsConsole => Some(&ENHANCED_SECTION),
+            KeyboardSupport::WindowsTerminal => Some(&WINDOWS_TERMINAL_SECTION),
+        }
+    }
+}
+
+const ENHANCED_SECTION: HelpSection = HelpSection {
+    title: Some("KEYBOARD (all modifiers reported)"),
+    entries: &[
+        ("Shift+Enter", "Output query string only and exit"),
+        ("Alt+Enter", "Output query string only and exit"),
+    ],
+};
+
+const LEGACY_SECTION: HelpSection = HelpSection {
+    title: Some("KEYBOARD (this terminal can't report every modifier)"),
+    entries: &[
+        ("Shift+Enter", "Arrives as Enter: use Ctrl+Q or Alt+Enter"),
+        ("Ctrl+I", "Arrives as Tab, so it can't toggle the tooltip"),
+    ],
+};
+
+const WINDOWS_TERMINAL_SECTION: HelpSection = HelpSection {
+    title: Some("KEYBOARD (Windows Terminal)"),
+    entries: &[
+        ("Shift+Enter", "Output query string only and exit"),
+        (
+            "Alt+Enter",
+            "Toggles full screen here: use Shift+Enter or Ctrl+Q",
+        ),
+        (
+            "Ctrl+C",
+            "Copies instead while text is selected with the mouse",
+        ),
+    ],
+};
+
+/// What a Windows console reports, by whether it's Windows Terminal
+pub fn windows_support(in_windows_terminal: bool) -> KeyboardSupport {
+    if in_windows_terminal {
+        KeyboardSupport::WindowsTerminal
+    } else {
+        KeyboardSupport::WindowsConsole
+    }
+}
+
+/// Ask the terminal to report all modifiers when it can. Call with raw
+/// mode on and the alternate screen entered, since each screen keeps its
+/// own flags.
+pub fn enable() -> KeyboardSupport {
+    if crate::platform::WINDOWS {
+        return windows_support(crate::platform::in_windows_terminal());
+    }
+    match ratatui::crossterm::terminal::supports_keyboard_enhancement() {
+        Ok(true) => {}
+        Ok(false) => return KeyboardSupport::Legacy,
+        Err(e) => {
+            log::debug!("Keyboard enhancement query failed: {}", e);
+            return KeyboardSupport::Legacy;
+        }
+    }
+    match execute!(
+        stdout(),
+        PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+    ) {
+        Ok(()) => {
+            PUSHED.store(true, Ordering::Relaxed);
+            KeyboardSupport::Enhanced
+        }
+        Err(e) => {
+            log::debug!("Failed to enable keyboard enhancement: {}", e);
+            KeyboardSupport::Legacy
+        }
+    }
+}
+
+/// Undo [`enable`], before leaving the alternate screen; does nothing when
+/// it didn't turn anything on
+pub fn disable() {
+    if PUSHED.swap(false, Ordering::Relaxed) {
+        let _ = execute!(stdout(), PopKeyboardEnhancementFlags);
+    }
+}
+
+#[cfg(test)]
+#[path = "keyboard_tests.rs"]
+mod keyboard_tests;
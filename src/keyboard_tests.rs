@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn test_no_help_without_a_terminal() {
+    assert!(KeyboardSupport::default().help_section().is_none());
+}
+
+#[test]
+fn test_legacy_help_names_the_fallbacks() {
+    let section = KeyboardSupport::Legacy.help_section().unwrap();
+    let (key, fallback) = section.entries[0];
+    assert_eq!(key, "Shift+Enter");
+    assert!(fallback.contains("Ctrl+Q"));
+    assert!(fallback.contains("Alt+Enter"));
+}
+
+#[test]
+fn test_enhanced_help_lists_shift_enter() {
+    let section = KeyboardSupport::Enhanced.help_section().unwrap();
+    assert!(section.entries.iter().any(|(key, _)| *key == "Shift+Enter"));
+}
+
+#[test]
+fn test_disable_without_enable_is_a_no_op() {
+    // Nothing was pushed, so nothing is written to the terminal
+    disable();
+    assert!(!PUSHED.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_windows_terminal_help_warns_about_alt_enter() {
+    let support = windows_support(true);
+    assert_eq!(support, KeyboardSupport::WindowsTerminal);
+    let section = support.help_section().unwrap();
+    let (_, note) = section
+        .entries
+        .iter()
+        .find(|(key, _)| *key == "Alt+Enter")
+        .unwrap();
+    assert!(note.contains("Shift+Enter"));
+}
+
+#[test]
+fn test_windows_console_reports_every_modifier() {
+    let support = windows_support(false);
+    assert_eq!(support, KeyboardSupport::WindowsConsole);
+    assert_eq!(
+        support.help_section().unwrap().title,
+        KeyboardSupport::Enhanced.help_section().unwrap().title
+    );
+}
@@ -1,29 +1,46 @@
 //! jiq library - Interactive JSON query tool
 //!
 //! This library exposes the core functionality of jiq for testing purposes.
+//! [`headless::Headless`] drives the whole app without a terminal.
 
+pub mod accessibility;
 pub mod ai;
 pub mod app;
 pub mod autocomplete;
 pub mod clipboard;
+pub mod command_line;
 pub mod config;
+pub mod debug_log;
 pub mod editor;
 pub mod error;
+pub mod flags_panel;
+pub mod headless;
 pub mod help;
 pub mod history;
+pub mod hooks;
 pub mod input;
 pub mod json;
 pub mod json_path;
+pub mod keyboard;
 pub mod layout;
 pub mod notification;
+pub mod params;
 pub mod path_at_cursor;
 pub mod path_at_cursor_apply;
+pub mod path_picker;
+pub mod platform;
+pub mod plugins;
+pub mod presets;
+pub mod progress;
 pub mod query;
 pub mod query_undo;
 pub mod results;
+pub mod root_stack;
 pub mod save;
+pub mod scratch;
 pub mod scroll;
 pub mod search;
+pub mod shell_init;
 pub mod snippets;
 pub mod stats;
 pub mod str_utils;
@@ -8,31 +8,50 @@ use ratatui::crossterm::execute;
 use ratatui::crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
-use std::io::stdout;
-use std::path::PathBuf;
+use std::io::{Write, stdout};
+use std::path::{Path, PathBuf};
 
+mod accessibility;
 mod ai;
 mod app;
 mod autocomplete;
 mod clipboard;
+mod command_line;
 mod config;
+mod debug_log;
 mod editor;
 mod error;
+mod flags_panel;
+// Library API for driving the app without a terminal; the binary only
+// uses it for --render-once
+#[allow(dead_code)]
+mod headless;
 mod help;
 mod history;
+mod hooks;
 mod input;
 mod json;
 mod json_path;
+mod keyboard;
 mod layout;
 mod notification;
+mod params;
 mod path_at_cursor;
 mod path_at_cursor_apply;
+mod path_picker;
+mod platform;
+mod plugins;
+mod presets;
+mod progress;
 mod query;
 mod query_undo;
 mod results;
+mod root_stack;
 mod save;
+mod scratch;
 mod scroll;
 mod search;
+mod shell_init;
 mod snippets;
 mod stats;
 mod str_utils;
@@ -45,7 +64,8 @@ mod widgets;
 
 use app::{App, OutputMode};
 use error::JiqError;
-use input::loader::peek_clipboard;
+use input::json5::InputSyntax;
+use input::loader::{LoadOptions, peek_clipboard};
 use input::{FileLoader, PasteRecoveryState, SourcePickerState};
 use query::executor::JqExecutor;
 
@@ -56,8 +76,9 @@ use query::executor::JqExecutor;
     about = "Interactive JSON query tool with real-time filtering using jq"
 )]
 struct Args {
-    /// Input JSON file (if not provided, reads from stdin)
-    input: Option<PathBuf>,
+    /// Input JSON files (if none provided, reads from stdin). Several
+    /// files are fed to jq as one stream of inputs, in order.
+    input: Vec<PathBuf>,
 
     /// Force reading JSON from the system clipboard. Mutually exclusive
     /// with --paste; cannot be combined with piped stdin (the
@@ -71,23 +92,162 @@ struct Args {
     #[arg(long, conflicts_with = "clipboard")]
     paste: bool,
 
+    /// Bind the text of FILE to `$NAME` in every query, as jq's
+    /// `--rawfile`. Repeatable.
+    #[arg(long, num_args = 2, value_names = ["NAME", "FILE"], action = clap::ArgAction::Append)]
+    rawfile: Vec<String>,
+
+    /// Bind an array of the JSON values in FILE to `$NAME` in every
+    /// query, as jq's `--slurpfile`. Repeatable.
+    #[arg(long, num_args = 2, value_names = ["NAME", "FILE"], action = clap::ArgAction::Append)]
+    slurpfile: Vec<String>,
+
+    /// Search DIR for jq modules, as jq's `-L`. Repeatable.
+    #[arg(short = 'L', long = "library-path", value_name = "DIR")]
+    library_path: Vec<PathBuf>,
+
+    /// Include the definitions in FILE (a `.jq` file) in every query.
+    /// Defaults to `prelude.jq` in jiq's config directory when it exists.
+    #[arg(long, value_name = "FILE")]
+    prelude: Option<PathBuf>,
+
+    /// Use `null` as the input so queries read the documents with
+    /// `input` / `inputs`, as jq's `--null-input`.
+    #[arg(short = 'n', long)]
+    null_input: bool,
+
+    /// On Enter, exit with jq's `--exit-status` code for the printed
+    /// result: 1 when the last value is `false` or `null`, 4 when there
+    /// is none, 5 when the query fails. The flags panel (F4) toggles it.
+    #[arg(short = 'e', long)]
+    exit_status: bool,
+
+    /// Give queries each line of the input as a string, as jq's
+    /// `--raw-input`, for logs and other text. `:raw-input` toggles it
+    /// from inside jiq.
+    #[arg(short = 'R', long)]
+    raw_input: bool,
+
+    /// Give queries the input as `[path, leaf]` events, as jq's
+    /// `--stream`. `S` in the results pane shows the result's events put
+    /// back together.
+    #[arg(long)]
+    stream: bool,
+
+    /// Read the input as JSON5, allowing comments, trailing commas,
+    /// unquoted keys and single-quoted strings. Always on for `.json5`
+    /// and `.jsonc` files.
+    #[arg(long)]
+    json5: bool,
+
+    /// Disable every network-touching feature (the AI assistant). Same as
+    /// `offline = true` under `[network]` in the config file.
+    #[arg(long)]
+    offline: bool,
+
+    /// Blank out borders and say what changed in the bottom line, for
+    /// terminal screen readers. Same as `screen_reader = true` under
+    /// `[accessibility]` in the config file.
+    #[arg(long)]
+    screen_reader: bool,
+
+    /// Add the queries in FILE to history and exit. FILE holds one query
+    /// per line, or is a shell history whose jq commands are imported.
+    #[arg(long, value_name = "FILE")]
+    import_history: Option<PathBuf>,
+
+    /// Don't record queries in history this session. `:private` toggles it
+    /// from inside jiq.
+    #[arg(long)]
+    private: bool,
+
+    /// On exit, write a JSON object with the final query, output mode,
+    /// execution time and result line count to stderr, or to PATH
+    /// (e.g. `--emit-meta=/dev/fd/3`).
+    #[arg(long, value_name = "PATH", num_args = 0..=1, require_equals = true, default_missing_value = "-")]
+    emit_meta: Option<PathBuf>,
+
+    /// Draw one frame on a WIDTHxHEIGHT screen (default 100x30), print it
+    /// as plain text and exit. Needs a file argument or piped input.
+    #[arg(long, value_name = "WIDTHxHEIGHT", num_args = 0..=1, require_equals = true, default_missing_value = "100x30", value_parser = parse_screen_size)]
+    render_once: Option<(u16, u16)>,
+
+    /// Query to run before the frame is drawn, with --render-once
+    #[arg(long, value_name = "QUERY", requires = "render_once")]
+    query: Option<String>,
+
+    /// Print the shell integration script for SHELL and exit. Add
+    /// `eval "$(jiq --shell-init zsh)"` to your rc file; Alt+J then opens
+    /// jiq on the command line's output and inserts the query.
+    #[arg(long, value_name = "SHELL")]
+    shell_init: Option<shell_init::Shell>,
+
+    /// Run the jq at PATH instead of the one in PATH. Same as `path` under
+    /// `[jq]` in the config file.
+    #[arg(long, value_name = "PATH")]
+    jq: Option<PathBuf>,
+
+    /// Kill a query still running after SECS seconds (0 for no limit).
+    /// Same as `timeout_secs` under `[jq]` in the config file.
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<u64>,
+
     /// Enable debug logging to /tmp/jiq-debug.log
     #[arg(long)]
     debug: bool,
+
+    /// Write debug logs to PATH instead, turning logging on. Same as
+    /// `JIQ_LOG_FILE=PATH`. F12 shows the latest lines while jiq runs.
+    #[arg(long, value_name = "PATH")]
+    log_file: Option<PathBuf>,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
-    init_logger(args.debug);
+    if let Some(shell) = args.shell_init {
+        print!("{}", shell.init_script());
+        return Ok(());
+    }
+
+    init_logger(&args);
 
     color_eyre::install()?;
 
     // Load config early to avoid defaults during app initialization
-    let config_result = config::load_config();
+    let mut config_result = config::load_config();
+    if args.offline {
+        config_result.config.network.offline = true;
+    }
+    if args.screen_reader {
+        config_result.config.accessibility.screen_reader = true;
+    }
+
+    if let Some(path) = &args.import_history {
+        let mut history = history::HistoryState::new(&config_result.config.history);
+        if let Some(warning) = history.load_warning() {
+            eprintln!("jiq: {}", warning);
+            std::process::exit(1);
+        }
+        match history::import::import_file(&mut history, path) {
+            Ok(summary) => println!("{}", summary.message()),
+            Err(e) => {
+                eprintln!("jiq: can't import {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
 
-    validate_jq_exists()?;
-    log::debug!("jq binary found in PATH");
+    let jq = probe_jq(&args, &config_result.config)?;
+    log::debug!("jq binary: {:?} ({})", jq.path, jq.version_text);
+
+    let jq_args = std::sync::Arc::new(build_jq_args(&args, &config_result.config, &jq)?);
+    if let Some(prelude) = jq_args.prelude() {
+        register_prelude_functions(&prelude.path);
+    }
+    let jq_warning = jq.age_warning();
+    query::jq_binary::set(jq);
 
     // H1 hard-error: an explicit source flag combined with ANY other
     // source is contradictory. The user typed `--clipboard` / `--paste`
@@ -105,7 +265,7 @@ fn main() -> Result<()> {
     //
     // Runs *before* init_terminal so the message lands on the user's
     // normal terminal, not inside the alt screen.
-    let has_file = args.input.is_some();
+    let has_file = !args.input.is_empty();
     let has_pipe = !std::io::IsTerminal::is_terminal(&std::io::stdin());
     let flag: Option<&str> = if args.clipboard {
         Some("--clipboard")
@@ -139,54 +299,209 @@ fn main() -> Result<()> {
     //
     // File and stdin loads stay deferred so a large input never blocks
     // the splash. `--paste` skips the clipboard entirely.
-    let pre_input = resolve_pre_input(&args);
+    let pre_input = resolve_pre_input(&args, &config_result.config);
 
     // Resolve the theme palette before `init_terminal()`. In `auto`
     // mode the detection probe emits an OSC 11 query and reads the
     // reply; this MUST complete before enable_raw_mode() /
     // EnterAlternateScreen own the terminal, or the escape sequences
     // collide with the alt-screen handshake.
-    let resolved = resolve_theme(
-        config_result.config.theme.mode,
-        theme::detect::detect_background,
+    let monochrome = theme::monochrome::wanted(
+        config_result.config.theme.monochrome,
+        std::env::var_os("NO_COLOR").as_deref(),
+        std::env::var_os("TERM").as_deref(),
     );
-    theme::init(match resolved {
-        theme::ResolvedTheme::Light => theme::galaxy_light(),
-        theme::ResolvedTheme::Dark => theme::galaxy_dark(),
-    });
+    theme::monochrome::set_enabled(monochrome);
+    // Without colors the palette only decides which attribute stands in
+    // for each color, so there's no need to probe the terminal
+    let resolved = if monochrome {
+        resolve_theme(config_result.config.theme.mode, || {
+            theme::ResolvedTheme::Dark
+        })
+    } else {
+        resolve_theme(
+            config_result.config.theme.mode,
+            theme::detect::detect_background,
+        )
+    };
+    theme::set(resolved.clone());
     log::debug!(
-        "theme mode={:?} -> {:?}",
+        "theme mode={:?} -> {:?}, monochrome={}",
         config_result.config.theme.mode,
-        resolved
+        resolved,
+        monochrome
     );
 
+    if let Some((width, height)) = args.render_once {
+        return render_once(
+            &args,
+            pre_input,
+            jq_args,
+            &config_result.config,
+            width,
+            height,
+        );
+    }
+
     let terminal = init_terminal()?;
-    let app = match pre_input {
+    let keyboard = keyboard::enable();
+    log::debug!("Keyboard support: {:?}", keyboard);
+    let mut app = match pre_input {
         PreInput::Loader(loader) => App::new_with_loader(loader, &config_result.config),
         PreInput::PasteRecovery(state) => {
             App::new_with_paste_recovery(state, &config_result.config)
         }
         PreInput::Picker(state) => App::new_with_source_picker(state, &config_result.config),
     };
+    app.jq_args = jq_args;
+    app.input_paths = input_paths(&args);
+    app.keyboard = keyboard;
+    app.history.set_private(args.private);
+    app.exit_status |= args.exit_status;
+    if let Some(warning) = jq_warning {
+        app.notification.show_warning(&warning);
+    }
+    if let Some(warning) = app.history.load_warning().map(str::to_string) {
+        app.notification.show_warning(&warning);
+    }
     let result = run(terminal, app, config_result);
 
     restore_terminal()?;
     let app = result?;
 
     // Output after terminal restore to prevent corruption
-    handle_output(&app)?;
+    let exit_code = handle_output(&app);
+    if let Some(target) = &args.emit_meta {
+        emit_meta(&app, target);
+    }
 
     log::debug!("=== JIQ DEBUG SESSION ENDED ===");
 
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
     Ok(())
 }
 
-/// Validate that jq binary exists in PATH
-fn validate_jq_exists() -> Result<(), JiqError> {
-    which::which("jq").map_err(|_| JiqError::JqNotFound)?;
+/// `--render-once`: load the input, run `--query`, print one frame as text
+fn render_once(
+    args: &Args,
+    pre_input: PreInput,
+    jq_args: std::sync::Arc<query::JqArgs>,
+    config: &config::Config,
+    width: u16,
+    height: u16,
+) -> Result<()> {
+    let PreInput::Loader(loader) = pre_input else {
+        return Err(JiqError::InvalidArgument(
+            "--render-once needs a file argument or piped input".to_string(),
+        )
+        .into());
+    };
+    let mut app = App::new_with_loader(loader, config);
+    app.jq_args = jq_args;
+    app.input_paths = input_paths(args);
+
+    let mut jiq = headless::Headless::from_app(app, width, height);
+    if let Some(query) = &args.query {
+        jiq.settle();
+        jiq.paste(query);
+        // Draw the results rather than suggestions for what was typed
+        jiq.app_mut().autocomplete.hide();
+    }
+    if !jiq.settle() {
+        log::debug!("render-once: drawing before the query finished");
+    }
+    print!("{}", jiq.snapshot());
     Ok(())
 }
 
+/// `WIDTHxHEIGHT` for `--render-once`
+fn parse_screen_size(value: &str) -> Result<(u16, u16), String> {
+    let invalid = || format!("expected WIDTHxHEIGHT such as 100x30, got `{}`", value);
+    let (width, height) = value.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let width: u16 = width.trim().parse().map_err(|_| invalid())?;
+    let height: u16 = height.trim().parse().map_err(|_| invalid())?;
+    if width == 0 || height == 0 {
+        return Err(invalid());
+    }
+    Ok((width, height))
+}
+
+/// Input files as absolute paths, so an exported script runs from anywhere
+fn input_paths(args: &Args) -> Vec<PathBuf> {
+    args.input
+        .iter()
+        .map(|path| std::path::absolute(path).unwrap_or_else(|_| path.clone()))
+        .collect()
+}
+
+/// Find the jq to run, `--jq` first, then `[jq] path`, then PATH, and
+/// ask its version
+fn probe_jq(args: &Args, config: &config::Config) -> Result<query::jq_binary::JqBinary, JiqError> {
+    let path = args.jq.as_deref().or(config.jq.path.as_deref());
+    query::jq_binary::JqBinary::probe(path)
+}
+
+/// Collect `--rawfile` / `--slurpfile` pairs, validating names and files
+/// and that `jq` has the flags for them, the module directories,
+/// prelude and timeout from the flags and `[jq]`, and the output limit
+/// from `[results]`
+fn build_jq_args(
+    args: &Args,
+    config: &config::Config,
+    jq: &query::jq_binary::JqBinary,
+) -> Result<query::JqArgs, JiqError> {
+    use query::jq_args::FileVarKind;
+
+    if !args.rawfile.is_empty() {
+        jq.require_flag(FileVarKind::Raw.flag())?;
+    }
+
+    let mut jq_args = query::JqArgs::new();
+    jq_args.set_null_input(args.null_input);
+    jq_args.set_raw_input(args.raw_input);
+    jq_args.set_stream(args.stream);
+    for (kind, values) in [
+        (FileVarKind::Raw, &args.rawfile),
+        (FileVarKind::Slurp, &args.slurpfile),
+    ] {
+        for pair in values.chunks_exact(2) {
+            jq_args.push_file_var(kind, &pair[0], std::path::Path::new(&pair[1]))?;
+        }
+    }
+    for dir in config.jq.library_paths.iter().chain(&args.library_path) {
+        jq_args.push_library_path(dir)?;
+    }
+    let prelude = match args.prelude.as_ref().or(config.jq.prelude.as_ref()) {
+        Some(path) => Some(query::prelude::Prelude::new(path)?),
+        None => platform::config_dir()
+            .map(|dir| dir.join(query::prelude::DEFAULT_FILE))
+            .filter(|path| path.is_file())
+            .and_then(|path| query::prelude::Prelude::new(&path).ok()),
+    };
+    jq_args.set_prelude(prelude);
+    jq_args.set_timeout(match args.timeout {
+        Some(0) => None,
+        Some(secs) => Some(std::time::Duration::from_secs(secs)),
+        None => config.jq.timeout(),
+    });
+    jq_args.set_output_limit(config.results.output_limit());
+    Ok(jq_args)
+}
+
+/// Offer the prelude's definitions in autocomplete and tooltips
+fn register_prelude_functions(path: &std::path::Path) {
+    match std::fs::read_to_string(path) {
+        Ok(text) => {
+            let functions = query::prelude::parse_definitions(&text);
+            log::debug!("Prelude {:?}: {} functions", path, functions.len());
+            query::prelude::register(functions);
+        }
+        Err(e) => log::warn!("Failed to read prelude {:?}: {}", path, e),
+    }
+}
+
 /// Map the configured theme mode to a concrete palette selector.
 /// `Light`/`Dark` force the palette; `Auto` defers to `detect`, which
 /// probes the terminal background. Kept pure (detection injected) so
@@ -226,23 +541,48 @@ enum PreInput {
 /// skip the chooser entirely and drop straight into the explicit-paste
 /// editor with a context line explaining what jiq saw on the
 /// clipboard.
-fn resolve_pre_input(args: &Args) -> PreInput {
+fn resolve_pre_input(args: &Args, config: &config::Config) -> PreInput {
     if args.paste {
         log::debug!("Entering explicit paste mode (--paste)");
         return PreInput::PasteRecovery(PasteRecoveryState::new_explicit());
     }
-    if let Some(ref path) = args.input {
-        log::debug!("File loader spawned for: {:?}", path);
-        return PreInput::Loader(FileLoader::spawn_load(path.clone()));
+    let options = LoadOptions {
+        syntax: if args.raw_input {
+            InputSyntax::Raw
+        } else if args.json5 {
+            InputSyntax::Json5
+        } else {
+            InputSyntax::Json
+        },
+        map_threshold: config.large_input.threshold_bytes(),
+        allow_empty: args.null_input,
+    };
+    if let [path] = args.input.as_slice()
+        && !args.raw_input
+        && let Some(plugin) = plugins::PluginRegistry::new().input_for(path)
+    {
+        log::debug!("Converting {:?} with plugin {}", path, plugin.name);
+        return PreInput::Loader(FileLoader::spawn_convert(path.clone(), plugin.path.clone()));
+    }
+    if !args.input.is_empty() {
+        log::debug!("File loader spawned for: {:?}", args.input);
+        return PreInput::Loader(FileLoader::spawn_load_files_with(
+            args.input.clone(),
+            options,
+        ));
     }
     if !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
         log::debug!("File loader spawned for stdin");
-        return PreInput::Loader(FileLoader::spawn_load_stdin());
+        return PreInput::Loader(FileLoader::spawn_load_stdin_with(options));
     }
     if args.clipboard {
         log::debug!("Loading clipboard synchronously (forced via --clipboard)");
         return PreInput::Loader(FileLoader::load_clipboard_blocking());
     }
+    if args.null_input {
+        log::debug!("Null input without documents; skipping the source picker");
+        return PreInput::Loader(FileLoader::from_json(String::new()));
+    }
     log::debug!("Bare TTY launch — peeking clipboard for source picker");
     let peek = peek_clipboard();
     if peek.is_usable() {
@@ -306,6 +646,7 @@ fn init_terminal() -> Result<DefaultTerminal> {
     log::debug!("Initializing terminal");
     let hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
+        keyboard::disable();
         let _ = execute!(
             stdout(),
             DisableMouseCapture,
@@ -358,6 +699,7 @@ fn init_terminal() -> Result<DefaultTerminal> {
 /// Restore terminal to normal state
 fn restore_terminal() -> Result<()> {
     log::debug!("Restoring terminal");
+    keyboard::disable();
     let _ = execute!(
         stdout(),
         DisableMouseCapture,
@@ -396,16 +738,48 @@ fn run(
 
         app.handle_events()?;
 
+        if let Some(path) = app.take_input_edit() {
+            let status = edit_outside_terminal(&mut terminal, &mut app, &path)?;
+            app.finish_input_edit(&path, status);
+        }
+
         if app.should_quit() {
             break;
         }
     }
 
+    app.discard_input_edit_draft();
     Ok(app)
 }
 
+/// Hand the terminal to the editor for `:edit` and take it back once the
+/// editor exits
+fn edit_outside_terminal(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    path: &Path,
+) -> Result<std::io::Result<std::process::ExitStatus>> {
+    restore_terminal()?;
+    let status = app::run_editor(path);
+    enable_raw_mode()?;
+    execute!(
+        stdout(),
+        EnterAlternateScreen,
+        EnableBracketedPaste,
+        EnableMouseCapture
+    )?;
+    app.keyboard = keyboard::enable();
+    terminal.clear()?;
+    Ok(status)
+}
+
 /// Set up the AI worker thread and channels
 fn setup_ai_worker(app: &mut App, config: &config::Config) {
+    if config.network.offline {
+        log::debug!("AI: skipping worker spawn (offline mode)");
+        return;
+    }
+
     log::debug!(
         "AI setup: enabled={}, configured={}",
         config.ai.enabled,
@@ -431,62 +805,20 @@ fn setup_ai_worker(app: &mut App, config: &config::Config) {
     log::debug!("AI worker spawned");
 }
 
-/// Initialize debug logger when activated via --debug flag, JIQ_DEBUG=1, or debug build.
-/// All output goes to /tmp/jiq-debug.log (never stdout/stderr).
-fn init_logger(cli_debug: bool) {
-    let env_debug = std::env::var("JIQ_DEBUG").is_ok_and(|v| v == "1");
-    let debug_build = cfg!(debug_assertions);
-    let enabled = cli_debug || env_debug || debug_build;
-
-    if !enabled {
-        return;
-    }
-
-    use std::io::Write;
-
-    let log_file = match std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("/tmp/jiq-debug.log")
-    {
-        Ok(f) => f,
-        Err(_) => return,
-    };
-
-    env_logger::Builder::new()
-        .filter_level(log::LevelFilter::Debug)
-        .target(env_logger::Target::Pipe(Box::new(log_file)))
-        .format(|buf, record| {
-            use std::time::SystemTime;
-            let datetime: chrono::DateTime<chrono::Local> = SystemTime::now().into();
-            writeln!(
-                buf,
-                "[{}] [{}] {}",
-                datetime.format("%Y-%m-%dT%H:%M:%S%.3f"),
-                record.level(),
-                record.args()
-            )
-        })
-        .init();
-
-    let method = match (cli_debug, env_debug, debug_build) {
-        (true, true, _) => "--debug flag and JIQ_DEBUG env var",
-        (true, false, _) => "--debug flag",
-        (false, true, _) => "JIQ_DEBUG env var",
-        (false, false, true) => "debug build",
-        _ => "unknown",
-    };
-
-    log::debug!(
-        "=== JIQ DEBUG SESSION STARTED (v{}) ===",
-        env!("CARGO_PKG_VERSION")
-    );
-    log::debug!("Activated via: {}", method);
-    log::debug!(
-        "Platform: {} {}",
-        std::env::consts::OS,
-        std::env::consts::ARCH
+/// Initialize the debug logger when activated via --debug, --log-file,
+/// JIQ_DEBUG=1, JIQ_LOG_FILE or a debug build.
+/// Output goes to the log file only (never stdout/stderr).
+fn init_logger(args: &Args) {
+    let settings = debug_log::LogSettings::resolve(
+        args.debug,
+        args.log_file.as_deref(),
+        std::env::var("JIQ_DEBUG").is_ok_and(|v| v == "1"),
+        std::env::var_os(debug_log::LOG_FILE_ENV).map(PathBuf::from),
+        cfg!(debug_assertions),
     );
+    if let Some(settings) = settings {
+        debug_log::init(&settings);
+    }
 }
 
 /// RAII timer that logs `"[TIMING] {label} took {ms}ms"` on drop. When the
@@ -517,31 +849,97 @@ impl Drop for Timer {
 }
 
 /// Handle output after terminal is restored
-fn handle_output(app: &App) -> Result<()> {
-    match app.output_mode() {
-        Some(OutputMode::Results) => {
+///
+/// Returns the exit code: 0, unless `-e` is on and the result was printed,
+/// then jq's `--exit-status` code for it.
+fn handle_output(app: &App) -> i32 {
+    let (printed, code) = match app.output_mode() {
+        Some(OutputMode::Results) => match &app.query {
             // Execute final query and output results
             // Only output if query is available
-            if let Some(query_state) = &app.query {
-                let json_input = query_state.executor.json_input();
-                let executor = JqExecutor::new(json_input.to_string());
+            Some(query_state) => {
+                let json_input = query_state.executor.json_input().clone();
+                let executor = JqExecutor::new(json_input)
+                    .with_jq_args(std::sync::Arc::clone(query_state.executor.jq_args()));
                 let cancel_token = tokio_util::sync::CancellationToken::new();
-                match executor.execute_for_output(app.query(), &cancel_token) {
-                    Ok(result) => println!("{}", result),
-                    Err(e) => eprintln!("Error: {}", e),
+                let format = query_state.output_format();
+                if app.exit_status {
+                    match executor.execute_for_exit_status(app.query(), format, &cancel_token) {
+                        Ok((result, code)) => (Some(print_output(app, result)), code),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            (None, JQ_ERROR_EXIT_CODE)
+                        }
+                    }
+                } else {
+                    match executor.execute_for_output(app.query(), format, &cancel_token) {
+                        Ok(result) => (Some(print_output(app, result)), 0),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            (None, 0)
+                        }
+                    }
                 }
             }
-        }
+            None => (None, 0),
+        },
         Some(OutputMode::Query) => {
             // Output just the query string
             println!("{}", app.query());
+            (Some(app.query().to_string()), 0)
         }
         None => {
             // No output mode (exited with Ctrl+C or q)
+            (None, 0)
         }
+    };
+
+    if let Some(printed) = printed
+        && let Err(e) = hooks::on_exit(app, &printed)
+    {
+        eprintln!("Error: {}", e);
     }
+    code
+}
 
-    Ok(())
+/// Print the result on exit, through the `:post` plugin when one was
+/// chosen, and return what was printed
+fn print_output(app: &App, result: String) -> String {
+    let (output, error) = plugins::post_process(app, result);
+    if let Some(e) = error {
+        eprintln!("Error: {}", e);
+    }
+    let output = output.trim_end_matches('\n');
+    println!("{}", output);
+    output.to_string()
+}
+
+/// jq's exit code when the query fails
+const JQ_ERROR_EXIT_CODE: i32 = 5;
+
+/// Write `--emit-meta` output; `-` means stderr
+///
+/// A failed write is reported but doesn't change the exit status: the
+/// result has already been printed.
+fn emit_meta(app: &App, target: &Path) {
+    let line = format!("{}\n", app.exit_meta());
+    let written = if target == Path::new("-") {
+        std::io::stderr().write_all(line.as_bytes())
+    } else {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(target)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+    };
+    if let Err(e) = written {
+        eprintln!(
+            "Error: cannot write exit metadata to {}: {}",
+            target.display(),
+            e
+        );
+    }
 }
 
 #[cfg(test)]
@@ -61,3 +61,166 @@ fn setup_ai_worker_warns_when_enabled_but_unconfigured() {
         "guard must not flip configured; worker spawn is skipped"
     );
 }
+
+#[test]
+fn build_jq_args_collects_rawfile_and_slurpfile_pairs() {
+    let raw = tempfile::NamedTempFile::new().unwrap();
+    let slurp = tempfile::NamedTempFile::new().unwrap();
+    let raw_path = raw.path().to_str().unwrap();
+    let slurp_path = slurp.path().to_str().unwrap();
+
+    let args = Args::parse_from([
+        "jiq",
+        "--rawfile",
+        "a",
+        raw_path,
+        "--slurpfile",
+        "b",
+        slurp_path,
+        "--rawfile",
+        "c",
+        raw_path,
+    ]);
+    let jq_args = build_jq_args(&args, &config::Config::default(), &jq_1_7()).unwrap();
+
+    let names: Vec<&str> = jq_args
+        .file_vars()
+        .iter()
+        .map(|v| v.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["a", "c", "b"]);
+}
+
+fn jq_1_7() -> query::jq_binary::JqBinary {
+    query::jq_binary::JqBinary::new(PathBuf::from("/usr/bin/jq"), "jq-1.7.1")
+}
+
+#[test]
+fn build_jq_args_refuses_rawfile_on_old_jq() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let args = Args::parse_from(["jiq", "--rawfile", "a", file.path().to_str().unwrap()]);
+    let old = query::jq_binary::JqBinary::new(PathBuf::from("/usr/bin/jq"), "jq-1.5");
+
+    let err = build_jq_args(&args, &config::Config::default(), &old).unwrap_err();
+    assert!(err.to_string().contains("--rawfile needs jq 1.6"));
+}
+
+#[test]
+fn args_take_a_jq_override() {
+    let args = Args::parse_from(["jiq", "--jq", "/opt/jq"]);
+    assert_eq!(args.jq, Some(PathBuf::from("/opt/jq")));
+}
+
+#[test]
+fn build_jq_args_rejects_missing_file() {
+    let args = Args::parse_from(["jiq", "--slurpfile", "x", "/nonexistent/jiq.json"]);
+
+    assert!(matches!(
+        build_jq_args(&args, &config::Config::default(), &jq_1_7()),
+        Err(JiqError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn args_accept_several_input_files() {
+    let args = Args::parse_from(["jiq", "a.json", "b.json", "c.json"]);
+
+    assert_eq!(
+        args.input,
+        vec![
+            PathBuf::from("a.json"),
+            PathBuf::from("b.json"),
+            PathBuf::from("c.json")
+        ]
+    );
+}
+
+#[test]
+fn build_jq_args_passes_null_input() {
+    let first_flag = |argv: &[&str]| {
+        let mut command = std::process::Command::new("jq");
+        build_jq_args(
+            &Args::parse_from(argv),
+            &config::Config::default(),
+            &jq_1_7(),
+        )
+        .unwrap()
+        .apply(&mut command);
+        command
+            .get_args()
+            .next()
+            .map(|a| a.to_string_lossy().into_owned())
+    };
+
+    assert_eq!(
+        first_flag(&["jiq", "-n", "a.json"]).as_deref(),
+        Some("--null-input")
+    );
+    assert_eq!(first_flag(&["jiq"]), None);
+}
+
+#[test]
+fn emit_meta_defaults_to_stderr_and_takes_a_path() {
+    assert_eq!(Args::parse_from(["jiq"]).emit_meta, None);
+
+    let args = Args::parse_from(["jiq", "--emit-meta", "a.json"]);
+    assert_eq!(args.emit_meta, Some(PathBuf::from("-")));
+    assert_eq!(args.input, vec![PathBuf::from("a.json")]);
+
+    let args = Args::parse_from(["jiq", "--emit-meta=/dev/fd/3"]);
+    assert_eq!(args.emit_meta, Some(PathBuf::from("/dev/fd/3")));
+}
+
+#[test]
+fn emit_meta_writes_json_line_to_path() {
+    use crate::test_utils::test_helpers::app_with_query;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let target = dir.path().join("meta.json");
+    let app = app_with_query(".name");
+
+    emit_meta(&app, &target);
+
+    let written = std::fs::read_to_string(&target).unwrap();
+    assert!(written.ends_with('\n'));
+    let meta: serde_json::Value = serde_json::from_str(&written).unwrap();
+    assert_eq!(meta["query"], ".name");
+}
+
+#[test]
+fn shell_init_takes_a_shell_name() {
+    let args = Args::parse_from(["jiq", "--shell-init", "fish"]);
+    assert_eq!(args.shell_init, Some(shell_init::Shell::Fish));
+
+    assert!(Args::try_parse_from(["jiq", "--shell-init", "tcsh"]).is_err());
+}
+
+#[test]
+fn render_once_takes_an_optional_size() {
+    assert_eq!(Args::parse_from(["jiq"]).render_once, None);
+
+    let args = Args::parse_from(["jiq", "--render-once", "a.json"]);
+    assert_eq!(args.render_once, Some((100, 30)));
+    assert_eq!(args.input, vec![PathBuf::from("a.json")]);
+
+    let args = Args::parse_from(["jiq", "--render-once=80x24"]);
+    assert_eq!(args.render_once, Some((80, 24)));
+}
+
+#[test]
+fn query_needs_render_once() {
+    assert!(Args::try_parse_from(["jiq", "--query", ".a"]).is_err());
+
+    let args = Args::parse_from(["jiq", "--render-once", "--query", ".a"]);
+    assert_eq!(args.query.as_deref(), Some(".a"));
+}
+
+#[test]
+fn parse_screen_size_reads_width_by_height() {
+    assert_eq!(parse_screen_size("100x30"), Ok((100, 30)));
+    assert_eq!(parse_screen_size("80X24"), Ok((80, 24)));
+    assert!(parse_screen_size("80").is_err());
+    assert!(parse_screen_size("0x24").is_err());
+    assert!(parse_screen_size("80xtall").is_err());
+    assert!(parse_screen_size("70000x24").is_err());
+}
@@ -1,5 +1,9 @@
+pub mod notification_center_render;
+pub mod notification_events;
 mod notification_render;
 mod notification_state;
+pub mod onboarding;
 
 pub use notification_render::render_notification;
-pub use notification_state::NotificationState;
+pub use notification_state::{NotificationState, NotificationType};
+pub use onboarding::OnboardingTour;
@@ -0,0 +1,111 @@
+//! Notification center popup (Alt+N): recent messages, newest first, with
+//! the time each was shown and its severity
+
+use ratatui::{
+    Frame,
+    style::{Color, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use super::notification_state::{LoggedNotification, NotificationState, NotificationType};
+use crate::str_utils::truncate_to_width;
+use crate::theme;
+use crate::widgets::{popup, scrollbar};
+
+const POPUP_WIDTH: u16 = 80;
+const POPUP_HEIGHT: u16 = 20;
+/// `HH:MM:SS  LABEL  `
+const PREFIX_WIDTH: usize = 17;
+
+pub fn render_center(frame: &mut Frame, notification: &mut NotificationState) {
+    let frame_area = frame.area();
+    let width = POPUP_WIDTH.min(frame_area.width.saturating_sub(4));
+    let height = POPUP_HEIGHT.min(frame_area.height.saturating_sub(2));
+    if width < 30 || height < 5 {
+        return;
+    }
+
+    let popup_area = popup::centered_popup(frame_area, width, height);
+    popup::clear_area(frame, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(
+            " Notifications ({}) ",
+            notification.history().len()
+        ))
+        .title_bottom(
+            theme::border_hints::build_hints(
+                &[("j/k", "Scroll"), ("c", "Clear"), ("Esc", "Close")],
+                theme::help::border(),
+            )
+            .centered(),
+        )
+        .border_style(Style::default().fg(theme::help::border()))
+        .style(Style::default().bg(theme::help::background()))
+        .padding(Padding::horizontal(1));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let message_width = (inner.width as usize).saturating_sub(PREFIX_WIDTH + 1);
+    let lines: Vec<Line> = if notification.history().is_empty() {
+        vec![Line::from(Span::styled(
+            "No notifications yet",
+            Style::default().fg(theme::help::footer()),
+        ))]
+    } else {
+        notification
+            .history()
+            .iter()
+            .rev()
+            .map(|entry| history_line(entry, message_width))
+            .collect()
+    };
+
+    let content_height = lines.len() as u32;
+    let scroll = &mut notification.center_scroll;
+    scroll.update_bounds(content_height, inner.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        inner,
+    );
+
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        popup_area,
+        content_height as usize,
+        inner.height as usize,
+        scroll.offset as usize,
+        theme::help::scrollbar(),
+    );
+}
+
+fn history_line(entry: &LoggedNotification, message_width: usize) -> Line<'static> {
+    let (label, color) = severity(entry.notification_type);
+    Line::from(vec![
+        Span::styled(
+            format!("{}  ", entry.shown_at.format("%H:%M:%S")),
+            Style::default().fg(theme::help::footer()),
+        ),
+        Span::styled(format!("{:<5}  ", label), Style::default().fg(color)),
+        Span::styled(
+            truncate_to_width(&entry.message, message_width),
+            Style::default().fg(theme::help::description()),
+        ),
+    ])
+}
+
+fn severity(notification_type: NotificationType) -> (&'static str, Color) {
+    match notification_type {
+        NotificationType::Info => ("INFO", theme::palette::info()),
+        NotificationType::Warning => ("WARN", theme::palette::warning()),
+        NotificationType::Error => ("ERROR", theme::palette::error()),
+        NotificationType::Hint => ("TIP", theme::palette::text_dim()),
+    }
+}
+
+#[cfg(test)]
+#[path = "notification_center_render_tests.rs"]
+mod notification_center_render_tests;
@@ -0,0 +1,73 @@
+//! Tests for notification/notification_center_render
+
+use super::*;
+use chrono::{Local, TimeZone};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_center_to_string(
+    notification: &mut NotificationState,
+    width: u16,
+    height: u16,
+) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| render_center(f, notification)).unwrap();
+    terminal.backend().to_string()
+}
+
+/// History entries with fixed timestamps so snapshots are stable
+fn logged(entries: &[(&str, NotificationType)]) -> NotificationState {
+    let mut state = NotificationState::new();
+    for (i, (message, notification_type)) in entries.iter().enumerate() {
+        state.history.push_back(LoggedNotification {
+            message: message.to_string(),
+            notification_type: *notification_type,
+            shown_at: Local.with_ymd_and_hms(2026, 1, 2, 9, 30, i as u32).unwrap(),
+        });
+    }
+    state
+}
+
+#[test]
+fn snapshot_center_lists_newest_first() {
+    let mut state = logged(&[
+        ("Failed to load file", NotificationType::Error),
+        ("Config invalid: unknown key", NotificationType::Warning),
+        (
+            "Tip 1/4: Tab accepts the highlighted suggestion",
+            NotificationType::Hint,
+        ),
+        ("Copied query!", NotificationType::Info),
+    ]);
+
+    let output = render_center_to_string(&mut state, 90, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_center_empty() {
+    let mut state = NotificationState::new();
+
+    let output = render_center_to_string(&mut state, 90, 10);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_long_history_scrolls() {
+    let entries: Vec<(String, NotificationType)> = (0..30)
+        .map(|i| (format!("Message {i}"), NotificationType::Info))
+        .collect();
+    let refs: Vec<(&str, NotificationType)> =
+        entries.iter().map(|(m, t)| (m.as_str(), *t)).collect();
+    let mut state = logged(&refs);
+
+    render_center_to_string(&mut state, 90, 24);
+    assert_eq!(state.center_scroll.max_offset, 30 - 18);
+
+    state.center_scroll.jump_to_bottom();
+    let output = render_center_to_string(&mut state, 90, 24);
+    assert!(output.contains("Message 0"));
+    assert!(!output.contains("Message 29"));
+}
@@ -0,0 +1,24 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::notification_state::NotificationState;
+
+/// Keys for the open notification center; it takes every key until closed
+pub fn handle_center_key(state: &mut NotificationState, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => state.toggle_center(),
+        KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::ALT) => state.toggle_center(),
+        KeyCode::Char('c') => state.clear_history(),
+        KeyCode::Char('j') | KeyCode::Down => state.center_scroll.scroll_down(1),
+        KeyCode::Char('k') | KeyCode::Up => state.center_scroll.scroll_up(1),
+        KeyCode::PageDown => state.center_scroll.page_down(),
+        KeyCode::PageUp => state.center_scroll.page_up(),
+        KeyCode::Char('g') | KeyCode::Home => state.center_scroll.jump_to_top(),
+        KeyCode::Char('G') | KeyCode::End => state.center_scroll.jump_to_bottom(),
+        _ => {}
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "notification_events_tests.rs"]
+mod notification_events_tests;
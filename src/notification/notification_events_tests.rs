@@ -0,0 +1,56 @@
+//! Tests for notification/notification_events
+
+use super::*;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::empty())
+}
+
+fn open_center() -> NotificationState {
+    let mut state = NotificationState::new();
+    state.show("Copied!");
+    state.toggle_center();
+    state.center_scroll.update_bounds(30, 10);
+    state
+}
+
+#[test]
+fn test_esc_q_and_alt_n_close_the_center() {
+    for close in [
+        key(KeyCode::Esc),
+        key(KeyCode::Char('q')),
+        KeyEvent::new(KeyCode::Char('n'), KeyModifiers::ALT),
+    ] {
+        let mut state = open_center();
+        assert!(handle_center_key(&mut state, close));
+        assert!(!state.center_visible);
+    }
+}
+
+#[test]
+fn test_c_clears_history() {
+    let mut state = open_center();
+    handle_center_key(&mut state, key(KeyCode::Char('c')));
+    assert!(state.history().is_empty());
+    assert!(state.center_visible);
+}
+
+#[test]
+fn test_scroll_keys() {
+    let mut state = open_center();
+    handle_center_key(&mut state, key(KeyCode::Char('j')));
+    assert_eq!(state.center_scroll.offset, 1);
+    handle_center_key(&mut state, key(KeyCode::Char('G')));
+    assert_eq!(state.center_scroll.offset, 20);
+    handle_center_key(&mut state, key(KeyCode::Up));
+    assert_eq!(state.center_scroll.offset, 19);
+    handle_center_key(&mut state, key(KeyCode::Home));
+    assert_eq!(state.center_scroll.offset, 0);
+}
+
+#[test]
+fn test_other_keys_are_consumed() {
+    let mut state = open_center();
+    assert!(handle_center_key(&mut state, key(KeyCode::Char('x'))));
+    assert!(state.center_visible);
+}
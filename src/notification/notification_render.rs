@@ -6,17 +6,27 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
-use super::notification_state::NotificationState;
+use super::notification_state::{Notification, NotificationState};
 use crate::widgets::popup;
 
+const MARGIN: u16 = 2;
+
+/// Draw the toasts on screen stacked down from the top-right corner,
+/// newest first
 pub fn render_notification(frame: &mut Frame, notification: &mut NotificationState) {
     notification.clear_if_expired();
 
-    let notif = match notification.current() {
-        Some(n) => n,
-        None => return,
-    };
+    let mut y = MARGIN;
+    for notif in notification.visible().iter().rev() {
+        match render_toast(frame, notif, y) {
+            Some(height) => y += height,
+            None => break,
+        }
+    }
+}
 
+/// Draw one toast with its top row at `y`, returning the rows it took
+fn render_toast(frame: &mut Frame, notif: &Notification, y: u16) -> Option<u16> {
     let message = &notif.message;
     let style = &notif.style;
 
@@ -25,20 +35,18 @@ pub fn render_notification(frame: &mut Frame, notification: &mut NotificationSta
     let notification_height = 3;
 
     let frame_area = frame.area();
-    let margin = 2;
-    let notification_x = frame_area.width.saturating_sub(notification_width + margin);
-    let notification_y = margin;
+    let notification_x = frame_area.width.saturating_sub(notification_width + MARGIN);
 
     let notification_area = Rect {
         x: notification_x,
-        y: notification_y,
-        width: notification_width.min(frame_area.width.saturating_sub(margin * 2)),
-        height: notification_height.min(frame_area.height.saturating_sub(margin * 2)),
+        y,
+        width: notification_width.min(frame_area.width.saturating_sub(MARGIN * 2)),
+        height: notification_height.min(frame_area.height.saturating_sub(y + MARGIN)),
     };
 
     // Don't render if area is too small
     if notification_area.width < 5 || notification_area.height < 3 {
-        return;
+        return None;
     }
 
     popup::clear_area(frame, notification_area);
@@ -57,6 +65,7 @@ pub fn render_notification(frame: &mut Frame, notification: &mut NotificationSta
     let paragraph = Paragraph::new(text).block(block);
 
     frame.render_widget(paragraph, notification_area);
+    Some(notification_height)
 }
 
 #[cfg(test)]
@@ -68,3 +68,13 @@ fn snapshot_notification_error_brief() {
     let output = render_notification_to_string(&mut notification, 80, 24);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_notifications_stack_newest_on_top() {
+    let mut notification = NotificationState::new();
+    notification.show_warning("Config invalid");
+    notification.show("Copied query!");
+
+    let output = render_notification_to_string(&mut notification, 80, 12);
+    assert_snapshot!(output);
+}
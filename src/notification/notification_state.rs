@@ -1,6 +1,9 @@
+use chrono::{DateTime, Local};
 use ratatui::style::Color;
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+use crate::scroll::ScrollState;
 use crate::theme;
 
 /// Notification type - determines style and duration
@@ -13,6 +16,8 @@ pub enum NotificationType {
     Warning,
     /// Error (red) - permanent until dismissed - for critical errors
     Error,
+    /// Hint (gray) - long duration (8s) - for onboarding tips; Esc dismisses
+    Hint,
 }
 
 impl NotificationType {
@@ -21,12 +26,13 @@ impl NotificationType {
             NotificationType::Info => Some(Duration::from_millis(1500)),
             NotificationType::Warning => Some(Duration::from_secs(10)),
             NotificationType::Error => None, // Permanent
+            NotificationType::Hint => Some(Duration::from_secs(8)),
         }
     }
 
     fn style(self) -> NotificationStyle {
         match self {
-            NotificationType::Info => NotificationStyle {
+            NotificationType::Info | NotificationType::Hint => NotificationStyle {
                 fg: theme::notification::info().fg,
                 bg: theme::notification::info().bg,
                 border: theme::notification::info().border,
@@ -62,14 +68,14 @@ impl Default for NotificationStyle {
 pub struct Notification {
     pub message: String,
     pub style: NotificationStyle,
-    /// The type of notification (test assertion metadata)
-    #[allow(dead_code)]
+    /// The type of notification; hints can be dismissed early
     pub notification_type: NotificationType,
     pub created_at: Instant,
     pub duration: Option<Duration>,
 }
 
 impl Notification {
+    #[cfg(test)]
     pub fn new(message: &str) -> Self {
         Self::with_type(message, NotificationType::Info)
     }
@@ -92,9 +98,30 @@ impl Notification {
     }
 }
 
+/// Toasts on screen at once; later ones wait their turn
+const MAX_VISIBLE: usize = 3;
+/// Messages the notification center keeps
+pub const HISTORY_LEN: usize = 50;
+
+/// A shown message as listed in the notification center
+#[derive(Debug, Clone)]
+pub struct LoggedNotification {
+    pub message: String,
+    pub notification_type: NotificationType,
+    pub shown_at: DateTime<Local>,
+}
+
 #[derive(Debug, Default)]
 pub struct NotificationState {
-    pub current: Option<Notification>,
+    /// Toasts on screen, oldest first
+    visible: Vec<Notification>,
+    /// Toasts waiting for room on screen
+    queue: VecDeque<Notification>,
+    /// Recent messages, oldest first
+    pub(super) history: VecDeque<LoggedNotification>,
+    /// Whether the notification center popup (Alt+N) is open
+    pub center_visible: bool,
+    pub center_scroll: ScrollState,
 }
 
 impl NotificationState {
@@ -103,11 +130,30 @@ impl NotificationState {
     }
 
     pub fn show(&mut self, message: &str) {
-        self.current = Some(Notification::new(message));
+        self.show_with_type(message, NotificationType::Info);
     }
 
+    /// Stack a toast under the ones already showing, or queue it when the
+    /// stack is full. Repeating a message on screen restarts its timer
+    /// instead of stacking a copy.
     pub fn show_with_type(&mut self, message: &str, notification_type: NotificationType) {
-        self.current = Some(Notification::with_type(message, notification_type));
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(LoggedNotification {
+            message: message.to_string(),
+            notification_type,
+            shown_at: Local::now(),
+        });
+
+        self.visible.retain(|n| n.message != message);
+        self.queue.retain(|n| n.message != message);
+        let notification = Notification::with_type(message, notification_type);
+        if self.visible.len() < MAX_VISIBLE {
+            self.visible.push(notification);
+        } else {
+            self.queue.push_back(notification);
+        }
     }
 
     pub fn show_warning(&mut self, message: &str) {
@@ -121,32 +167,79 @@ impl NotificationState {
         self.show_with_type(message, NotificationType::Error);
     }
 
-    /// Dismiss the current notification (test helper)
+    /// Dismiss the newest notification (test helper)
     ///
     /// Note: Production code uses auto-expiry via clear_if_expired().
     #[cfg(test)]
     pub fn dismiss(&mut self) {
-        self.current = None;
+        self.visible.pop();
+        self.promote_queued();
     }
 
-    /// Clear expired notification, returns true if cleared
+    /// Dismiss the onboarding hints on screen or waiting to be shown
+    pub fn dismiss_hints(&mut self) {
+        let is_hint = |n: &Notification| n.notification_type == NotificationType::Hint;
+        self.visible.retain(|n| !is_hint(n));
+        self.queue.retain(|n| !is_hint(n));
+        self.promote_queued();
+    }
+
+    /// Whether an onboarding hint is on screen
+    pub fn is_showing_hint(&self) -> bool {
+        self.visible
+            .iter()
+            .any(|n| n.notification_type == NotificationType::Hint)
+    }
+
+    /// Clear expired notifications, returns true if any were cleared
     pub fn clear_if_expired(&mut self) -> bool {
-        if let Some(ref notif) = self.current
-            && notif.is_expired()
+        let before = self.visible.len();
+        self.visible.retain(|n| !n.is_expired());
+        if self.visible.len() == before {
+            return false;
+        }
+        self.promote_queued();
+        true
+    }
+
+    /// Move queued toasts on screen while there is room, starting their
+    /// timers now
+    fn promote_queued(&mut self) {
+        while self.visible.len() < MAX_VISIBLE
+            && let Some(mut notification) = self.queue.pop_front()
         {
-            self.current = None;
-            return true;
+            notification.created_at = Instant::now();
+            self.visible.push(notification);
         }
-        false
     }
 
+    /// The newest notification on screen
     pub fn current(&self) -> Option<&Notification> {
-        self.current.as_ref()
+        self.visible.last()
+    }
+
+    /// Notifications on screen, oldest first
+    pub fn visible(&self) -> &[Notification] {
+        &self.visible
+    }
+
+    /// Recent messages, oldest first
+    pub fn history(&self) -> &VecDeque<LoggedNotification> {
+        &self.history
+    }
+
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.center_scroll.reset();
+    }
+
+    pub fn toggle_center(&mut self) {
+        self.center_visible = !self.center_visible;
+        self.center_scroll.reset();
     }
 
-    #[cfg(test)]
     pub fn current_message(&self) -> Option<&str> {
-        self.current.as_ref().map(|n| n.message.as_str())
+        self.current().map(|n| n.message.as_str())
     }
 }
 
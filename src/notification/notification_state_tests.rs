@@ -81,9 +81,7 @@ fn test_clear_if_expired() {
     state.show("Test");
 
     // Manually set a very short duration
-    if let Some(ref mut notif) = state.current {
-        notif.duration = Some(Duration::from_millis(10));
-    }
+    state.visible[0].duration = Some(Duration::from_millis(10));
 
     assert!(!state.clear_if_expired()); // Not expired yet
     thread::sleep(Duration::from_millis(20));
@@ -103,3 +101,107 @@ fn test_error_notification_never_expires() {
     assert!(!state.clear_if_expired()); // Should not clear
     assert!(state.current().is_some());
 }
+
+#[test]
+fn test_hint_notification() {
+    let mut state = NotificationState::new();
+    state.show_with_type("Tip", NotificationType::Hint);
+    let notif = state.current().unwrap();
+    assert_eq!(notif.duration, Some(Duration::from_secs(8)));
+    assert_eq!(notif.style.fg, theme::notification::info().fg);
+    assert!(state.is_showing_hint());
+
+    // Other messages stack under the hint; dismissing hints leaves them
+    state.show("Copied!");
+    assert!(state.is_showing_hint());
+    state.dismiss_hints();
+    assert!(!state.is_showing_hint());
+    assert_eq!(state.current_message(), Some("Copied!"));
+}
+
+#[test]
+fn test_notifications_stack_newest_last() {
+    let mut state = NotificationState::new();
+    state.show_warning("Config invalid");
+    state.show("Copied!");
+
+    let messages: Vec<_> = state.visible().iter().map(|n| n.message.as_str()).collect();
+    assert_eq!(messages, ["Config invalid", "Copied!"]);
+    assert_eq!(state.current_message(), Some("Copied!"));
+}
+
+#[test]
+fn test_repeated_message_does_not_stack() {
+    let mut state = NotificationState::new();
+    state.show("Copied!");
+    state.show("Saved");
+    state.show("Copied!");
+
+    let messages: Vec<_> = state.visible().iter().map(|n| n.message.as_str()).collect();
+    assert_eq!(messages, ["Saved", "Copied!"]);
+}
+
+#[test]
+fn test_full_stack_queues_until_room_frees() {
+    let mut state = NotificationState::new();
+    for message in ["One", "Two", "Three", "Four"] {
+        state.show(message);
+    }
+    assert_eq!(state.visible().len(), MAX_VISIBLE);
+    assert_eq!(state.current_message(), Some("Three"));
+
+    state.visible[0].duration = Some(Duration::ZERO);
+    thread::sleep(Duration::from_millis(1));
+    assert!(state.clear_if_expired());
+
+    let messages: Vec<_> = state.visible().iter().map(|n| n.message.as_str()).collect();
+    assert_eq!(messages, ["Two", "Three", "Four"]);
+    // The promoted toast's timer starts when it is shown
+    assert!(!state.current().unwrap().is_expired());
+}
+
+#[test]
+fn test_history_keeps_the_latest_messages() {
+    let mut state = NotificationState::new();
+    for i in 0..HISTORY_LEN + 5 {
+        state.show(&format!("Message {i}"));
+    }
+    assert_eq!(state.history().len(), HISTORY_LEN);
+    assert_eq!(state.history().front().unwrap().message, "Message 5");
+    assert_eq!(
+        state.history().back().unwrap().message,
+        format!("Message {}", HISTORY_LEN + 4)
+    );
+}
+
+#[test]
+fn test_history_outlives_expired_toasts() {
+    let mut state = NotificationState::new();
+    state.show_error("Failed to load file");
+    state.show("Copied!");
+    state.visible.clear();
+
+    let kinds: Vec<_> = state
+        .history()
+        .iter()
+        .map(|entry| entry.notification_type)
+        .collect();
+    assert_eq!(kinds, [NotificationType::Error, NotificationType::Info]);
+
+    state.clear_history();
+    assert!(state.history().is_empty());
+}
+
+#[test]
+fn test_toggle_center_resets_scroll() {
+    let mut state = NotificationState::new();
+    state.center_scroll.update_bounds(50, 10);
+    state.center_scroll.scroll_down(5);
+
+    state.toggle_center();
+    assert!(state.center_visible);
+    assert_eq!(state.center_scroll.offset, 0);
+
+    state.toggle_center();
+    assert!(!state.center_visible);
+}
@@ -0,0 +1,168 @@
+//! First-run tour of keys new users tend to miss
+//!
+//! On the first launch the tips are shown one after another as hint
+//! notifications, then `onboarding.json` next to the query history records
+//! that the tour ran so later launches stay quiet. F2 steps to the next tip,
+//! or replays them once the tour is over; Esc dismisses the rest of the tour.
+//! `enabled = false` in the `[onboarding]` config section skips the
+//! first-run tour but leaves F2 working.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use serde::{Deserialize, Serialize};
+
+use super::notification_state::{NotificationState, NotificationType};
+
+const ONBOARDING_DIR: &str = "jiq";
+const ONBOARDING_FILE: &str = "onboarding.json";
+
+/// Tips in the order they are shown
+pub const HINTS: &[&str] = &[
+    "Tip 1/4: Tab accepts the highlighted suggestion · F2 next · Esc skip",
+    "Tip 2/4: Ctrl+R searches your query history · F2 next · Esc skip",
+    "Tip 3/4: Shift+Enter exits printing the query, not the result · F2 next",
+    "Tip 4/4: F1 lists every key · F2 replays these tips",
+];
+
+pub fn onboarding_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join(ONBOARDING_DIR).join(ONBOARDING_FILE))
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct OnboardingFile {
+    #[serde(default)]
+    completed: bool,
+}
+
+pub struct OnboardingTour {
+    /// Index into [`HINTS`] of the next tip; None when the tour is idle
+    next: Option<usize>,
+    /// Whether the tour still has to be recorded as seen
+    first_run: bool,
+    persist_to_disk: bool,
+}
+
+impl OnboardingTour {
+    /// Start the tour when `enabled` and it has never run
+    pub fn new(enabled: bool) -> Self {
+        let first_run = enabled
+            && !onboarding_path()
+                .map(|p| load_from_path(&p))
+                .unwrap_or_default()
+                .completed;
+        Self {
+            next: first_run.then_some(0),
+            first_run,
+            persist_to_disk: true,
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            next: None,
+            first_run: false,
+            persist_to_disk: false,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.next.is_some()
+    }
+
+    /// Show the tips again from the first one
+    pub fn replay(&mut self) {
+        self.next = Some(0);
+    }
+
+    /// Drop the tips not yet shown
+    pub fn skip(&mut self) {
+        self.next = None;
+    }
+
+    /// Show the next tip once the notification area is free
+    ///
+    /// Returns true when a tip was shown.
+    pub fn poll(&mut self, notification: &mut NotificationState) -> bool {
+        let Some(index) = self.next else {
+            return false;
+        };
+        if notification.current().is_some() {
+            return false;
+        }
+        notification.show_with_type(HINTS[index], NotificationType::Hint);
+        self.next = Some(index + 1).filter(|&i| i < HINTS.len());
+
+        if self.first_run {
+            self.first_run = false;
+            if self.persist_to_disk
+                && let Err(e) = self.save()
+            {
+                log::warn!("Failed to save onboarding state: {}", e);
+            }
+        }
+        true
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = onboarding_path() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Could not determine onboarding state path",
+            ));
+        };
+        save_to_path(&OnboardingFile { completed: true }, &path)
+    }
+}
+
+/// F2 moves to the next tip, or replays the tour once the last one is
+/// reached; Esc dismisses a showing tip and the rest of the tour
+pub fn handle_onboarding_key(
+    tour: &mut OnboardingTour,
+    notification: &mut NotificationState,
+    key: KeyEvent,
+) -> bool {
+    match key.code {
+        KeyCode::F(2) => {
+            if notification.is_showing_hint() {
+                notification.dismiss_hints();
+            }
+            if !tour.is_running() {
+                tour.replay();
+            }
+            tour.poll(notification);
+            true
+        }
+        KeyCode::Esc if notification.is_showing_hint() => {
+            notification.dismiss_hints();
+            tour.skip();
+            true
+        }
+        _ => false,
+    }
+}
+
+fn load_from_path(path: &Path) -> OnboardingFile {
+    let Ok(contents) = fs::read_to_string(path) else {
+        log::debug!("No onboarding state file at {:?}", path);
+        return OnboardingFile::default();
+    };
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        log::warn!("Failed to parse onboarding state: {}", e);
+        OnboardingFile::default()
+    })
+}
+
+fn save_to_path(state: &OnboardingFile, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string(state).map_err(io::Error::other)?;
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+#[path = "onboarding_tests.rs"]
+mod onboarding_tests;
@@ -0,0 +1,128 @@
+//! Tests for notification/onboarding
+
+use super::*;
+use ratatui::crossterm::event::KeyModifiers;
+use tempfile::TempDir;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::empty())
+}
+
+/// A first-run tour that stays off disk
+fn first_run_tour() -> OnboardingTour {
+    let mut tour = OnboardingTour::empty();
+    tour.replay();
+    tour.first_run = true;
+    tour
+}
+
+#[test]
+fn test_empty_tour_is_idle() {
+    let mut tour = OnboardingTour::empty();
+    let mut notification = NotificationState::new();
+    assert!(!tour.is_running());
+    assert!(!tour.poll(&mut notification));
+    assert!(notification.current().is_none());
+}
+
+#[test]
+fn test_poll_shows_tips_in_order() {
+    let mut tour = first_run_tour();
+    let mut notification = NotificationState::new();
+
+    for hint in HINTS {
+        assert!(tour.poll(&mut notification));
+        assert_eq!(notification.current_message(), Some(*hint));
+        assert!(notification.is_showing_hint());
+        notification.dismiss();
+    }
+    assert!(!tour.is_running());
+    assert!(!tour.poll(&mut notification));
+    assert!(!tour.first_run);
+}
+
+#[test]
+fn test_poll_waits_for_other_notifications() {
+    let mut tour = first_run_tour();
+    let mut notification = NotificationState::new();
+    notification.show("Copied!");
+
+    assert!(!tour.poll(&mut notification));
+    assert_eq!(notification.current_message(), Some("Copied!"));
+    assert!(tour.is_running());
+}
+
+#[test]
+fn test_f2_steps_through_tips_then_replays() {
+    let mut tour = OnboardingTour::empty();
+    let mut notification = NotificationState::new();
+
+    assert!(handle_onboarding_key(
+        &mut tour,
+        &mut notification,
+        key(KeyCode::F(2))
+    ));
+    assert_eq!(notification.current_message(), Some(HINTS[0]));
+
+    for hint in &HINTS[1..] {
+        handle_onboarding_key(&mut tour, &mut notification, key(KeyCode::F(2)));
+        assert_eq!(notification.current_message(), Some(*hint));
+    }
+
+    // Past the last tip F2 starts over
+    handle_onboarding_key(&mut tour, &mut notification, key(KeyCode::F(2)));
+    assert_eq!(notification.current_message(), Some(HINTS[0]));
+}
+
+#[test]
+fn test_esc_dismisses_the_rest_of_the_tour() {
+    let mut tour = first_run_tour();
+    let mut notification = NotificationState::new();
+    tour.poll(&mut notification);
+
+    assert!(handle_onboarding_key(
+        &mut tour,
+        &mut notification,
+        key(KeyCode::Esc)
+    ));
+    assert!(notification.current().is_none());
+    assert!(!tour.is_running());
+}
+
+#[test]
+fn test_esc_passes_through_without_a_tip() {
+    let mut tour = OnboardingTour::empty();
+    let mut notification = NotificationState::new();
+    notification.show("Copied!");
+
+    assert!(!handle_onboarding_key(
+        &mut tour,
+        &mut notification,
+        key(KeyCode::Esc)
+    ));
+    assert_eq!(notification.current_message(), Some("Copied!"));
+}
+
+#[test]
+fn test_state_file_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("nested").join(ONBOARDING_FILE);
+    assert!(!load_from_path(&path).completed);
+
+    save_to_path(&OnboardingFile { completed: true }, &path).unwrap();
+    assert!(load_from_path(&path).completed);
+}
+
+#[test]
+fn test_corrupt_state_file_counts_as_first_run() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(ONBOARDING_FILE);
+    fs::write(&path, "not json").unwrap();
+    assert!(!load_from_path(&path).completed);
+}
+
+#[test]
+fn test_disabled_tour_does_not_start() {
+    let tour = OnboardingTour::new(false);
+    assert!(!tour.is_running());
+}
@@ -0,0 +1,16 @@
+//! Values for the query's `$parameters`
+//!
+//! A query can use `$names` it never binds, as a template:
+//! `select(.env == $env)`. F6 lists them with a value each, and Enter
+//! passes the values to jq as `--arg`, or `--argjson` when the value is
+//! JSON, on every run and for the output on exit. The values stay set for
+//! the session, so any snippet or history entry that uses the same names
+//! runs with them.
+
+mod params_events;
+mod params_render;
+mod params_state;
+
+pub use params_events::{handle_key, toggle};
+pub use params_render::render_popup;
+pub use params_state::ParamsPanel;
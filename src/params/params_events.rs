@@ -0,0 +1,86 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::params_state::Param;
+use crate::app::App;
+use crate::autocomplete::free_variables;
+use crate::query::jq_args::NamedArg;
+
+/// F6 — open the panel for the query's `$parameters`, or close it
+pub fn toggle(app: &mut App) {
+    if app.params.is_visible() {
+        app.params.close();
+        return;
+    }
+    let jq_args = std::sync::Arc::clone(&app.jq_args);
+    let params: Vec<Param> = free_variables(app.query())
+        .into_iter()
+        // `--rawfile` and `--slurpfile` names are bound already
+        .filter(|name| !jq_args.file_vars().iter().any(|v| &v.name == name))
+        .map(|name| {
+            let value = jq_args
+                .named_args()
+                .iter()
+                .find(|arg| arg.name == name)
+                .map(|arg| arg.value.clone())
+                .unwrap_or_default();
+            Param { name, value }
+        })
+        .collect();
+    if params.is_empty() {
+        app.notification.show("No $parameters in the query");
+        return;
+    }
+    app.params.open(params);
+}
+
+/// Keys while the panel is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::F(6) => app.params.close(),
+        KeyCode::Enter => apply(app),
+        KeyCode::Down | KeyCode::Tab => app.params.select_next(),
+        KeyCode::Up | KeyCode::BackTab => app.params.select_prev(),
+        _ => {
+            app.params.input(key.into());
+        }
+    }
+}
+
+/// Pass the panel's values to jq and run the query with them. Values set
+/// earlier for names this query doesn't use are kept; an empty value
+/// unsets its name.
+fn apply(app: &mut App) {
+    app.params.close();
+    let params = app.params.params();
+    let mut args: Vec<NamedArg> = app
+        .jq_args
+        .named_args()
+        .iter()
+        .filter(|arg| !params.iter().any(|p| p.name == arg.name))
+        .cloned()
+        .collect();
+    let set: Vec<NamedArg> = params
+        .iter()
+        .filter(|p| !p.value.is_empty())
+        .map(|p| NamedArg {
+            name: p.name.clone(),
+            value: p.value.clone(),
+        })
+        .collect();
+    let message = if set.is_empty() {
+        "Parameters cleared".to_string()
+    } else {
+        let shown: Vec<String> = set
+            .iter()
+            .map(|arg| format!("${}={}", arg.name, arg.value))
+            .collect();
+        format!("Running with {}", shown.join(" "))
+    };
+    args.extend(set);
+    app.update_jq_args(|jq_args| jq_args.set_named_args(args));
+    app.notification.show(&message);
+}
+
+#[cfg(test)]
+#[path = "params_events_tests.rs"]
+mod params_events_tests;
@@ -0,0 +1,137 @@
+//! Tests for params/params_events
+
+use ratatui::crossterm::event::KeyCode;
+
+use super::*;
+use crate::test_utils::test_helpers::{execute_query_and_wait, key, test_app};
+
+const JSON: &str = r#"[{"env": "prod", "n": 3}, {"env": "dev", "n": 9}]"#;
+
+fn app_with_query(query: &str) -> App {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str(query);
+    execute_query_and_wait(&mut app);
+    app
+}
+
+fn type_value(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn f6_lists_the_query_parameters() {
+    let mut app = app_with_query(".[] | select(.env == $env and .n > $min) | .n");
+
+    app.handle_key_event(key(KeyCode::F(6)));
+
+    assert!(app.params.is_visible());
+    let names: Vec<&str> = app
+        .params
+        .params()
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect();
+    assert_eq!(names, ["env", "min"]);
+}
+
+#[test]
+fn query_without_parameters_says_so() {
+    let mut app = app_with_query(".[0]");
+
+    toggle(&mut app);
+
+    assert!(!app.params.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No $parameters in the query")
+    );
+}
+
+#[test]
+fn enter_runs_the_query_with_the_values() {
+    let mut app = app_with_query("[.[] | select(.env == $env and .n > $min) | .n]");
+    toggle(&mut app);
+
+    type_value(&mut app, "dev");
+    app.handle_key_event(key(KeyCode::Tab));
+    type_value(&mut app, "5");
+    app.handle_key_event(key(KeyCode::Enter));
+    execute_query_and_wait(&mut app);
+
+    assert!(!app.params.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Running with $env=dev $min=5")
+    );
+    let words = app.jq_args.to_words();
+    assert_eq!(words, ["--arg", "env", "dev", "--argjson", "min", "5"]);
+    let result = app.query.as_ref().unwrap().result.clone().unwrap();
+    assert!(result.contains('9'), "{}", result);
+}
+
+#[test]
+fn values_come_back_when_reopened() {
+    let mut app = app_with_query(".[] | select(.env == $env)");
+    toggle(&mut app);
+    type_value(&mut app, "prod");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    toggle(&mut app);
+
+    assert_eq!(app.params.params()[0].value, "prod");
+}
+
+#[test]
+fn values_for_other_names_are_kept() {
+    let mut app = app_with_query("$a");
+    toggle(&mut app);
+    type_value(&mut app, "1");
+    app.handle_key_event(key(KeyCode::Enter));
+    app.input.textarea.select_all();
+    app.input.textarea.cut();
+    app.input.textarea.insert_str("$b");
+    toggle(&mut app);
+    type_value(&mut app, "2");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    let names: Vec<&str> = app
+        .jq_args
+        .named_args()
+        .iter()
+        .map(|arg| arg.name.as_str())
+        .collect();
+    assert_eq!(names, ["a", "b"]);
+}
+
+#[test]
+fn esc_discards_the_edits() {
+    let mut app = app_with_query("$a");
+    toggle(&mut app);
+    type_value(&mut app, "1");
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.params.is_visible());
+    assert!(app.jq_args.named_args().is_empty());
+}
+
+#[test]
+fn emptied_value_unsets_the_name() {
+    let mut app = app_with_query("$a");
+    toggle(&mut app);
+    type_value(&mut app, "1");
+    app.handle_key_event(key(KeyCode::Enter));
+    toggle(&mut app);
+
+    app.handle_key_event(key(KeyCode::Backspace));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.jq_args.named_args().is_empty());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Parameters cleared")
+    );
+}
@@ -0,0 +1,111 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+};
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const POPUP_WIDTH: u16 = 60;
+const MAX_ROWS: usize = 10;
+const MAX_NAME_WIDTH: usize = 20;
+
+/// Render the parameters panel centered over `area`
+pub fn render_popup(app: &App, frame: &mut Frame, area: Rect) {
+    let params = app.params.params();
+    let rows = params.len().min(MAX_ROWS);
+    let height = rows as u16 + 2;
+    let popup_area = centered_rect(area, POPUP_WIDTH, height);
+    if popup_area.width < 30 || popup_area.height < height {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(
+        " Parameters ",
+        &[("Enter", "Run"), ("↑↓", "Select"), ("Esc", "Close")],
+    );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let selected = app.params.selected_index();
+    // Keep the highlighted parameter in view
+    let offset = (selected + 1).saturating_sub(rows);
+    let name_width = params
+        .iter()
+        .map(|p| p.name.chars().count() + 1)
+        .max()
+        .unwrap_or(0)
+        .min(MAX_NAME_WIDTH);
+    let value_width = (inner.width as usize).saturating_sub(name_width + 1).max(1);
+    let lines: Vec<Line> = params
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(rows)
+        .map(|(i, param)| {
+            let name: String = format!("${}", param.name)
+                .chars()
+                .take(name_width)
+                .collect();
+            let mut spans = vec![Span::styled(
+                format!("{:<name_width$} ", name),
+                if i == selected {
+                    Style::default()
+                        .fg(theme::results::border_focused())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme::palette::text())
+                },
+            )];
+            if i == selected {
+                spans.extend(editing_spans(
+                    &param.value,
+                    app.params.cursor(),
+                    value_width,
+                ));
+            } else if param.value.is_empty() {
+                spans.push(Span::styled(
+                    "unset",
+                    Style::default()
+                        .fg(theme::palette::text_dim())
+                        .add_modifier(Modifier::ITALIC),
+                ));
+            } else {
+                let value: String = param.value.chars().take(value_width).collect();
+                spans.push(Span::styled(
+                    value,
+                    Style::default().fg(theme::palette::text()),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// The value being edited, scrolled so the cursor stays in `width`
+fn editing_spans(value: &str, cursor: usize, width: usize) -> Vec<Span<'static>> {
+    let chars: Vec<char> = value.chars().collect();
+    let start = (cursor + 1).saturating_sub(width);
+    let end = chars.len().min(start + width);
+    let before: String = chars[start..cursor.min(end)].iter().collect();
+    let at: String = chars.get(cursor).map_or(" ".to_string(), char::to_string);
+    let after: String = chars
+        .get(cursor + 1..end)
+        .map_or(String::new(), |rest| rest.iter().collect());
+    let text = Style::default().fg(theme::palette::text());
+    vec![
+        Span::styled(before, text),
+        Span::styled(at, text.add_modifier(Modifier::REVERSED)),
+        Span::styled(after, text),
+    ]
+}
+
+#[cfg(test)]
+#[path = "params_render_tests.rs"]
+mod params_render_tests;
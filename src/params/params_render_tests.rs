@@ -0,0 +1,49 @@
+//! Tests for params/params_render
+
+use insta::assert_snapshot;
+
+use super::*;
+use crate::params::params_state::Param;
+use crate::test_utils::test_helpers::{render_popup_to_string, test_app};
+
+fn param(name: &str, value: &str) -> Param {
+    Param {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn snapshot_params_panel() {
+    let mut app = test_app("{}");
+    app.params.open(vec![
+        param("env", "prod"),
+        param("min_count", "5"),
+        param("region", ""),
+    ]);
+    app.params.select_next();
+
+    assert_snapshot!(render_popup_to_string(&app, 70, 8, render_popup));
+}
+
+#[test]
+fn long_value_scrolls_to_the_cursor() {
+    let mut app = test_app("{}");
+    let value = format!("{}END", "x".repeat(100));
+    app.params.open(vec![param("q", &value)]);
+
+    let output = render_popup_to_string(&app, 70, 5, render_popup);
+
+    assert!(output.contains("xEND"));
+    assert!(output.contains("$q"));
+}
+
+#[test]
+fn not_drawn_when_too_small() {
+    let mut app = test_app("{}");
+    app.params.open(vec![param("env", "prod")]);
+
+    let output = render_popup_to_string(&app, 20, 10, render_popup);
+
+    assert!(!output.contains("Parameters"));
+}
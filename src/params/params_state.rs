@@ -0,0 +1,106 @@
+use tui_textarea::{Input, Key, TextArea};
+
+/// One `$name` in the panel and the value typed for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    /// Variable name without the `$`
+    pub name: String,
+    pub value: String,
+}
+
+/// The F6 panel of values for the query's `$parameters`
+#[derive(Debug, Default)]
+pub struct ParamsPanel {
+    visible: bool,
+    params: Vec<Param>,
+    selected: usize,
+    /// Edits the highlighted parameter's value
+    editor: TextArea<'static>,
+}
+
+impl ParamsPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show `params`, editing the first
+    pub fn open(&mut self, params: Vec<Param>) {
+        self.visible = true;
+        self.params = params;
+        self.selected = 0;
+        self.load_editor();
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn params(&self) -> &[Param] {
+        &self.params
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    /// Where the cursor is in the highlighted value, in chars
+    pub fn cursor(&self) -> usize {
+        self.editor.cursor().1
+    }
+
+    /// Edit the next parameter, wrapping to the top
+    pub fn select_next(&mut self) {
+        if !self.params.is_empty() {
+            self.selected = (self.selected + 1) % self.params.len();
+            self.load_editor();
+        }
+    }
+
+    /// Edit the previous parameter, wrapping to the bottom
+    pub fn select_prev(&mut self) {
+        if !self.params.is_empty() {
+            self.selected = (self.selected + self.params.len() - 1) % self.params.len();
+            self.load_editor();
+        }
+    }
+
+    /// Edit the highlighted value with a key; values are one line, so
+    /// Enter never reaches it. True when the key was used.
+    pub fn input(&mut self, input: Input) -> bool {
+        if input.key == Key::Enter {
+            return false;
+        }
+        let changed = self.editor.input(input);
+        self.store_editor();
+        changed
+    }
+
+    /// Type `text` into the highlighted value, as one line
+    pub fn insert_str(&mut self, text: &str) {
+        self.editor.insert_str(text.replace(['\r', '\n'], " "));
+        self.store_editor();
+    }
+
+    fn load_editor(&mut self) {
+        let value = self
+            .params
+            .get(self.selected)
+            .map_or("", |p| p.value.as_str());
+        self.editor = TextArea::default();
+        self.editor.insert_str(value);
+    }
+
+    fn store_editor(&mut self) {
+        if let Some(param) = self.params.get_mut(self.selected) {
+            param.value = self.editor.lines().concat();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "params_state_tests.rs"]
+mod params_state_tests;
@@ -0,0 +1,71 @@
+//! Tests for params/params_state
+
+use super::*;
+
+fn panel(values: &[(&str, &str)]) -> ParamsPanel {
+    let mut panel = ParamsPanel::new();
+    panel.open(
+        values
+            .iter()
+            .map(|(name, value)| Param {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect(),
+    );
+    panel
+}
+
+fn typed(c: char) -> Input {
+    Input {
+        key: Key::Char(c),
+        ..Input::default()
+    }
+}
+
+#[test]
+fn typing_edits_the_highlighted_value() {
+    let mut panel = panel(&[("env", "pr"), ("min", "")]);
+
+    panel.input(typed('o'));
+    panel.input(typed('d'));
+
+    assert_eq!(panel.params()[0].value, "prod");
+    assert_eq!(panel.cursor(), 4);
+}
+
+#[test]
+fn selection_wraps_and_keeps_values() {
+    let mut panel = panel(&[("env", "prod"), ("min", "")]);
+
+    panel.select_next();
+    panel.input(typed('5'));
+    panel.select_next();
+
+    assert_eq!(panel.selected_index(), 0);
+    assert_eq!(panel.params()[1].value, "5");
+    panel.select_prev();
+    assert_eq!(panel.selected_index(), 1);
+}
+
+#[test]
+fn enter_is_left_to_the_panel() {
+    let mut panel = panel(&[("env", "prod")]);
+
+    let used = panel.input(Input {
+        key: Key::Enter,
+        ..Input::default()
+    });
+
+    assert!(!used);
+    assert_eq!(panel.params()[0].value, "prod");
+}
+
+#[test]
+fn pasted_text_stays_on_one_line() {
+    let mut panel = panel(&[("ids", "")]);
+
+    panel.insert_str("[1,\n2]");
+
+    assert_eq!(panel.params()[0].value, "[1, 2]");
+}
@@ -156,6 +156,35 @@ pub fn apply_keep_kv(app: &mut App, source: PathSource) -> ApplyOutcome {
     compose_and_apply(app, suffix, /* push_to_ring */ true)
 }
 
+/// `E`, then Enter — decode the string at `path` in place with
+/// `PATH |= filter`, such as `.body |= fromjson`, or just `filter` when
+/// the whole result is the string. Snapshots prior state to the ring
+/// like `>`.
+pub fn apply_decode(app: &mut App, path: &JsonPath, filter: &str) -> ApplyOutcome {
+    let suffix = if path.is_empty() {
+        filter.to_string()
+    } else if has_top_level_pipe(filter) {
+        format!("{} |= ({})", path.to_jq(), filter)
+    } else {
+        format!("{} |= {}", path.to_jq(), filter)
+    };
+    compose_and_apply(app, suffix, /* push_to_ring */ true)
+}
+
+/// Whether `filter` pipes outside any parentheses, so that `|=` would
+/// only take its first stage
+fn has_top_level_pipe(filter: &str) -> bool {
+    let mut depth = 0i32;
+    filter.chars().any(|c| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        c == '|' && depth == 0
+    })
+}
+
 /// `[` / `]` — locate the previous / next sibling of `source`'s path in
 /// the parent container and return the line where it renders. Pure
 /// resolution: does NOT touch the textarea, the undo ring, the cursor,
@@ -324,6 +353,9 @@ pub fn apply_pending_viewport_restore(app: &mut App) {
 fn resolve_path(app: &mut App, source: PathSource) -> Option<JsonPath> {
     match source {
         PathSource::CursorRow => app.current_cursor_path(),
+        PathSource::Row(row) if app.results_paths.is_active() => {
+            app.results_paths.path_at(row).cloned()
+        }
         PathSource::Row(row) => {
             let parsed = app.query.as_ref()?.last_successful_result_parsed.clone()?;
             // Mirror the gating in `App::current_cursor_path`: skip when
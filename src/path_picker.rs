@@ -0,0 +1,15 @@
+//! Pick a path by walking the input document
+//!
+//! F7 shows the input as miller columns: the level above, the keys or
+//! indices of the current level, and a preview of the highlighted value.
+//! Arrow keys move through the structure and Enter inserts the jq path of
+//! the highlighted value into the query, as a structural alternative to
+//! typing paths with autocomplete.
+
+mod path_picker_events;
+mod path_picker_render;
+mod path_picker_state;
+
+pub use path_picker_events::{handle_key, toggle};
+pub use path_picker_render::render_popup;
+pub use path_picker_state::PathPicker;
@@ -0,0 +1,87 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::editor::editor_events::execute_query;
+
+/// Rows PageUp and PageDown move by
+const PAGE: isize = 10;
+
+/// F7 — open the picker at the top of the input, or close it
+pub fn toggle(app: &mut App) {
+    if app.path_picker.is_visible() {
+        app.path_picker.close();
+        return;
+    }
+    let Some(query) = app.query.as_ref() else {
+        return;
+    };
+    let Some(root) = query.executor.json_input_parsed() else {
+        app.notification
+            .show("The path picker needs the input analysis, off for large inputs");
+        return;
+    };
+    if !app.path_picker.open(root) {
+        app.notification
+            .show("Nothing to walk into: the input has no keys or elements");
+    }
+}
+
+/// Keys while the picker is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let picker = &mut app.path_picker;
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::F(7) => picker.close(),
+        KeyCode::Down | KeyCode::Char('j') => picker.move_by(1),
+        KeyCode::Up | KeyCode::Char('k') => picker.move_by(-1),
+        KeyCode::PageDown => picker.move_by(PAGE),
+        KeyCode::PageUp => picker.move_by(-PAGE),
+        KeyCode::Home | KeyCode::Char('g') => picker.move_to_end(false),
+        KeyCode::End | KeyCode::Char('G') => picker.move_to_end(true),
+        KeyCode::Right | KeyCode::Char('l') => {
+            picker.enter();
+        }
+        KeyCode::Left | KeyCode::Char('h') => {
+            picker.leave();
+        }
+        KeyCode::Enter => pick(app, false),
+        KeyCode::Char('*') => pick(app, true),
+        _ => {}
+    }
+}
+
+/// Insert the highlighted path and close; `splat` turns its last array
+/// index into `[]` to take every element
+fn pick(app: &mut App, splat: bool) {
+    let mut path = app.path_picker.path();
+    if splat {
+        path.splat_nearest_index();
+    }
+    app.path_picker.close();
+    insert_path(app, &path.to_jq());
+}
+
+/// Put `path` into the query at the cursor. An empty query or a lone `.`
+/// is replaced, and a `.` just before the cursor stands in for the path's
+/// own leading dot.
+pub fn insert_path(app: &mut App, path: &str) {
+    let query = app.query().trim();
+    if query.is_empty() || query == "." {
+        app.input.textarea.delete_line_by_head();
+        app.input.textarea.delete_line_by_end();
+        app.input.textarea.insert_str(path);
+    } else {
+        let cursor = app.input.textarea.cursor().1;
+        let before_is_dot = app.query().chars().nth(cursor.wrapping_sub(1)) == Some('.');
+        let text = if before_is_dot {
+            path.strip_prefix('.').unwrap_or(path)
+        } else {
+            path
+        };
+        app.input.textarea.insert_str(text);
+    }
+    execute_query(app);
+}
+
+#[cfg(test)]
+#[path = "path_picker_events_tests.rs"]
+mod path_picker_events_tests;
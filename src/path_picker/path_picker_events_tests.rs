@@ -0,0 +1,108 @@
+//! Tests for path_picker/path_picker_events
+
+use ratatui::crossterm::event::KeyCode;
+
+use super::*;
+use crate::test_utils::test_helpers::{key, test_app};
+
+const JSON: &str =
+    r#"{"users": [{"name": "ann", "tags": ["a", "b"]}, {"name": "bob", "tags": []}], "count": 2}"#;
+
+fn press(app: &mut App, codes: &[KeyCode]) {
+    for code in codes {
+        app.handle_key_event(key(*code));
+    }
+}
+
+#[test]
+fn f7_opens_at_the_top_of_the_input() {
+    let mut app = test_app(JSON);
+
+    app.handle_key_event(key(KeyCode::F(7)));
+
+    assert!(app.path_picker.is_visible());
+    assert_eq!(app.path_picker.path().to_jq(), ".users");
+}
+
+#[test]
+fn enter_replaces_an_empty_query_with_the_path() {
+    let mut app = test_app(JSON);
+    toggle(&mut app);
+
+    press(
+        &mut app,
+        &[
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Right,
+            KeyCode::Enter,
+        ],
+    );
+
+    assert!(!app.path_picker.is_visible());
+    assert_eq!(app.query(), ".users[1].name");
+}
+
+#[test]
+fn star_takes_every_element() {
+    let mut app = test_app(JSON);
+    toggle(&mut app);
+
+    press(
+        &mut app,
+        &[
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Char('*'),
+        ],
+    );
+
+    assert_eq!(app.query(), ".users[].tags");
+}
+
+#[test]
+fn inserts_at_the_cursor_after_a_pipe() {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str("keys | ");
+    toggle(&mut app);
+
+    press(&mut app, &[KeyCode::Down, KeyCode::Enter]);
+
+    assert_eq!(app.query(), "keys | .count");
+}
+
+#[test]
+fn dot_before_the_cursor_is_not_doubled() {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str("map(.");
+    toggle(&mut app);
+
+    press(&mut app, &[KeyCode::Down, KeyCode::Enter]);
+
+    assert_eq!(app.query(), "map(.count");
+}
+
+#[test]
+fn esc_closes_without_inserting() {
+    let mut app = test_app(JSON);
+    toggle(&mut app);
+
+    press(&mut app, &[KeyCode::Right, KeyCode::Esc]);
+
+    assert!(!app.path_picker.is_visible());
+    assert_eq!(app.query(), "");
+}
+
+#[test]
+fn scalar_input_has_nothing_to_walk() {
+    let mut app = test_app("42");
+
+    toggle(&mut app);
+
+    assert!(!app.path_picker.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing to walk into: the input has no keys or elements")
+    );
+}
@@ -0,0 +1,150 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+};
+use serde_json::Value;
+
+use super::path_picker_state::{child, child_count};
+use crate::app::App;
+use crate::json_path::JsonPathStep;
+use crate::str_utils::{display_width, head_truncate_to_width, truncate_to_width};
+use crate::theme;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const MAX_WIDTH: u16 = 110;
+const MAX_HEIGHT: u16 = 18;
+
+/// Render the picker's columns centered over `area`
+pub fn render_popup(app: &App, frame: &mut Frame, area: Rect) {
+    let picker = &app.path_picker;
+    let width = area.width.saturating_sub(4).min(MAX_WIDTH);
+    let height = area.height.saturating_sub(2).min(MAX_HEIGHT);
+    if width < 40 || height < 5 {
+        return;
+    }
+    let popup_area = centered_rect(area, width, height);
+    frame.render_widget(Clear, popup_area);
+
+    let path = picker.path().to_jq();
+    let title = format!(
+        " Path: {} ",
+        head_truncate_to_width(&path, width.saturating_sub(12) as usize)
+    );
+    let block = popup_block(
+        title,
+        &[
+            ("Enter", "Insert"),
+            ("*", "Insert with []"),
+            ("←→", "Level"),
+            ("↑↓", "Select"),
+            ("Esc", "Close"),
+        ],
+    );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let [parent_area, current_area, preview_area] = Layout::horizontal([
+        Constraint::Percentage(25),
+        Constraint::Percentage(35),
+        Constraint::Percentage(40),
+    ])
+    .areas(inner);
+    let separator = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(Style::default().fg(theme::palette::text_muted()));
+
+    let depth = picker.depth();
+    let parent_lines = match depth.checked_sub(1) {
+        Some(above) => picker.level(above).map_or_else(Vec::new, |level| {
+            column_lines(level, picker.selected_at(above), false, parent_area)
+        }),
+        None => vec![Line::from(Span::styled(
+            ".",
+            Style::default().fg(theme::palette::text_dim()),
+        ))],
+    };
+    frame.render_widget(Paragraph::new(parent_lines), parent_area);
+
+    let current_lines = picker.level(depth).map_or_else(Vec::new, |level| {
+        column_lines(level, picker.selected_at(depth), true, current_area)
+    });
+    let current_block = separator.clone().padding(Padding::left(1));
+    let current_inner = current_block.inner(current_area);
+    frame.render_widget(current_block, current_area);
+    frame.render_widget(Paragraph::new(current_lines), current_inner);
+
+    let preview_block = separator.padding(Padding::left(1));
+    let preview_inner = preview_block.inner(preview_area);
+    frame.render_widget(preview_block, preview_area);
+    if let Some(value) = picker.selected_value() {
+        let preview = if child_count(value) > 0 {
+            Paragraph::new(column_lines(value, usize::MAX, false, preview_inner))
+        } else {
+            Paragraph::new(scalar_text(value))
+                .style(Style::default().fg(theme::palette::text()))
+                .wrap(Wrap { trim: false })
+        };
+        frame.render_widget(preview, preview_inner);
+    }
+}
+
+/// The children of `level`, one per row, scrolled so `selected` is in view
+fn column_lines(level: &Value, selected: usize, focused: bool, area: Rect) -> Vec<Line<'static>> {
+    let rows = area.height as usize;
+    let width = area.width as usize;
+    let count = child_count(level);
+    let offset = if selected < count {
+        (selected + 1).saturating_sub(rows)
+    } else {
+        0
+    };
+    (offset..count.min(offset + rows))
+        .filter_map(|i| child(level, i).map(|(step, value)| (i, step, value)))
+        .map(|(i, step, value)| {
+            let label = match step {
+                JsonPathStep::Key(key) => key,
+                JsonPathStep::Index(index) => format!("[{}]", index),
+                JsonPathStep::Splat => "[]".to_string(),
+            };
+            let label = truncate_to_width(&label, width.saturating_sub(2));
+            let summary_width = width.saturating_sub(display_width(&label) + 1);
+            let summary = truncate_to_width(&summary(value), summary_width);
+            let label_style = if i == selected && focused {
+                Style::default()
+                    .fg(theme::results::border_focused())
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else if i == selected {
+                Style::default()
+                    .fg(theme::palette::text())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::palette::text())
+            };
+            Line::from(vec![
+                Span::styled(label, label_style),
+                Span::raw(" "),
+                Span::styled(summary, Style::default().fg(theme::palette::text_dim())),
+            ])
+        })
+        .collect()
+}
+
+/// A short hint of what a value holds
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Object(map) => format!("{{{}}}", map.len()),
+        Value::Array(items) => format!("[{}]", items.len()),
+        other => scalar_text(other),
+    }
+}
+
+fn scalar_text(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "path_picker_render_tests.rs"]
+mod path_picker_render_tests;
@@ -0,0 +1,54 @@
+//! Tests for path_picker/path_picker_render
+
+use insta::assert_snapshot;
+
+use super::*;
+use crate::path_picker::toggle;
+use crate::test_utils::test_helpers::{render_popup_to_string, test_app};
+
+const JSON: &str = r#"{"users": [{"name": "ann", "zip-code": "10001", "tags": ["a", "b"]}, {"name": "bob"}], "count": 2}"#;
+
+#[test]
+fn snapshot_path_picker_columns() {
+    let mut app = test_app(JSON);
+    toggle(&mut app);
+    app.path_picker.enter();
+    app.path_picker.enter();
+
+    assert_snapshot!(render_popup_to_string(&app, 90, 10, render_popup));
+}
+
+#[test]
+fn snapshot_path_picker_at_the_top() {
+    let mut app = test_app(JSON);
+    toggle(&mut app);
+
+    assert_snapshot!(render_popup_to_string(&app, 90, 8, render_popup));
+}
+
+#[test]
+fn long_level_scrolls_to_the_selection() {
+    let json = format!(
+        "[{}]",
+        (0..50).map(|i| i.to_string()).collect::<Vec<_>>().join(",")
+    );
+    let mut app = test_app(&json);
+    toggle(&mut app);
+    app.path_picker.move_by(40);
+
+    let output = render_popup_to_string(&app, 90, 12, render_popup);
+
+    assert!(output.contains("[40] 40"));
+    assert!(!output.contains("[0] 0"));
+    assert!(output.contains(" Path: .[40] "));
+}
+
+#[test]
+fn not_drawn_when_too_small() {
+    let mut app = test_app(JSON);
+    toggle(&mut app);
+
+    let output = render_popup_to_string(&app, 30, 10, render_popup);
+
+    assert!(!output.contains("Path:"));
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::json_path::{JsonPath, JsonPathStep};
+
+/// The F7 walk through the input document
+#[derive(Debug, Default)]
+pub struct PathPicker {
+    visible: bool,
+    root: Option<Arc<Value>>,
+    /// Highlighted child at each level, outermost first; the last one is
+    /// the cursor
+    trail: Vec<usize>,
+}
+
+impl PathPicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Start at the top of `root`. False when it has nothing to walk into.
+    pub fn open(&mut self, root: Arc<Value>) -> bool {
+        if child_count(&root) == 0 {
+            return false;
+        }
+        self.visible = true;
+        self.root = Some(root);
+        self.trail = vec![0];
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.root = None;
+    }
+
+    /// How many levels down the cursor is; 0 for the root's children
+    pub fn depth(&self) -> usize {
+        self.trail.len().saturating_sub(1)
+    }
+
+    /// The value whose children make up level `depth`
+    pub fn level(&self, depth: usize) -> Option<&Value> {
+        let mut value = self.root.as_deref()?;
+        for &index in self.trail.iter().take(depth) {
+            value = child(value, index)?.1;
+        }
+        Some(value)
+    }
+
+    /// The highlighted child at level `depth`
+    pub fn selected_at(&self, depth: usize) -> usize {
+        self.trail.get(depth).copied().unwrap_or(0)
+    }
+
+    /// The highlighted value itself
+    pub fn selected_value(&self) -> Option<&Value> {
+        let level = self.level(self.depth())?;
+        child(level, self.selected_at(self.depth())).map(|(_, value)| value)
+    }
+
+    /// The jq path of the highlighted value
+    pub fn path(&self) -> JsonPath {
+        let mut path = JsonPath::new();
+        let mut value = self.root.as_deref();
+        for &index in &self.trail {
+            let Some((step, next)) = value.and_then(|v| child(v, index)) else {
+                break;
+            };
+            match step {
+                JsonPathStep::Key(key) => path.push_key(key),
+                JsonPathStep::Index(i) => path.push_index(i),
+                JsonPathStep::Splat => {}
+            }
+            value = Some(next);
+        }
+        path
+    }
+
+    /// Move the highlight by `delta` within the level, stopping at the ends
+    pub fn move_by(&mut self, delta: isize) {
+        let count = self.level(self.depth()).map_or(0, child_count);
+        if let Some(last) = self.trail.last_mut()
+            && count > 0
+        {
+            *last = last.saturating_add_signed(delta).min(count - 1);
+        }
+    }
+
+    /// Highlight the first or last child of the level
+    pub fn move_to_end(&mut self, last: bool) {
+        let count = self.level(self.depth()).map_or(0, child_count);
+        if let Some(cursor) = self.trail.last_mut() {
+            *cursor = if last { count.saturating_sub(1) } else { 0 };
+        }
+    }
+
+    /// Step into the highlighted value. False when it has no children.
+    pub fn enter(&mut self) -> bool {
+        if self.selected_value().is_some_and(|v| child_count(v) > 0) {
+            self.trail.push(0);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Step back out to the level above. False at the top.
+    pub fn leave(&mut self) -> bool {
+        if self.trail.len() > 1 {
+            self.trail.pop();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// How many keys or elements `value` has
+pub fn child_count(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => map.len(),
+        Value::Array(items) => items.len(),
+        _ => 0,
+    }
+}
+
+/// The `index`th child of `value` and the step that reaches it
+pub fn child(value: &Value, index: usize) -> Option<(JsonPathStep, &Value)> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .nth(index)
+            .map(|(key, value)| (JsonPathStep::Key(key.clone()), value)),
+        Value::Array(items) => items
+            .get(index)
+            .map(|value| (JsonPathStep::Index(index), value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "path_picker_state_tests.rs"]
+mod path_picker_state_tests;
@@ -0,0 +1,85 @@
+//! Tests for path_picker/path_picker_state
+
+use serde_json::json;
+
+use super::*;
+
+fn picker(value: Value) -> PathPicker {
+    let mut picker = PathPicker::new();
+    assert!(picker.open(Arc::new(value)));
+    picker
+}
+
+#[test]
+fn scalars_and_empty_documents_cannot_be_walked() {
+    let mut picker = PathPicker::new();
+
+    assert!(!picker.open(Arc::new(json!(5))));
+    assert!(!picker.open(Arc::new(json!({}))));
+    assert!(!picker.is_visible());
+}
+
+#[test]
+fn starts_on_the_first_top_level_key() {
+    let picker = picker(json!({"users": [], "count": 2}));
+
+    assert_eq!(picker.depth(), 0);
+    assert_eq!(picker.path().to_jq(), ".users");
+}
+
+#[test]
+fn entering_and_moving_builds_the_path() {
+    let mut picker = picker(json!({"users": [{"name": "a"}, {"name": "b", "zip-code": 1}]}));
+
+    assert!(picker.enter());
+    picker.move_by(1);
+    assert!(picker.enter());
+    picker.move_by(1);
+
+    assert_eq!(picker.depth(), 2);
+    assert_eq!(picker.path().to_jq(), r#".users[1]["zip-code"]"#);
+    assert_eq!(picker.selected_value(), Some(&json!(1)));
+}
+
+#[test]
+fn scalars_cannot_be_entered() {
+    let mut picker = picker(json!({"count": 2}));
+
+    assert!(!picker.enter());
+    assert_eq!(picker.depth(), 0);
+}
+
+#[test]
+fn leaving_returns_to_the_parent_highlight() {
+    let mut picker = picker(json!({"a": 1, "b": [1, 2, 3]}));
+    picker.move_by(1);
+    picker.enter();
+    picker.move_by(2);
+
+    assert!(picker.leave());
+    assert!(!picker.leave());
+    assert_eq!(picker.path().to_jq(), ".b");
+}
+
+#[test]
+fn moves_stop_at_the_ends() {
+    let mut picker = picker(json!([1, 2, 3]));
+
+    picker.move_by(-1);
+    assert_eq!(picker.selected_at(0), 0);
+    picker.move_by(10);
+    assert_eq!(picker.selected_at(0), 2);
+    picker.move_to_end(false);
+    assert_eq!(picker.path().to_jq(), ".[0]");
+    picker.move_to_end(true);
+    assert_eq!(picker.path().to_jq(), ".[2]");
+}
+
+#[test]
+fn level_walks_the_trail() {
+    let mut picker = picker(json!({"a": {"b": true}}));
+    picker.enter();
+
+    assert_eq!(picker.level(0), Some(&json!({"a": {"b": true}})));
+    assert_eq!(picker.level(1), Some(&json!({"b": true})));
+}
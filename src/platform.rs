@@ -0,0 +1,100 @@
+//! Differences between platforms, kept in one place.
+//!
+//! Each function takes the platform as a plain `windows` flag instead of
+//! deciding with `cfg` itself, so the Windows behavior is exercised by
+//! `tests/platform_tests.rs` on every platform. Callers pass [`WINDOWS`].
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Whether jiq was built for Windows
+pub const WINDOWS: bool = cfg!(windows);
+
+/// Directory holding `config.toml` and `snippets.toml`
+pub fn config_dir() -> Option<PathBuf> {
+    config_dir_in(
+        dirs::home_dir().as_deref(),
+        dirs::config_dir().as_deref(),
+        WINDOWS,
+    )
+}
+
+/// `~/.config/jiq` everywhere but Windows, where the settings belong in
+/// the roaming application data folder (`%APPDATA%\jiq`, found through
+/// the known-folder API as `known_folder`). A `~/.config/jiq` that
+/// already exists on Windows is kept, so earlier setups still load.
+pub fn config_dir_in(
+    home: Option<&Path>,
+    known_folder: Option<&Path>,
+    windows: bool,
+) -> Option<PathBuf> {
+    let dotfile = home.map(|home| home.join(".config").join("jiq"));
+    if !windows {
+        return dotfile;
+    }
+    match (dotfile, known_folder) {
+        (Some(dotfile), _) if dotfile.is_dir() => Some(dotfile),
+        (_, Some(known_folder)) => Some(known_folder.join("jiq")),
+        (dotfile, None) => dotfile,
+    }
+}
+
+/// `text` as the system clipboard expects it: Windows programs want CRLF
+/// line endings, and Notepad and friends paste LF-only text as one line
+pub fn clipboard_text(text: &str, windows: bool) -> Cow<'_, str> {
+    if !windows || !text.contains('\n') {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() + text.matches('\n').count());
+    let mut previous = None;
+    for c in text.chars() {
+        if c == '\n' && previous != Some('\r') {
+            out.push('\r');
+        }
+        out.push(c);
+        previous = Some(c);
+    }
+    Cow::Owned(out)
+}
+
+/// `key` with the modifiers the Windows console adds for AltGr removed.
+///
+/// Windows reports AltGr as Ctrl+Alt, so on many European layouts `[`,
+/// `{`, `|`, `@` and `\` arrive as Ctrl+Alt+`[` and so on, and would be
+/// taken for shortcuts instead of typed. A character that isn't an ASCII
+/// letter or digit with both Ctrl and Alt held is treated as typed.
+/// Ctrl+Alt with a letter or digit is left as it is for shortcuts.
+pub fn normalize_key(key: KeyEvent, windows: bool) -> KeyEvent {
+    let alt_gr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    match key.code {
+        KeyCode::Char(c)
+            if windows && key.modifiers.contains(alt_gr) && !c.is_ascii_alphanumeric() =>
+        {
+            KeyEvent {
+                modifiers: key.modifiers - alt_gr,
+                ..key
+            }
+        }
+        _ => key,
+    }
+}
+
+/// The editor `:edit` runs, as a program and its arguments: `$VISUAL`,
+/// then `$EDITOR`, then Notepad on Windows and `vi` elsewhere. The
+/// variables may carry arguments, as in `code --wait`.
+pub fn editor_command(visual: Option<&str>, editor: Option<&str>, windows: bool) -> Vec<String> {
+    let configured = [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|command| command.split_whitespace().map(str::to_string).collect())
+        .find(|words: &Vec<String>| !words.is_empty());
+    configured.unwrap_or_else(|| vec![if windows { "notepad" } else { "vi" }.to_string()])
+}
+
+/// Whether jiq runs inside Windows Terminal, which sets `WT_SESSION` for
+/// its shells
+pub fn in_windows_terminal() -> bool {
+    std::env::var_os("WT_SESSION").is_some()
+}
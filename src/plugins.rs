@@ -0,0 +1,16 @@
+//! Plugins: executables named `jiq-plugin-<name>` in `PATH` or in
+//! `plugins/` under the config directory. Each is asked once, with
+//! `describe`, what it offers — commands for the `:` command line, file
+//! extensions it converts to JSON, and rewriting the output printed on
+//! exit — and is run with `command`, `input` or `post` to do it. Niche
+//! formats and in-house tools plug in without changes to jiq.
+
+mod discovery;
+mod manifest;
+mod plugin_events;
+mod plugin_run;
+mod registry;
+
+pub use plugin_events::{list, post_process, run_command, set_post_processor};
+pub use plugin_run::convert_input;
+pub use registry::PluginRegistry;
@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+
+/// What a plugin's executable name starts with; the rest is its name
+pub const PREFIX: &str = "jiq-plugin-";
+
+/// Where plugins are looked for, in order: `plugins/` in the config
+/// directory, then each directory in `PATH`
+pub fn plugin_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = crate::platform::config_dir()
+        .map(|dir| dir.join("plugins"))
+        .into_iter()
+        .collect();
+    if let Some(path) = std::env::var_os("PATH") {
+        dirs.extend(std::env::split_paths(&path));
+    }
+    dirs
+}
+
+/// Every `jiq-plugin-*` executable in `dirs` as `(name, path)`, sorted by
+/// name. A name found in two directories is the first one's.
+pub fn discover(dirs: &[PathBuf]) -> Vec<(String, PathBuf)> {
+    let mut found: Vec<(String, PathBuf)> = Vec::new();
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let Some(name) = plugin_name(&path) else {
+                continue;
+            };
+            if is_executable(&path) && !found.iter().any(|(known, _)| *known == name) {
+                found.push((name, path));
+            }
+        }
+    }
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+    found
+}
+
+/// `csv` for `jiq-plugin-csv`, or `jiq-plugin-csv.exe` on Windows
+fn plugin_name(path: &Path) -> Option<String> {
+    let file_name = if crate::platform::WINDOWS {
+        path.file_stem()?.to_str()?
+    } else {
+        path.file_name()?.to_str()?
+    };
+    let name = file_name.strip_prefix(PREFIX)?;
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(path).is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+        && path.extension().and_then(|e| e.to_str()).is_some_and(|e| {
+            ["exe", "bat", "cmd"]
+                .iter()
+                .any(|known| e.eq_ignore_ascii_case(known))
+        })
+}
+
+#[cfg(test)]
+#[path = "discovery_tests.rs"]
+mod discovery_tests;
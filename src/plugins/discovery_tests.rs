@@ -0,0 +1,53 @@
+//! Tests for plugins/discovery
+
+use tempfile::TempDir;
+
+use super::*;
+
+#[test]
+fn plugin_dirs_start_with_the_config_directory() {
+    let dirs = plugin_dirs();
+
+    assert!(dirs[0].ends_with("plugins"));
+}
+
+#[test]
+fn missing_directories_are_skipped() {
+    let dir = TempDir::new().unwrap();
+
+    assert!(discover(&[dir.path().join("nowhere")]).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn finds_executables_with_the_prefix() {
+    use crate::test_utils::test_helpers::write_plugin;
+
+    let dir = TempDir::new().unwrap();
+    write_plugin(dir.path(), "csv", "exit 0");
+    write_plugin(dir.path(), "audit", "exit 0");
+    std::fs::write(dir.path().join("jiq-plugin-notes"), "not executable").unwrap();
+    std::fs::write(dir.path().join("other-tool"), "").unwrap();
+
+    let names: Vec<String> = discover(&[dir.path().to_path_buf()])
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+
+    assert_eq!(names, ["audit", "csv"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn earlier_directory_wins_a_name() {
+    use crate::test_utils::test_helpers::write_plugin;
+
+    let first = TempDir::new().unwrap();
+    let second = TempDir::new().unwrap();
+    let expected = write_plugin(first.path(), "csv", "exit 0");
+    write_plugin(second.path(), "csv", "exit 0");
+
+    let found = discover(&[first.path().to_path_buf(), second.path().to_path_buf()]);
+
+    assert_eq!(found, [("csv".to_string(), expected)]);
+}
@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+/// What a plugin offers, as it prints it for `describe`
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+pub struct Manifest {
+    /// Commands the `:` command line runs by name
+    #[serde(default)]
+    pub commands: Vec<PluginCommand>,
+    /// File extensions, without the dot, the plugin converts to JSON
+    #[serde(default)]
+    pub input: Vec<String>,
+    /// Whether the plugin can rewrite the output jiq prints on exit
+    #[serde(default)]
+    pub post_process: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct PluginCommand {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|e| format!("invalid describe output: {}", e))
+    }
+
+    /// Whether files ending in `.extension` go through the plugin
+    pub fn converts(&self, extension: &str) -> bool {
+        self.input
+            .iter()
+            .any(|ext| ext.trim_start_matches('.').eq_ignore_ascii_case(extension))
+    }
+}
+
+#[cfg(test)]
+#[path = "manifest_tests.rs"]
+mod manifest_tests;
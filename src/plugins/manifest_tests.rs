@@ -0,0 +1,33 @@
+//! Tests for plugins/manifest
+
+use super::*;
+
+#[test]
+fn parses_every_capability() {
+    let manifest = Manifest::parse(
+        r#"{
+            "commands": [{"name": "upload", "description": "Send the result to the team bin"}],
+            "input": ["csv", ".tsv"],
+            "post_process": true
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(manifest.commands[0].name, "upload");
+    assert!(manifest.converts("csv"));
+    assert!(manifest.converts("TSV"));
+    assert!(!manifest.converts("json"));
+    assert!(manifest.post_process);
+}
+
+#[test]
+fn missing_fields_offer_nothing() {
+    assert_eq!(Manifest::parse("{}").unwrap(), Manifest::default());
+}
+
+#[test]
+fn bad_output_is_an_error() {
+    let err = Manifest::parse("usage: jiq-plugin-x ...").unwrap_err();
+
+    assert!(err.starts_with("invalid describe output"));
+}
@@ -0,0 +1,96 @@
+use super::plugin_run;
+use crate::app::App;
+
+/// `:name args` naming a plugin command: run it on the result and show
+/// the first line it printed. False when no plugin has the command.
+pub fn run_command(app: &mut App, name: &str, args: &str) -> bool {
+    let Some((plugin, _)) = app.plugins.command(name) else {
+        return false;
+    };
+    let path = plugin.path.clone();
+    let result = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.as_ref())
+        .map(|result| result.to_string())
+        .unwrap_or_default();
+    let query = app.query().to_string();
+
+    match plugin_run::run_command(&path, name, args, &result, &query) {
+        Ok(output) => {
+            let line = output.lines().find(|line| !line.trim().is_empty());
+            app.notification
+                .show(line.map_or(&format!("Ran {}", name), |line| line.trim()));
+        }
+        Err(e) => app.notification.show_error(&e),
+    }
+    true
+}
+
+/// `:post name` / `:post off` — pass the output printed on exit through a
+/// plugin, or stop
+pub fn set_post_processor(app: &mut App, name: Option<String>) {
+    match name {
+        None => {
+            app.post_processor = None;
+            app.notification.show("Output is printed as jq wrote it");
+        }
+        Some(name) if app.plugins.post_processor(&name).is_some() => {
+            app.notification
+                .show(&format!("Output on exit goes through {}", name));
+            app.post_processor = Some(name);
+        }
+        Some(name) => app
+            .notification
+            .show_error(&format!("No plugin '{}' that post-processes output", name)),
+    }
+}
+
+/// `:plugins` — name the plugins found and what each offers
+pub fn list(app: &mut App) {
+    let summary: Vec<String> = app
+        .plugins
+        .plugins()
+        .iter()
+        .map(|plugin| {
+            let manifest = &plugin.manifest;
+            let mut offers: Vec<String> = manifest
+                .commands
+                .iter()
+                .map(|command| format!(":{}", command.name))
+                .collect();
+            offers.extend(manifest.input.iter().map(|ext| format!(".{}", ext)));
+            if manifest.post_process {
+                offers.push("post".to_string());
+            }
+            format!("{} ({})", plugin.name, offers.join(" "))
+        })
+        .collect();
+    if summary.is_empty() {
+        app.notification
+            .show("No plugins: add jiq-plugin-* executables to PATH");
+    } else {
+        app.notification
+            .show(&format!("Plugins: {}", summary.join(", ")));
+    }
+}
+
+/// The printed output as the chosen post-processor rewrites it; as it is
+/// without one, or when the plugin fails, with the reason
+pub fn post_process(app: &App, output: String) -> (String, Option<String>) {
+    let Some(plugin) = app
+        .post_processor
+        .as_deref()
+        .and_then(|name| app.plugins.post_processor(name))
+    else {
+        return (output, None);
+    };
+    match plugin_run::post_process(&plugin.path, &output) {
+        Ok(rewritten) => (rewritten, None),
+        Err(e) => (output, Some(e)),
+    }
+}
+
+#[cfg(test)]
+#[path = "plugin_events_tests.rs"]
+mod plugin_events_tests;
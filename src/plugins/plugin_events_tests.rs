@@ -0,0 +1,126 @@
+//! Tests for plugins/plugin_events
+
+#![cfg(unix)]
+
+use ratatui::crossterm::event::KeyCode;
+use tempfile::TempDir;
+
+use super::*;
+use crate::editor::EditorMode;
+use crate::plugins::PluginRegistry;
+use crate::test_utils::test_helpers::{execute_query_and_wait, key, test_app, write_plugin};
+
+const TOOLS: &str = r#"case "$1" in
+describe) echo '{"commands": [{"name": "count"}, {"name": "fail"}], "post_process": true}' ;;
+command) [ "$2" = fail ] && { echo 'upload refused' >&2; exit 1; }; echo "$(tr -cd '0-9' | wc -c | tr -d ' ') digits in $JIQ_QUERY" ;;
+post) tr a-z A-Z ;;
+esac"#;
+
+fn app_with_plugins(json: &str) -> (TempDir, App) {
+    let dir = TempDir::new().unwrap();
+    write_plugin(dir.path(), "tools", TOOLS);
+    let mut app = test_app(json);
+    app.plugins = PluginRegistry::with_dirs(vec![dir.path().to_path_buf()]);
+    (dir, app)
+}
+
+fn type_command(app: &mut App, command: &str) {
+    app.input.editor_mode = EditorMode::Normal;
+    app.handle_key_event(key(KeyCode::Char(':')));
+    for c in command.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    app.handle_key_event(key(KeyCode::Enter));
+}
+
+#[test]
+fn command_runs_on_the_result_and_shows_its_output() {
+    let (_dir, mut app) = app_with_plugins(r#"{"a": [12, 345]}"#);
+    app.input.textarea.insert_str(".a");
+    execute_query_and_wait(&mut app);
+
+    type_command(&mut app, "count");
+
+    assert_eq!(app.notification.current_message(), Some("5 digits in .a"));
+}
+
+#[test]
+fn failing_command_shows_its_error() {
+    let (_dir, mut app) = app_with_plugins("{}");
+
+    assert!(run_command(&mut app, "fail", ""));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("jiq-plugin-tools: upload refused")
+    );
+}
+
+#[test]
+fn unknown_name_is_not_a_plugin_command() {
+    let (_dir, mut app) = app_with_plugins("{}");
+
+    type_command(&mut app, "upload");
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Unknown command 'upload'")
+    );
+}
+
+#[test]
+fn post_processor_rewrites_the_printed_output() {
+    let (_dir, mut app) = app_with_plugins("{}");
+
+    set_post_processor(&mut app, Some("tools".to_string()));
+    let (output, error) = post_process(&app, "{\"a\": 1}".to_string());
+
+    assert_eq!(app.post_processor.as_deref(), Some("tools"));
+    assert_eq!(output, "{\"A\": 1}");
+    assert_eq!(error, None);
+}
+
+#[test]
+fn unknown_post_processor_is_refused() {
+    let (_dir, mut app) = app_with_plugins("{}");
+
+    set_post_processor(&mut app, Some("nope".to_string()));
+
+    assert_eq!(app.post_processor, None);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No plugin 'nope' that post-processes output")
+    );
+}
+
+#[test]
+fn output_is_unchanged_without_a_post_processor() {
+    let (_dir, app) = app_with_plugins("{}");
+
+    assert_eq!(post_process(&app, "x".to_string()), ("x".to_string(), None));
+}
+
+#[test]
+fn plugins_lists_what_each_offers() {
+    let (_dir, mut app) = app_with_plugins("{}");
+
+    list(&mut app);
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Plugins: tools (:count :fail post)")
+    );
+}
+
+#[test]
+fn tab_completes_plugin_commands() {
+    let (_dir, mut app) = app_with_plugins("{}");
+    app.input.editor_mode = EditorMode::Normal;
+    app.handle_key_event(key(KeyCode::Char(':')));
+    app.handle_key_event(key(KeyCode::Char('c')));
+    app.handle_key_event(key(KeyCode::Char('o')));
+
+    app.handle_key_event(key(KeyCode::Tab));
+
+    assert_eq!(app.command_line.text(), "count ");
+}
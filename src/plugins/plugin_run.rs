@@ -0,0 +1,87 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use super::manifest::Manifest;
+
+/// `describe`: ask the plugin what it offers
+pub fn describe(plugin: &Path) -> Result<Manifest, String> {
+    let output = run(plugin, &["describe"], None, &[])?;
+    Manifest::parse(&output)
+}
+
+/// `command <name> [args...]`: run one of the plugin's commands on
+/// `result`, with the query in `JIQ_QUERY`. Returns what it printed.
+pub fn run_command(
+    plugin: &Path,
+    name: &str,
+    args: &str,
+    result: &str,
+    query: &str,
+) -> Result<String, String> {
+    let mut argv = vec!["command", name];
+    argv.extend(args.split_whitespace());
+    run(plugin, &argv, Some(result), &[("JIQ_QUERY", query)])
+}
+
+/// `input <file>`: the file converted to JSON
+pub fn convert_input(plugin: &Path, file: &Path) -> Result<String, String> {
+    let file = file.to_string_lossy();
+    run(plugin, &["input", &file], None, &[])
+}
+
+/// `post`: `output` as the plugin rewrites it
+pub fn post_process(plugin: &Path, output: &str) -> Result<String, String> {
+    run(plugin, &["post"], Some(output), &[])
+}
+
+/// Run `plugin` with `args`, feeding it `stdin`, and take its stdout. A
+/// failed run is the first line it wrote to stderr, or its exit status.
+fn run(
+    plugin: &Path,
+    args: &[&str],
+    stdin: Option<&str>,
+    env: &[(&str, &str)],
+) -> Result<String, String> {
+    let name = plugin.file_name().map_or_else(
+        || plugin.display().to_string(),
+        |n| n.to_string_lossy().into_owned(),
+    );
+    let mut child = Command::new(plugin)
+        .args(args)
+        .envs(env.iter().copied())
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("cannot run {}: {}", name, e))?;
+
+    if let (Some(text), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        // Writing from another thread keeps a plugin that prints before
+        // reading all its input from blocking on a full pipe
+        let text = text.to_string();
+        std::thread::spawn(move || {
+            let _ = pipe.write_all(text.as_bytes());
+        });
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("{} failed: {}", name, e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(match stderr.lines().find(|line| !line.trim().is_empty()) {
+            Some(line) => format!("{}: {}", name, line.trim()),
+            None => format!("{} failed with {}", name, output.status),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+#[path = "plugin_run_tests.rs"]
+mod plugin_run_tests;
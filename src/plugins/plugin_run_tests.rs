@@ -0,0 +1,77 @@
+//! Tests for plugins/plugin_run
+
+#![cfg(unix)]
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::test_utils::test_helpers::write_plugin;
+
+#[test]
+fn describe_reads_the_manifest() {
+    let dir = TempDir::new().unwrap();
+    let plugin = write_plugin(
+        dir.path(),
+        "csv",
+        r#"[ "$1" = describe ] && echo '{"input": ["csv"]}'"#,
+    );
+
+    let manifest = describe(&plugin).unwrap();
+
+    assert!(manifest.converts("csv"));
+}
+
+#[test]
+fn command_gets_the_result_args_and_query() {
+    let dir = TempDir::new().unwrap();
+    let plugin = write_plugin(dir.path(), "echo", r#"echo "$2 $3 $JIQ_QUERY $(cat)""#);
+
+    let output = run_command(&plugin, "show", "--loud", "[1]", ".a").unwrap();
+
+    assert_eq!(output, "show --loud .a [1]\n");
+}
+
+#[test]
+fn input_converts_the_file() {
+    let dir = TempDir::new().unwrap();
+    let plugin = write_plugin(dir.path(), "csv", r#"echo "{\"file\": \"$2\"}""#);
+
+    let json = convert_input(&plugin, Path::new("data.csv")).unwrap();
+
+    assert_eq!(json, "{\"file\": \"data.csv\"}\n");
+}
+
+#[test]
+fn post_rewrites_the_output() {
+    let dir = TempDir::new().unwrap();
+    let plugin = write_plugin(dir.path(), "upper", "tr a-z A-Z");
+
+    assert_eq!(post_process(&plugin, "{\"a\": 1}").unwrap(), "{\"A\": 1}");
+}
+
+#[test]
+fn failure_reports_stderr() {
+    let dir = TempDir::new().unwrap();
+    let plugin = write_plugin(dir.path(), "broken", "echo 'no such table' >&2; exit 3");
+
+    let err = describe(&plugin).unwrap_err();
+
+    assert_eq!(err, "jiq-plugin-broken: no such table");
+}
+
+#[test]
+fn silent_failure_reports_the_status() {
+    let dir = TempDir::new().unwrap();
+    let plugin = write_plugin(dir.path(), "quiet", "exit 2");
+
+    let err = post_process(&plugin, "").unwrap_err();
+
+    assert!(err.starts_with("jiq-plugin-quiet failed with"));
+}
+
+#[test]
+fn missing_plugin_cannot_run() {
+    let err = describe(Path::new("/nonexistent/jiq-plugin-x")).unwrap_err();
+
+    assert!(err.starts_with("cannot run jiq-plugin-x"));
+}
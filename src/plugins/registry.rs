@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::discovery;
+use super::manifest::{Manifest, PluginCommand};
+use super::plugin_run;
+
+/// A plugin found on disk, with what it said it offers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plugin {
+    pub name: String,
+    pub path: PathBuf,
+    pub manifest: Manifest,
+}
+
+/// The plugins in the plugin directories. They are found and asked to
+/// describe themselves the first time one is needed, so a session that
+/// uses none never runs them.
+#[derive(Debug, Default)]
+pub struct PluginRegistry {
+    dirs: Vec<PathBuf>,
+    plugins: OnceLock<Vec<Plugin>>,
+}
+
+/// Extensions jiq reads itself, which never go through a plugin
+const JSON_EXTENSIONS: &[&str] = &["json", "json5", "jsonc", "jsonl", "ndjson"];
+
+impl PluginRegistry {
+    /// The plugins in `plugins/` under the config directory and in `PATH`
+    pub fn new() -> Self {
+        Self::with_dirs(discovery::plugin_dirs())
+    }
+
+    pub fn with_dirs(dirs: Vec<PathBuf>) -> Self {
+        Self {
+            dirs,
+            plugins: OnceLock::new(),
+        }
+    }
+
+    /// A registry with no plugins, that never looks on disk
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn plugins(&self) -> &[Plugin] {
+        self.plugins.get_or_init(|| {
+            discovery::discover(&self.dirs)
+                .into_iter()
+                .filter_map(|(name, path)| match plugin_run::describe(&path) {
+                    Ok(manifest) => Some(Plugin {
+                        name,
+                        path,
+                        manifest,
+                    }),
+                    Err(e) => {
+                        log::warn!("Skipping plugin {}: {}", name, e);
+                        None
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// The plugin offering the command `name`; the first by plugin name
+    /// when several do
+    pub fn command(&self, name: &str) -> Option<(&Plugin, &PluginCommand)> {
+        self.plugins().iter().find_map(|plugin| {
+            plugin
+                .manifest
+                .commands
+                .iter()
+                .find(|command| command.name == name)
+                .map(|command| (plugin, command))
+        })
+    }
+
+    /// Every plugin command's name, for Tab completion
+    pub fn command_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .plugins()
+            .iter()
+            .flat_map(|plugin| plugin.manifest.commands.iter().map(|c| c.name.clone()))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// The plugin converting `file` to JSON, going by its extension
+    pub fn input_for(&self, file: &Path) -> Option<&Plugin> {
+        let extension = file.extension()?.to_str()?;
+        if JSON_EXTENSIONS
+            .iter()
+            .any(|known| extension.eq_ignore_ascii_case(known))
+        {
+            return None;
+        }
+        self.plugins()
+            .iter()
+            .find(|plugin| plugin.manifest.converts(extension))
+    }
+
+    /// The plugin `name`, when it can rewrite the printed output
+    pub fn post_processor(&self, name: &str) -> Option<&Plugin> {
+        self.plugins()
+            .iter()
+            .find(|plugin| plugin.name == name && plugin.manifest.post_process)
+    }
+}
+
+#[cfg(test)]
+#[path = "registry_tests.rs"]
+mod registry_tests;
@@ -0,0 +1,76 @@
+//! Tests for plugins/registry
+
+#![cfg(unix)]
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::test_utils::test_helpers::write_plugin;
+
+const CSV: &str =
+    r#"[ "$1" = describe ] && echo '{"commands": [{"name": "count"}], "input": ["csv"]}'"#;
+const UPPER: &str = r#"[ "$1" = describe ] && echo '{"commands": [{"name": "count"}, {"name": "shout"}], "post_process": true}'"#;
+
+fn registry_with(plugins: &[(&str, &str)]) -> (TempDir, PluginRegistry) {
+    let dir = TempDir::new().unwrap();
+    for (name, body) in plugins {
+        write_plugin(dir.path(), name, body);
+    }
+    let registry = PluginRegistry::with_dirs(vec![dir.path().to_path_buf()]);
+    (dir, registry)
+}
+
+#[test]
+fn empty_registry_has_no_plugins() {
+    assert!(PluginRegistry::empty().plugins().is_empty());
+}
+
+#[test]
+fn plugins_that_cannot_describe_themselves_are_skipped() {
+    let (_dir, registry) = registry_with(&[("csv", CSV), ("broken", "exit 1")]);
+
+    let names: Vec<&str> = registry.plugins().iter().map(|p| p.name.as_str()).collect();
+
+    assert_eq!(names, ["csv"]);
+}
+
+#[test]
+fn command_belongs_to_the_first_plugin_by_name() {
+    let (_dir, registry) = registry_with(&[("upper", UPPER), ("csv", CSV)]);
+
+    let (plugin, _) = registry.command("count").unwrap();
+
+    assert_eq!(plugin.name, "csv");
+    assert!(registry.command("upload").is_none());
+    assert_eq!(registry.command_names(), ["count", "shout"]);
+}
+
+#[test]
+fn input_plugin_by_extension() {
+    let (_dir, registry) = registry_with(&[("csv", CSV)]);
+
+    assert_eq!(
+        registry.input_for(Path::new("data.CSV")).unwrap().name,
+        "csv"
+    );
+    assert!(registry.input_for(Path::new("data.tsv")).is_none());
+    assert!(registry.input_for(Path::new("data")).is_none());
+}
+
+#[test]
+fn json_files_never_go_through_a_plugin() {
+    let (_dir, registry) = registry_with(&[(
+        "json",
+        r#"[ "$1" = describe ] && echo '{"input": ["json"]}'"#,
+    )]);
+
+    assert!(registry.input_for(Path::new("data.json")).is_none());
+}
+
+#[test]
+fn post_processor_must_say_it_post_processes() {
+    let (_dir, registry) = registry_with(&[("upper", UPPER), ("csv", CSV)]);
+
+    assert!(registry.post_processor("upper").is_some());
+    assert!(registry.post_processor("csv").is_none());
+}
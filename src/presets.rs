@@ -0,0 +1,16 @@
+//! Presets (F5): curated queries for well-known documents. The loaded
+//! input is matched against signatures for Kubernetes manifests, AWS CLI
+//! output, `package.json` and GitHub API payloads, and the menu offers the
+//! presets written for that shape. `presets.toml` in the config directory
+//! adds presets and signatures of its own.
+
+mod builtin;
+mod preset_storage;
+mod presets_events;
+mod presets_render;
+mod presets_state;
+mod shape;
+
+pub use presets_events::{handle_key, toggle};
+pub use presets_render::render_popup;
+pub use presets_state::{Preset, PresetMenu};
@@ -0,0 +1,113 @@
+use super::Preset;
+
+/// The presets jiq ships with, as `(shape, name, query)`
+const BUILTIN: &[(&str, &str, &str)] = &[
+    (
+        "kubernetes",
+        "List container images",
+        "[.. | objects | (.containers, .initContainers) | arrays | .[].image] | unique",
+    ),
+    (
+        "kubernetes",
+        "Kinds and names",
+        r#"(.items // [.])[] | "\(.kind)/\(.metadata.name)""#,
+    ),
+    (
+        "kubernetes",
+        "Count by namespace",
+        r#"[(.items // [.])[] | .metadata.namespace // "default"] | group_by(.) | map({namespace: .[0], count: length})"#,
+    ),
+    (
+        "kubernetes",
+        "Pods not running",
+        r#"(.items // [.])[] | select(.kind == "Pod" and .status.phase != "Running") | .metadata.name"#,
+    ),
+    (
+        "kubernetes",
+        "Replicas wanted and ready",
+        "(.items // [.])[] | select(.spec.replicas) | {name: .metadata.name, replicas: .spec.replicas, ready: .status.readyReplicas}",
+    ),
+    (
+        "kubernetes",
+        "Labels by name",
+        "(.items // [.])[] | {name: .metadata.name, labels: .metadata.labels}",
+    ),
+    (
+        "aws",
+        "Count each list",
+        "map_values(if type == \"array\" then length else . end)",
+    ),
+    (
+        "aws",
+        "EC2 instances",
+        ".Reservations[]?.Instances[] | {InstanceId, InstanceType, State: .State.Name, PrivateIpAddress}",
+    ),
+    (
+        "aws",
+        "Resource names",
+        "[.. | objects | (.Name, .FunctionName, .StackName, .DBInstanceIdentifier) | strings] | unique",
+    ),
+    (
+        "aws",
+        "Tags as objects",
+        r#".. | objects | select(.Tags | type == "array") | .Tags | from_entries"#,
+    ),
+    (
+        "npm",
+        "Dependencies with versions",
+        r#".dependencies // {} | to_entries | map("\(.key)@\(.value)")"#,
+    ),
+    (
+        "npm",
+        "Every dependency name",
+        "[(.dependencies, .devDependencies, .peerDependencies, .optionalDependencies) | objects | keys[]] | unique",
+    ),
+    ("npm", "Scripts", ".scripts"),
+    (
+        "npm",
+        "Package summary",
+        "{name, version, license, main, engines}",
+    ),
+    (
+        "github",
+        "Issues and pull requests",
+        r#"if type == "array" then .[] else . end | {number, title, state, author: .user.login}"#,
+    ),
+    (
+        "github",
+        "Pull request links",
+        r#"if type == "array" then .[] else . end | select(.pull_request) | .html_url"#,
+    ),
+    (
+        "github",
+        "Label counts",
+        r#"[if type == "array" then .[] else . end | .labels[]?.name] | group_by(.) | map({label: .[0], count: length}) | sort_by(-.count)"#,
+    ),
+    (
+        "github",
+        "Everyone mentioned",
+        "[.. | objects | .login | strings] | unique",
+    ),
+    (
+        "github",
+        "Repositories",
+        r#"if type == "array" then .[] else . end | {full_name, stargazers_count, language}"#,
+    ),
+];
+
+/// The built-in presets for the shape named `shape`
+pub fn builtin_presets(shape: &str) -> Vec<Preset> {
+    BUILTIN
+        .iter()
+        .filter(|(s, _, _)| *s == shape)
+        .map(|&(shape, name, query)| Preset {
+            shape: shape.to_string(),
+            name: name.to_string(),
+            query: query.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "builtin_tests.rs"]
+mod builtin_tests;
@@ -0,0 +1,53 @@
+//! Tests for presets/builtin
+
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+
+use super::*;
+use crate::presets::shape::{Shape, detect};
+use crate::query::executor::JqExecutor;
+
+/// A small document of each built-in shape
+fn samples() -> Vec<serde_json::Value> {
+    vec![
+        json!({"apiVersion": "v1", "kind": "List", "items": [{
+            "apiVersion": "v1", "kind": "Pod",
+            "metadata": {"name": "web", "namespace": "prod", "labels": {"app": "web"}},
+            "spec": {"containers": [{"name": "web", "image": "nginx:1.25"}]},
+            "status": {"phase": "Pending"}
+        }]}),
+        json!({"Reservations": [{"Instances": [{
+            "InstanceId": "i-1", "InstanceType": "t3.micro",
+            "State": {"Name": "running"}, "Tags": [{"Key": "Name", "Value": "web"}]
+        }]}]}),
+        json!({"name": "app", "version": "1.0.0", "scripts": {"test": "jest"},
+            "dependencies": {"react": "^18"}}),
+        json!([{"node_id": "I_1", "url": "https://api.github.com/repos/o/r/issues/1",
+            "number": 1, "title": "Bug", "state": "open", "user": {"login": "ada"},
+            "labels": [{"name": "bug"}]}]),
+    ]
+}
+
+#[test]
+fn every_builtin_shape_has_presets() {
+    for shape in [Shape::Kubernetes, Shape::Aws, Shape::Npm, Shape::GitHub] {
+        assert!(!builtin_presets(shape.id()).is_empty(), "{:?}", shape);
+    }
+}
+
+#[test]
+fn unknown_shape_has_none() {
+    assert!(builtin_presets("terraform-plan").is_empty());
+}
+
+#[test]
+fn every_builtin_preset_runs_on_its_shape() {
+    for sample in samples() {
+        let shape = detect(&sample, &[]).unwrap();
+        let executor = JqExecutor::new(sample.to_string());
+        for preset in builtin_presets(shape.id()) {
+            let result = executor.execute_compact(&preset.query, &CancellationToken::new());
+            assert!(result.is_ok(), "{}: {:?}", preset.name, result);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::Preset;
+use super::shape::ShapeSignature;
+
+const PRESETS_FILE: &str = "presets.toml";
+
+/// The user's `presets.toml`: extra presets, and signatures for shapes
+/// jiq doesn't know
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct PresetsFile {
+    #[serde(default)]
+    pub shapes: Vec<ShapeSignature>,
+    #[serde(default)]
+    pub presets: Vec<Preset>,
+}
+
+pub fn presets_path() -> Option<PathBuf> {
+    crate::platform::config_dir().map(|p| p.join(PRESETS_FILE))
+}
+
+pub fn load_presets_file() -> PresetsFile {
+    presets_path()
+        .map(|path| load_presets_from_path(&path))
+        .unwrap_or_default()
+}
+
+pub fn load_presets_from_path(path: &Path) -> PresetsFile {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_presets_toml(&contents),
+        Err(_) => {
+            log::debug!("No presets file at {:?}", path);
+            PresetsFile::default()
+        }
+    }
+}
+
+pub fn parse_presets_toml(content: &str) -> PresetsFile {
+    match toml::from_str(content) {
+        Ok(file) => file,
+        Err(e) => {
+            log::warn!("Failed to parse presets TOML: {}", e);
+            PresetsFile::default()
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "preset_storage_tests.rs"]
+mod preset_storage_tests;
@@ -0,0 +1,69 @@
+//! Tests for presets/preset_storage
+
+use tempfile::TempDir;
+
+use super::*;
+
+#[test]
+fn presets_path_is_next_to_the_config() {
+    let path = presets_path().unwrap();
+    assert!(path.ends_with("presets.toml"));
+}
+
+#[test]
+fn parses_shapes_and_presets() {
+    let file = parse_presets_toml(
+        r#"
+[[shapes]]
+name = "terraform-plan"
+keys = ["format_version", "resource_changes"]
+
+[[presets]]
+shape = "terraform-plan"
+name = "Changed resources"
+query = ".resource_changes[].address"
+
+[[presets]]
+shape = "any"
+name = "Top-level keys"
+query = "keys"
+"#,
+    );
+
+    assert_eq!(file.shapes.len(), 1);
+    assert_eq!(file.shapes[0].keys, ["format_version", "resource_changes"]);
+    assert_eq!(file.presets.len(), 2);
+    assert_eq!(file.presets[1].shape, "any");
+}
+
+#[test]
+fn bad_toml_is_an_empty_file() {
+    assert_eq!(
+        parse_presets_toml("[[presets]]\nname = 1"),
+        PresetsFile::default()
+    );
+}
+
+#[test]
+fn missing_file_is_empty() {
+    let dir = TempDir::new().unwrap();
+
+    let file = load_presets_from_path(&dir.path().join(PRESETS_FILE));
+
+    assert_eq!(file, PresetsFile::default());
+}
+
+#[test]
+fn loads_from_disk() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(PRESETS_FILE);
+    fs::write(
+        &path,
+        "[[presets]]\nshape = \"npm\"\nname = \"Bin\"\nquery = \".bin\"\n",
+    )
+    .unwrap();
+
+    let file = load_presets_from_path(&path);
+
+    assert_eq!(file.presets[0].query, ".bin");
+}
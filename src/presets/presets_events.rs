@@ -0,0 +1,77 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::builtin::builtin_presets;
+use super::preset_storage::{self, PresetsFile};
+use super::shape::detect;
+use crate::app::App;
+
+/// Presets whose `shape` is this are offered for every document
+pub const ANY_SHAPE: &str = "any";
+
+/// F5 — open the menu for the document loaded, or close it
+pub fn toggle(app: &mut App) {
+    if app.presets.is_visible() {
+        app.presets.close();
+    } else {
+        // Read on each open, so edits to the file show up straight away
+        open_with(app, preset_storage::load_presets_file());
+    }
+}
+
+/// Open the menu with the built-in presets for the detected shape and the
+/// ones in `file` for it or for any document
+pub fn open_with(app: &mut App, file: PresetsFile) {
+    let input = app
+        .query
+        .as_ref()
+        .and_then(|q| q.executor.json_input_parsed());
+    let shape = input
+        .as_deref()
+        .and_then(|input| detect(input, &file.shapes));
+
+    let mut presets = shape
+        .as_ref()
+        .map_or_else(Vec::new, |shape| builtin_presets(shape.id()));
+    presets.extend(file.presets.into_iter().filter(|preset| {
+        preset.shape == ANY_SHAPE || shape.as_ref().is_some_and(|s| preset.shape == s.id())
+    }));
+
+    if presets.is_empty() {
+        app.notification.show("No presets for this document");
+        return;
+    }
+    app.presets.open(shape, presets);
+}
+
+/// Keys while the menu is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::F(5) => app.presets.close(),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => app.presets.select_next(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => app.presets.select_prev(),
+        KeyCode::Enter => {
+            if let Some(preset) = app.presets.selected() {
+                let query = preset.query.clone();
+                apply_preset(app, &query);
+            }
+            app.presets.close();
+        }
+        _ => {}
+    }
+}
+
+/// Replace the query with `query` and run it
+fn apply_preset(app: &mut App, query: &str) {
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    app.input.textarea.insert_str(query);
+    crate::editor::editor_events::execute_query(app);
+
+    app.results_scroll.reset();
+    app.results_cursor.reset();
+    app.error_overlay_visible = false;
+}
+
+#[cfg(test)]
+#[path = "presets_events_tests.rs"]
+mod presets_events_tests;
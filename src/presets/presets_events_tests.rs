@@ -0,0 +1,123 @@
+//! Tests for presets/presets_events
+
+use ratatui::crossterm::event::KeyCode;
+
+use super::*;
+use crate::presets::Preset;
+use crate::presets::shape::Shape;
+use crate::test_utils::test_helpers::{key, test_app};
+
+const PACKAGE_JSON: &str = r#"{
+    "name": "app",
+    "version": "1.0.0",
+    "scripts": {"test": "jest"},
+    "dependencies": {"react": "^18"}
+}"#;
+
+fn user_preset(shape: &str, name: &str, query: &str) -> Preset {
+    Preset {
+        shape: shape.to_string(),
+        name: name.to_string(),
+        query: query.to_string(),
+    }
+}
+
+#[test]
+fn opens_with_the_builtin_presets_for_the_shape() {
+    let mut app = test_app(PACKAGE_JSON);
+
+    open_with(&mut app, PresetsFile::default());
+
+    assert!(app.presets.is_visible());
+    assert_eq!(app.presets.shape(), Some(&Shape::Npm));
+    assert_eq!(app.presets.presets(), builtin_presets("npm"));
+}
+
+#[test]
+fn user_presets_for_the_shape_or_any_come_after() {
+    let mut app = test_app(PACKAGE_JSON);
+    let file = PresetsFile {
+        shapes: Vec::new(),
+        presets: vec![
+            user_preset("npm", "Bin", ".bin"),
+            user_preset("kubernetes", "Pods", ".items"),
+            user_preset(ANY_SHAPE, "Keys", "keys"),
+        ],
+    };
+
+    open_with(&mut app, file);
+
+    let names: Vec<&str> = app
+        .presets
+        .presets()
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect();
+    assert_eq!(&names[names.len() - 2..], ["Bin", "Keys"]);
+    assert!(!names.contains(&"Pods"));
+}
+
+#[test]
+fn unknown_document_says_there_are_no_presets() {
+    let mut app = test_app(r#"{"a": 1}"#);
+
+    open_with(&mut app, PresetsFile::default());
+
+    assert!(!app.presets.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No presets for this document")
+    );
+}
+
+#[test]
+fn unknown_document_still_offers_presets_for_any() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let file = PresetsFile {
+        shapes: Vec::new(),
+        presets: vec![user_preset(ANY_SHAPE, "Keys", "keys")],
+    };
+
+    open_with(&mut app, file);
+
+    assert!(app.presets.is_visible());
+    assert_eq!(app.presets.shape(), None);
+}
+
+#[test]
+fn enter_replaces_the_query_with_the_preset() {
+    let mut app = test_app(PACKAGE_JSON);
+    app.input.textarea.insert_str(".name");
+    open_with(&mut app, PresetsFile::default());
+    app.handle_key_event(key(KeyCode::Down));
+    let expected = app.presets.selected().unwrap().query.clone();
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.presets.is_visible());
+    assert_eq!(app.query(), expected);
+}
+
+#[test]
+fn f5_toggles_and_esc_closes() {
+    let mut app = test_app(PACKAGE_JSON);
+    open_with(&mut app, PresetsFile::default());
+
+    app.handle_key_event(key(KeyCode::F(5)));
+    assert!(!app.presets.is_visible());
+
+    open_with(&mut app, PresetsFile::default());
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.presets.is_visible());
+}
+
+#[test]
+fn other_keys_are_swallowed() {
+    let mut app = test_app(PACKAGE_JSON);
+    open_with(&mut app, PresetsFile::default());
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.presets.is_visible());
+    assert_eq!(app.query(), "");
+}
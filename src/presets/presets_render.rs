@@ -0,0 +1,70 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+};
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const POPUP_WIDTH: u16 = 72;
+const MAX_ROWS: usize = 12;
+const NAME_WIDTH: usize = 28;
+
+/// Render the presets menu centered over `area`
+pub fn render_popup(app: &App, frame: &mut Frame, area: Rect) {
+    let presets = app.presets.presets();
+    let rows = presets.len().min(MAX_ROWS);
+    let height = rows as u16 + 2;
+    let popup_area = centered_rect(area, POPUP_WIDTH, height);
+    if popup_area.width < 40 || popup_area.height < height {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let title = match app.presets.shape() {
+        Some(shape) => format!(" Presets: {} ", shape.label()),
+        None => " Presets ".to_string(),
+    };
+    let block = popup_block(
+        title,
+        &[("Enter", "Use"), ("↑↓", "Select"), ("Esc", "Close")],
+    );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let selected = app.presets.selected_index();
+    // Keep the highlighted preset in view
+    let offset = (selected + 1).saturating_sub(rows);
+    let query_width = (inner.width as usize).saturating_sub(NAME_WIDTH + 1);
+    let query_style = Style::default().fg(theme::palette::text_dim());
+    let lines: Vec<Line> = presets
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(rows)
+        .map(|(i, preset)| {
+            let name_style = if i == selected {
+                Style::default()
+                    .fg(theme::results::border_focused())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::palette::text())
+            };
+            let name: String = preset.name.chars().take(NAME_WIDTH).collect();
+            let query: String = preset.query.chars().take(query_width).collect();
+            Line::from(vec![
+                Span::styled(format!("{:<NAME_WIDTH$} ", name), name_style),
+                Span::styled(query, query_style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+#[path = "presets_render_tests.rs"]
+mod presets_render_tests;
@@ -0,0 +1,51 @@
+//! Tests for presets/presets_render
+
+use insta::assert_snapshot;
+
+use super::*;
+use crate::presets::Preset;
+use crate::presets::builtin::builtin_presets;
+use crate::presets::shape::Shape;
+use crate::test_utils::test_helpers::{render_popup_to_string, test_app};
+
+#[test]
+fn snapshot_presets_menu() {
+    let mut app = test_app("{}");
+    app.presets
+        .open(Some(Shape::Kubernetes), builtin_presets("kubernetes"));
+    app.presets.select_next();
+
+    assert_snapshot!(render_popup_to_string(&app, 80, 10, render_popup));
+}
+
+#[test]
+fn long_menu_scrolls_to_the_selection() {
+    let mut app = test_app("{}");
+    let presets = (0..20)
+        .map(|i| Preset {
+            shape: "any".to_string(),
+            name: format!("preset {}", i),
+            query: ".".to_string(),
+        })
+        .collect();
+    app.presets.open(None, presets);
+    for _ in 0..15 {
+        app.presets.select_next();
+    }
+
+    let output = render_popup_to_string(&app, 80, 20, render_popup);
+
+    assert!(output.contains("preset 15"));
+    assert!(!output.contains("preset 3 "));
+    assert!(output.contains(" Presets "));
+}
+
+#[test]
+fn not_drawn_when_too_small() {
+    let mut app = test_app("{}");
+    app.presets.open(Some(Shape::Npm), builtin_presets("npm"));
+
+    let output = render_popup_to_string(&app, 30, 10, render_popup);
+
+    assert!(!output.contains("Presets"));
+}
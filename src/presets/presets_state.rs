@@ -0,0 +1,77 @@
+use serde::Deserialize;
+
+use super::shape::Shape;
+
+/// A curated query for one shape of document
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct Preset {
+    /// The shape's id, or `any` to offer it for every document
+    pub shape: String,
+    pub name: String,
+    pub query: String,
+}
+
+/// The F5 menu of presets for the document loaded
+#[derive(Debug, Default)]
+pub struct PresetMenu {
+    visible: bool,
+    shape: Option<Shape>,
+    presets: Vec<Preset>,
+    selected: usize,
+}
+
+impl PresetMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show `presets`, offered for a document of `shape`
+    pub fn open(&mut self, shape: Option<Shape>, presets: Vec<Preset>) {
+        self.visible = true;
+        self.shape = shape;
+        self.presets = presets;
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn shape(&self) -> Option<&Shape> {
+        self.shape.as_ref()
+    }
+
+    pub fn presets(&self) -> &[Preset] {
+        &self.presets
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected(&self) -> Option<&Preset> {
+        self.presets.get(self.selected)
+    }
+
+    /// Highlight the next preset, wrapping to the top
+    pub fn select_next(&mut self) {
+        if !self.presets.is_empty() {
+            self.selected = (self.selected + 1) % self.presets.len();
+        }
+    }
+
+    /// Highlight the previous preset, wrapping to the bottom
+    pub fn select_prev(&mut self) {
+        if !self.presets.is_empty() {
+            self.selected = (self.selected + self.presets.len() - 1) % self.presets.len();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "presets_state_tests.rs"]
+mod presets_state_tests;
@@ -0,0 +1,49 @@
+//! Tests for presets/presets_state
+
+use super::*;
+
+fn preset(name: &str) -> Preset {
+    Preset {
+        shape: "npm".to_string(),
+        name: name.to_string(),
+        query: ".".to_string(),
+    }
+}
+
+#[test]
+fn selection_wraps_both_ways() {
+    let mut menu = PresetMenu::new();
+    menu.open(
+        Some(Shape::Npm),
+        vec![preset("a"), preset("b"), preset("c")],
+    );
+
+    menu.select_prev();
+    assert_eq!(menu.selected().unwrap().name, "c");
+
+    menu.select_next();
+    menu.select_next();
+    assert_eq!(menu.selected().unwrap().name, "b");
+}
+
+#[test]
+fn opening_again_starts_at_the_top() {
+    let mut menu = PresetMenu::new();
+    menu.open(None, vec![preset("a"), preset("b")]);
+    menu.select_next();
+
+    menu.open(None, vec![preset("a"), preset("b")]);
+
+    assert_eq!(menu.selected_index(), 0);
+}
+
+#[test]
+fn empty_menu_has_nothing_selected() {
+    let mut menu = PresetMenu::new();
+    menu.open(None, Vec::new());
+
+    menu.select_next();
+    menu.select_prev();
+
+    assert!(menu.selected().is_none());
+}
@@ -0,0 +1,130 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// A kind of document presets are written for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    /// A manifest, or a `kubectl get -o json` list of them
+    Kubernetes,
+    /// `aws ... --output json`
+    Aws,
+    /// `package.json`
+    Npm,
+    /// A GitHub REST API payload: an issue, repo, user, or a list of them
+    GitHub,
+    /// One of the `[[shapes]]` in the presets file, by name
+    Custom(String),
+}
+
+impl Shape {
+    /// The name a preset's `shape` refers to it by
+    pub fn id(&self) -> &str {
+        match self {
+            Shape::Kubernetes => "kubernetes",
+            Shape::Aws => "aws",
+            Shape::Npm => "npm",
+            Shape::GitHub => "github",
+            Shape::Custom(name) => name,
+        }
+    }
+
+    /// How the menu title names it
+    pub fn label(&self) -> &str {
+        match self {
+            Shape::Kubernetes => "Kubernetes",
+            Shape::Aws => "AWS CLI",
+            Shape::Npm => "package.json",
+            Shape::GitHub => "GitHub API",
+            Shape::Custom(name) => name,
+        }
+    }
+}
+
+/// A `[[shapes]]` entry: documents whose top-level object, or first
+/// element, has all of `keys`
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct ShapeSignature {
+    pub name: String,
+    pub keys: Vec<String>,
+}
+
+/// What `input` looks like, if it's one of the shapes presets know. The
+/// user's signatures are tried before the built-in ones.
+pub fn detect(input: &Value, signatures: &[ShapeSignature]) -> Option<Shape> {
+    let object = sample_object(input)?;
+
+    if let Some(signature) = signatures
+        .iter()
+        .find(|s| !s.keys.is_empty() && s.keys.iter().all(|k| object.contains_key(k)))
+    {
+        return Some(Shape::Custom(signature.name.clone()));
+    }
+
+    if is_kubernetes(object) {
+        Some(Shape::Kubernetes)
+    } else if is_npm(object) {
+        Some(Shape::Npm)
+    } else if is_github(object) {
+        Some(Shape::GitHub)
+    } else if input.is_object() && is_aws(object) {
+        Some(Shape::Aws)
+    } else {
+        None
+    }
+}
+
+/// The object the signatures are checked against: the input, or the
+/// first element of an input array
+fn sample_object(input: &Value) -> Option<&Map<String, Value>> {
+    match input {
+        Value::Object(object) => Some(object),
+        Value::Array(items) => items.first()?.as_object(),
+        _ => None,
+    }
+}
+
+fn is_string(object: &Map<String, Value>, key: &str) -> bool {
+    object.get(key).is_some_and(Value::is_string)
+}
+
+fn is_kubernetes(object: &Map<String, Value>) -> bool {
+    is_string(object, "apiVersion") && is_string(object, "kind")
+}
+
+fn is_npm(object: &Map<String, Value>) -> bool {
+    is_string(object, "name")
+        && is_string(object, "version")
+        && ["dependencies", "devDependencies", "scripts"]
+            .iter()
+            .any(|key| object.get(*key).is_some_and(Value::is_object))
+}
+
+fn is_github(object: &Map<String, Value>) -> bool {
+    is_string(object, "node_id")
+        && object
+            .get("url")
+            .and_then(Value::as_str)
+            .is_some_and(|url| url.starts_with("https://api.github.com/"))
+}
+
+/// Every key PascalCase, with at least one list of PascalCase records:
+/// `{"Reservations": [{"Instances": ...}]}`
+fn is_aws(object: &Map<String, Value>) -> bool {
+    !object.is_empty()
+        && object.keys().all(|key| is_pascal_case(key))
+        && object.values().any(|value| {
+            value
+                .as_array()
+                .and_then(|items| items.first())
+                .and_then(Value::as_object)
+                .is_some_and(|record| record.keys().any(|key| is_pascal_case(key)))
+        })
+}
+
+fn is_pascal_case(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+#[path = "shape_tests.rs"]
+mod shape_tests;
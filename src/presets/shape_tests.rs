@@ -0,0 +1,83 @@
+//! Tests for presets/shape
+
+use serde_json::json;
+
+use super::*;
+
+fn shape_of(input: Value) -> Option<Shape> {
+    detect(&input, &[])
+}
+
+#[test]
+fn kubernetes_manifest_and_list() {
+    let manifest = json!({"apiVersion": "apps/v1", "kind": "Deployment", "metadata": {}});
+    let list = json!({"apiVersion": "v1", "kind": "List", "items": []});
+
+    assert_eq!(shape_of(manifest), Some(Shape::Kubernetes));
+    assert_eq!(shape_of(list), Some(Shape::Kubernetes));
+}
+
+#[test]
+fn package_json_needs_dependencies_or_scripts() {
+    let package = json!({"name": "app", "version": "1.0.0", "scripts": {"test": "jest"}});
+    let bare = json!({"name": "app", "version": "1.0.0"});
+
+    assert_eq!(shape_of(package), Some(Shape::Npm));
+    assert_eq!(shape_of(bare), None);
+}
+
+#[test]
+fn github_payload_alone_or_in_a_list() {
+    let issue = json!({
+        "node_id": "I_kwDO",
+        "url": "https://api.github.com/repos/o/r/issues/1",
+        "number": 1
+    });
+
+    assert_eq!(shape_of(issue.clone()), Some(Shape::GitHub));
+    assert_eq!(shape_of(json!([issue])), Some(Shape::GitHub));
+}
+
+#[test]
+fn aws_output_is_pascal_case_records() {
+    let ec2 = json!({"Reservations": [{"Instances": [], "OwnerId": "1"}]});
+    let camel = json!({"reservations": [{"instances": []}]});
+    let no_records = json!({"Count": 3});
+
+    assert_eq!(shape_of(ec2), Some(Shape::Aws));
+    assert_eq!(shape_of(camel), None);
+    assert_eq!(shape_of(no_records), None);
+}
+
+#[test]
+fn scalars_and_empty_arrays_have_no_shape() {
+    assert_eq!(shape_of(json!(42)), None);
+    assert_eq!(shape_of(json!([])), None);
+}
+
+#[test]
+fn user_signatures_come_first() {
+    let signatures = [ShapeSignature {
+        name: "terraform-plan".to_string(),
+        keys: vec!["format_version".to_string(), "resource_changes".to_string()],
+    }];
+    let plan =
+        json!({"format_version": "1.2", "resource_changes": [], "kind": "x", "apiVersion": "y"});
+    let partial = json!({"format_version": "1.2"});
+
+    assert_eq!(
+        detect(&plan, &signatures),
+        Some(Shape::Custom("terraform-plan".to_string()))
+    );
+    assert_eq!(detect(&partial, &signatures), None);
+}
+
+#[test]
+fn signature_without_keys_matches_nothing() {
+    let signatures = [ShapeSignature {
+        name: "anything".to_string(),
+        keys: Vec::new(),
+    }];
+
+    assert_eq!(detect(&json!({"a": 1}), &signatures), None);
+}
@@ -0,0 +1,12 @@
+//! Progress of long-running operations
+//!
+//! Each frame the app reports which operations are running; the tracker
+//! remembers when each started so the status bar can show a spinner, the
+//! elapsed time and the key that cancels the operation once it has run
+//! long enough to notice.
+
+mod progress_render;
+mod progress_state;
+
+pub use progress_render::{render_status, spinner_span};
+pub use progress_state::{Operation, ProgressTracker};
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use super::progress_state::Operation;
+use crate::theme;
+
+const SPINNER_CHARS: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Spinner frame and color for the current render frame
+pub fn spinner(frame_count: u64) -> (char, Color) {
+    let index = (frame_count / 8) as usize;
+    let colors = theme::results::spinner_colors();
+    (
+        SPINNER_CHARS[index % SPINNER_CHARS.len()],
+        colors[index % colors.len()],
+    )
+}
+
+/// `<spinner> `, ready to lead a title or status line
+pub fn spinner_span(frame_count: u64) -> Span<'static> {
+    let (spinner_char, spinner_color) = spinner(frame_count);
+    Span::styled(
+        format!("{} ", spinner_char),
+        Style::default().fg(spinner_color),
+    )
+}
+
+/// Status bar line for a running operation:
+/// `⠋ Running query… 2.4s • Ctrl+X Cancel`
+pub fn render_status(operation: Operation, elapsed: Duration, frame_count: u64) -> Line<'static> {
+    let (key, action) = operation.cancel_hint();
+    Line::from(vec![
+        Span::raw(" "),
+        spinner_span(frame_count),
+        Span::styled(
+            format!("{}… {:.1}s", operation.label(), elapsed.as_secs_f64()),
+            Style::default().fg(theme::help_line::description()),
+        ),
+        Span::styled(
+            " \u{2022} ",
+            Style::default().fg(theme::help_line::separator()),
+        ),
+        Span::styled(key, Style::default().fg(theme::help_line::key())),
+        Span::raw(" "),
+        Span::styled(action, Style::default().fg(theme::help_line::description())),
+    ])
+}
+
+#[cfg(test)]
+#[path = "progress_render_tests.rs"]
+mod progress_render_tests;
@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+/// Operations slow enough on large inputs to show progress for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// jq running the query in the background worker
+    Query,
+    /// Reading and parsing the input file or stdin
+    FileLoad,
+    /// Waiting on the AI provider
+    AiRequest,
+    /// Writing a result cut at the output limit to a file in full
+    SaveOutput,
+    /// Running the query a frequency count ranks
+    Frequency,
+}
+
+impl Operation {
+    pub fn label(self) -> &'static str {
+        match self {
+            Operation::Query => "Running query",
+            Operation::FileLoad => "Loading input",
+            Operation::AiRequest => "Waiting for AI",
+            Operation::SaveOutput => "Saving output",
+            Operation::Frequency => "Counting values",
+        }
+    }
+
+    /// Key and action that stop the operation. A file load cannot be cut
+    /// short, so its key quits instead.
+    pub fn cancel_hint(self) -> (&'static str, &'static str) {
+        match self {
+            Operation::Query
+            | Operation::AiRequest
+            | Operation::SaveOutput
+            | Operation::Frequency => ("Ctrl+X", "Cancel"),
+            Operation::FileLoad => ("Ctrl+C", "Quit"),
+        }
+    }
+}
+
+/// Operations shorter than this finish without showing progress
+pub const SHOW_AFTER: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Default)]
+pub struct ProgressTracker {
+    /// Running operations and when each started, oldest first
+    running: Vec<(Operation, Instant)>,
+}
+
+impl ProgressTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record whether `operation` is running, starting its clock when it
+    /// begins and dropping it when it ends
+    pub fn update(&mut self, operation: Operation, active: bool) {
+        self.update_at(operation, active, Instant::now());
+    }
+
+    /// [`update`](Self::update) with an explicit clock
+    pub fn update_at(&mut self, operation: Operation, active: bool, now: Instant) {
+        let tracked = self.running.iter().position(|(op, _)| *op == operation);
+        match (active, tracked) {
+            (true, None) => self.running.push((operation, now)),
+            (false, Some(index)) => {
+                self.running.remove(index);
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_running(&self, operation: Operation) -> bool {
+        self.running.iter().any(|(op, _)| *op == operation)
+    }
+
+    /// The most recently started operation that has run past
+    /// [`SHOW_AFTER`], with how long it has been running
+    pub fn visible(&self) -> Option<(Operation, Duration)> {
+        self.visible_at(Instant::now())
+    }
+
+    fn visible_at(&self, now: Instant) -> Option<(Operation, Duration)> {
+        self.running
+            .iter()
+            .rev()
+            .map(|(op, started)| (*op, now.saturating_duration_since(*started)))
+            .find(|(_, elapsed)| *elapsed >= SHOW_AFTER)
+    }
+}
+
+#[cfg(test)]
+#[path = "progress_state_tests.rs"]
+mod progress_state_tests;
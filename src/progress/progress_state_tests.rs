@@ -0,0 +1,69 @@
+//! Tests for progress/progress_state
+
+use super::*;
+
+#[test]
+fn test_new_tracker_shows_nothing() {
+    let tracker = ProgressTracker::new();
+    assert!(tracker.visible().is_none());
+    assert!(!tracker.is_running(Operation::Query));
+}
+
+#[test]
+fn test_short_operations_stay_hidden() {
+    let mut tracker = ProgressTracker::new();
+    let start = Instant::now();
+    tracker.update_at(Operation::Query, true, start);
+
+    assert!(tracker.is_running(Operation::Query));
+    assert!(tracker.visible_at(start + SHOW_AFTER / 2).is_none());
+    assert_eq!(
+        tracker.visible_at(start + SHOW_AFTER),
+        Some((Operation::Query, SHOW_AFTER))
+    );
+}
+
+#[test]
+fn test_clock_starts_when_the_operation_begins() {
+    let mut tracker = ProgressTracker::new();
+    let start = Instant::now();
+    tracker.update_at(Operation::Query, true, start);
+    // Still running on later frames: the start time is kept
+    tracker.update_at(Operation::Query, true, start + Duration::from_secs(1));
+
+    let (_, elapsed) = tracker.visible_at(start + Duration::from_secs(2)).unwrap();
+    assert_eq!(elapsed, Duration::from_secs(2));
+}
+
+#[test]
+fn test_finished_operation_is_dropped() {
+    let mut tracker = ProgressTracker::new();
+    let start = Instant::now();
+    tracker.update_at(Operation::Query, true, start);
+    tracker.update_at(Operation::Query, false, start + Duration::from_secs(1));
+
+    assert!(!tracker.is_running(Operation::Query));
+    assert!(tracker.visible_at(start + Duration::from_secs(2)).is_none());
+}
+
+#[test]
+fn test_newest_visible_operation_wins() {
+    let mut tracker = ProgressTracker::new();
+    let start = Instant::now();
+    tracker.update_at(Operation::AiRequest, true, start);
+    tracker.update_at(Operation::Query, true, start + Duration::from_secs(1));
+
+    // The query has not run long enough yet, so the AI request shows
+    let at = start + Duration::from_secs(1) + SHOW_AFTER / 2;
+    assert_eq!(tracker.visible_at(at).unwrap().0, Operation::AiRequest);
+
+    let later = start + Duration::from_secs(3);
+    assert_eq!(tracker.visible_at(later).unwrap().0, Operation::Query);
+}
+
+#[test]
+fn test_cancel_hints() {
+    assert_eq!(Operation::Query.cancel_hint(), ("Ctrl+X", "Cancel"));
+    assert_eq!(Operation::AiRequest.cancel_hint(), ("Ctrl+X", "Cancel"));
+    assert_eq!(Operation::FileLoad.cancel_hint(), ("Ctrl+C", "Quit"));
+}
@@ -1,9 +1,18 @@
+pub mod debounced_preview;
 pub mod debouncer;
 pub mod error_enhance;
 pub mod executor;
+pub mod field_hint;
+pub mod jq_args;
+pub mod jq_binary;
+pub mod lint;
+pub mod optional_access;
+pub mod output_format;
+pub mod prelude;
 pub mod query_state;
 pub mod worker;
 
 // Re-export public types
 pub use debouncer::Debouncer;
+pub use jq_args::JqArgs;
 pub use query_state::{QueryState, ResultType};
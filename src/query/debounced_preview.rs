@@ -0,0 +1,136 @@
+//! Debounced background runs of a previewed query
+//!
+//! The autocomplete and history popups both preview the query their
+//! selection would give. Each selection change cancels the run in flight,
+//! and the new query only starts once navigation has paused for
+//! [`PREVIEW_DELAY`], so scrolling through a list doesn't start jq for
+//! every row it passes.
+
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+use std::time::{Duration, Instant};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::query::executor::JqExecutor;
+
+/// Pause in navigation before the previewed query runs
+pub const PREVIEW_DELAY: Duration = Duration::from_millis(150);
+
+/// The query being previewed and its run on another thread
+pub struct DebouncedPreview<T> {
+    query: Option<String>,
+    /// When the pending query starts, unless the selection moves on
+    due: Option<Instant>,
+    cancel: Option<CancellationToken>,
+    response_rx: Option<Receiver<T>>,
+}
+
+impl<T: Send + 'static> DebouncedPreview<T> {
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            due: None,
+            cancel: None,
+            response_rx: None,
+        }
+    }
+
+    /// The query being previewed
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// Preview `query` from now on, or nothing with `None`
+    ///
+    /// A different query cancels the run in flight and starts after
+    /// [`PREVIEW_DELAY`]. Returns true when the query changed.
+    pub fn set_query(&mut self, query: Option<String>, now: Instant) -> bool {
+        if query == self.query {
+            return false;
+        }
+        self.stop();
+        self.due = query.as_ref().map(|_| now + PREVIEW_DELAY);
+        self.query = query;
+        true
+    }
+
+    /// Whether the query is waiting for navigation to pause
+    #[cfg(test)]
+    pub fn is_due(&self) -> bool {
+        self.due.is_some()
+    }
+
+    /// Whether the query is running
+    #[cfg(test)]
+    pub fn is_running(&self) -> bool {
+        self.response_rx.is_some()
+    }
+
+    /// Start the pending query with `run` once due and pick up what it
+    /// returned. `run` gives `None` when it was cancelled.
+    pub fn poll<F>(&mut self, executor: &JqExecutor, now: Instant, run: F) -> Option<T>
+    where
+        F: FnOnce(&JqExecutor, &str, &CancellationToken) -> Option<T> + Send + 'static,
+    {
+        if self.due.is_some_and(|due| now >= due)
+            && let Some(query) = self.query.clone()
+        {
+            self.due = None;
+            self.start(executor.share(), query, run);
+        }
+
+        let response_rx = self.response_rx.as_ref()?;
+        let result = match response_rx.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => None,
+        };
+        self.response_rx = None;
+        self.cancel = None;
+        result
+    }
+
+    fn start<F>(&mut self, executor: JqExecutor, query: String, run: F)
+    where
+        F: FnOnce(&JqExecutor, &str, &CancellationToken) -> Option<T> + Send + 'static,
+    {
+        let cancel = CancellationToken::new();
+        let (response_tx, response_rx) = channel();
+        let thread_cancel = cancel.clone();
+        std::thread::spawn(move || {
+            if let Some(result) = run(&executor, &query, &thread_cancel) {
+                let _ = response_tx.send(result);
+            }
+        });
+        self.cancel = Some(cancel);
+        self.response_rx = Some(response_rx);
+    }
+
+    /// Cancel the run in flight and the one waiting to start, keeping the
+    /// query
+    pub fn stop(&mut self) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.cancel();
+        }
+        self.response_rx = None;
+        self.due = None;
+    }
+}
+
+impl<T: Send + 'static> Default for DebouncedPreview<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DebouncedPreview<T> {
+    fn drop(&mut self) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "debounced_preview_tests.rs"]
+mod debounced_preview_tests;
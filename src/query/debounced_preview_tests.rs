@@ -0,0 +1,69 @@
+//! Tests for debounced_preview
+
+use super::*;
+
+fn run_output(executor: &JqExecutor, query: &str, cancel: &CancellationToken) -> Option<String> {
+    executor.execute_compact(query, cancel).ok()
+}
+
+/// Poll until a result arrives, as the event loop would
+fn wait_for(preview: &mut DebouncedPreview<String>, executor: &JqExecutor) -> String {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        if let Some(result) = preview.poll(executor, Instant::now(), run_output) {
+            return result;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("preview did not arrive");
+}
+
+#[test]
+fn test_query_waits_for_the_delay() {
+    let executor = JqExecutor::new("1".to_string());
+    let mut preview = DebouncedPreview::new();
+    let now = Instant::now();
+    preview.set_query(Some(".".to_string()), now);
+
+    assert_eq!(preview.poll(&executor, now, run_output), None);
+    assert!(preview.is_due());
+    assert!(!preview.is_running());
+}
+
+#[test]
+fn test_due_query_runs_and_returns_its_result() {
+    let executor = JqExecutor::new("[1, 2]".to_string());
+    let mut preview = DebouncedPreview::new();
+    preview.set_query(Some(".[]".to_string()), Instant::now() - PREVIEW_DELAY);
+
+    assert_eq!(wait_for(&mut preview, &executor), "1\n2\n");
+    assert!(!preview.is_running());
+}
+
+#[test]
+fn test_same_query_keeps_its_schedule() {
+    let mut preview: DebouncedPreview<String> = DebouncedPreview::new();
+    let now = Instant::now();
+    assert!(preview.set_query(Some(".a".to_string()), now));
+
+    assert!(!preview.set_query(Some(".a".to_string()), now + PREVIEW_DELAY));
+    assert!(preview.set_query(None, now));
+    assert!(!preview.is_due());
+}
+
+#[test]
+fn test_new_query_cancels_the_run_in_flight() {
+    let executor = JqExecutor::new("null".to_string());
+    let mut preview = DebouncedPreview::new();
+    preview.set_query(
+        Some("last(range(1e9))".to_string()),
+        Instant::now() - PREVIEW_DELAY,
+    );
+    assert_eq!(preview.poll(&executor, Instant::now(), run_output), None);
+    assert!(preview.is_running());
+
+    preview.set_query(Some(".".to_string()), Instant::now());
+
+    assert!(!preview.is_running());
+    assert_eq!(preview.query(), Some("."));
+}
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 const DEBOUNCE_MS: u64 = 150;
 
@@ -53,6 +53,19 @@ impl Debouncer {
     pub fn has_pending(&self) -> bool {
         self.pending_execution
     }
+
+    /// How long until the pending query is due; None with nothing pending
+    pub fn time_until_due(&self) -> Option<Duration> {
+        self.time_until_due_at(system_time_ms())
+    }
+
+    pub fn time_until_due_at(&self, current_time_ms: u64) -> Option<Duration> {
+        if !self.pending_execution {
+            return None;
+        }
+        let due = self.scheduled_at_ms? + DEBOUNCE_MS;
+        Some(Duration::from_millis(due.saturating_sub(current_time_ms)))
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +60,25 @@ fn test_schedule_resets_timer() {
     assert!(debouncer.should_execute_at(TEST_DEBOUNCE_MS + TEST_DEBOUNCE_MS / 2 + 10));
 }
 
+#[test]
+fn test_time_until_due() {
+    let mut debouncer = Debouncer::new();
+    assert_eq!(debouncer.time_until_due_at(0), None);
+
+    debouncer.schedule_execution_at(1000);
+    assert_eq!(
+        debouncer.time_until_due_at(1000),
+        Some(Duration::from_millis(TEST_DEBOUNCE_MS))
+    );
+    assert_eq!(
+        debouncer.time_until_due_at(1000 + TEST_DEBOUNCE_MS + 50),
+        Some(Duration::ZERO)
+    );
+
+    debouncer.mark_executed();
+    assert_eq!(debouncer.time_until_due_at(2000), None);
+}
+
 #[test]
 fn test_default_impl() {
     let debouncer = Debouncer::default();
@@ -20,6 +20,8 @@
 //! Only the human-facing overlay is enhanced. The AI assistant still receives
 //! jq's raw stderr, which models read fluently and benefit from.
 
+use crate::query::worker::types::TIMED_OUT_PREFIX;
+
 /// A human-friendly rendering of a jq error.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EnhancedError {
@@ -74,6 +76,7 @@ impl EnhancedError {
 /// detecting which bracket is unclosed) without depending on jq's snippet
 /// formatting, which differs across versions.
 ///
+/// A query jiq stopped for running past the timeout is explained too.
 /// Returns `None` when the text is not a jq error at all (e.g. jiq's own
 /// "Query worker disconnected"), so the caller can show it verbatim.
 pub fn enhance_jq_error(raw: &str, query: &str) -> Option<EnhancedError> {
@@ -82,6 +85,11 @@ pub fn enhance_jq_error(raw: &str, query: &str) -> Option<EnhancedError> {
         return None;
     }
 
+    // jiq killed jq for running too long
+    if let Some(after) = raw.strip_prefix(TIMED_OUT_PREFIX) {
+        return Some(enhance_timeout(after));
+    }
+
     // Runtime errors: `jq: error (at <stdin>:N): <message>`
     if let Some(message) = runtime_message(raw) {
         return Some(enhance_runtime(&message));
@@ -98,6 +106,17 @@ pub fn enhance_jq_error(raw: &str, query: &str) -> Option<EnhancedError> {
     None
 }
 
+/// `after` is the time the query had, such as `5s`
+fn enhance_timeout(after: &str) -> EnhancedError {
+    EnhancedError::new(format!(
+        "Query timed out after {after}: jq was still running, so it was stopped."
+    ))
+    .with_hint(
+        "Look for recurse, .. or nested [] that multiply the output. A query that is only slow \
+         can have longer with timeout_secs under [jq] in config.toml, or --timeout.",
+    )
+}
+
 // --------------------------------------------------------------------------
 // Runtime errors
 // --------------------------------------------------------------------------
@@ -105,10 +124,11 @@ pub fn enhance_jq_error(raw: &str, query: &str) -> Option<EnhancedError> {
 /// Extract the message from the first `jq: error (at ...)` line, dropping the
 /// `(at <stdin>:N)` location noise. Handles the `(not a string): null` variant
 /// emitted by a bare `error`.
-fn runtime_message(raw: &str) -> Option<String> {
-    // A runtime error is always the first line of jq's stderr.
-    let line = raw.lines().next()?.trim_start();
-    let rest = line.strip_prefix("jq: error (at ")?;
+pub(super) fn runtime_message(raw: &str) -> Option<String> {
+    // Older jq can print a warning about $JQ_COLORS first
+    let rest = raw
+        .lines()
+        .find_map(|line| line.trim_start().strip_prefix("jq: error (at "))?;
     // rest = "<stdin>:1): Cannot index..." or "<stdin>:1) (not a string): null"
     let close = rest.find(')')?;
     let after = rest[close + 1..].trim_start();
@@ -333,8 +353,9 @@ fn enhance_compile(core: &str, query: &str) -> EnhancedError {
 
 fn enhance_not_defined(name: &str) -> EnhancedError {
     if let Some(var) = name.strip_prefix('$') {
-        return EnhancedError::new(format!("Unknown variable `${var}`."))
-            .with_hint("Bind it first with `... as $name`, or check for a typo.");
+        return EnhancedError::new(format!("Unknown variable `${var}`.")).with_hint(
+            "Give it a value with F6, bind it with `... as $name`, or check for a typo.",
+        );
     }
 
     // jq reports functions as `name/arity`.
@@ -512,7 +533,7 @@ fn closest_builtin(name: &str) -> Option<&'static str> {
     best.map(|(b, _)| b)
 }
 
-fn levenshtein(a: &str, b: &str) -> usize {
+pub(super) fn levenshtein(a: &str, b: &str) -> usize {
     let a: Vec<char> = a.chars().collect();
     let b: Vec<char> = b.chars().collect();
     let mut prev: Vec<usize> = (0..=b.len()).collect();
@@ -235,6 +235,15 @@ fn index_scalar() {
     assert!(e.summary.contains("Can't index a number value"));
 }
 
+#[test]
+fn runtime_error_after_jq_colors_warning() {
+    // jq 1.6 rejects the eight-color JQ_COLORS and warns before the error
+    let raw =
+        "Failed to set $JQ_COLORS\njq: error (at <stdin>:0): Cannot index number with string \"a\"";
+    let e = enhance_jq_error(raw, ".a").unwrap();
+    assert!(e.summary.contains("Can't index a number value"));
+}
+
 // --------------------------------------------------------------------------
 // Runtime: iteration
 // --------------------------------------------------------------------------
@@ -548,3 +557,13 @@ fn extract_quoted_field_empty_value_returns_none() {
         None
     );
 }
+
+#[test]
+fn timeout_explains_and_points_at_the_setting() {
+    let e = enhance_jq_error("query timed out after 5s", "[recurse]").unwrap();
+    assert_eq!(
+        e.summary,
+        "Query timed out after 5s: jq was still running, so it was stopped."
+    );
+    assert!(e.hint.as_deref().unwrap().contains("timeout_secs"));
+}
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
-use std::process::{Command, Stdio};
+use std::process::Stdio;
+use std::sync::mpsc::Receiver;
 use std::sync::{Arc, OnceLock};
 use std::thread::sleep;
 use std::time::Duration;
@@ -8,28 +9,96 @@ use serde_json::Value;
 use tokio_util::sync::CancellationToken;
 
 use crate::autocomplete::json_navigator::DEFAULT_ARRAY_SAMPLE_SIZE;
+use crate::config::ArraySampling;
+use crate::input::InputText;
+use crate::query::jq_args::JqArgs;
+use crate::query::output_format::OutputFormat;
 use crate::query::worker::types::QueryError;
 
 /// Execute jq queries against JSON input
 ///
-/// Uses Arc<String> to enable cheap cloning when spawning worker threads.
-/// Without Arc, each query execution would copy the entire JSON input (O(n)),
-/// causing typing lag on large files. With Arc, cloning is just a reference
-/// count increment (O(1)).
+/// Holds the input as an [`InputText`] to enable cheap cloning when spawning
+/// worker threads. Without it, each query execution would copy the entire
+/// JSON input (O(n)), causing typing lag on large files. Cloning it is just
+/// a reference count increment (O(1)).
 pub struct JqExecutor {
-    json_input: Arc<String>,
-    /// Lazily parsed JSON input, cached for autocomplete navigation.
+    json_input: InputText,
+    /// The parsed input and the indexes built from it, shared with the
+    /// thread that builds them in the background
+    analysis: Arc<InputAnalysis>,
+    array_sample_size: usize,
+    /// Which elements of a long array the field index looks at
+    array_sampling: ArraySampling,
+    /// `--rawfile` / `--slurpfile` bindings passed to every jq run
+    jq_args: Arc<JqArgs>,
+    /// When false the input is never parsed in-process, so
+    /// `json_input_parsed` is None and the field and value sets are empty.
+    /// Off for large inputs unless configured otherwise.
+    analyze_input: bool,
+    /// Set once `analyze_in_background` started a thread on the analysis
+    analyzing_in_background: bool,
+}
+
+/// Lazily computed views of the input, each filled once
+#[derive(Default)]
+struct InputAnalysis {
+    /// Lazily parsed JSON input, cached for autocomplete navigation, with
+    /// the number of documents in it. Several concatenated documents are
+    /// held as an array of them.
     /// Uses OnceLock for thread-safe one-time initialization.
-    json_input_parsed: OnceLock<Option<Arc<Value>>>,
+    parsed: OnceLock<Option<(Arc<Value>, usize)>>,
     /// All unique field names from the JSON, collected recursively.
     /// Cached for non-deterministic autocomplete fallback.
-    all_field_names: OnceLock<Arc<HashSet<String>>>,
+    field_names: OnceLock<Arc<HashSet<String>>>,
     /// All distinct string VALUES from the JSON, collected recursively,
     /// sorted by descending frequency (alphabetical tiebreaker), capped at
     /// `MAX_GLOBAL_STRING_VALUES`. Used as the last-resort fallback by
     /// string-value autocomplete.
-    all_string_values: OnceLock<Arc<Vec<String>>>,
-    array_sample_size: usize,
+    string_values: OnceLock<Arc<Vec<String>>>,
+}
+
+impl InputAnalysis {
+    fn parsed(&self, input: &str) -> Option<Arc<Value>> {
+        self.parsed_documents(input)
+            .as_ref()
+            .map(|(value, _)| Arc::clone(value))
+    }
+
+    fn parsed_documents(&self, input: &str) -> &Option<(Arc<Value>, usize)> {
+        self.parsed.get_or_init(|| {
+            crate::json::parse_documents(input).map(|(value, count)| (Arc::new(value), count))
+        })
+    }
+
+    fn field_names(&self, input: &str, sample: ArraySample) -> Arc<HashSet<String>> {
+        self.field_names
+            .get_or_init(|| {
+                let mut fields = HashSet::new();
+                if let Some(parsed) = self.parsed(input) {
+                    collect_fields_recursive(&parsed, &mut fields, sample);
+                }
+                Arc::new(fields)
+            })
+            .clone()
+    }
+
+    fn string_values(&self, input: &str) -> Arc<Vec<String>> {
+        self.string_values
+            .get_or_init(|| {
+                let mut counts: HashMap<String, u32> = HashMap::new();
+                if let Some(parsed) = self.parsed(input) {
+                    collect_string_values_recursive(&parsed, &mut counts);
+                }
+                Arc::new(sort_and_cap_strings(counts, MAX_GLOBAL_STRING_VALUES))
+            })
+            .clone()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.parsed.get().is_some()
+            && self.field_names.get().is_some()
+            && self.string_values.get().is_some()
+    }
 }
 
 /// Cap on distinct values returned by `all_string_values`. Keeps the lazy
@@ -39,23 +108,78 @@ pub const MAX_GLOBAL_STRING_VALUES: usize = 10_000;
 
 impl JqExecutor {
     /// Create a new JQ executor with JSON input and default sample size
-    pub fn new(json_input: String) -> Self {
+    pub fn new(json_input: impl Into<InputText>) -> Self {
         Self::new_with_sample_size(json_input, DEFAULT_ARRAY_SAMPLE_SIZE)
     }
 
     /// Create a new JQ executor with JSON input and custom array sample size
-    pub fn new_with_sample_size(json_input: String, array_sample_size: usize) -> Self {
+    pub fn new_with_sample_size(
+        json_input: impl Into<InputText>,
+        array_sample_size: usize,
+    ) -> Self {
         Self {
-            json_input: Arc::new(json_input),
-            json_input_parsed: OnceLock::new(),
-            all_field_names: OnceLock::new(),
-            all_string_values: OnceLock::new(),
+            json_input: json_input.into(),
+            analysis: Arc::default(),
             array_sample_size,
+            array_sampling: ArraySampling::default(),
+            jq_args: Arc::new(JqArgs::default()),
+            analyze_input: true,
+            analyzing_in_background: false,
+        }
+    }
+
+    /// Turn parsing the input for autocomplete and tooltips on or off
+    pub fn set_input_analysis(&mut self, on: bool) {
+        self.analyze_input = on;
+    }
+
+    /// Pick the elements of long arrays the field index looks at with
+    /// `sampling`
+    pub fn set_array_sampling(&mut self, sampling: ArraySampling) {
+        self.array_sampling = sampling;
+    }
+
+    /// Parse the input and build the field and value indexes on another
+    /// thread, so loading doesn't wait for them
+    ///
+    /// Until they are ready [`input_analysis_pending`] is true; the
+    /// accessors below still block until the thread is done.
+    ///
+    /// [`input_analysis_pending`]: Self::input_analysis_pending
+    pub fn analyze_in_background(&mut self) {
+        if !self.analyze_input || self.analyzing_in_background {
+            return;
         }
+        self.analyzing_in_background = true;
+        let analysis = Arc::clone(&self.analysis);
+        let input = self.json_input.clone();
+        let sample = self.array_sample();
+        std::thread::spawn(move || {
+            let _t = crate::Timer::new("input analysis");
+            analysis.field_names(&input, sample);
+            analysis.string_values(&input);
+        });
+    }
+
+    /// Whether the background analysis is still running, so asking for the
+    /// parsed input or the indexes now would block
+    pub fn input_analysis_pending(&self) -> bool {
+        self.analyze_input && self.analyzing_in_background && !self.analysis.is_complete()
+    }
+
+    /// Pass `jq_args` on every jq invocation
+    pub fn with_jq_args(mut self, jq_args: Arc<JqArgs>) -> Self {
+        self.jq_args = jq_args;
+        self
+    }
+
+    /// The variable bindings passed to jq
+    pub fn jq_args(&self) -> &Arc<JqArgs> {
+        &self.jq_args
     }
 
-    /// Get a reference to the JSON input
-    pub fn json_input(&self) -> &str {
+    /// Get a reference to the JSON input; clone it to share the input
+    pub fn json_input(&self) -> &InputText {
         &self.json_input
     }
 
@@ -65,49 +189,39 @@ impl JqExecutor {
     /// This is the true original file input that never changes during the session.
     /// Used by autocomplete to navigate nested structures.
     ///
+    /// An input of several concatenated documents comes back as an array
+    /// of them; see [`input_is_stream`](Self::input_is_stream).
+    ///
     /// Returns `None` if the JSON input is invalid.
     pub fn json_input_parsed(&self) -> Option<Arc<Value>> {
-        self.json_input_parsed
-            .get_or_init(|| serde_json::from_str(&self.json_input).ok().map(Arc::new))
-            .clone()
+        if !self.analyze_input {
+            return None;
+        }
+        self.analysis.parsed(&self.json_input)
+    }
+
+    /// Whether the input is several concatenated documents, which jq runs
+    /// the query on one at a time, rather than one value
+    pub fn input_is_stream(&self) -> bool {
+        self.analyze_input
+            && self
+                .analysis
+                .parsed_documents(&self.json_input)
+                .as_ref()
+                .is_some_and(|(_, count)| *count > 1)
     }
 
     /// Get all unique field names from the JSON, collected recursively.
     ///
-    /// Returns a cached set of all field names found anywhere in the JSON tree.
+    /// Returns a cached set of all field names found anywhere in the JSON tree,
+    /// looking at the elements of each array the sampling picks.
     /// Used for non-deterministic autocomplete fallback when path navigation fails.
     pub fn all_field_names(&self) -> Arc<HashSet<String>> {
-        let sample_size = self.array_sample_size;
-        self.all_field_names
-            .get_or_init(|| {
-                let mut fields = HashSet::new();
-                if let Some(parsed) = self.json_input_parsed() {
-                    Self::collect_fields_recursive(&parsed, &mut fields, sample_size);
-                }
-                Arc::new(fields)
-            })
-            .clone()
-    }
-
-    fn collect_fields_recursive(
-        value: &Value,
-        fields: &mut HashSet<String>,
-        array_sample_size: usize,
-    ) {
-        match value {
-            Value::Object(map) => {
-                for (key, val) in map {
-                    fields.insert(key.clone());
-                    Self::collect_fields_recursive(val, fields, array_sample_size);
-                }
-            }
-            Value::Array(arr) => {
-                for element in arr.iter().take(array_sample_size) {
-                    Self::collect_fields_recursive(element, fields, array_sample_size);
-                }
-            }
-            _ => {}
+        if !self.analyze_input {
+            return Arc::default();
         }
+        self.analysis
+            .field_names(&self.json_input, self.array_sample())
     }
 
     /// Get all distinct string VALUES from the JSON, sorted by descending
@@ -117,47 +231,16 @@ impl JqExecutor {
     /// once per session; subsequent callers get an `Arc::clone`. Mirrors
     /// `all_field_names()` but for string values rather than keys.
     pub fn all_string_values(&self) -> Arc<Vec<String>> {
-        self.all_string_values
-            .get_or_init(|| {
-                let mut counts: HashMap<String, u32> = HashMap::new();
-                if let Some(parsed) = self.json_input_parsed() {
-                    Self::collect_string_values_recursive(&parsed, &mut counts);
-                }
-                Arc::new(sort_and_cap_strings(counts, MAX_GLOBAL_STRING_VALUES))
-            })
-            .clone()
+        if !self.analyze_input {
+            return Arc::default();
+        }
+        self.analysis.string_values(&self.json_input)
     }
 
-    fn collect_string_values_recursive(value: &Value, counts: &mut HashMap<String, u32>) {
-        // Iterative DFS to avoid stack overflow on deeply nested JSON.
-        let mut stack: Vec<&Value> = vec![value];
-        while let Some(node) = stack.pop() {
-            if counts.len() >= MAX_GLOBAL_STRING_VALUES {
-                // Don't add NEW distinct values past the cap, but keep walking
-                // so we accumulate frequency for already-seen strings. (Walking
-                // unbounded JSON for this is intentional — caller is gated by
-                // OnceLock so this only ever runs once.)
-            }
-            match node {
-                Value::String(s) => {
-                    if let Some(c) = counts.get_mut(s.as_str()) {
-                        *c += 1;
-                    } else if counts.len() < MAX_GLOBAL_STRING_VALUES {
-                        counts.insert(s.clone(), 1);
-                    }
-                }
-                Value::Array(arr) => {
-                    for element in arr {
-                        stack.push(element);
-                    }
-                }
-                Value::Object(map) => {
-                    for (_, v) in map {
-                        stack.push(v);
-                    }
-                }
-                _ => {}
-            }
+    fn array_sample(&self) -> ArraySample {
+        ArraySample {
+            size: self.array_sample_size,
+            sampling: self.array_sampling,
         }
     }
 
@@ -181,11 +264,93 @@ impl JqExecutor {
     ) -> Result<String, QueryError> {
         // TUI display path: color jq output with the active theme's palette so
         // the results pane tracks light/dark mode.
-        self.run_jq(
+        self.execute_formatted(query, OutputFormat::default(), cancel_token)
+    }
+
+    /// Execute a jq query for the results pane, pretty-printed as `format`
+    pub fn execute_formatted(
+        &self,
+        query: &str,
+        format: OutputFormat,
+        cancel_token: &CancellationToken,
+    ) -> Result<String, QueryError> {
+        self.run_jq(query, Some((Some(pane_jq_colors()), format)), cancel_token)
+    }
+
+    /// [`execute_formatted`](Self::execute_formatted), handing
+    /// `on_partial` the output so far while a slow query runs
+    ///
+    /// The output given is cut at its last line break, so each line in it
+    /// is whole. A fast query never calls it.
+    ///
+    /// Output past the bindings' output limit isn't kept: jq is stopped
+    /// and the output returned ends at the last whole line before the
+    /// limit, or at the limit when one line is longer, with `true` to say
+    /// it was cut.
+    pub fn execute_streaming(
+        &self,
+        query: &str,
+        format: OutputFormat,
+        cancel_token: &CancellationToken,
+        mut on_partial: impl FnMut(String),
+    ) -> Result<(String, bool), QueryError> {
+        self.run_jq_with_status(
             query,
-            jq_colors_env(crate::theme::results::jq_colors()),
+            Some((Some(pane_jq_colors()), format)),
+            false,
+            true,
             cancel_token,
+            &mut |so_far| {
+                if let Some(end) = so_far.iter().rposition(|&b| b == b'\n') {
+                    on_partial(String::from_utf8_lossy(&so_far[..=end]).into_owned());
+                }
+            },
         )
+        .map(|run| (run.output, run.truncated))
+    }
+
+    /// Write the whole output of `query`, pretty-printed as `format` and
+    /// without colors, to `file` as jq prints it, never holding it in
+    /// memory; for output too large for the results pane
+    pub fn write_output(
+        &self,
+        query: &str,
+        format: OutputFormat,
+        file: std::fs::File,
+        cancel_token: &CancellationToken,
+    ) -> Result<(), QueryError> {
+        let mut child = self
+            .command(query, Some((None, format)), false)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::from(file))
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| QueryError::SpawnFailed(e.to_string()))?;
+        self.feed_stdin(&mut child, cancel_token);
+        let stderr_rx = child.stderr.take().map(read_in_chunks);
+
+        let status = loop {
+            if cancel_token.is_cancelled() {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(QueryError::Cancelled);
+            }
+            if let Some(status) = child
+                .try_wait()
+                .map_err(|e| QueryError::OutputReadFailed(e.to_string()))?
+            {
+                break status;
+            }
+            sleep(Duration::from_millis(10));
+        };
+        if status.success() {
+            return Ok(());
+        }
+        let stderr: Vec<u8> = stderr_rx.into_iter().flatten().flatten().collect();
+        Err(QueryError::ExecutionFailed(
+            self.jq_args
+                .locate_error(String::from_utf8_lossy(&stderr).to_string()),
+        ))
     }
 
     /// Execute a jq query for the FINAL stdout output (the deliverable emitted
@@ -194,28 +359,182 @@ impl JqExecutor {
     /// Unlike [`execute_with_cancel`], this always uses the fixed dark Galaxy
     /// jq palette, independent of the active theme mode. The piped/redirected
     /// output must be consistent whether the TUI was in light or dark mode.
+    /// It is pretty-printed as `format`, like the results pane, and has no
+    /// colors at all in monochrome mode.
+    ///
+    /// Neither the output limit nor the timeout applies: what is printed
+    /// is never cut short.
     pub fn execute_for_output(
         &self,
         query: &str,
+        format: OutputFormat,
         cancel_token: &CancellationToken,
     ) -> Result<String, QueryError> {
-        self.run_jq(
+        let jq_colors = (!crate::theme::monochrome::is_enabled())
+            .then(|| jq_colors_env(crate::theme::results::output_jq_colors()));
+        self.run_jq_with_status(
             query,
-            jq_colors_env(crate::theme::results::output_jq_colors()),
+            Some((jq_colors, format)),
+            false,
+            false,
             cancel_token,
+            &mut |_| {},
         )
+        .map(|run| run.output)
     }
 
+    /// [`execute_for_output`](Self::execute_for_output) with jq's
+    /// `--exit-status`, also returning jq's exit code: 1 when the last
+    /// value was `false` or `null`, 4 when there was no value at all
+    pub fn execute_for_exit_status(
+        &self,
+        query: &str,
+        format: OutputFormat,
+        cancel_token: &CancellationToken,
+    ) -> Result<(String, i32), QueryError> {
+        let jq_colors = (!crate::theme::monochrome::is_enabled())
+            .then(|| jq_colors_env(crate::theme::results::output_jq_colors()));
+        self.run_jq_with_status(
+            query,
+            Some((jq_colors, format)),
+            true,
+            false,
+            cancel_token,
+            &mut |_| {},
+        )
+        .map(|run| (run.output, run.code))
+    }
+
+    /// Execute a jq query for plain output, one value per line
+    ///
+    /// Used for autocomplete previews, which only show the first value.
+    pub fn execute_compact(
+        &self,
+        query: &str,
+        cancel_token: &CancellationToken,
+    ) -> Result<String, QueryError> {
+        self.run_jq(query, None, cancel_token)
+    }
+
+    /// A copy sharing this executor's input and bindings, for running
+    /// queries on another thread
+    ///
+    /// Caches are not shared; the copy only runs queries.
+    pub fn share(&self) -> Self {
+        Self {
+            json_input: self.json_input.clone(),
+            analysis: Arc::default(),
+            array_sample_size: self.array_sample_size,
+            array_sampling: self.array_sampling,
+            jq_args: Arc::clone(&self.jq_args),
+            analyze_input: self.analyze_input,
+            analyzing_in_background: false,
+        }
+    }
+
+    /// Run jq pretty-printed as the format given, colored with the
+    /// `jq_colors` paired with it when there are some, or compact and
+    /// monochrome without a format
+    ///
+    /// Without `--exit-status`, so any exit code but 0 is an error. Output
+    /// past the bindings' output limit is cut, as in
+    /// [`execute_streaming`](Self::execute_streaming).
     fn run_jq(
         &self,
         query: &str,
-        jq_colors: String,
+        pretty: Option<(Option<String>, OutputFormat)>,
         cancel_token: &CancellationToken,
     ) -> Result<String, QueryError> {
+        self.run_jq_with_status(query, pretty, false, true, cancel_token, &mut |_| {})
+            .map(|run| run.output)
+    }
+
+    /// The jq command line for `query`, colored and formatted as in
+    /// [`run_jq`](Self::run_jq)
+    fn command(
+        &self,
+        query: &str,
+        pretty: Option<(Option<String>, OutputFormat)>,
+        exit_status: bool,
+    ) -> std::process::Command {
+        let mut command = crate::query::jq_binary::command();
+        match pretty {
+            Some((jq_colors, format)) => {
+                match jq_colors {
+                    Some(jq_colors) => command.env("JQ_COLORS", jq_colors).arg("--color-output"),
+                    None => command.arg("--monochrome-output"),
+                };
+                format.apply(&mut command);
+            }
+            None => {
+                command.args(["--compact-output", "--monochrome-output"]);
+            }
+        }
+        if exit_status {
+            command.arg("--exit-status");
+        }
+        self.jq_args.apply(&mut command);
+        command.arg(self.jq_args.program(query).as_ref());
+        command
+    }
+
+    /// Feed the input to `child`'s stdin from its own thread, a chunk at
+    /// a time, so a large input (more than the pipe's 64KB) can't
+    /// deadlock against a slow jq, and a cancelled query stops being fed.
+    /// Cloning the input is O(1) - just a reference count increment, no
+    /// data copying.
+    fn feed_stdin(&self, child: &mut std::process::Child, cancel_token: &CancellationToken) {
+        let json_input = self.json_input.clone();
+        if let Some(stdin) = child.stdin.take() {
+            let cancel_token = cancel_token.clone();
+            std::thread::spawn(move || {
+                use std::io::Write;
+                let mut stdin = stdin;
+                for chunk in json_input.as_bytes().chunks(STREAM_CHUNK_BYTES) {
+                    if cancel_token.is_cancelled() || stdin.write_all(chunk).is_err() {
+                        break;
+                    }
+                }
+                // stdin is dropped here, closing the pipe
+            });
+        }
+    }
+
+    /// [`run_jq`](Self::run_jq), handing `on_partial` the output so far
+    /// while jq is slow to finish
+    ///
+    /// The input is written to jq's stdin a chunk at a time from a
+    /// background thread, and jq's stdout is read a chunk at a time as it
+    /// arrives, so neither side waits on the whole text. Once the query has
+    /// run for [`PARTIAL_RESULT_AFTER`], `on_partial` gets everything read
+    /// so far, then again every [`PARTIAL_RESULT_EVERY`] while more comes.
+    ///
+    /// With `exit_status`, jq runs with `--exit-status` and its codes for a
+    /// `false`/`null` or missing last value count as success.
+    ///
+    /// An `interactive` run, for the TUI, is held to the bindings' timeout
+    /// and output limit: once more than the limit is read, jq is stopped
+    /// and the output is cut at the last line break before the limit, or
+    /// within the line when its first line alone is longer. The final
+    /// output is neither timed nor cut.
+    fn run_jq_with_status(
+        &self,
+        query: &str,
+        pretty: Option<(Option<String>, OutputFormat)>,
+        exit_status: bool,
+        interactive: bool,
+        cancel_token: &CancellationToken,
+        on_partial: &mut dyn FnMut(&[u8]),
+    ) -> Result<JqRun, QueryError> {
         use std::io::Read;
-        use std::sync::mpsc::channel;
+        use std::sync::mpsc::{RecvTimeoutError, channel};
 
         let _t = crate::Timer::new("jq query");
+        let (timeout, limit) = if interactive {
+            (self.jq_args.timeout(), self.jq_args.output_limit())
+        } else {
+            (None, None)
+        };
 
         // Empty query defaults to identity filter
         let query = if query.trim().is_empty() { "." } else { query };
@@ -226,42 +545,23 @@ impl JqExecutor {
         );
 
         // Spawn jq process with custom colors
-        let mut child = Command::new("jq")
-            .env("JQ_COLORS", jq_colors)
-            .arg("--color-output")
-            .arg(query)
+        let mut child = self
+            .command(query, pretty, exit_status)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
             .map_err(|e| QueryError::SpawnFailed(e.to_string()))?;
+        self.feed_stdin(&mut child, cancel_token);
 
-        // Spawn thread to write JSON to stdin
-        // This prevents deadlock if JSON is large (>64KB) and jq is slow to read
-        // Arc::clone is O(1) - just increments reference count, no data copying
-        let json_input = Arc::clone(&self.json_input);
-        if let Some(stdin) = child.stdin.take() {
-            std::thread::spawn(move || {
-                use std::io::Write;
-                let mut stdin = stdin;
-                let _ = stdin.write_all(json_input.as_bytes());
-                // stdin is dropped here, closing the pipe
-            });
-        }
-
-        // Spawn threads to read stdout/stderr concurrently
-        // This prevents pipe buffer deadlock on large outputs
-        let (stdout_tx, stdout_rx) = channel();
+        // Read stdout in chunks as jq writes it, and stderr whole; both
+        // on their own threads so neither pipe fills up and blocks jq
+        let stdout_rx = child
+            .stdout
+            .take()
+            .map(read_in_chunks)
+            .ok_or_else(|| QueryError::OutputReadFailed("No stdout".to_string()))?;
         let (stderr_tx, stderr_rx) = channel();
-
-        if let Some(mut stdout) = child.stdout.take() {
-            std::thread::spawn(move || {
-                let mut buffer = Vec::new();
-                let _ = stdout.read_to_end(&mut buffer);
-                let _ = stdout_tx.send(buffer);
-            });
-        }
-
         if let Some(mut stderr) = child.stderr.take() {
             std::thread::spawn(move || {
                 let mut buffer = Vec::new();
@@ -270,10 +570,14 @@ impl JqExecutor {
             });
         }
 
-        // Poll for completion or cancellation
-        const POLL_INTERVAL_MS: u64 = 10;
+        // Poll for completion or cancellation, collecting output meanwhile
+        const POLL_INTERVAL: Duration = Duration::from_millis(10);
         let poll_start = std::time::Instant::now();
         let mut slow_warned = false;
+        let mut stdout_data = Vec::new();
+        let mut stdout_open = true;
+        let mut next_partial = PARTIAL_RESULT_AFTER;
+        let mut partial_len = 0;
         let status = loop {
             // Check cancellation first
             if cancel_token.is_cancelled() {
@@ -282,44 +586,156 @@ impl JqExecutor {
                 return Err(QueryError::Cancelled);
             }
 
+            if let Some(timeout) = timeout
+                && poll_start.elapsed() >= timeout
+            {
+                log::warn!(
+                    "jq process killed after {:?} for query {:?}",
+                    timeout,
+                    query
+                );
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(QueryError::TimedOut(timeout));
+            }
+
             // Warn once if jq is taking a long time
             if !slow_warned && poll_start.elapsed() > Duration::from_secs(5) {
                 log::warn!("jq process still running after 5s for query {:?}", query);
                 slow_warned = true;
             }
 
+            // Wait briefly for output, which doubles as the poll interval
+            if stdout_open {
+                match stdout_rx.recv_timeout(POLL_INTERVAL) {
+                    Ok(chunk) => {
+                        stdout_data.extend_from_slice(&chunk);
+                        if let Some(limit) = limit
+                            && stdout_data.len() > limit
+                        {
+                            log::warn!("jq output passed {} bytes, stopping it", limit);
+                            let _ = child.kill();
+                            let _ = child.wait();
+                            stdout_data.truncate(cut_point(&stdout_data, limit));
+                            return Ok(JqRun {
+                                output: String::from_utf8_lossy(&stdout_data).to_string(),
+                                code: 0,
+                                truncated: true,
+                            });
+                        }
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => stdout_open = false,
+                }
+            } else {
+                sleep(POLL_INTERVAL);
+            }
+
             // Check if process finished
-            match child
+            if let Some(s) = child
                 .try_wait()
                 .map_err(|e| QueryError::OutputReadFailed(e.to_string()))?
             {
-                Some(s) => break s,
-                None => {
-                    // Process still running - sleep briefly
-                    sleep(Duration::from_millis(POLL_INTERVAL_MS));
-                }
+                break s;
+            }
+
+            if stdout_data.len() > partial_len && poll_start.elapsed() >= next_partial {
+                on_partial(&stdout_data);
+                partial_len = stdout_data.len();
+                next_partial = poll_start.elapsed() + PARTIAL_RESULT_EVERY;
             }
         };
 
-        // Process has exited - collect output from reader threads
-        let stdout_data = stdout_rx
-            .recv()
-            .map_err(|_| QueryError::OutputReadFailed("Failed to read stdout".to_string()))?;
+        // Process has exited - collect what the reader threads still hold
+        stdout_data.extend(stdout_rx.iter().flatten());
         let stderr_data = stderr_rx
             .recv()
             .map_err(|_| QueryError::OutputReadFailed("Failed to read stderr".to_string()))?;
 
-        if status.success() {
+        let code = status.code().unwrap_or(0);
+        if status.success() || (exit_status && EXIT_STATUS_CODES.contains(&code)) {
             log::debug!("jq succeeded: {} bytes output", stdout_data.len());
-            Ok(String::from_utf8_lossy(&stdout_data).to_string())
+            Ok(JqRun {
+                output: String::from_utf8_lossy(&stdout_data).to_string(),
+                code,
+                truncated: false,
+            })
         } else {
-            let stderr_str = String::from_utf8_lossy(&stderr_data).to_string();
+            let stderr_str = self
+                .jq_args
+                .locate_error(String::from_utf8_lossy(&stderr_data).to_string());
             log::debug!("jq failed (exit {:?}): {}", status.code(), stderr_str);
             Err(QueryError::ExecutionFailed(stderr_str))
         }
     }
 }
 
+/// What a jq run printed
+struct JqRun {
+    output: String,
+    /// jq's exit code
+    code: i32,
+    /// Whether jq was stopped at the output limit, cutting the output
+    truncated: bool,
+}
+
+/// jq's `--exit-status` codes for a last value of `false` or `null` (1)
+/// and for no value at all (4)
+const EXIT_STATUS_CODES: [i32; 2] = [1, 4];
+
+/// Bytes written to jq's stdin, or read from its stdout, at a time
+const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// How long a query runs before its output so far is shown
+pub const PARTIAL_RESULT_AFTER: Duration = Duration::from_millis(500);
+
+/// How often the output so far is shown again after that
+pub const PARTIAL_RESULT_EVERY: Duration = Duration::from_millis(500);
+
+/// Where to cut `output`, which is longer than `limit`: after the last
+/// line break within the limit, or at the last character boundary within
+/// it when there is no line break, so a single long value still shows
+fn cut_point(output: &[u8], limit: usize) -> usize {
+    match output[..limit].iter().rposition(|&b| b == b'\n') {
+        Some(newline) => newline + 1,
+        // A byte that isn't a UTF-8 continuation byte starts a character
+        None => (0..=limit)
+            .rev()
+            .find(|&i| output[i] & 0xC0 != 0x80)
+            .unwrap_or(0),
+    }
+}
+
+/// Read `reader` to the end on a new thread, sending each chunk as it
+/// arrives; the channel closes at the end
+fn read_in_chunks(mut reader: impl std::io::Read + Send + 'static) -> Receiver<Vec<u8>> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut buffer = vec![0; STREAM_CHUNK_BYTES];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if tx.send(buffer[..n].to_vec()).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    rx
+}
+
+/// `JQ_COLORS` for the results pane: the theme's, or attributes only in
+/// monochrome mode
+fn pane_jq_colors() -> String {
+    if crate::theme::monochrome::is_enabled() {
+        crate::theme::monochrome::JQ_COLORS.to_string()
+    } else {
+        jq_colors_env(crate::theme::results::jq_colors())
+    }
+}
+
 /// Build the `JQ_COLORS` env value from a theme's jq palette.
 ///
 /// Order matches jq's: null:false:true:numbers:strings:arrays:objects:keys.
@@ -341,6 +757,102 @@ fn jq_colors_env(palette: [ratatui::style::Color; 8]) -> String {
         .join(":")
 }
 
+/// How many elements of each array the field index looks at, and which
+#[derive(Debug, Clone, Copy)]
+struct ArraySample {
+    size: usize,
+    sampling: ArraySampling,
+}
+
+impl ArraySample {
+    /// The elements of `arr` to look at
+    fn pick(self, arr: &[Value]) -> Vec<&Value> {
+        if arr.len() <= self.size {
+            return arr.iter().collect();
+        }
+        match self.sampling {
+            ArraySampling::First => arr[..self.size].iter().collect(),
+            ArraySampling::FirstLast => {
+                let head = self.size.div_ceil(2);
+                let tail = self.size - head;
+                arr[..head].iter().chain(&arr[arr.len() - tail..]).collect()
+            }
+            ArraySampling::Random => {
+                // One element at random from each of `size` equal slices,
+                // seeded by the length so every run picks the same ones
+                let mut state = arr.len() as u64;
+                (0..self.size)
+                    .map(|slice| {
+                        let start = slice * arr.len() / self.size;
+                        let end = (slice + 1) * arr.len() / self.size;
+                        let offset = splitmix64(&mut state) % (end - start) as u64;
+                        &arr[start + offset as usize]
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Next number from a SplitMix64 sequence
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn collect_fields_recursive(value: &Value, fields: &mut HashSet<String>, sample: ArraySample) {
+    match value {
+        Value::Object(map) => {
+            for (key, val) in map {
+                fields.insert(key.clone());
+                collect_fields_recursive(val, fields, sample);
+            }
+        }
+        Value::Array(arr) => {
+            for element in sample.pick(arr) {
+                collect_fields_recursive(element, fields, sample);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_string_values_recursive(value: &Value, counts: &mut HashMap<String, u32>) {
+    // Iterative DFS to avoid stack overflow on deeply nested JSON.
+    let mut stack: Vec<&Value> = vec![value];
+    while let Some(node) = stack.pop() {
+        if counts.len() >= MAX_GLOBAL_STRING_VALUES {
+            // Don't add NEW distinct values past the cap, but keep walking
+            // so we accumulate frequency for already-seen strings. (Walking
+            // unbounded JSON for this is intentional — caller is gated by
+            // OnceLock so this only ever runs once.)
+        }
+        match node {
+            Value::String(s) => {
+                if let Some(c) = counts.get_mut(s.as_str()) {
+                    *c += 1;
+                } else if counts.len() < MAX_GLOBAL_STRING_VALUES {
+                    counts.insert(s.clone(), 1);
+                }
+            }
+            Value::Array(arr) => {
+                for element in arr {
+                    stack.push(element);
+                }
+            }
+            Value::Object(map) => {
+                for (_, v) in map {
+                    stack.push(v);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 fn sort_and_cap_strings(counts: HashMap<String, u32>, cap: usize) -> Vec<String> {
     let mut entries: Vec<(String, u32)> = counts.into_iter().collect();
     entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
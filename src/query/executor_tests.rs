@@ -105,7 +105,7 @@ fn test_final_output_uses_fixed_dark_colors() {
     let executor = JqExecutor::new(json.to_string());
     let cancel_token = CancellationToken::new();
     let output = executor
-        .execute_for_output(".", &cancel_token)
+        .execute_for_output(".", OutputFormat::default(), &cancel_token)
         .expect("query should succeed");
 
     assert!(
@@ -114,6 +114,102 @@ fn test_final_output_uses_fixed_dark_colors() {
     );
 }
 
+fn exit_status(json: &str, query: &str) -> Result<(String, i32), QueryError> {
+    JqExecutor::new(json.to_string()).execute_for_exit_status(
+        query,
+        OutputFormat::default(),
+        &CancellationToken::new(),
+    )
+}
+
+#[test]
+fn test_exit_status_follows_the_last_value() {
+    let json = r#"{"a": 1, "b": null}"#;
+
+    assert_eq!(exit_status(json, ".a").unwrap().1, 0);
+    assert_eq!(exit_status(json, ".b").unwrap().1, 1);
+    assert_eq!(exit_status(json, "false").unwrap().1, 1);
+    assert_eq!(exit_status(json, ".b, .a").unwrap().1, 0);
+    assert_eq!(exit_status(json, "empty").unwrap().1, 4);
+}
+
+#[test]
+fn test_exit_status_keeps_the_output() {
+    let (output, code) = exit_status(r#"{"b": null}"#, ".b").unwrap();
+
+    assert_eq!(code, 1);
+    assert!(output.contains("null"));
+}
+
+#[test]
+fn test_exit_status_still_reports_errors() {
+    let result = exit_status(r#"{"a": 1}"#, ".a | error(\"boom\")");
+
+    assert!(matches!(result, Err(QueryError::ExecutionFailed(_))));
+}
+
+#[test]
+fn test_compact_output_is_plain_and_one_value_per_line() {
+    let json = r#"{"items": [{"a": 1}, {"a": 2}]}"#;
+    let executor = JqExecutor::new(json.to_string());
+    let output = executor
+        .execute_compact(".items[]", &CancellationToken::new())
+        .unwrap();
+
+    assert_eq!(output, "{\"a\":1}\n{\"a\":2}\n");
+}
+
+#[test]
+fn test_runaway_query_is_killed_at_the_timeout() {
+    let mut jq_args = JqArgs::default();
+    jq_args.set_timeout(Some(Duration::from_millis(200)));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+
+    let started = std::time::Instant::now();
+    let result = executor.execute_with_cancel("last(range(1e12))", &CancellationToken::new());
+
+    assert!(matches!(result, Err(QueryError::TimedOut(_))));
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "query timed out after 0.2s"
+    );
+}
+
+#[test]
+fn test_final_output_is_not_timed() {
+    let mut jq_args = JqArgs::default();
+    jq_args.set_timeout(Some(Duration::from_millis(1)));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+    let cancel_token = CancellationToken::new();
+    let query = "last(range(300000))";
+
+    assert!(matches!(
+        executor.execute_with_cancel(query, &cancel_token),
+        Err(QueryError::TimedOut(_))
+    ));
+    let output = executor
+        .execute_for_output(query, OutputFormat::default(), &cancel_token)
+        .unwrap();
+    let (_, code) = executor
+        .execute_for_exit_status(query, OutputFormat::default(), &cancel_token)
+        .unwrap();
+
+    assert!(output.contains("299999"));
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn test_shared_executor_runs_against_same_input() {
+    let executor = JqExecutor::new(r#"{"name": "Alice"}"#.to_string());
+    let shared = executor.share();
+    let output = shared
+        .execute_compact(".name", &CancellationToken::new())
+        .unwrap();
+
+    assert_eq!(output, "\"Alice\"\n");
+}
+
 #[test]
 fn test_execute_with_cancel_success() {
     let json = r#"{"name": "Alice", "age": 30}"#;
@@ -232,6 +328,25 @@ fn test_json_input_parsed_handles_arrays() {
     assert_eq!(value.as_array().map(|a| a.len()), Some(2));
 }
 
+#[test]
+fn test_json_input_parsed_holds_concatenated_documents_as_an_array() {
+    let executor = JqExecutor::new("{\"id\": 1}\n{\"name\": \"x\"}".to_string());
+
+    let value = executor.json_input_parsed().unwrap();
+
+    assert_eq!(value.as_array().map(|a| a.len()), Some(2));
+    assert!(executor.input_is_stream());
+    let fields = executor.all_field_names();
+    assert!(fields.contains("id") && fields.contains("name"));
+}
+
+#[test]
+fn test_single_array_input_is_not_a_stream() {
+    let executor = JqExecutor::new(r#"[{"id": 1}, {"id": 2}]"#.to_string());
+
+    assert!(!executor.input_is_stream());
+}
+
 #[test]
 fn test_json_input_parsed_preserves_original_after_queries() {
     let json = r#"{"users": [{"name": "Alice"}, {"name": "Bob"}]}"#;
@@ -257,6 +372,19 @@ fn test_json_input_parsed_preserves_original_after_queries() {
     );
 }
 
+#[test]
+fn test_input_analysis_off_skips_parsing_the_input() {
+    let mut executor = JqExecutor::new(r#"{"a": {"b": "x"}}"#.to_string());
+    executor.set_input_analysis(false);
+
+    assert!(executor.json_input_parsed().is_none());
+    assert!(executor.all_field_names().is_empty());
+    assert!(executor.all_string_values().is_empty());
+    let cancel_token = CancellationToken::new();
+    let result = executor.execute_with_cancel(".a.b", &cancel_token).unwrap();
+    assert!(result.contains("\"x\""));
+}
+
 #[test]
 fn test_all_field_names_heterogeneous_array() {
     let json = r#"[{"a": 1}, {"b": 2}, {"c": 3}]"#;
@@ -347,6 +475,88 @@ fn test_all_field_names_respects_sample_limit() {
     );
 }
 
+fn keyed_array(len: usize) -> String {
+    let objects: Vec<String> = (0..len)
+        .map(|i| format!(r#"{{"key_{}": {}}}"#, i, i))
+        .collect();
+    format!("[{}]", objects.join(","))
+}
+
+#[test]
+fn test_all_field_names_first_last_sampling_reaches_the_end() {
+    let mut executor = JqExecutor::new(keyed_array(20));
+    executor.set_array_sampling(ArraySampling::FirstLast);
+    let fields = executor.all_field_names();
+
+    let mut keys: Vec<&String> = fields.iter().collect();
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            "key_0", "key_1", "key_15", "key_16", "key_17", "key_18", "key_19", "key_2", "key_3",
+            "key_4"
+        ]
+    );
+}
+
+#[test]
+fn test_all_field_names_random_sampling_picks_one_per_stretch() {
+    let mut executor = JqExecutor::new(keyed_array(100));
+    executor.set_array_sampling(ArraySampling::Random);
+    let fields = executor.all_field_names();
+
+    assert_eq!(fields.len(), 10);
+    for stretch in 0..10 {
+        let in_stretch = (stretch * 10..stretch * 10 + 10)
+            .filter(|i| fields.contains(&format!("key_{}", i)))
+            .count();
+        assert_eq!(in_stretch, 1, "stretch {stretch}: {fields:?}");
+    }
+
+    let mut again = JqExecutor::new(keyed_array(100));
+    again.set_array_sampling(ArraySampling::Random);
+    assert_eq!(again.all_field_names(), fields);
+}
+
+#[test]
+fn test_sampling_short_arrays_keeps_every_element() {
+    for sampling in [
+        ArraySampling::First,
+        ArraySampling::FirstLast,
+        ArraySampling::Random,
+    ] {
+        let mut executor = JqExecutor::new(keyed_array(10));
+        executor.set_array_sampling(sampling);
+        assert_eq!(executor.all_field_names().len(), 10, "{sampling:?}");
+    }
+}
+
+#[test]
+fn test_background_analysis_becomes_ready() {
+    let mut executor = JqExecutor::new(keyed_array(5));
+    assert!(!executor.input_analysis_pending());
+    executor.analyze_in_background();
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while executor.input_analysis_pending() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "analysis never finished"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    assert!(executor.all_field_names().contains("key_4"));
+    assert!(executor.json_input_parsed().is_some());
+}
+
+#[test]
+fn test_background_analysis_skipped_when_analysis_off() {
+    let mut executor = JqExecutor::new(keyed_array(5));
+    executor.set_input_analysis(false);
+    executor.analyze_in_background();
+    assert!(!executor.input_analysis_pending());
+}
+
 #[test]
 fn test_collect_string_values_skips_non_string_scalars() {
     // all_string_values must collect ONLY string values, silently skipping
@@ -475,3 +685,269 @@ fn test_jq_colors_env_formats_rgb_and_falls_back_for_non_rgb() {
         parts[7]
     );
 }
+
+#[test]
+fn test_jq_args_bind_rawfile_and_slurpfile() {
+    use crate::query::jq_args::{FileVarKind, JqArgs};
+    use std::io::Write;
+
+    let mut raw = tempfile::NamedTempFile::new().unwrap();
+    raw.write_all(b"hello").unwrap();
+    let mut slurp = tempfile::NamedTempFile::new().unwrap();
+    slurp.write_all(b"1 2").unwrap();
+
+    let mut jq_args = JqArgs::new();
+    jq_args
+        .push_file_var(FileVarKind::Raw, "greeting", raw.path())
+        .unwrap();
+    jq_args
+        .push_file_var(FileVarKind::Slurp, "nums", slurp.path())
+        .unwrap();
+
+    let executor = JqExecutor::new("{}".to_string()).with_jq_args(Arc::new(jq_args));
+    let cancel_token = CancellationToken::new();
+    let output = executor
+        .execute_with_cancel("[$greeting, ($nums | add)] | tostring", &cancel_token)
+        .unwrap();
+
+    assert!(output.contains(r#"[\"hello\",3]"#), "{output}");
+}
+
+#[test]
+fn test_undeclared_variable_still_errors() {
+    let executor = JqExecutor::new("{}".to_string());
+    let cancel_token = CancellationToken::new();
+
+    assert!(
+        executor
+            .execute_with_cancel("$nope", &cancel_token)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_null_input_reads_each_document_via_inputs() {
+    use crate::query::jq_args::JqArgs;
+
+    let mut jq_args = JqArgs::new();
+    jq_args.set_null_input(true);
+    let executor =
+        JqExecutor::new("{\"n\":1}\n{\"n\":2}".to_string()).with_jq_args(Arc::new(jq_args));
+    let cancel_token = CancellationToken::new();
+
+    let output = executor
+        .execute_with_cancel("[inputs.n] | add", &cancel_token)
+        .unwrap();
+
+    assert!(output.contains('3'), "{output}");
+}
+
+#[test]
+fn test_execute_formatted_applies_format() {
+    use crate::query::output_format::Indent;
+    use crate::query::worker::preprocess::strip_ansi_codes;
+
+    let executor = JqExecutor::new(r#"{"b": "é", "a": 1}"#.to_string());
+    let cancel_token = CancellationToken::new();
+    let format = OutputFormat {
+        indent: Indent::Spaces(4),
+        sort_keys: true,
+        ascii: true,
+        raw: false,
+        compact: false,
+    };
+
+    let output = executor
+        .execute_formatted(".", format, &cancel_token)
+        .unwrap();
+
+    assert_eq!(
+        strip_ansi_codes(&output),
+        "{\n    \"a\": 1,\n    \"b\": \"\\u00e9\"\n}\n"
+    );
+}
+
+#[test]
+fn test_streaming_hands_over_whole_lines_while_a_slow_query_runs() {
+    let executor = JqExecutor::new("null".to_string());
+    let cancel_token = CancellationToken::new();
+    // More than jq's output buffer up front, then a long stretch of nothing
+    let query = r#"(range(20000) | tostring), (range(10000000) | empty), "done""#;
+    let mut partials = Vec::new();
+    let (output, truncated) = executor
+        .execute_streaming(query, OutputFormat::default(), &cancel_token, |so_far| {
+            partials.push(so_far)
+        })
+        .unwrap();
+
+    assert!(
+        partials.iter().any(|p| !p.contains("done")),
+        "expected output before jq finished"
+    );
+    for partial in &partials {
+        assert!(partial.ends_with('\n'));
+        assert!(output.starts_with(partial.as_str()));
+    }
+    assert!(output.contains("done"));
+    assert!(!truncated);
+}
+
+#[test]
+fn test_streaming_a_fast_query_has_no_partial_output() {
+    let executor = JqExecutor::new(r#"{"a": 1}"#.to_string());
+    let cancel_token = CancellationToken::new();
+    let mut partials = 0;
+    let (output, _) = executor
+        .execute_streaming(".a", OutputFormat::default(), &cancel_token, |_| {
+            partials += 1
+        })
+        .unwrap();
+
+    assert!(output.contains('1'));
+    assert_eq!(partials, 0);
+}
+
+#[test]
+fn test_streaming_stops_jq_at_the_output_limit_on_a_whole_line() {
+    use crate::query::worker::preprocess::strip_ansi_codes;
+
+    let mut jq_args = JqArgs::default();
+    jq_args.set_output_limit(Some(1000));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+    let cancel_token = CancellationToken::new();
+
+    let started = std::time::Instant::now();
+    let (output, truncated) = executor
+        .execute_streaming(
+            "range(1e12)",
+            OutputFormat::default(),
+            &cancel_token,
+            |_| {},
+        )
+        .unwrap();
+
+    assert!(truncated);
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(output.len() <= 1000);
+    assert!(output.ends_with('\n'));
+    assert!(strip_ansi_codes(&output).starts_with("0\n1\n2\n"));
+}
+
+#[test]
+fn test_streaming_cuts_one_long_line_at_the_limit() {
+    let mut jq_args = JqArgs::default();
+    jq_args.set_output_limit(Some(64 * 1024));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+
+    let (output, truncated) = executor
+        .execute_streaming(
+            "[range(300000)] | tostring",
+            OutputFormat::default(),
+            &CancellationToken::new(),
+            |_| {},
+        )
+        .unwrap();
+
+    assert!(truncated);
+    assert!(output.len() > 60 * 1024);
+    assert!(output.len() <= 64 * 1024);
+}
+
+#[test]
+fn test_cut_point_keeps_whole_characters_without_a_line_break() {
+    assert_eq!(cut_point(b"ab\ncdef", 5), 3);
+    assert_eq!(cut_point(b"abcdef", 4), 4);
+    // "é" is two bytes; a limit between them cuts before it
+    assert_eq!(cut_point("aéb".as_bytes(), 2), 1);
+}
+
+#[test]
+fn test_compact_and_formatted_output_stop_at_the_limit() {
+    let mut jq_args = JqArgs::default();
+    jq_args.set_output_limit(Some(1000));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+    let cancel_token = CancellationToken::new();
+
+    let compact = executor
+        .execute_compact("range(10000)", &cancel_token)
+        .unwrap();
+    let formatted = executor
+        .execute_formatted("range(10000)", OutputFormat::default(), &cancel_token)
+        .unwrap();
+
+    assert!(compact.len() <= 1000);
+    assert!(compact.starts_with("0\n1\n2\n"));
+    assert!(compact.ends_with('\n'));
+    assert!(formatted.len() <= 1000);
+}
+
+#[test]
+fn test_final_output_ignores_the_output_limit() {
+    let mut jq_args = JqArgs::default();
+    jq_args.set_output_limit(Some(10));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+
+    let output = executor
+        .execute_for_output(
+            "range(1000)",
+            OutputFormat::default(),
+            &CancellationToken::new(),
+        )
+        .unwrap();
+
+    assert!(output.len() > 1000);
+}
+
+#[test]
+fn test_write_output_ignores_the_output_limit() {
+    let mut jq_args = JqArgs::default();
+    jq_args.set_output_limit(Some(10));
+    let executor = JqExecutor::new("null".to_string()).with_jq_args(Arc::new(jq_args));
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("out.json");
+
+    executor
+        .write_output(
+            "range(1000)",
+            OutputFormat::default(),
+            std::fs::File::create(&path).unwrap(),
+            &CancellationToken::new(),
+        )
+        .unwrap();
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(written.lines().count(), 1000);
+    assert!(written.ends_with("999\n"));
+    assert!(!written.contains('\x1b'));
+}
+
+#[test]
+fn test_large_input_is_streamed_whole() {
+    let items: Vec<String> = (0..200_000).map(|i| i.to_string()).collect();
+    let json = format!("[{}]", items.join(","));
+    let executor = JqExecutor::new(json);
+    let cancel_token = CancellationToken::new();
+    let output = executor.execute_compact("length", &cancel_token).unwrap();
+    assert_eq!(output.trim(), "200000");
+}
+
+#[test]
+fn test_prelude_functions_are_available_to_queries() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("prelude.jq");
+    std::fs::write(&path, "def double: . * 2;\n").unwrap();
+    let mut jq_args = JqArgs::new();
+    jq_args.set_prelude(Some(crate::query::prelude::Prelude::new(&path).unwrap()));
+    let executor = JqExecutor::new("5".to_string()).with_jq_args(Arc::new(jq_args));
+    let cancel_token = CancellationToken::new();
+
+    let output = executor.execute_compact("double", &cancel_token).unwrap();
+    assert_eq!(output.trim(), "10");
+
+    let Err(QueryError::ExecutionFailed(message)) =
+        executor.execute_compact(".foo |", &cancel_token)
+    else {
+        panic!("expected a syntax error");
+    };
+    assert!(!message.contains("include"), "{message}");
+}
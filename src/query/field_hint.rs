@@ -0,0 +1,124 @@
+//! "Did you mean" for fields the input doesn't have
+//!
+//! jq answers `.userNmae` with `null` rather than an error, so a typo in a
+//! key looks like missing data, or fails later on with "Cannot iterate over
+//! null". When a result is all nulls or such an error, the query's field
+//! accesses are checked against every key in the input. The first field no
+//! object has, with a key close to it (by edit distance, ignoring case),
+//! becomes a hint the error overlay offers, and `Alt+F` puts the key in its
+//! place.
+
+use std::collections::HashSet;
+
+use super::error_enhance::levenshtein;
+use super::lint::{after_number, is_ident, is_ident_start, mask, skip_spaces};
+
+/// The field at chars `start..end` of the query, which the input has no
+/// key for, and the key meant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldHint {
+    pub start: usize,
+    pub end: usize,
+    pub field: String,
+    pub suggestion: String,
+}
+
+impl FieldHint {
+    /// `query` with the field replaced by the suggestion, and the char
+    /// just past it. None once the query no longer has the field there.
+    pub fn apply(&self, query: &str) -> Option<(String, usize)> {
+        let chars: Vec<char> = query.chars().collect();
+        let field: String = chars.get(self.start..self.end)?.iter().collect();
+        if field != self.field {
+            return None;
+        }
+        let fixed: String = chars[..self.start]
+            .iter()
+            .copied()
+            .chain(self.suggestion.chars())
+            .chain(chars[self.end..].iter().copied())
+            .collect();
+        Some((fixed, self.start + self.suggestion.chars().count()))
+    }
+}
+
+/// The first field of `query` that none of `keys` matches, when one of
+/// them is close to it
+pub fn unknown_field(query: &str, keys: &HashSet<String>) -> Option<FieldHint> {
+    if keys.is_empty() {
+        return None;
+    }
+    let code = mask(query);
+    field_accesses(&code)
+        .filter(|(start, end)| {
+            let field: String = code[*start..*end].iter().collect();
+            !keys.contains(&field) && !constructs_key(&code, &field)
+        })
+        .find_map(|(start, end)| {
+            let field: String = code[start..end].iter().collect();
+            closest_key(&field, keys).map(|suggestion| FieldHint {
+                start,
+                end,
+                field,
+                suggestion,
+            })
+        })
+}
+
+/// Char ranges of the names in `.name` accesses, without the dot
+pub(super) fn field_accesses(code: &[char]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < code.len() {
+            let dot = i;
+            i += 1;
+            let field_start = code[dot] == '.'
+                && code.get(dot + 1).is_some_and(|c| is_ident_start(*c))
+                && !after_number(code, dot)
+                && (dot == 0 || !is_ident(code[dot - 1]) && code[dot - 1] != '$');
+            if !field_start {
+                continue;
+            }
+            while i < code.len() && is_ident(code[i]) {
+                i += 1;
+            }
+            return Some((dot + 1, i));
+        }
+        None
+    })
+}
+
+/// Whether the query builds an object with `field` as a key, as in
+/// `{field: 1} | .field`, so the input needn't have it
+fn constructs_key(code: &[char], field: &str) -> bool {
+    let name: Vec<char> = field.chars().collect();
+    (0..code.len().saturating_sub(name.len() - 1)).any(|at| {
+        code[at..].starts_with(&name)
+            && (at == 0 || !is_ident(code[at - 1]) && code[at - 1] != '.')
+            && code.get(at + name.len()).is_none_or(|c| !is_ident(*c))
+            && code.get(skip_spaces(code, at + name.len())) == Some(&':')
+    })
+}
+
+/// The key nearest `field`: one differing only in case, or else within an
+/// edit (two for names past three chars, so swapped letters count), the
+/// smaller distance and then the key first in order winning
+fn closest_key(field: &str, keys: &HashSet<String>) -> Option<String> {
+    let lower = field.to_lowercase();
+    let max_distance = if field.chars().count() <= 3 { 1 } else { 2 };
+    keys.iter()
+        .filter_map(|key| {
+            let distance = if key.to_lowercase() == lower {
+                0
+            } else {
+                levenshtein(field, key)
+            };
+            (distance <= max_distance).then_some((distance, key))
+        })
+        .min()
+        .map(|(_, key)| key.clone())
+}
+
+#[cfg(test)]
+#[path = "field_hint_tests.rs"]
+mod field_hint_tests;
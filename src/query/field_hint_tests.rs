@@ -0,0 +1,67 @@
+//! Tests for query/field_hint
+
+use super::*;
+
+fn keys(names: &[&str]) -> HashSet<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_typo_suggests_the_nearest_key() {
+    let hint = unknown_field(".users[].userNmae", &keys(&["users", "userName", "id"])).unwrap();
+
+    assert_eq!(hint.field, "userNmae");
+    assert_eq!(hint.suggestion, "userName");
+    assert_eq!((hint.start, hint.end), (9, 17));
+}
+
+#[test]
+fn test_case_slip_suggests_the_key() {
+    let hint = unknown_field(".userid", &keys(&["userId", "user"])).unwrap();
+
+    assert_eq!(hint.suggestion, "userId");
+}
+
+#[test]
+fn test_known_fields_give_no_hint() {
+    assert!(unknown_field(".users[].id", &keys(&["users", "id"])).is_none());
+}
+
+#[test]
+fn test_nothing_close_gives_no_hint() {
+    assert!(unknown_field(".zzz", &keys(&["users", "id"])).is_none());
+}
+
+#[test]
+fn test_first_unknown_field_wins() {
+    let hint = unknown_field(".usrs[] | .nmae", &keys(&["users", "name"])).unwrap();
+
+    assert_eq!(hint.suggestion, "users");
+}
+
+#[test]
+fn test_strings_numbers_and_variables_are_not_fields() {
+    let known = keys(&["name"]);
+
+    assert!(unknown_field(r#"select(.name == ".nmae")"#, &known).is_none());
+    assert!(unknown_field(".name * 1.5", &known).is_none());
+    assert!(unknown_field(". as $nmae | $nmae", &known).is_none());
+}
+
+#[test]
+fn test_keys_the_query_builds_are_not_unknown() {
+    let known = keys(&["name", "names"]);
+
+    assert!(unknown_field("{nme: .name} | .nme", &known).is_none());
+}
+
+#[test]
+fn test_apply_replaces_the_field() {
+    let hint = unknown_field(".users[].userNmae | length", &keys(&["users", "userName"])).unwrap();
+
+    assert_eq!(
+        hint.apply(".users[].userNmae | length"),
+        Some((".users[].userName | length".to_string(), 17))
+    );
+    assert_eq!(hint.apply(".users"), None);
+}
@@ -0,0 +1,278 @@
+//! Extra jq arguments declared on the command line
+//!
+//! `--rawfile name file` and `--slurpfile name file` bind a file's contents
+//! to `$name` for every query, exactly as they do for jq itself. The
+//! bindings are passed through on each jq invocation (live results and the
+//! final output), and the names are offered as `$name` in autocomplete.
+//! Values typed for the query's `$parameters` go along as `--arg` or
+//! `--argjson`. `--null-input` is passed through the same way, for
+//! `inputs`-driven queries over several input files, and so are `-L`
+//! module directories and the user's [`Prelude`]. Each run is also held to the timeout, if
+//! one is set, and the results pane's runs to the output limit.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::error::JiqError;
+use crate::query::prelude::Prelude;
+
+/// How a file is bound to its variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileVarKind {
+    /// `--rawfile`: the file's text as a single string
+    Raw,
+    /// `--slurpfile`: an array of the JSON values in the file
+    Slurp,
+}
+
+impl FileVarKind {
+    /// The jq flag that declares this binding
+    pub fn flag(self) -> &'static str {
+        match self {
+            FileVarKind::Raw => "--rawfile",
+            FileVarKind::Slurp => "--slurpfile",
+        }
+    }
+}
+
+/// A `$name` bound to a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileVar {
+    /// Variable name without the `$`
+    pub name: String,
+    pub path: PathBuf,
+    pub kind: FileVarKind,
+}
+
+/// A `$name` given a value in the parameters panel (F6)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedArg {
+    /// Variable name without the `$`
+    pub name: String,
+    pub value: String,
+}
+
+impl NamedArg {
+    /// `--argjson` when the value is JSON, so `5` is a number and `"5"` a
+    /// string; `--arg` for any other text
+    pub fn flag(&self) -> &'static str {
+        if serde_json::from_str::<serde_json::Value>(&self.value).is_ok() {
+            "--argjson"
+        } else {
+            "--arg"
+        }
+    }
+}
+
+/// Variable bindings passed to every jq invocation
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JqArgs {
+    file_vars: Vec<FileVar>,
+    named_args: Vec<NamedArg>,
+    null_input: bool,
+    raw_input: bool,
+    stream: bool,
+    /// `-L` directories searched for `import` and `include`
+    library_paths: Vec<PathBuf>,
+    prelude: Option<Prelude>,
+    /// How long a jq run may take before it is killed; None for no limit
+    timeout: Option<Duration>,
+    /// Bytes of output kept for the results pane; None for no limit
+    output_limit: Option<usize>,
+}
+
+impl JqArgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind `$name` to a file, rejecting bad names and unreadable files up front
+    ///
+    /// jq would report these on every keystroke; failing at startup gives
+    /// one clear message instead. A later binding of the same name replaces
+    /// the earlier one, matching jq.
+    pub fn push_file_var(
+        &mut self,
+        kind: FileVarKind,
+        name: &str,
+        path: &Path,
+    ) -> Result<(), JiqError> {
+        if !is_valid_name(name) {
+            return Err(JiqError::InvalidArgument(format!(
+                "{} {}: variable names must start with a letter or '_' and contain only letters, digits, and '_'",
+                kind.flag(),
+                name
+            )));
+        }
+        if !path.is_file() {
+            return Err(JiqError::InvalidArgument(format!(
+                "{} {}: cannot read {}",
+                kind.flag(),
+                name,
+                path.display()
+            )));
+        }
+
+        self.file_vars.retain(|v| v.name != name);
+        self.file_vars.push(FileVar {
+            name: name.to_string(),
+            path: path.to_path_buf(),
+            kind,
+        });
+        Ok(())
+    }
+
+    pub fn file_vars(&self) -> &[FileVar] {
+        &self.file_vars
+    }
+
+    /// Pass each of `args` as `--arg` or `--argjson`, replacing the last set
+    pub fn set_named_args(&mut self, args: Vec<NamedArg>) {
+        self.named_args = args;
+    }
+
+    pub fn named_args(&self) -> &[NamedArg] {
+        &self.named_args
+    }
+
+    /// Run jq with `null` as `.` so queries read the inputs via `input`/`inputs`
+    pub fn set_null_input(&mut self, null_input: bool) {
+        self.null_input = null_input;
+    }
+
+    pub fn null_input(&self) -> bool {
+        self.null_input
+    }
+
+    /// Give jq each line of the input as a string rather than parsing it
+    pub fn set_raw_input(&mut self, raw_input: bool) {
+        self.raw_input = raw_input;
+    }
+
+    pub fn raw_input(&self) -> bool {
+        self.raw_input
+    }
+
+    /// Give jq the input as `[path, leaf]` events instead of whole values
+    pub fn set_stream(&mut self, stream: bool) {
+        self.stream = stream;
+    }
+
+    pub fn stream(&self) -> bool {
+        self.stream
+    }
+
+    /// Search `dir` for jq modules, as jq's `-L`
+    pub fn push_library_path(&mut self, dir: &Path) -> Result<(), JiqError> {
+        if !dir.is_dir() {
+            return Err(JiqError::InvalidArgument(format!(
+                "-L {}: not a directory",
+                dir.display()
+            )));
+        }
+        self.library_paths.push(dir.to_path_buf());
+        Ok(())
+    }
+
+    /// Include `prelude` in every query
+    pub fn set_prelude(&mut self, prelude: Option<Prelude>) {
+        self.prelude = prelude;
+    }
+
+    pub fn prelude(&self) -> Option<&Prelude> {
+        self.prelude.as_ref()
+    }
+
+    /// Kill a jq run still going after `timeout`, so a runaway query such
+    /// as an unbounded `recurse` can't hang the session
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Stop a query for the results pane once it has printed `limit`
+    /// bytes, so a query that expands to gigabytes can't use up memory.
+    /// The output on exit is never cut.
+    pub fn set_output_limit(&mut self, limit: Option<usize>) {
+        self.output_limit = limit;
+    }
+
+    pub fn output_limit(&self) -> Option<usize> {
+        self.output_limit
+    }
+
+    /// The program jq runs for `query`: the query itself, after the
+    /// prelude's `include` when there is one
+    pub fn program<'a>(&self, query: &'a str) -> Cow<'a, str> {
+        match &self.prelude {
+            Some(prelude) => prelude.program(query),
+            None => Cow::Borrowed(query),
+        }
+    }
+
+    /// jq's error for a [`program`](Self::program), told in terms of the
+    /// query alone
+    pub fn locate_error(&self, message: String) -> String {
+        match &self.prelude {
+            Some(prelude) => prelude.locate_error(&message),
+            None => message,
+        }
+    }
+
+    /// Append the flags and bindings to a jq command line
+    pub fn apply(&self, command: &mut Command) {
+        command.args(self.to_words());
+    }
+
+    /// The same flags and bindings as plain words, for an exported script
+    pub fn to_words(&self) -> Vec<String> {
+        let mut words = Vec::new();
+        if self.null_input {
+            words.push("--null-input".to_string());
+        }
+        if self.raw_input {
+            words.push("--raw-input".to_string());
+        }
+        if self.stream {
+            words.push("--stream".to_string());
+        }
+        for var in &self.file_vars {
+            words.push(var.kind.flag().to_string());
+            words.push(var.name.clone());
+            words.push(var.path.display().to_string());
+        }
+        for arg in &self.named_args {
+            words.push(arg.flag().to_string());
+            words.push(arg.name.clone());
+            words.push(arg.value.clone());
+        }
+        let prelude_dir = self.prelude.as_ref().map(Prelude::library_dir);
+        for dir in self
+            .library_paths
+            .iter()
+            .map(PathBuf::as_path)
+            .chain(prelude_dir)
+        {
+            words.push("-L".to_string());
+            words.push(dir.display().to_string());
+        }
+        words
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+#[path = "jq_args_tests.rs"]
+mod jq_args_tests;
@@ -0,0 +1,217 @@
+//! Tests for jq_args
+
+use super::*;
+use std::io::Write;
+
+fn temp_file(contents: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(contents.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_push_file_var_records_binding() {
+    let file = temp_file("hello");
+    let mut args = JqArgs::new();
+
+    args.push_file_var(FileVarKind::Raw, "greeting", file.path())
+        .unwrap();
+
+    assert_eq!(args.file_vars().len(), 1);
+    assert_eq!(args.file_vars()[0].name, "greeting");
+    assert_eq!(args.file_vars()[0].kind.flag(), "--rawfile");
+}
+
+#[test]
+fn test_push_file_var_rejects_invalid_name() {
+    let file = temp_file("x");
+    let mut args = JqArgs::new();
+
+    for name in ["", "1abc", "a-b", "$x"] {
+        let err = args
+            .push_file_var(FileVarKind::Slurp, name, file.path())
+            .unwrap_err();
+        assert!(matches!(err, JiqError::InvalidArgument(_)), "{name}");
+    }
+    assert!(args.file_vars().is_empty());
+}
+
+#[test]
+fn test_push_file_var_rejects_missing_file() {
+    let mut args = JqArgs::new();
+
+    let err = args
+        .push_file_var(FileVarKind::Raw, "x", Path::new("/nonexistent/jiq-file"))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("--rawfile x"));
+}
+
+#[test]
+fn test_same_name_replaces_earlier_binding() {
+    let first = temp_file("1");
+    let second = temp_file("2");
+    let mut args = JqArgs::new();
+
+    args.push_file_var(FileVarKind::Raw, "v", first.path())
+        .unwrap();
+    args.push_file_var(FileVarKind::Slurp, "v", second.path())
+        .unwrap();
+
+    assert_eq!(args.file_vars().len(), 1);
+    assert_eq!(args.file_vars()[0].kind, FileVarKind::Slurp);
+    assert_eq!(args.file_vars()[0].path, second.path());
+}
+
+#[test]
+fn test_apply_appends_flags_in_order() {
+    let raw = temp_file("text");
+    let slurp = temp_file("[1]");
+    let mut args = JqArgs::new();
+    args.push_file_var(FileVarKind::Raw, "a", raw.path())
+        .unwrap();
+    args.push_file_var(FileVarKind::Slurp, "b", slurp.path())
+        .unwrap();
+
+    let mut command = Command::new("jq");
+    args.apply(&mut command);
+
+    let got: Vec<String> = command
+        .get_args()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            "--rawfile".to_string(),
+            "a".to_string(),
+            raw.path().display().to_string(),
+            "--slurpfile".to_string(),
+            "b".to_string(),
+            slurp.path().display().to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_apply_passes_null_input_first() {
+    let raw = temp_file("text");
+    let mut args = JqArgs::new();
+    args.push_file_var(FileVarKind::Raw, "a", raw.path())
+        .unwrap();
+    args.set_null_input(true);
+
+    let mut command = Command::new("jq");
+    args.apply(&mut command);
+
+    assert_eq!(command.get_args().next().unwrap(), "--null-input");
+    assert_eq!(command.get_args().count(), 4);
+}
+
+#[test]
+fn test_raw_input_is_passed_on() {
+    let mut args = JqArgs::new();
+    args.set_raw_input(true);
+
+    assert!(args.raw_input());
+    assert_eq!(args.to_words(), ["--raw-input"]);
+}
+
+#[test]
+fn test_stream_is_passed_on() {
+    let mut args = JqArgs::new();
+    args.set_stream(true);
+
+    assert!(args.stream());
+    assert_eq!(args.to_words(), ["--stream"]);
+}
+
+#[test]
+fn test_named_args_pick_arg_or_argjson() {
+    let mut args = JqArgs::new();
+    args.set_named_args(vec![
+        NamedArg {
+            name: "env".to_string(),
+            value: "prod".to_string(),
+        },
+        NamedArg {
+            name: "min".to_string(),
+            value: "5".to_string(),
+        },
+        NamedArg {
+            name: "code".to_string(),
+            value: "\"5\"".to_string(),
+        },
+    ]);
+
+    assert_eq!(
+        args.to_words(),
+        [
+            "--arg",
+            "env",
+            "prod",
+            "--argjson",
+            "min",
+            "5",
+            "--argjson",
+            "code",
+            "\"5\""
+        ]
+    );
+}
+
+#[test]
+fn test_to_words_matches_apply() {
+    let raw = temp_file("text");
+    let mut args = JqArgs::new();
+    args.push_file_var(FileVarKind::Raw, "a", raw.path())
+        .unwrap();
+    args.set_null_input(true);
+
+    let mut command = Command::new("jq");
+    args.apply(&mut command);
+    let applied: Vec<String> = command
+        .get_args()
+        .map(|a| a.to_string_lossy().to_string())
+        .collect();
+
+    assert_eq!(args.to_words(), applied);
+}
+
+#[test]
+fn test_library_paths_and_prelude_become_l_flags() {
+    let libs = tempfile::TempDir::new().unwrap();
+    let prelude_dir = tempfile::TempDir::new().unwrap();
+    let prelude_path = prelude_dir.path().join("prelude.jq");
+    std::fs::write(&prelude_path, "def one: 1;").unwrap();
+
+    let mut args = JqArgs::new();
+    args.push_library_path(libs.path()).unwrap();
+    args.set_prelude(Some(Prelude::new(&prelude_path).unwrap()));
+
+    assert_eq!(
+        args.to_words(),
+        vec![
+            "-L".to_string(),
+            libs.path().display().to_string(),
+            "-L".to_string(),
+            prelude_dir.path().display().to_string(),
+        ]
+    );
+    assert_eq!(args.program(".a"), r#"include "prelude"; .a"#);
+}
+
+#[test]
+fn test_library_path_must_be_a_directory() {
+    let mut args = JqArgs::new();
+    let err = args
+        .push_library_path(Path::new("/nonexistent/jiq-lib"))
+        .unwrap_err();
+    assert!(err.to_string().contains("-L /nonexistent/jiq-lib"));
+}
+
+#[test]
+fn test_program_without_prelude_is_the_query() {
+    assert_eq!(JqArgs::new().program(".a"), ".a");
+    assert_eq!(JqArgs::new().locate_error("oops".to_string()), "oops");
+}
@@ -0,0 +1,231 @@
+//! The jq binary jiq runs, and what it can do
+//!
+//! At startup jiq finds jq (in PATH, or where `--jq` or `[jq] path`
+//! points), asks it for its version and keeps the answer here for the
+//! whole session. Every query runs that binary. The version is shown in
+//! the help popup, and builtins and flags newer than it are flagged
+//! instead of failing with jq's own, less helpful, error. A binary that
+//! doesn't report a jq version, such as a jq clone, is assumed to support
+//! everything.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+use crate::error::JiqError;
+use crate::help::HelpSection;
+
+/// A jq release, e.g. 1.7.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JqVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl JqVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// The version in `jq --version` output: `jq-1.7.1`, `jq-1.6` or a
+    /// development build like `jq-1.5rc2-1-gdeadbee`
+    pub fn parse(text: &str) -> Option<Self> {
+        let rest = text.trim().strip_prefix("jq-")?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let mut parts = rest[..end].split('.').map(str::parse::<u32>);
+        let major = parts.next()?.ok()?;
+        let minor = parts.next()?.ok()?;
+        let patch = match parts.next() {
+            Some(patch) => patch.ok()?,
+            None => 0,
+        };
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for JqVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.patch == 0 {
+            write!(f, "{}.{}", self.major, self.minor)
+        } else {
+            write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+        }
+    }
+}
+
+/// Oldest jq every jiq feature works with
+pub const RECOMMENDED: JqVersion = JqVersion::new(1, 6, 0);
+
+/// Builtins newer than jq 1.5, with the release that added them, from
+/// jq's release notes
+const BUILTINS_SINCE: &[(&str, JqVersion)] = &[
+    ("walk", JqVersion::new(1, 6, 0)),
+    ("halt", JqVersion::new(1, 6, 0)),
+    ("halt_error", JqVersion::new(1, 6, 0)),
+    ("utf8bytelength", JqVersion::new(1, 6, 0)),
+    ("builtins", JqVersion::new(1, 6, 0)),
+    ("IN", JqVersion::new(1, 6, 0)),
+    ("INDEX", JqVersion::new(1, 6, 0)),
+    ("@base32d", JqVersion::new(1, 6, 0)),
+    ("pick", JqVersion::new(1, 7, 0)),
+    ("have_literal_numbers", JqVersion::new(1, 7, 0)),
+    ("significand", JqVersion::new(1, 7, 0)),
+    ("abs", JqVersion::new(1, 7, 1)),
+    ("toarray", JqVersion::new(1, 7, 1)),
+    ("trim", JqVersion::new(1, 7, 1)),
+    ("ltrim", JqVersion::new(1, 7, 1)),
+    ("rtrim", JqVersion::new(1, 7, 1)),
+];
+
+/// Command-line flags older jq lacks
+const FLAGS_SINCE: &[(&str, JqVersion)] = &[
+    ("--rawfile", JqVersion::new(1, 6, 0)),
+    ("--args", JqVersion::new(1, 6, 0)),
+    ("--jsonargs", JqVersion::new(1, 6, 0)),
+];
+
+/// The jq binary found at startup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JqBinary {
+    pub path: PathBuf,
+    /// What `--version` printed, trimmed
+    pub version_text: String,
+    /// None for a binary that isn't versioned like jq
+    pub version: Option<JqVersion>,
+}
+
+impl JqBinary {
+    /// A binary that printed `version_text` for `--version`
+    pub fn new(path: PathBuf, version_text: &str) -> Self {
+        let version_text = version_text.trim().to_string();
+        Self {
+            version: JqVersion::parse(&version_text),
+            path,
+            version_text,
+        }
+    }
+
+    /// Find jq at `path`, or in PATH without one, and ask its version
+    pub fn probe(path: Option<&Path>) -> Result<Self, JiqError> {
+        let path = match path {
+            Some(path) => which::which(path).map_err(|_| {
+                JiqError::InvalidArgument(format!("jq binary not found at {}", path.display()))
+            })?,
+            None => which::which("jq").map_err(|_| JiqError::JqNotFound)?,
+        };
+        let output = Command::new(&path).arg("--version").output().map_err(|e| {
+            JiqError::InvalidArgument(format!("cannot run {}: {}", path.display(), e))
+        })?;
+        Ok(Self::new(path, &String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Whether this jq is at least `since`; a binary of unknown version
+    /// is assumed to be
+    pub fn supports(&self, since: JqVersion) -> bool {
+        self.version.is_none_or(|version| version >= since)
+    }
+
+    /// The release that added `builtin`, when it's newer than this jq
+    pub fn missing_builtin(&self, builtin: &str) -> Option<JqVersion> {
+        missing(BUILTINS_SINCE, builtin, self)
+    }
+
+    /// The release that added `flag`, when it's newer than this jq
+    pub fn missing_flag(&self, flag: &str) -> Option<JqVersion> {
+        missing(FLAGS_SINCE, flag, self)
+    }
+
+    /// Fail for a flag this jq doesn't have
+    pub fn require_flag(&self, flag: &str) -> Result<(), JiqError> {
+        match self.missing_flag(flag) {
+            Some(since) => Err(JiqError::InvalidArgument(format!(
+                "{} needs jq {} or newer; {} is {}",
+                flag,
+                since,
+                self.path.display(),
+                self.label()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// `jq 1.7.1`, or what it printed when that isn't a jq version
+    pub fn label(&self) -> String {
+        match self.version {
+            Some(version) => format!("jq {}", version),
+            None if self.version_text.is_empty() => "unknown version".to_string(),
+            None => self.version_text.clone(),
+        }
+    }
+
+    /// Warning for the status bar when this jq is older than
+    /// [`RECOMMENDED`]
+    pub fn age_warning(&self) -> Option<String> {
+        (!self.supports(RECOMMENDED)).then(|| {
+            format!(
+                "{} is older than jq {}: some builtins and flags are missing",
+                self.label(),
+                RECOMMENDED
+            )
+        })
+    }
+}
+
+fn missing(table: &[(&str, JqVersion)], name: &str, binary: &JqBinary) -> Option<JqVersion> {
+    table
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, since)| *since)
+        .filter(|since| !binary.supports(*since))
+}
+
+static BINARY: OnceLock<JqBinary> = OnceLock::new();
+
+/// Use `binary` for every query from now on; only the first call counts
+pub fn set(binary: JqBinary) {
+    let _ = BINARY.set(binary);
+}
+
+/// The binary found at startup; None before, as in tests
+pub fn get() -> Option<&'static JqBinary> {
+    BINARY.get()
+}
+
+/// A command running the jq found at startup, or `jq` from PATH
+pub fn command() -> Command {
+    match get() {
+        Some(binary) => Command::new(&binary.path),
+        None => Command::new("jq"),
+    }
+}
+
+/// The jq in use, for the Global help tab
+pub fn help_section() -> Option<HelpSection> {
+    static ENTRIES: OnceLock<Vec<(&'static str, &'static str)>> = OnceLock::new();
+    let binary = get()?;
+    let entries = ENTRIES.get_or_init(|| {
+        let label: &'static str = Box::leak(binary.label().into_boxed_str());
+        let path: &'static str = Box::leak(binary.path.display().to_string().into_boxed_str());
+        let mut entries = vec![("Version", label), ("Binary", path)];
+        if !binary.supports(RECOMMENDED) {
+            entries.push(("Note", "Upgrade to jq 1.6+ for every builtin and flag"));
+        }
+        entries
+    });
+    Some(HelpSection {
+        title: Some("JQ"),
+        entries,
+    })
+}
+
+#[cfg(test)]
+#[path = "jq_binary_tests.rs"]
+mod jq_binary_tests;
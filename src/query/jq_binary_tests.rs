@@ -0,0 +1,94 @@
+//! Tests for query/jq_binary
+
+use super::*;
+
+fn binary(version_text: &str) -> JqBinary {
+    JqBinary::new(PathBuf::from("/usr/bin/jq"), version_text)
+}
+
+#[test]
+fn test_parse_release_versions() {
+    assert_eq!(
+        JqVersion::parse("jq-1.7.1\n"),
+        Some(JqVersion::new(1, 7, 1))
+    );
+    assert_eq!(JqVersion::parse("jq-1.6"), Some(JqVersion::new(1, 6, 0)));
+}
+
+#[test]
+fn test_parse_development_builds() {
+    assert_eq!(
+        JqVersion::parse("jq-1.5rc2-1-gdeadbee"),
+        Some(JqVersion::new(1, 5, 0))
+    );
+    assert_eq!(
+        JqVersion::parse("jq-1.7.1-dirty"),
+        Some(JqVersion::new(1, 7, 1))
+    );
+}
+
+#[test]
+fn test_parse_rejects_other_tools() {
+    assert_eq!(JqVersion::parse("gojq 0.12.16 (rev: HEAD/go1.22)"), None);
+    assert_eq!(JqVersion::parse(""), None);
+    assert_eq!(JqVersion::parse("jq-"), None);
+}
+
+#[test]
+fn test_version_display_drops_a_zero_patch() {
+    assert_eq!(JqVersion::new(1, 6, 0).to_string(), "1.6");
+    assert_eq!(JqVersion::new(1, 7, 1).to_string(), "1.7.1");
+}
+
+#[test]
+fn test_missing_builtin_by_version() {
+    let old = binary("jq-1.5");
+    assert_eq!(old.missing_builtin("walk"), Some(JqVersion::new(1, 6, 0)));
+    assert_eq!(old.missing_builtin("map"), None);
+
+    let current = binary("jq-1.7.1");
+    assert_eq!(current.missing_builtin("walk"), None);
+    assert_eq!(current.missing_builtin("abs"), None);
+    assert_eq!(
+        binary("jq-1.7").missing_builtin("abs"),
+        Some(JqVersion::new(1, 7, 1))
+    );
+}
+
+#[test]
+fn test_unknown_binary_supports_everything() {
+    let clone = binary("gojq 0.12.16");
+    assert_eq!(clone.version, None);
+    assert!(clone.supports(JqVersion::new(9, 0, 0)));
+    assert_eq!(clone.missing_builtin("pick"), None);
+    assert_eq!(clone.label(), "gojq 0.12.16");
+    assert_eq!(clone.age_warning(), None);
+}
+
+#[test]
+fn test_require_flag_names_the_version_needed() {
+    let err = binary("jq-1.5").require_flag("--rawfile").unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("--rawfile needs jq 1.6"), "{message}");
+    assert!(message.contains("jq 1.5"), "{message}");
+    assert!(binary("jq-1.6").require_flag("--rawfile").is_ok());
+}
+
+#[test]
+fn test_age_warning_only_below_recommended() {
+    assert!(binary("jq-1.5").age_warning().unwrap().contains("jq 1.5"));
+    assert_eq!(binary("jq-1.6").age_warning(), None);
+}
+
+#[test]
+fn test_probe_finds_jq_in_path() {
+    let jq = JqBinary::probe(None).unwrap();
+    assert!(jq.path.is_absolute());
+    assert!(jq.version.is_some(), "{:?}", jq.version_text);
+}
+
+#[test]
+fn test_probe_reports_a_missing_override() {
+    let err = JqBinary::probe(Some(Path::new("/nonexistent/jq"))).unwrap_err();
+    assert!(err.to_string().contains("/nonexistent/jq"));
+}
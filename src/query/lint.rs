@@ -0,0 +1,301 @@
+//! Query lints: queries jq runs happily that probably don't do what was
+//! meant
+//!
+//! A lint never stops a query from running. Each one marks a stretch of
+//! the query, which the input field underlines, and says what looks wrong.
+//! The checks are textual: strings and comments are skipped, and only
+//! `.[] | .[]` at the start of a query looks at the input.
+
+use serde_json::Value;
+
+/// A likely mistake at chars `start..end` of the query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+impl Lint {
+    fn new(start: usize, end: usize, message: impl Into<String>) -> Self {
+        Self {
+            start,
+            end,
+            message: message.into(),
+        }
+    }
+}
+
+/// How many values of the input `.[] | .[]` looks at
+const SAMPLE: usize = 100;
+
+/// Lints for `query`, in order of position. `input` is the parsed input,
+/// when there is one.
+pub fn lint(query: &str, input: Option<&Value>) -> Vec<Lint> {
+    let code = mask(query);
+    let mut lints = Vec::new();
+    lints.extend(double_iteration(&code, input));
+    lints.extend(unquoted_keys(&code));
+    lints.extend(selects(&code));
+    lints.sort_by_key(|lint| lint.start);
+    lints
+}
+
+/// `query` as chars with the inside of strings replaced by `_` and
+/// comments by spaces, so the checks only see code
+pub(super) fn mask(query: &str) -> Vec<char> {
+    let mut code: Vec<char> = query.chars().collect();
+    let mut i = 0;
+    while i < code.len() {
+        match code[i] {
+            '"' => {
+                i += 1;
+                while i < code.len() && code[i] != '"' {
+                    if code[i] == '\\' && i + 1 < code.len() {
+                        code[i] = '_';
+                        i += 1;
+                    }
+                    code[i] = '_';
+                    i += 1;
+                }
+                i += 1;
+            }
+            '#' => {
+                while i < code.len() {
+                    code[i] = ' ';
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    code
+}
+
+pub(super) fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+pub(super) fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+pub(super) fn skip_spaces(code: &[char], mut i: usize) -> usize {
+    while i < code.len() && code[i].is_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+pub(super) fn starts_with(code: &[char], at: usize, text: &str) -> bool {
+    text.chars()
+        .enumerate()
+        .all(|(offset, c)| code.get(at + offset) == Some(&c))
+}
+
+/// `.[] | .[]` (or `.[][]`) at the start of a query over an input whose
+/// values aren't arrays or objects, which fails on the first of them
+fn double_iteration(code: &[char], input: Option<&Value>) -> Option<Lint> {
+    let values: Vec<&Value> = match input? {
+        Value::Array(items) => items.iter().take(SAMPLE).collect(),
+        Value::Object(fields) => fields.values().take(SAMPLE).collect(),
+        _ => return None,
+    };
+    if values.is_empty() || values.iter().any(|v| v.is_array() || v.is_object()) {
+        return None;
+    }
+    let first = skip_spaces(code, 0);
+    if !starts_with(code, first, ".[]") {
+        return None;
+    }
+    let after = first + 3;
+    let second = if starts_with(code, after, "[]") {
+        after
+    } else {
+        let pipe = skip_spaces(code, after);
+        if code.get(pipe) != Some(&'|') {
+            return None;
+        }
+        let second = skip_spaces(code, pipe + 1);
+        if !starts_with(code, second, ".[]") {
+            return None;
+        }
+        second
+    };
+    let end = if code[second] == '[' {
+        second + 2
+    } else {
+        second + 3
+    };
+    Some(Lint::new(
+        second,
+        end,
+        "The input's values aren't arrays or objects, so the second `[]` has nothing to iterate",
+    ))
+}
+
+/// `.foo-bar`, which jq reads as `.foo - bar`
+fn unquoted_keys(code: &[char]) -> Vec<Lint> {
+    let mut lints = Vec::new();
+    let mut i = 0;
+    while i < code.len() {
+        let field_start = code[i] == '.'
+            && code.get(i + 1).is_some_and(|c| is_ident_start(*c))
+            && !after_number(code, i);
+        if !field_start {
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        while end < code.len() && is_ident(code[end]) {
+            end += 1;
+        }
+        let name_end = end;
+        while code.get(end) == Some(&'-') && code.get(end + 1).is_some_and(|c| is_ident_start(*c)) {
+            end += 1;
+            while end < code.len() && is_ident(code[end]) {
+                end += 1;
+            }
+        }
+        if end > name_end {
+            let key: String = code[i + 1..end].iter().collect();
+            lints.push(Lint::new(
+                i,
+                end,
+                format!(
+                    "`.{}` subtracts from `.{}`; quote a key with a `-` in it: `.\"{}\"`",
+                    key,
+                    code[i + 1..name_end].iter().collect::<String>(),
+                    key
+                ),
+            ));
+        }
+        i = end;
+    }
+    lints
+}
+
+/// Whether the `.` at `dot` is a decimal point, as in `1.5`
+pub(super) fn after_number(code: &[char], dot: usize) -> bool {
+    let digits = code[..dot]
+        .iter()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    digits > 0 && (digits == dot || !is_ident(code[dot - digits - 1]))
+}
+
+/// `select` without a condition, with a constant, or with `=` where `==`
+/// was meant
+fn selects(code: &[char]) -> Vec<Lint> {
+    let mut lints = Vec::new();
+    let mut i = 0;
+    while i < code.len() {
+        let word_start = starts_with(code, i, "select")
+            && (i == 0 || !(is_ident(code[i - 1]) || matches!(code[i - 1], '.' | '$' | '@')))
+            && !code.get(i + 6).is_some_and(|c| is_ident(*c));
+        if !word_start {
+            i += 1;
+            continue;
+        }
+        let word_end = i + 6;
+        let open = skip_spaces(code, word_end);
+        // An object key, as in `{select: .x}` or the shorthand `{select}`
+        if matches!(code.get(open), Some(':' | ',' | '}')) {
+            i = word_end;
+            continue;
+        }
+        if code.get(open) != Some(&'(') {
+            lints.push(Lint::new(i, word_end, NO_CONDITION));
+            i = word_end;
+            continue;
+        }
+        let Some(close) = closing_paren(code, open) else {
+            i = word_end;
+            continue;
+        };
+        let arg: String = code[open + 1..close].iter().collect();
+        match arg.trim() {
+            "" => lints.push(Lint::new(i, close + 1, NO_CONDITION)),
+            "false" | "null" => lints.push(Lint::new(
+                i,
+                close + 1,
+                "This `select` has a constant condition and drops every input",
+            )),
+            constant if is_constant(constant) => lints.push(Lint::new(
+                i,
+                close + 1,
+                "This `select` has a constant condition and keeps every input",
+            )),
+            _ => {
+                if let Some(at) = assignment(code, open + 1, close) {
+                    lints.push(Lint::new(
+                        at,
+                        at + 1,
+                        "`=` assigns instead of comparing; use `==` in a `select`",
+                    ));
+                }
+            }
+        }
+        i = word_end;
+    }
+    lints
+}
+
+const NO_CONDITION: &str = "`select` needs a condition, e.g. `select(.age > 30)`";
+
+/// Whether `arg` is `true`, a number or a single string
+fn is_constant(arg: &str) -> bool {
+    arg == "true"
+        || arg.parse::<f64>().is_ok()
+        || (arg.len() >= 2
+            && arg.starts_with('"')
+            && arg.ends_with('"')
+            && !arg[1..arg.len() - 1].contains('"'))
+}
+
+/// The `)` closing the `(` at `open`
+fn closing_paren(code: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in code.iter().enumerate().skip(open) {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return (*c == ')').then_some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// A plain `=` outside brackets in `code[start..end]`
+fn assignment(code: &[char], start: usize, end: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for i in start..end {
+        match code[i] {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            '=' if depth == 0 => {
+                let before = code[i - 1];
+                let after = code.get(i + 1).copied();
+                if !matches!(
+                    before,
+                    '=' | '!' | '<' | '>' | '|' | '+' | '-' | '*' | '/' | '%'
+                ) && after != Some('=')
+                {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "lint_tests.rs"]
+mod lint_tests;
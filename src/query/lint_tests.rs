@@ -0,0 +1,132 @@
+//! Tests for query/lint
+
+use super::*;
+use serde_json::json;
+
+fn messages(query: &str) -> Vec<String> {
+    lint(query, None).into_iter().map(|l| l.message).collect()
+}
+
+/// The linted stretch of `query`
+fn marked(query: &str, lint: &Lint) -> String {
+    query
+        .chars()
+        .skip(lint.start)
+        .take(lint.end - lint.start)
+        .collect()
+}
+
+#[test]
+fn test_clean_queries_have_no_lints() {
+    for query in [
+        ".",
+        ".users[] | select(.age > 30) | .name",
+        "map(select(.active == true))",
+        ".a - .b",
+        ".price * 1.5e-3",
+        ".[] | .[]",
+        "select(.x != 1 and .y <= 2)",
+        ".n |= . + 1",
+    ] {
+        assert!(messages(query).is_empty(), "{query}: {:?}", messages(query));
+    }
+}
+
+#[test]
+fn test_hyphenated_key_is_flagged_with_the_quoted_form() {
+    let query = ".items[] | .first-name";
+    let lints = lint(query, None);
+
+    assert_eq!(lints.len(), 1);
+    assert_eq!(marked(query, &lints[0]), ".first-name");
+    assert!(lints[0].message.contains(r#"."first-name""#));
+}
+
+#[test]
+fn test_hyphenated_key_after_a_digit_in_a_field_name() {
+    let lints = lint(".v2.x-y", None);
+
+    assert_eq!(lints.len(), 1);
+    assert_eq!(marked(".v2.x-y", &lints[0]), ".x-y");
+}
+
+#[test]
+fn test_quoted_and_commented_text_is_not_linted() {
+    assert!(messages(r#"."first-name" | "select(.a = 1)""#).is_empty());
+    assert!(messages(". # .first-name").is_empty());
+}
+
+#[test]
+fn test_select_without_a_condition() {
+    for query in ["map(select())", "map(select)", ".[] | select( )"] {
+        let lints = lint(query, None);
+        assert_eq!(lints.len(), 1, "{query}");
+        assert!(lints[0].message.contains("needs a condition"));
+        assert!(marked(query, &lints[0]).starts_with("select"));
+    }
+}
+
+#[test]
+fn test_select_with_a_constant() {
+    assert!(messages(r#"select("active")"#)[0].contains("keeps every input"));
+    assert!(messages("select(1)")[0].contains("keeps every input"));
+    assert!(messages("select(true)")[0].contains("keeps every input"));
+    assert!(messages("select(null)")[0].contains("drops every input"));
+}
+
+#[test]
+fn test_select_with_an_assignment_marks_the_equals_sign() {
+    let query = r#".[] | select(.name = "x")"#;
+    let lints = lint(query, None);
+
+    assert_eq!(lints.len(), 1);
+    assert_eq!(marked(query, &lints[0]), "=");
+    assert!(lints[0].message.contains("=="));
+}
+
+#[test]
+fn test_assignment_inside_brackets_of_a_select_is_not_flagged() {
+    assert!(messages("select(any(.tags[]; . == 1))").is_empty());
+    assert!(messages("select((.a = 1) | .a)").is_empty());
+}
+
+#[test]
+fn test_select_as_an_object_key_is_not_flagged() {
+    for query in ["{select: .x}", "{select , id}", "{id, select}", "{select}"] {
+        assert!(messages(query).is_empty(), "{query}");
+    }
+}
+
+#[test]
+fn test_select_inside_an_object_value_is_still_checked() {
+    assert!(messages("{a: [.[] | select]}")[0].contains("condition"));
+}
+
+#[test]
+fn test_double_iteration_over_flat_array() {
+    let input = json!([1, 2, 3]);
+    let query = ".[] | .[]";
+    let lints = lint(query, Some(&input));
+
+    assert_eq!(lints.len(), 1);
+    assert_eq!(marked(query, &lints[0]), ".[]");
+    assert_eq!(lints[0].start, 6);
+
+    let lints = lint(".[][]", Some(&input));
+    assert_eq!(marked(".[][]", &lints[0]), "[]");
+}
+
+#[test]
+fn test_double_iteration_over_nested_input_is_fine() {
+    assert!(lint(".[] | .[]", Some(&json!([[1], [2]]))).is_empty());
+    assert!(lint(".[] | .[]", Some(&json!({"a": {"b": 1}}))).is_empty());
+    assert!(lint(".[] | .[]", Some(&json!([]))).is_empty());
+}
+
+#[test]
+fn test_lints_are_in_order_of_position() {
+    let lints = lint(".a-b | select()", None);
+
+    assert_eq!(lints.len(), 2);
+    assert!(lints[0].start < lints[1].start);
+}
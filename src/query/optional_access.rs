@@ -0,0 +1,135 @@
+//! Optional access for arrays whose elements differ in shape
+//!
+//! `.items[].foo` stops with "Cannot index string with \"foo\"" as soon as
+//! one item isn't an object, even when every other item has the key. When
+//! jq fails like that after the query has started iterating, the failing
+//! accesses are made optional: `?` goes after each of them and after the
+//! `[]` just before one, so `.items[].foo` becomes `.items[]?.foo?` and
+//! the items that can't be indexed are skipped. The error overlay offers
+//! the rewrite and `Alt+F` applies it.
+
+use super::error_enhance::runtime_message;
+use super::field_hint::field_accesses;
+use super::lint::{is_ident, mask, starts_with};
+
+/// The query jq failed on and the same query with the accesses made
+/// optional
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionalFix {
+    pub original: String,
+    pub fixed: String,
+    /// Char just past the last `?` added
+    pub cursor: usize,
+}
+
+/// The rewrite of `query` for jq's `error`, when it failed indexing or
+/// iterating some of the values the query iterates over
+pub fn optional_access(query: &str, error: &str) -> Option<OptionalFix> {
+    let message = runtime_message(error)?;
+    let code = mask(query);
+    // (where the access starts, where its `?`s go)
+    let accesses: Vec<(usize, Vec<usize>)> =
+        if let Some(rest) = message.strip_prefix("Cannot index ") {
+            let key = &rest[rest.find(" with ")? + " with ".len()..];
+            match quoted(key) {
+                Some(name) => named_accesses(&code, name),
+                None if key.starts_with("number") => brackets(&code, false),
+                None => return None,
+            }
+        } else if message.starts_with("Cannot iterate over ") {
+            brackets(&code, true)
+        } else {
+            return None;
+        };
+
+    // Failing before anything was iterated is a mistake in the query, not
+    // an element of another shape
+    let first_iteration = iterations(&code).min()?;
+    let mut inserts: Vec<usize> = accesses
+        .into_iter()
+        .filter(|(start, _)| *start > first_iteration)
+        .flat_map(|(_, inserts)| inserts)
+        .collect();
+    inserts.sort_unstable();
+    inserts.dedup();
+    let last = *inserts.last()?;
+
+    let mut fixed = String::new();
+    for (i, c) in query.chars().enumerate() {
+        if inserts.binary_search(&i).is_ok() {
+            fixed.push('?');
+        }
+        fixed.push(c);
+    }
+    if last == code.len() {
+        fixed.push('?');
+    }
+    Some(OptionalFix {
+        original: query.to_string(),
+        fixed,
+        cursor: last + inserts.len(),
+    })
+}
+
+/// The key in `with "foo"` (jq 1.7+) or `with string "foo"` (jq 1.6)
+fn quoted(key: &str) -> Option<&str> {
+    let open = key.find('"')?;
+    let close = key.rfind('"')?;
+    (close > open).then(|| &key[open + 1..close])
+}
+
+/// `.name` accesses not yet optional, each with the `[]` right before it
+fn named_accesses(code: &[char], name: &str) -> Vec<(usize, Vec<usize>)> {
+    field_accesses(code)
+        .filter(|(start, end)| {
+            code[*start..*end].iter().copied().eq(name.chars()) && code.get(*end) != Some(&'?')
+        })
+        .map(|(start, end)| {
+            let dot = start - 1;
+            let mut inserts = vec![end];
+            if dot >= 2 && code[dot - 2] == '[' && code[dot - 1] == ']' {
+                inserts.push(dot);
+            }
+            (dot, inserts)
+        })
+        .collect()
+}
+
+/// `[]` iterations, or else `[0]` indexes, not yet optional
+fn brackets(code: &[char], iteration: bool) -> Vec<(usize, Vec<usize>)> {
+    (0..code.len())
+        .filter(|at| code[*at] == '[' && is_postfix(code, *at))
+        .filter_map(|open| {
+            let close = open + code[open..].iter().position(|c| *c == ']')?;
+            let inside: String = code[open + 1..close].iter().collect();
+            let matches = if iteration {
+                inside.is_empty()
+            } else {
+                let digits = inside.strip_prefix('-').unwrap_or(&inside);
+                !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+            };
+            (matches && code.get(close + 1) != Some(&'?')).then(|| (open, vec![close + 1]))
+        })
+        .collect()
+}
+
+/// Where the query starts going through many values: `[]`, `..` and
+/// `map(`
+fn iterations(code: &[char]) -> impl Iterator<Item = usize> + '_ {
+    (0..code.len()).filter(|at| {
+        let iterate = starts_with(code, *at, "[]") && is_postfix(code, *at);
+        let recurse = starts_with(code, *at, "..") && !starts_with(code, *at + 2, ".");
+        let map = starts_with(code, *at, "map(") && (*at == 0 || !is_ident(code[*at - 1]));
+        iterate || recurse || map
+    })
+}
+
+/// Whether the `[` at `open` indexes what comes before it, rather than
+/// starting an array
+fn is_postfix(code: &[char], open: usize) -> bool {
+    open > 0 && (is_ident(code[open - 1]) || matches!(code[open - 1], '.' | ']' | ')' | '?' | '"'))
+}
+
+#[cfg(test)]
+#[path = "optional_access_tests.rs"]
+mod optional_access_tests;
@@ -0,0 +1,96 @@
+//! Tests for query/optional_access
+
+use super::*;
+
+fn error(message: &str) -> String {
+    format!("jq: error (at <stdin>:0): {}", message)
+}
+
+fn fixed(query: &str, message: &str) -> Option<String> {
+    optional_access(query, &error(message)).map(|fix| fix.fixed)
+}
+
+#[test]
+fn test_field_after_iteration_becomes_optional() {
+    let fix = optional_access(".items[].foo", &error(r#"Cannot index string with "foo""#)).unwrap();
+
+    assert_eq!(fix.fixed, ".items[]?.foo?");
+    assert_eq!(fix.original, ".items[].foo");
+    assert_eq!(fix.cursor, 14);
+}
+
+#[test]
+fn test_jq_16_wording_names_the_key_too() {
+    assert_eq!(
+        fixed(".items[].foo", r#"Cannot index string with string "foo""#),
+        Some(".items[]?.foo?".to_string())
+    );
+}
+
+#[test]
+fn test_only_the_failing_key_is_rewritten() {
+    assert_eq!(
+        fixed(
+            ".items[] | {id, foo: .foo.bar}",
+            r#"Cannot index number with "foo""#
+        ),
+        Some(".items[] | {id, foo: .foo?.bar}".to_string())
+    );
+}
+
+#[test]
+fn test_map_counts_as_iteration() {
+    assert_eq!(
+        fixed("map(.name)", r#"Cannot index array with "name""#),
+        Some("map(.name?)".to_string())
+    );
+}
+
+#[test]
+fn test_numeric_index_becomes_optional() {
+    assert_eq!(
+        fixed(".rows[] | .[0]", "Cannot index object with number"),
+        Some(".rows[] | .[0]?".to_string())
+    );
+}
+
+#[test]
+fn test_nested_iteration_becomes_optional() {
+    assert_eq!(
+        fixed(".items[].tags[]", r#"Cannot iterate over string ("x")"#),
+        Some(".items[].tags[]?".to_string())
+    );
+}
+
+#[test]
+fn test_failing_before_iterating_gets_no_fix() {
+    assert_eq!(fixed(".foo.bar", r#"Cannot index string with "bar""#), None);
+    assert_eq!(fixed(".[]", r#"Cannot iterate over number (1)"#), None);
+}
+
+#[test]
+fn test_already_optional_or_other_errors_get_no_fix() {
+    assert_eq!(
+        fixed(".items[]?.foo?", r#"Cannot index string with "foo""#),
+        None
+    );
+    assert_eq!(
+        fixed(".items[] + 1", "object ({}) and number (1) cannot be added"),
+        None
+    );
+    assert_eq!(
+        optional_access(".items[].foo", "jq: error: syntax error"),
+        None
+    );
+}
+
+#[test]
+fn test_strings_are_not_accesses() {
+    assert_eq!(
+        fixed(
+            r#".items[] | ".foo" + .foo"#,
+            r#"Cannot index string with "foo""#
+        ),
+        Some(r#".items[] | ".foo" + .foo?"#.to_string())
+    );
+}
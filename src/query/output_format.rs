@@ -0,0 +1,160 @@
+//! Pretty-printing options for jq output
+//!
+//! jq's `--indent N`, `--tab`, `--sort-keys` and `--ascii-output` can be
+//! changed while jiq runs (`o` in the results pane), and `--raw-output` and
+//! `--compact-output` from the flags panel (F4). They apply to the live
+//! results and to the final output printed on exit, so what is shown is
+//! what gets piped on.
+
+use std::process::Command;
+
+/// Default indentation, jq's own
+pub const DEFAULT_INDENT: u8 = 2;
+
+/// Widest indentation jq accepts
+pub const MAX_INDENT: u8 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// `--indent N`, 1 to 7 spaces
+    Spaces(u8),
+    /// `--tab`
+    Tab,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(DEFAULT_INDENT)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OutputFormat {
+    pub indent: Indent,
+    /// `--sort-keys`
+    pub sort_keys: bool,
+    /// `--ascii-output`
+    pub ascii: bool,
+    /// `--raw-output`: strings without quotes
+    pub raw: bool,
+    /// `--compact-output`: each value on one line, whatever the indent
+    pub compact: bool,
+}
+
+impl OutputFormat {
+    /// One more space of indentation, switching from tabs to the widest
+    pub fn indent_more(&mut self) {
+        self.indent = match self.indent {
+            Indent::Spaces(n) => Indent::Spaces((n + 1).min(MAX_INDENT)),
+            Indent::Tab => Indent::Spaces(MAX_INDENT),
+        };
+    }
+
+    /// One less space of indentation, down to one
+    pub fn indent_less(&mut self) {
+        self.indent = match self.indent {
+            Indent::Spaces(n) => Indent::Spaces(n.saturating_sub(1).max(1)),
+            Indent::Tab => Indent::Spaces(1),
+        };
+    }
+
+    /// Indent with tabs, or back to the default spaces
+    pub fn toggle_tab(&mut self) {
+        self.indent = match self.indent {
+            Indent::Tab => Indent::default(),
+            Indent::Spaces(_) => Indent::Tab,
+        };
+    }
+
+    pub fn toggle_sort_keys(&mut self) {
+        self.sort_keys = !self.sort_keys;
+    }
+
+    pub fn toggle_ascii(&mut self) {
+        self.ascii = !self.ascii;
+    }
+
+    pub fn toggle_raw(&mut self) {
+        self.raw = !self.raw;
+    }
+
+    pub fn toggle_compact(&mut self) {
+        self.compact = !self.compact;
+    }
+
+    /// The format saved under `[output]` in the config file
+    pub fn from_config(config: &crate::config::OutputConfig) -> Self {
+        OutputFormat {
+            indent: if config.tab {
+                Indent::Tab
+            } else {
+                Indent::default()
+            },
+            sort_keys: config.sort_keys,
+            raw: config.raw,
+            compact: config.compact,
+            ..OutputFormat::default()
+        }
+    }
+
+    /// Add the matching jq flags to `command`. Nothing is added for the
+    /// default format, leaving jq's own defaults in charge.
+    pub fn apply(&self, command: &mut Command) {
+        match self.indent {
+            _ if self.compact => {
+                command.arg("--compact-output");
+            }
+            Indent::Spaces(DEFAULT_INDENT) => {}
+            Indent::Spaces(n) => {
+                command.arg("--indent").arg(n.to_string());
+            }
+            Indent::Tab => {
+                command.arg("--tab");
+            }
+        }
+        if self.sort_keys {
+            command.arg("--sort-keys");
+        }
+        if self.ascii {
+            command.arg("--ascii-output");
+        }
+        if self.raw {
+            command.arg("--raw-output");
+        }
+    }
+
+    /// Short description of the indentation, e.g. `2 spaces` or `tab`
+    pub fn indent_label(&self) -> String {
+        match self.indent {
+            Indent::Spaces(1) => "1 space".to_string(),
+            Indent::Spaces(n) => format!("{} spaces", n),
+            Indent::Tab => "tab".to_string(),
+        }
+    }
+
+    /// What differs from the default, e.g. `indent 4 · sort-keys`, for the
+    /// status line. None for the default format.
+    pub fn summary(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        match self.indent {
+            _ if self.compact => parts.push("compact".to_string()),
+            Indent::Spaces(DEFAULT_INDENT) => {}
+            Indent::Spaces(n) => parts.push(format!("indent {}", n)),
+            Indent::Tab => parts.push("tab".to_string()),
+        }
+        if self.sort_keys {
+            parts.push("sort-keys".to_string());
+        }
+        if self.ascii {
+            parts.push("ascii".to_string());
+        }
+        if self.raw {
+            parts.push("raw".to_string());
+        }
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+}
+
+#[cfg(test)]
+#[path = "output_format_tests.rs"]
+mod output_format_tests;
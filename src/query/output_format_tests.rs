@@ -0,0 +1,127 @@
+//! Tests for output_format
+
+use super::*;
+
+fn args(format: &OutputFormat) -> Vec<String> {
+    let mut command = Command::new("jq");
+    format.apply(&mut command);
+    command
+        .get_args()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect()
+}
+
+#[test]
+fn test_default_format_adds_no_flags() {
+    let format = OutputFormat::default();
+
+    assert!(args(&format).is_empty());
+    assert_eq!(format.summary(), None);
+}
+
+#[test]
+fn test_apply_adds_each_flag() {
+    let format = OutputFormat {
+        indent: Indent::Spaces(4),
+        sort_keys: true,
+        ascii: true,
+        raw: false,
+        compact: false,
+    };
+
+    assert_eq!(
+        args(&format),
+        vec!["--indent", "4", "--sort-keys", "--ascii-output"]
+    );
+}
+
+#[test]
+fn test_apply_tab_indent() {
+    let format = OutputFormat {
+        indent: Indent::Tab,
+        ..OutputFormat::default()
+    };
+
+    assert_eq!(args(&format), vec!["--tab"]);
+}
+
+#[test]
+fn test_indent_stays_between_one_and_max() {
+    let mut format = OutputFormat::default();
+    for _ in 0..10 {
+        format.indent_more();
+    }
+    assert_eq!(format.indent, Indent::Spaces(MAX_INDENT));
+
+    for _ in 0..10 {
+        format.indent_less();
+    }
+    assert_eq!(format.indent, Indent::Spaces(1));
+}
+
+#[test]
+fn test_indent_steps_leave_tab() {
+    let mut format = OutputFormat {
+        indent: Indent::Tab,
+        ..OutputFormat::default()
+    };
+    format.indent_less();
+    assert_eq!(format.indent, Indent::Spaces(1));
+
+    format.indent = Indent::Tab;
+    format.indent_more();
+    assert_eq!(format.indent, Indent::Spaces(MAX_INDENT));
+}
+
+#[test]
+fn test_toggle_tab_returns_to_default_indent() {
+    let mut format = OutputFormat {
+        indent: Indent::Spaces(5),
+        ..OutputFormat::default()
+    };
+
+    format.toggle_tab();
+    assert_eq!(format.indent, Indent::Tab);
+    format.toggle_tab();
+    assert_eq!(format.indent, Indent::Spaces(DEFAULT_INDENT));
+}
+
+#[test]
+fn test_summary_lists_what_differs() {
+    let mut format = OutputFormat::default();
+    format.toggle_sort_keys();
+    assert_eq!(format.summary().as_deref(), Some("sort-keys"));
+
+    format.indent_more();
+    format.toggle_ascii();
+    assert_eq!(
+        format.summary().as_deref(),
+        Some("indent 3 · sort-keys · ascii")
+    );
+
+    format.toggle_tab();
+    assert_eq!(format.summary().as_deref(), Some("tab · sort-keys · ascii"));
+}
+
+#[test]
+fn test_indent_label() {
+    let mut format = OutputFormat::default();
+    assert_eq!(format.indent_label(), "2 spaces");
+    format.indent_less();
+    assert_eq!(format.indent_label(), "1 space");
+    format.toggle_tab();
+    assert_eq!(format.indent_label(), "tab");
+}
+
+#[test]
+fn test_compact_replaces_the_indent_flags() {
+    let format = OutputFormat {
+        indent: Indent::Tab,
+        compact: true,
+        raw: true,
+        ..OutputFormat::default()
+    };
+
+    assert_eq!(args(&format), vec!["--compact-output", "--raw-output"]);
+    assert_eq!(format.summary().as_deref(), Some("compact · raw"));
+}
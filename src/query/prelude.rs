@@ -0,0 +1,254 @@
+//! A user prelude: a `.jq` file of the user's own `def`s, available in
+//! every query
+//!
+//! jiq passes the prelude's directory to jq with `-L` and starts each
+//! query with `include "<name>"; `, so the definitions behave exactly as
+//! they would with jq itself, and an error inside the prelude is reported
+//! against the prelude file. The functions it defines are read once at
+//! startup and offered by autocomplete and the tooltip next to jq's
+//! builtins.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::error::JiqError;
+use crate::tooltip::TooltipContent;
+
+/// File name of the prelude in jiq's config directory, used when it exists
+pub const DEFAULT_FILE: &str = "prelude.jq";
+
+/// A prelude file, checked to be includable by jq
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prelude {
+    pub path: PathBuf,
+}
+
+impl Prelude {
+    /// The prelude at `path`, which must be a readable `.jq` file since
+    /// jq only includes those
+    pub fn new(path: &Path) -> Result<Self, JiqError> {
+        if !path.is_file() {
+            return Err(JiqError::InvalidArgument(format!(
+                "prelude {}: cannot read file",
+                path.display()
+            )));
+        }
+        if path.extension().is_none_or(|e| e != "jq") {
+            return Err(JiqError::InvalidArgument(format!(
+                "prelude {}: must end in .jq",
+                path.display()
+            )));
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Directory to pass with `-L`
+    pub fn library_dir(&self) -> &Path {
+        self.path.parent().unwrap_or(Path::new("."))
+    }
+
+    /// What goes before each query: `include "name"; `
+    pub fn include(&self) -> String {
+        let name = self
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy())
+            .unwrap_or_default();
+        format!(
+            "include \"{}\"; ",
+            name.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    }
+
+    /// `query` with the prelude included
+    pub fn program<'a>(&self, query: &'a str) -> Cow<'a, str> {
+        Cow::Owned(format!("{}{}", self.include(), query))
+    }
+
+    /// jq's error for a query run with [`program`](Self::program), with
+    /// columns and the echoed source put back as the user typed it
+    pub fn locate_error(&self, message: &str) -> String {
+        let include = self.include();
+        let shift = include.chars().count();
+        let mut out = Vec::new();
+        // Column in the previous line where the include was cut out
+        let mut cut_at: Option<usize> = None;
+        for line in message.lines() {
+            if let Some(at) = cut_at.take()
+                && line.trim_start().starts_with('^')
+            {
+                out.push(remove_spaces(line, at, shift));
+                continue;
+            }
+            if let Some(at) = line.find(&include) {
+                cut_at = Some(line[..at].chars().count());
+                out.push(line.replacen(&include, "", 1));
+                continue;
+            }
+            out.push(shift_line_one_column(line, shift));
+        }
+        let mut located = out.join("\n");
+        if message.ends_with('\n') {
+            located.push('\n');
+        }
+        located
+    }
+}
+
+/// `line` with up to `count` spaces removed from char `at` on
+fn remove_spaces(line: &str, at: usize, count: usize) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let spaces = chars
+        .iter()
+        .skip(at)
+        .take(count)
+        .take_while(|c| **c == ' ')
+        .count();
+    chars[..at.min(chars.len())]
+        .iter()
+        .chain(chars.iter().skip(at + spaces))
+        .collect()
+}
+
+/// ` line 1, column N` in a jq error, with N moved back past the include
+fn shift_line_one_column(line: &str, shift: usize) -> String {
+    const MARKER: &str = ", line 1, column ";
+    let Some(at) = line.find(MARKER) else {
+        return line.to_string();
+    };
+    let start = at + MARKER.len();
+    let digits = line[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(line.len(), |end| start + end);
+    match line[start..digits].parse::<usize>() {
+        Ok(column) => format!(
+            "{}{}{}",
+            &line[..start],
+            column.saturating_sub(shift).max(1),
+            &line[digits..]
+        ),
+        Err(_) => line.to_string(),
+    }
+}
+
+/// A function the prelude defines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserFunction {
+    pub name: String,
+    /// Parameter names as written, `$` included for value parameters
+    pub params: Vec<String>,
+    /// The `#` comment lines right above the `def`, joined
+    pub doc: Option<String>,
+}
+
+impl UserFunction {
+    /// `name(a; $b)`, or just `name` without parameters
+    pub fn signature(&self) -> String {
+        if self.params.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}({})", self.name, self.params.join("; "))
+        }
+    }
+
+    /// One-line description for autocomplete
+    pub fn description(&self) -> String {
+        match &self.doc {
+            Some(doc) => doc.lines().next().unwrap_or_default().to_string(),
+            None => "Defined in your prelude".to_string(),
+        }
+    }
+}
+
+/// The `def`s starting a line in `text`, with the comments above them.
+/// Indented `def`s are taken to be local to another definition and left
+/// out.
+pub fn parse_definitions(text: &str) -> Vec<UserFunction> {
+    let mut functions: Vec<UserFunction> = Vec::new();
+    let mut comments: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if let Some(comment) = line.strip_prefix('#') {
+            comments.push(comment.trim());
+            continue;
+        }
+        let doc = (!comments.is_empty()).then(|| comments.join("\n"));
+        comments.clear();
+        let Some(rest) = line.strip_prefix("def ") else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let name_end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        if name.is_empty() || functions.iter().any(|f| f.name == name) {
+            continue;
+        }
+        let after = rest[name_end..].trim_start();
+        let params = match after.strip_prefix('(') {
+            Some(list) => match list.find(')') {
+                Some(end) => list[..end]
+                    .split(';')
+                    .map(|p| p.trim().to_string())
+                    .filter(|p| !p.is_empty())
+                    .collect(),
+                None => continue,
+            },
+            None => Vec::new(),
+        };
+        functions.push(UserFunction {
+            name: name.to_string(),
+            params,
+            doc,
+        });
+    }
+    functions
+}
+
+struct Registered {
+    functions: Vec<UserFunction>,
+    tooltips: Vec<TooltipContent>,
+}
+
+static REGISTERED: OnceLock<Registered> = OnceLock::new();
+
+/// Offer `functions` in autocomplete and the tooltip from now on; only the
+/// first call counts
+pub fn register(functions: Vec<UserFunction>) {
+    let leak = |s: String| -> &'static str { Box::leak(s.into_boxed_str()) };
+    let tooltips = functions
+        .iter()
+        .map(|f| {
+            let signature = leak(f.signature());
+            let examples: &'static [&'static str] = Box::leak(vec![signature].into_boxed_slice());
+            let description = leak(f.doc.clone().unwrap_or_else(|| f.description()));
+            TooltipContent::new(leak(f.name.clone()), description, examples, None)
+                .with_signatures(examples)
+        })
+        .collect();
+    let _ = REGISTERED.set(Registered {
+        functions,
+        tooltips,
+    });
+}
+
+/// The prelude's functions; empty without a prelude
+pub fn functions() -> &'static [UserFunction] {
+    REGISTERED.get().map_or(&[], |r| &r.functions)
+}
+
+/// Tooltip for the prelude function `name`
+pub fn tooltip(name: &str) -> Option<&'static TooltipContent> {
+    REGISTERED
+        .get()?
+        .tooltips
+        .iter()
+        .find(|t| t.function == name)
+}
+
+#[cfg(test)]
+#[path = "prelude_tests.rs"]
+mod prelude_tests;
@@ -0,0 +1,94 @@
+//! Tests for query/prelude
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn prelude_in(dir: &TempDir, name: &str, text: &str) -> Prelude {
+    let path = dir.path().join(name);
+    fs::write(&path, text).unwrap();
+    Prelude::new(&path).unwrap()
+}
+
+#[test]
+fn test_include_names_the_file_stem() {
+    let dir = TempDir::new().unwrap();
+    let prelude = prelude_in(&dir, "mine.jq", "def one: 1;");
+    assert_eq!(prelude.include(), r#"include "mine"; "#);
+    assert_eq!(prelude.library_dir(), dir.path());
+    assert_eq!(prelude.program(".a"), r#"include "mine"; .a"#);
+}
+
+#[test]
+fn test_prelude_must_be_a_jq_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("defs.txt");
+    fs::write(&path, "def one: 1;").unwrap();
+    let err = Prelude::new(&path).unwrap_err();
+    assert!(err.to_string().contains("must end in .jq"));
+
+    let err = Prelude::new(&dir.path().join("missing.jq")).unwrap_err();
+    assert!(err.to_string().contains("cannot read"));
+}
+
+#[test]
+fn test_locate_error_removes_the_echoed_include() {
+    let dir = TempDir::new().unwrap();
+    let prelude = prelude_in(&dir, "prelude.jq", "");
+    let message = "jq: error: syntax error, unexpected $end at <top-level>, line 1:\n\
+                   include \"prelude\"; .foo |\n\
+                   jq: 1 compile error\n";
+    let located = prelude.locate_error(message);
+    assert!(!located.contains("include"), "{located}");
+    assert!(located.contains("\n.foo |\n"), "{located}");
+    assert!(located.ends_with('\n'));
+}
+
+#[test]
+fn test_locate_error_shifts_columns_and_carets_back() {
+    let dir = TempDir::new().unwrap();
+    let prelude = prelude_in(&dir, "prelude.jq", "");
+    let shift = prelude.include().len();
+    let message = format!(
+        "jq: error: syntax error at <top-level>, line 1, column {}:\n    {}.foo |\n    {}^\n",
+        shift + 6,
+        prelude.include(),
+        " ".repeat(shift + 5)
+    );
+    let located = prelude.locate_error(&message);
+    assert_eq!(
+        located,
+        "jq: error: syntax error at <top-level>, line 1, column 6:\n    .foo |\n         ^\n"
+    );
+}
+
+#[test]
+fn test_parse_definitions_with_params_and_docs() {
+    let text = "# Doubles a number\n\
+                # Handy for tests\n\
+                def double: . * 2;\n\
+                \n\
+                def add_n($n): . + $n;\n\
+                def apply(f; $x):\n  def inner: f;\n  inner + $x;\n";
+    let functions = parse_definitions(text);
+    let names: Vec<&str> = functions.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["double", "add_n", "apply"]);
+
+    assert_eq!(
+        functions[0].doc.as_deref(),
+        Some("Doubles a number\nHandy for tests")
+    );
+    assert_eq!(functions[0].description(), "Doubles a number");
+    assert_eq!(functions[0].signature(), "double");
+
+    assert_eq!(functions[1].doc, None);
+    assert_eq!(functions[1].description(), "Defined in your prelude");
+    assert_eq!(functions[2].signature(), "apply(f; $x)");
+}
+
+#[test]
+fn test_parse_definitions_keeps_the_first_of_several_arities() {
+    let functions = parse_definitions("def pad: pad(2);\ndef pad($n): .;\n");
+    assert_eq!(functions.len(), 1);
+    assert!(functions[0].params.is_empty());
+}
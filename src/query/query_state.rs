@@ -7,11 +7,16 @@ use ratatui::text::{Line, Span, Text};
 
 #[cfg(test)]
 use crate::autocomplete::json_navigator::DEFAULT_ARRAY_SAMPLE_SIZE;
+use crate::input::InputText;
 use crate::query::executor::JqExecutor;
+use crate::query::field_hint::FieldHint;
+use crate::query::jq_args::JqArgs;
+use crate::query::optional_access::OptionalFix;
+use crate::query::output_format::OutputFormat;
 use crate::query::worker::preprocess::{
     normalize_jq_text, parse_and_detect_type, strip_ansi_codes,
 };
-use crate::query::worker::types::RenderedLine;
+use crate::query::worker::types::{ProcessedResult, RenderedLine};
 use crate::query::worker::{QueryRequest, QueryResponse, spawn_worker};
 use serde_json::Value;
 
@@ -70,12 +75,26 @@ pub struct QueryState {
     pub(crate) cached_execution_time_ms: Option<u64>,
     /// Whether current result is null/empty (valid query but no results)
     pub is_empty_result: bool,
+    /// A field of the query the input has no key for, when that may be
+    /// why the result is null; see [`crate::query::field_hint`]
+    pub field_hint: Option<FieldHint>,
+    /// The query with its failing accesses made optional, when jq failed
+    /// on some of the values it iterates; see
+    /// [`crate::query::optional_access`]
+    pub optional_fix: Option<OptionalFix>,
     /// True when `last_successful_result_parsed` was synthesized by merging
     /// multiple top-level JSON documents. When true, the parsed Value's
     /// `to_string_pretty` line layout does NOT match the rendered output, so
     /// any line-indexed walk of the parsed Value (e.g., path-at-cursor)
     /// would produce wrong paths and must be skipped.
     pub is_synthetic_merge: bool,
+    /// The query whose output the pane shows, when jq was stopped at the
+    /// output limit and only the start of it is shown
+    pub truncated_query: Option<String>,
+    /// How jq pretty-prints results, live and on exit
+    output_format: OutputFormat,
+    /// Set while the result shown is a running query's output so far
+    partial: bool,
 
     // Async execution support
     /// Channel to send query requests to worker
@@ -98,16 +117,19 @@ pub struct QueryState {
 impl QueryState {
     /// Create a new QueryState with default sample size
     #[cfg(test)]
-    pub fn new(json_input: String) -> Self {
-        Self::new_with_sample_size(json_input, DEFAULT_ARRAY_SAMPLE_SIZE, true)
+    pub fn new(json_input: impl Into<InputText>) -> Self {
+        Self::new_with_sample_size(json_input, DEFAULT_ARRAY_SAMPLE_SIZE, true, Arc::default())
     }
 
     pub fn new_with_sample_size(
-        json_input: String,
+        json_input: impl Into<InputText>,
         array_sample_size: usize,
         ai_active: bool,
+        jq_args: Arc<JqArgs>,
     ) -> Self {
-        let executor = JqExecutor::new_with_sample_size(json_input.clone(), array_sample_size);
+        let json_input = json_input.into();
+        let executor = JqExecutor::new_with_sample_size(json_input.clone(), array_sample_size)
+            .with_jq_args(Arc::clone(&jq_args));
         let cancel_token = CancellationToken::new();
         let result = executor
             .execute_with_cancel(".", &cancel_token)
@@ -163,7 +185,7 @@ impl QueryState {
                     let mut widths: Vec<u16> = Vec::new();
                     for line in s.lines() {
                         line_count += 1;
-                        let width = line.len().min(u16::MAX as usize);
+                        let width = crate::str_utils::display_width(line).min(u16::MAX as usize);
                         widths.push(width as u16);
                         if width > max_width {
                             max_width = width;
@@ -180,7 +202,13 @@ impl QueryState {
         let (request_tx, request_rx) = channel();
         let (response_tx, response_rx) = channel();
 
-        spawn_worker(json_input, request_rx, response_tx, array_sample_size);
+        spawn_worker(
+            json_input,
+            request_rx,
+            response_tx,
+            array_sample_size,
+            jq_args,
+        );
 
         Self {
             executor,
@@ -197,28 +225,51 @@ impl QueryState {
             cached_line_widths,
             cached_execution_time_ms: None,
             is_empty_result: false,
+            field_hint: None,
+            optional_fix: None,
             is_synthetic_merge: is_synthetic_merge_initial,
+            truncated_query: None,
+            output_format: OutputFormat::default(),
             request_tx: Some(request_tx),
             response_rx: Some(response_rx),
             next_request_id: 1, // Reserve 0 for worker errors
             in_flight_request_id: None,
+            partial: false,
             current_cancel_token: None,
             array_sample_size,
             ai_active,
         }
     }
 
+    pub fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
+
+    /// Pretty-print later results as `format`; the caller re-runs the query
+    /// to show it
+    pub fn set_output_format(&mut self, format: OutputFormat) {
+        self.output_format = format;
+    }
+
+    /// Prepare the AI context from the next result on, or stop
+    pub fn set_ai_active(&mut self, ai_active: bool) {
+        self.ai_active = ai_active;
+    }
+
     /// Execute a query and update results
     /// Only caches non-null results for autosuggestions
     pub fn execute(&mut self, query: &str) {
         let cancel_token = CancellationToken::new();
         self.result = self
             .executor
-            .execute_with_cancel(query, &cancel_token)
+            .execute_formatted(query, self.output_format, &cancel_token)
             .map_err(|e| e.to_string());
         if let Ok(result) = &self.result {
             self.update_successful_result(result.clone(), query);
         }
+        if self.result.is_ok() && !self.is_empty_result {
+            self.truncated_query = None;
+        }
     }
 
     /// Update cached results for autosuggestions
@@ -237,7 +288,7 @@ impl QueryState {
 
         for line in unformatted.lines() {
             cached_line_count += 1;
-            let width = line.len().min(u16::MAX as usize);
+            let width = crate::str_utils::display_width(line).min(u16::MAX as usize);
             widths.push(width as u16);
             if width > cached_max_line_width {
                 cached_max_line_width = width;
@@ -323,6 +374,7 @@ impl QueryState {
         let cancel_token = CancellationToken::new();
         self.current_cancel_token = Some(cancel_token.clone());
         self.in_flight_request_id = Some(request_id);
+        self.partial = false;
 
         // Send request to worker
         if let Some(ref tx) = self.request_tx {
@@ -330,6 +382,7 @@ impl QueryState {
                 query: query.to_string(),
                 request_id,
                 cancel_token,
+                format: self.output_format,
             };
 
             // If send fails, worker died - clear channels
@@ -418,49 +471,24 @@ impl QueryState {
                     return None;
                 }
 
-                // Use precomputed is_only_nulls from worker thread
-                let is_only_nulls = processed.is_only_nulls;
-
-                self.is_empty_result = is_only_nulls;
-
                 // Clear in-flight tracking immediately
                 self.in_flight_request_id = None;
                 self.current_cancel_token = None;
+                self.partial = false;
 
-                // Only update cache if result is not null (same as sync path)
-                if !is_only_nulls {
-                    // Convert rendered lines to Text (fast - just allocations)
-                    let rendered = Self::rendered_lines_to_text(processed.rendered_lines);
-
-                    // Update result and all caches
-                    self.result = Ok(processed.output.as_ref().clone());
-                    self.last_successful_result = Some(processed.output);
-                    self.last_successful_result_unformatted = Some(processed.unformatted.clone());
-                    self.last_successful_result_rendered = Some(rendered);
-                    self.last_successful_result_parsed = processed.parsed;
-                    // Pre-process for AI context (skipped when AI isn't active).
-                    self.last_successful_result_for_context = if self.ai_active {
-                        Some(Arc::new(crate::ai::context::prepare_json_for_context(
-                            &processed.unformatted,
-                            crate::ai::context::MAX_JSON_SAMPLE_LENGTH,
-                        )))
-                    } else {
-                        None
-                    };
-                    self.cached_line_count = processed.line_count;
-                    self.cached_max_line_width = processed.max_width;
-                    self.cached_line_widths = Some(processed.line_widths);
-                    self.cached_execution_time_ms = processed.execution_time_ms;
-                    self.base_query_for_suggestions = Some(processed.query.clone());
-                    self.base_type_for_suggestions = Some(processed.result_type);
-                    self.is_synthetic_merge = processed.is_synthetic_merge;
-                } else {
-                    // Null result - preserve ALL cache including rendered output
-                    // Only update self.result so it shows as "null" in error state
-                    self.result = Ok(processed.output.as_ref().clone());
+                let query = processed.query.clone();
+                self.show_processed(processed, true);
+                Some(query)
+            }
+            QueryResponse::Partial {
+                processed,
+                request_id,
+            } => {
+                if Some(request_id) == current_request_id && !processed.is_only_nulls {
+                    self.partial = true;
+                    self.show_processed(processed, false);
                 }
-
-                Some(processed.query)
+                None
             }
             QueryResponse::Error {
                 message,
@@ -491,11 +519,65 @@ impl QueryState {
         }
     }
 
+    /// Show a result from the worker. Only a finished one becomes the base
+    /// for suggestions and AI context; the output of a query still running
+    /// is shown as it is, and an all-null one keeps the last result.
+    fn show_processed(&mut self, processed: ProcessedResult, finished: bool) {
+        // Use precomputed is_only_nulls from worker thread
+        let is_only_nulls = processed.is_only_nulls;
+
+        self.is_empty_result = is_only_nulls;
+
+        // Only update cache if result is not null (same as sync path)
+        if is_only_nulls {
+            // Null result - preserve ALL cache including rendered output
+            // Only update self.result so it shows as "null" in error state
+            self.result = Ok(processed.output.as_ref().clone());
+            return;
+        }
+
+        // Convert rendered lines to Text (fast - just allocations)
+        let rendered = Self::rendered_lines_to_text(processed.rendered_lines);
+
+        // Update result and all caches
+        self.result = Ok(processed.output.as_ref().clone());
+        self.last_successful_result = Some(processed.output);
+        self.last_successful_result_unformatted = Some(processed.unformatted.clone());
+        self.last_successful_result_rendered = Some(rendered);
+        self.last_successful_result_parsed = processed.parsed;
+        self.cached_line_count = processed.line_count;
+        self.cached_max_line_width = processed.max_width;
+        self.cached_line_widths = Some(processed.line_widths);
+        self.cached_execution_time_ms = processed.execution_time_ms;
+        self.is_synthetic_merge = processed.is_synthetic_merge;
+        self.truncated_query = processed.truncated_query;
+        if !finished {
+            return;
+        }
+        // Pre-process for AI context (skipped when AI isn't active).
+        self.last_successful_result_for_context = if self.ai_active {
+            Some(Arc::new(crate::ai::context::prepare_json_for_context(
+                &processed.unformatted,
+                crate::ai::context::MAX_JSON_SAMPLE_LENGTH,
+            )))
+        } else {
+            None
+        };
+        self.base_query_for_suggestions = Some(processed.query);
+        self.base_type_for_suggestions = Some(processed.result_type);
+    }
+
     /// Check if a query is currently pending
     pub fn is_pending(&self) -> bool {
         self.in_flight_request_id.is_some()
     }
 
+    /// Whether the result shown is the output so far of a query still
+    /// running
+    pub fn is_partial(&self) -> bool {
+        self.partial && self.is_pending()
+    }
+
     /// Normalize base query by stripping trailing incomplete operations
     ///
     /// Strips patterns like:
@@ -7,6 +7,8 @@ use crate::query::worker::preprocess::{parse_and_detect_type, strip_ansi_codes};
 // Submodules
 #[path = "query_state_tests/async_preprocessing_tests.rs"]
 mod async_preprocessing_tests;
+#[path = "query_state_tests/partial_response_tests.rs"]
+mod partial_response_tests;
 
 #[test]
 fn test_new_query_state() {
@@ -1104,6 +1106,7 @@ fn make_processed_result(
         query: query.to_string(),
         execution_time_ms: None,
         is_only_nulls,
+        truncated_query: None,
     }
 }
 
@@ -188,8 +188,12 @@ fn test_context_cache_skipped_when_ai_inactive() {
     // With ai_active = false, the AI context cache should never be built —
     // not on construction, and not after an async query completes.
     let json = r#"{"name": "test", "value": 42}"#;
-    let mut state =
-        QueryState::new_with_sample_size(json.to_string(), DEFAULT_ARRAY_SAMPLE_SIZE, false);
+    let mut state = QueryState::new_with_sample_size(
+        json.to_string(),
+        DEFAULT_ARRAY_SAMPLE_SIZE,
+        false,
+        std::sync::Arc::default(),
+    );
 
     assert!(
         state.last_successful_result_for_context.is_none(),
@@ -217,7 +221,12 @@ fn test_context_cache_built_when_ai_active() {
     // Explicit counterpart: ai_active = true builds the cache, confirming the
     // flag is what gates it (not some unrelated condition).
     let json = r#"{"name": "test", "value": 42}"#;
-    let state = QueryState::new_with_sample_size(json.to_string(), DEFAULT_ARRAY_SAMPLE_SIZE, true);
+    let state = QueryState::new_with_sample_size(
+        json.to_string(),
+        DEFAULT_ARRAY_SAMPLE_SIZE,
+        true,
+        std::sync::Arc::default(),
+    );
 
     assert!(
         state.last_successful_result_for_context.is_some(),
@@ -0,0 +1,46 @@
+//! Tests for partial responses shown while a query is still running
+
+use super::*;
+
+#[test]
+fn test_partial_response_shows_output_and_keeps_the_query_pending() {
+    let mut state = QueryState::new(ASYNC_TEST_JSON.to_string());
+    state.in_flight_request_id = Some(5);
+    let base_before = state.base_query_for_suggestions.clone();
+
+    let returned = state.process_response(QueryResponse::Partial {
+        processed: make_processed_result(".slow", false),
+        request_id: 5,
+    });
+
+    assert!(returned.is_none());
+    assert!(state.is_pending());
+    assert!(state.is_partial());
+    assert_eq!(state.result, Ok("42".to_string()));
+    // Suggestions wait for the finished result
+    assert_eq!(state.base_query_for_suggestions, base_before);
+
+    let returned = state.process_response(QueryResponse::ProcessedSuccess {
+        processed: make_processed_result(".slow", false),
+        request_id: 5,
+    });
+    assert_eq!(returned, Some(".slow".to_string()));
+    assert!(!state.is_pending());
+    assert!(!state.is_partial());
+    assert_eq!(state.base_query_for_suggestions, Some(".slow".to_string()));
+}
+
+#[test]
+fn test_stale_partial_response_is_ignored() {
+    let mut state = QueryState::new(ASYNC_TEST_JSON.to_string());
+    state.in_flight_request_id = Some(5);
+    let result_before = state.result.clone();
+
+    state.process_response(QueryResponse::Partial {
+        processed: make_processed_result(".old", false),
+        request_id: 4,
+    });
+
+    assert!(!state.is_partial());
+    assert_eq!(state.result, result_before);
+}
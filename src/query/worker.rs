@@ -32,6 +32,7 @@
 //!     query: ".foo".to_string(),
 //!     request_id: 1,
 //!     cancel_token,
+//!     format: OutputFormat::default(),
 //! }).unwrap();
 //!
 //! // Receive response
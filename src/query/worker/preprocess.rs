@@ -71,6 +71,7 @@ pub fn preprocess_result(
         query: base_query,
         execution_time_ms: None,
         is_only_nulls,
+        truncated_query: None,
     })
 }
 
@@ -86,7 +87,7 @@ fn compute_line_metrics(output: &str) -> (u32, u16, Arc<Vec<u16>>, bool) {
 
     for line in output.lines() {
         line_count += 1;
-        let width = line.len().min(u16::MAX as usize);
+        let width = crate::str_utils::display_width(line).min(u16::MAX as usize);
         widths.push(width as u16);
         if width > max_width {
             max_width = width;
@@ -149,7 +150,7 @@ fn parse_ansi_to_rendered_lines(
                     let mut style = span.style;
                     style.bg = None;
                     RenderedSpan {
-                        content: span.content.to_string(),
+                        content: expand_tabs(&span.content).into_owned(),
                         style,
                     }
                 })
@@ -179,6 +180,9 @@ pub(crate) fn normalize_jq_text(text: Text<'static>) -> Text<'static> {
                         .into_iter()
                         .map(|mut span| {
                             span.style.bg = None;
+                            if span.content.contains('\t') {
+                                span.content = expand_tabs(&span.content).into_owned().into();
+                            }
                             span
                         })
                         .collect::<Vec<_>>(),
@@ -188,6 +192,17 @@ pub(crate) fn normalize_jq_text(text: Text<'static>) -> Text<'static> {
     )
 }
 
+/// Show `--tab` indentation as one space per tab. The terminal draws
+/// nothing for a tab, and a column each keeps the text lined up with the
+/// line widths, folds and search matches, which all count a tab as one.
+fn expand_tabs(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.contains('\t') {
+        text.replace('\t', " ").into()
+    } else {
+        text.into()
+    }
+}
+
 /// Strip ANSI escape codes from a string using SIMD-accelerated scanning
 ///
 /// jq outputs colored results with ANSI codes like:
@@ -1000,6 +1000,16 @@ fn test_parse_ansi_to_rendered_lines_cancelled_at_entry() {
     );
 }
 
+#[test]
+fn test_parse_ansi_to_rendered_lines_shows_tabs_as_one_column() {
+    let cancel_token = CancellationToken::new();
+
+    let lines = super::parse_ansi_to_rendered_lines("{\n\t\"a\": 1\n}", &cancel_token).unwrap();
+
+    let text: String = lines[1].spans.iter().map(|s| s.content.as_str()).collect();
+    assert_eq!(text, " \"a\": 1");
+}
+
 // ============================================================================
 // normalize_base_query Trailing-Pattern Tests
 // ============================================================================
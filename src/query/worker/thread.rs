@@ -5,12 +5,21 @@
 //! and sends responses back to the main thread.
 
 use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
 use std::sync::mpsc::{Receiver, Sender};
 use std::time::Instant;
 
 use super::preprocess::preprocess_result;
 use super::types::{QueryError, QueryRequest, QueryResponse};
+use crate::debug_log::LogSpan;
+use crate::input::InputText;
 use crate::query::executor::JqExecutor;
+use crate::query::jq_args::JqArgs;
+
+/// Largest output shown while the query runs; past it the pane waits for
+/// the finished result, since preprocessing it again and again would slow
+/// the query down
+const MAX_PARTIAL_BYTES: usize = 8 * 1024 * 1024;
 
 /// Spawn the query worker thread
 ///
@@ -25,12 +34,15 @@ use crate::query::executor::JqExecutor;
 /// * `json_input` - JSON input for query execution
 /// * `request_rx` - Channel to receive requests
 /// * `response_tx` - Channel to send responses
+/// * `jq_args` - `--rawfile` / `--slurpfile` bindings for every query
 pub fn spawn_worker(
-    json_input: String,
+    json_input: impl Into<InputText>,
     request_rx: Receiver<QueryRequest>,
     response_tx: Sender<QueryResponse>,
     array_sample_size: usize,
+    jq_args: Arc<JqArgs>,
 ) {
+    let json_input = json_input.into();
     std::thread::spawn(move || {
         // Set panic hook to prevent TUI corruption
         let response_tx_clone = response_tx.clone();
@@ -61,7 +73,13 @@ pub fn spawn_worker(
 
         // Wrap worker in catch_unwind
         let result = panic::catch_unwind(AssertUnwindSafe(|| {
-            worker_loop(&json_input, request_rx, response_tx, array_sample_size);
+            worker_loop(
+                json_input,
+                request_rx,
+                response_tx,
+                array_sample_size,
+                jq_args,
+            );
         }));
 
         // Restore panic hook
@@ -84,12 +102,14 @@ pub fn spawn_worker(
 ///
 /// Uses blocking recv() which is fine in dedicated thread.
 fn worker_loop(
-    json_input: &str,
+    json_input: InputText,
     request_rx: Receiver<QueryRequest>,
     response_tx: Sender<QueryResponse>,
     array_sample_size: usize,
+    jq_args: Arc<JqArgs>,
 ) {
-    let executor = JqExecutor::new_with_sample_size(json_input.to_string(), array_sample_size);
+    let executor =
+        JqExecutor::new_with_sample_size(json_input, array_sample_size).with_jq_args(jq_args);
 
     // Process requests until channel closes
     while let Ok(request) = request_rx.recv() {
@@ -104,6 +124,11 @@ fn handle_request(
     response_tx: &Sender<QueryResponse>,
     array_sample_size: usize,
 ) {
+    let _span = LogSpan::enter(
+        "query",
+        format_args!("{} {:?}", request.request_id, request.query),
+    );
+
     // Check if already cancelled
     if request.cancel_token.is_cancelled() {
         log::debug!("Query {} already cancelled", request.request_id);
@@ -118,11 +143,30 @@ fn handle_request(
     let start = Instant::now();
     log::debug!("Query {}: {:?}", request.request_id, query);
 
-    match executor.execute_with_cancel(&request.query, &request.cancel_token) {
-        Ok(output) => {
+    let on_partial = |so_far: String| {
+        if so_far.len() > MAX_PARTIAL_BYTES {
+            return;
+        }
+        if let Ok(processed) =
+            preprocess_result(so_far, &query, &request.cancel_token, array_sample_size)
+        {
+            let _ = response_tx.send(QueryResponse::Partial {
+                processed,
+                request_id: request.request_id,
+            });
+        }
+    };
+    match executor.execute_streaming(
+        &request.query,
+        request.format,
+        &request.cancel_token,
+        on_partial,
+    ) {
+        Ok((output, truncated)) => {
             // Preprocess result (expensive operations done in worker thread)
             match preprocess_result(output, &query, &request.cancel_token, array_sample_size) {
                 Ok(mut processed) => {
+                    processed.truncated_query = truncated.then(|| request.query.clone());
                     let elapsed = start.elapsed();
                     processed.execution_time_ms = Some(elapsed.as_millis() as u64);
                     log::debug!("Query {} completed in {:?}", request.request_id, elapsed);
@@ -6,6 +6,7 @@ use std::sync::mpsc::channel;
 use tokio_util::sync::CancellationToken;
 
 use crate::autocomplete::json_navigator::DEFAULT_ARRAY_SAMPLE_SIZE;
+use crate::query::output_format::OutputFormat;
 
 #[test]
 fn test_worker_spawns_successfully() {
@@ -19,6 +20,7 @@ fn test_worker_spawns_successfully() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     // Send a simple query
@@ -28,6 +30,7 @@ fn test_worker_spawns_successfully() {
             query: ".".to_string(),
             request_id: 1,
             cancel_token,
+            format: OutputFormat::default(),
         })
         .unwrap();
 
@@ -52,6 +55,7 @@ fn test_worker_handles_invalid_query() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     // Send an invalid query
@@ -61,6 +65,7 @@ fn test_worker_handles_invalid_query() {
             query: ".invalid syntax [".to_string(),
             request_id: 1,
             cancel_token,
+            format: OutputFormat::default(),
         })
         .unwrap();
 
@@ -85,6 +90,7 @@ fn test_worker_handles_pre_cancelled_request() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     // Cancel before sending
@@ -96,6 +102,7 @@ fn test_worker_handles_pre_cancelled_request() {
             query: ".".to_string(),
             request_id: 1,
             cancel_token,
+            format: OutputFormat::default(),
         })
         .unwrap();
 
@@ -120,6 +127,7 @@ fn test_worker_sends_error_response_for_jq_failure() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     // Send query with invalid syntax
@@ -129,6 +137,7 @@ fn test_worker_sends_error_response_for_jq_failure() {
             query: ".invalid syntax [".to_string(),
             request_id: 1,
             cancel_token,
+            format: OutputFormat::default(),
         })
         .unwrap();
 
@@ -159,6 +168,7 @@ fn test_worker_handles_multiple_rapid_queries() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     // Send multiple queries rapidly
@@ -169,6 +179,7 @@ fn test_worker_handles_multiple_rapid_queries() {
                 query: format!(".{}", if i % 2 == 0 { "a" } else { "b" }),
                 request_id: i,
                 cancel_token,
+                format: OutputFormat::default(),
             })
             .unwrap();
     }
@@ -184,6 +195,7 @@ fn test_worker_handles_multiple_rapid_queries() {
                 // Acceptable - query was cancelled
                 received_count += 1;
             }
+            Ok(QueryResponse::Partial { .. }) => panic!("Fast queries have no partial output"),
             Err(e) => panic!("Timeout after {} responses: {}", received_count, e),
         }
     }
@@ -202,6 +214,7 @@ fn test_worker_response_includes_original_query() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     let original_query = ".test";
@@ -211,6 +224,7 @@ fn test_worker_response_includes_original_query() {
             query: original_query.to_string(),
             request_id: 42,
             cancel_token,
+            format: OutputFormat::default(),
         })
         .unwrap();
 
@@ -242,6 +256,7 @@ fn test_worker_error_response_includes_original_query() {
         request_rx,
         response_tx,
         DEFAULT_ARRAY_SAMPLE_SIZE,
+        Arc::default(),
     );
 
     let original_query = ".invalid syntax [";
@@ -251,6 +266,7 @@ fn test_worker_error_response_includes_original_query() {
             query: original_query.to_string(),
             request_id: 99,
             cancel_token,
+            format: OutputFormat::default(),
         })
         .unwrap();
 
@@ -4,9 +4,11 @@
 //! These types enable request/response pattern with cancellation support.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio_util::sync::CancellationToken;
 
+use crate::query::output_format::OutputFormat;
 use crate::query::query_state::ResultType;
 
 /// Request to execute a jq query
@@ -18,6 +20,8 @@ pub struct QueryRequest {
     pub request_id: u64,
     /// Token for cancelling this request
     pub cancel_token: CancellationToken,
+    /// How jq pretty-prints the result
+    pub format: OutputFormat,
 }
 
 /// Pre-rendered span with style (Send-safe)
@@ -52,6 +56,9 @@ pub struct ProcessedResult {
     pub query: String,
     pub execution_time_ms: Option<u64>,
     pub is_only_nulls: bool,
+    /// The query, when jq was stopped at the output limit and `output` is
+    /// only the start of what it prints
+    pub truncated_query: Option<String>,
 }
 
 /// Response from query execution
@@ -64,6 +71,12 @@ pub enum QueryResponse {
         /// Request ID this response belongs to
         request_id: u64,
     },
+    /// Output so far of a query that is still running, shown until the
+    /// finished result replaces it
+    Partial {
+        processed: ProcessedResult,
+        request_id: u64,
+    },
     /// Query execution failed
     Error {
         /// Error message from jq stderr
@@ -81,6 +94,10 @@ pub enum QueryResponse {
     },
 }
 
+/// How a [`QueryError::TimedOut`] message starts, for telling it apart
+/// from jq's own errors
+pub const TIMED_OUT_PREFIX: &str = "query timed out after ";
+
 /// Error types for query execution
 #[derive(Debug, Clone)]
 pub enum QueryError {
@@ -92,6 +109,8 @@ pub enum QueryError {
     Cancelled,
     /// jq returned non-zero exit code
     ExecutionFailed(String),
+    /// jq was killed for running longer than the timeout
+    TimedOut(Duration),
 }
 
 impl std::fmt::Display for QueryError {
@@ -101,6 +120,9 @@ impl std::fmt::Display for QueryError {
             QueryError::OutputReadFailed(e) => write!(f, "Failed to read jq output: {}", e),
             QueryError::Cancelled => write!(f, "Query execution cancelled"),
             QueryError::ExecutionFailed(e) => write!(f, "{}", e),
+            QueryError::TimedOut(after) => {
+                write!(f, "{}{}s", TIMED_OUT_PREFIX, after.as_secs_f64())
+            }
         }
     }
 }
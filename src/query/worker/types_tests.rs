@@ -22,6 +22,7 @@ fn test_query_request_creation() {
         query: ".foo".to_string(),
         request_id: 42,
         cancel_token: cancel_token.clone(),
+        format: OutputFormat::default(),
     };
 
     assert_eq!(request.query, ".foo");
@@ -45,6 +46,7 @@ fn test_query_response_variants() {
         query: ".foo".to_string(),
         execution_time_ms: None,
         is_only_nulls: false,
+        truncated_query: None,
     };
     let response = QueryResponse::ProcessedSuccess {
         processed,
@@ -1,4 +1,44 @@
+pub mod chart;
+pub mod chart_events;
+pub mod chart_render;
+pub mod cursor_render;
 pub mod cursor_state;
+pub mod decode;
+pub mod decode_events;
+pub mod decode_render;
+pub mod diff_render;
 pub mod error_overlay_render;
+pub mod fold_render;
+pub mod fold_state;
+pub mod follow_events;
+pub mod format_menu_events;
+pub mod format_menu_render;
+pub mod frequency;
+pub mod frequency_events;
+pub mod frequency_render;
+pub mod last_stage;
+pub mod last_stage_events;
+pub mod last_stage_render;
+pub mod line_filter;
+pub mod line_filter_events;
+pub mod line_filter_render;
+pub mod malformed_render;
+pub mod marks;
+pub mod marks_events;
+pub mod match_highlights;
+pub mod match_highlights_render;
+pub mod path_view;
+pub mod path_view_events;
+pub mod path_view_render;
+pub mod pin_render;
+pub mod result_diff;
+pub mod result_pins;
 pub mod results_events;
 pub mod results_render;
+pub mod search_highlight_render;
+pub mod stream_view;
+pub mod stream_view_events;
+pub mod stream_view_render;
+pub mod table_events;
+pub mod table_render;
+pub mod table_view;
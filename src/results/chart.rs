@@ -0,0 +1,246 @@
+//! Glance-at charts of numeric results
+//!
+//! `#` in the results pane opens a popup charting the result when it is a
+//! list of numbers, such as `map(.price)` or the stream from `.[] | .age`,
+//! or a set of counts, such as the object `{"bug": 12, "docs": 3}` or the
+//! rows `[{"type": "bug", "count": 12}]` that `group_by` pipelines end in.
+//! Numbers show as a histogram or as a sparkline in their own order;
+//! counts show one bar per label.
+
+use serde_json::Value;
+
+/// Most bins a histogram is split into
+pub const MAX_BINS: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartData {
+    /// Numbers in result order
+    Series(Vec<f64>),
+    /// A number for each label, in result order
+    Labelled(Vec<(String, f64)>),
+}
+
+impl ChartData {
+    /// Read printed jq output: one array or object, or several values one
+    /// after another. Returns None unless it is all numbers or all
+    /// labelled numbers.
+    pub fn from_output(text: &str) -> Option<Self> {
+        let values = serde_json::Deserializer::from_str(text)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let items = match values.as_slice() {
+            [Value::Array(items)] => items.as_slice(),
+            [Value::Object(map)] => {
+                let counts = map
+                    .iter()
+                    .map(|(key, value)| Some((key.clone(), value.as_f64()?)))
+                    .collect::<Option<Vec<_>>>()?;
+                return (!counts.is_empty()).then_some(ChartData::Labelled(counts));
+            }
+            items => items,
+        };
+        if items.is_empty() {
+            return None;
+        }
+        if let Some(numbers) = items.iter().map(Value::as_f64).collect::<Option<Vec<_>>>() {
+            return Some(ChartData::Series(numbers));
+        }
+        items
+            .iter()
+            .map(labelled_number)
+            .collect::<Option<Vec<_>>>()
+            .map(ChartData::Labelled)
+    }
+
+    pub fn values(&self) -> Vec<f64> {
+        match self {
+            ChartData::Series(values) => values.clone(),
+            ChartData::Labelled(counts) => counts.iter().map(|(_, value)| *value).collect(),
+        }
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary::of(&self.values())
+    }
+}
+
+/// A `[label, number]` pair, or an object with one number field whose
+/// other fields are scalars that make up the label
+fn labelled_number(item: &Value) -> Option<(String, f64)> {
+    match item {
+        Value::Array(pair) => match pair.as_slice() {
+            [label, value] if !label.is_number() => Some((label_text(label)?, value.as_f64()?)),
+            _ => None,
+        },
+        Value::Object(map) => {
+            let mut numbers = map.values().filter_map(Value::as_f64);
+            let value = numbers.next()?;
+            if numbers.next().is_some() {
+                return None;
+            }
+            let label = map
+                .values()
+                .filter(|value| !value.is_number())
+                .map(label_text)
+                .collect::<Option<Vec<_>>>()?;
+            (!label.is_empty()).then(|| (label.join(" "), value))
+        }
+        _ => None,
+    }
+}
+
+fn label_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Array(_) | Value::Object(_) => None,
+        scalar => Some(scalar.to_string()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+impl Summary {
+    fn of(values: &[f64]) -> Self {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mean = values.iter().sum::<f64>() / values.len().max(1) as f64;
+        Self {
+            count: values.len(),
+            min,
+            max,
+            mean,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bin {
+    pub start: f64,
+    pub end: f64,
+    pub count: usize,
+}
+
+impl Bin {
+    pub fn label(&self) -> String {
+        if self.start == self.end {
+            format_number(self.start)
+        } else {
+            format!("{}–{}", format_number(self.start), format_number(self.end))
+        }
+    }
+}
+
+/// Count `values` into at most `max_bins` equal ranges. Whole numbers
+/// spanning few enough values get a bin each.
+pub fn histogram(values: &[f64], max_bins: usize) -> Vec<Bin> {
+    let Summary { min, max, .. } = Summary::of(values);
+    if values.is_empty() || max_bins == 0 {
+        return Vec::new();
+    }
+    let whole = values.iter().all(|value| value.fract() == 0.0);
+    let span = max - min;
+    if (whole || span == 0.0) && span < max_bins as f64 {
+        let mut bins: Vec<Bin> = (0..=span as usize)
+            .map(|offset| {
+                let value = min + offset as f64;
+                Bin {
+                    start: value,
+                    end: value,
+                    count: 0,
+                }
+            })
+            .collect();
+        for value in values {
+            bins[(value - min) as usize].count += 1;
+        }
+        return bins;
+    }
+
+    // Sturges' rule, which suits the small samples a result usually holds
+    let count = ((values.len() as f64).log2().ceil() as usize + 1).clamp(1, max_bins);
+    let width = span / count as f64;
+    let mut bins: Vec<Bin> = (0..count)
+        .map(|index| Bin {
+            start: min + width * index as f64,
+            end: min + width * (index + 1) as f64,
+            count: 0,
+        })
+        .collect();
+    for value in values {
+        let index = (((value - min) / width) as usize).min(count - 1);
+        bins[index].count += 1;
+    }
+    bins
+}
+
+/// Whole numbers without a fraction, others to at most two decimals
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{:.0}", value);
+    }
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartMode {
+    /// A histogram of numbers, or a bar for each label
+    #[default]
+    Bars,
+    /// Values in result order
+    Sparkline,
+}
+
+#[derive(Debug, Default)]
+pub struct ChartPopup {
+    data: Option<ChartData>,
+    mode: ChartMode,
+}
+
+impl ChartPopup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open on the chart of `text`. Returns false, staying closed, when it
+    /// has nothing to chart.
+    pub fn open(&mut self, text: &str) -> bool {
+        self.data = ChartData::from_output(text);
+        self.mode = ChartMode::Bars;
+        self.data.is_some()
+    }
+
+    pub fn close(&mut self) {
+        self.data = None;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.data.is_some()
+    }
+
+    pub fn data(&self) -> Option<&ChartData> {
+        self.data.as_ref()
+    }
+
+    pub fn mode(&self) -> ChartMode {
+        self.mode
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            ChartMode::Bars => ChartMode::Sparkline,
+            ChartMode::Sparkline => ChartMode::Bars,
+        };
+    }
+}
+
+#[cfg(test)]
+#[path = "chart_tests.rs"]
+mod chart_tests;
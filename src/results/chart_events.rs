@@ -0,0 +1,29 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+/// `#` — chart the current result when it is numbers or labelled numbers
+pub fn open_chart(app: &mut App) {
+    let source = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone());
+    let opened = source.is_some_and(|text| app.results_chart.open(&text));
+    if !opened {
+        app.notification
+            .show("Nothing to chart · needs numbers or labelled counts");
+    }
+}
+
+/// Keys while the chart popup is open; it takes them all
+pub fn handle_chart_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('#') => app.results_chart.close(),
+        KeyCode::Tab | KeyCode::BackTab => app.results_chart.toggle_mode(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "chart_events_tests.rs"]
+mod chart_events_tests;
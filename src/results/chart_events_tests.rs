@@ -0,0 +1,73 @@
+//! Tests for results/chart_events
+
+use crate::app::{App, Focus};
+use crate::results::chart::ChartMode;
+use crate::test_utils::test_helpers::{key, test_app};
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn app_showing(result: &str) -> App {
+    let mut app = test_app(result);
+    let arc = Arc::new(result.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(arc);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+#[test]
+fn test_hash_opens_the_chart() {
+    let mut app = app_showing("[3, 1, 4]");
+
+    app.handle_key_event(key(KeyCode::Char('#')));
+
+    assert!(app.results_chart.is_visible());
+}
+
+#[test]
+fn test_hash_on_a_result_without_numbers_explains_why() {
+    let mut app = app_showing(r#"{"name": "jiq"}"#);
+
+    app.handle_key_event(key(KeyCode::Char('#')));
+
+    assert!(!app.results_chart.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing to chart · needs numbers or labelled counts")
+    );
+}
+
+#[test]
+fn test_tab_switches_to_the_sparkline() {
+    let mut app = app_showing("[3, 1, 4]");
+    app.handle_key_event(key(KeyCode::Char('#')));
+
+    app.handle_key_event(key(KeyCode::Tab));
+
+    assert_eq!(app.results_chart.mode(), ChartMode::Sparkline);
+}
+
+#[test]
+fn test_esc_closes_the_chart() {
+    let mut app = app_showing("[3, 1, 4]");
+    app.handle_key_event(key(KeyCode::Char('#')));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.results_chart.is_visible());
+    assert_eq!(app.focus, Focus::ResultsPane);
+}
+
+#[test]
+fn test_chart_takes_keys_while_open() {
+    let mut app = app_showing("[3, 1, 4]");
+    app.handle_key_event(key(KeyCode::Char('#')));
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    assert!(app.results_chart.is_visible());
+    assert!(!app.results_table.is_active());
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+}
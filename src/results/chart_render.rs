@@ -0,0 +1,213 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph, Sparkline},
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::chart::{ChartData, ChartMode, ChartPopup, MAX_BINS, format_number, histogram};
+use crate::str_utils::truncate_to_width;
+use crate::theme;
+use crate::widgets::bar::bar;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const POPUP_WIDTH: u16 = 72;
+const SPARKLINE_HEIGHT: u16 = 10;
+const MAX_LABEL_WIDTH: usize = 20;
+/// Steps a sparkline column is scaled into
+const SPARKLINE_MAX: u64 = 1000;
+
+/// Render the chart popup centered over `area`
+pub fn render_popup(popup: &ChartPopup, frame: &mut Frame, area: Rect) {
+    let Some(data) = popup.data() else {
+        return;
+    };
+    let mode = popup.mode();
+    let rows = match mode {
+        ChartMode::Bars => bar_rows(data),
+        ChartMode::Sparkline => Vec::new(),
+    };
+    let content_height = match mode {
+        ChartMode::Bars => rows.len() as u16,
+        ChartMode::Sparkline => SPARKLINE_HEIGHT,
+    };
+    let popup_area = centered_rect(
+        area,
+        POPUP_WIDTH.min(area.width.saturating_sub(4)),
+        (content_height + 2).min(area.height),
+    );
+    if popup_area.width < 20 || popup_area.height < 3 {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(title(data, mode), &hints(data, mode))
+        .border_style(Style::default().fg(theme::results::chart_bar()))
+        .title_top(summary_line(data).alignment(Alignment::Right));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    match mode {
+        ChartMode::Bars => render_bars(frame, inner, &rows),
+        ChartMode::Sparkline => render_sparkline(frame, inner, &data.values()),
+    }
+}
+
+fn title(data: &ChartData, mode: ChartMode) -> String {
+    match (data, mode) {
+        (ChartData::Series(values), ChartMode::Bars) => {
+            format!(" Histogram · {} values ", values.len())
+        }
+        (ChartData::Labelled(counts), ChartMode::Bars) => {
+            format!(" Bars · {} labels ", counts.len())
+        }
+        (_, ChartMode::Sparkline) => format!(" Sparkline · {} values ", data.values().len()),
+    }
+}
+
+fn summary_line(data: &ChartData) -> Line<'static> {
+    let summary = data.summary();
+    Line::from(Span::styled(
+        format!(
+            " min {} · max {} · mean {} ",
+            format_number(summary.min),
+            format_number(summary.max),
+            format_number(summary.mean)
+        ),
+        Style::default().fg(theme::results::fold_marker()),
+    ))
+}
+
+fn hints(data: &ChartData, mode: ChartMode) -> Vec<(&'static str, &'static str)> {
+    let other = match (data, mode) {
+        (_, ChartMode::Bars) => "Sparkline",
+        (ChartData::Series(_), ChartMode::Sparkline) => "Histogram",
+        (ChartData::Labelled(_), ChartMode::Sparkline) => "Bars",
+    };
+    vec![("Tab", other), ("Esc", "Close")]
+}
+
+/// One row per histogram bin or label: label, length and value shown
+fn bar_rows(data: &ChartData) -> Vec<(String, f64, String)> {
+    match data {
+        ChartData::Series(values) => histogram(values, MAX_BINS)
+            .into_iter()
+            .map(|bin| (bin.label(), bin.count as f64, bin.count.to_string()))
+            .collect(),
+        ChartData::Labelled(counts) => counts
+            .iter()
+            .map(|(label, value)| (label.clone(), *value, format_number(*value)))
+            .collect(),
+    }
+}
+
+fn render_bars(frame: &mut Frame, area: Rect, rows: &[(String, f64, String)]) {
+    let height = area.height as usize;
+    // The last row tells how many did not fit
+    let (shown, hidden) = if rows.len() > height {
+        let shown = height.saturating_sub(1);
+        (&rows[..shown], rows.len() - shown)
+    } else {
+        (rows, 0)
+    };
+
+    let label_width = shown
+        .iter()
+        .map(|(label, ..)| label.width())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    let value_width = shown
+        .iter()
+        .map(|(.., value)| value.width())
+        .max()
+        .unwrap_or(0);
+    let bar_width = (area.width as usize).saturating_sub(label_width + value_width + 2);
+    let longest = shown
+        .iter()
+        .map(|(_, length, _)| *length)
+        .fold(0.0, f64::max);
+
+    let bar_style = Style::default().fg(theme::results::chart_bar());
+    let muted = Style::default().fg(theme::results::fold_marker());
+    let mut lines: Vec<Line> = shown
+        .iter()
+        .map(|(label, length, value)| {
+            let label = truncate_to_width(label, label_width);
+            let fill = " ".repeat(label_width - label.width());
+            let bar = if longest > 0.0 && *length > 0.0 {
+                bar(length / longest * bar_width as f64)
+            } else {
+                String::new()
+            };
+            Line::from(vec![
+                Span::raw(format!("{}{} ", label, fill)),
+                Span::styled(bar.clone(), bar_style),
+                Span::raw(" ".repeat((bar_width + 1).saturating_sub(bar.chars().count()))),
+                Span::styled(format!("{:>width$}", value, width = value_width), muted),
+            ])
+        })
+        .collect();
+    if hidden > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("… {} more", hidden),
+            muted,
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_sparkline(frame: &mut Frame, area: Rect, values: &[f64]) {
+    let [chart_area, caption_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(area);
+
+    // Several values share a column when there are more than fit, and each
+    // spans several columns when there are few
+    let width = chart_area.width.max(1) as usize;
+    let per_column = values.len().div_ceil(width).max(1);
+    let repeat = (width / values.len().max(1)).max(1);
+    let columns: Vec<f64> = values
+        .chunks(per_column)
+        .map(|chunk| chunk.iter().sum::<f64>() / chunk.len() as f64)
+        .flat_map(|value| std::iter::repeat_n(value, repeat))
+        .collect();
+    let min = columns.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = columns.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let span = max - min;
+    let scaled: Vec<u64> = columns
+        .iter()
+        .map(|value| {
+            if span > 0.0 {
+                ((value - min) / span * SPARKLINE_MAX as f64).round() as u64
+            } else {
+                SPARKLINE_MAX / 2
+            }
+        })
+        .collect();
+    frame.render_widget(
+        Sparkline::default()
+            .data(&scaled)
+            .max(SPARKLINE_MAX)
+            .style(Style::default().fg(theme::results::chart_bar())),
+        chart_area,
+    );
+
+    let caption = if per_column > 1 {
+        format!("In result order, {} values per column", per_column)
+    } else {
+        "In result order".to_string()
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            caption,
+            Style::default().fg(theme::results::fold_marker()),
+        )),
+        caption_area,
+    );
+}
+
+#[cfg(test)]
+#[path = "chart_render_tests.rs"]
+mod chart_render_tests;
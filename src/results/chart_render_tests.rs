@@ -0,0 +1,72 @@
+//! Tests for results/chart_render
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+
+use super::*;
+
+fn render_to_string(popup: &ChartPopup, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let area = Rect {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    };
+    terminal.draw(|f| render_popup(popup, f, area)).unwrap();
+    terminal.backend().to_string()
+}
+
+fn popup_of(text: &str) -> ChartPopup {
+    let mut popup = ChartPopup::new();
+    assert!(popup.open(text));
+    popup
+}
+
+#[test]
+fn snapshot_histogram() {
+    let popup = popup_of("[1, 2, 2, 3, 3, 3, 5]");
+    assert_snapshot!(render_to_string(&popup, 60, 10));
+}
+
+#[test]
+fn snapshot_labelled_bars() {
+    let popup = popup_of(r#"[{"type": "bug", "count": 12}, {"type": "docs", "count": 3}]"#);
+    assert_snapshot!(render_to_string(&popup, 60, 6));
+}
+
+#[test]
+fn snapshot_sparkline() {
+    let mut popup = popup_of("[1, 4, 2, 8, 5, 7, 3, 6]");
+    popup.toggle_mode();
+    assert_snapshot!(render_to_string(&popup, 60, 14));
+}
+
+#[test]
+fn rows_that_do_not_fit_are_counted() {
+    let values: Vec<String> = (0..10)
+        .map(|i| format!("[\"k{}\", {}]", i, i + 1))
+        .collect();
+    let popup = popup_of(&format!("[{}]", values.join(",")));
+
+    let output = render_to_string(&popup, 60, 6);
+
+    assert!(output.contains("k2"), "{output}");
+    assert!(!output.contains("k3"), "{output}");
+    assert!(output.contains("… 7 more"), "{output}");
+}
+
+#[test]
+fn closed_popup_draws_nothing() {
+    let popup = ChartPopup::new();
+    let output = render_to_string(&popup, 40, 6);
+    assert!(
+        output
+            .trim_matches(|c: char| c == ' ' || c == '"' || c == '\n')
+            .is_empty(),
+        "{output}"
+    );
+}
@@ -0,0 +1,160 @@
+//! Tests for results/chart
+
+use super::*;
+
+#[test]
+fn test_array_of_numbers_is_a_series() {
+    assert_eq!(
+        ChartData::from_output("[3, 1.5, 4]"),
+        Some(ChartData::Series(vec![3.0, 1.5, 4.0]))
+    );
+}
+
+#[test]
+fn test_stream_of_numbers_is_a_series() {
+    assert_eq!(
+        ChartData::from_output("3\n1\n4\n"),
+        Some(ChartData::Series(vec![3.0, 1.0, 4.0]))
+    );
+}
+
+#[test]
+fn test_object_of_numbers_is_labelled() {
+    assert_eq!(
+        ChartData::from_output(r#"{"bug": 12, "docs": 3}"#),
+        Some(ChartData::Labelled(vec![
+            ("bug".to_string(), 12.0),
+            ("docs".to_string(), 3.0)
+        ]))
+    );
+}
+
+#[test]
+fn test_rows_with_one_number_are_labelled_by_the_other_fields() {
+    let rows = r#"[{"type": "bug", "count": 12}, {"type": "docs", "open": true, "count": 3}]"#;
+
+    assert_eq!(
+        ChartData::from_output(rows),
+        Some(ChartData::Labelled(vec![
+            ("bug".to_string(), 12.0),
+            ("docs true".to_string(), 3.0)
+        ]))
+    );
+}
+
+#[test]
+fn test_label_number_pairs_are_labelled() {
+    assert_eq!(
+        ChartData::from_output(r#"[["a", 1], [null, 2]]"#),
+        Some(ChartData::Labelled(vec![
+            ("a".to_string(), 1.0),
+            ("null".to_string(), 2.0)
+        ]))
+    );
+}
+
+#[test]
+fn test_results_without_numbers_to_chart_are_refused() {
+    for text in [
+        "[]",
+        "{}",
+        "\"text\"",
+        r#"[1, "two"]"#,
+        r#"{"a": 1, "b": "x"}"#,
+        r#"[{"a": 1, "b": 2}]"#,
+        r#"[{"a": 1}]"#,
+        r#"[{"a": {"b": 1}, "n": 1}]"#,
+        "[[1, 2]]",
+        "not json",
+    ] {
+        assert_eq!(ChartData::from_output(text), None, "{}", text);
+    }
+}
+
+#[test]
+fn test_summary() {
+    let summary = ChartData::Series(vec![2.0, 8.0, 5.0]).summary();
+
+    assert_eq!(summary.count, 3);
+    assert_eq!(summary.min, 2.0);
+    assert_eq!(summary.max, 8.0);
+    assert_eq!(summary.mean, 5.0);
+}
+
+#[test]
+fn test_whole_numbers_with_a_small_range_get_a_bin_each() {
+    let bins = histogram(&[1.0, 3.0, 3.0, 2.0, 3.0], MAX_BINS);
+
+    let counts: Vec<(String, usize)> = bins.iter().map(|b| (b.label(), b.count)).collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("1".to_string(), 1),
+            ("2".to_string(), 1),
+            ("3".to_string(), 3)
+        ]
+    );
+}
+
+#[test]
+fn test_wide_ranges_are_split_into_equal_bins() {
+    let values: Vec<f64> = (0..100).map(|v| v as f64).collect();
+
+    let bins = histogram(&values, MAX_BINS);
+
+    // Sturges: ceil(log2 100) + 1
+    assert_eq!(bins.len(), 8);
+    assert_eq!(bins.iter().map(|b| b.count).sum::<usize>(), 100);
+    assert_eq!(bins[0].start, 0.0);
+    assert_eq!(bins[7].end, 99.0);
+    assert_eq!(bins[7].count, 13);
+}
+
+#[test]
+fn test_bins_are_capped() {
+    let values: Vec<f64> = (0..100_000).map(|v| v as f64).collect();
+
+    assert_eq!(histogram(&values, 5).len(), 5);
+}
+
+#[test]
+fn test_equal_fractions_share_one_bin() {
+    let bins = histogram(&[0.5, 0.5], MAX_BINS);
+
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].count, 2);
+    assert_eq!(bins[0].label(), "0.5");
+}
+
+#[test]
+fn test_format_number() {
+    assert_eq!(format_number(12.0), "12");
+    assert_eq!(format_number(-3.0), "-3");
+    assert_eq!(format_number(2.5), "2.5");
+    assert_eq!(format_number(1.0 / 3.0), "0.33");
+}
+
+#[test]
+fn test_popup_opens_only_on_something_to_chart() {
+    let mut popup = ChartPopup::new();
+
+    assert!(!popup.open("\"text\""));
+    assert!(!popup.is_visible());
+    assert!(popup.open("[1, 2]"));
+    assert!(popup.is_visible());
+
+    popup.close();
+    assert!(!popup.is_visible());
+}
+
+#[test]
+fn test_mode_toggles_and_resets_on_open() {
+    let mut popup = ChartPopup::new();
+    popup.open("[1, 2]");
+
+    popup.toggle_mode();
+    assert_eq!(popup.mode(), ChartMode::Sparkline);
+
+    popup.open("[1, 2]");
+    assert_eq!(popup.mode(), ChartMode::Bars);
+}
@@ -0,0 +1,119 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::Paragraph,
+};
+
+use crate::results::cursor_state::CursorState;
+use crate::results::fold_state::FoldState;
+use crate::theme;
+
+pub fn apply_highlights(
+    text: Text<'_>,
+    cursor_state: &CursorState,
+    folds: &FoldState,
+    scroll_offset: u16,
+) -> Text<'static> {
+    // Compared as shown rows so a folded block highlights as one row
+    let cursor_line = folds.row_of(cursor_state.cursor_line());
+    let hovered_line = cursor_state.hovered_line().map(|line| folds.row_of(line));
+    let is_visual = cursor_state.is_visual_mode();
+    let (sel_start, sel_end) = cursor_state.selection_range();
+    let (sel_start, sel_end) = (folds.row_of(sel_start), folds.row_of(sel_end));
+
+    Text::from(
+        text.lines
+            .into_iter()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                let absolute_line = line_idx as u32 + scroll_offset as u32;
+
+                let bg_color =
+                    if is_visual && absolute_line >= sel_start && absolute_line <= sel_end {
+                        Some(theme::results::visual_selection_bg())
+                    } else if absolute_line == cursor_line {
+                        Some(theme::results::cursor_line_bg())
+                    } else if Some(absolute_line) == hovered_line {
+                        Some(theme::results::hovered_line_bg())
+                    } else {
+                        None
+                    };
+
+                if let Some(bg) = bg_color {
+                    Line::from(
+                        line.spans
+                            .into_iter()
+                            .map(|span| {
+                                let existing_bg = span.style.bg;
+                                let is_search_highlight = existing_bg
+                                    == Some(theme::results::current_match_bg())
+                                    || existing_bg == Some(theme::results::match_highlight_bg());
+
+                                if is_search_highlight {
+                                    Span::styled(span.content.into_owned(), span.style)
+                                } else {
+                                    Span::styled(span.content.into_owned(), span.style.bg(bg))
+                                }
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                } else {
+                    Line::from(
+                        line.spans
+                            .into_iter()
+                            .map(|span| Span::styled(span.content.into_owned(), span.style))
+                            .collect::<Vec<_>>(),
+                    )
+                }
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+pub fn render_indicator(
+    frame: &mut Frame,
+    results_area: Rect,
+    cursor_state: &CursorState,
+    folds: &FoldState,
+    scroll_offset: u16,
+    _h_offset: u16,
+) {
+    let cursor_line = folds.row_of(cursor_state.cursor_line());
+
+    if cursor_line < scroll_offset as u32 {
+        return;
+    }
+
+    let relative_line = cursor_line.saturating_sub(scroll_offset as u32) as u16;
+    let viewport_height = results_area.height.saturating_sub(2);
+
+    if relative_line >= viewport_height {
+        return;
+    }
+
+    let indicator_x = results_area.x;
+    let indicator_y = results_area
+        .y
+        .saturating_add(1)
+        .saturating_add(relative_line);
+
+    let indicator = Span::styled(
+        "▌",
+        Style::default().fg(theme::results::cursor_indicator_fg()),
+    );
+    frame.render_widget(
+        Paragraph::new(Line::from(indicator)),
+        Rect {
+            x: indicator_x,
+            y: indicator_y,
+            width: 1,
+            height: 1,
+        },
+    );
+}
+
+#[cfg(test)]
+#[path = "cursor_render_tests.rs"]
+mod cursor_render_tests;
@@ -0,0 +1,121 @@
+//! Tests for results/cursor_render
+
+use super::apply_highlights;
+use super::render_indicator;
+use crate::results::cursor_state::CursorState;
+use crate::results::fold_state::FoldState;
+use crate::theme;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+
+fn text_three_lines() -> Text<'static> {
+    Text::from(vec![
+        Line::from(Span::styled("line0", Style::default())),
+        Line::from(Span::styled("line1", Style::default())),
+        Line::from(Span::styled("line2", Style::default())),
+    ])
+}
+
+#[test]
+fn apply_cursor_highlights_visual_and_hover_backgrounds() {
+    // Visual selection: enter visual at line 0, move down to line 1 so the
+    // range covers lines 0..=1. Both selected lines get visual_selection_bg.
+    let mut cursor = CursorState::new();
+    cursor.update_total_lines(3);
+    cursor.enter_visual_mode();
+    cursor.move_down(1);
+    assert!(cursor.is_visual_mode());
+    assert_eq!(cursor.selection_range(), (0, 1));
+
+    let out = apply_highlights(text_three_lines(), &cursor, &FoldState::new(), 0);
+    assert_eq!(
+        out.lines[0].spans[0].style.bg,
+        Some(theme::results::visual_selection_bg()),
+        "line 0 is in the visual selection range"
+    );
+    assert_eq!(
+        out.lines[1].spans[0].style.bg,
+        Some(theme::results::visual_selection_bg()),
+        "line 1 is in the visual selection range"
+    );
+    // Line 2 is outside the selection and not the cursor/hover -> no bg.
+    assert_eq!(out.lines[2].spans[0].style.bg, None);
+
+    // Hover (normal mode): hovering a line that is not the cursor row paints
+    // hovered_line_bg. Cursor sits at line 0; hover line 2.
+    let mut cursor2 = CursorState::new();
+    cursor2.update_total_lines(3);
+    cursor2.set_hovered(Some(2));
+    assert!(!cursor2.is_visual_mode());
+
+    let out2 = apply_highlights(text_three_lines(), &cursor2, &FoldState::new(), 0);
+    assert_eq!(
+        out2.lines[2].spans[0].style.bg,
+        Some(theme::results::hovered_line_bg()),
+        "the hovered non-cursor line must carry hovered_line_bg"
+    );
+    // The cursor line (0) takes cursor_line_bg, distinct from hover.
+    assert_eq!(
+        out2.lines[0].spans[0].style.bg,
+        Some(theme::results::cursor_line_bg()),
+    );
+    assert_ne!(
+        theme::results::hovered_line_bg(),
+        theme::results::cursor_line_bg(),
+        "hover and cursor backgrounds must differ for the assertion to bite"
+    );
+}
+
+const INDICATOR: &str = "▌";
+
+fn render_with(cursor: &CursorState, scroll_offset: u16, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            let area = Rect::new(0, 0, width, height);
+            render_indicator(frame, area, cursor, &FoldState::new(), scroll_offset, 0);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn render_cursor_indicator_offscreen_early_returns() {
+    // Above the viewport: cursor_line (1) < scroll_offset (5) -> nothing drawn.
+    let mut above = CursorState::new();
+    above.update_total_lines(100);
+    above.move_to_line(1);
+    let out_above = render_with(&above, 5, 20, 12);
+    assert!(
+        !out_above.contains(INDICATOR),
+        "no indicator when cursor is above the scroll offset:\n{}",
+        out_above
+    );
+
+    // Below the viewport: with height 12, viewport_height = 10. A cursor at
+    // relative_line >= 10 (here line 50, offset 0) sits past the viewport.
+    let mut below = CursorState::new();
+    below.update_total_lines(100);
+    below.move_to_line(50);
+    let out_below = render_with(&below, 0, 20, 12);
+    assert!(
+        !out_below.contains(INDICATOR),
+        "no indicator when cursor is below the viewport:\n{}",
+        out_below
+    );
+
+    // In view: cursor at line 3, offset 0 -> indicator is painted.
+    let mut visible = CursorState::new();
+    visible.update_total_lines(100);
+    visible.move_to_line(3);
+    let out_visible = render_with(&visible, 0, 20, 12);
+    assert!(
+        out_visible.contains(INDICATOR),
+        "indicator must paint when the cursor is within the viewport:\n{}",
+        out_visible
+    );
+}
@@ -0,0 +1,239 @@
+//! Encoded string values
+//!
+//! APIs often carry a value inside a string: JSON as an escaped string,
+//! such as a webhook body, bytes as base64, or URL parts percent-encoded.
+//! jq prints these as one opaque line. `E` on such a string in the
+//! results pane opens a popup with what it decodes to, and Enter rewrites
+//! the query to decode it in place, as `PATH |= fromjson` or
+//! `PATH |= @base64d`.
+
+use base64::Engine;
+use base64::engine::DecodePaddingMode;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use serde_json::Value;
+
+use crate::json_path::JsonPath;
+
+/// Shortest string taken for base64; shorter ones are more often words
+const MIN_BASE64_LEN: usize = 8;
+
+/// jq has no percent-decoding builtin, so each escape is rebuilt from its
+/// hex digits. Being one codepoint per byte, it is only right for ASCII.
+const PERCENT_FILTER: &str = r#"gsub("%(?<h>[0-9A-Fa-f]{2})"; .h | ascii_downcase | explode | map(if . > 57 then . - 87 else . - 48 end) | [.[0] * 16 + .[1]] | implode)"#;
+
+/// Standard alphabet, with or without the `=` padding, as `@base64d` reads
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// How a string's value is encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Json,
+    Base64,
+    Percent,
+}
+
+impl Encoding {
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Json => "JSON string",
+            Encoding::Base64 => "Base64",
+            Encoding::Percent => "Percent-encoded",
+        }
+    }
+}
+
+/// What a string decodes to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    pub encoding: Encoding,
+    /// The decoded text, pretty-printed when it is JSON
+    pub lines: Vec<String>,
+    pub is_json: bool,
+    /// The jq that decodes it, when jq can
+    pub filter: Option<String>,
+}
+
+/// Decode `text` as an embedded JSON object or array, base64, or percent
+/// escapes. Strings that would only decode to a bare number, boolean or
+/// garbled bytes are left alone.
+pub fn decode(text: &str) -> Option<Decoded> {
+    if let Some(value) = json(text) {
+        return Some(Decoded {
+            encoding: Encoding::Json,
+            lines: pretty_lines(&value),
+            is_json: true,
+            filter: Some("fromjson".to_string()),
+        });
+    }
+    let (encoding, decoded, filter) = if let Some((decoded, ascii)) = percent(text) {
+        (Encoding::Percent, decoded, ascii.then_some(PERCENT_FILTER))
+    } else {
+        (Encoding::Base64, base64(text)?, Some("@base64d"))
+    };
+    // Encoded JSON is shown, and decoded, as JSON
+    Some(match json(&decoded) {
+        Some(value) => Decoded {
+            encoding,
+            lines: pretty_lines(&value),
+            is_json: true,
+            filter: filter.map(|f| format!("{} | fromjson", f)),
+        },
+        None => Decoded {
+            encoding,
+            lines: decoded.lines().map(str::to_string).collect(),
+            is_json: false,
+            filter: filter.map(str::to_string),
+        },
+    })
+}
+
+/// The object or array a string holds, if it holds one
+fn json(text: &str) -> Option<Value> {
+    let trimmed = text.trim_start();
+    if !trimmed.starts_with('{') && !trimmed.starts_with('[') {
+        return None;
+    }
+    serde_json::from_str(text).ok()
+}
+
+/// Base64 that decodes to readable UTF-8 text
+fn base64(text: &str) -> Option<String> {
+    if text.len() < MIN_BASE64_LEN {
+        return None;
+    }
+    let bytes = BASE64.decode(text).ok()?;
+    let decoded = String::from_utf8(bytes).ok()?;
+    readable(&decoded).then_some(decoded)
+}
+
+/// Percent escapes decoded, and whether every escape was ASCII. Needs at
+/// least one escape, and every `%` to start one.
+fn percent(text: &str) -> Option<(String, bool)> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut escapes = 0;
+    let mut ascii = true;
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            let decoded = u8::from_str_radix(hex, 16).ok()?;
+            ascii &= decoded.is_ascii();
+            bytes.push(decoded);
+            escapes += 1;
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    if escapes == 0 {
+        return None;
+    }
+    let decoded = String::from_utf8(bytes).ok()?;
+    readable(&decoded).then_some((decoded, ascii))
+}
+
+/// Text without control characters other than line breaks and tabs
+fn readable(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+}
+
+fn pretty_lines(value: &Value) -> Vec<String> {
+    serde_json::to_string_pretty(value)
+        .unwrap_or_default()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+/// The `E` popup
+#[derive(Debug, Default)]
+pub struct DecodePopup {
+    /// Where the string is in the result
+    path: JsonPath,
+    decoded: Option<Decoded>,
+    scroll: usize,
+    /// Rows shown at the last render, for paging and clamping
+    viewport: usize,
+}
+
+impl DecodePopup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open on what the string at `path` decodes to. Returns false,
+    /// staying closed, when `value` is not an encoded string.
+    pub fn open(&mut self, path: JsonPath, value: &Value) -> bool {
+        let Some(decoded) = value.as_str().and_then(decode) else {
+            return false;
+        };
+        self.decoded = Some(decoded);
+        self.path = path;
+        self.scroll = 0;
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.decoded = None;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.decoded.is_some()
+    }
+
+    pub fn path(&self) -> &JsonPath {
+        &self.path
+    }
+
+    pub fn decoded(&self) -> Option<&Decoded> {
+        self.decoded.as_ref()
+    }
+
+    pub fn lines(&self) -> &[String] {
+        self.decoded.as_ref().map_or(&[], |d| &d.lines)
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    pub fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    pub fn set_viewport(&mut self, rows: usize) {
+        self.viewport = rows;
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.lines().len().saturating_sub(self.viewport)
+    }
+
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = (self.scroll + rows).min(self.max_scroll());
+    }
+
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+}
+
+#[cfg(test)]
+#[path = "decode_tests.rs"]
+mod decode_tests;
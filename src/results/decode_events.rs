@@ -0,0 +1,59 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::json_path::value_at;
+use crate::path_at_cursor_apply::apply_decode;
+
+/// `E` — show what the JSON, base64 or percent-encoded string at the
+/// cursor holds
+pub fn open(app: &mut App) {
+    let value = app.current_cursor_path().and_then(|path| {
+        let parsed = app.query.as_ref()?.last_successful_result_parsed.clone()?;
+        let value = value_at(&parsed, &path)?.clone();
+        Some((path, value))
+    });
+    let opened = value.is_some_and(|(path, value)| app.results_decode.open(path, &value));
+    if !opened {
+        app.notification
+            .show("Nothing to decode at cursor · needs a JSON, base64 or percent-encoded string");
+    }
+}
+
+/// Keys while the decoded view is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let popup = &mut app.results_decode;
+    let page = popup.viewport().max(1);
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('E') => popup.close(),
+        KeyCode::Enter => {
+            let path = popup.path().clone();
+            let filter = popup.decoded().and_then(|d| d.filter.clone());
+            popup.close();
+            match filter {
+                Some(filter) => {
+                    apply_decode(app, &path, &filter);
+                }
+                None => app
+                    .notification
+                    .show("jq can't decode percent escapes beyond ASCII"),
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => popup.scroll_down(1),
+        KeyCode::Up | KeyCode::Char('k') => popup.scroll_up(1),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            popup.scroll_down(page / 2)
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            popup.scroll_up(page / 2)
+        }
+        KeyCode::PageDown => popup.scroll_down(page),
+        KeyCode::PageUp => popup.scroll_up(page),
+        KeyCode::Home | KeyCode::Char('g') => popup.scroll_to_top(),
+        KeyCode::End | KeyCode::Char('G') => popup.scroll_to_bottom(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "decode_events_tests.rs"]
+mod decode_events_tests;
@@ -0,0 +1,119 @@
+//! Tests for results/decode_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{execute_query_and_wait, key, test_app};
+use ratatui::crossterm::event::KeyCode;
+
+const EVENT: &str = r#"{"id": 1, "payload": "{\"user\": \"ada\", \"tags\": [\"a\"]}"}"#;
+
+fn app_on_line(json: &str, line: u32) -> App {
+    let mut app = test_app(json);
+    execute_query_and_wait(&mut app);
+    app.focus = Focus::ResultsPane;
+    app.results_cursor
+        .update_total_lines(app.results_line_count_u32());
+    app.results_cursor.move_to_line(line);
+    app
+}
+
+#[test]
+fn test_e_on_a_json_string_opens_the_decoded_view() {
+    let mut app = app_on_line(EVENT, 2);
+
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    assert!(app.results_decode.is_visible());
+    assert_eq!(app.results_decode.path().to_jq(), ".payload");
+}
+
+#[test]
+fn test_e_elsewhere_explains_why() {
+    let mut app = app_on_line(EVENT, 1);
+
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    assert!(!app.results_decode.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing to decode at cursor · needs a JSON, base64 or percent-encoded string")
+    );
+}
+
+#[test]
+fn test_enter_decodes_in_place() {
+    let mut app = app_on_line(EVENT, 2);
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.results_decode.is_visible());
+    assert_eq!(app.query(), ".payload |= fromjson");
+}
+
+#[test]
+fn test_enter_composes_onto_the_query_and_back_undoes() {
+    let mut app = test_app(&format!("[{}]", EVENT));
+    app.input.textarea.insert_str(".[0]");
+    execute_query_and_wait(&mut app);
+    app.focus = Focus::ResultsPane;
+    app.results_cursor
+        .update_total_lines(app.results_line_count_u32());
+    app.results_cursor.move_to_line(2);
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+    assert_eq!(app.query(), ".[0] | .payload |= fromjson");
+
+    app.handle_key_event(key(KeyCode::Char('<')));
+    assert_eq!(app.query(), ".[0]");
+}
+
+#[test]
+fn test_enter_decodes_base64_json_in_place() {
+    let mut app = app_on_line(r#"{"token": "eyJpZCI6IDd9"}"#, 1);
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.query(), ".token |= (@base64d | fromjson)");
+}
+
+#[test]
+fn test_enter_decodes_percent_escapes_in_place() {
+    let mut app = app_on_line(r#"{"q": "a%20b"}"#, 1);
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.query().starts_with(".q |= gsub("));
+}
+
+#[test]
+fn test_enter_explains_non_ascii_percent_escapes() {
+    let mut app = app_on_line(r#"{"q": "caf%C3%A9"}"#, 1);
+    app.handle_key_event(key(KeyCode::Char('E')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.results_decode.is_visible());
+    assert_eq!(app.query(), "");
+    assert_eq!(
+        app.notification.current_message(),
+        Some("jq can't decode percent escapes beyond ASCII")
+    );
+}
+
+#[test]
+fn test_popup_takes_keys_until_closed() {
+    let mut app = app_on_line(EVENT, 2);
+    app.handle_key_event(key(KeyCode::Char('E')));
+    app.results_decode.set_viewport(1);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    assert_eq!(app.results_decode.scroll(), 1);
+    assert_eq!(app.results_cursor.cursor_line(), 2);
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.results_decode.is_visible());
+    assert_eq!(app.focus, Focus::ResultsPane);
+}
@@ -0,0 +1,73 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::Line,
+    widgets::{Clear, Paragraph},
+};
+
+use super::decode::{DecodePopup, Decoded};
+use crate::syntax_highlight::JqHighlighter;
+use crate::widgets::popup::{centered_rect, popup_block};
+use crate::widgets::scrollbar;
+
+const POPUP_WIDTH: u16 = 80;
+const MAX_HEIGHT: u16 = 30;
+
+/// Render the decoded value centered over `area`
+pub fn render_popup(popup: &mut DecodePopup, frame: &mut Frame, area: Rect) {
+    let Some(decoded) = popup.decoded().cloned() else {
+        return;
+    };
+    let height = (popup.lines().len() as u16)
+        .saturating_add(2)
+        .min(MAX_HEIGHT)
+        .min(area.height);
+    let popup_area = centered_rect(area, POPUP_WIDTH.min(area.width.saturating_sub(4)), height);
+    if popup_area.width < 20 || popup_area.height < 3 {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(
+        format!(" {} · {} ", decoded.encoding.name(), popup.path().to_jq()),
+        &hints(&decoded),
+    );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    popup.set_viewport(inner.height as usize);
+    let lines: Vec<Line> = popup
+        .lines()
+        .iter()
+        .skip(popup.scroll())
+        .take(inner.height as usize)
+        .map(|line| {
+            if decoded.is_json {
+                Line::from(JqHighlighter::highlight(line))
+            } else {
+                Line::raw(line.clone())
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+    scrollbar::render_vertical_scrollbar(
+        frame,
+        popup_area,
+        popup.lines().len(),
+        inner.height as usize,
+        popup.scroll(),
+    );
+}
+
+fn hints(decoded: &Decoded) -> Vec<(&'static str, &'static str)> {
+    let mut hints = Vec::new();
+    if decoded.filter.is_some() {
+        hints.push(("Enter", "Decode in query"));
+    }
+    hints.extend([("j/k", "Scroll"), ("Esc", "Close")]);
+    hints
+}
+
+#[cfg(test)]
+#[path = "decode_render_tests.rs"]
+mod decode_render_tests;
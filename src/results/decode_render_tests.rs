@@ -0,0 +1,62 @@
+//! Tests for results/decode_render
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use serde_json::json;
+
+use super::*;
+use crate::json_path::JsonPath;
+
+fn render_to_string(popup: &mut DecodePopup, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let area = Rect::new(0, 0, width, height);
+    terminal.draw(|f| render_popup(popup, f, area)).unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_json_string() {
+    let mut popup = DecodePopup::new();
+    let mut path = JsonPath::new();
+    path.push_key("events");
+    path.push_index(0);
+    path.push_key("payload");
+    popup.open(path, &json!(r#"{"user":"ada","tags":["a","b"]}"#));
+
+    assert_snapshot!(render_to_string(&mut popup, 60, 12));
+}
+
+#[test]
+fn snapshot_base64_text() {
+    let mut popup = DecodePopup::new();
+    let mut path = JsonPath::new();
+    path.push_key("note");
+    popup.open(path, &json!("aGVsbG8gd29ybGQ="));
+
+    assert_snapshot!(render_to_string(&mut popup, 60, 5));
+}
+
+#[test]
+fn non_ascii_percent_escapes_offer_no_enter() {
+    let mut popup = DecodePopup::new();
+    popup.open(JsonPath::new(), &json!("caf%C3%A9"));
+
+    let output = render_to_string(&mut popup, 60, 5);
+
+    assert!(output.contains("Percent-encoded"));
+    assert!(output.contains("café"));
+    assert!(!output.contains("Enter"));
+}
+
+#[test]
+fn test_render_records_the_viewport() {
+    let mut popup = DecodePopup::new();
+    popup.open(JsonPath::new(), &json!("[1, 2, 3, 4]"));
+
+    render_to_string(&mut popup, 60, 5);
+
+    assert_eq!(popup.viewport(), 3);
+}
@@ -0,0 +1,117 @@
+//! Tests for results/decode
+
+use serde_json::json;
+
+use super::*;
+
+#[test]
+fn decode_reads_json_objects_and_arrays() {
+    let decoded = decode(r#"{"a": 1}"#).unwrap();
+
+    assert_eq!(decoded.encoding, Encoding::Json);
+    assert_eq!(decoded.lines, ["{", "  \"a\": 1", "}"]);
+    assert_eq!(decoded.filter.as_deref(), Some("fromjson"));
+    assert_eq!(decode(" [1, 2]").unwrap().encoding, Encoding::Json);
+}
+
+#[test]
+fn decode_leaves_plain_strings_alone() {
+    assert_eq!(decode("42"), None);
+    assert_eq!(decode("true"), None);
+    assert_eq!(decode("{not json"), None);
+    assert_eq!(decode("[1, 2] and more"), None);
+    assert_eq!(decode("hello world"), None);
+}
+
+#[test]
+fn decode_reads_base64_text() {
+    let decoded = decode("aGVsbG8gd29ybGQ=").unwrap();
+
+    assert_eq!(decoded.encoding, Encoding::Base64);
+    assert_eq!(decoded.lines, ["hello world"]);
+    assert!(!decoded.is_json);
+    assert_eq!(decoded.filter.as_deref(), Some("@base64d"));
+}
+
+#[test]
+fn decode_reads_unpadded_base64() {
+    assert_eq!(decode("aGVsbG8gd29ybGQ").unwrap().lines, ["hello world"]);
+}
+
+#[test]
+fn base64_json_is_decoded_as_json() {
+    let decoded = decode("eyJpZCI6IDd9").unwrap();
+
+    assert_eq!(decoded.encoding, Encoding::Base64);
+    assert!(decoded.is_json);
+    assert_eq!(decoded.lines, ["{", "  \"id\": 7", "}"]);
+    assert_eq!(decoded.filter.as_deref(), Some("@base64d | fromjson"));
+}
+
+#[test]
+fn words_and_binary_are_not_base64() {
+    // Valid base64 alphabet, but the bytes are not text
+    assert_eq!(decode("password"), None);
+    assert_eq!(decode("12345678"), None);
+    assert_eq!(decode("deadbeef"), None);
+    // Too short to tell
+    assert_eq!(decode("aGk="), None);
+}
+
+#[test]
+fn decode_reads_percent_escapes() {
+    let decoded = decode("a%20b%2Fc%3D").unwrap();
+
+    assert_eq!(decoded.encoding, Encoding::Percent);
+    assert_eq!(decoded.lines, ["a b/c="]);
+    assert!(decoded.filter.unwrap().starts_with("gsub("));
+}
+
+#[test]
+fn non_ascii_percent_escapes_preview_without_a_filter() {
+    let decoded = decode("caf%C3%A9").unwrap();
+
+    assert_eq!(decoded.lines, ["café"]);
+    assert_eq!(decoded.filter, None);
+}
+
+#[test]
+fn stray_percent_is_not_an_escape() {
+    assert_eq!(decode("100% sure"), None);
+    assert_eq!(decode("50%"), None);
+}
+
+#[test]
+fn open_shows_the_decoded_lines() {
+    let mut popup = DecodePopup::new();
+    let mut path = JsonPath::new();
+    path.push_key("body");
+
+    assert!(popup.open(path.clone(), &json!(r#"{"id":7}"#)));
+
+    assert!(popup.is_visible());
+    assert_eq!(popup.path(), &path);
+    assert_eq!(popup.lines(), ["{", "  \"id\": 7", "}"]);
+}
+
+#[test]
+fn open_refuses_values_that_are_not_encoded_strings() {
+    let mut popup = DecodePopup::new();
+
+    assert!(!popup.open(JsonPath::new(), &json!({"id": 7})));
+    assert!(!popup.open(JsonPath::new(), &json!("plain text")));
+    assert!(!popup.is_visible());
+}
+
+#[test]
+fn scroll_stops_at_the_last_page() {
+    let mut popup = DecodePopup::new();
+    popup.open(JsonPath::new(), &json!("[1, 2, 3, 4]"));
+    popup.set_viewport(4);
+
+    popup.scroll_down(10);
+    assert_eq!(popup.scroll(), 2);
+
+    popup.scroll_up(1);
+    assert_eq!(popup.scroll(), 1);
+}
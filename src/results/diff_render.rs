@@ -0,0 +1,75 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::Paragraph,
+};
+
+use crate::results::fold_state::FoldState;
+use crate::results::result_diff::DiffHighlight;
+use crate::theme;
+
+/// Background on lines the last query edit added
+pub fn apply_highlights(
+    text: Text<'static>,
+    diff: &DiffHighlight,
+    folds: &FoldState,
+    scroll_offset: u16,
+) -> Text<'static> {
+    let added_bg = theme::results::diff_added_bg();
+    let lines = text
+        .lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut line)| {
+            let row = index as u32 + scroll_offset as u32;
+            if folds.line_at(row).is_some_and(|line| diff.is_added(line)) {
+                for span in &mut line.spans {
+                    span.style = span.style.bg(added_bg);
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+/// `▸` on the left border where the last query edit, or the last stage,
+/// removed lines; a removal past the last line marks the last row
+pub fn render_removal_markers(
+    frame: &mut Frame,
+    results_area: Rect,
+    is_removed_at: impl Fn(u32) -> bool,
+    folds: &FoldState,
+    scroll_offset: u16,
+    line_count: u32,
+) {
+    let viewport_height = results_area.height.saturating_sub(2);
+    let row_count = folds.row_count(line_count);
+    let marker = Span::styled("▸", Style::default().fg(theme::results::diff_removed_fg()));
+    for relative in 0..viewport_height {
+        let row = scroll_offset as u32 + relative as u32;
+        let Some(line) = folds.line_at(row).filter(|_| row < row_count) else {
+            break;
+        };
+        let is_last = row + 1 == row_count;
+        let removed_here = is_removed_at(line) || (is_last && is_removed_at(line_count));
+        if !removed_here {
+            continue;
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(marker.clone())),
+            Rect {
+                x: results_area.x,
+                y: results_area.y.saturating_add(1).saturating_add(relative),
+                width: 1,
+                height: 1,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "diff_render_tests.rs"]
+mod diff_render_tests;
@@ -0,0 +1,73 @@
+//! Tests for results/diff_render
+
+use crate::app::App;
+use crate::results::result_diff::DiffHighlight;
+use crate::test_utils::test_helpers::test_app;
+use crate::theme;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+const OLD: &str = "{\n  \"a\": 1,\n  \"b\": 2,\n  \"c\": 3\n}";
+const NEW: &str = "{\n  \"a\": 1,\n  \"d\": 4\n}";
+
+fn app_after_edit() -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"{"a": 1}"#);
+    let arc = Arc::new(NEW.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(NEW.to_string()));
+    q.result = Ok(NEW.to_string());
+    q.cached_line_count = NEW.lines().count() as u32;
+    q.is_empty_result = false;
+    app.results_diff = DiffHighlight::new(true);
+    app.results_diff.record(OLD, NEW);
+    app
+}
+
+#[test]
+fn added_lines_get_the_diff_background() {
+    let mut app = app_after_edit();
+    let mut terminal = create_test_terminal(40, 12);
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    // Row 1 of the pane is `{`, row 3 is the new `"d": 4`
+    let added_bg = Some(theme::results::diff_added_bg());
+    assert_eq!(buffer[(3, 3)].style().bg, added_bg);
+    assert_ne!(buffer[(3, 2)].style().bg, added_bg);
+}
+
+#[test]
+fn removals_are_marked_on_the_border() {
+    let mut app = app_after_edit();
+    let mut terminal = create_test_terminal(40, 12);
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    assert_eq!(buffer[(0, 3)].symbol(), "▸");
+    assert_eq!(buffer[(0, 2)].symbol(), "│");
+}
+
+#[test]
+fn faded_flash_leaves_lines_plain() {
+    let mut app = app_after_edit();
+    app.results_diff.clear();
+    let mut terminal = create_test_terminal(40, 12);
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    assert_ne!(
+        buffer[(3, 3)].style().bg,
+        Some(theme::results::diff_added_bg())
+    );
+    assert_eq!(buffer[(0, 3)].symbol(), "│");
+}
@@ -3,7 +3,10 @@
 //! Draws the <kbd>Ctrl</kbd>+<kbd>E</kbd> error overlay. jq's raw stderr is
 //! enhanced into a plain-language summary, a fix hint, and a source location
 //! via [`crate::query::error_enhance`]; unrecognized messages fall back to the
-//! raw text so no detail is lost.
+//! raw text so no detail is lost. A mistyped field (see
+//! [`crate::query::field_hint`]) opens it for a null result too, offering
+//! the key meant, and a failure on some of the values the query iterates
+//! offers optional access ([`crate::query::optional_access`]).
 
 use ratatui::{
     Frame,
@@ -15,6 +18,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::query::error_enhance::EnhancedError;
+use crate::query::field_hint::FieldHint;
 use crate::theme;
 use crate::widgets::popup;
 
@@ -30,62 +34,100 @@ pub fn render_error_overlay(app: &App, frame: &mut Frame, results_area: Rect) ->
         None => return None,
     };
 
-    if let Err(error) = &query_state.result {
-        // Inset matches the legacy overlay; content width subtracts borders (2)
-        // and the 1-cell horizontal padding on each side (2).
-        let overlay_with_margins = popup::inset_rect(results_area, 2, 0);
-        let content_width = overlay_with_margins.width.saturating_sub(4) as usize;
+    let hint = query_state.field_hint.as_ref();
+    let optional = query_state
+        .optional_fix
+        .as_ref()
+        .filter(|fix| fix.original == app.input.query());
+    let (title, body) = match (&query_state.result, hint) {
+        (Err(error), _) => (" Error ", Some(error.as_str())),
+        (Ok(_), Some(_)) => (" No such field ", None),
+        (Ok(_), None) => return None,
+    };
+
+    // Inset matches the legacy overlay; content width subtracts borders (2)
+    // and the 1-cell horizontal padding on each side (2).
+    let overlay_with_margins = popup::inset_rect(results_area, 2, 0);
+    let content_width = overlay_with_margins.width.saturating_sub(4) as usize;
 
-        // Enhance jq's raw stderr into plain-language lines. Unrecognized
-        // messages (e.g. jiq-internal errors) fall back to the raw text.
-        let body_lines =
+    // Enhance jq's raw stderr into plain-language lines. Unrecognized
+    // messages (e.g. jiq-internal errors) fall back to the raw text. A
+    // mistyped field takes the place of jq's own hint.
+    let body_lines = match body {
+        Some(error) => {
             match crate::query::error_enhance::enhance_jq_error(error, app.input.query()) {
-                Some(enhanced) => build_enhanced_error_lines(&enhanced, content_width),
+                Some(mut enhanced) => {
+                    if let Some(hint) = hint {
+                        enhanced.hint = Some(did_you_mean(hint));
+                    } else if let Some(fix) = optional {
+                        enhanced.hint =
+                            Some(format!("`{}` skips the values that don't fit", fix.fixed));
+                    }
+                    build_enhanced_error_lines(&enhanced, content_width)
+                }
                 None => build_raw_error_lines(error, content_width),
+            }
+        }
+        None => {
+            let hint = hint.expect("an Ok result shows only with a hint");
+            let enhanced = EnhancedError {
+                summary: format!(
+                    "No object in the input has a `{}` key, so `.{}` is null.",
+                    hint.field, hint.field
+                ),
+                hint: Some(did_you_mean(hint)),
+                location: None,
             };
-
-        let truncated = body_lines.len() > MAX_CONTENT_LINES;
-        let mut display_lines: Vec<Line<'static>> =
-            body_lines.into_iter().take(MAX_CONTENT_LINES).collect();
-        if truncated {
-            display_lines.push(Line::from(Span::styled(
-                "… (error truncated)",
-                Style::default().fg(theme::results::error_location()),
-            )));
+            build_enhanced_error_lines(&enhanced, content_width)
         }
+    };
 
-        let content_lines = display_lines.len();
-        // +2 for borders, +2 for top/bottom padding.
-        let overlay_height = (content_lines as u16 + 4).clamp(5, 11);
-
-        let overlay_y = results_area.bottom().saturating_sub(overlay_height + 1);
-        let overlay_area = Rect {
-            x: overlay_with_margins.x,
-            y: overlay_y,
-            width: overlay_with_margins.width,
-            height: overlay_height,
-        };
-
-        popup::clear_area(frame, overlay_area);
-        let close_hint = theme::border_hints::build_hints(
-            &[("Ctrl+E", "Close")],
-            theme::results::border_error(),
-        );
-        let error_block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .title(" Error ")
-            .title_bottom(close_hint.alignment(Alignment::Center))
-            .border_style(Style::default().fg(theme::results::border_error()))
-            .style(Style::default().bg(theme::results::background()))
-            .padding(Padding::new(1, 1, 1, 1));
-
-        let error_widget = Paragraph::new(Text::from(display_lines)).block(error_block);
-
-        frame.render_widget(error_widget, overlay_area);
-        return Some(overlay_area);
+    let truncated = body_lines.len() > MAX_CONTENT_LINES;
+    let mut display_lines: Vec<Line<'static>> =
+        body_lines.into_iter().take(MAX_CONTENT_LINES).collect();
+    if truncated {
+        display_lines.push(Line::from(Span::styled(
+            "… (error truncated)",
+            Style::default().fg(theme::results::error_location()),
+        )));
     }
-    None
+
+    let content_lines = display_lines.len();
+    // +2 for borders, +2 for top/bottom padding.
+    let overlay_height = (content_lines as u16 + 4).clamp(5, 11);
+
+    let overlay_y = results_area.bottom().saturating_sub(overlay_height + 1);
+    let overlay_area = Rect {
+        x: overlay_with_margins.x,
+        y: overlay_y,
+        width: overlay_with_margins.width,
+        height: overlay_height,
+    };
+
+    popup::clear_area(frame, overlay_area);
+    let hints: &[(&str, &str)] = if hint.is_some() || optional.is_some() {
+        &[("Alt+F", "Apply fix"), ("Ctrl+E", "Close")]
+    } else {
+        &[("Ctrl+E", "Close")]
+    };
+    let close_hint = theme::border_hints::build_hints(hints, theme::results::border_error());
+    let error_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(close_hint.alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::results::border_error()))
+        .style(Style::default().bg(theme::results::background()))
+        .padding(Padding::new(1, 1, 1, 1));
+
+    let error_widget = Paragraph::new(Text::from(display_lines)).block(error_block);
+
+    frame.render_widget(error_widget, overlay_area);
+    Some(overlay_area)
+}
+
+fn did_you_mean(hint: &FieldHint) -> String {
+    format!("`.{}` instead of `.{}`", hint.suggestion, hint.field)
 }
 
 /// Build the styled, word-wrapped body lines for an enhanced jq error:
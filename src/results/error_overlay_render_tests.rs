@@ -1,6 +1,7 @@
 //! Unit tests for the error-overlay line builders.
 
 use super::*;
+use crate::query::optional_access::OptionalFix;
 use crate::test_utils::test_helpers::{TEST_JSON, test_app};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
@@ -162,3 +163,44 @@ fn overlay_falls_back_to_raw_text_for_unrecognized_error() {
         "overlay should carry the Error title. Output:\n{output}"
     );
 }
+
+#[test]
+fn overlay_offers_the_key_for_a_mistyped_field() {
+    let mut app = test_app(TEST_JSON);
+    let query = app.query.as_mut().unwrap();
+    query.result = Ok("null".to_string());
+    query.field_hint = Some(FieldHint {
+        start: 1,
+        end: 5,
+        field: "nmae".to_string(),
+        suggestion: "name".to_string(),
+    });
+    app.error_overlay_visible = true;
+
+    let output = render_to_string(&mut app, 80, 24);
+
+    assert!(output.contains("No such field"), "{output}");
+    assert!(output.contains("`.name` instead of `.nmae`"), "{output}");
+    assert!(output.contains("Apply fix"), "{output}");
+}
+
+#[test]
+fn overlay_offers_optional_access_for_the_query_as_typed() {
+    let mut app =
+        app_with_error_overlay(r#"jq: error (at <stdin>:0): Cannot index string with "foo""#);
+    app.input.textarea.insert_str(".items[].foo");
+    let fix = OptionalFix {
+        original: app.input.query().to_string(),
+        fixed: ".items[]?.foo?".to_string(),
+        cursor: 14,
+    };
+    app.query.as_mut().unwrap().optional_fix = Some(fix);
+
+    let output = render_to_string(&mut app, 80, 24);
+    assert!(output.contains("`.items[]?.foo?` skips"), "{output}");
+    assert!(output.contains("Apply fix"), "{output}");
+
+    app.input.textarea.insert_str(" | length");
+    let output = render_to_string(&mut app, 80, 24);
+    assert!(!output.contains("Apply fix"), "{output}");
+}
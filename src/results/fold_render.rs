@@ -0,0 +1,38 @@
+use ratatui::{
+    style::Style,
+    text::{Span, Text},
+};
+
+use crate::results::fold_state::FoldState;
+use crate::theme;
+
+/// Rows of the folded output from `offset`, each folded block's opening
+/// line followed by a `… N lines }` marker
+pub fn viewport(
+    rendered: &Text<'static>,
+    folds: &FoldState,
+    offset: usize,
+    height: usize,
+) -> Text<'static> {
+    let marker_style = Style::default().fg(theme::results::fold_marker());
+    let lines = (offset..offset + height)
+        .map_while(|row| {
+            let line = folds.line_at(row as u32)?;
+            let mut shown = rendered.lines.get(line as usize)?.clone();
+            if let Some(block) = folds.block_at(row as u32) {
+                let mut marker = format!(" … {} lines", block.hidden);
+                if let Some(closer) = &block.closer {
+                    marker.push(' ');
+                    marker.push_str(closer);
+                }
+                shown.spans.push(Span::styled(marker, marker_style));
+            }
+            Some(shown)
+        })
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+#[cfg(test)]
+#[path = "fold_render_tests.rs"]
+mod fold_render_tests;
@@ -0,0 +1,77 @@
+//! Tests for results/fold_render
+
+use crate::app::App;
+use crate::test_utils::test_helpers::{key, test_app};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+const CONTENT: &str = r#"{
+  "name": "store",
+  "users": [
+    {
+      "name": "Alice"
+    }
+  ],
+  "meta": {
+    "count": 2
+  }
+}"#;
+
+fn folded_app(depth_key: char) -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"{"name": "store"}"#);
+    let arc = Arc::new(CONTENT.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(CONTENT.to_string()));
+    q.result = Ok(CONTENT.to_string());
+    q.cached_line_count = CONTENT.lines().count() as u32;
+    q.is_empty_result = false;
+    app.focus_results_pane();
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char(depth_key)));
+    app.notification.dismiss();
+    app
+}
+
+#[test]
+fn snapshot_results_folded_to_depth_one() {
+    let mut app = folded_app('1');
+    let output = render_to_string(&mut app, 60, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn folded_rows_show_hidden_line_count_and_closer() {
+    let mut app = folded_app('1');
+    let output = render_to_string(&mut app, 60, 12);
+    assert!(output.contains(r#""users": [ … 4 lines ],"#), "{output}");
+    assert!(output.contains(r#""meta": { … 2 lines }"#), "{output}");
+    assert!(output.contains("fold 1"), "{output}");
+}
+
+#[test]
+fn unfolding_restores_every_line() {
+    let mut app = folded_app('M');
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('R')));
+    let output = render_to_string(&mut app, 60, 16);
+    assert!(output.contains(r#""name": "Alice""#), "{output}");
+    assert!(!output.contains("lines }"), "{output}");
+}
@@ -0,0 +1,217 @@
+//! Folding of the results pane by indentation depth
+//!
+//! Works on the pretty-printed text rather than the parsed value, so it also
+//! folds results jq printed as several documents. Lines indented deeper than
+//! the fold depth are hidden, along with the closing bracket of a container
+//! whose contents were hidden; the container's opening line then stands in
+//! for the whole block and carries a `… N lines }` marker.
+//!
+//! The results cursor keeps pointing at lines of the full output, so paths,
+//! yanks and search matches are unaffected. Scrolling works in shown rows,
+//! which [`FoldState::row_of`] and [`FoldState::line_at`] convert between.
+
+use std::sync::Arc;
+
+/// The hidden lines following a shown row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoldedBlock {
+    pub hidden: u32,
+    /// The block's closing bracket, e.g. `},`, when it was hidden with it
+    pub closer: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct FoldState {
+    /// Deepest indentation level shown; None when nothing is folded
+    depth: Option<u16>,
+    /// Result text the rows were computed from
+    source: Option<Arc<String>>,
+    /// Output line shown on each row
+    rows: Vec<u32>,
+    /// What each row hides, parallel to `rows`
+    blocks: Vec<Option<FoldedBlock>>,
+    /// Whether `z` was pressed and the rest of the chord is awaited
+    pending_chord: bool,
+}
+
+impl FoldState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn depth(&self) -> Option<u16> {
+        self.depth
+    }
+
+    /// Whether rows differ from output lines
+    pub fn is_active(&self) -> bool {
+        self.depth.is_some() && self.source.is_some()
+    }
+
+    /// Recompute the rows when `source` is a different result
+    pub fn sync(&mut self, source: Option<&Arc<String>>) {
+        let unchanged = match (&self.source, source) {
+            (Some(current), Some(new)) => Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
+            return;
+        }
+        self.source = source.cloned();
+        self.recompute();
+    }
+
+    /// Hide everything indented deeper than `depth`, or unfold with None
+    pub fn set_depth(&mut self, depth: Option<u16>) {
+        self.depth = depth;
+        self.recompute();
+    }
+
+    /// Hide one more level, starting from the deepest when unfolded
+    pub fn fold_more(&mut self) {
+        let depth = match self.depth {
+            Some(depth) => depth.saturating_sub(1),
+            None => self.source_depth().saturating_sub(1),
+        };
+        self.set_depth(Some(depth));
+    }
+
+    /// Show one more level, unfolding entirely once nothing is hidden
+    pub fn fold_less(&mut self) {
+        let Some(depth) = self.depth else {
+            return;
+        };
+        let depth = depth + 1;
+        if depth >= self.source_depth() {
+            self.set_depth(None);
+        } else {
+            self.set_depth(Some(depth));
+        }
+    }
+
+    pub fn start_chord(&mut self) {
+        self.pending_chord = true;
+    }
+
+    /// Whether a `z` chord was awaiting its second key, clearing it
+    pub fn take_chord(&mut self) -> bool {
+        std::mem::take(&mut self.pending_chord)
+    }
+
+    /// Number of rows shown for an output of `total_lines`
+    pub fn row_count(&self, total_lines: u32) -> u32 {
+        if self.is_active() {
+            self.rows.len() as u32
+        } else {
+            total_lines
+        }
+    }
+
+    /// Output line shown on `row`, or None past the last row
+    pub fn line_at(&self, row: u32) -> Option<u32> {
+        if self.is_active() {
+            self.rows.get(row as usize).copied()
+        } else {
+            Some(row)
+        }
+    }
+
+    /// Row showing `line`; a hidden line maps to the row folding it away
+    pub fn row_of(&self, line: u32) -> u32 {
+        if self.is_active() {
+            self.rows
+                .partition_point(|&shown| shown <= line)
+                .saturating_sub(1) as u32
+        } else {
+            line
+        }
+    }
+
+    /// What `row` folds away, if anything
+    pub fn block_at(&self, row: u32) -> Option<&FoldedBlock> {
+        if !self.is_active() {
+            return None;
+        }
+        self.blocks.get(row as usize)?.as_ref()
+    }
+
+    /// Last output line of the row showing `line`, counting what it folds
+    pub fn last_line_of(&self, line: u32) -> u32 {
+        let row = self.row_of(line);
+        match (self.line_at(row), self.block_at(row)) {
+            (Some(shown), Some(block)) => shown + block.hidden,
+            _ => line,
+        }
+    }
+
+    /// Deepest indentation level in the current result
+    fn source_depth(&self) -> u16 {
+        let Some(source) = &self.source else {
+            return 0;
+        };
+        let unit = indent_unit(source);
+        source
+            .lines()
+            .map(|line| indent_width(line) / unit)
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    fn recompute(&mut self) {
+        self.rows.clear();
+        self.blocks.clear();
+        let (Some(depth), Some(source)) = (self.depth, &self.source) else {
+            return;
+        };
+        let depth = depth as usize;
+        let unit = indent_unit(source);
+
+        let mut previous_hidden = false;
+        for (index, line) in source.lines().enumerate() {
+            let level = indent_width(line) / unit;
+            let trimmed = line.trim_start();
+            let closes_block = level == depth
+                && previous_hidden
+                && (trimmed.starts_with('}') || trimmed.starts_with(']'));
+            let hidden = level > depth || closes_block;
+
+            if hidden {
+                if let Some(Some(block)) = self.blocks.last_mut() {
+                    block.hidden += 1;
+                    if closes_block {
+                        block.closer = Some(trimmed.to_string());
+                    }
+                } else if let Some(last) = self.blocks.last_mut() {
+                    *last = Some(FoldedBlock {
+                        hidden: 1,
+                        closer: closes_block.then(|| trimmed.to_string()),
+                    });
+                }
+            } else {
+                self.rows.push(index as u32);
+                self.blocks.push(None);
+            }
+            previous_hidden = hidden;
+        }
+    }
+}
+
+/// Leading whitespace width, counting a tab as one column like jq's `--tab`
+fn indent_width(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+/// Columns per indentation level: the shallowest indent in the text,
+/// which is jq's `--indent` setting for pretty-printed output
+fn indent_unit(text: &str) -> usize {
+    text.lines()
+        .map(indent_width)
+        .filter(|&width| width > 0)
+        .min()
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+#[path = "fold_state_tests.rs"]
+mod fold_state_tests;
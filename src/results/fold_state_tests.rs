@@ -0,0 +1,161 @@
+//! Tests for results/fold_state
+
+use super::*;
+
+const RESULT: &str = r#"{
+  "name": "store",
+  "users": [
+    {
+      "name": "Alice"
+    },
+    {
+      "name": "Bob"
+    }
+  ],
+  "meta": {
+    "count": 2
+  }
+}"#;
+
+fn folded(depth: u16) -> FoldState {
+    let mut folds = FoldState::new();
+    folds.sync(Some(&Arc::new(RESULT.to_string())));
+    folds.set_depth(Some(depth));
+    folds
+}
+
+#[test]
+fn test_unfolded_rows_are_output_lines() {
+    let mut folds = FoldState::new();
+    folds.sync(Some(&Arc::new(RESULT.to_string())));
+
+    assert!(!folds.is_active());
+    assert_eq!(folds.row_count(14), 14);
+    assert_eq!(folds.line_at(5), Some(5));
+    assert_eq!(folds.row_of(5), 5);
+    assert_eq!(folds.block_at(2), None);
+}
+
+#[test]
+fn test_depth_zero_folds_the_whole_document() {
+    let folds = folded(0);
+
+    assert_eq!(folds.row_count(14), 1);
+    assert_eq!(
+        folds.block_at(0),
+        Some(&FoldedBlock {
+            hidden: 13,
+            closer: Some("}".to_string()),
+        })
+    );
+}
+
+#[test]
+fn test_depth_one_shows_top_level_keys() {
+    let folds = folded(1);
+
+    // `{`, "name", "users": [, "meta": {, `}`
+    assert_eq!(folds.row_count(14), 5);
+    assert_eq!(folds.line_at(2), Some(2));
+    assert_eq!(folds.line_at(3), Some(10));
+    assert_eq!(
+        folds.block_at(2),
+        Some(&FoldedBlock {
+            hidden: 7,
+            closer: Some("],".to_string()),
+        })
+    );
+    assert_eq!(folds.block_at(1), None);
+}
+
+#[test]
+fn test_hidden_lines_map_to_the_row_folding_them() {
+    let folds = folded(1);
+
+    assert_eq!(folds.row_of(5), 2);
+    assert_eq!(folds.row_of(9), 2);
+    assert_eq!(folds.row_of(10), 3);
+    assert_eq!(folds.line_at(5), None);
+}
+
+#[test]
+fn test_fold_more_starts_from_the_deepest_level() {
+    let mut folds = FoldState::new();
+    folds.sync(Some(&Arc::new(RESULT.to_string())));
+
+    folds.fold_more();
+    assert_eq!(folds.depth(), Some(2));
+    folds.fold_more();
+    assert_eq!(folds.depth(), Some(1));
+}
+
+#[test]
+fn test_fold_less_unfolds_past_the_deepest_level() {
+    let mut folds = folded(1);
+
+    folds.fold_less();
+    assert_eq!(folds.depth(), Some(2));
+    folds.fold_less();
+    assert_eq!(folds.depth(), None);
+    assert!(!folds.is_active());
+}
+
+#[test]
+fn test_new_result_recomputes_rows() {
+    let mut folds = folded(0);
+    folds.sync(Some(&Arc::new("[\n  1,\n  2\n]".to_string())));
+
+    assert_eq!(folds.row_count(4), 1);
+    assert_eq!(folds.block_at(0).unwrap().hidden, 3);
+}
+
+#[test]
+fn test_indent_unit_follows_jq_indent_setting() {
+    let mut folds = FoldState::new();
+    folds.sync(Some(&Arc::new(
+        "{\n    \"a\": {\n        \"b\": 1\n    }\n}".to_string(),
+    )));
+    folds.set_depth(Some(1));
+
+    assert_eq!(folds.row_count(5), 3);
+    assert_eq!(folds.block_at(1).unwrap().closer.as_deref(), Some("}"));
+}
+
+#[test]
+fn test_tab_indented_output() {
+    let mut folds = FoldState::new();
+    folds.sync(Some(&Arc::new("[\n\t[\n\t\t1\n\t]\n]".to_string())));
+    folds.set_depth(Some(1));
+
+    assert_eq!(folds.row_count(5), 3);
+}
+
+#[test]
+fn test_several_documents_fold_separately() {
+    let mut folds = FoldState::new();
+    folds.sync(Some(&Arc::new(
+        "{\n  \"a\": 1\n}\n{\n  \"a\": 2\n}".to_string(),
+    )));
+    folds.set_depth(Some(0));
+
+    assert_eq!(folds.row_count(6), 2);
+    assert_eq!(folds.line_at(1), Some(3));
+}
+
+#[test]
+fn test_chord_is_taken_once() {
+    let mut folds = FoldState::new();
+    assert!(!folds.take_chord());
+    folds.start_chord();
+    assert!(folds.take_chord());
+    assert!(!folds.take_chord());
+}
+
+#[test]
+fn test_last_line_of_a_folded_row_includes_its_block() {
+    let folds = folded(1);
+
+    assert_eq!(folds.last_line_of(2), 9);
+    assert_eq!(folds.last_line_of(1), 1);
+    assert_eq!(FoldState::new().last_line_of(4), 4);
+}
@@ -0,0 +1,57 @@
+//! `F` in the results pane: follow the output like `tail -f` or `less +F`.
+//!
+//! While following, the last line stays in view as a slow query's output
+//! streams in and as each new result arrives. Scrolling up stops it, so
+//! reading back through the output isn't yanked away by the next update.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::results::results_events;
+
+/// `F` — start or stop following the output
+pub fn toggle(app: &mut App) {
+    app.results_follow = !app.results_follow;
+    if app.results_follow {
+        app.notification
+            .show("Following output · scroll up or F to stop");
+        pin_to_end(app);
+    } else {
+        app.notification.show("Stopped following output");
+    }
+}
+
+/// Stop following when `key` moves up through the output
+pub fn stop_on_scroll_up_key(app: &mut App, key: KeyEvent) {
+    let control = key.modifiers.contains(KeyModifiers::CONTROL);
+    let up = match key.code {
+        KeyCode::Up | KeyCode::PageUp | KeyCode::Home => true,
+        KeyCode::Char('k' | 'K' | 'g') => !control,
+        KeyCode::Char('u') => control,
+        _ => false,
+    };
+    if up {
+        stop(app);
+    }
+}
+
+/// Stop following, saying so if it was on
+pub fn stop(app: &mut App) {
+    if std::mem::take(&mut app.results_follow) {
+        app.notification.show("Stopped following output");
+    }
+}
+
+/// Keep the last line in view; called each frame once the pane knows how
+/// much output there is
+pub fn pin_to_end(app: &mut App) {
+    if !app.results_follow {
+        return;
+    }
+    results_events::move_cursor_to_last(app);
+    app.results_scroll.jump_to_bottom();
+}
+
+#[cfg(test)]
+#[path = "follow_events_tests.rs"]
+mod follow_events_tests;
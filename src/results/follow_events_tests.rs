@@ -0,0 +1,109 @@
+//! Tests for results/follow_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{execute_query_and_wait, key, key_with_mods, test_app};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+const JSON: &str = r#"{"short": [1, 2], "long": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30]}"#;
+
+fn app_with_query(query: &str) -> App {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str(query);
+    execute_query_and_wait(&mut app);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+fn set_query(app: &mut App, query: &str) {
+    app.input.textarea.select_all();
+    app.input.textarea.cut();
+    app.input.textarea.insert_str(query);
+    execute_query_and_wait(app);
+}
+
+fn last_line(app: &App) -> u32 {
+    app.results_line_count_u32().saturating_sub(1)
+}
+
+#[test]
+fn f_pins_the_pane_to_the_last_line() {
+    let mut app = app_with_query(".long");
+
+    app.handle_key_event(key(KeyCode::Char('F')));
+    let output = render_to_string(&mut app, 80, 20);
+
+    assert!(app.results_follow);
+    assert!(app.results_scroll.max_offset > 0);
+    assert_eq!(app.results_scroll.offset, app.results_scroll.max_offset);
+    assert_eq!(app.results_cursor.cursor_line(), last_line(&app));
+    assert!(output.contains("following"), "{}", output);
+}
+
+#[test]
+fn new_output_stays_pinned_while_following() {
+    let mut app = app_with_query(".short");
+    app.handle_key_event(key(KeyCode::Char('F')));
+    render_to_string(&mut app, 80, 20);
+
+    set_query(&mut app, ".long");
+    render_to_string(&mut app, 80, 20);
+
+    assert_eq!(app.results_scroll.offset, app.results_scroll.max_offset);
+    assert_eq!(app.results_cursor.cursor_line(), last_line(&app));
+}
+
+#[test]
+fn scrolling_up_stops_following() {
+    let mut app = app_with_query(".long");
+    app.handle_key_event(key(KeyCode::Char('F')));
+    render_to_string(&mut app, 80, 20);
+
+    app.handle_key_event(key(KeyCode::Char('k')));
+
+    assert!(!app.results_follow);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Stopped following output")
+    );
+    let offset = app.results_scroll.offset;
+    render_to_string(&mut app, 80, 20);
+    assert_eq!(app.results_scroll.offset, offset);
+}
+
+#[test]
+fn half_page_up_stops_following() {
+    let mut app = app_with_query(".long");
+    app.handle_key_event(key(KeyCode::Char('F')));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('u'), KeyModifiers::CONTROL));
+
+    assert!(!app.results_follow);
+}
+
+#[test]
+fn moving_down_keeps_following() {
+    let mut app = app_with_query(".long");
+    app.handle_key_event(key(KeyCode::Char('F')));
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+
+    assert!(app.results_follow);
+}
+
+#[test]
+fn f_again_stops_following() {
+    let mut app = app_with_query(".long");
+    app.handle_key_event(key(KeyCode::Char('F')));
+
+    app.handle_key_event(key(KeyCode::Char('F')));
+
+    assert!(!app.results_follow);
+}
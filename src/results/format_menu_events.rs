@@ -0,0 +1,55 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::query::output_format::OutputFormat;
+
+/// `o` — open the output format menu
+pub fn open_menu(app: &mut App) {
+    if app.query.is_some() {
+        app.format_menu_visible = true;
+    }
+}
+
+/// Keys while the output format menu is open; it takes them all. Each
+/// change re-runs the query so the results show it at once.
+pub fn handle_format_menu_key(app: &mut App, key: KeyEvent) {
+    let Some(query_state) = app.query.as_ref() else {
+        app.format_menu_visible = false;
+        return;
+    };
+    let mut format = query_state.output_format();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('o') => {
+            app.format_menu_visible = false;
+            return;
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right | KeyCode::Char('l') => {
+            format.indent_more();
+        }
+        KeyCode::Char('-') | KeyCode::Left | KeyCode::Char('h') => format.indent_less(),
+        KeyCode::Char('t') => format.toggle_tab(),
+        KeyCode::Char('s') => format.toggle_sort_keys(),
+        KeyCode::Char('a') => format.toggle_ascii(),
+        KeyCode::Char('r') => format = OutputFormat::default(),
+        _ => return,
+    }
+    set_format(app, format);
+}
+
+/// Apply `format` and re-run the query when it changed. New tabs and
+/// reloaded inputs start with it too.
+pub fn set_format(app: &mut App, format: OutputFormat) {
+    app.output_format = format;
+    let Some(query_state) = app.query.as_mut() else {
+        return;
+    };
+    if query_state.output_format() == format {
+        return;
+    }
+    query_state.set_output_format(format);
+    crate::editor::editor_events::execute_query(app);
+}
+
+#[cfg(test)]
+#[path = "format_menu_events_tests.rs"]
+mod format_menu_events_tests;
@@ -0,0 +1,82 @@
+//! Tests for results/format_menu_events
+
+use crate::app::{App, Focus};
+use crate::query::output_format::{Indent, OutputFormat};
+use crate::query::worker::preprocess::strip_ansi_codes;
+use crate::test_utils::test_helpers::{key, test_app, wait_for_query_completion};
+use ratatui::crossterm::event::KeyCode;
+
+fn app_with_menu(json: &str) -> App {
+    let mut app = test_app(json);
+    app.focus = Focus::ResultsPane;
+    app.handle_key_event(key(KeyCode::Char('o')));
+    app
+}
+
+fn format(app: &App) -> OutputFormat {
+    app.query.as_ref().unwrap().output_format()
+}
+
+#[test]
+fn test_o_opens_the_menu() {
+    let app = app_with_menu("{}");
+
+    assert!(app.format_menu_visible);
+}
+
+#[test]
+fn test_esc_closes_the_menu_keeping_the_format() {
+    let mut app = app_with_menu("{}");
+    app.handle_key_event(key(KeyCode::Char('s')));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.format_menu_visible);
+    assert!(format(&app).sort_keys);
+}
+
+#[test]
+fn test_keys_change_the_format() {
+    let mut app = app_with_menu("{}");
+
+    app.handle_key_event(key(KeyCode::Char('+')));
+    app.handle_key_event(key(KeyCode::Char('+')));
+    app.handle_key_event(key(KeyCode::Char('a')));
+    assert_eq!(
+        format(&app),
+        OutputFormat {
+            indent: Indent::Spaces(4),
+            sort_keys: false,
+            ascii: true,
+            raw: false,
+            compact: false,
+        }
+    );
+
+    app.handle_key_event(key(KeyCode::Char('t')));
+    assert_eq!(format(&app).indent, Indent::Tab);
+
+    app.handle_key_event(key(KeyCode::Char('r')));
+    assert_eq!(format(&app), OutputFormat::default());
+}
+
+#[test]
+fn test_other_keys_are_swallowed() {
+    let mut app = app_with_menu("{}");
+
+    app.handle_key_event(key(KeyCode::Char('i')));
+
+    assert!(app.format_menu_visible);
+    assert_eq!(app.focus, Focus::ResultsPane);
+}
+
+#[test]
+fn test_change_reruns_the_query_in_the_new_format() {
+    let mut app = app_with_menu(r#"{"b": 1, "a": 2}"#);
+
+    app.handle_key_event(key(KeyCode::Char('s')));
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    let result = app.query.as_ref().unwrap().result.as_ref().unwrap();
+    assert_eq!(strip_ansi_codes(result), "{\n  \"a\": 2,\n  \"b\": 1\n}\n");
+}
@@ -0,0 +1,65 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+};
+
+use crate::query::output_format::{Indent, OutputFormat};
+use crate::theme;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const POPUP_WIDTH: u16 = 40;
+const LABEL_WIDTH: usize = 12;
+const VALUE_WIDTH: usize = 12;
+
+/// Render the output format menu centered over `area`
+pub fn render_popup(format: OutputFormat, frame: &mut Frame, area: Rect) {
+    let rows = [
+        ("Indent", format.indent_label(), "-/+"),
+        ("Tabs", on_off(format.indent == Indent::Tab), "t"),
+        ("Sort keys", on_off(format.sort_keys), "s"),
+        ("ASCII", on_off(format.ascii), "a"),
+    ];
+    let popup_area = centered_rect(area, POPUP_WIDTH, rows.len() as u16 + 2);
+    if popup_area.width < 30 || popup_area.height < rows.len() as u16 + 2 {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(" Output format ", &[("r", "Reset"), ("Esc", "Close")]);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let key_style = Style::default().fg(theme::results::hint_key());
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .map(|(label, value, keys)| {
+            Line::from(vec![
+                Span::raw(format!("{:<LABEL_WIDTH$}", label)),
+                Span::styled(
+                    format!("{:<VALUE_WIDTH$}", value),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        "{:>width$}",
+                        keys,
+                        width = (inner.width as usize).saturating_sub(LABEL_WIDTH + VALUE_WIDTH)
+                    ),
+                    key_style,
+                ),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn on_off(on: bool) -> String {
+    if on { "on" } else { "off" }.to_string()
+}
+
+#[cfg(test)]
+#[path = "format_menu_render_tests.rs"]
+mod format_menu_render_tests;
@@ -0,0 +1,44 @@
+//! Tests for results/format_menu_render
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+
+use super::*;
+
+fn render_to_string(format: OutputFormat, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let area = Rect {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    };
+    terminal.draw(|f| render_popup(format, f, area)).unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_default_format() {
+    assert_snapshot!(render_to_string(OutputFormat::default(), 50, 8));
+}
+
+#[test]
+fn shows_changed_settings() {
+    let format = OutputFormat {
+        indent: Indent::Tab,
+        sort_keys: true,
+        ascii: false,
+        raw: false,
+        compact: false,
+    };
+
+    let output = render_to_string(format, 50, 8);
+
+    assert!(output.contains("Indent      tab"), "{output}");
+    assert!(output.contains("Tabs        on"), "{output}");
+    assert!(output.contains("Sort keys   on"), "{output}");
+    assert!(output.contains("ASCII       off"), "{output}");
+}
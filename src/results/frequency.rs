@@ -0,0 +1,274 @@
+//! Most frequent values in a result
+//!
+//! `c` in the results pane, or `:freq [PATH]`, counts the result's values,
+//! or the values `PATH` picks out of it, and ranks them, as
+//! `group_by(.) | map({value: .[0], count: length}) | sort_by(-.count)`
+//! would. A result of objects can be counted by key set instead, to see
+//! which shapes of record it holds. jq runs in the background, shown in
+//! the status bar and stopped with `Ctrl+X` like the main query.
+
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+use crate::query::executor::JqExecutor;
+
+/// What a value is counted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrequencyMode {
+    /// The value itself
+    #[default]
+    Values,
+    /// An object's sorted keys
+    Keys,
+}
+
+/// One distinct value and how often it appears
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranked {
+    pub label: String,
+    pub count: usize,
+}
+
+/// Values read from printed jq output: the elements of a lone array, or
+/// else every value printed. The flag says whether it was a lone array.
+pub fn values_of(text: &str) -> Option<(Vec<Value>, bool)> {
+    let mut values = serde_json::Deserializer::from_str(text)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    if let [Value::Array(items)] = values.as_mut_slice() {
+        return Some((std::mem::take(items), true));
+    }
+    Some((values, false))
+}
+
+/// Distinct values, most frequent first; ties keep the order they first
+/// appear in
+pub fn rank(values: &[Value], mode: FrequencyMode) -> Vec<Ranked> {
+    let mut ranked: Vec<Ranked> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for value in values {
+        let label = label(value, mode);
+        match index.get(&label) {
+            Some(&i) => ranked[i].count += 1,
+            None => {
+                index.insert(label.clone(), ranked.len());
+                ranked.push(Ranked { label, count: 1 });
+            }
+        }
+    }
+    ranked.sort_by_key(|r| std::cmp::Reverse(r.count));
+    ranked
+}
+
+fn label(value: &Value, mode: FrequencyMode) -> String {
+    match (mode, value) {
+        (FrequencyMode::Keys, Value::Object(map)) => {
+            let mut keys: Vec<&str> = map.keys().map(String::as_str).collect();
+            keys.sort_unstable();
+            format!("{{{}}}", keys.join(", "))
+        }
+        (FrequencyMode::Keys, Value::Array(_)) => "(array)".to_string(),
+        (FrequencyMode::Keys, _) => "(not an object)".to_string(),
+        (FrequencyMode::Values, value) => serde_json::to_string(value).unwrap_or_default(),
+    }
+}
+
+/// The jq that gives the same counts for `base`, the query the values
+/// came from
+pub fn jq_pipeline(base: &str, lone_array: bool, mode: FrequencyMode) -> String {
+    let input = if lone_array {
+        base.to_string()
+    } else {
+        format!("[{}]", base)
+    };
+    match mode {
+        FrequencyMode::Values => format!(
+            "{} | group_by(.) | map({{value: .[0], count: length}}) | sort_by(-.count)",
+            input
+        ),
+        FrequencyMode::Keys => format!(
+            "{} | group_by(keys) | map({{keys: .[0] | keys, count: length}}) | sort_by(-.count)",
+            input
+        ),
+    }
+}
+
+/// Counts on show in the frequency popup
+#[derive(Debug)]
+pub struct Frequency {
+    values: Vec<Value>,
+    lone_array: bool,
+    /// The query the values came from, path included
+    base: String,
+    mode: FrequencyMode,
+    ranked: Vec<Ranked>,
+}
+
+impl Frequency {
+    pub fn total(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn ranked(&self) -> &[Ranked] {
+        &self.ranked
+    }
+
+    pub fn mode(&self) -> FrequencyMode {
+        self.mode
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Whether counting by key set means anything here
+    pub fn has_objects(&self) -> bool {
+        self.values.iter().any(Value::is_object)
+    }
+
+    /// The jq giving these counts
+    pub fn jq_pipeline(&self) -> String {
+        jq_pipeline(&self.base, self.lone_array, self.mode)
+    }
+}
+
+/// The `c` popup
+#[derive(Debug, Default)]
+pub struct FrequencyPopup {
+    data: Option<Frequency>,
+    selected: usize,
+    /// The query being run for the next popup
+    counting: Option<Counting>,
+}
+
+/// A count under way
+#[derive(Debug)]
+struct Counting {
+    base: String,
+    cancel: CancellationToken,
+    /// jq's output, or its error
+    response_rx: Receiver<Result<String, String>>,
+}
+
+impl FrequencyPopup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count the values printed as `text` by `base`. Objects are counted
+    /// by key set when every value is one. Returns false, staying closed,
+    /// when there is nothing to count.
+    pub fn open(&mut self, text: &str, base: &str) -> bool {
+        let Some((values, lone_array)) = values_of(text).filter(|(v, _)| !v.is_empty()) else {
+            return false;
+        };
+        let mode = if values.iter().all(Value::is_object) {
+            FrequencyMode::Keys
+        } else {
+            FrequencyMode::Values
+        };
+        let ranked = rank(&values, mode);
+        self.data = Some(Frequency {
+            values,
+            lone_array,
+            base: base.to_string(),
+            mode,
+            ranked,
+        });
+        self.selected = 0;
+        true
+    }
+
+    /// Run `base` with `executor` on another thread, replacing a count
+    /// still under way. [`poll`](Self::poll) opens the popup on its output.
+    pub fn start(&mut self, executor: JqExecutor, base: String) {
+        self.cancel();
+        let cancel = CancellationToken::new();
+        let (response_tx, response_rx) = channel();
+        let thread_cancel = cancel.clone();
+        let query = base.clone();
+        std::thread::spawn(move || {
+            let output = executor
+                .execute_compact(&query, &thread_cancel)
+                .map_err(|e| e.to_string());
+            if !thread_cancel.is_cancelled() {
+                let _ = response_tx.send(output);
+            }
+        });
+        self.counting = Some(Counting {
+            base,
+            cancel,
+            response_rx,
+        });
+    }
+
+    pub fn is_counting(&self) -> bool {
+        self.counting.is_some()
+    }
+
+    /// Stop the count under way
+    pub fn cancel(&mut self) {
+        if let Some(counting) = self.counting.take() {
+            counting.cancel.cancel();
+        }
+    }
+
+    /// Open on the count once jq has finished: whether there was anything
+    /// to count, or jq's error
+    pub fn poll(&mut self) -> Option<Result<bool, String>> {
+        let counting = self.counting.as_ref()?;
+        let output = match counting.response_rx.try_recv() {
+            Ok(output) => output,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err("count stopped".to_string()),
+        };
+        let base = self.counting.take().map(|c| c.base).unwrap_or_default();
+        Some(output.map(|text| self.open(&text, &base)))
+    }
+
+    pub fn close(&mut self) {
+        self.data = None;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.data.is_some()
+    }
+
+    pub fn data(&self) -> Option<&Frequency> {
+        self.data.as_ref()
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Move the highlight by `delta`, stopping at the ends
+    pub fn move_by(&mut self, delta: isize) {
+        let count = self.data.as_ref().map_or(0, |d| d.ranked.len());
+        self.selected = self
+            .selected
+            .saturating_add_signed(delta)
+            .min(count.saturating_sub(1));
+    }
+
+    /// Count by value or by key set; only results with objects have both
+    pub fn toggle_mode(&mut self) {
+        let Some(data) = self.data.as_mut().filter(|d| d.has_objects()) else {
+            return;
+        };
+        data.mode = match data.mode {
+            FrequencyMode::Values => FrequencyMode::Keys,
+            FrequencyMode::Keys => FrequencyMode::Values,
+        };
+        data.ranked = rank(&data.values, data.mode);
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+#[path = "frequency_tests.rs"]
+mod frequency_tests;
@@ -0,0 +1,71 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::editor::editor_events::execute_query;
+
+/// Rows PageUp and PageDown move by
+const PAGE: isize = 10;
+
+/// `c` — rank the current result's values by how often they appear
+pub fn open_frequency(app: &mut App) {
+    open_frequency_at(app, None);
+}
+
+/// `:freq [PATH]` — rank the values `PATH` picks out of the current
+/// result, or the result's own values without one
+pub fn open_frequency_at(app: &mut App, path: Option<&str>) {
+    let Some(query_state) = app.query.as_ref() else {
+        return;
+    };
+    let query = match app.query().trim() {
+        "" => ".",
+        query => query,
+    };
+    let base = match path {
+        Some(path) => format!("{} | {}", query, path),
+        None => query.to_string(),
+    };
+    let executor = query_state.executor.share();
+    app.results_frequency.start(executor, base);
+    app.update_progress();
+}
+
+/// Open the popup on a count that finished. Returns true when one did.
+pub fn poll_frequency(app: &mut App) -> bool {
+    match app.results_frequency.poll() {
+        Some(Ok(true)) => {}
+        Some(Ok(false)) => app.notification.show("Nothing to count"),
+        Some(Err(message)) => app
+            .notification
+            .show_error(message.lines().next().unwrap_or_default()),
+        None => return false,
+    }
+    true
+}
+
+/// Keys while the popup is open; it takes them all
+pub fn handle_frequency_key(app: &mut App, key: KeyEvent) {
+    let popup = &mut app.results_frequency;
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('c') => popup.close(),
+        KeyCode::Down | KeyCode::Char('j') => popup.move_by(1),
+        KeyCode::Up | KeyCode::Char('k') => popup.move_by(-1),
+        KeyCode::PageDown => popup.move_by(PAGE),
+        KeyCode::PageUp => popup.move_by(-PAGE),
+        KeyCode::Tab | KeyCode::BackTab => popup.toggle_mode(),
+        KeyCode::Enter => {
+            if let Some(pipeline) = popup.data().map(|data| data.jq_pipeline()) {
+                popup.close();
+                app.input.textarea.delete_line_by_head();
+                app.input.textarea.delete_line_by_end();
+                app.input.textarea.insert_str(&pipeline);
+                execute_query(app);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "frequency_events_tests.rs"]
+mod frequency_events_tests;
@@ -0,0 +1,159 @@
+//! Tests for results/frequency_events
+
+use crate::app::{App, Focus};
+use crate::editor::EditorMode;
+use crate::results::frequency::FrequencyMode;
+use crate::test_utils::test_helpers::{key, test_app};
+use ratatui::crossterm::event::KeyCode;
+use std::time::{Duration, Instant};
+
+const ISSUES: &str =
+    r#"[{"label": "bug", "id": 1}, {"label": "docs", "id": 2}, {"label": "bug", "id": 3}]"#;
+
+fn app_in_results(json: &str) -> App {
+    let mut app = test_app(json);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+/// Wait for the background count to finish and report it
+fn counted(app: &mut App) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !super::poll_frequency(app) {
+        assert!(Instant::now() < deadline, "count never finished");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// Open the popup with `c` and wait for its count
+fn press_c(app: &mut App) {
+    app.handle_key_event(key(KeyCode::Char('c')));
+    counted(app);
+}
+
+/// `:freq PATH`, waiting for its count
+fn freq(app: &mut App, path: &str) {
+    super::open_frequency_at(app, Some(path));
+    counted(app);
+}
+
+#[test]
+fn test_c_counts_the_result() {
+    let mut app = app_in_results(ISSUES);
+
+    press_c(&mut app);
+
+    let data = app.results_frequency.data().unwrap();
+    assert_eq!(data.mode(), FrequencyMode::Keys);
+    assert_eq!(data.total(), 3);
+    assert_eq!(data.ranked()[0].label, "{id, label}");
+}
+
+#[test]
+fn test_freq_path_counts_values_at_the_path() {
+    let mut app = app_in_results(ISSUES);
+
+    freq(&mut app, ".[].label");
+
+    let data = app.results_frequency.data().unwrap();
+    assert_eq!(data.base(), ". | .[].label");
+    assert_eq!(data.ranked()[0].label, "\"bug\"");
+    assert_eq!(data.ranked()[0].count, 2);
+}
+
+#[test]
+fn test_nothing_to_count_explains_why() {
+    let mut app = app_in_results("[]");
+
+    press_c(&mut app);
+
+    assert!(!app.results_frequency.is_visible());
+    assert_eq!(app.notification.current_message(), Some("Nothing to count"));
+}
+
+#[test]
+fn test_bad_path_shows_the_error() {
+    let mut app = app_in_results(ISSUES);
+
+    freq(&mut app, ".[] | error(\"boom\")");
+
+    assert!(!app.results_frequency.is_visible());
+    assert!(app.notification.current_message().is_some());
+}
+
+#[test]
+fn test_tab_switches_to_values() {
+    let mut app = app_in_results(ISSUES);
+    press_c(&mut app);
+
+    app.handle_key_event(key(KeyCode::Tab));
+
+    assert_eq!(
+        app.results_frequency.data().unwrap().mode(),
+        FrequencyMode::Values
+    );
+}
+
+#[test]
+fn test_enter_uses_the_pipeline_as_query() {
+    let mut app = app_in_results(ISSUES);
+    app.input.editor_mode = EditorMode::Normal;
+    freq(&mut app, ".[].label");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.results_frequency.is_visible());
+    assert_eq!(
+        app.query(),
+        "[. | .[].label] | group_by(.) | map({value: .[0], count: length}) | sort_by(-.count)"
+    );
+}
+
+#[test]
+fn test_frequency_takes_keys_while_open() {
+    let mut app = app_in_results(ISSUES);
+    press_c(&mut app);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    assert!(app.results_frequency.is_visible());
+    assert!(!app.results_table.is_active());
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+}
+
+#[test]
+fn test_esc_closes_the_popup() {
+    let mut app = app_in_results(ISSUES);
+    press_c(&mut app);
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.results_frequency.is_visible());
+    assert_eq!(app.focus, Focus::ResultsPane);
+}
+
+#[test]
+fn test_count_runs_in_the_background() {
+    let mut app = app_in_results(ISSUES);
+
+    app.handle_key_event(key(KeyCode::Char('c')));
+
+    assert!(app.results_frequency.is_counting());
+    assert!(!app.results_frequency.is_visible());
+    counted(&mut app);
+    assert!(!app.results_frequency.is_counting());
+    assert!(app.results_frequency.is_visible());
+}
+
+#[test]
+fn test_cancelled_count_opens_nothing() {
+    let mut app = app_in_results(ISSUES);
+    super::open_frequency_at(&mut app, Some("last(range(1e9))"));
+
+    app.results_frequency.cancel();
+
+    assert!(!app.results_frequency.is_counting());
+    assert!(!super::poll_frequency(&mut app));
+    assert!(!app.results_frequency.is_visible());
+}
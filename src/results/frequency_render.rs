@@ -0,0 +1,132 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::frequency::{Frequency, FrequencyMode, FrequencyPopup};
+use crate::str_utils::truncate_to_width;
+use crate::theme;
+use crate::widgets::bar::bar;
+use crate::widgets::popup::{centered_rect, popup_block};
+
+const POPUP_WIDTH: u16 = 72;
+const MAX_ROWS: u16 = 15;
+const MAX_LABEL_WIDTH: usize = 28;
+
+/// Render the frequency popup centered over `area`
+pub fn render_popup(popup: &FrequencyPopup, frame: &mut Frame, area: Rect) {
+    let Some(data) = popup.data() else {
+        return;
+    };
+    let rows = (data.ranked().len() as u16).min(MAX_ROWS);
+    let popup_area = centered_rect(
+        area,
+        POPUP_WIDTH.min(area.width.saturating_sub(4)),
+        (rows + 2).min(area.height),
+    );
+    if popup_area.width < 20 || popup_area.height < 3 {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(title(data), &hints(data))
+        .border_style(Style::default().fg(theme::results::chart_bar()))
+        .title_top(base_line(data, popup_area.width).alignment(Alignment::Right));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    render_rows(frame, inner, data, popup.selected());
+}
+
+fn title(data: &Frequency) -> String {
+    let by = match data.mode() {
+        FrequencyMode::Values => "values",
+        FrequencyMode::Keys => "key sets",
+    };
+    format!(
+        " Frequency · {} values · {} distinct {} ",
+        data.total(),
+        data.ranked().len(),
+        by
+    )
+}
+
+/// The query counted, as far as it fits beside the title
+fn base_line(data: &Frequency, width: u16) -> Line<'static> {
+    let room = (width as usize).saturating_sub(title(data).width() + 6);
+    Line::from(Span::styled(
+        format!(" {} ", truncate_to_width(data.base(), room)),
+        Style::default().fg(theme::results::fold_marker()),
+    ))
+}
+
+fn hints(data: &Frequency) -> Vec<(&'static str, &'static str)> {
+    let mut hints = vec![("Enter", "Use as query")];
+    if data.has_objects() {
+        hints.push((
+            "Tab",
+            match data.mode() {
+                FrequencyMode::Values => "By keys",
+                FrequencyMode::Keys => "By values",
+            },
+        ));
+    }
+    hints.push(("Esc", "Close"));
+    hints
+}
+
+/// Label, bar, count and share of each distinct value, scrolled to keep
+/// `selected` in view
+fn render_rows(frame: &mut Frame, area: Rect, data: &Frequency, selected: usize) {
+    let height = (area.height as usize).max(1);
+    let first = selected.saturating_sub(height - 1);
+    let shown = data.ranked().iter().skip(first).take(height);
+
+    let total = data.total().max(1);
+    let longest = data.ranked().first().map_or(1, |r| r.count).max(1);
+    let label_width = data
+        .ranked()
+        .iter()
+        .map(|r| r.label.width())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    let count_width = longest.to_string().len();
+    // Count, then a share like " 100%"
+    let bar_width = (area.width as usize).saturating_sub(label_width + count_width + 7);
+
+    let bar_style = Style::default().fg(theme::results::chart_bar());
+    let muted = Style::default().fg(theme::results::fold_marker());
+    let lines: Vec<Line> = shown
+        .enumerate()
+        .map(|(i, ranked)| {
+            let label = truncate_to_width(&ranked.label, label_width);
+            let fill = " ".repeat(label_width - label.width());
+            let bar = bar(ranked.count as f64 / longest as f64 * bar_width as f64);
+            let line = Line::from(vec![
+                Span::raw(format!("{}{} ", label, fill)),
+                Span::styled(bar.clone(), bar_style),
+                Span::raw(" ".repeat((bar_width + 1).saturating_sub(bar.chars().count()))),
+                Span::raw(format!("{:>width$}", ranked.count, width = count_width)),
+                Span::styled(
+                    format!(" {:>3}%", (ranked.count * 100 + total / 2) / total),
+                    muted,
+                ),
+            ]);
+            if first + i == selected {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+#[cfg(test)]
+#[path = "frequency_render_tests.rs"]
+mod frequency_render_tests;
@@ -0,0 +1,56 @@
+//! Tests for results/frequency_render
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+
+use super::*;
+
+fn render_to_string(popup: &FrequencyPopup, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let area = Rect {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    };
+    terminal.draw(|f| render_popup(popup, f, area)).unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_value_counts() {
+    let mut popup = FrequencyPopup::new();
+    assert!(popup.open(
+        r#"["bug", "docs", "bug", "feature", "bug", "docs"]"#,
+        ".labels"
+    ));
+    assert_snapshot!(render_to_string(&popup, 60, 7));
+}
+
+#[test]
+fn snapshot_key_sets() {
+    let mut popup = FrequencyPopup::new();
+    assert!(popup.open(
+        r#"{"id": 1, "name": "a"}
+{"id": 2}
+{"id": 3, "name": "c"}"#,
+        ".[]",
+    ));
+    assert_snapshot!(render_to_string(&popup, 60, 6));
+}
+
+#[test]
+fn selection_scrolls_into_view() {
+    let values: Vec<String> = (0..30).map(|i| i.to_string()).collect();
+    let mut popup = FrequencyPopup::new();
+    assert!(popup.open(&format!("[{}]", values.join(",")), "."));
+    popup.move_by(29);
+
+    let output = render_to_string(&popup, 60, 10);
+
+    assert!(output.contains("29 "));
+    assert!(!output.contains(" 0 "));
+}
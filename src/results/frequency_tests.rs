@@ -0,0 +1,138 @@
+//! Tests for results/frequency
+
+use serde_json::json;
+
+use super::*;
+
+#[test]
+fn lone_array_is_counted_by_element() {
+    let (values, lone_array) = values_of(r#"["a", "b", "a"]"#).unwrap();
+
+    assert!(lone_array);
+    assert_eq!(values.len(), 3);
+}
+
+#[test]
+fn stream_is_counted_by_value() {
+    let (values, lone_array) = values_of("1\n[2]\n1").unwrap();
+
+    assert!(!lone_array);
+    assert_eq!(values, [json!(1), json!([2]), json!(1)]);
+}
+
+#[test]
+fn unparsable_output_has_no_values() {
+    assert!(values_of("plain text").is_none());
+}
+
+#[test]
+fn rank_puts_the_most_frequent_first() {
+    let values = [
+        json!("b"),
+        json!("a"),
+        json!("a"),
+        json!(1),
+        json!("b"),
+        json!("a"),
+    ];
+
+    let ranked = rank(&values, FrequencyMode::Values);
+
+    assert_eq!(
+        ranked,
+        [
+            Ranked {
+                label: "\"a\"".to_string(),
+                count: 3
+            },
+            Ranked {
+                label: "\"b\"".to_string(),
+                count: 2
+            },
+            Ranked {
+                label: "1".to_string(),
+                count: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn strings_and_numbers_stay_apart() {
+    let ranked = rank(&[json!("1"), json!(1)], FrequencyMode::Values);
+
+    assert_eq!(ranked.len(), 2);
+}
+
+#[test]
+fn key_sets_ignore_key_order_and_values() {
+    let values = [
+        json!({"id": 1, "name": "a"}),
+        json!({"name": "b", "id": 2}),
+        json!({"id": 3}),
+        json!(5),
+    ];
+
+    let ranked = rank(&values, FrequencyMode::Keys);
+
+    assert_eq!(ranked[0].label, "{id, name}");
+    assert_eq!(ranked[0].count, 2);
+    assert_eq!(ranked[1].label, "{id}");
+    assert_eq!(ranked[2].label, "(not an object)");
+}
+
+#[test]
+fn pipeline_wraps_a_stream_in_an_array() {
+    assert_eq!(
+        jq_pipeline(".[].role", false, FrequencyMode::Values),
+        "[.[].role] | group_by(.) | map({value: .[0], count: length}) | sort_by(-.count)"
+    );
+    assert_eq!(
+        jq_pipeline(".users", true, FrequencyMode::Keys),
+        ".users | group_by(keys) | map({keys: .[0] | keys, count: length}) | sort_by(-.count)"
+    );
+}
+
+#[test]
+fn objects_open_by_key_set() {
+    let mut popup = FrequencyPopup::new();
+
+    assert!(popup.open(r#"[{"a": 1}, {"a": 2}]"#, ".users"));
+
+    let data = popup.data().unwrap();
+    assert_eq!(data.mode(), FrequencyMode::Keys);
+    assert_eq!(data.ranked().len(), 1);
+    assert_eq!(data.total(), 2);
+}
+
+#[test]
+fn toggle_needs_objects() {
+    let mut popup = FrequencyPopup::new();
+    popup.open("[1, 2, 2]", ".");
+
+    popup.toggle_mode();
+
+    assert_eq!(popup.data().unwrap().mode(), FrequencyMode::Values);
+}
+
+#[test]
+fn toggle_recounts_from_the_top() {
+    let mut popup = FrequencyPopup::new();
+    popup.open(r#"[{"a": 1}, {"a": 1}, {"a": 2}]"#, ".");
+    popup.move_by(5);
+
+    popup.toggle_mode();
+
+    let data = popup.data().unwrap();
+    assert_eq!(data.mode(), FrequencyMode::Values);
+    assert_eq!(data.ranked()[0].label, r#"{"a":1}"#);
+    assert_eq!(popup.selected(), 0);
+}
+
+#[test]
+fn empty_result_does_not_open() {
+    let mut popup = FrequencyPopup::new();
+
+    assert!(!popup.open("[]", "."));
+    assert!(!popup.is_visible());
+}
@@ -0,0 +1,190 @@
+//! Dim the result lines the last pipeline stage left unchanged
+//!
+//! With `s` in the results pane, the query minus its last stage (`.a | .b`
+//! for `.a | .b | select(.c)`) also runs in the background, and the lines
+//! its output shares with the full result are dimmed. Lines the last stage
+//! produced stand out, and `▸` on the border marks where it dropped lines,
+//! which shows what an appended `select` kept and removed.
+
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::query::executor::JqExecutor;
+use crate::query::output_format::OutputFormat;
+use crate::query::worker::preprocess::strip_ansi_codes;
+use crate::results::result_diff::diff_lines;
+use crate::save::save_script::pipeline_prefixes;
+
+#[derive(Default)]
+pub struct LastStage {
+    enabled: bool,
+    /// The query without its last stage and the format it runs in
+    base: Option<(String, OutputFormat)>,
+    /// Output of the query without its last stage, once it has arrived
+    base_output: Option<String>,
+    cancel: Option<CancellationToken>,
+    response_rx: Option<Receiver<Option<String>>>,
+    /// Result `unchanged` and `removed_at` were worked out for
+    compared: Option<Arc<String>>,
+    /// Result lines also in the base output, ascending
+    unchanged: Vec<u32>,
+    /// Result lines where base lines were dropped, as in [`diff_lines`]
+    removed_at: Vec<u32>,
+}
+
+impl LastStage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Switched on with `base_output` as the output without the last stage
+    #[cfg(test)]
+    pub fn comparing_with(base_output: &str) -> Self {
+        let mut stage = Self::new();
+        stage.enabled = true;
+        stage.base_output = Some(base_output.to_string());
+        stage
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Switch the highlight on or off; returns the new setting
+    pub fn toggle(&mut self) -> bool {
+        self.enabled = !self.enabled;
+        if !self.enabled {
+            self.reset(None);
+        }
+        self.enabled
+    }
+
+    /// The query without its last stage, when it has more than one
+    pub fn base_query(query: &str) -> Option<&str> {
+        pipeline_prefixes(query).last().copied()
+    }
+
+    /// Compare the last stage of `query` from now on. When that changes
+    /// the stages before it, or the format, the comparison shown is dropped
+    /// and they run with `executor`; edits to the last stage alone reuse
+    /// their output.
+    pub fn set_query(&mut self, query: &str, executor: &JqExecutor, format: OutputFormat) {
+        if !self.enabled {
+            return;
+        }
+        let base = Self::base_query(query).map(|base| (base.to_string(), format));
+        if base == self.base {
+            return;
+        }
+        self.reset(base.clone());
+        if let Some((base, format)) = base {
+            self.start(executor.share(), base, format);
+        }
+    }
+
+    /// Forget the base output, which came from a document that has been
+    /// replaced; the next [`set_query`](Self::set_query) runs it again
+    pub fn invalidate(&mut self) {
+        self.reset(None);
+    }
+
+    fn reset(&mut self, base: Option<(String, OutputFormat)>) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.cancel();
+        }
+        self.response_rx = None;
+        self.base_output = None;
+        self.compared = None;
+        self.unchanged.clear();
+        self.removed_at.clear();
+        self.base = base;
+    }
+
+    fn start(&mut self, executor: JqExecutor, base: String, format: OutputFormat) {
+        let cancel = CancellationToken::new();
+        let (response_tx, response_rx) = channel();
+        let thread_cancel = cancel.clone();
+        std::thread::spawn(move || {
+            let output = executor
+                .execute_formatted(&base, format, &thread_cancel)
+                .ok()
+                .map(|output| strip_ansi_codes(&output));
+            if !thread_cancel.is_cancelled() {
+                let _ = response_tx.send(output);
+            }
+        });
+        self.cancel = Some(cancel);
+        self.response_rx = Some(response_rx);
+    }
+
+    /// Pick up the base output. Returns true when it arrived.
+    pub fn poll(&mut self) -> bool {
+        let Some(response_rx) = &self.response_rx else {
+            return false;
+        };
+        match response_rx.try_recv() {
+            Ok(output) => {
+                self.base_output = output;
+                self.response_rx = None;
+                self.cancel = None;
+                true
+            }
+            Err(TryRecvError::Empty) => false,
+            Err(TryRecvError::Disconnected) => {
+                self.response_rx = None;
+                self.cancel = None;
+                false
+            }
+        }
+    }
+
+    /// Compare `result`, the full query's output, with the base output
+    /// when it is a result not yet compared. Returns true when it was.
+    pub fn sync(&mut self, result: Option<&Arc<String>>) -> bool {
+        let (Some(base), Some(result)) = (&self.base_output, result) else {
+            return false;
+        };
+        if self
+            .compared
+            .as_ref()
+            .is_some_and(|compared| Arc::ptr_eq(compared, result))
+        {
+            return false;
+        }
+        let diff = diff_lines(base, result);
+        let line_count = result.lines().count() as u32;
+        self.unchanged = (0..line_count)
+            .filter(|line| diff.added.binary_search(line).is_err())
+            .collect();
+        self.removed_at = diff.removed_at;
+        self.compared = Some(Arc::clone(result));
+        true
+    }
+
+    /// Whether there is a comparison to show
+    pub fn is_visible(&self) -> bool {
+        self.enabled && self.compared.is_some()
+    }
+
+    pub fn is_unchanged(&self, line: u32) -> bool {
+        self.is_visible() && self.unchanged.binary_search(&line).is_ok()
+    }
+
+    pub fn is_removed_at(&self, line: u32) -> bool {
+        self.is_visible() && self.removed_at.binary_search(&line).is_ok()
+    }
+}
+
+impl Drop for LastStage {
+    fn drop(&mut self) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "last_stage_tests.rs"]
+mod last_stage_tests;
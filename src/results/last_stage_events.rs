@@ -0,0 +1,48 @@
+use crate::app::App;
+use crate::results::last_stage::LastStage;
+
+/// `s` — dim the lines the query's last stage left unchanged, or stop
+pub fn toggle(app: &mut App) {
+    if app.results_last_stage.toggle() {
+        let message = if LastStage::base_query(app.input.query()).is_some() {
+            "Last stage · lines it left unchanged are dimmed"
+        } else {
+            "Last stage · add a `|` stage to compare"
+        };
+        app.notification.show(message);
+    } else {
+        app.notification.show("Last stage highlight off");
+    }
+    poll(app);
+}
+
+/// Keep the comparison in step with the query and its result
+///
+/// Returns true when it changed and needs redrawing.
+pub fn poll(app: &mut App) -> bool {
+    if !app.results_last_stage.is_enabled() {
+        return false;
+    }
+    let Some(query_state) = &app.query else {
+        return false;
+    };
+    app.results_last_stage.set_query(
+        app.input.query(),
+        &query_state.executor,
+        query_state.output_format(),
+    );
+    let arrived = app.results_last_stage.poll();
+
+    // Only a result of the query as typed is compared
+    let current =
+        !app.debouncer.has_pending() && !query_state.is_pending() && query_state.result.is_ok();
+    let result = query_state
+        .last_successful_result_unformatted
+        .as_ref()
+        .filter(|_| current);
+    app.results_last_stage.sync(result) || arrived
+}
+
+#[cfg(test)]
+#[path = "last_stage_events_tests.rs"]
+mod last_stage_events_tests;
@@ -0,0 +1,94 @@
+//! Tests for results/last_stage_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{execute_query_and_wait, key, test_app};
+use ratatui::crossterm::event::KeyCode;
+use std::time::{Duration, Instant};
+
+const JSON: &str = r#"[{"a": 1}, {"a": 2}, {"a": 3}]"#;
+
+fn app_with_query(query: &str) -> App {
+    let mut app = test_app(JSON);
+    app.input.textarea.insert_str(query);
+    execute_query_and_wait(&mut app);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+fn wait_for_comparison(app: &mut App) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(2) {
+        super::poll(app);
+        if app.results_last_stage.is_visible() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    false
+}
+
+#[test]
+fn test_s_toggles_the_highlight() {
+    let mut app = app_with_query(".[] | select(.a > 1)");
+
+    app.handle_key_event(key(KeyCode::Char('s')));
+    assert!(app.results_last_stage.is_enabled());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Last stage · lines it left unchanged are dimmed")
+    );
+
+    app.handle_key_event(key(KeyCode::Char('s')));
+    assert!(!app.results_last_stage.is_enabled());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Last stage highlight off")
+    );
+}
+
+#[test]
+fn test_single_stage_query_explains_what_is_compared() {
+    let mut app = app_with_query(".[0]");
+
+    app.handle_key_event(key(KeyCode::Char('s')));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Last stage · add a `|` stage to compare")
+    );
+}
+
+#[test]
+fn test_poll_compares_the_result_with_the_query_before_its_last_stage() {
+    let mut app = app_with_query(".[] | select(.a > 1)");
+    app.handle_key_event(key(KeyCode::Char('s')));
+
+    assert!(wait_for_comparison(&mut app));
+
+    // Both kept values were already in `.[]`; the dropped one is marked
+    assert!((0..6).all(|line| app.results_last_stage.is_unchanged(line)));
+    assert!(app.results_last_stage.is_removed_at(1));
+}
+
+#[test]
+fn test_lines_the_last_stage_changed_stay_bright() {
+    let mut app = app_with_query(".[] | .b = 1");
+    app.handle_key_event(key(KeyCode::Char('s')));
+
+    assert!(wait_for_comparison(&mut app));
+
+    assert!(app.results_last_stage.is_unchanged(0));
+    assert!(!app.results_last_stage.is_unchanged(2));
+}
+
+#[test]
+fn test_new_document_drops_the_comparison() {
+    let mut app = app_with_query(".[] | select(.a > 1)");
+    app.handle_key_event(key(KeyCode::Char('s')));
+    assert!(wait_for_comparison(&mut app));
+
+    app.set_raw_input(false);
+
+    assert!(app.results_last_stage.is_enabled());
+    assert!(!app.results_last_stage.is_visible());
+}
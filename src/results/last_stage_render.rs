@@ -0,0 +1,37 @@
+use ratatui::text::Text;
+
+use crate::results::fold_state::FoldState;
+use crate::results::last_stage::LastStage;
+use crate::theme;
+
+/// Mute the lines the last pipeline stage left as they were
+pub fn apply_dimming(
+    text: Text<'static>,
+    last_stage: &LastStage,
+    folds: &FoldState,
+    scroll_offset: u16,
+) -> Text<'static> {
+    let unchanged_fg = theme::results::stage_unchanged_fg();
+    let lines = text
+        .lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut line)| {
+            let row = index as u32 + scroll_offset as u32;
+            if folds
+                .line_at(row)
+                .is_some_and(|line| last_stage.is_unchanged(line))
+            {
+                for span in &mut line.spans {
+                    span.style = span.style.fg(unchanged_fg);
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+#[cfg(test)]
+#[path = "last_stage_render_tests.rs"]
+mod last_stage_render_tests;
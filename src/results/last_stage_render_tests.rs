@@ -0,0 +1,62 @@
+//! Tests for results/last_stage_render
+
+use crate::app::App;
+use crate::results::last_stage::LastStage;
+use crate::test_utils::test_helpers::test_app;
+use crate::theme;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+const BASE: &str = "{\n  \"a\": 1\n}\n{\n  \"a\": 2\n}";
+const RESULT: &str = "{\n  \"a\": 1\n}\n{\n  \"a\": 2,\n  \"b\": 3\n}";
+
+fn app_comparing() -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"[{"a": 1}, {"a": 2}]"#);
+    let arc = Arc::new(RESULT.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(RESULT.to_string()));
+    q.result = Ok(RESULT.to_string());
+    q.cached_line_count = RESULT.lines().count() as u32;
+    q.is_empty_result = false;
+    app.results_last_stage = LastStage::comparing_with(BASE);
+    app.results_last_stage.sync(Some(&arc));
+    app
+}
+
+#[test]
+fn unchanged_lines_are_dimmed() {
+    let mut app = app_comparing();
+    let mut terminal = create_test_terminal(40, 12);
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    // Row 2 of the pane is `"a": 1`, row 6 the added `"b": 3`
+    let unchanged = Some(theme::results::stage_unchanged_fg());
+    assert_eq!(buffer[(3, 2)].style().fg, unchanged);
+    assert_ne!(buffer[(3, 6)].style().fg, unchanged);
+}
+
+#[test]
+fn title_says_the_last_stage_is_compared() {
+    let mut app = app_comparing();
+
+    let output = render_to_string(&mut app, 60, 12);
+
+    assert!(output.contains("· last stage"), "{output}");
+}
@@ -0,0 +1,132 @@
+//! Tests for results/last_stage
+
+use super::*;
+use std::time::{Duration, Instant};
+
+const BASE: &str = "{\n  \"a\": 1\n}\n{\n  \"a\": 2\n}\n{\n  \"a\": 3\n}";
+
+fn wait_for_base(stage: &mut LastStage) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(2) {
+        if stage.poll() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    false
+}
+
+#[test]
+fn test_base_query_drops_the_last_stage() {
+    assert_eq!(
+        LastStage::base_query(".a | .b | select(.c)"),
+        Some(".a | .b")
+    );
+    assert_eq!(LastStage::base_query("map(.a | .b)"), None);
+    assert_eq!(LastStage::base_query(".a"), None);
+}
+
+#[test]
+fn test_lines_kept_by_a_select_are_unchanged() {
+    let mut stage = LastStage::comparing_with(BASE);
+    let result = Arc::new("{\n  \"a\": 2\n}\n{\n  \"a\": 3\n}".to_string());
+
+    assert!(stage.sync(Some(&result)));
+
+    assert!(stage.is_visible());
+    assert!((0..6).all(|line| stage.is_unchanged(line)));
+    // The lines of the dropped first value are marked where they went
+    assert!(stage.is_removed_at(1));
+    assert!(!stage.is_removed_at(3));
+}
+
+#[test]
+fn test_lines_the_stage_added_are_not_unchanged() {
+    let mut stage = LastStage::comparing_with(BASE);
+    let result = Arc::new("{\n  \"a\": 1,\n  \"b\": true\n}".to_string());
+
+    stage.sync(Some(&result));
+
+    assert!(stage.is_unchanged(0));
+    assert!(!stage.is_unchanged(1));
+    assert!(!stage.is_unchanged(2));
+    assert!(stage.is_unchanged(3));
+}
+
+#[test]
+fn test_same_result_is_compared_once() {
+    let mut stage = LastStage::comparing_with(BASE);
+    let result = Arc::new(BASE.to_string());
+
+    assert!(stage.sync(Some(&result)));
+    assert!(!stage.sync(Some(&result)));
+}
+
+#[test]
+fn test_nothing_is_shown_once_switched_off() {
+    let mut stage = LastStage::comparing_with(BASE);
+    stage.sync(Some(&Arc::new(BASE.to_string())));
+
+    assert!(!stage.toggle());
+
+    assert!(!stage.is_visible());
+    assert!(!stage.is_unchanged(0));
+}
+
+#[test]
+fn test_set_query_runs_the_stages_before_the_last() {
+    let executor = JqExecutor::new(r#"[{"a": 1}, {"a": 2}]"#.to_string());
+    let mut stage = LastStage::new();
+    stage.toggle();
+
+    stage.set_query(".[] | select(.a > 1)", &executor, OutputFormat::default());
+
+    assert!(wait_for_base(&mut stage));
+    assert_eq!(
+        stage.base_output.as_deref(),
+        Some("{\n  \"a\": 1\n}\n{\n  \"a\": 2\n}\n")
+    );
+}
+
+#[test]
+fn test_editing_only_the_last_stage_keeps_the_base_output() {
+    let executor = JqExecutor::new(r#"[{"a": 1}]"#.to_string());
+    let mut stage = LastStage::new();
+    stage.toggle();
+    stage.set_query(".[] | .a", &executor, OutputFormat::default());
+    assert!(wait_for_base(&mut stage));
+
+    stage.set_query(".[] | .b", &executor, OutputFormat::default());
+
+    assert!(stage.base_output.is_some());
+    assert!(stage.response_rx.is_none());
+}
+
+#[test]
+fn test_single_stage_query_runs_nothing() {
+    let executor = JqExecutor::new("{}".to_string());
+    let mut stage = LastStage::new();
+    stage.toggle();
+
+    stage.set_query(".a", &executor, OutputFormat::default());
+
+    assert!(stage.response_rx.is_none());
+    assert!(!stage.is_visible());
+}
+
+#[test]
+fn test_invalidate_runs_the_base_again_for_the_same_query() {
+    let executor = JqExecutor::new(r#"[{"a": 1}]"#.to_string());
+    let mut stage = LastStage::new();
+    stage.toggle();
+    stage.set_query(".[] | .a", &executor, OutputFormat::default());
+    assert!(wait_for_base(&mut stage));
+
+    stage.invalidate();
+    assert!(stage.base_output.is_none());
+    let other = JqExecutor::new(r#"[{"a": 2}]"#.to_string());
+    stage.set_query(".[] | .a", &other, OutputFormat::default());
+
+    assert!(wait_for_base(&mut stage));
+    assert_eq!(stage.base_output.as_deref(), Some("{\n  \"a\": 2\n}\n"));
+}
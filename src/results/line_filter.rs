@@ -0,0 +1,315 @@
+//! Client-side line operations on the displayed result
+//!
+//! Opened with `|` in the results pane, the line filter bar takes a small
+//! pipeline such as `grep name | sort | uniq` and shows the result's lines
+//! through it without touching the jq query. It works on the printed text,
+//! so it is meant for eyeballing: the filtered view has no cursor, paths or
+//! folds, and Esc returns to the full result.
+//!
+//! Steps compare lines without their indentation, so `sort` and `uniq` line
+//! up values printed at different depths.
+
+use std::sync::Arc;
+
+use ratatui::style::Style;
+use tui_textarea::TextArea;
+
+use crate::theme;
+
+/// Lines `head` and `tail` keep when no count is given
+const DEFAULT_COUNT: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineOp {
+    /// Keep lines containing the pattern, or drop them when inverted
+    Grep {
+        pattern: String,
+        ignore_case: bool,
+        invert: bool,
+    },
+    Sort {
+        reverse: bool,
+    },
+    /// Drop lines equal to the one before them
+    Uniq,
+    Head(usize),
+    Tail(usize),
+}
+
+/// Parse a `|`-separated pipeline of line operations
+pub fn parse_pipeline(input: &str) -> Result<Vec<LineOp>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    input.split('|').map(parse_step).collect()
+}
+
+fn parse_step(step: &str) -> Result<LineOp, String> {
+    let step = step.trim();
+    let (command, rest) = step.split_once(' ').unwrap_or((step, ""));
+    let rest = rest.trim();
+
+    match command {
+        "grep" => {
+            let (flags, pattern) = split_flags(command, rest, "iv")?;
+            let pattern = unquote(pattern);
+            if pattern.is_empty() {
+                return Err("grep needs a pattern".to_string());
+            }
+            Ok(LineOp::Grep {
+                pattern: pattern.to_string(),
+                ignore_case: flags.contains('i'),
+                invert: flags.contains('v'),
+            })
+        }
+        "sort" => {
+            let (flags, rest) = split_flags(command, rest, "r")?;
+            no_arguments(command, rest)?;
+            Ok(LineOp::Sort {
+                reverse: flags.contains('r'),
+            })
+        }
+        "uniq" => {
+            no_arguments(command, rest)?;
+            Ok(LineOp::Uniq)
+        }
+        "head" => Ok(LineOp::Head(parse_count(command, rest)?)),
+        "tail" => Ok(LineOp::Tail(parse_count(command, rest)?)),
+        "" => Err("Empty step".to_string()),
+        other => Err(format!(
+            "Unknown step '{}': use grep, sort, uniq, head or tail",
+            other
+        )),
+    }
+}
+
+/// Split leading `-x` flags off `rest`, checking each is in `allowed`
+fn split_flags<'a>(
+    command: &str,
+    rest: &'a str,
+    allowed: &str,
+) -> Result<(String, &'a str), String> {
+    let mut flags = String::new();
+    let mut rest = rest;
+    while let Some(word) = rest.strip_prefix('-') {
+        let (word, remaining) = word.split_once(' ').unwrap_or((word, ""));
+        if let Some(flag) = word.chars().find(|&flag| !allowed.contains(flag)) {
+            return Err(format!("{} has no -{} option", command, flag));
+        }
+        flags.push_str(word);
+        rest = remaining.trim_start();
+    }
+    Ok((flags, rest))
+}
+
+fn no_arguments(command: &str, words: &str) -> Result<(), String> {
+    if words.trim().is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} takes no argument", command))
+    }
+}
+
+/// `head`, `head 5`, `head -5` and `head -n 5` are all accepted
+fn parse_count(command: &str, rest: &str) -> Result<usize, String> {
+    let count = rest.strip_prefix("-n").unwrap_or(rest).trim();
+    let count = count.strip_prefix('-').unwrap_or(count);
+    if count.is_empty() {
+        return Ok(DEFAULT_COUNT);
+    }
+    count
+        .parse()
+        .map_err(|_| format!("{} needs a line count, not '{}'", command, rest))
+}
+
+fn unquote(pattern: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|&quote| {
+            pattern
+                .strip_prefix(quote)
+                .and_then(|p| p.strip_suffix(quote))
+        })
+        .unwrap_or(pattern)
+}
+
+/// Run `ops` over `lines`, returning the indices of the lines left, in
+/// display order
+pub fn apply(ops: &[LineOp], lines: &[&str]) -> Vec<u32> {
+    let mut rows: Vec<u32> = (0..lines.len() as u32).collect();
+    let text = |row: &u32| lines[*row as usize].trim_start();
+    for op in ops {
+        match op {
+            LineOp::Grep {
+                pattern,
+                ignore_case,
+                invert,
+            } => {
+                let needle = if *ignore_case {
+                    pattern.to_lowercase()
+                } else {
+                    pattern.clone()
+                };
+                rows.retain(|row| {
+                    let found = if *ignore_case {
+                        text(row).to_lowercase().contains(&needle)
+                    } else {
+                        text(row).contains(&needle)
+                    };
+                    found != *invert
+                });
+            }
+            LineOp::Sort { reverse } => {
+                rows.sort_by(|a, b| text(a).cmp(text(b)));
+                if *reverse {
+                    rows.reverse();
+                }
+            }
+            LineOp::Uniq => rows.dedup_by(|a, b| text(a) == text(b)),
+            LineOp::Head(count) => rows.truncate(*count),
+            LineOp::Tail(count) => {
+                let start = rows.len().saturating_sub(*count);
+                rows.drain(..start);
+            }
+        }
+    }
+    rows
+}
+
+fn create_filter_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(theme::palette::cursor());
+    textarea
+}
+
+pub struct LineFilterState {
+    /// Whether the bar is open for typing
+    editing: bool,
+    textarea: TextArea<'static>,
+    /// Pipeline in effect; empty when the full result is shown
+    ops: Vec<LineOp>,
+    /// Why the typed pipeline could not be parsed; the last valid one stays
+    /// in effect meanwhile
+    error: Option<String>,
+    /// Result text the rows were computed from
+    source: Option<Arc<String>>,
+    /// Output line shown on each row of the filtered view
+    rows: Vec<u32>,
+    /// Lines in the unfiltered result
+    total: u32,
+}
+
+impl Default for LineFilterState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineFilterState {
+    pub fn new() -> Self {
+        Self {
+            editing: false,
+            textarea: create_filter_textarea(),
+            ops: Vec::new(),
+            error: None,
+            source: None,
+            rows: Vec::new(),
+            total: 0,
+        }
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Whether the results pane shows the filtered view
+    pub fn is_active(&self) -> bool {
+        !self.ops.is_empty()
+    }
+
+    pub fn open(&mut self) {
+        self.editing = true;
+    }
+
+    /// Stop editing, keeping the pipeline; closes entirely when it is
+    /// empty. A pipeline that does not parse stays open for fixing.
+    pub fn confirm(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+        if self.is_active() {
+            self.editing = false;
+        } else {
+            self.close();
+        }
+    }
+
+    /// Drop the pipeline and show the full result again
+    pub fn close(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn text(&self) -> &str {
+        self.textarea.lines().first().map_or("", String::as_str)
+    }
+
+    pub fn textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.textarea
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Re-parse the typed pipeline after an edit
+    pub fn update(&mut self) {
+        match parse_pipeline(self.text()) {
+            Ok(ops) => {
+                self.ops = ops;
+                self.error = None;
+                // Force the rows to be recomputed on the next sync
+                self.source = None;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    /// Recompute the rows when `source` is a different result
+    pub fn sync(&mut self, source: Option<&Arc<String>>) {
+        let unchanged = match (&self.source, source) {
+            (Some(current), Some(new)) => Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged || !self.is_active() {
+            return;
+        }
+        self.source = source.cloned();
+        let lines: Vec<&str> = self
+            .source
+            .as_ref()
+            .map(|text| text.lines().collect())
+            .unwrap_or_default();
+        self.total = lines.len() as u32;
+        self.rows = apply(&self.ops, &lines);
+    }
+
+    pub fn row_count(&self) -> u32 {
+        self.rows.len() as u32
+    }
+
+    /// Lines in the result the rows were picked from
+    pub fn total_lines(&self) -> u32 {
+        self.total
+    }
+
+    /// Output lines in display order
+    pub fn rows(&self) -> &[u32] {
+        &self.rows
+    }
+}
+
+#[cfg(test)]
+#[path = "line_filter_tests.rs"]
+mod line_filter_tests;
@@ -0,0 +1,70 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::clipboard;
+use crate::results::results_events::{CURSOR_KEYS, scroll_without_cursor};
+
+/// `|` — open the line filter bar, closing a search first since the
+/// filtered view has no matches to show
+pub fn open_bar(app: &mut App) {
+    if app.search.is_visible() {
+        crate::search::search_events::close_search(app);
+    }
+    app.results_filter.open();
+}
+
+/// Keys while typing in the line filter bar. Every key is taken so the
+/// pipeline can contain any character.
+pub fn handle_bar_key(app: &mut App, key: KeyEvent) -> bool {
+    if !app.results_filter.is_editing() {
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Esc => close(app),
+        KeyCode::Enter | KeyCode::Tab => app.results_filter.confirm(),
+        _ => {
+            app.results_filter.textarea_mut().input(key);
+            app.results_filter.update();
+            app.results_scroll.jump_to_top();
+        }
+    }
+    true
+}
+
+/// Keys in the results pane while the filtered view is shown. It has no
+/// cursor, so the movement keys scroll and keys that act on the cursor's
+/// line ask for the filter to be cleared first. Returns false for keys
+/// the pane handles as usual.
+pub fn handle_filtered_view_key(app: &mut App, key: KeyEvent) -> bool {
+    if !app.results_filter.is_active() {
+        return false;
+    }
+
+    if scroll_without_cursor(app, key) {
+        return true;
+    }
+    match key.code {
+        KeyCode::Esc => close(app),
+        KeyCode::Char('|') => app.results_filter.open(),
+        KeyCode::Char('y') => {
+            clipboard::clipboard_events::handle_yank_key(app, app.clipboard_backend);
+        }
+        KeyCode::Char(c) if CURSOR_KEYS.contains(c) || "T=".contains(c) => {
+            app.notification
+                .show("Line filter is on · Esc shows the full result");
+        }
+        _ => return false,
+    }
+    true
+}
+
+fn close(app: &mut App) {
+    app.results_filter.close();
+    app.results_scroll.jump_to_top();
+    app.ensure_results_cursor_visible();
+}
+
+#[cfg(test)]
+#[path = "line_filter_events_tests.rs"]
+mod line_filter_events_tests;
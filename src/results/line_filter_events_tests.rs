@@ -0,0 +1,107 @@
+//! Tests for results/line_filter_events
+
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+fn app_in_results() -> crate::app::App {
+    let mut app = app_with_query(".");
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+fn type_str(app: &mut crate::app::App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_pipe_opens_the_bar_and_typing_applies_the_filter() {
+    let mut app = app_in_results();
+
+    app.handle_key_event(key(KeyCode::Char('|')));
+    assert!(app.results_filter.is_editing());
+
+    type_str(&mut app, "grep name");
+    assert!(app.results_filter.is_active());
+    assert_eq!(app.results_filter.text(), "grep name");
+}
+
+#[test]
+fn test_enter_keeps_the_filter_and_leaves_the_bar() {
+    let mut app = app_in_results();
+    app.handle_key_event(key(KeyCode::Char('|')));
+    type_str(&mut app, "sort");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.results_filter.is_editing());
+    assert!(app.results_filter.is_active());
+}
+
+#[test]
+fn test_bar_takes_keys_the_pane_would_handle() {
+    let mut app = app_in_results();
+    app.handle_key_event(key(KeyCode::Char('|')));
+
+    type_str(&mut app, "grep j");
+
+    assert_eq!(app.results_filter.text(), "grep j");
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+}
+
+#[test]
+fn test_esc_in_the_filtered_view_shows_the_full_result() {
+    let mut app = app_in_results();
+    app.handle_key_event(key(KeyCode::Char('|')));
+    type_str(&mut app, "uniq");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.results_filter.is_active());
+    assert!(!app.results_filter.is_editing());
+}
+
+#[test]
+fn test_esc_in_the_bar_clears_the_filter() {
+    let mut app = app_in_results();
+    app.handle_key_event(key(KeyCode::Char('|')));
+    type_str(&mut app, "uniq");
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.results_filter.is_active());
+}
+
+#[test]
+fn test_cursor_keys_need_the_full_result() {
+    let mut app = app_in_results();
+    app.handle_key_event(key(KeyCode::Char('|')));
+    type_str(&mut app, "uniq");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('>')));
+
+    assert_eq!(app.input.textarea.lines()[0], ".");
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Line filter is on · Esc shows the full result")
+    );
+}
+
+#[test]
+fn test_movement_keys_scroll_the_filtered_view() {
+    let mut app = app_in_results();
+    app.handle_key_event(key(KeyCode::Char('|')));
+    type_str(&mut app, "uniq");
+    app.handle_key_event(key(KeyCode::Enter));
+    app.results_scroll.update_bounds(20, 5);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key_with_mods(KeyCode::Char('d'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.results_scroll.offset, 3);
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+}
@@ -0,0 +1,82 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders},
+};
+
+use crate::app::App;
+use crate::theme;
+
+const PLACEHOLDER: &str = "grep [-iv] text | sort [-r] | uniq | head N | tail N";
+
+/// The line filter bar, styled like the search bar it replaces. A pipeline
+/// that does not parse shows why on the right of the border.
+pub fn render_bar(app: &mut App, frame: &mut Frame, area: Rect) {
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Line filter ")
+        .border_style(Style::default().fg(theme::search::border_active()))
+        .style(Style::default().bg(theme::search::background()));
+
+    if let Some(error) = app.results_filter.error() {
+        block = block.title_top(
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(format!("  {}  ", error), theme::search::badge_no_matches()),
+                Span::raw(" "),
+            ])
+            .alignment(Alignment::Right),
+        );
+    }
+    let hints: Vec<(&'static str, &'static str)> = vec![("Enter", "Apply"), ("Esc", "Clear")];
+    block = block.title_bottom(
+        theme::border_hints::build_hints(&hints, theme::search::hints())
+            .alignment(Alignment::Center),
+    );
+
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let textarea = app.results_filter.textarea_mut();
+    textarea.set_style(
+        Style::default()
+            .fg(theme::search::text_active())
+            .bg(theme::search::background()),
+    );
+    textarea.set_placeholder_text(PLACEHOLDER);
+    textarea.set_placeholder_style(Style::default().fg(theme::search::text_inactive()));
+    frame.render_widget(&*textarea, inner_area);
+}
+
+/// The line filter's rows from `offset`, in the filter's order
+pub fn viewport(
+    rendered: &Text<'static>,
+    rows: &[u32],
+    offset: usize,
+    height: usize,
+) -> Text<'static> {
+    let lines = rows
+        .iter()
+        .skip(offset)
+        .take(height)
+        .filter_map(|&line| rendered.lines.get(line as usize).cloned())
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+/// Keys for the bottom border while the filter applies
+pub fn hints() -> Line<'static> {
+    let hints: Vec<(&'static str, &'static str)> = vec![
+        ("|", "Edit Filter"),
+        ("y", "Copy Shown"),
+        ("Esc", "Full Result"),
+    ];
+    theme::border_hints::build_hints(&hints, theme::results::hint_key())
+}
+
+#[cfg(test)]
+#[path = "line_filter_render_tests.rs"]
+mod line_filter_render_tests;
@@ -0,0 +1,71 @@
+//! Tests for results/line_filter_render
+
+use crate::app::App;
+use crate::test_utils::test_helpers::{key, test_app};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+const CONTENT: &str = "[\n  \"pear\",\n  \"apple\",\n  \"pear\",\n  \"fig\"\n]";
+
+fn app_with_filter(pipeline: &str, confirm: bool) -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"["pear"]"#);
+    let arc = Arc::new(CONTENT.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(CONTENT.to_string()));
+    q.result = Ok(CONTENT.to_string());
+    q.cached_line_count = CONTENT.lines().count() as u32;
+    q.is_empty_result = false;
+    app.focus_results_pane();
+    app.handle_key_event(key(KeyCode::Char('|')));
+    for c in pipeline.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    if confirm {
+        app.handle_key_event(key(KeyCode::Enter));
+    }
+    app.notification.dismiss();
+    app
+}
+
+#[test]
+fn snapshot_results_through_line_filter() {
+    let mut app = app_with_filter("grep \" | sort | uniq", true);
+    let output = render_to_string(&mut app, 70, 10);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn filtered_view_counts_shown_lines() {
+    let mut app = app_with_filter("grep pear", true);
+    let output = render_to_string(&mut app, 70, 10);
+    assert!(output.contains("2 of 6 lines"), "{output}");
+    assert!(!output.contains("apple"), "{output}");
+}
+
+#[test]
+fn bar_shows_the_pipeline_and_parse_errors() {
+    let mut app = app_with_filter("wc", false);
+    let output = render_to_string(&mut app, 100, 14);
+    assert!(output.contains("Line filter"), "{output}");
+    assert!(output.contains("Unknown step 'wc'"), "{output}");
+    // Nothing parsed yet, so the full result stays
+    assert!(output.contains("apple"), "{output}");
+}
@@ -0,0 +1,163 @@
+//! Tests for results/line_filter
+
+use super::*;
+
+const RESULT: &str = r#"[
+  "pear",
+  "apple",
+  "Pear",
+  "apple",
+  "fig"
+]"#;
+
+fn run(pipeline: &str) -> Vec<&'static str> {
+    let lines: Vec<&str> = RESULT.lines().collect();
+    let ops = parse_pipeline(pipeline).unwrap();
+    apply(&ops, &lines)
+        .into_iter()
+        .map(|row| RESULT.lines().nth(row as usize).unwrap().trim())
+        .collect()
+}
+
+#[test]
+fn test_grep_keeps_matching_lines() {
+    assert_eq!(run("grep pear"), vec![r#""pear","#]);
+}
+
+#[test]
+fn test_grep_ignore_case_and_invert() {
+    assert_eq!(run("grep -i pear"), vec![r#""pear","#, r#""Pear","#]);
+    assert_eq!(run("grep -v \""), vec!["[", "]"]);
+    assert_eq!(run("grep -vi e | grep -v ["), vec![r#""fig""#, "]"]);
+}
+
+#[test]
+fn test_grep_pattern_may_be_quoted() {
+    assert_eq!(run("grep \"fig\""), vec![r#""fig""#]);
+    assert_eq!(run("grep 'ap'"), vec![r#""apple","#, r#""apple","#]);
+}
+
+#[test]
+fn test_sort_then_uniq_dedupes() {
+    assert_eq!(
+        run("grep \" | sort | uniq"),
+        vec![r#""Pear","#, r#""apple","#, r#""fig""#, r#""pear","#]
+    );
+}
+
+#[test]
+fn test_sort_reverse() {
+    assert_eq!(
+        run("grep a | sort -r"),
+        vec![r#""pear","#, r#""apple","#, r#""apple","#, r#""Pear","#]
+    );
+}
+
+#[test]
+fn test_uniq_only_drops_adjacent_repeats() {
+    assert_eq!(run("grep apple | uniq"), vec![r#""apple","#]);
+    assert_eq!(run("uniq").len(), 7);
+}
+
+#[test]
+fn test_head_and_tail_counts() {
+    assert_eq!(run("head 2"), vec!["[", r#""pear","#]);
+    assert_eq!(run("head -n 1"), vec!["["]);
+    assert_eq!(run("tail -1"), vec!["]"]);
+    assert_eq!(run("tail").len(), 7);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(
+        parse_pipeline("wc -l")
+            .unwrap_err()
+            .contains("Unknown step 'wc'")
+    );
+    assert_eq!(parse_pipeline("grep").unwrap_err(), "grep needs a pattern");
+    assert_eq!(
+        parse_pipeline("sort -x").unwrap_err(),
+        "sort has no -x option"
+    );
+    assert_eq!(
+        parse_pipeline("uniq -c").unwrap_err(),
+        "uniq takes no argument"
+    );
+    assert_eq!(parse_pipeline("sort |").unwrap_err(), "Empty step");
+    assert!(
+        parse_pipeline("head lots")
+            .unwrap_err()
+            .contains("line count")
+    );
+}
+
+#[test]
+fn test_empty_pipeline_is_no_filter() {
+    assert_eq!(parse_pipeline("  "), Ok(Vec::new()));
+}
+
+#[test]
+fn test_state_keeps_last_valid_pipeline_while_typing() {
+    let mut state = LineFilterState::new();
+    state.open();
+    state.textarea_mut().insert_str("grep fig");
+    state.update();
+    assert!(state.is_active());
+
+    state.textarea_mut().insert_str(" | sor");
+    state.update();
+    assert!(state.error().is_some());
+    assert!(state.is_active());
+
+    state.sync(Some(&Arc::new(RESULT.to_string())));
+    assert_eq!(state.rows(), &[5]);
+    assert_eq!(state.total_lines(), 7);
+}
+
+#[test]
+fn test_confirm_keeps_filter_and_stops_editing() {
+    let mut state = LineFilterState::new();
+    state.open();
+    state.textarea_mut().insert_str("grep a");
+    state.update();
+
+    state.confirm();
+
+    assert!(!state.is_editing());
+    assert!(state.is_active());
+}
+
+#[test]
+fn test_confirm_with_parse_error_stays_open() {
+    let mut state = LineFilterState::new();
+    state.open();
+    state.textarea_mut().insert_str("nope");
+    state.update();
+
+    state.confirm();
+
+    assert!(state.is_editing());
+}
+
+#[test]
+fn test_confirm_empty_pipeline_closes() {
+    let mut state = LineFilterState::new();
+    state.open();
+
+    state.confirm();
+
+    assert!(!state.is_editing());
+    assert!(!state.is_active());
+}
+
+#[test]
+fn test_new_result_recomputes_rows() {
+    let mut state = LineFilterState::new();
+    state.textarea_mut().insert_str("grep 1");
+    state.update();
+    state.sync(Some(&Arc::new("[\n  1,\n  2\n]".to_string())));
+    assert_eq!(state.row_count(), 1);
+
+    state.sync(Some(&Arc::new("[\n  1,\n  1\n]".to_string())));
+    assert_eq!(state.row_count(), 2);
+}
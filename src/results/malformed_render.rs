@@ -0,0 +1,93 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::input::malformed::{MalformedInput, Recovery};
+use crate::scroll::ScrollState;
+use crate::theme;
+
+/// Render input that failed to parse in place of the results: its text
+/// with line numbers, the line at fault marked and the character at
+/// fault highlighted, and the ways to load it anyway on the border
+pub fn render(malformed: &MalformedInput, scroll: &mut ScrollState, frame: &mut Frame, area: Rect) {
+    let error_color = theme::results::border_error();
+    let hints: Vec<(&'static str, &'static str)> =
+        Recovery::ALL.iter().map(|r| (r.key(), r.label())).collect();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Span::styled(
+            format!(" {} ", malformed.summary()),
+            Style::default()
+                .fg(error_color)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(
+            theme::border_hints::build_hints(&hints, theme::results::hint_key())
+                .alignment(Alignment::Center),
+        )
+        .border_style(Style::default().fg(error_color))
+        .style(Style::default().bg(theme::results::background()));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut text: Vec<&str> = malformed.text.lines().collect();
+    // Parsing can stop past the last line, as when the text ends early
+    text.resize(text.len().max(malformed.line), "");
+    scroll.update_bounds(text.len() as u32, inner.height);
+    let gutter = text.len().to_string().len();
+    let lines: Vec<Line> = text
+        .into_iter()
+        .enumerate()
+        .skip(scroll.offset as usize)
+        .take(inner.height as usize)
+        .map(|(i, text)| numbered_line(malformed, i + 1, text, gutter))
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn numbered_line(
+    malformed: &MalformedInput,
+    number: usize,
+    text: &str,
+    gutter: usize,
+) -> Line<'static> {
+    let muted = Style::default().fg(theme::palette::text_muted());
+    let text_style = Style::default().fg(theme::palette::text());
+    if number != malformed.line {
+        return Line::from(vec![
+            Span::styled(format!("  {:>gutter$} ", number), muted),
+            Span::styled(text.to_string(), text_style),
+        ]);
+    }
+
+    let error_style = Style::default().fg(theme::results::border_error());
+    let mut spans = vec![Span::styled(
+        format!("▶ {:>gutter$} ", number),
+        error_style.add_modifier(Modifier::BOLD),
+    )];
+    // The column is the one just read, or 0 before the line's first
+    let at = malformed.column.saturating_sub(1);
+    let before: String = text.chars().take(at).collect();
+    let culprit: String = text.chars().skip(at).take(1).collect();
+    let after: String = text.chars().skip(at + 1).collect();
+    spans.push(Span::styled(before, error_style));
+    spans.push(Span::styled(
+        if culprit.is_empty() {
+            " ".to_string()
+        } else {
+            culprit
+        },
+        error_style.add_modifier(Modifier::REVERSED | Modifier::BOLD),
+    ));
+    spans.push(Span::styled(after, error_style));
+    Line::from(spans)
+}
+
+#[cfg(test)]
+#[path = "malformed_render_tests.rs"]
+mod malformed_render_tests;
@@ -0,0 +1,60 @@
+use super::*;
+use crate::headless::buffer_text;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+
+fn draw(text: &str, width: u16, height: u16) -> Buffer {
+    let malformed = MalformedInput::find(text.to_string()).unwrap();
+    let mut scroll = ScrollState::new();
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| render(&malformed, &mut scroll, f, f.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn snapshot_malformed_input() {
+    let buffer = draw("{\n  \"a\": 1,\n  \"b\" 2\n}\n", 70, 8);
+    insta::assert_snapshot!(buffer_text(&buffer));
+}
+
+#[test]
+fn offending_character_is_highlighted() {
+    let buffer = draw("[1, 2,, 3]", 40, 5);
+    let text = buffer_text(&buffer);
+    let row = text.lines().position(|l| l.contains("[1, 2,, 3]")).unwrap();
+    let line = text.lines().nth(row).unwrap();
+    let col = line[..line.find("[1").unwrap()].chars().count() as u16;
+
+    // The second comma of `,,`
+    let culprit = &buffer[(col + 6, row as u16)];
+    assert_eq!(culprit.symbol(), ",");
+    assert!(culprit.modifier.contains(Modifier::REVERSED));
+    assert!(
+        !buffer[(col + 5, row as u16)]
+            .modifier
+            .contains(Modifier::REVERSED)
+    );
+}
+
+#[test]
+fn error_at_the_end_of_a_line_marks_a_space() {
+    // serde stops after the newline, at column 0 of line 2
+    let buffer = draw("[1,\n", 40, 5);
+    let text = buffer_text(&buffer);
+    assert!(text.contains("▶ 2"), "{text}");
+}
+
+#[test]
+fn scroll_is_clamped_to_the_text() {
+    let malformed = MalformedInput::find("{\n\"a\" 1\n}".to_string()).unwrap();
+    let mut scroll = ScrollState::new();
+    scroll.offset = 40;
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+    terminal
+        .draw(|f| render(&malformed, &mut scroll, f, f.area()))
+        .unwrap();
+    assert_eq!(scroll.offset, 0);
+}
@@ -0,0 +1,78 @@
+//! Marks and percentage jumps in the results pane
+//!
+//! `m` and a letter marks the cursor's line, and `'` or `` ` `` and the
+//! letter returns to it, as `ma` and `'a` do in vim.
+//! A number and `%` jumps that far through the output, as `50%` does in
+//! vim. Marks are line numbers kept for the session, so they suit finding
+//! the way back through one very long output.
+
+use std::collections::BTreeMap;
+
+/// Which half-typed mark chord awaits its letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkChord {
+    /// `m` — mark the cursor's line
+    Set,
+    /// `'` or `` ` `` — jump to a mark
+    Jump,
+}
+
+#[derive(Debug, Default)]
+pub struct ResultMarks {
+    marks: BTreeMap<char, u32>,
+    pending_chord: Option<MarkChord>,
+    /// Digits typed ahead of `%`
+    count: Option<u32>,
+}
+
+impl ResultMarks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, letter: char, line: u32) {
+        self.marks.insert(letter, line);
+    }
+
+    pub fn get(&self, letter: char) -> Option<u32> {
+        self.marks.get(&letter).copied()
+    }
+
+    pub fn start_chord(&mut self, chord: MarkChord) {
+        self.pending_chord = Some(chord);
+    }
+
+    pub fn has_chord(&self) -> bool {
+        self.pending_chord.is_some()
+    }
+
+    /// The chord awaiting its letter, clearing it
+    pub fn take_chord(&mut self) -> Option<MarkChord> {
+        self.pending_chord.take()
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    pub fn push_digit(&mut self, digit: u32) {
+        let count = self.count.unwrap_or(0);
+        self.count = Some(count.saturating_mul(10).saturating_add(digit));
+    }
+
+    /// The number typed so far, clearing it
+    pub fn take_count(&mut self) -> Option<u32> {
+        self.count.take()
+    }
+}
+
+/// The 0-based line `percent` of the way through `total` lines, rounding
+/// up as vim's `N%` does
+pub fn percent_line(percent: u32, total: u32) -> u32 {
+    let line = (percent as u64 * total as u64).div_ceil(100) as u32;
+    line.clamp(1, total.max(1)) - 1
+}
+
+#[cfg(test)]
+#[path = "marks_tests.rs"]
+mod marks_tests;
@@ -0,0 +1,71 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::marks::{MarkChord, percent_line};
+use crate::app::App;
+
+/// Digits and `%` of a percentage jump. Returns whether the key was one;
+/// any other key drops the digits typed so far.
+pub fn handle_count_key(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        // A lone `0` scrolls to the left edge
+        KeyCode::Char(c @ '0'..='9') if c != '0' || app.results_marks.has_count() => {
+            app.results_marks.push_digit(c.to_digit(10).unwrap_or(0));
+            true
+        }
+        KeyCode::Char('%') => {
+            match app.results_marks.take_count() {
+                Some(percent) => jump_to_percent(app, percent),
+                None => app.notification.show("Type a percentage first, e.g. 50%"),
+            }
+            true
+        }
+        _ => {
+            app.results_marks.take_count();
+            false
+        }
+    }
+}
+
+/// `N%` or `:N%` — move the cursor `percent` of the way through the output
+pub fn jump_to_percent(app: &mut App, percent: u32) {
+    if percent > 100 {
+        app.notification.show("Percentage must be 0-100");
+        return;
+    }
+    let total = app.results_line_count_u32();
+    move_cursor_to(app, percent_line(percent, total));
+}
+
+/// Letter of an `m`, `'` or `` ` `` chord. Returns false for anything but a
+/// lowercase letter so it is handled as usual.
+pub fn handle_chord(app: &mut App, chord: MarkChord, key: KeyEvent) -> bool {
+    let KeyCode::Char(letter @ 'a'..='z') = key.code else {
+        return false;
+    };
+    match chord {
+        MarkChord::Set => {
+            let line = app.results_cursor.cursor_line();
+            app.results_marks.set(letter, line);
+            app.notification
+                .show(&format!("Mark {} at line {}", letter, line + 1));
+        }
+        MarkChord::Jump => match app.results_marks.get(letter) {
+            Some(line) => move_cursor_to(app, line),
+            None => app
+                .notification
+                .show(&format!("Mark {} is not set", letter)),
+        },
+    }
+    true
+}
+
+fn move_cursor_to(app: &mut App, line: u32) {
+    let total = app.results_line_count_u32();
+    app.results_cursor.update_total_lines(total);
+    app.results_cursor.move_to_line(line);
+    app.ensure_results_cursor_visible();
+}
+
+#[cfg(test)]
+#[path = "marks_events_tests.rs"]
+mod marks_events_tests;
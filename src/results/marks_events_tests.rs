@@ -0,0 +1,122 @@
+//! Tests for results/marks_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{app_with_query, key};
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn app_with_lines(line_count: u32) -> App {
+    let mut app = app_with_query(".");
+    app.focus = Focus::ResultsPane;
+
+    let content: String = (0..line_count).map(|i| format!("line{}\n", i)).collect();
+    let query_state = app.query.as_mut().unwrap();
+    query_state.result = Ok(content.clone());
+    query_state.last_successful_result = Some(Arc::new(content));
+    query_state.cached_line_count = line_count;
+
+    app.results_scroll.update_bounds(line_count, 10);
+    app.results_cursor.update_total_lines(line_count);
+    app
+}
+
+fn press(app: &mut App, keys: &str) {
+    for c in keys.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_number_and_percent_jump_through_the_output() {
+    let mut app = app_with_lines(200);
+
+    press(&mut app, "50%");
+
+    assert_eq!(app.results_cursor.cursor_line(), 99);
+    let offset = app.results_scroll.offset as u32;
+    assert!((offset..offset + 10).contains(&99));
+}
+
+#[test]
+fn test_percent_without_a_number_explains() {
+    let mut app = app_with_lines(200);
+
+    press(&mut app, "%");
+
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Type a percentage first, e.g. 50%")
+    );
+}
+
+#[test]
+fn test_other_keys_drop_the_number() {
+    let mut app = app_with_lines(200);
+
+    press(&mut app, "5j%");
+
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+}
+
+#[test]
+fn test_lone_zero_still_scrolls_to_the_left_edge() {
+    let mut app = app_with_lines(200);
+    app.results_scroll.h_offset = 10;
+
+    press(&mut app, "0");
+
+    assert_eq!(app.results_scroll.h_offset, 0);
+    assert!(!app.results_marks.has_count());
+}
+
+#[test]
+fn test_mark_and_return() {
+    let mut app = app_with_lines(200);
+    app.results_cursor.move_to_line(42);
+
+    press(&mut app, "ma");
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Mark a at line 43")
+    );
+    press(&mut app, "G'a");
+
+    assert_eq!(app.results_cursor.cursor_line(), 42);
+}
+
+#[test]
+fn test_backtick_also_jumps_to_a_mark() {
+    let mut app = app_with_lines(200);
+    app.results_cursor.move_to_line(42);
+
+    press(&mut app, "mbG`b");
+
+    assert_eq!(app.results_cursor.cursor_line(), 42);
+}
+
+#[test]
+fn test_jump_to_an_unset_mark_explains() {
+    let mut app = app_with_lines(200);
+
+    press(&mut app, "'q");
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Mark q is not set")
+    );
+    assert!(app.results_marks.take_chord().is_none());
+}
+
+#[test]
+fn test_marks_stay_with_their_tab() {
+    let mut app = app_with_lines(200);
+    app.results_cursor.move_to_line(42);
+    press(&mut app, "ma");
+
+    app.new_tab();
+    assert_eq!(app.results_marks.get('a'), None);
+
+    app.switch_tab(0);
+    assert_eq!(app.results_marks.get('a'), Some(42));
+}
@@ -0,0 +1,39 @@
+//! Tests for results/marks
+
+use super::*;
+
+#[test]
+fn test_percent_line_rounds_up_like_vim() {
+    assert_eq!(percent_line(50, 200), 99);
+    assert_eq!(percent_line(50, 7), 3);
+    assert_eq!(percent_line(100, 200), 199);
+}
+
+#[test]
+fn test_percent_line_stays_on_the_first_line() {
+    assert_eq!(percent_line(0, 200), 0);
+    assert_eq!(percent_line(1, 10), 0);
+    assert_eq!(percent_line(50, 0), 0);
+}
+
+#[test]
+fn test_digits_make_one_count() {
+    let mut marks = ResultMarks::new();
+
+    marks.push_digit(5);
+    marks.push_digit(0);
+
+    assert_eq!(marks.take_count(), Some(50));
+    assert!(!marks.has_count());
+}
+
+#[test]
+fn test_setting_a_mark_again_moves_it() {
+    let mut marks = ResultMarks::new();
+
+    marks.set('a', 3);
+    marks.set('a', 40);
+
+    assert_eq!(marks.get('a'), Some(40));
+    assert_eq!(marks.get('b'), None);
+}
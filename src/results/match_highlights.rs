@@ -0,0 +1,76 @@
+//! `:match` highlights in the results pane
+//!
+//! Each `:match <regex>` adds a pattern whose matches stay highlighted,
+//! in a color of its own, across query edits and tabs until `:match-clear`.
+//! Unlike `/` search there is no current match and nothing to step
+//! through: the point is to see at a glance where an ID or a value turns
+//! up again in a long output.
+
+use regex::Regex;
+
+#[derive(Debug, Default)]
+pub struct MatchHighlights {
+    patterns: Vec<Regex>,
+}
+
+/// Where a pattern matched on a line, in chars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchSpan {
+    pub start: usize,
+    pub end: usize,
+    /// Which pattern matched, for its color
+    pub pattern: usize,
+}
+
+impl MatchHighlights {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Highlight `pattern` too, returning its index. A pattern already
+    /// shown keeps its index and color.
+    pub fn add(&mut self, pattern: &str) -> Result<usize, regex::Error> {
+        if let Some(index) = self.patterns.iter().position(|r| r.as_str() == pattern) {
+            return Ok(index);
+        }
+        self.patterns.push(Regex::new(pattern)?);
+        Ok(self.patterns.len() - 1)
+    }
+
+    /// Drop every pattern, returning how many there were
+    pub fn clear(&mut self) -> usize {
+        std::mem::take(&mut self.patterns).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Matches of every pattern on `line`; where two overlap, the later
+    /// pattern's comes last so it is drawn on top
+    pub fn spans(&self, line: &str) -> Vec<MatchSpan> {
+        let mut spans = Vec::new();
+        for (pattern, regex) in self.patterns.iter().enumerate() {
+            for found in regex.find_iter(line) {
+                if found.is_empty() {
+                    continue;
+                }
+                let start = line[..found.start()].chars().count();
+                spans.push(MatchSpan {
+                    start,
+                    end: start + found.as_str().chars().count(),
+                    pattern,
+                });
+            }
+        }
+        spans
+    }
+}
+
+#[cfg(test)]
+#[path = "match_highlights_tests.rs"]
+mod match_highlights_tests;
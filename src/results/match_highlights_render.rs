@@ -0,0 +1,36 @@
+use ratatui::{
+    style::Style,
+    text::{Line, Text},
+};
+
+use super::search_highlight_render::{char_styles, line_from_char_styles};
+use crate::results::match_highlights::MatchHighlights;
+use crate::theme;
+
+/// Color each `:match` pattern's matches on the shown lines
+pub fn apply_highlights(text: Text<'_>, highlights: &MatchHighlights) -> Text<'static> {
+    let colors = theme::results::match_colors();
+    let lines: Vec<Line<'static>> = text
+        .lines
+        .into_iter()
+        .map(|line| {
+            let plain: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            let mut char_styles = char_styles(&line);
+            let len = char_styles.len();
+            for span in highlights.spans(&plain) {
+                let style = Style::default()
+                    .fg(theme::results::current_match_fg())
+                    .bg(colors[span.pattern % colors.len()]);
+                for entry in &mut char_styles[span.start.min(len)..span.end.min(len)] {
+                    entry.1 = style;
+                }
+            }
+            line_from_char_styles(char_styles)
+        })
+        .collect();
+    Text::from(lines)
+}
+
+#[cfg(test)]
+#[path = "match_highlights_render_tests.rs"]
+mod match_highlights_render_tests;
@@ -0,0 +1,38 @@
+//! Tests for results/match_highlights_render
+
+use super::apply_highlights;
+use crate::results::match_highlights::MatchHighlights;
+use crate::theme;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span, Text};
+
+#[test]
+fn each_pattern_gets_its_own_color() {
+    let input = Text::from(Line::from(vec![
+        Span::styled("\"id-1\": ", Style::default().fg(Color::Yellow)),
+        Span::styled("\"error\"", Style::default().fg(Color::Green)),
+    ]));
+    let mut highlights = MatchHighlights::new();
+    highlights.add(r"id-\d").unwrap();
+    highlights.add("err").unwrap();
+
+    let out = apply_highlights(input, &highlights);
+
+    let spans: Vec<(&str, Option<Color>)> = out.lines[0]
+        .spans
+        .iter()
+        .map(|s| (s.content.as_ref(), s.style.bg))
+        .collect();
+    let colors = theme::results::match_colors();
+    assert_eq!(
+        spans,
+        [
+            ("\"", None),
+            ("id-1", Some(colors[0])),
+            ("\": ", None),
+            ("\"", None),
+            ("err", Some(colors[1])),
+            ("or\"", None),
+        ]
+    );
+}
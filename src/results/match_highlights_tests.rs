@@ -0,0 +1,69 @@
+//! Tests for results/match_highlights
+
+use super::*;
+
+#[test]
+fn test_each_pattern_gets_its_own_index() {
+    let mut highlights = MatchHighlights::new();
+
+    assert_eq!(highlights.add(r"id-\d+").unwrap(), 0);
+    assert_eq!(highlights.add("error").unwrap(), 1);
+    assert_eq!(highlights.add(r"id-\d+").unwrap(), 0);
+    assert_eq!(highlights.len(), 2);
+}
+
+#[test]
+fn test_invalid_regex_is_rejected() {
+    let mut highlights = MatchHighlights::new();
+
+    assert!(highlights.add("(unclosed").is_err());
+    assert!(highlights.is_empty());
+}
+
+#[test]
+fn test_spans_are_in_chars() {
+    let mut highlights = MatchHighlights::new();
+    highlights.add(r"id-\d+").unwrap();
+    highlights.add("é").unwrap();
+
+    let spans = highlights.spans(r#"  "né": "id-42", "x": "id-7""#);
+
+    assert_eq!(
+        spans,
+        [
+            MatchSpan {
+                start: 9,
+                end: 14,
+                pattern: 0
+            },
+            MatchSpan {
+                start: 23,
+                end: 27,
+                pattern: 0
+            },
+            MatchSpan {
+                start: 4,
+                end: 5,
+                pattern: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_empty_matches_are_skipped() {
+    let mut highlights = MatchHighlights::new();
+    highlights.add("x*").unwrap();
+
+    assert!(highlights.spans("abc").is_empty());
+}
+
+#[test]
+fn test_clear_drops_every_pattern() {
+    let mut highlights = MatchHighlights::new();
+    highlights.add("a").unwrap();
+    highlights.add("b").unwrap();
+
+    assert_eq!(highlights.clear(), 2);
+    assert!(highlights.is_empty());
+}
@@ -0,0 +1,148 @@
+//! gron-style `path = value` view of results
+//!
+//! `=` in the results pane prints every value of the result on its own
+//! line after its full jq path, like `gron`:
+//!
+//! ```text
+//! . = {}
+//! .users = []
+//! .users[0] = {}
+//! .users[0].name = "ada"
+//! ```
+//!
+//! Each line is valid jq and greppable, so `/` finds a deeply nested field
+//! by its path, and `>` on a match zooms the query into it. A result printed
+//! as several values lists each one from `.` in turn.
+
+use std::sync::Arc;
+
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::json_path::JsonPath;
+
+#[derive(Debug, Default)]
+pub struct PathView {
+    enabled: bool,
+    /// Result text the lines were built from
+    source: Option<Arc<String>>,
+    lines: Vec<String>,
+    /// Path of the value on each line
+    paths: Vec<JsonPath>,
+    /// The lines joined, for searching
+    text: String,
+    /// Widest line, for horizontal scrolling
+    width: usize,
+}
+
+impl PathView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the results pane shows the paths: it is switched on and the
+    /// current result is JSON
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.lines.is_empty()
+    }
+
+    /// Show `source` as paths. Returns false, leaving the view off, when
+    /// it is not JSON.
+    ///
+    /// Once on, the view stays on across query edits; results that are not
+    /// JSON show as usual meanwhile.
+    pub fn enable(&mut self, source: Option<&Arc<String>>) -> bool {
+        self.enabled = true;
+        self.source = None;
+        self.lines.clear();
+        self.sync(source);
+        self.enabled = self.is_active();
+        self.enabled
+    }
+
+    pub fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    /// Rebuild the lines when `source` is a different result
+    pub fn sync(&mut self, source: Option<&Arc<String>>) {
+        if !self.enabled {
+            return;
+        }
+        let unchanged = match (&self.source, source) {
+            (Some(current), Some(new)) => Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
+            return;
+        }
+        self.source = source.cloned();
+        let (lines, paths) = source
+            .and_then(|text| flatten_output(text))
+            .unwrap_or_default();
+        self.text = lines.join("\n");
+        self.width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+        self.lines = lines;
+        self.paths = paths;
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn path_at(&self, row: u32) -> Option<&JsonPath> {
+        self.paths.get(row as usize)
+    }
+}
+
+/// Flatten printed jq output into `path = value` lines and their paths.
+/// Returns None when the text is not JSON.
+pub fn flatten_output(text: &str) -> Option<(Vec<String>, Vec<JsonPath>)> {
+    let mut lines = Vec::new();
+    let mut paths = Vec::new();
+    for value in serde_json::Deserializer::from_str(text).into_iter::<Value>() {
+        flatten(&value.ok()?, &mut JsonPath::new(), &mut lines, &mut paths);
+    }
+    Some((lines, paths))
+}
+
+fn flatten(value: &Value, path: &mut JsonPath, lines: &mut Vec<String>, paths: &mut Vec<JsonPath>) {
+    let shown = match value {
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        scalar => scalar.to_string(),
+    };
+    lines.push(format!("{} = {}", path.to_jq(), shown));
+    paths.push(path.clone());
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                path.push_key(key.as_str());
+                flatten(child, path, lines, paths);
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                path.push_index(index);
+                flatten(child, path, lines, paths);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "path_view_tests.rs"]
+mod path_view_tests;
@@ -0,0 +1,69 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::results::results_events::{CURSOR_KEYS, scroll_without_cursor};
+use crate::search::search_events;
+
+/// `=` — show the result as `path = value` lines, or the JSON again. An
+/// open search is run again on what is now shown.
+pub fn toggle(app: &mut App) {
+    if app.results_paths.is_active() {
+        app.results_paths.disable();
+        app.results_scroll.jump_to_top();
+        app.ensure_results_cursor_visible();
+        app.notification.show("Path view off");
+    } else {
+        enable(app);
+    }
+    if app.search.is_visible() {
+        search_events::rematch(app);
+    }
+}
+
+fn enable(app: &mut App) {
+    let source = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone());
+    if app.results_paths.enable(source.as_ref()) {
+        app.results_scroll.jump_to_top();
+        app.results_scroll.jump_to_left();
+        app.notification.show("Path view · / searches paths");
+    } else {
+        app.notification.show("Path view needs a JSON result");
+    }
+}
+
+/// Keys in the results pane while the paths are shown. The view has no
+/// cursor: movement keys scroll, `/` searches the paths and keys that act
+/// on the cursor's line are declined. Returns false for keys the pane
+/// handles as usual, such as horizontal scrolling and `y`, which copies
+/// the lines.
+pub fn handle_path_view_key(app: &mut App, key: KeyEvent) -> bool {
+    if !app.results_paths.is_active()
+        || app.results_filter.is_active()
+        || app.results_table.is_active()
+    {
+        return false;
+    }
+    if scroll_without_cursor(app, key) {
+        return true;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('=') => toggle(app),
+        KeyCode::Char('$') => {
+            app.results_scroll.h_offset = app.results_scroll.max_h_offset;
+        }
+        KeyCode::Char('/') => return false,
+        KeyCode::Char(c) if CURSOR_KEYS.contains(c) || "|T".contains(c) => {
+            app.notification.show("Path view is on · = shows the JSON");
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "path_view_events_tests.rs"]
+mod path_view_events_tests;
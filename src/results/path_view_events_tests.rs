@@ -0,0 +1,120 @@
+//! Tests for results/path_view_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{key, test_app};
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+const RESULT: &str = r#"{"a": {"b": 1}, "c": [true]}"#;
+
+fn app_showing(result: &str) -> App {
+    let mut app = test_app(result);
+    let arc = Arc::new(result.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(arc);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+fn type_str(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_equals_shows_the_paths_and_again_shows_the_json() {
+    let mut app = app_showing(RESULT);
+
+    app.handle_key_event(key(KeyCode::Char('=')));
+    assert!(app.results_paths.is_active());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Path view · / searches paths")
+    );
+
+    app.handle_key_event(key(KeyCode::Char('=')));
+    assert!(!app.results_paths.is_active());
+    assert_eq!(app.notification.current_message(), Some("Path view off"));
+}
+
+#[test]
+fn test_equals_on_a_result_that_is_not_json_explains_why() {
+    let mut app = app_showing("not json");
+
+    app.handle_key_event(key(KeyCode::Char('=')));
+
+    assert!(!app.results_paths.is_active());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Path view needs a JSON result")
+    );
+}
+
+#[test]
+fn test_esc_leaves_the_path_view() {
+    let mut app = app_showing(RESULT);
+    app.handle_key_event(key(KeyCode::Char('=')));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.results_paths.is_active());
+}
+
+#[test]
+fn test_cursor_keys_are_declined_in_the_path_view() {
+    let mut app = app_showing(RESULT);
+    app.handle_key_event(key(KeyCode::Char('=')));
+
+    app.handle_key_event(key(KeyCode::Char('v')));
+
+    assert!(!app.results_cursor.is_visual_mode());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Path view is on · = shows the JSON")
+    );
+}
+
+#[test]
+fn test_search_finds_matches_on_the_path_lines() {
+    let mut app = app_showing(RESULT);
+    app.handle_key_event(key(KeyCode::Char('=')));
+
+    app.handle_key_event(key(KeyCode::Char('/')));
+    type_str(&mut app, "a.b");
+
+    assert_eq!(app.search.matches().len(), 1);
+    assert_eq!(app.search.current_match().map(|m| m.line), Some(2));
+}
+
+#[test]
+fn test_zoom_from_a_search_match_uses_the_lines_path() {
+    let mut app = app_showing(RESULT);
+    app.handle_key_event(key(KeyCode::Char('=')));
+    app.handle_key_event(key(KeyCode::Char('/')));
+    type_str(&mut app, "c[0]");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('>')));
+
+    assert!(
+        app.input.query().ends_with(".c[0]"),
+        "{}",
+        app.input.query()
+    );
+}
+
+#[test]
+fn test_switching_the_view_searches_again() {
+    let mut app = app_showing(RESULT);
+    app.handle_key_event(key(KeyCode::Char('/')));
+    type_str(&mut app, "c");
+    assert_eq!(app.search.matches().len(), 1);
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('=')));
+
+    // `.c = []` and `.c[0] = true`
+    assert_eq!(app.search.matches().len(), 2);
+}
@@ -0,0 +1,26 @@
+use ratatui::text::{Line, Text};
+
+use crate::syntax_highlight::JqHighlighter;
+use crate::theme;
+
+/// The path view's lines from `offset`, highlighted as the jq they are
+pub fn viewport(lines: &[String], offset: usize, height: usize) -> Text<'static> {
+    let lines = lines
+        .iter()
+        .skip(offset)
+        .take(height)
+        .map(|line| Line::from(JqHighlighter::highlight(line)))
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+/// Keys for the bottom border while the path view shows
+pub fn hints() -> Line<'static> {
+    let hints: Vec<(&'static str, &'static str)> =
+        vec![("/", "Search Paths"), ("y", "Copy"), ("=", "JSON")];
+    theme::border_hints::build_hints(&hints, theme::results::hint_key())
+}
+
+#[cfg(test)]
+#[path = "path_view_render_tests.rs"]
+mod path_view_render_tests;
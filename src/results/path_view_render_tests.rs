@@ -0,0 +1,59 @@
+//! Tests for results/path_view_render
+
+use crate::app::App;
+use crate::test_utils::test_helpers::{key, test_app};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+const CONTENT: &str = "{\n  \"user\": {\n    \"name\": \"ada\",\n    \"tags\": [\"x\"]\n  }\n}";
+
+fn app_with_paths() -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"{"user": {}}"#);
+    let arc = Arc::new(CONTENT.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(CONTENT.to_string()));
+    q.result = Ok(CONTENT.to_string());
+    q.cached_line_count = CONTENT.lines().count() as u32;
+    q.is_empty_result = false;
+    app.focus_results_pane();
+    app.handle_key_event(key(KeyCode::Char('=')));
+    app.notification.dismiss();
+    app
+}
+
+#[test]
+fn snapshot_results_as_paths() {
+    let mut app = app_with_paths();
+    let output = render_to_string(&mut app, 70, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn search_match_shows_its_path() {
+    let mut app = app_with_paths();
+    app.handle_key_event(key(KeyCode::Char('/')));
+    for c in "tags[0]".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    let output = render_to_string(&mut app, 70, 12);
+    assert!(output.contains(".user.tags[0] = \"x\""), "{output}");
+    assert!(output.contains(" · paths"), "{output}");
+}
@@ -0,0 +1,89 @@
+//! Tests for results/path_view
+
+use super::*;
+
+fn lines_of(text: &str) -> Vec<String> {
+    flatten_output(text).unwrap().0
+}
+
+#[test]
+fn test_every_value_gets_a_line_after_its_path() {
+    assert_eq!(
+        lines_of(r#"{"users": [{"name": "ada", "admin": true}], "n": null}"#),
+        vec![
+            ". = {}",
+            ".users = []",
+            ".users[0] = {}",
+            ".users[0].name = \"ada\"",
+            ".users[0].admin = true",
+            ".n = null",
+        ]
+    );
+}
+
+#[test]
+fn test_keys_that_are_not_identifiers_are_bracketed() {
+    assert_eq!(
+        lines_of(r#"{"zip-code": 1}"#),
+        vec![". = {}", ".[\"zip-code\"] = 1"]
+    );
+}
+
+#[test]
+fn test_strings_stay_escaped_on_one_line() {
+    assert_eq!(
+        lines_of(r#"{"a": "one\ntwo"}"#),
+        vec![". = {}", ".a = \"one\\ntwo\""]
+    );
+}
+
+#[test]
+fn test_scalar_result_is_one_line() {
+    assert_eq!(lines_of("42"), vec![". = 42"]);
+}
+
+#[test]
+fn test_each_value_of_a_stream_starts_from_the_root() {
+    assert_eq!(
+        lines_of("{\"a\": 1}\n[2]\n"),
+        vec![". = {}", ".a = 1", ". = []", ".[0] = 2"]
+    );
+}
+
+#[test]
+fn test_text_that_is_not_json_is_refused() {
+    assert!(flatten_output("not json").is_none());
+    assert!(flatten_output("{\"a\": 1} oops").is_none());
+}
+
+#[test]
+fn test_paths_match_the_lines() {
+    let (lines, paths) = flatten_output(r#"{"a": [true]}"#).unwrap();
+
+    assert_eq!(paths.len(), lines.len());
+    assert_eq!(paths[2].to_jq(), ".a[0]");
+}
+
+#[test]
+fn test_enable_stays_off_for_results_that_are_not_json() {
+    let mut view = PathView::new();
+
+    assert!(!view.enable(Some(&Arc::new("oops".to_string()))));
+    assert!(!view.is_active());
+    assert!(view.enable(Some(&Arc::new("[1]".to_string()))));
+    assert!(view.is_active());
+    assert_eq!(view.text(), ". = []\n.[0] = 1");
+    assert_eq!(view.width(), 8);
+}
+
+#[test]
+fn test_view_follows_new_results_and_can_be_switched_off() {
+    let mut view = PathView::new();
+    view.enable(Some(&Arc::new("[1]".to_string())));
+
+    view.sync(Some(&Arc::new(r#"{"b": 2}"#.to_string())));
+    assert_eq!(view.path_at(1).map(JsonPath::to_jq), Some(".b".to_string()));
+
+    view.disable();
+    assert!(!view.is_active());
+}
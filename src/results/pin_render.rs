@@ -0,0 +1,60 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::results::fold_state::FoldState;
+use crate::results::result_pins::ResultPins;
+use crate::theme;
+
+/// `●` on the left border of rows showing a pinned line; a pinned value
+/// folded away marks the row folding it
+pub fn render_markers(
+    frame: &mut Frame,
+    results_area: Rect,
+    pins: &ResultPins,
+    folds: &FoldState,
+    scroll_offset: u16,
+    row_count: u32,
+) {
+    let viewport_height = results_area.height.saturating_sub(2);
+    let marker = Span::styled("●", Style::default().fg(theme::results::pin_marker()));
+    for relative in 0..viewport_height {
+        let row = scroll_offset as u32 + relative as u32;
+        if row >= row_count {
+            break;
+        }
+        let Some(first) = folds.line_at(row) else {
+            break;
+        };
+        let last = folds.last_line_of(first);
+        if !pins.any_pinned_in(first, last) {
+            continue;
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(marker.clone())),
+            Rect {
+                x: results_area.x,
+                y: results_area.y.saturating_add(1).saturating_add(relative),
+                width: 1,
+                height: 1,
+            },
+        );
+    }
+}
+
+/// ` · 2 pinned`, or ` · 2 pinned (1 missing)` when some pinned values are
+/// not in the current result
+pub fn format_count(pins: &ResultPins) -> String {
+    match pins.missing() {
+        0 => format!(" · {} pinned", pins.len()),
+        missing => format!(" · {} pinned ({} missing)", pins.len(), missing),
+    }
+}
+
+#[cfg(test)]
+#[path = "pin_render_tests.rs"]
+mod pin_render_tests;
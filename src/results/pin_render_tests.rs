@@ -0,0 +1,69 @@
+//! Tests for results/pin_render
+
+use crate::app::App;
+use crate::json_path::parse_jq_path;
+use crate::test_utils::test_helpers::test_app;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use serde_json::json;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+const CONTENT: &str = "{\n  \"a\": 1,\n  \"b\": 2\n}";
+
+fn app_with_pin(jq: &str) -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"{"a": 1, "b": 2}"#);
+    let arc = Arc::new(CONTENT.to_string());
+    let parsed = Arc::new(json!({"a": 1, "b": 2}));
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(CONTENT.to_string()));
+    q.last_successful_result_parsed = Some(Arc::clone(&parsed));
+    q.result = Ok(CONTENT.to_string());
+    q.cached_line_count = CONTENT.lines().count() as u32;
+    q.is_empty_result = false;
+    app.results_pins.relocate(&parsed);
+    app.results_pins.toggle(parse_jq_path(jq).unwrap(), 2);
+    app
+}
+
+#[test]
+fn pinned_line_is_marked_on_the_border() {
+    let mut app = app_with_pin(".b");
+    let mut terminal = create_test_terminal(40, 12);
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    // Row 1 of the pane is `{`, row 3 is `"b": 2`
+    assert_eq!(buffer[(0, 3)].symbol(), "●");
+    assert_eq!(buffer[(0, 2)].symbol(), "│");
+}
+
+#[test]
+fn position_indicator_counts_pins() {
+    let mut app = app_with_pin(".b");
+    let output = render_to_string(&mut app, 60, 12);
+    assert!(output.contains("1 pinned"), "{output}");
+    assert!(!output.contains("missing"), "{output}");
+}
+
+#[test]
+fn missing_pins_are_counted() {
+    let mut app = app_with_pin(".b");
+    app.results_pins.relocate(&Arc::new(json!({"a": 1})));
+    let output = render_to_string(&mut app, 60, 12);
+    assert!(output.contains("1 pinned (1 missing)"), "{output}");
+}
@@ -0,0 +1,178 @@
+//! Flash the lines a query edit changed
+//!
+//! When a new result replaces the previous one, its lines are diffed against
+//! the old ones: added lines get a highlighted background and lines where old
+//! ones were removed get a marker on the pane border. The highlight fades
+//! after [`FLASH_DURATION`] unless pinned with `d` in the results pane.
+
+use std::time::{Duration, Instant};
+
+pub const FLASH_DURATION: Duration = Duration::from_secs(1);
+
+/// Above this many cells the changed middle of two results is not
+/// line-matched and counts as replaced wholesale
+const MAX_DIFF_CELLS: usize = 1_000_000;
+
+/// Lines of the new result that differ from the previous one
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LineDiff {
+    /// New-result lines absent from the old result, ascending
+    pub added: Vec<u32>,
+    /// New-result lines where old lines were removed, ascending: the first
+    /// line replacing them, or the line after a pure removal (the line count
+    /// when the removal was at the very end)
+    pub removed_at: Vec<u32>,
+}
+
+impl LineDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed_at.is_empty()
+    }
+}
+
+pub struct DiffHighlight {
+    enabled: bool,
+    /// Keep showing the last diff instead of fading it
+    pinned: bool,
+    diff: LineDiff,
+    shown_at: Option<Instant>,
+}
+
+impl DiffHighlight {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            pinned: false,
+            diff: LineDiff::default(),
+            shown_at: None,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Diff `new` against `old` and start the flash
+    pub fn record(&mut self, old: &str, new: &str) {
+        if !self.enabled {
+            return;
+        }
+        self.diff = diff_lines(old, new);
+        self.shown_at = (!self.diff.is_empty()).then(Instant::now);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.is_visible_at(Instant::now())
+    }
+
+    fn is_visible_at(&self, now: Instant) -> bool {
+        match self.shown_at {
+            Some(_) if self.pinned => true,
+            Some(shown_at) => now.duration_since(shown_at) < FLASH_DURATION,
+            None => false,
+        }
+    }
+
+    /// Returns true when a flash just ended and the pane needs a redraw
+    pub fn clear_if_expired(&mut self) -> bool {
+        if self.shown_at.is_some() && !self.is_visible() {
+            self.shown_at = None;
+            return true;
+        }
+        false
+    }
+
+    /// Drop the current flash
+    #[cfg(test)]
+    pub fn clear(&mut self) {
+        self.shown_at = None;
+    }
+
+    /// Toggle keeping the highlight on screen; returns the new setting
+    pub fn toggle_pinned(&mut self) -> bool {
+        self.pinned = !self.pinned;
+        self.pinned
+    }
+
+    pub fn is_added(&self, line: u32) -> bool {
+        self.is_visible() && self.diff.added.binary_search(&line).is_ok()
+    }
+
+    pub fn is_removed_at(&self, line: u32) -> bool {
+        self.is_visible() && self.diff.removed_at.binary_search(&line).is_ok()
+    }
+}
+
+/// Line-level diff of `new` against `old`
+///
+/// Lines shared at the start and end are skipped first, which covers the
+/// usual small query edit cheaply; the rest is matched by longest common
+/// subsequence when small enough.
+pub fn diff_lines(old: &str, new: &str) -> LineDiff {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let kept = if old_mid.len().saturating_mul(new_mid.len()) <= MAX_DIFF_CELLS {
+        common_subsequence(old_mid, new_mid)
+    } else {
+        Vec::new()
+    };
+
+    let mut diff = LineDiff::default();
+    let (mut i, mut j) = (0, 0);
+    for (old_index, new_index) in kept.into_iter().chain([(old_mid.len(), new_mid.len())]) {
+        diff.added
+            .extend((j..new_index).map(|line| (prefix + line) as u32));
+        if old_index > i {
+            diff.removed_at.push((prefix + j) as u32);
+        }
+        i = old_index + 1;
+        j = new_index + 1;
+    }
+    diff
+}
+
+/// Index pairs of a longest common subsequence of `old` and `new`
+fn common_subsequence(old: &[&str], new: &[&str]) -> Vec<(usize, usize)> {
+    let width = new.len() + 1;
+    // lengths[i * width + j] = LCS length of old[i..] and new[j..]
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+#[path = "result_diff_tests.rs"]
+mod result_diff_tests;
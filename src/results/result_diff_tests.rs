@@ -0,0 +1,78 @@
+//! Tests for results/result_diff
+
+use super::*;
+
+#[test]
+fn test_identical_results_have_no_diff() {
+    assert!(diff_lines("a\nb\nc", "a\nb\nc").is_empty());
+}
+
+#[test]
+fn test_added_lines() {
+    let diff = diff_lines("a\nc", "a\nb\nc\nd");
+    assert_eq!(diff.added, vec![1, 3]);
+    assert!(diff.removed_at.is_empty());
+}
+
+#[test]
+fn test_removed_lines_mark_the_following_line() {
+    let diff = diff_lines("a\nb\nc\nd", "a\nd");
+    assert!(diff.added.is_empty());
+    assert_eq!(diff.removed_at, vec![1]);
+}
+
+#[test]
+fn test_changed_line_is_added_and_marked() {
+    let diff = diff_lines("{\n  \"a\": 1\n}", "{\n  \"a\": 2\n}");
+    assert_eq!(diff.added, vec![1]);
+    assert_eq!(diff.removed_at, vec![1]);
+}
+
+#[test]
+fn test_removal_at_the_end_marks_the_line_count() {
+    let diff = diff_lines("a\nb\nc", "a");
+    assert_eq!(diff.removed_at, vec![1]);
+}
+
+#[test]
+fn test_common_lines_in_the_middle_are_kept() {
+    let diff = diff_lines("x\na\nb\ny", "z\na\nb\nw");
+    assert_eq!(diff.added, vec![0, 3]);
+    assert_eq!(diff.removed_at, vec![0, 3]);
+}
+
+#[test]
+fn test_flash_fades() {
+    let mut highlight = DiffHighlight::new(true);
+    highlight.record("a", "a\nb");
+
+    assert!(highlight.is_added(1));
+    assert!(!highlight.is_added(0));
+    let shown_at = highlight.shown_at.unwrap();
+    assert!(!highlight.is_visible_at(shown_at + FLASH_DURATION));
+}
+
+#[test]
+fn test_pinned_flash_stays() {
+    let mut highlight = DiffHighlight::new(true);
+    assert!(highlight.toggle_pinned());
+    highlight.record("a", "a\nb");
+
+    let shown_at = highlight.shown_at.unwrap();
+    assert!(highlight.is_visible_at(shown_at + FLASH_DURATION * 10));
+    assert!(!highlight.clear_if_expired());
+}
+
+#[test]
+fn test_disabled_highlight_records_nothing() {
+    let mut highlight = DiffHighlight::new(false);
+    highlight.record("a", "b");
+    assert!(!highlight.is_visible());
+}
+
+#[test]
+fn test_unchanged_result_does_not_flash() {
+    let mut highlight = DiffHighlight::new(true);
+    highlight.record("a\nb", "a\nb");
+    assert!(!highlight.is_visible());
+}
@@ -0,0 +1,118 @@
+//! Pinned result lines that survive query edits
+//!
+//! A pin remembers the jq path of the value on a results-pane line, e.g.
+//! `.users[3].email`, rather than the line number. After every new result
+//! the paths are looked up again, so a pin follows its value when lines
+//! above it come and go, and is reported once when the value disappears.
+
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::json_path::{JsonPath, line_at_path};
+
+#[derive(Debug, Clone)]
+pub struct Pin {
+    pub path: JsonPath,
+    /// Line of the current result showing the value; None when absent
+    pub line: Option<u32>,
+}
+
+#[derive(Debug, Default)]
+pub struct ResultPins {
+    pins: Vec<Pin>,
+    /// Result the lines were resolved against
+    source: Option<Arc<Value>>,
+}
+
+impl ResultPins {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pins.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pins.len()
+    }
+
+    /// Pins whose value is not in the current result
+    pub fn missing(&self) -> usize {
+        self.pins.iter().filter(|pin| pin.line.is_none()).count()
+    }
+
+    /// Pin `path`, shown on `line`, or unpin it when already pinned
+    ///
+    /// Returns true when the path is now pinned.
+    pub fn toggle(&mut self, path: JsonPath, line: u32) -> bool {
+        if let Some(index) = self.pins.iter().position(|pin| pin.path == path) {
+            self.pins.remove(index);
+            return false;
+        }
+        self.pins.push(Pin {
+            path,
+            line: Some(line),
+        });
+        true
+    }
+
+    /// Drop every pin, returning how many there were
+    pub fn clear(&mut self) -> usize {
+        std::mem::take(&mut self.pins).len()
+    }
+
+    #[cfg(test)]
+    pub fn is_pinned(&self, line: u32) -> bool {
+        self.any_pinned_in(line, line)
+    }
+
+    /// Whether a pinned value starts on a line from `first` to `last`
+    pub fn any_pinned_in(&self, first: u32, last: u32) -> bool {
+        self.pins
+            .iter()
+            .any(|pin| pin.line.is_some_and(|line| (first..=last).contains(&line)))
+    }
+
+    /// The first pinned line after `line`, wrapping to the first one
+    pub fn next_after(&self, line: u32) -> Option<u32> {
+        let mut lines: Vec<u32> = self.pins.iter().filter_map(|pin| pin.line).collect();
+        lines.sort_unstable();
+        lines
+            .iter()
+            .copied()
+            .find(|&pinned| pinned > line)
+            .or_else(|| lines.first().copied())
+    }
+
+    /// Look the pins up in a new result
+    ///
+    /// Returns the paths that were present in the previous result and are
+    /// not in this one. Does nothing when `value` is the result already
+    /// resolved against.
+    pub fn relocate(&mut self, value: &Arc<Value>) -> Vec<String> {
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| Arc::ptr_eq(source, value))
+        {
+            return Vec::new();
+        }
+        self.source = Some(Arc::clone(value));
+
+        let mut lost = Vec::new();
+        for pin in &mut self.pins {
+            let line = line_at_path(value, &pin.path).map(|line| line as u32);
+            if pin.line.is_some() && line.is_none() {
+                lost.push(pin.path.to_jq());
+            }
+            pin.line = line;
+        }
+        lost
+    }
+}
+
+#[cfg(test)]
+#[path = "result_pins_tests.rs"]
+mod result_pins_tests;
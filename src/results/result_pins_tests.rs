@@ -0,0 +1,87 @@
+//! Tests for results/result_pins
+
+use super::*;
+use crate::json_path::parse_jq_path;
+use serde_json::json;
+
+fn path(jq: &str) -> JsonPath {
+    parse_jq_path(jq).unwrap()
+}
+
+#[test]
+fn test_toggle_pins_and_unpins() {
+    let mut pins = ResultPins::new();
+
+    assert!(pins.toggle(path(".a"), 1));
+    assert!(pins.is_pinned(1));
+    assert_eq!(pins.len(), 1);
+
+    assert!(!pins.toggle(path(".a"), 1));
+    assert!(pins.is_empty());
+}
+
+#[test]
+fn test_pin_follows_its_value_to_a_new_line() {
+    let mut pins = ResultPins::new();
+    pins.relocate(&Arc::new(json!({"b": 2})));
+    pins.toggle(path(".b"), 1);
+
+    let lost = pins.relocate(&Arc::new(json!({"a": 1, "b": 2})));
+
+    assert!(lost.is_empty());
+    assert!(pins.is_pinned(2));
+    assert!(!pins.is_pinned(1));
+}
+
+#[test]
+fn test_vanished_value_is_reported_once() {
+    let mut pins = ResultPins::new();
+    pins.toggle(path(".users[1]"), 5);
+
+    let lost = pins.relocate(&Arc::new(json!({"users": [1]})));
+    assert_eq!(lost, vec![".users[1]".to_string()]);
+    assert_eq!(pins.missing(), 1);
+
+    // Still missing from the next result: not reported again
+    let lost = pins.relocate(&Arc::new(json!({"users": []})));
+    assert!(lost.is_empty());
+    assert_eq!(pins.missing(), 1);
+
+    // Back again
+    pins.relocate(&Arc::new(json!({"users": [1, 2]})));
+    assert_eq!(pins.missing(), 0);
+}
+
+#[test]
+fn test_same_result_is_not_resolved_twice() {
+    let mut pins = ResultPins::new();
+    let value = Arc::new(json!({"a": 1}));
+    pins.relocate(&value);
+    pins.toggle(path(".gone"), 3);
+
+    // The pin's line was set by hand; an unchanged result leaves it alone
+    assert!(pins.relocate(&value).is_empty());
+    assert!(pins.is_pinned(3));
+}
+
+#[test]
+fn test_next_after_wraps() {
+    let mut pins = ResultPins::new();
+    pins.toggle(path(".a"), 4);
+    pins.toggle(path(".b"), 1);
+
+    assert_eq!(pins.next_after(0), Some(1));
+    assert_eq!(pins.next_after(1), Some(4));
+    assert_eq!(pins.next_after(4), Some(1));
+    assert_eq!(ResultPins::new().next_after(0), None);
+}
+
+#[test]
+fn test_clear_counts_pins() {
+    let mut pins = ResultPins::new();
+    pins.toggle(path(".a"), 1);
+    pins.toggle(path(".b"), 2);
+
+    assert_eq!(pins.clear(), 2);
+    assert!(pins.is_empty());
+}
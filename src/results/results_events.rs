@@ -3,18 +3,58 @@ use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::app::App;
 use crate::clipboard;
 use crate::editor::EditorMode;
-use crate::help::HelpTab;
 use crate::json_path::SiblingDir;
 use crate::path_at_cursor_apply::{
     ApplyOutcome, PathSource, SiblingCursorOutcome, StepOutOutcome, UndoOutcome, apply_iterate,
     apply_keep_kv, apply_path, apply_sibling_cursor, apply_step_out, pop_undo,
 };
+use crate::results::marks::MarkChord;
+use crate::results::{
+    chart_events, decode_events, follow_events, format_menu_events, frequency_events,
+    last_stage_events, line_filter_events, marks_events, path_view_events, stream_view_events,
+    table_events,
+};
 
 pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
+    follow_events::stop_on_scroll_up_key(app, key);
+
+    if line_filter_events::handle_filtered_view_key(app, key)
+        || table_events::handle_table_view_key(app, key)
+        || path_view_events::handle_path_view_key(app, key)
+    {
+        return;
+    }
+
+    if app.results_folds.take_chord() && handle_fold_chord(app, key) {
+        return;
+    }
+
+    if app.root_stack.take_jump()
+        && let KeyCode::Char(c @ '0'..='9') = key.code
+    {
+        app.jump_to_root(c as usize - '0' as usize);
+        return;
+    }
+
+    if app.scratch.take_chord() && key.code == KeyCode::Char('y') {
+        park_shown_result(app);
+        return;
+    }
+
     if app.results_cursor.is_visual_mode() && handle_visual_mode_key(app, key) {
         return;
     }
 
+    if let Some(chord) = app.results_marks.take_chord()
+        && marks_events::handle_chord(app, chord, key)
+    {
+        return;
+    }
+
+    if marks_events::handle_count_key(app, key) {
+        return;
+    }
+
     match key.code {
         KeyCode::Tab if !key.modifiers.contains(KeyModifiers::CONTROL) => {
             exit_results_pane(app);
@@ -33,17 +73,44 @@ pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
             crate::search::search_events::open_search(app);
         }
 
+        KeyCode::Char('|') => {
+            line_filter_events::open_bar(app);
+        }
+
+        KeyCode::Char('T') => {
+            table_events::toggle(app);
+        }
+
+        KeyCode::Char('#') => {
+            chart_events::open_chart(app);
+        }
+
+        KeyCode::Char('c') => {
+            frequency_events::open_frequency(app);
+        }
+
+        KeyCode::Char('S') => {
+            stream_view_events::open(app);
+        }
+
+        KeyCode::Char('E') => {
+            decode_events::open(app);
+        }
+
+        KeyCode::Char('=') => {
+            path_view_events::toggle(app);
+        }
+
+        KeyCode::Char('o') => {
+            format_menu_events::open_menu(app);
+        }
+
+        KeyCode::Char('s') => {
+            last_stage_events::toggle(app);
+        }
+
         KeyCode::Char('?') => {
-            if app.help.visible {
-                app.help.reset();
-            } else {
-                app.help.active_tab = if app.search.is_visible() {
-                    HelpTab::Search
-                } else {
-                    HelpTab::Result
-                };
-                app.help.visible = true;
-            }
+            app.toggle_help();
         }
 
         KeyCode::Char('y') => {
@@ -94,14 +161,56 @@ pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
 
         KeyCode::Home | KeyCode::Char('g') => {
             app.results_cursor.move_to_first();
-            app.results_scroll
-                .ensure_cursor_visible(app.results_cursor.cursor_line());
+            app.ensure_results_cursor_visible();
         }
 
         KeyCode::End | KeyCode::Char('G') => {
-            app.results_cursor.move_to_last();
-            app.results_scroll
-                .ensure_cursor_visible(app.results_cursor.cursor_line());
+            move_cursor_to_last(app);
+            app.ensure_results_cursor_visible();
+        }
+
+        KeyCode::Char('F') => {
+            follow_events::toggle(app);
+        }
+
+        KeyCode::Char('z') => {
+            app.results_folds.start_chord();
+        }
+
+        KeyCode::Char('"') => {
+            app.scratch.start_chord();
+        }
+
+        KeyCode::Char('d') if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if app.results_diff.toggle_pinned() {
+                app.notification.show("Changed lines stay highlighted");
+            } else {
+                app.notification.show("Changed lines fade after a second");
+            }
+        }
+
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::ALT) => {
+            if app.results_pins.clear() == 0 {
+                app.notification.show("No pinned lines");
+            } else {
+                app.notification.show("Cleared all pins");
+            }
+        }
+
+        KeyCode::Char('p') => {
+            toggle_pin(app);
+        }
+
+        KeyCode::Char('P') => {
+            jump_to_next_pin(app);
+        }
+
+        KeyCode::Char('m') => {
+            app.results_marks.start_chord(MarkChord::Set);
+        }
+
+        KeyCode::Char('\'') | KeyCode::Char('`') => {
+            app.results_marks.start_chord(MarkChord::Jump);
         }
 
         KeyCode::PageUp | KeyCode::Char('u')
@@ -133,6 +242,18 @@ pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
             step_out(app);
         }
 
+        KeyCode::Char('R') => {
+            app.reroot();
+        }
+
+        KeyCode::Backspace => {
+            app.pop_root();
+        }
+
+        KeyCode::Char('B') if !app.root_stack.is_empty() => {
+            app.root_stack.start_jump();
+        }
+
         KeyCode::Char('}') => {
             keep_kv(app, PathSource::CursorRow);
         }
@@ -149,6 +270,31 @@ pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+/// Results-pane keys that act on the cursor's line, which views without a
+/// cursor (the line filter and the table) decline
+pub(crate) const CURSOR_KEYS: &str = ">*}[]vVzpPm'd/`%E";
+
+/// Movement keys for views of the result that have no cursor: they scroll
+/// the view instead. Returns false for any other key.
+pub(crate) fn scroll_without_cursor(app: &mut App, key: KeyEvent) -> bool {
+    let half_page = (app.results_scroll.viewport_height / 2).max(1);
+    let control = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => app.results_scroll.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => app.results_scroll.scroll_down(1),
+        KeyCode::Char('K') => app.results_scroll.scroll_up(10),
+        KeyCode::Char('J') => app.results_scroll.scroll_down(10),
+        KeyCode::Home | KeyCode::Char('g') => app.results_scroll.jump_to_top(),
+        KeyCode::End | KeyCode::Char('G') => app.results_scroll.jump_to_bottom(),
+        KeyCode::PageUp => app.results_scroll.scroll_up(half_page),
+        KeyCode::PageDown => app.results_scroll.scroll_down(half_page),
+        KeyCode::Char('u') if control => app.results_scroll.scroll_up(half_page),
+        KeyCode::Char('d') if control => app.results_scroll.scroll_down(half_page),
+        _ => return false,
+    }
+    true
+}
+
 /// Map an `ApplyOutcome` to a notification. Used by all chords that go
 /// through `apply_*` helpers (`>`, `*`, `}`) so the user-facing strings
 /// stay in one place.
@@ -193,8 +339,7 @@ pub(crate) fn sibling(app: &mut App, source: PathSource, dir: SiblingDir) {
             let total = app.results_line_count_u32();
             app.results_cursor.update_total_lines(total);
             app.results_cursor.move_to_line(line);
-            app.results_scroll
-                .ensure_cursor_visible(app.results_cursor.cursor_line());
+            app.ensure_results_cursor_visible();
         }
         SiblingCursorOutcome::AtRoot => app.notification.show("Already at root"),
         SiblingCursorOutcome::NoPath => app.notification.show("No path at cursor"),
@@ -225,6 +370,64 @@ pub(crate) fn drill_back(app: &mut App) {
     }
 }
 
+/// `p` — pin the cursor row's path, or unpin it. The pin is kept by path,
+/// so it follows the value through later query edits.
+fn toggle_pin(app: &mut App) {
+    let Some(path) = app.current_cursor_path() else {
+        app.notification.show("No path at cursor");
+        return;
+    };
+    let jq = path.to_jq();
+    let mut line = app.results_cursor.cursor_line();
+    if let Some(parsed) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_parsed.as_ref())
+    {
+        // Resolve against the shown result so the next one is compared to
+        // it; a closing bracket's path is marked on its opening line
+        app.results_pins.relocate(parsed);
+        if let Some(opening) = crate::json_path::line_at_path(parsed, &path) {
+            line = opening as u32;
+        }
+    }
+    if app.results_pins.toggle(path, line) {
+        app.notification.show(&format!("Pinned {}", jq));
+    } else {
+        app.notification.show(&format!("Unpinned {}", jq));
+    }
+}
+
+/// `P` — move the cursor to the next pinned line, wrapping to the first
+fn jump_to_next_pin(app: &mut App) {
+    let Some(line) = app
+        .results_pins
+        .next_after(app.results_cursor.cursor_line())
+    else {
+        let message = if app.results_pins.is_empty() {
+            "No pinned lines"
+        } else {
+            "Pinned lines are not in this result"
+        };
+        app.notification.show(message);
+        return;
+    };
+    app.results_cursor.move_to_line(line);
+    app.ensure_results_cursor_visible();
+}
+
+/// `"y`: keep what `y` would copy in the scratch register
+fn park_shown_result(app: &mut App) {
+    let Some((text, what)) = clipboard::clipboard_events::shown_result(app) else {
+        app.notification.show("Nothing to park");
+        return;
+    };
+    app.scratch.set(&text);
+    app.results_cursor.exit_visual_mode();
+    app.notification
+        .show(&format!("Parked {} in scratch", what));
+}
+
 fn handle_visual_mode_key(app: &mut App, key: KeyEvent) -> bool {
     match key.code {
         KeyCode::Esc => {
@@ -254,16 +457,83 @@ fn handle_visual_mode_key(app: &mut App, key: KeyEvent) -> bool {
     }
 }
 
-fn move_cursor_up(app: &mut App, lines: u32) {
-    app.results_cursor.move_up(lines);
+/// Second key of a `z` fold chord: `zM` folds everything, `zR` unfolds,
+/// `zm`/`zr` fold one level more or less and `z0`-`z9` fold below that depth.
+/// Returns false for any other key so it is handled as usual.
+fn handle_fold_chord(app: &mut App, key: KeyEvent) -> bool {
+    let source = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone());
+    app.results_folds.sync(source.as_ref());
+
+    match key.code {
+        KeyCode::Char('M') => app.results_folds.set_depth(Some(0)),
+        KeyCode::Char('R') => app.results_folds.set_depth(None),
+        KeyCode::Char('m') => app.results_folds.fold_more(),
+        KeyCode::Char('r') => app.results_folds.fold_less(),
+        KeyCode::Char(c @ '0'..='9') => app
+            .results_folds
+            .set_depth(c.to_digit(10).map(|depth| depth as u16)),
+        _ => return false,
+    }
+
+    match app.results_folds.depth() {
+        Some(depth) => app
+            .notification
+            .show(&format!("Folded below depth {}", depth)),
+        None => app.notification.show("Unfolded all"),
+    }
+    // Keep the cursor on a shown row so it stays where the eye is
+    let row = app.results_folds.row_of(app.results_cursor.cursor_line());
+    if let Some(line) = app.results_folds.line_at(row) {
+        app.results_cursor.move_to_line(line);
+    }
+    let total = app.results_line_count_u32();
+    let viewport_height = app.results_scroll.viewport_height;
     app.results_scroll
-        .ensure_cursor_visible(app.results_cursor.cursor_line());
+        .update_bounds(app.results_folds.row_count(total), viewport_height);
+    app.ensure_results_cursor_visible();
+    true
+}
+
+/// Put the cursor on the last line shown
+pub(crate) fn move_cursor_to_last(app: &mut App) {
+    app.results_cursor.move_to_last();
+    // The last line is usually a folded-away closing bracket
+    let row = app.results_folds.row_of(app.results_cursor.cursor_line());
+    if let Some(line) = app.results_folds.line_at(row) {
+        app.results_cursor.move_to_line(line);
+    }
+}
+
+fn move_cursor_up(app: &mut App, lines: u32) {
+    if app.results_folds.is_active() {
+        let row = app.results_folds.row_of(app.results_cursor.cursor_line());
+        move_cursor_to_row(app, row.saturating_sub(lines));
+    } else {
+        app.results_cursor.move_up(lines);
+    }
+    app.ensure_results_cursor_visible();
 }
 
 fn move_cursor_down(app: &mut App, lines: u32) {
-    app.results_cursor.move_down(lines);
-    app.results_scroll
-        .ensure_cursor_visible(app.results_cursor.cursor_line());
+    if app.results_folds.is_active() {
+        let row = app.results_folds.row_of(app.results_cursor.cursor_line());
+        move_cursor_to_row(app, row.saturating_add(lines));
+    } else {
+        app.results_cursor.move_down(lines);
+    }
+    app.ensure_results_cursor_visible();
+}
+
+/// Put the cursor on the line shown at `row`, stopping at the last row
+fn move_cursor_to_row(app: &mut App, row: u32) {
+    let total = app.results_line_count_u32();
+    let last_row = app.results_folds.row_count(total).saturating_sub(1);
+    if let Some(line) = app.results_folds.line_at(row.min(last_row)) {
+        app.results_cursor.move_to_line(line);
+    }
 }
 
 fn exit_results_pane(app: &mut App) {
@@ -2,6 +2,7 @@
 
 use super::*;
 use crate::app::Focus;
+use crate::help::HelpTab;
 use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
 use std::sync::Arc;
 
@@ -1228,3 +1229,10 @@ mod drill_tests {
         );
     }
 }
+
+#[path = "results_events_tests/fold_tests.rs"]
+mod fold_tests;
+#[path = "results_events_tests/pin_tests.rs"]
+mod pin_tests;
+#[path = "results_events_tests/scratch_tests.rs"]
+mod scratch_tests;
@@ -0,0 +1,98 @@
+//! Tests for folding by depth with the `z` chords
+
+use super::*;
+
+fn setup_app_with_json_lines(content: &str) -> crate::app::App {
+    let mut app = app_with_query(".");
+    app.focus = Focus::ResultsPane;
+
+    let line_count = content.lines().count() as u32;
+    let arc = Arc::new(content.to_string());
+    let query_state = app.query.as_mut().unwrap();
+    query_state.result = Ok(content.to_string());
+    query_state.last_successful_result = Some(Arc::clone(&arc));
+    query_state.last_successful_result_unformatted = Some(arc);
+    query_state.cached_line_count = line_count;
+
+    app.results_scroll.update_bounds(line_count, 10);
+    app.results_cursor.update_total_lines(line_count);
+    app
+}
+
+const NESTED: &str = "{\n  \"a\": {\n    \"x\": 1,\n    \"y\": 2\n  },\n  \"b\": [\n    3\n  ]\n}";
+
+#[test]
+fn test_z_digit_folds_below_depth() {
+    let mut app = setup_app_with_json_lines(NESTED);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('1')));
+
+    assert_eq!(app.results_folds.depth(), Some(1));
+    assert_eq!(app.results_folds.row_count(9), 4);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Folded below depth 1")
+    );
+}
+
+#[test]
+fn test_j_skips_folded_lines() {
+    let mut app = setup_app_with_json_lines(NESTED);
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('1')));
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+    app.handle_key_event(key(KeyCode::Char('j')));
+    assert_eq!(app.results_cursor.cursor_line(), 5);
+    app.handle_key_event(key(KeyCode::Char('k')));
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+}
+
+#[test]
+fn test_capital_g_lands_on_last_shown_row() {
+    let mut app = setup_app_with_json_lines(NESTED);
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('M')));
+
+    app.handle_key_event(key(KeyCode::Char('G')));
+
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+}
+
+#[test]
+fn test_folding_moves_cursor_onto_a_shown_row() {
+    let mut app = setup_app_with_json_lines(NESTED);
+    app.results_cursor.move_to_line(3);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('1')));
+
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+}
+
+#[test]
+fn test_z_capital_r_unfolds_all() {
+    let mut app = setup_app_with_json_lines(NESTED);
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('m')));
+    assert_eq!(app.results_folds.depth(), Some(1));
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('R')));
+
+    assert_eq!(app.results_folds.depth(), None);
+    assert_eq!(app.notification.current_message(), Some("Unfolded all"));
+}
+
+#[test]
+fn test_z_followed_by_other_key_handles_that_key() {
+    let mut app = setup_app_with_json_lines(NESTED);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('j')));
+
+    assert_eq!(app.results_folds.depth(), None);
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+}
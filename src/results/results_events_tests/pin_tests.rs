@@ -0,0 +1,78 @@
+//! Tests for the change highlight toggle and for pinning lines with `p`, `P` and `Alt+P`
+
+use super::*;
+
+#[test]
+fn test_d_toggles_keeping_the_change_highlight() {
+    let mut app = setup_app_with_content(5, 10);
+
+    app.handle_key_event(key(KeyCode::Char('d')));
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Changed lines stay highlighted")
+    );
+
+    app.handle_key_event(key(KeyCode::Char('d')));
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Changed lines fade after a second")
+    );
+}
+
+fn app_on_test_json() -> crate::app::App {
+    let mut app = app_with_query(".");
+    app.focus = Focus::ResultsPane;
+    let line_count = app.results_line_count_u32();
+    app.results_scroll.update_bounds(line_count, 10);
+    app.results_cursor.update_total_lines(line_count);
+    app
+}
+
+#[test]
+fn test_p_pins_and_unpins_the_cursor_path() {
+    let mut app = app_on_test_json();
+    app.results_cursor.move_to_line(1);
+
+    app.handle_key_event(key(KeyCode::Char('p')));
+    assert_eq!(app.notification.current_message(), Some("Pinned .name"));
+    assert_eq!(app.results_pins.len(), 1);
+
+    app.handle_key_event(key(KeyCode::Char('p')));
+    assert_eq!(app.notification.current_message(), Some("Unpinned .name"));
+    assert!(app.results_pins.is_empty());
+}
+
+#[test]
+fn test_capital_p_jumps_to_the_next_pin() {
+    let mut app = app_on_test_json();
+    app.results_cursor.move_to_line(1);
+    app.handle_key_event(key(KeyCode::Char('p')));
+    app.results_cursor.move_to_line(3);
+    app.handle_key_event(key(KeyCode::Char('p')));
+
+    app.handle_key_event(key(KeyCode::Char('P')));
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+    app.handle_key_event(key(KeyCode::Char('P')));
+    assert_eq!(app.results_cursor.cursor_line(), 3);
+}
+
+#[test]
+fn test_capital_p_without_pins_notifies() {
+    let mut app = app_on_test_json();
+
+    app.handle_key_event(key(KeyCode::Char('P')));
+
+    assert_eq!(app.notification.current_message(), Some("No pinned lines"));
+}
+
+#[test]
+fn test_alt_p_clears_pins() {
+    let mut app = app_on_test_json();
+    app.results_cursor.move_to_line(1);
+    app.handle_key_event(key(KeyCode::Char('p')));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('p'), KeyModifiers::ALT));
+
+    assert!(app.results_pins.is_empty());
+    assert_eq!(app.notification.current_message(), Some("Cleared all pins"));
+}
@@ -0,0 +1,32 @@
+//! Tests for parking a visual selection in the scratch register with `"y`
+
+use super::*;
+
+#[test]
+fn test_visual_selection_parks_in_scratch() {
+    let mut app = setup_app_for_yank(20, 10);
+    app.results_cursor.move_to_line(2);
+
+    app.handle_key_event(key(KeyCode::Char('v')));
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('"')));
+    app.handle_key_event(key(KeyCode::Char('y')));
+
+    assert_eq!(app.scratch.text(), Some("line2\nline3"));
+    assert!(!app.results_cursor.is_visual_mode());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Parked 2 lines in scratch")
+    );
+}
+
+#[test]
+fn test_quote_then_other_key_is_handled_as_usual() {
+    let mut app = setup_app_for_yank(20, 10);
+
+    app.handle_key_event(key(KeyCode::Char('"')));
+    app.handle_key_event(key(KeyCode::Char('j')));
+
+    assert_eq!(app.scratch.text(), None);
+    assert_eq!(app.results_cursor.cursor_line(), 1);
+}
@@ -1,20 +1,23 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Color, Style},
     text::{Line, Span, Text},
     widgets::{Block, BorderType, Borders, Padding, Paragraph},
 };
 
 use crate::app::App;
+use crate::progress::spinner_span;
+use crate::results::fold_state::FoldState;
+use crate::results::{
+    cursor_render, diff_render, fold_render, last_stage_render, line_filter_render,
+    match_highlights_render, path_view_render, pin_render, search_highlight_render, table_render,
+};
 use crate::scroll::ScrollState;
-use crate::search::Match;
 use crate::search::search_render::SEARCH_BAR_HEIGHT;
 use crate::theme;
 use crate::widgets::scrollbar;
 
-const SPINNER_CHARS: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
-
 /// Below this column budget, the path-at-cursor span hides entirely rather
 /// than rendering a lonely `…` next to the existing stats prefix.
 const PATH_AT_CURSOR_MIN_WIDTH: usize = 5;
@@ -54,9 +57,12 @@ fn path_chord_hints(can_undo: bool) -> Vec<(&'static str, &'static str)> {
     hints
 }
 
-fn build_results_pane_hints(can_undo: bool) -> Line<'static> {
+fn build_results_pane_hints(can_undo: bool, rerooted: bool) -> Line<'static> {
     let mut hints: Vec<(&'static str, &'static str)> =
         vec![("Ctrl+T", "Edit Query"), ("i", "Edit Query")];
+    if rerooted {
+        hints.push(("⌫", "Up a Root"));
+    }
     hints.extend(path_chord_hints(can_undo));
     theme::border_hints::build_hints(&hints, theme::results::hint_key())
 }
@@ -178,15 +184,6 @@ fn build_back_badge(
     (spans, rect)
 }
 
-fn get_spinner(frame_count: u64) -> (char, Color) {
-    let index = (frame_count / 8) as usize;
-    let colors = theme::results::spinner_colors();
-    (
-        SPINNER_CHARS[index % SPINNER_CHARS.len()],
-        colors[index % colors.len()],
-    )
-}
-
 fn format_position_indicator(scroll: &ScrollState, line_count: u32) -> String {
     if line_count == 0 {
         return String::new();
@@ -286,22 +283,31 @@ fn render_scrollbar(frame: &mut Frame, area: Rect, scroll: &ScrollState, line_co
 ///
 /// Returns the (results_area, search_bar_area) tuple for region tracking.
 pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Option<Rect>) {
-    let (results_area, search_area) = if app.search.is_visible() {
+    let bar_visible = app.search.is_visible() || app.results_filter.is_editing();
+    let (results_area, bar_area) = if bar_visible {
         let layout = Layout::vertical([Constraint::Min(3), Constraint::Length(SEARCH_BAR_HEIGHT)])
             .split(area);
         (layout[0], Some(layout[1]))
     } else {
         (area, None)
     };
+    let search_area = bar_area.filter(|_| app.search.is_visible());
 
     // Check if query is available
     let query_state = match &app.query {
         Some(q) => q,
         None => {
             // Show loading indicator or error if file loader is present
-            if let Some(loader) = &app.file_loader {
+            if let Some(malformed) = &app.malformed_input {
+                crate::results::malformed_render::render(
+                    malformed,
+                    &mut app.results_scroll,
+                    frame,
+                    results_area,
+                );
+            } else if let Some(loader) = &app.file_loader {
                 if loader.is_loading() {
-                    render_loading_indicator(frame, results_area);
+                    render_loading_indicator(frame, results_area, app.frame_count);
                 } else if let crate::input::loader::LoadingState::Error(e) = loader.state() {
                     render_error_message(
                         frame,
@@ -315,14 +321,21 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
     };
 
     let is_pending = query_state.is_pending();
-    let stats_info = app.stats.display().unwrap_or_else(|| "Results".to_string());
+    let stats_info = if query_state.is_partial() {
+        // Stats wait for the finished result
+        format!("{} lines so far", query_state.line_count())
+    } else {
+        app.stats.display().unwrap_or_else(|| "Results".to_string())
+    };
 
     // Path-at-cursor: only resolved on success branch. Always falls back to
     // the cursor row, but prefers the current search match's row when
     // search is visible and has matches — that way the path tracks what the
     // user is actually looking at while typing or navigating a search.
     // Computed once here so the borrow on query_state below stays clean.
-    let path_at_cursor_jq: Option<String> = if app.focus == crate::app::Focus::ResultsPane
+    let path_at_cursor_jq: Option<String> = if app.has_focus(crate::app::Focus::ResultsPane)
+        && !app.results_filter.is_active()
+        && !app.results_table.is_active()
         && !query_state.is_synthetic_merge
         && query_state.result.is_ok()
         && !query_state.is_empty_result
@@ -334,31 +347,76 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         };
         match path_row {
             Some(row) => app.path_at_row(row).map(|p| p.to_jq()),
-            None if !app.search.is_visible() => app.current_cursor_path().map(|p| p.to_jq()),
+            // The path view has no cursor, only search matches
+            None if !app.search.is_visible() && !app.results_paths.is_active() => {
+                app.current_cursor_path().map(|p| p.to_jq())
+            }
             None => None,
         }
     } else {
         None
     };
 
-    // Re-borrow query_state after the &mut self call above released it.
-    let query_state = match &app.query {
-        Some(q) => q,
-        None => return (results_area, search_area),
-    };
-
     // Calculate viewport dimensions and position indicator early for title
     let viewport_height = results_area.height.saturating_sub(2);
     let viewport_width = results_area.width.saturating_sub(2);
     let line_count = app.results_line_count_u32();
-    app.results_scroll
-        .update_bounds(line_count, viewport_height);
-    if let Some(q) = &app.query {
+    // Scrolling counts shown rows; the cursor keeps counting output lines
+    let source = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone());
+    app.results_folds.sync(source.as_ref());
+    app.results_filter.sync(source.as_ref());
+    app.results_table.sync(source.as_ref());
+    app.results_paths.sync(source.as_ref());
+    let filtered = app.results_filter.is_active();
+    let table = app.results_table.shown().filter(|_| !filtered);
+    let tabled = table.is_some();
+    let paths = !filtered && !tabled && app.results_paths.is_active();
+    // Views that have no cursor show other lines than the output's, so
+    // nothing keyed by output line is drawn over them
+    let plain = !filtered && !tabled && !paths;
+    let row_count = if filtered {
+        app.results_filter.row_count()
+    } else if let Some(table) = table {
+        table.rows.len() as u32
+    } else if paths {
+        app.results_paths.lines().len() as u32
+    } else {
+        app.results_folds.row_count(line_count)
+    };
+    if let Some(table) = table {
+        // The header row stays put above the scrolling rows
+        let table_width = table.width() as u16;
+        app.results_scroll
+            .update_bounds(row_count, viewport_height.saturating_sub(1));
         app.results_scroll
-            .update_h_bounds(q.max_line_width(), viewport_width);
+            .update_h_bounds(table_width, viewport_width);
+    } else if paths {
+        let width = app.results_paths.width().min(u16::MAX as usize) as u16;
+        app.results_scroll.update_bounds(row_count, viewport_height);
+        app.results_scroll.update_h_bounds(width, viewport_width);
+    } else {
+        app.results_scroll.update_bounds(row_count, viewport_height);
+        if let Some(q) = &app.query {
+            app.results_scroll
+                .update_h_bounds(q.max_line_width(), viewport_width);
+        }
     }
 
     app.results_cursor.update_total_lines(line_count);
+    if std::mem::take(&mut app.reveal_results_cursor) && plain {
+        let row = app.results_folds.row_of(app.results_cursor.cursor_line());
+        app.results_scroll.ensure_cursor_visible(row);
+    }
+    crate::results::follow_events::pin_to_end(app);
+
+    // Re-borrow query_state after the &mut calls above released it.
+    let query_state = match &app.query {
+        Some(q) => q,
+        None => return (results_area, search_area),
+    };
 
     if let Some(q) = &app.query
         && let Some(widths) = &q.cached_line_widths
@@ -367,7 +425,34 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             .update_line_widths(std::sync::Arc::clone(widths));
     }
 
-    let position_indicator = format_position_indicator(&app.results_scroll, line_count);
+    let mut position_indicator = format_position_indicator(&app.results_scroll, row_count);
+    if let Some(depth) = app.results_folds.depth()
+        && app.results_folds.is_active()
+    {
+        position_indicator.push_str(&format!(" · fold {}", depth));
+    }
+    if !app.results_pins.is_empty() {
+        position_indicator.push_str(&pin_render::format_count(&app.results_pins));
+    }
+    if filtered {
+        position_indicator.push_str(&format!(
+            " · {} of {} lines",
+            app.results_filter.row_count(),
+            app.results_filter.total_lines()
+        ));
+    }
+    if tabled {
+        position_indicator.push_str(" · table");
+    }
+    if paths {
+        position_indicator.push_str(" · paths");
+    }
+    if plain && app.results_last_stage.is_visible() {
+        position_indicator.push_str(" · last stage");
+    }
+    if app.results_follow {
+        position_indicator.push_str(" · following");
+    }
 
     let search_visible = app.search.is_visible();
 
@@ -423,11 +508,7 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         };
         let mut spans = Vec::new();
         if is_pending {
-            let (spinner_char, spinner_color) = get_spinner(app.frame_count);
-            spans.push(Span::styled(
-                format!("{} ", spinner_char),
-                Style::default().fg(spinner_color),
-            ));
+            spans.push(spinner_span(app.frame_count));
         }
         // The back-badge spans already start with a leading space. Always
         // push a trailing neutral space so the cyan badge never renders flush
@@ -437,8 +518,16 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             spans.extend(back_spans.clone());
         }
         spans.push(Span::raw(" "));
+        let timed_out = query_state
+            .result
+            .as_ref()
+            .is_err_and(|e| e.starts_with(crate::query::worker::types::TIMED_OUT_PREFIX));
         spans.push(Span::styled(
-            "  ⚠ Syntax Error  ",
+            if timed_out {
+                "  ⏱ Timed Out  "
+            } else {
+                "  ⚠ Syntax Error  "
+            },
             theme::results::badge_syntax_error(),
         ));
         if !stats_info.is_empty() {
@@ -447,6 +536,16 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
                 Style::default().fg(text_color),
             ));
         }
+        if query_state
+            .optional_fix
+            .as_ref()
+            .is_some_and(|fix| fix.original == app.input.query())
+        {
+            spans.push(Span::styled(
+                "| []? fix: Ctrl+E ",
+                Style::default().fg(theme::results::error_hint_text()),
+            ));
+        }
         (Line::from(spans), theme::results::border_warning())
     } else if query_state.is_empty_result {
         // EMPTY: Gray text, gray border (unfocused) - or search color when search visible
@@ -457,11 +556,7 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         };
         let mut spans = Vec::new();
         if is_pending {
-            let (spinner_char, spinner_color) = get_spinner(app.frame_count);
-            spans.push(Span::styled(
-                format!("{} ", spinner_char),
-                Style::default().fg(spinner_color),
-            ));
+            spans.push(spinner_span(app.frame_count));
         }
         if has_back_badge {
             spans.extend(back_spans.clone());
@@ -475,6 +570,12 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             format!(" {} | Showing last non-empty result ", stats_info),
             Style::default().fg(text_color),
         ));
+        if let Some(hint) = &query_state.field_hint {
+            spans.push(Span::styled(
+                format!("| .{}? Ctrl+E ", hint.suggestion),
+                Style::default().fg(theme::results::error_hint_text()),
+            ));
+        }
         (Line::from(spans), theme::results::border_unfocused())
     } else {
         // SUCCESS: Green text, green border (unfocused) - or search color when search visible
@@ -507,11 +608,7 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             _ => Vec::new(),
         };
         if is_pending {
-            let (spinner_char, spinner_color) = get_spinner(app.frame_count);
-            let mut spans = vec![Span::styled(
-                format!("{} ", spinner_char),
-                Style::default().fg(spinner_color),
-            )];
+            let mut spans = vec![spinner_span(app.frame_count)];
             // Without the back badge, the spinner already supplies the
             // trailing space before the stats. With the badge inserted
             // between them, the stats span needs its own leading space so
@@ -561,7 +658,7 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         } else {
             theme::results::search_inactive()
         }
-    } else if app.focus == crate::app::Focus::ResultsPane {
+    } else if app.has_focus(crate::app::Focus::ResultsPane) {
         theme::results::border_focused()
     } else {
         unfocused_border_color
@@ -585,102 +682,130 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         title
     };
 
-    // Always render from cached pre-rendered text
-    if let Some(rendered) = &query_state.last_successful_result_rendered {
-        // Pre-compute the bottom-row pieces so the centered hint strip can be
-        // trimmed to the room left over after the timing (left) claims its
-        // slot. The right-anchored indicator now lives on the TOP border, so
-        // the bottom-right slot is empty and the centered strip reclaims that
-        // space (right_title = None). ratatui's Block draws right then center
-        // then left over the same row, so a centered title wider than its
-        // slot would silently overwrite a right title — explicit trimming
-        // here keeps the strip clear of the timing badge.
-        let timing_title = query_state.cached_execution_time_ms.map(|ms| {
-            let timing_text = format!(" {} ", format_execution_time(ms));
-            let timing_color = get_timing_color(ms, border_color);
-            Line::from(vec![Span::styled(
-                timing_text,
-                Style::default().fg(timing_color),
-            )])
-        });
-        let match_count_badge = if search_visible && app.search.is_confirmed() {
-            let match_count = app.search.match_count_display();
-            Some(Line::from(vec![
-                Span::raw(" "),
-                Span::styled(
-                    format!("  {}  ", match_count),
-                    theme::search::badge_match_count(),
-                ),
-                Span::raw(" "),
-            ]))
-        } else {
+    // Pre-compute the bottom-row pieces so the centered hint strip can be
+    // trimmed to the room left over after the timing (left) claims its
+    // slot. The right-anchored indicator now lives on the TOP border, so
+    // the bottom-right slot is empty and the centered strip reclaims that
+    // space (right_title = None). ratatui's Block draws right then center
+    // then left over the same row, so a centered title wider than its
+    // slot would silently overwrite a right title — explicit trimming
+    // here keeps the strip clear of the timing badge.
+    let timing_title = query_state.cached_execution_time_ms.map(|ms| {
+        let timing_text = format!(" {} ", format_execution_time(ms));
+        let timing_color = get_timing_color(ms, border_color);
+        Line::from(vec![Span::styled(
+            timing_text,
+            Style::default().fg(timing_color),
+        )])
+    });
+    let match_count_badge = if search_visible && app.search.is_confirmed() {
+        let match_count = app.search.match_count_display();
+        Some(Line::from(vec![
+            Span::raw(" "),
+            Span::styled(
+                format!("  {}  ", match_count),
+                theme::search::badge_match_count(),
+            ),
+            Span::raw(" "),
+        ]))
+    } else {
+        None
+    };
+    // Right-anchored TOP-border indicator: match-count badge during
+    // confirmed search, position indicator otherwise.
+    let top_right_title: Option<Line<'static>> = match_count_badge.or(position_title);
+
+    let center_budget = bottom_center_budget(results_area.width, timing_title.as_ref(), None);
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::right(1))
+        .title(title)
+        .border_style(Style::default().fg(border_color));
+    // The right-aligned top title coexists with the left-anchored `.title`
+    // above (ratatui supports multiple top titles with distinct
+    // alignments); the breadcrumb budget already reserved space for it.
+    if let Some(rt) = top_right_title {
+        block = block.title_top(rt.alignment(Alignment::Right));
+    }
+    if search_visible && app.search.is_confirmed() {
+        block = block.title_bottom(
+            truncate_hints_to_width(
+                build_search_hints(!app.query_undo.is_empty()),
+                center_budget,
+            )
+            .alignment(Alignment::Center),
+        );
+    }
+    // While editing the search query the drill chords are inert
+    // (typed as text instead), so the results-pane bottom strip is
+    // intentionally bare in that mode — the search bar carries the
+    // applicable Enter / Tab / Esc hints.
+
+    // Add navigation hints when results pane is focused; the views other
+    // than the plain output only apply once there is a result
+    let has_result = query_state.last_successful_result_rendered.is_some();
+    if app.has_focus(crate::app::Focus::ResultsPane) {
+        let hints = if has_result && filtered {
+            Some(line_filter_render::hints())
+        } else if has_result && tabled {
+            Some(table_render::hints())
+        } else if search_visible {
             None
+        } else if has_result && paths {
+            Some(path_view_render::hints())
+        } else {
+            Some(build_results_pane_hints(
+                !app.query_undo.is_empty(),
+                !app.root_stack.is_empty(),
+            ))
         };
-        // Right-anchored TOP-border indicator: match-count badge during
-        // confirmed search, position indicator otherwise.
-        let top_right_title: Option<Line<'static>> =
-            match_count_badge.or_else(|| position_title.clone());
-
-        let center_budget = bottom_center_budget(results_area.width, timing_title.as_ref(), None);
-
-        let mut block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .padding(Padding::right(1))
-            .title(title)
-            .border_style(Style::default().fg(border_color));
-        // The right-aligned top title coexists with the left-anchored `.title`
-        // above (ratatui supports multiple top titles with distinct
-        // alignments); the breadcrumb budget already reserved space for it.
-        if let Some(rt) = top_right_title {
-            block = block.title_top(rt.alignment(Alignment::Right));
-        }
-        if search_visible && app.search.is_confirmed() {
+        if let Some(hints) = hints {
             block = block.title_bottom(
-                truncate_hints_to_width(
-                    build_search_hints(!app.query_undo.is_empty()),
-                    center_budget,
-                )
-                .alignment(Alignment::Center),
-            );
-        }
-        // While editing the search query the drill chords are inert
-        // (typed as text instead), so the results-pane bottom strip is
-        // intentionally bare in that mode — the search bar carries the
-        // applicable Enter / Tab / Esc hints.
-
-        // Add navigation hints when results pane is focused and search is not visible
-        if !search_visible && app.focus == crate::app::Focus::ResultsPane {
-            block = block.title_bottom(
-                truncate_hints_to_width(
-                    build_results_pane_hints(!app.query_undo.is_empty()),
-                    center_budget,
-                )
-                .alignment(Alignment::Center),
+                truncate_hints_to_width(hints, center_budget).alignment(Alignment::Center),
             );
         }
+    }
 
-        // Add execution time display in bottom-left corner
-        if let Some(tt) = timing_title.clone() {
-            block = block.title_bottom(tt.alignment(Alignment::Left));
-        }
+    // Add execution time display in bottom-left corner
+    if let Some(tt) = timing_title {
+        block = block.title_bottom(tt.alignment(Alignment::Left));
+    }
 
+    // Always render from cached pre-rendered text
+    if let Some(rendered) = &query_state.last_successful_result_rendered {
         // Use cached pre-rendered text
         // Optimization: Only clone visible viewport to avoid massive allocations
         let scroll_offset = app.results_scroll.offset as usize;
         let viewport_lines = viewport_height as usize;
 
         // Slice to viewport range (with bounds checking)
-        let total_lines = rendered.lines.len();
-        let end_line = (scroll_offset + viewport_lines).min(total_lines);
-        let visible_lines = if scroll_offset < total_lines {
-            &rendered.lines[scroll_offset..end_line]
+        let viewport_text = if filtered {
+            line_filter_render::viewport(
+                rendered,
+                app.results_filter.rows(),
+                scroll_offset,
+                viewport_lines,
+            )
+        } else if tabled {
+            table_render::viewport(&app.results_table, scroll_offset, viewport_lines)
+        } else if paths {
+            path_view_render::viewport(app.results_paths.lines(), scroll_offset, viewport_lines)
+        } else if app.results_folds.is_active() {
+            fold_render::viewport(rendered, &app.results_folds, scroll_offset, viewport_lines)
         } else {
-            &[]
-        };
+            let total_lines = rendered.lines.len();
+            let end_line = (scroll_offset + viewport_lines).min(total_lines);
+            let visible_lines = if scroll_offset < total_lines {
+                &rendered.lines[scroll_offset..end_line]
+            } else {
+                &[]
+            };
 
-        // Clone only visible lines (50 lines instead of 100K+ for large files!)
-        let viewport_text = Text::from(visible_lines.to_vec());
+            // Clone only visible lines (50 lines instead of 100K+ for large files!)
+            Text::from(visible_lines.to_vec())
+        };
 
         // Apply the theme's stale modifier (DIM on dark; none on light,
         // where DIM bleaches toward the near-white background).
@@ -690,21 +815,64 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             viewport_text
         };
 
-        // Apply search highlights only to visible viewport
-        let final_text = if app.search.is_visible() && !app.search.matches().is_empty() {
-            apply_search_highlights(
+        // The edit's diff flash takes precedence over the last stage
+        let show_last_stage = app.results_last_stage.is_visible()
+            && plain
+            && !is_stale
+            && !app.results_diff.is_visible();
+        let viewport_text = if show_last_stage {
+            last_stage_render::apply_dimming(
                 viewport_text,
-                &app.search,
+                &app.results_last_stage,
+                &app.results_folds,
                 app.results_scroll.offset,
-                viewport_height,
             )
         } else {
             viewport_text
         };
 
-        let show_cursor = app.focus == crate::app::Focus::ResultsPane;
+        let viewport_text = if app.results_diff.is_visible() && plain {
+            diff_render::apply_highlights(
+                viewport_text,
+                &app.results_diff,
+                &app.results_folds,
+                app.results_scroll.offset,
+            )
+        } else {
+            viewport_text
+        };
+
+        let viewport_text = if app.results_matches.is_empty() {
+            viewport_text
+        } else {
+            match_highlights_render::apply_highlights(viewport_text, &app.results_matches)
+        };
+
+        // Apply search highlights only to visible viewport
+        // The path view is searched by its own lines, which never fold
+        let unfolded = FoldState::new();
+        let folds = if paths { &unfolded } else { &app.results_folds };
+        let final_text =
+            if app.search.is_visible() && !app.search.matches().is_empty() && (plain || paths) {
+                search_highlight_render::apply_highlights(
+                    viewport_text,
+                    &app.search,
+                    folds,
+                    app.results_scroll.offset,
+                    viewport_height,
+                )
+            } else {
+                viewport_text
+            };
+
+        let show_cursor = app.has_focus(crate::app::Focus::ResultsPane) && plain;
         let final_text = if show_cursor {
-            apply_cursor_highlights(final_text, &app.results_cursor, app.results_scroll.offset)
+            cursor_render::apply_highlights(
+                final_text,
+                &app.results_cursor,
+                &app.results_folds,
+                app.results_scroll.offset,
+            )
         } else {
             final_text
         };
@@ -715,98 +883,72 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             .scroll((0, app.results_scroll.h_offset));
 
         frame.render_widget(content, results_area);
-        render_scrollbar(frame, results_area, &app.results_scroll, line_count);
+        render_scrollbar(frame, results_area, &app.results_scroll, row_count);
+        if app.results_diff.is_visible() && plain {
+            diff_render::render_removal_markers(
+                frame,
+                results_area,
+                |line| app.results_diff.is_removed_at(line),
+                &app.results_folds,
+                app.results_scroll.offset,
+                line_count,
+            );
+        } else if show_last_stage {
+            diff_render::render_removal_markers(
+                frame,
+                results_area,
+                |line| app.results_last_stage.is_removed_at(line),
+                &app.results_folds,
+                app.results_scroll.offset,
+                line_count,
+            );
+        }
+        if !app.results_pins.is_empty() && plain {
+            pin_render::render_markers(
+                frame,
+                results_area,
+                &app.results_pins,
+                &app.results_folds,
+                app.results_scroll.offset,
+                row_count,
+            );
+        }
 
         if show_cursor {
-            render_cursor_indicator(
+            cursor_render::render_indicator(
                 frame,
                 results_area,
                 &app.results_cursor,
+                &app.results_folds,
                 app.results_scroll.offset,
                 app.results_scroll.h_offset,
             );
         }
     } else {
         // No successful result yet - show empty
-        let timing_title = query_state.cached_execution_time_ms.map(|ms| {
-            let timing_text = format!(" {} ", format_execution_time(ms));
-            let timing_color = get_timing_color(ms, border_color);
-            Line::from(vec![Span::styled(
-                timing_text,
-                Style::default().fg(timing_color),
-            )])
-        });
-        let match_count_badge = if search_visible && app.search.is_confirmed() {
-            let match_count = app.search.match_count_display();
-            Some(Line::from(vec![
-                Span::raw(" "),
-                Span::styled(
-                    format!("  {}  ", match_count),
-                    theme::search::badge_match_count(),
-                ),
-                Span::raw(" "),
-            ]))
-        } else {
-            None
-        };
-        // Right-anchored TOP-border indicator (see the cached branch above):
-        // match-count badge during confirmed search, position otherwise. With
-        // it off the bottom border, the centered hint strip reclaims the
-        // bottom-right space (right_title = None).
-        let top_right_title: Option<Line<'static>> =
-            match_count_badge.or_else(|| position_title.clone());
-
-        let center_budget = bottom_center_budget(results_area.width, timing_title.as_ref(), None);
-
-        let mut block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .padding(Padding::right(1))
-            .title(title)
-            .border_style(Style::default().fg(border_color));
-        if let Some(rt) = top_right_title {
-            block = block.title_top(rt.alignment(Alignment::Right));
-        }
-        if search_visible && app.search.is_confirmed() {
-            block = block.title_bottom(
-                truncate_hints_to_width(
-                    build_search_hints(!app.query_undo.is_empty()),
-                    center_budget,
-                )
-                .alignment(Alignment::Center),
-            );
-        } else if app.focus == crate::app::Focus::ResultsPane && !search_visible {
-            block = block.title_bottom(
-                truncate_hints_to_width(
-                    build_results_pane_hints(!app.query_undo.is_empty()),
-                    center_budget,
-                )
-                .alignment(Alignment::Center),
-            );
-        }
-
-        if let Some(tt) = timing_title {
-            block = block.title_bottom(tt.alignment(Alignment::Left));
-        }
-
-        let empty_text = Text::from("");
-        let content = Paragraph::new(empty_text).block(block);
-
+        let content = Paragraph::new(Text::from("")).block(block);
         frame.render_widget(content, results_area);
     }
     if let Some(search_rect) = search_area {
         crate::search::search_render::render_bar(app, frame, search_rect);
+    } else if let Some(filter_rect) = bar_area {
+        line_filter_render::render_bar(app, frame, filter_rect);
     }
 
     (results_area, search_area)
 }
 
-fn render_loading_indicator(frame: &mut Frame, area: Rect) {
+fn render_loading_indicator(frame: &mut Frame, area: Rect, frame_count: u64) {
     let text = "Loading file...";
+    let title = Line::from(vec![
+        Span::raw(" "),
+        spinner_span(frame_count),
+        Span::raw("Loading "),
+    ]);
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .title(" Loading ")
+        .title(title)
         .border_style(Style::default().fg(theme::results::border_warning()));
 
     let paragraph = Paragraph::new(text)
@@ -852,220 +994,6 @@ fn apply_stale_modifier(text: Text<'_>) -> Text<'static> {
     )
 }
 
-fn apply_search_highlights(
-    text: Text<'_>,
-    search_state: &crate::search::SearchState,
-    scroll_offset: u16,
-    viewport_height: u16,
-) -> Text<'static> {
-    let matches = search_state.matches();
-    let current_match_index = search_state.current_index();
-
-    if matches.is_empty() {
-        return Text::from(
-            text.lines
-                .into_iter()
-                .map(|line| {
-                    Line::from(
-                        line.spans
-                            .into_iter()
-                            .map(|span| Span::styled(span.content.into_owned(), span.style))
-                            .collect::<Vec<_>>(),
-                    )
-                })
-                .collect::<Vec<_>>(),
-        );
-    }
-
-    let _ = viewport_height;
-    let highlighted_lines: Vec<Line<'static>> = text
-        .lines
-        .into_iter()
-        .enumerate()
-        .map(|(line_idx, line)| {
-            // Adjust line_idx by scroll_offset to get absolute line number
-            let absolute_line = line_idx + scroll_offset as usize;
-            let line_matches: Vec<(usize, &Match)> =
-                search_state.matches_on_line(absolute_line as u32).collect();
-
-            if line_matches.is_empty() {
-                Line::from(
-                    line.spans
-                        .into_iter()
-                        .map(|span| Span::styled(span.content.into_owned(), span.style))
-                        .collect::<Vec<_>>(),
-                )
-            } else {
-                apply_highlights_to_line(line, &line_matches, current_match_index)
-            }
-        })
-        .collect();
-
-    Text::from(highlighted_lines)
-}
-fn apply_highlights_to_line(
-    line: Line<'_>,
-    matches: &[(usize, &Match)],
-    current_match_index: usize,
-) -> Line<'static> {
-    let mut char_styles: Vec<(char, Style)> = Vec::new();
-
-    for span in &line.spans {
-        for ch in span.content.chars() {
-            char_styles.push((ch, span.style));
-        }
-    }
-
-    for (match_idx, m) in matches {
-        let col_start = m.col as usize;
-        let col_end = col_start + m.len as usize;
-
-        let highlight_style = if *match_idx == current_match_index {
-            Style::default()
-                .fg(theme::results::current_match_fg())
-                .bg(theme::results::current_match_bg())
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default()
-                .fg(theme::results::match_highlight_fg())
-                .bg(theme::results::match_highlight_bg())
-        };
-
-        for i in col_start..col_end.min(char_styles.len()) {
-            char_styles[i].1 = highlight_style;
-        }
-    }
-
-    let visible_chars: Vec<(char, Style)> = char_styles;
-    let mut result_spans: Vec<Span<'static>> = Vec::new();
-    let mut current_text = String::new();
-    let mut current_style: Option<Style> = None;
-
-    for (ch, style) in visible_chars {
-        match current_style {
-            Some(s) if s == style => {
-                current_text.push(ch);
-            }
-            _ => {
-                if !current_text.is_empty()
-                    && let Some(s) = current_style
-                {
-                    result_spans.push(Span::styled(current_text.clone(), s));
-                }
-                current_text = ch.to_string();
-                current_style = Some(style);
-            }
-        }
-    }
-    if !current_text.is_empty()
-        && let Some(s) = current_style
-    {
-        result_spans.push(Span::styled(current_text, s));
-    }
-
-    Line::from(result_spans)
-}
-
-fn apply_cursor_highlights(
-    text: Text<'_>,
-    cursor_state: &crate::results::cursor_state::CursorState,
-    scroll_offset: u16,
-) -> Text<'static> {
-    let cursor_line = cursor_state.cursor_line();
-    let hovered_line = cursor_state.hovered_line();
-    let is_visual = cursor_state.is_visual_mode();
-    let (sel_start, sel_end) = cursor_state.selection_range();
-
-    Text::from(
-        text.lines
-            .into_iter()
-            .enumerate()
-            .map(|(line_idx, line)| {
-                let absolute_line = line_idx as u32 + scroll_offset as u32;
-
-                let bg_color =
-                    if is_visual && absolute_line >= sel_start && absolute_line <= sel_end {
-                        Some(theme::results::visual_selection_bg())
-                    } else if absolute_line == cursor_line {
-                        Some(theme::results::cursor_line_bg())
-                    } else if Some(absolute_line) == hovered_line {
-                        Some(theme::results::hovered_line_bg())
-                    } else {
-                        None
-                    };
-
-                if let Some(bg) = bg_color {
-                    Line::from(
-                        line.spans
-                            .into_iter()
-                            .map(|span| {
-                                let existing_bg = span.style.bg;
-                                let is_search_highlight = existing_bg
-                                    == Some(theme::results::current_match_bg())
-                                    || existing_bg == Some(theme::results::match_highlight_bg());
-
-                                if is_search_highlight {
-                                    Span::styled(span.content.into_owned(), span.style)
-                                } else {
-                                    Span::styled(span.content.into_owned(), span.style.bg(bg))
-                                }
-                            })
-                            .collect::<Vec<_>>(),
-                    )
-                } else {
-                    Line::from(
-                        line.spans
-                            .into_iter()
-                            .map(|span| Span::styled(span.content.into_owned(), span.style))
-                            .collect::<Vec<_>>(),
-                    )
-                }
-            })
-            .collect::<Vec<_>>(),
-    )
-}
-
-fn render_cursor_indicator(
-    frame: &mut Frame,
-    results_area: Rect,
-    cursor_state: &crate::results::cursor_state::CursorState,
-    scroll_offset: u16,
-    _h_offset: u16,
-) {
-    let cursor_line = cursor_state.cursor_line();
-
-    if cursor_line < scroll_offset as u32 {
-        return;
-    }
-
-    let relative_line = cursor_line.saturating_sub(scroll_offset as u32) as u16;
-    let viewport_height = results_area.height.saturating_sub(2);
-
-    if relative_line >= viewport_height {
-        return;
-    }
-
-    let indicator_x = results_area.x;
-    let indicator_y = results_area
-        .y
-        .saturating_add(1)
-        .saturating_add(relative_line);
-
-    let indicator = Span::styled(
-        "▌",
-        Style::default().fg(theme::results::cursor_indicator_fg()),
-    );
-    frame.render_widget(
-        Paragraph::new(Line::from(indicator)),
-        Rect {
-            x: indicator_x,
-            y: indicator_y,
-            width: 1,
-            height: 1,
-        },
-    );
-}
-
 #[cfg(test)]
 #[path = "results_render_tests.rs"]
 mod results_render_tests;
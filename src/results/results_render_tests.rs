@@ -72,121 +72,6 @@ mod property_tests {
     }
 }
 
-#[cfg(test)]
-mod spinner_tests {
-    use super::super::{SPINNER_CHARS, get_spinner};
-    use crate::theme;
-
-    #[test]
-    fn test_spinner_first_frame() {
-        let (char, color) = get_spinner(0);
-        assert_eq!(char, SPINNER_CHARS[0]);
-        assert_eq!(color, theme::results::spinner_colors()[0]);
-    }
-
-    #[test]
-    fn test_spinner_second_frame() {
-        let (char, color) = get_spinner(8);
-        assert_eq!(char, SPINNER_CHARS[1]);
-        assert_eq!(color, theme::results::spinner_colors()[1]);
-    }
-
-    #[test]
-    fn test_spinner_char_cycling() {
-        // Test all 10 spinner characters
-        for i in 0..10 {
-            let (char, _) = get_spinner(i * 8);
-            assert_eq!(
-                char,
-                SPINNER_CHARS[i as usize],
-                "Frame {} should have char {}",
-                i * 8,
-                SPINNER_CHARS[i as usize]
-            );
-        }
-    }
-
-    #[test]
-    fn test_spinner_color_cycling() {
-        // Test all 8 colors
-        for i in 0..8 {
-            let (_, color) = get_spinner(i * 8);
-            assert_eq!(
-                color,
-                theme::results::spinner_colors()[i as usize],
-                "Frame {} should have color at index {}",
-                i * 8,
-                i
-            );
-        }
-    }
-
-    #[test]
-    fn test_spinner_char_wrapping() {
-        // After 10 chars (80 frames), should wrap back to first char
-        let (char_start, _) = get_spinner(0);
-        let (char_wrap, _) = get_spinner(80);
-        assert_eq!(
-            char_start, char_wrap,
-            "Character should wrap after 10 iterations"
-        );
-    }
-
-    #[test]
-    fn test_spinner_color_wrapping() {
-        // After 8 colors (64 frames), should wrap back to first color
-        let (_, color_start) = get_spinner(0);
-        let (_, color_wrap) = get_spinner(64);
-        assert_eq!(
-            color_start, color_wrap,
-            "Color should wrap after 8 iterations"
-        );
-    }
-
-    #[test]
-    fn test_spinner_independent_cycling() {
-        // Chars and colors cycle independently (different lengths: 10 vs 8)
-        // At frame 40: char index = 5, color index = 5
-        let (char, _) = get_spinner(40);
-        assert_eq!(char, SPINNER_CHARS[5]);
-
-        // At frame 48: char index = 6, color index = 6
-        let (char, _) = get_spinner(48);
-        assert_eq!(char, SPINNER_CHARS[6]);
-
-        // At frame 64: char index = 8, color index = 0 (wrapped)
-        let (char, color) = get_spinner(64);
-        assert_eq!(char, SPINNER_CHARS[8]);
-        assert_eq!(color, theme::results::spinner_colors()[0]);
-    }
-
-    #[test]
-    fn test_spinner_large_frame_count() {
-        // Test with large frame count to ensure no overflow/panic
-        let (char, color) = get_spinner(u64::MAX);
-        // Should still produce valid char and color
-        assert!(SPINNER_CHARS.contains(&char));
-        assert!(theme::results::spinner_colors().contains(&color));
-    }
-
-    #[test]
-    fn test_spinner_animation_speed() {
-        // Verify frames 0-7 all use same char (changes every 8 frames)
-        let (char0, _) = get_spinner(0);
-        for frame in 1..8 {
-            let (char, _) = get_spinner(frame);
-            assert_eq!(char, char0, "Frames 0-7 should all use same character");
-        }
-
-        // Frame 8 should use different char
-        let (char8, _) = get_spinner(8);
-        assert_ne!(
-            char8, char0,
-            "Frame 8 should use different character than frame 0"
-        );
-    }
-}
-
 #[cfg(test)]
 mod position_indicator_tests {
     use super::super::format_position_indicator;
@@ -1013,7 +898,7 @@ mod truncate_hints_tests {
         // A full hint line is far wider than 1 cell. The triple-pop loop stops
         // once <= 3 spans remain, so a pathological max_width of 1 forces the
         // secondary per-span fallback loop to run until the line fits or empties.
-        let hints = build_results_pane_hints(true);
+        let hints = build_results_pane_hints(true, false);
         let original = line_width(&hints);
         assert!(
             original > 1,
@@ -1028,7 +913,7 @@ mod truncate_hints_tests {
         );
 
         // Width 0 is the extreme degenerate case: the fallback must empty it.
-        let hints0 = build_results_pane_hints(true);
+        let hints0 = build_results_pane_hints(true, false);
         let truncated0 = truncate_hints_to_width(hints0, 0);
         assert_eq!(
             line_width(&truncated0),
@@ -1080,166 +965,13 @@ mod back_badge_tests {
     }
 }
 
-#[cfg(test)]
-mod apply_search_highlights_tests {
-    use super::super::apply_search_highlights;
-    use crate::search::SearchState;
-    use ratatui::style::{Color, Style};
-    use ratatui::text::{Line, Span, Text};
-
-    #[test]
-    fn apply_search_highlights_empty_matches_passthrough() {
-        // With no matches, the early-return branch copies every span through
-        // unchanged (content and style) rather than running highlight logic.
-        let input = Text::from(vec![
-            Line::from(vec![
-                Span::styled("hello ", Style::default().fg(Color::Green)),
-                Span::styled("world", Style::default().fg(Color::Red)),
-            ]),
-            Line::from(Span::styled("second", Style::default().fg(Color::Blue))),
-        ]);
-
-        let search = SearchState::default();
-        assert!(search.matches().is_empty(), "fixture must have no matches");
-
-        let out = apply_search_highlights(input, &search, 0, 24);
-
-        assert_eq!(out.lines.len(), 2);
-        // Line 0: two spans preserved verbatim.
-        assert_eq!(out.lines[0].spans.len(), 2);
-        assert_eq!(out.lines[0].spans[0].content.as_ref(), "hello ");
-        assert_eq!(out.lines[0].spans[0].style.fg, Some(Color::Green));
-        assert_eq!(out.lines[0].spans[1].content.as_ref(), "world");
-        assert_eq!(out.lines[0].spans[1].style.fg, Some(Color::Red));
-        // Line 1: single span preserved.
-        assert_eq!(out.lines[1].spans[0].content.as_ref(), "second");
-        assert_eq!(out.lines[1].spans[0].style.fg, Some(Color::Blue));
-    }
-}
-
-#[cfg(test)]
-mod apply_cursor_highlights_tests {
-    use super::super::apply_cursor_highlights;
-    use crate::results::cursor_state::CursorState;
-    use crate::theme;
-    use ratatui::style::Style;
-    use ratatui::text::{Line, Span, Text};
-
-    fn text_three_lines() -> Text<'static> {
-        Text::from(vec![
-            Line::from(Span::styled("line0", Style::default())),
-            Line::from(Span::styled("line1", Style::default())),
-            Line::from(Span::styled("line2", Style::default())),
-        ])
-    }
-
-    #[test]
-    fn apply_cursor_highlights_visual_and_hover_backgrounds() {
-        // Visual selection: enter visual at line 0, move down to line 1 so the
-        // range covers lines 0..=1. Both selected lines get visual_selection_bg.
-        let mut cursor = CursorState::new();
-        cursor.update_total_lines(3);
-        cursor.enter_visual_mode();
-        cursor.move_down(1);
-        assert!(cursor.is_visual_mode());
-        assert_eq!(cursor.selection_range(), (0, 1));
-
-        let out = apply_cursor_highlights(text_three_lines(), &cursor, 0);
-        assert_eq!(
-            out.lines[0].spans[0].style.bg,
-            Some(theme::results::visual_selection_bg()),
-            "line 0 is in the visual selection range"
-        );
-        assert_eq!(
-            out.lines[1].spans[0].style.bg,
-            Some(theme::results::visual_selection_bg()),
-            "line 1 is in the visual selection range"
-        );
-        // Line 2 is outside the selection and not the cursor/hover -> no bg.
-        assert_eq!(out.lines[2].spans[0].style.bg, None);
-
-        // Hover (normal mode): hovering a line that is not the cursor row paints
-        // hovered_line_bg. Cursor sits at line 0; hover line 2.
-        let mut cursor2 = CursorState::new();
-        cursor2.update_total_lines(3);
-        cursor2.set_hovered(Some(2));
-        assert!(!cursor2.is_visual_mode());
-
-        let out2 = apply_cursor_highlights(text_three_lines(), &cursor2, 0);
-        assert_eq!(
-            out2.lines[2].spans[0].style.bg,
-            Some(theme::results::hovered_line_bg()),
-            "the hovered non-cursor line must carry hovered_line_bg"
-        );
-        // The cursor line (0) takes cursor_line_bg, distinct from hover.
-        assert_eq!(
-            out2.lines[0].spans[0].style.bg,
-            Some(theme::results::cursor_line_bg()),
-        );
-        assert_ne!(
-            theme::results::hovered_line_bg(),
-            theme::results::cursor_line_bg(),
-            "hover and cursor backgrounds must differ for the assertion to bite"
-        );
-    }
-}
-
-#[cfg(test)]
-mod cursor_indicator_tests {
-    use super::super::render_cursor_indicator;
-    use crate::results::cursor_state::CursorState;
-    use ratatui::Terminal;
-    use ratatui::backend::TestBackend;
-    use ratatui::layout::Rect;
-
-    const INDICATOR: &str = "▌";
-
-    fn render_with(cursor: &CursorState, scroll_offset: u16, width: u16, height: u16) -> String {
-        let backend = TestBackend::new(width, height);
-        let mut terminal = Terminal::new(backend).unwrap();
-        terminal
-            .draw(|frame| {
-                let area = Rect::new(0, 0, width, height);
-                render_cursor_indicator(frame, area, cursor, scroll_offset, 0);
-            })
-            .unwrap();
-        terminal.backend().to_string()
-    }
-
-    #[test]
-    fn render_cursor_indicator_offscreen_early_returns() {
-        // Above the viewport: cursor_line (1) < scroll_offset (5) -> nothing drawn.
-        let mut above = CursorState::new();
-        above.update_total_lines(100);
-        above.move_to_line(1);
-        let out_above = render_with(&above, 5, 20, 12);
-        assert!(
-            !out_above.contains(INDICATOR),
-            "no indicator when cursor is above the scroll offset:\n{}",
-            out_above
-        );
+#[test]
+fn timed_out_query_is_badged_as_such() {
+    let mut app = crate::test_utils::test_helpers::test_app(r#"{"a": 1}"#);
+    app.query.as_mut().unwrap().result = Err("query timed out after 5s".to_string());
 
-        // Below the viewport: with height 12, viewport_height = 10. A cursor at
-        // relative_line >= 10 (here line 50, offset 0) sits past the viewport.
-        let mut below = CursorState::new();
-        below.update_total_lines(100);
-        below.move_to_line(50);
-        let out_below = render_with(&below, 0, 20, 12);
-        assert!(
-            !out_below.contains(INDICATOR),
-            "no indicator when cursor is below the viewport:\n{}",
-            out_below
-        );
+    let output = render_to_string(&mut app, 60, 12);
 
-        // In view: cursor at line 3, offset 0 -> indicator is painted.
-        let mut visible = CursorState::new();
-        visible.update_total_lines(100);
-        visible.move_to_line(3);
-        let out_visible = render_with(&visible, 0, 20, 12);
-        assert!(
-            out_visible.contains(INDICATOR),
-            "indicator must paint when the cursor is within the viewport:\n{}",
-            out_visible
-        );
-    }
+    assert!(output.contains("⏱ Timed Out"), "{output}");
+    assert!(!output.contains("Syntax Error"), "{output}");
 }
@@ -0,0 +1,138 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+use crate::results::fold_state::FoldState;
+use crate::search::{Match, SearchState};
+use crate::theme;
+
+/// Highlight the search matches on the shown lines, the current one
+/// brighter than the rest
+pub fn apply_highlights(
+    text: Text<'_>,
+    search_state: &SearchState,
+    folds: &FoldState,
+    scroll_offset: u16,
+    viewport_height: u16,
+) -> Text<'static> {
+    let matches = search_state.matches();
+    let current_match_index = search_state.current_index();
+
+    if matches.is_empty() {
+        return Text::from(
+            text.lines
+                .into_iter()
+                .map(|line| {
+                    Line::from(
+                        line.spans
+                            .into_iter()
+                            .map(|span| Span::styled(span.content.into_owned(), span.style))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    let _ = viewport_height;
+    let highlighted_lines: Vec<Line<'static>> = text
+        .lines
+        .into_iter()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            // Adjust line_idx by scroll_offset to get absolute line number
+            let row = line_idx as u32 + scroll_offset as u32;
+            let line_matches: Vec<(usize, &Match)> = match folds.line_at(row) {
+                Some(absolute_line) => search_state.matches_on_line(absolute_line).collect(),
+                None => Vec::new(),
+            };
+
+            if line_matches.is_empty() {
+                Line::from(
+                    line.spans
+                        .into_iter()
+                        .map(|span| Span::styled(span.content.into_owned(), span.style))
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                apply_highlights_to_line(line, &line_matches, current_match_index)
+            }
+        })
+        .collect();
+
+    Text::from(highlighted_lines)
+}
+
+fn apply_highlights_to_line(
+    line: Line<'_>,
+    matches: &[(usize, &Match)],
+    current_match_index: usize,
+) -> Line<'static> {
+    let mut char_styles = char_styles(&line);
+
+    for (match_idx, m) in matches {
+        let col_start = m.col as usize;
+        let col_end = col_start + m.len as usize;
+
+        let highlight_style = if *match_idx == current_match_index {
+            Style::default()
+                .fg(theme::results::current_match_fg())
+                .bg(theme::results::current_match_bg())
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(theme::results::match_highlight_fg())
+                .bg(theme::results::match_highlight_bg())
+        };
+
+        for i in col_start..col_end.min(char_styles.len()) {
+            char_styles[i].1 = highlight_style;
+        }
+    }
+
+    line_from_char_styles(char_styles)
+}
+
+/// Each char of `line` with the style of its span
+pub fn char_styles(line: &Line<'_>) -> Vec<(char, Style)> {
+    line.spans
+        .iter()
+        .flat_map(|span| span.content.chars().map(move |ch| (ch, span.style)))
+        .collect()
+}
+
+/// Rejoin runs of chars with the same style into spans
+pub fn line_from_char_styles(visible_chars: Vec<(char, Style)>) -> Line<'static> {
+    let mut result_spans: Vec<Span<'static>> = Vec::new();
+    let mut current_text = String::new();
+    let mut current_style: Option<Style> = None;
+
+    for (ch, style) in visible_chars {
+        match current_style {
+            Some(s) if s == style => {
+                current_text.push(ch);
+            }
+            _ => {
+                if !current_text.is_empty()
+                    && let Some(s) = current_style
+                {
+                    result_spans.push(Span::styled(current_text.clone(), s));
+                }
+                current_text = ch.to_string();
+                current_style = Some(style);
+            }
+        }
+    }
+    if !current_text.is_empty()
+        && let Some(s) = current_style
+    {
+        result_spans.push(Span::styled(current_text, s));
+    }
+
+    Line::from(result_spans)
+}
+
+#[cfg(test)]
+#[path = "search_highlight_render_tests.rs"]
+mod search_highlight_render_tests;
@@ -0,0 +1,36 @@
+//! Tests for results/search_highlight_render
+
+use super::apply_highlights;
+use crate::results::fold_state::FoldState;
+use crate::search::SearchState;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span, Text};
+
+#[test]
+fn apply_search_highlights_empty_matches_passthrough() {
+    // With no matches, the early-return branch copies every span through
+    // unchanged (content and style) rather than running highlight logic.
+    let input = Text::from(vec![
+        Line::from(vec![
+            Span::styled("hello ", Style::default().fg(Color::Green)),
+            Span::styled("world", Style::default().fg(Color::Red)),
+        ]),
+        Line::from(Span::styled("second", Style::default().fg(Color::Blue))),
+    ]);
+
+    let search = SearchState::default();
+    assert!(search.matches().is_empty(), "fixture must have no matches");
+
+    let out = apply_highlights(input, &search, &FoldState::new(), 0, 24);
+
+    assert_eq!(out.lines.len(), 2);
+    // Line 0: two spans preserved verbatim.
+    assert_eq!(out.lines[0].spans.len(), 2);
+    assert_eq!(out.lines[0].spans[0].content.as_ref(), "hello ");
+    assert_eq!(out.lines[0].spans[0].style.fg, Some(Color::Green));
+    assert_eq!(out.lines[0].spans[1].content.as_ref(), "world");
+    assert_eq!(out.lines[0].spans[1].style.fg, Some(Color::Red));
+    // Line 1: single span preserved.
+    assert_eq!(out.lines[1].spans[0].content.as_ref(), "second");
+    assert_eq!(out.lines[1].spans[0].style.fg, Some(Color::Blue));
+}
@@ -0,0 +1,179 @@
+//! Reassembled view of `--stream` events
+//!
+//! With `--stream`, jq reads the input as `[path, leaf]` events plus a
+//! `[path]` event closing each container, so a document too big to hold
+//! parsed can be taken apart one piece at a time. `S` in the results pane
+//! opens a popup that puts the result's events back together, as jq's
+//! `fromstream` would, and pretty-prints the values they make. Events
+//! picked out without their closing events, such as those `select` keeps,
+//! still show, nested under their full paths.
+
+use serde_json::{Map, Value};
+
+/// Values rebuilt from a run of stream events
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reassembled {
+    pub values: Vec<Value>,
+    pub events: usize,
+}
+
+/// Rebuild the values of the events in printed jq output. Returns None
+/// unless it is all `[path, leaf]` and `[path]` events.
+pub fn reassemble(text: &str) -> Option<Reassembled> {
+    let mut values = Vec::new();
+    let mut current: Option<Value> = None;
+    let mut events = 0;
+    for event in serde_json::Deserializer::from_str(text).into_iter::<Value>() {
+        let Value::Array(parts) = event.ok()? else {
+            return None;
+        };
+        match parts.as_slice() {
+            // A top-level scalar or empty container
+            [Value::Array(path), leaf] if path.is_empty() => values.push(leaf.clone()),
+            [Value::Array(path), leaf] => {
+                set_at(current.get_or_insert(Value::Null), path, leaf.clone())?;
+            }
+            // Closing a top-level value
+            [Value::Array(path)] if path.len() == 1 => values.extend(current.take()),
+            [Value::Array(path)] if !path.is_empty() => {}
+            _ => return None,
+        }
+        events += 1;
+    }
+    // Events whose closing event was filtered out
+    values.extend(current);
+    (events > 0).then_some(Reassembled { values, events })
+}
+
+/// Put `leaf` at `path` under `target`, making the objects and arrays on
+/// the way
+fn set_at(target: &mut Value, path: &[Value], leaf: Value) -> Option<()> {
+    let Some((step, rest)) = path.split_first() else {
+        *target = leaf;
+        return Some(());
+    };
+    let slot = match step {
+        Value::String(key) => {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+            target
+                .as_object_mut()?
+                .entry(key.clone())
+                .or_insert(Value::Null)
+        }
+        Value::Number(index) => {
+            let index = usize::try_from(index.as_u64()?).ok()?;
+            if !target.is_array() {
+                *target = Value::Array(Vec::new());
+            }
+            let items = target.as_array_mut()?;
+            if items.len() <= index {
+                items.resize(index + 1, Value::Null);
+            }
+            &mut items[index]
+        }
+        _ => return None,
+    };
+    set_at(slot, rest, leaf)
+}
+
+#[derive(Debug, Default)]
+pub struct StreamPopup {
+    visible: bool,
+    /// The values pretty-printed, one after another
+    lines: Vec<String>,
+    events: usize,
+    values: usize,
+    scroll: usize,
+    /// Rows shown at the last render, for paging and clamping
+    viewport: usize,
+}
+
+impl StreamPopup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open on the values the events in `text` make. Returns false,
+    /// staying closed, when it is not stream events.
+    pub fn open(&mut self, text: &str) -> bool {
+        let Some(reassembled) = reassemble(text) else {
+            return false;
+        };
+        self.lines = reassembled
+            .values
+            .iter()
+            .flat_map(|value| {
+                serde_json::to_string_pretty(value)
+                    .unwrap_or_default()
+                    .lines()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        self.events = reassembled.events;
+        self.values = reassembled.values.len();
+        self.scroll = 0;
+        self.visible = true;
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.lines.clear();
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn events(&self) -> usize {
+        self.events
+    }
+
+    pub fn values(&self) -> usize {
+        self.values
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    pub fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    pub fn set_viewport(&mut self, rows: usize) {
+        self.viewport = rows;
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.viewport)
+    }
+
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = (self.scroll + rows).min(self.max_scroll());
+    }
+
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+}
+
+#[cfg(test)]
+#[path = "stream_view_tests.rs"]
+mod stream_view_tests;
@@ -0,0 +1,42 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+
+/// `S` — put the result's stream events back together
+pub fn open(app: &mut App) {
+    let source = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone());
+    let opened = source.is_some_and(|text| app.results_stream.open(&text));
+    if !opened {
+        app.notification
+            .show("Nothing to reassemble · needs [path, value] events, as from --stream");
+    }
+}
+
+/// Keys while the reassembled view is open; it takes them all
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    let popup = &mut app.results_stream;
+    let page = popup.viewport().max(1);
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('S') => popup.close(),
+        KeyCode::Down | KeyCode::Char('j') => popup.scroll_down(1),
+        KeyCode::Up | KeyCode::Char('k') => popup.scroll_up(1),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            popup.scroll_down(page / 2)
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            popup.scroll_up(page / 2)
+        }
+        KeyCode::PageDown => popup.scroll_down(page),
+        KeyCode::PageUp => popup.scroll_up(page),
+        KeyCode::Home | KeyCode::Char('g') => popup.scroll_to_top(),
+        KeyCode::End | KeyCode::Char('G') => popup.scroll_to_bottom(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "stream_view_events_tests.rs"]
+mod stream_view_events_tests;
@@ -0,0 +1,53 @@
+//! Tests for results/stream_view_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{key, test_app};
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn app_showing(result: &str) -> App {
+    let mut app = test_app(result);
+    let arc = Arc::new(result.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(arc);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+#[test]
+fn test_s_opens_the_reassembled_view() {
+    let mut app = app_showing(r#"[["a"],1] [["a"]]"#);
+
+    app.handle_key_event(key(KeyCode::Char('S')));
+
+    assert!(app.results_stream.is_visible());
+}
+
+#[test]
+fn test_s_on_a_result_without_events_explains_why() {
+    let mut app = app_showing(r#"{"a": 1}"#);
+
+    app.handle_key_event(key(KeyCode::Char('S')));
+
+    assert!(!app.results_stream.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing to reassemble · needs [path, value] events, as from --stream")
+    );
+}
+
+#[test]
+fn test_popup_takes_keys_until_closed() {
+    let mut app = app_showing(r#"[[0],1] [[1],2] [[1]]"#);
+    app.handle_key_event(key(KeyCode::Char('S')));
+    app.results_stream.set_viewport(1);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    assert_eq!(app.results_stream.scroll(), 1);
+    assert_eq!(app.results_scroll.offset, 0);
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.results_stream.is_visible());
+    assert_eq!(app.focus, Focus::ResultsPane);
+}
@@ -0,0 +1,70 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::Line,
+    widgets::{Clear, Paragraph},
+};
+
+use super::stream_view::StreamPopup;
+use crate::syntax_highlight::JqHighlighter;
+use crate::widgets::popup::{centered_rect, popup_block};
+use crate::widgets::scrollbar;
+
+const POPUP_WIDTH: u16 = 80;
+const MAX_HEIGHT: u16 = 30;
+
+/// Render the reassembled values centered over `area`
+pub fn render_popup(popup: &mut StreamPopup, frame: &mut Frame, area: Rect) {
+    let height = (popup.lines().len() as u16)
+        .saturating_add(2)
+        .min(MAX_HEIGHT)
+        .min(area.height);
+    let popup_area = centered_rect(area, POPUP_WIDTH.min(area.width.saturating_sub(4)), height);
+    if popup_area.width < 20 || popup_area.height < 3 {
+        return;
+    }
+    frame.render_widget(Clear, popup_area);
+
+    let block = popup_block(
+        title(popup),
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+    );
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    popup.set_viewport(inner.height as usize);
+    let lines: Vec<Line> = popup
+        .lines()
+        .iter()
+        .skip(popup.scroll())
+        .take(inner.height as usize)
+        .map(|line| Line::from(JqHighlighter::highlight(line)))
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+    scrollbar::render_vertical_scrollbar(
+        frame,
+        popup_area,
+        popup.lines().len(),
+        inner.height as usize,
+        popup.scroll(),
+    );
+}
+
+fn title(popup: &StreamPopup) -> String {
+    let plural = |n: usize, word: &str| {
+        if n == 1 {
+            format!("{} {}", n, word)
+        } else {
+            format!("{} {}s", n, word)
+        }
+    };
+    format!(
+        " Reassembled · {} → {} ",
+        plural(popup.events(), "event"),
+        plural(popup.values(), "value")
+    )
+}
+
+#[cfg(test)]
+#[path = "stream_view_render_tests.rs"]
+mod stream_view_render_tests;
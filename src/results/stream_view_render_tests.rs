@@ -0,0 +1,34 @@
+//! Tests for results/stream_view_render
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+
+use super::*;
+
+fn render_to_string(popup: &mut StreamPopup, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let area = Rect::new(0, 0, width, height);
+    terminal.draw(|f| render_popup(popup, f, area)).unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_reassembled_values() {
+    let mut popup = StreamPopup::new();
+    popup.open(r#"[["a",0],1] [["a",0]] [["a"]] [["b"],"x"] [["b"]]"#);
+
+    assert_snapshot!(render_to_string(&mut popup, 60, 10));
+}
+
+#[test]
+fn test_render_records_the_viewport() {
+    let mut popup = StreamPopup::new();
+    popup.open(r#"[[0],1] [[1],2] [[2],3] [[3],4] [[3]]"#);
+
+    render_to_string(&mut popup, 60, 6);
+
+    assert_eq!(popup.viewport(), 4);
+}
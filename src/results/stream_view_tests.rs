@@ -0,0 +1,81 @@
+//! Tests for results/stream_view
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn test_reassembles_a_streamed_document() {
+    // jq --stream . <<< '{"a": [1, {"b": 2}], "c": "x"}'
+    let events = r#"[["a",0],1]
+[["a",1,"b"],2]
+[["a",1,"b"]]
+[["a",1]]
+[["c"],"x"]
+[["c"]]"#;
+
+    let reassembled = reassemble(events).unwrap();
+
+    assert_eq!(reassembled.values, [json!({"a": [1, {"b": 2}], "c": "x"})]);
+    assert_eq!(reassembled.events, 6);
+}
+
+#[test]
+fn test_reassembles_each_top_level_value() {
+    let events = r#"[[0],1]
+[[0]]
+[["k"],true]
+[["k"]]
+[[],3]"#;
+
+    let reassembled = reassemble(events).unwrap();
+
+    assert_eq!(
+        reassembled.values,
+        [json!([1]), json!({"k": true}), json!(3)]
+    );
+}
+
+#[test]
+fn test_events_without_closing_events_nest_under_their_paths() {
+    // Leaves only, as `select(length == 2)` keeps
+    let events = r#"[["users",0,"name"],"ada"]
+[["users",2,"name"],"bob"]"#;
+
+    let reassembled = reassemble(events).unwrap();
+
+    assert_eq!(
+        reassembled.values,
+        [json!({"users": [{"name": "ada"}, null, {"name": "bob"}]})]
+    );
+}
+
+#[test]
+fn test_anything_but_events_is_not_reassembled() {
+    assert!(reassemble(r#"{"a": 1}"#).is_none());
+    assert!(reassemble("[1, 2]").is_none());
+    assert!(reassemble(r#"[["a"], 1, 2]"#).is_none());
+    assert!(reassemble("").is_none());
+}
+
+#[test]
+fn test_open_pretty_prints_the_values() {
+    let mut popup = StreamPopup::new();
+
+    assert!(popup.open(r#"[["a"],1] [["a"]]"#));
+
+    assert_eq!(popup.lines(), ["{", r#"  "a": 1"#, "}"]);
+    assert_eq!((popup.events(), popup.values()), (2, 1));
+}
+
+#[test]
+fn test_scrolling_stops_at_the_last_page() {
+    let mut popup = StreamPopup::new();
+    popup.open(r#"[[0],1] [[1],2] [[2],3] [[2]]"#);
+    popup.set_viewport(3);
+
+    popup.scroll_down(10);
+    assert_eq!(popup.scroll(), 2);
+
+    popup.scroll_to_top();
+    assert_eq!(popup.scroll(), 0);
+}
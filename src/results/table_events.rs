@@ -0,0 +1,64 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::results::results_events::{CURSOR_KEYS, scroll_without_cursor};
+
+/// `T` — show the result as a table, or the JSON again
+pub fn toggle(app: &mut App) {
+    if app.results_table.is_active() {
+        app.results_table.disable();
+        app.results_scroll.jump_to_top();
+        app.ensure_results_cursor_visible();
+        app.notification.show("Table view off");
+        return;
+    }
+    let source = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone());
+    if app.results_table.enable(source.as_ref()) {
+        app.results_scroll.jump_to_top();
+        app.results_scroll.jump_to_left();
+        app.notification.show("Table view · 1-9 sort by column");
+    } else {
+        app.notification
+            .show("Table view needs an array of objects with mostly scalar fields");
+    }
+}
+
+/// Keys in the results pane while the table is shown. Like the line
+/// filter's view it has no cursor: movement keys scroll, digits sort by a
+/// column and keys that act on the cursor's line are declined. Returns
+/// false for keys the pane handles as usual, such as horizontal scrolling
+/// and `y`, which copies the table as tab-separated values.
+pub fn handle_table_view_key(app: &mut App, key: KeyEvent) -> bool {
+    // The line filter's view takes over when both are on
+    if !app.results_table.is_active() || app.results_filter.is_active() {
+        return false;
+    }
+    if scroll_without_cursor(app, key) {
+        return true;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('T') => toggle(app),
+        KeyCode::Char(digit @ '1'..='9') => {
+            let column = digit as usize - '1' as usize;
+            if !app.results_table.cycle_sort(column) {
+                app.notification.show(&format!("No column {}", digit));
+            }
+        }
+        KeyCode::Char('$') => {
+            app.results_scroll.h_offset = app.results_scroll.max_h_offset;
+        }
+        KeyCode::Char(c) if CURSOR_KEYS.contains(c) || "|=".contains(c) => {
+            app.notification.show("Table view is on · T shows the JSON");
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "table_events_tests.rs"]
+mod table_events_tests;
@@ -0,0 +1,113 @@
+//! Tests for results/table_events
+
+use crate::app::{App, Focus};
+use crate::test_utils::test_helpers::{key, test_app};
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+const PEOPLE: &str = r#"[{"name": "bo", "age": 31}, {"name": "al", "age": 4}]"#;
+
+fn app_showing(result: &str) -> App {
+    let mut app = test_app(result);
+    let arc = Arc::new(result.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(arc);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+#[test]
+fn test_t_shows_the_table_and_again_shows_the_json() {
+    let mut app = app_showing(PEOPLE);
+
+    app.handle_key_event(key(KeyCode::Char('T')));
+    assert!(app.results_table.is_active());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Table view · 1-9 sort by column")
+    );
+
+    app.handle_key_event(key(KeyCode::Char('T')));
+    assert!(!app.results_table.is_active());
+    assert_eq!(app.notification.current_message(), Some("Table view off"));
+}
+
+#[test]
+fn test_t_on_a_result_that_is_not_a_table_explains_why() {
+    let mut app = app_showing("[1, 2, 3]");
+
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    assert!(!app.results_table.is_active());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Table view needs an array of objects with mostly scalar fields")
+    );
+}
+
+#[test]
+fn test_esc_leaves_the_table() {
+    let mut app = app_showing(PEOPLE);
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.results_table.is_active());
+}
+
+#[test]
+fn test_digits_sort_by_column() {
+    let mut app = app_showing(PEOPLE);
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    app.handle_key_event(key(KeyCode::Char('2')));
+
+    assert_eq!(app.results_table.sort_key().map(|k| k.column), Some(1));
+    assert_eq!(app.results_table.order(), &[1, 0]);
+}
+
+#[test]
+fn test_digit_past_the_last_column_is_reported() {
+    let mut app = app_showing(PEOPLE);
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    app.handle_key_event(key(KeyCode::Char('5')));
+
+    assert_eq!(app.notification.current_message(), Some("No column 5"));
+    assert!(app.results_table.sort_key().is_none());
+}
+
+#[test]
+fn test_cursor_keys_are_declined_in_the_table() {
+    let mut app = app_showing(PEOPLE);
+    app.handle_key_event(key(KeyCode::Char('T')));
+
+    app.handle_key_event(key(KeyCode::Char('p')));
+
+    assert!(app.results_pins.is_empty());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Table view is on · T shows the JSON")
+    );
+}
+
+#[test]
+fn test_dollar_scrolls_to_the_right_edge() {
+    let mut app = app_showing(PEOPLE);
+    app.handle_key_event(key(KeyCode::Char('T')));
+    app.results_scroll.max_h_offset = 12;
+
+    app.handle_key_event(key(KeyCode::Char('$')));
+
+    assert_eq!(app.results_scroll.h_offset, 12);
+}
+
+#[test]
+fn test_digits_outside_the_table_are_not_taken() {
+    let mut app = app_showing(PEOPLE);
+
+    app.handle_key_event(key(KeyCode::Char('2')));
+
+    assert!(app.results_table.sort_key().is_none());
+}
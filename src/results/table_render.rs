@@ -0,0 +1,91 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::results::table_view::{COLUMN_GAP, Cell, TableView};
+use crate::str_utils::truncate_to_width;
+use crate::theme;
+
+/// The header row followed by the table rows from `offset`, `height` lines
+/// in all. Horizontal scrolling is left to the paragraph showing them.
+pub fn viewport(view: &TableView, offset: usize, height: usize) -> Text<'static> {
+    let Some(table) = view.table() else {
+        return Text::default();
+    };
+    let sort = view.sort_key();
+    let muted = Style::default().fg(theme::results::fold_marker());
+
+    let header_style = Style::default()
+        .fg(theme::results::table_header())
+        .add_modifier(Modifier::BOLD);
+    let mut header = Vec::new();
+    for (index, column) in table.columns.iter().enumerate() {
+        let width = table.widths[index];
+        let marker = match sort {
+            Some(key) if key.column == index && key.descending => " ▼",
+            Some(key) if key.column == index => " ▲",
+            _ => "",
+        };
+        let label = format!(
+            "{}{}",
+            truncate_to_width(column, width - marker.width()),
+            marker
+        );
+        header.push(Span::styled(
+            pad(&label, width, table.numeric[index]),
+            header_style,
+        ));
+        header.push(Span::raw(" ".repeat(COLUMN_GAP)));
+    }
+    header.pop();
+
+    let mut lines = vec![Line::from(header)];
+    for &row in view
+        .order()
+        .iter()
+        .skip(offset)
+        .take(height.saturating_sub(1))
+    {
+        let mut spans = Vec::new();
+        for (index, cell) in table.rows[row].iter().enumerate() {
+            let width = table.widths[index];
+            let text = pad(
+                &truncate_to_width(cell.text(), width),
+                width,
+                table.numeric[index],
+            );
+            let style = match cell {
+                Cell::Missing | Cell::Null | Cell::Nested(_) => muted,
+                _ => Style::default(),
+            };
+            spans.push(Span::styled(text, style));
+            spans.push(Span::raw(" ".repeat(COLUMN_GAP)));
+        }
+        spans.pop();
+        lines.push(Line::from(spans));
+    }
+    Text::from(lines)
+}
+
+/// Pad `text` to `width` columns, on the left for numeric columns
+fn pad(text: &str, width: usize, right_align: bool) -> String {
+    let fill = " ".repeat(width.saturating_sub(text.width()));
+    if right_align {
+        format!("{}{}", fill, text)
+    } else {
+        format!("{}{}", text, fill)
+    }
+}
+
+/// Keys for the bottom border while the table shows
+pub fn hints() -> Line<'static> {
+    let hints: Vec<(&'static str, &'static str)> =
+        vec![("1-9", "Sort"), ("y", "Copy TSV"), ("T", "JSON")];
+    theme::border_hints::build_hints(&hints, theme::results::hint_key())
+}
+
+#[cfg(test)]
+#[path = "table_render_tests.rs"]
+mod table_render_tests;
@@ -0,0 +1,61 @@
+//! Tests for results/table_render
+
+use crate::app::App;
+use crate::test_utils::test_helpers::{key, test_app};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    let backend = TestBackend::new(width, height);
+    Terminal::new(backend).unwrap()
+}
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+const CONTENT: &str = "[\n  {\"name\": \"pear\", \"count\": 3},\n  {\"name\": \"fig\", \"count\": 12, \"ripe\": true},\n  {\"name\": \"apple\", \"count\": null}\n]";
+
+fn app_with_table(sort_keys: &str) -> App {
+    use ratatui::text::Text;
+
+    let mut app = test_app(r#"[{"name": "pear"}]"#);
+    let arc = Arc::new(CONTENT.to_string());
+    let q = app.query.as_mut().unwrap();
+    q.last_successful_result = Some(Arc::clone(&arc));
+    q.last_successful_result_unformatted = Some(Arc::clone(&arc));
+    q.last_successful_result_rendered = Some(Text::raw(CONTENT.to_string()));
+    q.result = Ok(CONTENT.to_string());
+    q.cached_line_count = CONTENT.lines().count() as u32;
+    q.is_empty_result = false;
+    app.focus_results_pane();
+    app.handle_key_event(key(KeyCode::Char('T')));
+    for c in sort_keys.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    app.notification.dismiss();
+    app
+}
+
+#[test]
+fn snapshot_results_as_table() {
+    let mut app = app_with_table("22");
+    let output = render_to_string(&mut app, 70, 10);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn table_marks_the_sorted_column() {
+    let mut app = app_with_table("1");
+    let output = render_to_string(&mut app, 70, 10);
+    assert!(output.contains("name ▲"), "{output}");
+    assert!(output.contains("· table"), "{output}");
+    let apple = output.find("apple").unwrap();
+    let pear = output.find("pear").unwrap();
+    assert!(apple < pear, "{output}");
+}
@@ -0,0 +1,323 @@
+//! Table rendering of results that are lists of flat objects
+//!
+//! `T` in the results pane shows an array of objects, or a stream of
+//! objects such as the output of `.[]`, as aligned columns under a header
+//! row, much like `column -t` but reading the JSON. Columns appear in the
+//! order their keys are first seen; nested values are printed as compact
+//! JSON. Digits sort by a column, and the view scrolls sideways like the
+//! normal one.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+/// Widest a column gets; longer cells are cut with `…`
+pub const MAX_COLUMN_WIDTH: usize = 40;
+
+/// Cells between columns
+pub const COLUMN_GAP: usize = 2;
+
+/// Room kept after each header for the ` ▲` or ` ▼` of a sorted column
+pub const SORT_MARKER_WIDTH: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Missing,
+    Null,
+    Bool(bool),
+    Number(f64, String),
+    Text(String),
+    /// An object or array, as compact JSON
+    Nested(String),
+}
+
+impl Cell {
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Null => Cell::Null,
+            Value::Bool(b) => Cell::Bool(*b),
+            Value::Number(n) => Cell::Number(n.as_f64().unwrap_or(f64::NAN), n.to_string()),
+            // Keep each row on one line
+            Value::String(s) => Cell::Text(
+                s.replace('\n', "\\n")
+                    .replace('\r', "\\r")
+                    .replace('\t', "\\t"),
+            ),
+            Value::Array(_) | Value::Object(_) => Cell::Nested(value.to_string()),
+        }
+    }
+
+    /// Missing and null cells, which sort last whichever way the column is
+    /// sorted
+    fn is_blank(&self) -> bool {
+        matches!(self, Cell::Missing | Cell::Null)
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            Cell::Missing => "",
+            Cell::Null => "null",
+            Cell::Bool(true) => "true",
+            Cell::Bool(false) => "false",
+            Cell::Number(_, text) | Cell::Text(text) | Cell::Nested(text) => text,
+        }
+    }
+
+    fn compare(&self, other: &Cell) -> Ordering {
+        match (self, other) {
+            (Cell::Number(a, _), Cell::Number(b, _)) => a.total_cmp(b),
+            (a, b) if a.is_blank() || b.is_blank() => a.is_blank().cmp(&b.is_blank()),
+            _ => self.text().cmp(other.text()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Cell>>,
+    /// Display width of each column, header and sort marker included
+    pub widths: Vec<usize>,
+    /// Columns whose cells are all numbers or blank, shown right-aligned
+    pub numeric: Vec<bool>,
+}
+
+impl Table {
+    /// Build a table from printed jq output: one array of objects, or
+    /// several objects one after another. Returns None unless every value
+    /// is an object and most of their fields are scalars.
+    pub fn from_output(text: &str) -> Option<Self> {
+        let mut values = serde_json::Deserializer::from_str(text).into_iter::<Value>();
+        let first = values.next()?.ok()?;
+        let objects: Vec<Value> = match first {
+            Value::Array(items) => {
+                if values.next().is_some() {
+                    return None;
+                }
+                items
+            }
+            Value::Object(_) => {
+                let mut objects = vec![first];
+                for value in values {
+                    objects.push(value.ok()?);
+                }
+                objects
+            }
+            _ => return None,
+        };
+        Self::from_objects(&objects)
+    }
+
+    fn from_objects(objects: &[Value]) -> Option<Self> {
+        if objects.is_empty() {
+            return None;
+        }
+        let mut columns: Vec<String> = Vec::new();
+        let (mut scalars, mut fields) = (0usize, 0usize);
+        for object in objects {
+            let Value::Object(map) = object else {
+                return None;
+            };
+            for (key, value) in map {
+                fields += 1;
+                if !value.is_object() && !value.is_array() {
+                    scalars += 1;
+                }
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+        if columns.is_empty() || scalars * 2 < fields {
+            return None;
+        }
+
+        let rows: Vec<Vec<Cell>> = objects
+            .iter()
+            .map(|object| {
+                columns
+                    .iter()
+                    .map(|column| object.get(column).map_or(Cell::Missing, Cell::from_value))
+                    .collect()
+            })
+            .collect();
+        let widths = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                rows.iter()
+                    .map(|row| row[index].text().width())
+                    .chain([column.width() + SORT_MARKER_WIDTH])
+                    .max()
+                    .unwrap_or(0)
+                    .min(MAX_COLUMN_WIDTH)
+            })
+            .collect();
+        let numeric = (0..columns.len())
+            .map(|index| {
+                rows.iter()
+                    .all(|row| matches!(row[index], Cell::Number(..)) || row[index].is_blank())
+            })
+            .collect();
+        Some(Self {
+            columns,
+            rows,
+            widths,
+            numeric,
+        })
+    }
+
+    /// Width of a full row
+    pub fn width(&self) -> usize {
+        self.widths.iter().sum::<usize>() + COLUMN_GAP * self.widths.len().saturating_sub(1)
+    }
+
+    /// Header and rows as tab-separated values, rows in `order`
+    pub fn to_tsv(&self, order: &[usize]) -> String {
+        let mut lines = vec![self.columns.join("\t")];
+        lines.extend(order.iter().map(|&row| {
+            self.rows[row]
+                .iter()
+                .map(Cell::text)
+                .collect::<Vec<_>>()
+                .join("\t")
+        }));
+        lines.join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub column: usize,
+    pub descending: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct TableView {
+    enabled: bool,
+    /// Result text the table was built from
+    source: Option<Arc<String>>,
+    table: Option<Table>,
+    /// Column sorted by, kept by name so it survives query edits
+    sort: Option<(String, bool)>,
+    /// Row indices in display order
+    order: Vec<usize>,
+}
+
+impl TableView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the results pane shows the table: it is switched on and the
+    /// current result can be shown as one
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.table.is_some()
+    }
+
+    /// Show `source` as a table. Returns false, leaving the table off,
+    /// when it cannot be shown as one.
+    ///
+    /// Once on, the table stays on across query edits; results that are
+    /// not tables show as usual meanwhile.
+    pub fn enable(&mut self, source: Option<&Arc<String>>) -> bool {
+        self.enabled = true;
+        self.source = None;
+        self.table = None;
+        self.sync(source);
+        self.enabled = self.is_active();
+        self.enabled
+    }
+
+    pub fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    /// Rebuild the table when `source` is a different result
+    pub fn sync(&mut self, source: Option<&Arc<String>>) {
+        if !self.enabled {
+            return;
+        }
+        let unchanged = match (&self.source, source) {
+            (Some(current), Some(new)) => Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
+            return;
+        }
+        self.source = source.cloned();
+        self.table = source.and_then(|text| Table::from_output(text));
+        self.resort();
+    }
+
+    pub fn table(&self) -> Option<&Table> {
+        self.table.as_ref()
+    }
+
+    /// The table when the results pane shows it
+    pub fn shown(&self) -> Option<&Table> {
+        self.table.as_ref().filter(|_| self.enabled)
+    }
+
+    /// Row indices of the table in display order
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    pub fn sort_key(&self) -> Option<SortKey> {
+        let (name, descending) = self.sort.as_ref()?;
+        let column = self
+            .table
+            .as_ref()?
+            .columns
+            .iter()
+            .position(|c| c == name)?;
+        Some(SortKey {
+            column,
+            descending: *descending,
+        })
+    }
+
+    /// Sort by `column`: ascending, then descending on the next press, then
+    /// back to the result's order. Returns false when there is no such
+    /// column.
+    pub fn cycle_sort(&mut self, column: usize) -> bool {
+        let Some(name) = self.table.as_ref().and_then(|t| t.columns.get(column)) else {
+            return false;
+        };
+        self.sort = match &self.sort {
+            Some((current, false)) if current == name => Some((name.clone(), true)),
+            Some((current, true)) if current == name => None,
+            _ => Some((name.clone(), false)),
+        };
+        self.resort();
+        true
+    }
+
+    fn resort(&mut self) {
+        let sort = self.sort_key();
+        let Some(table) = &self.table else {
+            self.order.clear();
+            return;
+        };
+        let mut order: Vec<usize> = (0..table.rows.len()).collect();
+        if let Some(SortKey { column, descending }) = sort {
+            order.sort_by(|&a, &b| {
+                let (a, b) = (&table.rows[a][column], &table.rows[b][column]);
+                match (a, b) {
+                    _ if a.is_blank() || b.is_blank() => a.compare(b),
+                    _ if descending => b.compare(a),
+                    _ => a.compare(b),
+                }
+            });
+        }
+        self.order = order;
+    }
+}
+
+#[cfg(test)]
+#[path = "table_view_tests.rs"]
+mod table_view_tests;
@@ -0,0 +1,203 @@
+//! Tests for results/table_view
+
+use super::*;
+
+const PEOPLE: &str = r#"[
+  {"name": "bo", "age": 31},
+  {"name": "al", "age": 4, "city": "Oslo"},
+  {"name": "cy", "age": 12}
+]"#;
+
+fn view_of(text: &str) -> TableView {
+    let mut view = TableView::new();
+    view.enable(Some(&Arc::new(text.to_string())));
+    view
+}
+
+fn column_in_order(view: &TableView, column: usize) -> Vec<String> {
+    let table = view.table().unwrap();
+    view.order()
+        .iter()
+        .map(|&row| table.rows[row][column].text().to_string())
+        .collect()
+}
+
+#[test]
+fn test_array_of_objects_becomes_a_table() {
+    let table = Table::from_output(PEOPLE).unwrap();
+
+    assert_eq!(table.columns, vec!["name", "age", "city"]);
+    assert_eq!(table.rows.len(), 3);
+    assert_eq!(table.rows[0][2], Cell::Missing);
+    assert_eq!(table.rows[1][2], Cell::Text("Oslo".to_string()));
+}
+
+#[test]
+fn test_stream_of_objects_becomes_a_table() {
+    let table = Table::from_output("{\"a\": 1}\n{\"a\": 2, \"b\": true}\n").unwrap();
+
+    assert_eq!(table.columns, vec!["a", "b"]);
+    assert_eq!(table.rows[1][1], Cell::Bool(true));
+}
+
+#[test]
+fn test_values_that_are_not_all_objects_are_not_a_table() {
+    assert!(Table::from_output("[1, 2, 3]").is_none());
+    assert!(Table::from_output(r#"[{"a": 1}, 2]"#).is_none());
+    assert!(Table::from_output("{\"a\": 1}\n3\n").is_none());
+    assert!(Table::from_output("[]").is_none());
+    assert!(Table::from_output("[{}]").is_none());
+    assert!(Table::from_output("\"text\"").is_none());
+}
+
+#[test]
+fn test_mostly_nested_objects_are_not_a_table() {
+    let nested = r#"[{"a": {"x": 1}, "b": [1], "c": 1}]"#;
+    assert!(Table::from_output(nested).is_none());
+
+    let table = Table::from_output(r#"[{"a": {"x": 1}, "c": 1}]"#).unwrap();
+    assert_eq!(table.rows[0][0], Cell::Nested(r#"{"x":1}"#.to_string()));
+}
+
+#[test]
+fn test_widths_fit_the_header_and_cells_and_are_capped() {
+    let long = "x".repeat(100);
+    let text = format!(r#"[{{"id": 1, "note": "{}"}}]"#, long);
+    let table = Table::from_output(&text).unwrap();
+
+    assert_eq!(table.widths, vec![2 + SORT_MARKER_WIDTH, MAX_COLUMN_WIDTH]);
+    assert_eq!(table.width(), 4 + COLUMN_GAP + MAX_COLUMN_WIDTH);
+}
+
+#[test]
+fn test_columns_of_numbers_are_numeric() {
+    let table = Table::from_output(PEOPLE).unwrap();
+
+    assert_eq!(table.numeric, vec![false, true, false]);
+}
+
+#[test]
+fn test_strings_keep_to_one_line() {
+    let table = Table::from_output(r#"[{"a": "one\ntwo\tthree"}]"#).unwrap();
+
+    assert_eq!(table.rows[0][0].text(), "one\\ntwo\\tthree");
+}
+
+#[test]
+fn test_enable_stays_off_for_results_that_are_not_tables() {
+    let mut view = TableView::new();
+
+    assert!(!view.enable(Some(&Arc::new("[1, 2]".to_string()))));
+    assert!(!view.is_active());
+    assert!(view.enable(Some(&Arc::new(PEOPLE.to_string()))));
+    assert!(view.is_active());
+}
+
+#[test]
+fn test_sort_cycles_ascending_descending_and_off() {
+    let mut view = view_of(PEOPLE);
+
+    assert!(view.cycle_sort(1));
+    assert_eq!(column_in_order(&view, 1), vec!["4", "12", "31"]);
+    assert!(view.cycle_sort(1));
+    assert_eq!(column_in_order(&view, 1), vec!["31", "12", "4"]);
+    assert!(view.cycle_sort(1));
+    assert_eq!(column_in_order(&view, 1), vec!["31", "4", "12"]);
+    assert_eq!(view.sort_key(), None);
+}
+
+#[test]
+fn test_sort_on_another_column_starts_ascending() {
+    let mut view = view_of(PEOPLE);
+    view.cycle_sort(1);
+    view.cycle_sort(1);
+
+    view.cycle_sort(0);
+
+    assert_eq!(
+        view.sort_key(),
+        Some(SortKey {
+            column: 0,
+            descending: false
+        })
+    );
+    assert_eq!(column_in_order(&view, 0), vec!["al", "bo", "cy"]);
+}
+
+#[test]
+fn test_missing_and_null_cells_sort_last_either_way() {
+    let mut view = view_of(PEOPLE);
+
+    view.cycle_sort(2);
+    assert_eq!(column_in_order(&view, 2), vec!["Oslo", "", ""]);
+    view.cycle_sort(2);
+    assert_eq!(column_in_order(&view, 2), vec!["Oslo", "", ""]);
+}
+
+#[test]
+fn test_sort_past_the_last_column_is_refused() {
+    let mut view = view_of(PEOPLE);
+
+    assert!(!view.cycle_sort(3));
+    assert_eq!(view.sort_key(), None);
+}
+
+#[test]
+fn test_sort_is_kept_by_column_name_across_results() {
+    let mut view = view_of(PEOPLE);
+    view.cycle_sort(1);
+
+    view.sync(Some(&Arc::new(
+        r#"[{"age": 9, "name": "x"}, {"age": 2, "name": "y"}]"#.to_string(),
+    )));
+
+    assert_eq!(
+        view.sort_key(),
+        Some(SortKey {
+            column: 0,
+            descending: false
+        })
+    );
+    assert_eq!(column_in_order(&view, 0), vec!["2", "9"]);
+}
+
+#[test]
+fn test_view_stays_on_while_a_result_is_not_a_table() {
+    let mut view = view_of(PEOPLE);
+
+    view.sync(Some(&Arc::new("42".to_string())));
+    assert!(!view.is_active());
+
+    view.sync(Some(&Arc::new(PEOPLE.to_string())));
+    assert!(view.is_active());
+}
+
+#[test]
+fn test_disabled_view_shows_no_table() {
+    let mut view = view_of(PEOPLE);
+
+    view.disable();
+
+    assert!(!view.is_active());
+    assert!(view.shown().is_none());
+}
+
+#[test]
+fn test_tsv_follows_the_display_order() {
+    let mut view = view_of(PEOPLE);
+    view.cycle_sort(0);
+
+    let tsv = view.table().unwrap().to_tsv(view.order());
+
+    assert_eq!(tsv, "name\tage\tcity\nal\t4\tOslo\nbo\t31\t\ncy\t12\t");
+}
+
+#[test]
+fn test_nulls_in_a_number_column_sort_last() {
+    let mut view = view_of(r#"[{"n": null}, {"n": 3}, {"n": 12}]"#);
+
+    assert!(view.table().unwrap().numeric[0]);
+    view.cycle_sort(0);
+    view.cycle_sort(0);
+    assert_eq!(column_in_order(&view, 0), vec!["12", "3", "null"]);
+}
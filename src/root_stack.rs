@@ -0,0 +1,80 @@
+//! Documents left behind by `R` (re-root) in the results pane.
+//!
+//! Re-rooting makes the current result the new input and clears the
+//! query, so a huge document can be narrowed one step at a time instead
+//! of with one long pipeline. Each step pushes the document and query it
+//! replaced; popping restores them, so the user can walk back out, one
+//! level with `Backspace` or straight to any level from the breadcrumb bar.
+
+use std::path::PathBuf;
+
+use crate::input::{InputFilesState, InputText};
+use crate::query_undo::QueryUndoRing;
+
+/// One replaced document and the state that went with it
+#[derive(Debug)]
+pub struct RootLevel {
+    pub input: InputText,
+    /// The query whose result became the next root
+    pub query: String,
+    pub input_paths: Vec<PathBuf>,
+    pub input_files: Option<InputFilesState>,
+    /// `<` snapshots taken against this document
+    pub query_undo: QueryUndoRing,
+}
+
+#[derive(Debug, Default)]
+pub struct RootStack {
+    levels: Vec<RootLevel>,
+    /// Whether `B` was pressed and the level's digit is awaited
+    pending_jump: bool,
+}
+
+impl RootStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// How many re-roots deep the live document is; the original document
+    /// is level 0
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn push(&mut self, level: RootLevel) {
+        self.levels.push(level);
+    }
+
+    /// Drop every level from `depth` up and return the one at `depth`,
+    /// whose document and query come back; None when the live document
+    /// is already at or above `depth`
+    pub fn pop_to(&mut self, depth: usize) -> Option<RootLevel> {
+        if depth >= self.levels.len() {
+            return None;
+        }
+        self.levels.drain(depth..).next()
+    }
+
+    pub fn start_jump(&mut self) {
+        self.pending_jump = true;
+    }
+
+    /// Whether a `B` chord was awaiting its digit, clearing it
+    pub fn take_jump(&mut self) -> bool {
+        std::mem::take(&mut self.pending_jump)
+    }
+
+    /// The queries applied so far, the first one against the original
+    /// document
+    pub fn queries(&self) -> impl Iterator<Item = &str> {
+        self.levels.iter().map(|level| level.query.as_str())
+    }
+}
+
+#[cfg(test)]
+#[path = "root_stack_tests.rs"]
+mod root_stack_tests;
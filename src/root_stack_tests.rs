@@ -0,0 +1,56 @@
+use super::*;
+
+fn level(input: &str, query: &str) -> RootLevel {
+    RootLevel {
+        input: InputText::from(input.to_string()),
+        query: query.to_string(),
+        input_paths: Vec::new(),
+        input_files: None,
+        query_undo: QueryUndoRing::new(),
+    }
+}
+
+#[test]
+fn new_stack_is_empty() {
+    let stack = RootStack::new();
+    assert!(stack.is_empty());
+    assert_eq!(stack.depth(), 0);
+}
+
+#[test]
+fn pop_to_one_below_returns_levels_newest_first() {
+    let mut stack = RootStack::new();
+    stack.push(level(r#"{"a":{"b":1}}"#, ".a"));
+    stack.push(level(r#"{"b":1}"#, ".b"));
+
+    assert!(!stack.is_empty());
+    assert_eq!(stack.pop_to(1).unwrap().query, ".b");
+    let first = stack.pop_to(0).unwrap();
+    assert_eq!(first.query, ".a");
+    assert_eq!(first.input, r#"{"a":{"b":1}}"#);
+    assert!(stack.pop_to(0).is_none());
+}
+
+#[test]
+fn pop_to_returns_the_level_and_drops_those_above() {
+    let mut stack = RootStack::new();
+    stack.push(level("a", ".a"));
+    stack.push(level("b", ".b"));
+    stack.push(level("c", ".c"));
+
+    let restored = stack.pop_to(1).unwrap();
+
+    assert_eq!(restored.query, ".b");
+    assert_eq!(stack.depth(), 1);
+    assert_eq!(stack.queries().collect::<Vec<_>>(), [".a"]);
+}
+
+#[test]
+fn pop_to_the_live_level_or_beyond_is_none() {
+    let mut stack = RootStack::new();
+    stack.push(level("a", ".a"));
+
+    assert!(stack.pop_to(1).is_none());
+    assert!(stack.pop_to(5).is_none());
+    assert_eq!(stack.depth(), 1);
+}
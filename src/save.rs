@@ -1,6 +1,9 @@
 pub mod save_events;
 pub mod save_io;
 pub mod save_render;
+pub mod save_script;
+pub mod save_share;
 pub mod save_state;
+pub mod save_stream;
 
 pub use save_state::SaveState;
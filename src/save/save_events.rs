@@ -1,9 +1,12 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tui_textarea::Input;
 
-use super::save_io::{current_timestamp, write_atomic};
+use super::save_io::{current_timestamp, expand_path, ext_for_result, write_atomic};
+use super::save_script::{ExportSource, ScriptFormat, render_script};
+use super::save_share::{ShareSource, is_markdown_path, render_markdown};
 use super::save_state::{SaveMode, WriteOutcome};
 use crate::app::App;
+use crate::clipboard::copy_to_clipboard;
 
 pub fn open_save_popup(app: &mut App) {
     let result = current_result_text(app);
@@ -32,12 +35,28 @@ fn handle_enter_filename(app: &mut App, key: KeyEvent) {
         attempt_write_from_filename(app);
         return;
     }
+    if key.code == KeyCode::Tab && app.save.script_format().is_some() {
+        app.save.toggle_include_steps();
+        return;
+    }
     let input: Input = key.into();
     if app.save.filename_mut().input(input) {
         app.save.mark_filename_edited();
     }
 }
 
+/// `:w path` — save the result, or export the query for a script
+/// extension, without the popup. The path expands as in the popup.
+pub fn save_to(app: &mut App, pattern: &str) {
+    match expand_path(pattern, ext_for_result(), &current_timestamp()) {
+        Ok(path) => write_to_path(app, &path),
+        Err(err) => {
+            app.notification
+                .show_error(&format!("Save failed: {}", err));
+        }
+    }
+}
+
 fn attempt_write_from_filename(app: &mut App) {
     match app.save.prepare_write() {
         WriteOutcome::ReadyToWrite(path) => write_to_path(app, &path),
@@ -49,6 +68,34 @@ fn attempt_write_from_filename(app: &mut App) {
 }
 
 fn write_to_path(app: &mut App, path: &std::path::Path) {
+    if let Some(format) = ScriptFormat::for_path(path) {
+        let script = current_script(app, format);
+        write_contents(app, path, &script, "Exported to");
+        return;
+    }
+    if is_markdown_path(path) {
+        match current_markdown(app) {
+            Some((markdown, _)) => write_contents(app, path, &markdown, "Exported to"),
+            None => {
+                app.notification.show("Nothing to save");
+                app.save.close();
+            }
+        }
+        return;
+    }
+    if let Some(query_state) = &app.query
+        && let Some(query) = &query_state.truncated_query
+    {
+        app.save_stream.start(
+            query_state.executor.share(),
+            query.clone(),
+            query_state.output_format(),
+            path,
+        );
+        app.save.close();
+        app.update_progress();
+        return;
+    }
     let result = match current_result_text(app) {
         Some(text) => text,
         None => {
@@ -58,10 +105,14 @@ fn write_to_path(app: &mut App, path: &std::path::Path) {
         }
     };
 
-    match write_atomic(path, &result) {
+    write_contents(app, path, &result, "Saved to");
+}
+
+fn write_contents(app: &mut App, path: &std::path::Path, contents: &str, done: &str) {
+    match write_atomic(path, contents) {
         Ok(canonical) => {
             app.notification
-                .show(&format!("Saved to {}", canonical.display()));
+                .show(&format!("{} {}", done, canonical.display()));
             app.save.close();
         }
         Err(err) => {
@@ -71,6 +122,61 @@ fn write_to_path(app: &mut App, path: &std::path::Path) {
     }
 }
 
+/// Report a full-output save that finished. Returns true when one did.
+pub fn poll_save_stream(app: &mut App) -> bool {
+    match app.save_stream.poll() {
+        Some(Ok(path)) => app
+            .notification
+            .show(&format!("Saved to {}", path.display())),
+        Some(Err(err)) => app
+            .notification
+            .show_error(&format!("Save failed: {}", err)),
+        None => return false,
+    }
+    true
+}
+
+fn current_script(app: &App, format: ScriptFormat) -> String {
+    let source = ExportSource {
+        query: app.query(),
+        jq_args: &app.jq_args,
+        inputs: &app.source_paths(),
+    };
+    render_script(format, &source, app.save.include_steps())
+}
+
+/// `:share` — copy the query, an input sample and the output to the
+/// clipboard as markdown
+pub fn share_to_clipboard(app: &mut App) {
+    let Some((markdown, redacted)) = current_markdown(app) else {
+        app.notification.show("Nothing to share");
+        return;
+    };
+    if copy_to_clipboard(&markdown, app.clipboard_backend).is_err() {
+        app.notification.show_error("Copy failed");
+        return;
+    }
+    match redacted {
+        0 => app.notification.show("Copied markdown snippet!"),
+        n => app.notification.show(&format!(
+            "Copied markdown snippet ({} value{} redacted)",
+            n,
+            if n == 1 { "" } else { "s" }
+        )),
+    }
+}
+
+fn current_markdown(app: &App) -> Option<(String, usize)> {
+    let output = current_result_text(app)?;
+    let query_state = app.query.as_ref()?;
+    let source = ShareSource {
+        query: app.query(),
+        input: query_state.executor.json_input(),
+        output: &output,
+    };
+    Some(render_markdown(&source, &app.ai.redactor))
+}
+
 fn current_result_text(app: &App) -> Option<String> {
     let query_state = app.query.as_ref()?;
     let text_arc = query_state.last_successful_result_unformatted.as_ref()?;
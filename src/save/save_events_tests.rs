@@ -195,3 +195,83 @@ fn set_filename(app: &mut crate::app::App, text: &str) {
     }
     ta.insert_str(text);
 }
+
+#[test]
+fn enter_on_sh_path_exports_script() {
+    let dir = TempDir::new().unwrap();
+    let target = dir.path().join("run.sh");
+
+    let mut app = test_app(TEST_JSON);
+    app.input.textarea.insert_str(".name | ascii_upcase");
+    app.input_paths = vec![std::path::PathBuf::from("/data/in.json")];
+    install_result(&mut app, "\"X\"");
+    super::open_save_popup(&mut app);
+    set_filename(&mut app, target.to_string_lossy().as_ref());
+
+    super::handle_save_popup_key(&mut app, key(KeyCode::Tab));
+    super::handle_save_popup_key(&mut app, key(KeyCode::Enter));
+
+    let script = fs::read_to_string(&target).unwrap();
+    assert!(script.starts_with("#!/bin/sh\n"));
+    assert!(script.contains("#   jq '.name' /data/in.json\n"));
+    assert!(script.ends_with("jq '.name | ascii_upcase' /data/in.json\n"));
+    let msg = app.notification.current_message().unwrap_or("");
+    assert!(msg.starts_with("Exported to "), "got {:?}", msg);
+}
+
+#[test]
+fn enter_on_md_path_exports_markdown_snippet() {
+    let dir = TempDir::new().unwrap();
+    let target = dir.path().join("issue.md");
+
+    let mut app = test_app(TEST_JSON);
+    app.input.textarea.insert_str(".name");
+    install_result(&mut app, "\"Test\"");
+    super::open_save_popup(&mut app);
+    set_filename(&mut app, target.to_string_lossy().as_ref());
+
+    super::handle_save_popup_key(&mut app, key(KeyCode::Enter));
+
+    let markdown = fs::read_to_string(&target).unwrap();
+    assert!(markdown.starts_with("**Query**\n\n```jq\n.name\n```\n"));
+    assert!(markdown.contains("**Input**\n\n```json\n{\n"));
+    assert!(markdown.ends_with("**Output**\n\n```json\n\"Test\"\n```\n"));
+    let msg = app.notification.current_message().unwrap_or("");
+    assert!(msg.starts_with("Exported to "), "got {:?}", msg);
+}
+
+#[test]
+fn tab_only_toggles_steps_for_script_paths() {
+    let mut app = test_app(TEST_JSON);
+    install_result(&mut app, "x");
+    super::open_save_popup(&mut app);
+
+    super::handle_save_popup_key(&mut app, key(KeyCode::Tab));
+    assert!(!app.save.include_steps());
+
+    set_filename(&mut app, "run.sh");
+    super::handle_save_popup_key(&mut app, key(KeyCode::Tab));
+    assert!(app.save.include_steps());
+}
+
+#[test]
+fn save_of_a_cut_result_runs_the_query_into_the_file() {
+    let dir = TempDir::new().unwrap();
+    let target = dir.path().join("out.json");
+
+    let mut app = test_app(r#"[1, 2, 3]"#);
+    install_result(&mut app, "1\n");
+    app.query.as_mut().unwrap().truncated_query = Some(".[]".to_string());
+
+    super::save_to(&mut app, target.to_string_lossy().as_ref());
+    assert!(app.save_stream.is_running());
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while !super::poll_save_stream(&mut app) {
+        assert!(std::time::Instant::now() < deadline, "save never finished");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    assert_eq!(fs::read_to_string(&target).unwrap(), "1\n2\n3\n");
+    let msg = app.notification.current_message().unwrap_or("");
+    assert!(msg.starts_with("Saved to "), "got {:?}", msg);
+}
@@ -147,6 +147,15 @@ fn home_dir_string() -> Result<String, SaveError> {
 }
 
 pub fn write_atomic(path: &Path, contents: &str) -> Result<PathBuf, SaveError> {
+    write_atomic_with(path, |file| file.write_all(contents.as_bytes()))
+}
+
+/// [`write_atomic`] with the contents written by `write`, for output
+/// produced straight into the file
+pub fn write_atomic_with(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> io::Result<()>,
+) -> Result<PathBuf, SaveError> {
     let parent = path
         .parent()
         .ok_or_else(|| SaveError::BadPath(format!("path has no parent directory: {:?}", path)))?;
@@ -175,7 +184,7 @@ pub fn write_atomic(path: &Path, contents: &str) -> Result<PathBuf, SaveError> {
 
     let result = (|| -> io::Result<()> {
         let mut file = fs::File::create(&tmp_path)?;
-        file.write_all(contents.as_bytes())?;
+        write(&mut file)?;
         file.sync_all()?;
         drop(file);
         Ok(())
@@ -187,14 +196,15 @@ pub fn write_atomic(path: &Path, contents: &str) -> Result<PathBuf, SaveError> {
     }
 
     if let Err(rename_err) = fs::rename(&tmp_path, path) {
-        let _ = fs::remove_file(&tmp_path);
-        if rename_err.raw_os_error() == Some(libc_exdev())
+        let copied = if rename_err.raw_os_error() == Some(libc_exdev())
             || matches!(rename_err.kind(), io::ErrorKind::CrossesDevices)
         {
-            fs::write(path, contents).map_err(SaveError::Io)?;
+            fs::copy(&tmp_path, path).map(|_| ())
         } else {
-            return Err(SaveError::Io(rename_err));
-        }
+            Err(rename_err)
+        };
+        let _ = fs::remove_file(&tmp_path);
+        copied.map_err(SaveError::Io)?;
     }
 
     let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
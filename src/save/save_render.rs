@@ -7,8 +7,10 @@ use ratatui::{
 };
 use unicode_width::UnicodeWidthStr;
 
+use super::save_script::ScriptFormat;
 use super::save_state::{PathPreview, SaveMode, SaveState};
 use crate::theme;
+use crate::widgets::popup::centered_rect;
 
 const POPUP_WIDTH: u16 = 64;
 const POPUP_HEIGHT: u16 = 10;
@@ -28,18 +30,27 @@ fn render_enter_filename(frame: &mut Frame<'_>, area: Rect, state: &mut SaveStat
     frame.render_widget(Clear, popup_area);
 
     let border_style = Style::default().fg(border_color(&preview));
+    let format = state.script_format();
+    let title = match format {
+        Some(format) => format!(" Export query as {} ", format.label()),
+        None if state.is_markdown() => " Export query as markdown ".to_string(),
+        None => " Save Result to file ".to_string(),
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(border_style)
         .title(Span::styled(
-            " Save Result to file ",
+            title,
             Style::default()
                 .fg(theme::save::title())
                 .add_modifier(Modifier::BOLD),
         ))
-        .title_bottom(build_bottom_hints(&preview).alignment(Alignment::Center));
+        .title_bottom(
+            build_bottom_hints(&preview, format, state.include_steps())
+                .alignment(Alignment::Center),
+        );
 
     let outer_inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -147,13 +158,29 @@ fn truncate_front(path: &str, max_width: u16, prefix_chars: usize) -> String {
     out
 }
 
-fn build_bottom_hints(preview: &PathPreview) -> Line<'static> {
-    let action = if preview.would_overwrite() {
-        "Overwrite"
-    } else {
-        "Save"
+fn build_bottom_hints(
+    preview: &PathPreview,
+    format: Option<ScriptFormat>,
+    include_steps: bool,
+) -> Line<'static> {
+    let action = match (preview.would_overwrite(), format) {
+        (true, _) => "Overwrite",
+        (false, Some(_)) => "Export",
+        (false, None) => "Save",
     };
-    theme::border_hints::build_hints(&[("Enter", action), ("Esc", "Cancel")], hint_color(preview))
+    let mut hints = vec![("Enter", action)];
+    if format.is_some() {
+        hints.push((
+            "Tab",
+            if include_steps {
+                "Steps: on"
+            } else {
+                "Steps: off"
+            },
+        ));
+    }
+    hints.push(("Esc", "Cancel"));
+    theme::border_hints::build_hints(&hints, hint_color(preview))
 }
 
 fn hint_color(preview: &PathPreview) -> ratatui::style::Color {
@@ -171,19 +198,6 @@ fn border_color(_preview: &PathPreview) -> ratatui::style::Color {
     theme::save::border()
 }
 
-fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
-    let width = width.min(area.width);
-    let height = height.min(area.height);
-    let x = area.x + area.width.saturating_sub(width) / 2;
-    let y = area.y + area.height.saturating_sub(height) / 2;
-    Rect {
-        x,
-        y,
-        width,
-        height,
-    }
-}
-
 #[cfg(test)]
 #[path = "save_render_tests.rs"]
 mod save_render_tests;
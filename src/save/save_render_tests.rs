@@ -137,3 +137,11 @@ fn truncate_front_returns_empty_when_prefix_eats_all_budget() {
     assert_eq!(super::truncate_front("anything", 2, 5), "");
     assert_eq!(super::truncate_front("anything", 0, 0), "");
 }
+
+#[test]
+fn snapshot_export_shell_script_with_steps() {
+    let mut s = open_state_with_filename("/tmp/jiq-export-snapshot-does-not-exist.sh");
+    s.toggle_include_steps();
+    let out = render_to_string(&mut s, 80, 12);
+    assert_snapshot!(out);
+}
@@ -0,0 +1,236 @@
+//! Export the current exploration as a runnable script
+//!
+//! Saving to a `.sh` path writes a shell script, and saving to a
+//! `Makefile` or `.mk` path writes a Makefile snippet, instead of the
+//! result. Either one runs jq with the same input files, `--rawfile` /
+//! `--slurpfile` bindings and `--null-input` flag as the session, so the
+//! query can be rerun outside jiq. The query's top-level pipeline stages
+//! can be added as commented-out intermediate commands.
+
+use std::path::{Path, PathBuf};
+
+use crate::query::JqArgs;
+
+/// Make target the snippet defines
+const MAKE_TARGET: &str = "jiq";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptFormat {
+    Shell,
+    Makefile,
+}
+
+impl ScriptFormat {
+    /// The script format a save path asks for, or `None` to save the result
+    pub fn for_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy();
+        if matches!(name.as_ref(), "Makefile" | "makefile" | "GNUmakefile") {
+            return Some(Self::Makefile);
+        }
+        match path.extension()?.to_string_lossy().as_ref() {
+            "sh" => Some(Self::Shell),
+            "mk" => Some(Self::Makefile),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Shell => "shell script",
+            Self::Makefile => "Makefile snippet",
+        }
+    }
+}
+
+/// Everything the exported command needs from the session
+pub struct ExportSource<'a> {
+    pub query: &'a str,
+    pub jq_args: &'a JqArgs,
+    /// Files the document came from; empty for stdin, clipboard and paste
+    pub inputs: &'a [PathBuf],
+}
+
+/// Render the script, with the pipeline's intermediate steps as comments
+/// when `include_steps` is set
+pub fn render_script(
+    format: ScriptFormat,
+    source: &ExportSource<'_>,
+    include_steps: bool,
+) -> String {
+    let steps = if include_steps {
+        pipeline_prefixes(source.query)
+    } else {
+        Vec::new()
+    };
+    match format {
+        ScriptFormat::Shell => render_shell(source, &steps),
+        ScriptFormat::Makefile => render_makefile(source, &steps),
+    }
+}
+
+fn render_shell(source: &ExportSource<'_>, steps: &[&str]) -> String {
+    let inputs = if source.inputs.is_empty() {
+        "\"$@\"".to_string()
+    } else {
+        input_words(source.inputs)
+    };
+    let command = |query: &str| format!("{} {}", jq_command(source.jq_args, query), inputs);
+
+    let mut out = String::from("#!/bin/sh\n# Exported from jiq\n");
+    if source.inputs.is_empty() {
+        out.push_str("# Reads the JSON files given as arguments, or stdin\n");
+    }
+    out.push_str("set -eu\n\n");
+    push_steps(&mut out, steps, command);
+    out.push_str(&command(source.query));
+    out.push('\n');
+    out
+}
+
+fn render_makefile(source: &ExportSource<'_>, steps: &[&str]) -> String {
+    let inputs = if source.inputs.is_empty() {
+        "$(INPUT)".to_string()
+    } else {
+        make_escape(&input_words(source.inputs))
+    };
+    // A recipe is a single line, and make expands `$` before the shell runs
+    let command = |query: &str| {
+        let command = jq_command(source.jq_args, &query.replace('\n', " "));
+        format!("{} {}", make_escape(&command), inputs)
+    };
+
+    let mut out = String::from("# Exported from jiq\n");
+    if source.inputs.is_empty() {
+        out.push_str(&format!(
+            "# Run with: make {} INPUT=file.json\nINPUT ?= /dev/stdin\n",
+            MAKE_TARGET
+        ));
+    }
+    out.push('\n');
+    push_steps(&mut out, steps, command);
+    out.push_str(&format!(
+        ".PHONY: {0}\n{0}:\n\t{1}\n",
+        MAKE_TARGET,
+        command(source.query)
+    ));
+    out
+}
+
+fn push_steps(out: &mut String, steps: &[&str], command: impl Fn(&str) -> String) {
+    if steps.is_empty() {
+        return;
+    }
+    out.push_str("# Intermediate steps:\n");
+    for step in steps {
+        for line in command(step).lines() {
+            out.push_str("#   ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+}
+
+/// `jq`, its flags and the quoted query, ready for the input words
+fn jq_command(jq_args: &JqArgs, query: &str) -> String {
+    let mut words = vec!["jq".to_string()];
+    words.extend(jq_args.to_words().iter().map(|w| shell_word(w)));
+    words.push(single_quote(&jq_args.program(query)));
+    words.join(" ")
+}
+
+fn input_words(inputs: &[PathBuf]) -> String {
+    inputs
+        .iter()
+        .map(|p| shell_word(&p.display().to_string()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The query up to each top-level `|`, shortest first
+///
+/// Pipes inside strings, brackets, `if … end` blocks and comments don't
+/// split, and neither does `|=`. A pipe that ends an `… as $x` binding
+/// starts the binding's body, so it doesn't split either.
+pub fn pipeline_prefixes(query: &str) -> Vec<&str> {
+    let mut prefixes = Vec::new();
+    // Open brackets, `"` for a string and `i` for an `if` block
+    let mut stack: Vec<char> = Vec::new();
+    let mut binding = false;
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if stack.last() == Some(&'"') {
+            match c {
+                '\\' => {
+                    if let Some((_, '(')) = chars.next() {
+                        stack.push('(');
+                    }
+                }
+                '"' => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' | '(' | '[' | '{' => stack.push(c),
+            ')' | ']' | '}' => {
+                stack.pop();
+            }
+            '#' => while chars.next_if(|&(_, c)| c != '\n').is_some() {},
+            '|' if chars.peek().map(|&(_, c)| c) == Some('=') => {}
+            '|' if stack.is_empty() => {
+                if binding {
+                    binding = false;
+                } else if !query[..i].trim().is_empty() {
+                    prefixes.push(query[..i].trim());
+                }
+            }
+            c if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+                let mut end = i + c.len_utf8();
+                while let Some((j, c)) =
+                    chars.next_if(|&(_, c)| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    end = j + c.len_utf8();
+                }
+                match &query[i..end] {
+                    "if" => stack.push('i'),
+                    "end" if stack.last() == Some(&'i') => {
+                        stack.pop();
+                    }
+                    "as" if stack.is_empty() => binding = true,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    prefixes
+}
+
+/// Quote a word for sh, leaving plain paths and flags bare
+fn shell_word(word: &str) -> String {
+    let plain = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@%+,".contains(c));
+    if plain {
+        word.to_string()
+    } else {
+        single_quote(word)
+    }
+}
+
+fn single_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "'\\''"))
+}
+
+fn make_escape(text: &str) -> String {
+    text.replace('$', "$$")
+}
+
+#[cfg(test)]
+#[path = "save_script_tests.rs"]
+mod save_script_tests;
@@ -0,0 +1,191 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::*;
+use crate::query::jq_args::FileVarKind;
+
+fn source<'a>(query: &'a str, jq_args: &'a JqArgs, inputs: &'a [PathBuf]) -> ExportSource<'a> {
+    ExportSource {
+        query,
+        jq_args,
+        inputs,
+    }
+}
+
+#[test]
+fn format_follows_path() {
+    assert_eq!(
+        ScriptFormat::for_path(Path::new("/tmp/run.sh")),
+        Some(ScriptFormat::Shell)
+    );
+    assert_eq!(
+        ScriptFormat::for_path(Path::new("jq.mk")),
+        Some(ScriptFormat::Makefile)
+    );
+    assert_eq!(
+        ScriptFormat::for_path(Path::new("/src/Makefile")),
+        Some(ScriptFormat::Makefile)
+    );
+    assert_eq!(ScriptFormat::for_path(Path::new("out.json")), None);
+    assert_eq!(ScriptFormat::for_path(Path::new("notes")), None);
+}
+
+#[test]
+fn shell_script_runs_query_on_input_files() {
+    let args = JqArgs::new();
+    let inputs = [
+        PathBuf::from("/data/a.json"),
+        PathBuf::from("/data/my b.json"),
+    ];
+
+    let script = render_script(
+        ScriptFormat::Shell,
+        &source(".items[] | .price", &args, &inputs),
+        false,
+    );
+
+    assert_eq!(
+        script,
+        "#!/bin/sh\n# Exported from jiq\nset -eu\n\n\
+         jq '.items[] | .price' /data/a.json '/data/my b.json'\n"
+    );
+}
+
+#[test]
+fn shell_script_without_files_reads_arguments_or_stdin() {
+    let args = JqArgs::new();
+
+    let script = render_script(ScriptFormat::Shell, &source(".", &args, &[]), false);
+
+    assert!(script.contains("# Reads the JSON files given as arguments, or stdin\n"));
+    assert!(script.ends_with("jq '.' \"$@\"\n"));
+}
+
+#[test]
+fn shell_script_passes_jq_flags() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(b"text").unwrap();
+    let mut args = JqArgs::new();
+    args.push_file_var(FileVarKind::Raw, "note", file.path())
+        .unwrap();
+    args.set_null_input(true);
+
+    let script = render_script(ScriptFormat::Shell, &source("$note", &args, &[]), false);
+
+    let expected = format!(
+        "jq --null-input --rawfile note {} '$note' \"$@\"\n",
+        file.path().display()
+    );
+    assert!(script.ends_with(&expected), "{script}");
+}
+
+#[test]
+fn shell_script_quotes_single_quotes_in_query() {
+    let args = JqArgs::new();
+
+    let script = render_script(
+        ScriptFormat::Shell,
+        &source(r#"."it's""#, &args, &[]),
+        false,
+    );
+
+    assert!(script.contains(r#"jq '."it'\''s"' "$@""#));
+}
+
+#[test]
+fn shell_script_lists_intermediate_steps_as_comments() {
+    let args = JqArgs::new();
+    let inputs = [PathBuf::from("d.json")];
+
+    let script = render_script(
+        ScriptFormat::Shell,
+        &source(".items[] | select(.ok) | .id", &args, &inputs),
+        true,
+    );
+
+    assert!(script.contains(
+        "# Intermediate steps:\n\
+         #   jq '.items[]' d.json\n\
+         #   jq '.items[] | select(.ok)' d.json\n\n\
+         jq '.items[] | select(.ok) | .id' d.json\n"
+    ));
+}
+
+#[test]
+fn makefile_snippet_escapes_dollars() {
+    let args = JqArgs::new();
+    let inputs = [PathBuf::from("/d.json")];
+
+    let snippet = render_script(
+        ScriptFormat::Makefile,
+        &source(".a as $x | $x", &args, &inputs),
+        false,
+    );
+
+    assert_eq!(
+        snippet,
+        "# Exported from jiq\n\n.PHONY: jiq\njiq:\n\tjq '.a as $$x | $$x' /d.json\n"
+    );
+}
+
+#[test]
+fn makefile_snippet_without_files_uses_input_variable() {
+    let args = JqArgs::new();
+
+    let snippet = render_script(
+        ScriptFormat::Makefile,
+        &source(".a\n| .b", &args, &[]),
+        true,
+    );
+
+    assert!(snippet.contains("INPUT ?= /dev/stdin\n"));
+    assert!(snippet.contains("#   jq '.a' $(INPUT)\n"));
+    assert!(snippet.ends_with("\tjq '.a | .b' $(INPUT)\n"));
+}
+
+#[test]
+fn prefixes_split_on_top_level_pipes() {
+    assert_eq!(pipeline_prefixes(".a | .b | .c"), vec![".a", ".a | .b"]);
+    assert!(pipeline_prefixes(".a").is_empty());
+}
+
+#[test]
+fn prefixes_ignore_nested_and_quoted_pipes() {
+    assert_eq!(
+        pipeline_prefixes(r#"map(.a | .b) | [.[] | "x|y"] | {k: (.x | .y)} | .z"#),
+        vec![
+            "map(.a | .b)",
+            r#"map(.a | .b) | [.[] | "x|y"]"#,
+            r#"map(.a | .b) | [.[] | "x|y"] | {k: (.x | .y)}"#
+        ]
+    );
+    assert_eq!(
+        pipeline_prefixes(r#""\(.a | .b)" | length"#),
+        vec![r#""\(.a | .b)""#]
+    );
+}
+
+#[test]
+fn prefixes_ignore_update_assignment_and_if_blocks() {
+    assert_eq!(pipeline_prefixes(".a |= . + 1 | .b"), vec![".a |= . + 1"]);
+    assert_eq!(
+        pipeline_prefixes("if .a then .b | .c else .d end | .e"),
+        vec!["if .a then .b | .c else .d end"]
+    );
+}
+
+#[test]
+fn prefixes_keep_binding_with_its_body() {
+    assert_eq!(
+        pipeline_prefixes(".a as $x | $x | .b"),
+        vec![".a as $x | $x"]
+    );
+}
+
+#[test]
+fn prefixes_skip_comments() {
+    assert_eq!(
+        pipeline_prefixes(".a # not | a pipe\n| .b"),
+        vec![".a # not | a pipe"]
+    );
+}
@@ -0,0 +1,184 @@
+//! Share the current query as a markdown snippet
+//!
+//! Saving to a `.md` path, or `:share` for the clipboard, produces a
+//! self-contained snippet for an issue or a chat: the query, a trimmed
+//! sample of the input and the output, each in its own code block. The AI
+//! redaction rules (`[ai.redaction]`) mask secrets in all three first.
+
+use serde_json::{Deserializer, Value};
+
+use crate::ai::context::Redactor;
+
+/// Top-level documents of a stream kept in the input sample
+const MAX_INPUT_DOCUMENTS: usize = 3;
+/// Elements kept from each array in the input sample
+const MAX_ARRAY_ITEMS: usize = 3;
+/// Characters kept from each string in the input sample
+const MAX_STRING_CHARS: usize = 80;
+/// Lines kept from the input sample and from the output
+const MAX_BLOCK_LINES: usize = 40;
+
+/// What the snippet shows
+pub struct ShareSource<'a> {
+    pub query: &'a str,
+    pub input: &'a str,
+    pub output: &'a str,
+}
+
+/// Whether a save path asks for the markdown snippet
+pub fn is_markdown_path(path: &std::path::Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| matches!(ext.to_string_lossy().as_ref(), "md" | "markdown"))
+}
+
+/// Render the snippet; returns it with the number of values redacted
+pub fn render_markdown(source: &ShareSource<'_>, redactor: &Redactor) -> (String, usize) {
+    let (query, query_redacted) = redactor.redact_text(source.query.trim());
+    let mut out = String::new();
+    push_block(&mut out, "**Query**", "jq", &query);
+    let mut redacted = query_redacted;
+
+    if !source.input.trim().is_empty() {
+        let (sample, trimmed, n) = input_sample(source.input, redactor);
+        redacted += n;
+        let (sample, cut) = cap_lines(&sample);
+        let heading = if trimmed || cut {
+            "**Input** (trimmed)"
+        } else {
+            "**Input**"
+        };
+        out.push('\n');
+        push_block(&mut out, heading, "json", &sample);
+    }
+
+    let (output, n, lang) = redact_output(source.output.trim_end(), redactor);
+    redacted += n;
+    let (output, cut) = cap_lines(&output);
+    let heading = if cut {
+        "**Output** (trimmed)"
+    } else {
+        "**Output**"
+    };
+    out.push('\n');
+    push_block(&mut out, heading, lang, &output);
+    (out, redacted)
+}
+
+fn push_block(out: &mut String, heading: &str, lang: &str, body: &str) {
+    let fence = "`".repeat(longest_backtick_run(body).max(2) + 1);
+    out.push_str(&format!(
+        "{}\n\n{}{}\n{}\n{}\n",
+        heading, fence, lang, body, fence
+    ));
+}
+
+/// The input pretty printed with long arrays, strings and streams cut
+/// short and secrets masked; also whether anything was cut and how many
+/// values were masked
+fn input_sample(input: &str, redactor: &Redactor) -> (String, bool, usize) {
+    let mut documents = Vec::new();
+    let mut trimmed = false;
+    let mut redacted = 0;
+    for value in Deserializer::from_str(input).into_iter::<Value>() {
+        match value {
+            Ok(value) if documents.len() < MAX_INPUT_DOCUMENTS => {
+                let mut value = trim_value(value, &mut trimmed);
+                redacted += redactor.redact_in_place(&mut value);
+                documents.push(value);
+            }
+            Ok(_) => {
+                trimmed = true;
+                break;
+            }
+            // Not JSON after all; show the text as it is
+            Err(_) => {
+                let (text, n) = redactor.redact_text(input.trim_end());
+                return (text, false, n);
+            }
+        }
+    }
+    (pretty_lines(&documents), trimmed, redacted)
+}
+
+/// The output with secrets masked, the number masked and the code block
+/// language. Output that needed no masking keeps jq's formatting.
+fn redact_output(output: &str, redactor: &Redactor) -> (String, usize, &'static str) {
+    let values = Deserializer::from_str(output)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>();
+    match values {
+        Ok(mut values) => {
+            let redacted: usize = values
+                .iter_mut()
+                .map(|value| redactor.redact_in_place(value))
+                .sum();
+            if redacted == 0 {
+                (output.to_string(), 0, "json")
+            } else {
+                (pretty_lines(&values), redacted, "json")
+            }
+        }
+        Err(_) => {
+            let (text, n) = redactor.redact_text(output);
+            (text, n, "text")
+        }
+    }
+}
+
+fn pretty_lines(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(|value| serde_json::to_string_pretty(value).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn trim_value(value: Value, trimmed: &mut bool) -> Value {
+    match value {
+        Value::Array(items) => {
+            if items.len() > MAX_ARRAY_ITEMS {
+                *trimmed = true;
+            }
+            Value::Array(
+                items
+                    .into_iter()
+                    .take(MAX_ARRAY_ITEMS)
+                    .map(|item| trim_value(item, trimmed))
+                    .collect(),
+            )
+        }
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, item)| (key, trim_value(item, trimmed)))
+                .collect(),
+        ),
+        Value::String(s) if s.chars().count() > MAX_STRING_CHARS => {
+            *trimmed = true;
+            let mut short: String = s.chars().take(MAX_STRING_CHARS).collect();
+            short.push('…');
+            Value::String(short)
+        }
+        other => other,
+    }
+}
+
+/// At most [`MAX_BLOCK_LINES`] of `text`, with a line saying how many
+/// were left out, and whether any were
+fn cap_lines(text: &str) -> (String, bool) {
+    let total = text.lines().count();
+    if total <= MAX_BLOCK_LINES {
+        return (text.to_string(), false);
+    }
+    let mut kept: Vec<&str> = text.lines().take(MAX_BLOCK_LINES).collect();
+    let more = format!("… {} more lines", total - MAX_BLOCK_LINES);
+    kept.push(&more);
+    (kept.join("\n"), true)
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+#[path = "save_share_tests.rs"]
+mod save_share_tests;
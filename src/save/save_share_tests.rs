@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use super::*;
+use crate::config::ai_types::RedactionConfig;
+
+fn source<'a>(query: &'a str, input: &'a str, output: &'a str) -> ShareSource<'a> {
+    ShareSource {
+        query,
+        input,
+        output,
+    }
+}
+
+fn render(source: &ShareSource<'_>) -> String {
+    render_markdown(source, &Redactor::default()).0
+}
+
+#[test]
+fn markdown_path_by_extension() {
+    assert!(is_markdown_path(Path::new("/tmp/issue.md")));
+    assert!(is_markdown_path(Path::new("notes.markdown")));
+    assert!(!is_markdown_path(Path::new("out.json")));
+    assert!(!is_markdown_path(Path::new("md")));
+}
+
+#[test]
+fn snippet_has_query_input_and_output_blocks() {
+    let markdown = render(&source(".a", r#"{"a":1}"#, "1\n"));
+
+    assert_eq!(
+        markdown,
+        "**Query**\n\n```jq\n.a\n```\n\n\
+         **Input**\n\n```json\n{\n  \"a\": 1\n}\n```\n\n\
+         **Output**\n\n```json\n1\n```\n"
+    );
+}
+
+#[test]
+fn long_arrays_and_strings_in_input_are_trimmed() {
+    let long = "x".repeat(200);
+    let input = format!(r#"{{"items":[1,2,3,4,5],"note":"{}"}}"#, long);
+
+    let markdown = render(&source(".items", &input, "[1,2,3,4,5]"));
+
+    assert!(markdown.contains("**Input** (trimmed)"));
+    assert!(markdown.contains("    3\n  ],"), "{}", markdown);
+    assert!(!markdown.contains("    4"));
+    assert!(markdown.contains(&format!("\"{}…\"", "x".repeat(MAX_STRING_CHARS))));
+}
+
+#[test]
+fn input_stream_keeps_first_documents() {
+    let input = (1..=5)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let markdown = render(&source(".", &input, "1"));
+
+    assert!(markdown.contains("```json\n1\n2\n3\n```"), "{}", markdown);
+    assert!(markdown.contains("**Input** (trimmed)"));
+}
+
+#[test]
+fn long_output_is_cut_with_a_count() {
+    let output = (1..=50)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let markdown = render(&source(".[]", "[]", &output));
+
+    assert!(markdown.contains("**Output** (trimmed)"));
+    assert!(markdown.contains("40\n… 10 more lines\n```"));
+    assert!(!markdown.contains("\n41\n"));
+}
+
+#[test]
+fn raw_output_is_a_text_block() {
+    let markdown = render(&source(".name", r#"{"name":"ada"}"#, "ada lovelace\n"));
+
+    assert!(markdown.contains("```text\nada lovelace\n```"));
+}
+
+#[test]
+fn fence_outgrows_backticks_in_the_content() {
+    let markdown = render(&source(r#""```" + .a"#, r#"{"a":"x"}"#, "\"```x\""));
+
+    assert!(markdown.contains("````jq\n\"```\" + .a\n````"));
+}
+
+#[test]
+fn empty_input_leaves_out_the_input_block() {
+    let markdown = render(&source("1 + 1", "", "2"));
+
+    assert!(!markdown.contains("**Input**"));
+}
+
+#[test]
+fn redaction_masks_secrets_everywhere() {
+    let redactor = Redactor::from_config(&RedactionConfig::default());
+    let key = "sk-abcdefghijklmnopqrstuvwx";
+    let query = format!(r#"select(.key == "{}")"#, key);
+    let input = r#"{"user":"ada","password":"hunter2"}"#;
+
+    let (markdown, redacted) = render_markdown(
+        &source(&query, input, r#"{"password":"hunter2"}"#),
+        &redactor,
+    );
+
+    assert!(!markdown.contains(key));
+    assert!(!markdown.contains("hunter2"));
+    assert!(markdown.contains("\"user\": \"ada\""));
+    assert_eq!(redacted, 3);
+}
@@ -1,8 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use tui_textarea::TextArea;
 
 use super::save_io::{SaveError, expand_path, ext_for_result};
+use super::save_script::ScriptFormat;
 
 pub const DEFAULT_PATH_PATTERN: &str = "jiq-{timestamp}.json";
 
@@ -35,6 +36,8 @@ pub struct SaveState {
     filename: TextArea<'static>,
     filename_dirty: bool,
     locked_timestamp: String,
+    /// Whether an exported script lists the pipeline's intermediate steps
+    include_steps: bool,
 }
 
 impl Default for SaveState {
@@ -50,6 +53,7 @@ impl SaveState {
             filename: TextArea::default(),
             filename_dirty: false,
             locked_timestamp: String::new(),
+            include_steps: false,
         }
     }
 
@@ -57,6 +61,7 @@ impl SaveState {
         self.mode = SaveMode::EnterFilename;
         self.filename_dirty = false;
         self.locked_timestamp = locked_timestamp;
+        self.include_steps = false;
         let initial = expand_initial_pattern(DEFAULT_PATH_PATTERN, &self.locked_timestamp);
         self.filename = make_textarea(&initial);
     }
@@ -85,6 +90,24 @@ impl SaveState {
         self.filename.lines().join("")
     }
 
+    /// The script the typed path exports, or `None` when it saves the result
+    pub fn script_format(&self) -> Option<ScriptFormat> {
+        ScriptFormat::for_path(Path::new(self.current_filename_text().trim()))
+    }
+
+    /// Whether the typed path exports the markdown snippet
+    pub fn is_markdown(&self) -> bool {
+        super::save_share::is_markdown_path(Path::new(self.current_filename_text().trim()))
+    }
+
+    pub fn include_steps(&self) -> bool {
+        self.include_steps
+    }
+
+    pub fn toggle_include_steps(&mut self) {
+        self.include_steps = !self.include_steps;
+    }
+
     #[cfg(test)]
     pub fn locked_timestamp(&self) -> &str {
         &self.locked_timestamp
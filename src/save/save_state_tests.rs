@@ -186,3 +186,22 @@ fn open_replaces_locked_timestamp_each_time() {
     s.open("20260202-111111".to_string());
     assert_eq!(s.locked_timestamp(), "20260202-111111");
 }
+
+#[test]
+fn script_format_follows_typed_extension() {
+    assert_eq!(open_state(None).script_format(), None);
+    assert_eq!(
+        open_state(Some("run.sh")).script_format(),
+        Some(ScriptFormat::Shell)
+    );
+}
+
+#[test]
+fn include_steps_toggles_and_resets_on_open() {
+    let mut s = open_state(Some("run.sh"));
+    s.toggle_include_steps();
+    assert!(s.include_steps());
+
+    s.open(TS.to_string());
+    assert!(!s.include_steps());
+}
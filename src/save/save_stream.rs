@@ -0,0 +1,87 @@
+//! Saving a result that was cut at the output limit
+//!
+//! The results pane only holds the start of such a result, so `:w` runs
+//! the query again in the background with jq printing straight into the
+//! file. It shows as an operation in the status bar until it is done and
+//! can be stopped with `Ctrl+X`, which leaves no file behind.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+
+use tokio_util::sync::CancellationToken;
+
+use super::save_io::write_atomic_with;
+use crate::query::executor::JqExecutor;
+use crate::query::output_format::OutputFormat;
+
+#[derive(Default)]
+pub struct SaveStream {
+    cancel: Option<CancellationToken>,
+    /// The saved file's path, or why the save failed
+    response_rx: Option<Receiver<Result<PathBuf, String>>>,
+}
+
+impl SaveStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.response_rx.is_some()
+    }
+
+    /// Write the output of `query` to `path` with `executor`, replacing a
+    /// save still under way
+    pub fn start(
+        &mut self,
+        executor: JqExecutor,
+        query: String,
+        format: OutputFormat,
+        path: &Path,
+    ) {
+        self.cancel();
+        let cancel = CancellationToken::new();
+        let (response_tx, response_rx) = channel();
+        let thread_cancel = cancel.clone();
+        let path = path.to_path_buf();
+        std::thread::spawn(move || {
+            let saved = write_atomic_with(&path, |file| {
+                executor
+                    .write_output(&query, format, file.try_clone()?, &thread_cancel)
+                    .map_err(|e| io::Error::other(e.to_string()))
+            })
+            .map_err(|e| e.to_string());
+            if !thread_cancel.is_cancelled() {
+                let _ = response_tx.send(saved);
+            }
+        });
+        self.cancel = Some(cancel);
+        self.response_rx = Some(response_rx);
+    }
+
+    /// Stop the save under way; the partly written file is removed
+    pub fn cancel(&mut self) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.cancel();
+        }
+        self.response_rx = None;
+    }
+
+    /// The outcome, once the save has finished
+    pub fn poll(&mut self) -> Option<Result<PathBuf, String>> {
+        let response_rx = self.response_rx.as_ref()?;
+        let outcome = match response_rx.try_recv() {
+            Ok(outcome) => outcome,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err("save stopped".to_string()),
+        };
+        self.cancel = None;
+        self.response_rx = None;
+        Some(outcome)
+    }
+}
+
+#[cfg(test)]
+#[path = "save_stream_tests.rs"]
+mod save_stream_tests;
@@ -0,0 +1,77 @@
+use super::*;
+
+use std::time::{Duration, Instant};
+
+use tempfile::TempDir;
+
+fn wait(stream: &mut SaveStream) -> Result<PathBuf, String> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        if let Some(outcome) = stream.poll() {
+            return outcome;
+        }
+        assert!(Instant::now() < deadline, "save never finished");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn saves_the_whole_output_to_the_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("out.json");
+    let mut stream = SaveStream::new();
+
+    stream.start(
+        JqExecutor::new("[1, 2]".to_string()),
+        ".[], range(3)".to_string(),
+        OutputFormat::default(),
+        &path,
+    );
+    assert!(stream.is_running());
+
+    let saved = wait(&mut stream).unwrap();
+    assert_eq!(saved, std::fs::canonicalize(&path).unwrap());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2\n0\n1\n2\n");
+    assert!(!stream.is_running());
+}
+
+#[test]
+fn failed_query_reports_the_jq_error_and_leaves_no_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("out.json");
+    let mut stream = SaveStream::new();
+
+    stream.start(
+        JqExecutor::new("null".to_string()),
+        "error(\"boom\")".to_string(),
+        OutputFormat::default(),
+        &path,
+    );
+
+    let err = wait(&mut stream).unwrap_err();
+    assert!(err.contains("boom"), "{err}");
+    assert!(!path.exists());
+}
+
+#[test]
+fn cancelled_save_leaves_no_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("out.json");
+    let mut stream = SaveStream::new();
+
+    stream.start(
+        JqExecutor::new("null".to_string()),
+        "range(1e12)".to_string(),
+        OutputFormat::default(),
+        &path,
+    );
+    stream.cancel();
+
+    assert!(!stream.is_running());
+    assert!(stream.poll().is_none());
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while std::fs::read_dir(dir.path()).unwrap().next().is_some() {
+        assert!(Instant::now() < deadline, "temp file left behind");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
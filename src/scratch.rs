@@ -0,0 +1,99 @@
+//! The scratch register: one piece of text kept from one run to the next.
+//!
+//! `"y` parks the query in it, or in the results pane what `y` would copy,
+//! and `"p` / `"P` put it into the query after / before the cursor. Unlike
+//! history it only holds what was parked on purpose, and unlike snippets
+//! it has no name, so carrying a fragment or a value over to another jiq
+//! session is two keystrokes each way. It lives in `scratch.txt` next to
+//! the query history; the last jiq to park something wins.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SCRATCH_DIR: &str = "jiq";
+const SCRATCH_FILE: &str = "scratch.txt";
+
+pub fn scratch_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join(SCRATCH_DIR).join(SCRATCH_FILE))
+}
+
+#[derive(Debug, Default)]
+pub struct Scratch {
+    text: Option<String>,
+    persist_to_disk: bool,
+    /// Whether `"` was pressed in the results pane and its `y` is awaited
+    pending_chord: bool,
+}
+
+impl Scratch {
+    pub fn new() -> Self {
+        Self {
+            text: scratch_path().and_then(|p| load_from_path(&p)),
+            persist_to_disk: true,
+            pending_chord: false,
+        }
+    }
+
+    /// A register that is never read from or written to disk
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Park `text`, replacing what was there, and save it for later sessions
+    pub fn set(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+        if self.persist_to_disk
+            && let Some(path) = scratch_path()
+            && let Err(e) = save_to_path(text, &path)
+        {
+            log::warn!("Failed to save scratch register: {}", e);
+        }
+    }
+
+    /// The text as it goes into the one-line query: a multi-line result
+    /// snippet becomes one line, while a one-line fragment keeps its spacing
+    pub fn text_for_query(&self) -> Option<String> {
+        let text = self.text()?;
+        if !text.contains('\n') {
+            return Some(text.to_string());
+        }
+        let line = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(line)
+    }
+
+    pub fn start_chord(&mut self) {
+        self.pending_chord = true;
+    }
+
+    /// Whether a `"` chord was awaiting its key, clearing it
+    pub fn take_chord(&mut self) -> bool {
+        std::mem::take(&mut self.pending_chord)
+    }
+}
+
+fn load_from_path(path: &Path) -> Option<String> {
+    let text = fs::read_to_string(path).ok()?;
+    (!text.is_empty()).then_some(text)
+}
+
+/// No file locking - last writer wins if multiple instances run simultaneously.
+fn save_to_path(text: &str, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, text)
+}
+
+#[cfg(test)]
+#[path = "scratch_tests.rs"]
+mod scratch_tests;
@@ -0,0 +1,63 @@
+use super::*;
+
+use tempfile::TempDir;
+
+#[test]
+fn empty_register_has_nothing_to_put() {
+    let scratch = Scratch::empty();
+    assert_eq!(scratch.text(), None);
+    assert_eq!(scratch.text_for_query(), None);
+}
+
+#[test]
+fn parking_replaces_what_was_there() {
+    let mut scratch = Scratch::empty();
+    scratch.set(".a");
+    scratch.set(".b");
+    assert_eq!(scratch.text(), Some(".b"));
+}
+
+#[test]
+fn multi_line_snippet_goes_into_the_query_on_one_line() {
+    let mut scratch = Scratch::empty();
+    scratch.set("{\n  \"id\": 1,\n  \"tags\": []\n}\n");
+    assert_eq!(
+        scratch.text_for_query().as_deref(),
+        Some(r#"{ "id": 1, "tags": [] }"#)
+    );
+}
+
+#[test]
+fn one_line_fragment_keeps_its_spacing() {
+    let mut scratch = Scratch::empty();
+    scratch.set(" | .id");
+    assert_eq!(scratch.text_for_query().as_deref(), Some(" | .id"));
+}
+
+#[test]
+fn chord_is_taken_once() {
+    let mut scratch = Scratch::empty();
+    scratch.start_chord();
+    assert!(scratch.take_chord());
+    assert!(!scratch.take_chord());
+}
+
+#[test]
+fn saved_text_loads_in_a_later_session() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("jiq").join(SCRATCH_FILE);
+
+    save_to_path(".users[] | .id", &path).unwrap();
+
+    assert_eq!(load_from_path(&path).as_deref(), Some(".users[] | .id"));
+}
+
+#[test]
+fn missing_or_empty_file_is_an_empty_register() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(SCRATCH_FILE);
+    assert_eq!(load_from_path(&path), None);
+
+    std::fs::write(&path, "").unwrap();
+    assert_eq!(load_from_path(&path), None);
+}
@@ -1,4 +1,5 @@
 use super::search_state::Match;
+use crate::str_utils::display_width;
 
 pub struct SearchMatcher;
 
@@ -20,11 +21,15 @@ impl SearchMatcher {
                 // Convert byte position to character position
                 let col = line[..absolute_byte_pos].chars().count() as u16;
                 let len = query.chars().count() as u16;
+                let x = display_width(&line[..absolute_byte_pos]) as u16;
+                let width = display_width(query) as u16;
 
                 matches.push(Match {
                     line: line_num as u32,
                     col,
                     len,
+                    x,
+                    width,
                 });
 
                 // Move past this match to find overlapping matches
@@ -68,6 +68,16 @@ fn test_no_match() {
 // *For any* search query and results content, the matcher should find the
 // same matches regardless of case differences between query and content.
 // **Validates: Requirements 2.1**
+#[test]
+fn test_wide_chars_give_display_columns() {
+    let matches = SearchMatcher::find_all(r#""名前": "東京""#, "東京");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].col, 7);
+    assert_eq!(matches[0].len, 2);
+    assert_eq!(matches[0].x, 9);
+    assert_eq!(matches[0].width, 4);
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(100))]
 
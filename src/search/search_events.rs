@@ -152,12 +152,7 @@ pub fn handle_search_key(app: &mut App, key: KeyEvent) -> bool {
         _ => {
             app.search.search_textarea_mut().input(key);
 
-            // Only update matches if query state is available
-            if let Some(query_state) = &app.query
-                && let Some(content) = &query_state.last_successful_result_unformatted
-            {
-                app.search.update_matches(content);
-            }
+            update_matches(app);
 
             if let Some(m) = app.search.current_match() {
                 scroll_to_line(app, m.line);
@@ -171,6 +166,32 @@ pub fn handle_search_key(app: &mut App, key: KeyEvent) -> bool {
     }
 }
 
+/// Search the result as shown: the path view is searched by its own lines
+fn update_matches(app: &mut App) {
+    // Only update matches if query state is available
+    if app.results_paths.is_active() {
+        app.search.update_matches(app.results_paths.text());
+    } else if let Some(query_state) = &app.query
+        && let Some(content) = &query_state.last_successful_result_unformatted
+    {
+        app.search.update_matches(content);
+    }
+}
+
+/// Search again after the shown text changed, such as when the path view
+/// is switched
+pub fn rematch(app: &mut App) {
+    if app.results_paths.is_active() {
+        app.search.rematch(app.results_paths.text());
+    } else if let Some(content) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone())
+    {
+        app.search.rematch(&content);
+    }
+}
+
 pub fn open_search(app: &mut App) {
     app.saved_ai_visibility_for_search = app.ai.visible;
     app.ai.visible = false;
@@ -248,6 +269,11 @@ fn keep_kv_from_search(app: &mut App) {
 /// next sibling row of the match row's path. Pure cursor movement: does
 /// NOT close the search overlay or modify the query.
 fn sibling_from_search(app: &mut App, dir: SiblingDir) {
+    // Siblings move the cursor, which the path view does not have
+    if app.results_paths.is_active() {
+        app.notification.show("Path view is on · = shows the JSON");
+        return;
+    }
     let match_row = match resolve_match_row(app) {
         Some(r) => r,
         None => return,
@@ -257,8 +283,7 @@ fn sibling_from_search(app: &mut App, dir: SiblingDir) {
             let total = app.results_line_count_u32();
             app.results_cursor.update_total_lines(total);
             app.results_cursor.move_to_line(line);
-            app.results_scroll
-                .ensure_cursor_visible(app.results_cursor.cursor_line());
+            app.ensure_results_cursor_visible();
         }
         SiblingCursorOutcome::AtRoot => app.notification.show("Already at root"),
         SiblingCursorOutcome::NoPath => app.notification.show("No path at cursor"),
@@ -10,11 +10,21 @@ pub(super) fn scroll_to_match(app: &mut App) {
         return;
     };
 
-    let target_line = current_match.line.min(u16::MAX as u32) as u16;
-    let target_col = current_match.col;
-    let match_len = current_match.len;
+    // Scrolling counts shown rows, which differ from lines when folded.
+    // Matches in the path view are on its own lines, and it has no cursor.
+    let paths = app.results_paths.is_active();
+    let target_line = if paths {
+        current_match.line
+    } else {
+        app.results_folds.row_of(current_match.line)
+    }
+    .min(u16::MAX as u32) as u16;
+    let target_col = current_match.x;
+    let match_len = current_match.width;
 
-    app.results_cursor.move_to_line(current_match.line);
+    if !paths {
+        app.results_cursor.move_to_line(current_match.line);
+    }
 
     // Vertical scrolling - Neovim-style with scroll margin
     let viewport_height = app.results_scroll.viewport_height;
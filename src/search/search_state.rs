@@ -13,6 +13,10 @@ pub struct Match {
     pub col: u16,
     /// Length of match in characters
     pub len: u16,
+    /// Display column of the match start, for horizontal scrolling
+    pub x: u16,
+    /// Display width of the match in terminal columns
+    pub width: u16,
 }
 
 /// Creates a TextArea configured for search input.
@@ -178,6 +182,13 @@ impl SearchState {
         }
     }
 
+    /// Find the matches again in `content`, which has changed although the
+    /// query has not
+    pub fn rematch(&mut self, content: &str) {
+        self.last_query.clear();
+        self.update_matches(content);
+    }
+
     /// Get the current match index (0-indexed)
     pub fn current_index(&self) -> usize {
         self.current_index
@@ -29,11 +29,15 @@ fn test_close_resets_state() {
             line: 0,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 1,
             col: 5,
             len: 4,
+            x: 5,
+            width: 4,
         },
     ];
     state.current_index = 1;
@@ -69,16 +73,22 @@ fn test_match_count_display_with_matches() {
             line: 0,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 1,
             col: 5,
             len: 4,
+            x: 5,
+            width: 4,
         },
         Match {
             line: 2,
             col: 10,
             len: 4,
+            x: 10,
+            width: 4,
         },
     ];
     state.current_index = 0;
@@ -102,16 +112,22 @@ fn test_next_match_wraps() {
             line: 0,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 5,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 10,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
     ];
     state.current_index = 0;
@@ -141,16 +157,22 @@ fn test_prev_match_wraps() {
             line: 0,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 5,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 10,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
     ];
     state.current_index = 0;
@@ -176,11 +198,15 @@ fn test_current_match() {
             line: 0,
             col: 5,
             len: 3,
+            x: 5,
+            width: 3,
         },
         Match {
             line: 2,
             col: 10,
             len: 3,
+            x: 10,
+            width: 3,
         },
     ];
     state.current_index = 0;
@@ -227,11 +253,15 @@ fn test_update_matches_resets_index() {
             line: 0,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
         Match {
             line: 1,
             col: 0,
             len: 4,
+            x: 0,
+            width: 4,
         },
     ];
     state.current_index = 1;
@@ -254,6 +284,8 @@ fn test_update_matches_skips_if_query_unchanged() {
         line: 99,
         col: 0,
         len: 4,
+        x: 0,
+        width: 4,
     }];
     state.current_index = 0;
 
@@ -265,6 +297,22 @@ fn test_update_matches_skips_if_query_unchanged() {
     assert_eq!(state.matches()[0].line, 99);
 }
 
+#[test]
+fn test_rematch_searches_new_content_with_the_same_query() {
+    let mut state = SearchState::new();
+    state.search_textarea_mut().insert_str("test");
+    state.update_matches("test one");
+
+    state.rematch(
+        "no
+test
+test",
+    );
+
+    assert_eq!(state.matches().len(), 2);
+    assert_eq!(state.matches()[0].line, 1);
+}
+
 // Feature: search-in-results, Property 3: Match count accuracy
 // *For any* search query and results content, the displayed match count
 // (current/total) should accurately reflect the actual number of matches
@@ -286,6 +334,8 @@ proptest! {
                 line: i as u32,
                 col: 0,
                 len: 3,
+                x: 0,
+                width: 3,
             });
         }
 
@@ -342,6 +392,8 @@ proptest! {
                 line: i as u32,
                 col: 0,
                 len: 3,
+                x: 0,
+                width: 3,
             });
         }
 
@@ -402,6 +454,8 @@ proptest! {
                 line: i as u32,
                 col: 0,
                 len: 3,
+                x: 0,
+                width: 3,
             });
         }
 
@@ -451,6 +505,8 @@ proptest! {
                 line: i as u32,
                 col: 0,
                 len: 3,
+                x: 0,
+                width: 3,
             });
         }
 
@@ -0,0 +1,29 @@
+//! Shell integration scripts printed by `jiq --shell-init <shell>`
+//!
+//! Each script binds Alt+J to a widget that pipes the command on the
+//! line (or the previous command) into jiq. When jiq is left with
+//! Shift+Enter, [`crate::app::OutputMode::Query`], the widget reads the
+//! query from `--emit-meta` and appends `| jq '<query>'` to the command
+//! line. Results printed with Enter are left alone.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Shell {
+    Zsh,
+    Bash,
+    Fish,
+}
+
+impl Shell {
+    /// The script to `eval` (or `source`) from the shell's rc file
+    pub fn init_script(self) -> &'static str {
+        match self {
+            Shell::Zsh => include_str!("shell_init/jiq.zsh"),
+            Shell::Bash => include_str!("shell_init/jiq.bash"),
+            Shell::Fish => include_str!("shell_init/jiq.fish"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "shell_init_tests.rs"]
+mod shell_init_tests;
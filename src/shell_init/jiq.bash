@@ -0,0 +1,30 @@
+# jiq shell integration for bash
+#
+# Add to ~/.bashrc:
+#   eval "$(jiq --shell-init bash)"
+#
+# Alt+J pipes the command on the line into jiq, or re-runs the previous
+# command when the line is empty. Leave jiq with Shift+Enter and
+# `| jq '<query>'` is appended to the command line, ready to run.
+
+_jiq_widget() {
+  local cmd=$READLINE_LINE
+  if [[ -z $cmd ]]; then
+    cmd=$(fc -ln -1 2>/dev/null)
+    cmd=${cmd#"${cmd%%[![:space:]]*}"}
+  fi
+  [[ -n $cmd ]] || return 0
+
+  local meta query
+  meta=$(mktemp "${TMPDIR:-/tmp}/jiq-meta.XXXXXX") || return 1
+  eval "$cmd" | jiq --emit-meta="$meta"
+  query=$(jq -r 'select(.output == "query" and .query != "") | .query | @sh' "$meta" 2>/dev/null)
+  rm -f "$meta"
+
+  if [[ -n $query ]]; then
+    READLINE_LINE="$cmd | jq $query"
+    READLINE_POINT=${#READLINE_LINE}
+  fi
+}
+
+bind -x '"\ej": _jiq_widget'
@@ -0,0 +1,27 @@
+# jiq shell integration for fish
+#
+# Add to ~/.config/fish/config.fish:
+#   jiq --shell-init fish | source
+#
+# Alt+J pipes the command on the line into jiq, or re-runs the previous
+# command when the line is empty. Leave jiq with Shift+Enter and
+# `| jq '<query>'` is appended to the command line, ready to run.
+
+function _jiq_widget
+    set -l cmd (commandline | string collect)
+    test -n "$cmd"; or set cmd $history[1]
+    test -n "$cmd"; or return 0
+
+    set -l meta (mktemp); or return 1
+    eval $cmd | jiq --emit-meta=$meta
+    set -l query (jq -r 'select(.output == "query" and .query != "") | .query | @sh' $meta 2>/dev/null | string collect)
+    rm -f $meta
+
+    if test -n "$query"
+        commandline -r -- "$cmd | jq $query"
+        commandline -f end-of-line
+    end
+    commandline -f repaint
+end
+
+bind \ej _jiq_widget
@@ -0,0 +1,45 @@
+//! Tests for shell_init
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use clap::ValueEnum;
+
+use super::*;
+
+#[test]
+fn every_script_binds_alt_j_and_reads_emit_meta() {
+    for shell in Shell::value_variants() {
+        let script = shell.init_script();
+        assert!(script.contains("\\ej"), "{:?}", shell);
+        assert!(script.contains("jiq --emit-meta="), "{:?}", shell);
+        assert!(
+            script.contains(r#"select(.output == "query""#),
+            "{:?}",
+            shell
+        );
+    }
+}
+
+#[test]
+fn shell_names_parse() {
+    assert_eq!(Shell::from_str("zsh", false), Ok(Shell::Zsh));
+    assert_eq!(Shell::from_str("bash", false), Ok(Shell::Bash));
+    assert_eq!(Shell::from_str("fish", false), Ok(Shell::Fish));
+    assert!(Shell::from_str("tcsh", false).is_err());
+}
+
+#[test]
+fn bash_script_is_valid_syntax() {
+    let Ok(mut child) = Command::new("bash").arg("-n").stdin(Stdio::piped()).spawn() else {
+        return;
+    };
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(Shell::Bash.init_script().as_bytes())
+        .unwrap();
+
+    assert!(child.wait().unwrap().success());
+}
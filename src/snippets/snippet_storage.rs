@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 
 use super::snippet_state::Snippet;
 
-const CONFIG_DIR: &str = "jiq";
 const SNIPPETS_FILE: &str = "snippets.toml";
 
 #[derive(Deserialize, Serialize)]
@@ -16,7 +15,7 @@ struct SnippetsFile {
 }
 
 pub fn snippets_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join(CONFIG_DIR).join(SNIPPETS_FILE))
+    crate::platform::config_dir().map(|p| p.join(SNIPPETS_FILE))
 }
 
 pub fn load_snippets() -> Vec<Snippet> {
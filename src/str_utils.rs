@@ -3,6 +3,15 @@
 //! `tui_textarea` reports cursor positions as character indices, but Rust
 //! string slicing requires byte offsets. Using these helpers at module
 //! boundaries lets downstream code treat positions uniformly as byte offsets.
+//! Also hosts the display-width helpers for column math, and small string
+//! formatting helpers shared across renderers.
+//!
+//! Widths are counted per grapheme cluster, the way ratatui draws text: an
+//! emoji built from several chars (a ZWJ family, a flag, a skin tone) takes
+//! the columns of the one glyph it draws as, all counted on its first char.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Convert a character index to its byte offset in `s`.
 ///
@@ -27,6 +36,53 @@ pub fn byte_pos_to_char_pos(s: &str, byte_pos: usize) -> usize {
     s.char_indices().take_while(|(b, _)| *b < byte_pos).count()
 }
 
+/// Display width of `s` in terminal columns.
+///
+/// ASCII text, almost all of jq's output, is measured by its length.
+pub fn display_width(s: &str) -> usize {
+    if s.is_ascii() {
+        s.len()
+    } else {
+        UnicodeWidthStr::width(s)
+    }
+}
+
+/// The columns each char of `s` takes: a grapheme's width sits on its
+/// first char and the rest take none.
+pub fn char_widths(s: &str) -> Vec<usize> {
+    let mut widths = Vec::with_capacity(s.len());
+    for grapheme in s.graphemes(true) {
+        widths.push(UnicodeWidthStr::width(grapheme));
+        widths.extend(std::iter::repeat_n(0, grapheme.chars().count() - 1));
+    }
+    widths
+}
+
+/// Index of the char drawn `column` columns right of char `from`, or the
+/// char count when that is past the end. A column in the right half of a
+/// wide glyph belongs to that glyph.
+pub fn char_at_column(s: &str, from: usize, column: usize) -> usize {
+    let widths = char_widths(s);
+    let mut used = 0;
+    for (index, width) in widths.iter().enumerate().skip(from) {
+        if *width > 0 && used + width > column {
+            return index;
+        }
+        used += width;
+    }
+    widths.len()
+}
+
+/// Whether char index `char_pos` of `s` starts a grapheme (or is the
+/// end), so a cursor there doesn't split what's drawn as one glyph.
+pub fn is_grapheme_boundary(s: &str, char_pos: usize) -> bool {
+    let byte_pos = char_pos_to_byte_pos(s, char_pos);
+    s.grapheme_indices(true)
+        .map(|(b, _)| b)
+        .chain(std::iter::once(s.len()))
+        .any(|b| b == byte_pos)
+}
+
 /// Truncate `s` from the front, keeping the trailing characters that fit
 /// within `max_width` display columns and prefixing with `…` when content
 /// is dropped. Display width is counted via `unicode_width` so CJK and
@@ -64,6 +120,47 @@ pub fn head_truncate_to_width(s: &str, max_width: usize) -> String {
     out
 }
 
+/// Truncate `s` from the back, keeping the leading characters that fit
+/// within `max_width` display columns and ending with `…` when content is
+/// dropped. The tail-keeping counterpart is [`head_truncate_to_width`].
+pub fn truncate_to_width(s: &str, max_width: usize) -> String {
+    use unicode_width::UnicodeWidthChar;
+
+    if max_width == 0 {
+        return String::new();
+    }
+    if UnicodeWidthStr::width(s) <= max_width {
+        return s.to_string();
+    }
+
+    let budget = max_width.saturating_sub(1);
+    let mut accumulated: usize = 0;
+    let mut out = String::new();
+    for ch in s.chars() {
+        let w = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if accumulated + w > budget {
+            break;
+        }
+        accumulated += w;
+        out.push(ch);
+    }
+    out.push('…');
+    out
+}
+
+/// Format a byte count using SI-style suffixes (B / KB / MB).
+pub fn fmt_bytes(n: usize) -> String {
+    const KB: usize = 1024;
+    const MB: usize = 1024 * 1024;
+    if n >= MB {
+        format!("{:.1} MB", n as f64 / MB as f64)
+    } else if n >= KB {
+        format!("{:.1} KB", n as f64 / KB as f64)
+    } else {
+        format!("{} B", n)
+    }
+}
+
 #[cfg(test)]
 #[path = "str_utils_tests.rs"]
 mod str_utils_tests;
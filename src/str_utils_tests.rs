@@ -198,3 +198,92 @@ mod head_truncate {
         assert!(out.starts_with('…'));
     }
 }
+
+mod truncate {
+    use super::*;
+
+    #[test]
+    fn passthrough_when_within_budget() {
+        assert_eq!(truncate_to_width("hello", 5), "hello");
+    }
+
+    #[test]
+    fn empty_when_budget_zero() {
+        assert_eq!(truncate_to_width("hello", 0), "");
+    }
+
+    #[test]
+    fn truncates_with_ellipsis_suffix() {
+        assert_eq!(truncate_to_width(r#"{"name":"Alice"}"#, 8), r#"{"name"…"#);
+    }
+
+    #[test]
+    fn handles_cjk_double_width() {
+        assert_eq!(truncate_to_width("中文中文", 5), "中文…");
+    }
+}
+
+mod display_width {
+    use super::*;
+
+    #[test]
+    fn ascii_is_its_length() {
+        assert_eq!(display_width(".user.name"), 10);
+    }
+
+    #[test]
+    fn cjk_and_emoji_are_two_columns() {
+        assert_eq!(display_width("名前"), 4);
+        assert_eq!(display_width("a🙂"), 3);
+    }
+}
+
+mod char_widths {
+    use super::*;
+
+    #[test]
+    fn one_entry_per_char() {
+        assert_eq!(char_widths("a名"), vec![1, 2]);
+    }
+
+    #[test]
+    fn emoji_sequence_width_sits_on_first_char() {
+        // Thumbs up with a skin tone modifier
+        assert_eq!(char_widths("👍🏽a"), vec![2, 0, 1]);
+    }
+}
+
+mod char_at_column {
+    use super::*;
+
+    #[test]
+    fn ascii_column_is_char_index() {
+        assert_eq!(char_at_column("abcdef", 0, 3), 3);
+        assert_eq!(char_at_column("abcdef", 2, 3), 5);
+    }
+
+    #[test]
+    fn right_half_of_wide_char_belongs_to_it() {
+        assert_eq!(char_at_column("名前x", 0, 1), 0);
+        assert_eq!(char_at_column("名前x", 0, 2), 1);
+        assert_eq!(char_at_column("名前x", 0, 4), 2);
+    }
+
+    #[test]
+    fn past_end_returns_char_count() {
+        assert_eq!(char_at_column("名前", 0, 10), 2);
+    }
+}
+
+mod is_grapheme_boundary {
+    use super::*;
+
+    #[test]
+    fn inside_emoji_sequence_is_not_a_boundary() {
+        let s = "a👍🏽b";
+        assert!(is_grapheme_boundary(s, 1));
+        assert!(!is_grapheme_boundary(s, 2));
+        assert!(is_grapheme_boundary(s, 3));
+        assert!(is_grapheme_boundary(s, 4));
+    }
+}
@@ -319,87 +319,89 @@ fn is_keyword(word: &str) -> bool {
     )
 }
 
+/// Builtins highlighted as functions; each has tooltip content
+pub(crate) const BUILTIN_FUNCTIONS: &[&str] = &[
+    "type",
+    "length",
+    "keys",
+    "keys_unsorted",
+    "values",
+    "empty",
+    "has",
+    "in",
+    "contains",
+    "inside",
+    "getpath",
+    "setpath",
+    "delpaths",
+    "map",
+    "select",
+    "sort",
+    "sort_by",
+    "reverse",
+    "unique",
+    "unique_by",
+    "group_by",
+    "min",
+    "max",
+    "min_by",
+    "max_by",
+    "add",
+    "any",
+    "all",
+    "flatten",
+    "range",
+    "first",
+    "last",
+    "nth",
+    "indices",
+    "index",
+    "rindex",
+    "to_entries",
+    "from_entries",
+    "with_entries",
+    "tostring",
+    "tonumber",
+    "toarray",
+    "split",
+    "join",
+    "ltrimstr",
+    "rtrimstr",
+    "startswith",
+    "endswith",
+    "test",
+    "match",
+    "capture",
+    "sub",
+    "gsub",
+    "ascii_downcase",
+    "ascii_upcase",
+    "floor",
+    "ceil",
+    "round",
+    "sqrt",
+    "pow",
+    "now",
+    "fromdateiso8601",
+    "todateiso8601",
+    "fromdate",
+    "todate",
+    "input",
+    "inputs",
+    "debug",
+    "error",
+    "recurse",
+    "walk",
+    "paths",
+    "leaf_paths",
+    "limit",
+    "until",
+    "while",
+    "repeat",
+];
+
 fn is_builtin_function(word: &str) -> bool {
-    matches!(
-        word,
-        "type"
-            | "length"
-            | "keys"
-            | "keys_unsorted"
-            | "values"
-            | "empty"
-            | "has"
-            | "in"
-            | "contains"
-            | "inside"
-            | "getpath"
-            | "setpath"
-            | "delpaths"
-            | "map"
-            | "select"
-            | "sort"
-            | "sort_by"
-            | "reverse"
-            | "unique"
-            | "unique_by"
-            | "group_by"
-            | "min"
-            | "max"
-            | "min_by"
-            | "max_by"
-            | "add"
-            | "any"
-            | "all"
-            | "flatten"
-            | "range"
-            | "first"
-            | "last"
-            | "nth"
-            | "indices"
-            | "index"
-            | "rindex"
-            | "to_entries"
-            | "from_entries"
-            | "with_entries"
-            | "tostring"
-            | "tonumber"
-            | "toarray"
-            | "split"
-            | "join"
-            | "ltrimstr"
-            | "rtrimstr"
-            | "startswith"
-            | "endswith"
-            | "test"
-            | "match"
-            | "capture"
-            | "sub"
-            | "gsub"
-            | "ascii_downcase"
-            | "ascii_upcase"
-            | "floor"
-            | "ceil"
-            | "round"
-            | "sqrt"
-            | "pow"
-            | "now"
-            | "fromdateiso8601"
-            | "todateiso8601"
-            | "fromdate"
-            | "todate"
-            | "input"
-            | "inputs"
-            | "debug"
-            | "error"
-            | "recurse"
-            | "walk"
-            | "paths"
-            | "leaf_paths"
-            | "limit"
-            | "until"
-            | "while"
-            | "repeat"
-    )
+    BUILTIN_FUNCTIONS.contains(&word)
 }
 
 /// Checks if a word is a jq variable (starts with $).
@@ -7,6 +7,7 @@
 
 use ratatui::style::Modifier;
 use ratatui::text::Span;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::theme;
 
@@ -18,14 +19,16 @@ use crate::theme;
 /// # Parameters
 /// - `spans`: Complete styled text spans
 /// - `scroll_offset`: Horizontal scroll position (characters from left)
-/// - `viewport_width`: Width of visible area in characters
+/// - `viewport_width`: Width of visible area in terminal columns
 ///
 /// # Returns
-/// Vector of spans containing only text visible in the viewport.
+/// Vector of spans containing only text visible in the viewport. A wide
+/// character that would only half fit at the right edge is left out.
 ///
 /// # Example
 /// For text "Hello World" with scroll_offset=3 and viewport_width=5,
-/// returns spans for "lo Wo".
+/// returns spans for "lo Wo"; for "名前です" with scroll_offset=0 and
+/// viewport_width=5, returns spans for "名前".
 pub fn extract_visible_spans(
     spans: &[Span<'static>],
     scroll_offset: usize,
@@ -33,7 +36,8 @@ pub fn extract_visible_spans(
 ) -> Vec<Span<'static>> {
     let mut result = Vec::new();
     let mut current_col = 0;
-    let end_col = scroll_offset + viewport_width;
+    let text: String = spans.iter().map(|span| span.content.as_ref()).collect();
+    let end_col = crate::str_utils::char_at_column(&text, scroll_offset, viewport_width);
 
     for span in spans {
         let span_len = span.content.chars().count();
@@ -71,7 +75,8 @@ pub fn extract_visible_spans(
 ///
 /// Splits the span containing the cursor and applies a REVERSED style modifier
 /// to the cursor character for visibility. This makes the cursor character appear
-/// with inverted colors.
+/// with inverted colors. Marks combined into the cursor character, such as an
+/// emoji's skin tone, are reversed with it.
 ///
 /// # Parameters
 /// - `spans`: Styled text spans
@@ -107,14 +112,17 @@ pub fn insert_cursor_into_spans(
             result.push(Span::styled(before, span.style));
         }
 
-        let cursor_char = span_chars[cursor_in_span].to_string();
+        // Reverse the whole grapheme so an emoji sequence stays one glyph
+        let rest: String = span_chars[cursor_in_span..].iter().collect();
+        let cursor_grapheme = rest.graphemes(true).next().unwrap_or_default();
+        let cursor_end = cursor_in_span + cursor_grapheme.chars().count();
         result.push(Span::styled(
-            cursor_char,
+            cursor_grapheme.to_string(),
             span.style.add_modifier(Modifier::REVERSED),
         ));
 
-        if cursor_in_span + 1 < span_len {
-            let after: String = span_chars[cursor_in_span + 1..].iter().collect();
+        if cursor_end < span_len {
+            let after: String = span_chars[cursor_end..].iter().collect();
             result.push(Span::styled(after, span.style));
         }
 
@@ -166,6 +174,64 @@ pub fn highlight_bracket_pairs(
     )
 }
 
+/// Underlines the chars in each `start..end` range in the warning color.
+///
+/// Used for query lints: the text keeps its syntax colors and only gains
+/// the underline, so a lint reads as "look here" rather than as an error.
+///
+/// # Parameters
+/// - `spans`: Styled text spans to process
+/// - `ranges`: Character ranges to underline
+///
+/// # Returns
+/// Vector of spans with the characters in the ranges underlined.
+pub fn underline_ranges(
+    spans: Vec<Span<'static>>,
+    ranges: &[(usize, usize)],
+) -> Vec<Span<'static>> {
+    if ranges.is_empty() {
+        return spans;
+    }
+    let underlined = |pos: usize| ranges.iter().any(|&(start, end)| pos >= start && pos < end);
+
+    let mut result = Vec::new();
+    let mut current_pos = 0;
+    for span in spans {
+        let mut run = String::new();
+        let mut run_underlined = false;
+        for c in span.content.chars() {
+            let here = underlined(current_pos);
+            if here != run_underlined && !run.is_empty() {
+                result.push(underline_run(
+                    std::mem::take(&mut run),
+                    span.style,
+                    run_underlined,
+                ));
+            }
+            run_underlined = here;
+            run.push(c);
+            current_pos += 1;
+        }
+        if !run.is_empty() {
+            result.push(underline_run(run, span.style, run_underlined));
+        }
+    }
+    result
+}
+
+fn underline_run(text: String, style: ratatui::style::Style, underlined: bool) -> Span<'static> {
+    if underlined {
+        Span::styled(
+            text,
+            style
+                .add_modifier(Modifier::UNDERLINED)
+                .underline_color(theme::input::syntax_error_warning()),
+        )
+    } else {
+        Span::styled(text, style)
+    }
+}
+
 /// Applies modifiers to characters at specific positions while preserving existing style.
 ///
 /// This helper function splits spans as needed and adds the given modifiers
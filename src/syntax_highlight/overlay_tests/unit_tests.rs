@@ -109,7 +109,7 @@ fn test_extract_visible_spans_unicode() {
     let visible = extract_visible_spans(&spans, 3, 5);
 
     assert_eq!(visible.len(), 1);
-    assert_eq!(visible[0].content, "lo👋Wo");
+    assert_eq!(visible[0].content, "lo👋W");
 }
 
 #[test]
@@ -383,3 +383,84 @@ fn test_apply_modifier_at_positions_consecutive_positions() {
     assert!(result[2].style.add_modifier.contains(Modifier::UNDERLINED));
     assert_eq!(result[3].content, "d");
 }
+
+#[test]
+fn test_underline_ranges_splits_spans_at_range_edges() {
+    let spans = vec![
+        Span::styled(".foo", Style::default().fg(Color::Red)),
+        Span::styled("-bar", Style::default().fg(Color::Blue)),
+    ];
+
+    let underlined = underline_ranges(spans, &[(2, 6)]);
+
+    let contents: Vec<&str> = underlined.iter().map(|s| s.content.as_ref()).collect();
+    assert_eq!(contents, vec![".f", "oo", "-b", "ar"]);
+    assert!(
+        !underlined[0]
+            .style
+            .add_modifier
+            .contains(Modifier::UNDERLINED)
+    );
+    assert!(
+        underlined[1]
+            .style
+            .add_modifier
+            .contains(Modifier::UNDERLINED)
+    );
+    assert!(
+        underlined[2]
+            .style
+            .add_modifier
+            .contains(Modifier::UNDERLINED)
+    );
+    assert!(
+        !underlined[3]
+            .style
+            .add_modifier
+            .contains(Modifier::UNDERLINED)
+    );
+    assert_eq!(underlined[1].style.fg, Some(Color::Red));
+    assert_eq!(
+        underlined[1].style.underline_color,
+        Some(theme::input::syntax_error_warning())
+    );
+}
+
+#[test]
+fn test_underline_ranges_without_ranges_keeps_spans() {
+    let spans = vec![Span::styled(".foo", Style::default().fg(Color::Red))];
+
+    assert_eq!(underline_ranges(spans.clone(), &[]), spans);
+}
+
+#[test]
+fn test_extract_visible_spans_counts_wide_chars_as_two_columns() {
+    let spans = vec![Span::styled("名前です", Style::default().fg(Color::Red))];
+
+    let visible = extract_visible_spans(&spans, 0, 5);
+
+    assert_eq!(visible.len(), 1);
+    assert_eq!(visible[0].content, "名前");
+}
+
+#[test]
+fn test_extract_visible_spans_keeps_emoji_sequences_whole() {
+    // Thumbs up with a skin tone: two chars, one two-column glyph
+    let spans = vec![Span::styled("a👍🏽b", Style::default())];
+
+    let visible = extract_visible_spans(&spans, 0, 3);
+
+    assert_eq!(visible[0].content, "a👍🏽");
+}
+
+#[test]
+fn test_insert_cursor_reverses_whole_emoji_sequence() {
+    let spans = vec![Span::styled("a👍🏽b", Style::default())];
+
+    let result = insert_cursor_into_spans(spans, 1);
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[1].content, "👍🏽");
+    assert!(result[1].style.add_modifier.contains(Modifier::REVERSED));
+    assert_eq!(result[2].content, "b");
+}
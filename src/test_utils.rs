@@ -1,10 +1,18 @@
 #[cfg(test)]
 pub mod test_helpers {
     use crate::app::App;
-    use crate::config::Config;
+    use crate::autocomplete::completion_usage::CompletionUsage;
+    use crate::config::{Config, SuggestionOrder};
     use crate::history::HistoryState;
     use crate::input::FileLoader;
+    use crate::notification::OnboardingTour;
+    use crate::plugins::PluginRegistry;
+    use crate::results::result_diff::DiffHighlight;
+    use crate::scratch::Scratch;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::backend::TestBackend;
+    use ratatui::layout::Rect;
+    use ratatui::{Frame, Terminal};
 
     pub const TEST_JSON: &str = r#"{
         "name": "test",
@@ -19,16 +27,7 @@ pub mod test_helpers {
     /// This helper creates a FileLoader that immediately has the JSON available,
     /// avoiding the need for actual file I/O or background threads in tests.
     pub fn create_test_loader(json: String) -> FileLoader {
-        use crate::input::loader::{LoaderSource, LoadingState};
-        use std::sync::mpsc::channel;
-        let (tx, rx) = channel();
-        // Send the result immediately so poll() will return it
-        let _ = tx.send(Ok(json));
-        FileLoader {
-            state: LoadingState::Loading,
-            rx: Some(rx),
-            source: LoaderSource::File,
-        }
+        FileLoader::from_json(json)
     }
 
     pub fn test_app(json: &str) -> App {
@@ -36,8 +35,19 @@ pub mod test_helpers {
         let mut app = App::new_with_loader(loader, &Config::default());
         // Poll the loader to complete loading
         app.poll_file_loader();
+        // Suggestions wait for the input analysis; have it done up front
+        if let Some(query) = &app.query {
+            query.executor.all_field_names();
+            query.executor.all_string_values();
+        }
         // Disable history persistence to avoid polluting real history file
         app.history = HistoryState::empty();
+        app.completion_usage = CompletionUsage::empty(SuggestionOrder::Usage);
+        app.onboarding = OnboardingTour::empty();
+        app.scratch = Scratch::empty();
+        app.plugins = PluginRegistry::empty();
+        // A fading flash would make renders depend on timing
+        app.results_diff = DiffHighlight::new(false);
         app
     }
 
@@ -59,6 +69,21 @@ pub mod test_helpers {
         app
     }
 
+    /// Draw `render_popup` centered over a `width` x `height` screen and
+    /// return the screen as the test backend prints it
+    pub fn render_popup_to_string(
+        app: &App,
+        width: u16,
+        height: u16,
+        render_popup: fn(&App, &mut Frame, Rect),
+    ) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|f| render_popup(app, f, Rect::new(0, 0, width, height)))
+            .unwrap();
+        terminal.backend().to_string()
+    }
+
     /// Wait for async query to complete by polling
     ///
     /// Polls query_state.poll_response() until query completes or timeout.
@@ -81,6 +106,18 @@ pub mod test_helpers {
         false
     }
 
+    /// Write an executable `jiq-plugin-<name>` shell script to `dir` that
+    /// runs `body` with the subcommand in `$1`
+    #[cfg(unix)]
+    pub fn write_plugin(dir: &std::path::Path, name: &str, body: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join(format!("jiq-plugin-{}", name));
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
     /// Execute async query and wait for completion
     ///
     /// Helper for tests that need to wait for async query results.
@@ -1,10 +1,10 @@
 //! Centralized theme configuration for all UI components.
 //!
-//! Colors and styles are resolved at runtime from a single [`Theme`] held in a
-//! global [`OnceLock`]. Call sites use lowercase accessor functions
-//! (`theme::module::name()`); the active theme is chosen once at startup via
-//! [`init`]. When the theme is read before [`init`] runs, it falls back to
-//! [`galaxy_dark`] so tests stay deterministic.
+//! Colors and styles are resolved at runtime from the active [`Theme`], one of
+//! the two built-ins held in global [`LazyLock`]s. Call sites use lowercase
+//! accessor functions (`theme::module::name()`); the active theme is chosen
+//! at startup via [`set`] and may be switched later with `:theme`. Until
+//! [`set`] runs it is [`galaxy_dark`], so tests stay deterministic.
 //!
 //! When adding or modifying UI components:
 //! - Add new fields to the appropriate sub-struct and accessor module
@@ -12,9 +12,11 @@
 //! - Do NOT hardcode `Color::*` values directly in render files
 
 use ratatui::style::{Color, Modifier, Style};
-use std::sync::OnceLock;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 pub mod detect;
+pub mod monochrome;
 
 mod galaxy;
 pub use galaxy::{galaxy_dark, galaxy_light};
@@ -22,15 +24,30 @@ pub use galaxy::{galaxy_dark, galaxy_light};
 mod structs;
 pub use structs::*;
 
-static THEME: OnceLock<Theme> = OnceLock::new();
+static DARK: LazyLock<Theme> = LazyLock::new(galaxy_dark);
+static LIGHT: LazyLock<Theme> = LazyLock::new(galaxy_light);
+static LIGHT_ACTIVE: AtomicBool = AtomicBool::new(false);
 
-/// Install the active theme. The first call wins; later calls are ignored.
-pub fn init(t: Theme) {
-    let _ = THEME.set(t);
+/// Make `resolved` the active theme; the next frame is drawn with it
+pub fn set(resolved: ResolvedTheme) {
+    LIGHT_ACTIVE.store(resolved == ResolvedTheme::Light, Ordering::Relaxed);
+}
+
+/// The active built-in theme
+pub fn active() -> ResolvedTheme {
+    if LIGHT_ACTIVE.load(Ordering::Relaxed) {
+        ResolvedTheme::Light
+    } else {
+        ResolvedTheme::Dark
+    }
 }
 
 fn theme() -> &'static Theme {
-    THEME.get_or_init(galaxy_dark)
+    if LIGHT_ACTIVE.load(Ordering::Relaxed) {
+        &LIGHT
+    } else {
+        &DARK
+    }
 }
 
 /// Core color palette - shared base colors.
@@ -133,6 +150,9 @@ pub mod input {
     pub fn query_unfocused() -> Color {
         super::theme().input.query_unfocused
     }
+    pub fn ghost_text() -> Color {
+        super::theme().input.ghost_text
+    }
     pub fn cursor() -> Style {
         super::theme().input.cursor
     }
@@ -229,6 +249,27 @@ pub mod results {
     pub fn path_at_cursor() -> Color {
         super::theme().results.path_at_cursor
     }
+    pub fn fold_marker() -> Color {
+        super::theme().results.fold_marker
+    }
+    pub fn diff_added_bg() -> Color {
+        super::theme().results.diff_added_bg
+    }
+    pub fn diff_removed_fg() -> Color {
+        super::theme().results.diff_removed_fg
+    }
+    pub fn pin_marker() -> Color {
+        super::theme().results.pin_marker
+    }
+    pub fn table_header() -> Color {
+        super::theme().results.table_header
+    }
+    pub fn chart_bar() -> Color {
+        super::theme().results.chart_bar
+    }
+    pub fn stage_unchanged_fg() -> Color {
+        super::theme().results.stage_unchanged_fg
+    }
     pub fn hint_key() -> Color {
         super::theme().results.hint_key
     }
@@ -238,6 +279,9 @@ pub mod results {
     pub fn spinner_colors() -> &'static [Color] {
         &super::theme().results.spinner_colors
     }
+    pub fn match_colors() -> &'static [Color] {
+        &super::theme().results.match_colors
+    }
     pub fn jq_colors() -> [Color; 8] {
         super::theme().results.jq_colors
     }
@@ -385,6 +429,13 @@ pub mod history {
     pub fn delete_button_hover() -> Color {
         super::theme().history.delete_button_hover
     }
+    pub fn ai_marker() -> Color {
+        super::theme().history.ai_marker
+    }
+
+    pub fn pin_marker() -> Color {
+        super::theme().history.pin_marker
+    }
 }
 
 /// Snippets popup styles.
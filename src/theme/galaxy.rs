@@ -43,6 +43,7 @@ pub fn galaxy_dark() -> Theme {
             tooltip_hint: Color::Rgb(198, 120, 221),
             unfocused_hint: Color::Rgb(90, 92, 119),
             query_unfocused: Color::Rgb(90, 92, 119),
+            ghost_text: Color::Rgb(90, 92, 119),
             cursor: Style::new().add_modifier(Modifier::REVERSED),
         },
         results: ResultsTheme {
@@ -88,6 +89,13 @@ pub fn galaxy_dark() -> Theme {
             stale_modifier: Modifier::DIM,
             path_at_cursor_separator: Color::Rgb(90, 92, 119),
             path_at_cursor: Color::Rgb(189, 147, 249),
+            fold_marker: Color::Rgb(90, 92, 119),
+            diff_added_bg: Color::Rgb(28, 58, 44),
+            diff_removed_fg: Color::Rgb(255, 85, 119),
+            pin_marker: Color::Rgb(241, 250, 140),
+            table_header: Color::Rgb(0, 217, 255),
+            chart_bar: Color::Rgb(189, 147, 249),
+            stage_unchanged_fg: Color::Rgb(108, 110, 138),
             hint_key: Color::Rgb(0, 217, 255),
             hint_description: Style::new()
                 .fg(Color::Rgb(0, 217, 255))
@@ -102,6 +110,14 @@ pub fn galaxy_dark() -> Theme {
                 Color::Rgb(198, 120, 221),
                 Color::Rgb(224, 108, 117),
             ],
+            match_colors: vec![
+                Color::Rgb(255, 217, 61),
+                Color::Rgb(107, 203, 119),
+                Color::Rgb(0, 217, 255),
+                Color::Rgb(255, 107, 157),
+                Color::Rgb(189, 147, 249),
+                Color::Rgb(255, 184, 108),
+            ],
             jq_colors: [
                 Color::Rgb(130, 133, 158), // null - muted gray
                 Color::Rgb(224, 108, 117), // false - soft red
@@ -170,6 +186,8 @@ pub fn galaxy_dark() -> Theme {
             search_bg: Color::Rgb(26, 26, 46),
             delete_button: Color::Rgb(130, 133, 158),
             delete_button_hover: Color::Rgb(255, 107, 107),
+            ai_marker: Color::Rgb(189, 147, 249),
+            pin_marker: Color::Rgb(241, 250, 140),
         },
         snippets: SnippetsTheme {
             border: Color::Rgb(107, 203, 119),
@@ -363,6 +381,7 @@ pub fn galaxy_light() -> Theme {
             tooltip_hint: Color::Rgb(162, 28, 175),
             unfocused_hint: Color::Rgb(118, 119, 140),
             query_unfocused: Color::Rgb(118, 119, 140),
+            ghost_text: Color::Rgb(118, 119, 140),
             cursor: Style::new().add_modifier(Modifier::REVERSED),
         },
         results: ResultsTheme {
@@ -408,6 +427,13 @@ pub fn galaxy_light() -> Theme {
             stale_modifier: Modifier::empty(),
             path_at_cursor_separator: Color::Rgb(118, 119, 140),
             path_at_cursor: Color::Rgb(136, 57, 239),
+            fold_marker: Color::Rgb(118, 119, 140),
+            diff_added_bg: Color::Rgb(212, 240, 222),
+            diff_removed_fg: Color::Rgb(200, 30, 70),
+            pin_marker: Color::Rgb(176, 112, 0),
+            table_header: Color::Rgb(14, 116, 144),
+            chart_bar: Color::Rgb(136, 57, 239),
+            stage_unchanged_fg: Color::Rgb(162, 163, 180),
             hint_key: Color::Rgb(14, 116, 144),
             hint_description: Style::new().fg(Color::Rgb(89, 99, 110)),
             spinner_colors: vec![
@@ -420,6 +446,14 @@ pub fn galaxy_light() -> Theme {
                 Color::Rgb(162, 28, 175),
                 Color::Rgb(210, 15, 57),
             ],
+            match_colors: vec![
+                Color::Rgb(255, 232, 130),
+                Color::Rgb(190, 235, 200),
+                Color::Rgb(175, 225, 240),
+                Color::Rgb(250, 200, 220),
+                Color::Rgb(220, 205, 250),
+                Color::Rgb(255, 214, 170),
+            ],
             jq_colors: [
                 Color::Rgb(96, 99, 128),  // null - muted gray
                 Color::Rgb(210, 15, 57),  // false - red
@@ -486,6 +520,8 @@ pub fn galaxy_light() -> Theme {
             search_bg: Color::Rgb(255, 255, 255),
             delete_button: Color::Rgb(96, 99, 128),
             delete_button_hover: Color::Rgb(210, 15, 57),
+            ai_marker: Color::Rgb(136, 57, 239),
+            pin_marker: Color::Rgb(176, 112, 0),
         },
         snippets: SnippetsTheme {
             border: Color::Rgb(26, 127, 55),
@@ -0,0 +1,218 @@
+//! Monochrome mode: no colors, only bold, underline, reverse and dim.
+//!
+//! Turned on by `NO_COLOR`, `TERM=dumb` or `monochrome = true` under
+//! `[theme]`. Each frame is drawn with the theme as usual and then
+//! [`strip_colors`] replaces every color with the attribute standing in for
+//! it, so what a color meant survives on basic terminals and for readers
+//! who can't tell the colors apart.
+
+use std::ffi::OsStr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+
+use super::Theme;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// `JQ_COLORS` for the results pane: default colors, with arrays, objects
+/// and keys bold as in the themes
+pub const JQ_COLORS: &str = "0;39:0;39:0;39:0;39:0;39:1;39:1;39:1;39";
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Whether to draw without colors. `setting` is `monochrome` from the
+/// config file and wins when set; otherwise a non-empty `NO_COLOR` or
+/// `TERM=dumb` asks for it.
+pub fn wanted(setting: Option<bool>, no_color: Option<&OsStr>, term: Option<&OsStr>) -> bool {
+    setting.unwrap_or_else(|| {
+        no_color.is_some_and(|value| !value.is_empty()) || term == Some(OsStr::new("dumb"))
+    })
+}
+
+/// Replace the colors in `buffer` with attributes
+///
+/// Selected and matched cells are reversed, hovered ones underlined;
+/// errors are bold and underlined, accents such as the focused border and
+/// key hints bold, muted text dim. Other colors are dropped.
+pub fn strip_colors(buffer: &mut Buffer) {
+    let stand_ins = stand_ins();
+    for cell in buffer.content.iter_mut() {
+        let modifier = match stand_ins.background(cell.bg) {
+            Some(modifier) if !modifier.is_empty() => modifier,
+            _ => stand_ins.foreground(cell.fg).unwrap_or_default(),
+        };
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        cell.modifier |= modifier;
+    }
+}
+
+/// The attribute each color of the theme stands in for
+struct StandIns {
+    backgrounds: Vec<(Color, Modifier)>,
+    foregrounds: Vec<(Color, Modifier)>,
+}
+
+impl StandIns {
+    fn background(&self, color: Color) -> Option<Modifier> {
+        lookup(&self.backgrounds, color)
+    }
+
+    fn foreground(&self, color: Color) -> Option<Modifier> {
+        lookup(&self.foregrounds, color)
+    }
+}
+
+fn lookup(stand_ins: &[(Color, Modifier)], color: Color) -> Option<Modifier> {
+    stand_ins
+        .iter()
+        .find(|(c, _)| *c == color)
+        .map(|(_, modifier)| *modifier)
+}
+
+fn stand_ins() -> &'static StandIns {
+    static STAND_INS: OnceLock<StandIns> = OnceLock::new();
+    STAND_INS.get_or_init(|| stand_ins_for(super::theme()))
+}
+
+/// The first entry for a color wins, so fills and plain text come first:
+/// a highlight that happens to share a popup's background isn't drawn
+/// over the whole popup.
+fn stand_ins_for(t: &Theme) -> StandIns {
+    let none = Modifier::empty();
+    let reversed = Modifier::REVERSED;
+    let underlined = Modifier::UNDERLINED;
+    let bold = Modifier::BOLD;
+    let error = Modifier::BOLD | Modifier::UNDERLINED;
+
+    let fills = [
+        Color::Reset,
+        t.palette.bg_dark,
+        t.palette.bg_surface,
+        t.results.background,
+        t.search.background,
+        t.help.background,
+        t.history.background,
+        t.history.item_normal_bg,
+        t.history.search_bg,
+        t.snippets.background,
+        t.snippets.item_normal_bg,
+        t.snippets.field_bg,
+        t.snippets.search_bg,
+        t.save.input_bg,
+        t.ai.background,
+        t.autocomplete.background,
+        t.autocomplete.item_normal_bg,
+        t.tooltip.background,
+        t.notification.info.bg,
+        t.notification.warning.bg,
+        t.notification.error.bg,
+    ];
+    let mut backgrounds: Vec<(Color, Modifier)> = fills.iter().map(|c| (*c, none)).collect();
+    backgrounds.push((t.results.current_match_bg, reversed | bold));
+    for selected in [
+        t.palette.bg_highlight,
+        t.results.match_highlight_bg,
+        t.results.cursor_line_bg,
+        t.results.visual_selection_bg,
+        t.history.item_selected_bg,
+        t.snippets.item_selected_bg,
+        t.ai.suggestion_selected_bg,
+        t.autocomplete.item_selected_bg,
+    ] {
+        backgrounds.push((selected, reversed));
+    }
+    for matched in &t.results.match_colors {
+        backgrounds.push((*matched, reversed));
+    }
+    for badge in [
+        t.results.badge_syntax_error,
+        t.results.badge_empty_result,
+        t.results.badge_back,
+        t.results.badge_back_hover,
+        t.search.badge_no_matches,
+        t.search.badge_match_count,
+        t.search.badge_match_count_confirmed,
+        t.help.tab_active,
+        t.syntax.bracket_match_style,
+    ] {
+        if let Some(bg) = badge.bg {
+            backgrounds.push((bg, reversed));
+        }
+    }
+    for hovered in [
+        t.palette.bg_hover,
+        t.results.hovered_line_bg,
+        t.results.diff_added_bg,
+        t.help.tab_hover_bg,
+        t.snippets.item_hovered_bg,
+        t.ai.suggestion_hovered_bg,
+    ] {
+        backgrounds.push((hovered, underlined));
+    }
+
+    let mut foregrounds = vec![(Color::Reset, none), (t.palette.text, none)];
+    for failed in [
+        t.palette.error,
+        t.input.border_error,
+        t.results.border_error,
+        t.results.result_error,
+        t.results.error_summary,
+        t.results.diff_removed_fg,
+        t.save.error,
+        t.ai.error_icon,
+        t.notification.error.fg,
+        t.notification.error.border,
+    ] {
+        foregrounds.push((failed, error));
+    }
+    for accent in [
+        t.input.mode_insert,
+        t.input.mode_normal,
+        t.input.mode_operator,
+        t.input.mode_char_search,
+        t.results.border_focused,
+        t.results.border_warning,
+        t.results.hint_key,
+        t.results.cursor_indicator_fg,
+        t.search.border_active,
+        t.help_line.key,
+        t.snippets.hint_key,
+        t.save.hint_key,
+        t.syntax.keyword,
+        t.syntax.function,
+        t.palette.warning,
+    ] {
+        foregrounds.push((accent, bold));
+    }
+    for accent in [t.help.key, t.help.title, t.help.section_header] {
+        if let Some(fg) = accent.fg {
+            foregrounds.push((fg, bold));
+        }
+    }
+    for muted in [
+        t.palette.text_dim,
+        t.palette.text_muted,
+        t.results.stage_unchanged_fg,
+    ] {
+        foregrounds.push((muted, Modifier::DIM));
+    }
+
+    StandIns {
+        backgrounds,
+        foregrounds,
+    }
+}
+
+#[cfg(test)]
+#[path = "monochrome_tests.rs"]
+mod monochrome_tests;
@@ -0,0 +1,162 @@
+//! Tests for theme/monochrome
+
+use std::ffi::OsStr;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+use super::*;
+use crate::theme::galaxy_dark;
+
+fn strip_one(style: Style) -> (Color, Color, Modifier) {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+    buffer.set_string(0, 0, "x", style);
+    strip_colors(&mut buffer);
+    let cell = &buffer[(0, 0)];
+    (cell.fg, cell.bg, cell.modifier)
+}
+
+#[test]
+fn off_without_a_reason() {
+    assert!(!wanted(None, None, Some(OsStr::new("xterm-256color"))));
+}
+
+#[test]
+fn no_color_turns_it_on() {
+    assert!(wanted(None, Some(OsStr::new("1")), None));
+}
+
+#[test]
+fn empty_no_color_is_ignored() {
+    assert!(!wanted(None, Some(OsStr::new("")), None));
+}
+
+#[test]
+fn dumb_terminal_turns_it_on() {
+    assert!(wanted(None, None, Some(OsStr::new("dumb"))));
+}
+
+#[test]
+fn config_setting_wins() {
+    assert!(!wanted(
+        Some(false),
+        Some(OsStr::new("1")),
+        Some(OsStr::new("dumb"))
+    ));
+    assert!(wanted(Some(true), None, None));
+}
+
+#[test]
+fn colors_are_dropped() {
+    let (fg, bg, modifier) =
+        strip_one(Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Rgb(4, 5, 6)));
+
+    assert_eq!((fg, bg), (Color::Reset, Color::Reset));
+    assert_eq!(modifier, Modifier::empty());
+}
+
+#[test]
+fn selection_is_reversed() {
+    let t = galaxy_dark();
+
+    let (_, bg, modifier) = strip_one(Style::new().bg(t.results.cursor_line_bg));
+
+    assert_eq!(bg, Color::Reset);
+    assert_eq!(modifier, Modifier::REVERSED);
+}
+
+#[test]
+fn current_match_is_reversed_and_bold() {
+    let t = galaxy_dark();
+
+    let (_, _, modifier) = strip_one(Style::new().bg(t.results.current_match_bg));
+
+    assert_eq!(modifier, Modifier::REVERSED | Modifier::BOLD);
+}
+
+#[test]
+fn hover_is_underlined() {
+    let t = galaxy_dark();
+
+    let (_, _, modifier) = strip_one(Style::new().bg(t.results.hovered_line_bg));
+
+    assert_eq!(modifier, Modifier::UNDERLINED);
+}
+
+#[test]
+fn errors_are_bold_and_underlined() {
+    let t = galaxy_dark();
+
+    let (fg, _, modifier) = strip_one(Style::new().fg(t.palette.error));
+
+    assert_eq!(fg, Color::Reset);
+    assert_eq!(modifier, Modifier::BOLD | Modifier::UNDERLINED);
+}
+
+#[test]
+fn accent_on_a_popup_background_is_bold() {
+    let t = galaxy_dark();
+
+    let (_, _, modifier) = strip_one(Style::new().fg(t.results.hint_key).bg(t.help.background));
+
+    assert_eq!(modifier, Modifier::BOLD);
+}
+
+#[test]
+fn popup_background_is_not_reversed() {
+    let t = galaxy_dark();
+
+    let (_, _, modifier) = strip_one(Style::new().fg(t.palette.text).bg(t.palette.bg_surface));
+
+    assert_eq!(modifier, Modifier::empty());
+}
+
+#[test]
+fn muted_text_is_dim() {
+    let t = galaxy_dark();
+
+    let (_, _, modifier) = strip_one(Style::new().fg(t.results.stage_unchanged_fg));
+
+    assert_eq!(modifier, Modifier::DIM);
+}
+
+#[test]
+fn existing_attributes_are_kept() {
+    let (_, _, modifier) = strip_one(
+        Style::new()
+            .fg(Color::Rgb(1, 2, 3))
+            .add_modifier(Modifier::ITALIC),
+    );
+
+    assert_eq!(modifier, Modifier::ITALIC);
+}
+
+#[test]
+fn rendered_app_has_no_colors_left() {
+    use crate::test_utils::test_helpers::app_with_query;
+
+    let mut app = app_with_query(".services");
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| {
+            app.render(frame);
+            strip_colors(frame.buffer_mut());
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    assert!(
+        buffer
+            .content
+            .iter()
+            .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset)
+    );
+    // The focused query border stands out
+    assert!(
+        buffer
+            .content
+            .iter()
+            .any(|cell| cell.modifier.contains(Modifier::BOLD))
+    );
+}
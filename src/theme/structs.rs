@@ -58,6 +58,8 @@ pub struct InputTheme {
     pub tooltip_hint: Color,
     pub unfocused_hint: Color,
     pub query_unfocused: Color,
+    /// Inline completion shown after the cursor
+    pub ghost_text: Color,
     pub cursor: Style,
 }
 
@@ -97,9 +99,18 @@ pub struct ResultsTheme {
     pub stale_modifier: Modifier,
     pub path_at_cursor_separator: Color,
     pub path_at_cursor: Color,
+    pub fold_marker: Color,
+    pub diff_added_bg: Color,
+    pub diff_removed_fg: Color,
+    pub pin_marker: Color,
+    pub table_header: Color,
+    pub chart_bar: Color,
+    pub stage_unchanged_fg: Color,
     pub hint_key: Color,
     pub hint_description: Style,
     pub spinner_colors: Vec<Color>,
+    /// Backgrounds of `:match` patterns, one per pattern in turn
+    pub match_colors: Vec<Color>,
     /// Colors for jq's --color-output, in jq's order:
     /// null, false, true, numbers, strings, arrays, objects, keys.
     /// arrays/objects/keys are rendered bold by the executor.
@@ -155,6 +166,8 @@ pub struct HistoryTheme {
     pub search_bg: Color,
     pub delete_button: Color,
     pub delete_button_hover: Color,
+    pub ai_marker: Color,
+    pub pin_marker: Color,
 }
 
 /// Snippets popup styles.
@@ -1,13 +1,18 @@
 mod detector;
+mod field_info;
 mod operator_content;
+mod signature_help;
 mod tooltip_content;
 pub mod tooltip_events;
 pub mod tooltip_render;
 mod tooltip_state;
 
+pub use detector::detect_field_path_at_cursor;
 pub use detector::detect_function_at_cursor;
 pub use detector::detect_operator_at_cursor;
-pub use operator_content::get_operator_content;
-pub use tooltip_content::get_tooltip_content;
+pub use field_info::{FieldInfo, field_info_at_cursor};
+pub use operator_content::{OPERATOR_CONTENT, get_operator_content};
+pub use signature_help::{SignatureHelp, signature_at_cursor};
+pub use tooltip_content::{TOOLTIP_CONTENT, TooltipContent, get_tooltip_content};
 pub use tooltip_state::TooltipState;
 pub use tooltip_state::update_tooltip_from_app;
@@ -153,6 +153,50 @@ fn lookup_function(token: &str) -> Option<&'static str> {
         .iter()
         .find(|f| f.name == token)
         .map(|f| f.name)
+        .or_else(|| {
+            crate::query::prelude::functions()
+                .iter()
+                .find(|f| f.name == token)
+                .map(|f| f.name.as_str())
+        })
+}
+
+/// Detect the field path the cursor is on, e.g. `.users[].name` with the
+/// cursor anywhere in `name`. Returns the byte offset where the path starts
+/// and the path up to the end of that field.
+pub fn detect_field_path_at_cursor(query: &str, cursor_pos: usize) -> Option<(usize, String)> {
+    let chars: Vec<char> = query.chars().collect();
+    if cursor_pos > chars.len() {
+        return None;
+    }
+
+    let (start, end) = find_word_boundaries(&chars, cursor_pos);
+    if start == end || start == 0 || chars[start - 1] != '.' {
+        return None;
+    }
+
+    // Walk back over the rest of the path: `.a`, `[]`, `[0]`, `[-1]`, `?`
+    let mut path_start = start - 1;
+    while path_start > 0 && is_path_char(&chars, path_start - 1) {
+        path_start -= 1;
+    }
+    if chars[path_start] != '.' {
+        return None;
+    }
+
+    let path: String = chars[path_start..end].iter().collect();
+    Some((
+        crate::str_utils::char_pos_to_byte_pos(query, path_start),
+        path,
+    ))
+}
+
+fn is_path_char(chars: &[char], pos: usize) -> bool {
+    match chars[pos] {
+        '.' | '?' | '[' | ']' => true,
+        '-' => pos > 0 && chars[pos - 1] == '[',
+        c => is_word_char(c),
+    }
 }
 
 /// Detect jq operator at cursor position. Checks multi-char operators first.
@@ -471,3 +471,56 @@ proptest! {
         );
     }
 }
+
+// ========== Field Path Detection Tests ==========
+
+#[test]
+fn test_detect_field_path_on_root_field() {
+    assert_eq!(
+        detect_field_path_at_cursor(".name", 3),
+        Some((0, ".name".to_string()))
+    );
+    assert_eq!(
+        detect_field_path_at_cursor(".name", 5),
+        Some((0, ".name".to_string()))
+    );
+}
+
+#[test]
+fn test_detect_field_path_takes_whole_chain_up_to_the_field() {
+    // Cursor on "address": the chain ends there, not at "city"
+    assert_eq!(
+        detect_field_path_at_cursor(".users[0].address.city", 12),
+        Some((0, ".users[0].address".to_string()))
+    );
+    assert_eq!(
+        detect_field_path_at_cursor(".items[-1].id", 12),
+        Some((0, ".items[-1].id".to_string()))
+    );
+    assert_eq!(
+        detect_field_path_at_cursor(".a?.b", 4),
+        Some((0, ".a?.b".to_string()))
+    );
+}
+
+#[test]
+fn test_detect_field_path_after_pipe_reports_offset() {
+    assert_eq!(
+        detect_field_path_at_cursor(".users[] | .email", 14),
+        Some((11, ".email".to_string()))
+    );
+    assert_eq!(
+        detect_field_path_at_cursor("\"é\" + .name", 9),
+        Some((7, ".name".to_string()))
+    );
+}
+
+#[test]
+fn test_detect_field_path_ignores_non_fields() {
+    assert_eq!(detect_field_path_at_cursor("length", 2), None);
+    assert_eq!(detect_field_path_at_cursor("$user.name", 8), None);
+    assert_eq!(detect_field_path_at_cursor("1.5", 2), None);
+    assert_eq!(detect_field_path_at_cursor(".a | .b", 3), None);
+    assert_eq!(detect_field_path_at_cursor("", 0), None);
+    assert_eq!(detect_field_path_at_cursor(".a", 9), None);
+}
@@ -0,0 +1,111 @@
+//! Field tooltip: what the input holds at the path under the cursor
+//!
+//! The path is folded through enclosing pipes and `select`/`map` calls, the
+//! same way value autocomplete does, so `.users[] | select(.age > 30)` with
+//! the cursor on `age` looks up `.users[].age` in the input.
+
+use serde_json::Value;
+
+use crate::autocomplete::json_navigator::navigate_multi;
+use crate::autocomplete::path_parser::{PathSegment, parse_path};
+use crate::autocomplete::value_trigger::fold_to_absolute_path;
+use crate::autocomplete::{JsonFieldType, ResultAnalyzer};
+use crate::tooltip::detect_field_path_at_cursor;
+
+/// Characters of the sample value kept for display
+const MAX_SAMPLE_CHARS: usize = 120;
+
+/// A field in the input, as the tooltip describes it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Path from the input root, e.g. `.users[].name`
+    pub path: String,
+    pub field_type: JsonFieldType,
+    /// Element count when the value is an array
+    pub length: Option<usize>,
+    /// The first value found, compact and abbreviated
+    pub sample: String,
+}
+
+/// The field the cursor is on, if the path exists in `json`
+pub fn field_info_at_cursor(
+    query: &str,
+    cursor_pos: usize,
+    json: &Value,
+    array_sample_size: usize,
+) -> Option<FieldInfo> {
+    let (start, relative) = detect_field_path_at_cursor(query, cursor_pos)?;
+    let path = fold_to_absolute_path(&query[..start], start, Some(relative))?;
+
+    let parsed = parse_path(&path);
+    let mut segments = parsed.segments;
+    if !parsed.partial.is_empty() {
+        segments.push(PathSegment::Field(parsed.partial));
+    }
+    let value = *navigate_multi(json, &segments, array_sample_size).first()?;
+
+    Some(FieldInfo {
+        path,
+        field_type: ResultAnalyzer::detect_json_type(value),
+        length: value.as_array().map(Vec::len),
+        sample: abbreviate(value, MAX_SAMPLE_CHARS),
+    })
+}
+
+/// Compact JSON for `value`, cut to `max_chars` without serializing more of
+/// a large array or object than is shown
+fn abbreviate(value: &Value, max_chars: usize) -> String {
+    let mut out = String::new();
+    let complete = write_abbreviated(value, max_chars, &mut out);
+    if complete && out.chars().count() <= max_chars {
+        return out;
+    }
+    let mut cut: String = out.chars().take(max_chars - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Append compact JSON for `value` to `out`, stopping once `out` is past
+/// `max_chars`; returns false if it stopped early
+fn write_abbreviated(value: &Value, max_chars: usize, out: &mut String) -> bool {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if out.len() > max_chars {
+                    return false;
+                }
+                if i > 0 {
+                    out.push(',');
+                }
+                if !write_abbreviated(item, max_chars, out) {
+                    return false;
+                }
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            out.push('{');
+            for (i, (key, item)) in map.iter().enumerate() {
+                if out.len() > max_chars {
+                    return false;
+                }
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::from(key.as_str()).to_string());
+                out.push(':');
+                if !write_abbreviated(item, max_chars, out) {
+                    return false;
+                }
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "field_info_tests.rs"]
+mod field_info_tests;
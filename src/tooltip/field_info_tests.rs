@@ -0,0 +1,118 @@
+//! Tests for tooltip/field_info
+
+use super::*;
+use serde_json::json;
+
+const SAMPLE: usize = 10;
+
+fn input() -> Value {
+    json!({
+        "name": "store",
+        "users": [
+            {"name": "Alice", "age": 30, "tags": ["admin", "dev"]},
+            {"name": "Bob", "age": 25, "tags": []}
+        ],
+        "meta": {"count": 2, "odd-key": true}
+    })
+}
+
+/// Info with the cursor at the end of `query`
+fn info_at_end(query: &str) -> Option<FieldInfo> {
+    field_info_at_cursor(query, query.chars().count(), &input(), SAMPLE)
+}
+
+#[test]
+fn test_root_field() {
+    let info = info_at_end(".name").unwrap();
+    assert_eq!(info.path, ".name");
+    assert_eq!(info.field_type, JsonFieldType::String);
+    assert_eq!(info.length, None);
+    assert_eq!(info.sample, "\"store\"");
+}
+
+#[test]
+fn test_array_field_reports_length() {
+    let info = info_at_end(".users").unwrap();
+    assert_eq!(
+        info.field_type,
+        JsonFieldType::ArrayOf(Box::new(JsonFieldType::Object))
+    );
+    assert_eq!(info.length, Some(2));
+    assert!(info.sample.starts_with(r#"[{"name":"Alice","age":30"#));
+}
+
+#[test]
+fn test_path_through_array_samples_first_element() {
+    let info = info_at_end(".users[].tags").unwrap();
+    assert_eq!(info.path, ".users[].tags");
+    assert_eq!(info.length, Some(2));
+    assert_eq!(info.sample, r#"["admin","dev"]"#);
+}
+
+#[test]
+fn test_cursor_inside_field_name() {
+    let info = field_info_at_cursor(".meta.count | . + 1", 8, &input(), SAMPLE).unwrap();
+    assert_eq!(info.path, ".meta.count");
+    assert_eq!(info.sample, "2");
+}
+
+#[test]
+fn test_path_folds_through_pipe_and_select() {
+    let info = field_info_at_cursor(".users[] | select(.age > 26)", 22, &input(), SAMPLE).unwrap();
+    assert_eq!(info.path, ".users[].age");
+    assert_eq!(info.field_type, JsonFieldType::Number);
+}
+
+#[test]
+fn test_path_folds_through_map() {
+    let info = field_info_at_cursor(".users | map(.name)", 16, &input(), SAMPLE).unwrap();
+    assert_eq!(info.path, ".users[].name");
+    assert_eq!(info.sample, "\"Alice\"");
+}
+
+#[test]
+fn test_missing_field_has_no_info() {
+    assert_eq!(info_at_end(".missing"), None);
+    assert_eq!(info_at_end(".users[].missing"), None);
+}
+
+#[test]
+fn test_unfoldable_context_has_no_info() {
+    // Folding gives up inside calls other than select, map and friends
+    let query = ".users | sort_by(.age)";
+    assert_eq!(field_info_at_cursor(query, 19, &input(), SAMPLE), None);
+}
+
+#[test]
+fn test_cursor_off_a_field_has_no_info() {
+    assert_eq!(field_info_at_cursor("length", 3, &input(), SAMPLE), None);
+    assert_eq!(
+        field_info_at_cursor(".users | length", 7, &input(), SAMPLE),
+        None
+    );
+}
+
+#[test]
+fn test_abbreviate_keeps_short_values() {
+    assert_eq!(
+        abbreviate(&json!({"a": [1, null]}), 20),
+        r#"{"a":[1,null]}"#
+    );
+}
+
+#[test]
+fn test_abbreviate_cuts_long_values() {
+    let items: Vec<u32> = (0..10_000).collect();
+    let sample = abbreviate(&json!(items), 20);
+    assert_eq!(sample.chars().count(), 20);
+    assert!(sample.starts_with("[0,1,2,3"));
+    assert!(sample.ends_with('…'));
+}
+
+#[test]
+fn test_abbreviate_marks_values_stopped_short_of_the_limit() {
+    // Multi-byte characters fill the byte budget before the character one
+    let sample = abbreviate(&json!(["é".repeat(8), "x", "y"]), 12);
+    assert!(sample.ends_with('…'), "got {sample}");
+    assert!(sample.chars().count() <= 12);
+}
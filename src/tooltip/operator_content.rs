@@ -12,7 +12,8 @@ pub static OPERATOR_CONTENT: &[TooltipContent] = &[
             "(.x // .y) // .z       # chain alternatives",
         ],
         Some("Only triggers on null/false - use 'if . == \"\" then ... end' for empty strings"),
-    ),
+    )
+    .with_manual("Alternative operator: //"),
     TooltipContent::new(
         "|=",
         "Update operator - transform value in place using expression",
@@ -23,7 +24,8 @@ pub static OPERATOR_CONTENT: &[TooltipContent] = &[
             ".config |= . + {new: 1} # add to object",
         ],
         Some("Right side receives current value as input; use = for simple assignment"),
-    ),
+    )
+    .with_manual("Update-assignment: |="),
     TooltipContent::new(
         "//=",
         "Alternative assignment - set value only if currently null or false",
@@ -34,7 +36,8 @@ pub static OPERATOR_CONTENT: &[TooltipContent] = &[
             ".items //= []          # ensure array exists",
         ],
         Some("Equivalent to: .field = (.field // value)"),
-    ),
+    )
+    .with_manual("Arithmetic update-assignment: +=, -=, *=, /=, %=, //="),
     TooltipContent::new(
         "..",
         "Recursive descent - generate all values in structure",
@@ -45,7 +48,8 @@ pub static OPERATOR_CONTENT: &[TooltipContent] = &[
             ".. | objects | select(has(\"error\")) # find error objects",
         ],
         Some("Shorthand for recurse; use with type filters to avoid duplicates"),
-    ),
+    )
+    .with_manual("Recursive Descent: .."),
 ];
 
 pub fn get_operator_content(operator: &str) -> Option<&'static TooltipContent> {
@@ -0,0 +1,130 @@
+//! Signature help for the call the cursor is inside
+//!
+//! While typing `sub("a"; `, the query box's bottom border shows
+//! `sub(regex; str; flags?)` with the argument being typed highlighted.
+//! Signatures come from the tooltip table, so a function documented there
+//! (or in the prelude) gets help too; arities that extend a shorter one
+//! are folded into a single line with the extra arguments marked `?`.
+
+use super::get_tooltip_content;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureHelp {
+    pub function: String,
+    pub params: Vec<String>,
+    /// The argument the cursor is in; past the end with too many
+    pub current: usize,
+    /// Arguments from here on may be left out
+    pub optional_from: usize,
+}
+
+/// Signature help for the innermost call around char `cursor` of `query`
+/// whose signature is known
+pub fn signature_at_cursor(query: &str, cursor: usize) -> Option<SignatureHelp> {
+    let chars: Vec<char> = query.chars().collect();
+    let calls = open_calls(&chars[..cursor.min(chars.len())]);
+    calls.into_iter().rev().find_map(|(name, argument)| {
+        let signatures = signatures_of(&name);
+        pick(&signatures, argument).map(|(params, optional_from)| SignatureHelp {
+            function: name,
+            params,
+            current: argument,
+            optional_from,
+        })
+    })
+}
+
+/// Named calls still open at the end of `chars`, outermost first, with
+/// the argument each has reached
+fn open_calls(chars: &[char]) -> Vec<(String, usize)> {
+    // (opening char, name before a `(`, `;`s seen directly inside)
+    let mut open: Vec<(char, String, usize)> = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' => open.push(('(', name_before(chars, i), 0)),
+            '[' | '{' => open.push((c, String::new(), 0)),
+            ')' | ']' | '}' => {
+                open.pop();
+            }
+            ';' => {
+                if let Some((opener, _, semicolons)) = open.last_mut()
+                    && *opener == '('
+                {
+                    *semicolons += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    open.into_iter()
+        .filter(|(opener, name, _)| *opener == '(' && !name.is_empty())
+        .map(|(_, name, semicolons)| (name, semicolons))
+        .collect()
+}
+
+fn name_before(chars: &[char], paren: usize) -> String {
+    let end = paren;
+    let mut start = end;
+    while start > 0 && (chars[start - 1].is_alphanumeric() || chars[start - 1] == '_') {
+        start -= 1;
+    }
+    chars[start..end].iter().collect()
+}
+
+/// Every arity of `name`, as parameter lists
+fn signatures_of(name: &str) -> Vec<Vec<String>> {
+    let Some(content) = get_tooltip_content(name) else {
+        return Vec::new();
+    };
+    let mut signatures: Vec<Vec<String>> = content
+        .signatures
+        .iter()
+        .filter_map(|signature| parameters(signature))
+        .collect();
+    signatures.sort_by_key(Vec::len);
+    signatures
+}
+
+/// `sub(regex; str)` → `["regex", "str"]`; None without arguments
+fn parameters(signature: &str) -> Option<Vec<String>> {
+    let open = signature.find('(')?;
+    let close = signature.rfind(')')?;
+    let params: Vec<String> = signature[open + 1..close]
+        .split(';')
+        .map(|param| param.trim().to_string())
+        .filter(|param| !param.is_empty())
+        .collect();
+    (!params.is_empty()).then_some(params)
+}
+
+/// The parameters to show at `argument` and where the optional ones start:
+/// the longest arity when the shorter ones are its prefixes, or else the
+/// shortest that reaches the argument
+fn pick(signatures: &[Vec<String>], argument: usize) -> Option<(Vec<String>, usize)> {
+    let longest = signatures.last()?;
+    let nested = signatures.iter().all(|params| longest.starts_with(params));
+    if nested {
+        return Some((longest.clone(), signatures[0].len()));
+    }
+    let params = signatures
+        .iter()
+        .find(|params| params.len() > argument)
+        .unwrap_or(longest);
+    Some((params.clone(), params.len()))
+}
+
+#[cfg(test)]
+#[path = "signature_help_tests.rs"]
+mod signature_help_tests;
@@ -0,0 +1,51 @@
+use super::*;
+
+fn help(query: &str) -> Option<SignatureHelp> {
+    signature_at_cursor(query, query.chars().count())
+}
+
+#[test]
+fn optional_arguments_fold_into_one_signature() {
+    let help = help(r#".name | sub("a"; "#).unwrap();
+
+    assert_eq!(help.function, "sub");
+    assert_eq!(help.params, ["regex", "str", "flags"]);
+    assert_eq!(help.current, 1);
+    assert_eq!(help.optional_from, 2);
+}
+
+#[test]
+fn first_argument_right_after_the_paren() {
+    let help = help("limit(").unwrap();
+
+    assert_eq!(help.params, ["n", "f"]);
+    assert_eq!((help.current, help.optional_from), (0, 2));
+}
+
+#[test]
+fn arities_with_different_names_follow_the_argument() {
+    assert_eq!(help("range(").unwrap().params, ["upto"]);
+    assert_eq!(help("range(1; ").unwrap().params, ["from", "upto"]);
+}
+
+#[test]
+fn innermost_known_call_wins() {
+    assert_eq!(help("map(select(").unwrap().function, "select");
+    assert_eq!(help("limit(3; (.a | ").unwrap().function, "limit");
+    assert_eq!(help("limit(3; (.a | ").unwrap().current, 1);
+}
+
+#[test]
+fn semicolons_in_strings_and_nested_calls_dont_count() {
+    assert_eq!(help(r#"sub(";"; "#).unwrap().current, 1);
+    assert_eq!(help("limit(range(1; 3); ").unwrap().current, 1);
+    assert_eq!(help("limit(3; [1, 2").unwrap().current, 1);
+}
+
+#[test]
+fn no_help_outside_a_call_or_after_it_closes() {
+    assert!(help(".name").is_none());
+    assert!(help("map(.a)").is_none());
+    assert!(help(r#""map("#).is_none());
+    assert!(help("length(").is_none());
+}
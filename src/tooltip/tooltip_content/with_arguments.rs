@@ -0,0 +1,565 @@
+//! Tooltip content for jq functions that take arguments, plus the date
+//! functions
+
+use super::TooltipContent;
+
+pub(super) static FUNCTIONS_WITH_ARGUMENTS: &[TooltipContent] = &[
+    // ===== Array/Filter Functions (with arguments) =====
+    TooltipContent::new(
+        "map",
+        "Apply expression to each element of an array",
+        &[
+            "map(.name)              # extract field from each",
+            "map(. + 1)              # increment each number",
+            "map(select(.active))    # filter to active items",
+            "map({id, name})         # reshape each object",
+        ],
+        Some("Use [.[] | expr] for same result - less memory for large arrays"),
+    )
+    .with_signatures(&["map(f)"])
+    .with_manual("map(f), map_values(f)"),
+    TooltipContent::new(
+        "select",
+        "Filter elements that match a condition",
+        &[
+            "select(.age > 18)                       # numeric filter",
+            "select(.status == \"active\")            # exact match",
+            "select(.tags | contains([\"important\"])) # array check",
+            "select(.name | test(\"^test\"; \"i\"))     # regex match",
+        ],
+        Some("For null-safe checks, use select(.field? // false)"),
+    )
+    .with_signatures(&["select(cond)"])
+    .with_manual("select(boolean_expression)"),
+    TooltipContent::new(
+        "sort_by",
+        "Sort array elements by a computed value",
+        &[
+            "sort_by(.name)          # sort by field",
+            "sort_by(.date) | reverse # sort descending",
+            "sort_by(-.price)        # descending (cleaner)",
+            "sort_by(.price | tonumber) # ensure numeric",
+        ],
+        Some("Use sort_by(-.field) instead of sort_by(.field) | reverse"),
+    )
+    .with_signatures(&["sort_by(f)"])
+    .with_manual("sort, sort_by(path_expression)"),
+    TooltipContent::new(
+        "group_by",
+        "Group array elements by a computed key",
+        &[
+            "group_by(.category)     # group by field",
+            "group_by(.type) | map({type: .[0].type, count: length})",
+            "group_by(.date[:10])    # group by date part",
+        ],
+        Some("Output sorted by key; use map({key: .[0].field, values: .})"),
+    )
+    .with_signatures(&["group_by(f)"])
+    .with_manual("group_by(path_expression)"),
+    TooltipContent::new(
+        "unique_by",
+        "Remove duplicates based on a computed key",
+        &[
+            "unique_by(.id)          # dedupe by ID",
+            "unique_by(.email | ascii_downcase) # case-insensitive",
+            "unique_by([.first, .last]) # by multiple fields",
+        ],
+        Some(
+            "Keeps FIRST occurrence; output is SORTED; use group_by | map(first) to preserve order",
+        ),
+    )
+    .with_signatures(&["unique_by(f)"])
+    .with_manual("unique, unique_by(path_exp)"),
+    TooltipContent::new(
+        "min_by",
+        "Find element with minimum value by expression",
+        &[
+            "min_by(.price)          # object with lowest price",
+            "min_by(.date)           # earliest by date",
+            "min_by(.name | length)  # shortest name",
+        ],
+        Some("Returns entire object; use .field after: max_by(.score).score"),
+    )
+    .with_signatures(&["min_by(f)"])
+    .with_manual("min, max, min_by(path_exp), max_by(path_exp)"),
+    TooltipContent::new(
+        "max_by",
+        "Find element with maximum value by expression",
+        &[
+            "max_by(.score)          # object with highest score",
+            "max_by(.date | fromdateiso8601) # most recent by ISO date",
+            "max_by(.name | length)  # longest name",
+        ],
+        Some("Returns entire object; use .field after: max_by(.score).score"),
+    )
+    .with_signatures(&["max_by(f)"])
+    .with_manual("min, max, min_by(path_exp), max_by(path_exp)"),
+    TooltipContent::new(
+        "limit",
+        "Take only first N results from a generator",
+        &[
+            "limit(5; .[])           # first 5 elements",
+            "limit(10; recurse)      # limit recursion",
+            "limit(3; .[] | select(.active)) # first 3 matches",
+            "[limit(100; inputs)]    # first 100 JSONL lines",
+        ],
+        Some("More efficient than [:5] - stops early; essential with recurse"),
+    )
+    .with_signatures(&["limit(n; f)"])
+    .with_manual("limit(n; exp)"),
+    TooltipContent::new(
+        "nth",
+        "Get the nth element from a generator",
+        &[
+            "nth(0)                  # first element (same as first)",
+            "nth(2; .[])             # third element from array",
+            "nth(0; .[] | select(.valid)) # first valid element",
+        ],
+        Some("Use first/last for clearer code; nth for specific positions"),
+    )
+    .with_signatures(&["nth(n)", "nth(n; f)"])
+    .with_manual("first(expr), last(expr), nth(n; expr)"),
+    TooltipContent::new(
+        "range",
+        "Generate a sequence of numbers",
+        &[
+            "range(5)                # 0, 1, 2, 3, 4",
+            "range(1; 11)            # 1 to 10 (end exclusive)",
+            "range(0; 100; 10)       # 0, 10, 20, ..., 90",
+            "[range(5)] | map(. * 2) # [0,2,4,6,8]",
+        ],
+        Some("End is EXCLUSIVE (like Python); wrap in [range(n)] for array"),
+    )
+    .with_signatures(&["range(upto)", "range(from; upto)", "range(from; upto; by)"])
+    .with_manual("range(upto), range(from; upto), range(from; upto; by)"),
+    TooltipContent::new(
+        "until",
+        "Repeat until condition is true",
+        &[
+            "until(. >= 100; . * 2)  # returns 128 (final value only)",
+            "1 | until(. > 10; . + 1) # returns 11",
+        ],
+        Some("Returns FINAL value only; use while for ALL intermediate values"),
+    )
+    .with_signatures(&["until(cond; next)"])
+    .with_manual("until(cond; next)"),
+    TooltipContent::new(
+        "while",
+        "Repeat while condition is true",
+        &[
+            "[while(. < 100; . * 2)] # [1,2,4,8,16,32,64] (all values)",
+            "[1 | while(. <= 10; . + 1)] # [1,2,3,4,5,6,7,8,9,10]",
+        ],
+        Some("Returns ALL intermediate values as stream; wrap in [] for array"),
+    )
+    .with_signatures(&["while(cond; update)"])
+    .with_manual("while(cond; update)"),
+    TooltipContent::new(
+        "recurse",
+        "Recursively apply expression (depth-first traversal)",
+        &[
+            "recurse(.children[]?)   # traverse tree via children field",
+            "recurse | .name?        # get all \"name\" fields in tree",
+            "recurse(.next?) | .value # follow linked list",
+        ],
+        Some("Use .. as shorthand; add ? for missing fields; use limit() always"),
+    )
+    .with_signatures(&["recurse", "recurse(f)", "recurse(f; cond)"])
+    .with_manual("recurse(f), recurse, recurse(f; condition)"),
+    TooltipContent::new(
+        "walk",
+        "Transform all values recursively (bottom-up)",
+        &[
+            "walk(if type == \"string\" then ascii_downcase else . end)",
+            "walk(if type == \"object\" then del(.internal) else . end)",
+            "walk(if . == null then \"N/A\" else . end) # replace nulls",
+        ],
+        Some("Processes BOTTOM-UP (children before parents); use recurse for top-down"),
+    )
+    .with_signatures(&["walk(f)"])
+    .with_manual("walk(f)"),
+    TooltipContent::new(
+        "with_entries",
+        "Transform object's key-value pairs",
+        &[
+            "with_entries(.value |= . + 1) # increment all values",
+            "with_entries(select(.value != null)) # remove null values",
+            "with_entries(.key |= \"prefix_\" + .) # prefix all keys",
+            "with_entries(select(.key | startswith(\"public_\")))",
+        ],
+        Some("Shorthand for to_entries | map(...) | from_entries"),
+    )
+    .with_signatures(&["with_entries(f)"])
+    .with_manual("to_entries, from_entries, with_entries(f)"),
+    TooltipContent::new(
+        "repeat",
+        "Apply an expression again and again, outputting each step",
+        &[
+            "[limit(5; 1 | repeat(. * 2))] # doubling sequence",
+            "first(repeat(.next) | select(.done)) # follow links",
+        ],
+        Some("Never stops on its own - wrap it in limit, first or until"),
+    )
+    .with_signatures(&["repeat(f)"])
+    .with_manual("repeat(f)"),
+    // ===== Object Functions (with arguments) =====
+    TooltipContent::new(
+        "has",
+        "Check if object has a specific key",
+        &[
+            "has(\"email\")           # check if key exists",
+            "select(has(\"config\"))  # filter objects that have config",
+            "if has(\"error\") then .error else \"ok\" end",
+            "has(\"key\") and .key != null # key exists AND not null",
+        ],
+        Some("Only checks key existence; use .key? // default for null-safe access"),
+    )
+    .with_signatures(&["has(key)"])
+    .with_manual("has(key)"),
+    TooltipContent::new(
+        "del",
+        "Delete keys or paths from object/array",
+        &[
+            "del(.password, .secret) # remove sensitive fields",
+            "del(.users[0])          # remove first array element",
+            "del(.config.debug)      # remove nested field",
+            "map(del(.internal))     # remove field from all objects",
+        ],
+        Some("For pattern matching: with_entries(select(.key | test(\"x\") | not))"),
+    )
+    .with_signatures(&["del(path)"])
+    .with_manual("del(path_expression)"),
+    TooltipContent::new(
+        "getpath",
+        "Get value using dynamic path array",
+        &[
+            "getpath([\"user\", \"address\", \"city\"]) # nested access",
+            "getpath([\"items\", 0])  # get first item",
+            "getpath($path)          # variable path",
+        ],
+        Some("Use .a.b.c for static paths; getpath for dynamic/computed paths"),
+    )
+    .with_signatures(&["getpath(path)"])
+    .with_manual("getpath(PATHS)"),
+    TooltipContent::new(
+        "setpath",
+        "Set value using dynamic path array",
+        &[
+            "setpath([\"config\", \"enabled\"]; true) # set nested",
+            "setpath([\"items\", 0]; \"new\") # set by index",
+        ],
+        Some("Creates intermediate objects/arrays as needed"),
+    )
+    .with_signatures(&["setpath(path; value)"])
+    .with_manual("setpath(PATHS; VALUE)"),
+    TooltipContent::new(
+        "delpaths",
+        "Delete multiple paths at once",
+        &[
+            "delpaths([[\"a\"], [\"b\", \"c\"]]) # delete paths",
+            "delpaths([paths(type == \"null\")]) # delete nulls",
+            "delpaths([paths(. == \"\")])  # delete empty",
+        ],
+        Some("Combine with [paths(...)] - more efficient than chaining del()"),
+    )
+    .with_signatures(&["delpaths(paths)"])
+    .with_manual("delpaths(PATHS)"),
+    TooltipContent::new(
+        "in",
+        "Check whether the input key exists in an object or array",
+        &[
+            "\"name\" | in({\"name\": 1}) # true",
+            "keys[] | select(in($allowed)) # keys present in $allowed",
+        ],
+        Some("The reverse of has: k | in(obj) is obj | has(k)"),
+    )
+    .with_signatures(&["in(obj)"])
+    .with_manual("in"),
+    // ===== String Functions (with arguments) =====
+    TooltipContent::new(
+        "split",
+        "Split string to array by delimiter",
+        &[
+            "split(\",\")             # \"a,b,c\" -> [\"a\",\"b\",\"c\"]",
+            "split(\"\\n\") | map(select(. != \"\")) # split lines",
+            "[.items[].name] | join(\" | \") # join values",
+        ],
+        Some("Use splits(\"\\\\s+\") for regex splitting (e.g., by whitespace)"),
+    )
+    .with_signatures(&["split(str)", "split(regex; flags)"])
+    .with_manual("split(str)"),
+    TooltipContent::new(
+        "join",
+        "Join array to string with delimiter",
+        &[
+            "join(\", \")             # [\"a\",\"b\"] -> \"a, b\"",
+            "[.items[].name] | join(\" | \") # join field values",
+        ],
+        Some("Ensure all elements are strings first with map(tostring) if needed"),
+    )
+    .with_signatures(&["join(str)"])
+    .with_manual("join(str)"),
+    TooltipContent::new(
+        "ltrimstr",
+        "Remove prefix from string",
+        &[
+            "ltrimstr(\"https://\")   # remove URL scheme",
+            "ltrimstr(\"v\") | tonumber # parse version number",
+        ],
+        Some("Safe if prefix doesn't exist (returns unchanged); use sub() for regex"),
+    )
+    .with_signatures(&["ltrimstr(str)"])
+    .with_manual("ltrimstr(str)"),
+    TooltipContent::new(
+        "rtrimstr",
+        "Remove suffix from string",
+        &[
+            "rtrimstr(\".json\")      # remove file extension",
+            ".filename | rtrimstr(\".bak\") # remove backup suffix",
+        ],
+        Some("Safe if suffix doesn't exist (returns unchanged); use sub() for regex"),
+    )
+    .with_signatures(&["rtrimstr(str)"])
+    .with_manual("rtrimstr(str)"),
+    TooltipContent::new(
+        "startswith",
+        "Check if string starts with prefix",
+        &[
+            "select(startswith(\"http\")) # filter URLs",
+            "select(.path | startswith(\"/api/\")) # filter API paths",
+            "if endswith(\".json\") then \"JSON\" else \"other\" end",
+        ],
+        Some("For case-insensitive: use test(\"^pattern\"; \"i\") instead"),
+    )
+    .with_signatures(&["startswith(str)"])
+    .with_manual("startswith(str)"),
+    TooltipContent::new(
+        "endswith",
+        "Check if string ends with suffix",
+        &[
+            "select(endswith(\".json\")) # filter by suffix",
+            "select(.email | endswith(\"@company.com\")) # by domain",
+        ],
+        Some("For case-insensitive: use test(\"pattern$\"; \"i\") instead"),
+    )
+    .with_signatures(&["endswith(str)"])
+    .with_manual("endswith(str)"),
+    TooltipContent::new(
+        "test",
+        "Test if string matches regex pattern",
+        &[
+            "test(\"^[0-9]+$\")       # all digits",
+            "test(\"[a-z]\"; \"i\")    # case-insensitive",
+            "select(test(\"error|warning\"; \"i\")) # filter logs",
+            "test(\"\\\\d{4}-\\\\d{2}-\\\\d{2}\") # date YYYY-MM-DD",
+        ],
+        Some("Flags: i=case-insensitive, x=extended, m=multiline, s=single-line"),
+    )
+    .with_signatures(&["test(regex)", "test(regex; flags)"])
+    .with_manual("test(val), test(regex; flags)"),
+    TooltipContent::new(
+        "match",
+        "Extract regex match information",
+        &[
+            "match(\"v([0-9]+)\\\\.([0-9]+)\") | .captures[].string",
+            "match(\"\\\\d+\"; \"g\") | .string # all matches (global)",
+        ],
+        Some("Use capture() for named groups - returns object with field names"),
+    )
+    .with_signatures(&["match(regex)", "match(regex; flags)"])
+    .with_manual("match(val), match(regex; flags)"),
+    TooltipContent::new(
+        "capture",
+        "Extract named capture groups from regex",
+        &[
+            "capture(\"(?<user>[^@]+)@(?<domain>.+)\") # email",
+            "capture(\"(?<y>\\\\d{4})-(?<m>\\\\d{2})-(?<d>\\\\d{2})\") # date",
+        ],
+        Some("Returns object with named fields; cleaner than match()"),
+    )
+    .with_signatures(&["capture(regex)", "capture(regex; flags)"])
+    .with_manual("capture(val), capture(regex; flags)"),
+    TooltipContent::new(
+        "scan",
+        "Find all regex matches (returns stream)",
+        &[
+            "[scan(\"[0-9]+\")]       # extract all numbers as array",
+            "[scan(\"\\\\w+\")]         # extract all words",
+            "scan(\"https?://[^\\\\s]+\") # extract all URLs",
+            "[scan(\"[A-Z]{2,}\")] | unique # find all acronyms",
+        ],
+        Some("Returns STREAM, not array - wrap in [] to collect"),
+    )
+    .with_signatures(&["scan(regex)", "scan(regex; flags)"])
+    .with_manual("scan(regex), scan(regex; flags)"),
+    TooltipContent::new(
+        "splits",
+        "Split by regex pattern (returns stream)",
+        &[
+            "[splits(\"\\\\s+\")]       # split by whitespace",
+            "[splits(\"[,;]\\\\s*\")]   # split by comma or semicolon",
+            "[splits(\"::\")]  | .[1] # get second segment",
+        ],
+        Some("Returns STREAM; use split() for literal delimiters"),
+    )
+    .with_signatures(&["splits(regex)", "splits(regex; flags)"])
+    .with_manual("splits(regex), splits(regex; flags)"),
+    TooltipContent::new(
+        "sub",
+        "Replace first regex match",
+        &[
+            "sub(\"old\"; \"new\")      # replace first occurrence",
+            "sub(\"^v\"; \"\")          # remove leading 'v'",
+        ],
+        Some("Use gsub() to replace ALL occurrences"),
+    )
+    .with_signatures(&["sub(regex; str)", "sub(regex; str; flags)"])
+    .with_manual("sub(regex; tostring), sub(regex; tostring; flags)"),
+    TooltipContent::new(
+        "gsub",
+        "Replace all regex matches",
+        &[
+            "gsub(\"\\\\s+\"; \" \")      # normalize whitespace",
+            "gsub(\"[^a-zA-Z0-9]\"; \"_\") # sanitize to alphanumeric",
+            "gsub(\"(?<n>\\\\d+)\"; \"[\\(.n)]\") # wrap numbers in brackets",
+        ],
+        Some("Use capture groups in replacement with \\(.name) syntax"),
+    )
+    .with_signatures(&["gsub(regex; str)", "gsub(regex; str; flags)"])
+    .with_manual("gsub(regex; tostring), gsub(regex; tostring; flags)"),
+    // ===== Comparison/Search Functions (with arguments) =====
+    TooltipContent::new(
+        "contains",
+        "Check if value contains another",
+        &[
+            "contains(\"error\")      # string contains substring",
+            ".tags | contains([\"urgent\"]) # array contains element",
+            "{a:1} | contains({a:1}) # object contains keys/values",
+        ],
+        Some("For regex or case-insensitive matching: use test() instead"),
+    )
+    .with_signatures(&["contains(val)"])
+    .with_manual("contains(element)"),
+    TooltipContent::new(
+        "inside",
+        "Check if value is contained by another",
+        &[
+            "\"sub\" | inside(\"substring\") # is substring",
+            "[1] | inside([1,2,3])   # is subarray",
+        ],
+        Some("Inverse of contains: a|inside(b) == b|contains(a)"),
+    )
+    .with_signatures(&["inside(val)"])
+    .with_manual("inside"),
+    TooltipContent::new(
+        "index",
+        "Find first position of value",
+        &[
+            "index(\",\")             # first comma position",
+            "[1,2,3,2] | index(2)   # returns 1",
+        ],
+        Some("Returns null if not found (not -1 like other languages)"),
+    )
+    .with_signatures(&["index(val)"])
+    .with_manual("index(s), rindex(s)"),
+    TooltipContent::new(
+        "rindex",
+        "Find last position of value",
+        &[
+            "rindex(\"/\")            # last slash position",
+            "[1,2,3,2] | rindex(2)  # returns 3",
+            ".path | rindex(\"/\") as $i | .[$i+1:] # filename",
+        ],
+        Some("Returns null if not found (not -1 like other languages)"),
+    )
+    .with_signatures(&["rindex(val)"])
+    .with_manual("index(s), rindex(s)"),
+    TooltipContent::new(
+        "indices",
+        "Find all positions of value",
+        &[
+            "indices(\",\")           # all comma positions",
+            "[1,2,3,2] | indices(2) # returns [1,3]",
+        ],
+        Some("Returns empty array if not found"),
+    )
+    .with_signatures(&["indices(val)"])
+    .with_manual("indices(s)"),
+    // ===== Math Functions (with arguments) =====
+    TooltipContent::new(
+        "pow",
+        "Raise a number to a power",
+        &["pow(2; 10) # 1024", "pow(.; 2) # square the input"],
+        Some("Arguments are separated by ; not ,"),
+    )
+    .with_signatures(&["pow(x; y)"])
+    .with_manual("Math"),
+    // ===== Date Functions =====
+    TooltipContent::new(
+        "strftime",
+        "Format timestamp with custom format",
+        &[
+            "now | strftime(\"%Y-%m-%d %H:%M:%S\") # custom format",
+            "now | strftime(\"%B %d, %Y\") # \"January 15, 2024\"",
+        ],
+        Some("%Y=year, %m=month, %d=day, %H=hour, %M=minute, %S=second"),
+    )
+    .with_signatures(&["strftime(fmt)"])
+    .with_manual("Dates"),
+    TooltipContent::new(
+        "strptime",
+        "Parse date string with custom format",
+        &[
+            "\"2024-01-15\" | strptime(\"%Y-%m-%d\") | .[0]",
+            "\"Jan 15, 2024\" | strptime(\"%b %d, %Y\") | mktime",
+        ],
+        Some("Returns [seconds, tz_offset]; use .[0] or mktime for timestamp"),
+    )
+    .with_signatures(&["strptime(fmt)"])
+    .with_manual("Dates"),
+    TooltipContent::new(
+        "fromdate",
+        "Parse ISO 8601 date string to timestamp",
+        &[
+            "\"2024-01-15T10:30:00Z\" | fromdate # to timestamp",
+            ".created_at | fromdate | . + 86400 | todate # add 1 day",
+            "[.events[].date | fromdate] | min | todate # earliest",
+        ],
+        Some("ISO 8601 only; use strptime for custom formats"),
+    )
+    .with_signatures(&["fromdate"])
+    .with_manual("Dates"),
+    TooltipContent::new(
+        "todate",
+        "Format timestamp as ISO 8601",
+        &[
+            "now | todate            # current time as ISO 8601",
+            ".timestamp | todate     # format unix timestamp",
+        ],
+        Some("ISO 8601 only; use strftime for custom formats"),
+    )
+    .with_signatures(&["todate"])
+    .with_manual("Dates"),
+    TooltipContent::new(
+        "fromdateiso8601",
+        "Parse an ISO 8601 date string to a Unix timestamp",
+        &[
+            "\"2015-03-05T23:51:47Z\" | fromdateiso8601 # 1425599507",
+            "map(.created | fromdateiso8601) # timestamps for sorting",
+        ],
+        Some("Accepts only the UTC form ending in Z, without fractional seconds"),
+    )
+    .with_signatures(&["fromdateiso8601"])
+    .with_manual("Dates"),
+    TooltipContent::new(
+        "todateiso8601",
+        "Format a Unix timestamp as an ISO 8601 date string",
+        &[
+            "1425599507 | todateiso8601 # \"2015-03-05T23:51:47Z\"",
+            "now | todateiso8601 # current time",
+        ],
+        Some("Same as todate; use strftime for other formats"),
+    )
+    .with_signatures(&["todateiso8601"])
+    .with_manual("Dates"),
+];
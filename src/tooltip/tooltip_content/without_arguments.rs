@@ -0,0 +1,557 @@
+//! Tooltip content for jq functions called without arguments
+
+use super::TooltipContent;
+
+pub(super) static FUNCTIONS_WITHOUT_ARGUMENTS: &[TooltipContent] = &[
+    // ===== Array Functions (no arguments) =====
+    TooltipContent::new(
+        "keys",
+        "Get object keys or array indices",
+        &[
+            "keys                    # sorted keys: [\"a\",\"b\",\"c\"]",
+            "keys | length           # count of keys",
+            "keys | map(select(startswith(\"_\"))) # private keys only",
+        ],
+        Some("SORTS output alphabetically; use keys_unsorted to preserve order"),
+    )
+    .with_signatures(&["keys"])
+    .with_manual("keys, keys_unsorted"),
+    TooltipContent::new(
+        "keys_unsorted",
+        "Get object keys in original order",
+        &[
+            "keys_unsorted           # preserve original order",
+            ".config | keys_unsorted # list config keys as defined",
+        ],
+        Some("Use when order matters (e.g., preserving config file order)"),
+    )
+    .with_signatures(&["keys_unsorted"])
+    .with_manual("keys, keys_unsorted"),
+    TooltipContent::new(
+        "values",
+        "Get all values from object or array",
+        &[
+            "values                  # all values (strips keys)",
+            ".config | values | add  # sum all config values",
+            "values | map(select(. != null)) # non-null values only",
+        ],
+        Some("Same as .[] but clearer intent; does NOT filter nulls"),
+    )
+    .with_signatures(&["values"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "sort",
+        "Sort array ascending",
+        &[
+            "sort                    # sort ascending",
+            "sort | reverse          # sort descending",
+            "sort | unique           # sort and dedupe",
+        ],
+        Some("Works on numbers, strings, mixed; use sort_by(.field) for objects"),
+    )
+    .with_signatures(&["sort"])
+    .with_manual("sort, sort_by(path_expression)"),
+    TooltipContent::new(
+        "reverse",
+        "Reverse array order",
+        &[
+            "reverse                 # reverse array order",
+            ".logs | reverse | first # most recent log entry",
+        ],
+        Some("Use .[-1] instead of reverse | first for just the last element"),
+    )
+    .with_signatures(&["reverse"])
+    .with_manual("reverse"),
+    TooltipContent::new(
+        "unique",
+        "Remove duplicate values (sorts output)",
+        &[
+            "unique                  # remove duplicates",
+            "[.items[].category] | unique # unique values of field",
+            "unique | length         # count distinct values",
+        ],
+        Some("Output is always SORTED; use unique_by(.field) for objects"),
+    )
+    .with_signatures(&["unique"])
+    .with_manual("unique, unique_by(path_exp)"),
+    TooltipContent::new(
+        "flatten",
+        "Flatten nested arrays",
+        &[
+            "flatten                 # flatten all levels",
+            "flatten(1)              # flatten one level only",
+            "[[1,2],[3,[4,5]]] | flatten # [1,2,3,4,5]",
+            ".pages[].items | flatten # combine paginated results",
+        ],
+        Some("Use add to concatenate arrays without deep flattening"),
+    )
+    .with_signatures(&["flatten", "flatten(depth)"])
+    .with_manual("flatten, flatten(depth)"),
+    TooltipContent::new(
+        "add",
+        "Sum numbers or concatenate arrays/strings",
+        &[
+            "[.items[].price] | add  # sum prices",
+            "[\"a\",\"b\",\"c\"] | add    # \"abc\" (string concat)",
+            "[[1,2],[3,4]] | add     # [1,2,3,4] (array concat)",
+            "[.counts[]] | add // 0  # sum with default 0 for empty",
+        ],
+        Some("Returns NULL for empty arrays - use // 0 or // \"\" for defaults"),
+    )
+    .with_signatures(&["add"])
+    .with_manual("add"),
+    TooltipContent::new(
+        "length",
+        "Length of string/array/object, absolute value of number",
+        &[
+            "length                  # element count",
+            ".items | length         # array length",
+            "select(length > 0)      # filter non-empty",
+            "select(.name | length <= 50) # max name length",
+        ],
+        Some("Returns 0 for null; for byte length use utf8bytelength"),
+    )
+    .with_signatures(&["length"])
+    .with_manual("length"),
+    TooltipContent::new(
+        "first",
+        "First element from array or generator",
+        &[
+            "first                   # first element",
+            "first(.[] | select(.valid)) # first valid item",
+            "last(inputs)            # last line from JSONL",
+        ],
+        Some("More efficient than .[0] for generators and large streams"),
+    )
+    .with_signatures(&["first", "first(f)"])
+    .with_manual("first, last, nth(n)"),
+    TooltipContent::new(
+        "last",
+        "Last element from array or generator",
+        &[
+            "last                    # last element",
+            "last(.[] | select(.type == \"error\")) # last error",
+        ],
+        Some("Use .[-1] for arrays; last() essential for generators"),
+    )
+    .with_signatures(&["last", "last(f)"])
+    .with_manual("first, last, nth(n)"),
+    TooltipContent::new(
+        "min",
+        "Minimum value in array",
+        &[
+            "min                     # minimum value",
+            "[.items[].price] | min  # lowest price",
+        ],
+        Some("Returns value, not object; returns null for empty; use min_by()"),
+    )
+    .with_signatures(&["min"])
+    .with_manual("min, max, min_by(path_exp), max_by(path_exp)"),
+    TooltipContent::new(
+        "max",
+        "Maximum value in array",
+        &[
+            "max                     # maximum value",
+            "[.scores[]] | max       # highest score",
+        ],
+        Some("Returns value, not object; returns null for empty; use max_by()"),
+    )
+    .with_signatures(&["max"])
+    .with_manual("min, max, min_by(path_exp), max_by(path_exp)"),
+    TooltipContent::new(
+        "transpose",
+        "Transpose matrix (swap rows and columns)",
+        &[
+            "[[1,2],[3,4]] | transpose # [[1,3],[2,4]]",
+            "[.names, .ages] | transpose # zip arrays",
+            "[.headers, .values] | transpose | map({(.[0]): .[1]}) | add",
+        ],
+        Some("Great for zipping arrays; uses nulls for different lengths"),
+    )
+    .with_signatures(&["transpose"])
+    .with_manual("transpose"),
+    TooltipContent::new(
+        "any",
+        "Check whether any element is true, or matches a condition",
+        &[
+            "any # [false, true] -> true",
+            "any(.[]; .price > 100) # any item over 100",
+        ],
+        Some("any(gen; cond) stops at the first match"),
+    )
+    .with_signatures(&["any", "any(cond)", "any(gen; cond)"])
+    .with_manual("any, any(condition), any(generator; condition)"),
+    TooltipContent::new(
+        "all",
+        "Check whether every element is true, or matches a condition",
+        &[
+            "all # [true, true] -> true",
+            "all(.[]; .active) # every item active",
+        ],
+        Some("An empty array gives true"),
+    )
+    .with_signatures(&["all", "all(cond)", "all(gen; cond)"])
+    .with_manual("all, all(condition), all(generator; condition)"),
+    // ===== Object Functions (no arguments) =====
+    TooltipContent::new(
+        "to_entries",
+        "Convert object to key-value pairs",
+        &[
+            "to_entries              # object to [{key,value},...]",
+            "to_entries | map(.value += 1) | from_entries",
+        ],
+        Some("Use with_entries for transform+convert in one step"),
+    )
+    .with_signatures(&["to_entries"])
+    .with_manual("to_entries, from_entries, with_entries(f)"),
+    TooltipContent::new(
+        "from_entries",
+        "Convert key-value pairs to object",
+        &[
+            "from_entries            # [{key,value},...] to object",
+            "[{key:\"a\",value:1}] | from_entries # {\"a\":1}",
+        ],
+        Some("Also accepts {name,value} or {k,v} pairs"),
+    )
+    .with_signatures(&["from_entries"])
+    .with_manual("to_entries, from_entries, with_entries(f)"),
+    TooltipContent::new(
+        "paths",
+        "Get all paths in structure",
+        &[
+            "paths                   # all paths in structure",
+            "[paths(type == \"string\")] # paths to all strings",
+        ],
+        Some("Use with getpath/setpath for dynamic access"),
+    )
+    .with_signatures(&["paths", "paths(f)"])
+    .with_manual("paths, paths(node_filter)"),
+    TooltipContent::new(
+        "leaf_paths",
+        "Get paths to leaf values only",
+        &[
+            "[paths(scalars)]        # recommended alternative",
+            "leaf_paths              # deprecated - avoid",
+        ],
+        Some("DEPRECATED in jq 1.7+; use paths(scalars) instead"),
+    )
+    .with_signatures(&["leaf_paths"])
+    .with_manual("paths, paths(node_filter)"),
+    // ===== Type Functions (no arguments) =====
+    TooltipContent::new(
+        "type",
+        "Get the type name of a value",
+        &[
+            "type                    # \"string\", \"number\", etc.",
+            "select(type == \"object\") # filter by type",
+            ".[] | select(type != \"null\") # remove nulls",
+            "group_by(type)          # group by type",
+        ],
+        Some("Use arrays, objects, etc. for type filtering - cleaner and faster"),
+    )
+    .with_signatures(&["type"])
+    .with_manual("type"),
+    TooltipContent::new(
+        "tostring",
+        "Convert to string",
+        &[
+            ".id | tostring          # ensure string type",
+            "(.count | tostring) + \" items\" # string concatenation",
+        ],
+        Some("Use @json for JSON-encoded string; tostring on strings is no-op"),
+    )
+    .with_signatures(&["tostring"])
+    .with_manual("tostring"),
+    TooltipContent::new(
+        "tonumber",
+        "Convert to number",
+        &[
+            ".price | tonumber       # parse string to number",
+            ".amount | tonumber? // 0 # safe parse with default",
+        ],
+        Some("Throws error on invalid input - use tonumber? // default"),
+    )
+    .with_signatures(&["tonumber"])
+    .with_manual("tonumber"),
+    TooltipContent::new(
+        "tojson",
+        "Convert value to JSON string",
+        &[
+            "tojson                  # encode value as JSON string",
+            ".config | tojson        # serialize object to JSON",
+            ".data | tojson | @base64 # encode JSON as base64",
+        ],
+        Some("Use @json format string for similar effect; tojson always valid JSON"),
+    )
+    .with_signatures(&["tojson"])
+    .with_manual("tojson, fromjson"),
+    TooltipContent::new(
+        "fromjson",
+        "Parse JSON string to value",
+        &[
+            "fromjson                # parse JSON string",
+            ".payload | fromjson     # decode JSON field",
+            ".json_str | fromjson? // {} # safe parse with default",
+        ],
+        Some("Throws error on invalid JSON - use fromjson? // default for safety"),
+    )
+    .with_signatures(&["fromjson"])
+    .with_manual("tojson, fromjson"),
+    TooltipContent::new(
+        "arrays",
+        "Filter to keep only arrays",
+        &[".[] | arrays            # keep only arrays"],
+        Some("Cleaner than select(type == \"array\")"),
+    )
+    .with_signatures(&["arrays"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "objects",
+        "Filter to keep only objects",
+        &[
+            ".[] | objects           # keep only objects",
+            ".[] | objects | .name   # names from object children only",
+        ],
+        Some("Cleaner than select(type == \"object\")"),
+    )
+    .with_signatures(&["objects"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "iterables",
+        "Filter to keep arrays and objects",
+        &[
+            "iterables               # arrays and objects",
+            ".[] | iterables         # only nested structures",
+        ],
+        Some("Opposite of scalars"),
+    )
+    .with_signatures(&["iterables"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "booleans",
+        "Filter to keep only booleans",
+        &[".[] | booleans          # keep only booleans"],
+        Some("Cleaner than select(type == \"boolean\")"),
+    )
+    .with_signatures(&["booleans"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "numbers",
+        "Filter to keep only numbers",
+        &[".[] | numbers           # keep only numbers"],
+        Some("Cleaner than select(type == \"number\")"),
+    )
+    .with_signatures(&["numbers"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "strings",
+        "Filter to keep only strings",
+        &[".[] | strings           # keep only strings"],
+        Some("Cleaner than select(type == \"string\")"),
+    )
+    .with_signatures(&["strings"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "nulls",
+        "Filter to keep only nulls",
+        &[".[] | nulls             # keep only nulls"],
+        Some("Use // to provide default for null values"),
+    )
+    .with_signatures(&["nulls"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "scalars",
+        "Filter to keep non-iterable values (primitives)",
+        &[
+            ".. | scalars            # all leaf values in tree",
+            "[.. | scalars] | unique # all unique primitive values",
+        ],
+        Some("Opposite of iterables; perfect for extracting leaf values"),
+    )
+    .with_signatures(&["scalars"])
+    .with_manual("arrays, objects, iterables, booleans, numbers, normals, finites, strings, nulls, values, scalars"),
+    TooltipContent::new(
+        "toarray",
+        "Wrap a value in an array unless it already is one",
+        &[
+            "toarray # 1 -> [1], [1] -> [1]",
+            ".tags | toarray | join(\",\") # tag or list of tags",
+        ],
+        Some("Needs jq 1.7.1 or later; older jq: if type == \"array\" then . else [.] end"),
+    )
+    .with_signatures(&["toarray"])
+    .with_manual("toarray"),
+    // ===== Math Functions (no arguments) =====
+    TooltipContent::new(
+        "floor",
+        "Round down toward negative infinity",
+        &[
+            "floor                   # 2.7 -> 2, -2.7 -> -3",
+            ". * 100 | floor / 100   # truncate to 2 decimals",
+        ],
+        Some("Rounds toward negative infinity (not toward zero)"),
+    )
+    .with_signatures(&["floor"])
+    .with_manual("Math"),
+    TooltipContent::new(
+        "ceil",
+        "Round up toward positive infinity",
+        &[
+            "ceil                    # 2.1 -> 3, -2.1 -> -2",
+            ". * 100 | ceil / 100    # round up to 2 decimals",
+        ],
+        Some("Rounds toward positive infinity"),
+    )
+    .with_signatures(&["ceil"])
+    .with_manual("Math"),
+    TooltipContent::new(
+        "round",
+        "Round to nearest integer",
+        &[
+            "round                   # 2.5 -> 3, 2.4 -> 2",
+            ". * 100 | round / 100   # round to 2 decimal places",
+        ],
+        Some("Rounds half away from zero"),
+    )
+    .with_signatures(&["round"])
+    .with_manual("Math"),
+    TooltipContent::new(
+        "sqrt",
+        "Square root",
+        &[
+            "sqrt                    # 16 -> 4",
+            ". | sqrt | floor        # integer square root",
+        ],
+        Some("Returns float even for perfect squares; use floor/round"),
+    )
+    .with_signatures(&["sqrt"])
+    .with_manual("Math"),
+    TooltipContent::new(
+        "abs",
+        "Absolute value",
+        &[
+            "abs                     # -5 -> 5",
+            "(.a - .b) | abs         # distance between values",
+        ],
+        Some("Works on numbers only"),
+    )
+    .with_signatures(&["abs"])
+    .with_manual("abs"),
+    // ===== Other Functions (no arguments) =====
+    TooltipContent::new(
+        "now",
+        "Current Unix timestamp",
+        &[
+            "now                     # current timestamp (float)",
+            "now | floor             # current timestamp (integer)",
+            "now | strftime(\"%Y-%m-%d\") # today's date",
+            "now | todate            # current time as ISO 8601",
+        ],
+        Some("Returns seconds since epoch as FLOAT with microsecond precision"),
+    )
+    .with_signatures(&["now"])
+    .with_manual("Dates"),
+    TooltipContent::new(
+        "empty",
+        "Produce no output (filter out current value)",
+        &[
+            "empty                   # produces nothing",
+            "if .skip then empty else . end # conditionally omit",
+            "select(. >= 0)          # same as above, cleaner",
+        ],
+        Some("Useful in conditionals; often select() is cleaner"),
+    )
+    .with_signatures(&["empty"])
+    .with_manual("empty"),
+    TooltipContent::new(
+        "error",
+        "Raise an error and stop processing",
+        &[
+            "error(\"Invalid input\") # error with message",
+            "if .required == null then error(\"Missing field\") else . end",
+            "try .data catch error(\"No data\") # re-throw with message",
+        ],
+        Some("Catch with: try expr catch \"fallback\"; errors go to stderr"),
+    )
+    .with_signatures(&["error", "error(message)"])
+    .with_manual("error, error(message)"),
+    TooltipContent::new(
+        "not",
+        "Logical NOT",
+        &[
+            "not                     # invert boolean",
+            "select(.active | not)   # select inactive items",
+            "select(has(\"error\") | not) # objects without error field",
+        ],
+        Some("Note: 0 and \"\" are TRUTHY in jq (unlike JavaScript)"),
+    )
+    .with_signatures(&["not"])
+    .with_manual("and, or, not"),
+    TooltipContent::new(
+        "ascii_downcase",
+        "Convert ASCII letters to lowercase",
+        &[
+            "ascii_downcase          # \"Hello\" -> \"hello\"",
+            ".name | ascii_downcase  # normalize for comparison",
+        ],
+        Some("ASCII only (a-z, A-Z); non-ASCII chars unchanged"),
+    )
+    .with_signatures(&["ascii_downcase"])
+    .with_manual("ascii_downcase, ascii_upcase"),
+    TooltipContent::new(
+        "ascii_upcase",
+        "Convert ASCII letters to uppercase",
+        &[
+            "ascii_upcase            # \"hello\" -> \"HELLO\"",
+            "select(.code | ascii_upcase == \"USA\") # case-insensitive",
+        ],
+        Some("ASCII only (a-z, A-Z); non-ASCII chars unchanged"),
+    )
+    .with_signatures(&["ascii_upcase"])
+    .with_manual("ascii_downcase, ascii_upcase"),
+    TooltipContent::new(
+        "env",
+        "Access environment variables",
+        &[
+            "env.HOME                # get HOME variable",
+            "env.USER                # get current username",
+            "env.API_KEY // error(\"API_KEY not set\") # required env var",
+        ],
+        Some("Use $ENV for object of all env vars"),
+    )
+    .with_signatures(&["env"])
+    .with_manual("$ENV, env"),
+    TooltipContent::new(
+        "input",
+        "Read the next input value",
+        &[
+            "[., input] # pair the first two inputs",
+            "input.id # field of the next input",
+        ],
+        Some("Errors when no input is left; jiq passes a single document"),
+    )
+    .with_signatures(&["input"])
+    .with_manual("input"),
+    TooltipContent::new(
+        "inputs",
+        "Output all remaining input values",
+        &[
+            "[inputs] # collect the remaining inputs",
+            "reduce inputs as $x (.; . + $x) # sum every input",
+        ],
+        Some("Usually combined with jq -n to handle each input yourself"),
+    )
+    .with_signatures(&["inputs"])
+    .with_manual("inputs"),
+    TooltipContent::new(
+        "debug",
+        "Print the input to stderr and pass it on unchanged",
+        &[
+            ".items | debug | length # inspect a midway value",
+            "debug(\"step 2\") # message first (jq 1.7+)",
+        ],
+        Some("Goes to stderr, so jiq's results pane does not show it"),
+    )
+    .with_signatures(&["debug", "debug(msg)"])
+    .with_manual("debug, debug(msgs)"),
+];
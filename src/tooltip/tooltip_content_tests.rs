@@ -132,3 +132,41 @@ fn test_all_metadata_functions_have_content() {
         missing
     );
 }
+
+#[test]
+fn test_all_highlighted_builtins_have_content() {
+    let missing: Vec<&str> = crate::syntax_highlight::BUILTIN_FUNCTIONS
+        .iter()
+        .copied()
+        .filter(|name| get_tooltip_content(name).is_none())
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "Missing tooltip content for highlighted builtins: {:?}",
+        missing
+    );
+}
+
+#[test]
+fn test_all_content_has_signatures_and_manual_section() {
+    for content in TOOLTIP_CONTENT.iter() {
+        assert!(
+            !content.signatures.is_empty(),
+            "{} has no signatures",
+            content.function
+        );
+        for signature in content.signatures {
+            assert!(
+                signature.starts_with(content.function),
+                "{} signature '{}' should start with the function name",
+                content.function,
+                signature
+            );
+        }
+        assert!(
+            content.manual.is_some_and(|section| !section.is_empty()),
+            "{} has no jq manual section",
+            content.function
+        );
+    }
+}
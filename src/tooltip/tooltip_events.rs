@@ -1,3 +1,5 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
 use super::tooltip_state::TooltipState;
 
 pub fn handle_tooltip_toggle(state: &mut TooltipState) -> bool {
@@ -5,6 +7,31 @@ pub fn handle_tooltip_toggle(state: &mut TooltipState) -> bool {
     true
 }
 
+/// F3: open the detailed view of the shown tooltip, or close it
+///
+/// Returns false when there is no tooltip to expand.
+pub fn handle_detail_toggle(state: &mut TooltipState) -> bool {
+    if !state.has_detail() {
+        return false;
+    }
+    state.toggle_detail();
+    true
+}
+
+/// Keys for the open detailed view: arrows and PageUp/PageDown scroll,
+/// Esc and F3 close it; everything else passes through to the query
+pub fn handle_detail_key(state: &mut TooltipState, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Esc | KeyCode::F(3) => state.close_detail(),
+        KeyCode::Down => state.detail_scroll.scroll_down(1),
+        KeyCode::Up => state.detail_scroll.scroll_up(1),
+        KeyCode::PageDown => state.detail_scroll.page_down(),
+        KeyCode::PageUp => state.detail_scroll.page_up(),
+        _ => return false,
+    }
+    true
+}
+
 #[cfg(test)]
 #[path = "tooltip_events_tests.rs"]
 mod tooltip_events_tests;
@@ -1,6 +1,7 @@
 //! Tests for tooltip/tooltip_events
 
 use super::*;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
 #[test]
 fn test_handle_tooltip_toggle_from_enabled() {
@@ -33,3 +34,43 @@ fn test_handle_tooltip_toggle_preserves_current_function() {
 
     assert_eq!(state.current_function, Some("select".to_string()));
 }
+
+#[test]
+fn test_detail_toggle_needs_a_tooltip() {
+    let mut state = TooltipState::new(true);
+    assert!(!handle_detail_toggle(&mut state));
+    assert!(!state.detail_visible);
+
+    state.set_current_function(Some("range".to_string()));
+    assert!(handle_detail_toggle(&mut state));
+    assert!(state.detail_visible);
+}
+
+#[test]
+fn test_detail_keys_scroll_and_close() {
+    let mut state = TooltipState::new(true);
+    state.set_current_function(Some("range".to_string()));
+    state.toggle_detail();
+    state.detail_scroll.update_bounds(20, 5);
+
+    assert!(handle_detail_key(&mut state, KeyEvent::from(KeyCode::Down)));
+    assert!(handle_detail_key(&mut state, KeyEvent::from(KeyCode::Down)));
+    assert!(handle_detail_key(&mut state, KeyEvent::from(KeyCode::Up)));
+    assert_eq!(state.detail_scroll.offset, 1);
+
+    assert!(handle_detail_key(&mut state, KeyEvent::from(KeyCode::Esc)));
+    assert!(!state.detail_visible);
+}
+
+#[test]
+fn test_detail_passes_other_keys_through() {
+    let mut state = TooltipState::new(true);
+    state.set_current_function(Some("range".to_string()));
+    state.toggle_detail();
+
+    assert!(!handle_detail_key(
+        &mut state,
+        KeyEvent::from(KeyCode::Char('a'))
+    ));
+    assert!(state.detail_visible);
+}
@@ -6,10 +6,14 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Padding, Paragraph},
 };
 
+use unicode_width::UnicodeWidthStr;
+
 use crate::app::App;
+use crate::str_utils::truncate_to_width;
 use crate::theme;
-use crate::tooltip::{get_operator_content, get_tooltip_content};
-use crate::widgets::popup;
+use crate::tooltip::tooltip_content::TooltipContent;
+use crate::tooltip::{FieldInfo, get_operator_content, get_tooltip_content};
+use crate::widgets::{popup, scrollbar};
 
 const TOOLTIP_MIN_WIDTH: u16 = 40;
 const TOOLTIP_MAX_WIDTH: u16 = 90;
@@ -17,8 +21,22 @@ const TOOLTIP_BORDER_HEIGHT: u16 = 4; // top border (1) + padding (1) + bottom b
 const TOOLTIP_BORDER_WIDTH: u16 = 6; // left border (1) + padding (2) + right border (1) + padding (2)
 const TOOLTIP_MIN_HEIGHT: u16 = 8;
 const TOOLTIP_MAX_HEIGHT: u16 = 18;
+/// Width of the "Type" / "Length" / "Sample" column in the field tooltip
+const FIELD_LABEL_WIDTH: usize = 8;
 
 fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
+    let mut lines = wrap_all(text, max_width);
+
+    // Limit to 2 lines max for tips
+    if lines.len() > 2 {
+        lines.truncate(2);
+    }
+
+    lines
+}
+
+/// Word-wrap `text` to `max_width` columns without a line limit
+fn wrap_all(text: &str, max_width: usize) -> Vec<String> {
     if text.len() <= max_width {
         return vec![text.to_string()];
     }
@@ -42,37 +60,24 @@ fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
         lines.push(current_line);
     }
 
-    // Limit to 2 lines max for tips
-    if lines.len() > 2 {
-        lines.truncate(2);
-    }
-
     lines
 }
 
-/// Render the tooltip popup
-///
-/// Returns the popup area for region tracking.
-pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Rect> {
-    // Determine what to show: function takes priority over operator
-    let (title_prefix, name, content) = if let Some(func) = &app.tooltip.current_function {
-        if let Some(c) = get_tooltip_content(func) {
-            ("fn", func.as_str(), c)
-        } else {
-            return None;
-        }
+/// The tooltip for the cursor: title prefix, name and content.
+/// Functions take priority over operators.
+fn shown_content(app: &App) -> Option<(&'static str, &str, &'static TooltipContent)> {
+    if let Some(func) = &app.tooltip.current_function {
+        get_tooltip_content(func).map(|c| ("fn", func.as_str(), c))
     } else if let Some(op) = &app.tooltip.current_operator {
-        if let Some(c) = get_operator_content(op) {
-            ("operator", op.as_str(), c)
-        } else {
-            return None;
-        }
+        get_operator_content(op).map(|c| ("operator", op.as_str(), c))
     } else {
-        return None;
-    };
+        None
+    }
+}
 
-    // Parse examples into (code, description) pairs
-    let parsed_examples: Vec<(&str, &str)> = content
+/// Split examples into (code, description) pairs at the `#`
+fn parse_examples(content: &TooltipContent) -> Vec<(&'static str, &'static str)> {
+    content
         .examples
         .iter()
         .map(|e| {
@@ -82,7 +87,82 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
                 (*e, "")
             }
         })
-        .collect();
+        .collect()
+}
+
+/// Examples in two columns: code │ description
+fn example_lines(parsed_examples: &[(&'static str, &'static str)]) -> Vec<Line<'static>> {
+    let max_code_width = parsed_examples
+        .iter()
+        .map(|(code, _)| code.len())
+        .max()
+        .unwrap_or(0);
+
+    parsed_examples
+        .iter()
+        .map(|(code, desc)| {
+            if desc.is_empty() {
+                // No description, just show code
+                Line::from(vec![Span::styled(
+                    format!("  {}", code),
+                    Style::default().fg(theme::tooltip::example()),
+                )])
+            } else {
+                // Two-column: code (padded) │ description
+                let padded_code = format!("{:width$}", code, width = max_code_width);
+                Line::from(vec![
+                    Span::styled(
+                        format!("  {}", padded_code),
+                        Style::default().fg(theme::tooltip::example()),
+                    ),
+                    Span::styled(" │ ", Style::default().fg(theme::tooltip::separator())),
+                    Span::styled(*desc, Style::default().fg(theme::tooltip::example_desc())),
+                ])
+            }
+        })
+        .collect()
+}
+
+/// Tip lines, the first with the 💡 prefix and the rest aligned after it
+fn tip_lines(wrapped_tip_lines: &[String]) -> Vec<Line<'static>> {
+    wrapped_tip_lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 {
+                Span::styled("💡 ", Style::default())
+            } else {
+                Span::raw("   ") // 3 spaces to align with text after emoji
+            };
+            Line::from(vec![
+                prefix,
+                Span::styled(line.clone(), Style::default().fg(theme::tooltip::tip())),
+            ])
+        })
+        .collect()
+}
+
+/// Title with prefix and name, e.g. "fn: select" or "operator: //"
+fn title_line(title_prefix: &str, name: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::raw(" "),
+        Span::styled(
+            format!("{}: {}", title_prefix, name),
+            theme::tooltip::title(),
+        ),
+        Span::raw(" "),
+    ])
+}
+
+/// Render the tooltip popup
+///
+/// Returns the popup area for region tracking.
+pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Rect> {
+    if let Some(field) = &app.tooltip.current_field {
+        return Some(render_field_popup(field, frame, input_area));
+    }
+    let (title_prefix, name, content) = shown_content(app)?;
+    let parsed_examples = parse_examples(content);
 
     // Calculate the max code width for alignment
     let max_code_width = parsed_examples
@@ -166,63 +246,24 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
     lines.push(Line::from(""));
 
     // Examples with two-column layout: code │ description
-    for (code, desc) in &parsed_examples {
-        if desc.is_empty() {
-            // No description, just show code
-            lines.push(Line::from(vec![Span::styled(
-                format!("  {}", code),
-                Style::default().fg(theme::tooltip::example()),
-            )]));
-        } else {
-            // Two-column: code (padded) │ description
-            let padded_code = format!("{:width$}", code, width = max_code_width);
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("  {}", padded_code),
-                    Style::default().fg(theme::tooltip::example()),
-                ),
-                Span::styled(" │ ", Style::default().fg(theme::tooltip::separator())),
-                Span::styled(*desc, Style::default().fg(theme::tooltip::example_desc())),
-            ]));
-        }
-    }
+    lines.extend(example_lines(&parsed_examples));
 
     // Optional tip (with wrapping)
     if content.tip.is_some() && !wrapped_tip_lines.is_empty() {
         lines.push(Line::from(""));
-        // First line with emoji prefix
-        lines.push(Line::from(vec![
-            Span::styled("💡 ", Style::default()),
-            Span::styled(
-                wrapped_tip_lines[0].clone(),
-                Style::default().fg(theme::tooltip::tip()),
-            ),
-        ]));
-        // Subsequent lines with spacing to align with first line
-        for line in wrapped_tip_lines.iter().skip(1) {
-            lines.push(Line::from(vec![
-                Span::raw("   "), // 3 spaces to align with text after emoji
-                Span::styled(line.clone(), Style::default().fg(theme::tooltip::tip())),
-            ]));
-        }
+        lines.extend(tip_lines(&wrapped_tip_lines));
     }
 
     let text = Text::from(lines);
 
     // Build title with prefix and name in purple (left side)
-    // Format: "fn: select" or "operator: //"
-    let title = Line::from(vec![
-        Span::raw(" "),
-        Span::styled(
-            format!("{}: {}", title_prefix, name),
-            theme::tooltip::title(),
-        ),
-        Span::raw(" "),
-    ]);
+    let title = title_line(title_prefix, name);
 
     // Build dismiss hint for bottom-center of border
-    let dismiss_hint =
-        theme::border_hints::build_hints(&[("Ctrl+I", "Dismiss")], theme::tooltip::border());
+    let dismiss_hint = theme::border_hints::build_hints(
+        &[("Ctrl+I", "Dismiss"), ("F3", "More")],
+        theme::tooltip::border(),
+    );
 
     // Create the popup widget with purple border
     // Title on top-left, dismiss hint on bottom-center, padding inside
@@ -242,6 +283,240 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
     Some(popup_area)
 }
 
+/// Render the field tooltip: type, array length and a sample value
+fn render_field_popup(field: &FieldInfo, frame: &mut Frame, input_area: Rect) -> Rect {
+    let mut rows = vec![("Type", field.field_type.to_string())];
+    if let Some(length) = field.length {
+        rows.push(("Length", length.to_string()));
+    }
+    rows.push(("Sample", field.sample.clone()));
+
+    let title_width = "field: ".len() + field.path.width() + 2;
+    let content_width = rows
+        .iter()
+        .map(|(_, value)| FIELD_LABEL_WIDTH + value.width())
+        .max()
+        .unwrap_or(0)
+        .max(title_width);
+
+    let frame_area = frame.area();
+    let max_allowed_width = (frame_area.width * 3) / 4;
+    let popup_width = ((content_width as u16) + TOOLTIP_BORDER_WIDTH)
+        .clamp(TOOLTIP_MIN_WIDTH, TOOLTIP_MAX_WIDTH)
+        .min(max_allowed_width);
+    let value_width = (popup_width.saturating_sub(TOOLTIP_BORDER_WIDTH) as usize)
+        .saturating_sub(FIELD_LABEL_WIDTH);
+    let popup_height = rows.len() as u16 + TOOLTIP_BORDER_HEIGHT;
+
+    let popup_area = Rect {
+        x: frame_area.width.saturating_sub(popup_width + 2),
+        y: input_area.y.saturating_sub(popup_height),
+        width: popup_width,
+        height: popup_height.min(input_area.y),
+    };
+    popup::clear_area(frame, popup_area);
+
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .map(|(label, value)| {
+            let value_color = if label == "Sample" {
+                theme::tooltip::example()
+            } else {
+                theme::tooltip::description()
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{:width$}", label, width = FIELD_LABEL_WIDTH),
+                    Style::default().fg(theme::tooltip::separator()),
+                ),
+                Span::styled(
+                    truncate_to_width(&value, value_width),
+                    Style::default().fg(value_color),
+                ),
+            ])
+        })
+        .collect();
+
+    let dismiss_hint =
+        theme::border_hints::build_hints(&[("Ctrl+I", "Dismiss")], theme::tooltip::border());
+    let popup_widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(title_line("field", &field.path))
+            .title_bottom(dismiss_hint.alignment(Alignment::Center))
+            .border_style(Style::default().fg(theme::tooltip::border()))
+            .style(Style::default().bg(theme::tooltip::background()))
+            .padding(Padding::uniform(1)),
+    );
+    frame.render_widget(popup_widget, popup_area);
+
+    popup_area
+}
+
+/// Render the detailed view of the tooltip (F3)
+///
+/// Shows every signature, all examples, the whole tip and the jq manual
+/// section, scrolling when taller than the space above the input.
+/// Returns the popup area for region tracking.
+pub fn render_detail(app: &mut App, frame: &mut Frame, input_area: Rect) -> Option<Rect> {
+    let (title_prefix, name, content) = shown_content(app)?;
+    let title = title_line(title_prefix, name);
+    let parsed_examples = parse_examples(content);
+
+    let max_code_width = parsed_examples
+        .iter()
+        .map(|(code, _)| code.len())
+        .max()
+        .unwrap_or(0);
+    let max_example_width = parsed_examples
+        .iter()
+        .map(|(code, desc)| {
+            if desc.is_empty() {
+                code.len() + 2
+            } else {
+                max_code_width + 3 + desc.len() + 2
+            }
+        })
+        .max()
+        .unwrap_or(0);
+    let max_signature_width = content
+        .signatures
+        .iter()
+        .map(|s| s.len() + 2)
+        .max()
+        .unwrap_or(0);
+    let content_width = content
+        .description
+        .len()
+        .max(max_example_width)
+        .max(max_signature_width)
+        .max(title.width());
+
+    let frame_area = frame.area();
+    let max_allowed_width = (frame_area.width * 3) / 4;
+    let width = ((content_width as u16) + TOOLTIP_BORDER_WIDTH)
+        .clamp(TOOLTIP_MIN_WIDTH, TOOLTIP_MAX_WIDTH)
+        .min(max_allowed_width);
+    let text_width = width.saturating_sub(TOOLTIP_BORDER_WIDTH) as usize;
+
+    let lines = detail_lines(content, text_width);
+    let content_height = lines.len() as u16;
+    let height = (content_height + TOOLTIP_BORDER_HEIGHT).min(input_area.y);
+
+    let popup_area = Rect {
+        x: frame_area.width.saturating_sub(width + 2),
+        y: input_area.y.saturating_sub(height),
+        width,
+        height,
+    };
+    popup::clear_area(frame, popup_area);
+
+    let hints = theme::border_hints::build_hints(
+        &[("↑↓", "Scroll"), ("Enter", "Manual"), ("F3", "Close")],
+        theme::tooltip::border(),
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(hints.alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::tooltip::border()))
+        .style(Style::default().bg(theme::tooltip::background()))
+        .padding(Padding::uniform(1));
+
+    let viewport_height = block.inner(popup_area).height;
+    let scroll = &mut app.tooltip.detail_scroll;
+    scroll.update_bounds(content_height as u32, viewport_height);
+    let offset = scroll.offset;
+
+    let popup_widget = Paragraph::new(Text::from(lines))
+        .block(block)
+        .scroll((offset, 0));
+    frame.render_widget(popup_widget, popup_area);
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        viewport_height as usize,
+        offset as usize,
+        theme::tooltip::border(),
+    );
+
+    Some(popup_area)
+}
+
+/// Lines of the detailed view, with prose wrapped to `text_width`
+fn detail_lines(content: &'static TooltipContent, text_width: usize) -> Vec<Line<'static>> {
+    let mut lines = reference_lines(content, text_width);
+
+    if let Some(manual) = content.manual {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "jq manual",
+            Style::default().fg(theme::tooltip::separator()),
+        )));
+        for line in wrap_all(manual, text_width.saturating_sub(2)) {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", line),
+                Style::default().fg(theme::tooltip::description()),
+            )));
+        }
+    }
+
+    lines
+}
+
+/// Description, signatures, examples and tip of `content`, with prose
+/// wrapped to `text_width`; shared with the help popup's manual browser
+pub(crate) fn reference_lines(
+    content: &'static TooltipContent,
+    text_width: usize,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = wrap_all(content.description, text_width)
+        .into_iter()
+        .map(|line| {
+            Line::from(Span::styled(
+                line,
+                Style::default().fg(theme::tooltip::description()),
+            ))
+        })
+        .collect();
+
+    if !content.signatures.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Usage", theme::tooltip::title())));
+        for signature in content.signatures {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", signature),
+                Style::default().fg(theme::tooltip::example()),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Examples",
+        theme::tooltip::title(),
+    )));
+    lines.extend(example_lines(&parse_examples(content)));
+
+    if let Some(tip) = content.tip {
+        lines.push(Line::from(""));
+        // Leave room for the emoji prefix
+        lines.extend(tip_lines(&wrap_all(tip, text_width.saturating_sub(3))));
+    }
+
+    lines
+}
+
 #[cfg(test)]
 pub fn format_tooltip_title(is_function: bool, name: &str) -> String {
     if is_function {
@@ -238,3 +238,100 @@ fn render_popup_renders_multiline_wrapped_tip() {
         "wrapped tip continuation line should be rendered, got:\n{screen}"
     );
 }
+
+/// Render `render_detail` the same way `render_tooltip` renders the popup
+fn render_detail_view(app: &mut App, width: u16, height: u16) -> (Option<Rect>, String) {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let input_area = Rect::new(0, height.saturating_sub(3), width, 3);
+
+    let mut detail_area = None;
+    terminal
+        .draw(|f| {
+            detail_area = render_detail(app, f, input_area);
+        })
+        .unwrap();
+
+    (detail_area, terminal.backend().to_string())
+}
+
+#[test]
+fn render_popup_hints_detail_view() {
+    let app = app_with_tooltip_function("range");
+    let (_, screen) = render_tooltip(&app, 80, 24);
+    assert!(
+        screen.contains("F3"),
+        "popup should hint F3, got:\n{screen}"
+    );
+}
+
+#[test]
+fn snapshot_render_detail_range() {
+    let mut app = app_with_tooltip_function("range");
+    app.tooltip.toggle_detail();
+    let (area, screen) = render_detail_view(&mut app, 100, 30);
+
+    assert!(area.is_some());
+    insta::assert_snapshot!(screen);
+}
+
+#[test]
+fn render_detail_lists_every_signature_and_manual_section() {
+    let mut app = app_with_tooltip_function("range");
+    app.tooltip.toggle_detail();
+    let (_, screen) = render_detail_view(&mut app, 100, 30);
+
+    for signature in ["range(upto)", "range(from; upto)", "range(from; upto; by)"] {
+        assert!(screen.contains(signature), "missing {signature}:\n{screen}");
+    }
+    assert!(
+        screen.contains("jq manual"),
+        "missing manual section:\n{screen}"
+    );
+}
+
+#[test]
+fn render_detail_scrolls_when_taller_than_the_space() {
+    let mut app = app_with_tooltip_function("range");
+    app.tooltip.toggle_detail();
+    let (_, top) = render_detail_view(&mut app, 100, 12);
+    assert!(app.tooltip.detail_scroll.max_offset > 0);
+
+    app.tooltip.detail_scroll.scroll_down(100);
+    let (_, bottom) = render_detail_view(&mut app, 100, 12);
+
+    assert!(!top.contains("jq manual"));
+    assert!(bottom.contains("jq manual"));
+}
+
+#[test]
+fn snapshot_render_field_tooltip() {
+    let mut app = test_app("{}");
+    app.tooltip.current_field = Some(FieldInfo {
+        path: ".users[].tags".to_string(),
+        field_type: crate::autocomplete::JsonFieldType::ArrayOf(Box::new(
+            crate::autocomplete::JsonFieldType::String,
+        )),
+        length: Some(2),
+        sample: r#"["admin","dev"]"#.to_string(),
+    });
+    let (area, screen) = render_tooltip(&app, 80, 14);
+
+    assert!(area.is_some());
+    insta::assert_snapshot!(screen);
+}
+
+#[test]
+fn render_field_tooltip_truncates_long_sample() {
+    let mut app = test_app("{}");
+    app.tooltip.current_field = Some(FieldInfo {
+        path: ".text".to_string(),
+        field_type: crate::autocomplete::JsonFieldType::String,
+        length: None,
+        sample: format!("\"{}\"", "x".repeat(118)),
+    });
+    let (area, screen) = render_tooltip(&app, 80, 14);
+
+    assert!(area.unwrap().width <= 60);
+    assert!(screen.contains('…'), "sample should be cut:\n{screen}");
+}
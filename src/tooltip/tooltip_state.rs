@@ -1,26 +1,51 @@
 use crate::app::App;
-use crate::tooltip::{detect_function_at_cursor, detect_operator_at_cursor};
+use crate::tooltip::{
+    FieldInfo, detect_function_at_cursor, detect_operator_at_cursor, field_info_at_cursor,
+};
 
-/// Update tooltip state based on cursor position. Functions take priority over operators.
+/// Update tooltip state based on cursor position. A field the input holds
+/// takes priority over the function around it; functions take priority
+/// over operators.
 pub fn update_tooltip_from_app(app: &mut App) {
     let query = app.input.query();
     let cursor_pos = app.input.textarea.cursor().1; // Column position
 
-    // Detect function (takes priority)
-    let detected_function = detect_function_at_cursor(query, cursor_pos);
+    // Until the input analysis is ready only functions and operators get
+    // a tooltip; `App::tick` looks again then
+    let analysis_pending = app
+        .query
+        .as_ref()
+        .is_some_and(|q| q.executor.input_analysis_pending());
+    app.input_analysis_pending |= analysis_pending;
+    let detected_field = app
+        .query
+        .as_ref()
+        .filter(|_| !analysis_pending)
+        .and_then(|q| q.executor.json_input_parsed())
+        .and_then(|json| field_info_at_cursor(query, cursor_pos, &json, app.array_sample_size));
+
+    // Detect function only if no field detected
+    let detected_function = if detected_field.is_none() {
+        detect_function_at_cursor(query, cursor_pos)
+    } else {
+        None
+    };
     app.tooltip
         .set_current_function(detected_function.map(|s| s.to_string()));
 
     // Detect operator only if no function detected (function takes priority)
-    let detected_operator = if detected_function.is_none() {
+    let detected_operator = if detected_field.is_none() && detected_function.is_none() {
         detect_operator_at_cursor(query, cursor_pos)
     } else {
         None
     };
     app.tooltip
         .set_current_operator(detected_operator.map(|s| s.to_string()));
+    app.tooltip.current_field = detected_field;
 }
 
+use crate::scroll::ScrollState;
+
 pub struct TooltipState {
     /// Whether tooltip feature is enabled (shows automatically)
     pub enabled: bool,
@@ -28,6 +53,12 @@ pub struct TooltipState {
     pub current_function: Option<String>,
     /// Currently detected operator (if any)
     pub current_operator: Option<String>,
+    /// Field of the input under the cursor (if any)
+    pub current_field: Option<FieldInfo>,
+    /// Whether the detailed view (F3) replaces the short tooltip
+    pub detail_visible: bool,
+    /// Scroll position within the detailed view
+    pub detail_scroll: ScrollState,
 }
 
 impl TooltipState {
@@ -36,24 +67,61 @@ impl TooltipState {
             enabled: auto_show,
             current_function: None,
             current_operator: None,
+            current_field: None,
+            detail_visible: false,
+            detail_scroll: ScrollState::new(),
         }
     }
 
     pub fn toggle(&mut self) {
         self.enabled = !self.enabled;
+        self.detail_visible = false;
     }
 
     pub fn set_current_function(&mut self, func: Option<String>) {
+        if func != self.current_function {
+            self.detail_scroll.reset();
+        }
         self.current_function = func;
     }
 
     pub fn set_current_operator(&mut self, op: Option<String>) {
+        if op != self.current_operator {
+            self.detail_scroll.reset();
+        }
         self.current_operator = op;
     }
 
-    pub fn should_show(&self) -> bool {
+    /// Open the detailed view of the shown tooltip, or close it
+    pub fn toggle_detail(&mut self) {
+        self.detail_visible = !self.detail_visible;
+        self.detail_scroll.reset();
+    }
+
+    pub fn close_detail(&mut self) {
+        self.detail_visible = false;
+    }
+
+    /// Name of the function or operator whose tooltip is shown
+    pub fn shown_name(&self) -> Option<&str> {
+        self.current_function
+            .as_deref()
+            .or(self.current_operator.as_deref())
+    }
+
+    /// Whether the shown tooltip has a detailed view; fields have none
+    pub fn has_detail(&self) -> bool {
         self.enabled && (self.current_function.is_some() || self.current_operator.is_some())
     }
+
+    /// Whether the detailed view is open and has something to show
+    pub fn should_show_detail(&self) -> bool {
+        self.detail_visible && self.has_detail()
+    }
+
+    pub fn should_show(&self) -> bool {
+        self.has_detail() || (self.enabled && self.current_field.is_some())
+    }
 }
 
 impl Default for TooltipState {
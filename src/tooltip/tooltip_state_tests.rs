@@ -264,12 +264,13 @@ fn test_update_tooltip_inside_function_parens() {
     // Type a query with a function
     app.input.textarea.insert_str("select(.name)");
 
-    // Move cursor inside the parentheses (on ".name")
+    // Move cursor inside the parentheses, before ".name" (on the field
+    // itself the field tooltip wins)
     app.input
         .textarea
         .move_cursor(tui_textarea::CursorMove::Head);
-    for _ in 0..8 {
-        // Position 8 is inside parens
+    for _ in 0..7 {
+        // Position 7 is inside parens
         app.input
             .textarea
             .move_cursor(tui_textarea::CursorMove::Forward);
@@ -286,8 +287,8 @@ fn test_update_tooltip_no_function() {
     let json = r#"{"name": "test"}"#;
     let mut app = test_app(json);
 
-    // Type a query without a function
-    app.input.textarea.insert_str(".name");
+    // Type a query without a function, on a field the input lacks
+    app.input.textarea.insert_str(".other");
 
     update_tooltip_from_app(&mut app);
 
@@ -554,7 +555,9 @@ mod app_property_tests {
             let query = format!("{}{}{}", prefix, func_name, suffix);
             let func_start = prefix.len();
 
-            let json = r#"{"test": true}"#;
+            // No key shares a function name, so `.test` and the like stay
+            // function tooltips rather than field ones
+            let json = r#"{"a": true}"#;
             let mut app = test_app(json);
 
             // Insert the query
@@ -772,3 +775,104 @@ mod app_property_tests {
         }
     }
 }
+
+#[test]
+fn test_detail_shows_only_with_a_tooltip() {
+    let mut state = TooltipState::new(true);
+    state.toggle_detail();
+    assert!(!state.should_show_detail());
+
+    state.set_current_function(Some("range".to_string()));
+    assert!(state.should_show_detail());
+}
+
+#[test]
+fn test_disabling_tooltip_closes_detail() {
+    let mut state = TooltipState::new(true);
+    state.set_current_function(Some("range".to_string()));
+    state.toggle_detail();
+
+    state.toggle();
+    state.toggle();
+
+    assert!(!state.detail_visible);
+}
+
+#[test]
+fn test_detail_scroll_resets_when_function_changes() {
+    let mut state = TooltipState::new(true);
+    state.set_current_function(Some("range".to_string()));
+    state.toggle_detail();
+    state.detail_scroll.update_bounds(20, 5);
+    state.detail_scroll.scroll_down(3);
+
+    state.set_current_function(Some("range".to_string()));
+    assert_eq!(state.detail_scroll.offset, 3);
+
+    state.set_current_function(Some("limit".to_string()));
+    assert_eq!(state.detail_scroll.offset, 0);
+    assert!(state.detail_visible);
+}
+
+// ========== Field Tooltip Tests ==========
+
+fn sample_field() -> FieldInfo {
+    FieldInfo {
+        path: ".name".to_string(),
+        field_type: crate::autocomplete::JsonFieldType::String,
+        length: None,
+        sample: "\"test\"".to_string(),
+    }
+}
+
+#[test]
+fn test_field_shows_without_detail_view() {
+    let mut state = TooltipState::new(true);
+    state.current_field = Some(sample_field());
+    state.toggle_detail();
+
+    assert!(state.should_show());
+    assert!(!state.has_detail());
+    assert!(!state.should_show_detail());
+}
+
+#[test]
+fn test_update_tooltip_shows_existing_field() {
+    let mut app = test_app(r#"{"user": {"tags": ["a", "b"]}}"#);
+    app.input.textarea.insert_str(".user.tags");
+
+    update_tooltip_from_app(&mut app);
+
+    let field = app.tooltip.current_field.as_ref().unwrap();
+    assert_eq!(field.path, ".user.tags");
+    assert_eq!(field.length, Some(2));
+    assert!(app.tooltip.should_show());
+}
+
+#[test]
+fn test_update_tooltip_field_wins_over_enclosing_function() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.input.textarea.insert_str("select(.name)");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Back);
+
+    update_tooltip_from_app(&mut app);
+
+    assert!(app.tooltip.current_function.is_none());
+    assert_eq!(app.tooltip.current_field.as_ref().unwrap().path, ".name");
+}
+
+#[test]
+fn test_update_tooltip_falls_back_to_function_for_missing_field() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.input.textarea.insert_str("select(.other)");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Back);
+
+    update_tooltip_from_app(&mut app);
+
+    assert!(app.tooltip.current_field.is_none());
+    assert_eq!(app.tooltip.current_function, Some("select".to_string()));
+}
@@ -1,2 +1,3 @@
+pub mod bar;
 pub mod popup;
 pub mod scrollbar;
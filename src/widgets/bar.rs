@@ -0,0 +1,21 @@
+//! Horizontal bars drawn with block characters, shared by the chart and
+//! frequency popups
+
+/// Partial blocks for the end of a bar, in eighths
+pub const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// A bar `cells` long, ending in a partial block
+pub fn bar(cells: f64) -> String {
+    let eighths = (cells * 8.0).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    bar.push_str(EIGHTHS[eighths % 8]);
+    // A value too small to draw still shows
+    if bar.is_empty() {
+        bar.push_str(EIGHTHS[1]);
+    }
+    bar
+}
+
+#[cfg(test)]
+#[path = "bar_tests.rs"]
+mod bar_tests;
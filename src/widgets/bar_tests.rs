@@ -0,0 +1,14 @@
+//! Tests for widgets/bar
+
+use super::bar;
+
+#[test]
+fn bar_ends_in_a_partial_block() {
+    assert_eq!(bar(2.5), "██▌");
+    assert_eq!(bar(0.01), "▏");
+}
+
+#[test]
+fn whole_cells_have_no_partial_block() {
+    assert_eq!(bar(3.0), "███");
+}
@@ -1,4 +1,14 @@
-use ratatui::{Frame, layout::Rect, widgets::Clear};
+use std::borrow::Cow;
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, BorderType, Borders, Clear, Padding},
+};
+
+use crate::theme;
 
 pub fn centered_popup(frame_area: Rect, width: u16, height: u16) -> Rect {
     let popup_width = width.min(frame_area.width);
@@ -15,6 +25,21 @@ pub fn centered_popup(frame_area: Rect, width: u16, height: u16) -> Rect {
     }
 }
 
+/// Center a `width` x `height` rect in `area`, shrunk to fit. Unlike
+/// [`centered_popup`], `area` need not start at the frame's origin.
+pub fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
 pub fn popup_above_anchor(anchor: Rect, width: u16, height: u16, x_offset: u16) -> Rect {
     let popup_x = anchor.x + x_offset;
     let popup_y = anchor.y.saturating_sub(height);
@@ -40,6 +65,28 @@ pub fn clear_area(frame: &mut Frame, area: Rect) {
     frame.render_widget(Clear, area);
 }
 
+/// The frame shared by the popups opened over the results: a rounded
+/// border in the focused color, a bold title and `hints` centered along
+/// the bottom
+pub fn popup_block<'a>(
+    title: impl Into<Cow<'a, str>>,
+    hints: &[(&'static str, &'static str)],
+) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme::results::border_focused()))
+        .padding(Padding::horizontal(1))
+        .title(Span::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(
+            theme::border_hints::build_hints(hints, theme::results::hint_key())
+                .alignment(Alignment::Center),
+        )
+}
+
 #[cfg(test)]
 #[path = "popup_tests.rs"]
 mod popup_tests;
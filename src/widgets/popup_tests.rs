@@ -19,6 +19,19 @@ fn test_centered_popup_basic() {
     assert_eq!(popup.height, 20);
 }
 
+#[test]
+fn test_centered_rect_is_centered_in_an_offset_area() {
+    let area = Rect {
+        x: 10,
+        y: 5,
+        width: 40,
+        height: 20,
+    };
+
+    assert_eq!(centered_rect(area, 20, 10), Rect::new(20, 10, 20, 10));
+    assert_eq!(centered_rect(area, 60, 30), area);
+}
+
 #[test]
 fn test_centered_popup_too_large_is_clamped() {
     let frame = Rect {
@@ -99,3 +112,12 @@ fn test_inset_rect_saturates() {
     assert_eq!(inset.width, 0);
     assert_eq!(inset.height, 0);
 }
+
+#[test]
+fn test_popup_block_pads_inside_the_border() {
+    let area = Rect::new(0, 0, 20, 5);
+
+    let inner = popup_block(" Title ", &[("Esc", "Close")]).inner(area);
+
+    assert_eq!(inner, Rect::new(2, 1, 16, 3));
+}
@@ -79,6 +79,18 @@ pub fn render_vertical_scrollbar(
     frame.render_stateful_widget(scrollbar, area, &mut state);
 }
 
+/// The scroll offset for a click or drag at `row` of a scrollbar track
+/// `track_height` rows tall: the top row is offset 0, the bottom row
+/// `max_scroll`, and rows in between are spread evenly
+pub fn offset_at(row: u16, track_height: u16, max_scroll: usize) -> usize {
+    let last_row = track_height.saturating_sub(1) as usize;
+    if last_row == 0 {
+        return 0;
+    }
+    let row = (row as usize).min(last_row);
+    (row * max_scroll + last_row / 2) / last_row
+}
+
 #[cfg(test)]
 #[path = "scrollbar_tests.rs"]
 mod scrollbar_tests;
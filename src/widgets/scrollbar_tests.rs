@@ -159,3 +159,21 @@ fn snapshot_scrollbar_position_simple_case() {
     let output = render_scrollbar_to_string(20, 10, 10, 10);
     assert_snapshot!(output);
 }
+
+#[test]
+fn test_offset_at_maps_track_ends_to_scroll_ends() {
+    assert_eq!(super::offset_at(0, 10, 90), 0);
+    assert_eq!(super::offset_at(9, 10, 90), 90);
+}
+
+#[test]
+fn test_offset_at_spreads_rows_evenly() {
+    assert_eq!(super::offset_at(3, 10, 90), 30);
+    assert_eq!(super::offset_at(1, 5, 10), 3);
+}
+
+#[test]
+fn test_offset_at_clamps_rows_past_the_track() {
+    assert_eq!(super::offset_at(40, 10, 90), 90);
+    assert_eq!(super::offset_at(3, 1, 90), 0);
+}
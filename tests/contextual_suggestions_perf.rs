@@ -0,0 +1,121 @@
+//! Performance gate for path-aware field suggestions.
+//!
+//! Builds a deep synthetic document and a long query, then asks for field
+//! suggestions at every cursor position, as moving the cursor through the
+//! query does, with one `NavigationCache` kept across keystrokes as the
+//! editor keeps it, and asserts the walk fits within budget and suggests
+//! what navigating from the root would. How the kept cache compares with
+//! a fresh one per keystroke is measured by the `cursor_walk` group in
+//! `cargo bench --bench keystroke`.
+
+use jiq::autocomplete::BraceTracker;
+use jiq::autocomplete::get_suggestions_with_variables;
+use jiq::autocomplete::json_navigator::{DEFAULT_ARRAY_SAMPLE_SIZE, NavigationCache};
+use serde_json::{Map, Value, json};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const DEPTH: usize = 12;
+const WIDTH: usize = 200;
+const PASSES: usize = 20;
+
+/// `{"level0": {"level1": ... {"leaf": 1}}}`, each level also holding
+/// `WIDTH` sibling fields and an array of objects
+fn build_fixture() -> Value {
+    let mut node = json!({"leaf": 1});
+    for depth in (0..DEPTH).rev() {
+        let mut map = Map::new();
+        for i in 0..WIDTH {
+            map.insert(format!("field_{}_{}", depth, i), json!(i));
+        }
+        map.insert(
+            "items".to_string(),
+            Value::Array((0..WIDTH).map(|i| json!({"id": i})).collect()),
+        );
+        map.insert(format!("level{}", depth + 1), node);
+        node = Value::Object(map);
+    }
+    node
+}
+
+fn long_query() -> String {
+    let path: String = (1..=DEPTH).map(|d| format!(".level{}", d)).collect();
+    format!("{path}.leaf | {path}.items[].id")
+}
+
+/// Field suggestions at every cursor position in `query`, `PASSES` times,
+/// one cache serving every keystroke
+fn walk_cursor(json: &Arc<Value>, query: &str) -> Duration {
+    let mut tracker = BraceTracker::new();
+    tracker.rebuild(query);
+    let field_names = Arc::new(HashSet::new());
+    let mut kept = NavigationCache::new();
+    let start = Instant::now();
+    for _ in 0..PASSES {
+        for cursor in 1..query.len() {
+            get_suggestions_with_variables(
+                &mut kept,
+                query,
+                cursor,
+                None,
+                None,
+                Some(Arc::clone(json)),
+                Arc::clone(&field_names),
+                &tracker,
+                DEFAULT_ARRAY_SAMPLE_SIZE,
+                true,
+                &[],
+            );
+        }
+    }
+    start.elapsed()
+}
+
+// The budget is deliberately loose so coverage / debug runs don't flake.
+
+#[test]
+fn contextual_suggestions_across_the_query_with_kept_cache() {
+    let json = Arc::new(build_fixture());
+    let elapsed = walk_cursor(&json, &long_query());
+    assert!(
+        elapsed.as_secs() < 60,
+        "cursor walk with a kept cache took {:?} (budget: 60s)",
+        elapsed
+    );
+    eprintln!("cursor walk, reusing navigated prefixes: {:?}", elapsed);
+}
+
+#[test]
+fn kept_cache_suggests_the_same_fields() {
+    let json = Arc::new(build_fixture());
+    let query = long_query();
+    let mut tracker = BraceTracker::new();
+    tracker.rebuild(&query);
+    let mut kept = NavigationCache::new();
+    for cursor in 1..query.len() {
+        let suggest = |cache: &mut NavigationCache| {
+            get_suggestions_with_variables(
+                cache,
+                &query,
+                cursor,
+                None,
+                None,
+                Some(Arc::clone(&json)),
+                Arc::new(HashSet::new()),
+                &tracker,
+                DEFAULT_ARRAY_SAMPLE_SIZE,
+                true,
+                &[],
+            )
+            .into_iter()
+            .map(|s| s.text)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            suggest(&mut kept),
+            suggest(&mut NavigationCache::new()),
+            "cursor {cursor}"
+        );
+    }
+}
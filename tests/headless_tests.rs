@@ -0,0 +1,63 @@
+//! Full user flows scripted through the headless API
+
+use jiq::headless::Headless;
+use ratatui::crossterm::event::KeyCode;
+
+const USERS: &str =
+    r#"{"users": [{"name": "ada", "admin": true}, {"name": "bob", "admin": false}]}"#;
+
+#[test]
+fn filter_users_and_read_the_screen() {
+    let mut jiq = Headless::builder(USERS).size(80, 20).build();
+
+    jiq.type_str(".users[] | select(.admin) | .name");
+    assert!(jiq.settle(), "query did not finish");
+
+    assert_eq!(jiq.output().as_deref(), Some("\"ada\"\n"));
+    let screen = jiq.screen();
+    assert!(
+        screen.contains(".users[] | select(.admin) | .name"),
+        "{screen}"
+    );
+    assert!(screen.contains("\"ada\""), "{screen}");
+    assert!(!screen.contains("\"bob\""), "{screen}");
+}
+
+#[test]
+fn fix_a_typo_and_rerun() {
+    let mut jiq = Headless::new(USERS);
+
+    jiq.type_str(".usrs");
+    assert!(jiq.settle());
+    assert_eq!(jiq.output().as_deref(), Some("null\n"));
+
+    for _ in 0..2 {
+        jiq.key(KeyCode::Backspace);
+    }
+    jiq.type_str("ers | length");
+    assert!(jiq.settle());
+
+    assert_eq!(jiq.query(), ".users | length");
+    assert_eq!(jiq.output().as_deref(), Some("2\n"));
+}
+
+#[test]
+fn open_and_close_help() {
+    let mut jiq = Headless::new(USERS);
+    assert!(jiq.settle());
+
+    jiq.key(KeyCode::F(1));
+    assert!(jiq.screen().contains("Keyboard Shortcuts"));
+
+    jiq.key(KeyCode::Esc);
+    assert!(!jiq.screen().contains("Keyboard Shortcuts"));
+}
+
+#[test]
+fn snapshot_filtered_users() {
+    let mut jiq = Headless::builder(USERS).size(70, 14).build();
+    jiq.type_str(".users[] | select(.admin)");
+    assert!(jiq.settle());
+
+    insta::assert_snapshot!(jiq.snapshot());
+}
@@ -23,10 +23,12 @@ fn create_test_loader(json: String) -> jiq::input::FileLoader {
     use std::sync::mpsc::channel;
     let (tx, rx) = channel();
     // Send the result immediately so poll() will return it
-    let _ = tx.send(Ok(json));
+    let _ = tx.send(Ok(json.into()));
     jiq::input::FileLoader {
         state: LoadingState::Loading,
         rx: Some(rx),
+        files_rx: None,
+        malformed_rx: None,
         source: LoaderSource::File,
     }
 }
@@ -76,6 +78,18 @@ fn test_cli_version_flag() {
 // so the message is visible in the user's normal terminal.
 // ============================================================================
 
+#[test]
+fn test_cli_jq_override_that_does_not_exist_errors() {
+    cargo_bin_cmd!()
+        .args(["--jq", "/nonexistent/jq"])
+        .write_stdin(r#"{"a": 1}"#)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "jq binary not found at /nonexistent/jq",
+        ));
+}
+
 #[test]
 fn test_cli_clipboard_with_piped_stdin_errors() {
     cargo_bin_cmd!()
@@ -901,3 +915,64 @@ fn test_visibility_control_mechanisms_complete() {
 
     // Summary: Only config (initial) and toggle (runtime) control visibility
 }
+
+#[test]
+fn test_cli_render_once_prints_a_frame() {
+    cargo_bin_cmd!()
+        .args(["--render-once=60x10", "--query", ".name"])
+        .write_stdin(r#"{"name": "jiq"}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"jiq\""))
+        .stdout(predicate::str::contains("Query"))
+        .stdout(predicate::function(|out: &str| out.lines().count() == 10));
+}
+
+#[test]
+fn test_cli_import_history_adds_queries_and_exits() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let source = dir.path().join(".zsh_history");
+    fs::write(
+        &source,
+        ": 1700000000:0;curl -s api | jq '.items[] | .name'\n\
+         : 1700000001:0;ls -la\n\
+         : 1700000002:0;jq -r --arg id 7 '.[] | select(.id == $id)' data.json\n",
+    )
+    .unwrap();
+
+    let run = || {
+        cargo_bin_cmd!()
+            .arg("--import-history")
+            .arg(&source)
+            .env("XDG_DATA_HOME", dir.path())
+            .env("XDG_CONFIG_HOME", dir.path())
+            .assert()
+            .success()
+    };
+    run().stdout(predicate::str::contains(
+        "Imported 2 queries (0 already in history)",
+    ));
+    run().stdout(predicate::str::contains(
+        "Imported 0 queries (2 already in history)",
+    ));
+
+    let history = fs::read_to_string(dir.path().join("jiq").join("history")).unwrap();
+    assert_eq!(
+        history, ".[] | select(.id == $id)\n.items[] | .name\n",
+        "newest command first"
+    );
+}
+
+#[test]
+fn test_cli_import_history_missing_file_errors() {
+    let dir = tempfile::TempDir::new().unwrap();
+    cargo_bin_cmd!()
+        .args(["--import-history", "/nonexistent/queries"])
+        .env("XDG_DATA_HOME", dir.path())
+        .env("XDG_CONFIG_HOME", dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "can't import /nonexistent/queries",
+        ));
+}
@@ -0,0 +1,56 @@
+//! Performance budget for typing latency.
+//!
+//! Types a query a character at a time into a session over a ~5 MB
+//! document and asserts the main-thread work per keystroke (key handling,
+//! autocomplete, tooltip and a frame) stays within budget. The query runs
+//! themselves happen on the worker thread and aren't counted. See
+//! `benches/keystroke.rs` for the per-step numbers.
+
+use jiq::headless::Headless;
+use serde_json::{Value, json};
+use std::time::{Duration, Instant};
+
+const OBJECTS: usize = 20_000;
+const QUERY: &str = r#".orders[] | select(.total > 100) | {id, city: .customer.address.city}"#;
+
+// Deliberately loose so coverage / debug runs (5-10x slower than release)
+// don't flake; a release build stays well under a millisecond.
+const KEYSTROKE_BUDGET: Duration = Duration::from_millis(100);
+
+fn build_fixture() -> String {
+    let orders: Vec<Value> = (0..OBJECTS)
+        .map(|i| {
+            json!({
+                "id": i,
+                "total": i % 500,
+                "customer": {"name": format!("Customer {i}"), "address": {"city": "Paris"}},
+            })
+        })
+        .collect();
+    serde_json::to_string(&json!({ "orders": orders })).expect("serialize fixture")
+}
+
+#[test]
+fn typing_a_query_stays_within_the_keystroke_budget() {
+    let mut jiq = Headless::new(build_fixture());
+    jiq.settle();
+
+    let mut slowest = Duration::ZERO;
+    let start = Instant::now();
+    for c in QUERY.chars() {
+        let key_start = Instant::now();
+        jiq.type_str(&c.to_string());
+        jiq.screen();
+        slowest = slowest.max(key_start.elapsed());
+    }
+    let average = start.elapsed() / QUERY.chars().count() as u32;
+
+    assert!(
+        average < KEYSTROKE_BUDGET,
+        "average keystroke took {:?} (budget: {:?}; slowest {:?})",
+        average,
+        KEYSTROKE_BUDGET,
+        slowest
+    );
+    eprintln!("keystroke: average {:?}, slowest {:?}", average, slowest);
+}
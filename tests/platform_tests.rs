@@ -0,0 +1,106 @@
+//! Windows behavior, checked on every platform through the seams in
+//! `jiq::platform`
+
+use std::fs;
+use std::path::Path;
+
+use jiq::platform::{clipboard_text, config_dir_in, editor_command, normalize_key};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tempfile::TempDir;
+
+const ALT_GR: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::ALT);
+
+#[test]
+fn config_lives_in_dot_config_outside_windows() {
+    let home = Path::new("/home/user");
+    let roaming = Path::new("/roaming");
+    assert_eq!(
+        config_dir_in(Some(home), Some(roaming), false),
+        Some(home.join(".config").join("jiq"))
+    );
+}
+
+#[test]
+fn config_lives_in_the_roaming_folder_on_windows() {
+    let home = TempDir::new().unwrap();
+    let roaming = TempDir::new().unwrap();
+    assert_eq!(
+        config_dir_in(Some(home.path()), Some(roaming.path()), true),
+        Some(roaming.path().join("jiq"))
+    );
+}
+
+#[test]
+fn an_existing_dot_config_is_kept_on_windows() {
+    let home = TempDir::new().unwrap();
+    let roaming = TempDir::new().unwrap();
+    let dotfile = home.path().join(".config").join("jiq");
+    fs::create_dir_all(&dotfile).unwrap();
+    assert_eq!(
+        config_dir_in(Some(home.path()), Some(roaming.path()), true),
+        Some(dotfile)
+    );
+}
+
+#[test]
+fn windows_falls_back_to_home_without_a_known_folder() {
+    let home = TempDir::new().unwrap();
+    assert_eq!(
+        config_dir_in(Some(home.path()), None, true),
+        Some(home.path().join(".config").join("jiq"))
+    );
+    assert_eq!(config_dir_in(None, None, true), None);
+}
+
+#[test]
+fn windows_clipboard_text_uses_crlf() {
+    assert_eq!(clipboard_text("a\nb\r\nc\n", true), "a\r\nb\r\nc\r\n");
+    assert_eq!(clipboard_text("one line", true), "one line");
+}
+
+#[test]
+fn clipboard_text_is_untouched_elsewhere() {
+    assert_eq!(clipboard_text("a\nb", false), "a\nb");
+}
+
+#[test]
+fn alt_gr_characters_are_typed_on_windows() {
+    for c in ['[', '{', '|', '@', '\\', 'ä'] {
+        let key = normalize_key(KeyEvent::new(KeyCode::Char(c), ALT_GR), true);
+        assert_eq!(key.code, KeyCode::Char(c));
+        assert_eq!(key.modifiers, KeyModifiers::NONE, "{c:?}");
+    }
+}
+
+#[test]
+fn ctrl_alt_letters_stay_shortcuts() {
+    let key = KeyEvent::new(KeyCode::Char('s'), ALT_GR);
+    assert_eq!(normalize_key(key, true), key);
+}
+
+#[test]
+fn ctrl_alt_is_kept_outside_windows() {
+    let key = KeyEvent::new(KeyCode::Char('['), ALT_GR);
+    assert_eq!(normalize_key(key, false), key);
+}
+
+#[test]
+fn plain_ctrl_keys_are_untouched() {
+    let key = KeyEvent::new(KeyCode::Char('['), KeyModifiers::CONTROL);
+    assert_eq!(normalize_key(key, true), key);
+}
+
+#[test]
+fn editor_prefers_visual_then_editor() {
+    assert_eq!(
+        editor_command(Some("code --wait"), Some("nano"), false),
+        ["code", "--wait"]
+    );
+    assert_eq!(editor_command(Some(" "), Some("nano"), false), ["nano"]);
+}
+
+#[test]
+fn editor_falls_back_to_notepad_on_windows() {
+    assert_eq!(editor_command(None, None, true), ["notepad"]);
+    assert_eq!(editor_command(None, Some(""), false), ["vi"]);
+}